@@ -1,6 +1,6 @@
-use boa::{Context, JsString, JsValue};
+use boa::{Context, JsError, JsString};
 
-fn main() -> Result<(), JsValue> {
+fn main() -> Result<(), JsError> {
     let mut context = Context::new();
 
     let variable = JsString::new("I am a captured variable");
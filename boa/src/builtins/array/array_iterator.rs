@@ -4,6 +4,7 @@ use crate::{
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
+    teardown::Contents,
     BoaProfiler, Context, JsResult,
 };
 
@@ -23,6 +24,11 @@ pub struct ArrayIterator {
 impl ArrayIterator {
     pub(crate) const NAME: &'static str = "ArrayIterator";
 
+    /// Queues the iterated array to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_value(&self.array);
+    }
+
     fn new(array: JsValue, kind: PropertyNameKind) -> Self {
         ArrayIterator {
             array,
@@ -73,12 +79,27 @@ impl ArrayIterator {
                         true,
                     ));
                 }
-                let len = array_iterator
-                    .array
-                    .get_field("length", context)?
-                    .as_number()
-                    .ok_or_else(|| context.construct_type_error("Not an array"))?
-                    as u32;
+                let typed_array = array_iterator.array.as_object().and_then(|array| {
+                    array
+                        .borrow()
+                        .as_typed_array()
+                        .map(|inner| (inner.is_out_of_bounds(), inner.array_length()))
+                });
+                let len = match typed_array {
+                    // A typed array whose buffer was detached, or shrunk past its end, can't be
+                    // iterated.
+                    Some((true, _)) => {
+                        return context
+                            .throw_type_error("TypedArray buffer was detached or is out of bounds")
+                    }
+                    Some((false, array_length)) => array_length as u32,
+                    None => array_iterator
+                        .array
+                        .get_field("length", context)?
+                        .as_number()
+                        .ok_or_else(|| context.construct_type_error("Not an array"))?
+                        as u32,
+                };
                 if array_iterator.next_index >= len {
                     array_iterator.array = JsValue::undefined();
                     return Ok(create_iter_result_object(
@@ -0,0 +1,484 @@
+//! This module implements the async closure of `Array.fromAsync`.
+//!
+//! The closure is run as a state machine: every time it awaits a value, the functions resuming it
+//! once the value settles capture its state, like the suspended frame of an async function.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+
+use crate::{
+    builtins::{
+        function::Captures,
+        iterable::{AsyncFromSyncIterator, IteratorRecord},
+        promise::PromiseCapability,
+        Array, Number, Promise,
+    },
+    error::JsErrorCode,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject},
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    Context, JsResult, JsValue,
+};
+
+/// The values an `Array.fromAsync` call adds to its array.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Source {
+    /// An async iterator, or an async-from-sync iterator wrapping a sync one.
+    Iterator(IteratorRecord),
+    /// An array-like object, and its length.
+    ArrayLike { array_like: JsObject, len: usize },
+}
+
+/// The value an `Array.fromAsync` call is awaiting.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Awaiting {
+    /// The result of the `next` method of the iterator.
+    NextResult,
+    /// The value at the current index of the array-like object.
+    Value,
+    /// The value returned by the mapping function.
+    MappedValue,
+    /// The result of the `return` method of the iterator, closed because of the error.
+    Close(JsValue),
+}
+
+/// The state of an `Array.fromAsync` call, between the values it awaits.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(super) struct FromAsync {
+    capability: PromiseCapability,
+    array: JsObject,
+    source: Source,
+    mapfn: Option<JsObject>,
+    this_arg: JsValue,
+    /// The index of the next value added to the array.
+    k: usize,
+    awaiting: Awaiting,
+}
+
+impl FromAsync {
+    /// Runs the closure of `Array.fromAsync` until it awaits its first value, settling the
+    /// promise of the capability once the array is complete.
+    pub(super) fn start(
+        this: &JsValue,
+        args: &[JsValue],
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match Self::create(this, args, capability.clone(), context) {
+            Ok(mut from_async) => {
+                let step = from_async.next_step(context);
+                from_async.drive(step, context)?;
+            }
+            Err(error) => {
+                capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[error], context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the steps of the closure preceding its loop, creating the array and finding the
+    /// values to add to it.
+    fn create(
+        this: &JsValue,
+        args: &[JsValue],
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let async_items = args.get_or_undefined(0);
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapfn = match args.get_or_undefined(1) {
+            JsValue::Undefined => None,
+            JsValue::Object(mapfn) if mapfn.is_callable() => Some(mapfn.clone()),
+            _ => {
+                return Err(
+                    context.construct_type_error("Array.fromAsync: mapper is not a function")
+                )
+            }
+        };
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let items = async_items.to_object(context)?;
+        let using_async_iterator = items.get_method(context, WellKnownSymbols::async_iterator())?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        // e. Let iteratorRecord be undefined.
+        // f. If usingAsyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+        // g. Else if usingSyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+        let iterator_record = match using_async_iterator {
+            Some(method) => Some(get_iterator_from_method(async_items, &method, context)?),
+            None => match items.get_method(context, WellKnownSymbols::iterator())? {
+                Some(method) => {
+                    let sync_iterator_record =
+                        get_iterator_from_method(async_items, &method, context)?;
+                    Some(AsyncFromSyncIterator::create(
+                        sync_iterator_record,
+                        context,
+                    )?)
+                }
+                None => None,
+            },
+        };
+
+        // h. If iteratorRecord is not undefined, then
+        let (array, source) = if let Some(iterator_record) = iterator_record {
+            // i. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C).
+            // ii. Else,
+            //     1. Let A be ! ArrayCreate(0).
+            let array = match this.as_object() {
+                Some(constructor) if constructor.is_constructable() => constructor
+                    .construct(&[], this, context)?
+                    .as_object()
+                    .expect("constructors return objects"),
+                _ => Array::array_create(0, None, context)?,
+            };
+            (array, Source::Iterator(iterator_record))
+        } else {
+            // i. Else,
+            // i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+            // ii. Let arrayLike be ! ToObject(asyncItems).
+            // iii. Let len be ? LengthOfArrayLike(arrayLike).
+            let len = items.length_of_array_like(context)?;
+
+            // iv. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C, « 𝔽(len) »).
+            // v. Else,
+            //     1. Let A be ? ArrayCreate(len).
+            let array = match this.as_object() {
+                Some(constructor) if constructor.is_constructable() => constructor
+                    .construct(&[len.into()], this, context)?
+                    .as_object()
+                    .expect("constructors return objects"),
+                _ => Array::array_create(len, None, context)?,
+            };
+            let source = Source::ArrayLike {
+                array_like: items,
+                len,
+            };
+            (array, source)
+        };
+
+        // Let k be 0.
+        Ok(Self {
+            capability,
+            array,
+            source,
+            mapfn,
+            this_arg,
+            k: 0,
+            awaiting: Awaiting::NextResult,
+        })
+    }
+
+    /// Awaits the values returned by the steps of the closure, until it returns the array or
+    /// throws, settling the promise of the capability.
+    ///
+    /// A step returns the value it awaits, or `None` once the array is complete.
+    fn drive(
+        mut self,
+        mut step: JsResult<Option<JsValue>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        loop {
+            let value = match step {
+                Ok(Some(value)) => value,
+                Ok(None) => {
+                    return self.capability.resolve().call(
+                        &JsValue::undefined(),
+                        &[self.array.clone().into()],
+                        context,
+                    );
+                }
+                Err(error) => {
+                    return self
+                        .capability
+                        .reject()
+                        .call(&JsValue::undefined(), &[error], context);
+                }
+            };
+
+            // Await ( value )
+            // 2. Let promise be ? PromiseResolve(%Promise%, value).
+            let constructor = context
+                .standard_objects()
+                .promise_object()
+                .constructor()
+                .into();
+            let promise = match Promise::promise_resolve(&constructor, value, context) {
+                Ok(promise) => promise,
+                Err(error) => {
+                    step = self.resume(Err(error), context);
+                    continue;
+                }
+            };
+
+            // 3-6. Let onFulfilled and onRejected be functions resuming the closure with the
+            //      settled value.
+            let on_fulfilled = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    let value = args.get_or_undefined(0).clone();
+                    Self::resume_awaiting(captures, Ok(value), context)
+                },
+                self.clone(),
+            )
+            .length(1)
+            .build();
+            let on_rejected = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    let reason = args.get_or_undefined(0).clone();
+                    Self::resume_awaiting(captures, Err(reason), context)
+                },
+                self,
+            )
+            .length(1)
+            .build();
+
+            // 7. Perform ! PerformPromiseThen(promise, onFulfilled, onRejected).
+            let promise = promise
+                .as_object()
+                .expect("PromiseResolve returns a promise");
+            Promise::perform_promise_then(
+                &promise,
+                &on_fulfilled.into(),
+                &on_rejected.into(),
+                None,
+                context,
+            );
+            return Ok(JsValue::undefined());
+        }
+    }
+
+    /// Resumes the closure once the value it awaits settles.
+    fn resume_awaiting(
+        captures: &Captures,
+        completion: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let mut from_async = captures
+            .downcast_ref::<Self>()
+            .expect("Array.fromAsync functions capture its state")
+            .clone();
+        let step = from_async.resume(completion, context);
+        from_async.drive(step, context)
+    }
+
+    /// Runs the steps of the closure following the await of a value.
+    fn resume(
+        &mut self,
+        completion: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        let awaiting = std::mem::replace(&mut self.awaiting, Awaiting::NextResult);
+        match awaiting {
+            Awaiting::NextResult => {
+                // 4. Set nextResult to ? Await(nextResult).
+                let next_result = completion?;
+
+                // 5. If nextResult is not an Object, throw a TypeError exception.
+                if !next_result.is_object() {
+                    return Err(context.construct_coded_error(
+                        JsErrorCode::ITERATOR_RESULT_NOT_AN_OBJECT,
+                        "iterator result is not an object",
+                    ));
+                }
+
+                // 6. Let done be ? IteratorComplete(nextResult).
+                // 7. If done is true,
+                //     a. Perform ? Set(A, "length", 𝔽(k), true).
+                //     b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                if next_result.get_field("done", context)?.to_boolean() {
+                    self.array.set("length", self.k, true, context)?;
+                    return Ok(None);
+                }
+
+                // 8. Let nextValue be ? IteratorValue(nextResult).
+                let next_value = next_result.get_field("value", context)?;
+
+                // 9. If mapping is true, then
+                //     a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                //     b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                //     c. Set mappedValue to Await(mappedValue).
+                // 10. Else, let mappedValue be nextValue.
+                if let Some(ref mapfn) = self.mapfn {
+                    return match mapfn.call(&self.this_arg, &[next_value, self.k.into()], context) {
+                        Ok(mapped_value) => {
+                            self.awaiting = Awaiting::MappedValue;
+                            Ok(Some(mapped_value))
+                        }
+                        Err(error) => self.close(error, context),
+                    };
+                }
+                self.add_value(next_value, context)
+            }
+            Awaiting::Value => {
+                // 3. Set kValue to ? Await(kValue).
+                let k_value = completion?;
+
+                // 4. If mapping is true, then
+                //     a. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+                //     b. Set mappedValue to ? Await(mappedValue).
+                // 5. Else, let mappedValue be kValue.
+                if let Some(ref mapfn) = self.mapfn {
+                    let mapped_value =
+                        mapfn.call(&self.this_arg, &[k_value, self.k.into()], context)?;
+                    self.awaiting = Awaiting::MappedValue;
+                    return Ok(Some(mapped_value));
+                }
+                self.add_value(k_value, context)
+            }
+            Awaiting::MappedValue => {
+                // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                let mapped_value = match completion {
+                    Ok(mapped_value) => mapped_value,
+                    Err(error) if matches!(self.source, Source::Iterator(_)) => {
+                        return self.close(error, context)
+                    }
+                    Err(error) => return Err(error),
+                };
+                self.add_value(mapped_value, context)
+            }
+            // AsyncIteratorClose ( iteratorRecord, completion )
+            // 6. If completion.[[Type]] is throw, return ? completion.
+            Awaiting::Close(ref error) => Err(error.clone()),
+        }
+    }
+
+    /// Adds a value to the array, then runs the next iteration of the loop of the closure.
+    fn add_value(&mut self, value: JsValue, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        let define_status = self
+            .array
+            .create_data_property_or_throw(self.k, value, context);
+        if let Err(error) = define_status {
+            // If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+            return match self.source {
+                Source::Iterator(_) => self.close(error, context),
+                Source::ArrayLike { .. } => Err(error),
+            };
+        }
+
+        // Set k to k + 1.
+        self.k += 1;
+        self.next_step(context)
+    }
+
+    /// Runs the steps of an iteration of the loop of the closure, until its first await.
+    fn next_step(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        match self.source {
+            Source::Iterator(ref iterator_record) => {
+                // 1. If k ≥ 2^53 - 1, then
+                //     a. Let error be ThrowCompletion(a newly created TypeError object).
+                //     b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                if self.k as f64 >= Number::MAX_SAFE_INTEGER {
+                    let error = context.construct_type_error("Array.fromAsync: too many values");
+                    return self.close(error, context);
+                }
+
+                // 2. Let Pk be ! ToString(𝔽(k)).
+                // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+                let next_result = context.call(
+                    iterator_record.next_function(),
+                    iterator_record.iterator_object(),
+                    &[],
+                )?;
+                self.awaiting = Awaiting::NextResult;
+                Ok(Some(next_result))
+            }
+            Source::ArrayLike {
+                ref array_like,
+                len,
+            } => {
+                // vi. Repeat, while k < len,
+                if self.k < len {
+                    // 1. Let Pk be ! ToString(𝔽(k)).
+                    // 2. Let kValue be ? Get(arrayLike, Pk).
+                    let k_value = array_like.get(self.k, context)?;
+                    self.awaiting = Awaiting::Value;
+                    return Ok(Some(k_value));
+                }
+
+                // vii. Perform ? Set(A, "length", 𝔽(len), true).
+                // viii. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                self.array.set("length", len, true, context)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// `AsyncIteratorClose ( iteratorRecord, completion )`, for a throw completion.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+    fn close(&mut self, error: JsValue, context: &mut Context) -> JsResult<Option<JsValue>> {
+        let iterator = match self.source {
+            Source::Iterator(ref iterator_record) => iterator_record.iterator_object().clone(),
+            Source::ArrayLike { .. } => return Err(error),
+        };
+        let iterator_object = iterator.as_object().expect("iterators are objects");
+
+        // 4. Let innerResult be Completion(GetMethod(iterator, "return")).
+        // 5. If innerResult.[[Type]] is normal, then
+        //     a. Let return be innerResult.[[Value]].
+        //     b. If return is undefined, return ? completion.
+        //     c. Set innerResult to Completion(Call(return, iterator)).
+        //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+        // 6. If completion.[[Type]] is throw, return ? completion.
+        match iterator_object.get_method(context, "return") {
+            Ok(Some(r#return)) => match r#return.call(&iterator, &[], context) {
+                Ok(inner_result) => {
+                    self.awaiting = Awaiting::Close(error);
+                    Ok(Some(inner_result))
+                }
+                Err(_) => Err(error),
+            },
+            _ => Err(error),
+        }
+    }
+}
+
+/// `GetIteratorFromMethod ( obj, method )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratorfrommethod
+fn get_iterator_from_method(
+    obj: &JsValue,
+    method: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let iterator be ? Call(method, obj).
+    let iterator = method.call(obj, &[], context)?;
+
+    // 2. If iterator is not an Object, throw a TypeError exception.
+    if !iterator.is_object() {
+        return Err(context.construct_coded_error(
+            JsErrorCode::ITERATOR_NOT_AN_OBJECT,
+            "iterator is not an object",
+        ));
+    }
+
+    // 3. Let nextMethod be ? Get(iterator, "next").
+    let next_method = iterator.get_field("next", context)?;
+
+    // 4. Let iteratorRecord be the Iterator Record { [[Iterator]]: iterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 5. Return iteratorRecord.
+    Ok(IteratorRecord::new(iterator, next_method))
+}
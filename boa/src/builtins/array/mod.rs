@@ -10,20 +10,28 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
 
 pub mod array_iterator;
+mod from_async;
 #[cfg(test)]
 mod tests;
 
+use self::from_async::FromAsync;
 use crate::{
     builtins::array::array_iterator::ArrayIterator,
     builtins::BuiltIn,
     builtins::Number,
-    object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectData, PROTOTYPE},
+    builtins::Promise,
+    error::JsErrorCode,
+    object::{ConstructorBuilder, FunctionBuilder, JsObject, Object, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     value::{IntegerOrInfinity, JsValue},
     BoaProfiler, Context, JsResult, JsString,
 };
-use std::cmp::{max, min, Ordering};
+use std::{
+    cmp::{max, min, Ordering},
+    convert::TryFrom,
+};
 
 /// JavaScript `Array` built-in implementation.
 #[derive(Debug, Clone, Copy)]
@@ -33,7 +41,7 @@ impl BuiltIn for Array {
     const NAME: &'static str = "Array";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -52,6 +60,8 @@ impl BuiltIn for Array {
             .constructable(false)
             .build();
 
+        let unscopables_object = Self::unscopables_intrinsic();
+
         let array = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -65,20 +75,13 @@ impl BuiltIn for Array {
             None,
             Attribute::CONFIGURABLE,
         )
+        .property("length", 0, Attribute::builder().writable().build())
+        .property("values", values_function.clone(), Attribute::builtin())
+        .property(symbol_iterator, values_function, Attribute::builtin())
         .property(
-            "length",
-            0,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-        )
-        .property(
-            "values",
-            values_function.clone(),
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
-        .property(
-            symbol_iterator,
-            values_function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            WellKnownSymbols::unscopables(),
+            unscopables_object,
+            Attribute::CONFIGURABLE,
         )
         .method(Self::concat, "concat", 1)
         .method(Self::push, "push", 1)
@@ -101,16 +104,22 @@ impl BuiltIn for Array {
         .method(Self::flat, "flat", 0)
         .method(Self::flat_map, "flatMap", 1)
         .method(Self::slice, "slice", 2)
-        .method(Self::some, "some", 2)
+        .method(Self::splice, "splice", 2)
+        .method(Self::some, "some", 1)
         .method(Self::sort, "sort", 1)
-        .method(Self::reduce, "reduce", 2)
-        .method(Self::reduce_right, "reduceRight", 2)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::reduce_right, "reduceRight", 1)
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
-        .method(Self::copy_within, "copyWithin", 3)
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::to_reversed, "toReversed", 0)
+        .method(Self::to_sorted, "toSorted", 1)
+        .method(Self::to_spliced, "toSpliced", 2)
+        .method(Self::with, "with", 2)
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
         .static_method(Self::of, "of", 0)
+        .static_method(Self::from_async, "fromAsync", 1)
         .build();
 
         (Self::NAME, array.into(), Self::attribute())
@@ -120,6 +129,52 @@ impl BuiltIn for Array {
 impl Array {
     const LENGTH: usize = 1;
 
+    /// Creates the `Array.prototype[@@unscopables]` object, naming the methods that are hidden
+    /// from `with` statements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype-@@unscopables
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@unscopables
+    fn unscopables_intrinsic() -> JsObject {
+        // 1. Let unscopableList be OrdinaryObjectCreate(null).
+        let unscopable_list = JsObject::new(Object::default());
+
+        // 2-17. Perform ! CreateDataPropertyOrThrow(unscopableList, name, true) for each name.
+        for name in [
+            "at",
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "findLast",
+            "findLastIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "toReversed",
+            "toSorted",
+            "toSpliced",
+            "values",
+        ] {
+            unscopable_list.insert(
+                name,
+                PropertyDescriptor::builder()
+                    .value(true)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true),
+            );
+        }
+
+        // 18. Return unscopableList.
+        unscopable_list
+    }
+
     fn constructor(
         new_target: &JsValue,
         args: &[JsValue],
@@ -311,10 +366,7 @@ impl Array {
             return Ok(spreadable.to_boolean());
         }
         // 4. Return ? IsArray(O).
-        match this.as_object() {
-            Some(obj) => Ok(obj.is_array()),
-            _ => Ok(false),
-        }
+        this.is_array(context)
     }
 
     /// `get Array [ @@species ]`
@@ -343,7 +395,7 @@ impl Array {
     ) -> JsResult<JsObject> {
         // 1. Let isArray be ? IsArray(originalArray).
         // 2. If isArray is false, return ? ArrayCreate(length).
-        if !original_array.is_array() {
+        if !JsValue::from(original_array.clone()).is_array(context)? {
             return Self::array_create(length, None, context);
         }
         // 3. Let C be ? Get(originalArray, "constructor").
@@ -378,7 +430,10 @@ impl Array {
         // 7. If IsConstructor(C) is false, throw a TypeError exception.
         if let Some(c) = c.as_object() {
             if !c.is_constructable() {
-                return Err(context.construct_type_error("Symbol.species must be a constructor"));
+                return Err(context.construct_coded_error(
+                    JsErrorCode::SPECIES_NOT_A_CONSTRUCTOR,
+                    "Symbol.species must be a constructor",
+                ));
             }
             // 8. Return ? Construct(C, « 𝔽(length) »).
             Ok(
@@ -387,7 +442,10 @@ impl Array {
                     .unwrap(),
             )
         } else {
-            Err(context.construct_type_error("Symbol.species must be a constructor"))
+            Err(context.construct_coded_error(
+                JsErrorCode::SPECIES_NOT_A_CONSTRUCTOR,
+                "Symbol.species must be a constructor",
+            ))
         }
     }
 
@@ -433,11 +491,13 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.isarray
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/isArray
-    pub(crate) fn is_array(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        match args.get(0).and_then(|x| x.as_object()) {
-            Some(object) => Ok(JsValue::new(object.borrow().is_array())),
-            None => Ok(JsValue::new(false)),
-        }
+    pub(crate) fn is_array(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? IsArray(arg).
+        args.get_or_undefined(0).is_array(context).map(JsValue::new)
     }
 
     /// `Array.of(...items)`
@@ -486,6 +546,40 @@ impl Array {
         Ok(a.into())
     }
 
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// The `Array.fromAsync()` method creates a new array from the values of an async iterable,
+    /// an iterable or an array-like object, awaiting each of them. It returns a promise of the
+    /// array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let capability = Promise::new_promise_capability(&constructor, context)?;
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C, mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        FromAsync::start(this, args, capability.clone(), context)?;
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise().clone().into())
+    }
+
     /// `Array.prototype.concat(...arguments)`
     ///
     /// When the concat method is called with zero or more arguments, it returns an
@@ -687,7 +781,7 @@ impl Array {
                 // i. Let kValue be ? Get(O, Pk).
                 let k_value = o.get(pk, context)?;
                 // ii. Perform ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
-                let this_arg = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+                let this_arg = args.get_or_undefined(1).clone();
                 callback.call(&this_arg, &[k_value, k.into(), o.clone().into()], context)?;
             }
             // d. Set k to k + 1.
@@ -730,6 +824,7 @@ impl Array {
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         for k in 0..len {
+            context.check_interrupt()?;
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
                 r.push_str(&separator);
@@ -1022,7 +1117,7 @@ impl Array {
             return context.throw_type_error("Array.prototype.every: callback is not callable");
         };
 
-        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get_or_undefined(1).clone();
 
         // 4. Let k be 0.
         // 5. Repeat, while k < len,
@@ -1070,7 +1165,7 @@ impl Array {
         // 2. Let len be ? LengthOfArrayLike(O).
         let len = o.length_of_array_like(context)?;
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
-        let callback = args.get(0).cloned().unwrap_or_default();
+        let callback = args.get_or_undefined(0).clone();
         if !callback.is_function() {
             return context.throw_type_error("Array.prototype.map: Callbackfn is not callable");
         }
@@ -1078,7 +1173,7 @@ impl Array {
         // 4. Let A be ? ArraySpeciesCreate(O, len).
         let a = Self::array_species_create(&o, len, context)?;
 
-        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get_or_undefined(1).clone();
 
         // 5. Let k be 0.
         // 6. Repeat, while k < len,
@@ -1102,6 +1197,30 @@ impl Array {
         Ok(a.into())
     }
 
+    /// Calls `f` with the elements `start..end` of `o`, if `o` is an array holding all of them as
+    /// data properties in its dense element store.
+    ///
+    /// Reading these elements has no side effects, so the searches of `indexOf`, `lastIndexOf` and
+    /// `includes` compare them directly instead of going through `[[HasProperty]]` and `[[Get]]`.
+    fn with_dense_elements<R>(
+        o: &JsObject,
+        start: i64,
+        end: i64,
+        f: impl FnOnce(&[PropertyDescriptor]) -> R,
+    ) -> Option<R> {
+        let o = o.borrow();
+        if !o.is_array() {
+            return None;
+        }
+
+        let range = usize::try_from(start).ok()?..usize::try_from(end).ok()?;
+        let elements = o.properties().dense_index_properties()?.get(range)?;
+        if !elements.iter().all(PropertyDescriptor::is_data_descriptor) {
+            return None;
+        }
+        Some(f(elements))
+    }
+
     /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
     ///
     /// More information:
@@ -1156,7 +1275,18 @@ impl Array {
             }
         };
 
-        let search_element = args.get(0).cloned().unwrap_or_default();
+        let search_element = args.get_or_undefined(0).clone();
+
+        if let Some(found) = Self::with_dense_elements(&o, k, len, |elements| {
+            elements
+                .iter()
+                .position(|element| search_element.strict_equals(element.expect_value()))
+        }) {
+            return Ok(match found {
+                Some(index) => JsValue::new(k + index as i64),
+                None => JsValue::new(-1),
+            });
+        }
 
         // 10. Repeat, while k < len,
         while k < len {
@@ -1232,7 +1362,18 @@ impl Array {
             IntegerOrInfinity::Integer(n) => len + n,
         };
 
-        let search_element = args.get(0).cloned().unwrap_or_default();
+        let search_element = args.get_or_undefined(0).clone();
+
+        if let Some(found) = Self::with_dense_elements(&o, 0, k + 1, |elements| {
+            elements
+                .iter()
+                .rposition(|element| search_element.strict_equals(element.expect_value()))
+        }) {
+            return Ok(match found {
+                Some(index) => JsValue::new(index as i64),
+                None => JsValue::new(-1),
+            });
+        }
 
         // 8. Repeat, while k ≥ 0,
         while k >= 0 {
@@ -1286,7 +1427,7 @@ impl Array {
             }
         };
 
-        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get_or_undefined(1).clone();
 
         // 4. Let k be 0.
         let mut k = 0;
@@ -1347,7 +1488,7 @@ impl Array {
             }
         };
 
-        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get_or_undefined(1).clone();
 
         // 4. Let k be 0.
         let mut k = 0;
@@ -1451,7 +1592,7 @@ impl Array {
         let source_len = o.length_of_array_like(context)?;
 
         // 3. If ! IsCallable(mapperFunction) is false, throw a TypeError exception.
-        let mapper_function = args.get(0).cloned().unwrap_or_default();
+        let mapper_function = args.get_or_undefined(0).clone();
         if !mapper_function.is_function() {
             return context.throw_type_error("flatMap mapper function is not callable");
         }
@@ -1467,7 +1608,7 @@ impl Array {
             0,
             1,
             Some(mapper_function.as_object().unwrap()),
-            &args.get(1).cloned().unwrap_or_default(),
+            &args.get_or_undefined(1).clone(),
             context,
         )?;
 
@@ -1622,7 +1763,7 @@ impl Array {
         // 10. Else, let final be min(relativeEnd, len).
         let final_ = Self::get_relative_end(context, args.get(2), len)?;
 
-        let value = args.get(0).cloned().unwrap_or_default();
+        let value = args.get_or_undefined(0).clone();
 
         // 11. Repeat, while k < final,
         while k < final_ {
@@ -1693,7 +1834,15 @@ impl Array {
             }
         }
 
-        let search_element = args.get(0).cloned().unwrap_or_default();
+        let search_element = args.get_or_undefined(0).clone();
+
+        if let Some(found) = Self::with_dense_elements(&o, k, len, |elements| {
+            elements
+                .iter()
+                .any(|element| JsValue::same_value_zero(&search_element, element.expect_value()))
+        }) {
+            return Ok(JsValue::new(found));
+        }
 
         // 10. Repeat, while k < len,
         while k < len {
@@ -1781,6 +1930,164 @@ impl Array {
         Ok(a.into())
     }
 
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// The splice method removes `deleteCount` elements of the array from element `start`, inserts
+    /// the `items` in their place, and returns an array containing the removed elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart is -∞, let actualStart be 0.
+        // 5. Else if relativeStart < 0, let actualStart be max(len + relativeStart, 0).
+        // 6. Else, let actualStart be min(relativeStart, len).
+        let actual_start = Self::get_relative_start(context, args.get(0), len)?;
+
+        let items = args.get(2..).unwrap_or_default();
+        let (item_count, actual_delete_count) = match args.get(1) {
+            // 7. If start is not present, then
+            //     a. Let insertCount be 0.
+            //     b. Let actualDeleteCount be 0.
+            _ if args.is_empty() => (0, 0),
+            // 8. Else if deleteCount is not present, then
+            //     a. Let insertCount be 0.
+            //     b. Let actualDeleteCount be len - actualStart.
+            None => (0, len - actual_start),
+            // 9. Else,
+            Some(delete_count) => {
+                // a. Let insertCount be the number of elements in items.
+                // b. Let dc be ? ToIntegerOrInfinity(deleteCount).
+                // c. Let actualDeleteCount be the result of clamping dc between 0 and len - actualStart.
+                let actual_delete_count = match delete_count.to_integer_or_infinity(context)? {
+                    IntegerOrInfinity::Integer(dc) => {
+                        dc.clamp(0, (len - actual_start) as i64) as usize
+                    }
+                    IntegerOrInfinity::PositiveInfinity => len - actual_start,
+                    IntegerOrInfinity::NegativeInfinity => 0,
+                };
+                (items.len(), actual_delete_count)
+            }
+        };
+
+        // 10. If len + insertCount - actualDeleteCount > 2^53 - 1, throw a TypeError exception.
+        let new_len = len + item_count - actual_delete_count;
+        if new_len as u64 > Number::MAX_SAFE_INTEGER as u64 {
+            return context.throw_type_error("Array.prototype.splice: new length is too large");
+        }
+
+        // 11. Let A be ? ArraySpeciesCreate(O, actualDeleteCount).
+        let a = Self::array_species_create(&o, actual_delete_count, context)?;
+
+        // 12. Let k be 0.
+        // 13. Repeat, while k < actualDeleteCount,
+        for k in 0..actual_delete_count {
+            // a. Let from be ! ToString(𝔽(actualStart + k)).
+            let from = actual_start + k;
+            // b. Let fromPresent be ? HasProperty(O, from).
+            // c. If fromPresent is true, then
+            if o.has_property(from, context)? {
+                // i. Let fromValue be ? Get(O, from).
+                let from_value = o.get(from, context)?;
+                // ii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(k)), fromValue).
+                a.create_data_property_or_throw(k, from_value, context)?;
+            }
+            // d. Set k to k + 1.
+        }
+
+        // 14. Perform ? Set(A, "length", 𝔽(actualDeleteCount), true).
+        a.set("length", actual_delete_count, true, context)?;
+
+        // 15. Let itemCount be the number of elements in items.
+        // 16. If itemCount < actualDeleteCount, then
+        if item_count < actual_delete_count {
+            // a. Set k to actualStart.
+            // b. Repeat, while k < (len - actualDeleteCount),
+            for k in actual_start..len - actual_delete_count {
+                context.check_interrupt()?;
+                // i. Let from be ! ToString(𝔽(k + actualDeleteCount)).
+                let from = k + actual_delete_count;
+                // ii. Let to be ! ToString(𝔽(k + itemCount)).
+                let to = k + item_count;
+                // iii. Let fromPresent be ? HasProperty(O, from).
+                // iv. If fromPresent is true, then
+                if o.has_property(from, context)? {
+                    // 1. Let fromValue be ? Get(O, from).
+                    let from_value = o.get(from, context)?;
+                    // 2. Perform ? Set(O, to, fromValue, true).
+                    o.set(to, from_value, true, context)?;
+                // v. Else,
+                } else {
+                    // 1. Assert: fromPresent is false.
+                    // 2. Perform ? DeletePropertyOrThrow(O, to).
+                    o.delete_property_or_throw(to, context)?;
+                }
+                // vi. Set k to k + 1.
+            }
+            // c. Set k to len.
+            // d. Repeat, while k > (len - actualDeleteCount + itemCount),
+            for k in (new_len..len).rev() {
+                context.check_interrupt()?;
+                // i. Perform ? DeletePropertyOrThrow(O, ! ToString(𝔽(k - 1))).
+                o.delete_property_or_throw(k, context)?;
+                // ii. Set k to k - 1.
+            }
+        // 17. Else if itemCount > actualDeleteCount, then
+        } else if item_count > actual_delete_count {
+            // a. Set k to (len - actualDeleteCount).
+            // b. Repeat, while k > actualStart,
+            for k in (actual_start..len - actual_delete_count).rev() {
+                context.check_interrupt()?;
+                // i. Let from be ! ToString(𝔽(k + actualDeleteCount - 1)).
+                let from = k + actual_delete_count;
+                // ii. Let to be ! ToString(𝔽(k + itemCount - 1)).
+                let to = k + item_count;
+                // iii. Let fromPresent be ? HasProperty(O, from).
+                // iv. If fromPresent is true, then
+                if o.has_property(from, context)? {
+                    // 1. Let fromValue be ? Get(O, from).
+                    let from_value = o.get(from, context)?;
+                    // 2. Perform ? Set(O, to, fromValue, true).
+                    o.set(to, from_value, true, context)?;
+                // v. Else,
+                } else {
+                    // 1. Assert: fromPresent is false.
+                    // 2. Perform ? DeletePropertyOrThrow(O, to).
+                    o.delete_property_or_throw(to, context)?;
+                }
+                // vi. Set k to k - 1.
+            }
+        }
+
+        // 18. Set k to actualStart.
+        // 19. For each element E of items, do
+        for (k, e) in (actual_start..).zip(&items[..item_count]) {
+            // a. Perform ? Set(O, ! ToString(𝔽(k)), E, true).
+            o.set(k, e.clone(), true, context)?;
+            // b. Set k to k + 1.
+        }
+
+        // 20. Perform ? Set(O, "length", 𝔽(len - actualDeleteCount + itemCount), true).
+        o.set("length", new_len, true, context)?;
+
+        // 21. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.filter( callback, [ thisArg ] )`
     ///
     /// For each element in the array the callback function is called, and a new
@@ -1813,7 +2120,7 @@ impl Array {
                     "missing argument 0 when calling function Array.prototype.filter",
                 )
             })?;
-        let this_val = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let this_val = args.get_or_undefined(1).clone();
 
         if !callback.is_callable() {
             return context.throw_type_error("the callback must be callable");
@@ -1899,7 +2206,7 @@ impl Array {
                 // i. Let kValue be ? Get(O, Pk).
                 let k_value = o.get(k, context)?;
                 // ii. Let testResult be ! ToBoolean(? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »)).
-                let this_arg = args.get(1).cloned().unwrap_or_default();
+                let this_arg = args.get_or_undefined(1).clone();
                 let test_result = callback
                     .call(&this_arg, &[k_value, k.into(), o.clone().into()], context)?
                     .to_boolean();
@@ -1932,61 +2239,7 @@ impl Array {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
-        let comparefn = match args.get(0).cloned() {
-            // todo: change to `is_callable` inside `JsValue`
-            Some(fun) if fun.is_function() => fun,
-            None => JsValue::undefined(),
-            _ => {
-                return context.throw_type_error(
-                    "The comparison function must be either a function or undefined",
-                )
-            }
-        };
-
-        // Abstract method `SortCompare`.
-        //
-        // More information:
-        //  - [ECMAScript reference][spec]
-        //
-        // [spec]: https://tc39.es/ecma262/#sec-sortcompare
-        let sort_compare =
-            |x: &JsValue, y: &JsValue, context: &mut Context| -> JsResult<Ordering> {
-                match (x.is_undefined(), y.is_undefined()) {
-                    // 1. If x and y are both undefined, return +0𝔽.
-                    (true, true) => return Ok(Ordering::Equal),
-                    // 2. If x is undefined, return 1𝔽.
-                    (true, false) => return Ok(Ordering::Greater),
-                    // 3. If y is undefined, return -1𝔽.
-                    (false, true) => return Ok(Ordering::Less),
-                    _ => {}
-                }
-
-                // 4. If comparefn is not undefined, then
-                if !comparefn.is_undefined() {
-                    let args = [x.clone(), y.clone()];
-                    // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                    let v = context
-                        .call(&comparefn, &JsValue::Undefined, &args)?
-                        .to_number(context)?;
-                    // b. If v is NaN, return +0𝔽.
-                    // c. Return v.
-                    return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
-                }
-                // 5. Let xString be ? ToString(x).
-                // 6. Let yString be ? ToString(y).
-                let x_str = x.to_string(context)?;
-                let y_str = y.to_string(context)?;
-
-                // 7. Let xSmaller be IsLessThan(xString, yString, true).
-                // 8. If xSmaller is true, return -1𝔽.
-                // 9. Let ySmaller be IsLessThan(yString, xString, true).
-                // 10. If ySmaller is true, return 1𝔽.
-                // 11. Return +0𝔽.
-
-                // NOTE: skipped IsLessThan because it just makes a lexicographic comparation
-                // when x and y are strings
-                Ok(x_str.cmp(&y_str))
-            };
+        let comparefn = Self::comparefn(args, context)?;
 
         // 2. Let obj be ? ToObject(this value).
         let obj = this.to_object(context)?;
@@ -2000,6 +2253,7 @@ impl Array {
         // 5. Let k be 0.
         // 6. Repeat, while k < len,
         for k in 0..length {
+            context.check_interrupt()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             // b. Let kPresent be ? HasProperty(obj, Pk).
             // c. If kPresent is true, then
@@ -2018,18 +2272,7 @@ impl Array {
         // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
         // If any such call returns an abrupt completion, stop before performing any further
         // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
+        Self::sort_values(&mut items, &comparefn, context)?;
 
         // 9. Let j be 0.
         // 10. Repeat, while j < itemCount,
@@ -2050,6 +2293,343 @@ impl Array {
         Ok(obj.into())
     }
 
+    /// Returns the comparison function of `sort` and `toSorted`, which must be either a function
+    /// or undefined.
+    fn comparefn(args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        match args.get(0).cloned() {
+            // todo: change to `is_callable` inside `JsValue`
+            Some(fun) if fun.is_function() => Ok(fun),
+            None | Some(JsValue::Undefined) => Ok(JsValue::undefined()),
+            _ => context
+                .throw_type_error("The comparison function must be either a function or undefined"),
+        }
+    }
+
+    /// Sorts `items` using `SortCompare` with the given comparison function.
+    ///
+    /// If any call to `SortCompare` returns an abrupt completion, no further calls are made and
+    /// that completion is returned. The sort can also be interrupted by the interrupt handler.
+    fn sort_values(
+        items: &mut [JsValue],
+        comparefn: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let mut sort_err = Ok(());
+        items.sort_by(|x, y| {
+            if sort_err.is_ok() {
+                context
+                    .check_interrupt()
+                    .and_then(|_| Self::sort_compare(comparefn, x, y, context))
+                    .unwrap_or_else(|err| {
+                        sort_err = Err(err);
+                        Ordering::Equal
+                    })
+            } else {
+                Ordering::Equal
+            }
+        });
+        sort_err
+    }
+
+    /// Abstract method `SortCompare`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortcompare
+    fn sort_compare(
+        comparefn: &JsValue,
+        x: &JsValue,
+        y: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Ordering> {
+        match (x.is_undefined(), y.is_undefined()) {
+            // 1. If x and y are both undefined, return +0𝔽.
+            (true, true) => return Ok(Ordering::Equal),
+            // 2. If x is undefined, return 1𝔽.
+            (true, false) => return Ok(Ordering::Greater),
+            // 3. If y is undefined, return -1𝔽.
+            (false, true) => return Ok(Ordering::Less),
+            _ => {}
+        }
+
+        // 4. If comparefn is not undefined, then
+        if !comparefn.is_undefined() {
+            let args = [x.clone(), y.clone()];
+            // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+            let v = context
+                .call(comparefn, &JsValue::Undefined, &args)?
+                .to_number(context)?;
+            // b. If v is NaN, return +0𝔽.
+            // c. Return v.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+        // 5. Let xString be ? ToString(x).
+        // 6. Let yString be ? ToString(y).
+        let x_str = x.to_string(context)?;
+        let y_str = y.to_string(context)?;
+
+        // 7. Let xSmaller be IsLessThan(xString, yString, true).
+        // 8. If xSmaller is true, return -1𝔽.
+        // 9. Let ySmaller be IsLessThan(yString, xString, true).
+        // 10. If ySmaller is true, return 1𝔽.
+        // 11. Return +0𝔽.
+
+        // NOTE: skipped IsLessThan because it just makes a lexicographic comparation
+        // when x and y are strings
+        Ok(x_str.cmp(&y_str))
+    }
+
+    /// `Array.prototype.toReversed ( )`
+    ///
+    /// Returns a copy of the array with its elements in reverse order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.toreversed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toReversed
+    pub(crate) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 4. Let k be 0.
+        // 5. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(len - k - 1)).
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(len - k - 1, context)?;
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)?;
+            // e. Set k to k + 1.
+        }
+
+        // 6. Return A.
+        Ok(a.into())
+    }
+
+    /// `Array.prototype.toSorted ( comparefn )`
+    ///
+    /// Returns a sorted copy of the array, reading holes as `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tosorted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSorted
+    pub(crate) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = Self::comparefn(args, context)?;
+
+        // 2. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 3. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 4. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 5. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? SortCompare(x, y, comparefn).
+        // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let mut sorted_list = Vec::with_capacity(len);
+        for k in 0..len {
+            context.check_interrupt()?;
+            sorted_list.push(o.get(k, context)?);
+        }
+        Self::sort_values(&mut sorted_list, &comparefn, context)?;
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(j)), sortedList[j]).
+            a.create_data_property_or_throw(j, item, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 9. Return A.
+        Ok(a.into())
+    }
+
+    /// `Array.prototype.toSpliced ( start, skipCount, ...items )`
+    ///
+    /// Returns a copy of the array with `skipCount` elements removed at `start` and replaced by
+    /// `items`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tospliced
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSpliced
+    pub(crate) fn to_spliced(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart is -∞, let actualStart be 0.
+        // 5. Else if relativeStart < 0, let actualStart be max(len + relativeStart, 0).
+        // 6. Else, let actualStart be min(relativeStart, len).
+        let actual_start = Self::get_relative_start(context, args.get(0), len)?;
+
+        // 7. Let insertCount be the number of elements in items.
+        let items = args.get(2..).unwrap_or_default();
+        let insert_count = items.len();
+
+        let actual_skip_count = match args.get(1) {
+            // 8. If start is not present, then
+            //     a. Let actualSkipCount be 0.
+            _ if args.is_empty() => 0,
+            // 9. Else if skipCount is not present, then
+            //     a. Let actualSkipCount be len - actualStart.
+            None => len - actual_start,
+            // 10. Else,
+            Some(skip_count) => {
+                // a. Let sc be ? ToIntegerOrInfinity(skipCount).
+                // b. Let actualSkipCount be the result of clamping sc between 0 and len - actualStart.
+                match skip_count.to_integer_or_infinity(context)? {
+                    IntegerOrInfinity::Integer(sc) => {
+                        sc.clamp(0, (len - actual_start) as i64) as usize
+                    }
+                    IntegerOrInfinity::PositiveInfinity => len - actual_start,
+                    IntegerOrInfinity::NegativeInfinity => 0,
+                }
+            }
+        };
+
+        // 11. Let newLen be len + insertCount - actualSkipCount.
+        let new_len = len + insert_count - actual_skip_count;
+
+        // 12. If newLen > 2^53 - 1, throw a TypeError exception.
+        if new_len as u64 > Number::MAX_SAFE_INTEGER as u64 {
+            return context.throw_type_error("Array.prototype.toSpliced: new length is too large");
+        }
+
+        // 13. Let A be ? ArrayCreate(newLen).
+        let a = Self::array_create(new_len, None, context)?;
+
+        // 14. Let i be 0.
+        // 15. Let r be actualStart + actualSkipCount.
+        // 16. Repeat, while i < actualStart,
+        for i in 0..actual_start {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let iValue be ? Get(O, Pi).
+            let i_value = o.get(i, context)?;
+            // c. Perform ! CreateDataPropertyOrThrow(A, Pi, iValue).
+            a.create_data_property_or_throw(i, i_value, context)?;
+            // d. Set i to i + 1.
+        }
+
+        // 17. For each element E of items, do
+        for (i, e) in (actual_start..).zip(items) {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Perform ! CreateDataPropertyOrThrow(A, Pi, E).
+            a.create_data_property_or_throw(i, e.clone(), context)?;
+            // c. Set i to i + 1.
+        }
+
+        // 18. Repeat, while i < newLen,
+        let start = actual_start + insert_count;
+        for (i, r) in (start..new_len).zip(actual_start + actual_skip_count..) {
+            context.check_interrupt()?;
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let from be ! ToString(𝔽(r)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(r, context)?;
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pi, fromValue).
+            a.create_data_property_or_throw(i, from_value, context)?;
+            // e. Set i to i + 1.
+            // f. Set r to r + 1.
+        }
+
+        // 19. Return A.
+        Ok(a.into())
+    }
+
+    /// `Array.prototype.with ( index, value )`
+    ///
+    /// Returns a copy of the array with the element at `index` replaced by `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        // 4. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        // 5. Else, let actualIndex be len + relativeIndex.
+        // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
+        let actual_index = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(i) if i >= 0 && (i as u64) < len as u64 => i as usize,
+            IntegerOrInfinity::Integer(i) if i < 0 && i.unsigned_abs() <= len as u64 => {
+                len - i.unsigned_abs() as usize
+            }
+            _ => {
+                return context.throw_range_error("Array.prototype.with: index out of range");
+            }
+        };
+        let value = args.get_or_undefined(1);
+
+        // 7. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 8. Let k be 0.
+        // 9. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be value.
+            // c. Else, let fromValue be ? Get(O, Pk).
+            let from_value = if k == actual_index {
+                value.clone()
+            } else {
+                o.get(k, context)?
+            };
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)?;
+            // e. Set k to k + 1.
+        }
+
+        // 10. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.reduce( callbackFn [ , initialValue ] )`
     ///
     /// More information:
@@ -2397,7 +2977,7 @@ impl Array {
     }
 
     /// Represents the algorithm to calculate `relativeStart` (or `k`) in array functions.
-    pub(super) fn get_relative_start(
+    pub(crate) fn get_relative_start(
         context: &mut Context,
         arg: Option<&JsValue>,
         len: usize,
@@ -2422,7 +3002,7 @@ impl Array {
     }
 
     /// Represents the algorithm to calculate `relativeEnd` (or `final`) in array functions.
-    pub(super) fn get_relative_end(
+    pub(crate) fn get_relative_end(
         context: &mut Context,
         arg: Option<&JsValue>,
         len: usize,
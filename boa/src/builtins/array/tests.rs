@@ -1,6 +1,6 @@
 use super::Array;
 use crate::builtins::Number;
-use crate::{forward, Context, JsValue};
+use crate::{forward, set_fuel, Context, JsValue};
 
 #[test]
 fn is_array() {
@@ -758,6 +758,45 @@ fn includes_value() {
     assert_eq!(second_in_many, String::from("false"));
 }
 
+#[test]
+fn search_holes_and_accessors() {
+    let mut context = Context::new();
+    let init = r#"
+        var holes = [1, 2, 3];
+        delete holes[1];
+        var reads = 0;
+        var accessor = [1, 2, 3];
+        Object.defineProperty(accessor, 1, { get() { reads++; return 'two'; } });
+        var notArray = { length: 3, 0: 'a', 1: 'b', 2: 'c' };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "holes.indexOf(undefined)"), "-1");
+    assert_eq!(forward(&mut context, "holes.includes(undefined)"), "true");
+    assert_eq!(forward(&mut context, "holes.lastIndexOf(3, -1)"), "2");
+    assert_eq!(forward(&mut context, "holes.lastIndexOf(1, -4)"), "-1");
+
+    // Holes read through to the prototype.
+    forward(&mut context, "Array.prototype[1] = 2;");
+    assert_eq!(forward(&mut context, "holes.indexOf(2)"), "1");
+    assert_eq!(forward(&mut context, "holes.includes(2)"), "true");
+    forward(&mut context, "delete Array.prototype[1];");
+
+    assert_eq!(forward(&mut context, "accessor.indexOf('two')"), "1");
+    assert_eq!(forward(&mut context, "accessor.lastIndexOf(3)"), "2");
+    assert_eq!(forward(&mut context, "accessor.includes(3)"), "true");
+    assert_eq!(forward(&mut context, "reads"), "2");
+
+    assert_eq!(
+        forward(&mut context, "Array.prototype.indexOf.call(notArray, 'c')"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "[NaN].indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "[NaN].includes(NaN)"), "true");
+    assert_eq!(forward(&mut context, "[1, 2, 1].lastIndexOf(1)"), "2");
+    assert_eq!(forward(&mut context, "[1, 2, 1].indexOf(1, 1)"), "2");
+}
+
 #[test]
 fn map() {
     let mut context = Context::new();
@@ -845,6 +884,96 @@ fn slice() {
     assert_eq!(forward(&mut context, "many3.length"), "0");
 }
 
+#[test]
+fn splice() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = ['a', 'b', 'c', 'd'];
+        var removed = arr.splice(1, 2, 'x');
+        var grown = ['a', 'b'];
+        var none = grown.splice(1, 0, 'x', 'y');
+        var holes = [0, 1, 2, 3];
+        delete holes[1];
+        holes.splice(0, 1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "arr.join()"), "\"a,x,d\"");
+    assert_eq!(forward(&mut context, "removed.join()"), "\"b,c\"");
+    assert_eq!(forward(&mut context, "grown.join()"), "\"a,x,y,b\"");
+    assert_eq!(forward(&mut context, "none.length"), "0");
+    assert_eq!(forward(&mut context, "holes.length"), "3");
+    assert_eq!(forward(&mut context, "0 in holes"), "false");
+    assert_eq!(forward(&mut context, "[1, 2, 3].splice().length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var a = [1, 2, 3]; a.splice(-2).join() + '|' + a.join()"
+        ),
+        "\"2,3|1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var o = { length: 3, 0: 'a', 1: 'b', 2: 'c' }; Array.prototype.splice.call(o, 0, 1); o.length + o[0]"
+        ),
+        "\"2b\""
+    );
+}
+
+#[test]
+fn species_create() {
+    let mut context = Context::new();
+    let init = r#"
+        function Tagged(length) {
+            this.length = length;
+            this.tagged = true;
+        }
+        var arr = [1, 2, 3];
+        arr.constructor = {};
+        arr.constructor[Symbol.species] = Tagged;
+        "#;
+    forward(&mut context, init);
+
+    for method in [
+        "map(x => x)",
+        "filter(x => x)",
+        "slice()",
+        "splice(0, 1)",
+        "concat()",
+        "flat()",
+        "flatMap(x => x)",
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("arr.{}.tagged", method)),
+            "true",
+            "{}",
+            method
+        );
+    }
+    assert_eq!(
+        forward(&mut context, "arr.splice(0, 1)[0] + ',' + arr.length"),
+        "\"2,1\""
+    );
+
+    // A null species and a missing constructor fall back to plain arrays.
+    forward(&mut context, "arr.constructor[Symbol.species] = null;");
+    assert_eq!(forward(&mut context, "Array.isArray(arr.slice())"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.isArray(Array.prototype.map.call({ length: 0 }, x => x))"
+        ),
+        "true"
+    );
+
+    forward(&mut context, "arr.constructor[Symbol.species] = 1;");
+    assert_eq!(
+        forward(&mut context, "try { arr.slice() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
 #[test]
 fn for_each() {
     let mut context = Context::new();
@@ -1569,3 +1698,179 @@ fn array_sort() {
         "\"1,5,9,40,80,200,700\""
     );
 }
+
+#[test]
+fn interrupt_sort_and_join() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "var values = new Array(1000).fill(0).map((_, i) => 1000 - i);",
+    );
+
+    set_fuel(&mut context, 100);
+    assert_eq!(
+        forward(&mut context, "values.sort()"),
+        "Uncaught \"RangeError\": \"out of fuel\""
+    );
+    assert_eq!(
+        forward(&mut context, "values.join()"),
+        "Uncaught \"RangeError\": \"out of fuel\""
+    );
+
+    context.clear_interrupt_handler();
+    assert_eq!(forward(&mut context, "values.sort()[0]"), "1");
+}
+
+#[test]
+fn change_by_copy_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [3, 1, 0, 2];
+        delete arr[2];
+        var reversed = arr.toReversed();
+        var sorted = arr.toSorted();
+        var spliced = arr.toSpliced(1, 2, 'a', 'b', 'c');
+        var replaced = arr.with(-1, 'z');
+        "#;
+    forward(&mut context, init);
+
+    // The original array is left untouched.
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,1,,2\"");
+    assert_eq!(forward(&mut context, "2 in arr"), "false");
+
+    // Holes are read as undefined, so the copies are dense.
+    assert_eq!(forward(&mut context, "reversed.join()"), "\"2,,1,3\"");
+    assert_eq!(forward(&mut context, "1 in reversed"), "true");
+    assert_eq!(forward(&mut context, "sorted.join()"), "\"1,2,3,\"");
+    assert_eq!(
+        forward(&mut context, "arr.toSorted((a, b) => b - a).join()"),
+        "\"3,2,1,\""
+    );
+    assert_eq!(forward(&mut context, "spliced.join()"), "\"3,a,b,c,2\"");
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced().join()"),
+        "\"3,1,,2\""
+    );
+    assert_eq!(forward(&mut context, "arr.toSpliced(1).join()"), "\"3\"");
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(-1, Infinity, 'x').join()"),
+        "\"3,1,,x\""
+    );
+    assert_eq!(forward(&mut context, "replaced.join()"), "\"3,1,,z\"");
+    assert_eq!(forward(&mut context, "arr.with(0, 'a')[0]"), "\"a\"");
+    assert_eq!(
+        forward(&mut context, "try { arr.with(4, 0) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { arr.with(-5, 0) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { arr.toSorted(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    // The copies are plain arrays, whatever the species of the original array.
+    forward(
+        &mut context,
+        "arr.constructor = { [Symbol.species]: function () { throw 'species'; } };",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[arr.toReversed(), arr.toSorted(), arr.toSpliced(0), arr.with(0, 1)].every(Array.isArray)"
+        ),
+        "true"
+    );
+
+    // Array-likes are copied too.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.toReversed.call({ length: 2, 0: 'a', 1: 'b' }).join()"
+        ),
+        "\"b,a\""
+    );
+}
+
+#[test]
+fn from_async() {
+    let mut context = Context::new();
+    let init = r#"
+        var results = [];
+        function record(promise) {
+            promise.then(
+                function (array) { results.push(array.join()); },
+                function (error) { results.push(error.name || error); }
+            );
+        }
+        async function* generate() {
+            yield 1;
+            yield Promise.resolve(2);
+            yield 3;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Array.fromAsync([]) instanceof Promise"),
+        "true"
+    );
+
+    forward(
+        &mut context,
+        r#"
+        record(Array.fromAsync([Promise.resolve('a'), 'b']));
+        record(Array.fromAsync(generate(), function (x, i) { return Promise.resolve(x * 10 + i); }));
+        record(Array.fromAsync({ length: 2, 0: Promise.resolve('x'), 1: 'y' }));
+        record(Array.fromAsync([1], 1));
+        record(Array.fromAsync(null));
+        "#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "results.sort().join(' ')"),
+        "\"10,21,32 TypeError TypeError a,b x,y\""
+    );
+
+    // The iterator is closed when the mapping function throws.
+    forward(
+        &mut context,
+        r#"
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.asyncIterator] = function () {
+            return {
+                next: function () { return Promise.resolve({ value: 1, done: false }); },
+                return: function () { closed = true; return {}; },
+            };
+        };
+        var error;
+        Array.fromAsync(iterable, function () { throw 'mapped'; }).catch(function (e) { error = e; });
+        "#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "[error, closed].join()"),
+        "\"mapped,true\""
+    );
+
+    // The array is created by the this value when it is a constructor.
+    forward(
+        &mut context,
+        r#"
+        function C() {}
+        var created;
+        Array.fromAsync.call(C, [1, 2]).then(function (c) { created = c; });
+        "#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(
+            &mut context,
+            "created instanceof C && created.length === 2 && created[1]"
+        ),
+        "2"
+    );
+}
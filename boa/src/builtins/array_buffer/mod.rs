@@ -0,0 +1,886 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! An `ArrayBuffer` is a raw binary data buffer. Its contents can't be manipulated directly, only
+//! through typed arrays or `DataView` objects viewing it. A resizable buffer, created with a
+//! `maxByteLength`, can change its length up to that maximum.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{shared_array_buffer::SharedDataBlock, typed_array::TypedArrayName, Array, BuiltIn},
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::{ArgumentsExt, JsValue},
+    BoaProfiler, Context, JsResult,
+};
+use std::ops::Range;
+
+/// The data of an `ArrayBuffer` or a `SharedArrayBuffer` object, its `[[ArrayBufferData]]`
+/// internal slot.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayBuffer {
+    /// The bytes of the buffer, or `None` if the buffer has been detached.
+    #[unsafe_ignore_trace]
+    data: Option<DataBlock>,
+    /// `[[ArrayBufferMaxByteLength]]`, which is `None` for a fixed-length buffer.
+    max_byte_length: Option<usize>,
+}
+
+/// The memory of a buffer, which is shared for a `SharedArrayBuffer`.
+#[derive(Debug, Clone)]
+enum DataBlock {
+    Unshared(Vec<u8>),
+    Shared(SharedDataBlock),
+}
+
+impl BuiltIn for ArrayBuffer {
+    const NAME: &'static str = "ArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::get_byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+
+        let get_max_byte_length = FunctionBuilder::native(context, Self::get_max_byte_length)
+            .name("get maxByteLength")
+            .constructable(false)
+            .build();
+
+        let get_resizable = FunctionBuilder::native(context, Self::get_resizable)
+            .name("get resizable")
+            .constructable(false)
+            .build();
+
+        let array_buffer_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().array_buffer_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::is_view, "isView", 1)
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "maxByteLength",
+            Some(get_max_byte_length),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "resizable",
+            Some(get_resizable),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::resize, "resize", 1)
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, array_buffer_object.into(), Self::attribute())
+    }
+}
+
+impl ArrayBuffer {
+    /// The amount of arguments the `ArrayBuffer` constructor takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates the data of a new `ArrayBuffer` from the given bytes.
+    #[inline]
+    pub(crate) fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self {
+            data: Some(DataBlock::Unshared(bytes)),
+            max_byte_length: None,
+        }
+    }
+
+    /// Creates the data of a new resizable `ArrayBuffer` from the given bytes, which can grow up
+    /// to `max_byte_length` bytes.
+    #[inline]
+    pub(crate) fn resizable_from_bytes(bytes: Vec<u8>, max_byte_length: usize) -> Self {
+        debug_assert!(bytes.len() <= max_byte_length);
+        Self {
+            data: Some(DataBlock::Unshared(bytes)),
+            max_byte_length: Some(max_byte_length),
+        }
+    }
+
+    /// Creates the data of a new `SharedArrayBuffer` viewing the given block.
+    #[inline]
+    pub(crate) fn from_shared_block(block: SharedDataBlock) -> Self {
+        Self {
+            data: Some(DataBlock::Shared(block)),
+            max_byte_length: None,
+        }
+    }
+
+    /// Gets the length of the buffer in bytes, which is `0` for a detached buffer.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        match &self.data {
+            Some(DataBlock::Unshared(bytes)) => bytes.len(),
+            Some(DataBlock::Shared(block)) => block.len(),
+            None => 0,
+        }
+    }
+
+    /// Gets the maximum length of a resizable buffer in bytes, or `None` if the buffer has a fixed
+    /// length.
+    #[inline]
+    pub fn max_byte_length(&self) -> Option<usize> {
+        self.max_byte_length
+    }
+
+    /// Abstract operation `IsFixedLengthArrayBuffer ( arrayBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isfixedlengtharraybuffer
+    #[inline]
+    pub fn is_fixed_length(&self) -> bool {
+        // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
+        // 2. Return true.
+        self.max_byte_length.is_none()
+    }
+
+    /// Abstract operation `IsSharedArrayBuffer ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-issharedarraybuffer
+    #[inline]
+    pub fn is_shared_array_buffer(&self) -> bool {
+        // 1. Let bufferData be obj.[[ArrayBufferData]].
+        // 2. If bufferData is null, return false.
+        // 3. If bufferData is a Data Block, return false.
+        // 4. Assert: bufferData is a Shared Data Block.
+        // 5. Return true.
+        matches!(self.data, Some(DataBlock::Shared(_)))
+    }
+
+    /// Abstract operation `IsDetachedBuffer ( arrayBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isdetachedbuffer
+    #[inline]
+    pub fn is_detached_buffer(&self) -> bool {
+        // 1. If arrayBuffer.[[ArrayBufferData]] is null, return true.
+        // 2. Return false.
+        self.data.is_none()
+    }
+
+    /// Abstract operation `DetachArrayBuffer ( arrayBuffer )`
+    ///
+    /// Detaches the buffer, releasing its bytes. Every view of the buffer becomes unusable.
+    ///
+    /// A `SharedArrayBuffer` can't be detached, so this returns `None` and leaves it unchanged.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    #[inline]
+    pub fn detach(&mut self) -> Option<Vec<u8>> {
+        // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+        if self.is_shared_array_buffer() {
+            return None;
+        }
+
+        // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
+        // 5. Set arrayBuffer.[[ArrayBufferByteLength]] to 0.
+        match self.data.take() {
+            Some(DataBlock::Unshared(bytes)) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Gets the bytes of the buffer, or `None` if it has been detached or is shared.
+    #[inline]
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match &self.data {
+            Some(DataBlock::Unshared(bytes)) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Gets the bytes of the buffer mutably, or `None` if it has been detached or is shared.
+    #[inline]
+    pub fn bytes_mut(&mut self) -> Option<&mut [u8]> {
+        match &mut self.data {
+            Some(DataBlock::Unshared(bytes)) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Gets the shared data block of a `SharedArrayBuffer`, or `None` if the buffer is not
+    /// shared.
+    #[inline]
+    pub fn shared_data_block(&self) -> Option<&SharedDataBlock> {
+        match &self.data {
+            Some(DataBlock::Shared(block)) => Some(block),
+            _ => None,
+        }
+    }
+
+    /// Copies the bytes of the buffer starting at `byte_index` to `dest`.
+    ///
+    /// The buffer must not be detached, and the bytes must be in bounds.
+    pub(crate) fn read_bytes(&self, byte_index: usize, dest: &mut [u8]) {
+        match self.data.as_ref().expect("cannot read a detached buffer") {
+            DataBlock::Unshared(bytes) => {
+                dest.copy_from_slice(&bytes[byte_index..byte_index + dest.len()]);
+            }
+            DataBlock::Shared(block) => block.read(byte_index, dest),
+        }
+    }
+
+    /// Copies `src` to the bytes of the buffer starting at `byte_index`.
+    ///
+    /// The buffer must not be detached, and the bytes must be in bounds.
+    pub(crate) fn write_bytes(&mut self, byte_index: usize, src: &[u8]) {
+        match self
+            .data
+            .as_mut()
+            .expect("cannot write to a detached buffer")
+        {
+            DataBlock::Unshared(bytes) => {
+                bytes[byte_index..byte_index + src.len()].copy_from_slice(src);
+            }
+            DataBlock::Shared(block) => block.write(byte_index, src),
+        }
+    }
+
+    /// Copies the bytes of `src` within the buffer to `dest`, like `slice::copy_within`.
+    ///
+    /// The buffer must not be detached, and the bytes must be in bounds.
+    pub(crate) fn copy_bytes_within(&mut self, src: Range<usize>, dest: usize) {
+        match self
+            .data
+            .as_mut()
+            .expect("cannot copy within a detached buffer")
+        {
+            DataBlock::Unshared(bytes) => bytes.copy_within(src, dest),
+            DataBlock::Shared(block) => block.copy_within(src, dest),
+        }
+    }
+
+    /// Reads the `len` bytes starting at `byte_index` and replaces them with the result of `op`,
+    /// if any, returning the bytes read.
+    ///
+    /// For a shared buffer, this is atomic with respect to the other atomic operations on the
+    /// buffer. The buffer must not be detached, and the bytes must be in bounds.
+    pub(crate) fn modify_bytes<F>(&mut self, byte_index: usize, len: usize, op: F) -> Vec<u8>
+    where
+        F: FnOnce(&[u8]) -> Option<Vec<u8>>,
+    {
+        match self.data.as_mut().expect("cannot modify a detached buffer") {
+            DataBlock::Unshared(bytes) => {
+                let bytes = &mut bytes[byte_index..byte_index + len];
+                let old = bytes.to_vec();
+                if let Some(new) = op(&old) {
+                    bytes.copy_from_slice(&new);
+                }
+                old
+            }
+            DataBlock::Shared(block) => block.modify(byte_index, len, op),
+        }
+    }
+
+    /// Abstract operation `GetValueFromBuffer ( arrayBuffer, byteIndex, type, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// The buffer must not be detached, and the element must be in bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvaluefrombuffer
+    pub(crate) fn get_value_from_buffer(
+        &self,
+        byte_index: usize,
+        element_type: TypedArrayName,
+        is_little_endian: bool,
+    ) -> JsValue {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 3. Let block be arrayBuffer.[[ArrayBufferData]].
+        // 4. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
+        // 5-6. Let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
+        let mut raw_value = [0; 8];
+        let raw_value = &mut raw_value[..element_type.element_size()];
+        self.read_bytes(byte_index, raw_value);
+
+        // 8. Return RawBytesToNumeric(type, rawValue, isLittleEndian).
+        element_type.raw_bytes_to_numeric(raw_value, is_little_endian)
+    }
+
+    /// Abstract operation `SetValueInBuffer ( arrayBuffer, byteIndex, type, value, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// `value` must already be a Number or a BigInt, according to `element_type`. The buffer must
+    /// not be detached, and the element must be in bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setvalueinbuffer
+    pub(crate) fn set_value_in_buffer(
+        &mut self,
+        byte_index: usize,
+        element_type: TypedArrayName,
+        value: &JsValue,
+        is_little_endian: bool,
+    ) {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 6. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
+        // 8. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
+        let raw_bytes = element_type.numeric_to_raw_bytes(value, is_little_endian);
+
+        // 9-10. Store the individual bytes of rawBytes into block, starting at block[byteIndex].
+        self.write_bytes(byte_index, &raw_bytes);
+    }
+
+    /// `ArrayBuffer ( length [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/ArrayBuffer
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin ArrayBuffer constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let max_byte_length = Self::get_max_byte_length_option(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? AllocateArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        Ok(Self::allocate(new_target, byte_length, max_byte_length, context)?.into())
+    }
+
+    /// Abstract operation `GetArrayBufferMaxByteLengthOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getarraybuffermaxbytelengthoption
+    fn get_max_byte_length_option(
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<usize>> {
+        // 1. If Type(options) is not Object, return empty.
+        let options = match options.as_object() {
+            Some(options) => options,
+            None => return Ok(None),
+        };
+
+        // 2. Let maxByteLength be ? Get(options, "maxByteLength").
+        let max_byte_length = options.get("maxByteLength", context)?;
+
+        // 3. If maxByteLength is undefined, return empty.
+        if max_byte_length.is_undefined() {
+            return Ok(None);
+        }
+
+        // 4. Return ? ToIndex(maxByteLength).
+        max_byte_length.to_index(context).map(Some)
+    }
+
+    /// `get ArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/@@species
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `ArrayBuffer.isView ( arg )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    fn is_view(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(arg) is not Object, return false.
+        // 2. If arg has a [[ViewedArrayBuffer]] internal slot, return true.
+        // 3. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .map(|obj| {
+                let obj = obj.borrow();
+                obj.is_typed_array() || obj.is_data_view()
+            })
+            .unwrap_or_default()
+            .into())
+    }
+
+    /// `get ArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+    fn get_byte_length(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
+        // 5. Let length be O.[[ArrayBufferByteLength]].
+        // 6. Return 𝔽(length).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let byte_length = this.as_object().and_then(|obj| {
+            obj.borrow()
+                .as_array_buffer()
+                .filter(|buffer| !buffer.is_shared_array_buffer())
+                .map(Self::byte_length)
+        });
+        match byte_length {
+            Some(byte_length) => Ok(byte_length.into()),
+            None => context
+                .throw_type_error("ArrayBuffer.prototype.byteLength called on a non-ArrayBuffer"),
+        }
+    }
+
+    /// `get ArrayBuffer.prototype.maxByteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/maxByteLength
+    fn get_max_byte_length(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
+        // 5. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 6. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        // 7. Return 𝔽(length).
+        let max_byte_length = this.as_object().and_then(|obj| {
+            obj.borrow()
+                .as_array_buffer()
+                .filter(|buffer| !buffer.is_shared_array_buffer())
+                .map(|buffer| {
+                    if buffer.is_detached_buffer() {
+                        0
+                    } else {
+                        buffer
+                            .max_byte_length
+                            .unwrap_or_else(|| buffer.byte_length())
+                    }
+                })
+        });
+        match max_byte_length {
+            Some(max_byte_length) => Ok(max_byte_length.into()),
+            None => context.throw_type_error(
+                "ArrayBuffer.prototype.maxByteLength called on a non-ArrayBuffer",
+            ),
+        }
+    }
+
+    /// `get ArrayBuffer.prototype.resizable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resizable
+    fn get_resizable(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        let resizable = this.as_object().and_then(|obj| {
+            obj.borrow()
+                .as_array_buffer()
+                .filter(|buffer| !buffer.is_shared_array_buffer())
+                .map(|buffer| !buffer.is_fixed_length())
+        });
+        match resizable {
+            Some(resizable) => Ok(resizable.into()),
+            None => context
+                .throw_type_error("ArrayBuffer.prototype.resizable called on a non-ArrayBuffer"),
+        }
+    }
+
+    /// `ArrayBuffer.prototype.resize ( newLength )`
+    ///
+    /// Resizes the buffer in place. The typed arrays and `DataView`s viewing it read its new
+    /// length every time they are used, so the ones tracking its length follow it, and the others
+    /// go out of bounds if it shrinks past their end.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resize
+    fn resize(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = match this.as_object() {
+            Some(obj)
+                if obj.borrow().as_array_buffer().map_or(false, |buffer| {
+                    !buffer.is_shared_array_buffer() && !buffer.is_fixed_length()
+                }) =>
+            {
+                obj
+            }
+            _ => {
+                return context.throw_type_error(
+                    "ArrayBuffer.prototype.resize called on a non-resizable ArrayBuffer",
+                )
+            }
+        };
+
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        let mut obj = obj.borrow_mut();
+        let buffer = obj
+            .as_array_buffer_mut()
+            .expect("checked to be an ArrayBuffer");
+
+        // 5. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let bytes = match &mut buffer.data {
+            Some(DataBlock::Unshared(bytes)) => bytes,
+            _ => {
+                drop(obj);
+                return context
+                    .throw_type_error("ArrayBuffer.prototype.resize called on a detached buffer");
+            }
+        };
+
+        // 6. If newByteLength > O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+        if Some(new_byte_length) > buffer.max_byte_length {
+            drop(obj);
+            return context.throw_range_error(format!(
+                "ArrayBuffer.prototype.resize: new length {} is larger than the maximum length",
+                new_byte_length
+            ));
+        }
+
+        // 7. Let hostHandled be ? HostResizeArrayBuffer(O, newByteLength).
+        // 8. If hostHandled is handled, return undefined.
+        // 9. Let oldBlock be O.[[ArrayBufferData]].
+        // 10. Let newBlock be ? CreateByteDataBlock(newByteLength).
+        // 11. Let copyLength be min(newByteLength, O.[[ArrayBufferByteLength]]).
+        // 12. Perform CopyDataBlockBytes(newBlock, 0, oldBlock, 0, copyLength).
+        // 13. NOTE: Neither creation of the new Data Block nor copying from the old Data Block
+        //     are observable. Implementations may implement this method as in-place growth or
+        //     shrinkage.
+        // 14. Set O.[[ArrayBufferData]] to newBlock.
+        // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
+        if new_byte_length > bytes.len()
+            && bytes
+                .try_reserve_exact(new_byte_length - bytes.len())
+                .is_err()
+        {
+            drop(obj);
+            return context.throw_range_error("array buffer allocation failed");
+        }
+        bytes.resize(new_byte_length, 0);
+
+        // 16. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `ArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = match this.as_object() {
+            Some(obj) if obj.borrow().is_unshared_array_buffer() => obj,
+            _ => {
+                return context
+                    .throw_type_error("ArrayBuffer.prototype.slice called on a non-ArrayBuffer")
+            }
+        };
+
+        // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        // 5. Let len be O.[[ArrayBufferByteLength]].
+        let len = {
+            let obj = obj.borrow();
+            let buffer = obj.as_array_buffer().expect("checked to be an ArrayBuffer");
+            if buffer.is_detached_buffer() {
+                return context
+                    .throw_type_error("ArrayBuffer.prototype.slice called on a detached buffer");
+            }
+            buffer.byte_length()
+        };
+
+        // 6. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 7-8. Let first be the clamped relativeStart.
+        let first = Array::get_relative_start(context, args.get(0), len)?;
+
+        // 9. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        // 10-11. Let final be the clamped relativeEnd.
+        let r#final = Array::get_relative_end(context, args.get(1), len)?;
+
+        // 12. Let newLen be max(final - first, 0).
+        let new_len = r#final.saturating_sub(first);
+
+        // 13. Let ctor be ? SpeciesConstructor(O, %ArrayBuffer%).
+        let ctor = obj.species_constructor(
+            context
+                .standard_objects()
+                .array_buffer_object()
+                .constructor()
+                .into(),
+            context,
+        )?;
+
+        // 14. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = match ctor.as_object() {
+            Some(ctor) => ctor.construct(&[new_len.into()], &ctor.clone().into(), context)?,
+            None => return context.throw_type_error("ArrayBuffer species is not a constructor"),
+        };
+
+        // 15. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+        // 16. If IsSharedArrayBuffer(new) is true, throw a TypeError exception.
+        let new = match new.as_object() {
+            Some(new) if new.borrow().is_unshared_array_buffer() => new.clone(),
+            _ => {
+                return context
+                    .throw_type_error("ArrayBuffer species did not construct an ArrayBuffer")
+            }
+        };
+
+        // 18. If SameValue(new, O) is true, throw a TypeError exception.
+        if JsObject::equals(&new, &obj) {
+            return context.throw_type_error("ArrayBuffer species constructed the same buffer");
+        }
+
+        {
+            let new = new.borrow();
+            let new_buffer = new.as_array_buffer().expect("checked to be an ArrayBuffer");
+
+            // 17. If IsDetachedBuffer(new) is true, throw a TypeError exception.
+            if new_buffer.is_detached_buffer() {
+                return context
+                    .throw_type_error("ArrayBuffer species constructed a detached buffer");
+            }
+
+            // 19. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
+            if new_buffer.byte_length() < new_len {
+                return context
+                    .throw_type_error("ArrayBuffer species constructed a too small buffer");
+            }
+        }
+
+        // 20. NOTE: Side-effects of the above steps may have detached O.
+        // 21. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let bytes = match obj.borrow().as_array_buffer().and_then(ArrayBuffer::bytes) {
+            // 22. Let fromBuf be O.[[ArrayBufferData]].
+            // 24. Let fromBuf be O.[[ArrayBufferData]].
+            // 25. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+            Some(bytes) => bytes[first..first + new_len].to_vec(),
+            None => return context.throw_type_error("ArrayBuffer was detached during slice"),
+        };
+        new.borrow_mut()
+            .as_array_buffer_mut()
+            .expect("checked to be an ArrayBuffer")
+            .write_bytes(0, &bytes);
+
+        // 26. Return new.
+        Ok(new.into())
+    }
+
+    /// Abstract operation `AllocateArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatearraybuffer
+    pub(crate) fn allocate(
+        constructor: &JsValue,
+        byte_length: usize,
+        max_byte_length: Option<usize>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let slots be « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] ».
+        // 2. If maxByteLength is present and maxByteLength is not empty, let allocatingResizableBuffer be true; otherwise let allocatingResizableBuffer be false.
+        // 3. If allocatingResizableBuffer is true, then
+        //     a. If byteLength > maxByteLength, throw a RangeError exception.
+        //     b. Append [[ArrayBufferMaxByteLength]] to slots.
+        if let Some(max_byte_length) = max_byte_length {
+            if byte_length > max_byte_length {
+                return Err(context.construct_range_error(format!(
+                    "ArrayBuffer length {} is larger than the maximum length {}",
+                    byte_length, max_byte_length
+                )));
+            }
+        }
+
+        // 4. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", slots).
+        let prototype = get_prototype_from_constructor(
+            constructor,
+            StandardObjects::array_buffer_object,
+            context,
+        )?;
+
+        // 5. Let block be ? CreateByteDataBlock(byteLength).
+        let block = create_byte_data_block(byte_length, context)?;
+
+        // 6. Set obj.[[ArrayBufferData]] to block.
+        // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 8. If allocatingResizableBuffer is true, then
+        //     a. If it is not possible to create a Data Block block consisting of maxByteLength
+        //        bytes, throw a RangeError exception.
+        //     b. NOTE: Resizable ArrayBuffers are designed to be implementable with in-place
+        //        growth. Implementations may throw if, for example, virtual memory cannot be
+        //        reserved up front.
+        //     c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+        let data = match max_byte_length {
+            Some(max_byte_length) => Self::resizable_from_bytes(block, max_byte_length),
+            None => Self::from_bytes(block),
+        };
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::array_buffer(data);
+
+        // 9. Return obj.
+        Ok(obj)
+    }
+
+    /// Abstract operation `CloneArrayBuffer ( srcBuffer, srcByteOffset, srcLength, cloneConstructor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clonearraybuffer
+    pub(crate) fn clone_array_buffer(
+        src_buffer: &JsObject,
+        src_byte_offset: usize,
+        src_length: usize,
+        clone_constructor: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let targetBuffer be ? AllocateArrayBuffer(cloneConstructor, srcLength).
+        let target_buffer = Self::allocate(clone_constructor, src_length, None, context)?;
+
+        // 2. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
+        // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
+        let mut bytes = vec![0; src_length];
+        match src_buffer.borrow().as_array_buffer() {
+            Some(buffer) if !buffer.is_detached_buffer() => {
+                buffer.read_bytes(src_byte_offset, &mut bytes);
+            }
+            _ => return Err(context.construct_type_error("cannot clone a detached ArrayBuffer")),
+        }
+
+        // 4. Let targetBlock be targetBuffer.[[ArrayBufferData]].
+        // 5. Perform CopyDataBlockBytes(targetBlock, 0, srcBlock, srcByteOffset, srcLength).
+        target_buffer
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .expect("a newly allocated ArrayBuffer")
+            .write_bytes(0, &bytes);
+
+        // 6. Return targetBuffer.
+        Ok(target_buffer)
+    }
+}
+
+/// Abstract operation `CreateByteDataBlock ( size )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createbytedatablock
+pub(crate) fn create_byte_data_block(size: usize, context: &mut Context) -> JsResult<Vec<u8>> {
+    // 1. Let db be a new Data Block value consisting of size bytes. If it is impossible to
+    //    create such a Data Block, throw a RangeError exception.
+    let mut data_block = Vec::new();
+    if data_block.try_reserve_exact(size).is_err() {
+        return Err(context.construct_range_error("array buffer allocation failed"));
+    }
+
+    // 2. Set all of the bytes of db to 0.
+    data_block.resize(size, 0);
+
+    // 3. Return db.
+    Ok(data_block)
+}
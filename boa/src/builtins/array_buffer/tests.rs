@@ -0,0 +1,171 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct_and_byte_length() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "new ArrayBuffer(8).byteLength"), "8");
+    assert_eq!(forward(&mut context, "new ArrayBuffer().byteLength"), "0");
+    assert_eq!(
+        forward(&mut context, "new ArrayBuffer(2.7).byteLength"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(-1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { ArrayBuffer(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new ArrayBuffer(1))"
+        ),
+        "\"[object ArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer[Symbol.species] === ArrayBuffer"),
+        "true"
+    );
+}
+
+#[test]
+fn is_view() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer.isView(new Int8Array(1))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "ArrayBuffer.isView(new DataView(new ArrayBuffer(1)))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer.isView(new ArrayBuffer(1))"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn slice() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new Uint8Array([1, 2, 3, 4, 5]).buffer;
+        var sliced = buffer.slice(1, -1);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sliced.byteLength"), "3");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(sliced).join()"),
+        "\"2,3,4\""
+    );
+    assert_eq!(forward(&mut context, "sliced !== buffer"), "true");
+    assert_eq!(forward(&mut context, "buffer.slice(4, 1).byteLength"), "0");
+
+    // The slice is a copy of the bytes.
+    forward(&mut context, "new Uint8Array(sliced)[0] = 42");
+    assert_eq!(forward(&mut context, "new Uint8Array(buffer)[1]"), "2");
+}
+
+#[test]
+fn detach() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(4);
+        var view = new Uint8Array(buffer);
+        var dataView = new DataView(buffer);
+        view[0] = 1;
+        "#;
+    forward(&mut context, init);
+
+    let buffer = context.eval("buffer").unwrap();
+    let bytes = buffer
+        .as_object()
+        .unwrap()
+        .borrow_mut()
+        .as_array_buffer_mut()
+        .unwrap()
+        .detach();
+    assert_eq!(bytes, Some(vec![1, 0, 0, 0]));
+
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut context, "view.length"), "0");
+    assert_eq!(forward(&mut context, "view[0]"), "undefined");
+    assert_eq!(
+        forward(&mut context, "try { buffer.slice() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { dataView.getInt8(0) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { view.fill(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { [...view] } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn resizable() {
+    let mut context = Context::new();
+    let init = r#"
+        var fixed = new ArrayBuffer(4);
+        var buffer = new ArrayBuffer(2, { maxByteLength: 8 });
+        var view = new Uint8Array(buffer);
+        view[1] = 5;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "fixed.resizable"), "false");
+    assert_eq!(forward(&mut context, "fixed.maxByteLength"), "4");
+    assert_eq!(forward(&mut context, "buffer.resizable"), "true");
+    assert_eq!(forward(&mut context, "buffer.maxByteLength"), "8");
+
+    forward(&mut context, "buffer.resize(6)");
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "6");
+    assert_eq!(forward(&mut context, "[...view].join()"), "\"0,5,0,0,0,0\"");
+
+    forward(&mut context, "buffer.resize(1)");
+    assert_eq!(forward(&mut context, "[...view].join()"), "\"0\"");
+    forward(&mut context, "buffer.resize(3)");
+    assert_eq!(
+        forward(&mut context, "[...view].join()"),
+        "\"0,0,0\"",
+        "bytes past a shrunk length are zeroed when growing again"
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.resize(9) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { fixed.resize(2) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(4, { maxByteLength: 2 }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(forward(&mut context, "buffer.slice(0).resizable"), "false");
+}
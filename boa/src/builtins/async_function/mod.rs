@@ -0,0 +1,272 @@
+//! This module implements the `AsyncFunction` object, the constructor of async functions.
+//!
+//! `AsyncFunction` is not a global object, it is reached through the `constructor` property of
+//! the prototype of async functions. Calling an async function runs its body in the vm, whose
+//! frame is suspended by every `await` expression until the awaited promise settles, and the
+//! function returns a promise of the result of its body.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncFunction
+
+use crate::{
+    builtins::{
+        function::{create_dynamic_function, Captures, DynamicFunctionKind},
+        promise::PromiseCapability,
+        Promise,
+    },
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    vm::{CodeBlock, CompletionType, FrameState, SuspendedFrame, Suspension},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use gc::{Gc, GcCell};
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// The values captured by the functions that resume an async function once the promise it
+/// awaits settles.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct AwaitCaptures {
+    /// The suspended frame, which is taken by the first of the functions to be called.
+    frame: Gc<GcCell<Option<SuspendedFrame>>>,
+    capability: PromiseCapability,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncFunction;
+
+impl AsyncFunction {
+    /// The name of the `AsyncFunction` constructor.
+    const NAME: &'static str = "AsyncFunction";
+
+    /// The amount of arguments the `AsyncFunction` constructor takes.
+    const LENGTH: usize = 1;
+
+    /// Initializes the `%AsyncFunction%` intrinsic object.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function_object = context.standard_objects().function_object().clone();
+        let async_function_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().async_function_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(function_object.prototype().into())
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        async_function_object.set_prototype_instance(function_object.constructor().into());
+    }
+
+    /// `AsyncFunction ( p1, p2, … , pn, body )`
+    ///
+    /// Creates an async function from the source text of its parameters and body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-async-function-constructor-arguments
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncFunction/AsyncFunction
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        create_dynamic_function(new_target, args, DynamicFunctionKind::Async, context)
+    }
+
+    /// `EvaluateAsyncFunctionBody`
+    ///
+    /// Starts running the body of a called async function in a new frame, on top of the given
+    /// environments of the call, and returns the promise of its result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-evaluateasyncfunctionbody
+    pub(crate) fn start(
+        code: Rc<CodeBlock>,
+        environments: Vec<Environment>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let capability = Promise::new_promise_capability(&constructor, context)?;
+
+        // 2. Let declResult be FunctionDeclarationInstantiation(functionObject, argumentsList).
+        // 3. If declResult is an abrupt completion, then
+        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « declResult.[[Value]] »).
+        // 4. Else,
+        //     a. Perform ! AsyncFunctionStart(promiseCapability, FunctionBody).
+        let frame = SuspendedFrame::new(code, environments);
+        let state = frame.resume(JsValue::undefined(), CompletionType::Normal, context);
+        Self::settle_or_await(state, &capability, context)?;
+
+        // 5. Return Completion { [[Type]]: return, [[Value]]: promiseCapability.[[Promise]], [[Target]]: empty }.
+        Ok(capability.promise().clone().into())
+    }
+
+    /// Continues the async execution of a frame, given the state it reached when it was last
+    /// resumed.
+    ///
+    /// When the frame awaits a value, this performs the steps of `Await` that register the
+    /// reactions resuming the frame once the awaited promise settles. When the frame completes,
+    /// this performs the last steps of `AsyncBlockStart`, settling the promise of the
+    /// `capability` with its result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    pub(crate) fn settle_or_await(
+        mut state: FrameState,
+        capability: &PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        loop {
+            let (value, frame) = match state {
+                // e. If result.[[Type]] is normal, then
+                //     i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                // f. Else if result.[[Type]] is return, then
+                //     i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
+                FrameState::Complete(Ok(value)) => {
+                    capability
+                        .resolve()
+                        .call(&JsValue::undefined(), &[value], context)?;
+                    return Ok(());
+                }
+                // g. Else,
+                //     i. Assert: result.[[Type]] is throw.
+                //     ii. Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
+                FrameState::Complete(Err(error)) => {
+                    capability
+                        .reject()
+                        .call(&JsValue::undefined(), &[error], context)?;
+                    return Ok(());
+                }
+                FrameState::Suspended(Suspension::Await(value), frame) => (value, frame),
+                FrameState::Suspended(Suspension::Yield(_), _) => {
+                    unreachable!("async functions do not yield")
+                }
+            };
+
+            // 2. Let promise be ? PromiseResolve(%Promise%, value).
+            let constructor = context
+                .standard_objects()
+                .promise_object()
+                .constructor()
+                .into();
+            let promise = match Promise::promise_resolve(&constructor, value, context) {
+                Ok(promise) => promise,
+                Err(error) => {
+                    state = frame.resume(error, CompletionType::Throw, context);
+                    continue;
+                }
+            };
+
+            let captures = AwaitCaptures {
+                frame: Gc::new(GcCell::new(Some(frame))),
+                capability: capability.clone(),
+            };
+
+            // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures asyncContext and performs the following steps when called:
+            //     a. Let prevContext be the running execution context.
+            //     b. Suspend prevContext.
+            //     c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
+            //     d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
+            //     e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
+            //     f. Return undefined.
+            // 4. Let onFulfilled be ! CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+            let on_fulfilled = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    let value = args.get_or_undefined(0).clone();
+                    Self::resume_awaiting(captures, Ok(value), context)
+                },
+                captures.clone(),
+            )
+            .length(1)
+            .build();
+
+            // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures asyncContext and performs the following steps when called:
+            //     a. Let prevContext be the running execution context.
+            //     b. Suspend prevContext.
+            //     c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
+            //     d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
+            //     e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
+            //     f. Return undefined.
+            // 6. Let onRejected be ! CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+            let on_rejected = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    let reason = args.get_or_undefined(0).clone();
+                    Self::resume_awaiting(captures, Err(reason), context)
+                },
+                captures,
+            )
+            .length(1)
+            .build();
+
+            // 7. Perform ! PerformPromiseThen(promise, onFulfilled, onRejected).
+            let promise = promise
+                .as_object()
+                .expect("PromiseResolve returns a promise");
+            Promise::perform_promise_then(
+                &promise,
+                &on_fulfilled.into(),
+                &on_rejected.into(),
+                None,
+                context,
+            );
+
+            // 8. Remove asyncContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+            return Ok(());
+        }
+    }
+
+    /// Resumes the async function that awaited a promise, once the promise settles.
+    fn resume_awaiting(
+        captures: &Captures,
+        completion: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let captures = captures
+            .downcast_ref::<AwaitCaptures>()
+            .expect("await functions capture their frame")
+            .clone();
+
+        // The promise settles once, so only one of the functions resumes the frame.
+        let frame = captures.frame.borrow_mut().take();
+        if let Some(frame) = frame {
+            let state = match completion {
+                Ok(value) => frame.resume(value, CompletionType::Normal, context),
+                Err(reason) => frame.resume(reason, CompletionType::Throw, context),
+            };
+            Self::settle_or_await(state, &captures.capability, context)?;
+        }
+
+        Ok(JsValue::undefined())
+    }
+}
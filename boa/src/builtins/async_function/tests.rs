@@ -0,0 +1,168 @@
+use crate::{forward, Context};
+
+#[test]
+fn returns_promise() {
+    let mut context = Context::new();
+    let init = r#"
+        var value;
+        async function f() { return 1; }
+        var p = f();
+        p.then(v => value = v);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "p instanceof Promise"), "true");
+    assert_eq!(forward(&mut context, "value"), "undefined");
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "value"), "1");
+}
+
+#[test]
+fn await_suspends() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        async function f(x) {
+            log.push("start " + x);
+            let y = await x;
+            log.push("resumed " + y);
+            const z = await Promise.resolve(y + 1);
+            log.push("resumed " + z);
+            return z + 1;
+        }
+        f(1).then(v => log.push("result " + v));
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"start 1,sync\"");
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"start 1,sync,resumed 1,resumed 2,result 3\""
+    );
+}
+
+#[test]
+fn await_in_loops_and_blocks() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var f = async function() {
+            let sum = 0;
+            for (let i = 0; i < 5; i++) {
+                let step = await i;
+                sum += step;
+            }
+            {
+                let block = await 10;
+                sum += block;
+            }
+            return sum;
+        };
+        f().then(v => result = v);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "20");
+}
+
+#[test]
+fn throw_and_rejection() {
+    let mut context = Context::new();
+    let init = r#"
+        var caught;
+        var reason;
+        async function f() {
+            try {
+                await Promise.reject("rejected");
+            } catch (e) {
+                caught = e;
+            }
+            throw "thrown";
+        }
+        f().catch(e => reason = e);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "caught"), "\"rejected\"");
+    assert_eq!(forward(&mut context, "reason"), "\"thrown\"");
+}
+
+#[test]
+fn interleaving() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        async function task(name) {
+            for (let i = 0; i < 2; i++) {
+                log.push(name + i);
+                await null;
+            }
+        }
+        task("a");
+        task("b");
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"a0,b0,a1,b1\"");
+}
+
+#[test]
+fn nested_async_calls() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        async function inner(x) {
+            await null;
+            return x * 2;
+        }
+        async function outer() {
+            const a = await inner(1);
+            const b = await inner(a);
+            return [a, b];
+        }
+        outer().then(v => result = v.join());
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"2,4\"");
+}
+
+#[test]
+fn async_function_object() {
+    let mut context = Context::new();
+    let init = r#"
+        async function f() {}
+        var AsyncFunction = Object.getPrototypeOf(f).constructor;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "AsyncFunction.name"),
+        "\"AsyncFunction\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(AsyncFunction) === Function"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(AsyncFunction.prototype) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(f)"),
+        "\"[object AsyncFunction]\""
+    );
+    assert_eq!(
+        forward(&mut context, "f.hasOwnProperty('prototype')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "try { new f() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
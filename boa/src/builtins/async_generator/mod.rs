@@ -0,0 +1,686 @@
+//! This module implements the `AsyncGenerator` object.
+//!
+//! Calling an async generator function does not run its body, it returns an async generator
+//! instead. Every call to one of its methods enqueues a request and returns a promise of its
+//! result. The body runs in the vm, and its frame is suspended by the `yield` expressions that
+//! settle the requests one at a time, and by the `await` expressions until the awaited promise
+//! settles.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator
+
+use crate::{
+    builtins::{
+        function::{make_builtin_fn, Captures},
+        iterable::create_iter_result_object,
+        promise::PromiseCapability,
+        Promise,
+    },
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject, Object, ObjectData, PROTOTYPE},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    teardown::Contents,
+    value::ArgumentsExt,
+    vm::{CodeBlock, CompletionType, FrameState, SuspendedFrame, Suspension},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use std::{collections::VecDeque, rc::Rc};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of an async generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AsyncGeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    AwaitingReturn,
+    Completed,
+}
+
+/// The kind of completion an async generator is resumed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AsyncGeneratorResumeKind {
+    Normal,
+    Return,
+    Throw,
+}
+
+/// `AsyncGeneratorRequest` record, a call to one of the methods of an async generator waiting
+/// to be settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorrequest-records
+#[derive(Debug, Trace, Finalize)]
+struct AsyncGeneratorRequest {
+    #[unsafe_ignore_trace]
+    kind: AsyncGeneratorResumeKind,
+    value: JsValue,
+    capability: PromiseCapability,
+}
+
+/// The internal representation of an `AsyncGenerator` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct AsyncGenerator {
+    #[unsafe_ignore_trace]
+    state: AsyncGeneratorState,
+    /// The requests waiting to be settled, the oldest one first.
+    queue: VecDeque<AsyncGeneratorRequest>,
+    /// The frame running the body of the generator, while it is suspended.
+    frame: Option<SuspendedFrame>,
+}
+
+impl AsyncGenerator {
+    pub(crate) const NAME: &'static str = "AsyncGenerator";
+
+    /// Queues the pending requests and the suspended frame to be emptied by the teardown of the
+    /// context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        for request in &self.queue {
+            contents.push_value(&request.value);
+            request.capability.collect_contents(contents);
+        }
+        if let Some(frame) = &self.frame {
+            frame.collect_contents(contents);
+        }
+    }
+
+    /// Create the `%AsyncGeneratorPrototype%` object, and link it with
+    /// `%AsyncGeneratorFunction.prototype%`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-asyncgenerator-prototype
+    pub(crate) fn create_prototype(
+        context: &mut Context,
+        async_iterator_prototype: JsValue,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let async_generator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &async_generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &async_generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &async_generator, 1, context);
+        async_generator.set_prototype_instance(async_iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value(Self::NAME)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        async_generator.insert(to_string_tag, to_string_tag_property);
+
+        let async_generator_function = context
+            .standard_objects()
+            .async_generator_function_object()
+            .prototype();
+        async_generator.insert_property(
+            "constructor",
+            PropertyDescriptor::builder()
+                .value(async_generator_function.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+        );
+        async_generator_function.insert_property(
+            PROTOTYPE,
+            PropertyDescriptor::builder()
+                .value(async_generator.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+        );
+
+        async_generator
+    }
+
+    /// `EvaluateAsyncGeneratorBody`
+    ///
+    /// Creates the async generator returned by a call to an async generator function, whose
+    /// body runs in a frame on top of the given environments of the call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-evaluateasyncgeneratorbody
+    pub(crate) fn start(
+        function: &JsObject,
+        code: Rc<CodeBlock>,
+        environments: Vec<Environment>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? FunctionDeclarationInstantiation(functionObject, argumentsList).
+        // 2. Let generator be ? OrdinaryCreateFromConstructor(functionObject, "%AsyncGeneratorFunction.prototype.prototype%", « [[AsyncGeneratorState]], [[AsyncGeneratorContext]], [[AsyncGeneratorQueue]], [[GeneratorBrand]] »).
+        let prototype = function.get(PROTOTYPE, context)?;
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            context.iterator_prototypes().async_generator().into()
+        };
+
+        // 3. Set generator.[[GeneratorBrand]] to empty.
+        // 4. Perform ! AsyncGeneratorStart(generator, FunctionBody).
+        let async_generator = Self {
+            state: AsyncGeneratorState::SuspendedStart,
+            queue: VecDeque::new(),
+            frame: Some(SuspendedFrame::new(code, environments)),
+        };
+
+        // 5. Return Completion { [[Type]]: return, [[Value]]: generator, [[Target]]: empty }.
+        Ok(JsObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::async_generator(async_generator),
+        ))
+        .into())
+    }
+
+    /// `AsyncGenerator.prototype.next ( value )`
+    ///
+    /// Requests the next value of the async generator, returning a promise of the iterator
+    /// result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-prototype-next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator/next
+    pub(crate) fn next(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let generator be the this value.
+        // 2. Let completion be NormalCompletion(value).
+        // 3. Return ! AsyncGeneratorEnqueue(generator, completion, empty).
+        let value = args.get_or_undefined(0).clone();
+        Self::enqueue(this, value, AsyncGeneratorResumeKind::Normal, context)
+    }
+
+    /// `AsyncGenerator.prototype.return ( value )`
+    ///
+    /// Requests the async generator to return, as if the suspended `yield` expression were a
+    /// `return` statement.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-prototype-return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator/return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let generator be the this value.
+        // 2. Let completion be Completion { [[Type]]: return, [[Value]]: value, [[Target]]: empty }.
+        // 3. Return ! AsyncGeneratorEnqueue(generator, completion, empty).
+        let value = args.get_or_undefined(0).clone();
+        Self::enqueue(this, value, AsyncGeneratorResumeKind::Return, context)
+    }
+
+    /// `AsyncGenerator.prototype.throw ( exception )`
+    ///
+    /// Requests the async generator to throw, as if the suspended `yield` expression were a
+    /// `throw` statement.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-prototype-throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator/throw
+    pub(crate) fn throw(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let generator be the this value.
+        // 2. Let completion be ThrowCompletion(exception).
+        // 3. Return ! AsyncGeneratorEnqueue(generator, completion, empty).
+        let exception = args.get_or_undefined(0).clone();
+        Self::enqueue(this, exception, AsyncGeneratorResumeKind::Throw, context)
+    }
+
+    /// `AsyncGeneratorEnqueue ( generator, completion, generatorBrand )`
+    ///
+    /// Adds a request to the queue of an async generator, and resumes the generator if it is
+    /// not running.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorenqueue
+    fn enqueue(
+        this: &JsValue,
+        value: JsValue,
+        kind: AsyncGeneratorResumeKind,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let capability = Promise::new_promise_capability(&constructor, context)?;
+
+        // 2. Let check be AsyncGeneratorValidate(generator, generatorBrand).
+        // 3. If check is an abrupt completion, then
+        //     a. Let badGeneratorError be a newly created TypeError object.
+        //     b. Perform ! Call(promiseCapability.[[Reject]], undefined, « badGeneratorError »).
+        //     c. Return promiseCapability.[[Promise]].
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_async_generator().is_some() => object,
+            _ => {
+                let error = context.construct_type_error("`this` is not an async generator");
+                return capability.reject_abrupt(error, context);
+            }
+        };
+
+        // 4. Let queue be generator.[[AsyncGeneratorQueue]].
+        // 5. Let request be AsyncGeneratorRequest { [[Completion]]: completion, [[Capability]]: promiseCapability }.
+        // 6. Append request to the end of queue.
+        // 7. Let state be generator.[[AsyncGeneratorState]].
+        let promise = capability.promise().clone();
+        let state = {
+            let mut object = object.borrow_mut();
+            let async_generator = object
+                .as_async_generator_mut()
+                .expect("checked to be an async generator");
+            async_generator.queue.push_back(AsyncGeneratorRequest {
+                kind,
+                value,
+                capability,
+            });
+            async_generator.state
+        };
+
+        // 8. If state is not executing, then
+        //     a. Perform ! AsyncGeneratorResumeNext(generator).
+        if state != AsyncGeneratorState::Executing {
+            Self::resume_next(&object, context)?;
+        }
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// `AsyncGeneratorResumeNext ( generator )`
+    ///
+    /// Settles or runs the oldest request of the queue of an async generator, until the queue
+    /// is empty or the generator has to wait for a promise to settle.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorresumenext
+    fn resume_next(object: &JsObject, context: &mut Context) -> JsResult<()> {
+        loop {
+            let (kind, value, frame) = {
+                let mut object = object.borrow_mut();
+                let async_generator = object
+                    .as_async_generator_mut()
+                    .expect("resumed objects are async generators");
+
+                // 2. Let state be generator.[[AsyncGeneratorState]].
+                // 3. Assert: state is not executing.
+                // 4. If state is awaiting-return, return undefined.
+                // 5. Let queue be generator.[[AsyncGeneratorQueue]].
+                // 6. If queue is an empty List, return undefined.
+                // 7. Let next be the value of the first element of queue.
+                // 8. Assert: next is an AsyncGeneratorRequest record.
+                // 9. Let completion be next.[[Completion]].
+                let next = match async_generator.queue.front() {
+                    Some(next)
+                        if async_generator.state != AsyncGeneratorState::Executing
+                            && async_generator.state != AsyncGeneratorState::AwaitingReturn =>
+                    {
+                        next
+                    }
+                    _ => return Ok(()),
+                };
+                let (kind, value) = (next.kind, next.value.clone());
+
+                // 10. If completion is an abrupt completion, then
+                //     a. If state is suspendedStart, then
+                //         i. Set generator.[[AsyncGeneratorState]] to completed.
+                //         ii. Set state to completed.
+                if kind != AsyncGeneratorResumeKind::Normal
+                    && async_generator.state == AsyncGeneratorState::SuspendedStart
+                {
+                    async_generator.state = AsyncGeneratorState::Completed;
+                    async_generator.frame = None;
+                }
+
+                if async_generator.state == AsyncGeneratorState::Completed {
+                    // b. If state is completed, then
+                    //     i. If completion.[[Type]] is return, then
+                    //         1. Set generator.[[AsyncGeneratorState]] to awaiting-return.
+                    if kind == AsyncGeneratorResumeKind::Return {
+                        async_generator.state = AsyncGeneratorState::AwaitingReturn;
+                    }
+                    (kind, value, None)
+                } else {
+                    // 12. Assert: state is either suspendedStart or suspendedYield.
+                    // 16. Set generator.[[AsyncGeneratorState]] to executing.
+                    async_generator.state = AsyncGeneratorState::Executing;
+                    (kind, value, async_generator.frame.take())
+                }
+            };
+
+            let frame = match (frame, kind) {
+                (Some(frame), _) => frame,
+                // i. If completion.[[Type]] is return, then
+                //     2. Let promise be ? PromiseResolve(%Promise%, completion.[[Value]]).
+                //     3. Let stepsFulfilled be the algorithm steps defined in AsyncGeneratorResumeNext Return Processor Fulfilled Functions.
+                //     6. Let stepsRejected be the algorithm steps defined in AsyncGeneratorResumeNext Return Processor Rejected Functions.
+                //     9. Perform ! PerformPromiseThen(promise, onFulfilled, onRejected).
+                //     10. Return undefined.
+                (None, AsyncGeneratorResumeKind::Return) => {
+                    Self::await_return(object, value, context);
+                    return Ok(());
+                }
+                // ii. Else,
+                //     1. Assert: completion.[[Type]] is throw.
+                //     2. Perform ! AsyncGeneratorReject(generator, completion.[[Value]]).
+                //     3. Return undefined.
+                (None, AsyncGeneratorResumeKind::Throw) => {
+                    Self::settle(object, Err(value), context)?;
+                    continue;
+                }
+                // 11. Else if state is completed, return ! AsyncGeneratorResolve(generator, undefined, true).
+                (None, AsyncGeneratorResumeKind::Normal) => {
+                    Self::settle(object, Ok((JsValue::undefined(), true)), context)?;
+                    continue;
+                }
+            };
+            // 17. Push genContext onto the execution context stack; genContext is now the running execution context.
+            // 18. Resume the suspended evaluation of genContext using completion as the result of the operation that suspended it. Let result be the completion record returned by the resumed computation.
+            let completion = match kind {
+                AsyncGeneratorResumeKind::Normal => CompletionType::Normal,
+                AsyncGeneratorResumeKind::Return => CompletionType::Return,
+                AsyncGeneratorResumeKind::Throw => CompletionType::Throw,
+            };
+            return Self::run(object, frame, value, completion, context);
+        }
+    }
+
+    /// Awaits the value of a `return` request to a completed async generator, before settling
+    /// the request with it.
+    fn await_return(object: &JsObject, value: JsValue, context: &mut Context) {
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let promise = match Promise::promise_resolve(&constructor, value, context) {
+            Ok(promise) => promise,
+            Err(error) => {
+                Self::complete_return(object, Err(error), context);
+                return;
+            }
+        };
+
+        // 1. Set generator.[[AsyncGeneratorState]] to completed.
+        // 2. Return ! AsyncGeneratorResolve(generator, value, true).
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures, context| {
+                let value = args.get_or_undefined(0).clone();
+                Self::complete_return(&Self::captured(captures), Ok(value), context);
+                Ok(JsValue::undefined())
+            },
+            object.clone(),
+        )
+        .length(1)
+        .build();
+
+        // 1. Set generator.[[AsyncGeneratorState]] to completed.
+        // 2. Return ! AsyncGeneratorReject(generator, reason).
+        let on_rejected = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures, context| {
+                let reason = args.get_or_undefined(0).clone();
+                Self::complete_return(&Self::captured(captures), Err(reason), context);
+                Ok(JsValue::undefined())
+            },
+            object.clone(),
+        )
+        .length(1)
+        .build();
+
+        let promise = promise
+            .as_object()
+            .expect("PromiseResolve returns a promise");
+        Promise::perform_promise_then(
+            &promise,
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
+    }
+
+    /// Settles a `return` request to a completed async generator, once its value settles.
+    fn complete_return(object: &JsObject, result: JsResult<JsValue>, context: &mut Context) {
+        object
+            .borrow_mut()
+            .as_async_generator_mut()
+            .expect("resumed objects are async generators")
+            .state = AsyncGeneratorState::Completed;
+        let _ = Self::settle(object, result.map(|value| (value, true)), context)
+            .and_then(|_| Self::resume_next(object, context));
+    }
+
+    /// Resumes the frame of an async generator with the given completion, until the body of
+    /// the generator yields, completes, or awaits a promise.
+    fn run(
+        object: &JsObject,
+        frame: SuspendedFrame,
+        value: JsValue,
+        completion: CompletionType,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let mut state = frame.resume(value, completion, context);
+        loop {
+            let (suspension, frame) = match state {
+                FrameState::Suspended(suspension, frame) => (suspension, frame),
+                // `AsyncGeneratorStart`
+                //  b. Set generator.[[AsyncGeneratorState]] to completed.
+                //  c. If result is a normal completion, let resultValue be undefined.
+                //  d. Else,
+                //     i. Let resultValue be result.[[Value]].
+                //     ii. If result.[[Type]] is not return, then
+                //         1. Return ! AsyncGeneratorReject(generator, resultValue).
+                //  e. Return ! AsyncGeneratorResolve(generator, resultValue, true).
+                FrameState::Complete(result) => {
+                    object
+                        .borrow_mut()
+                        .as_async_generator_mut()
+                        .expect("resumed objects are async generators")
+                        .state = AsyncGeneratorState::Completed;
+                    Self::settle(object, result.map(|value| (value, true)), context)?;
+                    return Self::resume_next(object, context);
+                }
+            };
+
+            match suspension {
+                // `AsyncGeneratorYield ( value )`
+                //  8. Set generator.[[AsyncGeneratorState]] to suspendedYield.
+                //  9. Remove genContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+                //  10. Set the code evaluation state of genContext such that when evaluation is resumed with a Completion resumptionValue the following steps will be performed:
+                //  11. Return ! AsyncGeneratorResolve(generator, value, false).
+                Suspension::Yield(value) => {
+                    {
+                        let mut object = object.borrow_mut();
+                        let async_generator = object
+                            .as_async_generator_mut()
+                            .expect("resumed objects are async generators");
+                        async_generator.state = AsyncGeneratorState::SuspendedYield;
+                        async_generator.frame = Some(frame);
+                    }
+                    Self::settle(object, Ok((value, false)), context)?;
+                    return Self::resume_next(object, context);
+                }
+                // `Await ( value )`
+                Suspension::Await(value) => {
+                    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+                    let constructor = context
+                        .standard_objects()
+                        .promise_object()
+                        .constructor()
+                        .into();
+                    let promise = match Promise::promise_resolve(&constructor, value, context) {
+                        Ok(promise) => promise,
+                        Err(error) => {
+                            state = frame.resume(error, CompletionType::Throw, context);
+                            continue;
+                        }
+                    };
+
+                    object
+                        .borrow_mut()
+                        .as_async_generator_mut()
+                        .expect("resumed objects are async generators")
+                        .frame = Some(frame);
+
+                    // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures asyncContext and performs the following steps when called:
+                    //     d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
+                    // 4. Let onFulfilled be ! CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+                    let on_fulfilled = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_, args, captures, context| {
+                            let value = args.get_or_undefined(0).clone();
+                            Self::resume_awaiting(&Self::captured(captures), Ok(value), context)
+                        },
+                        object.clone(),
+                    )
+                    .length(1)
+                    .build();
+
+                    // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures asyncContext and performs the following steps when called:
+                    //     d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
+                    // 6. Let onRejected be ! CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+                    let on_rejected = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_, args, captures, context| {
+                            let reason = args.get_or_undefined(0).clone();
+                            Self::resume_awaiting(&Self::captured(captures), Err(reason), context)
+                        },
+                        object.clone(),
+                    )
+                    .length(1)
+                    .build();
+
+                    // 7. Perform ! PerformPromiseThen(promise, onFulfilled, onRejected).
+                    let promise = promise
+                        .as_object()
+                        .expect("PromiseResolve returns a promise");
+                    Promise::perform_promise_then(
+                        &promise,
+                        &on_fulfilled.into(),
+                        &on_rejected.into(),
+                        None,
+                        context,
+                    );
+
+                    // 8. Remove asyncContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Resumes the async generator that awaited a promise, once the promise settles.
+    fn resume_awaiting(
+        object: &JsObject,
+        completion: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let frame = object
+            .borrow_mut()
+            .as_async_generator_mut()
+            .expect("resumed objects are async generators")
+            .frame
+            .take();
+
+        if let Some(frame) = frame {
+            match completion {
+                Ok(value) => Self::run(object, frame, value, CompletionType::Normal, context)?,
+                Err(reason) => Self::run(object, frame, reason, CompletionType::Throw, context)?,
+            }
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `AsyncGeneratorResolve ( generator, value, done )` and
+    /// `AsyncGeneratorReject ( generator, exception )`
+    ///
+    /// Removes the oldest request from the queue of an async generator, and settles its promise
+    /// with an iterator result or an exception.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorresolve
+    fn settle(
+        object: &JsObject,
+        result: JsResult<(JsValue, bool)>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 3. Assert: queue is not an empty List.
+        // 4. Let next be the first element of queue.
+        // 5. Remove the first element from queue.
+        // 6. Let promiseCapability be next.[[Capability]].
+        let next = object
+            .borrow_mut()
+            .as_async_generator_mut()
+            .expect("resumed objects are async generators")
+            .queue
+            .pop_front()
+            .expect("settled async generators have a request");
+
+        match result {
+            // 7. Let iteratorResult be ! CreateIterResultObject(value, done).
+            // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iteratorResult »).
+            Ok((value, done)) => {
+                let iterator_result = create_iter_result_object(context, value, done);
+                next.capability.resolve().call(
+                    &JsValue::undefined(),
+                    &[iterator_result],
+                    context,
+                )?;
+            }
+            // 7. Perform ! Call(promiseCapability.[[Reject]], undefined, « exception »).
+            Err(exception) => {
+                next.capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[exception], context)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the async generator captured by the functions that resume it.
+    fn captured(captures: &Captures) -> JsObject {
+        captures
+            .downcast_ref::<JsObject>()
+            .expect("async generator functions capture the generator")
+            .clone()
+    }
+}
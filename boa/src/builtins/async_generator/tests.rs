@@ -0,0 +1,237 @@
+use crate::{forward, Context};
+
+#[test]
+fn next_returns_promises() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        async function* g() {
+            yield 1;
+            yield 2;
+            return 3;
+        }
+        var it = g();
+        var p = it.next();
+        it.next().then(r => log.push(r.value + " " + r.done));
+        p.then(r => log.push(r.value + " " + r.done));
+        it.next().then(r => log.push(r.value + " " + r.done));
+        it.next().then(r => log.push(r.value + " " + r.done));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "p instanceof Promise"), "true");
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"1 false,2 false,3 true,undefined true\""
+    );
+}
+
+#[test]
+fn await_in_body() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        async function* g() {
+            const x = await Promise.resolve(1);
+            log.push("awaited " + x);
+            yield x + (await 1);
+            yield Promise.resolve(5);
+        }
+        var it = g();
+        it.next().then(r => log.push("next " + r.value));
+        it.next().then(r => log.push("next " + r.value));
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"sync,awaited 1,next 2,next 5\""
+    );
+}
+
+#[test]
+fn for_await_of() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        async function* g() {
+            yield 1;
+            yield await 2;
+            yield 3;
+        }
+        async function f() {
+            let sum = 0;
+            for await (const x of g()) {
+                sum += x;
+            }
+            for await (let x of [Promise.resolve(10), 20]) {
+                sum += x;
+            }
+            return sum;
+        }
+        f().then(v => result = v);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "36");
+}
+
+#[test]
+fn return_and_throw() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        async function* g() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                log.push("finally");
+            }
+        }
+        var it = g();
+        it.next();
+        it.return(Promise.resolve(5)).then(r => log.push(r.value + " " + r.done));
+        it.next().then(r => log.push(r.value + " " + r.done));
+
+        var it2 = g();
+        it2.throw(new Error("early")).catch(e => log.push(e.message));
+        it2.next().then(r => log.push(r.value + " " + r.done));
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"early,undefined true,finally,5 true,undefined true\""
+    );
+}
+
+#[test]
+fn throw_is_caught() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        async function* g() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+        }
+        var it = g();
+        it.next();
+        it.throw("error").then(r => result = r.value);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"caught error\"");
+}
+
+#[test]
+fn yield_delegates() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        async function* inner() {
+            yield await "a";
+            yield "b";
+            return "inner";
+        }
+        async function* outer() {
+            const returned = yield* inner();
+            yield returned;
+            yield* ["c", Promise.resolve("d")];
+        }
+        async function f() {
+            for await (const x of outer()) {
+                log.push(x);
+            }
+        }
+        f();
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"a,b,inner,c,d\"");
+}
+
+#[test]
+fn async_iterator_protocol() {
+    let mut context = Context::new();
+    let init = r#"
+        var result = [];
+        var iterable = {};
+        iterable[Symbol.asyncIterator] = function() {
+            let i = 0;
+            return {
+                next() {
+                    i++;
+                    return Promise.resolve({ value: i, done: i > 3 });
+                }
+            };
+        };
+        async function f() {
+            for await (const x of iterable) {
+                result.push(x);
+            }
+        }
+        f();
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn prototypes() {
+    let mut context = Context::new();
+    let init = r#"
+        async function* g() {}
+        var AsyncGeneratorFunction = Object.getPrototypeOf(g).constructor;
+        var AsyncGeneratorPrototype = Object.getPrototypeOf(g.prototype);
+        var AsyncIteratorPrototype = Object.getPrototypeOf(AsyncGeneratorPrototype);
+        var it = g();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "AsyncGeneratorFunction.name"),
+        "\"AsyncGeneratorFunction\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(it) === g.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "AsyncGeneratorPrototype[Symbol.toStringTag]"),
+        "\"AsyncGenerator\""
+    );
+    assert_eq!(
+        forward(&mut context, "it[Symbol.asyncIterator]() === it"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "typeof AsyncIteratorPrototype[Symbol.asyncIterator]"
+        ),
+        "\"function\""
+    );
+    assert_eq!(
+        forward(&mut context, "g.prototype.hasOwnProperty('constructor')"),
+        "false"
+    );
+}
+
+#[test]
+fn invalid_this_rejects() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        async function* g() {}
+        g.prototype.next.call({}).catch(e => result = e instanceof TypeError);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "true");
+}
@@ -0,0 +1,81 @@
+//! This module implements the `AsyncGeneratorFunction` object, the constructor of async generator
+//! functions.
+//!
+//! `AsyncGeneratorFunction` is not a global object, it is reached through the `constructor`
+//! property of the prototype of async generator functions.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorfunction-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGeneratorFunction
+
+use crate::{
+    builtins::function::{create_dynamic_function, DynamicFunctionKind},
+    object::ConstructorBuilder,
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncGeneratorFunction;
+
+impl AsyncGeneratorFunction {
+    /// The name of the `AsyncGeneratorFunction` constructor.
+    const NAME: &'static str = "AsyncGeneratorFunction";
+
+    /// The amount of arguments the `AsyncGeneratorFunction` constructor takes.
+    const LENGTH: usize = 1;
+
+    /// Initializes the `%AsyncGeneratorFunction%` intrinsic object.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function_object = context.standard_objects().function_object().clone();
+        let async_generator_function_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context
+                .standard_objects()
+                .async_generator_function_object()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(function_object.prototype().into())
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        async_generator_function_object
+            .set_prototype_instance(function_object.constructor().into());
+    }
+
+    /// `AsyncGeneratorFunction ( p1, p2, … , pn, body )`
+    ///
+    /// Creates an async generator function from the source text of its parameters and body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorfunction
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGeneratorFunction
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        create_dynamic_function(
+            new_target,
+            args,
+            DynamicFunctionKind::AsyncGenerator,
+            context,
+        )
+    }
+}
@@ -0,0 +1,601 @@
+//! This module implements the global `Atomics` object.
+//!
+//! The `Atomics` object provides atomic operations on the elements of integer typed arrays. On a
+//! `SharedArrayBuffer`, they are atomic with respect to the operations of the other agents
+//! sharing the buffer, which can also wait for and notify each other.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-atomics-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics
+
+use std::time::Duration;
+
+use crate::{
+    builtins::{
+        shared_array_buffer::SharedDataBlock,
+        typed_array::{ContentType, TypedArrayName},
+        BuiltIn, TypedArray,
+    },
+    object::{JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::{ArgumentsExt, IntegerOrInfinity},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Javascript `Atomics` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Atomics;
+
+impl BuiltIn for Atomics {
+    const NAME: &'static str = "Atomics";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        let object = ObjectInitializer::new(context)
+            .function(Self::add, "add", 3)
+            .function(Self::and, "and", 3)
+            .function(Self::compare_exchange, "compareExchange", 4)
+            .function(Self::exchange, "exchange", 3)
+            .function(Self::is_lock_free, "isLockFree", 1)
+            .function(Self::load, "load", 2)
+            .function(Self::or, "or", 3)
+            .function(Self::store, "store", 3)
+            .function(Self::sub, "sub", 3)
+            .function(Self::wait, "wait", 4)
+            .function(Self::notify, "notify", 3)
+            .function(Self::xor, "xor", 3)
+            .property(to_string_tag, Self::NAME, Attribute::readonly_builtin())
+            .build();
+        (Self::NAME, object.into(), Self::attribute())
+    }
+}
+
+impl Atomics {
+    /// `Atomics.add ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add
+    pub(crate) fn add(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let type be the Element Type value in Table 72 for typedArray.[[TypedArrayName]].
+        // 2. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 3. Let add be a new read-modify-write modification function ...
+        // 4. Return ? AtomicReadModifyWrite(typedArray, index, value, add).
+        Self::read_modify_write(
+            args,
+            |old, v| arithmetic(old, v, u64::wrapping_add),
+            context,
+        )
+    }
+
+    /// `Atomics.and ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.and
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and
+    pub(crate) fn and(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let and be a new read-modify-write modification function ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, and).
+        Self::read_modify_write(args, |old, v| bitwise(old, v, |x, y| x & y), context)
+    }
+
+    /// `Atomics.compareExchange ( typedArray, index, expectedValue, replacementValue )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.compareexchange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange
+    pub(crate) fn compare_exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let typed_array =
+            Self::validate_integer_typed_array(args.get_or_undefined(0), false, context)?;
+
+        // 3. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let byte_index =
+            Self::validate_atomic_access(&typed_array, args.get_or_undefined(1), context)?;
+
+        // 4-6. Convert expectedValue and replacementValue to the content type of the array.
+        let name = typed_array_name(&typed_array);
+        let expected = to_integer_value(name, args.get_or_undefined(2), context)?;
+        let replacement = to_integer_value(name, args.get_or_undefined(3), context)?;
+
+        // 7. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 8-12. Let expectedBytes and replacementBytes be the raw bytes of the values.
+        let expected = name.numeric_to_raw_bytes(&expected, true);
+        let replacement = name.numeric_to_raw_bytes(&replacement, true);
+
+        // 13. Perform EnterCriticalSection(WL).
+        // 14-15. Let rawBytesRead be the bytes at byteIndexInBuffer, and replace them with
+        //        replacementBytes if they are equal to expectedBytes.
+        // 16. Perform LeaveCriticalSection(WL).
+        // 17. Return RawBytesToNumeric(elementType, rawBytesRead, isLittleEndian).
+        Self::modify(&typed_array, byte_index, context, |old| {
+            if old == expected.as_slice() {
+                Some(replacement)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// `Atomics.exchange ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.exchange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange
+    pub(crate) fn exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let second be a new read-modify-write modification function ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, second).
+        Self::read_modify_write(args, |_, v| v.to_vec(), context)
+    }
+
+    /// `Atomics.isLockFree ( size )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.islockfree
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/isLockFree
+    pub(crate) fn is_lock_free(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let n be ? ToIntegerOrInfinity(size).
+        let n = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 2. Let AR be the Agent Record of the surrounding agent.
+        // 3-5. If n = 1, 2 or 8, return AR.[[IsLockFree1]], AR.[[IsLockFree2]] or AR.[[IsLockFree8]].
+        //      The atomic operations of this implementation all take the lock of the buffer.
+        // 6. If n = 4, return true.
+        // 7. Return false.
+        Ok((n == IntegerOrInfinity::Integer(4)).into())
+    }
+
+    /// `Atomics.load ( typedArray, index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.load
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load
+    pub(crate) fn load(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let typed_array =
+            Self::validate_integer_typed_array(args.get_or_undefined(0), false, context)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let byte_index =
+            Self::validate_atomic_access(&typed_array, args.get_or_undefined(1), context)?;
+
+        // 3. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 4-6. Return GetValueFromBuffer(buffer, indexedPosition, elementType, true, SeqCst).
+        Self::modify(&typed_array, byte_index, context, |_| None)
+    }
+
+    /// `Atomics.or ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.or
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or
+    pub(crate) fn or(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let or be a new read-modify-write modification function ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, or).
+        Self::read_modify_write(args, |old, v| bitwise(old, v, |x, y| x | y), context)
+    }
+
+    /// `Atomics.store ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.store
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store
+    pub(crate) fn store(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let typed_array =
+            Self::validate_integer_typed_array(args.get_or_undefined(0), false, context)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let byte_index =
+            Self::validate_atomic_access(&typed_array, args.get_or_undefined(1), context)?;
+
+        // 3-4. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        //      Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let name = typed_array_name(&typed_array);
+        let v = to_integer_value(name, args.get_or_undefined(2), context)?;
+
+        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 6-8. Perform SetValueInBuffer(buffer, indexedPosition, elementType, v, true, SeqCst).
+        let bytes = name.numeric_to_raw_bytes(&v, true);
+        Self::modify(&typed_array, byte_index, context, |_| Some(bytes))?;
+
+        // 9. Return v.
+        Ok(v)
+    }
+
+    /// `Atomics.sub ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.sub
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub
+    pub(crate) fn sub(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let subtract be a new read-modify-write modification function ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, subtract).
+        Self::read_modify_write(
+            args,
+            |old, v| arithmetic(old, v, u64::wrapping_sub),
+            context,
+        )
+    }
+
+    /// `Atomics.wait ( typedArray, index, value, timeout )`
+    ///
+    /// Suspends the agent until the element is notified or the timeout, in milliseconds, expires,
+    /// if the element holds the value. The context must be allowed to block, see
+    /// [`Context::set_can_block`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait
+    pub(crate) fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray, true).
+        let typed_array =
+            Self::validate_integer_typed_array(args.get_or_undefined(0), true, context)?;
+
+        // 2. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+        let block = match shared_data_block(&typed_array) {
+            Some(block) => block,
+            None => return context.throw_type_error("Atomics.wait requires a shared typed array"),
+        };
+
+        // 3. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let byte_index =
+            Self::validate_atomic_access(&typed_array, args.get_or_undefined(1), context)?;
+
+        // 4. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 5. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        // 6. Otherwise, let v be ? ToInt32(value).
+        let name = typed_array_name(&typed_array);
+        let v = name.to_element_value(args.get_or_undefined(2), context)?;
+        let expected = name.numeric_to_raw_bytes(&v, true);
+
+        // 7. Let q be ? ToNumber(timeout).
+        // 8. If q is NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+        let q = args.get_or_undefined(3).to_number(context)?;
+        let timeout = if q.is_nan() || q / 1000.0 >= u64::MAX as f64 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(q.max(0.0) / 1000.0))
+        };
+
+        // 9. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
+        if !context.can_block() {
+            return context.throw_type_error("Atomics.wait cannot block in this context");
+        }
+
+        // 10-32. Suspend the agent until it is notified or the timeout expires, if the element
+        //        holds v, and return "ok", "timed-out" or "not-equal".
+        Ok(block.wait(byte_index, &expected, timeout).as_str().into())
+    }
+
+    /// `Atomics.notify ( typedArray, index, count )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/notify
+    pub(crate) fn notify(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray, true).
+        let typed_array =
+            Self::validate_integer_typed_array(args.get_or_undefined(0), true, context)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let byte_index =
+            Self::validate_atomic_access(&typed_array, args.get_or_undefined(1), context)?;
+
+        // 3. If count is undefined, let c be +∞.
+        // 4. Else,
+        //     a. Let intCount be ? ToIntegerOrInfinity(count).
+        //     b. Let c be max(intCount, 0).
+        let count = match args.get_or_undefined(2) {
+            JsValue::Undefined => usize::MAX,
+            count => match count.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(count) => count.max(0) as usize,
+                IntegerOrInfinity::PositiveInfinity => usize::MAX,
+                IntegerOrInfinity::NegativeInfinity => 0,
+            },
+        };
+
+        // 5-9. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        // 10-14. Notify at most c of the agents waiting on the element.
+        // 15. Return 𝔽(n).
+        let notified = match shared_data_block(&typed_array) {
+            Some(block) => block.notify(byte_index, count),
+            None => 0,
+        };
+        Ok(notified.into())
+    }
+
+    /// `Atomics.xor ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.xor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor
+    pub(crate) fn xor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let xor be a new read-modify-write modification function ...
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, xor).
+        Self::read_modify_write(args, |old, v| bitwise(old, v, |x, y| x ^ y), context)
+    }
+
+    /// Abstract operation `AtomicReadModifyWrite ( typedArray, index, value, op )`
+    ///
+    /// `op` computes the new raw bytes of the element from its old raw bytes and the raw bytes of
+    /// the value, all little endian.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomicreadmodifywrite
+    fn read_modify_write<F>(args: &[JsValue], op: F, context: &mut Context) -> JsResult<JsValue>
+    where
+        F: FnOnce(&[u8], &[u8]) -> Vec<u8>,
+    {
+        // 1. Let buffer be ? ValidateIntegerTypedArray(typedArray).
+        let typed_array =
+            Self::validate_integer_typed_array(args.get_or_undefined(0), false, context)?;
+
+        // 2. Let i be ? ValidateAtomicAccess(typedArray, index).
+        let byte_index =
+            Self::validate_atomic_access(&typed_array, args.get_or_undefined(1), context)?;
+
+        // 3-4. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        //      Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let name = typed_array_name(&typed_array);
+        let v = to_integer_value(name, args.get_or_undefined(2), context)?;
+
+        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 6-9. Return GetModifySetValueInBuffer(buffer, indexedPosition, elementType, v, op).
+        let bytes = name.numeric_to_raw_bytes(&v, true);
+        Self::modify(&typed_array, byte_index, context, |old| {
+            Some(op(old, &bytes))
+        })
+    }
+
+    /// Abstract operation `ValidateIntegerTypedArray ( typedArray [ , waitable ] )`
+    ///
+    /// Returns the typed array instead of its buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateintegertypedarray
+    fn validate_integer_typed_array(
+        typed_array: &JsValue,
+        waitable: bool,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If waitable is not present, set waitable to false.
+        // 2. Let buffer be ? ValidateTypedArray(typedArray).
+        let typed_array = TypedArray::validate(typed_array, context)?;
+
+        // 3. Let typeName be typedArray.[[TypedArrayName]].
+        // 4. Let type be the Element Type value in Table 72 for typeName.
+        let name = typed_array_name(&typed_array);
+
+        // 5. If waitable is true, then
+        //     a. If typeName is not "Int32Array" or "BigInt64Array", throw a TypeError exception.
+        // 6. Else,
+        //     a. If ! IsUnclampedIntegerElementType(type) is false and ! IsBigIntElementType(type) is false, throw a TypeError exception.
+        let valid = if waitable {
+            matches!(
+                name,
+                TypedArrayName::Int32Array | TypedArrayName::BigInt64Array
+            )
+        } else {
+            !matches!(
+                name,
+                TypedArrayName::Uint8ClampedArray
+                    | TypedArrayName::Float32Array
+                    | TypedArrayName::Float64Array
+            )
+        };
+        if !valid {
+            return Err(context.construct_type_error(format!(
+                "Atomics operations are not allowed on {}",
+                name.name()
+            )));
+        }
+
+        // 7. Return buffer.
+        Ok(typed_array)
+    }
+
+    /// Abstract operation `ValidateAtomicAccess ( typedArray, requestIndex )`
+    ///
+    /// Returns the position of the element in the buffer, in bytes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateatomicaccess
+    fn validate_atomic_access(
+        typed_array: &JsObject,
+        request_index: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<usize> {
+        // 1. Let length be TypedArrayLength(taRecord).
+        let length = typed_array
+            .borrow()
+            .as_typed_array()
+            .expect("checked to be a typed array")
+            .array_length();
+
+        // 2. Let accessIndex be ? ToIndex(requestIndex).
+        let access_index = request_index.to_index(context)?;
+
+        // 3. Assert: accessIndex ≥ 0.
+        // 4. If accessIndex ≥ length, throw a RangeError exception.
+        if access_index >= length {
+            return Err(context.construct_range_error("Atomics access index out of range"));
+        }
+
+        // 5. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 6. Let elementSize be the Element Size value specified in Table 72 for arrayTypeName.
+        // 7. Let offset be typedArray.[[ByteOffset]].
+        // 8. Return (accessIndex × elementSize) + offset.
+        let typed_array = typed_array.borrow();
+        let typed_array = typed_array
+            .as_typed_array()
+            .expect("checked to be a typed array");
+        Ok(
+            access_index * typed_array.typed_array_name().element_size()
+                + typed_array.byte_offset(),
+        )
+    }
+
+    /// Replaces the raw bytes of the element at `byte_index` with the result of `op`, if any,
+    /// atomically, and returns the old value of the element.
+    ///
+    /// Throws a `TypeError` if the buffer of the typed array was detached since it was validated,
+    /// or a `RangeError` if it shrunk so that the element is no longer part of the typed array,
+    /// like `RevalidateAtomicAccess` in the spec.
+    fn modify<F>(
+        typed_array: &JsObject,
+        byte_index: usize,
+        context: &mut Context,
+        op: F,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(&[u8]) -> Option<Vec<u8>>,
+    {
+        let typed_array = typed_array.borrow();
+        let typed_array = typed_array
+            .as_typed_array()
+            .expect("checked to be a typed array");
+        if typed_array.is_out_of_bounds() {
+            return context.throw_type_error("TypedArray buffer was detached or is out of bounds");
+        }
+
+        let name = typed_array.typed_array_name();
+        if byte_index + name.element_size() > typed_array.byte_offset() + typed_array.byte_length()
+        {
+            return context.throw_range_error("Atomics access index out of range");
+        }
+
+        let old = typed_array
+            .viewed_array_buffer()
+            .expect("a typed array that is not detached has a buffer")
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .expect("the viewed buffer of a typed array is an ArrayBuffer")
+            .modify_bytes(byte_index, name.element_size(), op);
+        Ok(name.raw_bytes_to_numeric(&old, true))
+    }
+}
+
+/// Gets the `[[TypedArrayName]]` of the typed array `o`.
+fn typed_array_name(o: &JsObject) -> TypedArrayName {
+    o.borrow()
+        .as_typed_array()
+        .expect("checked to be a typed array")
+        .typed_array_name()
+}
+
+/// Gets the shared data block viewed by the typed array `o`, if its buffer is shared.
+fn shared_data_block(o: &JsObject) -> Option<SharedDataBlock> {
+    o.borrow()
+        .as_typed_array()
+        .expect("checked to be a typed array")
+        .viewed_array_buffer()?
+        .borrow()
+        .as_array_buffer()?
+        .shared_data_block()
+        .cloned()
+}
+
+/// Converts `value` to a BigInt with `ToBigInt`, or to an integral Number with
+/// `ToIntegerOrInfinity`, depending on the content type of the typed array.
+fn to_integer_value(
+    name: TypedArrayName,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    if name.content_type() == ContentType::BigInt {
+        return Ok(value.to_bigint_strict(context)?.into());
+    }
+    Ok(match value.to_integer_or_infinity(context)? {
+        IntegerOrInfinity::Integer(integer) => (integer as f64).into(),
+        IntegerOrInfinity::PositiveInfinity => f64::INFINITY.into(),
+        IntegerOrInfinity::NegativeInfinity => f64::NEG_INFINITY.into(),
+    })
+}
+
+/// Applies a wrapping arithmetic operation to two little endian integers of the same size.
+fn arithmetic(x: &[u8], y: &[u8], op: fn(u64, u64) -> u64) -> Vec<u8> {
+    let to_u64 = |bytes: &[u8]| {
+        let mut buffer = [0; 8];
+        buffer[..bytes.len()].copy_from_slice(bytes);
+        u64::from_le_bytes(buffer)
+    };
+    op(to_u64(x), to_u64(y)).to_le_bytes()[..x.len()].to_vec()
+}
+
+/// Applies a bitwise operation to each pair of bytes of two integers of the same size.
+fn bitwise(x: &[u8], y: &[u8], op: fn(u8, u8) -> u8) -> Vec<u8> {
+    x.iter().zip(y).map(|(&x, &y)| op(x, y)).collect()
+}
@@ -0,0 +1,209 @@
+use crate::{
+    builtins::shared_array_buffer::{SharedArrayBuffer, SharedDataBlock},
+    forward,
+    property::Attribute,
+    Context,
+};
+
+#[test]
+fn read_modify_write() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var ta = new Int32Array(new SharedArrayBuffer(8));
+        Atomics.store(ta, 0, 5);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Atomics.add(ta, 0, 3)"), "5");
+    assert_eq!(forward(&mut context, "Atomics.sub(ta, 0, 1)"), "8");
+    assert_eq!(forward(&mut context, "Atomics.and(ta, 0, 6)"), "7");
+    assert_eq!(forward(&mut context, "Atomics.or(ta, 0, 1)"), "6");
+    assert_eq!(forward(&mut context, "Atomics.xor(ta, 0, 2)"), "7");
+    assert_eq!(forward(&mut context, "Atomics.exchange(ta, 0, 10)"), "5");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 0)"), "10");
+}
+
+#[test]
+fn wrapping_arithmetic() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var u8 = new Uint8Array(new SharedArrayBuffer(1));
+        var i64 = new BigInt64Array(2);
+        Atomics.add(u8, 0, 250);
+        Atomics.add(u8, 0, 10);
+        Atomics.sub(i64, 1, 1n);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "u8[0]"), "4");
+    assert_eq!(forward(&mut context, "Atomics.load(i64, 1)"), "-1n");
+    assert_eq!(forward(&mut context, "i64[0]"), "0n");
+}
+
+#[test]
+fn compare_exchange() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var ta = new Int16Array(new SharedArrayBuffer(4));",
+    );
+
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 1, 1, 2)"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "ta[1]"), "0");
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 1, 0, 2)"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "ta[1]"), "2");
+}
+
+#[test]
+fn store_returns_integer() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var ta = new Int8Array(4);");
+
+    assert_eq!(forward(&mut context, "Atomics.store(ta, 0, 3.7)"), "3");
+    assert_eq!(forward(&mut context, "Atomics.store(ta, 1, 300)"), "300");
+    assert_eq!(forward(&mut context, "ta[1]"), "44");
+}
+
+#[test]
+fn validation() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function error(f) {
+            try { f(); } catch (e) { return e.name; }
+        }
+        var ta = new Int32Array(new SharedArrayBuffer(8));
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.load(new Float64Array(1), 0))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.add(new Uint8ClampedArray(1), 0, 1))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "error(() => Atomics.load([1], 0))"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "error(() => Atomics.load(ta, 2))"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.wait(new Int32Array(4), 0, 0, 0))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => Atomics.wait(new Uint32Array(new SharedArrayBuffer(4)), 0, 0, 0))"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn is_lock_free() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Atomics.isLockFree(4)"), "true");
+    assert_eq!(forward(&mut context, "Atomics.isLockFree(3)"), "false");
+}
+
+#[test]
+fn wait_without_notification() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var ta = new Int32Array(new SharedArrayBuffer(8));",
+    );
+
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 1)"),
+        "\"not-equal\""
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 0, 10)"),
+        "\"timed-out\""
+    );
+    assert_eq!(forward(&mut context, "Atomics.notify(ta, 0)"), "0");
+    assert_eq!(
+        forward(&mut context, "Atomics.notify(new Int32Array(2), 0)"),
+        "0"
+    );
+}
+
+#[test]
+fn wait_when_blocking_is_disallowed() {
+    let mut context = Context::new();
+    context.set_can_block(false);
+
+    let init = r#"
+        var ta = new Int32Array(new SharedArrayBuffer(8));
+        var message;
+        try { Atomics.wait(ta, 0, 0, 0); } catch (e) { message = e.message; }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "message"),
+        "\"Atomics.wait cannot block in this context\""
+    );
+}
+
+#[test]
+fn wait_and_notify_across_threads() {
+    let block = SharedDataBlock::new(8);
+
+    let waiter = {
+        let block = block.clone();
+        std::thread::spawn(move || {
+            let mut context = Context::new();
+            let buffer = SharedArrayBuffer::from_block(block, &mut context);
+            context.register_global_property("buffer", buffer, Attribute::all());
+            forward(
+                &mut context,
+                "var ta = new Int32Array(buffer); Atomics.store(ta, 1, 1); Atomics.wait(ta, 0, 0)",
+            )
+        })
+    };
+
+    let mut context = Context::new();
+    let buffer = SharedArrayBuffer::from_block(block, &mut context);
+    context.register_global_property("buffer", buffer, Attribute::all());
+    let init = r#"
+        var ta = new Int32Array(buffer);
+        function notifyWaiter() {
+            while (Atomics.load(ta, 1) !== 1) {}
+            Atomics.store(ta, 0, 1);
+            while (Atomics.notify(ta, 0) === 0) {}
+        }
+        notifyWaiter();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(waiter.join().unwrap(), "\"ok\"");
+}
@@ -14,7 +14,8 @@
 
 use crate::{
     builtins::BuiltIn, object::ConstructorBuilder, property::Attribute, symbol::WellKnownSymbols,
-    value::IntegerOrInfinity, BoaProfiler, Context, JsBigInt, JsResult, JsValue,
+    value::ArgumentsExt, value::IntegerOrInfinity, BoaProfiler, Context, JsBigInt, JsResult,
+    JsValue,
 };
 #[cfg(test)]
 mod tests;
@@ -27,7 +28,7 @@ impl BuiltIn for BigInt {
     const NAME: &'static str = "BigInt";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -48,11 +49,7 @@ impl BuiltIn for BigInt {
         .static_method(Self::as_uint_n, "asUintN", 2)
         .callable(true)
         .constructable(false)
-        .property(
-            to_string_tag,
-            Self::NAME,
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
+        .property(to_string_tag, Self::NAME, Attribute::readonly_builtin())
         .build();
 
         (Self::NAME, bigint_object.into(), Self::attribute())
@@ -131,7 +128,7 @@ impl BigInt {
         // 1. Let x be ? thisBigIntValue(this value).
         let x = Self::this_bigint_value(this, context)?;
 
-        let radix = args.get(0).cloned().unwrap_or_default();
+        let radix = args.get_or_undefined(0).clone();
 
         // 2. If radix is undefined, let radixMV be 10.
         let radix_mv = if radix.is_undefined() {
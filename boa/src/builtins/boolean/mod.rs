@@ -28,7 +28,7 @@ impl BuiltIn for Boolean {
     const NAME: &'static str = "Boolean";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
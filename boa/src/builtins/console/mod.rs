@@ -11,119 +11,375 @@
 //! [spec]: https://console.spec.whatwg.org/
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Console
 
-#![allow(clippy::print_stdout)]
-
 #[cfg(test)]
 mod tests;
 
 use crate::{
     builtins::BuiltIn,
-    object::ObjectInitializer,
-    property::Attribute,
-    value::{display::display_obj, JsValue},
+    object::{JsObject, ObjectInitializer},
+    property::{Attribute, PropertyNameKind},
+    value::{display::display_obj, ArgumentsExt, JsValue},
     BoaProfiler, Context, JsResult, JsString,
 };
 use rustc_hash::FxHashMap;
-use std::time::SystemTime;
+use std::{
+    cell::{RefCell, RefMut},
+    fmt::Debug,
+    io::{self, Write},
+    rc::Rc,
+    time::SystemTime,
+};
+
+/// The severity of a console message, given by the `console` method that printed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
+}
+
+/// A message printed with the `console` object.
+#[derive(Debug, Clone, Copy)]
+pub struct LogMessage<'a> {
+    level: LogLevel,
+    message: &'a str,
+    args: &'a [JsValue],
+    group_depth: usize,
+}
+
+impl<'a> LogMessage<'a> {
+    /// Returns the severity of the message.
+    #[inline]
+    pub fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Returns the text of the message, with its format specifiers substituted.
+    #[inline]
+    pub fn message(&self) -> &'a str {
+        self.message
+    }
+
+    /// Returns the arguments the message was formatted from, which are empty for the messages
+    /// of the console itself, like the timers and counters.
+    #[inline]
+    pub fn args(&self) -> &'a [JsValue] {
+        self.args
+    }
+
+    /// Returns the amount of groups opened with `console.group` the message belongs to.
+    #[inline]
+    pub fn group_depth(&self) -> usize {
+        self.group_depth
+    }
+}
 
-/// This represents the different types of log messages.
+/// The backend receiving the output of the `console` object.
+///
+/// The default logger, [`StdConsoleLogger`], prints the messages to the standard output, and
+/// the errors to the standard error. Embedders can capture the messages instead with
+/// [`Context::set_console_logger`].
+///
+/// # Examples
+/// ```
+///# use boa::{builtins::console::{ConsoleLogger, LogLevel, LogMessage}, Context};
+///# use std::{cell::RefCell, rc::Rc};
+/// #[derive(Debug, Default)]
+/// struct Capture(Rc<RefCell<Vec<String>>>);
+///
+/// impl ConsoleLogger for Capture {
+///     fn log(&self, message: &LogMessage<'_>, _context: &mut Context) {
+///         if message.level() >= LogLevel::Warn {
+///             self.0.borrow_mut().push(message.message().to_string());
+///         }
+///     }
+/// }
+///
+/// let warnings = Rc::new(RefCell::new(Vec::new()));
+/// let mut context = Context::new();
+/// context.set_console_logger(Capture(warnings.clone()));
+///
+/// context.eval("console.log('ignored'); console.warn('low on %s', 'memory');").unwrap();
+/// assert_eq!(*warnings.borrow(), ["low on memory"]);
+/// ```
+pub trait ConsoleLogger: Debug {
+    /// Receives a message printed with the `console` object.
+    fn log(&self, message: &LogMessage<'_>, context: &mut Context);
+
+    /// Clears the output, when `console.clear` is called.
+    ///
+    /// The default implementation does nothing.
+    fn clear(&self, _context: &mut Context) {}
+}
+
+/// The default [`ConsoleLogger`], printing the messages to the standard output, and the errors
+/// to the standard error, indented by the groups they belong to.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdConsoleLogger;
+
+impl ConsoleLogger for StdConsoleLogger {
+    fn log(&self, message: &LogMessage<'_>, _context: &mut Context) {
+        // A closed standard stream is ignored, as the script has no way to handle it.
+        let _ = match message.level() {
+            LogLevel::Error => write_message(&mut io::stderr().lock(), message),
+            LogLevel::Debug | LogLevel::Log | LogLevel::Info | LogLevel::Warn => {
+                write_message(&mut io::stdout().lock(), message)
+            }
+        };
+    }
+}
+
+/// A [`ConsoleLogger`] writing the messages to a stream, like a file or an in-memory buffer,
+/// indented by the groups they belong to.
+///
+/// Every message is written to the same stream, the errors included, so their order is kept.
+/// The logger can be cloned before being given to the context, the clones sharing the stream.
+///
+/// # Examples
+/// ```
+///# use boa::{builtins::console::WriteConsoleLogger, Context};
+/// let logger = WriteConsoleLogger::new(Vec::new());
+/// let mut context = Context::new();
+/// context.set_console_logger(logger.clone());
+///
+/// context.eval("console.log('a'); console.group('g'); console.error('b');").unwrap();
+/// assert_eq!(*logger.stream(), b"a\ngroup: g\n  b\n");
+/// ```
 #[derive(Debug)]
-pub enum LogMessage {
-    Log(String),
-    Info(String),
-    Warn(String),
-    Error(String),
+pub struct WriteConsoleLogger<W> {
+    stream: Rc<RefCell<W>>,
 }
 
-/// Helper function for logging messages.
-pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
-    let indent = 2 * console_state.groups.len();
+impl<W> WriteConsoleLogger<W>
+where
+    W: Write + Debug,
+{
+    /// Creates a logger writing the messages to the stream.
+    #[inline]
+    pub fn new(stream: W) -> Self {
+        Self {
+            stream: Rc::new(RefCell::new(stream)),
+        }
+    }
+
+    /// Gets the stream the messages are written to, to read a buffer or to flush it for example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stream is already borrowed.
+    #[inline]
+    pub fn stream(&self) -> RefMut<'_, W> {
+        self.stream.borrow_mut()
+    }
+}
 
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{:>width$}", msg, width = indent);
+impl<W> Clone for WriteConsoleLogger<W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            stream: self.stream.clone(),
         }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{:>width$}", msg, width = indent);
+    }
+}
+
+impl<W> ConsoleLogger for WriteConsoleLogger<W>
+where
+    W: Write + Debug,
+{
+    fn log(&self, message: &LogMessage<'_>, _context: &mut Context) {
+        // The console has no way to report the errors of the stream to the script.
+        let _ = write_message(&mut *self.stream.borrow_mut(), message);
+    }
+}
+
+/// Writes a message to the stream, with every line of multiline messages, like tables, indented
+/// by the groups the message belongs to.
+fn write_message<W>(stream: &mut W, message: &LogMessage<'_>) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    let indent = 2 * message.group_depth();
+    for line in message.message().lines() {
+        writeln!(stream, "{:indent$}{}", "", line, indent = indent)?;
+    }
+    Ok(())
+}
+
+/// Helper function for logging messages, through the console logger of the context.
+pub(crate) fn logger(level: LogLevel, message: &str, args: &[JsValue], context: &mut Context) {
+    let message = LogMessage {
+        level,
+        message,
+        args,
+        group_depth: context.console().groups.len(),
+    };
+    context.console_logger().log(&message, context);
+}
+
+/// Renders a value that is not consumed by a format specifier.
+///
+/// Strings are printed verbatim, every other value is printed the way it would be displayed in a REPL.
+fn format_value(value: &JsValue) -> String {
+    match value {
+        JsValue::String(string) => string.to_string(),
+        value => value.display().to_string(),
+    }
+}
+
+/// Renders a cell of the tables printed by `console.table`, where nested objects are abbreviated.
+fn format_cell(value: &JsValue) -> String {
+    match value {
+        JsValue::Object(object) if object.is_callable() => "[Function]".to_string(),
+        JsValue::Object(object) if object.is_array() => "[Array]".to_string(),
+        JsValue::Object(_) => "[Object]".to_string(),
+        value => value.display().to_string(),
+    }
+}
+
+/// Draws the rows of a table, the first of which is its header, with the contents of the cells
+/// centered in their column.
+fn format_table(rows: &[Vec<String>]) -> String {
+    let mut widths = vec![0; rows.first().map_or(0, Vec::len)];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count() + 2);
         }
     }
+
+    let border = |left: &str, middle: &str, right: &str| {
+        let lines: Vec<_> = widths.iter().map(|width| "─".repeat(*width)).collect();
+        format!("{}{}{}", left, lines.join(middle), right)
+    };
+    let line = |row: &[String]| {
+        let cells: Vec<_> = widths
+            .iter()
+            .zip(row)
+            .map(|(width, cell)| {
+                let padding = width - cell.chars().count();
+                format!(
+                    "{:left$}{}{:right$}",
+                    "",
+                    cell,
+                    "",
+                    left = padding / 2,
+                    right = padding - padding / 2
+                )
+            })
+            .collect();
+        format!("│{}│", cells.join("│"))
+    };
+
+    let mut table = vec![border("┌", "┬", "┐")];
+    if let Some((header, rows)) = rows.split_first() {
+        table.push(line(header));
+        table.push(border("├", "┼", "┤"));
+        table.extend(rows.iter().map(|row| line(row)));
+    }
+    table.push(border("└", "┴", "┘"));
+    table.join("\n")
 }
 
 /// This represents the `console` formatter.
+///
+/// More information:
+///  - [WHATWG `console` specification][spec]
+///
+/// [spec]: https://console.spec.whatwg.org/#formatter
 pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
-    let target = data
-        .get(0)
-        .cloned()
-        .unwrap_or_default()
-        .to_string(context)?;
-
-    match data.len() {
-        0 => Ok(String::new()),
-        1 => Ok(target.to_string()),
-        _ => {
-            let mut formatted = String::new();
-            let mut arg_index = 1;
-            let mut chars = target.chars();
-            while let Some(c) = chars.next() {
-                if c == '%' {
-                    let fmt = chars.next().unwrap_or('%');
-                    match fmt {
-                        /* integer */
-                        'd' | 'i' => {
-                            let arg = data
-                                .get(arg_index)
-                                .cloned()
-                                .unwrap_or_default()
-                                .to_integer(context)?;
-                            formatted.push_str(&format!("{}", arg));
-                            arg_index += 1;
-                        }
-                        /* float */
-                        'f' => {
-                            let arg = data
-                                .get(arg_index)
-                                .cloned()
-                                .unwrap_or_default()
-                                .to_number(context)?;
-                            formatted.push_str(&format!("{number:.prec$}", number = arg, prec = 6));
-                            arg_index += 1
-                        }
-                        /* object, FIXME: how to render this properly? */
-                        'o' | 'O' => {
-                            let arg = data.get(arg_index).cloned().unwrap_or_default();
-                            formatted.push_str(&format!("{}", arg.display()));
-                            arg_index += 1
-                        }
-                        /* string */
-                        's' => {
-                            let arg = data
-                                .get(arg_index)
-                                .cloned()
-                                .unwrap_or_default()
-                                .to_string(context)?;
-                            formatted.push_str(&arg);
-                            arg_index += 1
-                        }
-                        '%' => formatted.push('%'),
-                        /* TODO: %c is not implemented */
-                        c => {
-                            formatted.push('%');
-                            formatted.push(c);
-                        }
+    let target = match data.get(0) {
+        None => return Ok(String::new()),
+        Some(JsValue::String(target)) => target.clone(),
+        /* only a leading string is treated as a format string */
+        Some(_) => return Ok(data.iter().map(format_value).collect::<Vec<_>>().join(" ")),
+    };
+
+    if data.len() == 1 {
+        return Ok(target.to_string());
+    }
+
+    let mut formatted = String::new();
+    let mut arg_index = 1;
+    let mut chars = target.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            let fmt = chars.next().unwrap_or('%');
+            match fmt {
+                /* integer */
+                'd' | 'i' => {
+                    let arg = data.get(arg_index).cloned().unwrap_or_default();
+                    let number = if arg.is_symbol() {
+                        f64::NAN
+                    } else {
+                        arg.to_number(context)?
+                    };
+                    if number.is_finite() {
+                        formatted.push_str(&format!("{}", number.trunc() as i64));
+                    } else {
+                        formatted.push_str(&JsValue::new(number).display().to_string());
                     }
-                } else {
+                    arg_index += 1;
+                }
+                /* float */
+                'f' => {
+                    let arg = data
+                        .get(arg_index)
+                        .cloned()
+                        .unwrap_or_default()
+                        .to_number(context)?;
+                    formatted.push_str(&format!("{number:.prec$}", number = arg, prec = 6));
+                    arg_index += 1
+                }
+                /* object */
+                'o' | 'O' => {
+                    let arg = data.get(arg_index).cloned().unwrap_or_default();
+                    formatted.push_str(&format!("{}", arg.display()));
+                    arg_index += 1
+                }
+                /* string */
+                's' => {
+                    let arg = data.get(arg_index).cloned().unwrap_or_default();
+                    if arg.is_symbol() {
+                        formatted.push_str(&arg.display().to_string());
+                    } else {
+                        formatted.push_str(&arg.to_string(context)?);
+                    }
+                    arg_index += 1
+                }
+                /* CSS styling has no meaning in a terminal, so the argument is consumed and ignored */
+                'c' => arg_index += 1,
+                '%' => formatted.push('%'),
+                c => {
+                    formatted.push('%');
                     formatted.push(c);
-                };
+                }
             }
+        } else {
+            formatted.push(c);
+        };
+    }
 
-            /* unformatted data */
-            for rest in data.iter().skip(arg_index) {
-                formatted.push_str(&format!(" {}", rest.to_string(context)?))
-            }
+    /* unformatted data */
+    for rest in data.iter().skip(arg_index) {
+        formatted.push(' ');
+        formatted.push_str(&format_value(rest));
+    }
 
-            Ok(formatted)
-        }
+    Ok(formatted)
+}
+
+/// Builds the message printed by `console.trace`, a label followed by the current call stack.
+///
+/// The innermost frame is the `console.trace` call itself, so it is left out of the trace.
+pub(crate) fn trace_message(data: &[JsValue], context: &mut Context) -> JsResult<String> {
+    let mut message = "Trace".to_string();
+    if !data.is_empty() {
+        message.push_str(": ");
+        message.push_str(&formatter(data, context)?);
     }
+    message.push_str(&context.executor().format_call_stack(1));
+    Ok(message)
 }
 
 /// This is the internal console object state.
@@ -138,7 +394,7 @@ impl BuiltIn for Console {
     const NAME: &'static str = "console";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -163,6 +419,7 @@ impl BuiltIn for Console {
             .function(Self::time_end, "timeEnd", 0)
             .function(Self::dir, "dir", 0)
             .function(Self::dir, "dirxml", 0)
+            .function(Self::table, "table", 0)
             .build();
 
         (Self::NAME, console.into(), Self::attribute())
@@ -199,14 +456,12 @@ impl Console {
             } else if !args[0].is_string() {
                 args.insert(0, JsValue::new(message));
             } else {
-                let concat = format!("{}: {}", message, args[0].display());
+                let concat = format!("{}: {}", message, format_value(&args[0]));
                 args[0] = JsValue::new(concat);
             }
 
-            logger(
-                LogMessage::Error(formatter(&args, context)?),
-                context.console(),
-            );
+            let message = formatter(&args, context)?;
+            logger(LogLevel::Error, &message, &args, context);
         }
 
         Ok(JsValue::undefined())
@@ -224,6 +479,7 @@ impl Console {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/clear
     pub(crate) fn clear(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         context.console_mut().groups.clear();
+        context.console_logger().clear(context);
         Ok(JsValue::undefined())
     }
 
@@ -238,10 +494,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#debug
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/debug
     pub(crate) fn debug(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
-        );
+        let message = formatter(args, context)?;
+        logger(LogLevel::Debug, &message, args, context);
         Ok(JsValue::undefined())
     }
 
@@ -256,10 +510,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#error
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/error
     pub(crate) fn error(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Error(formatter(args, context)?),
-            context.console(),
-        );
+        let message = formatter(args, context)?;
+        logger(LogLevel::Error, &message, args, context);
         Ok(JsValue::undefined())
     }
 
@@ -274,10 +526,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#info
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/info
     pub(crate) fn info(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Info(formatter(args, context)?),
-            context.console(),
-        );
+        let message = formatter(args, context)?;
+        logger(LogLevel::Info, &message, args, context);
         Ok(JsValue::undefined())
     }
 
@@ -292,10 +542,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#log
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/log
     pub(crate) fn log(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
-        );
+        let message = formatter(args, context)?;
+        logger(LogLevel::Log, &message, args, context);
         Ok(JsValue::undefined())
     }
 
@@ -310,18 +558,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#trace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/trace
     pub(crate) fn trace(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        if !args.is_empty() {
-            logger(
-                LogMessage::Log(formatter(args, context)?),
-                context.console(),
-            );
-
-            /* TODO: get and print stack trace */
-            logger(
-                LogMessage::Log("Not implemented: <stack trace>".to_string()),
-                context.console(),
-            )
-        }
+        let message = trace_message(args, context)?;
+        logger(LogLevel::Log, &message, args, context);
 
         Ok(JsValue::undefined())
     }
@@ -337,10 +575,8 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#warn
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/warn
     pub(crate) fn warn(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Warn(formatter(args, context)?),
-            context.console(),
-        );
+        let message = formatter(args, context)?;
+        logger(LogLevel::Warn, &message, args, context);
         Ok(JsValue::undefined())
     }
 
@@ -364,10 +600,8 @@ impl Console {
         let c = context.console_mut().count_map.entry(label).or_insert(0);
         *c += 1;
 
-        logger(
-            LogMessage::Info(format!("{} {}", msg, c)),
-            context.console(),
-        );
+        let message = format!("{} {}", msg, c);
+        logger(LogLevel::Info, &message, &[], context);
         Ok(JsValue::undefined())
     }
 
@@ -394,8 +628,10 @@ impl Console {
         context.console_mut().count_map.remove(&label);
 
         logger(
-            LogMessage::Warn(format!("countReset {}", label)),
-            context.console(),
+            LogLevel::Warn,
+            &format!("countReset {}", label),
+            &[],
+            context,
         );
 
         Ok(JsValue::undefined())
@@ -427,8 +663,10 @@ impl Console {
 
         if context.console().timer_map.get(&label).is_some() {
             logger(
-                LogMessage::Warn(format!("Timer '{}' already exist", label)),
-                context.console(),
+                LogLevel::Warn,
+                &format!("Timer '{}' already exist", label),
+                &[],
+                context,
             );
         } else {
             let time = Self::system_time_in_ms();
@@ -458,17 +696,24 @@ impl Console {
             None => "default".into(),
         };
 
-        if let Some(t) = context.console().timer_map.get(&label) {
+        if let Some(t) = context.console().timer_map.get(&label).copied() {
             let time = Self::system_time_in_ms();
             let mut concat = format!("{}: {} ms", label, time - t);
             for msg in args.iter().skip(1) {
                 concat = concat + " " + &msg.display().to_string();
             }
-            logger(LogMessage::Log(concat), context.console());
+            logger(
+                LogLevel::Log,
+                &concat,
+                args.get(1..).unwrap_or(&[]),
+                context,
+            );
         } else {
             logger(
-                LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
-                context.console(),
+                LogLevel::Warn,
+                &format!("Timer '{}' doesn't exist", label),
+                &[],
+                context,
             );
         }
 
@@ -498,13 +743,17 @@ impl Console {
         if let Some(t) = context.console_mut().timer_map.remove(label.as_str()) {
             let time = Self::system_time_in_ms();
             logger(
-                LogMessage::Info(format!("{}: {} ms - timer removed", label, time - t)),
-                context.console(),
+                LogLevel::Info,
+                &format!("{}: {} ms - timer removed", label, time - t),
+                &[],
+                context,
             );
         } else {
             logger(
-                LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
-                context.console(),
+                LogLevel::Warn,
+                &format!("Timer '{}' doesn't exist", label),
+                &[],
+                context,
             );
         }
 
@@ -525,8 +774,10 @@ impl Console {
         let group_label = formatter(args, context)?;
 
         logger(
-            LogMessage::Info(format!("group: {}", &group_label)),
-            context.console(),
+            LogLevel::Info,
+            &format!("group: {}", &group_label),
+            args,
+            context,
         );
         context.console_mut().groups.push(group_label);
 
@@ -565,11 +816,113 @@ impl Console {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/dir
     pub(crate) fn dir(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let undefined = JsValue::undefined();
+        let item = args.get(0).unwrap_or(&undefined);
         logger(
-            LogMessage::Info(display_obj(args.get(0).unwrap_or(&undefined), true)),
-            context.console(),
+            LogLevel::Info,
+            &display_obj(item, true),
+            std::slice::from_ref(item),
+            context,
         );
 
         Ok(JsValue::undefined())
     }
+
+    /// `console.table(tabularData, properties)`
+    ///
+    /// Prints the properties of `tabularData` as the rows of a table, with a column for each
+    /// property of the rows, or for the given `properties` only, and a `Values` column for the
+    /// rows that are not objects. Data that isn't an object is logged as `console.log` would.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [WHATWG `console` specification][spec]
+    ///
+    /// [spec]: https://console.spec.whatwg.org/#table
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/table
+    pub(crate) fn table(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let data = match args.get_or_undefined(0) {
+            JsValue::Object(data) => data.clone(),
+            _ => return Self::log(this, args, context),
+        };
+        let properties = match args.get(1) {
+            Some(JsValue::Object(properties)) => Some(
+                properties
+                    .enumerable_own_property_names(PropertyNameKind::Value, context)?
+                    .iter()
+                    .map(|property| property.to_string(context))
+                    .collect::<JsResult<Vec<_>>>()?,
+            ),
+            _ => None,
+        };
+
+        let mut columns = properties.clone().unwrap_or_default();
+        let mut has_values = false;
+        let mut rows = Vec::new();
+        for index in data.enumerable_own_property_names(PropertyNameKind::Key, context)? {
+            let index = index.to_string(context)?;
+            let mut cells = FxHashMap::default();
+            let mut value = None;
+            match &data.get(index.clone(), context)? {
+                JsValue::Object(row) => {
+                    for column in Self::table_columns(row, properties.as_deref(), context)? {
+                        if !columns.contains(&column) {
+                            columns.push(column.clone());
+                        }
+                        let cell = row.get(column.clone(), context)?;
+                        cells.insert(column, format_cell(&cell));
+                    }
+                }
+                row if properties.is_none() => {
+                    has_values = true;
+                    value = Some(format_cell(row));
+                }
+                _ => {}
+            }
+            rows.push((index, cells, value));
+        }
+
+        let mut header = vec!["(index)".to_string()];
+        header.extend(columns.iter().map(JsString::to_string));
+        if has_values {
+            header.push("Values".to_string());
+        }
+        let mut table = vec![header];
+        for (index, mut cells, value) in rows {
+            let mut row = vec![index.to_string()];
+            row.extend(
+                columns
+                    .iter()
+                    .map(|column| cells.remove(column).unwrap_or_default()),
+            );
+            if has_values {
+                row.push(value.unwrap_or_default());
+            }
+            table.push(row);
+        }
+
+        logger(LogLevel::Log, &format_table(&table), args, context);
+        Ok(JsValue::undefined())
+    }
+
+    /// Gets the columns of the table printed by `console.table` filled by `row`, which are its
+    /// properties, or the given `properties` it has.
+    fn table_columns(
+        row: &JsObject,
+        properties: Option<&[JsString]>,
+        context: &mut Context,
+    ) -> JsResult<Vec<JsString>> {
+        let keys = row.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        let mut columns = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key.to_string(context)?;
+            if properties.map_or(true, |properties| properties.contains(&key)) {
+                columns.push(key);
+            }
+        }
+        Ok(columns)
+    }
 }
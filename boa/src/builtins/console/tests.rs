@@ -1,4 +1,11 @@
-use crate::{builtins::console::formatter, Context, JsValue};
+use crate::{
+    builtins::console::{
+        formatter, trace_message, ConsoleLogger, LogLevel, LogMessage, WriteConsoleLogger,
+    },
+    exec::StackFrame,
+    forward, forward_val, Context, JsValue,
+};
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -66,3 +73,234 @@ fn formatter_float_format_works() {
     let res = formatter(&val, &mut context).unwrap();
     assert_eq!(res, "3.141500");
 }
+
+#[test]
+fn formatter_css_format_is_ignored() {
+    let mut context = Context::new();
+
+    let val = [
+        JsValue::new("%cstyled%c text"),
+        JsValue::new("color: red"),
+        JsValue::new("font-weight: bold"),
+        JsValue::new("rest"),
+    ];
+    let res = formatter(&val, &mut context).unwrap();
+    assert_eq!(res, "styled text rest");
+}
+
+#[test]
+fn formatter_integer_format_truncates() {
+    let mut context = Context::new();
+
+    let val = [
+        JsValue::new("%d %i %d %d"),
+        JsValue::new(42.9),
+        JsValue::new("-7.5"),
+        JsValue::new("abc"),
+        JsValue::undefined(),
+    ];
+    let res = formatter(&val, &mut context).unwrap();
+    assert_eq!(res, "42 -7 NaN NaN");
+}
+
+#[test]
+fn formatter_string_and_object_formats() {
+    let mut context = Context::new();
+
+    let object = forward_val(&mut context, "[1, \"two\"]").unwrap();
+    let val = [
+        JsValue::new("%s|%o"),
+        JsValue::new(true),
+        JsValue::new("quoted"),
+    ];
+    let res = formatter(&val, &mut context).unwrap();
+    assert_eq!(res, "true|\"quoted\"");
+
+    let val = [JsValue::new("%s"), object.clone()];
+    let res = formatter(&val, &mut context).unwrap();
+    assert_eq!(res, "1,two");
+
+    let val = [JsValue::new("value:"), object];
+    let res = formatter(&val, &mut context).unwrap();
+    assert_eq!(res, "value: [ 1, \"two\" ]");
+}
+
+#[test]
+fn formatter_non_string_first_argument_is_not_a_format() {
+    let mut context = Context::new();
+
+    let val = [JsValue::new(1), JsValue::new("%d"), JsValue::new(2)];
+    let res = formatter(&val, &mut context).unwrap();
+    assert_eq!(res, "1 %d 2");
+}
+
+#[test]
+fn trace_message_prints_call_stack() {
+    let mut context = Context::new();
+
+    context.executor().push_frame(StackFrame::new("outer"));
+    context.executor().push_frame(StackFrame::new(""));
+    context.executor().push_frame(StackFrame::new("trace"));
+
+    let res = trace_message(&[], &mut context).unwrap();
+    assert_eq!(res, "Trace\n    at <anonymous>\n    at outer");
+
+    let val = [JsValue::new("label %d"), JsValue::new(1)];
+    let res = trace_message(&val, &mut context).unwrap();
+    assert_eq!(res, "Trace: label 1\n    at <anonymous>\n    at outer");
+}
+
+/// The level, message, argument count and group depth of a logged message.
+type Record = (LogLevel, String, usize, usize);
+
+/// A console logger recording the messages it receives.
+#[derive(Debug, Default, Clone)]
+struct RecordingLogger {
+    messages: Rc<RefCell<Vec<Record>>>,
+    clears: Rc<RefCell<usize>>,
+}
+
+impl ConsoleLogger for RecordingLogger {
+    fn log(&self, message: &LogMessage<'_>, _: &mut Context) {
+        self.messages.borrow_mut().push((
+            message.level(),
+            message.message().to_string(),
+            message.args().len(),
+            message.group_depth(),
+        ));
+    }
+
+    fn clear(&self, _: &mut Context) {
+        *self.clears.borrow_mut() += 1;
+    }
+}
+
+#[test]
+fn custom_logger_receives_messages() {
+    let mut context = Context::new();
+    let logger = RecordingLogger::default();
+    context.set_console_logger(logger.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.log('a %d', 1, 'b');
+        console.debug('debug');
+        console.group('outer');
+        console.info('info', 2);
+        console.warn('careful');
+        console.groupEnd();
+        console.error('failed');
+        console.assert(false, 'checked');
+        console.count();
+        console.clear();
+        "#,
+    );
+
+    let messages = logger.messages.borrow();
+    assert_eq!(
+        *messages,
+        vec![
+            (LogLevel::Log, "a 1 b".to_string(), 3, 0),
+            (LogLevel::Debug, "debug".to_string(), 1, 0),
+            (LogLevel::Info, "group: outer".to_string(), 1, 0),
+            (LogLevel::Info, "info 2".to_string(), 2, 1),
+            (LogLevel::Warn, "careful".to_string(), 1, 1),
+            (LogLevel::Error, "failed".to_string(), 1, 0),
+            (
+                LogLevel::Error,
+                "Assertion failed: checked".to_string(),
+                1,
+                0
+            ),
+            (LogLevel::Info, "count default: 1".to_string(), 0, 0),
+        ]
+    );
+    assert_eq!(*logger.clears.borrow(), 1);
+}
+
+#[test]
+fn custom_logger_is_kept_on_reset() {
+    let mut context = Context::new();
+    let logger = RecordingLogger::default();
+    context.set_console_logger(logger.clone());
+    context.capture_reset_point();
+
+    context.reset().unwrap();
+    forward(&mut context, "console.log('after reset')");
+    assert_eq!(logger.messages.borrow().len(), 1);
+}
+
+#[test]
+fn write_logger_writes_messages_in_order() {
+    let mut context = Context::new();
+    let logger = WriteConsoleLogger::new(Vec::new());
+    context.set_console_logger(logger.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.log('first');
+        console.group('outer');
+        console.error('multi\nline');
+        console.groupEnd();
+        console.warn('last');
+        "#,
+    );
+
+    assert_eq!(
+        String::from_utf8(logger.stream().clone()).unwrap(),
+        "first\ngroup: outer\n  multi\n  line\nlast\n"
+    );
+}
+
+#[test]
+fn table() {
+    let mut context = Context::new();
+    let logger = RecordingLogger::default();
+    context.set_console_logger(logger.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.table([{ a: 1, b: 'Y' }, { a: 'Z', b: 2 }]);
+        console.table({ x: 1, y: { z: [], w: 0 } }, ['z']);
+        console.table(['p', { q: true }]);
+        console.table(5);
+        "#,
+    );
+
+    let messages: Vec<_> = logger
+        .messages
+        .borrow()
+        .iter()
+        .map(|(level, message, _, _)| {
+            assert_eq!(*level, LogLevel::Log);
+            message.clone()
+        })
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "┌─────────┬─────┬─────┐\n\
+             │ (index) │  a  │  b  │\n\
+             ├─────────┼─────┼─────┤\n\
+             │    0    │  1  │ \"Y\" │\n\
+             │    1    │ \"Z\" │  2  │\n\
+             └─────────┴─────┴─────┘",
+            "┌─────────┬─────────┐\n\
+             │ (index) │    z    │\n\
+             ├─────────┼─────────┤\n\
+             │    x    │         │\n\
+             │    y    │ [Array] │\n\
+             └─────────┴─────────┘",
+            "┌─────────┬──────┬────────┐\n\
+             │ (index) │  q   │ Values │\n\
+             ├─────────┼──────┼────────┤\n\
+             │    0    │      │  \"p\"   │\n\
+             │    1    │ true │        │\n\
+             └─────────┴──────┴────────┘",
+            "5",
+        ]
+    );
+}
@@ -0,0 +1,577 @@
+//! This module implements the global `DataView` object.
+//!
+//! A `DataView` provides a low-level interface for reading and writing numbers of multiple types
+//! in an `ArrayBuffer`, independently of the endianness of the platform.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{array_buffer::ArrayBuffer, typed_array::TypedArrayName, BuiltIn},
+    context::StandardObjects,
+    error::JsErrorCode,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::{ArgumentsExt, JsValue},
+    BoaProfiler, Context, JsResult,
+};
+
+/// The data of a `DataView` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DataView {
+    /// `[[ViewedArrayBuffer]]`
+    viewed_array_buffer: JsObject,
+    /// `[[ByteLength]]`, which is `None` for a view that tracks the length of its resizable
+    /// buffer.
+    byte_length: Option<usize>,
+    /// `[[ByteOffset]]`
+    byte_offset: usize,
+}
+
+impl BuiltIn for DataView {
+    const NAME: &'static str = "DataView";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_buffer = FunctionBuilder::native(context, Self::get_buffer)
+            .name("get buffer")
+            .constructable(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::get_byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+
+        let get_byte_offset = FunctionBuilder::native(context, Self::get_byte_offset)
+            .name("get byteOffset")
+            .constructable(false)
+            .build();
+
+        let data_view_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().data_view_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "buffer",
+            Some(get_buffer),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "byteOffset",
+            Some(get_byte_offset),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::get_big_int64, "getBigInt64", 1)
+        .method(Self::get_big_uint64, "getBigUint64", 1)
+        .method(Self::get_float32, "getFloat32", 1)
+        .method(Self::get_float64, "getFloat64", 1)
+        .method(Self::get_int8, "getInt8", 1)
+        .method(Self::get_int16, "getInt16", 1)
+        .method(Self::get_int32, "getInt32", 1)
+        .method(Self::get_uint8, "getUint8", 1)
+        .method(Self::get_uint16, "getUint16", 1)
+        .method(Self::get_uint32, "getUint32", 1)
+        .method(Self::set_big_int64, "setBigInt64", 2)
+        .method(Self::set_big_uint64, "setBigUint64", 2)
+        .method(Self::set_float32, "setFloat32", 2)
+        .method(Self::set_float64, "setFloat64", 2)
+        .method(Self::set_int8, "setInt8", 2)
+        .method(Self::set_int16, "setInt16", 2)
+        .method(Self::set_int32, "setInt32", 2)
+        .method(Self::set_uint8, "setUint8", 2)
+        .method(Self::set_uint16, "setUint16", 2)
+        .method(Self::set_uint32, "setUint32", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, data_view_object.into(), Self::attribute())
+    }
+}
+
+macro_rules! view_accessors {
+    ($(($getter:ident, $setter:ident, $variant:ident, $name:literal)),* $(,)?) => {
+        $(
+            #[doc = concat!("`DataView.prototype.get", $name, " ( byteOffset [ , littleEndian ] )`")]
+            ///
+            /// More information:
+            ///  - [ECMAScript reference][spec]
+            ///  - [MDN documentation][mdn]
+            ///
+            /// [spec]: https://tc39.es/ecma262/#sec-properties-of-the-dataview-prototype-object
+            /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+            fn $getter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+                // 1. Let v be the this value.
+                // 2. If littleEndian is not present, set littleEndian to undefined.
+                // 3. Return ? GetViewValue(v, byteOffset, littleEndian, Type).
+                Self::get_view_value(
+                    this,
+                    args.get_or_undefined(0),
+                    args.get_or_undefined(1),
+                    TypedArrayName::$variant,
+                    context,
+                )
+            }
+
+            #[doc = concat!("`DataView.prototype.set", $name, " ( byteOffset, value [ , littleEndian ] )`")]
+            ///
+            /// More information:
+            ///  - [ECMAScript reference][spec]
+            ///  - [MDN documentation][mdn]
+            ///
+            /// [spec]: https://tc39.es/ecma262/#sec-properties-of-the-dataview-prototype-object
+            /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+            fn $setter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+                // 1. Let v be the this value.
+                // 2. If littleEndian is not present, set littleEndian to undefined.
+                // 3. Return ? SetViewValue(v, byteOffset, littleEndian, Type, value).
+                Self::set_view_value(
+                    this,
+                    args.get_or_undefined(0),
+                    args.get_or_undefined(2),
+                    TypedArrayName::$variant,
+                    args.get_or_undefined(1),
+                    context,
+                )
+            }
+        )*
+    };
+}
+
+impl DataView {
+    /// The amount of arguments the `DataView` constructor takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Gets the `ArrayBuffer` object viewed by the `DataView`.
+    #[inline]
+    pub fn viewed_array_buffer(&self) -> &JsObject {
+        &self.viewed_array_buffer
+    }
+
+    /// Abstract operation `GetViewByteLength ( viewRecord )`
+    ///
+    /// Gets the length of the view in bytes, which is `0` if it is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewbytelength
+    pub fn byte_length(&self) -> usize {
+        let buffer_byte_length = match Self::attached_byte_length(&self.viewed_array_buffer) {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => return 0,
+        };
+
+        match self.byte_length {
+            // 2. Let view be viewRecord.[[Object]].
+            // 3. If view.[[ByteLength]] is not auto, return view.[[ByteLength]].
+            Some(byte_length) if self.byte_offset + byte_length <= buffer_byte_length => {
+                byte_length
+            }
+            Some(_) => 0,
+            // 4. Assert: IsFixedLengthArrayBuffer(view.[[ViewedArrayBuffer]]) is false.
+            // 5. Let byteOffset be view.[[ByteOffset]].
+            // 6. Let byteLength be viewRecord.[[CachedBufferByteLength]].
+            // 7. Return byteLength - byteOffset.
+            None => buffer_byte_length.saturating_sub(self.byte_offset),
+        }
+    }
+
+    /// Checks if the view tracks the length of its buffer.
+    #[inline]
+    pub fn is_length_tracking(&self) -> bool {
+        self.byte_length.is_none()
+    }
+
+    /// Abstract operation `IsViewOutOfBounds ( viewRecord )`
+    ///
+    /// Checks if the view covers bytes past the end of its buffer, which happens once a resizable
+    /// buffer shrinks. A view of a detached buffer is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isviewoutofbounds
+    pub fn is_out_of_bounds(&self) -> bool {
+        // 3. If IsDetachedBuffer(view.[[ViewedArrayBuffer]]) is true, return true.
+        let buffer_byte_length = match Self::attached_byte_length(&self.viewed_array_buffer) {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => return true,
+        };
+
+        // 4. Let byteOffsetStart be view.[[ByteOffset]].
+        // 5. If view.[[ByteLength]] is auto, then
+        //     a. Let byteOffsetEnd be bufferByteLength.
+        // 6. Else,
+        //     a. Let byteOffsetEnd be byteOffsetStart + view.[[ByteLength]].
+        // 7. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 8. Return false.
+        let byte_offset_end = self.byte_length.map_or(buffer_byte_length, |byte_length| {
+            self.byte_offset + byte_length
+        });
+        self.byte_offset > buffer_byte_length || byte_offset_end > buffer_byte_length
+    }
+
+    /// Gets the offset of the view in its buffer, in bytes.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// `DataView ( buffer [ , byteOffset [ , byteLength ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview-buffer-byteoffset-bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin DataView constructor without new is forbidden",
+            );
+        }
+
+        // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
+        let buffer = match args.get_or_undefined(0).as_object() {
+            Some(buffer) if buffer.borrow().is_array_buffer() => buffer,
+            _ => return context.throw_type_error("DataView: buffer is not an ArrayBuffer"),
+        };
+
+        // 3. Let offset be ? ToIndex(byteOffset).
+        let offset = args.get_or_undefined(1).to_index(context)?;
+
+        // 4. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 5. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
+        let buffer_byte_length = match Self::attached_byte_length(&buffer) {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => {
+                return context.throw_coded_error(
+                    JsErrorCode::DETACHED_DATA_VIEW,
+                    "DataView: buffer is detached",
+                )
+            }
+        };
+
+        // 6. If offset > bufferByteLength, throw a RangeError exception.
+        if offset > buffer_byte_length {
+            return context.throw_range_error(format!(
+                "DataView: start offset {} is outside the bounds of the buffer",
+                offset
+            ));
+        }
+
+        // 7. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+        let buffer_is_fixed_length = buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, ArrayBuffer::is_fixed_length);
+
+        // 8. If byteLength is undefined, then
+        //     a. If bufferIsFixedLength is true, then
+        //         i. Let viewByteLength be bufferByteLength - offset.
+        //     b. Else,
+        //         i. Let viewByteLength be auto.
+        // 9. Else,
+        //     a. Let viewByteLength be ? ToIndex(byteLength).
+        //     b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+        let view_byte_length = match args.get_or_undefined(2) {
+            JsValue::Undefined if buffer_is_fixed_length => Some(buffer_byte_length - offset),
+            JsValue::Undefined => None,
+            byte_length => {
+                let view_byte_length = byte_length.to_index(context)?;
+                if offset.saturating_add(view_byte_length) > buffer_byte_length {
+                    return context.throw_range_error(format!(
+                        "DataView: invalid length {}",
+                        view_byte_length
+                    ));
+                }
+                Some(view_byte_length)
+            }
+        };
+
+        // 10. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::data_view_object, context)?;
+
+        // 11. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 12. Set bufferByteLength to ArrayBufferByteLength(buffer, seq-cst).
+        let buffer_byte_length = match Self::attached_byte_length(&buffer) {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => {
+                return context.throw_coded_error(
+                    JsErrorCode::DETACHED_DATA_VIEW,
+                    "DataView: buffer is detached",
+                )
+            }
+        };
+
+        // 13. If offset > bufferByteLength, throw a RangeError exception.
+        // 14. If byteLength is not undefined, then
+        //     a. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+        if offset > buffer_byte_length
+            || view_byte_length.map_or(false, |len| offset + len > buffer_byte_length)
+        {
+            return context
+                .throw_range_error("DataView: the buffer shrunk while the view was constructed");
+        }
+
+        // 15. Set O.[[ViewedArrayBuffer]] to buffer.
+        // 16. Set O.[[ByteLength]] to viewByteLength.
+        // 17. Set O.[[ByteOffset]] to offset.
+        let o = context.construct_object();
+        o.set_prototype_instance(prototype.into());
+        o.borrow_mut().data = ObjectData::data_view(Self {
+            viewed_array_buffer: buffer,
+            byte_length: view_byte_length,
+            byte_offset: offset,
+        });
+
+        // 18. Return O.
+        Ok(o.into())
+    }
+
+    /// `get DataView.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer
+    fn get_buffer(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
+        // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        // 4. Let buffer be O.[[ViewedArrayBuffer]].
+        // 5. Return buffer.
+        let view = Self::this_data_view(this, "buffer", context)?;
+        Ok(view.viewed_array_buffer.clone().into())
+    }
+
+    /// `get DataView.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength
+    fn get_byte_length(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
+        let view = Self::this_data_view(this, "byteLength", context)?;
+
+        // 4. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if view.is_out_of_bounds() {
+            return context.throw_type_error("DataView: buffer is detached or out of bounds");
+        }
+
+        // 6. Let size be GetViewByteLength(viewRecord).
+        // 7. Return 𝔽(size).
+        Ok(view.byte_length().into())
+    }
+
+    /// `get DataView.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset
+    fn get_byte_offset(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[DataView]]).
+        let view = Self::this_data_view(this, "byteOffset", context)?;
+
+        // 4. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if view.is_out_of_bounds() {
+            return context.throw_type_error("DataView: buffer is detached or out of bounds");
+        }
+
+        // 6. Let offset be O.[[ByteOffset]].
+        // 7. Return 𝔽(offset).
+        Ok(view.byte_offset.into())
+    }
+
+    view_accessors![
+        (get_big_int64, set_big_int64, BigInt64Array, "BigInt64"),
+        (get_big_uint64, set_big_uint64, BigUint64Array, "BigUint64"),
+        (get_float32, set_float32, Float32Array, "Float32"),
+        (get_float64, set_float64, Float64Array, "Float64"),
+        (get_int8, set_int8, Int8Array, "Int8"),
+        (get_int16, set_int16, Int16Array, "Int16"),
+        (get_int32, set_int32, Int32Array, "Int32"),
+        (get_uint8, set_uint8, Uint8Array, "Uint8"),
+        (get_uint16, set_uint16, Uint16Array, "Uint16"),
+        (get_uint32, set_uint32, Uint32Array, "Uint32"),
+    ];
+
+    /// Abstract operation `GetViewValue ( view, requestIndex, isLittleEndian, type )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+    fn get_view_value(
+        view: &JsValue,
+        request_index: &JsValue,
+        is_little_endian: &JsValue,
+        t: TypedArrayName,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? RequireInternalSlot(view, [[DataView]]).
+        // 2. Assert: view has a [[ViewedArrayBuffer]] internal slot.
+        let view = Self::this_data_view(view, "get", context)?;
+
+        // 3. Let getIndex be ? ToIndex(requestIndex).
+        let get_index = request_index.to_index(context)?;
+
+        // 4. Set isLittleEndian to ! ToBoolean(isLittleEndian).
+        let is_little_endian = is_little_endian.to_boolean();
+
+        // 5-11. Let bufferIndex be the checked index of the element in the buffer.
+        let buffer_index = view.buffer_index(get_index, t, context)?;
+
+        // 12. Return GetValueFromBuffer(buffer, bufferIndex, type, false, Unordered, isLittleEndian).
+        let buffer = view.viewed_array_buffer.borrow();
+        Ok(buffer
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer")
+            .get_value_from_buffer(buffer_index, t, is_little_endian))
+    }
+
+    /// Abstract operation `SetViewValue ( view, requestIndex, isLittleEndian, type, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+    fn set_view_value(
+        view: &JsValue,
+        request_index: &JsValue,
+        is_little_endian: &JsValue,
+        t: TypedArrayName,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? RequireInternalSlot(view, [[DataView]]).
+        // 2. Assert: view has a [[ViewedArrayBuffer]] internal slot.
+        let view = Self::this_data_view(view, "set", context)?;
+
+        // 3. Let getIndex be ? ToIndex(requestIndex).
+        let get_index = request_index.to_index(context)?;
+
+        // 4. If ! IsBigIntElementType(type) is true, let numberValue be ? ToBigInt(value).
+        // 5. Otherwise, let numberValue be ? ToNumber(value).
+        let number_value = t.to_element_value(value, context)?;
+
+        // 6. Set isLittleEndian to ! ToBoolean(isLittleEndian).
+        let is_little_endian = is_little_endian.to_boolean();
+
+        // 7-13. Let bufferIndex be the checked index of the element in the buffer.
+        let buffer_index = view.buffer_index(get_index, t, context)?;
+
+        // 14. Return SetValueInBuffer(buffer, bufferIndex, type, numberValue, false, Unordered, isLittleEndian).
+        view.viewed_array_buffer
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .expect("checked to be an ArrayBuffer")
+            .set_value_in_buffer(buffer_index, t, &number_value, is_little_endian);
+
+        Ok(JsValue::undefined())
+    }
+
+    /// Shared steps of `GetViewValue` and `SetViewValue`, which check that the view is still in
+    /// the bounds of its buffer and that the element at `get_index` is inside the view, returning
+    /// its index in the buffer.
+    fn buffer_index(
+        &self,
+        get_index: usize,
+        t: TypedArrayName,
+        context: &mut Context,
+    ) -> JsResult<usize> {
+        // a. Let viewOffset be view.[[ByteOffset]].
+        // b. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // c. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if self.is_out_of_bounds() {
+            return Err(
+                context.construct_type_error("DataView: buffer is detached or out of bounds")
+            );
+        }
+
+        // d. Let viewSize be GetViewByteLength(viewRecord).
+        // e. Let elementSize be TypedArrayElementSize(type).
+        // f. If getIndex + elementSize > viewSize, throw a RangeError exception.
+        if get_index.saturating_add(t.element_size()) > self.byte_length() {
+            return Err(
+                context.construct_range_error("DataView: offset is outside the bounds of the view")
+            );
+        }
+
+        // g. Let bufferIndex be getIndex + viewOffset.
+        Ok(get_index + self.byte_offset)
+    }
+
+    /// Gets the byte length of the `ArrayBuffer` object `buffer`, or `None` if it is detached.
+    fn attached_byte_length(buffer: &JsObject) -> Option<usize> {
+        buffer
+            .borrow()
+            .as_array_buffer()
+            .filter(|buffer| !buffer.is_detached_buffer())
+            .map(ArrayBuffer::byte_length)
+    }
+
+    /// Returns the data of the `DataView` object `this`, or throws a `TypeError` naming `method`.
+    fn this_data_view(this: &JsValue, method: &str, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_data_view().cloned())
+            .ok_or_else(|| {
+                context.construct_type_error(format!(
+                    "DataView.prototype.{} called on a non-DataView",
+                    method
+                ))
+            })
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new DataView(buffer, 2, 4);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut context, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "view.byteLength"), "4");
+    assert_eq!(
+        forward(&mut context, "new DataView(buffer, 3).byteLength"),
+        "5"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(view)"),
+        "\"[object DataView]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new DataView(buffer, 9) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new DataView(buffer, 4, 5) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new DataView({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn get_and_set() {
+    let mut context = Context::new();
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(16));
+        "#;
+    forward(&mut context, init);
+
+    forward(&mut context, "view.setInt16(0, -2)");
+    assert_eq!(forward(&mut context, "view.getInt16(0)"), "-2");
+    assert_eq!(forward(&mut context, "view.getUint16(0)"), "65534");
+    assert_eq!(forward(&mut context, "view.getUint8(0)"), "255");
+    assert_eq!(forward(&mut context, "view.getUint8(1)"), "254");
+
+    forward(&mut context, "view.setUint32(0, 0x01020304, true)");
+    assert_eq!(forward(&mut context, "view.getUint8(0)"), "4");
+    assert_eq!(forward(&mut context, "view.getUint32(0, true)"), "16909060");
+    assert_eq!(forward(&mut context, "view.getUint32(0)"), "67305985");
+
+    forward(&mut context, "view.setFloat64(8, 1.5)");
+    assert_eq!(forward(&mut context, "view.getFloat64(8)"), "1.5");
+    forward(&mut context, "view.setFloat32(8, 0.1)");
+    assert_eq!(
+        forward(&mut context, "view.getFloat32(8) === Math.fround(0.1)"),
+        "true"
+    );
+
+    forward(&mut context, "view.setBigInt64(0, -1n)");
+    assert_eq!(forward(&mut context, "view.getBigInt64(0)"), "-1n");
+    assert_eq!(
+        forward(&mut context, "view.getBigUint64(0)"),
+        "18446744073709551615n"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.setBigInt64(0) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getInt32(13) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getInt8(-1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn resizable_buffers() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        var tracking = new DataView(buffer, 1);
+        var fixed = new DataView(buffer, 0, 4);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "3");
+    forward(&mut context, "buffer.resize(8)");
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "7");
+    forward(&mut context, "tracking.setInt32(3, 7)");
+    assert_eq!(forward(&mut context, "tracking.getInt32(3)"), "7");
+
+    forward(&mut context, "buffer.resize(2)");
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { tracking.getInt16(0) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fixed.byteLength } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fixed.getInt8(0) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+
+    forward(&mut context, "buffer.resize(0)");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { tracking.byteOffset } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -1,12 +1,19 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "intl")]
+use crate::builtins::intl::date_time_format::{DateTimeFormat, Defaults, Required};
 use crate::{
     builtins::BuiltIn,
+    context::StandardObjects,
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        ObjectData,
+    },
     property::Attribute,
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     value::{JsValue, PreferredType},
     BoaProfiler, Context, JsResult, JsString,
 };
@@ -15,6 +22,8 @@ use std::fmt::Display;
 
 /// The number of nanoseconds in a millisecond.
 const NANOS_PER_MS: i64 = 1_000_000;
+/// The number of milliseconds in a day.
+const MILLIS_PER_DAY: i64 = 86_400_000;
 /// The number of milliseconds in an hour.
 const MILLIS_PER_HOUR: i64 = 3_600_000;
 /// The number of milliseconds in a minute.
@@ -22,27 +31,21 @@ const MILLIS_PER_MINUTE: i64 = 60_000;
 /// The number of milliseconds in a second.
 const MILLIS_PER_SECOND: i64 = 1000;
 
-#[inline]
-fn is_zero_or_normal_opt(value: Option<f64>) -> bool {
-    value
-        .map(|value| value == 0f64 || value.is_normal())
-        .unwrap_or(true)
-}
+/// The largest absolute time value a `Date` can represent, exactly 100,000,000 days on either side of the epoch.
+const MAX_TIME: f64 = 8.64e15;
 
-macro_rules! check_normal_opt {
-    ($($v:expr),+) => {
-        $(is_zero_or_normal_opt($v.into()) &&)+ true
-    };
-}
+/// The largest absolute time value for which the offset of the local time zone is looked up.
+///
+/// `chrono` cannot represent the whole range of time values, so the offset of times beyond this bound is the offset
+/// at the bound itself.
+const MAX_OFFSET_TIME: i64 = 8_000_000_000_000_000;
 
-#[inline]
-fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
-    match result {
-        LocalResult::Ambiguous(v, _) => Some(v),
-        LocalResult::Single(v) => Some(v),
-        LocalResult::None => None,
-    }
-}
+/// The abbreviated names of the days of the week, starting on Sunday.
+const WEEK_DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+/// The abbreviated names of the months of the year.
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
 
 macro_rules! getter_method {
     ($name:ident) => {{
@@ -59,8 +62,270 @@ macro_rules! getter_method {
     }};
 }
 
+/// Returns the year, the zero-based month and the date of the day `day` days after the epoch.
+fn year_month_date(day: i64) -> (i64, i64, i64) {
+    let z = day + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let date = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 2
+    } else {
+        shifted_month - 10
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 1);
+
+    (year, month, date)
+}
+
+/// Returns the number of days between the epoch and the first day of the zero-based `month` of `year`.
+fn day_from_year_month(year: i64, month: i64) -> i64 {
+    let year = if month <= 1 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 1 { month - 2 } else { month + 10 };
+    let day_of_year = (153 * shifted_month + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The abstract operation `MakeTime` calculates a number of milliseconds from its arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-maketime
+fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
+    // 1. If hour is not finite or min is not finite or sec is not finite or ms is not finite, return NaN.
+    if !(hour.is_finite() && min.is_finite() && sec.is_finite() && ms.is_finite()) {
+        return f64::NAN;
+    }
+
+    // 2. Let h be 𝔽(! ToIntegerOrInfinity(hour)).
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(min)).
+    // 4. Let s be 𝔽(! ToIntegerOrInfinity(sec)).
+    // 5. Let milli be 𝔽(! ToIntegerOrInfinity(ms)).
+    // 6. Let t be ((h * msPerHour + m * msPerMinute) + s * msPerSecond) + milli, performing the arithmetic according
+    //    to IEEE 754-2019 rules (that is, as if using the ECMAScript operators * and +).
+    // 7. Return t.
+    hour.trunc() * MILLIS_PER_HOUR as f64
+        + min.trunc() * MILLIS_PER_MINUTE as f64
+        + sec.trunc() * MILLIS_PER_SECOND as f64
+        + ms.trunc()
+}
+
+/// The abstract operation `MakeDay` calculates a number of days from its arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makeday
+fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    // 1. If year is not finite or month is not finite or date is not finite, return NaN.
+    if !(year.is_finite() && month.is_finite() && date.is_finite()) {
+        return f64::NAN;
+    }
+
+    // 2. Let y be 𝔽(! ToIntegerOrInfinity(year)).
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(month)).
+    // 4. Let dt be 𝔽(! ToIntegerOrInfinity(date)).
+    // 5. Let ym be y + 𝔽(floor(ℝ(m) / 12)).
+    let ym = year.trunc() + (month.trunc() / 12.0).floor();
+
+    // 6. Let mn be 𝔽(ℝ(m) modulo 12).
+    let mn = month.trunc().rem_euclid(12.0) as i64;
+
+    // Any year this far from the epoch is outside of the range of `TimeClip`, whatever the date is.
+    if ym.abs() > 400_000.0 {
+        return f64::NAN;
+    }
+
+    // 7. Find a finite time value t such that YearFromTime(t) is ym and MonthFromTime(t) is mn and DateFromTime(t) is
+    //    1𝔽; but if this is not possible (because some argument is out of range), return NaN.
+    let t = day_from_year_month(ym as i64, mn) as f64;
+
+    // 8. Return Day(t) + dt - 1𝔽.
+    t + date.trunc() - 1.0
+}
+
+/// The abstract operation `MakeDate` calculates a number of milliseconds from its arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makedate
+fn make_date(day: f64, time: f64) -> f64 {
+    // 1. If day is not finite or time is not finite, return NaN.
+    // 2. Let tv be day × msPerDay + time.
+    // 3. If tv is not finite, return NaN.
+    // 4. Return tv.
+    let tv = day * MILLIS_PER_DAY as f64 + time;
+    if tv.is_finite() {
+        tv
+    } else {
+        f64::NAN
+    }
+}
+
+/// The abstract operation `LocalTZA` returns the offset of the local time zone from UTC, in milliseconds.
+///
+/// If `is_utc` is `true`, `time` is a UTC time value, otherwise it is a local time value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-local-time-zone-adjustment
+fn local_tza(time: i64, is_utc: bool) -> i64 {
+    let time = time.clamp(-MAX_OFFSET_TIME, MAX_OFFSET_TIME);
+    let naive = NaiveDateTime::from_timestamp(
+        time.div_euclid(MILLIS_PER_SECOND),
+        (time.rem_euclid(MILLIS_PER_SECOND) * NANOS_PER_MS) as u32,
+    );
+
+    let offset = if is_utc {
+        Local.offset_from_utc_datetime(&naive)
+    } else {
+        match Local.offset_from_local_datetime(&naive) {
+            LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset,
+            // Local times skipped by a transition are interpreted using the offset before the transition.
+            LocalResult::None => Local.offset_from_utc_datetime(&(naive - Duration::days(1))),
+        }
+    };
+
+    i64::from(offset.local_minus_utc()) * MILLIS_PER_SECOND
+}
+
+/// The abstract operation `UTC` converts a local time value to a UTC time value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-utc-t
+fn utc_from_local(time: f64) -> f64 {
+    if time.is_finite() {
+        time - local_tza(time as i64, false) as f64
+    } else {
+        f64::NAN
+    }
+}
+
+/// The broken-down components of a time value, either in UTC or in local time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Components {
+    year: i64,
+    /// The zero-based month of the year.
+    month: i64,
+    /// The one-based day of the month.
+    date: i64,
+    /// The day of the week, where 0 represents Sunday.
+    week_day: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    milliseconds: i64,
+}
+
+impl Components {
+    fn new(time: i64) -> Self {
+        let day = time.div_euclid(MILLIS_PER_DAY);
+        let time_within_day = time.rem_euclid(MILLIS_PER_DAY);
+        let (year, month, date) = year_month_date(day);
+
+        Self {
+            year,
+            month,
+            date,
+            week_day: (day + 4).rem_euclid(7),
+            hours: time_within_day / MILLIS_PER_HOUR,
+            minutes: time_within_day % MILLIS_PER_HOUR / MILLIS_PER_MINUTE,
+            seconds: time_within_day % MILLIS_PER_MINUTE / MILLIS_PER_SECOND,
+            milliseconds: time_within_day % MILLIS_PER_SECOND,
+        }
+    }
+
+    /// `DateString ( tv )`, formatting the components as `Www Mmm DD YYYY`.
+    fn date_string(&self) -> String {
+        format!(
+            "{} {} {:02} {}",
+            WEEK_DAYS[self.week_day as usize],
+            MONTHS[self.month as usize],
+            self.date,
+            self.padded_year()
+        )
+    }
+
+    /// `TimeString ( tv )`, formatting the components as `HH:mm:ss GMT`.
+    fn time_string(&self) -> String {
+        format!(
+            "{:02}:{:02}:{:02} GMT",
+            self.hours, self.minutes, self.seconds
+        )
+    }
+
+    /// Formats the year with at least four digits, prefixed by `-` if it is negative.
+    fn padded_year(&self) -> String {
+        if self.year >= 0 {
+            format!("{:04}", self.year)
+        } else {
+            format!("-{:04}", -self.year)
+        }
+    }
+}
+
+/// A time value, along with its cached local time zone offset and broken-down components.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct TimeValue {
+    /// The number of milliseconds since the epoch, always in the range allowed by `TimeClip`.
+    time: i64,
+    /// The offset of the local time zone from UTC at `time`, in milliseconds.
+    offset: i64,
+    utc: Components,
+    local: Components,
+}
+
+impl TimeValue {
+    fn new(time: i64) -> Self {
+        let offset = local_tza(time, true);
+        Self {
+            time,
+            offset,
+            utc: Components::new(time),
+            local: Components::new(time + offset),
+        }
+    }
+
+    /// `TimeZoneString ( tv )`, formatting the offset of the local time zone as `+hhmm`.
+    fn time_zone_string(&self) -> String {
+        let sign = if self.offset >= 0 { '+' } else { '-' };
+        let offset = self.offset.abs();
+        format!(
+            "{}{:02}{:02}",
+            sign,
+            offset / MILLIS_PER_HOUR,
+            offset % MILLIS_PER_HOUR / MILLIS_PER_MINUTE
+        )
+    }
+}
+
+/// The components formatted by the locale-sensitive methods of `Date.prototype`.
+#[derive(Debug, Clone, Copy)]
+enum LocaleFormat {
+    DateTime,
+    Date,
+    Time,
+}
+
+/// The `[[DateValue]]` internal slot of a `Date` object.
+///
+/// The time value is stored as a number of milliseconds since the epoch, or `None` if it is `NaN`. Its broken-down
+/// UTC and local time components are computed once when the time value is set, so getters do not have to convert it.
 #[derive(Debug, Finalize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Date(Option<NaiveDateTime>);
+pub struct Date(Option<TimeValue>);
 
 impl Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -73,13 +338,12 @@ impl Display for Date {
 
 unsafe impl Trace for Date {
     // Date is a stack value, it doesn't require tracing.
-    // only safe if `chrono` never implements `Trace` for `NaiveDateTime`
     empty_trace!();
 }
 
 impl Default for Date {
     fn default() -> Self {
-        Self(Some(Utc::now().naive_utc()))
+        Self::new(Utc::now().timestamp_millis() as f64)
     }
 }
 
@@ -87,72 +351,90 @@ impl BuiltIn for Date {
     const NAME: &'static str = "Date";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let date_object = ConstructorBuilder::new(context, Self::constructor)
-            .name(Self::NAME)
-            .length(Self::LENGTH)
-            .method(getter_method!(get_date), "getDate", 0)
-            .method(getter_method!(get_day), "getDay", 0)
-            .method(getter_method!(get_full_year), "getFullYear", 0)
-            .method(getter_method!(get_hours), "getHours", 0)
-            .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
-            .method(getter_method!(get_minutes), "getMinutes", 0)
-            .method(getter_method!(get_month), "getMonth", 0)
-            .method(getter_method!(get_seconds), "getSeconds", 0)
-            .method(getter_method!(get_time), "getTime", 0)
-            .method(getter_method!(get_year), "getYear", 0)
-            .method(Self::get_timezone_offset, "getTimezoneOffset", 0)
-            .method(getter_method!(get_utc_date), "getUTCDate", 0)
-            .method(getter_method!(get_utc_day), "getUTCDay", 0)
-            .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
-            .method(getter_method!(get_utc_hours), "getUTCHours", 0)
-            .method(
-                getter_method!(get_utc_milliseconds),
-                "getUTCMilliseconds",
-                0,
-            )
-            .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
-            .method(getter_method!(get_utc_month), "getUTCMonth", 0)
-            .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
-            .method(Self::set_date, "setDate", 1)
-            .method(Self::set_full_year, "setFullYear", 3)
-            .method(Self::set_hours, "setHours", 4)
-            .method(Self::set_milliseconds, "setMilliseconds", 1)
-            .method(Self::set_minutes, "setMinutes", 3)
-            .method(Self::set_month, "setMonth", 2)
-            .method(Self::set_seconds, "setSeconds", 2)
-            .method(Self::set_year, "setYear", 1)
-            .method(Self::set_time, "setTime", 1)
-            .method(Self::set_utc_date, "setUTCDate", 1)
-            .method(Self::set_utc_full_year, "setUTCFullYear", 3)
-            .method(Self::set_utc_hours, "setUTCHours", 4)
-            .method(Self::set_utc_milliseconds, "setUTCMilliseconds", 1)
-            .method(Self::set_utc_minutes, "setUTCMinutes", 3)
-            .method(Self::set_utc_month, "setUTCMonth", 2)
-            .method(Self::set_utc_seconds, "setUTCSeconds", 2)
-            .method(Self::to_date_string, "toDateString", 0)
-            .method(getter_method!(to_gmt_string), "toGMTString", 0)
-            .method(Self::to_iso_string, "toISOString", 0)
-            .method(Self::to_json, "toJSON", 1)
-            // Locale strings
-            .method(Self::to_string, "toString", 0)
-            .method(Self::to_time_string, "toTimeString", 0)
-            .method(getter_method!(to_utc_string), "toUTCString", 0)
-            .method(getter_method!(value_of), "valueOf", 0)
-            .method(
-                Self::to_primitive,
-                (WellKnownSymbols::to_primitive(), "[Symbol.toPrimitive]"),
-                1,
-            )
-            .static_method(Self::now, "now", 0)
-            .static_method(Self::parse, "parse", 1)
-            .static_method(Self::utc, "UTC", 7)
+        // Unlike the other methods of the prototype, `@@toPrimitive` is not writable.
+        let to_primitive = FunctionBuilder::native(context, Self::to_primitive)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .constructable(false)
             .build();
+        let to_utc_string = FunctionBuilder::native(context, getter_method!(to_utc_string))
+            .name("toUTCString")
+            .length(0)
+            .constructable(false)
+            .build();
+
+        let date_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().date_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(getter_method!(get_date), "getDate", 0)
+        .method(getter_method!(get_day), "getDay", 0)
+        .method(getter_method!(get_full_year), "getFullYear", 0)
+        .method(getter_method!(get_hours), "getHours", 0)
+        .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
+        .method(getter_method!(get_minutes), "getMinutes", 0)
+        .method(getter_method!(get_month), "getMonth", 0)
+        .method(getter_method!(get_seconds), "getSeconds", 0)
+        .method(getter_method!(get_time), "getTime", 0)
+        .method(getter_method!(get_year), "getYear", 0)
+        .method(Self::get_timezone_offset, "getTimezoneOffset", 0)
+        .method(getter_method!(get_utc_date), "getUTCDate", 0)
+        .method(getter_method!(get_utc_day), "getUTCDay", 0)
+        .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
+        .method(getter_method!(get_utc_hours), "getUTCHours", 0)
+        .method(
+            getter_method!(get_utc_milliseconds),
+            "getUTCMilliseconds",
+            0,
+        )
+        .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
+        .method(getter_method!(get_utc_month), "getUTCMonth", 0)
+        .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
+        .method(Self::set_date, "setDate", 1)
+        .method(Self::set_full_year, "setFullYear", 3)
+        .method(Self::set_hours, "setHours", 4)
+        .method(Self::set_milliseconds, "setMilliseconds", 1)
+        .method(Self::set_minutes, "setMinutes", 3)
+        .method(Self::set_month, "setMonth", 2)
+        .method(Self::set_seconds, "setSeconds", 2)
+        .method(Self::set_year, "setYear", 1)
+        .method(Self::set_time, "setTime", 1)
+        .method(Self::set_utc_date, "setUTCDate", 1)
+        .method(Self::set_utc_full_year, "setUTCFullYear", 3)
+        .method(Self::set_utc_hours, "setUTCHours", 4)
+        .method(Self::set_utc_milliseconds, "setUTCMilliseconds", 1)
+        .method(Self::set_utc_minutes, "setUTCMinutes", 3)
+        .method(Self::set_utc_month, "setUTCMonth", 2)
+        .method(Self::set_utc_seconds, "setUTCSeconds", 2)
+        .method(Self::to_date_string, "toDateString", 0)
+        .property("toGMTString", to_utc_string.clone(), Attribute::builtin())
+        .method(Self::to_iso_string, "toISOString", 0)
+        .method(Self::to_json, "toJSON", 1)
+        .method(Self::to_locale_date_string, "toLocaleDateString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::to_locale_time_string, "toLocaleTimeString", 0)
+        .method(Self::to_string, "toString", 0)
+        .method(Self::to_time_string, "toTimeString", 0)
+        .property("toUTCString", to_utc_string, Attribute::builtin())
+        .method(getter_method!(value_of), "valueOf", 0)
+        .property(
+            WellKnownSymbols::to_primitive(),
+            to_primitive,
+            Attribute::readonly_builtin(),
+        )
+        .static_method(Self::now, "now", 0)
+        .static_method(Self::parse, "parse", 1)
+        .static_method(Self::utc, "UTC", 7)
+        .build();
 
         (Self::NAME, date_object.into(), Self::attribute())
     }
@@ -163,7 +445,7 @@ impl Date {
     pub(crate) const LENGTH: usize = 7;
 
     /// Check if the time (number of milliseconds) is in the expected range.
-    /// Returns None if the time is not in the range, otherwise returns the time itself in option.
+    /// Returns None if the time is not in the range, otherwise returns the time itself truncated to an integer.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -171,34 +453,52 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-timeclip
     #[inline]
     pub fn time_clip(time: f64) -> Option<f64> {
-        if time.abs() > 8.64e15 {
+        // 1. If time is not finite, return NaN.
+        // 2. If abs(ℝ(time)) > 8.64 × 10^15, return NaN.
+        if !time.is_finite() || time.abs() > MAX_TIME {
             None
         } else {
-            Some(time)
+            // 3. Return 𝔽(! ToIntegerOrInfinity(time)).
+            Some(time.trunc() + 0.0)
         }
     }
 
+    /// Creates a `Date` from a time value, applying `TimeClip` to it.
+    #[inline]
+    pub fn new(time: f64) -> Self {
+        Self(Self::time_clip(time).map(|time| TimeValue::new(time as i64)))
+    }
+
+    /// Creates a `Date` from a local time value, converting it to UTC before applying `TimeClip` to it.
+    #[inline]
+    fn from_local(time: f64) -> Self {
+        Self::new(utc_from_local(time))
+    }
+
     /// Converts the `Date` to a local `DateTime`.
     ///
-    /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
+    /// If the `Date` is invalid (i.e. NAN), or outside of the range supported by `chrono`, this function will return
+    /// `None`.
     #[inline]
     pub fn to_local(self) -> Option<DateTime<Local>> {
         self.0
-            .map(|utc| Local::now().timezone().from_utc_datetime(&utc))
+            .and_then(|tv| Local.timestamp_millis_opt(tv.time).single())
     }
 
     /// Converts the `Date` to a UTC `DateTime`.
     ///
-    /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
+    /// If the `Date` is invalid (i.e. NAN), or outside of the range supported by `chrono`, this function will return
+    /// `None`.
     pub fn to_utc(self) -> Option<DateTime<Utc>> {
         self.0
-            .map(|utc| Utc::now().timezone().from_utc_datetime(&utc))
+            .and_then(|tv| Utc.timestamp_millis_opt(tv.time).single())
     }
 
     /// Optionally sets the individual components of the `Date`.
     ///
     /// Each component does not have to be within the range of valid values. For example, if `month` is too large
-    /// then `year` will be incremented by the required amount.
+    /// then `year` will be incremented by the required amount. The components that are not provided are taken from
+    /// the current time value, so setting any component of an invalid `Date` leaves it invalid.
     #[allow(clippy::too_many_arguments)]
     pub fn set_components(
         &mut self,
@@ -211,113 +511,31 @@ impl Date {
         second: Option<f64>,
         millisecond: Option<f64>,
     ) {
-        #[inline]
-        fn num_days_in(year: i32, month: u32) -> Option<u32> {
-            let month = month + 1; // zero-based for calculations
-
-            Some(
-                NaiveDate::from_ymd_opt(
-                    match month {
-                        12 => year.checked_add(1)?,
-                        _ => year,
-                    },
-                    match month {
-                        12 => 1,
-                        _ => month + 1,
-                    },
-                    1,
-                )?
-                .signed_duration_since(NaiveDate::from_ymd_opt(year, month, 1)?)
-                .num_days() as u32,
-            )
-        }
-
-        #[inline]
-        fn fix_month(year: i32, month: i32) -> Option<(i32, u32)> {
-            let year = year.checked_add(month / 12)?;
-
-            if month < 0 {
-                let year = year.checked_sub(1)?;
-                let month = (11 + (month + 1) % 12) as u32;
-                Some((year, month))
-            } else {
-                let month = (month % 12) as u32;
-                Some((year, month))
-            }
-        }
-
-        #[inline]
-        fn fix_day(mut year: i32, mut month: i32, mut day: i32) -> Option<(i32, u32, u32)> {
-            loop {
-                if day < 0 {
-                    let (fixed_year, fixed_month) = fix_month(year, month.checked_sub(1)?)?;
-
-                    year = fixed_year;
-                    month = fixed_month as i32;
-                    day += num_days_in(fixed_year, fixed_month)? as i32;
-                } else {
-                    let (fixed_year, fixed_month) = fix_month(year, month)?;
-                    let num_days = num_days_in(fixed_year, fixed_month)? as i32;
-
-                    if day >= num_days {
-                        day -= num_days;
-                        month = month.checked_add(1)?;
-                    } else {
-                        break;
-                    }
-                }
-            }
-
-            let (fixed_year, fixed_month) = fix_month(year, month)?;
-            Some((fixed_year, fixed_month, day as u32))
-        }
-
-        // If any of the args are infinity or NaN, return an invalid date.
-        if !check_normal_opt!(year, month, day, hour, minute, second, millisecond) {
-            self.0 = None;
+        let tv = if let Some(tv) = self.0 {
+            tv
+        } else {
             return;
-        }
+        };
+        let components = if utc { tv.utc } else { tv.local };
 
-        let naive = if utc {
-            self.to_utc().map(|dt| dt.naive_utc())
+        let day = make_day(
+            year.unwrap_or(components.year as f64),
+            month.unwrap_or(components.month as f64),
+            day.unwrap_or(components.date as f64),
+        );
+        let time = make_time(
+            hour.unwrap_or(components.hours as f64),
+            minute.unwrap_or(components.minutes as f64),
+            second.unwrap_or(components.seconds as f64),
+            millisecond.unwrap_or(components.milliseconds as f64),
+        );
+        let date = make_date(day, time);
+
+        *self = if utc {
+            Self::new(date)
         } else {
-            self.to_local().map(|dt| dt.naive_local())
+            Self::from_local(date)
         };
-
-        self.0 = naive.and_then(|naive| {
-            let year = year.unwrap_or_else(|| naive.year() as f64) as i32;
-            let month = month.unwrap_or_else(|| naive.month0() as f64) as i32;
-            let day = (day.unwrap_or_else(|| naive.day() as f64) as i32).checked_sub(1)?;
-            let hour = hour.unwrap_or_else(|| naive.hour() as f64) as i64;
-            let minute = minute.unwrap_or_else(|| naive.minute() as f64) as i64;
-            let second = second.unwrap_or_else(|| naive.second() as f64) as i64;
-            let millisecond = millisecond
-                .unwrap_or_else(|| naive.nanosecond() as f64 / NANOS_PER_MS as f64)
-                as i64;
-
-            let (year, month, day) = fix_day(year, month, day)?;
-
-            let duration_hour = Duration::milliseconds(hour.checked_mul(MILLIS_PER_HOUR)?);
-            let duration_minute = Duration::milliseconds(minute.checked_mul(MILLIS_PER_MINUTE)?);
-            let duration_second = Duration::milliseconds(second.checked_mul(MILLIS_PER_SECOND)?);
-            let duration_milisecond = Duration::milliseconds(millisecond);
-
-            let duration = duration_hour
-                .checked_add(&duration_minute)?
-                .checked_add(&duration_second)?
-                .checked_add(&duration_milisecond)?;
-
-            NaiveDate::from_ymd_opt(year, month + 1, day + 1)
-                .and_then(|dt| dt.and_hms(0, 0, 0).checked_add_signed(duration))
-                .and_then(|dt| {
-                    if utc {
-                        Some(Utc.from_utc_datetime(&dt).naive_utc())
-                    } else {
-                        ignore_ambiguity(Local.from_local_datetime(&dt)).map(|dt| dt.naive_utc())
-                    }
-                })
-                .filter(|dt| Self::time_clip(dt.timestamp_millis() as f64).is_some())
-        });
     }
 
     /// `Date()`
@@ -338,15 +556,8 @@ impl Date {
         if new_target.is_undefined() {
             Ok(Self::make_date_string())
         } else {
-            let prototype = new_target
-                .as_object()
-                .and_then(|obj| {
-                    obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
-                        .map(|o| o.as_object())
-                        .transpose()
-                })
-                .transpose()?
-                .unwrap_or_else(|| context.standard_objects().object_object().prototype());
+            let prototype =
+                get_prototype_from_constructor(new_target, StandardObjects::date_object, context)?;
             let obj = context.construct_object();
             obj.set_prototype_instance(prototype.into());
             let this = obj.into();
@@ -406,28 +617,17 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let value = &args[0];
-        let tv = match this_time_value(value, context) {
-            Ok(dt) => dt.0,
+        let date = match this_time_value(value, context) {
+            Ok(date) => date,
             _ => match value.to_primitive(context, PreferredType::Default)? {
                 JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
+                    Ok(dt) => Self::new(dt.timestamp_millis() as f64),
+                    _ => Self(None),
                 },
-                tv => {
-                    let tv = tv.to_number(context)?;
-                    if tv.is_nan() {
-                        None
-                    } else {
-                        let secs = (tv / 1_000f64) as i64;
-                        let nsecs = ((tv % 1_000f64) * 1_000_000f64) as u32;
-                        NaiveDateTime::from_timestamp_opt(secs, nsecs)
-                    }
-                }
+                tv => Self::new(tv.to_number(context)?),
             },
         };
 
-        let tv = tv.filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
-        let date = Date(tv);
         this.set_data(ObjectData::date(date));
         Ok(this.clone())
     }
@@ -447,7 +647,7 @@ impl Date {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let mut year = args[0].to_number(context)?;
+        let year = args[0].to_number(context)?;
         let month = args[1].to_number(context)?;
         let day = args
             .get(2)
@@ -465,40 +665,32 @@ impl Date {
             .get(6)
             .map_or(Ok(0f64), |value| value.to_number(context))?;
 
-        // If any of the args are infinity or NaN, return an invalid date.
-        if !check_normal_opt!(year, month, day, hour, min, sec, milli) {
-            let date = Date(None);
-            this.set_data(ObjectData::date(date));
-            return Ok(this.clone());
-        }
-
-        if (0.0..=99.0).contains(&year) {
-            year += 1900.0
-        }
-
-        let mut date = Self(
-            NaiveDateTime::from_timestamp_opt(0, 0)
-                .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
-                .map(|local| local.naive_utc())
-                .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some()),
-        );
-
-        date.set_components(
-            false,
-            Some(year),
-            Some(month),
-            Some(day),
-            Some(hour),
-            Some(min),
-            Some(sec),
-            Some(milli),
-        );
+        let date = Self::from_local(make_date(
+            make_day(Self::full_year(year), month, day),
+            make_time(hour, min, sec, milli),
+        ));
 
         this.set_data(ObjectData::date(date));
 
         Ok(this.clone())
     }
 
+    /// Maps the years from 0 to 99 to the years from 1900 to 1999, as done by the `Date` constructor, `Date.UTC`
+    /// and `Date.prototype.setYear`.
+    #[inline]
+    fn full_year(year: f64) -> f64 {
+        // If y is NaN, let yr be NaN.
+        // Else,
+        //     i. Let yi be ! ToIntegerOrInfinity(y).
+        //     ii. If 0 ≤ yi ≤ 99, let yr be 1900𝔽 + 𝔽(yi); otherwise, let yr be y.
+        let yi = year.trunc();
+        if (0.0..=99.0).contains(&yi) {
+            1900.0 + yi
+        } else {
+            year
+        }
+    }
+
     /// `Date.prototype[@@toPrimitive]`
     ///
     /// The [@@toPrimitive]() method converts a Date object to a primitive value.
@@ -523,7 +715,7 @@ impl Date {
             return context.throw_type_error("Date.prototype[@@toPrimitive] called on non object");
         };
 
-        let hint = args.get(0).cloned().unwrap_or_default();
+        let hint = args.get_or_undefined(0).clone();
 
         let try_first = match hint.as_string().map(|s| s.as_str()) {
             // 3. If hint is "string" or "default", then
@@ -554,7 +746,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getdate
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDate
     pub fn get_date(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.day() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.date as f64)
     }
 
     /// `Date.prototype.getDay()`
@@ -569,11 +761,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getday
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDay
     pub fn get_day(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| {
-            let weekday = dt.weekday() as u32;
-            let weekday = (weekday + 1) % 7; // 0 represents Monday in Chrono
-            weekday as f64
-        })
+        self.0.map_or(f64::NAN, |tv| tv.local.week_day as f64)
     }
 
     /// `Date.prototype.getFullYear()`
@@ -587,7 +775,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getfullyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getFullYear
     pub fn get_full_year(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.year() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.year as f64)
     }
 
     /// `Date.prototype.getHours()`
@@ -601,7 +789,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gethours
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getHours
     pub fn get_hours(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.hour() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.hours as f64)
     }
 
     /// `Date.prototype.getMilliseconds()`
@@ -615,8 +803,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmilliseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMilliseconds
     pub fn get_milliseconds(&self) -> f64 {
-        self.to_local()
-            .map_or(f64::NAN, |dt| dt.nanosecond() as f64 / NANOS_PER_MS as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.milliseconds as f64)
     }
 
     /// `Date.prototype.getMinutes()`
@@ -630,7 +817,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getminutes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMinutes
     pub fn get_minutes(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.minute() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.minutes as f64)
     }
 
     /// `Date.prototype.getMonth()`
@@ -645,7 +832,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmonth
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMonth
     pub fn get_month(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.month0() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.month as f64)
     }
 
     /// `Date.prototype.getSeconds()`
@@ -659,7 +846,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getSeconds
     pub fn get_seconds(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.second() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.seconds as f64)
     }
 
     /// `Date.prototype.getYear()`
@@ -674,8 +861,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getYear
     pub fn get_year(&self) -> f64 {
-        self.to_local()
-            .map_or(f64::NAN, |dt| dt.year() as f64 - 1900f64)
+        self.0.map_or(f64::NAN, |tv| tv.local.year as f64 - 1900f64)
     }
 
     /// `Date.prototype.getTime()`
@@ -689,8 +875,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gettime
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTime
     pub fn get_time(&self) -> f64 {
-        self.to_utc()
-            .map_or(f64::NAN, |dt| dt.timestamp_millis() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.time as f64)
     }
 
     /// `Date.prototype.getTimeZoneOffset()`
@@ -714,14 +899,10 @@ impl Date {
         let t = this_time_value(this, context)?;
 
         // 2. If t is NaN, return NaN.
-        if t.0.is_none() {
-            return Ok(JsValue::nan());
-        }
-
         // 3. Return (t - LocalTime(t)) / msPerMinute.
-        Ok(JsValue::new(
-            -Local::now().offset().local_minus_utc() as f64 / 60f64,
-        ))
+        Ok(t.0.map_or(JsValue::nan(), |tv| {
+            JsValue::new(-tv.offset as f64 / MILLIS_PER_MINUTE as f64)
+        }))
     }
 
     /// `Date.prototype.getUTCDate()`
@@ -735,7 +916,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcdate
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDate
     pub fn get_utc_date(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| dt.day() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.date as f64)
     }
 
     /// `Date.prototype.getUTCDay()`
@@ -750,11 +931,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcday
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDay
     pub fn get_utc_day(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| {
-            let weekday = dt.weekday() as u32;
-            let weekday = (weekday + 1) % 7; // 0 represents Monday in Chrono
-            weekday as f64
-        })
+        self.0.map_or(f64::NAN, |tv| tv.utc.week_day as f64)
     }
 
     /// `Date.prototype.getUTCFullYear()`
@@ -768,7 +945,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcfullyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCFullYear
     pub fn get_utc_full_year(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| dt.year() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.year as f64)
     }
 
     /// `Date.prototype.getUTCHours()`
@@ -782,7 +959,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutchours
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCHours
     pub fn get_utc_hours(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| dt.hour() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.hours as f64)
     }
 
     /// `Date.prototype.getUTCMilliseconds()`
@@ -796,8 +973,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcmilliseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMilliseconds
     pub fn get_utc_milliseconds(&self) -> f64 {
-        self.to_utc()
-            .map_or(f64::NAN, |dt| dt.nanosecond() as f64 / NANOS_PER_MS as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.milliseconds as f64)
     }
 
     /// `Date.prototype.getUTCMinutes()`
@@ -811,7 +987,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcminutes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMinutes
     pub fn get_utc_minutes(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| dt.minute() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.minutes as f64)
     }
 
     /// `Date.prototype.getUTCMonth()`
@@ -826,7 +1002,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcmonth
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMonth
     pub fn get_utc_month(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| dt.month0() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.month as f64)
     }
 
     /// `Date.prototype.getUTCSeconds()`
@@ -840,7 +1016,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getutcseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCSeconds
     pub fn get_utc_seconds(&self) -> f64 {
-        self.to_utc().map_or(f64::NAN, |dt| dt.second() as f64)
+        self.0.map_or(f64::NAN, |tv| tv.utc.seconds as f64)
     }
 
     /// `Date.prototype.setDate()`
@@ -899,10 +1075,7 @@ impl Date {
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
-            t.0 = NaiveDateTime::from_timestamp_opt(0, 0)
-                .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
-                .map(|local| local.naive_utc())
-                .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
+            t = Self::from_local(0.0);
         }
 
         // 3. Let y be ? ToNumber(year).
@@ -1188,14 +1361,11 @@ impl Date {
 
         // 2. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         if t.0.is_none() {
-            t.0 = NaiveDateTime::from_timestamp_opt(0, 0)
-                .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
-                .map(|local| local.naive_utc())
-                .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
+            t = Self::from_local(0.0);
         }
 
         // 3. Let y be ? ToNumber(year).
-        let mut y = args
+        let y = args
             .get(0)
             .cloned()
             .unwrap_or_default()
@@ -1213,13 +1383,11 @@ impl Date {
         // 5. Let yi be ! ToIntegerOrInfinity(y).
         // 6. If 0 ≤ yi ≤ 99, let yyyy be 1900𝔽 + 𝔽(yi).
         // 7. Else, let yyyy be y.
-        if (0f64..=99f64).contains(&y) {
-            y += 1900f64;
-        }
+        let yyyy = Self::full_year(y);
 
         // 8. Let d be MakeDay(yyyy, MonthFromTime(t), DateFromTime(t)).
         // 9. Let date be UTC(MakeDate(d, TimeWithinDay(t))).
-        t.set_components(false, Some(y), None, None, None, None, None, None);
+        t.set_components(false, Some(yyyy), None, None, None, None, None, None);
 
         // 10. Set the [[DateValue]] internal slot of this Date object to TimeClip(date).
         this.set_data(ObjectData::date(t));
@@ -1244,19 +1412,8 @@ impl Date {
         this_time_value(this, context)?;
 
         // 2. Let t be ? ToNumber(time).
-        let t = if let Some(t) = args.get(0) {
-            let t = t.to_number(context)?;
-            let seconds = (t / 1_000f64) as i64;
-            let nanoseconds = ((t % 1_000f64) * 1_000_000f64) as u32;
-            Date(
-                ignore_ambiguity(Local.timestamp_opt(seconds, nanoseconds))
-                    .map(|dt| dt.naive_utc()),
-            )
-        } else {
-            Date(None)
-        };
-
         // 3. Let v be TimeClip(t).
+        let t = Date::new(args.get_or_undefined(0).to_number(context)?);
         let v = t.get_time();
 
         // 4. Set the [[DateValue]] internal slot of this Date object to v.
@@ -1325,10 +1482,7 @@ impl Date {
 
         // 2. If t is NaN, set t to +0𝔽.
         if t.0.is_none() {
-            t.0 = NaiveDateTime::from_timestamp_opt(0, 0)
-                .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
-                .map(|local| local.naive_utc())
-                .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
+            t = Self::new(0.0);
         }
 
         // 3. Let y be ? ToNumber(year).
@@ -1638,7 +1792,7 @@ impl Date {
         // 4. Let t be LocalTime(tv).
         // 5. Return DateString(t).
         if let Some(t) = tv.0 {
-            Ok(t.format("%a %b %d %Y").to_string().into())
+            Ok(t.local.date_string().into())
         } else {
             Ok(JsString::from("Invalid Date").into())
         }
@@ -1677,12 +1831,26 @@ impl Date {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if let Some(t) = this_time_value(this, context)?.0 {
-            Ok(Utc::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%Y-%m-%dT%H:%M:%S.%3fZ")
-                .to_string()
-                .into())
+            let utc = t.utc;
+            let year = if (0..=9999).contains(&utc.year) {
+                format!("{:04}", utc.year)
+            } else if utc.year > 0 {
+                format!("+{:06}", utc.year)
+            } else {
+                format!("-{:06}", -utc.year)
+            };
+
+            Ok(format!(
+                "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+                year,
+                utc.month + 1,
+                utc.date,
+                utc.hours,
+                utc.minutes,
+                utc.seconds,
+                utc.milliseconds
+            )
+            .into())
         } else {
             context.throw_range_error("Invalid time value")
         }
@@ -1721,6 +1889,140 @@ impl Date {
         }
     }
 
+    /// `Date.prototype.toLocaleDateString()`
+    ///
+    /// The `toLocaleDateString()` method returns the date portion of a Date object in a language-sensitive
+    /// representation.
+    ///
+    /// With the `intl` feature, the date is formatted by an `Intl.DateTimeFormat` created from the `locales` and
+    /// `options` arguments. Without it, the format of the string is implementation-defined: it follows the
+    /// conventions of American English.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_locale_date_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::to_locale_format(this, args, LocaleFormat::Date, context)
+    }
+
+    /// `Date.prototype.toLocaleString()`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of this date.
+    ///
+    /// With the `intl` feature, the date is formatted by an `Intl.DateTimeFormat` created from the `locales` and
+    /// `options` arguments. Without it, the format of the string is implementation-defined: it follows the
+    /// conventions of American English.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::to_locale_format(this, args, LocaleFormat::DateTime, context)
+    }
+
+    /// `Date.prototype.toLocaleTimeString()`
+    ///
+    /// The `toLocaleTimeString()` method returns the time portion of a Date object in a language-sensitive
+    /// representation.
+    ///
+    /// With the `intl` feature, the date is formatted by an `Intl.DateTimeFormat` created from the `locales` and
+    /// `options` arguments. Without it, the format of the string is implementation-defined: it follows the
+    /// conventions of American English.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_locale_time_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::to_locale_format(this, args, LocaleFormat::Time, context)
+    }
+
+    /// Formats the local time of a Date object, or returns `"Invalid Date"` if its time value is `NaN`.
+    #[cfg(not(feature = "intl"))]
+    fn to_locale_format(
+        this: &JsValue,
+        _: &[JsValue],
+        format: LocaleFormat,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let format = match format {
+            LocaleFormat::DateTime => "%-m/%-d/%Y, %-I:%M:%S %p",
+            LocaleFormat::Date => "%-m/%-d/%Y",
+            LocaleFormat::Time => "%-I:%M:%S %p",
+        };
+        let tv = this_time_value(this, context)?;
+        if let Some(t) = tv.to_local() {
+            Ok(t.format(format).to_string().into())
+        } else {
+            Ok(JsString::from("Invalid Date").into())
+        }
+    }
+
+    /// Formats a Date object with an `Intl.DateTimeFormat`, or returns `"Invalid Date"` if its time value is `NaN`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
+    #[cfg(feature = "intl")]
+    fn to_locale_format(
+        this: &JsValue,
+        args: &[JsValue],
+        format: LocaleFormat,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisTimeValue(this value).
+        let x = this_time_value(this, context)?.get_time();
+
+        // 2. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(JsString::from("Invalid Date").into());
+        }
+
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, required, defaults).
+        let (required, defaults) = match format {
+            LocaleFormat::DateTime => (Required::Any, Defaults::All),
+            LocaleFormat::Date => (Required::Date, Defaults::Date),
+            LocaleFormat::Time => (Required::Time, Defaults::Time),
+        };
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            required,
+            defaults,
+            context,
+        )?;
+
+        // 4. Return ! FormatDateTime(dateFormat, x).
+        match date_format.format(x) {
+            Some(formatted) => Ok(formatted.into()),
+            None => context.throw_range_error("Invalid time value"),
+        }
+    }
+
     /// `Date.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Date object.
@@ -1738,12 +2040,13 @@ impl Date {
 
         // 2. Return ToDateString(tv).
         if let Some(t) = tv.0 {
-            Ok(Local::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%a %b %d %Y %H:%M:%S GMT%z")
-                .to_string()
-                .into())
+            Ok(format!(
+                "{} {}{}",
+                t.local.date_string(),
+                t.local.time_string(),
+                t.time_zone_string()
+            )
+            .into())
         } else {
             Ok(JsString::from("Invalid Date").into())
         }
@@ -1774,12 +2077,7 @@ impl Date {
         // 4. Let t be LocalTime(tv).
         // 5. Return the string-concatenation of TimeString(t) and TimeZoneString(tv).
         if let Some(t) = tv.0 {
-            Ok(Local::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%H:%M:%S GMT%z")
-                .to_string()
-                .into())
+            Ok(format!("{}{}", t.local.time_string(), t.time_zone_string()).into())
         } else {
             Ok(JsString::from("Invalid Date").into())
         }
@@ -1796,9 +2094,22 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toutcstring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toUTCString
     pub fn to_utc_string(self) -> String {
-        self.to_utc()
-            .map(|date_time| date_time.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
-            .unwrap_or_else(|| "Invalid Date".to_string())
+        self.0.map_or_else(
+            || "Invalid Date".to_string(),
+            |tv| {
+                let utc = tv.utc;
+                format!(
+                    "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+                    WEEK_DAYS[utc.week_day as usize],
+                    utc.date,
+                    MONTHS[utc.month as usize],
+                    utc.padded_year(),
+                    utc.hours,
+                    utc.minutes,
+                    utc.seconds
+                )
+            },
+        )
     }
 
     /// `Date.prototype.valueOf()`
@@ -1850,7 +2161,9 @@ impl Date {
         }
 
         match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(JsValue::new(v.naive_utc().timestamp_millis() as f64)),
+            Ok(v) => Ok(JsValue::new(
+                Self::new(v.timestamp_millis() as f64).get_time(),
+            )),
             _ => Ok(JsValue::new(f64::NAN)),
         }
     }
@@ -1888,28 +2201,20 @@ impl Date {
             .get(6)
             .map_or(Ok(0f64), |value| value.to_number(context))?;
 
-        if !check_normal_opt!(year, month, day, hour, min, sec, milli) {
-            return Ok(JsValue::nan());
-        }
-
-        let year = year as i32;
-        let month = month as u32;
-        let day = day as u32;
-        let hour = hour as u32;
-        let min = min as u32;
-        let sec = sec as u32;
-        let milli = milli as u32;
+        // 8. If y is NaN, let yr be NaN.
+        // 9. Else,
+        //     a. Let yi be ! ToIntegerOrInfinity(y).
+        //     b. If 0 ≤ yi ≤ 99, let yr be 1900𝔽 + 𝔽(yi); otherwise, let yr be y.
+        let yr = Self::full_year(year);
 
-        let year = if (0..=99).contains(&year) {
-            1900 + year
-        } else {
-            year
-        };
-
-        NaiveDate::from_ymd_opt(year, month + 1, day)
-            .and_then(|f| f.and_hms_milli_opt(hour, min, sec, milli))
-            .and_then(|f| Self::time_clip(f.timestamp_millis() as f64))
-            .map_or(Ok(JsValue::nan()), |time| Ok(JsValue::new(time)))
+        // 10. Return TimeClip(MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli))).
+        Ok(JsValue::new(
+            Self::new(make_date(
+                make_day(yr, month, day),
+                make_time(hour, min, sec, milli),
+            ))
+            .get_time(),
+        ))
     }
 }
 
@@ -15,7 +15,7 @@ fn forward_dt_utc(context: &mut Context, src: &str) -> Option<NaiveDateTime> {
 
     if let JsValue::Object(ref date_time) = date_time {
         if let Some(date_time) = date_time.borrow().as_date() {
-            date_time.0
+            date_time.to_utc().map(|dt| dt.naive_utc())
         } else {
             panic!("expected date")
         }
@@ -419,8 +419,12 @@ fn date_proto_get_timezone_offset() -> Result<(), Box<dyn std::error::Error>> {
         "new Date('1975-08-19T23:15:30+07:00').getTimezoneOffset()",
     );
 
-    // The value of now().offset() depends on the host machine, so we have to replicate the method code here.
-    let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
+    // The offset depends on the host machine, so we have to replicate the method code here. It is the offset at the
+    // time of the date, not the current one.
+    let offset_seconds = chrono::Local
+        .from_utc_datetime(&NaiveDate::from_ymd(1975, 8, 19).and_hms(16, 15, 30))
+        .offset()
+        .local_minus_utc() as f64;
     let offset_minutes = -offset_seconds / 60f64;
     assert_eq!(Ok(JsValue::new(offset_minutes)), actual);
 
@@ -1291,6 +1295,34 @@ fn date_proto_to_string() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_proto_to_locale_string() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "let dt = new Date(2020, 06, 08, 19, 06, 05, 779);",
+    );
+    assert_eq!(
+        forward(&mut context, "dt.toLocaleString()"),
+        "\"7/8/2020, 7:06:05 PM\""
+    );
+    assert_eq!(
+        forward(&mut context, "dt.toLocaleDateString()"),
+        "\"7/8/2020\""
+    );
+    assert_eq!(
+        forward(&mut context, "dt.toLocaleTimeString()"),
+        "\"7:06:05 PM\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(NaN).toLocaleString()"),
+        "\"Invalid Date\""
+    );
+
+    Ok(())
+}
+
 #[test]
 fn date_proto_to_time_string() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -1377,3 +1409,65 @@ fn date_json() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn date_time_clip() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new Date(8.64e15).getTime()"),
+        "8640000000000000"
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(8.64e15 + 1).getTime()"),
+        "NaN"
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(-8.64e15).getTime()"),
+        "-8640000000000000"
+    );
+    assert_eq!(forward(&mut context, "new Date(1.9).getTime()"), "1");
+    assert_eq!(forward(&mut context, "new Date(-1.9).getTime()"), "-1");
+    assert_eq!(forward(&mut context, "new Date(Infinity).getTime()"), "NaN");
+    assert_eq!(forward(&mut context, "new Date(0).setTime()"), "NaN");
+    assert_eq!(
+        forward(&mut context, "new Date(8.64e15).toISOString()"),
+        "\"+275760-09-13T00:00:00.000Z\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(-8.64e15).toUTCString()"),
+        "\"Tue, 20 Apr -271821 00:00:00 GMT\""
+    );
+    assert_eq!(
+        forward(&mut context, "Date.UTC(275760, 8, 13, 0, 0, 0, 1)"),
+        "NaN"
+    );
+    assert_eq!(forward(&mut context, "Date.UTC(99.5)"), "915148800000");
+}
+
+#[test]
+fn date_nan_components() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var d = new Date(NaN);");
+
+    assert_eq!(forward(&mut context, "d.setDate(1)"), "NaN");
+    assert_eq!(forward(&mut context, "d.setUTCHours(1)"), "NaN");
+    assert_eq!(forward(&mut context, "d.getDay()"), "NaN");
+    assert_eq!(forward(&mut context, "d.getTimezoneOffset()"), "NaN");
+    assert_eq!(
+        forward(&mut context, "d.setUTCFullYear(2000)"),
+        "946684800000"
+    );
+    assert_eq!(forward(&mut context, "d.setUTCMinutes(NaN)"), "NaN");
+    assert_eq!(
+        forward(&mut context, "d.setUTCFullYear(2000, 1, 29)"),
+        "951782400000"
+    );
+    assert_eq!(forward(&mut context, "d.setUTCMonth(-1)"), "946425600000");
+    assert_eq!(forward(&mut context, "d.getUTCFullYear()"), "1999");
+    assert_eq!(forward(&mut context, "d.getUTCMonth()"), "11");
+    assert_eq!(forward(&mut context, "d.setUTCDate(0)"), "943920000000");
+    assert_eq!(forward(&mut context, "d.getUTCDate()"), "30");
+    assert_eq!(forward(&mut context, "d.getUTCDay()"), "2");
+}
@@ -0,0 +1,142 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! Represents several errors wrapped in a single error, like the rejection reasons of the
+//! promises passed to `Promise.any()`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{error::capture_stack_trace, iterable::iterable_to_list, Array, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    profiler::BoaProfiler,
+    property::{Attribute, PropertyDescriptor},
+    value::ArgumentsExt,
+    Context, JsResult, JsValue,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::builtin();
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        (Self::NAME, aggregate_error_object.into(), Self::attribute())
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `AggregateError( errors, message )`
+    ///
+    /// Create a new aggregate error object.
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .aggregate_error_object()
+                    .prototype()
+            });
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let this = JsValue::new(obj);
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(context)?, false, context)?;
+            }
+        }
+
+        let errors = iterable_to_list(context, args.get_or_undefined(0).clone())?;
+        Self::set_errors(&this, errors, context);
+
+        // This value is used by console.log and other routines to match Object type
+        // to its Javascript Identifier (global constructor method name)
+        this.set_data(ObjectData::error());
+        capture_stack_trace(&this, context)?;
+        Ok(this)
+    }
+
+    /// Creates a new aggregate error object from a list of errors, without iterating them.
+    pub(crate) fn create(
+        errors: Vec<JsValue>,
+        message: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let obj = context.construct_object();
+        obj.set_prototype_instance(
+            context
+                .standard_objects()
+                .aggregate_error_object()
+                .prototype()
+                .into(),
+        );
+        obj.insert_property(
+            "message",
+            PropertyDescriptor::builder()
+                .value(message)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+        let this = JsValue::new(obj);
+        Self::set_errors(&this, errors, context);
+        this.set_data(ObjectData::error());
+        capture_stack_trace(&this, context)?;
+        Ok(this)
+    }
+
+    /// Defines the `errors` property of an aggregate error.
+    fn set_errors(this: &JsValue, errors: Vec<JsValue>, context: &mut Context) {
+        let errors = Array::create_array_from_list(errors, context);
+        if let Some(object) = this.as_object() {
+            object.insert_property(
+                "errors",
+                PropertyDescriptor::builder()
+                    .value(errors)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+            );
+        }
+    }
+}
@@ -13,7 +13,7 @@
 
 use crate::object::PROTOTYPE;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{error::capture_stack_trace, BuiltIn},
     object::{ConstructorBuilder, ObjectData},
     profiler::BoaProfiler,
     property::Attribute,
@@ -28,14 +28,14 @@ impl BuiltIn for EvalError {
     const NAME: &'static str = "EvalError";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let error_prototype = context.standard_objects().error_object().prototype();
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let attribute = Attribute::builtin();
         let eval_error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -83,6 +83,7 @@ impl EvalError {
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
         this.set_data(ObjectData::error());
+        capture_stack_trace(&this, context)?;
         Ok(this)
     }
 }
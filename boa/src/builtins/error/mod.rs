@@ -12,12 +12,13 @@
 
 use crate::{
     builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectData, PROTOTYPE},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     Context, JsResult, JsValue,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -28,6 +29,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -43,13 +45,13 @@ impl BuiltIn for Error {
     const NAME: &'static str = "Error";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let attribute = Attribute::builtin();
         let error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -99,6 +101,7 @@ impl Error {
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
         this.set_data(ObjectData::error());
+        capture_stack_trace(&this, context)?;
         Ok(this)
     }
 
@@ -148,3 +151,127 @@ impl Error {
         }
     }
 }
+
+/// Creates a host-defined error constructor named `name`, in the style of the `NativeError`
+/// constructors.
+///
+/// The new constructor inherits from `parent`, and its `prototype` inherits from
+/// `parent.prototype`, so instances are catchable as any of their ancestor error types.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-nativeerror-object-structure
+pub(crate) fn create_error_class(
+    name: &str,
+    parent: &JsObject,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    if !parent.is_constructable() {
+        return Err(context.construct_type_error(format!(
+            "cannot create error class {}: parent is not a constructor",
+            name
+        )));
+    }
+    let parent_prototype = parent.get(PROTOTYPE, context)?.as_object().ok_or_else(|| {
+        context.construct_type_error(format!(
+            "cannot create error class {}: parent has no prototype object",
+            name
+        ))
+    })?;
+
+    let prototype = context.construct_object();
+    prototype.set_prototype_instance(parent_prototype.into());
+    prototype.insert_property(
+        "name",
+        PropertyDescriptor::builder()
+            .value(name)
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+    prototype.insert_property(
+        "message",
+        PropertyDescriptor::builder()
+            .value("")
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    let constructor = FunctionBuilder::closure_with_captures(
+        context,
+        |new_target, args, prototype, context| {
+            let prototype = prototype
+                .downcast_ref::<JsObject>()
+                .expect("error classes capture their prototype")
+                .clone();
+            let prototype = new_target
+                .as_object()
+                .and_then(|obj| {
+                    obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                        .map(|o| o.as_object())
+                        .transpose()
+                })
+                .transpose()?
+                .unwrap_or(prototype);
+            let obj = context.construct_object();
+            obj.set_prototype_instance(prototype.into());
+            let this = JsValue::new(obj);
+            if let Some(message) = args.get(0) {
+                if !message.is_undefined() {
+                    this.set_field("message", message.to_string(context)?, false, context)?;
+                }
+            }
+
+            this.set_data(ObjectData::error());
+            capture_stack_trace(&this, context)?;
+            Ok(this)
+        },
+        prototype.clone(),
+    )
+    .name(name)
+    .length(Error::LENGTH)
+    .constructable(true)
+    .build();
+    constructor.set_prototype_instance(parent.clone().into());
+    constructor.insert_property(
+        PROTOTYPE,
+        PropertyDescriptor::builder()
+            .value(prototype.clone())
+            .writable(false)
+            .enumerable(false)
+            .configurable(false),
+    );
+    prototype.insert_property(
+        "constructor",
+        PropertyDescriptor::builder()
+            .value(constructor.clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    Ok(constructor)
+}
+
+/// Records the current call stack in the `stack` property of a newly constructed error object.
+///
+/// The trace starts with the result of `Error.prototype.toString()` on the error, followed by a
+/// line for every active function call, innermost first. The frame of the error constructor
+/// itself is not part of the trace.
+pub(crate) fn capture_stack_trace(error: &JsValue, context: &mut Context) -> JsResult<()> {
+    let header = Error::to_string(error, &[], context)?.to_string(context)?;
+    let frames = context.executor().format_call_stack(1);
+    if let Some(object) = error.as_object() {
+        object.insert_property(
+            "stack",
+            PropertyDescriptor::builder()
+                .value(format!("{}{}", header, frames))
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+    }
+    Ok(())
+}
@@ -84,3 +84,132 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn error_stack() {
+    let mut context = Context::new();
+    let init = r#"
+        function inner() {
+            return new TypeError('oops');
+        }
+        function outer() {
+            return inner();
+        }
+        let nested = outer();
+        let top = new Error('top');
+        let anonymous = (() => new RangeError())();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "nested.stack"),
+        "\"TypeError: oops\n    at inner\n    at outer\""
+    );
+    assert_eq!(forward(&mut context, "top.stack"), "\"Error: top\"");
+    assert_eq!(
+        forward(&mut context, "anonymous.stack"),
+        "\"RangeError\n    at <anonymous>\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(nested).includes('stack')"),
+        "false"
+    );
+}
+
+#[test]
+fn error_stack_of_native_errors() {
+    let mut context = Context::new();
+    let init = r#"
+        let error;
+        function thrower() {
+            null.prop;
+        }
+        try {
+            thrower();
+        } catch (e) {
+            error = e;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error.stack.split('\\n')[1]"),
+        "\"    at thrower\""
+    );
+}
+
+#[test]
+fn register_error_class() {
+    let mut context = Context::new();
+    let app_error = context.register_error_class("AppError", None).unwrap();
+    let type_error = context.standard_objects().type_error_object().constructor();
+    context
+        .register_error_class("NetworkError", Some(&app_error))
+        .unwrap();
+    context
+        .register_error_class("InputError", Some(&type_error))
+        .unwrap();
+
+    forward(
+        &mut context,
+        r#"
+        var caught;
+        try {
+            throw new NetworkError('offline');
+        } catch (e) {
+            caught = e;
+        }
+        "#,
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "caught instanceof NetworkError && caught instanceof AppError && caught instanceof Error"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "caught.toString()"),
+        "\"NetworkError: offline\""
+    );
+    assert_eq!(
+        forward(&mut context, "caught.stack.split('\\n')[0]"),
+        "\"NetworkError: offline\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(NetworkError) === AppError && NetworkError.prototype.constructor === NetworkError"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[NetworkError.name, NetworkError.length].join()"
+        ),
+        "\"NetworkError,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var e = new InputError(); e instanceof TypeError && e.toString()"
+        ),
+        "\"InputError\""
+    );
+}
+
+#[test]
+fn register_error_class_with_invalid_parent() {
+    let mut context = Context::new();
+    let parent = context.construct_object();
+
+    let error = context
+        .register_error_class("BadError", Some(&parent))
+        .unwrap_err();
+    assert!(error
+        .as_object()
+        .map_or(false, |error| error.borrow().is_error()));
+    assert_eq!(
+        forward(&mut context, "typeof globalThis.BadError"),
+        "\"undefined\""
+    );
+}
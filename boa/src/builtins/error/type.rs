@@ -16,7 +16,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{error::capture_stack_trace, BuiltIn},
     object::{ConstructorBuilder, ObjectData, PROTOTYPE},
     property::Attribute,
     BoaProfiler, Context, JsResult, JsValue,
@@ -30,14 +30,14 @@ impl BuiltIn for TypeError {
     const NAME: &'static str = "TypeError";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let error_prototype = context.standard_objects().error_object().prototype();
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let attribute = Attribute::builtin();
         let type_error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -85,6 +85,7 @@ impl TypeError {
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
         this.set_data(ObjectData::error());
+        capture_stack_trace(&this, context)?;
         Ok(this)
     }
 }
@@ -0,0 +1,218 @@
+//! This module implements the global `eval` function.
+//!
+//! The `eval()` function evaluates JavaScript code represented as a string.
+//!
+//! A call to `eval` through the plain identifier `eval` is a direct eval, which evaluates the
+//! code in the environment and with the strictness of the caller. Any other call is an indirect
+//! eval, which evaluates the code in the global environment.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::BuiltIn,
+    environment::{
+        eval_environment_record::EvalEnvironmentRecord,
+        lexical_environment::{Environment, EnvironmentType},
+    },
+    exec::instantiate_lexical_declarations,
+    object::FunctionBuilder,
+    policy::SourceKind,
+    property::Attribute,
+    syntax::{ast::node::StatementList, Parser},
+    value::ArgumentsExt,
+    BoaProfiler, Context, Executable, JsResult, JsString, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `eval` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl BuiltIn for Eval {
+    const NAME: &'static str = "eval";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let eval = FunctionBuilder::native(context, Self::eval)
+            .name(Self::NAME)
+            .length(1)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, eval.into(), Self::attribute())
+    }
+}
+
+impl Eval {
+    /// `eval ( x )`
+    ///
+    /// Evaluates the given string as a script in the global environment, and returns its
+    /// completion value. Any other value is returned unchanged.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-eval-x
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+    fn eval(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? PerformEval(x, false, false).
+        Self::perform_eval(args.get_or_undefined(0), false, false, context)
+    }
+
+    /// `PerformEval ( x, strictCaller, direct )`
+    ///
+    /// Evaluates the given string as a script, and returns its completion value. Any other value
+    /// is returned unchanged.
+    ///
+    /// A direct eval runs in a new environment on top of the running one, and is strict mode code
+    /// if its caller is. An indirect eval runs in a new environment on top of the global one.
+    /// Unless the script is strict mode code, its `var` and function declarations are bound in
+    /// the variable environment of the caller, or in the global environment respectively.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform_eval(
+        x: &JsValue,
+        strict_caller: bool,
+        direct: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: If direct is false, then strictCaller is also false.
+        debug_assert!(direct || !strict_caller);
+
+        // 2. If Type(x) is not String, return x.
+        let source = match x {
+            JsValue::String(source) => source.clone(),
+            x => return Ok(x.clone()),
+        };
+
+        // 3-4. Perform ? HostEnsureCanCompileStrings(callerRealm, evalRealm).
+        let origin = context.get_active_module_key();
+        context.check_source(
+            SourceKind::Eval,
+            &source,
+            origin.as_ref().map(JsString::as_str),
+        )?;
+
+        // 11. Let script be ParseText(! StringToCodePoints(x), Script).
+        // 12. If script is a List of errors, throw a SyntaxError exception.
+        let script = Parser::new(source.as_bytes(), strict_caller)
+            .parse_all()
+            .map_err(|e| context.construct_parse_error(&e))?;
+
+        // 16. If strictCaller is true, let strictEval be true.
+        // 17. Else, let strictEval be IsStrict of script.
+        let strict_eval = strict_caller || script.strict();
+
+        // 18. Let runningContext be the running execution context.
+        // 19. If direct is true, then
+        //     a. Let lexEnv be NewDeclarativeEnvironment(runningContext's LexicalEnvironment).
+        //     b. Let varEnv be runningContext's VariableEnvironment.
+        // 20. Else,
+        //     a. Let lexEnv be NewDeclarativeEnvironment(evalRealm.[[GlobalEnv]]).
+        //     b. Let varEnv be evalRealm.[[GlobalEnv]].
+        // 21. If strictEval is true, set varEnv to lexEnv.
+        let outer = if direct {
+            context.get_current_environment()
+        } else {
+            context.get_global_environment()
+        };
+
+        // 25. Let result be EvalDeclarationInstantiation(body, varEnv, lexEnv, privateEnv, strictEval).
+        if !strict_eval {
+            check_var_declarations(&script, &outer, context)?;
+        }
+        context.push_environment(EvalEnvironmentRecord::new(Some(outer), strict_eval));
+
+        // 26. If result.[[Type]] is normal, then
+        //     a. Set result to the result of evaluating body.
+        let result = instantiate_lexical_declarations(script.items(), context)
+            .and_then(|()| script.run(context));
+        context.pop_environment();
+
+        // 27-28. Return result.
+        result
+    }
+}
+
+/// Throws a `SyntaxError` if a `var` or function declaration of non-strict eval code would be
+/// bound in the variable environment beyond a lexical declaration of the same name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-evaldeclarationinstantiation
+fn check_var_declarations(
+    body: &StatementList,
+    outer: &Environment,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. Let varNames be the VarDeclaredNames of body.
+    let var_names = body
+        .var_declared_names()
+        .into_iter()
+        .chain(body.function_declared_names());
+
+    // 3. If strict is false, then
+    for name in var_names {
+        // b. Let thisEnv be lexEnv.[[OuterEnv]].
+        // d. Repeat, while thisEnv is not the same as varEnv,
+        let mut this_env = Some(outer.clone());
+        while let Some(env) = this_env {
+            match env.get_environment_type() {
+                // a. If varEnv is a global Environment Record, then
+                //     i. For each element name of varNames, do
+                //         1. If varEnv.HasLexicalDeclaration(name) is true, throw a SyntaxError
+                //            exception.
+                EnvironmentType::Global => {
+                    if env
+                        .as_global_record()
+                        .map_or(false, |global| global.has_lexical_declaration(name))
+                    {
+                        return Err(context.construct_syntax_error(format!(
+                            "redeclaration of lexical binding {}",
+                            name
+                        )));
+                    }
+                    break;
+                }
+                EnvironmentType::Function | EnvironmentType::Module => break,
+                // i. If thisEnv is not an object Environment Record, then
+                //     1. NOTE: The environment of with statements cannot contain any lexical
+                //        declaration so it doesn't need to be checked for var/let hoisting
+                //        conflicts.
+                EnvironmentType::Object => {}
+                //     2. For each element name of varNames, do
+                //         a. If thisEnv.HasBinding(name) is true, then
+                //             i. Throw a SyntaxError exception.
+                EnvironmentType::Declarative => {
+                    if env.has_binding(name) {
+                        return Err(context.construct_syntax_error(format!(
+                            "redeclaration of lexical binding {}",
+                            name
+                        )));
+                    }
+                }
+            }
+            // ii. Set thisEnv to thisEnv.[[OuterEnv]].
+            this_env = env.get_outer_environment();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::{forward, Context};
+
+#[test]
+fn eval_strings() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "eval('1 + 2')"), "3");
+    assert_eq!(forward(&mut context, "eval(42)"), "42");
+    assert_eq!(forward(&mut context, "eval()"), "undefined");
+    assert_eq!(
+        forward(&mut context, "eval('var evaluated = 5; evaluated * 2')"),
+        "10"
+    );
+    assert_eq!(forward(&mut context, "evaluated"), "5");
+    assert_eq!(
+        forward(&mut context, "eval('1 +')"),
+        "Uncaught \"SyntaxError\": \"Syntax Error: abrupt end - could not preview next value as part of the operator at position: 1:4\""
+    );
+}
+
+#[test]
+fn indirect_eval_runs_in_global_environment() {
+    let mut context = Context::new();
+    let scenario = r#"
+        var x = 'global';
+        var indirect = eval;
+        function f(x) {
+            return [(0, eval)('x'), indirect('x')].join();
+        }
+        f('local')
+    "#;
+    assert_eq!(forward(&mut context, scenario), "\"global,global\"");
+    assert_eq!(
+        forward(&mut context, "(0, eval)('var declared = 1'); declared"),
+        "1"
+    );
+}
+
+#[test]
+fn direct_eval_runs_in_caller_environment() {
+    let mut context = Context::new();
+    let scenario = r#"
+        var x = 'global';
+        var obj = {
+            f(x) {
+                return [eval('x'), eval('this') === obj, eval('arguments.length')].join();
+            }
+        };
+        obj.f('local')
+    "#;
+    assert_eq!(forward(&mut context, scenario), "\"local,true,1\"");
+}
+
+#[test]
+fn direct_eval_declarations() {
+    let mut context = Context::new();
+    let init = r#"
+        function defined(f) {
+            try { f(); return true; } catch (e) { return false; }
+        }
+        function sloppy() {
+            eval('var v = 1; function h() { return 2; } let l = 3;');
+            return [v, h(), defined(() => l)].join();
+        }
+        function strictCaller() {
+            'use strict';
+            eval('var v = 1');
+            return defined(() => v);
+        }
+        function strictEval() {
+            eval('"use strict"; var v = 1');
+            return defined(() => v);
+        }
+        function conflict() {
+            {
+                let v = 1;
+                try { eval('var v = 2'); } catch (e) { return e.name; }
+            }
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sloppy()"), "\"1,2,false\"");
+    assert_eq!(forward(&mut context, "defined(() => v)"), "false");
+    assert_eq!(forward(&mut context, "strictCaller()"), "false");
+    assert_eq!(forward(&mut context, "strictEval()"), "false");
+    assert_eq!(forward(&mut context, "conflict()"), "\"SyntaxError\"");
+}
+
+#[test]
+fn direct_eval_inherits_strict_mode() {
+    let mut context = Context::new();
+    let scenario = r#"
+        function f() {
+            'use strict';
+            try { eval('with ({}) {}'); } catch (e) { return e.name; }
+        }
+        f()
+    "#;
+    assert_eq!(forward(&mut context, scenario), "\"SyntaxError\"");
+}
@@ -0,0 +1,352 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` calls a cleanup callback with the held value of each registered
+//! object that gets garbage collected. The callbacks are run as jobs, which are enqueued by
+//! [`Context::process_finalization`] once the collector has freed the registered objects.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    job::NativeJob,
+    object::{ConstructorBuilder, JsObject, ObjectData, WeakJsObject, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    teardown::Contents,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal representation of a `FinalizationRegistry` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup_callback: JsObject,
+    cells: Vec<RegistryCell>,
+    /// Whether a cleanup job for the registry is waiting in the job queue.
+    #[unsafe_ignore_trace]
+    cleanup_pending: bool,
+}
+
+/// A `Record` of the `[[Cells]]` of a `FinalizationRegistry`.
+#[derive(Debug, Trace, Finalize)]
+struct RegistryCell {
+    target: WeakJsObject,
+    held_value: JsValue,
+    unregister_token: Option<WeakJsObject>,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let finalization_registry_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context
+                .standard_objects()
+                .finalization_registry_object()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::register, "register", 2)
+        .method(Self::unregister, "unregister", 1)
+        .build();
+
+        (
+            Self::NAME,
+            finalization_registry_object.into(),
+            Self::attribute(),
+        )
+    }
+}
+
+impl FinalizationRegistry {
+    /// The amount of arguments the `FinalizationRegistry` constructor takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Queues the cleanup callback and the held values to be emptied by the teardown of the
+    /// context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_object(&self.cleanup_callback);
+        for cell in &self.cells {
+            contents.push_value(&cell.held_value);
+        }
+    }
+
+    /// `FinalizationRegistry ( cleanupCallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin FinalizationRegistry constructor without new is forbidden",
+            );
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = match args.get_or_undefined(0) {
+            JsValue::Object(callback) if callback.is_callable() => callback.clone(),
+            _ => {
+                return context
+                    .throw_type_error("FinalizationRegistry cleanup callback is not a function")
+            }
+        };
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let registry_prototype = context
+            .standard_objects()
+            .finalization_registry_object()
+            .prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(registry_prototype);
+
+        let registry = context.construct_object();
+        registry.set_prototype_instance(prototype.into());
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        registry.borrow_mut().data = ObjectData::finalization_registry(Self {
+            cleanup_callback,
+            cells: Vec::new(),
+            cleanup_pending: false,
+        });
+        context.register_finalization_registry(&registry);
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+
+    /// `FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = Self::this_registry(this, "register", context)?;
+
+        // 3. If Type(target) is not Object, throw a TypeError exception.
+        let target = match args.get_or_undefined(0) {
+            JsValue::Object(target) => target,
+            _ => return context.throw_type_error("FinalizationRegistry target must be an object"),
+        };
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        let held_value = args.get_or_undefined(1);
+        if matches!(held_value, JsValue::Object(held) if JsObject::equals(held, target)) {
+            return context.throw_type_error(
+                "FinalizationRegistry target and held value must not be the same",
+            );
+        }
+
+        // 5. If Type(unregisterToken) is not Object, then
+        let unregister_token = match args.get_or_undefined(2) {
+            JsValue::Object(token) => Some(token.downgrade()),
+            // b. Set unregisterToken to empty.
+            JsValue::Undefined => None,
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            _ => {
+                return context
+                    .throw_type_error("FinalizationRegistry unregister token must be an object")
+            }
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        let cell = RegistryCell {
+            target: target.downgrade(),
+            held_value: held_value.clone(),
+            unregister_token,
+        };
+
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry
+            .borrow_mut()
+            .as_finalization_registry_mut()
+            .expect("checked to be a FinalizationRegistry")
+            .cells
+            .push(cell);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister ( unregisterToken )`
+    ///
+    /// Removes the cells registered with the token, returning `true` if there were any.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = Self::this_registry(this, "unregister", context)?;
+
+        // 3. If Type(unregisterToken) is not Object, throw a TypeError exception.
+        let token = match args.get_or_undefined(0) {
+            JsValue::Object(token) => token,
+            _ => {
+                return context
+                    .throw_type_error("FinalizationRegistry unregister token must be an object")
+            }
+        };
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let mut registry = registry.borrow_mut();
+        let cells = &mut registry
+            .as_finalization_registry_mut()
+            .expect("checked to be a FinalizationRegistry")
+            .cells;
+        let len = cells.len();
+        cells.retain(|cell| {
+            !matches!(&cell.unregister_token, Some(cell_token) if cell_token.points_to(token))
+        });
+
+        // 6. Return removed.
+        Ok((cells.len() != len).into())
+    }
+
+    /// Enqueues a cleanup job for the registry if some of its targets have been collected, and
+    /// no cleanup job is already waiting.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn enqueue_cleanup(registry: &JsObject, context: &mut Context) {
+        {
+            let mut registry = registry.borrow_mut();
+            let registry = registry
+                .as_finalization_registry_mut()
+                .expect("only FinalizationRegistry objects are registered on the context");
+            if registry.cleanup_pending || registry.cells.iter().all(|c| c.target.is_alive()) {
+                return;
+            }
+            registry.cleanup_pending = true;
+        }
+
+        let registry = registry.clone();
+        context.enqueue_job(NativeJob::new(move |context| {
+            Self::cleanup(&registry, context)
+        }));
+    }
+
+    /// `CleanupFinalizationRegistry ( finalizationRegistry )`
+    ///
+    /// Calls the cleanup callback with the held value of each cell whose target was collected,
+    /// removing the cell. If the callback throws, the remaining cells are left for the next
+    /// cleanup.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(registry: &JsObject, context: &mut Context) -> JsResult<JsValue> {
+        registry
+            .borrow_mut()
+            .as_finalization_registry_mut()
+            .expect("cleanup jobs are only enqueued for FinalizationRegistry objects")
+            .cleanup_pending = false;
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, do
+        loop {
+            let (callback, held_value) = {
+                let mut registry = registry.borrow_mut();
+                let registry = registry
+                    .as_finalization_registry_mut()
+                    .expect("cleanup jobs are only enqueued for FinalizationRegistry objects");
+                // a. Choose any such cell.
+                // b. Remove cell from finalizationRegistry.[[Cells]].
+                match registry.cells.iter().position(|c| !c.target.is_alive()) {
+                    Some(index) => (
+                        registry.cleanup_callback.clone(),
+                        registry.cells.remove(index).held_value.clone(),
+                    ),
+                    None => break,
+                }
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call(&JsValue::undefined(), &[held_value], context)?;
+        }
+
+        // 4. Return NormalCompletion(empty).
+        Ok(JsValue::undefined())
+    }
+
+    /// Returns the `FinalizationRegistry` object `this`, or throws a `TypeError` naming `method`.
+    fn this_registry<'a>(
+        this: &'a JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<&'a JsObject> {
+        match this {
+            JsValue::Object(registry) if registry.borrow().as_finalization_registry().is_some() => {
+                Ok(registry)
+            }
+            _ => Err(context.construct_type_error(format!(
+                "FinalizationRegistry.prototype.{} called on a non-FinalizationRegistry",
+                method
+            ))),
+        }
+    }
+}
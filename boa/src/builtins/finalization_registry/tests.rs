@@ -0,0 +1,102 @@
+use crate::{forward, Context};
+
+#[test]
+fn cleanup_callback() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry(held => cleaned.push(held));
+        var kept = {};
+        registry.register(kept, "kept");
+        (() => {
+            registry.register({}, "first");
+            registry.register({}, "second");
+        })();
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+
+    // The callbacks run as jobs, after the collection.
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "cleaned.length"), "0");
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "cleaned.sort().join()"),
+        "\"first,second\""
+    );
+
+    forward(&mut context, "kept = undefined;");
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "cleaned.join()"),
+        "\"first,second,kept\""
+    );
+}
+
+#[test]
+fn run_jobs_processes_finalization() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = false;
+        var registry = new FinalizationRegistry(() => cleaned = true);
+        registry.register({}, 0);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned"), "true");
+}
+
+#[test]
+fn unregister() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry(held => cleaned.push(held));
+        var token = {};
+        registry.register({}, "unregistered", token);
+        registry.register({}, "registered");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "false");
+
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.join()"), "\"registered\"");
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "var registry = new FinalizationRegistry(() => {});",
+    );
+
+    let cases = [
+        "FinalizationRegistry(() => {})",
+        "new FinalizationRegistry(1)",
+        "registry.register(1, 'held')",
+        "var o = {}; registry.register(o, o)",
+        "registry.register({}, 'held', 1)",
+        "registry.unregister(1)",
+        "FinalizationRegistry.prototype.register.call({}, {}, 'held')",
+    ];
+    for case in cases {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {} }} catch (e) {{ e.name }}", case)
+            ),
+            "\"TypeError\"",
+            "{}",
+            case
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! This module implements the arguments objects of ECMAScript function calls.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/arguments
+
+use crate::{
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{JsObject, Object, ObjectData},
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::ast::node::{Declaration, FormalParameter},
+    Context, JsResult, JsValue,
+};
+
+/// The kind of an arguments object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum Arguments {
+    /// An ordinary arguments object, created for strict functions and functions with non-simple
+    /// parameter lists.
+    Unmapped,
+
+    /// An arguments exotic object, whose indices alias the parameters of the function.
+    Mapped(ParameterMap),
+}
+
+/// The `[[ParameterMap]]` of an arguments exotic object.
+///
+/// It maps the indices of the arguments object to the names of the parameter bindings of the
+/// function environment they alias.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ParameterMap {
+    /// The name of the parameter mapped to each index, if it is still mapped.
+    #[unsafe_ignore_trace]
+    names: Vec<Option<Box<str>>>,
+
+    /// The function environment holding the parameter bindings.
+    environment: Environment,
+}
+
+impl ParameterMap {
+    /// Gets the index of `key`, if it is mapped to a parameter.
+    fn index(&self, key: &PropertyKey) -> Option<usize> {
+        match key {
+            PropertyKey::Index(index) => {
+                let index = *index as usize;
+                matches!(self.names.get(index), Some(Some(_))).then(|| index)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if `key` is mapped to a parameter.
+    pub(crate) fn is_mapped(&self, key: &PropertyKey) -> bool {
+        self.index(key).is_some()
+    }
+
+    /// Gets the environment and the name of the parameter binding mapped to `key`, if any.
+    pub(crate) fn binding(&self, key: &PropertyKey) -> Option<(Environment, Box<str>)> {
+        self.index(key).and_then(|index| {
+            self.names[index]
+                .clone()
+                .map(|name| (self.environment.clone(), name))
+        })
+    }
+
+    /// Gets the function environment holding the parameter bindings.
+    pub(crate) fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// Removes the mapping of `key`, after which it behaves as an ordinary property.
+    pub(crate) fn delete(&mut self, key: &PropertyKey) {
+        if let Some(index) = self.index(key) {
+            self.names[index] = None;
+        }
+    }
+}
+
+/// Checks if the formal parameters are a simple parameter list, made only of identifiers without
+/// initializers.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-issimpleparameterlist
+pub(crate) fn is_simple_parameter_list(params: &[FormalParameter]) -> bool {
+    params
+        .iter()
+        .all(|param| !param.is_rest_param() && param.init().is_none() && param.is_identifier())
+}
+
+/// Defines the properties shared by both kinds of arguments objects: the indices, `length` and
+/// `@@iterator`.
+fn define_arguments_properties(obj: &JsObject, arguments_list: &[JsValue], context: &Context) {
+    // a. Perform ! CreateDataPropertyOrThrow(obj, ! ToString(𝔽(index)), val).
+    for (index, value) in arguments_list.iter().enumerate() {
+        obj.insert(
+            index,
+            PropertyDescriptor::builder()
+                .value(value.clone())
+                .writable(true)
+                .enumerable(true)
+                .configurable(true),
+        );
+    }
+
+    // Perform ! DefinePropertyOrThrow(obj, "length", PropertyDescriptor { [[Value]]: 𝔽(len),
+    // [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    obj.insert(
+        "length",
+        PropertyDescriptor::builder()
+            .value(arguments_list.len())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    // Perform ! DefinePropertyOrThrow(obj, @@iterator, PropertyDescriptor {
+    // [[Value]]: %Array.prototype.values%, [[Writable]]: true, [[Enumerable]]: false,
+    // [[Configurable]]: true }).
+    obj.insert(
+        WellKnownSymbols::iterator(),
+        PropertyDescriptor::builder()
+            .value(context.intrinsics().array_prototype_values().clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+}
+
+/// Creates an unmapped arguments object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createunmappedargumentsobject
+pub fn create_unmapped_arguments_object(
+    arguments_list: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let len be the number of elements in argumentsList.
+    // 2. Let obj be ! OrdinaryObjectCreate(%Object.prototype%, « [[ParameterMap]] »).
+    // 3. Set obj.[[ParameterMap]] to undefined.
+    let obj = JsObject::new(Object::with_prototype(
+        context
+            .standard_objects()
+            .object_object()
+            .prototype()
+            .into(),
+        ObjectData::arguments(Arguments::Unmapped),
+    ));
+
+    // 4-7. Define the indices, "length" and @@iterator.
+    define_arguments_properties(&obj, arguments_list, context);
+
+    // 8. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor {
+    // [[Get]]: %ThrowTypeError%, [[Set]]: %ThrowTypeError%, [[Enumerable]]: false,
+    // [[Configurable]]: false }).
+    let throw_type_error = context.intrinsics().throw_type_error().clone();
+    obj.insert(
+        "callee",
+        PropertyDescriptor::builder()
+            .get(throw_type_error.clone())
+            .set(throw_type_error)
+            .enumerable(false)
+            .configurable(false),
+    );
+
+    // 9. Return obj.
+    Ok(obj.into())
+}
+
+/// Creates a mapped arguments object, whose indices alias the bindings of the parameters in
+/// `environment`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createmappedargumentsobject
+pub(crate) fn create_mapped_arguments_object(
+    func: &JsObject,
+    formals: &[FormalParameter],
+    arguments_list: &[JsValue],
+    environment: &Environment,
+    context: &mut Context,
+) -> JsValue {
+    // 1. Assert: formals does not contain a rest parameter, any binding patterns, or any
+    //    initializers. It may contain duplicate identifiers.
+    debug_assert!(is_simple_parameter_list(formals));
+
+    // 2. Let len be the number of elements in argumentsList.
+    let len = arguments_list.len();
+
+    // 9. Let parameterNames be the BoundNames of formals.
+    // 14. Let mappedNames be a new empty List.
+    // 15. Let index be numberOfParameters - 1.
+    // 16. Repeat, while index ≥ 0,
+    //     a. Let name be parameterNames[index].
+    //     b. If name is not an element of mappedNames, then
+    //         i. Add name as an element of the list mappedNames.
+    //         ii. If index < len, then
+    //             1-4. Map index to the binding of name.
+    //     c. Set index to index - 1.
+    let mut names = vec![None; len.min(formals.len())];
+    let mut mapped_names: Vec<&str> = Vec::with_capacity(formals.len());
+    for (index, param) in formals.iter().enumerate().rev() {
+        let name = match param.declaration() {
+            Declaration::Identifier { ident, .. } => ident.as_ref(),
+            Declaration::Pattern(_) => unreachable!("binding pattern in a simple parameter list"),
+        };
+        if !mapped_names.contains(&name) {
+            mapped_names.push(name);
+            if index < len {
+                names[index] = Some(name.into());
+            }
+        }
+    }
+
+    // 3. Let obj be ! MakeBasicObject(« [[Prototype]], [[Extensible]], [[ParameterMap]] »).
+    // 4-6. Set the internal methods of obj to the arguments exotic object internal methods.
+    // 7. Set obj.[[Prototype]] to %Object.prototype%.
+    // 8. Let map be ! OrdinaryObjectCreate(null).
+    let obj = JsObject::new(Object::with_prototype(
+        context
+            .standard_objects()
+            .object_object()
+            .prototype()
+            .into(),
+        ObjectData::arguments(Arguments::Mapped(ParameterMap {
+            names,
+            environment: environment.clone(),
+        })),
+    ));
+
+    // 12-13, 17. Define the indices, "length" and @@iterator.
+    define_arguments_properties(&obj, arguments_list, context);
+
+    // 18. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor {
+    // [[Value]]: func, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+    obj.insert(
+        "callee",
+        PropertyDescriptor::builder()
+            .value(func.clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+    );
+
+    // 19. Return obj.
+    obj.into()
+}
@@ -15,25 +15,117 @@ use crate::object::PROTOTYPE;
 use crate::{
     builtins::{Array, BuiltIn},
     environment::lexical_environment::Environment,
+    error::JsErrorCode,
+    exec::BindingInitialization,
     gc::{custom_trace, empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, FunctionBuilder, JsObject, Object, ObjectData},
-    property::{Attribute, PropertyDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
-    BoaProfiler, Context, JsResult, JsValue,
+    object::{
+        ConstructorBuilder, FunctionBuilder, JsObject, NativeObject, Object, ObjectData,
+        PrivateElement,
+    },
+    policy::SourceKind,
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::{
+        ast::node::{Declaration, FormalParameter, Node, RcStatementList},
+        Parser,
+    },
+    teardown::Contents,
+    value::{ArgumentsExt, IntegerOrInfinity},
+    BoaProfiler, Context, Executable, JsResult, JsString, JsSymbol, JsValue,
 };
 use bitflags::bitflags;
+use gc::{Gc, GcCell, GcCellRef, GcCellRefMut};
 
 use std::fmt::{self, Debug};
 use std::rc::Rc;
 
+use crate::vm::CodeBlock;
+
+pub mod arguments;
 #[cfg(test)]
 mod tests;
 
+pub use arguments::create_unmapped_arguments_object;
+
 /// _fn(this, arguments, context) -> ResultValue_ - The signature of a native built-in function
 pub type NativeFunction = fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue>;
 
-/// _fn(this, arguments, context) -> ResultValue_ - The signature of a closure built-in function
-pub type ClosureFunction = dyn Fn(&JsValue, &[JsValue], &mut Context) -> JsResult<JsValue>;
+/// _fn(this, arguments, captures, context) -> ResultValue_ - The signature of a closure built-in function
+pub type ClosureFunction =
+    dyn Fn(&JsValue, &[JsValue], &Captures, &mut Context) -> JsResult<JsValue>;
+
+/// The values captured by a closure built-in function.
+///
+/// Rust closures are opaque to the garbage collector, so the garbage collected values that a
+/// closure needs must be stored in its `Captures` instead, where they are traced like the rest of
+/// the function object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Captures(Gc<GcCell<Box<dyn NativeObject>>>);
+
+impl Captures {
+    /// Creates new captures holding the given value.
+    #[inline]
+    pub fn new<T>(captures: T) -> Self
+    where
+        T: NativeObject,
+    {
+        Self(Gc::new(GcCell::new(Box::new(captures))))
+    }
+
+    /// Borrows the captured value, if it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently mutably borrowed.
+    #[inline]
+    pub fn downcast_ref<T>(&self) -> Option<GcCellRef<'_, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow();
+        if (**captures).as_any().is::<T>() {
+            Some(GcCellRef::map(captures, |captures| {
+                (**captures)
+                    .as_any()
+                    .downcast_ref::<T>()
+                    .expect("the type of the captures was checked")
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Mutably borrows the captured value, if it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently borrowed.
+    #[inline]
+    pub fn downcast_mut<T>(&self) -> Option<GcCellRefMut<'_, Box<dyn NativeObject>, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow_mut();
+        if (**captures).as_any().is::<T>() {
+            Some(GcCellRefMut::map(captures, |captures| {
+                (**captures)
+                    .as_mut_any()
+                    .downcast_mut::<T>()
+                    .expect("the type of the captures was checked")
+            }))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Captures {
+    /// Creates empty captures, for closures that do not capture garbage collected values.
+    #[inline]
+    fn default() -> Self {
+        Self::new(())
+    }
+}
 
 #[derive(Clone, Copy, Finalize)]
 pub struct BuiltInFunction(pub(crate) NativeFunction);
@@ -59,6 +151,10 @@ bitflags! {
     pub struct FunctionFlags: u8 {
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const ASYNC = 0b0000_1000;
+        const GENERATOR = 0b0001_0000;
+        const CLASS_CONSTRUCTOR = 0b0010_0000;
+        const DERIVED_CONSTRUCTOR = 0b0100_0000;
     }
 }
 
@@ -72,6 +168,26 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.contains(Self::CLASS_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_derived_constructor(&self) -> bool {
+        self.contains(Self::DERIVED_CONSTRUCTOR)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -92,15 +208,86 @@ pub enum Function {
     Closure {
         function: Rc<ClosureFunction>,
         constructable: bool,
+        captures: Captures,
     },
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
+        /// The object whose prototype is the base of `super` property accesses, if the
+        /// function is a method.
+        home_object: Option<JsObject>,
+        /// The source text of the function, if it was parsed from source code.
+        source_text: Option<JsString>,
+        /// The compiled body of the function.
+        code: Rc<CodeBlock>,
+        /// The fields of the instances, if the function is the constructor of a class.
+        fields: Vec<ClassFieldDefinition>,
+        /// The private methods and accessors of the instances, if the function is the
+        /// constructor of a class.
+        private_methods: Vec<(JsSymbol, PrivateElement)>,
+        /// The static fields and blocks of the class, while the class is being defined.
+        static_elements: Vec<ClassStaticElement>,
     },
 }
 
+/// A field declared by a class, which is added to the objects the class constructs.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-classfielddefinition-record-specification-type
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ClassFieldDefinition {
+    name: ClassFieldName,
+    initializer: Option<JsObject>,
+}
+
+/// The name of a field declared by a class.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum ClassFieldName {
+    /// A field defined as a property, like `field` or `[key]`.
+    Public(PropertyKey),
+    /// A private field, like `#field`.
+    Private(JsSymbol),
+}
+
+impl ClassFieldDefinition {
+    /// Creates the definition of the field `name`, whose value is the result of calling
+    /// `initializer`, if there is one.
+    #[inline]
+    pub(crate) fn new(name: ClassFieldName, initializer: Option<JsObject>) -> Self {
+        Self { name, initializer }
+    }
+
+    /// Gets the name of the field.
+    #[inline]
+    pub(crate) fn name(&self) -> &ClassFieldName {
+        &self.name
+    }
+
+    /// Gets the function evaluating the initial value of the field, if it has an initializer.
+    #[inline]
+    pub(crate) fn initializer(&self) -> Option<&JsObject> {
+        self.initializer.as_ref()
+    }
+}
+
+/// A static element of a class, which is evaluated once the class is bound to its name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum ClassStaticElement {
+    /// A static field, defined on the class.
+    Field(ClassFieldDefinition),
+    /// A `static { }` block, called with the class as `this`.
+    Block(JsObject),
+}
+
 impl Debug for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Function {{ ... }}")
@@ -111,15 +298,69 @@ unsafe impl Trace for Function {
     custom_trace!(this, {
         match this {
             Function::Native { .. } => {}
-            Function::Closure { .. } => {}
-            Function::Ordinary { environment, .. } => {
+            Function::Closure { captures, .. } => {
+                mark(captures);
+            }
+            Function::Ordinary {
+                environment,
+                home_object,
+                fields,
+                private_methods,
+                static_elements,
+                ..
+            } => {
                 mark(environment);
+                mark(home_object);
+                mark(fields);
+                mark(static_elements);
+                for (_, method) in private_methods {
+                    mark(method);
+                }
             }
         }
     });
 }
 
 impl Function {
+    /// Queues the environment and the home object of an ordinary function to be emptied by the
+    /// teardown of the context.
+    ///
+    /// The captures of a closure are opaque, so they are left to the collector.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        if let Self::Ordinary {
+            environment,
+            home_object,
+            fields,
+            private_methods,
+            static_elements,
+            ..
+        } = self
+        {
+            contents.push_environment(environment);
+            if let Some(home_object) = home_object {
+                contents.push_object(home_object);
+            }
+            for field in fields {
+                if let Some(initializer) = field.initializer() {
+                    contents.push_object(initializer);
+                }
+            }
+            for (_, method) in private_methods {
+                method.collect_contents(contents);
+            }
+            for element in static_elements {
+                match element {
+                    ClassStaticElement::Field(field) => {
+                        if let Some(initializer) = field.initializer() {
+                            contents.push_object(initializer);
+                        }
+                    }
+                    ClassStaticElement::Block(block) => contents.push_object(block),
+                }
+            }
+        }
+    }
+
     // Adds the final rest parameters to the Environment as an array
     pub(crate) fn add_rest_param(
         &self,
@@ -128,22 +369,13 @@ impl Function {
         args_list: &[JsValue],
         context: &mut Context,
         local_env: &Environment,
-    ) {
+    ) -> JsResult<()> {
         // Create array of values
         let array = Array::new_array(context);
         Array::add_to_array_object(&array, args_list.get(index..).unwrap_or_default(), context)
             .unwrap();
 
-        // Create binding
-        local_env
-            // Function parameters can share names in JavaScript...
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding for rest param");
-
-        // Set Binding to value
-        local_env
-            .initialize_binding(param.name(), array, context)
-            .expect("Failed to initialize rest param");
+        self.add_arguments_to_environment(param, array, local_env, context)
     }
 
     // Adds an argument to the environment
@@ -153,16 +385,110 @@ impl Function {
         value: JsValue,
         local_env: &Environment,
         context: &mut Context,
-    ) {
-        // Create binding
-        local_env
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding");
+    ) -> JsResult<()> {
+        let mut bind = |name: &str, value: JsValue, context: &mut Context| {
+            // Create binding
+            local_env
+                // Function parameters can share names in JavaScript...
+                .create_mutable_binding(name.to_owned(), false, true, context)
+                .expect("Failed to create binding");
+
+            // Set Binding to value
+            local_env
+                .initialize_binding(name, value, context)
+                .expect("Failed to intialize binding");
+            Ok(())
+        };
+
+        match param.declaration() {
+            Declaration::Identifier { ident, .. } => bind(ident.as_ref(), value, context),
+            Declaration::Pattern(pattern) => pattern.run(Some(value), &mut bind, context),
+        }
+    }
+
+    /// Sets the `[[HomeObject]]` of the function, making it a method of `home_object`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makemethod
+    pub(crate) fn set_home_object(&mut self, object: JsObject) {
+        if let Self::Ordinary { home_object, .. } = self {
+            *home_object = Some(object);
+        }
+    }
+
+    /// Gets the fields the function adds to the objects it constructs, if it is the constructor
+    /// of a class.
+    pub(crate) fn fields(&self) -> &[ClassFieldDefinition] {
+        match self {
+            Self::Ordinary { fields, .. } => fields,
+            Self::Native { .. } | Self::Closure { .. } => &[],
+        }
+    }
+
+    /// Adds a field to the objects constructed by the function, which must be the constructor of
+    /// a class.
+    pub(crate) fn push_field(&mut self, field: ClassFieldDefinition) {
+        if let Self::Ordinary { fields, .. } = self {
+            fields.push(field);
+        }
+    }
+
+    /// Gets the private methods and accessors the function adds to the objects it constructs, if
+    /// it is the constructor of a class.
+    pub(crate) fn private_methods(&self) -> &[(JsSymbol, PrivateElement)] {
+        match self {
+            Self::Ordinary {
+                private_methods, ..
+            } => private_methods,
+            Self::Native { .. } | Self::Closure { .. } => &[],
+        }
+    }
 
-        // Set Binding to value
-        local_env
-            .initialize_binding(param.name(), value, context)
-            .expect("Failed to intialize binding");
+    /// Adds a private method or accessor to the objects constructed by the function, which must
+    /// be the constructor of a class.
+    ///
+    /// A getter and a setter with the same name are combined into a single accessor.
+    pub(crate) fn push_private_method(&mut self, name: JsSymbol, method: PrivateElement) {
+        if let Self::Ordinary {
+            private_methods, ..
+        } = self
+        {
+            if let Some((_, element)) = private_methods.iter_mut().find(|(key, _)| *key == name) {
+                element.merge_accessor(&method);
+            } else {
+                private_methods.push((name, method));
+            }
+        }
+    }
+
+    /// Adds a static element to the class whose constructor is the function, while the class is
+    /// being defined.
+    pub(crate) fn push_static_element(&mut self, element: ClassStaticElement) {
+        if let Self::Ordinary {
+            static_elements, ..
+        } = self
+        {
+            static_elements.push(element);
+        }
+    }
+
+    /// Takes the static elements of the class whose constructor is the function, once the class
+    /// is bound to its name.
+    pub(crate) fn take_static_elements(&mut self) -> Vec<ClassStaticElement> {
+        match self {
+            Self::Ordinary {
+                static_elements, ..
+            } => std::mem::take(static_elements),
+            Self::Native { .. } | Self::Closure { .. } => Vec::new(),
+        }
+    }
+
+    /// Returns true if the function is the constructor of a class, which can only be called by
+    /// `new`.
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        matches!(self, Self::Ordinary { flags, .. } if flags.is_class_constructor())
     }
 
     /// Returns true if the function object is constructable.
@@ -173,44 +499,111 @@ impl Function {
             Self::Ordinary { flags, .. } => flags.is_constructable(),
         }
     }
+
+    /// Returns true if the function is strict mode code, which built-in functions always are.
+    pub fn is_strict(&self) -> bool {
+        match self {
+            Self::Native { .. } | Self::Closure { .. } => true,
+            Self::Ordinary { body, .. } => body.strict(),
+        }
+    }
 }
 
-/// Arguments.
+/// The internal slots of a bound function exotic object.
 ///
-/// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
-pub fn create_unmapped_arguments_object(
-    arguments_list: &[JsValue],
-    context: &mut Context,
-) -> JsResult<JsValue> {
-    let len = arguments_list.len();
-    let obj = JsObject::new(Object::default());
-    // Set length
-    let length = PropertyDescriptor::builder()
-        .value(len)
-        .writable(true)
-        .enumerable(false)
-        .configurable(true);
-    // Define length as a property
-    crate::object::internal_methods::ordinary_define_own_property(
-        &obj,
-        "length".into(),
-        length.into(),
-        context,
-    )?;
-    let mut index: usize = 0;
-    while index < len {
-        let val = arguments_list.get(index).expect("Could not get argument");
-        let prop = PropertyDescriptor::builder()
-            .value(val.clone())
-            .writable(true)
-            .enumerable(true)
-            .configurable(true);
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct BoundFunction {
+    target_function: JsObject,
+    this: JsValue,
+    args: Vec<JsValue>,
+    constructable: bool,
+}
+
+impl BoundFunction {
+    /// `BoundFunctionCreate ( targetFunction, boundThis, boundArgs )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-boundfunctioncreate
+    pub(crate) fn create(
+        target_function: JsObject,
+        this: JsValue,
+        args: Vec<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let proto be ? targetFunction.[[GetPrototypeOf]]().
+        let proto = target_function.__get_prototype_of__(context)?;
+
+        // 2. Let internalSlotsList be the internal slots listed in Table 34, plus [[Prototype]] and [[Extensible]].
+        // 3. Let obj be ! MakeBasicObject(internalSlotsList).
+        // 4. Set obj.[[Prototype]] to proto.
+        // 5. Set obj.[[Call]] as described in 10.4.1.1.
+        // 6. If IsConstructor(targetFunction) is true, then
+        //     a. Set obj.[[Construct]] as described in 10.4.1.2.
+        // 7. Set obj.[[BoundTargetFunction]] to targetFunction.
+        // 8. Set obj.[[BoundThis]] to boundThis.
+        // 9. Set obj.[[BoundArguments]] to boundArgs.
+        let bound_function = Self {
+            constructable: target_function.is_constructable(),
+            target_function,
+            this,
+            args,
+        };
+
+        // 10. Return obj.
+        Ok(JsObject::new(Object::with_prototype(
+            proto,
+            ObjectData::bound_function(bound_function),
+        )))
+    }
+
+    /// Gets the `[[BoundTargetFunction]]` of the bound function.
+    #[inline]
+    pub fn target_function(&self) -> &JsObject {
+        &self.target_function
+    }
+
+    /// Gets the `[[BoundThis]]` of the bound function.
+    #[inline]
+    pub fn this(&self) -> &JsValue {
+        &self.this
+    }
+
+    /// Gets the `[[BoundArguments]]` of the bound function.
+    #[inline]
+    pub fn args(&self) -> &[JsValue] {
+        &self.args
+    }
+
+    /// Returns `true` if the target function has a `[[Construct]]` internal method.
+    #[inline]
+    pub(crate) fn is_constructable(&self) -> bool {
+        self.constructable
+    }
+}
 
-        obj.insert(index, prop);
-        index += 1;
+/// Creates the `%ThrowTypeError%` intrinsic, a frozen function that throws a `TypeError` when
+/// called.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%throwtypeerror%
+pub(crate) fn create_throw_type_error(context: &mut Context) -> JsObject {
+    fn throw_type_error(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        context.throw_type_error("'callee' may not be accessed on strict mode arguments objects")
     }
 
-    Ok(JsValue::new(obj))
+    let function = FunctionBuilder::native(context, throw_type_error).build();
+    function
+        .freeze(context)
+        .expect("freezing a new function object cannot fail");
+    function
 }
 
 /// Creates a new member function of a `Object` or `prototype`.
@@ -271,37 +664,142 @@ pub fn make_builtin_fn<N>(
     );
 }
 
+/// The kinds of functions created from source text by `CreateDynamicFunction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DynamicFunctionKind {
+    Normal,
+    Generator,
+    Async,
+    AsyncGenerator,
+}
+
+impl DynamicFunctionKind {
+    /// The keywords starting the source text of a function of this kind.
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Normal => "function",
+            Self::Generator => "function*",
+            Self::Async => "async function",
+            Self::AsyncGenerator => "async function*",
+        }
+    }
+
+    /// Checks if the parsed expression is a function expression of this kind.
+    fn matches(self, node: &Node) -> bool {
+        matches!(
+            (self, node),
+            (Self::Normal, Node::FunctionExpr(_))
+                | (Self::Generator, Node::GeneratorExpr(_))
+                | (Self::Async, Node::AsyncFunctionExpr(_))
+                | (Self::AsyncGenerator, Node::AsyncGeneratorExpr(_))
+        )
+    }
+}
+
+/// `CreateDynamicFunction ( constructor, newTarget, kind, args )`
+///
+/// Creates a function of the given kind from the parameters and the body passed as strings to
+/// the `Function` constructor or one of its siblings. The function is created in the global
+/// environment, whatever the environment of the caller.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+pub(crate) fn create_dynamic_function(
+    new_target: &JsValue,
+    args: &[JsValue],
+    kind: DynamicFunctionKind,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 5-12. Let P be the parameters joined with ",", and bodyString be the body surrounded by
+    // line feeds.
+    let (parameters, body) = match args.split_last() {
+        Some((body, parameters)) => (parameters, body.to_string(context)?),
+        None => (args, JsString::default()),
+    };
+    let parameters = parameters
+        .iter()
+        .map(|parameter| parameter.to_string(context))
+        .collect::<JsResult<Vec<_>>>()?
+        .join(",");
+
+    // 13. Let sourceString be the string-concatenation of prefix, " anonymous(", P, 0x000A (LINE FEED), ") {", bodyString, and "}".
+    let source = format!(
+        "{} anonymous({}\n) {{\n{}\n}}",
+        kind.prefix(),
+        parameters,
+        body
+    );
+
+    // 2. Perform ? HostEnsureCanCompileStrings(callerRealm, calleeRealm).
+    let origin = context.get_active_module_key();
+    context.check_source(
+        SourceKind::Function,
+        &source,
+        origin.as_ref().map(JsString::as_str),
+    )?;
+
+    // 14-21. Parse the source text, which must be a single function expression of the expected
+    // kind, so the parameters and the body can't close the function early.
+    let expression = format!("({})", source);
+    let statements = Parser::new(expression.as_bytes(), false)
+        .parse_all()
+        .map_err(|e| context.construct_parse_error(&e))?;
+    let node = match statements.items() {
+        [node] if kind.matches(node) => node,
+        _ => {
+            return context.throw_coded_error(
+                JsErrorCode::INVALID_DYNAMIC_FUNCTION,
+                "invalid parameters or body for a dynamic function",
+            )
+        }
+    };
+
+    // 28-34. Let F be OrdinaryFunctionCreate(proto, sourceText, parameters, body, non-lexical-this, scope), with the global environment as its scope.
+    let global_env = context.get_global_environment();
+    context.push_environment(global_env);
+    let function = node.run(context);
+    context.pop_environment();
+    let function = function?;
+
+    // 22-27. Let proto be ? GetPrototypeFromConstructor(newTarget, fallbackProto).
+    if let Some(new_target) = new_target.as_object() {
+        let prototype = new_target.get(PROTOTYPE, context)?;
+        if prototype.is_object() {
+            function
+                .as_object()
+                .expect("functions are objects")
+                .set_prototype_instance(prototype);
+        }
+    }
+
+    // 36. Return F.
+    Ok(function)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BuiltInFunctionObject;
 
 impl BuiltInFunctionObject {
     pub const LENGTH: usize = 1;
 
+    /// `Function ( p1, p2, … , pn, body )`
+    ///
+    /// Creates a function from the source text of its parameters and body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function-p1-p2-pn-body
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
     fn constructor(
         new_target: &JsValue,
-        _: &[JsValue],
+        args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let prototype = new_target
-            .as_object()
-            .and_then(|obj| {
-                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
-                    .map(|o| o.as_object())
-                    .transpose()
-            })
-            .transpose()?
-            .unwrap_or_else(|| context.standard_objects().object_object().prototype());
-        let this = JsValue::new_object(context);
-
-        this.as_object()
-            .expect("this should be an object")
-            .set_prototype_instance(prototype.into());
-
-        this.set_data(ObjectData::function(Function::Native {
-            function: BuiltInFunction(|_, _, _| Ok(JsValue::undefined())),
-            constructable: true,
-        }));
-        Ok(this)
+        create_dynamic_function(new_target, args, DynamicFunctionKind::Normal, context)
     }
 
     fn prototype(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
@@ -320,9 +818,12 @@ impl BuiltInFunctionObject {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call
     fn call(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         if !this.is_function() {
-            return context.throw_type_error(format!("{} is not a function", this.display()));
+            return context.throw_coded_error(
+                JsErrorCode::NAMED_NOT_A_FUNCTION,
+                format!("{} is not a function", this.display()),
+            );
         }
-        let this_arg: JsValue = args.get(0).cloned().unwrap_or_default();
+        let this_arg = args.get_or_undefined(0).clone();
         // TODO?: 3. Perform PrepareForTailCall
         let start = if !args.is_empty() { 1 } else { 0 };
         context.call(this, &this_arg, &args[start..])
@@ -341,10 +842,13 @@ impl BuiltInFunctionObject {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply
     fn apply(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         if !this.is_function() {
-            return context.throw_type_error(format!("{} is not a function", this.display()));
+            return context.throw_coded_error(
+                JsErrorCode::NAMED_NOT_A_FUNCTION,
+                format!("{} is not a function", this.display()),
+            );
         }
-        let this_arg = args.get(0).cloned().unwrap_or_default();
-        let arg_array = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get_or_undefined(0).clone();
+        let arg_array = args.get_or_undefined(1).clone();
         if arg_array.is_null_or_undefined() {
             // TODO?: 3.a. PrepareForTailCall
             return context.call(this, &this_arg, &[]);
@@ -353,13 +857,175 @@ impl BuiltInFunctionObject {
         // TODO?: 5. PrepareForTailCall
         context.call(this, &this_arg, &arg_list)
     }
+
+    /// `Function.prototype.bind ( thisArg, ...args )`
+    ///
+    /// The bind() method creates a bound function, which calls the function with the given `this`
+    /// value and the given arguments prepended to the arguments it is called with.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+    fn bind(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let Target be the this value.
+        // 2. If IsCallable(Target) is false, throw a TypeError exception.
+        let target = match this.as_object() {
+            Some(object) if object.is_callable() => object,
+            _ => {
+                return context
+                    .throw_type_error("Function.prototype.bind requires that 'this' be a Function")
+            }
+        };
+
+        // 3. Let F be ? BoundFunctionCreate(Target, thisArg, args).
+        let this_arg = args.get_or_undefined(0).clone();
+        let bound_args = args.get(1..).unwrap_or_default().to_vec();
+        let arg_count = bound_args.len() as i64;
+        let function = BoundFunction::create(target.clone(), this_arg, bound_args, context)?;
+
+        // 4. Let L be 0.
+        let mut length = 0.0;
+
+        // 5. Let targetHasLength be ? HasOwnProperty(Target, "length").
+        // 6. If targetHasLength is true, then
+        if target.has_own_property("length", context)? {
+            // a. Let targetLen be ? Get(Target, "length").
+            let target_len = target.get("length", context)?;
+            // b. If Type(targetLen) is Number, then
+            if target_len.is_number() {
+                length = match target_len.to_integer_or_infinity(context)? {
+                    // i. If targetLen is +∞𝔽, set L to +∞.
+                    IntegerOrInfinity::PositiveInfinity => f64::INFINITY,
+                    // ii. Else if targetLen is -∞𝔽, set L to 0.
+                    IntegerOrInfinity::NegativeInfinity => 0.0,
+                    // iii. Else,
+                    //     1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    //     2. Assert: targetLenAsInt is finite.
+                    //     3. Let argCount be the number of elements in args.
+                    //     4. Set L to max(targetLenAsInt - argCount, 0).
+                    IntegerOrInfinity::Integer(target_len) => {
+                        (target_len.saturating_sub(arg_count)).max(0) as f64
+                    }
+                };
+            }
+        }
+
+        // 7. Perform ! SetFunctionLength(F, L).
+        function
+            .define_property_or_throw(
+                "length",
+                PropertyDescriptor::builder()
+                    .value(length)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(true),
+                context,
+            )
+            .expect("defining the length of a new bound function cannot fail");
+
+        // 8. Let targetName be ? Get(Target, "name").
+        // 9. If Type(targetName) is not String, set targetName to the empty String.
+        let target_name = target
+            .get("name", context)?
+            .as_string()
+            .cloned()
+            .unwrap_or_default();
+
+        // 10. Perform SetFunctionName(F, targetName, "bound").
+        function
+            .define_property_or_throw(
+                "name",
+                PropertyDescriptor::builder()
+                    .value(format!("bound {}", target_name))
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(true),
+                context,
+            )
+            .expect("defining the name of a new bound function cannot fail");
+
+        // 11. Return F.
+        Ok(function.into())
+    }
+
+    /// `Function.prototype.toString ( )`
+    ///
+    /// Returns the source text of the function, or a `NativeFunction` representation for
+    /// functions which are not created from source code.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
+    fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let func be the this value.
+        let object = match this.as_object() {
+            Some(object) if object.is_callable() => object,
+            // 5. Throw a TypeError exception.
+            _ => {
+                return context.throw_type_error(
+                    "Function.prototype.toString requires that 'this' be a Function",
+                )
+            }
+        };
+
+        // 2. If func has a [[SourceText]] internal slot, return func.[[SourceText]].
+        if let Some(Function::Ordinary {
+            source_text: Some(source_text),
+            ..
+        }) = object.borrow().as_function()
+        {
+            return Ok(source_text.clone().into());
+        }
+
+        // 3. If func is a built-in function object, return an implementation-defined String
+        // source code representation of func, with the syntax of a NativeFunction.
+        // 4. If IsCallable(func) is true, return an implementation-defined String source code
+        // representation of func, with the syntax of a NativeFunction.
+        let name = if object.is_function() {
+            object
+                .__get_own_property__(&"name".into(), context)?
+                .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+                .unwrap_or_default()
+        } else {
+            JsString::default()
+        };
+        Ok(format!("function {}() {{ [native code] }}", name).into())
+    }
+
+    /// `Function.prototype [ @@hasInstance ] ( V )`
+    ///
+    /// The default behaviour of the `instanceof` operator, checking if the `prototype` of the
+    /// function is in the prototype chain of `V`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/@@hasInstance
+    fn has_instance(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let F be the this value.
+        // 2. Return ? OrdinaryHasInstance(F, V).
+        match this.as_object() {
+            Some(object) => Ok(object
+                .ordinary_has_instance(context, args.get_or_undefined(0))?
+                .into()),
+            None => Ok(false.into()),
+        }
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
     const NAME: &'static str = "Function";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -372,6 +1038,14 @@ impl BuiltIn for BuiltInFunctionObject {
             .constructable(false)
             .build_function_prototype(&function_prototype);
 
+        // Unlike other methods, `@@hasInstance` can't be overwritten, so that it can't be used to
+        // make `instanceof` lie about the objects bound functions were created from.
+        let has_instance = FunctionBuilder::native(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .constructable(false)
+            .build();
+
         let function_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -380,7 +1054,14 @@ impl BuiltIn for BuiltInFunctionObject {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
-        .method(Self::apply, "apply", 1)
+        .method(Self::apply, "apply", 2)
+        .method(Self::bind, "bind", 1)
+        .method(Self::to_string, "toString", 0)
+        .property(
+            WellKnownSymbols::has_instance(),
+            has_instance,
+            Attribute::constant(),
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
@@ -24,6 +24,79 @@ fn arguments_object() {
     );
 }
 
+#[test]
+fn mapped_arguments_object() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function aliased(a, b) {
+            arguments[0] = 10;
+            b = 20;
+            return [a, arguments[1], arguments.callee === aliased];
+        }
+        function missing(a) {
+            arguments[0] = 10;
+            return a;
+        }
+        function deleted(a) {
+            delete arguments[0];
+            arguments[0] = 10;
+            return a;
+        }
+        function frozen(a) {
+            Object.defineProperty(arguments, '0', { writable: false });
+            a = 10;
+            return arguments[0];
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "aliased(1, 2).join()"),
+        "\"10,20,true\""
+    );
+    assert_eq!(forward(&mut context, "missing()"), "undefined");
+    assert_eq!(forward(&mut context, "deleted(1)"), "1");
+    assert_eq!(forward(&mut context, "frozen(1)"), "1");
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function strict(a) {
+            'use strict';
+            arguments[0] = 10;
+            return [a, arguments[0]];
+        }
+        function defaults(a = 0) {
+            arguments[0] = 10;
+            return a;
+        }
+        function callee() {
+            'use strict';
+            try {
+                arguments.callee;
+            } catch (e) {
+                return e.name;
+            }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "strict(1).join()"), "\"1,10\"");
+    assert_eq!(forward(&mut context, "defaults(1)"), "1");
+    assert_eq!(forward(&mut context, "callee()"), "\"TypeError\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () { return [...arguments].join(); })(1, 2)"
+        ),
+        "\"1,2\""
+    );
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     let mut context = Context::new();
@@ -212,3 +285,811 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_constructor_from_source() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "new Function('a', 'b', 'return a + b')(1, 2)"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "Function('a, b', 'return a * b')(2, 3)"),
+        "6"
+    );
+    assert_eq!(forward(&mut context, "Function()()"), "undefined");
+    assert_eq!(forward(&mut context, "Function('a', 'b', '').length"), "2");
+    assert_eq!(forward(&mut context, "Function('').name"), "\"anonymous\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var x = 'global'; (function(x) { return Function('return x')(); })('local')"
+        ),
+        "\"global\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...Object.getPrototypeOf(function*() {}).constructor('yield 1; yield 2')()]"
+        ),
+        "[ 1, 2 ]"
+    );
+}
+
+#[test]
+fn function_prototype_to_string() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        function  add ( a, b ) { return a + b; /* sum */ }
+        var gen = function* () { yield 1; };
+        var arrow = (x,
+            y) => x * y;
+        var obj = { method(a) { return a; }, get getter() { return 1; } };
+        "#,
+    );
+    assert_eq!(
+        forward(&mut context, "add.toString()"),
+        "\"function  add ( a, b ) { return a + b; /* sum */ }\""
+    );
+    assert_eq!(
+        forward(&mut context, "gen.toString()"),
+        "\"function* () { yield 1; }\""
+    );
+    assert_eq!(
+        forward(&mut context, "arrow.toString()"),
+        "\"(x,\n            y) => x * y\""
+    );
+    assert_eq!(
+        forward(&mut context, "obj.method.toString()"),
+        "\"method(a) { return a; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'getter').get.toString()"
+        ),
+        "\"get getter() { return 1; }\""
+    );
+    assert_eq!(
+        forward(&mut context, "Function('a', 'return a').toString()"),
+        "\"function anonymous(a\n) {\nreturn a\n}\""
+    );
+    assert_eq!(
+        forward(&mut context, "Math.max.toString()"),
+        "\"function max() { [native code] }\""
+    );
+    assert!(
+        forward(&mut context, "Function.prototype.toString.call({})")
+            .starts_with("Uncaught \"TypeError\"")
+    );
+}
+
+#[test]
+fn function_prototype_bind() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        function f(a, b, c) { return [this.x, a, b, c].join(); }
+        var g = f.bind({ x: 1 }, 2);
+        var h = g.bind({ x: 10 }, 3);
+        var arrow = (() => 1).bind(null);
+        "#,
+    );
+    assert_eq!(forward(&mut context, "g(3, 4)"), "\"1,2,3,4\"");
+    assert_eq!(forward(&mut context, "h(4)"), "\"1,2,3,4\"");
+    assert_eq!(forward(&mut context, "typeof g"), "\"function\"");
+    assert_eq!(forward(&mut context, "g.length"), "2");
+    assert_eq!(forward(&mut context, "h.length"), "1");
+    assert_eq!(
+        forward(&mut context, "f.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "g.name"), "\"bound f\"");
+    assert_eq!(forward(&mut context, "h.name"), "\"bound bound f\"");
+    assert_eq!(
+        forward(&mut context, "g.hasOwnProperty('prototype')"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(g) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "g.toString()"),
+        "\"function bound f() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].map(Math.max.bind(null, 2)).join()"),
+        "\"2,2,3\""
+    );
+    assert!(forward(&mut context, "new arrow()").starts_with("Uncaught \"TypeError\""));
+    assert!(forward(&mut context, "Function.prototype.bind.call({})")
+        .starts_with("Uncaught \"TypeError\""));
+}
+
+#[test]
+fn function_prototype_bind_length() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        function withLength(length) {
+            var f = function () {};
+            Object.defineProperty(f, "length", { value: length });
+            return f;
+        }
+        "#,
+    );
+    assert_eq!(
+        forward(&mut context, "withLength(Infinity).bind(null, 1).length"),
+        "Infinity"
+    );
+    assert_eq!(
+        forward(&mut context, "withLength(-Infinity).bind(null).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "withLength(2.5).bind(null, 1).length"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "withLength('3').bind(null).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var d = Object.getOwnPropertyDescriptor(withLength(1).bind(), 'length');
+            d.writable || d.enumerable || !d.configurable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn bound_function_construct() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        function Point(x, y) { this.x = x; this.y = y; }
+        var BoundPoint = Point.bind({ ignored: true }, 1);
+        var point = new BoundPoint(2);
+        "#,
+    );
+    assert_eq!(forward(&mut context, "point.x + point.y"), "3");
+    assert_eq!(forward(&mut context, "point.ignored"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(point) === Point.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "point instanceof BoundPoint"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.construct(BoundPoint, [2], Array) instanceof Array"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new (Date.bind(null, 0))().getTime()"),
+        "0"
+    );
+}
+
+#[test]
+fn function_constructor_rejects_injected_source() {
+    let mut context = Context::new();
+    for source in [
+        "Function('}, (function() {')",
+        "Function('a) {}, (function(', '')",
+        "Function('return 1 +')",
+    ] {
+        assert!(
+            forward(&mut context, source).starts_with("Uncaught \"SyntaxError\""),
+            "{}",
+            source
+        );
+    }
+}
+
+/// The `length` of every builtin function reachable from the global object and from the
+/// intrinsics, as given by the specification defining the function.
+const SPEC_LENGTHS: &[(&str, u32)] = &[
+    ("eval", 1),
+    ("structuredClone", 1),
+    ("Function", 1),
+    ("Function.prototype", 0),
+    ("Function.prototype.call", 1),
+    ("Function.prototype.apply", 2),
+    ("Function.prototype.bind", 1),
+    ("Function.prototype.toString", 0),
+    ("Function.prototype[Symbol.hasInstance]", 1),
+    ("Object", 1),
+    ("Object.create", 2),
+    ("Object.setPrototypeOf", 2),
+    ("Object.getPrototypeOf", 1),
+    ("Object.defineProperty", 3),
+    ("Object.defineProperties", 2),
+    ("Object.assign", 2),
+    ("Object.is", 2),
+    ("Object.keys", 1),
+    ("Object.values", 1),
+    ("Object.entries", 1),
+    ("Object.groupBy", 2),
+    ("Object.getOwnPropertyDescriptor", 2),
+    ("Object.getOwnPropertyDescriptors", 1),
+    ("Object.prototype.hasOwnProperty", 1),
+    ("Object.prototype.propertyIsEnumerable", 1),
+    ("Object.prototype.toString", 0),
+    ("Object.prototype.valueOf", 0),
+    ("Object.prototype.isPrototypeOf", 1),
+    ("Math.abs", 1),
+    ("Math.acos", 1),
+    ("Math.acosh", 1),
+    ("Math.asin", 1),
+    ("Math.asinh", 1),
+    ("Math.atan", 1),
+    ("Math.atanh", 1),
+    ("Math.atan2", 2),
+    ("Math.cbrt", 1),
+    ("Math.ceil", 1),
+    ("Math.clz32", 1),
+    ("Math.cos", 1),
+    ("Math.cosh", 1),
+    ("Math.exp", 1),
+    ("Math.expm1", 1),
+    ("Math.floor", 1),
+    ("Math.fround", 1),
+    ("Math.hypot", 2),
+    ("Math.imul", 2),
+    ("Math.log", 1),
+    ("Math.log1p", 1),
+    ("Math.log10", 1),
+    ("Math.log2", 1),
+    ("Math.max", 2),
+    ("Math.min", 2),
+    ("Math.pow", 2),
+    ("Math.random", 0),
+    ("Math.round", 1),
+    ("Math.sign", 1),
+    ("Math.sin", 1),
+    ("Math.sinh", 1),
+    ("Math.sqrt", 1),
+    ("Math.tan", 1),
+    ("Math.tanh", 1),
+    ("Math.trunc", 1),
+    ("JSON.parse", 2),
+    ("JSON.stringify", 3),
+    ("Intl.getCanonicalLocales", 1),
+    ("Intl.Collator", 0),
+    ("Intl.Collator.supportedLocalesOf", 1),
+    ("Intl.Collator.prototype.compare getter", 0),
+    ("Intl.Collator.prototype.resolvedOptions", 0),
+    ("Iterator", 0),
+    ("Iterator.prototype.map", 1),
+    ("Iterator.prototype.filter", 1),
+    ("Iterator.prototype.take", 1),
+    ("Iterator.prototype.drop", 1),
+    ("Iterator.prototype.flatMap", 1),
+    ("Iterator.prototype.reduce", 1),
+    ("Iterator.prototype.toArray", 0),
+    ("Iterator.prototype.forEach", 1),
+    ("Iterator.prototype.some", 1),
+    ("Iterator.prototype.every", 1),
+    ("Iterator.prototype.find", 1),
+    ("Iterator.prototype[Symbol.iterator]", 0),
+    ("Array", 1),
+    ("Array.isArray", 1),
+    ("Array.of", 0),
+    ("Array.fromAsync", 1),
+    ("Array.prototype.values", 0),
+    ("Array.prototype.concat", 1),
+    ("Array.prototype.push", 1),
+    ("Array.prototype.indexOf", 1),
+    ("Array.prototype.lastIndexOf", 1),
+    ("Array.prototype.includes", 1),
+    ("Array.prototype.map", 1),
+    ("Array.prototype.fill", 1),
+    ("Array.prototype.forEach", 1),
+    ("Array.prototype.filter", 1),
+    ("Array.prototype.pop", 0),
+    ("Array.prototype.join", 1),
+    ("Array.prototype.toString", 0),
+    ("Array.prototype.reverse", 0),
+    ("Array.prototype.shift", 0),
+    ("Array.prototype.unshift", 1),
+    ("Array.prototype.every", 1),
+    ("Array.prototype.find", 1),
+    ("Array.prototype.findIndex", 1),
+    ("Array.prototype.flat", 0),
+    ("Array.prototype.flatMap", 1),
+    ("Array.prototype.slice", 2),
+    ("Array.prototype.splice", 2),
+    ("Array.prototype.some", 1),
+    ("Array.prototype.sort", 1),
+    ("Array.prototype.reduce", 1),
+    ("Array.prototype.reduceRight", 1),
+    ("Array.prototype.keys", 0),
+    ("Array.prototype.entries", 0),
+    ("Array.prototype.copyWithin", 2),
+    ("Array.prototype.toReversed", 0),
+    ("Array.prototype.toSorted", 1),
+    ("Array.prototype.toSpliced", 2),
+    ("Array.prototype.with", 2),
+    ("Array.prototype[Symbol.iterator]", 0),
+    ("Array[Symbol.species] getter", 0),
+    ("BigInt", 1),
+    ("BigInt.asIntN", 2),
+    ("BigInt.asUintN", 2),
+    ("BigInt.prototype.toString", 0),
+    ("BigInt.prototype.valueOf", 0),
+    ("Boolean", 1),
+    ("Boolean.prototype.toString", 0),
+    ("Boolean.prototype.valueOf", 0),
+    ("Date", 7),
+    ("Date.now", 0),
+    ("Date.parse", 1),
+    ("Date.UTC", 7),
+    ("Date.prototype.getDate", 0),
+    ("Date.prototype.getDay", 0),
+    ("Date.prototype.getFullYear", 0),
+    ("Date.prototype.getHours", 0),
+    ("Date.prototype.getMilliseconds", 0),
+    ("Date.prototype.getMinutes", 0),
+    ("Date.prototype.getMonth", 0),
+    ("Date.prototype.getSeconds", 0),
+    ("Date.prototype.getTime", 0),
+    ("Date.prototype.getYear", 0),
+    ("Date.prototype.getTimezoneOffset", 0),
+    ("Date.prototype.getUTCDate", 0),
+    ("Date.prototype.getUTCDay", 0),
+    ("Date.prototype.getUTCFullYear", 0),
+    ("Date.prototype.getUTCHours", 0),
+    ("Date.prototype.getUTCMilliseconds", 0),
+    ("Date.prototype.getUTCMinutes", 0),
+    ("Date.prototype.getUTCMonth", 0),
+    ("Date.prototype.getUTCSeconds", 0),
+    ("Date.prototype.setDate", 1),
+    ("Date.prototype.setFullYear", 3),
+    ("Date.prototype.setHours", 4),
+    ("Date.prototype.setMilliseconds", 1),
+    ("Date.prototype.setMinutes", 3),
+    ("Date.prototype.setMonth", 2),
+    ("Date.prototype.setSeconds", 2),
+    ("Date.prototype.setYear", 1),
+    ("Date.prototype.setTime", 1),
+    ("Date.prototype.setUTCDate", 1),
+    ("Date.prototype.setUTCFullYear", 3),
+    ("Date.prototype.setUTCHours", 4),
+    ("Date.prototype.setUTCMilliseconds", 1),
+    ("Date.prototype.setUTCMinutes", 3),
+    ("Date.prototype.setUTCMonth", 2),
+    ("Date.prototype.setUTCSeconds", 2),
+    ("Date.prototype.toDateString", 0),
+    ("Date.prototype.toGMTString", 0),
+    ("Date.prototype.toISOString", 0),
+    ("Date.prototype.toJSON", 1),
+    ("Date.prototype.toLocaleDateString", 0),
+    ("Date.prototype.toLocaleString", 0),
+    ("Date.prototype.toLocaleTimeString", 0),
+    ("Date.prototype.toString", 0),
+    ("Date.prototype.toTimeString", 0),
+    ("Date.prototype.toUTCString", 0),
+    ("Date.prototype.valueOf", 0),
+    ("Date.prototype[Symbol.toPrimitive]", 1),
+    ("Map", 0),
+    ("Map.groupBy", 2),
+    ("Map.prototype.entries", 0),
+    ("Map.prototype.keys", 0),
+    ("Map.prototype.set", 2),
+    ("Map.prototype.delete", 1),
+    ("Map.prototype.get", 1),
+    ("Map.prototype.clear", 0),
+    ("Map.prototype.has", 1),
+    ("Map.prototype.forEach", 1),
+    ("Map.prototype.values", 0),
+    ("Map.prototype[Symbol.iterator]", 0),
+    ("Map[Symbol.species] getter", 0),
+    ("parseInt", 2),
+    ("parseFloat", 1),
+    ("isFinite", 1),
+    ("isNaN", 1),
+    ("Number", 1),
+    ("Number.isFinite", 1),
+    ("Number.isNaN", 1),
+    ("Number.isSafeInteger", 1),
+    ("Number.isInteger", 1),
+    ("Number.prototype.toExponential", 1),
+    ("Number.prototype.toFixed", 1),
+    ("Number.prototype.toLocaleString", 0),
+    ("Number.prototype.toPrecision", 1),
+    ("Number.prototype.toString", 1),
+    ("Number.prototype.valueOf", 0),
+    ("Promise", 1),
+    ("Promise.all", 1),
+    ("Promise.allSettled", 1),
+    ("Promise.any", 1),
+    ("Promise.race", 1),
+    ("Promise.reject", 1),
+    ("Promise.resolve", 1),
+    ("Promise.withResolvers", 0),
+    ("Promise.prototype.catch", 1),
+    ("Promise.prototype.finally", 1),
+    ("Promise.prototype.then", 2),
+    ("Promise[Symbol.species] getter", 0),
+    ("Set", 0),
+    ("Set.prototype.add", 1),
+    ("Set.prototype.clear", 0),
+    ("Set.prototype.delete", 1),
+    ("Set.prototype.entries", 0),
+    ("Set.prototype.forEach", 1),
+    ("Set.prototype.has", 1),
+    ("Set.prototype.keys", 0),
+    ("Set.prototype.size getter", 0),
+    ("Set.prototype.values", 0),
+    ("Set.prototype[Symbol.iterator]", 0),
+    ("Set[Symbol.species] getter", 0),
+    ("String", 1),
+    ("String.prototype.charAt", 1),
+    ("String.prototype.charCodeAt", 1),
+    ("String.prototype.codePointAt", 1),
+    ("String.prototype.toString", 0),
+    ("String.prototype.concat", 1),
+    ("String.prototype.repeat", 1),
+    ("String.prototype.slice", 2),
+    ("String.prototype.startsWith", 1),
+    ("String.prototype.endsWith", 1),
+    ("String.prototype.includes", 1),
+    ("String.prototype.indexOf", 1),
+    ("String.prototype.lastIndexOf", 1),
+    ("String.prototype.localeCompare", 1),
+    ("String.prototype.match", 1),
+    ("String.prototype.normalize", 0),
+    ("String.prototype.padEnd", 1),
+    ("String.prototype.padStart", 1),
+    ("String.prototype.trim", 0),
+    ("String.prototype.trimStart", 0),
+    ("String.prototype.trimEnd", 0),
+    ("String.prototype.toLowerCase", 0),
+    ("String.prototype.toUpperCase", 0),
+    ("String.prototype.toLocaleLowerCase", 0),
+    ("String.prototype.toLocaleUpperCase", 0),
+    ("String.prototype.substring", 2),
+    ("String.prototype.substr", 2),
+    ("String.prototype.split", 2),
+    ("String.prototype.valueOf", 0),
+    ("String.prototype.matchAll", 1),
+    ("String.prototype.replace", 2),
+    ("String.prototype.replaceAll", 2),
+    ("String.prototype.search", 1),
+    ("String.prototype.at", 1),
+    ("String.prototype[Symbol.iterator]", 0),
+    ("RegExp", 2),
+    ("RegExp.prototype.test", 1),
+    ("RegExp.prototype.exec", 1),
+    ("RegExp.prototype.toString", 0),
+    ("RegExp.prototype.global getter", 0),
+    ("RegExp.prototype.ignoreCase getter", 0),
+    ("RegExp.prototype.multiline getter", 0),
+    ("RegExp.prototype.dotAll getter", 0),
+    ("RegExp.prototype.unicode getter", 0),
+    ("RegExp.prototype.unicodeSets getter", 0),
+    ("RegExp.prototype.sticky getter", 0),
+    ("RegExp.prototype.flags getter", 0),
+    ("RegExp.prototype.source getter", 0),
+    ("RegExp.prototype[Symbol.match]", 1),
+    ("RegExp.prototype[Symbol.matchAll]", 1),
+    ("RegExp.prototype[Symbol.replace]", 2),
+    ("RegExp.prototype[Symbol.search]", 1),
+    ("RegExp.prototype[Symbol.split]", 2),
+    ("RegExp[Symbol.species] getter", 0),
+    ("Symbol", 0),
+    ("Symbol.for", 1),
+    ("Symbol.keyFor", 1),
+    ("Symbol.prototype.toString", 0),
+    ("Symbol.prototype.valueOf", 0),
+    ("Symbol.prototype.description getter", 0),
+    ("Symbol.prototype[Symbol.toPrimitive]", 1),
+    ("Error", 1),
+    ("Error.prototype.toString", 0),
+    ("RangeError", 1),
+    ("ReferenceError", 1),
+    ("TypeError", 1),
+    ("SyntaxError", 1),
+    ("EvalError", 1),
+    ("URIError", 1),
+    ("AggregateError", 2),
+    ("Reflect.apply", 3),
+    ("Reflect.construct", 2),
+    ("Reflect.defineProperty", 3),
+    ("Reflect.deleteProperty", 2),
+    ("Reflect.get", 2),
+    ("Reflect.getOwnPropertyDescriptor", 2),
+    ("Reflect.getPrototypeOf", 1),
+    ("Reflect.has", 2),
+    ("Reflect.isExtensible", 1),
+    ("Reflect.ownKeys", 1),
+    ("Reflect.preventExtensions", 1),
+    ("Reflect.set", 3),
+    ("Reflect.setPrototypeOf", 2),
+    ("Proxy", 2),
+    ("Proxy.revocable", 2),
+    ("WeakMap", 0),
+    ("WeakMap.prototype.delete", 1),
+    ("WeakMap.prototype.get", 1),
+    ("WeakMap.prototype.has", 1),
+    ("WeakMap.prototype.set", 2),
+    ("WeakSet", 0),
+    ("WeakSet.prototype.add", 1),
+    ("WeakSet.prototype.delete", 1),
+    ("WeakSet.prototype.has", 1),
+    ("WeakRef", 1),
+    ("WeakRef.prototype.deref", 0),
+    ("FinalizationRegistry", 1),
+    ("FinalizationRegistry.prototype.register", 2),
+    ("FinalizationRegistry.prototype.unregister", 1),
+    ("ArrayBuffer", 1),
+    ("ArrayBuffer.isView", 1),
+    ("ArrayBuffer.prototype.byteLength getter", 0),
+    ("ArrayBuffer.prototype.maxByteLength getter", 0),
+    ("ArrayBuffer.prototype.resizable getter", 0),
+    ("ArrayBuffer.prototype.resize", 1),
+    ("ArrayBuffer.prototype.slice", 2),
+    ("ArrayBuffer[Symbol.species] getter", 0),
+    ("SharedArrayBuffer", 1),
+    ("SharedArrayBuffer.prototype.byteLength getter", 0),
+    ("SharedArrayBuffer.prototype.slice", 2),
+    ("SharedArrayBuffer[Symbol.species] getter", 0),
+    ("Atomics.add", 3),
+    ("Atomics.and", 3),
+    ("Atomics.compareExchange", 4),
+    ("Atomics.exchange", 3),
+    ("Atomics.isLockFree", 1),
+    ("Atomics.load", 2),
+    ("Atomics.or", 3),
+    ("Atomics.store", 3),
+    ("Atomics.sub", 3),
+    ("Atomics.wait", 4),
+    ("Atomics.notify", 3),
+    ("Atomics.xor", 3),
+    ("DataView", 1),
+    ("DataView.prototype.buffer getter", 0),
+    ("DataView.prototype.byteLength getter", 0),
+    ("DataView.prototype.byteOffset getter", 0),
+    ("DataView.prototype.getBigInt64", 1),
+    ("DataView.prototype.getBigUint64", 1),
+    ("DataView.prototype.getFloat32", 1),
+    ("DataView.prototype.getFloat64", 1),
+    ("DataView.prototype.getInt8", 1),
+    ("DataView.prototype.getInt16", 1),
+    ("DataView.prototype.getInt32", 1),
+    ("DataView.prototype.getUint8", 1),
+    ("DataView.prototype.getUint16", 1),
+    ("DataView.prototype.getUint32", 1),
+    ("DataView.prototype.setBigInt64", 2),
+    ("DataView.prototype.setBigUint64", 2),
+    ("DataView.prototype.setFloat32", 2),
+    ("DataView.prototype.setFloat64", 2),
+    ("DataView.prototype.setInt8", 2),
+    ("DataView.prototype.setInt16", 2),
+    ("DataView.prototype.setInt32", 2),
+    ("DataView.prototype.setUint8", 2),
+    ("DataView.prototype.setUint16", 2),
+    ("DataView.prototype.setUint32", 2),
+    ("Int8Array", 3),
+    ("Uint8Array", 3),
+    ("Uint8ClampedArray", 3),
+    ("Int16Array", 3),
+    ("Uint16Array", 3),
+    ("Int32Array", 3),
+    ("Uint32Array", 3),
+    ("BigInt64Array", 3),
+    ("BigUint64Array", 3),
+    ("Float32Array", 3),
+    ("Float64Array", 3),
+    ("%ArrayIteratorPrototype%.next", 0),
+    ("%MapIteratorPrototype%.next", 0),
+    ("%SetIteratorPrototype%.next", 0),
+    ("%StringIteratorPrototype%.next", 0),
+    ("%RegExpStringIteratorPrototype%.next", 0),
+    ("%TypedArray%.from", 1),
+    ("%TypedArray%.of", 0),
+    ("%TypedArray%.prototype.buffer getter", 0),
+    ("%TypedArray%.prototype.byteLength getter", 0),
+    ("%TypedArray%.prototype.byteOffset getter", 0),
+    ("%TypedArray%.prototype.length getter", 0),
+    ("%TypedArray%.prototype.values", 0),
+    ("%TypedArray%.prototype.toString", 0),
+    ("%TypedArray%.prototype.at", 1),
+    ("%TypedArray%.prototype.copyWithin", 2),
+    ("%TypedArray%.prototype.entries", 0),
+    ("%TypedArray%.prototype.every", 1),
+    ("%TypedArray%.prototype.fill", 1),
+    ("%TypedArray%.prototype.filter", 1),
+    ("%TypedArray%.prototype.find", 1),
+    ("%TypedArray%.prototype.findIndex", 1),
+    ("%TypedArray%.prototype.forEach", 1),
+    ("%TypedArray%.prototype.includes", 1),
+    ("%TypedArray%.prototype.indexOf", 1),
+    ("%TypedArray%.prototype.join", 1),
+    ("%TypedArray%.prototype.keys", 0),
+    ("%TypedArray%.prototype.lastIndexOf", 1),
+    ("%TypedArray%.prototype.map", 1),
+    ("%TypedArray%.prototype.reduce", 1),
+    ("%TypedArray%.prototype.reduceRight", 1),
+    ("%TypedArray%.prototype.reverse", 0),
+    ("%TypedArray%.prototype.set", 1),
+    ("%TypedArray%.prototype.slice", 2),
+    ("%TypedArray%.prototype.some", 1),
+    ("%TypedArray%.prototype.sort", 1),
+    ("%TypedArray%.prototype.subarray", 2),
+    ("%TypedArray%.prototype.toLocaleString", 0),
+    ("%TypedArray%.prototype.toReversed", 0),
+    ("%TypedArray%.prototype.toSorted", 1),
+    ("%TypedArray%.prototype.with", 2),
+    ("%TypedArray%.prototype[Symbol.toStringTag] getter", 0),
+    ("%TypedArray%.prototype[Symbol.iterator]", 0),
+    ("%TypedArray%[Symbol.species] getter", 0),
+    ("%GeneratorFunction%.prototype.prototype.next", 1),
+    ("%GeneratorFunction%.prototype.prototype.return", 1),
+    ("%GeneratorFunction%.prototype.prototype.throw", 1),
+    ("%AsyncGeneratorFunction%.prototype.prototype.next", 1),
+    ("%AsyncGeneratorFunction%.prototype.prototype.return", 1),
+    ("%AsyncGeneratorFunction%.prototype.prototype.throw", 1),
+    ("%TypedArray%", 0),
+    ("%GeneratorFunction%", 1),
+    ("%AsyncFunction%", 1),
+    ("%AsyncGeneratorFunction%", 1),
+];
+
+/// The `length` of the methods of the console, as given by the WHATWG console specification.
+#[cfg(feature = "console")]
+const CONSOLE_LENGTHS: &[(&str, u32)] = &[
+    ("console.assert", 0),
+    ("console.clear", 0),
+    ("console.debug", 0),
+    ("console.error", 0),
+    ("console.info", 0),
+    ("console.log", 0),
+    ("console.trace", 0),
+    ("console.warn", 0),
+    ("console.exception", 0),
+    ("console.count", 0),
+    ("console.countReset", 0),
+    ("console.group", 0),
+    ("console.groupCollapsed", 0),
+    ("console.groupEnd", 0),
+    ("console.time", 0),
+    ("console.timeLog", 0),
+    ("console.timeEnd", 0),
+    ("console.dir", 0),
+    ("console.dirxml", 0),
+    ("console.table", 0),
+];
+
+#[test]
+fn builtin_function_names_and_lengths() {
+    // Reflects over the intrinsics reachable from the global object, checking the `name` of
+    // every builtin function against the property key it is installed under, and its `length`
+    // against the spec.
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        const aliases = {
+            "Array.prototype[Symbol.iterator]": "values",
+            "%TypedArray%.prototype[Symbol.iterator]": "values",
+            "Map.prototype[Symbol.iterator]": "entries",
+            "Set.prototype[Symbol.iterator]": "values",
+            "Set.prototype.keys": "values",
+            "String.prototype.trimLeft": "trimStart",
+            "String.prototype.trimRight": "trimEnd",
+            "Date.prototype.toGMTString": "toUTCString",
+            "Function.prototype": ""
+        };
+        const failures = [];
+        const lengths = [];
+        const visited = new Set();
+        const describe = function (key) {
+            return typeof key === "symbol" ? "[" + key.description + "]" : key;
+        };
+        const pathOf = function (base, key) {
+            if (base === "") { return key; }
+            return typeof key === "symbol" ? base + "[Symbol." + key.description.slice(7) + "]" : base + "." + key;
+        };
+        const checkAttributes = function (path, f, key) {
+            var desc = Object.getOwnPropertyDescriptor(f, key);
+            if (desc === undefined) { failures.push(path + " has no own " + key); return; }
+            if (desc.writable || desc.enumerable || !desc.configurable) {
+                failures.push(path + "." + key + " has the wrong attributes");
+            }
+        };
+        const checkFunction = function (path, f, expected) {
+            if (path in aliases) { expected = aliases[path]; }
+            checkAttributes(path, f, "name");
+            checkAttributes(path, f, "length");
+            if (f.name !== expected) { failures.push(path + ".name is " + JSON.stringify(f.name)); }
+            if (typeof f.length !== "number") { failures.push(path + ".length is not a number"); }
+            lengths.push(path + "=" + f.length);
+        };
+        const walk = function (path, o) {
+            if (visited.has(o)) { return; }
+            visited.add(o);
+            var keys = Reflect.ownKeys(o);
+            for (var i = 0; i < keys.length; i++) {
+                var key = keys[i];
+                var desc = Object.getOwnPropertyDescriptor(o, key);
+                var p = pathOf(path, key);
+                if (desc.get) { checkFunction(p + " getter", desc.get, "get " + describe(key)); }
+                if (desc.set) { checkFunction(p + " setter", desc.set, "set " + describe(key)); }
+                var v = desc.value;
+                if (typeof v === "function" && key !== "constructor") {
+                    checkFunction(p, v, describe(key));
+                    walk(p, v);
+                } else if (typeof v === "object" && v !== null && key !== "globalThis") {
+                    walk(p, v);
+                }
+            }
+        };
+        const roots = {
+            "globalThis": globalThis,
+            "%ArrayIteratorPrototype%": Object.getPrototypeOf([][Symbol.iterator]()),
+            "%MapIteratorPrototype%": Object.getPrototypeOf(new Map()[Symbol.iterator]()),
+            "%SetIteratorPrototype%": Object.getPrototypeOf(new Set()[Symbol.iterator]()),
+            "%StringIteratorPrototype%": Object.getPrototypeOf(""[Symbol.iterator]()),
+            "%RegExpStringIteratorPrototype%": Object.getPrototypeOf(/a/[Symbol.matchAll]("")),
+            "%IteratorPrototype%": Object.getPrototypeOf(Object.getPrototypeOf([][Symbol.iterator]())),
+            "%TypedArray%": Object.getPrototypeOf(Int8Array),
+            "%GeneratorFunction%": Object.getPrototypeOf(function* () {}).constructor,
+            "%AsyncFunction%": Object.getPrototypeOf(async function f() {}).constructor,
+            "%AsyncGeneratorFunction%": Object.getPrototypeOf(async function* g() {}).constructor
+        };
+        for (const name in roots) {
+            try {
+                if (typeof roots[name] === "function") { checkFunction(name, roots[name], name.slice(1, -1)); }
+                walk(name === "globalThis" ? "" : name, roots[name]);
+            } catch (e) {
+                failures.push(name + " " + e);
+            }
+        }
+        "#,
+    );
+    assert_eq!(forward(&mut context, "failures.join('\\n')"), "\"\"");
+
+    let lengths = forward_val(&mut context, "lengths.join('\\n')")
+        .unwrap()
+        .to_string(&mut context)
+        .unwrap();
+    let mut expected: Vec<_> = SPEC_LENGTHS.iter().collect();
+    #[cfg(feature = "console")]
+    expected.extend(CONSOLE_LENGTHS);
+    let mut missing: Vec<_> = expected.iter().map(|(path, _)| *path).collect();
+    for line in lengths.as_str().lines() {
+        let (path, length) = line.rsplit_once('=').unwrap();
+        match expected.iter().find(|(expected, _)| *expected == path) {
+            Some((_, expected)) => assert_eq!(
+                length,
+                expected.to_string(),
+                "the length of {} differs from the spec",
+                path
+            ),
+            None => panic!("{} is missing from the spec lengths", path),
+        }
+        missing.retain(|expected| *expected != path);
+    }
+    assert!(
+        missing.is_empty(),
+        "the spec lengths list functions that don't exist: {:?}",
+        missing
+    );
+}
@@ -0,0 +1,320 @@
+//! This module implements the `Generator` object.
+//!
+//! Calling a generator function does not run its body, it returns a generator instead. The frame
+//! of the body is saved in the generator, which runs it in the vm when one of its methods is
+//! called, until the next `yield` expression suspends it again.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{JsObject, Object, ObjectData, PROTOTYPE},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    teardown::Contents,
+    value::ArgumentsExt,
+    vm::{CodeBlock, CompletionType, FrameState, SuspendedFrame, Suspension},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The kind of completion a generator is resumed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GeneratorResumeKind {
+    Normal,
+    Return,
+    Throw,
+}
+
+/// The internal representation of a `Generator` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct Generator {
+    #[unsafe_ignore_trace]
+    state: GeneratorState,
+    /// The frame running the body of the generator, while it is suspended.
+    frame: Option<SuspendedFrame>,
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Queues the values and environments of the suspended frame to be emptied by the teardown
+    /// of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        if let Some(frame) = &self.frame {
+            frame.collect_contents(contents);
+        }
+    }
+
+    /// Create the `%GeneratorPrototype%` object, and link it with `%GeneratorFunction.prototype%`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: JsValue) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let generator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, context);
+        generator.set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value(Self::NAME)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        generator.insert(to_string_tag, to_string_tag_property);
+
+        let generator_function = context
+            .standard_objects()
+            .generator_function_object()
+            .prototype();
+        generator.insert_property(
+            "constructor",
+            PropertyDescriptor::builder()
+                .value(generator_function.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+        );
+        generator_function.insert_property(
+            PROTOTYPE,
+            PropertyDescriptor::builder()
+                .value(generator.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+        );
+
+        generator
+    }
+
+    /// `EvaluateGeneratorBody`
+    ///
+    /// Creates the generator returned by a call to a generator function, whose body runs in a
+    /// frame on top of the given environments of the call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-evaluategeneratorbody
+    pub(crate) fn start(
+        function: &JsObject,
+        code: Rc<CodeBlock>,
+        environments: Vec<Environment>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? FunctionDeclarationInstantiation(functionObject, argumentsList).
+        // 2. Let G be ? OrdinaryCreateFromConstructor(functionObject, "%GeneratorFunction.prototype.prototype%", « [[GeneratorState]], [[GeneratorContext]], [[GeneratorBrand]] »).
+        let prototype = function.get(PROTOTYPE, context)?;
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            context.iterator_prototypes().generator().into()
+        };
+
+        // 3. Set G.[[GeneratorBrand]] to empty.
+        // 4. Perform GeneratorStart(G, FunctionBody).
+        let generator = Self {
+            state: GeneratorState::SuspendedStart,
+            frame: Some(SuspendedFrame::new(code, environments)),
+        };
+
+        // 5. Return Completion { [[Type]]: return, [[Value]]: G, [[Target]]: empty }.
+        Ok(JsObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::generator(generator),
+        ))
+        .into())
+    }
+
+    /// `Generator.prototype.next ( value )`
+    ///
+    /// Resumes the generator, making the suspended `yield` expression evaluate to the given
+    /// value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Return ? GeneratorResume(g, value, empty).
+        let value = args.get_or_undefined(0).clone();
+        Self::resume(this, value, GeneratorResumeKind::Normal, context)
+    }
+
+    /// `Generator.prototype.return ( value )`
+    ///
+    /// Resumes the generator as if the suspended `yield` expression were a `return` statement,
+    /// running the `finally` blocks that enclose it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be Completion { [[Type]]: return, [[Value]]: value, [[Target]]: empty }.
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        let value = args.get_or_undefined(0).clone();
+        Self::resume(this, value, GeneratorResumeKind::Return, context)
+    }
+
+    /// `Generator.prototype.throw ( exception )`
+    ///
+    /// Resumes the generator as if the suspended `yield` expression were a `throw` statement.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be ThrowCompletion(exception).
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        let exception = args.get_or_undefined(0).clone();
+        Self::resume(this, exception, GeneratorResumeKind::Throw, context)
+    }
+
+    /// `GeneratorResume ( generator, value, generatorBrand )` and
+    /// `GeneratorResumeAbrupt ( generator, abruptCompletion, generatorBrand )`
+    ///
+    /// Resumes the frame of a generator with the given completion, until the body of the
+    /// generator yields or completes.
+    ///
+    /// A `return` completion unwinds the frame from the suspended `yield` expression, running
+    /// the `finally` blocks that enclose it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(
+        this: &JsValue,
+        value: JsValue,
+        kind: GeneratorResumeKind,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_generator().is_some() => object,
+            _ => return Err(context.construct_type_error("`this` is not a generator")),
+        };
+        let frame = {
+            let mut object = object.borrow_mut();
+            let generator = object
+                .as_generator_mut()
+                .expect("checked to be a generator");
+            match (generator.state, kind) {
+                (GeneratorState::Executing, _) => {
+                    return Err(context.construct_type_error("generator is already running"))
+                }
+                // 2. If state is suspendedStart, then
+                //     a. Set generator.[[GeneratorState]] to completed.
+                //     b. Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with generator can be discarded at this point.
+                //     c. Set state to completed.
+                (GeneratorState::SuspendedStart, GeneratorResumeKind::Return)
+                | (GeneratorState::SuspendedStart, GeneratorResumeKind::Throw) => {
+                    generator.state = GeneratorState::Completed;
+                    generator.frame = None;
+                }
+                _ => {}
+            }
+
+            if generator.state == GeneratorState::Completed {
+                None
+            } else {
+                generator.state = GeneratorState::Executing;
+                generator.frame.take()
+            }
+        };
+
+        // 3. If state is completed, then
+        //     a. If abruptCompletion.[[Type]] is return, then
+        //         i. Return CreateIterResultObject(abruptCompletion.[[Value]], true).
+        //     b. Return Completion(abruptCompletion).
+        let frame = match (frame, kind) {
+            (Some(frame), _) => frame,
+            (None, GeneratorResumeKind::Normal) => {
+                return Ok(create_iter_result_object(
+                    context,
+                    JsValue::undefined(),
+                    true,
+                ))
+            }
+            (None, GeneratorResumeKind::Return) => {
+                return Ok(create_iter_result_object(context, value, true))
+            }
+            (None, GeneratorResumeKind::Throw) => return Err(value),
+        };
+        let completion = match kind {
+            GeneratorResumeKind::Normal => CompletionType::Normal,
+            GeneratorResumeKind::Return => CompletionType::Return,
+            GeneratorResumeKind::Throw => CompletionType::Throw,
+        };
+        let state = frame.resume(value, completion, context);
+
+        let mut object = object.borrow_mut();
+        let generator = object
+            .as_generator_mut()
+            .expect("checked to be a generator");
+        match state {
+            // The yielded value is already an iterator result.
+            FrameState::Suspended(Suspension::Yield(result), frame) => {
+                generator.state = GeneratorState::SuspendedYield;
+                generator.frame = Some(frame);
+                Ok(result)
+            }
+            FrameState::Suspended(Suspension::Await(_), _) => {
+                unreachable!("generators do not await")
+            }
+            FrameState::Complete(result) => {
+                generator.state = GeneratorState::Completed;
+                drop(object);
+                Ok(create_iter_result_object(context, result?, true))
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{forward, Context};
+
+#[test]
+fn next_runs_until_yield() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        function* g() {
+            log.push("start");
+            yield 1;
+            log.push("resumed");
+            yield 2;
+            return 3;
+        }
+        var gen = g();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    forward(&mut context, "var result = gen.next()");
+    assert_eq!(forward(&mut context, "result.value"), "1");
+    assert_eq!(forward(&mut context, "result.done"), "false");
+    assert_eq!(forward(&mut context, "log.join()"), "\"start\"");
+    assert_eq!(forward(&mut context, "gen.next().value"), "2");
+    forward(&mut context, "result = gen.next()");
+    assert_eq!(forward(&mut context, "result.value"), "3");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+    forward(&mut context, "result = gen.next()");
+    assert_eq!(forward(&mut context, "result.value"), "undefined");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"start,resumed\"");
+}
+
+#[test]
+fn next_passes_values() {
+    let mut context = Context::new();
+    let init = r#"
+        var g = function*(x) {
+            const y = yield x;
+            const z = yield x + y;
+            return x + y + z;
+        };
+        var gen = g(1);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "gen.next(100).value"), "1");
+    assert_eq!(forward(&mut context, "gen.next(2).value"), "3");
+    assert_eq!(forward(&mut context, "gen.next(3).value"), "6");
+}
+
+#[test]
+fn return_runs_finally() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        function* g() {
+            try {
+                yield 1;
+                log.push("unreachable");
+            } finally {
+                log.push("finally");
+            }
+        }
+        var gen = g();
+        gen.next();
+        var result = gen.return(5);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.value"), "5");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"finally\"");
+    assert_eq!(forward(&mut context, "gen.next().done"), "true");
+}
+
+#[test]
+fn return_before_start() {
+    let mut context = Context::new();
+    let init = r#"
+        var started = false;
+        function* g() { started = true; yield 1; }
+        var gen = g();
+        var result = gen.return(2);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.value"), "2");
+    assert_eq!(forward(&mut context, "started"), "false");
+    assert_eq!(forward(&mut context, "gen.next().done"), "true");
+}
+
+#[test]
+fn throw_is_caught_inside() {
+    let mut context = Context::new();
+    let init = r#"
+        function* g() {
+            while (true) {
+                try {
+                    yield 1;
+                } catch (e) {
+                    yield "caught " + e;
+                }
+            }
+        }
+        var gen = g();
+        gen.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "gen.throw('err').value"),
+        "\"caught err\""
+    );
+    assert_eq!(forward(&mut context, "gen.next().value"), "1");
+    assert_eq!(
+        forward(&mut context, "try { g().throw('x') } catch (e) { e }"),
+        "\"x\""
+    );
+}
+
+#[test]
+fn yield_delegate() {
+    let mut context = Context::new();
+    let init = r#"
+        function* inner() {
+            const x = yield 1;
+            yield x;
+            return "inner done";
+        }
+        function* outer() {
+            const result = yield* inner();
+            yield result;
+            yield* [4, 5];
+        }
+        var gen = outer();
+        var values = [gen.next().value, gen.next(2).value, gen.next().value, gen.next().value, gen.next().value];
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "values.join()"),
+        "\"1,2,inner done,4,5\""
+    );
+    assert_eq!(forward(&mut context, "gen.next().done"), "true");
+}
+
+#[test]
+fn iteration() {
+    let mut context = Context::new();
+    let init = r#"
+        function* range(n) {
+            for (let i = 0; i < n; i++) {
+                yield i;
+            }
+        }
+        var sum = 0;
+        for (const i of range(4)) {
+            sum += i;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sum"), "6");
+    assert_eq!(forward(&mut context, "[...range(3)].join()"), "\"0,1,2\"");
+}
+
+#[test]
+fn prototype_chain() {
+    let mut context = Context::new();
+    let init = r#"
+        function* g() {}
+        var GeneratorFunction = Object.getPrototypeOf(g);
+        var Generator = GeneratorFunction.prototype;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(g()) === g.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(g.prototype) === Generator"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "GeneratorFunction.constructor.name"),
+        "\"GeneratorFunction\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(g())"),
+        "\"[object Generator]\""
+    );
+    assert_eq!(
+        forward(&mut context, "g()[Symbol.iterator]() instanceof g"),
+        "true"
+    );
+}
+
+#[test]
+fn already_running() {
+    let mut context = Context::new();
+    let init = r#"
+        var gen;
+        function* g() {
+            try {
+                gen.next();
+            } catch (e) {
+                yield e instanceof TypeError;
+            }
+        }
+        gen = g();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "gen.next().value"), "true");
+}
+
+#[test]
+fn not_a_constructor() {
+    let mut context = Context::new();
+    let init = r#"
+        function* g() {}
+        var error;
+        try {
+            new g();
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+}
@@ -0,0 +1,74 @@
+//! This module implements the `GeneratorFunction` object, the constructor of generator functions.
+//!
+//! `GeneratorFunction` is not a global object, it is reached through the `constructor` property
+//! of the prototype of generator functions.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generatorfunction-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/GeneratorFunction
+
+use crate::{
+    builtins::function::{create_dynamic_function, DynamicFunctionKind},
+    object::ConstructorBuilder,
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GeneratorFunction;
+
+impl GeneratorFunction {
+    /// The name of the `GeneratorFunction` constructor.
+    const NAME: &'static str = "GeneratorFunction";
+
+    /// The amount of arguments the `GeneratorFunction` constructor takes.
+    const LENGTH: usize = 1;
+
+    /// Initializes the `%GeneratorFunction%` intrinsic object.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function_object = context.standard_objects().function_object().clone();
+        let generator_function_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context
+                .standard_objects()
+                .generator_function_object()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(function_object.prototype().into())
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        generator_function_object.set_prototype_instance(function_object.constructor().into());
+    }
+
+    /// `GeneratorFunction ( p1, p2, … , pn, body )`
+    ///
+    /// Creates a generator function from the source text of its parameters and body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorfunction
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/GeneratorFunction
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        create_dynamic_function(new_target, args, DynamicFunctionKind::Generator, context)
+    }
+}
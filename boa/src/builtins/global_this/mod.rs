@@ -22,7 +22,7 @@ impl BuiltIn for GlobalThis {
     const NAME: &'static str = "globalThis";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -30,7 +30,7 @@ impl BuiltIn for GlobalThis {
 
         (
             Self::NAME,
-            context.global_object().into(),
+            context.global_this_binding().into(),
             Self::attribute(),
         )
     }
@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, forward, object::JsObject, property::Attribute, Context};
 
 #[test]
 fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
@@ -8,3 +8,52 @@ fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
 
     assert_eq!(&exec(scenario), "\"object\"");
 }
+
+#[test]
+fn global_this_is_the_global_this_binding() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "function sloppy() { return this; } globalThis === this && sloppy() === globalThis"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var desc = Object.getOwnPropertyDescriptor(globalThis, 'globalThis');
+            [desc.writable, desc.enumerable, desc.configurable].join()
+            "#
+        ),
+        "\"true,false,true\""
+    );
+}
+
+#[test]
+fn global_this_reflects_a_custom_binding() {
+    let mut context = Context::new();
+    let this_value = context.construct_object();
+    context.set_global_this_binding(this_value.clone());
+    assert!(JsObject::equals(
+        &context.global_this_binding(),
+        &this_value
+    ));
+
+    forward(&mut context, "var declared = 1;");
+    assert_eq!(
+        forward(
+            &mut context,
+            "function sloppy() { return this; } [this === globalThis, sloppy() === globalThis, globalThis.declared].join()"
+        ),
+        "\"true,true,\""
+    );
+    // A `globalThis` property that was changed since is left alone.
+    context.register_global_property("globalThis", "replaced", Attribute::builtin());
+    let other = context.construct_object();
+    context.set_global_this_binding(other);
+    assert_eq!(forward(&mut context, "globalThis"), "\"replaced\"");
+    assert_eq!(forward(&mut context, "typeof this"), "\"object\"");
+}
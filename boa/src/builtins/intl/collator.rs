@@ -0,0 +1,844 @@
+//! This module implements the `Intl.Collator` constructor and the locale-sensitive comparison
+//! of strings.
+//!
+//! Strings are compared in the manner of the [Unicode Collation Algorithm][uca]: they are
+//! compared by their base letters first, then by their accents, and finally by their case. Each
+//! of these levels only breaks the ties of the previous ones, so `"a" < "á" < "b"`.
+//!
+//! The root collation is tailored for the languages whose alphabets treat some accented letters
+//! as letters of their own, like `ñ` in Spanish or `ä` in Swedish, and for the German phonebook
+//! collation.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#collator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator
+//! [uca]: https://unicode.org/reports/tr10/
+
+use crate::{
+    builtins::{
+        intl::{canonicalize_locale_list, get_bool_option, get_option, LanguageTag},
+        Array, BuiltIn,
+    },
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+use std::cmp::Ordering;
+use unicode_normalization::{
+    char::{decompose_canonical, is_combining_mark},
+    UnicodeNormalization,
+};
+
+/// The locale used when no locale is requested.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// Which differences between strings make them unequal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Sensitivity {
+    /// Only strings with different base letters are unequal: `a ≠ b`, `a = á`, `a = A`.
+    Base,
+    /// Strings with different base letters or accents are unequal: `a ≠ á`, `a = A`.
+    Accent,
+    /// Strings with different base letters or case are unequal: `a = á`, `a ≠ A`.
+    Case,
+    /// All differences make strings unequal: `a ≠ á`, `a ≠ A`.
+    Variant,
+}
+
+impl Sensitivity {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Base => "base",
+            Self::Accent => "accent",
+            Self::Case => "case",
+            Self::Variant => "variant",
+        }
+    }
+}
+
+/// Whether uppercase or lowercase letters sort first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CaseFirst {
+    Upper,
+    Lower,
+    False,
+}
+
+impl CaseFirst {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+            Self::False => "false",
+        }
+    }
+}
+
+/// The data of an `Intl.Collator` object, and the options of a locale-sensitive string
+/// comparison.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Collator {
+    /// The resolved locale.
+    locale: JsString,
+    /// Whether the collator is used for sorting or searching.
+    usage: JsString,
+    /// The name of the collation, `default` for the standard collation of the locale.
+    collation: JsString,
+    #[unsafe_ignore_trace]
+    table: Option<&'static CollationTable>,
+    #[unsafe_ignore_trace]
+    sensitivity: Sensitivity,
+    numeric: bool,
+    #[unsafe_ignore_trace]
+    case_first: CaseFirst,
+    ignore_punctuation: bool,
+    /// The function returned by the `compare` getter, once created.
+    bound_compare: Option<JsObject>,
+}
+
+impl BuiltIn for Collator {
+    const NAME: &'static str = "Collator";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_compare = FunctionBuilder::native(context, Self::get_compare)
+            .name("get compare")
+            .constructable(false)
+            .build();
+
+        let collator = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().collator_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor(
+            "compare",
+            Some(get_compare),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.Collator",
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, collator.into(), Self::attribute())
+    }
+}
+
+impl Collator {
+    /// Creates a collator from the `locales` and `options` arguments of a locale-sensitive
+    /// comparison.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializecollator
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        let options = options.as_ref();
+
+        // 3. Let usage be ? GetOption(options, "usage", "string", « "sort", "search" », "sort").
+        // 4. Set collator.[[Usage]] to usage.
+        let usage = get_option(options, "usage", &["sort", "search"], context)?
+            .unwrap_or_else(|| JsString::from("sort"));
+
+        // 8. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        get_option(options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 10. Let collation be ? GetOption(options, "collation", "string", undefined, undefined).
+        // 11. If collation is not undefined, then
+        //     a. If collation does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        let collation = get_option(options, "collation", &[], context)?;
+        if let Some(collation) = &collation {
+            if LanguageTag::parse(&format!("und-u-co-{}", collation)).is_none() {
+                return Err(
+                    context.construct_range_error(format!("Invalid collation: {}", collation))
+                );
+            }
+        }
+
+        // 13. Let numeric be ? GetOption(options, "numeric", "boolean", undefined, undefined).
+        let numeric = get_bool_option(options, "numeric", context)?;
+
+        // 16. Let caseFirst be ? GetOption(options, "caseFirst", "string", « "upper", "lower", "false" », undefined).
+        let case_first = get_option(options, "caseFirst", &["upper", "lower", "false"], context)?;
+
+        // 18. Let relevantExtensionKeys be %Collator%.[[RelevantExtensionKeys]].
+        // 19. Let r be ResolveLocale(%Collator%.[[AvailableLocales]], requestedLocales, opt, relevantExtensionKeys, localeData).
+        //
+        // The root collation applies to every language, so every locale is available and the
+        // first requested locale is used.
+        let locale = requested_locales
+            .first()
+            .and_then(|locale| LanguageTag::parse(locale))
+            .unwrap_or_else(|| {
+                LanguageTag::parse(DEFAULT_LOCALE).expect("the default locale should be valid")
+            });
+        let keyword = |key| locale.unicode_keyword(key);
+
+        // The extension keywords of the locale apply when the options do not override them, and
+        // are kept in the resolved locale.
+        let mut extensions = Vec::new();
+
+        // 21. Let collation be r.[[co]].
+        // 22. If collation is null, let collation be "default".
+        // 23. Set collator.[[Collation]] to collation.
+        let language = locale.language();
+        let requested_collation = match collation {
+            Some(collation) => Some(collation.to_string()),
+            None => keyword("co").filter(|co| {
+                let supported = CollationTable::lookup(language, Some(co)).is_some();
+                if supported {
+                    extensions.push(format!("co-{}", co));
+                }
+                supported
+            }),
+        };
+        let (collation, table) = match requested_collation
+            .as_deref()
+            .and_then(|co| Some((co, CollationTable::lookup(language, Some(co))?)))
+        {
+            Some((co, table)) => (co, Some(table)),
+            None => ("default", CollationTable::lookup(language, None)),
+        };
+
+        // 24. If relevantExtensionKeys contains "kn", then
+        //     a. Set collator.[[Numeric]] to ! SameValue(r.[[kn]], "true").
+        let numeric = match numeric {
+            Some(numeric) => numeric,
+            None => match keyword("kn").as_deref() {
+                Some("") => {
+                    extensions.push("kn".to_string());
+                    true
+                }
+                Some("false") => {
+                    extensions.push("kn-false".to_string());
+                    false
+                }
+                _ => false,
+            },
+        };
+
+        // 25. If relevantExtensionKeys contains "kf", then
+        //     a. Set collator.[[CaseFirst]] to r.[[kf]].
+        let case_first = match case_first {
+            Some(case_first) => Some(case_first.to_string()),
+            None => keyword("kf").filter(|kf| {
+                let supported = matches!(kf.as_str(), "upper" | "lower" | "false");
+                if supported {
+                    extensions.push(format!("kf-{}", kf));
+                }
+                supported
+            }),
+        };
+        let case_first = match case_first.as_deref() {
+            Some("upper") => CaseFirst::Upper,
+            Some("lower") => CaseFirst::Lower,
+            _ => CaseFirst::False,
+        };
+
+        // 20. Set collator.[[Locale]] to r.[[locale]].
+        extensions.sort();
+        let mut resolved_locale = locale.base_name();
+        if !extensions.is_empty() {
+            resolved_locale.push_str("-u-");
+            resolved_locale.push_str(&extensions.join("-"));
+        }
+
+        // 26. Let sensitivity be ? GetOption(options, "sensitivity", "string", « "base", "accent", "case", "variant" », undefined).
+        // 27. If sensitivity is undefined, then
+        //     a. If usage is "sort", then
+        //         i. Let sensitivity be "variant".
+        //     b. Else,
+        //         i. Let dataLocale be r.[[dataLocale]].
+        //         ii. Let dataLocaleData be localeData.[[<dataLocale>]].
+        //         iii. Let sensitivity be dataLocaleData.[[sensitivity]].
+        let sensitivity = match get_option(
+            options,
+            "sensitivity",
+            &["base", "accent", "case", "variant"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("base") => Sensitivity::Base,
+            Some("accent") => Sensitivity::Accent,
+            Some("case") => Sensitivity::Case,
+            _ => Sensitivity::Variant,
+        };
+
+        // 29. Let ignorePunctuation be ? GetOption(options, "ignorePunctuation", "boolean", undefined, false).
+        let ignore_punctuation =
+            get_bool_option(options, "ignorePunctuation", context)?.unwrap_or(false);
+
+        Ok(Self {
+            locale: resolved_locale.into(),
+            usage,
+            collation: collation.into(),
+            table,
+            sensitivity,
+            numeric,
+            case_first,
+            ignore_punctuation,
+            bound_compare: None,
+        })
+    }
+
+    /// Compares two strings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-collator-comparestrings
+    pub(crate) fn compare(&self, x: &str, y: &str) -> Ordering {
+        let x = self.collation_elements(x);
+        let y = self.collation_elements(y);
+
+        // Base letters are compared first.
+        let primary = x
+            .iter()
+            .map(|e| &e.primary)
+            .cmp(y.iter().map(|e| &e.primary));
+        if primary != Ordering::Equal {
+            return primary;
+        }
+
+        // Then accents.
+        if matches!(self.sensitivity, Sensitivity::Accent | Sensitivity::Variant) {
+            let secondary = x
+                .iter()
+                .map(|e| &e.accents)
+                .cmp(y.iter().map(|e| &e.accents));
+            if secondary != Ordering::Equal {
+                return secondary;
+            }
+        }
+
+        // And finally case.
+        if matches!(self.sensitivity, Sensitivity::Case | Sensitivity::Variant) {
+            let tertiary = x
+                .iter()
+                .map(|e| e.uppercase)
+                .cmp(y.iter().map(|e| e.uppercase));
+            return match self.case_first {
+                CaseFirst::Upper => tertiary.reverse(),
+                CaseFirst::Lower | CaseFirst::False => tertiary,
+            };
+        }
+
+        Ordering::Equal
+    }
+
+    /// Splits a string into the elements it is compared by.
+    fn collation_elements(&self, string: &str) -> Vec<CollationElement> {
+        // Tailored letters are compared as a whole, so only the other characters are decomposed.
+        let mut decomposed = Vec::with_capacity(string.len());
+        for c in string.nfc() {
+            let tailored = self.table.map_or(false, |table| {
+                c.to_lowercase()
+                    .next()
+                    .and_then(|c| table.tailoring(c))
+                    .is_some()
+            });
+            if tailored {
+                decomposed.push(c);
+            } else {
+                decompose_canonical(c, |c| decomposed.push(c));
+            }
+        }
+
+        let mut elements: Vec<CollationElement> = Vec::new();
+        let mut chars = decomposed.into_iter().peekable();
+        while let Some(c) = chars.next() {
+            if is_combining_mark(c) {
+                match elements.last_mut() {
+                    Some(element) => element.accents.push(c),
+                    None => elements.push(CollationElement {
+                        primary: Primary::Ignorable,
+                        accents: vec![c],
+                        uppercase: false,
+                    }),
+                }
+                continue;
+            }
+
+            let primary = if self.numeric && c.is_ascii_digit() {
+                let mut digits = String::from(c);
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                let digits = digits.trim_start_matches('0');
+                Primary::Number(digits.len(), digits.to_string())
+            } else if c.is_whitespace() {
+                Primary::Whitespace(c)
+            } else if c.is_alphanumeric() {
+                let mut lowercase = c.to_lowercase();
+                let first = lowercase.next().unwrap_or(c);
+                let uppercase = c.is_uppercase();
+                let letter = |primary| CollationElement {
+                    primary,
+                    accents: Vec::new(),
+                    uppercase,
+                };
+                match self.table.and_then(|table| table.tailoring(first)) {
+                    Some(Tailoring::After(base, rank)) => {
+                        elements.push(letter(Primary::Letter(*base, *rank)));
+                    }
+                    Some(Tailoring::Expansion(letters)) => {
+                        // The expansion only differs from the letters it expands to by the
+                        // accents of the tailored letter.
+                        let mut accents = Vec::new();
+                        decompose_canonical(first, |c| {
+                            if is_combining_mark(c) {
+                                accents.push(c);
+                            }
+                        });
+                        let start = elements.len();
+                        elements.extend(letters.chars().map(|c| letter(Primary::Letter(c, 0))));
+                        if let Some(element) = elements.get_mut(start) {
+                            element.accents = accents;
+                        }
+                    }
+                    None => {
+                        let primary = if c.is_numeric() {
+                            Primary::Digit(first)
+                        } else {
+                            Primary::Letter(first, 0)
+                        };
+                        elements.push(letter(primary));
+                        elements.extend(lowercase.map(|c| letter(Primary::Letter(c, 0))));
+                    }
+                }
+                continue;
+            } else if is_punctuation(c) {
+                Primary::Punctuation(c)
+            } else {
+                Primary::Symbol(c)
+            };
+
+            if self.ignore_punctuation
+                && matches!(primary, Primary::Whitespace(_) | Primary::Punctuation(_))
+            {
+                continue;
+            }
+
+            elements.push(CollationElement {
+                primary,
+                accents: Vec::new(),
+                uppercase: false,
+            });
+        }
+        elements
+    }
+
+    /// `Intl.Collator ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-the-intl-collator-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/Collator
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = if new_target.is_undefined() {
+            context
+                .standard_objects()
+                .collator_object()
+                .constructor()
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 5. Let collator be ? OrdinaryCreateFromConstructor(newTarget, "%Collator.prototype%", internalSlotsList).
+        let prototype =
+            get_prototype_from_constructor(&new_target, StandardObjects::collator_object, context)?;
+
+        // 6. Return ? InitializeCollator(collator, locales, options).
+        let collator = Self::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::collator(Box::new(collator));
+
+        Ok(obj.into())
+    }
+
+    /// `Intl.Collator.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %Collator%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        //
+        // Every locale is available, so all the requested locales are supported.
+        let options = args.get_or_undefined(1);
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        get_option(
+            options.as_ref(),
+            "localeMatcher",
+            &["lookup", "best fit"],
+            context,
+        )?;
+        Ok(
+            Array::create_array_from_list(requested_locales.into_iter().map(JsValue::new), context)
+                .into(),
+        )
+    }
+
+    /// `get Intl.Collator.prototype.compare`
+    ///
+    /// Returns a function comparing its two arguments with the collator, which stays bound to it
+    /// when extracted, so it can be passed to `Array.prototype.sort`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator.prototype.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/compare
+    fn get_compare(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let collator be the this value.
+        // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
+        let collator = Self::require_collator(this, "compare", context)?;
+
+        // 3. If collator.[[BoundCompare]] is undefined, then
+        let bound_compare = collator
+            .borrow()
+            .as_collator()
+            .and_then(|collator| collator.bound_compare.clone());
+        if let Some(bound_compare) = bound_compare {
+            // 4. Return collator.[[BoundCompare]].
+            return Ok(bound_compare.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in 10.3.3.1.
+        // b. Set F.[[Collator]] to collator.
+        let bound_compare = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, collator, context| {
+                // 1. Let collator be F.[[Collator]].
+                let collator = collator
+                    .downcast_ref::<JsObject>()
+                    .expect("the compare function captures its collator");
+
+                // 3. If x is not provided, let x be undefined.
+                // 4. If y is not provided, let y be undefined.
+                // 5. Let X be ? ToString(x).
+                // 6. Let Y be ? ToString(y).
+                let x = args.get_or_undefined(0).to_string(context)?;
+                let y = args.get_or_undefined(1).to_string(context)?;
+
+                // 7. Return CompareStrings(collator, X, Y).
+                let ordering = collator
+                    .borrow()
+                    .as_collator()
+                    .expect("the captured object is a collator")
+                    .compare(&x, &y);
+                Ok(JsValue::new(ordering as i32))
+            },
+            collator.clone(),
+        )
+        .length(2)
+        .build();
+
+        // c. Set collator.[[BoundCompare]] to F.
+        if let Some(data) = collator.borrow_mut().as_collator_mut() {
+            data.bound_compare = Some(bound_compare.clone());
+        }
+
+        // 4. Return collator.[[BoundCompare]].
+        Ok(bound_compare.into())
+    }
+
+    /// `Intl.Collator.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.collator.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let collator be the this value.
+        // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
+        let collator = Self::require_collator(this, "resolvedOptions", context)?;
+        let collator = collator
+            .borrow()
+            .as_collator()
+            .expect("checked to be a collator")
+            .clone();
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 3, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of collator's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If the current row has an Extension Key value, then
+        //         i. Let extensionKey be the Extension Key value of the current row.
+        //         ii. If %Collator%.[[RelevantExtensionKeys]] does not contain extensionKey, then
+        //             1. Let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options.create_data_property_or_throw("locale", collator.locale.clone(), context)?;
+        options.create_data_property_or_throw("usage", collator.usage.clone(), context)?;
+        options.create_data_property_or_throw(
+            "sensitivity",
+            collator.sensitivity.as_str(),
+            context,
+        )?;
+        options.create_data_property_or_throw(
+            "ignorePunctuation",
+            collator.ignore_punctuation,
+            context,
+        )?;
+        options.create_data_property_or_throw("collation", collator.collation.clone(), context)?;
+        options.create_data_property_or_throw("numeric", collator.numeric, context)?;
+        options.create_data_property_or_throw(
+            "caseFirst",
+            collator.case_first.as_str(),
+            context,
+        )?;
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Returns `this` if it is a collator, or throws a `TypeError` naming `method`.
+    fn require_collator(this: &JsValue, method: &str, context: &mut Context) -> JsResult<JsObject> {
+        match this.as_object() {
+            Some(obj) if obj.borrow().is_collator() => Ok(obj),
+            _ => Err(context.construct_type_error(format!(
+                "Intl.Collator.prototype.{} called on an incompatible receiver",
+                method
+            ))),
+        }
+    }
+}
+
+/// The base weight of a collation element, ordered like the root collation of the CLDR.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Primary {
+    /// Combining marks that follow no base character.
+    Ignorable,
+    Whitespace(char),
+    Punctuation(char),
+    Symbol(char),
+    Digit(char),
+    /// A sequence of digits compared by its numeric value, as its length without leading zeros
+    /// and the digits.
+    Number(usize, String),
+    /// A letter, in lowercase, and its rank among the letters a tailoring sorts after it.
+    Letter(char, u8),
+}
+
+/// A unit of a string in a comparison.
+#[derive(Debug)]
+struct CollationElement {
+    primary: Primary,
+    accents: Vec<char>,
+    uppercase: bool,
+}
+
+/// A locale-specific change to the order of a lowercase letter.
+#[derive(Debug)]
+enum Tailoring {
+    /// The letter is a letter of its own, sorting after a base letter and the letters tailored
+    /// after it with a lower rank.
+    After(char, u8),
+    /// The letter sorts like a sequence of letters, only differing from them by its accents.
+    Expansion(&'static str),
+}
+
+/// The tailorings of a collation of a language.
+#[derive(Debug)]
+struct CollationTable {
+    language: &'static str,
+    /// The name of the collation, `standard` for the default collation of the language.
+    collation: &'static str,
+    tailorings: &'static [(char, Tailoring)],
+}
+
+impl CollationTable {
+    /// Finds the table of a collation of a language, or its default collation if `collation` is
+    /// `None`.
+    fn lookup(language: &str, collation: Option<&str>) -> Option<&'static Self> {
+        let collation = match collation {
+            None => "standard",
+            // These names are reserved, and cannot be requested.
+            Some("standard" | "search") => return None,
+            Some(collation) => collation,
+        };
+        COLLATION_TABLES
+            .iter()
+            .find(|table| table.language == language && table.collation == collation)
+    }
+
+    /// Gets the tailoring of a lowercase letter.
+    fn tailoring(&self, c: char) -> Option<&Tailoring> {
+        self.tailorings
+            .iter()
+            .find(|(letter, _)| *letter == c)
+            .map(|(_, tailoring)| tailoring)
+    }
+}
+
+/// The letters of the Swedish and Finnish alphabets that follow `z`.
+const SWEDISH: &[(char, Tailoring)] = &[
+    ('å', Tailoring::After('z', 1)),
+    ('ä', Tailoring::After('z', 2)),
+    ('ö', Tailoring::After('z', 3)),
+];
+
+/// The letters of the Danish and Norwegian alphabets that follow `z`.
+const DANISH: &[(char, Tailoring)] = &[
+    ('æ', Tailoring::After('z', 1)),
+    ('ø', Tailoring::After('z', 2)),
+    ('å', Tailoring::After('z', 3)),
+];
+
+/// The collations that differ from the root collation.
+static COLLATION_TABLES: [CollationTable; 10] = [
+    CollationTable {
+        language: "da",
+        collation: "standard",
+        tailorings: DANISH,
+    },
+    CollationTable {
+        language: "de",
+        collation: "phonebk",
+        tailorings: &[
+            ('ä', Tailoring::Expansion("ae")),
+            ('ö', Tailoring::Expansion("oe")),
+            ('ü', Tailoring::Expansion("ue")),
+        ],
+    },
+    CollationTable {
+        language: "es",
+        collation: "standard",
+        tailorings: &[('ñ', Tailoring::After('n', 1))],
+    },
+    CollationTable {
+        language: "fi",
+        collation: "standard",
+        tailorings: SWEDISH,
+    },
+    CollationTable {
+        language: "nb",
+        collation: "standard",
+        tailorings: DANISH,
+    },
+    CollationTable {
+        language: "nn",
+        collation: "standard",
+        tailorings: DANISH,
+    },
+    CollationTable {
+        language: "no",
+        collation: "standard",
+        tailorings: DANISH,
+    },
+    CollationTable {
+        language: "pl",
+        collation: "standard",
+        tailorings: &[
+            ('ą', Tailoring::After('a', 1)),
+            ('ć', Tailoring::After('c', 1)),
+            ('ę', Tailoring::After('e', 1)),
+            ('ł', Tailoring::After('l', 1)),
+            ('ń', Tailoring::After('n', 1)),
+            ('ó', Tailoring::After('o', 1)),
+            ('ś', Tailoring::After('s', 1)),
+            ('ź', Tailoring::After('z', 1)),
+            ('ż', Tailoring::After('z', 2)),
+        ],
+    },
+    CollationTable {
+        language: "sv",
+        collation: "standard",
+        tailorings: SWEDISH,
+    },
+    CollationTable {
+        language: "tr",
+        collation: "standard",
+        tailorings: &[
+            ('ç', Tailoring::After('c', 1)),
+            ('ğ', Tailoring::After('g', 1)),
+            ('ı', Tailoring::After('h', 1)),
+            ('ö', Tailoring::After('o', 1)),
+            ('ş', Tailoring::After('s', 1)),
+            ('ü', Tailoring::After('u', 1)),
+        ],
+    },
+];
+
+/// Checks if a character is punctuation, as opposed to a symbol.
+fn is_punctuation(c: char) -> bool {
+    if c.is_ascii() {
+        c.is_ascii_punctuation()
+            && !matches!(c, '$' | '+' | '<' | '=' | '>' | '^' | '`' | '|' | '~')
+    } else {
+        matches!(
+            c,
+            '\u{a1}' | '\u{a7}' | '\u{ab}' | '\u{b6}' | '\u{b7}' | '\u{bb}' | '\u{bf}'
+                | '\u{2010}'..='\u{2027}' | '\u{2030}'..='\u{205e}' | '\u{3001}'..='\u{3003}'
+                | '\u{3008}'..='\u{3011}'
+        )
+    }
+}
@@ -0,0 +1,1690 @@
+//! This module implements the `Intl.DateTimeFormat` constructor.
+//!
+//! Dates are formatted in the proleptic Gregorian calendar, with the names and patterns of a
+//! small set of built-in locales: `en` (with `en-GB`), `de`, `es`, `fr` and `ja`. Any other
+//! locale resolves to the default locale.
+//!
+//! Without a time zone database, the supported time zones are `UTC`, fixed UTC offsets (like
+//! `+05:30` or `Etc/GMT-5`) and the local time zone of the host.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+
+use crate::{
+    builtins::{
+        date::Date,
+        intl::{canonicalize_locale_list, get_bool_option, get_option},
+        Array, BuiltIn,
+    },
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+use chrono::{Datelike, Duration, Local, TimeZone as _, Timelike, Utc};
+use icu_locid::LanguageIdentifier;
+
+/// The locale used when none of the requested locales is available.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The date and time components that are present in the output of a date format, and their
+/// representation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Components {
+    weekday: Option<TextWidth>,
+    era: Option<TextWidth>,
+    year: Option<NumericWidth>,
+    month: Option<MonthWidth>,
+    day: Option<NumericWidth>,
+    hour: Option<NumericWidth>,
+    minute: Option<NumericWidth>,
+    second: Option<NumericWidth>,
+    fractional_second_digits: Option<u8>,
+    time_zone_name: Option<TimeZoneName>,
+}
+
+/// The width of a textual component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextWidth {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl TextWidth {
+    const VALUES: &'static [&'static str] = &["narrow", "short", "long"];
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "narrow" => Self::Narrow,
+            "short" => Self::Short,
+            _ => Self::Long,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Narrow => 0,
+            Self::Short => 1,
+            Self::Long => 2,
+        }
+    }
+}
+
+/// The representation of a numeric component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumericWidth {
+    Numeric,
+    TwoDigit,
+}
+
+impl NumericWidth {
+    const VALUES: &'static [&'static str] = &["numeric", "2-digit"];
+
+    fn from_str(value: &str) -> Self {
+        if value == "2-digit" {
+            Self::TwoDigit
+        } else {
+            Self::Numeric
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+        }
+    }
+}
+
+/// The representation of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MonthWidth {
+    Numeric(NumericWidth),
+    Text(TextWidth),
+}
+
+impl MonthWidth {
+    const VALUES: &'static [&'static str] = &["numeric", "2-digit", "narrow", "short", "long"];
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "numeric" | "2-digit" => Self::Numeric(NumericWidth::from_str(value)),
+            _ => Self::Text(TextWidth::from_str(value)),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Numeric(width) => width.as_str(),
+            Self::Text(width) => width.as_str(),
+        }
+    }
+}
+
+/// The representation of the time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeZoneName {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+}
+
+impl TimeZoneName {
+    const VALUES: &'static [&'static str] = &["short", "long", "shortOffset", "longOffset"];
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "short" => Self::Short,
+            "shortOffset" => Self::ShortOffset,
+            "longOffset" => Self::LongOffset,
+            _ => Self::Long,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Long => "long",
+            Self::ShortOffset => "shortOffset",
+            Self::LongOffset => "longOffset",
+        }
+    }
+}
+
+/// The hour cycle of the hours of a date format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HourCycle {
+    /// Hours from 0 to 11.
+    H11,
+    /// Hours from 1 to 12.
+    H12,
+    /// Hours from 0 to 23.
+    H23,
+    /// Hours from 1 to 24.
+    H24,
+}
+
+impl HourCycle {
+    const VALUES: &'static [&'static str] = &["h11", "h12", "h23", "h24"];
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "h11" => Self::H11,
+            "h12" => Self::H12,
+            "h24" => Self::H24,
+            _ => Self::H23,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::H11 => "h11",
+            Self::H12 => "h12",
+            Self::H23 => "h23",
+            Self::H24 => "h24",
+        }
+    }
+
+    fn is_12_hour(self) -> bool {
+        matches!(self, Self::H11 | Self::H12)
+    }
+}
+
+/// The value of the `dateStyle` and `timeStyle` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Style {
+    const VALUES: &'static [&'static str] = &["full", "long", "medium", "short"];
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "full" => Self::Full,
+            "long" => Self::Long,
+            "medium" => Self::Medium,
+            _ => Self::Short,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+        }
+    }
+}
+
+/// The components that must be present in a date format, i.e. the `required` argument of
+/// `CreateDateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Required {
+    Date,
+    Time,
+    Any,
+}
+
+/// The components added to a date format when none of the required ones is present, i.e. the
+/// `defaults` argument of `CreateDateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Defaults {
+    Date,
+    Time,
+    All,
+}
+
+/// The time zone a date format formats dates in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeZone {
+    /// The local time zone of the host.
+    Local,
+    /// A fixed offset from UTC, in minutes.
+    Offset(i32),
+}
+
+/// The data of an `Intl.DateTimeFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DateTimeFormat {
+    /// The resolved locale.
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    data: &'static LocaleData,
+    /// The identifier of the time zone.
+    time_zone_id: JsString,
+    #[unsafe_ignore_trace]
+    time_zone: TimeZone,
+    /// The hour cycle, if the hour is formatted.
+    #[unsafe_ignore_trace]
+    hour_cycle: Option<HourCycle>,
+    #[unsafe_ignore_trace]
+    components: Components,
+    #[unsafe_ignore_trace]
+    date_style: Option<Style>,
+    #[unsafe_ignore_trace]
+    time_style: Option<Style>,
+    /// The function returned by the `format` getter, once created.
+    bound_format: Option<JsObject>,
+}
+
+impl BuiltIn for DateTimeFormat {
+    const NAME: &'static str = "DateTimeFormat";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_format = FunctionBuilder::native(context, Self::get_format)
+            .name("get format")
+            .constructable(false)
+            .build();
+
+        let date_time_format = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().date_time_format_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor(
+            "format",
+            Some(get_format),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::format_to_parts, "formatToParts", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.DateTimeFormat",
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, date_time_format.into(), Self::attribute())
+    }
+}
+
+impl DateTimeFormat {
+    /// Creates a date format from the `locales` and `options` arguments of a locale-sensitive
+    /// formatting.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        required: Required,
+        defaults: Defaults,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Set options to ? CoerceOptionsToObject(options).
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        let options = options.as_ref();
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        get_option(options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 7. Let calendar be ? GetOption(options, "calendar", "string", empty, undefined).
+        // 8. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        //
+        // Only the Gregorian calendar is available, so any other calendar is ignored.
+        if let Some(calendar) = get_option(options, "calendar", &[], context)? {
+            if !is_unicode_type(&calendar) {
+                return Err(
+                    context.construct_range_error(format!("Invalid calendar: {}", calendar))
+                );
+            }
+        }
+
+        // 10. Let numberingSystem be ? GetOption(options, "numberingSystem", "string", empty, undefined).
+        //
+        // Only the Latin digits are available, so any other numbering system is ignored.
+        if let Some(numbering_system) = get_option(options, "numberingSystem", &[], context)? {
+            if !is_unicode_type(&numbering_system) {
+                return Err(context.construct_range_error(format!(
+                    "Invalid numbering system: {}",
+                    numbering_system
+                )));
+            }
+        }
+
+        // 13. Let hour12 be ? GetOption(options, "hour12", "boolean", empty, undefined).
+        let hour12 = get_bool_option(options, "hour12", context)?;
+
+        // 14. Let hourCycle be ? GetOption(options, "hourCycle", "string", « "h11", "h12", "h23", "h24" », undefined).
+        // 15. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        let hour_cycle = get_option(options, "hourCycle", HourCycle::VALUES, context)?
+            .filter(|_| hour12.is_none())
+            .map(|hc| HourCycle::from_str(&hc));
+
+        // 17. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %DateTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        let (locale, data) = requested_locales
+            .iter()
+            .find_map(|locale| {
+                let locale = LanguageIdentifier::try_from_locale_bytes(locale.as_bytes()).ok()?;
+                let data = LocaleData::lookup(&locale)?;
+                Some((locale.to_string(), data))
+            })
+            .unwrap_or_else(|| {
+                let locale = DEFAULT_LOCALE
+                    .parse()
+                    .expect("the default locale should be valid");
+                let data = LocaleData::lookup(&locale).expect("the default locale has data");
+                (locale.to_string(), data)
+            });
+
+        // 29. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = match options {
+            Some(options) => options.get("timeZone", context)?,
+            None => JsValue::undefined(),
+        };
+        let (time_zone_id, time_zone) = if time_zone.is_undefined() {
+            // 30. If timeZone is undefined, then
+            //     a. Set timeZone to DefaultTimeZone().
+            (local_time_zone_id(), TimeZone::Local)
+        } else {
+            // 31. Else,
+            //     a. Set timeZone to ? ToString(timeZone).
+            let time_zone = time_zone.to_string(context)?;
+            //     b. If IsTimeZoneOffsetString(timeZone) is false and IsAvailableTimeZoneName(timeZone) is false, throw a RangeError exception.
+            parse_time_zone(&time_zone).ok_or_else(|| {
+                context.construct_range_error(format!("Unsupported time zone: {}", time_zone))
+            })?
+        };
+
+        // 36. For each row of Table 7, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the row.
+        //     b. If prop is "fractionalSecondDigits", then
+        //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        //     c. Else,
+        //         i. Let values be a List whose elements are the strings given in the Values column of the row.
+        //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+        let text = |property, context: &mut Context| {
+            Ok::<_, JsValue>(
+                get_option(options, property, TextWidth::VALUES, context)?
+                    .map(|value| TextWidth::from_str(&value)),
+            )
+        };
+        let numeric = |property, context: &mut Context| {
+            Ok::<_, JsValue>(
+                get_option(options, property, NumericWidth::VALUES, context)?
+                    .map(|value| NumericWidth::from_str(&value)),
+            )
+        };
+
+        let weekday = text("weekday", context)?;
+        let era = text("era", context)?;
+        let year = numeric("year", context)?;
+        let month = get_option(options, "month", MonthWidth::VALUES, context)?
+            .map(|value| MonthWidth::from_str(&value));
+        let day = numeric("day", context)?;
+        let hour = numeric("hour", context)?;
+        let minute = numeric("minute", context)?;
+        let second = numeric("second", context)?;
+        let fractional_second_digits = match options {
+            Some(options) => {
+                let value = options.get("fractionalSecondDigits", context)?;
+                get_number_option(&value, "fractionalSecondDigits", 1, 3, context)?
+            }
+            None => None,
+        };
+        let time_zone_name = get_option(options, "timeZoneName", TimeZoneName::VALUES, context)?
+            .map(|value| TimeZoneName::from_str(&value));
+
+        let mut components = Components {
+            weekday,
+            era,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            fractional_second_digits,
+            time_zone_name,
+        };
+
+        // 37. Let formatMatcher be ? GetOption(options, "formatMatcher", "string", « "basic", "best fit" », "best fit").
+        get_option(options, "formatMatcher", &["basic", "best fit"], context)?;
+
+        // 38. Let dateStyle be ? GetOption(options, "dateStyle", "string", « "full", "long", "medium", "short" », undefined).
+        let date_style = get_option(options, "dateStyle", Style::VALUES, context)?
+            .map(|style| Style::from_str(&style));
+        // 40. Let timeStyle be ? GetOption(options, "timeStyle", "string", « "full", "long", "medium", "short" », undefined).
+        let time_style = get_option(options, "timeStyle", Style::VALUES, context)?
+            .map(|style| Style::from_str(&style));
+
+        // 42. If dateStyle is not undefined or timeStyle is not undefined, then
+        if date_style.is_some() || time_style.is_some() {
+            // a. If hasExplicitFormatComponents is true, then
+            if components != Components::default() {
+                // i. Throw a TypeError exception.
+                return Err(context.construct_type_error(
+                    "dateStyle and timeStyle cannot be used with date or time components",
+                ));
+            }
+            // b. If required is date and timeStyle is not undefined, then
+            if required == Required::Date && time_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(context.construct_type_error("timeStyle cannot be used here"));
+            }
+            // c. If required is time and dateStyle is not undefined, then
+            if required == Required::Time && date_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(context.construct_type_error("dateStyle cannot be used here"));
+            }
+            // d. Let styles be dataLocaleData.[[styles]].[[<resolvedCalendar>]].
+            // e. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+            components = style_components(date_style, time_style);
+        } else {
+            // 43. Else,
+            //     a. Let needDefaults be true.
+            //     b. If required is date or any, then
+            //         i. For each property name prop of « "weekday", "year", "month", "day" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            //     c. If required is time or any, then
+            //         i. For each property name prop of « "dayPeriod", "hour", "minute", "second", "fractionalSecondDigits" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            let has_date = components.weekday.is_some()
+                || components.year.is_some()
+                || components.month.is_some()
+                || components.day.is_some();
+            let has_time = components.hour.is_some()
+                || components.minute.is_some()
+                || components.second.is_some()
+                || components.fractional_second_digits.is_some();
+            let need_defaults = match required {
+                Required::Date => !has_date,
+                Required::Time => !has_time,
+                Required::Any => !has_date && !has_time,
+            };
+
+            if need_defaults {
+                // d. If needDefaults is true and defaults is either date or all, then
+                //     i. For each property name prop of « "year", "month", "day" », do
+                //         1. Set formatOptions.[[<prop>]] to "numeric".
+                if matches!(defaults, Defaults::Date | Defaults::All) {
+                    components.year = Some(NumericWidth::Numeric);
+                    components.month = Some(MonthWidth::Numeric(NumericWidth::Numeric));
+                    components.day = Some(NumericWidth::Numeric);
+                }
+                // e. If needDefaults is true and defaults is either time or all, then
+                //     i. For each property name prop of « "hour", "minute", "second" », do
+                //         1. Set formatOptions.[[<prop>]] to "numeric".
+                if matches!(defaults, Defaults::Time | Defaults::All) {
+                    components.hour = Some(NumericWidth::Numeric);
+                    components.minute = Some(NumericWidth::Numeric);
+                    components.second = Some(NumericWidth::Numeric);
+                }
+            }
+        }
+
+        // 44. If dateTimeFormat.[[Hour]] is undefined, then
+        //     a. Set dateTimeFormat.[[HourCycle]] to undefined.
+        // 45. Else, resolve the hour cycle from hour12, hourCycle and the locale.
+        let hour_cycle = components.hour.map(|_| match (hour12, hour_cycle) {
+            (Some(true), _) => data.hour_cycle_12,
+            (Some(false), _) => HourCycle::H23,
+            (None, Some(hour_cycle)) => hour_cycle,
+            (None, None) => data.hour_cycle,
+        });
+
+        Ok(Self {
+            locale: locale.into(),
+            data,
+            time_zone_id: time_zone_id.into(),
+            time_zone,
+            hour_cycle,
+            components,
+            date_style,
+            time_style,
+            bound_format: None,
+        })
+    }
+
+    /// Abstract operation `FormatDateTime ( dateTimeFormat, x )`
+    ///
+    /// Returns `None` if the time value is `NaN`, or too far from the epoch to be formatted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format(&self, x: f64) -> Option<String> {
+        let parts = self.partition_date_time_pattern(x)?;
+        Some(parts.into_iter().map(|part| part.value).collect())
+    }
+
+    /// Abstract operation `PartitionDateTimePattern ( dateTimeFormat, x )`
+    ///
+    /// Returns `None` if the time value is `NaN`, or too far from the epoch to be formatted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+    fn partition_date_time_pattern(&self, x: f64) -> Option<Vec<Part>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        let x = Date::time_clip(x)? as i64;
+
+        // 3. Let tm be ToLocalTime(ℤ(ℝ(x) × 10^6), dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let (tm, offset) = match self.time_zone {
+            TimeZone::Local => {
+                let date_time = Local.timestamp_millis_opt(x).single()?;
+                (
+                    date_time.naive_local(),
+                    date_time.offset().local_minus_utc() / 60,
+                )
+            }
+            TimeZone::Offset(offset) => {
+                let date_time = Utc.timestamp_millis_opt(x).single()?.naive_utc();
+                (
+                    date_time.checked_add_signed(Duration::minutes(i64::from(offset)))?,
+                    offset,
+                )
+            }
+        };
+
+        let data = self.data;
+        let c = self.components;
+
+        // Years before 1 CE are displayed in the era before the common era, which is shown even
+        // if it was not requested.
+        let (era, year) = if tm.year() > 0 {
+            (1, tm.year())
+        } else {
+            (0, 1 - tm.year())
+        };
+        let era_width = c
+            .era
+            .or_else(|| c.year.filter(|_| era == 0).map(|_| TextWidth::Short));
+
+        let numeric = |kind, value: u32, width: Option<NumericWidth>, pad: bool| match width {
+            Some(NumericWidth::TwoDigit) => Part::new(kind, format!("{:02}", value % 100)),
+            Some(NumericWidth::Numeric) if pad => Part::new(kind, format!("{:02}", value)),
+            Some(NumericWidth::Numeric) => Part::new(kind, value.to_string()),
+            None => Vec::new(),
+        };
+
+        let date_pattern = match c.month {
+            Some(MonthWidth::Text(_)) => data.text_date,
+            _ => data.numeric_date,
+        };
+        let date = interpolate(date_pattern, |name| match name {
+            "y" => match c.year {
+                Some(NumericWidth::TwoDigit) => Part::new("year", format!("{:02}", year % 100)),
+                Some(NumericWidth::Numeric) => Part::new("year", year.to_string()),
+                None => Vec::new(),
+            },
+            "M" | "MM" => match c.month {
+                Some(MonthWidth::Numeric(width)) => {
+                    numeric("month", tm.month(), Some(width), name == "MM")
+                }
+                Some(MonthWidth::Text(width)) => Part::new(
+                    "month",
+                    data.months[width.index()][tm.month0() as usize].to_string(),
+                ),
+                None => Vec::new(),
+            },
+            "d" | "dd" => numeric("day", tm.day(), c.day, name == "dd"),
+            _ => Vec::new(),
+        });
+        let date = interpolate(data.era_date, |name| match (name, era_width) {
+            ("G", Some(width)) => Part::new("era", data.eras[width.index()][era].to_string()),
+            ("D", _) => date.clone(),
+            _ => Vec::new(),
+        });
+        let date = interpolate(data.weekday_date, |name| match (name, c.weekday) {
+            ("E", Some(width)) => Part::new(
+                "weekday",
+                data.weekdays[width.index()][tm.weekday().num_days_from_sunday() as usize]
+                    .to_string(),
+            ),
+            ("D", _) => date.clone(),
+            _ => Vec::new(),
+        });
+
+        let hour_cycle = self.hour_cycle.unwrap_or(data.hour_cycle);
+        let hour = match hour_cycle {
+            HourCycle::H11 => tm.hour() % 12,
+            HourCycle::H12 if tm.hour() % 12 == 0 => 12,
+            HourCycle::H12 => tm.hour() % 12,
+            HourCycle::H23 => tm.hour(),
+            HourCycle::H24 if tm.hour() == 0 => 24,
+            HourCycle::H24 => tm.hour(),
+        };
+        let time = interpolate(data.time, |name| match name {
+            "h" => numeric(
+                "hour",
+                hour,
+                c.hour,
+                data.pad_hours && !hour_cycle.is_12_hour(),
+            ),
+            "m" => numeric("minute", tm.minute(), c.minute, c.hour.is_some()),
+            "s" => numeric("second", tm.second(), c.second, c.minute.is_some()),
+            "f" => match c.fractional_second_digits {
+                Some(digits) => {
+                    let millis = format!("{:03}", tm.timestamp_subsec_millis());
+                    Part::new(
+                        "fractionalSecond",
+                        millis[..usize::from(digits)].to_string(),
+                    )
+                }
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        });
+        let time = interpolate(data.day_period_time, |name| match name {
+            "a" if c.hour.is_some() && hour_cycle.is_12_hour() => Part::new(
+                "dayPeriod",
+                data.day_periods[usize::from(tm.hour() >= 12)].to_string(),
+            ),
+            "T" => time.clone(),
+            _ => Vec::new(),
+        });
+        let time = interpolate("{T} {z}", |name| match (name, c.time_zone_name) {
+            ("z", Some(style)) => Part::new(
+                "timeZoneName",
+                time_zone_name(&self.time_zone_id, offset, style),
+            ),
+            ("T", _) => time.clone(),
+            _ => Vec::new(),
+        });
+
+        Some(interpolate(data.date_time, |name| match name {
+            "D" => date.clone(),
+            "T" => time.clone(),
+            _ => Vec::new(),
+        }))
+    }
+
+    /// `Intl.DateTimeFormat ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = if new_target.is_undefined() {
+            context
+                .standard_objects()
+                .date_time_format_object()
+                .constructor()
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            &new_target,
+            StandardObjects::date_time_format_object,
+            context,
+        )?;
+        let date_time_format = Self::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            Required::Any,
+            Defaults::Date,
+            context,
+        )?;
+
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::date_time_format(Box::new(date_time_format));
+
+        // 5. Return dateTimeFormat.
+        Ok(obj.into())
+    }
+
+    /// `Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        let options = args.get_or_undefined(1);
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        get_option(
+            options.as_ref(),
+            "localeMatcher",
+            &["lookup", "best fit"],
+            context,
+        )?;
+        let supported = requested_locales.into_iter().filter(|locale| {
+            LanguageIdentifier::try_from_locale_bytes(locale.as_bytes())
+                .ok()
+                .and_then(|locale| LocaleData::lookup(&locale))
+                .is_some()
+        });
+        Ok(Array::create_array_from_list(supported.map(JsValue::new), context).into())
+    }
+
+    /// `get Intl.DateTimeFormat.prototype.format`
+    ///
+    /// Returns a function formatting its argument with the date format, which stays bound to it
+    /// when extracted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::require_date_time_format(this, "format", context)?;
+
+        // 4. If dtf.[[BoundFormat]] is undefined, then
+        let bound_format = dtf
+            .borrow()
+            .as_date_time_format()
+            .and_then(|date_time_format| date_time_format.bound_format.clone());
+        if let Some(bound_format) = bound_format {
+            // 5. Return dtf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in DateTime Format Functions.
+        // b. Set F.[[DateTimeFormat]] to dtf.
+        let bound_format = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, dtf, context| {
+                // 1. Let dtf be F.[[DateTimeFormat]].
+                let dtf = dtf
+                    .downcast_ref::<JsObject>()
+                    .expect("the format function captures its date format");
+
+                // 3. If date is undefined, then
+                //     a. Let x be ! Call(%Date.now%, undefined).
+                // 4. Else,
+                //     a. Let x be ? ToNumber(date).
+                let x = to_date_time_value(args.get_or_undefined(0), context)?;
+
+                // 5. Return ? FormatDateTime(dtf, x).
+                let formatted = dtf
+                    .borrow()
+                    .as_date_time_format()
+                    .expect("the captured object is a date format")
+                    .format(x);
+                match formatted {
+                    Some(formatted) => Ok(formatted.into()),
+                    None => context.throw_range_error("Invalid time value"),
+                }
+            },
+            dtf.clone(),
+        )
+        .length(1)
+        .build();
+
+        // c. Set dtf.[[BoundFormat]] to F.
+        if let Some(date_time_format) = dtf.borrow_mut().as_date_time_format_mut() {
+            date_time_format.bound_format = Some(bound_format.clone());
+        }
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// `Intl.DateTimeFormat.prototype.formatToParts ( date )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::require_date_time_format(this, "formatToParts", context)?;
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = to_date_time_value(args.get_or_undefined(0), context)?;
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let parts = dtf
+            .borrow()
+            .as_date_time_format()
+            .expect("checked to be a date format")
+            .partition_date_time_pattern(x);
+        let parts = match parts {
+            Some(parts) => parts,
+            None => return context.throw_range_error("Invalid time value"),
+        };
+
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataProperty(result, ! ToString(n), O).
+        let mut result = Vec::with_capacity(parts.len());
+        for part in parts {
+            let object = context.construct_object();
+            object.create_data_property_or_throw("type", part.kind, context)?;
+            object.create_data_property_or_throw("value", part.value, context)?;
+            result.push(object.into());
+        }
+
+        // 4. Return result.
+        Ok(Array::create_array_from_list(result, context).into())
+    }
+
+    /// `Intl.DateTimeFormat.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::require_date_time_format(this, "resolvedOptions", context)?;
+        let dtf = dtf
+            .borrow()
+            .as_date_time_format()
+            .expect("checked to be a date format")
+            .clone();
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 5. For each row of Table 8, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If there is an Internal Slot value in the current row, then
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     d. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options.create_data_property_or_throw("locale", dtf.locale.clone(), context)?;
+        options.create_data_property_or_throw("calendar", "gregory", context)?;
+        options.create_data_property_or_throw("numberingSystem", "latn", context)?;
+        options.create_data_property_or_throw("timeZone", dtf.time_zone_id.clone(), context)?;
+        if let Some(hour_cycle) = dtf.hour_cycle {
+            options.create_data_property_or_throw("hourCycle", hour_cycle.as_str(), context)?;
+            options.create_data_property_or_throw("hour12", hour_cycle.is_12_hour(), context)?;
+        }
+
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let c = dtf.components;
+            let components = [
+                ("weekday", c.weekday.map(TextWidth::as_str)),
+                ("era", c.era.map(TextWidth::as_str)),
+                ("year", c.year.map(NumericWidth::as_str)),
+                ("month", c.month.map(MonthWidth::as_str)),
+                ("day", c.day.map(NumericWidth::as_str)),
+                ("hour", c.hour.map(NumericWidth::as_str)),
+                ("minute", c.minute.map(NumericWidth::as_str)),
+                ("second", c.second.map(NumericWidth::as_str)),
+            ];
+            for (property, value) in components {
+                if let Some(value) = value {
+                    options.create_data_property_or_throw(property, value, context)?;
+                }
+            }
+            if let Some(digits) = c.fractional_second_digits {
+                options.create_data_property_or_throw(
+                    "fractionalSecondDigits",
+                    i32::from(digits),
+                    context,
+                )?;
+            }
+            if let Some(time_zone_name) = c.time_zone_name {
+                options.create_data_property_or_throw(
+                    "timeZoneName",
+                    time_zone_name.as_str(),
+                    context,
+                )?;
+            }
+        }
+        if let Some(date_style) = dtf.date_style {
+            options.create_data_property_or_throw("dateStyle", date_style.as_str(), context)?;
+        }
+        if let Some(time_style) = dtf.time_style {
+            options.create_data_property_or_throw("timeStyle", time_style.as_str(), context)?;
+        }
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+
+    /// Returns `this` if it is a date format, or throws a `TypeError` naming `method`.
+    fn require_date_time_format(
+        this: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        match this.as_object() {
+            Some(obj) if obj.borrow().is_date_time_format() => Ok(obj),
+            _ => Err(context.construct_type_error(format!(
+                "Intl.DateTimeFormat.prototype.{} called on an incompatible receiver",
+                method
+            ))),
+        }
+    }
+}
+
+/// A part of a formatted date, as returned by `formatToParts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Part {
+    kind: &'static str,
+    value: String,
+}
+
+impl Part {
+    /// Creates a list containing a single part.
+    fn new(kind: &'static str, value: String) -> Vec<Self> {
+        vec![Self { kind, value }]
+    }
+}
+
+/// Interpolates a pattern with `{name}` placeholders, replacing each placeholder by the parts
+/// returned by `lookup`.
+///
+/// A placeholder without parts is removed along with one separating literal: the literal text
+/// following it if it ends the pattern or comes before another formatted placeholder, or else
+/// the closest remaining literal text preceding it. This lets every pattern describe the full
+/// date or time while still being usable for a subset of its components.
+fn interpolate<F>(pattern: &str, mut lookup: F) -> Vec<Part>
+where
+    F: FnMut(&str) -> Vec<Part>,
+{
+    enum Token<'a> {
+        Literal(&'a str),
+        Placeholder(Vec<Part>),
+    }
+
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let end = rest.find('}').expect("placeholders should be closed");
+        if start > 0 {
+            tokens.push(Token::Literal(&rest[..start]));
+        }
+        tokens.push(Token::Placeholder(lookup(&rest[start + 1..end])));
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Literal(rest));
+    }
+
+    let is_formatted =
+        |token: &Token<'_>| matches!(token, Token::Placeholder(parts) if !parts.is_empty());
+
+    if !tokens.iter().any(is_formatted) {
+        return Vec::new();
+    }
+
+    let mut keep = vec![true; tokens.len()];
+    for (i, token) in tokens.iter().enumerate() {
+        if !matches!(token, Token::Placeholder(parts) if parts.is_empty()) {
+            continue;
+        }
+        keep[i] = false;
+
+        let formatted_after = tokens[i + 1..].iter().any(is_formatted);
+        if let Some(Token::Literal(_)) = tokens.get(i + 1) {
+            if formatted_after || i + 2 == tokens.len() {
+                keep[i + 1] = false;
+                continue;
+            }
+        }
+        for j in (0..i).rev() {
+            if is_formatted(&tokens[j]) {
+                break;
+            }
+            if keep[j] && matches!(tokens[j], Token::Literal(_)) {
+                keep[j] = false;
+                break;
+            }
+        }
+    }
+
+    let mut parts: Vec<Part> = Vec::new();
+    for (token, keep) in tokens.into_iter().zip(keep) {
+        match token {
+            _ if !keep => {}
+            Token::Literal(literal) => match parts.last_mut() {
+                Some(last) if last.kind == "literal" => last.value.push_str(literal),
+                _ => parts.push(Part {
+                    kind: "literal",
+                    value: literal.to_string(),
+                }),
+            },
+            Token::Placeholder(placeholder) => {
+                for part in placeholder {
+                    match parts.last_mut() {
+                        Some(last) if last.kind == "literal" && part.kind == "literal" => {
+                            last.value.push_str(&part.value);
+                        }
+                        _ => parts.push(part),
+                    }
+                }
+            }
+        }
+    }
+    parts
+}
+
+/// Returns the components formatted by a `dateStyle` and a `timeStyle`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-date-time-style-format
+fn style_components(date_style: Option<Style>, time_style: Option<Style>) -> Components {
+    let mut components = Components::default();
+
+    if let Some(date_style) = date_style {
+        components.day = Some(NumericWidth::Numeric);
+        match date_style {
+            Style::Full | Style::Long | Style::Medium => {
+                components.year = Some(NumericWidth::Numeric);
+                components.month = Some(MonthWidth::Text(if date_style == Style::Medium {
+                    TextWidth::Short
+                } else {
+                    TextWidth::Long
+                }));
+            }
+            Style::Short => {
+                components.year = Some(NumericWidth::TwoDigit);
+                components.month = Some(MonthWidth::Numeric(NumericWidth::Numeric));
+            }
+        }
+        if date_style == Style::Full {
+            components.weekday = Some(TextWidth::Long);
+        }
+    }
+
+    if let Some(time_style) = time_style {
+        components.hour = Some(NumericWidth::Numeric);
+        components.minute = Some(NumericWidth::Numeric);
+        if time_style != Style::Short {
+            components.second = Some(NumericWidth::Numeric);
+        }
+        components.time_zone_name = match time_style {
+            Style::Full => Some(TimeZoneName::Long),
+            Style::Long => Some(TimeZoneName::Short),
+            Style::Medium | Style::Short => None,
+        };
+    }
+
+    components
+}
+
+/// Converts the argument of a formatting method to a time value, defaulting to the current
+/// time.
+fn to_date_time_value(date: &JsValue, context: &mut Context) -> JsResult<f64> {
+    if date.is_undefined() {
+        Ok(Utc::now().timestamp_millis() as f64)
+    } else {
+        date.to_number(context)
+    }
+}
+
+/// Abstract operation `GetNumberOption ( options, property, minimum, maximum, fallback )`,
+/// for an already retrieved value.
+///
+/// Returns `None` if the value is undefined.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getnumberoption
+fn get_number_option(
+    value: &JsValue,
+    property: &str,
+    minimum: u8,
+    maximum: u8,
+    context: &mut Context,
+) -> JsResult<Option<u8>> {
+    // 1. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 2. Set value to ? ToNumber(value).
+    let value = value.to_number(context)?;
+
+    // 3. If value is NaN or less than minimum or greater than maximum, throw a RangeError exception.
+    if value.is_nan() || value < f64::from(minimum) || value > f64::from(maximum) {
+        return Err(context.construct_range_error(format!("{} is out of range", property)));
+    }
+
+    // 4. Return floor(value).
+    Ok(Some(value.floor() as u8))
+}
+
+/// Checks if a string matches the `type` Unicode locale nonterminal, i.e. is a sequence of
+/// alphanumeric subtags of 3 to 8 characters, separated by hyphens.
+fn is_unicode_type(value: &str) -> bool {
+    value.split('-').all(|subtag| {
+        (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
+
+/// Parses the identifier of a supported time zone, returning its canonical identifier.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-istimezoneoffsetstring
+fn parse_time_zone(time_zone: &str) -> Option<(String, TimeZone)> {
+    let upper = time_zone.to_ascii_uppercase();
+
+    if matches!(upper.as_str(), "UTC" | "ETC/UTC" | "GMT" | "ETC/GMT") {
+        return Some(("UTC".to_string(), TimeZone::Offset(0)));
+    }
+
+    // `Etc/GMT+5` is five hours behind UTC.
+    if let Some(hours) = upper.strip_prefix("ETC/GMT") {
+        let sign = match hours.as_bytes().first()? {
+            b'+' => -1,
+            b'-' => 1,
+            _ => return None,
+        };
+        let hours = &hours[1..];
+        if hours.is_empty() || hours.len() > 2 || !hours.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let hours = hours.parse::<i32>().ok().filter(|hours| *hours <= 14)?;
+        let id = format!("Etc/GMT{}{}", if sign < 0 { '+' } else { '-' }, hours);
+        return Some((id, TimeZone::Offset(sign * hours * 60)));
+    }
+
+    // UTC offsets, as `±HH`, `±HHMM` or `±HH:MM`.
+    if let Some(sign) = match time_zone.as_bytes().first()? {
+        b'+' => Some(1),
+        b'-' => Some(-1),
+        _ => None,
+    } {
+        let offset = &time_zone[1..];
+        let (hours, minutes) = match offset.len() {
+            2 => (offset, "00"),
+            4 => offset.split_at(2),
+            5 if offset.as_bytes()[2] == b':' => (&offset[..2], &offset[3..]),
+            _ => return None,
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let hours = hours.parse::<i32>().ok().filter(|hours| *hours <= 23)?;
+        let minutes = minutes
+            .parse::<i32>()
+            .ok()
+            .filter(|minutes| *minutes <= 59)?;
+        let offset = sign * (hours * 60 + minutes);
+        return Some((format_offset(offset, true), TimeZone::Offset(offset)));
+    }
+
+    let local = local_time_zone_id();
+    if local.eq_ignore_ascii_case(time_zone) {
+        return Some((local, TimeZone::Local));
+    }
+
+    None
+}
+
+/// Abstract operation `DefaultTimeZone ( )`
+///
+/// Returns the identifier of the local time zone of the host: the `TZ` environment variable if
+/// it names a time zone, the target of `/etc/localtime`, or else its current UTC offset.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-defaulttimezone
+fn local_time_zone_id() -> String {
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim_start_matches(':');
+        if tz.contains('/') || tz == "UTC" {
+            return tz.to_string();
+        }
+    }
+
+    if let Ok(path) = std::fs::read_link("/etc/localtime") {
+        if let Some((_, name)) = path.to_str().and_then(|path| path.split_once("zoneinfo/")) {
+            return name.to_string();
+        }
+    }
+
+    let offset = Local::now().offset().local_minus_utc() / 60;
+    format_offset(offset, true)
+}
+
+/// Formats a UTC offset in minutes as `±HH:MM`, or as `±H[:MM]` if `long` is `false`.
+fn format_offset(offset: i32, long: bool) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let (hours, minutes) = (offset.abs() / 60, offset.abs() % 60);
+    if long {
+        format!("{}{:02}:{:02}", sign, hours, minutes)
+    } else if minutes == 0 {
+        format!("{}{}", sign, hours)
+    } else {
+        format!("{}{}:{:02}", sign, hours, minutes)
+    }
+}
+
+/// Formats the name of a time zone, given its identifier and its offset at the formatted date.
+fn time_zone_name(id: &str, offset: i32, style: TimeZoneName) -> String {
+    match style {
+        TimeZoneName::Short if id == "UTC" => "UTC".to_string(),
+        TimeZoneName::Long if id == "UTC" => "Coordinated Universal Time".to_string(),
+        _ if offset == 0 => "GMT".to_string(),
+        TimeZoneName::Short | TimeZoneName::ShortOffset => {
+            format!("GMT{}", format_offset(offset, false))
+        }
+        TimeZoneName::Long | TimeZoneName::LongOffset => {
+            format!("GMT{}", format_offset(offset, true))
+        }
+    }
+}
+
+/// The names and patterns used to format dates in a locale.
+///
+/// Patterns contain `{name}` placeholders, which are interpolated with [`interpolate`]:
+///  - `y`, `M` and `d` for the year, month and day, with `MM` and `dd` for a month and a day
+///    that are padded to two digits when they are numeric.
+///  - `h`, `m`, `s`, `f` and `a` for the hour, minute, second, fraction of a second and day
+///    period.
+///  - `G`, `E` and `z` for the era, weekday and time zone name.
+///  - `D` and `T` for the date and time built by other patterns.
+#[derive(Debug)]
+struct LocaleData {
+    language: &'static str,
+    /// The region the data is specific to, if any.
+    region: Option<&'static str>,
+    /// The names of the months, from January, in narrow, short and long width.
+    months: [[&'static str; 12]; 3],
+    /// The names of the weekdays, from Sunday, in narrow, short and long width.
+    weekdays: [[&'static str; 7]; 3],
+    /// The names of the eras before and after the common era, in narrow, short and long width.
+    eras: [[&'static str; 2]; 3],
+    /// The names of the morning and afternoon periods.
+    day_periods: [&'static str; 2],
+    /// The default hour cycle, and the one used with `hour12: true`.
+    hour_cycle: HourCycle,
+    hour_cycle_12: HourCycle,
+    /// Whether the hours of a 24-hour clock are padded to two digits.
+    pad_hours: bool,
+    numeric_date: &'static str,
+    text_date: &'static str,
+    era_date: &'static str,
+    weekday_date: &'static str,
+    time: &'static str,
+    day_period_time: &'static str,
+    date_time: &'static str,
+}
+
+impl LocaleData {
+    /// Returns the data of a locale, if it is available.
+    fn lookup(locale: &LanguageIdentifier) -> Option<&'static Self> {
+        let language = locale.language.as_str();
+        let region = locale.region.as_ref().map(|region| region.as_str());
+        LOCALE_DATA
+            .iter()
+            .find(|data| {
+                data.language == language && data.region.is_some() && data.region == region
+            })
+            .or_else(|| {
+                LOCALE_DATA
+                    .iter()
+                    .find(|data| data.language == language && data.region.is_none())
+            })
+    }
+}
+
+const ENGLISH_MONTHS: [[&str; 12]; 3] = [
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+    [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+];
+
+const ENGLISH_WEEKDAYS: [[&str; 7]; 3] = [
+    ["S", "M", "T", "W", "T", "F", "S"],
+    ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+];
+
+const ENGLISH_ERAS: [[&str; 2]; 3] = [["B", "A"], ["BC", "AD"], ["Before Christ", "Anno Domini"]];
+
+/// The data of the available locales.
+static LOCALE_DATA: [LocaleData; 6] = [
+    LocaleData {
+        language: "en",
+        region: None,
+        months: ENGLISH_MONTHS,
+        weekdays: ENGLISH_WEEKDAYS,
+        eras: ENGLISH_ERAS,
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H12,
+        hour_cycle_12: HourCycle::H12,
+        pad_hours: false,
+        numeric_date: "{M}/{d}/{y}",
+        text_date: "{M} {d}, {y}",
+        era_date: "{D} {G}",
+        weekday_date: "{E}, {D}",
+        time: "{h}:{m}:{s}.{f}",
+        day_period_time: "{T} {a}",
+        date_time: "{D}, {T}",
+    },
+    LocaleData {
+        language: "en",
+        region: Some("GB"),
+        months: ENGLISH_MONTHS,
+        weekdays: ENGLISH_WEEKDAYS,
+        eras: ENGLISH_ERAS,
+        day_periods: ["am", "pm"],
+        hour_cycle: HourCycle::H23,
+        hour_cycle_12: HourCycle::H12,
+        pad_hours: true,
+        numeric_date: "{dd}/{MM}/{y}",
+        text_date: "{d} {M} {y}",
+        era_date: "{D} {G}",
+        weekday_date: "{E} {D}",
+        time: "{h}:{m}:{s}.{f}",
+        day_period_time: "{T} {a}",
+        date_time: "{D}, {T}",
+    },
+    LocaleData {
+        language: "de",
+        region: None,
+        months: [
+            ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+            [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+        ],
+        weekdays: [
+            ["S", "M", "D", "M", "D", "F", "S"],
+            ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+            [
+                "Sonntag",
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+            ],
+        ],
+        eras: [
+            ["v. Chr.", "n. Chr."],
+            ["v. Chr.", "n. Chr."],
+            ["v. Chr.", "n. Chr."],
+        ],
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H23,
+        hour_cycle_12: HourCycle::H12,
+        pad_hours: true,
+        numeric_date: "{d}.{M}.{y}",
+        text_date: "{d}. {M} {y}",
+        era_date: "{D} {G}",
+        weekday_date: "{E}, {D}",
+        time: "{h}:{m}:{s},{f}",
+        day_period_time: "{T} {a}",
+        date_time: "{D}, {T}",
+    },
+    LocaleData {
+        language: "es",
+        region: None,
+        months: [
+            ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+            [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+        ],
+        weekdays: [
+            ["D", "L", "M", "X", "J", "V", "S"],
+            ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+            [
+                "domingo",
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+            ],
+        ],
+        eras: [
+            ["a. C.", "d. C."],
+            ["a. C.", "d. C."],
+            ["antes de Cristo", "después de Cristo"],
+        ],
+        day_periods: ["a.\u{a0}m.", "p.\u{a0}m."],
+        hour_cycle: HourCycle::H23,
+        hour_cycle_12: HourCycle::H12,
+        pad_hours: false,
+        numeric_date: "{d}/{M}/{y}",
+        text_date: "{d} de {M} de {y}",
+        era_date: "{D} {G}",
+        weekday_date: "{E}, {D}",
+        time: "{h}:{m}:{s},{f}",
+        day_period_time: "{T} {a}",
+        date_time: "{D}, {T}",
+    },
+    LocaleData {
+        language: "fr",
+        region: None,
+        months: [
+            ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+            [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+        ],
+        weekdays: [
+            ["D", "L", "M", "M", "J", "V", "S"],
+            ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+            [
+                "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+            ],
+        ],
+        eras: [
+            ["av. J.-C.", "ap. J.-C."],
+            ["av. J.-C.", "ap. J.-C."],
+            ["avant Jésus-Christ", "après Jésus-Christ"],
+        ],
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H23,
+        hour_cycle_12: HourCycle::H12,
+        pad_hours: true,
+        numeric_date: "{dd}/{MM}/{y}",
+        text_date: "{d} {M} {y}",
+        era_date: "{D} {G}",
+        weekday_date: "{E} {D}",
+        time: "{h}:{m}:{s},{f}",
+        day_period_time: "{T} {a}",
+        date_time: "{D} {T}",
+    },
+    LocaleData {
+        language: "ja",
+        region: None,
+        months: [
+            [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+        ],
+        weekdays: [
+            ["日", "月", "火", "水", "木", "金", "土"],
+            ["日", "月", "火", "水", "木", "金", "土"],
+            [
+                "日曜日",
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+            ],
+        ],
+        eras: [["紀元前", "西暦"], ["紀元前", "西暦"], ["紀元前", "西暦"]],
+        day_periods: ["午前", "午後"],
+        hour_cycle: HourCycle::H23,
+        hour_cycle_12: HourCycle::H11,
+        pad_hours: false,
+        numeric_date: "{y}/{M}/{d}",
+        text_date: "{y}年{M}{d}日",
+        era_date: "{G}{D}",
+        weekday_date: "{D}{E}",
+        time: "{h}:{m}:{s}.{f}",
+        day_period_time: "{a}{T}",
+        date_time: "{D} {T}",
+    },
+];
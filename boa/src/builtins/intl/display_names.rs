@@ -0,0 +1,535 @@
+//! This module implements the `Intl.DisplayNames` constructor.
+//!
+//! The names of languages, regions and scripts are looked up in the [ICU4X][icu4x] data of the
+//! resolved locale. That data has no names for currencies, so their codes are used instead, or
+//! `undefined` with the `"none"` fallback.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-displaynames-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames
+//! [icu4x]: https://github.com/unicode-org/icu4x
+
+use crate::{
+    builtins::{
+        intl::{
+            canonicalize_locale_list, get_option,
+            locale::{is_region_subtag, is_script_subtag},
+            number_format::is_well_formed_currency_code,
+            LanguageTag,
+        },
+        Array, BuiltIn,
+    },
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{internal_methods::get_prototype_from_constructor, ConstructorBuilder, ObjectData},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+use icu_experimental::displaynames::{
+    DisplayNamesOptions, LanguageDisplay as IcuLanguageDisplay, LanguageDisplayNames,
+    LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames, Style as IcuStyle,
+};
+use icu_locid::{subtags, LanguageIdentifier, Locale};
+use icu_provider::DataLocale;
+use std::{fmt, rc::Rc};
+
+/// The locale used when none of the requested locales is available.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The length of the display names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl Style {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+}
+
+/// The kind of codes whose display names are given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DisplayType {
+    Language,
+    Region,
+    Script,
+    Currency,
+}
+
+impl DisplayType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Language => "language",
+            Self::Region => "region",
+            Self::Script => "script",
+            Self::Currency => "currency",
+        }
+    }
+}
+
+/// What is returned for the codes without a display name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fallback {
+    /// The canonicalized code.
+    Code,
+    /// `undefined`.
+    None,
+}
+
+impl Fallback {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::None => "none",
+        }
+    }
+}
+
+/// How the names of languages with a region or a script are given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LanguageDisplay {
+    /// With the name of the dialect when there is one, like "British English".
+    Dialect,
+    /// With the region or the script in parentheses, like "English (United Kingdom)".
+    Standard,
+}
+
+impl LanguageDisplay {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Dialect => "dialect",
+            Self::Standard => "standard",
+        }
+    }
+}
+
+/// The ICU4X lookups of the display names of a type.
+enum Names {
+    Language {
+        locales: Box<LocaleDisplayNamesFormatter>,
+        languages: LanguageDisplayNames,
+    },
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    Currency,
+}
+
+impl fmt::Debug for Names {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Names")
+    }
+}
+
+/// The data of an `Intl.DisplayNames` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DisplayNames {
+    /// The resolved locale.
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    style: Style,
+    #[unsafe_ignore_trace]
+    display_type: DisplayType,
+    #[unsafe_ignore_trace]
+    fallback: Fallback,
+    #[unsafe_ignore_trace]
+    language_display: LanguageDisplay,
+    #[unsafe_ignore_trace]
+    names: Rc<Names>,
+}
+
+impl BuiltIn for DisplayNames {
+    const NAME: &'static str = "DisplayNames";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let display_names = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().display_names_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(2)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::of, "of", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.DisplayNames",
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, display_names.into(), Self::attribute())
+    }
+}
+
+impl DisplayNames {
+    /// `Intl.DisplayNames ( locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Intl.DisplayNames constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%DisplayNames.prototype%", ...).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::display_names_object,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = match args.get_or_undefined(1) {
+            JsValue::Undefined => None,
+            JsValue::Object(options) => Some(options.clone()),
+            _ => return context.throw_type_error("Intl.DisplayNames options should be an Object"),
+        };
+        let options = options.as_ref();
+
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        get_option(options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 10. Let r be ResolveLocale(%DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %DisplayNames%.[[RelevantExtensionKeys]]).
+        //
+        // Every locale is supported, with the data of its closest ancestor, so the first
+        // requested locale is used without its extensions.
+        let locale = requested_locales
+            .first()
+            .and_then(|locale| LanguageIdentifier::try_from_locale_bytes(locale.as_bytes()).ok())
+            .unwrap_or_else(|| {
+                DEFAULT_LOCALE
+                    .parse()
+                    .expect("the default locale should be valid")
+            });
+        let data_locale = DataLocale::from(&locale);
+
+        // 11. Let style be ? GetOption(options, "style", "string", « "narrow", "short", "long" », "long").
+        let style =
+            match get_option(options, "style", &["narrow", "short", "long"], context)?.as_deref() {
+                Some("narrow") => Style::Narrow,
+                Some("short") => Style::Short,
+                _ => Style::Long,
+            };
+
+        // 13. Let type be ? GetOption(options, "type", "string", « "language", "region", "script", "currency" », undefined).
+        // 14. If type is undefined, throw a TypeError exception.
+        let display_type = match get_option(
+            options,
+            "type",
+            &["language", "region", "script", "currency"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("language") => DisplayType::Language,
+            Some("region") => DisplayType::Region,
+            Some("script") => DisplayType::Script,
+            Some("currency") => DisplayType::Currency,
+            _ => return context.throw_type_error("Intl.DisplayNames type option is required"),
+        };
+
+        // 16. Let fallback be ? GetOption(options, "fallback", "string", « "code", "none" », "code").
+        let fallback = match get_option(options, "fallback", &["code", "none"], context)?.as_deref()
+        {
+            Some("none") => Fallback::None,
+            _ => Fallback::Code,
+        };
+
+        // 24. Let languageDisplay be ? GetOption(options, "languageDisplay", "string", « "dialect", "standard" », "dialect").
+        let language_display = match get_option(
+            options,
+            "languageDisplay",
+            &["dialect", "standard"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("standard") => LanguageDisplay::Standard,
+            _ => LanguageDisplay::Dialect,
+        };
+
+        let mut icu_options = DisplayNamesOptions::default();
+        icu_options.style = Some(match style {
+            Style::Narrow => IcuStyle::Narrow,
+            Style::Short => IcuStyle::Short,
+            Style::Long => IcuStyle::Long,
+        });
+        icu_options.language_display = match language_display {
+            LanguageDisplay::Dialect => IcuLanguageDisplay::Dialect,
+            LanguageDisplay::Standard => IcuLanguageDisplay::Standard,
+        };
+        let names = match display_type {
+            DisplayType::Language => {
+                LocaleDisplayNamesFormatter::try_new(&data_locale, icu_options).and_then(
+                    |locales| {
+                        let languages = LanguageDisplayNames::try_new(&data_locale, icu_options)?;
+                        Ok(Names::Language {
+                            locales: Box::new(locales),
+                            languages,
+                        })
+                    },
+                )
+            }
+            DisplayType::Region => {
+                RegionDisplayNames::try_new(&data_locale, icu_options).map(Names::Region)
+            }
+            DisplayType::Script => {
+                ScriptDisplayNames::try_new(&data_locale, icu_options).map(Names::Script)
+            }
+            DisplayType::Currency => Ok(Names::Currency),
+        }
+        .map_err(|_| {
+            context.construct_range_error(format!("No display names data for locale {}", locale))
+        })?;
+
+        let display_names = Self {
+            locale: locale.to_string().into(),
+            style,
+            display_type,
+            fallback,
+            language_display,
+            names: Rc::new(names),
+        };
+
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::display_names(Box::new(display_names));
+
+        // 31. Return displayNames.
+        Ok(obj.into())
+    }
+
+    /// `Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Every locale is supported, so this returns the canonicalized requested locales.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        let options = args.get_or_undefined(1);
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        get_option(
+            options.as_ref(),
+            "localeMatcher",
+            &["lookup", "best fit"],
+            context,
+        )?;
+        Ok(
+            Array::create_array_from_list(requested_locales.into_iter().map(JsValue::new), context)
+                .into(),
+        )
+    }
+
+    /// `Intl.DisplayNames.prototype.of ( code )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = Self::this_display_names(this, "of", context)?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        let code = match canonical_code_for_display_names(display_names.display_type, &code) {
+            Some(code) => code,
+            None => {
+                return Err(context.construct_range_error(format!(
+                    "Invalid {} code: {}",
+                    display_names.display_type.as_str(),
+                    code
+                )))
+            }
+        };
+
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        if let Some(name) = display_names.names.of(&code) {
+            return Ok(name.into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        // 8. Return undefined.
+        match display_names.fallback {
+            Fallback::Code => Ok(code.into()),
+            Fallback::None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// `Intl.DisplayNames.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = Self::this_display_names(this, "resolvedOptions", context)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 17, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options.create_data_property_or_throw("locale", display_names.locale.clone(), context)?;
+        options.create_data_property_or_throw("style", display_names.style.as_str(), context)?;
+        options.create_data_property_or_throw(
+            "type",
+            display_names.display_type.as_str(),
+            context,
+        )?;
+        options.create_data_property_or_throw(
+            "fallback",
+            display_names.fallback.as_str(),
+            context,
+        )?;
+        if display_names.display_type == DisplayType::Language {
+            options.create_data_property_or_throw(
+                "languageDisplay",
+                display_names.language_display.as_str(),
+                context,
+            )?;
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Returns the data of `this` if it is a display names object, or throws a `TypeError`
+    /// naming `method`.
+    fn this_display_names(this: &JsValue, method: &str, context: &mut Context) -> JsResult<Self> {
+        match this
+            .as_object()
+            .and_then(|obj| obj.borrow().as_display_names().cloned())
+        {
+            Some(display_names) => Ok(display_names),
+            None => Err(context.construct_type_error(format!(
+                "Intl.DisplayNames.prototype.{} called on an incompatible receiver",
+                method
+            ))),
+        }
+    }
+}
+
+impl Names {
+    /// Gets the display name of a canonicalized code, if the locale data has one.
+    fn of(&self, code: &str) -> Option<String> {
+        match self {
+            Self::Language { locales, languages } => {
+                let locale: Locale = code.parse().ok()?;
+                // The names of the languages without a name are made of their codes.
+                languages.of(locale.id.language)?;
+                Some(locales.of(&locale).into_owned())
+            }
+            Self::Region(regions) => regions
+                .of(code.parse::<subtags::Region>().ok()?)
+                .map(str::to_string),
+            Self::Script(scripts) => scripts
+                .of(code.parse::<subtags::Script>().ok()?)
+                .map(str::to_string),
+            Self::Currency => None,
+        }
+    }
+}
+
+/// Abstract operation `CanonicalCodeForDisplayNames ( type, code )`
+///
+/// Returns `None` if the code is not well-formed for the type.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_for_display_names(display_type: DisplayType, code: &str) -> Option<String> {
+    match display_type {
+        // 1. If type is "language", then
+        //     a. If code does not match the unicode_language_id production, throw a RangeError exception.
+        //     b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+        //     c. Return CanonicalizeUnicodeLocaleId(code).
+        DisplayType::Language => {
+            let mut tag = LanguageTag::parse(code).filter(LanguageTag::is_language_id)?;
+            tag.canonicalize();
+            Some(tag.to_string())
+        }
+        // 2. If type is "region", then
+        //     a. If code does not match the unicode_region_subtag production, throw a RangeError exception.
+        //     b. Return the ASCII-uppercase of code.
+        DisplayType::Region => is_region_subtag(code).then(|| code.to_ascii_uppercase()),
+        // 3. If type is "script", then
+        //     a. If code does not match the unicode_script_subtag production, throw a RangeError exception.
+        //     b. Return the result of converting code to title case.
+        DisplayType::Script => is_script_subtag(code).then(|| {
+            let mut script = code.to_ascii_lowercase();
+            script[..1].make_ascii_uppercase();
+            script
+        }),
+        // 6. Assert: type is "currency".
+        // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+        // 8. Return the ASCII-uppercase of code.
+        DisplayType::Currency => {
+            is_well_formed_currency_code(code).then(|| code.to_ascii_uppercase())
+        }
+    }
+}
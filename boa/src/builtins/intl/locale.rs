@@ -0,0 +1,686 @@
+//! This module implements the parsing and canonicalization of locale identifiers.
+//!
+//! Locales are identified by [Unicode BCP 47 locale identifiers][uts35], like `en-US` or
+//! `de-DE-u-co-phonebk`. A structurally valid identifier is canonicalized by normalizing the
+//! case of its subtags, sorting its variants and extensions, and replacing deprecated subtags
+//! by their preferred values.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [Unicode Technical Standard #35][uts35]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-language-tags
+//! [uts35]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
+
+use std::fmt::{self, Display, Write};
+
+/// The language aliases of the CLDR, as `(alias, language, script, region)`.
+///
+/// The script and region of an alias are only added to a locale that has none.
+const LANGUAGE_ALIASES: &[(&str, &str, Option<&str>, Option<&str>)] = &[
+    ("aar", "aa", None, None),
+    ("afr", "af", None, None),
+    ("alb", "sq", None, None),
+    ("amh", "am", None, None),
+    ("ara", "ar", None, None),
+    ("arm", "hy", None, None),
+    ("aze", "az", None, None),
+    ("baq", "eu", None, None),
+    ("bel", "be", None, None),
+    ("ben", "bn", None, None),
+    ("bos", "bs", None, None),
+    ("bul", "bg", None, None),
+    ("bur", "my", None, None),
+    ("cat", "ca", None, None),
+    ("ces", "cs", None, None),
+    ("chi", "zh", None, None),
+    ("cnr", "sr", None, Some("ME")),
+    ("cym", "cy", None, None),
+    ("cze", "cs", None, None),
+    ("dan", "da", None, None),
+    ("deu", "de", None, None),
+    ("dut", "nl", None, None),
+    ("ell", "el", None, None),
+    ("eng", "en", None, None),
+    ("est", "et", None, None),
+    ("eus", "eu", None, None),
+    ("fas", "fa", None, None),
+    ("per", "fa", None, None),
+    ("fin", "fi", None, None),
+    ("fra", "fr", None, None),
+    ("fre", "fr", None, None),
+    ("geo", "ka", None, None),
+    ("ger", "de", None, None),
+    ("gle", "ga", None, None),
+    ("glg", "gl", None, None),
+    ("gre", "el", None, None),
+    ("guj", "gu", None, None),
+    ("heb", "he", None, None),
+    ("hin", "hi", None, None),
+    ("hrv", "hr", None, None),
+    ("hun", "hu", None, None),
+    ("hye", "hy", None, None),
+    ("ice", "is", None, None),
+    ("in", "id", None, None),
+    ("ind", "id", None, None),
+    ("isl", "is", None, None),
+    ("ita", "it", None, None),
+    ("iw", "he", None, None),
+    ("jpn", "ja", None, None),
+    ("ji", "yi", None, None),
+    ("jw", "jv", None, None),
+    ("kat", "ka", None, None),
+    ("kaz", "kk", None, None),
+    ("khm", "km", None, None),
+    ("kor", "ko", None, None),
+    ("lao", "lo", None, None),
+    ("lat", "la", None, None),
+    ("lav", "lv", None, None),
+    ("lit", "lt", None, None),
+    ("mac", "mk", None, None),
+    ("mal", "ml", None, None),
+    ("mar", "mr", None, None),
+    ("may", "ms", None, None),
+    ("mkd", "mk", None, None),
+    ("mo", "ro", None, None),
+    ("mol", "ro", None, None),
+    ("mon", "mn", None, None),
+    ("msa", "ms", None, None),
+    ("mya", "my", None, None),
+    ("nep", "ne", None, None),
+    ("nld", "nl", None, None),
+    ("nob", "nb", None, None),
+    ("nno", "nn", None, None),
+    ("nor", "no", None, None),
+    ("pol", "pl", None, None),
+    ("por", "pt", None, None),
+    ("pan", "pa", None, None),
+    ("ron", "ro", None, None),
+    ("rum", "ro", None, None),
+    ("rus", "ru", None, None),
+    ("sh", "sr", Some("Latn"), None),
+    ("sin", "si", None, None),
+    ("slk", "sk", None, None),
+    ("slo", "sk", None, None),
+    ("slv", "sl", None, None),
+    ("spa", "es", None, None),
+    ("sqi", "sq", None, None),
+    ("srp", "sr", None, None),
+    ("swa", "sw", None, None),
+    ("swe", "sv", None, None),
+    ("tam", "ta", None, None),
+    ("tel", "te", None, None),
+    ("tha", "th", None, None),
+    ("tl", "fil", None, None),
+    ("tur", "tr", None, None),
+    ("ukr", "uk", None, None),
+    ("urd", "ur", None, None),
+    ("uzb", "uz", None, None),
+    ("vie", "vi", None, None),
+    ("wel", "cy", None, None),
+    ("zho", "zh", None, None),
+];
+
+/// The aliases of a language and a variant that are replaced by a single language, as
+/// `(language, variant, replacement)`.
+const LANGUAGE_VARIANT_ALIASES: &[(&str, &str, &str)] = &[
+    ("art", "lojban", "jbo"),
+    ("cel", "gaulish", "xtg"),
+    ("zh", "guoyu", "zh"),
+    ("zh", "hakka", "hak"),
+    ("zh", "xiang", "hsn"),
+];
+
+/// The region aliases of the CLDR.
+const REGION_ALIASES: &[(&str, &str)] = &[
+    ("004", "AF"),
+    ("036", "AU"),
+    ("040", "AT"),
+    ("056", "BE"),
+    ("076", "BR"),
+    ("124", "CA"),
+    ("156", "CN"),
+    ("208", "DK"),
+    ("246", "FI"),
+    ("250", "FR"),
+    ("276", "DE"),
+    ("356", "IN"),
+    ("380", "IT"),
+    ("392", "JP"),
+    ("410", "KR"),
+    ("484", "MX"),
+    ("528", "NL"),
+    ("578", "NO"),
+    ("643", "RU"),
+    ("724", "ES"),
+    ("752", "SE"),
+    ("756", "CH"),
+    ("826", "GB"),
+    ("840", "US"),
+    ("BU", "MM"),
+    ("CS", "RS"),
+    ("DD", "DE"),
+    ("FX", "FR"),
+    ("NT", "SA"),
+    ("QU", "EU"),
+    ("SU", "RU"),
+    ("TP", "TL"),
+    ("UK", "GB"),
+    ("YD", "YE"),
+    ("YU", "RS"),
+    ("ZR", "CD"),
+];
+
+/// The variant aliases of the CLDR.
+const VARIANT_ALIASES: &[(&str, &str)] = &[("heploc", "alalc97"), ("polytoni", "polyton")];
+
+/// The language, script, region and variants of a locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LanguageId {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+}
+
+impl LanguageId {
+    /// Parses the language identifier at the start of `subtags`, advancing them past it.
+    fn parse(subtags: &mut &[String]) -> Option<Self> {
+        let language = match subtags.first() {
+            Some(subtag) if is_language_subtag(subtag) => subtag.clone(),
+            _ => return None,
+        };
+        *subtags = &subtags[1..];
+
+        let script = subtags
+            .first()
+            .filter(|subtag| is_script_subtag(subtag))
+            .map(|subtag| title_case(subtag));
+        if script.is_some() {
+            *subtags = &subtags[1..];
+        }
+
+        let region = subtags
+            .first()
+            .filter(|subtag| is_region_subtag(subtag))
+            .map(|subtag| subtag.to_ascii_uppercase());
+        if region.is_some() {
+            *subtags = &subtags[1..];
+        }
+
+        let mut variants: Vec<String> = Vec::new();
+        while let Some(variant) = subtags.first().filter(|subtag| is_variant_subtag(subtag)) {
+            // Duplicate variants are not allowed.
+            if variants.contains(variant) {
+                return None;
+            }
+            variants.push(variant.clone());
+            *subtags = &subtags[1..];
+        }
+
+        Some(Self {
+            language,
+            script,
+            region,
+            variants,
+        })
+    }
+
+    /// Sorts the variants and replaces the aliased subtags by their preferred values.
+    fn canonicalize(&mut self) {
+        let language_variant = LANGUAGE_VARIANT_ALIASES
+            .iter()
+            .find(|(language, variant, _)| {
+                self.language == *language && self.variants.iter().any(|v| v == variant)
+            });
+        if let Some((_, variant, replacement)) = language_variant {
+            self.language = replacement.to_string();
+            self.variants.retain(|v| v != variant);
+        }
+
+        let language_alias = LANGUAGE_ALIASES
+            .iter()
+            .find(|(alias, ..)| self.language == *alias);
+        if let Some((_, language, script, region)) = language_alias {
+            self.language = language.to_string();
+            if self.script.is_none() {
+                self.script = script.map(str::to_string);
+            }
+            if self.region.is_none() {
+                self.region = region.map(str::to_string);
+            }
+        }
+
+        if let Some(region) = &mut self.region {
+            if let Some((_, replacement)) = REGION_ALIASES.iter().find(|(alias, _)| region == alias)
+            {
+                *region = replacement.to_string();
+            }
+        }
+
+        for variant in &mut self.variants {
+            if let Some((_, replacement)) =
+                VARIANT_ALIASES.iter().find(|(alias, _)| variant == alias)
+            {
+                *variant = replacement.to_string();
+            }
+        }
+        self.variants.sort();
+        self.variants.dedup();
+    }
+}
+
+impl Display for LanguageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.language)?;
+        for subtag in self.script.iter().chain(&self.region).chain(&self.variants) {
+            write!(f, "-{}", subtag)?;
+        }
+        Ok(())
+    }
+}
+
+/// The extension of a locale, introduced by a singleton subtag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Extension {
+    /// The `u` extension, holding attributes and keywords of the locale.
+    Unicode {
+        attributes: Vec<String>,
+        keywords: Vec<(String, Vec<String>)>,
+    },
+    /// The `t` extension, describing the source a content was transformed from.
+    Transformed {
+        language: Option<LanguageId>,
+        fields: Vec<(String, Vec<String>)>,
+    },
+    /// Any other extension.
+    Other(char, Vec<String>),
+}
+
+impl Extension {
+    /// Gets the singleton subtag that introduces the extension.
+    fn singleton(&self) -> char {
+        match self {
+            Self::Unicode { .. } => 'u',
+            Self::Transformed { .. } => 't',
+            Self::Other(singleton, _) => *singleton,
+        }
+    }
+
+    /// Parses the subtags of an extension that follow its singleton.
+    fn parse(singleton: char, mut subtags: &[String]) -> Option<Self> {
+        let extension = match singleton {
+            'u' => {
+                let mut attributes = Vec::new();
+                while let Some(attribute) = subtags.first().filter(|s| (3..=8).contains(&s.len())) {
+                    attributes.push(attribute.clone());
+                    subtags = &subtags[1..];
+                }
+                let keywords = parse_fields(subtags, is_unicode_key, false)?;
+                if attributes.is_empty() && keywords.is_empty() {
+                    return None;
+                }
+                Self::Unicode {
+                    attributes,
+                    keywords,
+                }
+            }
+            't' => {
+                let language = if subtags.first().map_or(false, |s| is_language_subtag(s)) {
+                    Some(LanguageId::parse(&mut subtags)?)
+                } else {
+                    None
+                };
+                let fields = parse_fields(subtags, is_transformed_key, true)?;
+                if language.is_none() && fields.is_empty() {
+                    return None;
+                }
+                Self::Transformed { language, fields }
+            }
+            _ => {
+                if subtags.is_empty() || subtags.iter().any(|s| s.len() < 2) {
+                    return None;
+                }
+                Self::Other(singleton, subtags.to_vec())
+            }
+        };
+        Some(extension)
+    }
+
+    /// Sorts the parts of the extension and replaces the aliased subtags by their preferred
+    /// values.
+    fn canonicalize(&mut self) {
+        match self {
+            Self::Unicode {
+                attributes,
+                keywords,
+            } => {
+                attributes.sort();
+                attributes.dedup();
+
+                // Only the first keyword with a given key is kept.
+                let mut seen = Vec::new();
+                keywords.retain(|(key, _)| {
+                    let first = !seen.contains(key);
+                    seen.push(key.clone());
+                    first
+                });
+                keywords.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                // A keyword without a value is `true`, which is the canonical form.
+                for (_, value) in keywords {
+                    if value.len() == 1 && value[0] == "true" {
+                        value.clear();
+                    }
+                }
+            }
+            Self::Transformed { language, fields } => {
+                if let Some(language) = language {
+                    language.canonicalize();
+                }
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+            }
+            Self::Other(..) => {}
+        }
+    }
+}
+
+impl Display for Extension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char(self.singleton())?;
+        match self {
+            Self::Unicode {
+                attributes,
+                keywords,
+            } => {
+                for attribute in attributes {
+                    write!(f, "-{}", attribute)?;
+                }
+                write_fields(f, keywords)
+            }
+            Self::Transformed { language, fields } => {
+                if let Some(language) = language {
+                    // The whole `t` extension is lowercase.
+                    write!(f, "-{}", language.to_string().to_ascii_lowercase())?;
+                }
+                write_fields(f, fields)
+            }
+            Self::Other(_, subtags) => {
+                for subtag in subtags {
+                    write!(f, "-{}", subtag)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A Unicode BCP 47 locale identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LanguageTag {
+    id: LanguageId,
+    extensions: Vec<Extension>,
+    private_use: Vec<String>,
+}
+
+impl LanguageTag {
+    /// Parses a structurally valid language tag, returning `None` if the tag is invalid.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-isstructurallyvalidlanguagetag
+    pub(crate) fn parse(tag: &str) -> Option<Self> {
+        let subtags: Vec<String> = tag.split('-').map(str::to_ascii_lowercase).collect();
+        if subtags
+            .iter()
+            .any(|s| s.is_empty() || s.len() > 8 || !s.bytes().all(|b| b.is_ascii_alphanumeric()))
+        {
+            return None;
+        }
+
+        let mut rest = subtags.as_slice();
+        let id = LanguageId::parse(&mut rest)?;
+
+        let mut extensions: Vec<Extension> = Vec::new();
+        let mut private_use = Vec::new();
+        while let Some(singleton) = rest.first() {
+            if singleton.len() != 1 {
+                return None;
+            }
+            let singleton = singleton.chars().next().expect("subtags are not empty");
+            rest = &rest[1..];
+
+            if singleton == 'x' {
+                if rest.is_empty() {
+                    return None;
+                }
+                private_use = rest.to_vec();
+                break;
+            }
+
+            let len = rest.iter().position(|s| s.len() == 1).unwrap_or(rest.len());
+            let extension = Extension::parse(singleton, &rest[..len])?;
+            // Duplicate singletons are not allowed.
+            if extensions.iter().any(|e| e.singleton() == singleton) {
+                return None;
+            }
+            extensions.push(extension);
+            rest = &rest[len..];
+        }
+
+        Some(Self {
+            id,
+            extensions,
+            private_use,
+        })
+    }
+
+    /// Canonicalizes the language tag.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-canonicalizeunicodelocaleid
+    pub(crate) fn canonicalize(&mut self) {
+        self.id.canonicalize();
+        for extension in &mut self.extensions {
+            extension.canonicalize();
+        }
+        self.extensions.sort_by_key(Extension::singleton);
+    }
+
+    /// Gets the language subtag of the tag.
+    pub(crate) fn language(&self) -> &str {
+        &self.id.language
+    }
+
+    /// Gets the script subtag of the tag, if it has one.
+    #[cfg(feature = "intl")]
+    pub(crate) fn script(&self) -> Option<&str> {
+        self.id.script.as_deref()
+    }
+
+    /// Gets the region subtag of the tag, if it has one.
+    #[cfg(feature = "intl")]
+    pub(crate) fn region(&self) -> Option<&str> {
+        self.id.region.as_deref()
+    }
+
+    /// Sets the language subtag of the tag, which must be a `unicode_language_subtag`.
+    #[cfg(feature = "intl")]
+    pub(crate) fn set_language(&mut self, language: &str) {
+        self.id.language = language.to_ascii_lowercase();
+    }
+
+    /// Sets or removes the script subtag of the tag, which must be a `unicode_script_subtag`.
+    #[cfg(feature = "intl")]
+    pub(crate) fn set_script(&mut self, script: Option<&str>) {
+        self.id.script = script.map(|script| title_case(&script.to_ascii_lowercase()));
+    }
+
+    /// Sets or removes the region subtag of the tag, which must be a `unicode_region_subtag`.
+    #[cfg(feature = "intl")]
+    pub(crate) fn set_region(&mut self, region: Option<&str>) {
+        self.id.region = region.map(str::to_ascii_uppercase);
+    }
+
+    /// Checks if the tag is a `unicode_language_id`, without extensions and private use subtags.
+    #[cfg(feature = "intl")]
+    pub(crate) fn is_language_id(&self) -> bool {
+        self.extensions.is_empty() && self.private_use.is_empty()
+    }
+
+    /// Gets the tag without its extensions and private use subtags.
+    pub(crate) fn base_name(&self) -> String {
+        self.id.to_string()
+    }
+
+    /// Gets the value of a keyword of the `u` extension of the tag, if it has the keyword.
+    ///
+    /// The value of a keyword without a value is the empty string.
+    pub(crate) fn unicode_keyword(&self, key: &str) -> Option<String> {
+        self.extensions
+            .iter()
+            .find_map(|extension| match extension {
+                Extension::Unicode { keywords, .. } => keywords
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, value)| value.join("-")),
+                _ => None,
+            })
+    }
+
+    /// Sets the value of a keyword of the `u` extension of the tag, adding the extension if the
+    /// tag has none. The value must be a sequence of `type` subtags separated by hyphens.
+    #[cfg(feature = "intl")]
+    pub(crate) fn set_unicode_keyword(&mut self, key: &str, value: &str) {
+        let value: Vec<String> = value
+            .split('-')
+            .filter(|subtag| !subtag.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect();
+
+        let keywords = self
+            .extensions
+            .iter_mut()
+            .find_map(|extension| match extension {
+                Extension::Unicode { keywords, .. } => Some(keywords),
+                _ => None,
+            });
+        match keywords {
+            Some(keywords) => match keywords.iter_mut().find(|(k, _)| k == key) {
+                Some((_, old)) => *old = value,
+                None => keywords.push((key.to_string(), value)),
+            },
+            None => self.extensions.push(Extension::Unicode {
+                attributes: Vec::new(),
+                keywords: vec![(key.to_string(), value)],
+            }),
+        }
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id.fmt(f)?;
+        for extension in &self.extensions {
+            write!(f, "-{}", extension)?;
+        }
+        if !self.private_use.is_empty() {
+            write!(f, "-x-{}", self.private_use.join("-"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks if a subtag is a `unicode_language_subtag`.
+pub(crate) fn is_language_subtag(subtag: &str) -> bool {
+    matches!(subtag.len(), 2..=3 | 5..=8) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Checks if a subtag is a `unicode_script_subtag`.
+pub(crate) fn is_script_subtag(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Checks if a subtag is a `unicode_region_subtag`.
+pub(crate) fn is_region_subtag(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Checks if a subtag is a `unicode_variant_subtag`.
+fn is_variant_subtag(subtag: &str) -> bool {
+    match subtag.len() {
+        5..=8 => true,
+        4 => subtag.as_bytes()[0].is_ascii_digit(),
+        _ => false,
+    }
+}
+
+/// Checks if a string is a `type` of a keyword of the `u` extension, made of subtags of 3 to 8
+/// alphanumeric characters separated by hyphens.
+#[cfg(feature = "intl")]
+pub(crate) fn is_unicode_type(value: &str) -> bool {
+    value.split('-').all(|subtag| {
+        (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
+
+/// Checks if a subtag is the key of a keyword of the `u` extension.
+fn is_unicode_key(subtag: &str) -> bool {
+    subtag.len() == 2 && subtag.as_bytes()[1].is_ascii_alphabetic()
+}
+
+/// Checks if a subtag is the key of a field of the `t` extension.
+fn is_transformed_key(subtag: &str) -> bool {
+    let bytes = subtag.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1].is_ascii_digit()
+}
+
+/// Parses a sequence of keys, each followed by its values.
+///
+/// Keys of the `t` extension must have at least one value, which is given by `requires_value`.
+fn parse_fields(
+    mut subtags: &[String],
+    is_key: fn(&str) -> bool,
+    requires_value: bool,
+) -> Option<Vec<(String, Vec<String>)>> {
+    let mut fields = Vec::new();
+    while let Some(key) = subtags.first() {
+        if !is_key(key) {
+            return None;
+        }
+        subtags = &subtags[1..];
+
+        let len = subtags
+            .iter()
+            .position(|s| !(3..=8).contains(&s.len()))
+            .unwrap_or(subtags.len());
+        if len == 0 && requires_value {
+            return None;
+        }
+        fields.push((key.clone(), subtags[..len].to_vec()));
+        subtags = &subtags[len..];
+    }
+    Some(fields)
+}
+
+/// Writes the keys and values of an extension.
+fn write_fields(f: &mut fmt::Formatter<'_>, fields: &[(String, Vec<String>)]) -> fmt::Result {
+    for (key, value) in fields {
+        write!(f, "-{}", key)?;
+        for subtag in value {
+            write!(f, "-{}", subtag)?;
+        }
+    }
+    Ok(())
+}
+
+/// Converts a lowercase subtag to title case.
+fn title_case(subtag: &str) -> String {
+    let mut result = subtag.to_string();
+    result[..1].make_ascii_uppercase();
+    result
+}
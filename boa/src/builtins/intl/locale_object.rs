@@ -0,0 +1,531 @@
+//! This module implements the `Intl.Locale` constructor.
+//!
+//! A locale object holds a canonicalized Unicode locale identifier, whose subtags and `u`
+//! extension keywords are exposed by its accessors. Its likely subtags are added and removed
+//! with the [ICU4X][icu4x] data of the CLDR.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#locale-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale
+//! [icu4x]: https://github.com/unicode-org/icu4x
+
+use crate::{
+    builtins::{
+        function::NativeFunction,
+        intl::{
+            get_bool_option, get_option,
+            locale::{is_language_subtag, is_region_subtag, is_script_subtag, is_unicode_type},
+            LanguageTag,
+        },
+        BuiltIn,
+    },
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+use icu_locid::LanguageIdentifier;
+use icu_locid_transform::LocaleExpander;
+
+/// The data of an `Intl.Locale` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Locale {
+    /// The canonicalized locale identifier.
+    #[unsafe_ignore_trace]
+    tag: LanguageTag,
+}
+
+impl BuiltIn for Locale {
+    const NAME: &'static str = "Locale";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let accessors: [(&str, NativeFunction); 10] = [
+            ("baseName", Self::get_base_name),
+            ("calendar", Self::get_calendar),
+            ("caseFirst", Self::get_case_first),
+            ("collation", Self::get_collation),
+            ("hourCycle", Self::get_hour_cycle),
+            ("numeric", Self::get_numeric),
+            ("numberingSystem", Self::get_numbering_system),
+            ("language", Self::get_language),
+            ("script", Self::get_script),
+            ("region", Self::get_region),
+        ];
+        let accessors: Vec<_> = accessors
+            .iter()
+            .map(|&(name, getter)| {
+                let getter = FunctionBuilder::native(context, getter)
+                    .name(format!("get {}", name))
+                    .constructable(false)
+                    .build();
+                (name, getter)
+            })
+            .collect();
+
+        let mut locale = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().locale_object().clone(),
+        );
+        locale
+            .name(Self::NAME)
+            .length(1)
+            .method(Self::maximize, "maximize", 0)
+            .method(Self::minimize, "minimize", 0)
+            .method(Self::to_string, "toString", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Intl.Locale",
+                Attribute::readonly_builtin(),
+            );
+        for (name, getter) in accessors {
+            locale.accessor(name, Some(getter), None, Attribute::readonly_builtin());
+        }
+        let locale = locale.build();
+
+        (Self::NAME, locale.into(), Self::attribute())
+    }
+}
+
+impl Locale {
+    /// Gets the canonicalized identifier of the locale.
+    pub(crate) fn tag(&self) -> JsString {
+        self.tag.to_string().into()
+    }
+
+    /// `Intl.Locale ( tag [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/Locale
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Intl.Locale constructor without new is forbidden",
+            );
+        }
+
+        // 6. Let locale be ? OrdinaryCreateFromConstructor(NewTarget, "%Locale.prototype%", internalSlotsList).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::locale_object, context)?;
+
+        // 7. If Type(tag) is not String or Object, throw a TypeError exception.
+        let tag = args.get_or_undefined(0);
+        if !(tag.is_string() || tag.is_object()) {
+            return context.throw_type_error("Intl.Locale tag should be a String or an Object");
+        }
+
+        // 8. If Type(tag) is Object and tag has an [[InitializedLocale]] internal slot, then
+        //     a. Let tag be tag.[[Locale]].
+        // 9. Else,
+        //     a. Let tag be ? ToString(tag).
+        let tag = match tag
+            .as_object()
+            .and_then(|obj| obj.borrow().as_locale().map(Self::tag))
+        {
+            Some(tag) => tag,
+            None => tag.to_string(context)?,
+        };
+
+        // 10. Set options to ? CoerceOptionsToObject(options).
+        let options = args.get_or_undefined(1);
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        let options = options.as_ref();
+
+        // 11. Set tag to ? ApplyOptionsToTag(tag, options).
+        let mut tag = apply_options_to_tag(&tag, options, context)?;
+
+        // 13. Let calendar be ? GetOption(options, "calendar", "string", empty, undefined).
+        // 14. If calendar is not undefined, then
+        //     a. If calendar does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        let calendar = get_type_option(options, "calendar", context)?;
+        // 16. Let collation be ? GetOption(options, "collation", "string", empty, undefined).
+        let collation = get_type_option(options, "collation", context)?;
+        // 19. Let hc be ? GetOption(options, "hourCycle", "string", « "h11", "h12", "h23", "h24" », undefined).
+        let hour_cycle = get_option(options, "hourCycle", &["h11", "h12", "h23", "h24"], context)?;
+        // 21. Let kf be ? GetOption(options, "caseFirst", "string", « "upper", "lower", "false" », undefined).
+        let case_first = get_option(options, "caseFirst", &["upper", "lower", "false"], context)?;
+        // 23. Let kn be ? GetOption(options, "numeric", "boolean", empty, undefined).
+        // 24. If kn is not undefined, set kn to ! ToString(kn).
+        let numeric = get_bool_option(options, "numeric", context)?
+            .map(|numeric| JsString::from(if numeric { "true" } else { "false" }));
+        // 26. Let numberingSystem be ? GetOption(options, "numberingSystem", "string", empty, undefined).
+        let numbering_system = get_type_option(options, "numberingSystem", context)?;
+
+        // 29. Let r be ApplyUnicodeExtensionToTag(tag, opt, relevantExtensionKeys).
+        for (key, value) in [
+            ("ca", calendar),
+            ("co", collation),
+            ("hc", hour_cycle),
+            ("kf", case_first),
+            ("kn", numeric),
+            ("nu", numbering_system),
+        ] {
+            if let Some(value) = value {
+                tag.set_unicode_keyword(key, &value);
+            }
+        }
+        tag.canonicalize();
+
+        // 30. Set locale.[[Locale]] to r.[[locale]].
+        let locale = context.construct_object();
+        locale.set_prototype_instance(prototype.into());
+        locale.borrow_mut().data = ObjectData::locale(Box::new(Self { tag }));
+
+        // 44. Return locale.
+        Ok(locale.into())
+    }
+
+    /// Creates a new `Intl.Locale` object with the standard prototype.
+    fn create(tag: LanguageTag, context: &mut Context) -> JsValue {
+        let prototype = context.standard_objects().locale_object().prototype();
+        let locale = context.construct_object();
+        locale.set_prototype_instance(prototype.into());
+        locale.borrow_mut().data = ObjectData::locale(Box::new(Self { tag }));
+        locale.into()
+    }
+
+    /// `Intl.Locale.prototype.maximize ( )`
+    ///
+    /// Returns the locale with its likely subtags added.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.maximize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/maximize
+    fn maximize(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let mut tag = Self::this_locale(this, "maximize", context)?.tag.clone();
+
+        // 3. Let maximal be the result of the Add Likely Subtags algorithm applied to loc.[[Locale]].
+        expand_likely_subtags(&mut tag, |expander, id| {
+            expander.maximize(id);
+        });
+
+        // 4. Return ! Construct(%Locale%, maximal).
+        Ok(Self::create(tag, context))
+    }
+
+    /// `Intl.Locale.prototype.minimize ( )`
+    ///
+    /// Returns the locale with the subtags that would be added by `maximize` removed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.minimize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/minimize
+    fn minimize(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let mut tag = Self::this_locale(this, "minimize", context)?.tag.clone();
+
+        // 3. Let minimal be the result of the Remove Likely Subtags algorithm applied to loc.[[Locale]].
+        expand_likely_subtags(&mut tag, |expander, id| {
+            expander.minimize(id);
+        });
+
+        // 4. Return ! Construct(%Locale%, minimal).
+        Ok(Self::create(tag, context))
+    }
+
+    /// `Intl.Locale.prototype.toString ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.toString
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Return loc.[[Locale]].
+        Ok(Self::this_locale(this, "toString", context)?.tag().into())
+    }
+
+    /// `get Intl.Locale.prototype.baseName`
+    ///
+    /// Returns the locale without its extensions and private use subtags.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.baseName
+    fn get_base_name(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let locale = Self::this_locale(this, "baseName", context)?;
+        Ok(locale.tag.base_name().into())
+    }
+
+    /// `get Intl.Locale.prototype.calendar`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.calendar
+    fn get_calendar(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::get_keyword(this, "calendar", "ca", context)
+    }
+
+    /// `get Intl.Locale.prototype.caseFirst`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.caseFirst
+    fn get_case_first(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::get_keyword(this, "caseFirst", "kf", context)
+    }
+
+    /// `get Intl.Locale.prototype.collation`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.collation
+    fn get_collation(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::get_keyword(this, "collation", "co", context)
+    }
+
+    /// `get Intl.Locale.prototype.hourCycle`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.hourCycle
+    fn get_hour_cycle(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::get_keyword(this, "hourCycle", "hc", context)
+    }
+
+    /// `get Intl.Locale.prototype.numeric`
+    ///
+    /// Returns `true` if the `kn` keyword of the locale is `true`, which is also the value of
+    /// the keyword without a value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.numeric
+    fn get_numeric(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let locale = Self::this_locale(this, "numeric", context)?;
+        let numeric = locale.tag.unicode_keyword("kn");
+        Ok(matches!(numeric.as_deref(), Some("") | Some("true")).into())
+    }
+
+    /// `get Intl.Locale.prototype.numberingSystem`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.numberingSystem
+    fn get_numbering_system(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::get_keyword(this, "numberingSystem", "nu", context)
+    }
+
+    /// `get Intl.Locale.prototype.language`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.language
+    fn get_language(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let locale = Self::this_locale(this, "language", context)?;
+        Ok(locale.tag.language().into())
+    }
+
+    /// `get Intl.Locale.prototype.script`
+    ///
+    /// Returns `undefined` if the locale has no script subtag.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.script
+    fn get_script(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let locale = Self::this_locale(this, "script", context)?;
+        Ok(locale
+            .tag
+            .script()
+            .map_or(JsValue::undefined(), JsValue::new))
+    }
+
+    /// `get Intl.Locale.prototype.region`
+    ///
+    /// Returns `undefined` if the locale has no region subtag.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.region
+    fn get_region(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let locale = Self::this_locale(this, "region", context)?;
+        Ok(locale
+            .tag
+            .region()
+            .map_or(JsValue::undefined(), JsValue::new))
+    }
+
+    /// Gets the value of the `key` keyword of the `u` extension of the locale, or `undefined` if
+    /// the locale doesn't have the keyword.
+    fn get_keyword(
+        this: &JsValue,
+        method: &str,
+        key: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locale = Self::this_locale(this, method, context)?;
+        Ok(locale
+            .tag
+            .unicode_keyword(key)
+            .map_or(JsValue::undefined(), JsValue::new))
+    }
+
+    /// Returns the data of `this` if it is a locale, or throws a `TypeError` naming `method`.
+    fn this_locale(this: &JsValue, method: &str, context: &mut Context) -> JsResult<Self> {
+        match this
+            .as_object()
+            .and_then(|obj| obj.borrow().as_locale().cloned())
+        {
+            Some(locale) => Ok(locale),
+            None => Err(context.construct_type_error(format!(
+                "Intl.Locale.prototype.{} called on an incompatible receiver",
+                method
+            ))),
+        }
+    }
+}
+
+/// Abstract operation `ApplyOptionsToTag ( tag, options )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-apply-options-to-tag
+fn apply_options_to_tag(
+    tag: &str,
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<LanguageTag> {
+    // 1. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
+    let mut tag = match LanguageTag::parse(tag) {
+        Some(tag) => tag,
+        None => {
+            return Err(
+                context.construct_range_error(format!("Incorrect locale information: {}", tag))
+            )
+        }
+    };
+
+    // 2. Let language be ? GetOption(options, "language", "string", empty, undefined).
+    // 3. If language is not undefined, then
+    //     a. If language does not match the unicode_language_subtag production, throw a RangeError exception.
+    let language = get_subtag_option(options, "language", is_language_subtag, context)?;
+    // 4. Let script be ? GetOption(options, "script", "string", empty, undefined).
+    let script = get_subtag_option(options, "script", is_script_subtag, context)?;
+    // 6. Let region be ? GetOption(options, "region", "string", empty, undefined).
+    let region = get_subtag_option(options, "region", is_region_subtag, context)?;
+
+    // 8. Set tag to ! CanonicalizeUnicodeLocaleId(tag).
+    tag.canonicalize();
+
+    // 9. Assert: tag matches the unicode_locale_id production.
+    // 10. Let languageId be the longest prefix of tag matched by the unicode_language_id production.
+    // 11. If language is not undefined, then
+    //     a. Set languageId to languageId with the unicode_language_subtag replaced by language.
+    if let Some(language) = language {
+        tag.set_language(&language);
+    }
+    // 12. If script is not undefined, then
+    if let Some(script) = script {
+        tag.set_script(Some(&script));
+    }
+    // 13. If region is not undefined, then
+    if let Some(region) = region {
+        tag.set_region(Some(&region));
+    }
+
+    // 14. Replace the longest prefix of tag matched by the unicode_language_id production with languageId.
+    // 15. Return ! CanonicalizeUnicodeLocaleId(tag).
+    tag.canonicalize();
+    Ok(tag)
+}
+
+/// Gets the value of an option that must be a single subtag, throwing a `RangeError` if it is
+/// not one.
+fn get_subtag_option(
+    options: Option<&JsObject>,
+    property: &str,
+    is_subtag: fn(&str) -> bool,
+    context: &mut Context,
+) -> JsResult<Option<JsString>> {
+    match get_option(options, property, &[], context)? {
+        Some(value) if !is_subtag(&value) => Err(context
+            .construct_range_error(format!("Invalid value for option {}: {}", property, value))),
+        value => Ok(value),
+    }
+}
+
+/// Gets the value of an option that must be the `type` of a keyword of the `u` extension,
+/// throwing a `RangeError` if it is not one.
+fn get_type_option(
+    options: Option<&JsObject>,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<JsString>> {
+    get_subtag_option(options, property, is_unicode_type, context)
+}
+
+/// Replaces the language, script and region of a tag with the ones transformed by `expand`
+/// with the likely subtags of the CLDR.
+fn expand_likely_subtags(
+    tag: &mut LanguageTag,
+    expand: impl FnOnce(&LocaleExpander, &mut LanguageIdentifier),
+) {
+    let language = match tag.language().parse() {
+        Ok(language) => language,
+        Err(_) => return,
+    };
+    let script = tag.script().and_then(|script| script.parse().ok());
+    let region = tag.region().and_then(|region| region.parse().ok());
+    let mut id = LanguageIdentifier::from((language, script, region));
+
+    expand(&LocaleExpander::new_extended(), &mut id);
+
+    tag.set_language(id.language.as_str());
+    tag.set_script(id.script.as_ref().map(|script| script.as_str()));
+    tag.set_region(id.region.as_ref().map(|region| region.as_str()));
+}
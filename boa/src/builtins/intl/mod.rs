@@ -0,0 +1,269 @@
+//! This module implements the global `Intl` object.
+//!
+//! `Intl` is a built-in object that has properties and methods for i18n. It's not a function object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl
+
+pub mod collator;
+#[cfg(feature = "intl")]
+pub mod date_time_format;
+#[cfg(feature = "intl")]
+pub mod display_names;
+mod locale;
+#[cfg(feature = "intl")]
+pub mod locale_object;
+#[cfg(feature = "intl")]
+pub mod number_format;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::locale::LanguageTag;
+
+use crate::{
+    builtins::{Array, BuiltIn},
+    object::{JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+
+/// JavaScript `Intl` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Intl;
+
+impl BuiltIn for Intl {
+    const NAME: &'static str = "Intl";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let collator = collator::Collator::init(context);
+        #[cfg(feature = "intl")]
+        let number_format = number_format::NumberFormat::init(context);
+        #[cfg(feature = "intl")]
+        let date_time_format = date_time_format::DateTimeFormat::init(context);
+        #[cfg(feature = "intl")]
+        let display_names = display_names::DisplayNames::init(context);
+        #[cfg(feature = "intl")]
+        let locale = locale_object::Locale::init(context);
+
+        let string_tag = WellKnownSymbols::to_string_tag();
+        let mut object = ObjectInitializer::new(context);
+        object
+            .function(Self::get_canonical_locales, "getCanonicalLocales", 1)
+            .property(collator.0, collator.1, collator.2)
+            .property(string_tag, Self::NAME, Attribute::readonly_builtin());
+        #[cfg(feature = "intl")]
+        object
+            .property(number_format.0, number_format.1, number_format.2)
+            .property(date_time_format.0, date_time_format.1, date_time_format.2)
+            .property(display_names.0, display_names.1, display_names.2)
+            .property(locale.0, locale.1, locale.2);
+        let object = object.build();
+
+        (Self::NAME, object.into(), Self::attribute())
+    }
+}
+
+impl Intl {
+    /// `Intl.getCanonicalLocales ( locales )`
+    ///
+    /// Returns an array containing the canonical locale names. Duplicates will be omitted and
+    /// elements will be validated as structurally valid language tags.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.getcanonicallocales
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/getCanonicalLocales
+    pub(crate) fn get_canonical_locales(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0).clone();
+
+        // 1. Let ll be ? CanonicalizeLocaleList(locales).
+        let ll = canonicalize_locale_list(&locales, context)?;
+
+        // 2. Return CreateArrayFromList(ll).
+        Ok(Array::create_array_from_list(ll.into_iter().map(JsValue::new), context).into())
+    }
+}
+
+/// Canonicalizes a list of locales, given as a single locale or an array-like object of
+/// locales.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalizelocalelist
+pub(crate) fn canonicalize_locale_list(
+    locales: &JsValue,
+    context: &mut Context,
+) -> JsResult<Vec<JsString>> {
+    // 1. If locales is undefined, then
+    //     a. Return a new empty List.
+    if locales.is_undefined() {
+        return Ok(Vec::new());
+    }
+
+    // 2. Let seen be a new empty List.
+    let mut seen = Vec::new();
+
+    // 3. If Type(locales) is String or locales has an [[InitializedLocale]] internal slot, then
+    //     a. Let O be CreateArrayFromList(« locales »).
+    // 4. Else,
+    //     a. Let O be ? ToObject(locales).
+    let o = if locales.is_string() || locale_tag(locales).is_some() {
+        Array::create_array_from_list([locales.clone()], context)
+    } else {
+        locales.to_object(context)?
+    };
+
+    // 5. Let len be ? ToLength(? Get(O, "length")).
+    let len = o.length_of_array_like(context)?;
+
+    // 6. Let k be 0.
+    // 7. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ToString(k).
+        // b. Let kPresent be ? HasProperty(O, Pk).
+        // c. If kPresent is true, then
+        if o.has_property(k, context)? {
+            // i. Let kValue be ? Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // ii. If Type(kValue) is not String or Object, throw a TypeError exception.
+            if !(k_value.is_string() || k_value.is_object()) {
+                return Err(context.construct_type_error("locale should be a String or an Object"));
+            }
+
+            // iii. If Type(kValue) is Object and kValue has an [[InitializedLocale]] internal slot, then
+            //     1. Let tag be kValue.[[Locale]].
+            // iv. Else,
+            //     1. Let tag be ? ToString(kValue).
+            let tag = match locale_tag(&k_value) {
+                Some(tag) => tag,
+                None => k_value.to_string(context)?,
+            };
+
+            // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
+            let mut tag = match LanguageTag::parse(&tag) {
+                Some(tag) => tag,
+                None => {
+                    return Err(context
+                        .construct_range_error(format!("Incorrect locale information: {}", tag)))
+                }
+            };
+
+            // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
+            tag.canonicalize();
+            let canonicalized_tag = JsString::from(tag.to_string());
+
+            // vii. If canonicalizedTag is not an element of seen, append canonicalizedTag as the last element of seen.
+            if !seen.contains(&canonicalized_tag) {
+                seen.push(canonicalized_tag);
+            }
+        }
+        // d. Increase k by 1.
+    }
+
+    // 8. Return seen.
+    Ok(seen)
+}
+
+/// Gets the tag of a value if it is an `Intl.Locale` object.
+#[cfg(feature = "intl")]
+fn locale_tag(value: &JsValue) -> Option<JsString> {
+    let obj = value.as_object()?;
+    let obj = obj.borrow();
+    obj.as_locale().map(locale_object::Locale::tag)
+}
+
+/// Gets the tag of a value if it is an `Intl.Locale` object, which don't exist without the
+/// `intl` feature.
+#[cfg(not(feature = "intl"))]
+fn locale_tag(_: &JsValue) -> Option<JsString> {
+    None
+}
+
+/// Gets the value of a string option, throwing a `RangeError` if it is not one of the allowed
+/// `values`. Every value is allowed if `values` is empty.
+///
+/// Returns `None` if the option is not present.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_option(
+    options: Option<&JsObject>,
+    property: &str,
+    values: &[&str],
+    context: &mut Context,
+) -> JsResult<Option<JsString>> {
+    // 1. Assert: Type(options) is Object.
+    // 2. Let value be ? Get(options, property).
+    let value = match options {
+        Some(options) => options.get(property, context)?,
+        None => return Ok(None),
+    };
+
+    // 3. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 4. Assert: type is "boolean" or "string".
+    // 5. If type is "boolean", then
+    //     a. Let value be ! ToBoolean(value).
+    // 6. If type is "string", then
+    //     a. Let value be ? ToString(value).
+    let value = value.to_string(context)?;
+
+    // 7. If values is not undefined and values does not contain an element equal to value, throw a RangeError exception.
+    if !values.is_empty() && !values.contains(&value.as_str()) {
+        return Err(context
+            .construct_range_error(format!("Invalid value for option {}: {}", property, value)));
+    }
+
+    // 8. Return value.
+    Ok(Some(value))
+}
+
+/// Gets the value of a boolean option.
+///
+/// Returns `None` if the option is not present.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_bool_option(
+    options: Option<&JsObject>,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<bool>> {
+    let value = match options {
+        Some(options) => options.get(property, context)?,
+        None => return Ok(None),
+    };
+
+    if value.is_undefined() {
+        Ok(None)
+    } else {
+        Ok(Some(value.to_boolean()))
+    }
+}
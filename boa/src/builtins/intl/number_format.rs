@@ -0,0 +1,834 @@
+//! This module implements the `Intl.NumberFormat` constructor.
+//!
+//! Numbers are formatted with the [ICU4X][icu4x] data of the resolved locale, which determines
+//! their digits, their decimal and grouping separators, and the patterns of percentages and
+//! currencies.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#numberformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+//! [icu4x]: https://github.com/unicode-org/icu4x
+
+use crate::{
+    builtins::{
+        intl::{canonicalize_locale_list, get_bool_option, get_option},
+        Array, BuiltIn,
+    },
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::{ArgumentsExt, Numeric},
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use icu_decimal::{
+    options::{FixedDecimalFormatterOptions, GroupingStrategy},
+    FixedDecimalFormatter,
+};
+use icu_experimental::{
+    dimension::provider::{
+        currency::{CurrencyEssentialsV1Marker, PatternSelection, PlaceholderValue},
+        percent::PercentEssentialsV1Marker,
+    },
+    provider::Baked,
+};
+use icu_locid::LanguageIdentifier;
+use icu_provider::{DataLocale, DataProvider, DataRequest};
+use std::{rc::Rc, str::FromStr};
+use tinystr::TinyAsciiStr;
+
+/// The locale used when none of the requested locales is available.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The formatting style of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Decimal,
+    Percent,
+    Currency,
+}
+
+impl Style {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Decimal => "decimal",
+            Self::Percent => "percent",
+            Self::Currency => "currency",
+        }
+    }
+}
+
+/// How the currency of a currency format is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurrencyDisplay {
+    Code,
+    Symbol,
+    NarrowSymbol,
+}
+
+impl CurrencyDisplay {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::Symbol => "symbol",
+            Self::NarrowSymbol => "narrowSymbol",
+        }
+    }
+}
+
+/// How numbers are rounded before being formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rounding {
+    /// Keep between `min` and `max` fraction digits.
+    FractionDigits { min: u8, max: u8 },
+    /// Keep between `min` and `max` significant digits.
+    SignificantDigits { min: u8, max: u8 },
+}
+
+/// The data of an `Intl.NumberFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct NumberFormat {
+    /// The resolved locale.
+    locale: JsString,
+    #[unsafe_ignore_trace]
+    style: Style,
+    /// The upper case code of the currency of a currency format.
+    currency: Option<JsString>,
+    #[unsafe_ignore_trace]
+    currency_display: CurrencyDisplay,
+    minimum_integer_digits: u8,
+    #[unsafe_ignore_trace]
+    rounding: Rounding,
+    use_grouping: bool,
+    /// The formatter of the digits of the numbers.
+    #[unsafe_ignore_trace]
+    formatter: Rc<FixedDecimalFormatter>,
+    /// The prefix and the suffix wrapped around the formatted digits, for the percent sign or
+    /// the currency.
+    prefix: String,
+    suffix: String,
+    /// The minus sign of the locale.
+    minus_sign: String,
+    /// The function returned by the `format` getter, once created.
+    bound_format: Option<JsObject>,
+}
+
+impl BuiltIn for NumberFormat {
+    const NAME: &'static str = "NumberFormat";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_format = FunctionBuilder::native(context, Self::get_format)
+            .name("get format")
+            .constructable(false)
+            .build();
+
+        let number_format = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().number_format_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor(
+            "format",
+            Some(get_format),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.NumberFormat",
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, number_format.into(), Self::attribute())
+    }
+}
+
+impl NumberFormat {
+    /// Creates a number format from the `locales` and `options` arguments of a locale-sensitive
+    /// formatting.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        let options = options.as_ref();
+
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+        get_option(options, "localeMatcher", &["lookup", "best fit"], context)?;
+
+        // 11. Let r be ResolveLocale(%NumberFormat%.[[AvailableLocales]], requestedLocales, opt, %NumberFormat%.[[RelevantExtensionKeys]], localeData).
+        //
+        // Every locale is supported, with the data of its closest ancestor, so the first
+        // requested locale is used without its extensions.
+        let locale = requested_locales
+            .first()
+            .and_then(|locale| LanguageIdentifier::try_from_locale_bytes(locale.as_bytes()).ok())
+            .unwrap_or_else(|| {
+                DEFAULT_LOCALE
+                    .parse()
+                    .expect("the default locale should be valid")
+            });
+        let data_locale = DataLocale::from(&locale);
+
+        // 14. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+        let style = match get_option(
+            options,
+            "style",
+            &["decimal", "percent", "currency"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("percent") => Style::Percent,
+            Some("currency") => Style::Currency,
+            _ => Style::Decimal,
+        };
+
+        let currency = match get_option(options, "currency", &[], context)? {
+            Some(currency) if !is_well_formed_currency_code(&currency) => {
+                return Err(
+                    context.construct_range_error(format!("Invalid currency code: {}", currency))
+                );
+            }
+            currency => currency.map(|currency| JsString::from(currency.to_ascii_uppercase())),
+        };
+
+        let currency_display = match get_option(
+            options,
+            "currencyDisplay",
+            &["code", "symbol", "narrowSymbol"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("code") => CurrencyDisplay::Code,
+            Some("narrowSymbol") => CurrencyDisplay::NarrowSymbol,
+            _ => CurrencyDisplay::Symbol,
+        };
+
+        if style == Style::Currency && currency.is_none() {
+            return Err(
+                context.construct_type_error("Currency code is required with currency style")
+            );
+        }
+
+        // 15. Let style be numberFormat.[[Style]].
+        // 16. If style is "currency", then
+        //     a. Let currency be numberFormat.[[Currency]].
+        //     b. Let cDigits be CurrencyDigits(currency).
+        //     c. Let mnfdDefault be cDigits.
+        //     d. Let mxfdDefault be cDigits.
+        // 17. Else,
+        //     a. Let mnfdDefault be 0.
+        //     b. If style is "percent", then
+        //         i. Let mxfdDefault be 0.
+        //     c. Else,
+        //         i. Let mxfdDefault be 3.
+        let (mnfd_default, mxfd_default) = match (style, &currency) {
+            (Style::Currency, Some(currency)) => {
+                let digits = currency_digits(currency);
+                (digits, digits)
+            }
+            (Style::Percent, _) => (0, 0),
+            _ => (0, 3),
+        };
+
+        // 19. Perform ? SetNumberFormatDigitOptions(numberFormat, options, mnfdDefault, mxfdDefault, notation).
+        let (minimum_integer_digits, rounding) =
+            set_number_format_digit_options(options, mnfd_default, mxfd_default, context)?;
+
+        // 28. Let useGrouping be ? GetOption(options, "useGrouping", "boolean", undefined, true).
+        let use_grouping = get_bool_option(options, "useGrouping", context)?.unwrap_or(true);
+
+        let grouping_strategy = if use_grouping {
+            GroupingStrategy::Auto
+        } else {
+            GroupingStrategy::Never
+        };
+        let formatter = FixedDecimalFormatter::try_new(
+            &data_locale,
+            FixedDecimalFormatterOptions::from(grouping_strategy),
+        )
+        .map_err(|_| {
+            context.construct_range_error(format!("No number format data for locale {}", locale))
+        })?;
+
+        let (prefix, suffix) = match (style, &currency) {
+            (Style::Percent, _) => percent_affixes(&data_locale),
+            (Style::Currency, Some(currency)) => {
+                currency_affixes(&data_locale, currency, currency_display)
+            }
+            _ => Some((String::new(), String::new())),
+        }
+        .ok_or_else(|| {
+            context.construct_range_error(format!("No number format data for locale {}", locale))
+        })?;
+
+        let one = formatter.format_to_string(&FixedDecimal::from(1));
+        let minus_one = formatter.format_to_string(&FixedDecimal::from(-1));
+        let minus_sign = minus_one.strip_suffix(&one).unwrap_or("-").to_string();
+
+        Ok(Self {
+            locale: locale.to_string().into(),
+            style,
+            currency,
+            currency_display,
+            minimum_integer_digits,
+            rounding,
+            use_grouping,
+            formatter: Rc::new(formatter),
+            prefix,
+            suffix,
+            minus_sign,
+            bound_format: None,
+        })
+    }
+
+    /// Abstract operation `FormatNumeric ( numberFormat, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format(&self, x: &Numeric) -> String {
+        let (negative, digits) = match x {
+            Numeric::Number(x) if x.is_nan() => (false, "NaN".to_string()),
+            Numeric::Number(x) if x.is_infinite() => (*x < 0.0, "∞".to_string()),
+            Numeric::Number(x) => {
+                let decimal = FixedDecimal::try_from_f64(*x, FloatPrecision::Floating)
+                    .expect("a finite number should be representable as a decimal");
+                self.format_decimal(decimal)
+            }
+            Numeric::BigInt(x) => {
+                let decimal = FixedDecimal::from_str(&x.to_string())
+                    .expect("a BigInt should be representable as a decimal");
+                self.format_decimal(decimal)
+            }
+        };
+
+        let mut result = String::new();
+        if negative {
+            result.push_str(&self.minus_sign);
+        }
+        result.push_str(&self.prefix);
+        result.push_str(&digits);
+        result.push_str(&self.suffix);
+        result
+    }
+
+    /// Rounds and formats the digits of a finite number, returning its sign separately.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumberstring
+    fn format_decimal(&self, mut x: FixedDecimal) -> (bool, String) {
+        // 1. If intlObject.[[Style]] is "percent", let x be 100 × x.
+        if self.style == Style::Percent {
+            x.multiply_pow10(2);
+        }
+
+        // 2. Let x be ToRawPrecision or ToRawFixed of x, according to the rounding type.
+        match self.rounding {
+            Rounding::SignificantDigits { min, max } => {
+                let magnitude = x.nonzero_magnitude_start();
+                x.half_expand(magnitude - i16::from(max) + 1);
+                x.trim_end();
+                let magnitude = x.nonzero_magnitude_start();
+                x.pad_end(magnitude - i16::from(min) + 1);
+            }
+            Rounding::FractionDigits { min, max } => {
+                x.half_expand(-i16::from(max));
+                x.trim_end();
+                x.pad_end(-i16::from(min));
+            }
+        }
+
+        // 3. Let int be the number of integer digits; if int < minInteger, pad with zeros.
+        x.pad_start(i16::from(self.minimum_integer_digits));
+
+        let negative = x.sign() == Sign::Negative;
+        x.set_sign(Sign::None);
+        (negative, self.formatter.format_to_string(&x))
+    }
+
+    /// `Intl.NumberFormat ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = if new_target.is_undefined() {
+            context
+                .standard_objects()
+                .number_format_object()
+                .constructor()
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget, "%NumberFormat.prototype%", ...).
+        let prototype = get_prototype_from_constructor(
+            &new_target,
+            StandardObjects::number_format_object,
+            context,
+        )?;
+
+        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let number_format = Self::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::number_format(Box::new(number_format));
+
+        // 5. Return numberFormat.
+        Ok(obj.into())
+    }
+
+    /// `Intl.NumberFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// Every locale is supported, so this returns the canonicalized requested locales.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %NumberFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        let options = args.get_or_undefined(1);
+        let options = if options.is_undefined() {
+            None
+        } else {
+            Some(options.to_object(context)?)
+        };
+        get_option(
+            options.as_ref(),
+            "localeMatcher",
+            &["lookup", "best fit"],
+            context,
+        )?;
+        Ok(
+            Array::create_array_from_list(requested_locales.into_iter().map(JsValue::new), context)
+                .into(),
+        )
+    }
+
+    /// `get Intl.NumberFormat.prototype.format`
+    ///
+    /// Returns a function formatting its argument with the number format, which stays bound to
+    /// it when extracted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = Self::require_number_format(this, "format", context)?;
+
+        // 4. If nf.[[BoundFormat]] is undefined, then
+        let bound_format = nf
+            .borrow()
+            .as_number_format()
+            .and_then(|number_format| number_format.bound_format.clone());
+        if let Some(bound_format) = bound_format {
+            // 5. Return nf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in Number Format Functions.
+        // b. Set F.[[NumberFormat]] to nf.
+        let bound_format = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, nf, context| {
+                // 1. Let nf be F.[[NumberFormat]].
+                let nf = nf
+                    .downcast_ref::<JsObject>()
+                    .expect("the format function captures its number format");
+
+                // 4. Let x be ? ToIntlMathematicalValue(value).
+                let x = args.get_or_undefined(0).to_numeric(context)?;
+
+                // 5. Return ? FormatNumeric(nf, x).
+                let nf = nf.borrow();
+                let nf = nf
+                    .as_number_format()
+                    .expect("the captured object is a number format");
+                Ok(nf.format(&x).into())
+            },
+            nf.clone(),
+        )
+        .length(1)
+        .build();
+
+        // c. Set nf.[[BoundFormat]] to F.
+        if let Some(number_format) = nf.borrow_mut().as_number_format_mut() {
+            number_format.bound_format = Some(bound_format.clone());
+        }
+
+        // 5. Return nf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// `Intl.NumberFormat.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = Self::require_number_format(this, "resolvedOptions", context)?;
+        let nf = nf
+            .borrow()
+            .as_number_format()
+            .expect("checked to be a number format")
+            .clone();
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 5. For each row of Table 11, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of nf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options.create_data_property_or_throw("locale", nf.locale.clone(), context)?;
+        options.create_data_property_or_throw("style", nf.style.as_str(), context)?;
+        if let Some(currency) = nf.currency.clone().filter(|_| nf.style == Style::Currency) {
+            options.create_data_property_or_throw("currency", currency, context)?;
+            options.create_data_property_or_throw(
+                "currencyDisplay",
+                nf.currency_display.as_str(),
+                context,
+            )?;
+        }
+        options.create_data_property_or_throw(
+            "minimumIntegerDigits",
+            i32::from(nf.minimum_integer_digits),
+            context,
+        )?;
+        match nf.rounding {
+            Rounding::FractionDigits { min, max } => {
+                options.create_data_property_or_throw(
+                    "minimumFractionDigits",
+                    i32::from(min),
+                    context,
+                )?;
+                options.create_data_property_or_throw(
+                    "maximumFractionDigits",
+                    i32::from(max),
+                    context,
+                )?;
+            }
+            Rounding::SignificantDigits { min, max } => {
+                options.create_data_property_or_throw(
+                    "minimumSignificantDigits",
+                    i32::from(min),
+                    context,
+                )?;
+                options.create_data_property_or_throw(
+                    "maximumSignificantDigits",
+                    i32::from(max),
+                    context,
+                )?;
+            }
+        }
+        options.create_data_property_or_throw("useGrouping", nf.use_grouping, context)?;
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+
+    /// Returns `this` if it is a number format, or throws a `TypeError` naming `method`.
+    fn require_number_format(
+        this: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        match this.as_object() {
+            Some(obj) if obj.borrow().is_number_format() => Ok(obj),
+            _ => Err(context.construct_type_error(format!(
+                "Intl.NumberFormat.prototype.{} called on an incompatible receiver",
+                method
+            ))),
+        }
+    }
+}
+
+/// Abstract operation `SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`
+///
+/// Returns the minimum number of integer digits and the rounding of the numbers.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+fn set_number_format_digit_options(
+    options: Option<&JsObject>,
+    mnfd_default: u8,
+    mxfd_default: u8,
+    context: &mut Context,
+) -> JsResult<(u8, Rounding)> {
+    let get = |property, context: &mut Context| match options {
+        Some(options) => options.get(property, context),
+        None => Ok(JsValue::undefined()),
+    };
+
+    // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+    let mnid = get("minimumIntegerDigits", context)?;
+    let mnid = default_number_option(&mnid, "minimumIntegerDigits", 1, 21, context)?.unwrap_or(1);
+
+    // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+    let mnfd = get("minimumFractionDigits", context)?;
+    // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+    let mxfd = get("maximumFractionDigits", context)?;
+    // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+    let mnsd = get("minimumSignificantDigits", context)?;
+    // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+    let mxsd = get("maximumSignificantDigits", context)?;
+
+    // 7. If mnsd is not undefined or mxsd is not undefined, then
+    if !mnsd.is_undefined() || !mxsd.is_undefined() {
+        // a. Let mnsd be ? DefaultNumberOption(mnsd, 1, 21, 1).
+        let min =
+            default_number_option(&mnsd, "minimumSignificantDigits", 1, 21, context)?.unwrap_or(1);
+        // b. Let mxsd be ? DefaultNumberOption(mxsd, mnsd, 21, 21).
+        let max = default_number_option(&mxsd, "maximumSignificantDigits", min, 21, context)?
+            .unwrap_or(21);
+        // c. Set intlObj.[[RoundingType]] to significantDigits.
+        return Ok((mnid, Rounding::SignificantDigits { min, max }));
+    }
+
+    // 8. Else if mnfd is not undefined or mxfd is not undefined, then
+    //     a. Let mnfd be ? DefaultNumberOption(mnfd, 0, 20, undefined).
+    //     b. Let mxfd be ? DefaultNumberOption(mxfd, 0, 20, undefined).
+    let mnfd = default_number_option(&mnfd, "minimumFractionDigits", 0, 20, context)?;
+    let mxfd = default_number_option(&mxfd, "maximumFractionDigits", 0, 20, context)?;
+    let (min, max) = match (mnfd, mxfd) {
+        // c. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+        (None, Some(mxfd)) => (mnfd_default.min(mxfd), mxfd),
+        // d. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+        (Some(mnfd), None) => (mnfd, mxfd_default.max(mnfd)),
+        // e. Else if mnfd is greater than mxfd, throw a RangeError exception.
+        (Some(mnfd), Some(mxfd)) if mnfd > mxfd => {
+            return Err(context.construct_range_error(
+                "minimumFractionDigits is greater than maximumFractionDigits",
+            ));
+        }
+        (Some(mnfd), Some(mxfd)) => (mnfd, mxfd),
+        // 9. Else, use the default fraction digits.
+        (None, None) => (mnfd_default, mxfd_default),
+    };
+
+    // f. Set intlObj.[[RoundingType]] to fractionDigits.
+    Ok((mnid, Rounding::FractionDigits { min, max }))
+}
+
+/// Abstract operation `DefaultNumberOption ( value, minimum, maximum, fallback )`
+///
+/// Returns `None` if the value is undefined.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-defaultnumberoption
+fn default_number_option(
+    value: &JsValue,
+    property: &str,
+    minimum: u8,
+    maximum: u8,
+    context: &mut Context,
+) -> JsResult<Option<u8>> {
+    // 1. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 2. Set value to ? ToNumber(value).
+    let value = value.to_number(context)?;
+
+    // 3. If value is NaN or less than minimum or greater than maximum, throw a RangeError exception.
+    if value.is_nan() || value < f64::from(minimum) || value > f64::from(maximum) {
+        return Err(context.construct_range_error(format!("{} value is out of range", property)));
+    }
+
+    // 4. Return floor(value).
+    Ok(Some(value.floor() as u8))
+}
+
+/// Abstract operation `IsWellFormedCurrencyCode ( currency )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-iswellformedcurrencycode
+pub(crate) fn is_well_formed_currency_code(currency: &str) -> bool {
+    // 1. If the length of currency is not 3, return false.
+    // 2. Let normalized be the ASCII-uppercase of currency.
+    // 3. If normalized contains any code unit outside of 0x0041 through 0x005A (corresponding to the Unicode characters LATIN CAPITAL LETTER A through LATIN CAPITAL LETTER Z), return false.
+    // 4. Return true.
+    currency.len() == 3 && currency.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Abstract operation `CurrencyDigits ( currency )`
+///
+/// Gets the number of minor units of a currency in [ISO 4217][iso], which is `2` unless listed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-currencydigits
+/// [iso]: https://www.iso.org/iso-4217-currency-codes.html
+fn currency_digits(currency: &str) -> u8 {
+    match currency {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+/// Gets the text to put before and after the digits of a percentage in the locale.
+fn percent_affixes(locale: &DataLocale) -> Option<(String, String)> {
+    let payload = DataProvider::<PercentEssentialsV1Marker>::load(
+        &Baked,
+        DataRequest {
+            locale,
+            metadata: Default::default(),
+        },
+    )
+    .ok()?
+    .take_payload()
+    .ok()?;
+    let data = payload.get();
+
+    let sign = format!(
+        "{}{}{}",
+        data.percent_sign_affixes.prefix,
+        data.percent_sign_symbol,
+        data.percent_sign_affixes.suffix
+    );
+    if data.percent_symbol_index > data.number_index {
+        Some((String::new(), sign))
+    } else {
+        Some((sign, String::new()))
+    }
+}
+
+/// Gets the text to put before and after the digits of an amount of `currency` in the locale.
+fn currency_affixes(
+    locale: &DataLocale,
+    currency: &str,
+    display: CurrencyDisplay,
+) -> Option<(String, String)> {
+    let payload = DataProvider::<CurrencyEssentialsV1Marker>::load(
+        &Baked,
+        DataRequest {
+            locale,
+            metadata: Default::default(),
+        },
+    )
+    .ok()?
+    .take_payload()
+    .ok()?;
+    let data = payload.get();
+
+    let code = TinyAsciiStr::<3>::from_str(currency).ok()?;
+    let config = data
+        .pattern_config_map
+        .get_copied(&code.to_unvalidated())
+        .unwrap_or(data.default_pattern_config);
+
+    let (placeholder, selection) = match display {
+        CurrencyDisplay::Code => (
+            Some(PlaceholderValue::ISO),
+            PatternSelection::StandardAlphaNextToNumber,
+        ),
+        CurrencyDisplay::Symbol => (
+            config.short_placeholder_value,
+            config.short_pattern_selection,
+        ),
+        CurrencyDisplay::NarrowSymbol => (
+            config.narrow_placeholder_value,
+            config.narrow_pattern_selection,
+        ),
+    };
+    let sign = match placeholder {
+        Some(PlaceholderValue::Index(index)) => data.placeholders.get(index.into())?,
+        Some(PlaceholderValue::ISO) | None => currency,
+    };
+    let pattern = match selection {
+        PatternSelection::Standard => data.standard_pattern.as_ref(),
+        PatternSelection::StandardAlphaNextToNumber => data
+            .standard_alpha_next_to_number_pattern
+            .as_ref()
+            .or(data.standard_pattern.as_ref()),
+    }?;
+
+    // The digits are formatted separately, so the pattern is interpolated with a marker
+    // standing for them.
+    const DIGITS: &str = "\u{fffc}";
+    let interpolated = pattern.interpolate_to_string((DIGITS, sign));
+    let (prefix, suffix) = interpolated.split_once(DIGITS)?;
+    Some((prefix.to_string(), suffix.to_string()))
+}
@@ -0,0 +1,948 @@
+use crate::{forward, Context};
+
+#[test]
+fn get_canonical_locales() {
+    let mut context = Context::new();
+
+    let canonical = |context: &mut Context, locales: &str| {
+        forward(
+            context,
+            &format!("Intl.getCanonicalLocales({}).join(',')", locales),
+        )
+    };
+
+    assert_eq!(canonical(&mut context, "undefined"), "\"\"");
+    assert_eq!(canonical(&mut context, "'EN-us'"), "\"en-US\"");
+    assert_eq!(
+        canonical(&mut context, "['en-us', 'EN-US', 'zh-hant-tw']"),
+        "\"en-US,zh-Hant-TW\""
+    );
+    assert_eq!(
+        canonical(&mut context, "'de-DE-1996-1901'"),
+        "\"de-DE-1901-1996\""
+    );
+    assert_eq!(
+        canonical(&mut context, "'en-u-kn-true-ca-gregory-x-Private'"),
+        "\"en-u-ca-gregory-kn-x-private\""
+    );
+    assert_eq!(
+        canonical(&mut context, "'en-z-abc-t-ES-419-m0-ascii'"),
+        "\"en-t-es-419-m0-ascii-z-abc\""
+    );
+}
+
+#[test]
+fn get_canonical_locales_aliases() {
+    let mut context = Context::new();
+
+    let canonical = |context: &mut Context, locale: &str| {
+        forward(
+            context,
+            &format!("Intl.getCanonicalLocales('{}')[0]", locale),
+        )
+    };
+
+    assert_eq!(canonical(&mut context, "iw"), "\"he\"");
+    assert_eq!(canonical(&mut context, "sh-BA"), "\"sr-Latn-BA\"");
+    assert_eq!(canonical(&mut context, "art-lojban"), "\"jbo\"");
+    assert_eq!(canonical(&mut context, "de-DD"), "\"de-DE\"");
+    assert_eq!(canonical(&mut context, "es-840"), "\"es-US\"");
+}
+
+#[test]
+fn get_canonical_locales_errors() {
+    let mut context = Context::new();
+
+    for invalid in &[
+        "''",
+        "'en-'",
+        "'en--US'",
+        "'i-klingon'",
+        "'root'",
+        "'en-US-US'",
+        "'de-1996-1996'",
+        "'en-u-ca-gregory-u-nu-latn'",
+        "'en-x'",
+        "'en-t-m0'",
+        "['en', 'abcdefghi']",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ Intl.getCanonicalLocales({}); }} catch (e) {{ e.name }}",
+                    invalid
+                )
+            ),
+            "\"RangeError\"",
+            "{}",
+            invalid
+        );
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.getCanonicalLocales([1]); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn collator_compare() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var words = ['zebra', 'Äpfel', 'apple', 'Öl', 'ost', 'ñu', 'nube', 'oso'];
+        function sorted(locale, options) {
+            return words.slice().sort(new Intl.Collator(locale, options).compare).join(',');
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "sorted('en')"),
+        "\"Äpfel,apple,ñu,nube,Öl,oso,ost,zebra\""
+    );
+    assert_eq!(
+        forward(&mut context, "sorted('sv')"),
+        "\"apple,ñu,nube,oso,ost,zebra,Äpfel,Öl\""
+    );
+    assert_eq!(
+        forward(&mut context, "sorted('es')"),
+        "\"Äpfel,apple,nube,ñu,Öl,oso,ost,zebra\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('de').compare('Müller', 'Mulder')"
+        ),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('de-u-co-phonebk').compare('Müller', 'Mulder')"
+        ),
+        "-1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('de', { collation: 'phonebk' }).compare('Müller', 'Mueller')"
+        ),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.Collator('es').compare('ñ', 'o')"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.Collator('es').compare('ñ', 'nz')"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "Intl.Collator('sv').compare('a\\u030a', 'å')"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('en', { numeric: true }).compare('item 9', 'item 10')"
+        ),
+        "-1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Collator('en', { sensitivity: 'base' }).compare('Résumé', 'resume')"
+        ),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "'ä'.localeCompare('z', 'sv')"), "1");
+    assert_eq!(forward(&mut context, "'ä'.localeCompare('z', 'de')"), "-1");
+}
+
+#[test]
+fn collator_resolved_options() {
+    let mut context = Context::new();
+
+    let resolved = |context: &mut Context, args: &str| {
+        forward(
+            context,
+            &format!(
+                r#"
+                var o = new Intl.Collator({}).resolvedOptions();
+                [o.locale, o.usage, o.sensitivity, o.ignorePunctuation, o.collation, o.numeric, o.caseFirst].join()
+                "#,
+                args
+            ),
+        )
+    };
+
+    assert_eq!(
+        resolved(&mut context, ""),
+        "\"en-US,sort,variant,false,default,false,false\""
+    );
+    assert_eq!(
+        resolved(
+            &mut context,
+            "'de-DE-u-co-phonebk-kn', { usage: 'search', sensitivity: 'base' }"
+        ),
+        "\"de-DE-u-co-phonebk-kn,search,base,false,phonebk,true,false\""
+    );
+    assert_eq!(
+        resolved(
+            &mut context,
+            "'en-u-co-phonebk-kf-upper', { numeric: true, ignorePunctuation: true }"
+        ),
+        "\"en-u-kf-upper,sort,variant,true,default,true,upper\""
+    );
+    assert_eq!(
+        resolved(&mut context, "['sv-SE', 'en'], { collation: 'standard' }"),
+        "\"sv-SE,sort,variant,false,default,false,false\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.Collator.supportedLocalesOf(['sv', 'zh-hant', 'DE-de']).join()"
+        ),
+        "\"sv,zh-Hant,de-DE\""
+    );
+}
+
+#[test]
+fn collator_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var c = new Intl.Collator(); c.compare === c.compare"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(c)"),
+        "\"[object Intl.Collator]\""
+    );
+
+    for (source, error) in &[
+        ("new Intl.Collator('en--US')", "RangeError"),
+        (
+            "new Intl.Collator('en', { sensitivity: 'none' })",
+            "RangeError",
+        ),
+        ("new Intl.Collator('en', { collation: 'a' })", "RangeError"),
+        (
+            "Intl.Collator.prototype.resolvedOptions.call({})",
+            "TypeError",
+        ),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {}; 'none' }} catch (e) {{ e.name }}", source)
+            ),
+            format!("\"{}\"", error),
+            "{}",
+            source
+        );
+    }
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn number_format_decimal() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var en = new Intl.NumberFormat('en-US');
+        var de = new Intl.NumberFormat('de-DE');
+        var plain = new Intl.NumberFormat('en-US', { useGrouping: false });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "en.format(1234.5)"), "\"1,234.5\"");
+    assert_eq!(
+        forward(&mut context, "en.format(-1234.5678)"),
+        "\"-1,234.568\""
+    );
+    assert_eq!(forward(&mut context, "en.format(0)"), "\"0\"");
+    assert_eq!(forward(&mut context, "en.format(NaN)"), "\"NaN\"");
+    assert_eq!(forward(&mut context, "en.format(-Infinity)"), "\"-∞\"");
+    assert_eq!(
+        forward(&mut context, "en.format(12345678n)"),
+        "\"12,345,678\""
+    );
+    assert_eq!(forward(&mut context, "de.format(1234.5)"), "\"1.234,5\"");
+    assert_eq!(forward(&mut context, "plain.format(1234.5)"), "\"1234.5\"");
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn number_format_digit_options() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { minimumFractionDigits: 2 }).format(3)"
+        ),
+        "\"3.00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { maximumFractionDigits: 0 }).format(2.5)"
+        ),
+        "\"3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { maximumSignificantDigits: 3 }).format(123456)"
+        ),
+        "\"123,000\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { minimumSignificantDigits: 4 }).format(1.5)"
+        ),
+        "\"1.500\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { minimumIntegerDigits: 3 }).format(7)"
+        ),
+        "\"007\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn number_format_percent_and_currency() {
+    let mut context = Context::new();
+
+    let format = |context: &mut Context, locale: &str, options: &str, value: &str| {
+        forward(
+            context,
+            &format!(
+                "new Intl.NumberFormat('{}', {}).format({})",
+                locale, options, value
+            ),
+        )
+    };
+
+    assert_eq!(
+        format(&mut context, "en-US", "{ style: 'percent' }", "0.256"),
+        "\"26%\""
+    );
+    assert_eq!(
+        format(&mut context, "de-DE", "{ style: 'percent' }", "0.256"),
+        "\"26\u{a0}%\""
+    );
+    assert_eq!(
+        format(
+            &mut context,
+            "en-US",
+            "{ style: 'currency', currency: 'USD' }",
+            "-1234.5"
+        ),
+        "\"-$1,234.50\""
+    );
+    assert_eq!(
+        format(
+            &mut context,
+            "en-US",
+            "{ style: 'currency', currency: 'jpy' }",
+            "1234.5"
+        ),
+        "\"¥1,235\""
+    );
+    assert_eq!(
+        format(
+            &mut context,
+            "de-DE",
+            "{ style: 'currency', currency: 'EUR' }",
+            "1234.5"
+        ),
+        "\"1.234,50\u{a0}€\""
+    );
+    assert_eq!(
+        format(
+            &mut context,
+            "en-US",
+            "{ style: 'currency', currency: 'EUR', currencyDisplay: 'code' }",
+            "1"
+        ),
+        "\"EUR\u{a0}1.00\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn number_format_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en', { style: 'currency' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en', { currency: 'US' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en', { style: 'scientific' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en', { maximumFractionDigits: 21 }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn number_format_resolved_options() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var nf = new Intl.NumberFormat('de-DE', { style: 'currency', currency: 'eur' });
+        var options = nf.resolvedOptions();
+        var format = nf.format;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "options.locale"), "\"de-DE\"");
+    assert_eq!(forward(&mut context, "options.style"), "\"currency\"");
+    assert_eq!(forward(&mut context, "options.currency"), "\"EUR\"");
+    assert_eq!(forward(&mut context, "options.minimumFractionDigits"), "2");
+    assert_eq!(forward(&mut context, "options.useGrouping"), "true");
+    assert_eq!(forward(&mut context, "format === nf.format"), "true");
+    assert_eq!(
+        forward(&mut context, "[1, 2].map(format).join(' | ')"),
+        "\"1,00\u{a0}€ | 2,00\u{a0}€\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(nf)"),
+        "\"[object Intl.NumberFormat]\""
+    );
+    assert_eq!(
+        forward(&mut context, "(1234.5).toLocaleString('en-US')"),
+        "\"1,234.5\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn date_time_format_locales() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var date = new Date(Date.UTC(2024, 0, 5, 9, 5, 3, 123));",
+    );
+
+    let format = |context: &mut Context, locale: &str, options: &str| {
+        forward(
+            context,
+            &format!(
+                "new Intl.DateTimeFormat('{}', {{ timeZone: 'UTC', {} }}).format(date)",
+                locale, options
+            ),
+        )
+    };
+
+    let all = "year: 'numeric', month: 'numeric', day: 'numeric', \
+               hour: 'numeric', minute: 'numeric', second: 'numeric'";
+    assert_eq!(
+        format(&mut context, "en-US", all),
+        "\"1/5/2024, 9:05:03 AM\""
+    );
+    assert_eq!(
+        format(&mut context, "en-GB", all),
+        "\"05/01/2024, 09:05:03\""
+    );
+    assert_eq!(format(&mut context, "de-DE", all), "\"5.1.2024, 09:05:03\"");
+    assert_eq!(format(&mut context, "fr", all), "\"05/01/2024 09:05:03\"");
+    assert_eq!(format(&mut context, "ja", all), "\"2024/1/5 9:05:03\"");
+
+    assert_eq!(
+        format(&mut context, "en", "dateStyle: 'full'"),
+        "\"Friday, January 5, 2024\""
+    );
+    assert_eq!(
+        format(&mut context, "de", "dateStyle: 'long'"),
+        "\"5. Januar 2024\""
+    );
+    assert_eq!(
+        format(&mut context, "es", "dateStyle: 'full'"),
+        "\"viernes, 5 de enero de 2024\""
+    );
+    assert_eq!(
+        format(&mut context, "ja", "dateStyle: 'full'"),
+        "\"2024年1月5日金曜日\""
+    );
+    assert_eq!(
+        format(&mut context, "en", "month: 'long', year: 'numeric'"),
+        "\"January 2024\""
+    );
+    assert_eq!(format(&mut context, "en", "hour: 'numeric'"), "\"9 AM\"");
+    assert_eq!(
+        format(
+            &mut context,
+            "ja",
+            "hour: 'numeric', minute: 'numeric', hour12: true"
+        ),
+        "\"午前9:05\""
+    );
+    assert_eq!(
+        format(
+            &mut context,
+            "en",
+            "minute: '2-digit', second: '2-digit', fractionalSecondDigits: 2"
+        ),
+        "\"05:03.12\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('zh', { timeZone: 'UTC' }).resolvedOptions().locale"
+        ),
+        "\"en-US\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.DateTimeFormat.supportedLocalesOf(['zh', 'de-AT', 'en']).join()"
+        ),
+        "\"de-AT,en\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn date_time_format_time_zones() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var date = Date.UTC(2024, 0, 5, 9, 5, 3);");
+
+    let format = |context: &mut Context, time_zone: &str, time_zone_name: &str| {
+        forward(
+            context,
+            &format!(
+                "new Intl.DateTimeFormat('en', {{ hour: 'numeric', minute: 'numeric', \
+                 timeZone: '{}', timeZoneName: '{}' }}).format(date)",
+                time_zone, time_zone_name
+            ),
+        )
+    };
+
+    assert_eq!(format(&mut context, "UTC", "short"), "\"9:05 AM UTC\"");
+    assert_eq!(
+        format(&mut context, "Etc/UTC", "long"),
+        "\"9:05 AM Coordinated Universal Time\""
+    );
+    assert_eq!(
+        format(&mut context, "+05:30", "short"),
+        "\"2:35 PM GMT+5:30\""
+    );
+    assert_eq!(
+        format(&mut context, "-0800", "longOffset"),
+        "\"1:05 AM GMT-08:00\""
+    );
+    assert_eq!(
+        format(&mut context, "Etc/GMT+5", "short"),
+        "\"4:05 AM GMT-5\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'etc/gmt-2' }).resolvedOptions().timeZone"
+        ),
+        "\"Etc/GMT-2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DateTimeFormat('en', { timeZone: 'Mars/Olympus' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn date_time_format_to_parts() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var dtf = new Intl.DateTimeFormat('en', {
+            timeZone: 'UTC', dateStyle: 'medium', timeStyle: 'short'
+        });
+        var parts = dtf.formatToParts(Date.UTC(2024, 0, 5, 9, 5));
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "parts.map(function (part) { return part.type; }).join()"
+        ),
+        "\"month,literal,day,literal,year,literal,hour,literal,minute,literal,dayPeriod\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "parts.map(function (part) { return part.value; }).join('')"
+        ),
+        "\"Jan 5, 2024, 9:05 AM\""
+    );
+    assert_eq!(forward(&mut context, "dtf.format === dtf.format"), "true");
+    assert_eq!(
+        forward(&mut context, "[0, 86400000].map(dtf.format).join(' | ')"),
+        "\"Jan 1, 1970, 12:00 AM | Jan 2, 1970, 12:00 AM\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { dtf.format(NaN) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(dtf)"),
+        "\"[object Intl.DateTimeFormat]\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn date_time_format_options() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var options = new Intl.DateTimeFormat('de', { timeZone: 'UTC', hour: 'numeric' })
+            .resolvedOptions();
+        var styled = new Intl.DateTimeFormat('en', { timeZone: 'UTC', dateStyle: 'short' })
+            .resolvedOptions();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(options).join()"),
+        "\"locale,calendar,numberingSystem,timeZone,hourCycle,hour12,hour\""
+    );
+    assert_eq!(forward(&mut context, "options.hourCycle"), "\"h23\"");
+    assert_eq!(forward(&mut context, "styled.dateStyle"), "\"short\"");
+    assert_eq!(forward(&mut context, "styled.year"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).resolvedOptions().day"
+        ),
+        "\"numeric\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DateTimeFormat('en', { dateStyle: 'full', year: 'numeric' }) } \
+             catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Date(0).toLocaleDateString('en', { timeStyle: 'full' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DateTimeFormat('en', { month: 'tiny' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn date_to_locale_string_with_locales() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var date = new Date(Date.UTC(2020, 6, 8, 19, 6, 5));",
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleString('de-DE', { timeZone: 'UTC' })"
+        ),
+        "\"8.7.2020, 19:06:05\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleDateString('en-GB', { timeZone: 'UTC', month: 'long' })"
+        ),
+        "\"July\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleTimeString('ja', { timeZone: 'UTC' })"
+        ),
+        "\"19:06:05\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleTimeString('en', { timeZone: 'UTC', timeZoneName: 'short' })"
+        ),
+        "\"7:06:05 PM UTC\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn locale_accessors() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var locale = new Intl.Locale("en-latn-us-u-ca-gregory-kn", { hourCycle: "h12", region: "gb" });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "locale.toString()"),
+        "\"en-Latn-GB-u-ca-gregory-hc-h12-kn\""
+    );
+    assert_eq!(forward(&mut context, "locale.baseName"), "\"en-Latn-GB\"");
+    assert_eq!(forward(&mut context, "locale.language"), "\"en\"");
+    assert_eq!(forward(&mut context, "locale.script"), "\"Latn\"");
+    assert_eq!(forward(&mut context, "locale.region"), "\"GB\"");
+    assert_eq!(forward(&mut context, "locale.calendar"), "\"gregory\"");
+    assert_eq!(forward(&mut context, "locale.hourCycle"), "\"h12\"");
+    assert_eq!(forward(&mut context, "locale.numeric"), "true");
+    assert_eq!(forward(&mut context, "locale.collation"), "undefined");
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('de').region"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale(locale, { numeric: false, collation: 'phonebk' }).toString()"
+        ),
+        "\"en-Latn-GB-u-ca-gregory-co-phonebk-hc-h12-kn-false\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(locale)"),
+        "\"[object Intl.Locale]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.getCanonicalLocales([new Intl.Locale('EN-us'), 'fr']).join()"
+        ),
+        "\"en-US,fr\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn locale_likely_subtags() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('en').maximize().toString()"),
+        "\"en-Latn-US\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('zh-Hant').maximize().toString()"
+        ),
+        "\"zh-Hant-TW\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('en-Latn-US-u-nu-latn').minimize().toString()"
+        ),
+        "\"en-u-nu-latn\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('en').maximize() instanceof Intl.Locale"
+        ),
+        "true"
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn locale_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Intl.Locale('en')"),
+        "Uncaught \"TypeError\": \"calling a builtin Intl.Locale constructor without new is forbidden\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale(5)"),
+        "Uncaught \"TypeError\": \"Intl.Locale tag should be a String or an Object\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('en_US')"),
+        "Uncaught \"RangeError\": \"Incorrect locale information: en_US\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('en', { script: 'Latin' })"),
+        "Uncaught \"RangeError\": \"Invalid value for option script: Latin\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('en', { calendar: 'ab' })"),
+        "Uncaught \"RangeError\": \"Invalid value for option calendar: ab\""
+    );
+    assert_eq!(
+        forward(&mut context, "Intl.Locale.prototype.baseName"),
+        "Uncaught \"TypeError\": \"Intl.Locale.prototype.baseName called on an incompatible receiver\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn display_names_of() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var regions = new Intl.DisplayNames("en", { type: "region" });
+        var languages = new Intl.DisplayNames("en", { type: "language" });
+        var standard = new Intl.DisplayNames("en", { type: "language", languageDisplay: "standard" });
+        var scripts = new Intl.DisplayNames("fr", { type: "script" });
+        var currencies = new Intl.DisplayNames("en", { type: "currency" });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "regions.of('us')"),
+        "\"United States\""
+    );
+    assert_eq!(
+        forward(&mut context, "regions.of('419')"),
+        "\"Latin America\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('de', { type: 'region' }).of('DE')"
+        ),
+        "\"Deutschland\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'region', style: 'short' }).of('GB')"
+        ),
+        "\"UK\""
+    );
+    assert_eq!(forward(&mut context, "languages.of('fr')"), "\"French\"");
+    assert_eq!(
+        forward(&mut context, "languages.of('en-GB')"),
+        "\"British English\""
+    );
+    assert_eq!(
+        forward(&mut context, "standard.of('en-GB')"),
+        "\"English (United Kingdom)\""
+    );
+    assert_eq!(
+        forward(&mut context, "scripts.of('cyrl')"),
+        "\"cyrillique\""
+    );
+
+    // The codes without a display name are returned, unless the fallback is "none".
+    assert_eq!(forward(&mut context, "languages.of('xx')"), "\"xx\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'language', fallback: 'none' }).of('xx')"
+        ),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "currencies.of('usd')"), "\"USD\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'currency', fallback: 'none' }).of('USD')"
+        ),
+        "undefined"
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn display_names_options_and_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(new Intl.DisplayNames('en-US', { type: 'language' }).resolvedOptions())"
+        ),
+        r#""{"locale":"en-US","style":"long","type":"language","fallback":"code","languageDisplay":"dialect"}""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(new Intl.DisplayNames('de', { type: 'region', style: 'narrow', fallback: 'none' }).resolvedOptions())"
+        ),
+        r#""{"locale":"de","style":"narrow","type":"region","fallback":"none"}""#
+    );
+
+    assert_eq!(
+        forward(&mut context, "new Intl.DisplayNames('en')"),
+        "Uncaught \"TypeError\": \"Intl.DisplayNames type option is required\""
+    );
+    assert_eq!(
+        forward(&mut context, "Intl.DisplayNames('en', { type: 'region' })"),
+        "Uncaught \"TypeError\": \"calling a builtin Intl.DisplayNames constructor without new is forbidden\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.DisplayNames('en', 'region')"),
+        "Uncaught \"TypeError\": \"Intl.DisplayNames options should be an Object\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'region' }).of('USA')"
+        ),
+        "Uncaught \"RangeError\": \"Invalid region code: USA\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'language' }).of('en-u-ca-gregory')"
+        ),
+        "Uncaught \"RangeError\": \"Invalid language code: en-u-ca-gregory\""
+    );
+}
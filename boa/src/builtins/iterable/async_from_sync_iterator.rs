@@ -0,0 +1,351 @@
+//! This module implements the async-from-sync iterator objects.
+//!
+//! An async-from-sync iterator wraps a synchronous iterator, so that it can be consumed by
+//! `for await` loops and `yield*` expressions of async generators: each method of the wrapper
+//! returns a promise of the result of the synchronous method, once its value settles.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-from-sync-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, IteratorRecord},
+        promise::PromiseCapability,
+        Promise,
+    },
+    error::JsErrorCode,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject, ObjectData},
+    teardown::Contents,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+/// The internal representation of an async-from-sync iterator.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct AsyncFromSyncIterator {
+    sync_iterator_record: IteratorRecord,
+}
+
+impl AsyncFromSyncIterator {
+    pub(crate) const NAME: &'static str = "AsyncFromSyncIterator";
+
+    /// Queues the wrapped iterator to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        self.sync_iterator_record.collect_contents(contents);
+    }
+
+    /// Create the `%AsyncFromSyncIteratorPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%-object
+    pub(crate) fn create_prototype(
+        context: &mut Context,
+        async_iterator_prototype: JsValue,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let prototype = context.construct_object();
+        make_builtin_fn(Self::next, "next", &prototype, 1, context);
+        make_builtin_fn(Self::r#return, "return", &prototype, 1, context);
+        make_builtin_fn(Self::throw, "throw", &prototype, 1, context);
+        prototype.set_prototype_instance(async_iterator_prototype);
+        prototype
+    }
+
+    /// `CreateAsyncFromSyncIterator ( syncIteratorRecord )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createasyncfromsynciterator
+    pub(crate) fn create(
+        sync_iterator_record: IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<IteratorRecord> {
+        // 1. Let asyncIterator be ! OrdinaryObjectCreate(%AsyncFromSyncIteratorPrototype%, « [[SyncIteratorRecord]] »).
+        // 2. Set asyncIterator.[[SyncIteratorRecord]] to syncIteratorRecord.
+        let async_iterator = context.construct_object();
+        async_iterator.set_prototype_instance(
+            context
+                .iterator_prototypes()
+                .async_from_sync_iterator()
+                .into(),
+        );
+        async_iterator.borrow_mut().data = ObjectData::async_from_sync_iterator(Self {
+            sync_iterator_record,
+        });
+
+        // 3. Let nextMethod be ! Get(asyncIterator, "next").
+        let next_method = async_iterator.get("next", context)?;
+
+        // 4. Let iteratorRecord be the Record { [[Iterator]]: asyncIterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        // 5. Return iteratorRecord.
+        Ok(IteratorRecord::new(async_iterator.into(), next_method))
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.next ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.next
+    fn next(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Assert: Type(O) is Object and O has a [[SyncIteratorRecord]] internal slot.
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (sync_iterator_record, capability) = Self::this_record(this, context)?;
+
+        // 4. Let syncIteratorRecord be O.[[SyncIteratorRecord]].
+        // 5. If value is present, then
+        //     a. Let result be IteratorNext(syncIteratorRecord, value).
+        // 6. Else,
+        //     a. Let result be IteratorNext(syncIteratorRecord).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        let result = match context.call(
+            sync_iterator_record.next_function(),
+            sync_iterator_record.iterator_object(),
+            &args[..args.len().min(1)],
+        ) {
+            Ok(result) if result.is_object() => result,
+            Ok(_) => {
+                let error = context.construct_coded_error(
+                    JsErrorCode::ITERATOR_RESULT_NOT_AN_OBJECT,
+                    "iterator result is not an object",
+                );
+                return capability.reject_abrupt(error, context);
+            }
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 8. Return ! AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, capability, context)
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.return ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.return
+    fn r#return(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Assert: Type(O) is Object and O has a [[SyncIteratorRecord]] internal slot.
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (sync_iterator_record, capability) = Self::this_record(this, context)?;
+
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let sync_iterator = sync_iterator_record
+            .iterator_object()
+            .as_object()
+            .expect("iterators are objects");
+
+        // 5. Let return be GetMethod(syncIterator, "return").
+        // 6. IfAbruptRejectPromise(return, promiseCapability).
+        let r#return = match sync_iterator.get_method(context, "return") {
+            Ok(r#return) => r#return,
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 7. If return is undefined, then
+        //     a. Let iterResult be ! CreateIterResultObject(value, true).
+        //     b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iterResult »).
+        //     c. Return promiseCapability.[[Promise]].
+        let value = args.get_or_undefined(0).clone();
+        let r#return = match r#return {
+            Some(r#return) => r#return,
+            None => {
+                let iter_result = create_iter_result_object(context, value, true);
+                capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[iter_result], context)?;
+                return Ok(capability.promise().clone().into());
+            }
+        };
+
+        // 8. If value is present, then
+        //     a. Let result be Call(return, syncIterator, « value »).
+        // 9. Else,
+        //     a. Let result be Call(return, syncIterator).
+        // 10. IfAbruptRejectPromise(result, promiseCapability).
+        // 11. If Type(result) is not Object, then
+        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //     b. Return promiseCapability.[[Promise]].
+        let result = match r#return.call(&sync_iterator.into(), &args[..args.len().min(1)], context)
+        {
+            Ok(result) if result.is_object() => result,
+            Ok(_) => {
+                let error = context.construct_coded_error(
+                    JsErrorCode::ITERATOR_RESULT_NOT_AN_OBJECT,
+                    "iterator result is not an object",
+                );
+                return capability.reject_abrupt(error, context);
+            }
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 12. Return ! AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, capability, context)
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.throw ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.throw
+    fn throw(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Assert: Type(O) is Object and O has a [[SyncIteratorRecord]] internal slot.
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (sync_iterator_record, capability) = Self::this_record(this, context)?;
+
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let sync_iterator = sync_iterator_record
+            .iterator_object()
+            .as_object()
+            .expect("iterators are objects");
+
+        // 5. Let throw be GetMethod(syncIterator, "throw").
+        // 6. IfAbruptRejectPromise(throw, promiseCapability).
+        let throw = match sync_iterator.get_method(context, "throw") {
+            Ok(throw) => throw,
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 7. If throw is undefined, then
+        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « value »).
+        //     b. Return promiseCapability.[[Promise]].
+        let value = args.get_or_undefined(0).clone();
+        let throw = match throw {
+            Some(throw) => throw,
+            None => return capability.reject_abrupt(value, context),
+        };
+
+        // 8. If value is present, then
+        //     a. Let result be Call(throw, syncIterator, « value »).
+        // 9. Else,
+        //     a. Let result be Call(throw, syncIterator).
+        // 10. IfAbruptRejectPromise(result, promiseCapability).
+        // 11. If Type(result) is not Object, then
+        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //     b. Return promiseCapability.[[Promise]].
+        let result = match throw.call(&sync_iterator.into(), &args[..args.len().min(1)], context) {
+            Ok(result) if result.is_object() => result,
+            Ok(_) => {
+                let error = context.construct_coded_error(
+                    JsErrorCode::ITERATOR_RESULT_NOT_AN_OBJECT,
+                    "iterator result is not an object",
+                );
+                return capability.reject_abrupt(error, context);
+            }
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 12. Return ! AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, capability, context)
+    }
+
+    /// Gets the synchronous iterator record of an async-from-sync iterator, and creates the
+    /// capability of the promise returned by its methods.
+    fn this_record(
+        this: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<(IteratorRecord, PromiseCapability)> {
+        let sync_iterator_record = this
+            .as_object()
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .as_async_from_sync_iterator()
+                    .map(|iterator| iterator.sync_iterator_record.clone())
+            })
+            .ok_or_else(|| {
+                context.construct_type_error("`this` is not an AsyncFromSyncIterator")
+            })?;
+
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let capability = Promise::new_promise_capability(&constructor, context)?;
+        Ok((sync_iterator_record, capability))
+    }
+
+    /// `AsyncFromSyncIteratorContinuation ( result, promiseCapability )`
+    ///
+    /// Resolves the promise of the capability with the result of the synchronous iterator, once
+    /// its value settles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncfromsynciteratorcontinuation
+    fn continuation(
+        result: &JsValue,
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let done be IteratorComplete(result).
+        // 2. IfAbruptRejectPromise(done, promiseCapability).
+        let done = match result.get_field("done", context) {
+            Ok(done) => done.to_boolean(),
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 3. Let value be IteratorValue(result).
+        // 4. IfAbruptRejectPromise(value, promiseCapability).
+        let value = match result.get_field("value", context) {
+            Ok(value) => value,
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 5. Let valueWrapper be PromiseResolve(%Promise%, value).
+        // 6. IfAbruptRejectPromise(valueWrapper, promiseCapability).
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let value_wrapper = match Promise::promise_resolve(&constructor, value, context) {
+            Ok(value_wrapper) => value_wrapper,
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 7. Let unwrap be a new Abstract Closure with parameters (value) that captures done and performs the following steps when called:
+        //     a. Return ! CreateIterResultObject(value, done).
+        // 8. Let onFulfilled be ! CreateBuiltinFunction(unwrap, 1, "", « »).
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures, context| {
+                let done = *captures
+                    .downcast_ref::<bool>()
+                    .expect("unwrap captures done");
+                let value = args.get_or_undefined(0).clone();
+                Ok(create_iter_result_object(context, value, done))
+            },
+            done,
+        )
+        .length(1)
+        .build();
+
+        // 9. Perform ! PerformPromiseThen(valueWrapper, onFulfilled, undefined, promiseCapability).
+        // 10. Return promiseCapability.[[Promise]].
+        let value_wrapper = value_wrapper
+            .as_object()
+            .expect("PromiseResolve returns a promise");
+        Ok(Promise::perform_promise_then(
+            &value_wrapper,
+            &on_fulfilled.into(),
+            &JsValue::undefined(),
+            Some(capability),
+            context,
+        ))
+    }
+}
@@ -1,14 +1,28 @@
+mod async_from_sync_iterator;
+#[cfg(feature = "async")]
+mod stream_iterator;
+
+pub use self::async_from_sync_iterator::AsyncFromSyncIterator;
+#[cfg(feature = "async")]
+pub use self::stream_iterator::StreamIterator;
+
 use crate::{
     builtins::{
         regexp::regexp_string_iterator::RegExpStringIterator,
-        string::string_iterator::StringIterator, ArrayIterator, ForInIterator, MapIterator,
-        SetIterator,
+        string::string_iterator::StringIterator, ArrayIterator, AsyncGenerator, ForInIterator,
+        Generator, IteratorHelper, MapIterator, Number, SetIterator,
     },
+    error::JsErrorCode,
+    gc::{Finalize, Trace},
     object::{JsObject, ObjectInitializer},
     symbol::WellKnownSymbols,
+    teardown::Contents,
     BoaProfiler, Context, JsResult, JsValue,
 };
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Default)]
 pub struct IteratorPrototypes {
     iterator_prototype: JsObject,
@@ -18,11 +32,19 @@ pub struct IteratorPrototypes {
     regexp_string_iterator: JsObject,
     map_iterator: JsObject,
     for_in_iterator: JsObject,
+    generator: JsObject,
+    async_iterator_prototype: JsObject,
+    async_from_sync_iterator: JsObject,
+    async_generator: JsObject,
+    iterator_helper: JsObject,
+    #[cfg(feature = "async")]
+    stream_iterator: JsObject,
 }
 
 impl IteratorPrototypes {
     pub(crate) fn init(context: &mut Context) -> Self {
-        let iterator_prototype = create_iterator_prototype(context);
+        let iterator_prototype = context.standard_objects().iterator_object().prototype();
+        let async_iterator_prototype = create_async_iterator_prototype(context);
         Self {
             array_iterator: ArrayIterator::create_prototype(
                 context,
@@ -42,7 +64,26 @@ impl IteratorPrototypes {
                 context,
                 iterator_prototype.clone().into(),
             ),
+            generator: Generator::create_prototype(context, iterator_prototype.clone().into()),
+            iterator_helper: IteratorHelper::create_prototype(
+                context,
+                iterator_prototype.clone().into(),
+            ),
             iterator_prototype,
+            async_from_sync_iterator: AsyncFromSyncIterator::create_prototype(
+                context,
+                async_iterator_prototype.clone().into(),
+            ),
+            async_generator: AsyncGenerator::create_prototype(
+                context,
+                async_iterator_prototype.clone().into(),
+            ),
+            #[cfg(feature = "async")]
+            stream_iterator: StreamIterator::create_prototype(
+                context,
+                async_iterator_prototype.clone().into(),
+            ),
+            async_iterator_prototype,
         }
     }
 
@@ -80,6 +121,37 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> JsObject {
         self.for_in_iterator.clone()
     }
+
+    #[inline]
+    pub fn generator(&self) -> JsObject {
+        self.generator.clone()
+    }
+
+    #[inline]
+    pub fn async_iterator_prototype(&self) -> JsObject {
+        self.async_iterator_prototype.clone()
+    }
+
+    #[inline]
+    pub fn async_from_sync_iterator(&self) -> JsObject {
+        self.async_from_sync_iterator.clone()
+    }
+
+    #[inline]
+    pub fn async_generator(&self) -> JsObject {
+        self.async_generator.clone()
+    }
+
+    #[inline]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn stream_iterator(&self) -> JsObject {
+        self.stream_iterator.clone()
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -101,46 +173,263 @@ pub fn create_iter_result_object(context: &mut Context, value: JsValue, done: bo
 }
 
 /// Get an iterator record
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiterator
 pub fn get_iterator(context: &mut Context, iterable: JsValue) -> JsResult<IteratorRecord> {
-    let iterator_function = iterable.get_field(WellKnownSymbols::iterator(), context)?;
-    if iterator_function.is_null_or_undefined() {
-        return Err(context.construct_type_error("Not an iterable"));
+    // 3. Let method be ? GetMethod(obj, @@iterator).
+    let iterator_function = if iterable.is_null_or_undefined() {
+        None
+    } else {
+        iterable.get_method(context, WellKnownSymbols::iterator())?
+    };
+    let iterator_function = match iterator_function {
+        Some(function) => function,
+        None => return Err(context.construct_type_error("Not an iterable")),
+    };
+    // 4. Let iterator be ? Call(method, obj).
+    let iterator_object = iterator_function.call(&iterable, &[], context)?;
+    // 5. If Type(iterator) is not Object, throw a TypeError exception.
+    if !iterator_object.is_object() {
+        return Err(context.construct_coded_error(
+            JsErrorCode::ITERATOR_NOT_AN_OBJECT,
+            "iterator is not an object",
+        ));
     }
-    let iterator_object = context.call(&iterator_function, &iterable, &[])?;
+    // 6. Let nextMethod be ? GetV(iterator, "next").
     let next_function = iterator_object.get_field("next", context)?;
     if next_function.is_null_or_undefined() {
-        return Err(context.construct_type_error("Could not find property `next`"));
+        return Err(context
+            .construct_coded_error(JsErrorCode::MISSING_NEXT, "Could not find property `next`"));
+    }
+    // 7. Let iteratorRecord be the Record { [[Iterator]]: iterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 8. Return iteratorRecord.
+    Ok(IteratorRecord::new(iterator_object, next_function))
+}
+
+/// Get an async iterator record
+///
+/// Iterables without a `Symbol.asyncIterator` method are iterated synchronously, through an
+/// async-from-sync iterator.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiterator
+pub(crate) fn get_async_iterator(
+    context: &mut Context,
+    iterable: JsValue,
+) -> JsResult<IteratorRecord> {
+    let iterator_function = if iterable.is_null_or_undefined() {
+        None
+    } else {
+        iterable.get_method(context, WellKnownSymbols::async_iterator())?
+    };
+    let iterator_function = match iterator_function {
+        Some(function) => function,
+        None => {
+            let sync_iterator_record = get_iterator(context, iterable)?;
+            return AsyncFromSyncIterator::create(sync_iterator_record, context);
+        }
+    };
+    let iterator_object = iterator_function.call(&iterable, &[], context)?;
+    if !iterator_object.is_object() {
+        return Err(context.construct_type_error("async iterator is not an object"));
     }
+    let next_function = iterator_object.get_field("next", context)?;
     Ok(IteratorRecord::new(iterator_object, next_function))
 }
 
-/// Create the %IteratorPrototype% object
+/// Collects the values produced by an iterable into a list.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterabletolist
+pub fn iterable_to_list(context: &mut Context, iterable: JsValue) -> JsResult<Vec<JsValue>> {
+    // 1. Let iteratorRecord be ? GetIterator(items, sync, method).
+    let iterator_record = get_iterator(context, iterable)?;
+    // 2. Let values be a new empty List.
+    let mut values = Vec::new();
+    // 3. Let next be true.
+    // 4. Repeat, while next is not false,
+    //     a. Set next to ? IteratorStep(iteratorRecord).
+    //     b. If next is not false, then
+    //         i. Let nextValue be ? IteratorValue(next).
+    //         ii. Append nextValue to the end of the List values.
+    while let Some(value) = iterator_record.step(context)? {
+        values.push(value);
+    }
+    // 5. Return values.
+    Ok(values)
+}
+
+/// `IfAbruptCloseIterator ( value, iteratorRecord )`
+///
+/// Closes the iterator if `value` is an error, which is then thrown.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ifabruptcloseiterator
+pub(crate) fn if_abrupt_close_iterator<T>(
+    value: JsResult<T>,
+    iterator_record: &IteratorRecord,
+    context: &mut Context,
+) -> JsResult<T> {
+    // 1. If value is an abrupt completion, return ? IteratorClose(iteratorRecord, value).
+    // 2. Else if value is a Completion Record, set value to value.[[Value]].
+    value.map_err(|error| {
+        iterator_record
+            .close(Err(error), context)
+            .expect_err("closing an iterator with a throw completion throws")
+    })
+}
+
+/// How [`group_by`] coerces the keys returned by its callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyCoercion {
+    /// Keys are converted to property keys, as for `Object.groupBy`.
+    Property,
+    /// Keys are kept as they are, except for `-0` which becomes `+0`, as for `Map.groupBy`.
+    Zero,
+}
+
+/// `GroupBy ( items, callbackfn, keyCoercion )`
+///
+/// Groups the values produced by `items` by the key the callback returns for each of them,
+/// returning the groups in the order their keys are first returned.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-groupby
+pub(crate) fn group_by(
+    items: &JsValue,
+    callback: &JsValue,
+    key_coercion: KeyCoercion,
+    context: &mut Context,
+) -> JsResult<Vec<(JsValue, Vec<JsValue>)>> {
+    // 1. Perform ? RequireObjectCoercible(items).
+    items.require_object_coercible(context)?;
+
+    // 2. If IsCallable(callbackfn) is false, throw a TypeError exception.
+    let callback = match callback.as_object() {
+        Some(callback) if callback.is_callable() => callback,
+        _ => return Err(context.construct_type_error("GroupBy: callback is not a function")),
+    };
+
+    // 3. Let groups be a new empty List.
+    let mut groups: Vec<(JsValue, Vec<JsValue>)> = Vec::new();
+
+    // 4. Let iteratorRecord be ? GetIterator(items, sync).
+    let iterator_record = get_iterator(context, items.clone())?;
+
+    // 5. Let k be 0.
+    let mut k: u64 = 0;
+
+    // 6. Repeat,
+    loop {
+        // a. If k ≥ 2^53 - 1, then
+        if k >= Number::MAX_SAFE_INTEGER as u64 {
+            // i. Let error be ThrowCompletion(a newly created TypeError object).
+            // ii. Return ? IteratorClose(iteratorRecord, error).
+            let error = context.construct_type_error("GroupBy: too many values");
+            return iterator_record.close(Err(error), context).map(|_| groups);
+        }
+
+        // b. Let next be ? IteratorStepValue(iteratorRecord).
+        // c. If next is done, then
+        //     i. Return groups.
+        // d. Let value be next.
+        let value = match iterator_record.step(context)? {
+            Some(value) => value,
+            None => return Ok(groups),
+        };
+
+        // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+        // f. IfAbruptCloseIterator(key, iteratorRecord).
+        let key = callback.call(
+            &JsValue::undefined(),
+            &[value.clone(), JsValue::new(k as f64)],
+            context,
+        );
+        let key = if_abrupt_close_iterator(key, &iterator_record, context)?;
+
+        let key = match key_coercion {
+            // g. If keyCoercion is property, then
+            KeyCoercion::Property => {
+                // i. Set key to Completion(ToPropertyKey(key)).
+                // ii. IfAbruptCloseIterator(key, iteratorRecord).
+                let key = key.to_property_key(context);
+                if_abrupt_close_iterator(key, &iterator_record, context)?.into()
+            }
+            // h. Else,
+            //     i. Assert: keyCoercion is zero.
+            //     ii. If key is -0𝔽, set key to +0𝔽.
+            KeyCoercion::Zero if key.as_number() == Some(0.0) => JsValue::new(0),
+            KeyCoercion::Zero => key,
+        };
+
+        // i. Perform AddValueToKeyedGroup(groups, key, value).
+        match groups
+            .iter_mut()
+            .find(|(group_key, _)| JsValue::same_value(group_key, &key))
+        {
+            Some((_, elements)) => elements.push(value),
+            None => groups.push((key, vec![value])),
+        }
+
+        // j. Set k to k + 1.
+        k += 1;
+    }
+}
+
+/// Create the %AsyncIteratorPrototype% object
 ///
 /// More information:
 ///  - [ECMA reference][spec]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
-fn create_iterator_prototype(context: &mut Context) -> JsObject {
-    let _timer = BoaProfiler::global().start_event("Iterator Prototype", "init");
+/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorprototype
+fn create_async_iterator_prototype(context: &mut Context) -> JsObject {
+    let _timer = BoaProfiler::global().start_event("AsyncIterator Prototype", "init");
 
-    let symbol_iterator = WellKnownSymbols::iterator();
-    let iterator_prototype = ObjectInitializer::new(context)
+    let symbol_async_iterator = WellKnownSymbols::async_iterator();
+    ObjectInitializer::new(context)
         .function(
             |v, _, _| Ok(v.clone()),
-            (symbol_iterator, "[Symbol.iterator]"),
+            (symbol_async_iterator, "[Symbol.asyncIterator]"),
             0,
         )
-        .build();
-    iterator_prototype
+        .build()
 }
 
-#[derive(Debug)]
+/// An iterator record, holding an iterator object together with its `next` method.
+///
+/// Native functions consuming iterables should get one with [`get_iterator`], advance it with
+/// [`IteratorRecord::step`] and, when they stop before the iterator is done, close it with
+/// [`IteratorRecord::close`].
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-records
+#[derive(Debug, Clone, Trace, Finalize)]
 pub struct IteratorRecord {
     iterator_object: JsValue,
     next_function: JsValue,
 }
 
 impl IteratorRecord {
+    /// Queues the iterator and its `next` method to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_value(&self.iterator_object);
+        contents.push_value(&self.next_function);
+    }
+
+    /// Creates an iterator record from an iterator object and its `next` method.
     pub fn new(iterator_object: JsValue, next_function: JsValue) -> Self {
         Self {
             iterator_object,
@@ -148,18 +437,58 @@ impl IteratorRecord {
         }
     }
 
+    /// Gets the iterator object.
+    #[inline]
+    pub fn iterator_object(&self) -> &JsValue {
+        &self.iterator_object
+    }
+
+    /// Gets the `next` method of the iterator.
+    #[inline]
+    pub fn next_function(&self) -> &JsValue {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
     ///  - [ECMA reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-iteratornext
-    pub(crate) fn next(&self, context: &mut Context) -> JsResult<IteratorResult> {
+    pub fn next(&self, context: &mut Context) -> JsResult<IteratorResult> {
+        // 1. If value is not present, then
+        //     a. Let result be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
         let next = context.call(&self.next_function, &self.iterator_object, &[])?;
-        let done = next.get_field("done", context)?.to_boolean();
+        // 3. If Type(result) is not Object, throw a TypeError exception.
+        // 4. Return result.
+        IteratorResult::from_result_object(next, context)
+    }
+
+    /// Advances the iterator, returning the next value, or `None` once the iterator is done.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorstep
+    pub fn step(&self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // 1. Let result be ? IteratorNext(iteratorRecord).
+        let result = context.call(&self.next_function, &self.iterator_object, &[])?;
+        if !result.is_object() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::ITERATOR_RESULT_NOT_AN_OBJECT,
+                "iterator result is not an object",
+            ));
+        }
+
+        // 2. Let done be ? IteratorComplete(result).
+        // 3. If done is true, return false.
+        if result.get_field("done", context)?.to_boolean() {
+            return Ok(None);
+        }
 
-        let next_result = next.get_field("value", context)?;
-        Ok(IteratorResult::new(next_result, done))
+        // 4. Return result.
+        // IteratorValue(result)
+        result.get_field("value", context).map(Some)
     }
 
     /// Cleanup the iterator
@@ -168,11 +497,7 @@ impl IteratorRecord {
     ///  - [ECMA reference][spec]
     ///
     ///  [spec]: https://tc39.es/ecma262/#sec-iteratorclose
-    pub(crate) fn close(
-        &self,
-        completion: JsResult<JsValue>,
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
+    pub fn close(&self, completion: JsResult<JsValue>, context: &mut Context) -> JsResult<JsValue> {
         let mut inner_result = self.iterator_object.get_field("return", context);
 
         // 5
@@ -201,6 +526,7 @@ impl IteratorRecord {
     }
 }
 
+/// The completion status and value of an iterator result object.
 #[derive(Debug)]
 pub struct IteratorResult {
     value: JsValue,
@@ -208,14 +534,37 @@ pub struct IteratorResult {
 }
 
 impl IteratorResult {
-    fn new(value: JsValue, done: bool) -> Self {
+    pub(crate) fn new(value: JsValue, done: bool) -> Self {
         Self { value, done }
     }
 
+    /// Reads the `done` and `value` properties of an iterator result object, throwing a
+    /// `TypeError` if it is not an object.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorcomplete
+    pub fn from_result_object(result: JsValue, context: &mut Context) -> JsResult<Self> {
+        if !result.is_object() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::ITERATOR_RESULT_NOT_AN_OBJECT,
+                "iterator result is not an object",
+            ));
+        }
+        // IteratorComplete(iterResult)
+        let done = result.get_field("done", context)?.to_boolean();
+        // IteratorValue(iterResult)
+        let value = result.get_field("value", context)?;
+        Ok(Self::new(value, done))
+    }
+
+    /// Returns `true` if the iterator is done.
     pub fn is_done(&self) -> bool {
         self.done
     }
 
+    /// Returns the value of the result.
     pub fn value(self) -> JsValue {
         self.value
     }
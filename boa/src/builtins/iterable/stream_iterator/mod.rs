@@ -0,0 +1,333 @@
+//! This module implements the async iterators wrapping Rust streams.
+//!
+//! A stream iterator lets scripts consume the values produced by a host [`Stream`] with
+//! `for await` loops: each call to its `next` method returns a promise, which is settled with the
+//! next item of the stream once it is ready.
+//!
+//! The streams are polled when scripts request their next item, and again by
+//! [`Context::run_jobs`] once they wake their task. Streams woken by events external to the
+//! context, like I/O, make progress on the next call to [`Context::run_jobs`].
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::create_iter_result_object,
+        promise::{Promise, PromiseCapability},
+    },
+    gc::{Finalize, Trace},
+    object::{JsObject, ObjectData},
+    teardown::Contents,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use futures_core::Stream;
+use std::{
+    collections::VecDeque,
+    fmt,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{self, Poll, Wake, Waker},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The stream wrapped by a stream iterator.
+type JsStream = Pin<Box<dyn Stream<Item = JsResult<JsValue>>>>;
+
+/// The waker given to the wrapped stream, recording whether it should be polled again.
+#[derive(Debug, Default)]
+struct StreamWaker {
+    woken: AtomicBool,
+}
+
+impl Wake for StreamWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+    }
+}
+
+/// The internal representation of a stream iterator.
+#[derive(Trace, Finalize)]
+pub struct StreamIterator {
+    /// The wrapped stream, until it ends or the iterator is closed.
+    #[unsafe_ignore_trace]
+    stream: Option<JsStream>,
+    /// The capabilities of the promises returned by `next`, waiting for the items of the stream.
+    requests: VecDeque<PromiseCapability>,
+    #[unsafe_ignore_trace]
+    waker: Arc<StreamWaker>,
+}
+
+impl fmt::Debug for StreamIterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamIterator")
+            .field("done", &self.stream.is_none())
+            .field("requests", &self.requests)
+            .finish()
+    }
+}
+
+impl StreamIterator {
+    pub(crate) const NAME: &'static str = "StreamIterator";
+
+    /// Queues the promises waiting for the items of the stream to be emptied by the teardown of
+    /// the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        for capability in &self.requests {
+            capability.collect_contents(contents);
+        }
+    }
+
+    /// Create the prototype of the stream iterators.
+    pub(crate) fn create_prototype(
+        context: &mut Context,
+        async_iterator_prototype: JsValue,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let prototype = context.construct_object();
+        make_builtin_fn(Self::next, "next", &prototype, 0, context);
+        make_builtin_fn(Self::r#return, "return", &prototype, 1, context);
+        prototype.set_prototype_instance(async_iterator_prototype);
+        prototype
+    }
+
+    /// Wraps `stream` in an async iterator, which can be consumed by `for await` loops.
+    ///
+    /// The items of the stream fulfill the promises returned by the `next` method of the
+    /// iterator, and its errors reject them. Once the stream ends, the iterator is done.
+    ///
+    /// The stream is not traced by the garbage collector, so it must not hold objects before
+    /// producing them.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{builtins::iterable::StreamIterator, property::Attribute, Context, JsResult, JsValue};
+    ///# use futures_core::Stream;
+    ///# use std::{pin::Pin, task::{self, Poll}};
+    /// struct Countdown(i32);
+    ///
+    /// impl Stream for Countdown {
+    ///     type Item = JsResult<JsValue>;
+    ///
+    ///     fn poll_next(mut self: Pin<&mut Self>, _: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+    ///         if self.0 == 0 {
+    ///             return Poll::Ready(None);
+    ///         }
+    ///         self.0 -= 1;
+    ///         Poll::Ready(Some(Ok(JsValue::new(self.0))))
+    ///     }
+    /// }
+    ///
+    /// let mut context = Context::new();
+    /// let stream = StreamIterator::create(Countdown(3), &mut context);
+    /// context.register_global_property("stream", stream, Attribute::all());
+    ///
+    /// context
+    ///     .eval("var values = []; (async function () { for await (var v of stream) values.push(v); })();")
+    ///     .unwrap();
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("values.join()").unwrap(), JsValue::new("2,1,0"));
+    /// ```
+    pub fn create<S>(stream: S, context: &mut Context) -> JsObject
+    where
+        S: Stream<Item = JsResult<JsValue>> + 'static,
+    {
+        let iterator = context.construct_object();
+        iterator.set_prototype_instance(context.iterator_prototypes().stream_iterator().into());
+        iterator.borrow_mut().data = ObjectData::stream_iterator(Self {
+            stream: Some(Box::pin(stream)),
+            requests: VecDeque::new(),
+            waker: Arc::default(),
+        });
+        iterator
+    }
+
+    /// `next ( )`
+    ///
+    /// Returns a promise of the next item of the stream.
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let (iterator, capability) = Self::this_iterator(this, context)?;
+
+        let done = {
+            let mut object = iterator.borrow_mut();
+            let stream_iterator = object
+                .as_stream_iterator_mut()
+                .expect("checked to be a stream iterator");
+            if stream_iterator.stream.is_some() {
+                stream_iterator.requests.push_back(capability.clone());
+                false
+            } else {
+                true
+            }
+        };
+
+        if done {
+            let result = create_iter_result_object(context, JsValue::undefined(), true);
+            capability
+                .resolve()
+                .call(&JsValue::undefined(), &[result], context)?;
+        } else {
+            Self::poll(&iterator, context)?;
+        }
+        Ok(capability.promise().clone().into())
+    }
+
+    /// `return ( value )`
+    ///
+    /// Drops the stream, and completes the pending requests of the iterator.
+    fn r#return(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let (iterator, capability) = Self::this_iterator(this, context)?;
+
+        let requests = {
+            let mut object = iterator.borrow_mut();
+            let stream_iterator = object
+                .as_stream_iterator_mut()
+                .expect("checked to be a stream iterator");
+            stream_iterator.stream = None;
+            std::mem::take(&mut stream_iterator.requests)
+        };
+        context
+            .pending_streams
+            .retain(|pending| !JsObject::equals(pending, &iterator));
+        for request in requests {
+            let result = create_iter_result_object(context, JsValue::undefined(), true);
+            request
+                .resolve()
+                .call(&JsValue::undefined(), &[result], context)?;
+        }
+
+        let result = create_iter_result_object(context, args.get_or_undefined(0).clone(), true);
+        capability
+            .resolve()
+            .call(&JsValue::undefined(), &[result], context)?;
+        Ok(capability.promise().clone().into())
+    }
+
+    /// Gets the stream iterator object, and creates the capability of the promise returned by
+    /// its methods.
+    fn this_iterator(
+        this: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<(JsObject, PromiseCapability)> {
+        let iterator = this
+            .as_object()
+            .filter(|object| object.borrow().as_stream_iterator().is_some())
+            .ok_or_else(|| context.construct_type_error("`this` is not a StreamIterator"))?;
+
+        let constructor = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let capability = Promise::new_promise_capability(&constructor, context)?;
+        Ok((iterator, capability))
+    }
+
+    /// Polls the stream of the iterator until its pending requests are settled, or until the
+    /// stream is not ready.
+    ///
+    /// A stream that is not ready is tracked by the context, to be polled again by
+    /// [`Context::run_jobs`] once it wakes its task.
+    fn poll(iterator: &JsObject, context: &mut Context) -> JsResult<()> {
+        loop {
+            let (request, item) = {
+                let mut object = iterator.borrow_mut();
+                let stream_iterator = object
+                    .as_stream_iterator_mut()
+                    .expect("checked to be a stream iterator");
+                if stream_iterator.requests.is_empty() {
+                    return Ok(());
+                }
+                let stream = match stream_iterator.stream {
+                    Some(ref mut stream) => stream,
+                    None => return Ok(()),
+                };
+
+                stream_iterator.waker.woken.store(false, Ordering::Release);
+                let waker = Waker::from(stream_iterator.waker.clone());
+                match stream
+                    .as_mut()
+                    .poll_next(&mut task::Context::from_waker(&waker))
+                {
+                    Poll::Pending => {
+                        drop(object);
+                        if !context
+                            .pending_streams
+                            .iter()
+                            .any(|pending| JsObject::equals(pending, iterator))
+                        {
+                            context.pending_streams.push(iterator.clone());
+                        }
+                        return Ok(());
+                    }
+                    Poll::Ready(None) => {
+                        stream_iterator.stream = None;
+                        let requests = std::mem::take(&mut stream_iterator.requests);
+                        drop(object);
+                        for request in requests {
+                            let result =
+                                create_iter_result_object(context, JsValue::undefined(), true);
+                            request
+                                .resolve()
+                                .call(&JsValue::undefined(), &[result], context)?;
+                        }
+                        return Ok(());
+                    }
+                    Poll::Ready(Some(item)) => {
+                        let request = stream_iterator
+                            .requests
+                            .pop_front()
+                            .expect("checked to have a request");
+                        (request, item)
+                    }
+                }
+            };
+
+            match item {
+                Ok(value) => {
+                    let result = create_iter_result_object(context, value, false);
+                    request
+                        .resolve()
+                        .call(&JsValue::undefined(), &[result], context)?;
+                }
+                Err(error) => {
+                    request
+                        .reject()
+                        .call(&JsValue::undefined(), &[error], context)?;
+                }
+            }
+        }
+    }
+
+    /// Polls the pending streams that woke their task since they were last polled.
+    ///
+    /// This is called by [`Context::run_jobs`] once the job queue is empty.
+    pub(crate) fn poll_woken(context: &mut Context) -> JsResult<()> {
+        let (woken, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut context.pending_streams)
+            .into_iter()
+            .partition(|iterator| {
+                iterator
+                    .borrow()
+                    .as_stream_iterator()
+                    .expect("pending streams are stream iterators")
+                    .waker
+                    .woken
+                    .load(Ordering::Acquire)
+            });
+        context.pending_streams = pending;
+
+        for iterator in &woken {
+            Self::poll(iterator, context)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use super::StreamIterator;
+use crate::{forward, property::Attribute, Context, JsResult, JsValue};
+use futures_core::Stream;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    pin::Pin,
+    rc::Rc,
+    task::{self, Poll, Waker},
+};
+
+/// A stream producing the items pushed by the test, and pending while there are none.
+#[derive(Debug, Default)]
+struct Channel {
+    items: VecDeque<Option<JsResult<JsValue>>>,
+    waker: Option<Waker>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Receiver(Rc<RefCell<Channel>>);
+
+impl Receiver {
+    /// Makes `item` the next item of the stream, and wakes its task.
+    fn send(&self, item: Option<JsResult<JsValue>>) {
+        let mut channel = self.0.borrow_mut();
+        channel.items.push_back(item);
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Stream for Receiver {
+    type Item = JsResult<JsValue>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut channel = self.0.borrow_mut();
+        match channel.items.pop_front() {
+            Some(item) => Poll::Ready(item),
+            None => {
+                channel.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+const CONSUME: &str = r#"
+    var chunks = [];
+    var finished = false;
+    var failure;
+    (async function () {
+        try {
+            for await (var chunk of stream) {
+                chunks.push(chunk);
+            }
+            finished = true;
+        } catch (e) {
+            failure = e;
+        }
+    })();
+"#;
+
+#[test]
+fn consumed_incrementally() {
+    let mut context = Context::new();
+    let receiver = Receiver::default();
+    let stream = StreamIterator::create(receiver.clone(), &mut context);
+    context.register_global_property("stream", stream, Attribute::all());
+
+    forward(&mut context, CONSUME);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "chunks.length"), "0");
+
+    receiver.send(Some(Ok("first".into())));
+    receiver.send(Some(Ok(2.into())));
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "chunks.join()"), "\"first,2\"");
+    assert_eq!(forward(&mut context, "finished"), "false");
+
+    receiver.send(None);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "finished"), "true");
+    assert_eq!(forward(&mut context, "chunks.length"), "2");
+}
+
+#[test]
+fn errors_reject() {
+    let mut context = Context::new();
+    let receiver = Receiver::default();
+    let stream = StreamIterator::create(receiver.clone(), &mut context);
+    context.register_global_property("stream", stream, Attribute::all());
+
+    forward(&mut context, CONSUME);
+    receiver.send(Some(Ok(1.into())));
+    receiver.send(Some(Err("broken".into())));
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "chunks.join()"), "\"1\"");
+    assert_eq!(forward(&mut context, "failure"), "\"broken\"");
+    assert_eq!(forward(&mut context, "finished"), "false");
+}
+
+#[test]
+fn iterator_protocol() {
+    let mut context = Context::new();
+    let receiver = Receiver::default();
+    let stream = StreamIterator::create(receiver.clone(), &mut context);
+    context.register_global_property("stream", stream, Attribute::all());
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "stream[Symbol.asyncIterator]() === stream && typeof stream.next"
+        ),
+        "\"function\""
+    );
+
+    // Requests made before the items are ready are settled in order.
+    forward(
+        &mut context,
+        r#"
+        var results = [];
+        stream.next().then(r => results.push(r.value));
+        stream.next().then(r => results.push(r.value));
+        "#,
+    );
+    receiver.send(Some(Ok("a".into())));
+    receiver.send(Some(Ok("b".into())));
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "results.join()"), "\"a,b\"");
+
+    // Closing the iterator completes the pending requests, and drops the stream.
+    forward(
+        &mut context,
+        r#"
+        var pending = stream.next();
+        var closed = stream.return('bye');
+        var settled = [];
+        pending.then(r => settled.push(r.done));
+        closed.then(r => settled.push(r.value, r.done));
+        stream.next().then(r => settled.push(r.done));
+        "#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "settled.join()"),
+        "\"true,bye,true,true\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { stream.next.call({}); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
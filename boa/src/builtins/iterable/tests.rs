@@ -0,0 +1,134 @@
+use super::get_iterator;
+use crate::{forward, forward_val, Context};
+
+/// Defines `makeIterable(values)`, returning an iterable over `values` that logs the calls
+/// made to its iterator in the global `log` array.
+const LOGGING_ITERABLE: &str = r#"
+    var log = [];
+    function makeIterable(values) {
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            var index = 0;
+            return {
+                next: function () {
+                    log.push('next');
+                    var result = {};
+                    Object.defineProperty(result, 'done', {
+                        get: function () { log.push('done'); return index >= values.length; }
+                    });
+                    Object.defineProperty(result, 'value', {
+                        get: function () { log.push('value'); return values[index++]; }
+                    });
+                    return result;
+                },
+                return: function () {
+                    log.push('return');
+                    return {};
+                }
+            };
+        };
+        return iterable;
+    }
+"#;
+
+#[test]
+fn step_does_not_read_the_value_of_a_done_result() {
+    let mut context = Context::new();
+    forward(&mut context, LOGGING_ITERABLE);
+
+    assert_eq!(
+        forward(&mut context, "[...makeIterable([1])].join()"),
+        "\"1\""
+    );
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"next,done,value,next,done\""
+    );
+}
+
+#[test]
+fn iterator_results_must_be_objects() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var bad = {};
+        bad[Symbol.iterator] = function () {
+            return { next: function () { return 1; } };
+        };
+        var caught = [];
+        try { [...bad]; } catch (e) { caught.push(e instanceof TypeError); }
+        try { new Set(bad); } catch (e) { caught.push(e instanceof TypeError); }
+        try { var [x] = bad; } catch (e) { caught.push(e instanceof TypeError); }
+        "#,
+    );
+    assert_eq!(forward(&mut context, "caught.join()"), "\"true,true,true\"");
+
+    let value = forward_val(&mut context, "bad").unwrap();
+    let iterator = get_iterator(&mut context, value).unwrap();
+    assert!(iterator.step(&mut context).is_err());
+}
+
+#[test]
+fn destructuring_closes_unfinished_iterators() {
+    let mut context = Context::new();
+    forward(&mut context, LOGGING_ITERABLE);
+
+    forward(&mut context, "var [a] = makeIterable([1, 2]);");
+    assert_eq!(forward(&mut context, "a"), "1");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"next,done,value,return\""
+    );
+
+    forward(&mut context, "log = []; var [b, c, d] = makeIterable([1]);");
+    assert_eq!(forward(&mut context, "[b, c, d].join()"), "\"1,,\"");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"next,done,value,next,done\""
+    );
+
+    forward(
+        &mut context,
+        "log = []; var [...rest] = makeIterable([1, 2]);",
+    );
+    assert_eq!(forward(&mut context, "rest.join()"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "log.indexOf('return')"), "-1");
+}
+
+#[test]
+fn collection_constructors_close_iterators_on_errors() {
+    let mut context = Context::new();
+    forward(&mut context, LOGGING_ITERABLE);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var map = new Map(makeIterable([[1, 'one'], [2, 'two']])); [map.size, map.get(2)].join()"
+        ),
+        "\"2,two\""
+    );
+    assert_eq!(forward(&mut context, "log.indexOf('return')"), "-1");
+
+    forward(&mut context, "log = [];");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Map(makeIterable([[1, 'one'], 2])); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "log[log.length - 1]"), "\"return\"");
+
+    forward(&mut context, "log = [];");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            Set.prototype.add = function () { throw new RangeError('add'); };
+            try { new Set(makeIterable([1])); } catch (e) { e instanceof RangeError && log.join() }
+            "#
+        ),
+        "\"next,done,value,return\""
+    );
+}
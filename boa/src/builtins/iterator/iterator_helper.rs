@@ -0,0 +1,423 @@
+//! This module implements the iterator helper objects, returned by the lazy helpers of
+//! `%IteratorPrototype%`, like `map` or `take`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+
+use super::get_iterator_flattenable;
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, if_abrupt_close_iterator, IteratorRecord},
+    },
+    gc::{Finalize, Trace},
+    object::{JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    teardown::Contents,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+/// The helper that created an iterator helper object, with the state of its closure.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum IteratorHelperKind {
+    Map {
+        mapper: JsValue,
+    },
+    Filter {
+        predicate: JsValue,
+    },
+    /// The amount of values left to take, or `None` if they are all taken.
+    Take {
+        remaining: Option<u64>,
+    },
+    /// The amount of values left to drop, or `None` if they are all dropped.
+    Drop {
+        remaining: Option<u64>,
+    },
+    /// The iterator of the values returned by the mapper that is being flattened, if any.
+    FlatMap {
+        mapper: JsValue,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+/// The state of the closure of an iterator helper, like the state of a generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IteratorHelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The internal representation of an iterator helper object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct IteratorHelper {
+    underlying_iterator: IteratorRecord,
+    kind: IteratorHelperKind,
+    /// The amount of values the mapper or the predicate was called with.
+    counter: usize,
+    #[unsafe_ignore_trace]
+    state: IteratorHelperState,
+}
+
+impl IteratorHelper {
+    pub(crate) const NAME: &'static str = "Iterator Helper";
+
+    /// Queues the underlying iterator and the callback of the helper to be emptied by the
+    /// teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        self.underlying_iterator.collect_contents(contents);
+        match &self.kind {
+            IteratorHelperKind::Map { mapper } => contents.push_value(mapper),
+            IteratorHelperKind::Filter { predicate } => contents.push_value(predicate),
+            IteratorHelperKind::FlatMap { mapper, inner } => {
+                contents.push_value(mapper);
+                if let Some(inner) = inner {
+                    inner.collect_contents(contents);
+                }
+            }
+            IteratorHelperKind::Take { .. } | IteratorHelperKind::Drop { .. } => {}
+        }
+    }
+
+    /// Create the `%IteratorHelperPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: JsValue) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let prototype = context.construct_object();
+        make_builtin_fn(Self::next, "next", &prototype, 0, context);
+        make_builtin_fn(Self::r#return, "return", &prototype, 0, context);
+        prototype.set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value(Self::NAME)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        prototype.insert(to_string_tag, to_string_tag_property);
+        prototype
+    }
+
+    /// Creates an iterator helper of the `underlying_iterator`, whose values are produced by the
+    /// helper `kind`.
+    pub(crate) fn create(
+        underlying_iterator: IteratorRecord,
+        kind: IteratorHelperKind,
+        context: &mut Context,
+    ) -> JsValue {
+        let helper = context.construct_object();
+        helper.set_prototype_instance(context.iterator_prototypes().iterator_helper().into());
+        helper.borrow_mut().data = ObjectData::iterator_helper(Self {
+            underlying_iterator,
+            kind,
+            counter: 0,
+            state: IteratorHelperState::SuspendedStart,
+        });
+        helper.into()
+    }
+
+    /// Gets the iterator helper object `this`.
+    fn this_helper(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        match this {
+            JsValue::Object(object) if object.borrow().as_iterator_helper().is_some() => {
+                Ok(object.clone())
+            }
+            _ => Err(context.construct_type_error("`this` is not an Iterator Helper")),
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// Resumes the closure of the helper, until it produces the next value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let object = Self::this_helper(this, context)?;
+
+        let (underlying_iterator, mut kind, mut counter) = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_iterator_helper_mut()
+                .expect("checked that `this` is an iterator helper");
+            match helper.state {
+                IteratorHelperState::Executing => {
+                    drop(object);
+                    return context.throw_type_error("Iterator Helper is already running");
+                }
+                IteratorHelperState::Completed => {
+                    drop(object);
+                    return Ok(create_iter_result_object(
+                        context,
+                        JsValue::undefined(),
+                        true,
+                    ));
+                }
+                IteratorHelperState::SuspendedStart | IteratorHelperState::SuspendedYield => {}
+            }
+            helper.state = IteratorHelperState::Executing;
+            (
+                helper.underlying_iterator.clone(),
+                helper.kind.clone(),
+                helper.counter,
+            )
+        };
+
+        let result = Self::resume(&underlying_iterator, &mut kind, &mut counter, context);
+
+        {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_iterator_helper_mut()
+                .expect("checked that `this` is an iterator helper");
+            helper.kind = kind;
+            helper.counter = counter;
+            helper.state = match result {
+                Ok(Some(_)) => IteratorHelperState::SuspendedYield,
+                Ok(None) | Err(_) => IteratorHelperState::Completed,
+            };
+        }
+
+        match result? {
+            Some(value) => Ok(create_iter_result_object(context, value, false)),
+            None => Ok(create_iter_result_object(
+                context,
+                JsValue::undefined(),
+                true,
+            )),
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// Completes the helper, closing the underlying iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        let object = Self::this_helper(this, context)?;
+
+        let (underlying_iterator, kind) = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_iterator_helper_mut()
+                .expect("checked that `this` is an iterator helper");
+            match helper.state {
+                IteratorHelperState::Executing => {
+                    drop(object);
+                    return context.throw_type_error("Iterator Helper is already running");
+                }
+                IteratorHelperState::Completed => {
+                    drop(object);
+                    return Ok(create_iter_result_object(
+                        context,
+                        JsValue::undefined(),
+                        true,
+                    ));
+                }
+                IteratorHelperState::SuspendedStart | IteratorHelperState::SuspendedYield => {}
+            }
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            //     a. Set O.[[GeneratorState]] to completed.
+            // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            helper.state = match helper.state {
+                IteratorHelperState::SuspendedStart => IteratorHelperState::Completed,
+                _ => IteratorHelperState::Executing,
+            };
+            (helper.underlying_iterator.clone(), helper.kind.clone())
+        };
+
+        let result = Self::close(&underlying_iterator, &kind, context);
+        object
+            .borrow_mut()
+            .as_iterator_helper_mut()
+            .expect("checked that `this` is an iterator helper")
+            .state = IteratorHelperState::Completed;
+        result?;
+
+        Ok(create_iter_result_object(
+            context,
+            JsValue::undefined(),
+            true,
+        ))
+    }
+
+    /// Closes the iterators of a helper, once it is returned from.
+    fn close(
+        iterated: &IteratorRecord,
+        kind: &IteratorHelperKind,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // A `flatMap` helper suspended in the middle of an inner iterator closes it first.
+        if let IteratorHelperKind::FlatMap {
+            inner: Some(inner), ..
+        } = kind
+        {
+            // 1. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            // 2. IfAbruptCloseIterator(backupCompletion, iterated).
+            let inner_result = inner.close(Ok(JsValue::undefined()), context);
+            if_abrupt_close_iterator(inner_result, iterated, context)?;
+        }
+
+        // Return ? IteratorClose(iterated, completion).
+        iterated.close(Ok(JsValue::undefined()), context)
+    }
+
+    /// Runs the closure of the helper until it yields a value, or returns `None` once it is done.
+    fn resume(
+        iterated: &IteratorRecord,
+        kind: &mut IteratorHelperKind,
+        counter: &mut usize,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match kind {
+            IteratorHelperKind::Map { mapper } => {
+                // a. Let value be ? IteratorStepValue(iterated).
+                // b. If value is done, return ReturnCompletion(undefined).
+                let value = match iterated.step(context)? {
+                    Some(value) => value,
+                    None => return Ok(None),
+                };
+
+                // c. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                // d. IfAbruptCloseIterator(mapped, iterated).
+                let mapped =
+                    context.call(mapper, &JsValue::undefined(), &[value, (*counter).into()]);
+                let mapped = if_abrupt_close_iterator(mapped, iterated, context)?;
+
+                // e. Let completion be Completion(Yield(mapped)).
+                // g. Set counter to counter + 1.
+                *counter += 1;
+                Ok(Some(mapped))
+            }
+            IteratorHelperKind::Filter { predicate } => loop {
+                // a. Let value be ? IteratorStepValue(iterated).
+                // b. If value is done, return ReturnCompletion(undefined).
+                let value = match iterated.step(context)? {
+                    Some(value) => value,
+                    None => return Ok(None),
+                };
+
+                // c. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                // d. IfAbruptCloseIterator(selected, iterated).
+                let selected = context.call(
+                    predicate,
+                    &JsValue::undefined(),
+                    &[value.clone(), (*counter).into()],
+                );
+                let selected = if_abrupt_close_iterator(selected, iterated, context)?;
+
+                // f. Set counter to counter + 1.
+                *counter += 1;
+
+                // e. If ToBoolean(selected) is true, then
+                //     i. Let completion be Completion(Yield(value)).
+                if selected.to_boolean() {
+                    return Ok(Some(value));
+                }
+            },
+            IteratorHelperKind::Take { remaining } => {
+                // a. If remaining = 0, then
+                //     i. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                if *remaining == Some(0) {
+                    iterated.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                // b. If remaining ≠ +∞, then
+                //     i. Set remaining to remaining - 1.
+                if let Some(remaining) = remaining {
+                    *remaining -= 1;
+                }
+
+                // c. Let value be ? IteratorStepValue(iterated).
+                // d. If value is done, return ReturnCompletion(undefined).
+                // e. Let completion be Completion(Yield(value)).
+                iterated.step(context)
+            }
+            IteratorHelperKind::Drop { remaining } => {
+                // a. Repeat, while remaining > 0,
+                while *remaining != Some(0) {
+                    // i. If remaining ≠ +∞, then
+                    //     1. Set remaining to remaining - 1.
+                    if let Some(remaining) = remaining {
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if iterated.step(context)?.is_none() {
+                        return Ok(None);
+                    }
+                }
+
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                iterated.step(context)
+            }
+            IteratorHelperKind::FlatMap { mapper, inner } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    let inner_value = inner_iterator.step(context);
+                    match if_abrupt_close_iterator(inner_value, iterated, context)? {
+                        // 4. Else,
+                        //     a. Let completion be Completion(Yield(innerValue)).
+                        Some(inner_value) => return Ok(Some(inner_value)),
+                        // 3. If innerValue is done, then
+                        //     a. Set innerAlive to false.
+                        None => *inner = None,
+                    }
+                    continue;
+                }
+
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let value = match iterated.step(context)? {
+                    Some(value) => value,
+                    None => return Ok(None),
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped =
+                    context.call(mapper, &JsValue::undefined(), &[value, (*counter).into()]);
+                let mapped = if_abrupt_close_iterator(mapped, iterated, context)?;
+
+                // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                // vi. IfAbruptCloseIterator(innerIterator, iterated).
+                // vii. Let innerAlive be true.
+                let inner_iterator = get_iterator_flattenable(&mapped, context);
+                *inner = Some(if_abrupt_close_iterator(inner_iterator, iterated, context)?);
+
+                // ix. Set counter to counter + 1.
+                *counter += 1;
+            },
+        }
+    }
+}
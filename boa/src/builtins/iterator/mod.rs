@@ -0,0 +1,697 @@
+//! This module implements the global `Iterator` object, and the iterator helpers of its prototype,
+//! `%IteratorPrototype%`, which every builtin iterator inherits from.
+//!
+//! The `map`, `filter`, `take`, `drop` and `flatMap` helpers return lazy iterator helper objects,
+//! while the other helpers consume the iterator right away.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator
+
+use crate::{
+    builtins::{
+        iterable::{if_abrupt_close_iterator, IteratorRecord},
+        Array, BuiltIn,
+    },
+    context::StandardObjects,
+    error::JsErrorCode,
+    object::{internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+pub mod iterator_helper;
+
+pub(crate) use self::iterator_helper::{IteratorHelper, IteratorHelperKind};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Iterator` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Iterator;
+
+impl BuiltIn for Iterator {
+    const NAME: &'static str = "Iterator";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let iterator_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().iterator_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .method(
+            Self::iterator,
+            (WellKnownSymbols::iterator(), "[Symbol.iterator]"),
+            0,
+        )
+        .method(Self::map, "map", 1)
+        .method(Self::filter, "filter", 1)
+        .method(Self::take, "take", 1)
+        .method(Self::drop, "drop", 1)
+        .method(Self::flat_map, "flatMap", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::to_array, "toArray", 0)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::some, "some", 1)
+        .method(Self::every, "every", 1)
+        .method(Self::find, "find", 1)
+        .build();
+
+        (Self::NAME, iterator_object.into(), Self::attribute())
+    }
+}
+
+impl Iterator {
+    /// `Iterator ( )`
+    ///
+    /// `Iterator` is an abstract class, which can only be constructed through a subclass.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/Iterator
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let active_function = context.standard_objects().iterator_object().constructor();
+        match new_target {
+            JsValue::Object(new_target) if !JsObject::equals(new_target, &active_function) => {}
+            _ => return context.throw_type_error("Iterator is an abstract class"),
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::iterator_object, context)?;
+        let iterator = context.construct_object();
+        iterator.set_prototype_instance(prototype.into());
+        Ok(iterator.into())
+    }
+
+    /// `%IteratorPrototype% [ @@iterator ] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-@@iterator
+    fn iterator(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// Gets the `this` value of a helper, which must be an object.
+    fn this_object(this: &JsValue, method: &str, context: &mut Context) -> JsResult<JsObject> {
+        match this {
+            JsValue::Object(object) => Ok(object.clone()),
+            _ => Err(context.construct_type_error(format!(
+                "Iterator.prototype.{} called on a non-object",
+                method
+            ))),
+        }
+    }
+
+    /// Closes the iterator `object` after an invalid argument was passed to one of its helpers,
+    /// returning the error to throw.
+    fn close_with_error(object: &JsObject, error: JsValue, context: &mut Context) -> JsValue {
+        let iterated = IteratorRecord::new(object.clone().into(), JsValue::undefined());
+        iterated
+            .close(Err(error), context)
+            .expect_err("closing an iterator with a throw completion throws")
+    }
+
+    /// Checks that the argument of a helper is callable, closing the iterator `object` otherwise.
+    fn require_callable(
+        object: &JsObject,
+        function: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if function.is_callable() {
+            return Ok(());
+        }
+
+        let error = context.construct_type_error(format!(
+            "Iterator.prototype.{}: argument is not callable",
+            method
+        ));
+        Err(Self::close_with_error(object, error, context))
+    }
+
+    /// Converts the `limit` argument of the `take` and `drop` helpers, closing the iterator
+    /// `object` if it is not a positive number. The limit is `None` when it is infinite.
+    fn limit(
+        object: &JsObject,
+        limit: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<u64>> {
+        let iterated = IteratorRecord::new(object.clone().into(), JsValue::undefined());
+
+        // 3. Let numLimit be Completion(ToNumber(limit)).
+        // 4. IfAbruptCloseIterator(numLimit, iterated).
+        let num_limit = if_abrupt_close_iterator(limit.to_number(context), &iterated, context)?;
+
+        // 5. If numLimit is NaN, then
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        let integer_limit = num_limit.trunc();
+        if num_limit.is_nan() || integer_limit < 0.0 {
+            // a. Let error be ThrowCompletion(a newly created RangeError object).
+            // b. Return ? IteratorClose(iterated, error).
+            let error = context.construct_range_error(format!(
+                "Iterator.prototype.{}: limit must be a positive number",
+                method
+            ));
+            return Err(Self::close_with_error(object, error, context));
+        }
+
+        if integer_limit.is_infinite() {
+            Ok(None)
+        } else {
+            Ok(Some(integer_limit as u64))
+        }
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// Returns an iterator of the values of this iterator transformed by `mapper`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/map
+    pub(crate) fn map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "map", context)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = args.get_or_undefined(0);
+        Self::require_callable(&object, mapper, "map", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6-8. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperKind::Map {
+                mapper: mapper.clone(),
+            },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// Returns an iterator of the values of this iterator for which `predicate` returns a truthy
+    /// value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/filter
+    pub(crate) fn filter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "filter", context)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate = args.get_or_undefined(0);
+        Self::require_callable(&object, predicate, "filter", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6-8. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperKind::Filter {
+                predicate: predicate.clone(),
+            },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// Returns an iterator of the first `limit` values of this iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/take
+    pub(crate) fn take(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "take", context)?;
+
+        // 3-8.
+        let remaining = Self::limit(&object, args.get_or_undefined(0), "take", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 10-12. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperKind::Take { remaining },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// Returns an iterator of the values of this iterator after the first `limit` ones.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/drop
+    pub(crate) fn drop(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "drop", context)?;
+
+        // 3-8.
+        let remaining = Self::limit(&object, args.get_or_undefined(0), "drop", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 10-12. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperKind::Drop { remaining },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// Returns an iterator of the values of the iterables returned by `mapper` for the values of
+    /// this iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/flatMap
+    pub(crate) fn flat_map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "flatMap", context)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = args.get_or_undefined(0);
+        Self::require_callable(&object, mapper, "flatMap", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6-8. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperKind::FlatMap {
+                mapper: mapper.clone(),
+                inner: None,
+            },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// Reduces the values of this iterator to a single value with `reducer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/reduce
+    pub(crate) fn reduce(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "reduce", context)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(reducer) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let reducer = args.get_or_undefined(0);
+        Self::require_callable(&object, reducer, "reduce", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        let (mut accumulator, mut counter) = match args.get(1) {
+            // 6. If initialValue is not present, then
+            None => match iterated.step(context)? {
+                // a. Let accumulator be ? IteratorStepValue(iterated).
+                // b. If accumulator is done, throw a TypeError exception.
+                None => {
+                    return context.throw_type_error(
+                        "Iterator.prototype.reduce of an empty iterator with no initial value",
+                    )
+                }
+                // c. Let counter be 1.
+                Some(value) => (value, 1_usize),
+            },
+            // 7. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            Some(initial_value) => (initial_value.clone(), 0),
+        };
+
+        // 8. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return accumulator.
+            let value = match iterated.step(context)? {
+                Some(value) => value,
+                None => return Ok(accumulator),
+            };
+
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.
+            let result = context.call(
+                reducer,
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+            );
+            accumulator = if_abrupt_close_iterator(result, &iterated, context)?;
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// Collects the values of this iterator into an array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/toArray
+    pub(crate) fn to_array(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "toArray", context)?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 4. Let items be a new empty List.
+        let mut items = Vec::new();
+
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        while let Some(value) = iterated.step(context)? {
+            items.push(value);
+        }
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( procedure )`
+    ///
+    /// Calls `procedure` with each value of this iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/forEach
+    pub(crate) fn for_each(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, "forEach", context)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(procedure) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let procedure = args.get_or_undefined(0);
+        Self::require_callable(&object, procedure, "forEach", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let counter be 0.
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return undefined.
+        let mut counter = 0_usize;
+        while let Some(value) = iterated.step(context)? {
+            // c. Let result be Completion(Call(procedure, undefined, « value, 𝔽(counter) »)).
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = context.call(procedure, &JsValue::undefined(), &[value, counter.into()]);
+            if_abrupt_close_iterator(result, &iterated, context)?;
+
+            // e. Set counter to counter + 1.
+            counter += 1;
+        }
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// Checks if `predicate` returns a truthy value for any value of this iterator, stopping at
+    /// the first one it does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/some
+    pub(crate) fn some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::find_value(this, args, "some", context).map(|found| found.is_some().into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// Checks if `predicate` returns a truthy value for every value of this iterator, stopping
+    /// at the first one it doesn't.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/every
+    pub(crate) fn every(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::find_value(this, args, "every", context).map(|found| found.is_none().into())
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// Returns the first value of this iterator for which `predicate` returns a truthy value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/find
+    pub(crate) fn find(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::find_value(this, args, "find", context).map(Option::unwrap_or_default)
+    }
+
+    /// The shared steps of `some`, `every` and `find`, finding the first value of the iterator
+    /// for which the predicate returns a truthy value, or a falsy one for `every`.
+    ///
+    /// The iterator is closed once the value is found.
+    fn find_value(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_object(this, method, context)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate = args.get_or_undefined(0);
+        Self::require_callable(&object, predicate, method, context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let counter be 0.
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return false, true, or undefined.
+        let mut counter = 0_usize;
+        while let Some(value) = iterated.step(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = context.call(
+                predicate,
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+            );
+            let result = if_abrupt_close_iterator(result, &iterated, context)?;
+
+            // e. If ToBoolean(result) is true (false for every), return ? IteratorClose(iterated, NormalCompletion(true, false or value)).
+            if result.to_boolean() != (method == "every") {
+                return iterated.close(Ok(value), context).map(Some);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+        Ok(None)
+    }
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// Gets the iterator record of an object that is an iterator itself, as opposed to an iterable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = object.get("next", context)?;
+
+    // 2. Let record be the Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return record.
+    Ok(IteratorRecord::new(object.clone().into(), next_method))
+}
+
+/// `GetIteratorFlattenable ( obj, reject-primitives )`
+///
+/// Gets the iterator of an iterable object, or the object itself if it is not iterable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    //     a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+    let object = match value {
+        JsValue::Object(object) => object,
+        _ => return Err(context.construct_type_error("flattened value is not an object")),
+    };
+
+    // 2. Let method be ? GetMethod(obj, @@iterator).
+    let iterator = match object.get_method(context, WellKnownSymbols::iterator())? {
+        // 3. If method is undefined, then
+        //     a. Let iterator be obj.
+        None => value.clone(),
+        // 4. Else,
+        //     a. Let iterator be ? Call(method, obj).
+        Some(method) => method.call(value, &[], context)?,
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    // 6. Return ? GetIteratorDirect(iterator).
+    match &iterator {
+        JsValue::Object(iterator) => get_iterator_direct(iterator, context),
+        _ => Err(context.construct_coded_error(
+            JsErrorCode::ITERATOR_NOT_AN_OBJECT,
+            "iterator is not an object",
+        )),
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{forward, Context};
+
+/// Defines `counter(limit)`, returning an iterator over the integers below `limit` that records
+/// whether it was closed in the global `closed` variable.
+const COUNTER: &str = r#"
+    var closed = false;
+    function counter(limit) {
+        var index = 0;
+        var iterator = Object.create(Iterator.prototype);
+        iterator.next = function () {
+            return index < limit ? { value: index++, done: false } : { value: undefined, done: true };
+        };
+        iterator.return = function () {
+            closed = true;
+            return {};
+        };
+        return iterator;
+    }
+"#;
+
+#[test]
+fn constructor() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Iterator(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Iterator(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            function Sub() {}
+            Sub.prototype = Object.create(Iterator.prototype);
+            var sub = Reflect.construct(Iterator, [], Sub);
+            Object.getPrototypeOf(sub) === Sub.prototype
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            [
+                [].values(),
+                new Map().keys(),
+                new Set().values(),
+                ''[Symbol.iterator](),
+                (function* () {})(),
+            ].every(function (iterator) {
+                return iterator instanceof Iterator;
+            })
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn lazy_helpers() {
+    let mut context = Context::new();
+    forward(&mut context, COUNTER);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(5).map(function (x, i) { return x * 10 + i; }).toArray().join()"
+        ),
+        "\"0,11,22,33,44\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(10).filter(function (x) { return x % 3 === 0; }).toArray().join()"
+        ),
+        "\"0,3,6,9\""
+    );
+    assert_eq!(
+        forward(&mut context, "counter(10).drop(2).take(3).toArray().join()"),
+        "\"2,3,4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(3).flatMap(function (x) { return [x, 'a' + x]; }).toArray().join()"
+        ),
+        "\"0,a0,1,a1,2,a2\""
+    );
+    assert_eq!(
+        forward(&mut context, "counter(3).drop(Infinity).toArray().length"),
+        "0"
+    );
+
+    // Values are only produced when they are requested.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var calls = 0;
+            var mapped = counter(5).map(function (x) { calls++; return x; });
+            var before = calls;
+            mapped.next();
+            [before, calls].join()
+            "#
+        ),
+        "\"0,1\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(counter(1).map(function (x) { return x; }))"
+        ),
+        "\"[object Iterator Helper]\""
+    );
+}
+
+#[test]
+fn eager_helpers() {
+    let mut context = Context::new();
+    forward(&mut context, COUNTER);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(5).reduce(function (sum, x) { return sum + x; })"
+        ),
+        "10"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(3).reduce(function (acc, x, i) { return acc + x + ':' + i + ' '; }, '')"
+        ),
+        "\"0:0 1:1 2:2 \""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { counter(0).reduce(function () {}); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var seen = []; counter(3).forEach(function (x, i) { seen.push(x + i); }); seen.join()"
+        ),
+        "\"0,2,4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(5).some(function (x) { return x === 3; })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(5).every(function (x) { return x < 5; })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(5).find(function (x) { return x > 1; })"
+        ),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "counter(5).find(function (x) { return x > 9; })"
+        ),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2].values().toArray().join()"),
+        "\"1,2\""
+    );
+}
+
+#[test]
+fn closing() {
+    let mut context = Context::new();
+    forward(&mut context, COUNTER);
+
+    // Stopping early closes the iterator.
+    assert_eq!(
+        forward(
+            &mut context,
+            "closed = false; counter(5).some(function (x) { return x === 1; }); closed"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "closed = false; counter(5).every(function (x) { return x === 0; }); closed"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "closed = false; counter(5).take(2).toArray(); closed"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "closed = false; counter(5).take(5).toArray(); closed"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "closed = false; counter(5).toArray(); closed"),
+        "false"
+    );
+
+    // Errors thrown by the callbacks close the iterator.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            closed = false;
+            var error;
+            try {
+                counter(5).map(function () { throw 'failed'; }).next();
+            } catch (e) {
+                error = e;
+            }
+            [error, closed].join()
+            "#
+        ),
+        "\"failed,true\""
+    );
+
+    // Invalid arguments close the iterator too.
+    assert_eq!(
+        forward(
+            &mut context,
+            "closed = false; try { counter(5).map(1); } catch (e) { [e instanceof TypeError, closed].join() }"
+        ),
+        "\"true,true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "closed = false; try { counter(5).take(-1); } catch (e) { [e instanceof RangeError, closed].join() }"
+        ),
+        "\"true,true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { counter(5).drop(NaN); } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+
+    // Returning from a helper closes the underlying iterator, and completes the helper.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            closed = false;
+            var helper = counter(5).filter(function () { return true; });
+            helper.next();
+            var result = helper.return();
+            [result.done, closed, helper.next().done].join()
+            "#
+        ),
+        "\"true,true,true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var innerClosed = false;
+            var inner = Object.create(Iterator.prototype);
+            inner.next = function () { return { value: 'inner', done: false }; };
+            inner.return = function () { innerClosed = true; return {}; };
+            closed = false;
+            var flat = counter(5).flatMap(function () { return inner; });
+            flat.next();
+            flat.return();
+            [innerClosed, closed].join()
+            "#
+        ),
+        "\"true,true\""
+    );
+}
+
+#[test]
+fn invalid_receivers() {
+    let mut context = Context::new();
+    forward(&mut context, COUNTER);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Iterator.prototype.map.call(1, function () {}); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var helper = counter(1).map(function (x) { return x; });
+            try {
+                helper.next.call(counter(1));
+            } catch (e) {
+                e instanceof TypeError
+            }
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var reentrant = counter(3).map(function () { return reentrant.next(); });
+            try {
+                reentrant.next();
+            } catch (e) {
+                e instanceof TypeError
+            }
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { counter(1).flatMap(function () { return 1; }).next(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
@@ -13,20 +13,22 @@
 //! [json]: https://www.json.org/json-en.html
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
-use std::collections::HashSet;
+use indexmap::IndexSet;
 
 use crate::{
     builtins::{
         string::{is_leading_surrogate, is_trailing_surrogate},
         BuiltIn,
     },
-    object::{JsObject, ObjectInitializer, RecursionLimiter},
-    property::{Attribute, PropertyKey, PropertyNameKind},
+    object::{JsObject, ObjectInitializer},
+    property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     value::IntegerOrInfinity,
     BoaProfiler, Context, JsResult, JsString, JsValue,
 };
-use serde_json::{self, Value as JSONValue};
+
+mod parser;
 
 #[cfg(test)]
 mod tests;
@@ -39,14 +41,14 @@ impl BuiltIn for Json {
     const NAME: &'static str = "JSON";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let to_string_tag = WellKnownSymbols::to_string_tag();
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let attribute = Attribute::readonly_builtin();
 
         let json_object = ObjectInitializer::new(context)
             .function(Self::parse, "parse", 2)
@@ -72,59 +74,133 @@ impl Json {
     /// [spec]: https://tc39.es/ecma262/#sec-json.parse
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
     pub(crate) fn parse(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let arg = args
-            .get(0)
-            .cloned()
-            .unwrap_or_else(JsValue::undefined)
-            .to_string(context)?;
-
-        match serde_json::from_str::<JSONValue>(&arg) {
-            Ok(json) => {
-                let j = JsValue::from_json(json, context);
-                match args.get(1) {
-                    Some(reviver) if reviver.is_function() => {
-                        let mut holder: JsValue = context.construct_object().into();
-                        holder.set_field("", j, true, context)?;
-                        Self::walk(reviver, context, &mut holder, &PropertyKey::from(""))
-                    }
-                    _ => Ok(j),
-                }
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Parse ! StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404.
+        //    Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
+        // 3. Let scriptString be the string-concatenation of "(", jsonString, and ");".
+        // 4. Let completion be the result of parsing and evaluating ! StringToCodePoints(scriptString)
+        //    as if it was the source text of an ECMAScript Script.
+        let unfiltered = parser::parse(&json_string, context)?;
+
+        // 5. If IsCallable(reviver) is true, then
+        match args.get_or_undefined(1).as_object() {
+            Some(reviver) if reviver.is_callable() => {
+                // a. Let root be ! OrdinaryObjectCreate(%Object.prototype%).
+                let root = context.construct_object();
+
+                // b. Let rootName be the empty String.
+                // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
+                root.create_data_property_or_throw("", unfiltered, context)
+                    .expect("CreateDataPropertyOrThrow should never fail here");
+
+                // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
+                Self::internalize_json_property(root, JsString::new(""), &reviver, context)
             }
-            Err(err) => context.throw_syntax_error(err.to_string()),
+            // 6. Else,
+            // a. Return unfiltered.
+            _ => Ok(unfiltered),
         }
     }
 
-    /// This is a translation of the [Polyfill implementation][polyfill]
+    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
     ///
-    /// This function recursively walks the structure, passing each key-value pair to the reviver function
-    /// for possible transformation.
+    /// Instead of recursive calls for the properties of the objects found, this walks them with
+    /// an explicit stack, so deeply nested values cannot overflow the native stack.
     ///
-    /// [polyfill]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
-    fn walk(
-        reviver: &JsValue,
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    fn internalize_json_property(
+        mut holder: JsObject,
+        mut name: JsString,
+        reviver: &JsObject,
         context: &mut Context,
-        holder: &mut JsValue,
-        key: &PropertyKey,
     ) -> JsResult<JsValue> {
-        let value = holder.get_field(key.clone(), context)?;
+        let mut stack: Vec<Internalized> = Vec::new();
+        loop {
+            // 1. Let val be ? Get(holder, name).
+            let val = holder.get(name.clone(), context)?;
+
+            // 2. If Type(val) is Object, then
+            let keys = if let Some(object) = val.as_object() {
+                context.check_nesting_depth(stack.len() + 1)?;
+
+                // a. Let isArray be ? IsArray(val).
+                // b. If isArray is true, then
+                if val.is_array(context)? {
+                    // i. Let I be 0.
+                    // ii. Let len be ? LengthOfArrayLike(val).
+                    // iii. Repeat, while I < len,
+                    //     1. Let prop be ! ToString(𝔽(I)).
+                    let len = object.length_of_array_like(context)?;
+                    (0..len).map(|i| i.to_string().into()).collect()
+                // c. Else,
+                } else {
+                    // i. Let keys be ? EnumerableOwnPropertyNames(val, key).
+                    // ii. For each String P of keys, do
+                    let keys =
+                        object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+                    keys.iter()
+                        .map(|key| {
+                            key.as_string()
+                                .cloned()
+                                .expect("EnumerableOwnPropertyNames returns string keys")
+                        })
+                        .collect()
+                }
+            } else {
+                Vec::new()
+            };
+            stack.push(Internalized {
+                holder,
+                name,
+                val,
+                keys,
+                index: 0,
+            });
+
+            // Internalize the next property of the innermost object, and call the reviver for the
+            // ones that have no properties left.
+            let (next_holder, next_name) = loop {
+                let last = stack
+                    .last_mut()
+                    .expect("there is a property being internalized");
+                if let Some(prop) = last.keys.get(last.index).cloned() {
+                    last.index += 1;
+                    let val = last.val.as_object().expect("only objects have keys");
+                    break (val, prop);
+                }
 
-        if let JsValue::Object(ref object) = value {
-            let keys: Vec<_> = object.borrow().properties().keys().collect();
+                // 3. Return ? Call(reviver, holder, « name, val »).
+                let done = stack.pop().expect("there is a property being internalized");
+                let new_element = reviver.call(
+                    &done.holder.clone().into(),
+                    &[done.name.clone().into(), done.val.clone()],
+                    context,
+                )?;
+
+                let parent = match stack.last() {
+                    Some(parent) => parent.val.as_object().expect("only objects have keys"),
+                    None => return Ok(new_element),
+                };
 
-            for key in keys {
-                let v = Self::walk(reviver, context, &mut value.clone(), &key);
-                match v {
-                    Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v, false, context)?;
-                    }
-                    Ok(_) => {
-                        value.remove_property(key);
-                    }
-                    Err(_v) => {}
+                // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                // 3. If newElement is undefined, then
+                if new_element.is_undefined() {
+                    // a. Perform ? val.[[Delete]](prop).
+                    parent.__delete__(&done.name.clone().into(), context)?;
+                // 4. Else,
+                } else {
+                    // a. Perform ? CreateDataProperty(val, prop, newElement).
+                    parent.create_data_property(done.name.clone(), new_element, context)?;
                 }
-            }
+            };
+            holder = next_holder;
+            name = next_name;
         }
-        context.call(reviver, holder, &[key.into(), value])
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -158,7 +234,7 @@ impl Json {
         let mut property_list = None;
         let mut replacer_function = None;
 
-        let replacer = args.get(1).cloned().unwrap_or_default();
+        let replacer = args.get_or_undefined(1).clone();
 
         // 4. If Type(replacer) is Object, then
         if let Some(replacer_obj) = replacer.as_object() {
@@ -170,9 +246,9 @@ impl Json {
             } else {
                 // i. Let isArray be ? IsArray(replacer).
                 // ii. If isArray is true, then
-                if replacer_obj.is_array() {
+                if JsValue::from(replacer_obj.clone()).is_array(context)? {
                     // 1. Set PropertyList to a new empty List.
-                    let mut property_set = HashSet::new();
+                    let mut property_set = IndexSet::new();
 
                     // 2. Let len be ? LengthOfArrayLike(replacer).
                     let len = replacer_obj.length_of_array_like(context)?;
@@ -214,7 +290,7 @@ impl Json {
             }
         }
 
-        let mut space = args.get(2).cloned().unwrap_or_default();
+        let mut space = args.get_or_undefined(2).clone();
 
         // 5. If Type(space) is Object, then
         if let Some(space_obj) = space.as_object() {
@@ -266,7 +342,7 @@ impl Json {
 
         // 10. Perform ! CreateDataPropertyOrThrow(wrapper, the empty String, value).
         wrapper
-            .create_data_property_or_throw("", args.get(0).cloned().unwrap_or_default(), context)
+            .create_data_property_or_throw("", args.get_or_undefined(0).clone(), context)
             .expect("CreateDataPropertyOrThrow should never fail here");
 
         // 11. Let state be the Record { [[ReplacerFunction]]: ReplacerFunction, [[Stack]]: stack, [[Indent]]: indent, [[Gap]]: gap, [[PropertyList]]: PropertyList }.
@@ -288,6 +364,10 @@ impl Json {
 
     /// `25.5.2.1 SerializeJSONProperty ( state, key, holder )`
     ///
+    /// Instead of calling each other recursively, `SerializeJSONArray` and `SerializeJSONObject`
+    /// push the array or object they serialize on a stack, and the properties of the innermost one
+    /// are serialized by this loop, so deeply nested values cannot overflow the native stack.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -298,6 +378,53 @@ impl Json {
         holder: JsObject,
         context: &mut Context,
     ) -> JsResult<Option<JsString>> {
+        let mut frames: Vec<Frame> = Vec::new();
+        let mut serialized = Self::serialize_json_value(state, key, holder, context)?;
+        loop {
+            match serialized {
+                Serialized::Array(value) => {
+                    frames.push(Self::serialize_json_array(state, value, context)?);
+                }
+                Serialized::Object(value) => {
+                    frames.push(Self::serialize_json_object(state, value, context)?);
+                }
+                Serialized::String(str_p) => match frames.last_mut() {
+                    Some(frame) => frame.push(state, str_p),
+                    None => return Ok(str_p),
+                },
+            }
+
+            // Serialize the next property of the innermost array or object, finishing the ones
+            // that have no properties left.
+            serialized = loop {
+                let frame = frames
+                    .last_mut()
+                    .expect("there is an array or object being serialized");
+                if let Some(key) = frame.next_key() {
+                    let holder = frame.value.clone();
+                    break Self::serialize_json_value(state, key, holder, context)?;
+                }
+
+                let r#final = frames
+                    .pop()
+                    .expect("there is an array or object being serialized")
+                    .finish(state);
+                match frames.last_mut() {
+                    Some(frame) => frame.push(state, Some(r#final)),
+                    None => return Ok(Some(r#final)),
+                }
+            };
+        }
+    }
+
+    /// Steps 1 to 12 of `SerializeJSONProperty`, leaving the arrays and objects to serialize to
+    /// the caller.
+    fn serialize_json_value(
+        state: &mut StateRecord,
+        key: JsString,
+        holder: JsObject,
+        context: &mut Context,
+    ) -> JsResult<Serialized> {
         // 1. Let value be ? Get(holder, key).
         let mut value = holder.get(key.clone(), context)?;
 
@@ -347,36 +474,36 @@ impl Json {
 
         // 5. If value is null, return "null".
         if value.is_null() {
-            return Ok(Some(JsString::new("null")));
+            return Ok(Serialized::String(Some(JsString::new("null"))));
         }
 
         // 6. If value is true, return "true".
         // 7. If value is false, return "false".
         if value.is_boolean() {
             return match value.to_boolean() {
-                true => Ok(Some(JsString::new("true"))),
-                false => Ok(Some(JsString::new("false"))),
+                true => Ok(Serialized::String(Some(JsString::new("true")))),
+                false => Ok(Serialized::String(Some(JsString::new("false")))),
             };
         }
 
         // 8. If Type(value) is String, return QuoteJSONString(value).
         if let Some(s) = value.as_string() {
-            return Ok(Some(Self::quote_json_string(s)));
+            return Ok(Serialized::String(Some(Self::quote_json_string(s))));
         }
 
         // 9. If Type(value) is Number, then
         if let Some(n) = value.as_number() {
             // a. If value is finite, return ! ToString(value).
             if n.is_finite() {
-                return Ok(Some(
+                return Ok(Serialized::String(Some(
                     value
                         .to_string(context)
                         .expect("ToString should never fail here"),
-                ));
+                )));
             }
 
             // b. Return "null".
-            return Ok(Some(JsString::new("null")));
+            return Ok(Serialized::String(Some(JsString::new("null"))));
         }
 
         // 10. If Type(value) is BigInt, throw a TypeError exception.
@@ -390,16 +517,16 @@ impl Json {
                 // a. Let isArray be ? IsArray(value).
                 // b. If isArray is true, return ? SerializeJSONArray(state, value).
                 // c. Return ? SerializeJSONObject(state, value).
-                return if obj.is_array() {
-                    Ok(Some(Self::serialize_json_array(state, obj, context)?))
+                return if JsValue::from(obj.clone()).is_array(context)? {
+                    Ok(Serialized::Array(obj.clone()))
                 } else {
-                    Ok(Some(Self::serialize_json_object(state, obj, context)?))
+                    Ok(Serialized::Object(obj.clone()))
                 };
             }
         }
 
         // 12. Return undefined.
-        Ok(None)
+        Ok(Serialized::String(None))
     }
 
     /// `25.5.2.2 QuoteJSONString ( value )`
@@ -452,6 +579,9 @@ impl Json {
 
     /// `25.5.2.4 SerializeJSONObject ( state, value )`
     ///
+    /// Returns the frame serializing the properties of the object, which are serialized and
+    /// joined by [`Frame::push`] and [`Frame::finish`].
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -460,24 +590,15 @@ impl Json {
         state: &mut StateRecord,
         value: JsObject,
         context: &mut Context,
-    ) -> JsResult<JsString> {
+    ) -> JsResult<Frame> {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
-        let limiter = RecursionLimiter::new(&value);
-        if limiter.live {
-            return Err(context.construct_type_error("cyclic object value"));
-        }
-
         // 2. Append value to state.[[Stack]].
-        state.stack.push(value.clone().into());
-
         // 3. Let stepback be state.[[Indent]].
-        let stepback = state.indent.clone();
-
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        state.indent = JsString::concat(&state.indent, &state.gap);
+        let stepback = state.enter(&value, context)?;
 
         // 5. If state.[[PropertyList]] is not undefined, then
-        let mut k = if let Some(p) = &state.property_list {
+        let k = if let Some(p) = &state.property_list {
             // a. Let K be state.[[PropertyList]].
             p.clone()
         // 6. Else,
@@ -488,86 +609,22 @@ impl Json {
             keys.iter().map(|v| v.to_string(context).unwrap()).collect()
         };
 
-        // Sort the property key list, because the internal property hashmap of objects does not sort the properties.
-        k.sort();
-
         // 7. Let partial be a new empty List.
-        let mut partial = Vec::new();
-
-        // 8. For each element P of K, do
-        for p in &k {
-            // a. Let strP be ? SerializeJSONProperty(state, P, value).
-            let str_p = Self::serialize_json_property(state, p.clone(), value.clone(), context)?;
-
-            // b. If strP is not undefined, then
-            if let Some(str_p) = str_p {
-                // i. Let member be QuoteJSONString(P).
-                // ii. Set member to the string-concatenation of member and ":".
-                // iii. If state.[[Gap]] is not the empty String, then
-                // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
-                // iv. Set member to the string-concatenation of member and strP.
-                let member = if state.gap.is_empty() {
-                    format!("{}:{}", Self::quote_json_string(p).as_str(), str_p.as_str())
-                } else {
-                    format!(
-                        "{}: {}",
-                        Self::quote_json_string(p).as_str(),
-                        str_p.as_str()
-                    )
-                };
-
-                // v. Append member to partial.
-                partial.push(member);
-            }
-        }
-
-        // 9. If partial is empty, then
-        let r#final = if partial.is_empty() {
-            // a. Let final be "{}".
-            JsString::new("{}")
-        // 10. Else,
-        } else {
-            // a. If state.[[Gap]] is the empty String, then
-            if state.gap.is_empty() {
-                // i. Let properties be the String value formed by concatenating all the element Strings of partial
-                //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
-                //    A comma is not inserted either before the first String or after the last String.
-                // ii. Let final be the string-concatenation of "{", properties, and "}".
-                format!("{{{}}}", partial.join(",")).into()
-            // b. Else,
-            } else {
-                // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
-                //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let separator = format!(",{}{}", '\u{A}', state.indent.as_str());
-                // ii. Let properties be the String value formed by concatenating all the element Strings of partial
-                //     with each adjacent pair of Strings separated with separator.
-                //     The separator String is not inserted either before the first String or after the last String.
-                let properties = partial.join(&separator);
-                // iii. Let final be the string-concatenation of "{", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "}".
-                format!(
-                    "{{{}{}{}{}{}}}",
-                    '\u{A}',
-                    state.indent.as_str(),
-                    &properties,
-                    '\u{A}',
-                    stepback.as_str()
-                )
-                .into()
-            }
-        };
-
-        // 11. Remove the last element of state.[[Stack]].
-        state.stack.pop();
-
-        // 12. Set state.[[Indent]] to stepback.
-        state.indent = stepback;
-
-        // 13. Return final.
-        Ok(r#final)
+        Ok(Frame {
+            value,
+            len: k.len(),
+            keys: Some(k),
+            index: 0,
+            stepback,
+            partial: Vec::new(),
+        })
     }
 
     /// `25.5.2.5 SerializeJSONArray ( state, value )`
     ///
+    /// Returns the frame serializing the elements of the array, which are serialized and joined
+    /// by [`Frame::push`] and [`Frame::finish`].
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -576,59 +633,171 @@ impl Json {
         state: &mut StateRecord,
         value: JsObject,
         context: &mut Context,
-    ) -> JsResult<JsString> {
+    ) -> JsResult<Frame> {
+        // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
+        // 2. Append value to state.[[Stack]].
+        // 3. Let stepback be state.[[Indent]].
+        // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
+        let stepback = state.enter(&value, context)?;
+
+        // 5. Let partial be a new empty List.
+        // 6. Let len be ? LengthOfArrayLike(value).
+        let len = value.length_of_array_like(context)?;
+
+        // 7. Let index be 0.
+        Ok(Frame {
+            value,
+            keys: None,
+            len,
+            index: 0,
+            stepback,
+            partial: Vec::new(),
+        })
+    }
+}
+
+/// A property being internalized by `InternalizeJSONProperty`.
+struct Internalized {
+    holder: JsObject,
+    name: JsString,
+    val: JsValue,
+    /// The keys of the properties of `val`, if it is an object.
+    keys: Vec<JsString>,
+    /// The index of the next property of `val` to internalize.
+    index: usize,
+}
+
+struct StateRecord {
+    replacer_function: Option<JsObject>,
+    stack: Vec<JsObject>,
+    indent: JsString,
+    gap: JsString,
+    property_list: Option<Vec<JsString>>,
+}
+
+impl StateRecord {
+    /// Steps 1 to 4 of `SerializeJSONObject` and `SerializeJSONArray`, returning the stepback.
+    fn enter(&mut self, value: &JsObject, context: &mut Context) -> JsResult<JsString> {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
-        let limiter = RecursionLimiter::new(&value);
-        if limiter.live {
+        if self
+            .stack
+            .iter()
+            .any(|object| JsObject::equals(object, value))
+        {
             return Err(context.construct_type_error("cyclic object value"));
         }
+        context.check_nesting_depth(self.stack.len() + 1)?;
 
         // 2. Append value to state.[[Stack]].
-        state.stack.push(value.clone().into());
+        self.stack.push(value.clone());
 
         // 3. Let stepback be state.[[Indent]].
-        let stepback = state.indent.clone();
+        let stepback = self.indent.clone();
 
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        state.indent = JsString::concat(&state.indent, &state.gap);
+        self.indent = JsString::concat(&self.indent, &self.gap);
 
-        // 5. Let partial be a new empty List.
-        let mut partial = Vec::new();
+        Ok(stepback)
+    }
+}
 
-        // 6. Let len be ? LengthOfArrayLike(value).
-        let len = value.length_of_array_like(context)?;
+/// The result of steps 1 to 12 of `SerializeJSONProperty`.
+enum Serialized {
+    /// The string of the property, if it is serialized.
+    String(Option<JsString>),
+    /// An array, serialized by `SerializeJSONArray`.
+    Array(JsObject),
+    /// An object, serialized by `SerializeJSONObject`.
+    Object(JsObject),
+}
 
-        // 7. Let index be 0.
-        let mut index = 0;
-
-        // 8. Repeat, while index < len,
-        while index < len {
-            // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
-            let str_p = Self::serialize_json_property(
-                state,
-                index.to_string().into(),
-                value.clone(),
-                context,
-            )?;
-
-            // b. If strP is undefined, then
-            if let Some(str_p) = str_p {
-                // i. Append strP to partial.
-                partial.push(str_p)
-            // c. Else,
-            } else {
-                // i. Append "null" to partial.
-                partial.push("null".into())
-            }
+/// The state of `SerializeJSONObject` or `SerializeJSONArray` while the properties of its value
+/// are serialized.
+struct Frame {
+    value: JsObject,
+    /// The keys of the properties of an object, `None` for an array.
+    keys: Option<Vec<JsString>>,
+    len: usize,
+    /// The index of the next property to serialize.
+    index: usize,
+    stepback: JsString,
+    partial: Vec<String>,
+}
+
+impl Frame {
+    /// Returns the key of the next property to serialize, if any.
+    fn next_key(&mut self) -> Option<JsString> {
+        if self.index >= self.len {
+            return None;
+        }
+        let key = match &self.keys {
+            // SerializeJSONObject
+            // 8. For each element P of K, do
+            Some(keys) => keys[self.index].clone(),
+            // SerializeJSONArray
+            // 8. Repeat, while index < len,
+            None => self.index.to_string().into(),
+        };
+        self.index += 1;
+        Some(key)
+    }
 
-            // d. Set index to index + 1.
-            index += 1;
+    /// Adds the result of `SerializeJSONProperty` for the last key returned by
+    /// [`next_key`](Self::next_key) to partial.
+    fn push(&mut self, state: &StateRecord, str_p: Option<JsString>) {
+        match &self.keys {
+            // SerializeJSONObject
+            Some(keys) => {
+                // b. If strP is not undefined, then
+                if let Some(str_p) = str_p {
+                    let p = &keys[self.index - 1];
+                    // i. Let member be QuoteJSONString(P).
+                    // ii. Set member to the string-concatenation of member and ":".
+                    // iii. If state.[[Gap]] is not the empty String, then
+                    // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
+                    // iv. Set member to the string-concatenation of member and strP.
+                    let member = if state.gap.is_empty() {
+                        format!("{}:{}", Json::quote_json_string(p).as_str(), str_p.as_str())
+                    } else {
+                        format!(
+                            "{}: {}",
+                            Json::quote_json_string(p).as_str(),
+                            str_p.as_str()
+                        )
+                    };
+
+                    // v. Append member to partial.
+                    self.partial.push(member);
+                }
+            }
+            // SerializeJSONArray
+            None => {
+                // b. If strP is undefined, then
+                if let Some(str_p) = str_p {
+                    // i. Append strP to partial.
+                    self.partial.push(str_p.as_str().to_owned())
+                // c. Else,
+                } else {
+                    // i. Append "null" to partial.
+                    self.partial.push("null".to_owned())
+                }
+            }
         }
+    }
+
+    /// Steps 9 to 13 of `SerializeJSONObject` and `SerializeJSONArray`, which only differ in the
+    /// brackets enclosing the partial strings.
+    fn finish(self, state: &mut StateRecord) -> JsString {
+        let (open, close) = if self.keys.is_some() {
+            ('{', '}')
+        } else {
+            ('[', ']')
+        };
 
         // 9. If partial is empty, then
-        let r#final = if partial.is_empty() {
-            // a. Let final be "[]".
-            JsString::from("[]")
+        let r#final = if self.partial.is_empty() {
+            // a. Let final be "{}".
+            format!("{}{}", open, close).into()
         // 10. Else,
         } else {
             // a. If state.[[Gap]] is the empty String, then
@@ -636,8 +805,8 @@ impl Json {
                 // i. Let properties be the String value formed by concatenating all the element Strings of partial
                 //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
                 //    A comma is not inserted either before the first String or after the last String.
-                // ii. Let final be the string-concatenation of "[", properties, and "]".
-                format!("[{}]", partial.join(",")).into()
+                // ii. Let final be the string-concatenation of "{", properties, and "}".
+                format!("{}{}{}", open, self.partial.join(","), close).into()
             // b. Else,
             } else {
                 // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
@@ -646,15 +815,17 @@ impl Json {
                 // ii. Let properties be the String value formed by concatenating all the element Strings of partial
                 //     with each adjacent pair of Strings separated with separator.
                 //     The separator String is not inserted either before the first String or after the last String.
-                let properties = partial.join(&separator);
-                // iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
+                let properties = self.partial.join(&separator);
+                // iii. Let final be the string-concatenation of "{", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "}".
                 format!(
-                    "[{}{}{}{}{}]",
+                    "{}{}{}{}{}{}{}",
+                    open,
                     '\u{A}',
                     state.indent.as_str(),
                     &properties,
                     '\u{A}',
-                    stepback.as_str()
+                    self.stepback.as_str(),
+                    close
                 )
                 .into()
             }
@@ -664,17 +835,9 @@ impl Json {
         state.stack.pop();
 
         // 12. Set state.[[Indent]] to stepback.
-        state.indent = stepback;
+        state.indent = self.stepback;
 
         // 13. Return final.
-        Ok(r#final)
+        r#final
     }
 }
-
-struct StateRecord {
-    replacer_function: Option<JsObject>,
-    stack: Vec<JsValue>,
-    indent: JsString,
-    gap: JsString,
-    property_list: Option<Vec<JsString>>,
-}
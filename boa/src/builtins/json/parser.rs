@@ -0,0 +1,315 @@
+//! The parser of the JSON text read by `JSON.parse`.
+//!
+//! The parser builds the JavaScript values directly from the text. Nested arrays and objects are
+//! kept on an explicit stack instead of the native one, so deeply nested text cannot overflow it:
+//! nesting past [`Context::max_nesting_depth`] throws a `RangeError`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [JSON specification][json]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-json.parse
+//! [json]: https://www.json.org/json-en.html
+
+use crate::{
+    builtins::{
+        string::{is_leading_surrogate, is_trailing_surrogate},
+        Array,
+    },
+    error::JsErrorCode,
+    object::JsObject,
+    Context, JsResult, JsString, JsValue,
+};
+
+/// An array or an object whose members are being parsed.
+enum Container {
+    /// The elements of an array, parsed so far.
+    Array(Vec<JsValue>),
+    /// An object, and the key of the member whose value is being parsed.
+    Object(JsObject, JsString),
+}
+
+/// Parses a JSON text into the value it describes.
+pub(super) fn parse(text: &str, context: &mut Context) -> JsResult<JsValue> {
+    let mut parser = Parser {
+        input: text.as_bytes(),
+        position: 0,
+    };
+    let mut stack = Vec::new();
+
+    loop {
+        parser.skip_whitespace();
+        let mut value = match parser.peek() {
+            Some(b'[') => {
+                parser.position += 1;
+                parser.skip_whitespace();
+                if parser.peek() == Some(b']') {
+                    parser.position += 1;
+                    Array::array_create(0, None, context)?.into()
+                } else {
+                    context.check_nesting_depth(stack.len() + 1)?;
+                    stack.push(Container::Array(Vec::new()));
+                    continue;
+                }
+            }
+            Some(b'{') => {
+                parser.position += 1;
+                parser.skip_whitespace();
+                let object = context.construct_object();
+                if parser.peek() == Some(b'}') {
+                    parser.position += 1;
+                    object.into()
+                } else {
+                    context.check_nesting_depth(stack.len() + 1)?;
+                    let key = parser.parse_key(context)?;
+                    stack.push(Container::Object(object, key));
+                    continue;
+                }
+            }
+            Some(b'"') => parser.parse_string(context)?.into(),
+            Some(b't') => parser.parse_literal("true", true.into(), context)?,
+            Some(b'f') => parser.parse_literal("false", false.into(), context)?,
+            Some(b'n') => parser.parse_literal("null", JsValue::null(), context)?,
+            Some(b'-' | b'0'..=b'9') => parser.parse_number(context)?,
+            _ => return Err(parser.unexpected(context)),
+        };
+
+        // Add the parsed value to the containers, closing the ones that end after it.
+        loop {
+            parser.skip_whitespace();
+            match stack.last_mut() {
+                None if parser.peek().is_none() => return Ok(value),
+                None => return Err(parser.unexpected(context)),
+                Some(Container::Array(elements)) => {
+                    elements.push(value);
+                    match parser.peek() {
+                        Some(b',') => {
+                            parser.position += 1;
+                            break;
+                        }
+                        Some(b']') => {
+                            parser.position += 1;
+                            let elements = match stack.pop() {
+                                Some(Container::Array(elements)) => elements,
+                                _ => unreachable!("the last container is an array"),
+                            };
+                            value = Array::create_array_from_list(elements, context).into();
+                        }
+                        _ => return Err(parser.unexpected(context)),
+                    }
+                }
+                Some(Container::Object(object, key)) => {
+                    object.create_data_property_or_throw(key.clone(), value, context)?;
+                    match parser.peek() {
+                        Some(b',') => {
+                            parser.position += 1;
+                            parser.skip_whitespace();
+                            *key = parser.parse_key(context)?;
+                            break;
+                        }
+                        Some(b'}') => {
+                            parser.position += 1;
+                            value = object.clone().into();
+                            stack.pop();
+                        }
+                        _ => return Err(parser.unexpected(context)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The position of a parse in a JSON text.
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    /// Constructs the `SyntaxError` thrown for the byte at the current position.
+    fn unexpected(&self, context: &mut Context) -> JsValue {
+        let token = std::str::from_utf8(&self.input[self.position..])
+            .ok()
+            .and_then(|rest| rest.chars().next());
+        match token {
+            Some(token) => context.construct_syntax_error(format!(
+                "Unexpected token '{}' in JSON at position {}",
+                token, self.position
+            )),
+            None => context.construct_coded_error(
+                JsErrorCode::JSON_ABRUPT_END,
+                "Unexpected end of JSON input",
+            ),
+        }
+    }
+
+    fn parse_literal(
+        &mut self,
+        literal: &str,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        for expected in literal.bytes() {
+            if self.peek() != Some(expected) {
+                return Err(self.unexpected(context));
+            }
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Parses the key of an object member, and the colon following it.
+    fn parse_key(&mut self, context: &mut Context) -> JsResult<JsString> {
+        if self.peek() != Some(b'"') {
+            return Err(self.unexpected(context));
+        }
+        let key = self.parse_string(context)?;
+        self.skip_whitespace();
+        if self.peek() != Some(b':') {
+            return Err(self.unexpected(context));
+        }
+        self.position += 1;
+        Ok(key)
+    }
+
+    fn parse_string(&mut self, context: &mut Context) -> JsResult<JsString> {
+        // Skip the opening quotation mark.
+        self.position += 1;
+
+        let mut string = String::new();
+        loop {
+            let start = self.position;
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+            string.push_str(
+                std::str::from_utf8(&self.input[start..self.position])
+                    .expect("the input is a string, and only splits at ASCII bytes"),
+            );
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(string.into());
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{C}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.position += 1;
+                            let unit = self.parse_code_unit(context)?;
+                            string.push(self.parse_code_point(unit, context)?);
+                            continue;
+                        }
+                        _ => return Err(self.unexpected(context)),
+                    };
+                    self.position += 1;
+                    string.push(escaped);
+                }
+                _ => return Err(self.unexpected(context)),
+            }
+        }
+    }
+
+    /// Parses the four hexadecimal digits of a `\u` escape.
+    fn parse_code_unit(&mut self, context: &mut Context) -> JsResult<u16> {
+        let mut unit = 0;
+        for _ in 0..4 {
+            let digit = match self.peek().and_then(|byte| char::from(byte).to_digit(16)) {
+                Some(digit) => digit as u16,
+                None => return Err(self.unexpected(context)),
+            };
+            unit = unit * 16 + digit;
+            self.position += 1;
+        }
+        Ok(unit)
+    }
+
+    /// Combines a leading surrogate with the `\u` escape of a trailing surrogate following it.
+    ///
+    /// Strings cannot hold lone surrogates, which are replaced with U+FFFD.
+    fn parse_code_point(&mut self, unit: u16, context: &mut Context) -> JsResult<char> {
+        if is_leading_surrogate(unit) && self.input[self.position..].starts_with(b"\\u") {
+            let position = self.position;
+            self.position += 2;
+            let trailing = self.parse_code_unit(context)?;
+            if is_trailing_surrogate(trailing) {
+                let code_point =
+                    0x10000 + ((u32::from(unit) - 0xD800) << 10) + (u32::from(trailing) - 0xDC00);
+                return Ok(char::from_u32(code_point).expect("surrogate pairs are code points"));
+            }
+            self.position = position;
+        }
+        Ok(char::from_u32(u32::from(unit)).unwrap_or('\u{FFFD}'))
+    }
+
+    fn parse_number(&mut self, context: &mut Context) -> JsResult<JsValue> {
+        let start = self.position;
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.unexpected(context)),
+        }
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.unexpected(context));
+            }
+            self.skip_digits();
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.unexpected(context));
+            }
+            self.skip_digits();
+        }
+
+        let number: f64 = std::str::from_utf8(&self.input[start..self.position])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .expect("the number has the syntax of a Rust float");
+        if number.fract() == 0.0
+            && number.abs() <= f64::from(i32::MAX)
+            && !(number == 0.0 && number.is_sign_negative())
+        {
+            Ok((number as i32).into())
+        } else {
+            Ok(number.into())
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+    }
+}
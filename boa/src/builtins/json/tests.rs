@@ -336,7 +336,7 @@ fn json_parse_array_with_reviver() {
             if (typeof v == 'number') {
                 return v * 2;
             } else {
-                v
+                return v;
         }})"#,
     )
     .unwrap();
@@ -464,3 +464,110 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_parse_syntax() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Object.keys(JSON.parse('{"b": 1, "a": 2, "b": 3}')).join()"#
+        ),
+        r#""b,a""#
+    );
+    assert_eq!(
+        forward(&mut context, r#"JSON.parse('{"b": 1, "a": 2, "b": 3}').b"#),
+        "3"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.parse(' [1.5e2, -0, "\\u0041\\ud83e\\udd80\\n", true, null] ').map(String).join()"#
+        ),
+        "\"150,0,A🦀\n,true,null\""
+    );
+    assert_eq!(forward(&mut context, "1 / JSON.parse('-0')"), "-Infinity");
+    assert_eq!(
+        forward(&mut context, r#"JSON.parse('{"__proto__": 1}').__proto__"#),
+        "1"
+    );
+
+    for text in &[
+        "''",
+        "'[1,]'",
+        "'{\"a\" 1}'",
+        "'01'",
+        "'1.'",
+        "'[1] 2'",
+        "'\"\\\\x\"'",
+        "'tru'",
+        "'{a: 1}'",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!(
+                    "try {{ JSON.parse({}); }} catch (e) {{ e instanceof SyntaxError }}",
+                    text
+                )
+            ),
+            "true",
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+fn json_deeply_nested_values() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var deep = [0];
+        for (var i = 0; i < 2000; i++) {
+            deep = { a: [deep] };
+        }
+        var text = JSON.stringify(deep);
+        "#,
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(JSON.parse(text, function (k, v) { return v; })) === text"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { JSON.parse('['.repeat(1000000)); } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+
+    context.set_max_nesting_depth(100);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { JSON.stringify(deep); } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { JSON.parse(text); } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(JSON.parse('[[[[1]]]]', function (k, v) { return v; }))"
+        ),
+        "\"[[[[1]]]]\""
+    );
+}
@@ -1,8 +1,10 @@
 use crate::{
     builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, JsValue},
+    error::JsErrorCode,
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
+    teardown::Contents,
     BoaProfiler, Context, JsResult,
 };
 use gc::{Finalize, Trace};
@@ -25,6 +27,11 @@ pub struct MapIterator {
 impl MapIterator {
     pub(crate) const NAME: &'static str = "MapIterator";
 
+    /// Queues the iterated map to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_value(&self.iterated_map);
+    }
+
     /// Constructs a new `MapIterator`, that will iterate over `map`, starting at index 0
     fn new(map: JsValue, kind: PropertyNameKind, context: &mut Context) -> JsResult<Self> {
         let lock = Map::lock(&map, context)?;
@@ -120,10 +127,12 @@ impl MapIterator {
                             }
                         }
                     } else {
-                        return Err(context.construct_type_error("'this' is not a Map"));
+                        return Err(context
+                            .construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"));
                     }
                 } else {
-                    return Err(context.construct_type_error("'this' is not a Map"));
+                    return Err(context
+                        .construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"));
                 }
 
                 map_iterator.iterated_map = JsValue::undefined();
@@ -13,10 +13,15 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{
+        iterable::{get_iterator, group_by, KeyCoercion},
+        Array, BuiltIn,
+    },
+    error::JsErrorCode,
     object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     BoaProfiler, Context, JsResult, JsValue,
 };
 use ordered_map::OrderedMap;
@@ -31,13 +36,13 @@ pub mod ordered_map;
 mod tests;
 
 #[derive(Debug, Clone)]
-pub(crate) struct Map(OrderedMap<JsValue>);
+pub(crate) struct Map;
 
 impl BuiltIn for Map {
     const NAME: &'static str = "Map";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -70,21 +75,10 @@ impl BuiltIn for Map {
             None,
             Attribute::CONFIGURABLE,
         )
-        .property(
-            "entries",
-            entries_function.clone(),
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
-        .property(
-            to_string_tag,
-            Self::NAME,
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
-        .property(
-            iterator_symbol,
-            entries_function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
+        .static_method(Self::group_by, "groupBy", 2)
+        .property("entries", entries_function.clone(), Attribute::builtin())
+        .property(to_string_tag, Self::NAME, Attribute::readonly_builtin())
+        .property(iterator_symbol, entries_function, Attribute::builtin())
         .method(Self::keys, "keys", 0)
         .method(Self::set, "set", 2)
         .method(Self::delete, "delete", 1)
@@ -127,49 +121,70 @@ impl Map {
         obj.set_prototype_instance(prototype.into());
         let this = JsValue::new(obj);
 
-        // add our arguments in
-        let data = match args.len() {
-            0 => OrderedMap::new(),
-            _ => match &args[0] {
-                JsValue::Object(object) => {
-                    let object = object.borrow();
-                    if let Some(map) = object.as_map_ref().cloned() {
-                        map
-                    } else if object.is_array() {
-                        let mut map = OrderedMap::new();
-                        let len = args[0].get_field("length", context)?.to_integer(context)? as i32;
-                        for i in 0..len {
-                            let val = &args[0].get_field(i, context)?;
-                            let (key, value) =
-                                Self::get_key_value(val, context)?.ok_or_else(|| {
-                                    context.construct_type_error(
-                                        "iterable for Map should have array-like objects",
-                                    )
-                                })?;
-                            map.insert(key, value);
-                        }
-                        map
-                    } else {
-                        return Err(context.construct_type_error(
-                            "iterable for Map should have array-like objects",
-                        ));
-                    }
-                }
-                _ => {
-                    return Err(context
-                        .construct_type_error("iterable for Map should have array-like objects"))
-                }
-            },
-        };
-
-        // finally create size property
-        Self::set_size(&this, data.len());
-
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::map(data));
+        this.set_data(ObjectData::map(OrderedMap::new()));
+        Self::set_size(&this, 0);
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = args.get_or_undefined(0).clone();
+        if iterable.is_null_or_undefined() {
+            return Ok(this);
+        }
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = this.get_field("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&this, iterable, &adder, context)
+    }
+
+    /// `Map.groupBy( items, callbackfn )`
+    ///
+    /// This method groups the values of an iterable in the arrays of a new `Map`, under the keys
+    /// the callback returns for them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    pub(crate) fn group_by(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let groups be ? GroupBy(items, callbackfn, zero).
+        let groups = group_by(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            KeyCoercion::Zero,
+            context,
+        )?;
+
+        // 2. Let map be ! Construct(%Map%).
+        let map = context.construct_object();
+        map.set_prototype_instance(context.standard_objects().map_object().prototype().into());
+        let mut data = OrderedMap::new();
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+            // c. Append entry to map.[[MapData]].
+            data.insert(key, elements.into());
+        }
+
+        let size = data.len();
+        let map = JsValue::new(map);
+        map.set_data(ObjectData::map(data));
+        Self::set_size(&map, size);
 
-        Ok(this)
+        // 4. Return map.
+        Ok(map)
     }
 
     /// `get Map [ @@species ]`
@@ -245,21 +260,30 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let (key, value) = match args.len() {
+        let (mut key, value) = match args.len() {
             0 => (JsValue::undefined(), JsValue::undefined()),
             1 => (args[0].clone(), JsValue::undefined()),
             _ => (args[0].clone(), args[1].clone()),
         };
+        // Keys are compared with SameValueZero, so `-0` is stored as `+0`. The comparison matches
+        // both zeroes.
+        if key.as_number().map(|n| n == 0.0).unwrap_or(false) {
+            key = JsValue::Integer(0);
+        }
 
         let size = if let Some(object) = this.as_object() {
             if let Some(map) = object.borrow_mut().as_map_mut() {
                 map.insert(key, value);
                 map.len()
             } else {
-                return Err(context.construct_type_error("'this' is not a Map"));
+                return Err(
+                    context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map")
+                );
             }
         } else {
-            return Err(context.construct_type_error("'this' is not a Map"));
+            return Err(
+                context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map")
+            );
         };
 
         Self::set_size(this, size);
@@ -281,17 +305,21 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let key = args.get(0).cloned().unwrap_or_default();
+        let key = args.get_or_undefined(0).clone();
 
         let (deleted, size) = if let Some(object) = this.as_object() {
             if let Some(map) = object.borrow_mut().as_map_mut() {
                 let deleted = map.remove(&key).is_some();
                 (deleted, map.len())
             } else {
-                return Err(context.construct_type_error("'this' is not a Map"));
+                return Err(
+                    context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map")
+                );
             }
         } else {
-            return Err(context.construct_type_error("'this' is not a Map"));
+            return Err(
+                context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map")
+            );
         };
         Self::set_size(this, size);
         Ok(deleted.into())
@@ -312,7 +340,7 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let key = args.get(0).cloned().unwrap_or_default();
+        let key = args.get_or_undefined(0).clone();
 
         if let JsValue::Object(ref object) = this {
             let object = object.borrow();
@@ -325,7 +353,7 @@ impl Map {
             }
         }
 
-        Err(context.construct_type_error("'this' is not a Map"))
+        Err(context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"))
     }
 
     /// `Map.prototype.clear( )`
@@ -361,7 +389,7 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let key = args.get(0).cloned().unwrap_or_default();
+        let key = args.get_or_undefined(0).clone();
 
         if let JsValue::Object(ref object) = this {
             let object = object.borrow();
@@ -370,7 +398,7 @@ impl Map {
             }
         }
 
-        Err(context.construct_type_error("'this' is not a Map"))
+        Err(context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"))
     }
 
     /// `Map.prototype.forEach( callbackFn [ , thisArg ] )`
@@ -393,7 +421,7 @@ impl Map {
         }
 
         let callback_arg = &args[0];
-        let this_arg = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let this_arg = args.get_or_undefined(1).clone();
 
         let mut index = 0;
 
@@ -409,10 +437,11 @@ impl Map {
                         None
                     }
                 } else {
-                    return context.throw_type_error("'this' is not a Map");
+                    return context
+                        .throw_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map");
                 }
             } else {
-                return context.throw_type_error("'this' is not a Map");
+                return context.throw_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map");
             };
 
             if let Some(arguments) = arguments {
@@ -434,10 +463,10 @@ impl Map {
             if let Some(map) = object.as_map_ref() {
                 Ok(map.full_len())
             } else {
-                Err(context.construct_type_error("'this' is not a Map"))
+                Err(context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"))
             }
         } else {
-            Err(context.construct_type_error("'this' is not a Map"))
+            Err(context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"))
         }
     }
 
@@ -448,10 +477,10 @@ impl Map {
             if let Some(map) = map.as_map_mut() {
                 Ok(map.lock(object.clone()))
             } else {
-                Err(context.construct_type_error("'this' is not a Map"))
+                Err(context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"))
             }
         } else {
-            Err(context.construct_type_error("'this' is not a Map"))
+            Err(context.construct_coded_error(JsErrorCode::NOT_A_MAP, "'this' is not a Map"))
         }
     }
 
@@ -472,26 +501,59 @@ impl Map {
     ) -> JsResult<JsValue> {
         MapIterator::create_map_iterator(context, this.clone(), PropertyNameKind::Value)
     }
+}
 
-    /// Helper function to get a key-value pair from an array.
-    fn get_key_value(
-        value: &JsValue,
-        context: &mut Context,
-    ) -> JsResult<Option<(JsValue, JsValue)>> {
-        if let JsValue::Object(object) = value {
-            if object.is_array() {
-                let (key, value) =
-                    match value.get_field("length", context)?.as_number().unwrap() as i32 {
-                        0 => (JsValue::undefined(), JsValue::undefined()),
-                        1 => (value.get_field("0", context)?, JsValue::undefined()),
-                        _ => (
-                            value.get_field("0", context)?,
-                            value.get_field("1", context)?,
-                        ),
-                    };
-                return Ok(Some((key, value)));
-            }
+/// `AddEntriesFromIterable ( target, iterable, adder )`
+///
+/// Calls `adder` on `target` with the key and value of every entry produced by `iterable`,
+/// closing the iterator if an entry is not an object or if `adder` throws.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-add-entries-from-iterable
+pub(crate) fn add_entries_from_iterable(
+    target: &JsValue,
+    iterable: JsValue,
+    adder: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If IsCallable(adder) is false, throw a TypeError exception.
+    if !adder.is_function() {
+        return context.throw_type_error("'set' of 'newTarget' is not a function");
+    }
+
+    // 2. Let iteratorRecord be ? GetIterator(iterable).
+    let iterator_record = get_iterator(context, iterable)?;
+
+    // 3. Repeat,
+    loop {
+        // a. Let next be ? IteratorStep(iteratorRecord).
+        // b. If next is false, return target.
+        // c. Let nextItem be ? IteratorValue(next).
+        let item = match iterator_record.step(context)? {
+            Some(item) => item,
+            None => return Ok(target.clone()),
+        };
+
+        // d. If Type(nextItem) is not Object, then
+        if !item.is_object() {
+            // i. Let error be ThrowCompletion(a newly created TypeError object).
+            // ii. Return ? IteratorClose(iteratorRecord, error).
+            let error = context.construct_type_error("iterator value is not an entry object");
+            return iterator_record.close(Err(error), context);
+        }
+
+        // e. Let k be Get(nextItem, "0").
+        // g. Let v be Get(nextItem, "1").
+        // i. Let status be Call(adder, target, « k, v »).
+        // f, h, j. IfAbruptCloseIterator(status, iteratorRecord).
+        let entry = item
+            .get_field(0, context)
+            .and_then(|key| Ok((key, item.get_field(1, context)?)))
+            .and_then(|(key, value)| context.call(adder, target, &[key, value]));
+        if let Err(error) = entry {
+            return iterator_record.close(Err(error), context);
         }
-        Ok(None)
     }
 }
@@ -211,8 +211,12 @@ impl Clone for MapLock {
 
 impl Finalize for MapLock {
     fn finalize(&self) {
-        let mut map = self.0.borrow_mut();
-        let map = map.as_map_mut().expect("MapLock does not point to a map");
-        map.unlock();
+        // The collector can finalize an unreachable lock while its map is borrowed, like when
+        // an iterator result is allocated during an iteration over the map. The map then just
+        // keeps its empty entries, which does not change its observable state.
+        if let Ok(mut map) = self.0.try_borrow_mut() {
+            let map = map.as_map_mut().expect("MapLock does not point to a map");
+            map.unlock();
+        }
     }
 }
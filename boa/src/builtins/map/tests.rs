@@ -404,3 +404,77 @@ fn for_of_delete() {
     assert_eq!(forward(&mut context, "result[3][0]"), "3");
     assert_eq!(forward(&mut context, "result[3][1]"), "\"d\"");
 }
+
+#[test]
+fn group_by() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var zero = {};
+        var closed = false;
+        var groups = Map.groupBy([-0, 0, 1, zero, zero], function (x) {
+            return typeof x === 'number' ? x : zero;
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(groups) === Map.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "groups.size"), "3");
+    assert_eq!(
+        forward(&mut context, "Object.is(groups.keys().next().value, 0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "groups.get(0).length"), "2");
+    assert_eq!(forward(&mut context, "groups.get(zero).length"), "2");
+
+    // Errors thrown by the callback close the iterator.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var iterable = {};
+            iterable[Symbol.iterator] = function () {
+                return {
+                    next: function () { return { value: 1, done: false }; },
+                    return: function () { closed = true; return {}; },
+                };
+            };
+            try {
+                Map.groupBy(iterable, function () { throw 'failed'; });
+            } catch (e) {
+                [e, closed].join()
+            }
+            "#
+        ),
+        "\"failed,true\""
+    );
+}
+
+#[test]
+fn same_value_zero_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[-0, "zero"]]);
+        map.set(NaN, "nan");
+        let set = new Set([0 / 0, NaN, -(0 / 0), Math.sqrt(-1)]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(0)"), "\"zero\"");
+    assert_eq!(forward(&mut context, "map.get(0 / 0)"), "\"nan\"");
+    assert_eq!(forward(&mut context, "map.has(-(0 / 0))"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.is(map.set(-0, 1).keys().next().value, 0)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "map.size"), "2");
+    assert_eq!(forward(&mut context, "set.size"), "1");
+}
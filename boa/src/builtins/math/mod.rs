@@ -27,13 +27,13 @@ impl BuiltIn for Math {
     const NAME: &'static str = "Math";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::constant();
         let string_tag = WellKnownSymbols::to_string_tag();
         let object = ObjectInitializer::new(context)
             .property("E", std::f64::consts::E, attribute)
@@ -62,7 +62,7 @@ impl BuiltIn for Math {
             .function(Self::floor, "floor", 1)
             .function(Self::fround, "fround", 1)
             .function(Self::hypot, "hypot", 2)
-            .function(Self::imul, "imul", 1)
+            .function(Self::imul, "imul", 2)
             .function(Self::log, "log", 1)
             .function(Self::log1p, "log1p", 1)
             .function(Self::log10, "log10", 1)
@@ -79,11 +79,7 @@ impl BuiltIn for Math {
             .function(Self::tan, "tan", 1)
             .function(Self::tanh, "tanh", 1)
             .function(Self::trunc, "trunc", 1)
-            .property(
-                string_tag,
-                Math::NAME,
-                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
+            .property(string_tag, Math::NAME, Attribute::readonly_builtin())
             .build();
 
         (Self::NAME, object.into(), Self::attribute())
@@ -4,38 +4,77 @@
 #![allow(clippy::unnecessary_wraps)]
 
 pub mod array;
+pub mod array_buffer;
+pub mod async_function;
+pub mod async_generator;
+pub mod async_generator_function;
+pub mod atomics;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
+pub mod dataview;
 pub mod date;
 pub mod error;
+pub mod eval;
+pub mod finalization_registry;
 pub mod function;
+pub mod generator;
+pub mod generator_function;
 pub mod global_this;
 pub mod infinity;
+pub mod intl;
 pub mod iterable;
+pub mod iterator;
 pub mod json;
 pub mod map;
 pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shared_array_buffer;
 pub mod string;
+pub mod structured_clone;
 pub mod symbol;
+#[cfg(feature = "encoding")]
+pub mod text_decoder;
+#[cfg(feature = "encoding")]
+pub mod text_encoder;
+pub mod typed_array;
 pub mod undefined;
+pub mod weak_map;
+pub mod weak_ref;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    array_buffer::ArrayBuffer,
+    async_function::AsyncFunction,
+    async_generator::AsyncGenerator,
+    async_generator_function::AsyncGeneratorFunction,
+    atomics::Atomics,
     bigint::BigInt,
     boolean::Boolean,
+    dataview::DataView,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    eval::Eval,
+    finalization_registry::FinalizationRegistry,
     function::BuiltInFunctionObject,
+    generator::Generator,
+    generator_function::GeneratorFunction,
     global_this::GlobalThis,
     infinity::Infinity,
+    intl::Intl,
+    iterator::{Iterator, IteratorHelper},
     json::Json,
     map::map_iterator::MapIterator,
     map::Map,
@@ -44,13 +83,24 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
+    proxy::Proxy,
     reflect::Reflect,
     regexp::RegExp,
     set::set_iterator::SetIterator,
     set::Set,
+    shared_array_buffer::SharedArrayBuffer,
     string::String,
+    structured_clone::StructuredClone,
     symbol::Symbol,
+    typed_array::{
+        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
+        Int8Array, TypedArray, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+    },
     undefined::Undefined,
+    weak_map::WeakMap,
+    weak_ref::WeakRef,
+    weak_set::WeakSet,
 };
 use crate::{
     property::{Attribute, PropertyDescriptor},
@@ -74,16 +124,21 @@ pub fn init(context: &mut Context) {
         Infinity::init,
         NaN::init,
         GlobalThis::init,
+        Eval::init,
+        StructuredClone::init,
         BuiltInFunctionObject::init,
         BuiltInObjectObject::init,
         Math::init,
         Json::init,
+        Intl::init,
+        Iterator::init,
         Array::init,
         BigInt::init,
         Boolean::init,
         Date::init,
         Map::init,
         Number::init,
+        Promise::init,
         Set::init,
         String::init,
         RegExp::init,
@@ -95,11 +150,41 @@ pub fn init(context: &mut Context) {
         SyntaxError::init,
         EvalError::init,
         UriError::init,
+        AggregateError::init,
         Reflect::init,
+        Proxy::init,
+        WeakMap::init,
+        WeakSet::init,
+        WeakRef::init,
+        FinalizationRegistry::init,
+        ArrayBuffer::init,
+        SharedArrayBuffer::init,
+        Atomics::init,
+        DataView::init,
+        Int8Array::init,
+        Uint8Array::init,
+        Uint8ClampedArray::init,
+        Int16Array::init,
+        Uint16Array::init,
+        Int32Array::init,
+        Uint32Array::init,
+        BigInt64Array::init,
+        BigUint64Array::init,
+        Float32Array::init,
+        Float64Array::init,
+        #[cfg(feature = "encoding")]
+        text_encoder::TextEncoder::init,
+        #[cfg(feature = "encoding")]
+        text_decoder::TextDecoder::init,
         #[cfg(feature = "console")]
         console::Console::init,
     ];
 
+    // Intrinsic objects that are not global properties.
+    AsyncFunction::init(context);
+    GeneratorFunction::init(context);
+    AsyncGeneratorFunction::init(context);
+
     let global_object = context.global_object();
 
     for init in &globals {
@@ -111,4 +196,7 @@ pub fn init(context: &mut Context) {
             .configurable(attribute.configurable());
         global_object.borrow_mut().insert(name, property);
     }
+
+    // `%TypedArray%` shares `toString` with `Array.prototype`, so it must be initialized after it.
+    TypedArray::init(context);
 }
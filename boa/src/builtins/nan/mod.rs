@@ -23,7 +23,7 @@ impl BuiltIn for NaN {
     const NAME: &'static str = "NaN";
 
     fn attribute() -> Attribute {
-        Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT
+        Attribute::constant()
     }
 
     fn init(_: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -19,8 +19,9 @@ use crate::{
     builtins::BuiltIn,
     object::{ConstructorBuilder, ObjectData, PROTOTYPE},
     property::Attribute,
+    value::ArgumentsExt,
     value::{AbstractRelation, IntegerOrInfinity, JsValue},
-    BoaProfiler, Context, JsResult,
+    BoaProfiler, Context, JsResult, JsString,
 };
 use num_traits::{float::FloatCore, Num};
 
@@ -37,6 +38,60 @@ const BUF_SIZE: usize = 2200;
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Number;
 
+/// The strings of the small non-negative integers stringified in a context.
+///
+/// Array indices and small numbers are stringified often, by `join` or `Object.keys` for
+/// instance, so their strings are created once and shared instead of allocated every time.
+#[derive(Debug)]
+pub(crate) struct NumberStringCache {
+    strings: Vec<Option<JsString>>,
+    size: u32,
+}
+
+impl Default for NumberStringCache {
+    fn default() -> Self {
+        Self::with_size(Self::DEFAULT_SIZE)
+    }
+}
+
+impl NumberStringCache {
+    /// The amount of integers, starting from zero, whose strings are cached by default.
+    pub(crate) const DEFAULT_SIZE: u32 = 256;
+
+    /// Creates a cache for the strings of the integers below `size`.
+    pub(crate) fn with_size(size: u32) -> Self {
+        Self {
+            strings: Vec::new(),
+            size,
+        }
+    }
+
+    /// Gets the string of `index`, creating it once if it is small enough to be cached.
+    pub(crate) fn index_to_string(&mut self, index: u32) -> JsString {
+        if index >= self.size {
+            return index.to_string().into();
+        }
+
+        let index = index as usize;
+        if index >= self.strings.len() {
+            self.strings.resize(index + 1, None);
+        }
+        self.strings[index]
+            .get_or_insert_with(|| index.to_string().into())
+            .clone()
+    }
+
+    /// Gets the string of the number `x`, as `ToString` converts it.
+    pub(crate) fn number_to_string(&mut self, x: f64) -> JsString {
+        // `-0` is stringified as `"0"` too.
+        if x >= 0.0 && x < f64::from(self.size) && x.fract() == 0.0 {
+            self.index_to_string(x as u32)
+        } else {
+            Number::to_native_string(x).into()
+        }
+    }
+}
+
 /// Maximum number of arguments expected to the builtin parseInt() function.
 const PARSE_INT_MAX_ARG_COUNT: usize = 2;
 
@@ -47,13 +102,13 @@ impl BuiltIn for Number {
     const NAME: &'static str = "Number";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::constant();
         let number_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -270,24 +325,42 @@ impl Number {
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
-    /// internationalization logic.
+    /// With the `intl` feature, the number is formatted by an `Intl.NumberFormat` created from the
+    /// `locales` and `options` arguments. Without it, the format of the string is
+    /// implementation-defined: it is the same as the one of `toString()`.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [ECMA-402 reference][spec-402]
     ///  - [MDN documentation][mdn]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec-402]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
     #[allow(clippy::wrong_self_convention)]
+    #[cfg_attr(not(feature = "intl"), allow(unused_variables))]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
+        args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisNumberValue(this value).
         let this_num = Self::this_number_value(this, context)?;
-        let this_str_num = format!("{}", this_num);
-        Ok(JsValue::new(this_str_num))
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::number_format::NumberFormat;
+
+            // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+            let number_format =
+                NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 3. Return ? FormatNumeric(numberFormat, x).
+            Ok(number_format.format(&this_num.into()).into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        Ok(JsValue::new(Self::to_native_string(this_num)))
     }
 
     /// flt_str_to_exp - used in to_precision
@@ -392,7 +465,7 @@ impl Number {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let precision = args.get(0).cloned().unwrap_or_default();
+        let precision = args.get_or_undefined(0).clone();
 
         // 1 & 6
         let mut this_num = Self::this_number_value(this, context)?;
@@ -658,7 +731,7 @@ impl Number {
 
         // 5. If radixNumber = 10, return ! ToString(x).
         if radix == 10 {
-            return Ok(JsValue::new(Self::to_native_string(x)));
+            return Ok(context.number_strings.number_to_string(x).into());
         }
 
         if x == -0. {
@@ -1,6 +1,8 @@
 #![allow(clippy::float_cmp)]
 
-use crate::{builtins::Number, forward, forward_val, value::AbstractRelation, Context};
+use crate::{
+    builtins::Number, forward, forward_val, value::AbstractRelation, Context, JsString, JsValue,
+};
 
 #[test]
 fn integer_number_primitive_to_number_object() {
@@ -108,11 +110,10 @@ fn to_locale_string() {
         var small_locale = Number(5).toLocaleString();
         var big_locale = Number("345600").toLocaleString();
         var neg_locale = Number(-25).toLocaleString();
+        var inf_locale = Number(-Infinity).toLocaleString();
+        var frac_locale = Number(0.5).toLocaleString();
         "#;
 
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
-
     eprintln!("{}", forward(&mut context, init));
     let default_locale = forward(&mut context, "default_locale");
     let small_locale = forward(&mut context, "small_locale");
@@ -121,8 +122,19 @@ fn to_locale_string() {
 
     assert_eq!(default_locale, "\"0\"");
     assert_eq!(small_locale, "\"5\"");
-    assert_eq!(big_locale, "\"345600\"");
     assert_eq!(neg_locale, "\"-25\"");
+    assert_eq!(forward(&mut context, "frac_locale"), "\"0.5\"");
+
+    #[cfg(not(feature = "intl"))]
+    {
+        assert_eq!(big_locale, "\"345600\"");
+        assert_eq!(forward(&mut context, "inf_locale"), "\"-Infinity\"");
+    }
+    #[cfg(feature = "intl")]
+    {
+        assert_eq!(big_locale, "\"345,600\"");
+        assert_eq!(forward(&mut context, "inf_locale"), "\"-∞\"");
+    }
 }
 
 #[test]
@@ -890,3 +902,37 @@ fn number_is_safe_integer() {
         &forward(&mut context, "Number.isSafeInteger(new Number(5))")
     );
 }
+
+#[test]
+fn small_integer_strings_are_shared() {
+    let mut context = Context::new();
+
+    let first = JsValue::new(7).to_string(&mut context).unwrap();
+    let second = JsValue::new(7.0).to_string(&mut context).unwrap();
+    assert_eq!(first, "7");
+    assert!(JsString::ptr_eq(&first, &second));
+
+    let first = JsValue::new(256).to_string(&mut context).unwrap();
+    let second = JsValue::new(256).to_string(&mut context).unwrap();
+    assert_eq!(first, "256");
+    assert!(!JsString::ptr_eq(&first, &second));
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[-0, 0, 1.5, -1, 255, 256, 1e21].join() + ' ' + Object.keys([1, 2]) + ' ' + (255).toString()"
+        ),
+        "\"0,0,1.5,-1,255,256,1e+21 0,1 255\""
+    );
+
+    context.set_number_string_cache_size(0);
+    let first = JsValue::new(7).to_string(&mut context).unwrap();
+    let second = JsValue::new(7).to_string(&mut context).unwrap();
+    assert_eq!(first, "7");
+    assert!(!JsString::ptr_eq(&first, &second));
+
+    context.set_number_string_cache_size(1024);
+    let first = JsValue::new(1000).to_string(&mut context).unwrap();
+    let second = forward_val(&mut context, "String(1000)").unwrap();
+    assert!(JsString::ptr_eq(&first, second.as_string().unwrap()));
+}
@@ -5,6 +5,7 @@ use crate::{
     property::PropertyDescriptor,
     property::PropertyKey,
     symbol::WellKnownSymbols,
+    teardown::Contents,
     BoaProfiler, Context, JsResult, JsString, JsValue,
 };
 use rustc_hash::FxHashSet;
@@ -28,6 +29,11 @@ pub struct ForInIterator {
 impl ForInIterator {
     pub(crate) const NAME: &'static str = "ForInIterator";
 
+    /// Queues the iterated object to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_value(&self.object);
+    }
+
     fn new(object: JsValue) -> Self {
         ForInIterator {
             object,
@@ -77,7 +83,9 @@ impl ForInIterator {
                                     iterator.remaining_keys.push_back(k.clone());
                                 }
                                 PropertyKey::Index(i) => {
-                                    iterator.remaining_keys.push_back(i.to_string().into());
+                                    iterator
+                                        .remaining_keys
+                                        .push_back(context.number_strings.index_to_string(i));
                                 }
                                 _ => {}
                             }
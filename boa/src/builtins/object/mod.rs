@@ -14,13 +14,17 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{
+        iterable::{group_by, KeyCoercion},
+        BuiltIn,
+    },
     object::{
         ConstructorBuilder, JsObject, Object as BuiltinObject, ObjectData, ObjectInitializer,
         ObjectKind, PROTOTYPE,
     },
     property::{Attribute, DescriptorKind, PropertyDescriptor, PropertyKey, PropertyNameKind},
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     value::{JsValue, Type},
     BoaProfiler, Context, JsResult,
 };
@@ -39,7 +43,7 @@ impl BuiltIn for Object {
     const NAME: &'static str = "Object";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -53,11 +57,11 @@ impl BuiltIn for Object {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .inherit(JsValue::null())
-        .method(Self::has_own_property, "hasOwnProperty", 0)
-        .method(Self::property_is_enumerable, "propertyIsEnumerable", 0)
+        .method(Self::has_own_property, "hasOwnProperty", 1)
+        .method(Self::property_is_enumerable, "propertyIsEnumerable", 1)
         .method(Self::to_string, "toString", 0)
         .method(Self::value_of, "valueOf", 0)
-        .method(Self::is_prototype_of, "isPrototypeOf", 0)
+        .method(Self::is_prototype_of, "isPrototypeOf", 1)
         .static_method(Self::create, "create", 2)
         .static_method(Self::set_prototype_of, "setPrototypeOf", 2)
         .static_method(Self::get_prototype_of, "getPrototypeOf", 1)
@@ -68,6 +72,7 @@ impl BuiltIn for Object {
         .static_method(Self::keys, "keys", 1)
         .static_method(Self::values, "values", 1)
         .static_method(Self::entries, "entries", 1)
+        .static_method(Self::group_by, "groupBy", 2)
         .static_method(
             Self::get_own_property_descriptor,
             "getOwnPropertyDescriptor",
@@ -129,8 +134,8 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.create
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create
     pub fn create(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let prototype = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
-        let properties = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let prototype = args.get_or_undefined(0).clone();
+        let properties = args.get_or_undefined(1).clone();
 
         let obj = match prototype {
             JsValue::Object(_) | JsValue::Null => JsObject::new(BuiltinObject::with_prototype(
@@ -204,12 +209,10 @@ impl Object {
             .to_object(context)?;
         let descriptors = context.construct_object();
 
-        for key in object.borrow().properties().keys() {
-            let descriptor = {
-                let desc = object
-                    .__get_own_property__(&key, context)?
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, context)
+        for key in object.__own_property_keys__(context)? {
+            let descriptor = match object.__get_own_property__(&key, context)? {
+                Some(desc) => Self::from_property_descriptor(desc, context),
+                None => continue,
             };
 
             if !descriptor.is_undefined() {
@@ -232,13 +235,19 @@ impl Object {
     /// [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
-    fn from_property_descriptor(desc: PropertyDescriptor, context: &mut Context) -> JsValue {
+    pub(crate) fn from_property_descriptor(
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> JsValue {
         let mut descriptor = ObjectInitializer::new(context);
 
         // TODO: use CreateDataPropertyOrThrow
 
         match desc.kind() {
-            DescriptorKind::Data { value, writable } => {
+            DescriptorKind::Data { value, writable }
+            | DescriptorKind::Watched {
+                value, writable, ..
+            } => {
                 if let Some(value) = value {
                     descriptor.property("value", value.clone(), Attribute::all());
                 }
@@ -270,8 +279,8 @@ impl Object {
 
     /// Uses the SameValue algorithm to check equality of objects
     pub fn is(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        let x = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
-        let y = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let x = args.get_or_undefined(0).clone();
+        let y = args.get_or_undefined(1).clone();
 
         Ok(JsValue::same_value(&x, &y).into())
     }
@@ -288,7 +297,7 @@ impl Object {
         let obj = args[0].clone().to_object(ctx)?;
 
         // 2. Return ? obj.[[GetPrototypeOf]]().
-        Ok(obj.prototype_instance())
+        obj.__get_prototype_of__(ctx)
     }
 
     /// Set the `prototype` of an object.
@@ -313,7 +322,7 @@ impl Object {
             .clone();
 
         // 2. If Type(proto) is neither Object nor Null, throw a TypeError exception.
-        let proto = args.get(1).cloned().unwrap_or_default();
+        let proto = args.get_or_undefined(1).clone();
         if !matches!(proto.get_type(), Type::Object | Type::Null) {
             return ctx.throw_type_error(format!(
                 "expected an object or null, got {}",
@@ -379,7 +388,7 @@ impl Object {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let object = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
+        let object = args.get_or_undefined(0).clone();
         if let Some(object) = object.as_object() {
             let key = args
                 .get(1)
@@ -413,10 +422,10 @@ impl Object {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let arg = args.get(0).cloned().unwrap_or_default();
+        let arg = args.get_or_undefined(0).clone();
         let arg_obj = arg.as_object();
         if let Some(obj) = arg_obj {
-            let props = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+            let props = args.get_or_undefined(1).clone();
             object_define_properties(&obj, props, context)?;
             Ok(arg)
         } else {
@@ -459,24 +468,25 @@ impl Object {
         }
         // 3. Let O be ! ToObject(this value).
         let o = this.to_object(context)?;
-        // TODO: 4. Let isArray be ? IsArray(O).
-        // TODO: 5. If isArray is true, let builtinTag be "Array".
-
-        // 6. Else if O has a [[ParameterMap]] internal slot, let builtinTag be "Arguments".
-        // 7. Else if O has a [[Call]] internal method, let builtinTag be "Function".
-        // 8. Else if O has an [[ErrorData]] internal slot, let builtinTag be "Error".
-        // 9. Else if O has a [[BooleanData]] internal slot, let builtinTag be "Boolean".
-        // 10. Else if O has a [[NumberData]] internal slot, let builtinTag be "Number".
-        // 11. Else if O has a [[StringData]] internal slot, let builtinTag be "String".
-        // 12. Else if O has a [[DateValue]] internal slot, let builtinTag be "Date".
-        // 13. Else if O has a [[RegExpMatcher]] internal slot, let builtinTag be "RegExp".
-        // 14. Else, let builtinTag be "Object".
-        let builtin_tag = {
+        // 4. Let isArray be ? IsArray(O).
+        // 5. If isArray is true, let builtinTag be "Array".
+        let builtin_tag = if JsValue::from(o.clone()).is_array(context)? {
+            "Array"
+        } else if o.is_callable() {
+            // 7. Else if O has a [[Call]] internal method, let builtinTag be "Function".
+            "Function"
+        } else {
+            // 6. Else if O has a [[ParameterMap]] internal slot, let builtinTag be "Arguments".
+            // 8. Else if O has an [[ErrorData]] internal slot, let builtinTag be "Error".
+            // 9. Else if O has a [[BooleanData]] internal slot, let builtinTag be "Boolean".
+            // 10. Else if O has a [[NumberData]] internal slot, let builtinTag be "Number".
+            // 11. Else if O has a [[StringData]] internal slot, let builtinTag be "String".
+            // 12. Else if O has a [[DateValue]] internal slot, let builtinTag be "Date".
+            // 13. Else if O has a [[RegExpMatcher]] internal slot, let builtinTag be "RegExp".
+            // 14. Else, let builtinTag be "Object".
             let o = o.borrow();
             match o.kind() {
-                ObjectKind::Array => "Array",
-                // TODO: Arguments Exotic Objects are currently not supported
-                ObjectKind::Function(_) => "Function",
+                ObjectKind::Arguments(_) => "Arguments",
                 ObjectKind::Error => "Error",
                 ObjectKind::Boolean(_) => "Boolean",
                 ObjectKind::Number(_) => "Number",
@@ -687,6 +697,46 @@ impl Object {
 
         Ok(result.into())
     }
+
+    /// `Object.groupBy( items, callbackfn )`
+    ///
+    /// This method groups the values of an iterable in the arrays of an object without prototype,
+    /// under the property keys the callback returns for them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy
+    pub fn group_by(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let groups be ? GroupBy(items, callbackfn, property).
+        let groups = group_by(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            KeyCoercion::Property,
+            context,
+        )?;
+
+        // 2. Let obj be OrdinaryObjectCreate(null).
+        let obj = JsObject::new(BuiltinObject::with_prototype(
+            JsValue::null(),
+            ObjectData::ordinary(),
+        ));
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, g.[[Key]], elements).
+            obj.create_data_property_or_throw(key.to_property_key(context)?, elements, context)
+                .expect("CreateDataPropertyOrThrow should never fail here");
+        }
+
+        // 4. Return obj.
+        Ok(obj.into())
+    }
 }
 
 /// The abstract operation ObjectDefineProperties
@@ -197,6 +197,52 @@ fn object_to_string() {
     assert_eq!(forward(&mut context, "o.toString()"), "\"[object Object]\"");
 }
 
+#[test]
+fn object_to_string_tags() {
+    let mut context = Context::new();
+    let init = r#"
+        var toString = Object.prototype.toString;
+        function args() { return arguments; }
+        var custom = {};
+        custom[Symbol.toStringTag] = 'Custom';
+        var notString = [];
+        notString[Symbol.toStringTag] = 1;
+        var revoked = Proxy.revocable([], {});
+        revoked.revoke();
+    "#;
+    forward(&mut context, init);
+
+    for (expr, tag) in [
+        ("new Map()", "Map"),
+        ("new Set()", "Set"),
+        ("Promise.resolve()", "Promise"),
+        ("Object(Symbol())", "Symbol"),
+        ("Math", "Math"),
+        ("JSON", "JSON"),
+        ("new Map().entries()", "Map Iterator"),
+        ("args()", "Arguments"),
+        ("new Proxy([], {})", "Array"),
+        ("new Proxy(function() {}, {})", "Function"),
+        ("custom", "Custom"),
+        ("notString", "Array"),
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("toString.call({})", expr)),
+            format!("\"[object {}]\"", tag)
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(args()) === Object.prototype"
+        ),
+        "true"
+    );
+    assert!(
+        forward(&mut context, "toString.call(revoked.proxy)").starts_with("Uncaught \"TypeError\"")
+    );
+}
+
 #[test]
 fn define_symbol_property() {
     let mut context = Context::new();
@@ -289,3 +335,47 @@ fn object_is_prototype_of() {
 
     assert_eq!(context.eval(init).unwrap(), JsValue::new(true));
 }
+
+#[test]
+fn object_group_by() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var groups = Object.groupBy([1, 2, 3, 4, 5], function (x, i) {
+            return x % 2 === 0 ? 'even' : 'odd';
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(groups).join()"),
+        "\"odd,even\""
+    );
+    assert_eq!(forward(&mut context, "groups.odd.join()"), "\"1,3,5\"");
+    assert_eq!(forward(&mut context, "Array.isArray(groups.even)"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys(Object.groupBy(['a', 'b'], function (c, i) { return i < 1 ? 1 : '1'; })).join()"
+        ),
+        "\"1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.groupBy([1], 1); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.groupBy(null, function () {}); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
@@ -0,0 +1,1604 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion or failure of an asynchronous operation, and
+//! its resulting value. The reactions to the settlement of a promise are run as jobs, which the
+//! embedder drives by calling [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        error::AggregateError,
+        function::Captures,
+        iterable::{get_iterator, IteratorRecord},
+        Array, BuiltIn,
+    },
+    gc::{Finalize, Trace},
+    job::NativeJob,
+    object::{ConstructorBuilder, FunctionBuilder, JsObject, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    teardown::Contents,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use gc::{Gc, GcCell};
+use std::{cell::Cell, mem, rc::Rc};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PromiseState {
+    /// The promise is neither fulfilled nor rejected yet.
+    Pending,
+    /// The promise was fulfilled with the contained value.
+    Fulfilled(JsValue),
+    /// The promise was rejected with the contained reason.
+    Rejected(JsValue),
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<ReactionRecord>,
+    reject_reactions: Vec<ReactionRecord>,
+    handled: bool,
+}
+
+/// Whether a reaction handles the fulfillment or the rejection of a promise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReactionKind {
+    Fulfill,
+    Reject,
+}
+
+/// The `PromiseReaction` record, a handler to run once a promise settles.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReactionRecord {
+    capability: Option<PromiseCapability>,
+    #[unsafe_ignore_trace]
+    kind: ReactionKind,
+    handler: Option<JsObject>,
+}
+
+/// The `PromiseCapability` record, a promise together with the functions that settle it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    promise: JsObject,
+    resolve: JsObject,
+    reject: JsObject,
+}
+
+impl PromiseCapability {
+    /// Queues the promise and its resolving functions to be emptied by the teardown of the
+    /// context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_object(&self.promise);
+        contents.push_object(&self.resolve);
+        contents.push_object(&self.reject);
+    }
+
+    /// Returns the promise of the capability.
+    #[inline]
+    pub(crate) fn promise(&self) -> &JsObject {
+        &self.promise
+    }
+
+    /// Returns the function that resolves the promise of the capability.
+    #[inline]
+    pub(crate) fn resolve(&self) -> &JsObject {
+        &self.resolve
+    }
+
+    /// Returns the function that rejects the promise of the capability.
+    #[inline]
+    pub(crate) fn reject(&self) -> &JsObject {
+        &self.reject
+    }
+
+    /// `IfAbruptRejectPromise ( value, capability )`
+    ///
+    /// Rejects the promise of the capability with the given error, and returns the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+    pub(crate) fn reject_abrupt(&self, error: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+        self.reject.call(&JsValue::undefined(), &[error], context)?;
+        // b. Return capability.[[Promise]].
+        Ok(self.promise.clone().into())
+    }
+}
+
+/// The values captured by the resolving functions of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ResolvingFunctionCaptures {
+    promise: JsObject,
+    #[unsafe_ignore_trace]
+    already_resolved: Rc<Cell<bool>>,
+}
+
+/// The functions received by the executor of `NewPromiseCapability`.
+#[derive(Debug, Default, Trace, Finalize)]
+struct CapabilityFunctions {
+    resolve: JsValue,
+    reject: JsValue,
+}
+
+/// The values captured by the `thenFinally` and `catchFinally` functions of
+/// `Promise.prototype.finally()`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct FinallyCaptures {
+    on_finally: JsValue,
+    constructor: JsValue,
+}
+
+/// The promise combinators, the static methods of `Promise` that take an iterable of promises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    All,
+    AllSettled,
+    Any,
+    Race,
+}
+
+/// The values captured by the element functions of the promise combinators, like
+/// `Promise.all Resolve Element Functions`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ElementCaptures {
+    #[unsafe_ignore_trace]
+    combinator: Combinator,
+    #[unsafe_ignore_trace]
+    kind: ReactionKind,
+    #[unsafe_ignore_trace]
+    already_called: Rc<Cell<bool>>,
+    index: usize,
+    values: Gc<GcCell<Vec<JsValue>>>,
+    capability: PromiseCapability,
+    #[unsafe_ignore_trace]
+    remaining_elements: Rc<Cell<usize>>,
+}
+
+impl Combinator {
+    /// Settles the promise of a combinator once all the input promises have settled.
+    fn settle(
+        self,
+        values: Vec<JsValue>,
+        capability: &PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        match self {
+            Self::All | Self::AllSettled => {
+                // 1. Let valuesArray be ! CreateArrayFromList(values).
+                let values = Array::create_array_from_list(values, context);
+                // 2. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                capability
+                    .resolve
+                    .call(&JsValue::undefined(), &[values.into()], context)
+            }
+            Self::Any => {
+                // 1. Let error be a newly created AggregateError object.
+                // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: ! CreateArrayFromList(errors) }).
+                let error = AggregateError::create(values, "all promises were rejected", context)?;
+                // 3. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+                capability
+                    .reject
+                    .call(&JsValue::undefined(), &[error], context)
+            }
+            Self::Race => unreachable!("Promise.race settles with the first input promise"),
+        }
+    }
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .static_method(Self::with_resolvers, "withResolvers", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .method(Self::then, "then", 2)
+        .build();
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    /// The amount of arguments the `Promise` constructor takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Queues the result and the pending reactions of the promise to be emptied by the teardown
+    /// of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        if let PromiseState::Fulfilled(value) | PromiseState::Rejected(value) = &self.state {
+            contents.push_value(value);
+        }
+        for reaction in self.fulfill_reactions.iter().chain(&self.reject_reactions) {
+            if let Some(capability) = &reaction.capability {
+                capability.collect_contents(contents);
+            }
+            if let Some(handler) = &reaction.handler {
+                contents.push_object(handler);
+            }
+        }
+    }
+
+    /// Creates a new pending promise.
+    fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            handled: false,
+        }
+    }
+
+    /// Gets the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// `Promise ( executor )`
+    ///
+    /// Creates a new promise, and calls the executor with the functions that resolve or reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Promise constructor without new is forbidden",
+            );
+        }
+
+        // 2. If IsCallable(executor) is false, throw a TypeError exception.
+        let executor = args.get_or_undefined(0).clone();
+        if !executor.is_function() {
+            return context.throw_type_error("Promise executor is not a function");
+        }
+
+        // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
+        let promise_prototype = context.standard_objects().promise_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(promise_prototype);
+
+        let promise = context.construct_object();
+        promise.set_prototype_instance(prototype.into());
+        // 4. Set promise.[[PromiseState]] to pending.
+        // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
+        // 6. Set promise.[[PromiseRejectReactions]] to a new empty List.
+        // 7. Set promise.[[PromiseIsHandled]] to false.
+        promise.borrow_mut().data = ObjectData::promise(Self::new());
+
+        // 8. Let resolvingFunctions be CreateResolvingFunctions(promise).
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+
+        // 9. Let completion be Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »).
+        let completion = context.call(
+            &executor,
+            &JsValue::undefined(),
+            &[resolve.into(), reject.clone().into()],
+        );
+
+        // 10. If completion is an abrupt completion, then
+        if let Err(error) = completion {
+            // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
+            reject.call(&JsValue::undefined(), &[error], context)?;
+        }
+
+        // 11. Return promise.
+        Ok(promise.into())
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// The `Promise [ @@species ]` accessor property returns the Promise constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/@@species
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `CreateResolvingFunctions ( promise )`
+    ///
+    /// Creates the pair of functions that resolve or reject the given promise, only the first
+    /// call to either of them having an effect.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    pub(crate) fn create_resolving_functions(
+        promise: &JsObject,
+        context: &mut Context,
+    ) -> (JsObject, JsObject) {
+        // 1. Let alreadyResolved be the Record { [[Value]]: false }.
+        let captures = ResolvingFunctionCaptures {
+            promise: promise.clone(),
+            already_resolved: Rc::new(Cell::new(false)),
+        };
+
+        // 2. Let stepsResolve be the algorithm steps defined in Promise Resolve Functions.
+        // 3. Let lengthResolve be the number of non-optional parameters of the function definition in Promise Resolve Functions.
+        // 4. Let resolve be ! CreateBuiltinFunction(stepsResolve, lengthResolve, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 5. Set resolve.[[Promise]] to promise.
+        // 6. Set resolve.[[AlreadyResolved]] to alreadyResolved.
+        let resolve = FunctionBuilder::closure_with_captures(
+            context,
+            Self::resolve_function,
+            captures.clone(),
+        )
+        .length(1)
+        .build();
+
+        // 7. Let stepsReject be the algorithm steps defined in Promise Reject Functions.
+        // 8. Let lengthReject be the number of non-optional parameters of the function definition in Promise Reject Functions.
+        // 9. Let reject be ! CreateBuiltinFunction(stepsReject, lengthReject, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 10. Set reject.[[Promise]] to promise.
+        // 11. Set reject.[[AlreadyResolved]] to alreadyResolved.
+        let reject =
+            FunctionBuilder::closure_with_captures(context, Self::reject_function, captures)
+                .length(1)
+                .build();
+
+        // 12. Return the Record { [[Resolve]]: resolve, [[Reject]]: reject }.
+        (resolve, reject)
+    }
+
+    /// Promise Resolve Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(
+        _: &JsValue,
+        args: &[JsValue],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+        // 3. Let promise be F.[[Promise]].
+        // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+        let captures = captures
+            .downcast_ref::<ResolvingFunctionCaptures>()
+            .expect("resolve functions capture their promise")
+            .clone();
+        let promise = captures.promise.clone();
+        let already_resolved = captures.already_resolved.clone();
+
+        // 5. If alreadyResolved.[[Value]] is true, return undefined.
+        if already_resolved.get() {
+            return Ok(JsValue::undefined());
+        }
+
+        // 6. Set alreadyResolved.[[Value]] to true.
+        already_resolved.set(true);
+
+        let resolution = args.get_or_undefined(0).clone();
+        let resolution_object = match resolution.as_object() {
+            // 7. If SameValue(resolution, promise) is true, then
+            Some(object) if JsObject::equals(&object, &promise) => {
+                // a. Let selfResolutionError be a newly created TypeError object.
+                let error =
+                    context.construct_type_error("a promise cannot be resolved with itself");
+                // b. Return RejectPromise(promise, selfResolutionError).
+                Self::reject_promise(&promise, error, context);
+                return Ok(JsValue::undefined());
+            }
+            Some(object) => object,
+            // 8. If Type(resolution) is not Object, then
+            None => {
+                // a. Return FulfillPromise(promise, resolution).
+                Self::fulfill_promise(&promise, resolution, context);
+                return Ok(JsValue::undefined());
+            }
+        };
+
+        // 9. Let then be Get(resolution, "then").
+        let then = match resolution_object.get("then", context) {
+            Ok(then) => then,
+            // 10. If then is an abrupt completion, then
+            Err(error) => {
+                // a. Return RejectPromise(promise, then.[[Value]]).
+                Self::reject_promise(&promise, error, context);
+                return Ok(JsValue::undefined());
+            }
+        };
+
+        // 11. Let thenAction be then.[[Value]].
+        // 12. If IsCallable(thenAction) is false, then
+        if !then.is_function() {
+            // a. Return FulfillPromise(promise, resolution).
+            Self::fulfill_promise(&promise, resolution, context);
+            return Ok(JsValue::undefined());
+        }
+
+        // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
+        // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
+        let job = Self::promise_resolve_thenable_job(promise, resolution, then);
+
+        // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+        context.enqueue_job(job);
+
+        // 16. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Promise Reject Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(
+        _: &JsValue,
+        args: &[JsValue],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let F be the active function object.
+        // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+        // 3. Let promise be F.[[Promise]].
+        // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+        let captures = captures
+            .downcast_ref::<ResolvingFunctionCaptures>()
+            .expect("reject functions capture their promise")
+            .clone();
+        let promise = captures.promise.clone();
+        let already_resolved = captures.already_resolved.clone();
+
+        // 5. If alreadyResolved.[[Value]] is true, return undefined.
+        if already_resolved.get() {
+            return Ok(JsValue::undefined());
+        }
+
+        // 6. Set alreadyResolved.[[Value]] to true.
+        already_resolved.set(true);
+
+        // 7. Return RejectPromise(promise, reason).
+        let reason = args.get_or_undefined(0).clone();
+        Self::reject_promise(&promise, reason, context);
+        Ok(JsValue::undefined())
+    }
+
+    /// `FulfillPromise ( promise, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &JsObject, value: JsValue, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object
+                .as_promise_mut()
+                .expect("only promises can be fulfilled");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            // 2. Let reactions be promise.[[PromiseFulfillReactions]].
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            let reactions = mem::take(&mut promise.fulfill_reactions);
+
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            promise.reject_reactions.clear();
+
+            // 3. Set promise.[[PromiseResult]] to value.
+            // 6. Set promise.[[PromiseState]] to fulfilled.
+            promise.state = PromiseState::Fulfilled(value.clone());
+
+            reactions
+        };
+
+        // 7. Return TriggerPromiseReactions(reactions, value).
+        Self::trigger_promise_reactions(reactions, &value, context);
+    }
+
+    /// `RejectPromise ( promise, reason )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &JsObject, reason: JsValue, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object
+                .as_promise_mut()
+                .expect("only promises can be rejected");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            // 2. Let reactions be promise.[[PromiseRejectReactions]].
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            let reactions = mem::take(&mut promise.reject_reactions);
+
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            promise.fulfill_reactions.clear();
+
+            // 3. Set promise.[[PromiseResult]] to reason.
+            // 6. Set promise.[[PromiseState]] to rejected.
+            promise.state = PromiseState::Rejected(reason.clone());
+
+            // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
+            // NOTE: unhandled rejections are not tracked yet.
+
+            reactions
+        };
+
+        // 8. Return TriggerPromiseReactions(reactions, reason).
+        Self::trigger_promise_reactions(reactions, &reason, context);
+    }
+
+    /// `TriggerPromiseReactions ( reactions, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<ReactionRecord>,
+        argument: &JsValue,
+        context: &mut Context,
+    ) {
+        // 1. For each element reaction of reactions, do
+        for reaction in reactions {
+            // a. Let job be NewPromiseReactionJob(reaction, argument).
+            let job = Self::promise_reaction_job(reaction, argument.clone());
+
+            // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+            context.enqueue_job(job);
+        }
+
+        // 2. Return undefined.
+    }
+
+    /// `NewPromiseReactionJob ( reaction, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn promise_reaction_job(reaction: ReactionRecord, argument: JsValue) -> NativeJob {
+        // 1. Let job be a new Job Abstract Closure with no parameters that captures reaction and argument and performs the following steps when called:
+        NativeJob::new(move |context| {
+            // a. Let promiseCapability be reaction.[[Capability]].
+            // b. Let type be reaction.[[Type]].
+            // c. Let handler be reaction.[[Handler]].
+            let handler_result = match (reaction.handler.clone(), reaction.kind) {
+                // d. If handler is empty, then
+                //     i. If type is Fulfill, let handlerResult be NormalCompletion(argument).
+                (None, ReactionKind::Fulfill) => Ok(argument),
+                //     ii. Else, let handlerResult be ThrowCompletion(argument).
+                (None, ReactionKind::Reject) => Err(argument),
+                // e. Else, let handlerResult be HostCallJobCallback(handler, undefined, « argument »).
+                (Some(handler), _) => handler.call(&JsValue::undefined(), &[argument], context),
+            };
+
+            match reaction.capability.clone() {
+                // f. If promiseCapability is undefined, then
+                None => {
+                    // i. Assert: handlerResult is not an abrupt completion.
+                    debug_assert!(handler_result.is_ok());
+                    // ii. Return NormalCompletion(empty).
+                    Ok(JsValue::undefined())
+                }
+                // g. Assert: promiseCapability is a PromiseCapability Record.
+                Some(capability) => match handler_result {
+                    // h. If handlerResult is an abrupt completion, then
+                    //     i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
+                    Err(error) => capability
+                        .reject
+                        .call(&JsValue::undefined(), &[error], context),
+                    // i. Else,
+                    //     i. Return ? Call(promiseCapability.[[Resolve]], undefined, « handlerResult.[[Value]] »).
+                    Ok(value) => capability
+                        .resolve
+                        .call(&JsValue::undefined(), &[value], context),
+                },
+            }
+        })
+    }
+
+    /// `NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn promise_resolve_thenable_job(
+        promise_to_resolve: JsObject,
+        thenable: JsValue,
+        then: JsValue,
+    ) -> NativeJob {
+        // 1. Let job be a new Job Abstract Closure with no parameters that captures promiseToResolve, thenable, and then and performs the following steps when called:
+        NativeJob::new(move |context| {
+            // a. Let resolvingFunctions be CreateResolvingFunctions(promiseToResolve).
+            let (resolve, reject) = Self::create_resolving_functions(&promise_to_resolve, context);
+
+            // b. Let thenCallResult be HostCallJobCallback(then, thenable, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »).
+            let then_call_result =
+                context.call(&then, &thenable, &[resolve.into(), reject.clone().into()]);
+
+            // c. If thenCallResult is an abrupt completion, then
+            if let Err(error) = then_call_result {
+                // i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+                return reject.call(&JsValue::undefined(), &[error], context);
+            }
+
+            // d. Return ? thenCallResult.
+            then_call_result
+        })
+    }
+
+    /// `NewPromiseCapability ( C )`
+    ///
+    /// Creates a new promise from the given constructor, capturing the functions that settle it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new_promise_capability(
+        constructor: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<PromiseCapability> {
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let constructor_object = match constructor.as_object() {
+            Some(object) if object.is_constructable() => object,
+            _ => {
+                return Err(context.construct_type_error("promise constructor is not a constructor"))
+            }
+        };
+
+        // 2. NOTE: C is assumed to be a constructor function that supports the parameter conventions of the Promise constructor (see 27.2.3.1).
+        // 3. Let promiseCapability be the PromiseCapability Record { [[Promise]]: undefined, [[Resolve]]: undefined, [[Reject]]: undefined }.
+        let functions = Gc::new(GcCell::new(CapabilityFunctions::default()));
+
+        // 4. Let executorClosure be a new Abstract Closure with parameters (resolve, reject) that captures promiseCapability and performs the following steps when called:
+        // 5. Let executor be ! CreateBuiltinFunction(executorClosure, 2, "", « »).
+        let executor = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures, context| {
+                let functions = captures
+                    .downcast_ref::<Gc<GcCell<CapabilityFunctions>>>()
+                    .expect("the executor captures the capability functions")
+                    .clone();
+                let mut functions = functions.borrow_mut();
+
+                // a. If promiseCapability.[[Resolve]] is not undefined, throw a TypeError exception.
+                if !functions.resolve.is_undefined() {
+                    return context.throw_type_error("promise resolve function was already set");
+                }
+
+                // b. If promiseCapability.[[Reject]] is not undefined, throw a TypeError exception.
+                if !functions.reject.is_undefined() {
+                    return context.throw_type_error("promise reject function was already set");
+                }
+
+                // c. Set promiseCapability.[[Resolve]] to resolve.
+                functions.resolve = args.get_or_undefined(0).clone();
+
+                // d. Set promiseCapability.[[Reject]] to reject.
+                functions.reject = args.get_or_undefined(1).clone();
+
+                // e. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            functions.clone(),
+        )
+        .length(2)
+        .build();
+
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise = constructor_object.construct(&[executor.into()], constructor, context)?;
+        let promise = match promise.as_object() {
+            Some(promise) => promise,
+            None => {
+                return Err(context.construct_type_error("promise constructor returned a primitive"))
+            }
+        };
+
+        let functions = functions.borrow();
+
+        // 7. If IsCallable(promiseCapability.[[Resolve]]) is false, throw a TypeError exception.
+        let resolve = match functions.resolve.as_object() {
+            Some(resolve) if resolve.is_callable() => resolve,
+            _ => {
+                return Err(context.construct_type_error("promise resolve function is not callable"))
+            }
+        };
+
+        // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
+        let reject = match functions.reject.as_object() {
+            Some(reject) if reject.is_callable() => reject,
+            _ => {
+                return Err(context.construct_type_error("promise reject function is not callable"))
+            }
+        };
+
+        // 9. Set promiseCapability.[[Promise]] to promise.
+        // 10. Return promiseCapability.
+        Ok(PromiseCapability {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// `PromiseResolve ( C, x )`
+    ///
+    /// Returns `x` if it is a promise created by the constructor `C`, or a new promise from `C`
+    /// resolved with `x`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &JsValue,
+        x: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: Type(C) is Object.
+        // 2. If IsPromise(x) is true, then
+        if let Some(promise) = x.as_object().filter(JsObject::is_promise) {
+            // a. Let xConstructor be ? Get(x, "constructor").
+            let x_constructor = promise.get("constructor", context)?;
+
+            // b. If SameValue(xConstructor, C) is true, return x.
+            if JsValue::same_value(&x_constructor, constructor) {
+                return Ok(x);
+            }
+        }
+
+        // 3. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(constructor, context)?;
+
+        // 4. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+        capability
+            .resolve
+            .call(&JsValue::undefined(), &[x], context)?;
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone().into())
+    }
+
+    /// `PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )`
+    ///
+    /// Registers the reactions to the settlement of a promise, and returns the promise of the
+    /// result capability, or `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &JsObject,
+        on_fulfilled: &JsValue,
+        on_rejected: &JsValue,
+        result_capability: Option<PromiseCapability>,
+        context: &mut Context,
+    ) -> JsValue {
+        // 1. Assert: IsPromise(promise) is true.
+        // 2. If resultCapability is not present, then
+        //     a. Set resultCapability to undefined.
+        let result_promise = result_capability
+            .as_ref()
+            .map(|capability| capability.promise.clone().into())
+            .unwrap_or_default();
+
+        // 3. If IsCallable(onFulfilled) is false, then
+        //     a. Let onFulfilledJobCallback be empty.
+        // 4. Else,
+        //     a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
+        let on_fulfilled = on_fulfilled.as_object().filter(JsObject::is_callable);
+
+        // 5. If IsCallable(onRejected) is false, then
+        //     a. Let onRejectedJobCallback be empty.
+        // 6. Else,
+        //     a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
+        let on_rejected = on_rejected.as_object().filter(JsObject::is_callable);
+
+        // 7. Let fulfillReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Fulfill, [[Handler]]: onFulfilledJobCallback }.
+        let fulfill_reaction = ReactionRecord {
+            capability: result_capability.clone(),
+            kind: ReactionKind::Fulfill,
+            handler: on_fulfilled,
+        };
+
+        // 8. Let rejectReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Reject, [[Handler]]: onRejectedJobCallback }.
+        let reject_reaction = ReactionRecord {
+            capability: result_capability,
+            kind: ReactionKind::Reject,
+            handler: on_rejected,
+        };
+
+        let mut object = promise.borrow_mut();
+        let promise = object
+            .as_promise_mut()
+            .expect("reactions can only be added to promises");
+
+        match &promise.state {
+            // 9. If promise.[[PromiseState]] is pending, then
+            PromiseState::Pending => {
+                // a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
+                promise.fulfill_reactions.push(fulfill_reaction);
+                // b. Append rejectReaction as the last element of the List that is promise.[[PromiseRejectReactions]].
+                promise.reject_reactions.push(reject_reaction);
+            }
+            // 10. Else if promise.[[PromiseState]] is fulfilled, then
+            PromiseState::Fulfilled(value) => {
+                // a. Let value be promise.[[PromiseResult]].
+                // b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
+                // c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
+                context.enqueue_job(Self::promise_reaction_job(fulfill_reaction, value.clone()));
+            }
+            // 11. Else,
+            PromiseState::Rejected(reason) => {
+                // a. Assert: The value of promise.[[PromiseState]] is rejected.
+                // b. Let reason be promise.[[PromiseResult]].
+                // c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+                // d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
+                // e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
+                context.enqueue_job(Self::promise_reaction_job(reject_reaction, reason.clone()));
+            }
+        }
+
+        // 12. Set promise.[[PromiseIsHandled]] to true.
+        promise.handled = true;
+
+        // 13. If resultCapability is undefined, then
+        //     a. Return undefined.
+        // 14. Else,
+        //     a. Return resultCapability.[[Promise]].
+        result_promise
+    }
+
+    /// Calls the `then` method of a value, like `Invoke(promise, "then", args)`.
+    fn invoke_then(
+        promise: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let then = promise.get_field("then", context)?;
+        context.call(&then, promise, args)
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// Registers the handlers to call once the promise is fulfilled or rejected, and returns a
+    /// new promise resolved with the result of the handler.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. If IsPromise(promise) is false, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(promise) if promise.is_promise() => promise,
+            _ => return context.throw_type_error("Promise.prototype.then called on a non-promise"),
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let default_constructor = context.standard_objects().promise_object().constructor();
+        let constructor = promise.species_constructor(default_constructor.into(), context)?;
+
+        // 4. Let resultCapability be ? NewPromiseCapability(C).
+        let result_capability = Self::new_promise_capability(&constructor, context)?;
+
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        Ok(Self::perform_promise_then(
+            &promise,
+            &args.get_or_undefined(0).clone(),
+            &args.get_or_undefined(1).clone(),
+            Some(result_capability),
+            context,
+        ))
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// Registers the handler to call once the promise is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. Return ? Invoke(promise, "then", « undefined, onRejected »).
+        let on_rejected = args.get_or_undefined(0).clone();
+        Self::invoke_then(this, &[JsValue::undefined(), on_rejected], context)
+    }
+
+    /// `Promise.prototype.finally ( onFinally )`
+    ///
+    /// Registers the handler to call once the promise settles, keeping the settlement of the
+    /// promise in the returned promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. If Type(promise) is not Object, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(promise) => promise,
+            None => {
+                return context.throw_type_error("Promise.prototype.finally called on a non-object")
+            }
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        // 4. Assert: IsConstructor(C) is true.
+        let default_constructor = context.standard_objects().promise_object().constructor();
+        let constructor = promise.species_constructor(default_constructor.into(), context)?;
+
+        let on_finally = args.get_or_undefined(0).clone();
+        let (then_finally, catch_finally) = if on_finally.is_function() {
+            let captures = FinallyCaptures {
+                on_finally,
+                constructor,
+            };
+
+            // 6. Else,
+            //     a. Let thenFinallyClosure be a new Abstract Closure with parameters (value) that captures onFinally and C and performs the following steps when called:
+            //     b. Let thenFinally be ! CreateBuiltinFunction(thenFinallyClosure, 1, "", « »).
+            let then_finally = FunctionBuilder::closure_with_captures(
+                context,
+                Self::then_finally,
+                captures.clone(),
+            )
+            .length(1)
+            .build();
+
+            //     c. Let catchFinallyClosure be a new Abstract Closure with parameters (reason) that captures onFinally and C and performs the following steps when called:
+            //     d. Let catchFinally be ! CreateBuiltinFunction(catchFinallyClosure, 1, "", « »).
+            let catch_finally =
+                FunctionBuilder::closure_with_captures(context, Self::catch_finally, captures)
+                    .length(1)
+                    .build();
+
+            (then_finally.into(), catch_finally.into())
+        } else {
+            // 5. If IsCallable(onFinally) is false, then
+            //     a. Let thenFinally be onFinally.
+            //     b. Let catchFinally be onFinally.
+            (on_finally.clone(), on_finally)
+        };
+
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        Self::invoke_then(this, &[then_finally, catch_finally], context)
+    }
+
+    /// The `thenFinally` function of `Promise.prototype.finally()`.
+    fn then_finally(
+        _: &JsValue,
+        args: &[JsValue],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let captures = captures
+            .downcast_ref::<FinallyCaptures>()
+            .expect("thenFinally captures onFinally and the constructor")
+            .clone();
+        let (on_finally, constructor) = (captures.on_finally.clone(), captures.constructor.clone());
+        let value = args.get_or_undefined(0).clone();
+
+        // i. Let result be ? Call(onFinally, undefined).
+        let result = context.call(&on_finally, &JsValue::undefined(), &[])?;
+
+        // ii. Let promise be ? PromiseResolve(C, result).
+        let promise = Self::promise_resolve(&constructor, result, context)?;
+
+        // iii. Let returnValue be a new Abstract Closure with no parameters that captures value and performs the following steps when called:
+        //     1. Return value.
+        // iv. Let valueThunk be ! CreateBuiltinFunction(returnValue, 0, "", « »).
+        let value_thunk = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, captures, _| {
+                Ok(captures
+                    .downcast_ref::<JsValue>()
+                    .expect("valueThunk captures the value")
+                    .clone())
+            },
+            value,
+        )
+        .build();
+
+        // v. Return ? Invoke(promise, "then", « valueThunk »).
+        Self::invoke_then(&promise, &[value_thunk.into()], context)
+    }
+
+    /// The `catchFinally` function of `Promise.prototype.finally()`.
+    fn catch_finally(
+        _: &JsValue,
+        args: &[JsValue],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let captures = captures
+            .downcast_ref::<FinallyCaptures>()
+            .expect("catchFinally captures onFinally and the constructor")
+            .clone();
+        let (on_finally, constructor) = (captures.on_finally.clone(), captures.constructor.clone());
+        let reason = args.get_or_undefined(0).clone();
+
+        // i. Let result be ? Call(onFinally, undefined).
+        let result = context.call(&on_finally, &JsValue::undefined(), &[])?;
+
+        // ii. Let promise be ? PromiseResolve(C, result).
+        let promise = Self::promise_resolve(&constructor, result, context)?;
+
+        // iii. Let throwReason be a new Abstract Closure with no parameters that captures reason and performs the following steps when called:
+        //     1. Return ThrowCompletion(reason).
+        // iv. Let thrower be ! CreateBuiltinFunction(throwReason, 0, "", « »).
+        let thrower = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, captures, _| {
+                Err(captures
+                    .downcast_ref::<JsValue>()
+                    .expect("thrower captures the reason")
+                    .clone())
+            },
+            reason,
+        )
+        .build();
+
+        // v. Return ? Invoke(promise, "then", « thrower »).
+        Self::invoke_then(&promise, &[thrower.into()], context)
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// Returns a promise resolved with the given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. If Type(C) is not Object, throw a TypeError exception.
+        if !this.is_object() {
+            return context.throw_type_error("Promise.resolve called on a non-object");
+        }
+
+        // 3. Return ? PromiseResolve(C, x).
+        Self::promise_resolve(this, args.get_or_undefined(0).clone(), context)
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// Returns a promise rejected with the given reason.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(this, context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        let reason = args.get_or_undefined(0).clone();
+        capability
+            .reject
+            .call(&JsValue::undefined(), &[reason], context)?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone().into())
+    }
+
+    /// `Promise.withResolvers ( )`
+    ///
+    /// Returns an object holding a new promise, along with the functions resolving and rejecting
+    /// it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-promise-with-resolvers/#sec-promise.withResolvers
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
+    pub(crate) fn with_resolvers(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(this, context)?;
+
+        // 3. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+        let obj = context.construct_object();
+
+        // 4. Perform ! CreateDataPropertyOrThrow(obj, "promise", promiseCapability.[[Promise]]).
+        obj.create_data_property_or_throw("promise", capability.promise.clone(), context)
+            .expect("CreateDataPropertyOrThrow should never fail here");
+
+        // 5. Perform ! CreateDataPropertyOrThrow(obj, "resolve", promiseCapability.[[Resolve]]).
+        obj.create_data_property_or_throw("resolve", capability.resolve.clone(), context)
+            .expect("CreateDataPropertyOrThrow should never fail here");
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "reject", promiseCapability.[[Reject]]).
+        obj.create_data_property_or_throw("reject", capability.reject.clone(), context)
+            .expect("CreateDataPropertyOrThrow should never fail here");
+
+        // 7. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `Promise.all ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the values of all the given promises, or rejected with
+    /// the reason of the first one to be rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(this, args, Combinator::All, context)
+    }
+
+    /// `Promise.allSettled ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the outcomes of all the given promises, once they have
+    /// all settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(this, args, Combinator::AllSettled, context)
+    }
+
+    /// `Promise.any ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the value of the first of the given promises to be
+    /// fulfilled, or rejected with an `AggregateError` if all of them are rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(this, args, Combinator::Any, context)
+    }
+
+    /// `Promise.race ( iterable )`
+    ///
+    /// Returns a promise settled the same way as the first of the given promises to settle.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(this, args, Combinator::Race, context)
+    }
+
+    /// The steps shared by the promise combinators, up to their `PerformPromiseX` operation.
+    fn combinator(
+        this: &JsValue,
+        args: &[JsValue],
+        combinator: Combinator,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = Self::new_promise_capability(this, context)?;
+
+        // 3. Let promiseResolve be GetPromiseResolve(C).
+        // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+        let promise_resolve = match Self::get_promise_resolve(this, context) {
+            Ok(promise_resolve) => promise_resolve,
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 5. Let iteratorRecord be GetIterator(iterable).
+        // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+        let iterable = args.get_or_undefined(0).clone();
+        let iterator_record = match get_iterator(context, iterable) {
+            Ok(iterator_record) => iterator_record,
+            Err(error) => return capability.reject_abrupt(error, context),
+        };
+
+        // 7. Let result be PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve).
+        let mut done = false;
+        let result = Self::perform_combinator(
+            combinator,
+            &iterator_record,
+            &mut done,
+            this,
+            &capability,
+            &promise_resolve,
+            context,
+        );
+
+        // 8. If result is an abrupt completion, then
+        match result {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                // a. If iteratorRecord.[[Done]] is false, set result to IteratorClose(iteratorRecord, result).
+                let error = if done {
+                    error
+                } else {
+                    iterator_record
+                        .close(Err(error), context)
+                        .expect_err("closing an iterator keeps an abrupt completion")
+                };
+
+                // b. IfAbruptRejectPromise(result, promiseCapability).
+                capability.reject_abrupt(error, context)
+            }
+        }
+    }
+
+    /// `GetPromiseResolve ( promiseConstructor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getpromiseresolve
+    fn get_promise_resolve(constructor: &JsValue, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Assert: IsConstructor(promiseConstructor) is true.
+        // 2. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+        let promise_resolve = constructor.get_field("resolve", context)?;
+
+        // 3. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+        if !promise_resolve.is_function() {
+            return context.throw_type_error("promise constructor has no resolve function");
+        }
+
+        // 4. Return promiseResolve.
+        Ok(promise_resolve)
+    }
+
+    /// `PerformPromiseAll`, `PerformPromiseAllSettled`, `PerformPromiseAny` and
+    /// `PerformPromiseRace`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_combinator(
+        combinator: Combinator,
+        iterator_record: &IteratorRecord,
+        done: &mut bool,
+        constructor: &JsValue,
+        capability: &PromiseCapability,
+        promise_resolve: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let values be a new empty List.
+        let values = Gc::new(GcCell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements = Rc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be IteratorStep(iteratorRecord).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            // e. Let nextValue be IteratorValue(next).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            let next = iterator_record.step(context).map_err(|error| {
+                *done = true;
+                error
+            })?;
+
+            // d. If next is false, then
+            let next_value = if let Some(next_value) = next {
+                next_value
+            } else {
+                // i. Set iteratorRecord.[[Done]] to true.
+                *done = true;
+
+                if combinator != Combinator::Race {
+                    // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    remaining_elements.set(remaining_elements.get() - 1);
+
+                    // iii. If remainingElementsCount.[[Value]] is 0, then
+                    if remaining_elements.get() == 0 {
+                        let values = values.borrow().clone();
+                        combinator.settle(values, capability, context)?;
+                    }
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(capability.promise.clone().into());
+            };
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise = context.call(promise_resolve, constructor, &[next_value])?;
+
+            if combinator == Combinator::Race {
+                // Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], resultCapability.[[Reject]] »).
+                Self::invoke_then(
+                    &next_promise,
+                    &[
+                        capability.resolve.clone().into(),
+                        capability.reject.clone().into(),
+                    ],
+                    context,
+                )?;
+                continue;
+            }
+
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // j. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
+            // k. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
+            // l. Let onFulfilled be ! CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // m. Set onFulfilled.[[AlreadyCalled]] to false.
+            // n. Set onFulfilled.[[Index]] to index.
+            // o. Set onFulfilled.[[Values]] to values.
+            // p. Set onFulfilled.[[Capability]] to resultCapability.
+            // q. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            let captures = ElementCaptures {
+                combinator,
+                kind: ReactionKind::Fulfill,
+                already_called: Rc::new(Cell::new(false)),
+                index,
+                values: values.clone(),
+                capability: capability.clone(),
+                remaining_elements: remaining_elements.clone(),
+            };
+            let mut element_function = |kind| -> JsValue {
+                let mut captures = captures.clone();
+                captures.kind = kind;
+                FunctionBuilder::closure_with_captures(context, Self::element_function, captures)
+                    .length(1)
+                    .build()
+                    .into()
+            };
+            let (on_fulfilled, on_rejected) = match combinator {
+                Combinator::All => (
+                    element_function(ReactionKind::Fulfill),
+                    capability.reject.clone().into(),
+                ),
+                Combinator::AllSettled => (
+                    element_function(ReactionKind::Fulfill),
+                    element_function(ReactionKind::Reject),
+                ),
+                Combinator::Any => (
+                    capability.resolve.clone().into(),
+                    element_function(ReactionKind::Reject),
+                ),
+                Combinator::Race => unreachable!(),
+            };
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            remaining_elements.set(remaining_elements.get() + 1);
+
+            // s. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+            Self::invoke_then(&next_promise, &[on_fulfilled, on_rejected], context)?;
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `Promise.all Resolve Element Functions`, `Promise.allSettled Resolve Element Functions`,
+    /// `Promise.allSettled Reject Element Functions` and `Promise.any Reject Element Functions`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+    fn element_function(
+        _: &JsValue,
+        args: &[JsValue],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let captures = captures
+            .downcast_ref::<ElementCaptures>()
+            .expect("element functions capture the combinator state")
+            .clone();
+        let x = args.get_or_undefined(0).clone();
+
+        // 1. Let F be the active function object.
+        // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+        // 3. If alreadyCalled.[[Value]] is true, return undefined.
+        if captures.already_called.get() {
+            return Ok(JsValue::undefined());
+        }
+
+        // 4. Set alreadyCalled.[[Value]] to true.
+        captures.already_called.set(true);
+
+        let value = if captures.combinator == Combinator::AllSettled {
+            // 9. Let obj be ! OrdinaryObjectCreate(%Object.prototype%).
+            let obj = context.construct_object();
+            let (status, key) = match captures.kind {
+                ReactionKind::Fulfill => ("fulfilled", "value"),
+                ReactionKind::Reject => ("rejected", "reason"),
+            };
+
+            // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "fulfilled").
+            obj.create_data_property_or_throw("status", status, context)
+                .expect("CreateDataPropertyOrThrow on a new object cannot fail");
+
+            // 11. Perform ! CreateDataPropertyOrThrow(obj, "value", x).
+            obj.create_data_property_or_throw(key, x, context)
+                .expect("CreateDataPropertyOrThrow on a new object cannot fail");
+            obj.into()
+        } else {
+            x
+        };
+
+        // 5. Let index be F.[[Index]].
+        // 6. Let values be F.[[Values]].
+        // 7. Let promiseCapability be F.[[Capability]].
+        // 8. Let remainingElementsCount be F.[[RemainingElements]].
+        // 9. Set values[index] to x.
+        captures.values.borrow_mut()[captures.index] = value;
+
+        // 10. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+        let remaining_elements = captures.remaining_elements.get() - 1;
+        captures.remaining_elements.set(remaining_elements);
+
+        // 11. If remainingElementsCount.[[Value]] is 0, then
+        if remaining_elements == 0 {
+            let values = captures.values.borrow().clone();
+            return captures
+                .combinator
+                .settle(values, &captures.capability, context);
+        }
+
+        // 12. Return undefined.
+        Ok(JsValue::undefined())
+    }
+}
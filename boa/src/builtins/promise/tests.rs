@@ -0,0 +1,244 @@
+use crate::{
+    builtins::promise::PromiseState, forward, object::JsPromise, property::Attribute, Context,
+};
+
+#[test]
+fn reactions_run_as_jobs() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then(v => log.push("then " + v));
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"sync\"");
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"sync,then 1\"");
+}
+
+#[test]
+fn executor() {
+    let mut context = Context::new();
+    let init = r#"
+        var value;
+        var reason;
+        new Promise((resolve, reject) => { resolve(1); resolve(2); reject(3); })
+            .then(v => value = v);
+        new Promise(() => { throw "error"; })
+            .catch(r => reason = r);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "value"), "1");
+    assert_eq!(forward(&mut context, "reason"), "\"error\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Promise(() => {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new Promise(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn then_chain() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then(v => v + 1)
+            .then(v => { throw v + 1; })
+            .then(() => "skipped")
+            .catch(v => v + 1)
+            .then(v => result = v);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "4");
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var self_resolution;
+        var thenable = { then(resolve) { resolve("thenable"); } };
+        Promise.resolve(thenable).then(v => result = v);
+        var p = new Promise(resolve => Promise.resolve().then(() => resolve(p)));
+        p.catch(e => self_resolution = e.name);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"thenable\"");
+    assert_eq!(forward(&mut context, "self_resolution"), "\"TypeError\"");
+}
+
+#[test]
+fn resolve_returns_same_promise() {
+    let mut context = Context::new();
+    let init = r#"
+        var p = Promise.resolve(1);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "Promise.resolve(p) === p"), "true");
+    assert_eq!(forward(&mut context, "p.then() !== p"), "true");
+}
+
+#[test]
+fn finally() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1)
+            .finally(() => { log.push("finally"); return 2; })
+            .then(v => log.push(v));
+        Promise.reject(3)
+            .finally(() => log.push("finally"))
+            .catch(v => log.push(v));
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"finally,finally,1,3\""
+    );
+}
+
+#[test]
+fn all() {
+    let mut context = Context::new();
+    let init = r#"
+        var values;
+        var reason;
+        Promise.all([1, Promise.resolve(2), new Promise(r => r(3))]).then(v => values = v);
+        Promise.all([1, Promise.reject("rejected"), 3]).catch(r => reason = r);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "values.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "reason"), "\"rejected\"");
+}
+
+#[test]
+fn all_settled() {
+    let mut context = Context::new();
+    let init = r#"
+        var outcomes;
+        Promise.allSettled([Promise.resolve(1), Promise.reject(2)])
+            .then(v => outcomes = v.map(o => o.status + ":" + (o.value || o.reason)));
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "outcomes.join()"),
+        "\"fulfilled:1,rejected:2\""
+    );
+}
+
+#[test]
+fn any() {
+    let mut context = Context::new();
+    let init = r#"
+        var value;
+        var error;
+        Promise.any([Promise.reject(1), Promise.resolve(2)]).then(v => value = v);
+        Promise.any([Promise.reject(1), Promise.reject(2)]).catch(e => error = e);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "value"), "2");
+    assert_eq!(
+        forward(&mut context, "error instanceof AggregateError"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "error.errors.join()"), "\"1,2\"");
+}
+
+#[test]
+fn race() {
+    let mut context = Context::new();
+    let init = r#"
+        var value;
+        Promise.race([new Promise(() => {}), Promise.resolve(1), Promise.resolve(2)])
+            .then(v => value = v);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "value"), "1");
+}
+
+#[test]
+fn combinator_rejects_non_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        var reason;
+        Promise.all(5).catch(e => reason = e.name);
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "reason"), "\"TypeError\"");
+}
+
+#[test]
+fn species_and_to_string_tag() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "Promise[Symbol.species] === Promise"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(Promise.resolve())"
+        ),
+        "\"[object Promise]\""
+    );
+}
+
+#[test]
+fn with_resolvers() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var { promise, resolve, reject } = Promise.withResolvers();
+        promise.then(v => log.push("then " + v));
+        resolve(1);
+        reject(2);
+        var keys = Object.keys(Promise.withResolvers()).join();
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"then 1\"");
+    assert_eq!(forward(&mut context, "keys"), "\"promise,resolve,reject\"");
+    assert_eq!(forward(&mut context, "Promise.withResolvers.length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Promise.withResolvers.call(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn js_promise_new_pending() {
+    let mut context = Context::new();
+    let (promise, resolvers) = JsPromise::new_pending(&mut context);
+    assert!(matches!(promise.state(), PromiseState::Pending));
+
+    context.register_global_property("promise", promise.clone(), Attribute::all());
+    forward(&mut context, "var reason; promise.catch(r => reason = r);");
+    resolvers.reject("failed", &mut context).unwrap();
+    resolvers.resolve(1, &mut context).unwrap();
+    context.run_jobs().unwrap();
+
+    assert!(matches!(promise.state(), PromiseState::Rejected(_)));
+    assert_eq!(forward(&mut context, "reason"), "\"failed\"");
+    assert!(JsPromise::from_object(promise.into(), &mut context).is_ok());
+    assert!(JsPromise::from_object(context.construct_object(), &mut context).is_err());
+}
@@ -0,0 +1,236 @@
+//! This module implements the global `Proxy` object.
+//!
+//! The `Proxy` object enables you to create a proxy for another object,
+//! which can intercept and redefine fundamental operations for that object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject, Object, ObjectData},
+    property::{Attribute, PropertyDescriptor},
+    teardown::Contents,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal slots of a proxy exotic object.
+///
+/// The target and the handler of the proxy are cleared once the proxy is revoked.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    data: Option<(JsObject, JsObject)>,
+    callable: bool,
+    constructable: bool,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let revocable = FunctionBuilder::native(context, Self::revocable)
+            .name("revocable")
+            .length(2)
+            .constructable(false)
+            .build();
+
+        // The `Proxy` constructor does not have a `prototype` property.
+        let proxy = FunctionBuilder::native(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .constructable(true)
+            .build();
+        proxy.insert_property(
+            "revocable",
+            PropertyDescriptor::builder()
+                .value(revocable)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+
+        (Self::NAME, proxy.into(), Self::attribute())
+    }
+}
+
+impl Proxy {
+    const LENGTH: usize = 2;
+
+    /// Queues the target and the handler of the proxy to be emptied by the teardown of the
+    /// context, unless it is revoked.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        if let Some((target, handler)) = &self.data {
+            contents.push_object(target);
+            contents.push_object(handler);
+        }
+    }
+
+    fn new(target: JsObject, handler: JsObject) -> Self {
+        Self {
+            callable: target.is_callable(),
+            constructable: target.is_constructable(),
+            data: Some((target, handler)),
+        }
+    }
+
+    /// Returns `true` if the target of the proxy has a `[[Call]]` internal method.
+    #[inline]
+    pub(crate) fn is_callable(&self) -> bool {
+        self.callable
+    }
+
+    /// Returns `true` if the target of the proxy has a `[[Construct]]` internal method.
+    #[inline]
+    pub(crate) fn is_constructable(&self) -> bool {
+        self.constructable
+    }
+
+    /// Gets the target and the handler of the proxy, throwing a `TypeError` if it was revoked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validatenonrevokedproxy
+    pub(crate) fn try_data(&self, context: &mut Context) -> JsResult<(JsObject, JsObject)> {
+        self.data.clone().ok_or_else(|| {
+            context.construct_type_error("cannot perform an operation on a revoked proxy")
+        })
+    }
+
+    /// `Proxy ( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Proxy constructor called without new");
+        }
+
+        // 2. Return ? ProxyCreate(target, handler).
+        Self::create(args.get_or_undefined(0), args.get_or_undefined(1), context).map(JsValue::from)
+    }
+
+    /// `ProxyCreate ( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    pub(crate) fn create(
+        target: &JsValue,
+        handler: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If Type(target) is not Object, throw a TypeError exception.
+        let target = target.as_object().ok_or_else(|| {
+            context.construct_type_error("Proxy constructor called with a non-object target")
+        })?;
+
+        // 2. If Type(handler) is not Object, throw a TypeError exception.
+        let handler = handler.as_object().ok_or_else(|| {
+            context.construct_type_error("Proxy constructor called with a non-object handler")
+        })?;
+
+        // 3. Let P be ! MakeBasicObject(« [[ProxyHandler]], [[ProxyTarget]] »).
+        // 4. Set P's essential internal methods to the definitions specified in 10.5.
+        // 5. If IsCallable(target) is true, then
+        //     a. Set P.[[Call]] as specified in 10.5.12.
+        //     b. If IsConstructor(target) is true, then
+        //         i. Set P.[[Construct]] as specified in 10.5.13.
+        // 6. Set P.[[ProxyTarget]] to target.
+        // 7. Set P.[[ProxyHandler]] to handler.
+        let proxy = JsObject::new(Object::create(JsValue::null()));
+        proxy.borrow_mut().data = ObjectData::proxy(Self::new(target, handler));
+
+        // 8. Return P.
+        Ok(proxy)
+    }
+
+    /// `Proxy.revocable ( target, handler )`
+    ///
+    /// Creates a revocable proxy, returning an object with the proxy and a function revoking it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+    fn revocable(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let p be ? ProxyCreate(target, handler).
+        let proxy = Self::create(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 2. Let revokerClosure be a new Abstract Closure with no parameters that captures nothing and performs the following steps when called:
+        // 3. Let revoker be ! CreateBuiltinFunction(revokerClosure, 0, "", « [[RevocableProxy]] »).
+        // 4. Set revoker.[[RevocableProxy]] to p.
+        let revoker = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, captures, _| {
+                // a. Let F be the active function object.
+                // b. Let p be F.[[RevocableProxy]].
+                // c. If p is null, return undefined.
+                // d. Set F.[[RevocableProxy]] to null.
+                let proxy = captures
+                    .downcast_mut::<Option<JsObject>>()
+                    .expect("revoker captures the revocable proxy")
+                    .take();
+
+                // e. Assert: p is a Proxy object.
+                // f. Set p.[[ProxyTarget]] to null.
+                // g. Set p.[[ProxyHandler]] to null.
+                if let Some(proxy) = proxy {
+                    proxy
+                        .borrow_mut()
+                        .as_proxy_mut()
+                        .expect("the revocable proxy is a proxy")
+                        .data = None;
+                }
+
+                // h. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            Some(proxy.clone()),
+        )
+        .build();
+
+        // 5. Let result be ! OrdinaryObjectCreate(%Object.prototype%).
+        let result = context.construct_object();
+
+        // 6. Perform ! CreateDataPropertyOrThrow(result, "proxy", p).
+        result
+            .create_data_property_or_throw("proxy", proxy, context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+
+        // 7. Perform ! CreateDataPropertyOrThrow(result, "revoke", revoker).
+        result
+            .create_data_property_or_throw("revoke", revoker, context)
+            .expect("CreateDataPropertyOrThrow cannot fail here");
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+}
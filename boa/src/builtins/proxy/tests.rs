@@ -0,0 +1,272 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Proxy.length"), "2");
+    assert_eq!(forward(&mut context, "Proxy.prototype"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Proxy({}, {})"),
+        "Uncaught \"TypeError\": \"Proxy constructor called without new\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Proxy(1, {})"),
+        "Uncaught \"TypeError\": \"Proxy constructor called with a non-object target\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Proxy({}, null)"),
+        "Uncaught \"TypeError\": \"Proxy constructor called with a non-object handler\""
+    );
+}
+
+#[test]
+fn forwarding_without_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { a: 1 };
+        var proxy = new Proxy(target, {});
+        proxy.b = 2;
+        delete proxy.a;
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "target.b"), "2");
+    assert_eq!(forward(&mut context, "'a' in target"), "false");
+    assert_eq!(forward(&mut context, "Object.keys(proxy).join()"), "\"b\"");
+    assert_eq!(forward(&mut context, "'b' in proxy"), "true");
+}
+
+#[test]
+fn traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var log = [];
+        var handler = {
+            get: function (t, k, r) { log.push("get " + String(k)); return k === "x" ? 42 : Reflect.get(t, k, r); },
+            set: function (t, k, v, r) { log.push("set " + k); return Reflect.set(t, k, v * 2, r); },
+            has(t, k) { log.push("has " + k); return k === "hidden" ? false : Reflect.has(t, k); },
+            deleteProperty(t, k) { log.push("delete " + k); return Reflect.deleteProperty(t, k); },
+            defineProperty(t, k, d) { log.push("define " + k); return Reflect.defineProperty(t, k, d); },
+            getOwnPropertyDescriptor(t, k) { log.push("gopd " + k); return Reflect.getOwnPropertyDescriptor(t, k); },
+            ownKeys(t) { log.push("ownKeys"); return ["hidden", "z"]; },
+            getPrototypeOf(t) { log.push("getPrototypeOf"); return Array.prototype; },
+            setPrototypeOf(t, p) { log.push("setPrototypeOf"); return false; },
+            isExtensible(t) { log.push("isExtensible"); return Reflect.isExtensible(t); },
+            preventExtensions(t) { log.push("preventExtensions"); return false; },
+        };
+        var target = { hidden: 1 };
+        var proxy = new Proxy(target, handler);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy.x"), "42");
+    assert_eq!(forward(&mut context, "proxy.y = 2; target.y"), "4");
+    assert_eq!(forward(&mut context, "'hidden' in proxy"), "false");
+    assert_eq!(forward(&mut context, "delete proxy.y"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(proxy, 'z', { value: 1 })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(proxy, 'z').value"
+        ),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(proxy).join()"),
+        "\"hidden,z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(proxy) === Array.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(proxy, null)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Reflect.isExtensible(proxy)"), "true");
+    assert_eq!(
+        forward(&mut context, "Reflect.preventExtensions(proxy)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"get x,set y,gopd y,define y,has hidden,delete y,define z,gopd z,ownKeys,getPrototypeOf,setPrototypeOf,isExtensible,preventExtensions\""
+    );
+}
+
+#[test]
+fn apply_and_construct() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function add(a, b) { return this.base + a + b; }
+        var calls = new Proxy(add, {
+            apply(t, thisArg, args) { return Reflect.apply(t, thisArg, args) * 10; },
+        });
+        var base = { base: 1, calls: calls };
+        function Point(x) { this.x = x; }
+        var points = new Proxy(Point, {
+            construct(t, args, newTarget) { return { x: args[0] + 1, same: newTarget === points }; },
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "base.calls(2, 3)"), "60");
+    assert_eq!(forward(&mut context, "typeof calls"), "\"function\"");
+    assert_eq!(forward(&mut context, "new points(1).x"), "2");
+    assert_eq!(forward(&mut context, "new points(1).same"), "true");
+    assert_eq!(
+        forward(&mut context, "new (new Proxy(Point, {}))(5).x"),
+        "5"
+    );
+    assert_eq!(
+        forward(&mut context, "typeof new Proxy({}, {})"),
+        "\"object\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Proxy({}, {})()"),
+        "Uncaught \"TypeError\": \"not a function\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new (new Proxy(Point, { construct() { return 1; } }))()"
+        ),
+        "Uncaught \"TypeError\": \"Proxy trap constructed a non-object\""
+    );
+}
+
+#[test]
+fn invariants() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var frozen = {};
+        Object.defineProperty(frozen, 'a', { value: 1 });
+        var lying = new Proxy(frozen, {
+            get: function () { return 2; },
+            has() { return false; },
+            ownKeys() { return []; },
+            deleteProperty() { return true; },
+            getOwnPropertyDescriptor() { return undefined; },
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "lying.a"),
+        "Uncaught \"TypeError\": \"Proxy trap returned a different value for a non-writable, non-configurable property\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a' in lying"),
+        "Uncaught \"TypeError\": \"Proxy trap reported a non-configurable property as non-existent\""
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(lying)"),
+        "Uncaught \"TypeError\": \"Proxy trap result is missing a non-configurable key\""
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.deleteProperty(lying, 'a')"),
+        "Uncaught \"TypeError\": \"Proxy trap deleted a non-configurable property\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyDescriptor(lying, 'a')"),
+        "Uncaught \"TypeError\": \"Proxy trap reported a non-configurable property as non-existent\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.ownKeys(new Proxy({}, { ownKeys() { return ['a', 'a']; } }))"
+        ),
+        "Uncaught \"TypeError\": \"Proxy trap result contains duplicate keys\""
+    );
+}
+
+#[test]
+fn revocable() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var { proxy, revoke } = Proxy.revocable({ a: 1 }, {});
+        var before = proxy.a;
+        revoke();
+        revoke();
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "before"), "1");
+    assert_eq!(forward(&mut context, "revoke.length"), "0");
+    assert_eq!(
+        forward(&mut context, "proxy.a"),
+        "Uncaught \"TypeError\": \"cannot perform an operation on a revoked proxy\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.isArray(proxy)"),
+        "Uncaught \"TypeError\": \"cannot perform an operation on a revoked proxy\""
+    );
+}
+
+#[test]
+fn is_array() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Array.isArray(new Proxy([], {}))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.isArray(new Proxy(new Proxy([], {}), {}))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.isArray(new Proxy({}, {}))"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(new Proxy([1, 2], {}))"),
+        "\"[1,2]\""
+    );
+}
+
+#[test]
+fn prototype_cycle_through_proxy() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = {};
+        var proxy = new Proxy(target, {});
+        var obj = Object.create(proxy);
+        "#;
+
+    forward(&mut context, init);
+
+    // The cycle check stops at the proxy, whose [[GetPrototypeOf]] is not the ordinary one.
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(target, obj)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(target, target)"),
+        "false"
+    );
+}
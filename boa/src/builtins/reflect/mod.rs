@@ -12,9 +12,11 @@
 
 use crate::{
     builtins::{self, BuiltIn},
+    error::JsErrorCode,
     object::ObjectInitializer,
     property::Attribute,
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     BoaProfiler, Context, JsResult, JsValue,
 };
 
@@ -31,7 +33,7 @@ impl BuiltIn for Reflect {
     const NAME: &'static str = "Reflect";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -56,12 +58,8 @@ impl BuiltIn for Reflect {
             .function(Self::own_keys, "ownKeys", 1)
             .function(Self::prevent_extensions, "preventExtensions", 1)
             .function(Self::set, "set", 3)
-            .function(Self::set_prototype_of, "setPrototypeOf", 3)
-            .property(
-                to_string_tag,
-                Self::NAME,
-                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
+            .function(Self::set_prototype_of, "setPrototypeOf", 2)
+            .property(to_string_tag, Self::NAME, Attribute::readonly_builtin())
             .build();
         (Self::NAME, object.into(), Self::attribute())
     }
@@ -77,15 +75,20 @@ impl Reflect {
     /// [spec]: https://tc39.es/ecma262/#sec-reflect.apply
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply
     pub(crate) fn apply(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be a function"))?;
-        let this_arg = args.get(1).cloned().unwrap_or_default();
-        let args_list = args.get(2).cloned().unwrap_or_default();
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_A_FUNCTION,
+                "target must be a function",
+            )
+        })?;
+        let this_arg = args.get_or_undefined(1).clone();
+        let args_list = args.get_or_undefined(2).clone();
 
         if !target.is_callable() {
-            return context.throw_type_error("target must be a function");
+            return context.throw_coded_error(
+                JsErrorCode::TARGET_NOT_A_FUNCTION,
+                "target must be a function",
+            );
         }
         let args = args_list.create_list_from_array_like(&[], context)?;
         target.call(&this_arg, &args, context)
@@ -104,14 +107,19 @@ impl Reflect {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be a function"))?;
-        let args_list = args.get(1).cloned().unwrap_or_default();
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_A_FUNCTION,
+                "target must be a function",
+            )
+        })?;
+        let args_list = args.get_or_undefined(1).clone();
 
         if !target.is_constructable() {
-            return context.throw_type_error("target must be a constructor");
+            return context.throw_coded_error(
+                JsErrorCode::TARGET_NOT_A_CONSTRUCTOR,
+                "target must be a constructor",
+            );
         }
 
         let new_target = if let Some(new_target) = args.get(2) {
@@ -141,15 +149,22 @@ impl Reflect {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let undefined = JsValue::undefined();
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
         let prop_desc: JsValue = args
             .get(2)
             .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("property descriptor must be an object"))?
+            .ok_or_else(|| {
+                context.construct_coded_error(
+                    JsErrorCode::DESCRIPTOR_NOT_AN_OBJECT,
+                    "property descriptor must be an object",
+                )
+            })?
             .into();
 
         target
@@ -171,10 +186,12 @@ impl Reflect {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let undefined = JsValue::undefined();
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
 
         Ok(target.__delete__(&key, context)?.into())
@@ -191,10 +208,12 @@ impl Reflect {
     pub(crate) fn get(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let undefined = JsValue::undefined();
         // 1. If Type(target) is not Object, throw a TypeError exception.
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         // 2. Let key be ? ToPropertyKey(propertyKey).
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
         // 3. If receiver is not present, then
@@ -223,7 +242,12 @@ impl Reflect {
     ) -> JsResult<JsValue> {
         match args.get(0) {
             Some(v) if v.is_object() => (),
-            _ => return context.throw_type_error("target must be an object"),
+            _ => {
+                return context.throw_coded_error(
+                    JsErrorCode::TARGET_NOT_AN_OBJECT,
+                    "target must be an object",
+                )
+            }
         }
         // This function is the same as Object.prototype.getOwnPropertyDescriptor, that why
         // it is invoked here.
@@ -243,10 +267,12 @@ impl Reflect {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         target.__get_prototype_of__(context)
     }
 
@@ -259,10 +285,12 @@ impl Reflect {
     /// [spec]: https://tc39.es/ecma262/#sec-reflect.has
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has
     pub(crate) fn has(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         let key = args
             .get(1)
             .unwrap_or(&JsValue::undefined())
@@ -283,10 +311,12 @@ impl Reflect {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         Ok(target.__is_extensible__(context)?.into())
     }
 
@@ -303,10 +333,12 @@ impl Reflect {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
 
         let keys: Vec<JsValue> = target
             .__own_property_keys__(context)?
@@ -330,10 +362,12 @@ impl Reflect {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let mut target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
 
         Ok(target.__prevent_extensions__(context)?.into())
     }
@@ -348,10 +382,12 @@ impl Reflect {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set
     pub(crate) fn set(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let undefined = JsValue::undefined();
-        let target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
         let value = args.get(2).unwrap_or(&undefined);
         let receiver = if let Some(receiver) = args.get(3).cloned() {
@@ -378,13 +414,18 @@ impl Reflect {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let undefined = JsValue::undefined();
-        let mut target = args
-            .get(0)
-            .and_then(|v| v.as_object())
-            .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        let mut target = args.get(0).and_then(|v| v.as_object()).ok_or_else(|| {
+            context.construct_coded_error(
+                JsErrorCode::TARGET_NOT_AN_OBJECT,
+                "target must be an object",
+            )
+        })?;
         let proto = args.get(1).unwrap_or(&undefined);
         if !proto.is_null() && !proto.is_object() {
-            return context.throw_type_error("proto must be an object or null");
+            return context.throw_coded_error(
+                JsErrorCode::INVALID_PROTOTYPE,
+                "proto must be an object or null",
+            );
         }
         Ok(target.__set_prototype_of__(proto.clone(), context)?.into())
     }
@@ -189,3 +189,77 @@ fn set_prototype_of() {
 
     assert_eq!(forward(&mut context, "p.name"), "\"F\"");
 }
+
+#[test]
+fn construct_with_new_target() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function F() { this.a = 1 };
+        function G() {};
+        let obj = Reflect.construct(F, [], G);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.a"), "1");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(obj) === G.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Reflect.construct(F, [], undefined) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn function_lengths() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Reflect.apply.length"), "3");
+    assert_eq!(forward(&mut context, "Reflect.construct.length"), "2");
+    assert_eq!(forward(&mut context, "Reflect.set.length"), "3");
+    assert_eq!(forward(&mut context, "Reflect.setPrototypeOf.length"), "2");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Reflect)"),
+        "\"[object Reflect]\""
+    );
+}
+
+#[test]
+fn proxy_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let log = [];
+        let handler = {
+            get: function(t, k, r) { log.push('get'); return Reflect.get(t, k, r); },
+            has: function(t, k) { log.push('has'); return Reflect.has(t, k); },
+            ownKeys: function(t) { log.push('ownKeys'); return Reflect.ownKeys(t); },
+            deleteProperty: function(t, k) {
+                log.push('deleteProperty');
+                return Reflect.deleteProperty(t, k);
+            },
+        };
+        let proxy = new Proxy({ a: 1, b: 2 }, handler);
+        let a = Reflect.get(proxy, 'a');
+        let hasB = Reflect.has(proxy, 'b');
+        let keys = Reflect.ownKeys(proxy);
+        Reflect.deleteProperty(proxy, 'a');
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "1");
+    assert_eq!(forward(&mut context, "hasB"), "true");
+    assert_eq!(forward(&mut context, "keys.length"), "2");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"get,has,ownKeys,deleteProperty\""
+    );
+    assert_eq!(forward(&mut context, "Reflect.has(proxy, 'a')"), "false");
+}
@@ -10,18 +10,23 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
 pub mod regexp_string_iterator;
+pub(crate) mod unicode;
 
 use crate::{
     builtins::{array::Array, string, BuiltIn},
+    error::JsErrorCode,
     gc::{empty_trace, Finalize, Trace},
     object::{ConstructorBuilder, FunctionBuilder, JsObject, Object, ObjectData, PROTOTYPE},
     property::Attribute,
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     value::{IntegerOrInfinity, JsValue},
     BoaProfiler, Context, JsResult, JsString,
 };
 use regexp_string_iterator::RegExpStringIterator;
 use regress::Regex;
+use rustc_hash::FxHashMap;
+use std::{rc::Rc, sync::Arc};
 
 #[cfg(test)]
 mod tests;
@@ -30,7 +35,7 @@ mod tests;
 #[derive(Debug, Clone, Finalize)]
 pub struct RegExp {
     /// Regex matcher.
-    matcher: Regex,
+    matcher: Arc<Regex>,
 
     /// Update last_index, set if global or sticky flags are set.
     use_last_index: bool,
@@ -53,6 +58,12 @@ pub struct RegExp {
     /// Flag 'u' - Unicode.
     unicode: bool,
 
+    /// Flag 'v' - Unicode sets.
+    unicode_sets: bool,
+
+    /// The names of the capturing groups of the pattern, in the order they appear in it.
+    group_names: Rc<[JsString]>,
+
     original_source: JsString,
     original_flags: JsString,
 }
@@ -62,11 +73,47 @@ unsafe impl Trace for RegExp {
     empty_trace!();
 }
 
+/// The matchers compiled in a context, by pattern and flags.
+///
+/// Creating a `RegExp` with the same pattern and flags as a previous one, as regular expression
+/// literals evaluated in a loop do, reuses the matcher of the previous one.
+#[derive(Debug, Default)]
+pub(crate) struct RegExpCache {
+    matchers: FxHashMap<(JsString, JsString), Arc<Regex>>,
+}
+
+impl RegExpCache {
+    /// The number of matchers kept before the cache is emptied, bounding its memory usage for
+    /// scripts creating many distinct patterns.
+    const CAPACITY: usize = 1024;
+
+    /// Gets the matcher of the pattern `p` with the flags `f`, compiling it if it isn't cached.
+    ///
+    /// With the `shared-cache` feature, matchers missing from this cache are looked up in the
+    /// cache shared by every context before being compiled.
+    fn get_or_compile(&mut self, p: &JsString, f: &JsString) -> Result<Arc<Regex>, regress::Error> {
+        let key = (p.clone(), f.clone());
+        if let Some(matcher) = self.matchers.get(&key) {
+            return Ok(matcher.clone());
+        }
+
+        #[cfg(feature = "shared-cache")]
+        let matcher = crate::cache::matcher(p, f)?;
+        #[cfg(not(feature = "shared-cache"))]
+        let matcher = Arc::new(unicode::compile(p, f)?);
+        if self.matchers.len() >= Self::CAPACITY {
+            self.matchers.clear();
+        }
+        self.matchers.insert(key, matcher.clone());
+        Ok(matcher)
+    }
+}
+
 impl BuiltIn for RegExp {
     const NAME: &'static str = "RegExp";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -77,7 +124,7 @@ impl BuiltIn for RegExp {
             .constructable(false)
             .build();
 
-        let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+        let flag_attributes = Attribute::readonly_builtin();
 
         let get_global = FunctionBuilder::native(context, Self::get_global)
             .name("get global")
@@ -99,6 +146,10 @@ impl BuiltIn for RegExp {
             .name("get unicode")
             .constructable(false)
             .build();
+        let get_unicode_sets = FunctionBuilder::native(context, Self::get_unicode_sets)
+            .name("get unicodeSets")
+            .constructable(false)
+            .build();
         let get_sticky = FunctionBuilder::native(context, Self::get_sticky)
             .name("get sticky")
             .constructable(false)
@@ -158,6 +209,7 @@ impl BuiltIn for RegExp {
         .accessor("multiline", Some(get_multiline), None, flag_attributes)
         .accessor("dotAll", Some(get_dot_all), None, flag_attributes)
         .accessor("unicode", Some(get_unicode), None, flag_attributes)
+        .accessor("unicodeSets", Some(get_unicode_sets), None, flag_attributes)
         .accessor("sticky", Some(get_sticky), None, flag_attributes)
         .accessor("flags", Some(get_flags), None, flag_attributes)
         .accessor("source", Some(get_source), None, flag_attributes)
@@ -176,6 +228,16 @@ impl RegExp {
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 2;
 
+    /// Gets the `[[OriginalSource]]` of the regular expression.
+    pub(crate) fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the `[[OriginalFlags]]` of the regular expression.
+    pub(crate) fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+
     /// `22.2.3.1 RegExp ( pattern, flags )`
     ///
     /// More information:
@@ -187,8 +249,8 @@ impl RegExp {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let pattern = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
-        let flags = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let pattern = args.get_or_undefined(0).clone();
+        let flags = args.get_or_undefined(1).clone();
 
         // 1. Let patternIsRegExp be ? IsRegExp(pattern).
         let pattern_is_regexp = if let JsValue::Object(obj) = &pattern {
@@ -275,8 +337,8 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexpinitialize
     fn initialize(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let pattern = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
-        let flags = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let pattern = args.get_or_undefined(0).clone();
+        let flags = args.get_or_undefined(1).clone();
 
         // 1. If pattern is undefined, let P be the empty String.
         // 2. Else, let P be ? ToString(pattern).
@@ -294,38 +356,64 @@ impl RegExp {
             flags.to_string(context)?
         };
 
-        // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
+        // 5. If F contains any code unit other than "g", "i", "m", "s", "u", "v", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
         let mut global = false;
         let mut ignore_case = false;
         let mut multiline = false;
         let mut dot_all = false;
         let mut unicode = false;
+        let mut unicode_sets = false;
         let mut sticky = false;
         for c in f.chars() {
             match c {
                 'g' if global => {
-                    return context.throw_syntax_error("RegExp flags contains multiple 'g'")
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 'g'",
+                    )
                 }
                 'g' => global = true,
                 'i' if ignore_case => {
-                    return context.throw_syntax_error("RegExp flags contains multiple 'i'")
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 'i'",
+                    )
                 }
                 'i' => ignore_case = true,
                 'm' if multiline => {
-                    return context.throw_syntax_error("RegExp flags contains multiple 'm'")
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 'm'",
+                    )
                 }
                 'm' => multiline = true,
                 's' if dot_all => {
-                    return context.throw_syntax_error("RegExp flags contains multiple 's'")
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 's'",
+                    )
                 }
                 's' => dot_all = true,
                 'u' if unicode => {
-                    return context.throw_syntax_error("RegExp flags contains multiple 'u'")
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 'u'",
+                    )
                 }
                 'u' => unicode = true,
+                'v' if unicode_sets => {
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 'v'",
+                    )
+                }
+                'v' => unicode_sets = true,
                 'y' if sticky => {
-                    return context.throw_syntax_error("RegExp flags contains multiple 'y'")
+                    return context.throw_coded_error(
+                        JsErrorCode::DUPLICATE_REGEXP_FLAG,
+                        "RegExp flags contains multiple 'y'",
+                    )
                 }
                 'y' => sticky = true,
                 c => {
@@ -337,19 +425,42 @@ impl RegExp {
             }
         }
 
+        // 6. If F contains "u" and "v", throw a SyntaxError exception.
+        if unicode && unicode_sets {
+            return context.throw_syntax_error("RegExp flags contains both 'u' and 'v'");
+        }
+
         // 12. Set obj.[[OriginalSource]] to P.
         // 13. Set obj.[[OriginalFlags]] to F.
         // 14. Set obj.[[RegExpMatcher]] to the Abstract Closure that evaluates parseResult by applying the semantics provided in 22.2.2 using patternCharacters as the pattern's List of SourceCharacter values and F as the flag parameters.
-        let matcher = match Regex::with_flags(&p, f.as_ref()) {
+        let matcher = match context.regexp_cache.get_or_compile(&p, &f) {
             Err(error) => {
-                return Err(context
-                    .construct_syntax_error(format!("failed to create matcher: {}", error.text)));
+                return Err(context.construct_coded_error(
+                    JsErrorCode::INVALID_REGEXP,
+                    format!("failed to create matcher: {}", error.text),
+                ));
             }
             Ok(val) => val,
         };
 
+        // It is a Syntax Error if the pattern contains two groups with the same name.
+        let names = group_names(&p);
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                return context.throw_coded_error(
+                    JsErrorCode::DUPLICATE_CAPTURE_GROUP,
+                    format!(
+                        "RegExp pattern contains duplicate capture group name '{}'",
+                        name
+                    ),
+                );
+            }
+        }
+        let group_names = names.into_iter().map(JsString::new).collect();
+
         let regexp = RegExp {
             matcher,
+            group_names,
             use_last_index: global || sticky,
             dot_all,
             global,
@@ -357,6 +468,7 @@ impl RegExp {
             multiline,
             sticky,
             unicode,
+            unicode_sets,
             original_source: p,
             original_flags: f,
         };
@@ -375,11 +487,8 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexpcreate
     pub(crate) fn create(p: JsValue, f: JsValue, context: &mut Context) -> JsResult<JsValue> {
         // 1. Let obj be ? RegExpAlloc(%RegExp%).
-        let obj = RegExp::alloc(
-            &context.global_object().get(RegExp::NAME, context)?,
-            &[],
-            context,
-        )?;
+        let constructor = context.standard_objects().regexp_object().constructor();
+        let obj = RegExp::alloc(&constructor.into(), &[], context)?;
 
         // 2. Return ? RegExpInitialize(obj, P, F).
         RegExp::initialize(&obj, &[p, f], context)
@@ -410,6 +519,7 @@ impl RegExp {
                     's' => regexp.dot_all,
                     'i' => regexp.ignore_case,
                     'u' => regexp.unicode,
+                    'v' => regexp.unicode_sets,
                     'y' => regexp.sticky,
                     _ => unreachable!(),
                 }));
@@ -429,6 +539,7 @@ impl RegExp {
             's' => "dotAll",
             'i' => "ignoreCase",
             'u' => "unicode",
+            'v' => "unicodeSets",
             'y' => "sticky",
             _ => unreachable!(),
         };
@@ -530,6 +641,25 @@ impl RegExp {
         Self::regexp_has_flag(this, 'u', context)
     }
 
+    /// `get RegExp.prototype.unicodeSets`
+    ///
+    /// The unicodeSets property indicates whether or not the "`v`" flag is used with a regular
+    /// expression, enabling set operations and strings in its character classes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicodesets
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicodeSets
+    pub(crate) fn get_unicode_sets(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::regexp_has_flag(this, 'v', context)
+    }
+
     /// `get RegExp.prototype.sticky`
     ///
     /// This flag indicates that it matches only from the index indicated by the `lastIndex` property
@@ -598,13 +728,19 @@ impl RegExp {
                 result.push('u');
             }
 
-            // 14. Let sticky be ! ToBoolean(? Get(R, "sticky")).
-            // 15. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
+            // 14. Let unicodeSets be ! ToBoolean(? Get(R, "unicodeSets")).
+            // 15. If unicodeSets is true, append the code unit 0x0076 (LATIN SMALL LETTER V) as the last code unit of result.
+            if object.get("unicodeSets", context)?.to_boolean() {
+                result.push('v');
+            }
+
+            // 16. Let sticky be ! ToBoolean(? Get(R, "sticky")).
+            // 17. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
             if object.get("sticky", context)?.to_boolean() {
                 result.push('y');
             }
 
-            // 16. Return result.
+            // 18. Return result.
             return Ok(result.into());
         }
 
@@ -862,12 +998,15 @@ impl RegExp {
         // 9. Let matcher be R.[[RegExpMatcher]].
         let matcher = &rx.matcher;
 
-        // 10. If flags contains "u", let fullUnicode be true; else let fullUnicode be false.
-        let unicode = flags.contains('u');
+        // 10. If flags contains "u" or "v", let fullUnicode be true; else let fullUnicode be false.
+        let unicode = flags.contains('u') || flags.contains('v');
 
         // 11. Let matchSucceeded be false.
         // 12. Repeat, while matchSucceeded is false,
         let match_value = loop {
+            // Matching a long input can take a while, so it can be interrupted at each attempt.
+            context.check_interrupt()?;
+
             // a. If lastIndex > length, then
             if last_index > length {
                 // i. If global is true or sticky is true, then
@@ -982,33 +1121,30 @@ impl RegExp {
 
         // 24. If R contains any GroupName, then
         // 25. Else,
-        let named_groups = match_value.named_groups();
-        let groups = if named_groups.clone().count() > 0 {
+        let groups = if rx.group_names.is_empty() {
+            // a. Let groups be undefined.
+            JsValue::undefined()
+        } else {
             // a. Let groups be ! OrdinaryObjectCreate(null).
-            let groups = JsValue::new_object(context);
+            let groups = JsObject::new(Object::with_prototype(
+                JsValue::null(),
+                ObjectData::ordinary(),
+            ));
 
             // Perform 27.f here
             // f. If the ith capture of R was defined with a GroupName, then
             // i. Let s be the CapturingGroupName of the corresponding RegExpIdentifierName.
             // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-            for (name, range) in named_groups {
-                if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
-
-                    groups
-                        .to_object(context)?
-                        .create_data_property_or_throw(name, value, context)
-                        .unwrap();
-                }
+            for name in rx.group_names.iter() {
+                let value = match match_value.named_group(name) {
+                    Some(range) => input.get(range).unwrap_or("").into(),
+                    None => JsValue::undefined(),
+                };
+                groups
+                    .create_data_property_or_throw(name.clone(), value, context)
+                    .unwrap();
             }
-            groups
-        } else {
-            // a. Let groups be undefined.
-            JsValue::undefined()
+            groups.into()
         };
 
         // 26. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
@@ -1090,8 +1226,9 @@ impl RegExp {
         } else {
             // a. Assert: global is true.
 
-            // b. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")).
-            let unicode = rx.get("unicode", context)?.to_boolean();
+            // b. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")), or ! ToBoolean(? Get(rx, "unicodeSets")).
+            let unicode = rx.get("unicode", context)?.to_boolean()
+                || rx.get("unicodeSets", context)?.to_boolean();
 
             // c. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set("lastIndex", 0, true, context)?;
@@ -1234,9 +1371,9 @@ impl RegExp {
         // 10. Else, let global be false.
         let global = flags.contains('g');
 
-        // 11. If flags contains "u", let fullUnicode be true.
+        // 11. If flags contains "u" or "v", let fullUnicode be true.
         // 12. Else, let fullUnicode be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains('u') || flags.contains('v');
 
         // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
         RegExpStringIterator::create_regexp_string_iterator(
@@ -1282,7 +1419,7 @@ impl RegExp {
         let length_arg_str = arg_str.encode_utf16().count();
 
         // 5. Let functionalReplace be IsCallable(replaceValue).
-        let mut replace_value = args.get(1).cloned().unwrap_or_default();
+        let mut replace_value = args.get_or_undefined(1).clone();
         let functional_replace = replace_value.is_function();
 
         // 6. If functionalReplace is false, then
@@ -1297,8 +1434,9 @@ impl RegExp {
         // 8. If global is true, then
         let mut unicode = false;
         if global {
-            // a. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")).
-            unicode = rx.get("unicode", context)?.to_boolean();
+            // a. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")), or ! ToBoolean(? Get(rx, "unicodeSets")).
+            unicode = rx.get("unicode", context)?.to_boolean()
+                || rx.get("unicodeSets", context)?.to_boolean();
 
             // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set("lastIndex", 0, true, context)?;
@@ -1590,9 +1728,9 @@ impl RegExp {
         // 5. Let flags be ? ToString(? Get(rx, "flags")).
         let flags = rx.get("flags", context)?.to_string(context)?;
 
-        // 6. If flags contains "u", let unicodeMatching be true.
+        // 6. If flags contains "u" or "v", let unicodeMatching be true.
         // 7. Else, let unicodeMatching be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains('u') || flags.contains('v');
 
         // 8. If flags contains "y", let newFlags be flags.
         // 9. Else, let newFlags be the string-concatenation of flags and "y".
@@ -1619,7 +1757,7 @@ impl RegExp {
         let mut length_a = 0;
 
         // 13. If limit is undefined, let lim be 2^32 - 1; else let lim be ℝ(? ToUint32(limit)).
-        let limit = args.get(1).cloned().unwrap_or_default();
+        let limit = args.get_or_undefined(1).clone();
         let lim = if limit.is_undefined() {
             u32::MAX
         } else {
@@ -1760,6 +1898,36 @@ impl RegExp {
     }
 }
 
+/// Gets the names of the capturing groups of `pattern`, in the order they appear in it.
+///
+/// The escaped characters and the contents of character classes are skipped, as are lookbehind
+/// assertions, which also start with `(?<`.
+fn group_names(pattern: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut in_class = false;
+    let mut chars = pattern.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => {
+                if let Some(rest) = pattern[i + 1..].strip_prefix("?<") {
+                    if !rest.starts_with('=') && !rest.starts_with('!') {
+                        if let Some(end) = rest.find('>') {
+                            names.push(&rest[..end]);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
 /// `22.2.5.2.3 AdvanceStringIndex ( S, index, unicode )`
 ///
 /// More information:
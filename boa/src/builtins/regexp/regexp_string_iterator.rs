@@ -17,6 +17,7 @@ use crate::{
     object::{JsObject, ObjectData},
     property::PropertyDescriptor,
     symbol::WellKnownSymbols,
+    teardown::Contents,
     BoaProfiler, Context, JsResult, JsString, JsValue,
 };
 
@@ -32,6 +33,11 @@ pub struct RegExpStringIterator {
 
 // TODO: See todos in create_regexp_string_iterator and next.
 impl RegExpStringIterator {
+    /// Queues the matcher to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_value(&self.matcher);
+    }
+
     fn new(matcher: JsValue, string: JsString, global: bool, unicode: bool) -> Self {
         Self {
             matcher,
@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, set_fuel, Context};
 
 #[test]
 fn constructors() {
@@ -237,3 +237,208 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn interrupt_matching() {
+    let mut context = Context::new();
+    forward(&mut context, "var text = 'ab'.repeat(1000);");
+
+    set_fuel(&mut context, 100);
+    assert_eq!(
+        forward(&mut context, "text.replace(/a/g, 'c')"),
+        "Uncaught \"RangeError\": \"out of fuel\""
+    );
+    assert_eq!(
+        forward(&mut context, "text.split(/b/).length"),
+        "Uncaught \"RangeError\": \"out of fuel\""
+    );
+
+    context.clear_interrupt_handler();
+    assert_eq!(forward(&mut context, "text.match(/a/g).length"), "1000");
+}
+
+#[test]
+fn matchers_are_cached() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var regexps = [];
+        for (var i = 0; i < 10; i++) {
+            regexps.push(/a+/g, new RegExp("a+", "g"), new RegExp("a+"));
+        }
+    "#,
+    );
+    assert_eq!(context.regexp_cache.matchers.len(), 2);
+
+    // The instances sharing a matcher keep their own state.
+    assert_eq!(forward(&mut context, "regexps[0].exec('baab').index"), "1");
+    assert_eq!(forward(&mut context, "regexps[0].lastIndex"), "3");
+    assert_eq!(forward(&mut context, "regexps[1].lastIndex"), "0");
+    assert_eq!(forward(&mut context, "regexps[2].test('a')"), "true");
+}
+
+#[test]
+fn named_groups() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var date = /(?<year>\d{4})-(?<month>\d{2})|(?<never>x)/.exec("on 2020-05");
+        "#,
+    );
+    assert_eq!(forward(&mut context, "date.groups.year"), "\"2020\"");
+    assert_eq!(forward(&mut context, "date.groups.month"), "\"05\"");
+    assert_eq!(forward(&mut context, "'never' in date.groups"), "true");
+    assert_eq!(forward(&mut context, "date.groups.never"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(date.groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(date.groups).join()"),
+        "\"year,month,never\""
+    );
+    assert_eq!(forward(&mut context, "/(a)/.exec('a').groups"), "undefined");
+    assert_eq!(forward(&mut context, "/(?<c>.)\\k<c>/.test('aa')"), "true");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "'2020-05'.replace(/(?<y>\\d{4})-(?<m>\\d{2})/, '$<m>/$<y>')"
+        ),
+        "\"05/2020\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'a1b2'.replace(/(?<d>\\d)/g, (m, p, i, s, groups) => groups.d + groups.d)"
+        ),
+        "\"a11b22\""
+    );
+    assert_eq!(
+        forward(&mut context, "'ab'.replace(/(a)/, '$<x>')"),
+        "\"$<x>b\""
+    );
+
+    assert_eq!(
+        forward(&mut context, "new RegExp('(?<n>a)(?<n>b)')"),
+        "Uncaught \"SyntaxError\": \"RegExp pattern contains duplicate capture group name 'n'\""
+    );
+    assert_eq!(
+        forward(&mut context, "/[(?<n>](?<n>a)/.exec('(a').groups.n"),
+        "\"a\""
+    );
+}
+
+#[test]
+fn lookbehind_and_dot_all() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "/(?<=\\$)\\d+/.exec('cost $42')[0]"),
+        "\"42\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<!\\$)\\b\\d+/.exec('cost $42 or 17')[0]"),
+        "\"17\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<=(?<v>\\d)a)b/.exec('1ab').groups.v"),
+        "\"1\""
+    );
+
+    assert_eq!(forward(&mut context, "/a.b/s.test('a\\nb')"), "true");
+    assert_eq!(forward(&mut context, "/a.b/.test('a\\nb')"), "false");
+    assert_eq!(forward(&mut context, "/a.b/s.dotAll"), "true");
+}
+
+#[test]
+fn unicode_property_escapes() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "/\\p{L}+/u.exec('héllo wörld')[0]"),
+        "\"héllo\""
+    );
+    assert_eq!(
+        forward(&mut context, "/\\p{Script=Greek}/u.test('α')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "/\\p{sc=Grek}/u.test('a')"), "false");
+    assert_eq!(forward(&mut context, "/\\P{L}/u.test('a')"), "false");
+    assert_eq!(
+        forward(&mut context, "/[\\p{Lu}\\d]+/u.exec('ABC12def')[0]"),
+        "\"ABC12\""
+    );
+    assert_eq!(forward(&mut context, "/\\p{Lu}/ui.test('a')"), "true");
+    assert_eq!(
+        forward(&mut context, "/^\\p{Emoji_Presentation}$/u.test('😀')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "/\\p{General_Category=Nd}/u.test('٣')"),
+        "true"
+    );
+
+    assert_eq!(
+        forward(&mut context, "new RegExp('\\\\p{Foo}', 'u')"),
+        "Uncaught \"SyntaxError\": \"failed to create matcher: Invalid property name\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RegExp('[a-\\\\p{L}]', 'u')"),
+        "Uncaught \"SyntaxError\": \"failed to create matcher: Invalid character class range\""
+    );
+}
+
+#[test]
+fn unicode_sets() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "/[\\p{L}--[a-z]]/v.test('a')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "/[\\p{L}--[a-z]]/v.test('A')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "/[[a-z]&&[aeiou]]+/v.exec('xaeiy')[0]"),
+        "\"aei\""
+    );
+    assert_eq!(
+        forward(&mut context, "/[\\q{abc|d}x]/v.exec('zzabc')[0]"),
+        "\"abc\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "/^\\p{Emoji_Keycap_Sequence}$/v.test('1\\uFE0F\\u20E3')"
+        ),
+        "true"
+    );
+
+    // With the `v` flag, case folding applies before complementing a class.
+    assert_eq!(forward(&mut context, "/\\P{Lu}/ui.test('A')"), "true");
+    assert_eq!(forward(&mut context, "/\\P{Lu}/vi.test('A')"), "false");
+    assert_eq!(forward(&mut context, "/[^\\P{Lu}]/vi.test('a')"), "true");
+
+    assert_eq!(forward(&mut context, "/a/v.flags"), "\"v\"");
+    assert_eq!(forward(&mut context, "/a/v.unicodeSets"), "true");
+    assert_eq!(forward(&mut context, "/a/v.unicode"), "false");
+
+    assert_eq!(
+        forward(&mut context, "new RegExp('a', 'uv')"),
+        "Uncaught \"SyntaxError\": \"RegExp flags contains both 'u' and 'v'\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RegExp('[a&&&b]', 'v')"),
+        "Uncaught \"SyntaxError\": \"failed to create matcher: Invalid set operation in character class\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RegExp('[(]', 'v')"),
+        "Uncaught \"SyntaxError\": \"failed to create matcher: Invalid character in character class\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RegExp('[^\\\\q{ab}]', 'v')"),
+        "Uncaught \"SyntaxError\": \"failed to create matcher: Negated character class may contain strings\""
+    );
+}
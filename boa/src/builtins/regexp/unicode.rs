@@ -0,0 +1,755 @@
+//! This module implements the Unicode property escapes and the `unicodeSets` mode of regular
+//! expressions, which the matcher doesn't support by itself.
+//!
+//! Patterns with the `u` or `v` flag are translated before being compiled: property escapes like
+//! `\p{Script=Greek}`, and the character classes of the `v` flag with their nested classes, set
+//! operations and strings, are replaced with explicit lists of code point ranges.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-compiletocharset
+
+use icu_collections::codepointinvlist::{CodePointInversionList, CodePointInversionListBuilder};
+use icu_properties::{maps, script, sets, GeneralCategory, GeneralCategoryGroup, Script};
+use regress::{Error, Regex};
+use std::{collections::BTreeSet, fmt::Write};
+
+/// Compiles the pattern `p` with the flags `f`, translating the Unicode features the matcher
+/// doesn't support.
+pub(crate) fn compile(p: &str, f: &str) -> Result<Regex, Error> {
+    if f.contains('u') || f.contains('v') {
+        let pattern = Translator::new(p, f).translate()?;
+        Regex::with_flags(&pattern, f)
+    } else {
+        Regex::with_flags(p, f)
+    }
+}
+
+/// Creates the error returned for an invalid pattern.
+fn error<T>(text: &str) -> Result<T, Error> {
+    Err(Error { text: text.into() })
+}
+
+/// The characters a class escape of the `v` flag can escape without being an identity escape.
+const CLASS_SET_RESERVED_PUNCTUATORS: &str = "&-!#%,:;<=>@`~";
+
+/// The characters that can't be repeated in the class of the `v` flag without being escaped.
+const CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS: &str = "&!#$%*+,.:;<=>?@^`~";
+
+/// The characters that must be escaped in the class of the `v` flag.
+const CLASS_SET_SYNTAX_CHARACTERS: &str = "()[]{}/-\\|";
+
+/// The characters that can be escaped as themselves in Unicode mode.
+const SYNTAX_CHARACTERS: &str = "^$\\.*+?()[]{}|/";
+
+/// A set of code points and strings matched by a character class.
+#[derive(Debug)]
+struct ClassSet {
+    code_points: CodePointInversionList<'static>,
+    strings: BTreeSet<String>,
+}
+
+impl ClassSet {
+    /// Creates an empty set.
+    fn empty() -> Self {
+        Self::from_ranges(std::iter::empty())
+    }
+
+    /// Creates a set with the given ranges of code points.
+    fn from_ranges<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = std::ops::RangeInclusive<u32>>,
+    {
+        let mut builder = CodePointInversionListBuilder::new();
+        for range in ranges {
+            builder.add_range32(&range);
+        }
+        Self {
+            code_points: builder.build(),
+            strings: BTreeSet::new(),
+        }
+    }
+
+    /// Creates the set of the given string, which is a code point if it is a single character.
+    fn from_string(string: String) -> Self {
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::from_ranges([u32::from(c)..=u32::from(c)]),
+            _ => Self {
+                strings: std::iter::once(string).collect(),
+                ..Self::empty()
+            },
+        }
+    }
+
+    /// Applies an operation of the inversion list builder to the code points of the set.
+    fn with_builder<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut CodePointInversionListBuilder),
+    {
+        let mut builder = CodePointInversionListBuilder::new();
+        builder.add_set(&self.code_points);
+        f(&mut builder);
+        self.code_points = builder.build();
+    }
+
+    fn union(&mut self, other: Self) {
+        self.with_builder(|builder| builder.add_set(&other.code_points));
+        self.strings.extend(other.strings);
+    }
+
+    fn intersection(&mut self, other: Self) {
+        self.with_builder(|builder| builder.retain_set(&other.code_points));
+        self.strings.retain(|string| other.strings.contains(string));
+    }
+
+    fn subtraction(&mut self, other: Self) {
+        self.with_builder(|builder| builder.remove_set(&other.code_points));
+        self.strings
+            .retain(|string| !other.strings.contains(string));
+    }
+
+    /// Complements the code points of the set, failing if the set contains strings.
+    fn complement(&mut self) -> Result<(), Error> {
+        if !self.strings.is_empty() {
+            return error("Negated character class may contain strings");
+        }
+        self.with_builder(CodePointInversionListBuilder::complement);
+        Ok(())
+    }
+
+    /// Adds to the set every code point having the same simple case folding as one of its code
+    /// points, so that the set is closed under case folding.
+    fn close_over_case(&mut self) {
+        let mut builder = CodePointInversionListBuilder::new();
+        builder.add_set(&self.code_points);
+        CASE_CLASSES.with(|classes| {
+            for class in classes {
+                if class.iter().any(|&c| self.code_points.contains(c)) {
+                    for &c in class {
+                        builder.add_char(c);
+                    }
+                }
+            }
+        });
+        self.code_points = builder.build();
+    }
+
+    /// Writes the set as an expression the matcher understands: a class with the code points,
+    /// preceded by the alternatives of the strings, longest first.
+    fn write_expression(&self, output: &mut String) {
+        if self.strings.is_empty() {
+            output.push('[');
+            self.write_ranges(output);
+            output.push(']');
+            return;
+        }
+
+        let mut strings: Vec<_> = self.strings.iter().collect();
+        strings.sort_by_key(|string| std::cmp::Reverse(string.chars().count()));
+        output.push_str("(?:");
+        for string in strings {
+            for c in string.chars() {
+                write_code_point(output, u32::from(c));
+            }
+            output.push('|');
+        }
+        output.push('[');
+        self.write_ranges(output);
+        output.push_str("])");
+    }
+
+    /// Writes the ranges of code points of the set, as they appear inside a class.
+    ///
+    /// Surrogates can't appear in the strings given to the matcher, so they are left out.
+    fn write_ranges(&self, output: &mut String) {
+        for range in self.code_points.iter_ranges() {
+            let (start, end) = (*range.start(), *range.end());
+            let parts = [(start, end.min(0xD7FF)), (start.max(0xE000), end)];
+            for (start, end) in parts.iter().copied().filter(|(start, end)| start <= end) {
+                write_code_point(output, start);
+                if start != end {
+                    output.push('-');
+                    write_code_point(output, end);
+                }
+            }
+        }
+    }
+}
+
+/// Writes a code point as a Unicode escape.
+fn write_code_point(output: &mut String, cp: u32) {
+    write!(output, "\\u{{{:X}}}", cp).expect("writing to a String can't fail");
+}
+
+thread_local! {
+    /// The classes of code points with the same simple case folding, computed on first use.
+    static CASE_CLASSES: Vec<Vec<char>> = case_classes();
+}
+
+/// Computes the classes of code points with the same simple case folding.
+///
+/// The simple case folding of a code point is approximated by the lowercase form of its
+/// uppercase form, when both are single code points.
+fn case_classes() -> Vec<Vec<char>> {
+    fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    let mut folds: Vec<(char, char)> = Vec::new();
+    // Every code point with a case mapping is below this bound.
+    for c in (0..0x2_0000).filter_map(char::from_u32) {
+        let fold = single(c.to_uppercase())
+            .and_then(|upper| single(upper.to_lowercase()))
+            .or_else(|| single(c.to_lowercase()))
+            .unwrap_or(c);
+        if fold != c {
+            folds.push((fold, c));
+        }
+    }
+    folds.sort_unstable();
+
+    let mut classes: Vec<Vec<char>> = Vec::new();
+    for (fold, c) in folds {
+        match classes.last_mut() {
+            Some(class) if class[0] == fold => class.push(c),
+            _ => classes.push(vec![fold, c]),
+        }
+    }
+    classes
+}
+
+/// Translates a pattern with the `u` or `v` flag into a pattern the matcher understands.
+#[derive(Debug)]
+struct Translator {
+    chars: Vec<char>,
+    position: usize,
+    ignore_case: bool,
+    unicode_sets: bool,
+    output: String,
+}
+
+impl Translator {
+    fn new(pattern: &str, flags: &str) -> Self {
+        Self {
+            chars: pattern.chars().collect(),
+            position: 0,
+            ignore_case: flags.contains('i'),
+            unicode_sets: flags.contains('v'),
+            output: String::with_capacity(pattern.len()),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.chars.get(self.position + 1).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let len = s.chars().count();
+        let matches = self
+            .chars
+            .get(self.position..self.position + len)
+            .map(|chars| chars.iter().copied().eq(s.chars()))
+            .unwrap_or_default();
+        if matches {
+            self.position += len;
+        }
+        matches
+    }
+
+    /// Translates the whole pattern.
+    fn translate(mut self) -> Result<String, Error> {
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => match self.peek() {
+                    Some(p @ 'p') | Some(p @ 'P') => {
+                        self.position += 1;
+                        let set = self.property_escape(p == 'P')?;
+                        set.write_expression(&mut self.output);
+                    }
+                    Some(c) => {
+                        self.position += 1;
+                        self.output.push('\\');
+                        self.output.push(c);
+                    }
+                    None => self.output.push('\\'),
+                },
+                '[' if self.unicode_sets => {
+                    let set = self.class_set()?;
+                    set.write_expression(&mut self.output);
+                }
+                '[' => self.unicode_class()?,
+                c => self.output.push(c),
+            }
+        }
+        Ok(self.output)
+    }
+
+    /// Translates a class with the `u` flag, after its opening bracket, replacing the property
+    /// escapes with their ranges.
+    fn unicode_class(&mut self) -> Result<(), Error> {
+        self.output.push('[');
+        if self.eat('^') {
+            self.output.push('^');
+        }
+
+        while let Some(first) = self.unicode_class_atom()? {
+            if self.peek() == Some('-') && !matches!(self.peek_second(), Some(']') | None) {
+                self.position += 1;
+                let second = self
+                    .unicode_class_atom()?
+                    .expect("the class continues after the dash");
+                if first.1 || second.1 {
+                    return error("Invalid character class range");
+                }
+                self.output.push_str(&first.0);
+                self.output.push('-');
+                self.output.push_str(&second.0);
+            } else {
+                self.output.push_str(&first.0);
+            }
+        }
+
+        if !self.eat(']') {
+            return error("Unbalanced bracket");
+        }
+        self.output.push(']');
+        Ok(())
+    }
+
+    /// Gets the source of the next atom of a class with the `u` flag, and whether it is a
+    /// property escape, or `None` at the end of the class.
+    fn unicode_class_atom(&mut self) -> Result<Option<(String, bool)>, Error> {
+        let start = self.position;
+        match self.next() {
+            None | Some(']') => {
+                self.position = start;
+                return Ok(None);
+            }
+            Some('\\') => match self.next() {
+                Some(p @ 'p') | Some(p @ 'P') => {
+                    let set = self.property_escape(p == 'P')?;
+                    if !set.strings.is_empty() {
+                        return error("Invalid property name in character class");
+                    }
+                    let mut ranges = String::new();
+                    set.write_ranges(&mut ranges);
+                    return Ok(Some((ranges, true)));
+                }
+                Some('u') if self.eat('{') => while !matches!(self.next(), Some('}') | None) {},
+                Some('u') => self.position = (self.position + 4).min(self.chars.len()),
+                Some('x') => self.position = (self.position + 2).min(self.chars.len()),
+                Some('c') => self.position = (self.position + 1).min(self.chars.len()),
+                Some(_) => {}
+                None => return error("Unterminated escape"),
+            },
+            Some(_) => {}
+        }
+        Ok(Some((
+            self.chars[start..self.position].iter().collect(),
+            false,
+        )))
+    }
+
+    /// Parses a class with the `v` flag, after its opening bracket.
+    fn class_set(&mut self) -> Result<ClassSet, Error> {
+        let negated = self.eat('^');
+        let mut set = self.class_contents()?;
+        if negated {
+            set.complement()?;
+        }
+        Ok(set)
+    }
+
+    /// Parses the contents of a class with the `v` flag, up to and including its closing bracket.
+    fn class_contents(&mut self) -> Result<ClassSet, Error> {
+        if self.eat(']') {
+            return Ok(ClassSet::empty());
+        }
+
+        let (mut set, is_range) = self.class_operand_or_range()?;
+        if self.eat_str("&&") {
+            if is_range {
+                return error("Invalid set operation in character class");
+            }
+            loop {
+                if self.peek() == Some('&') {
+                    return error("Invalid set operation in character class");
+                }
+                set.intersection(self.class_operand()?);
+                if !self.eat_str("&&") {
+                    break;
+                }
+            }
+        } else if self.eat_str("--") {
+            if is_range {
+                return error("Invalid set operation in character class");
+            }
+            loop {
+                set.subtraction(self.class_operand()?);
+                if !self.eat_str("--") {
+                    break;
+                }
+            }
+        } else {
+            while !matches!(self.peek(), Some(']') | None) {
+                if self.eat_str("&&") || self.eat_str("--") {
+                    return error("Invalid set operation in character class");
+                }
+                set.union(self.class_operand_or_range()?.0);
+            }
+        }
+
+        if !self.eat(']') {
+            return error("Invalid set operation in character class");
+        }
+        Ok(set)
+    }
+
+    /// Parses an operand of a set operation.
+    fn class_operand(&mut self) -> Result<ClassSet, Error> {
+        match self.class_operand_or_range()? {
+            (_, true) => error("Invalid set operation in character class"),
+            (set, false) => Ok(set),
+        }
+    }
+
+    /// Parses an operand of a set operation or a range of characters, returning whether it was
+    /// a range.
+    fn class_operand_or_range(&mut self) -> Result<(ClassSet, bool), Error> {
+        if self.eat('[') {
+            return Ok((self.class_set()?, false));
+        }
+
+        if self.peek() == Some('\\') {
+            let escape = self.peek_second();
+            if let Some(c @ ('d' | 'D' | 's' | 'S' | 'w' | 'W' | 'p' | 'P')) = escape {
+                self.position += 2;
+                let set = match c {
+                    'p' | 'P' => self.property_escape(c == 'P')?,
+                    c => self.class_escape(c)?,
+                };
+                return Ok((set, false));
+            }
+            if escape == Some('q') {
+                self.position += 2;
+                if !self.eat('{') {
+                    return error("Invalid escape");
+                }
+                return Ok((self.class_strings()?, false));
+            }
+        }
+
+        let first = self.class_set_character()?;
+        if self.peek() == Some('-') && self.peek_second() != Some('-') {
+            self.position += 1;
+            let last = self.class_set_character()?;
+            if first > last {
+                return error("Range out of order in character class");
+            }
+            let mut set = ClassSet::from_ranges([u32::from(first)..=u32::from(last)]);
+            if self.ignore_case {
+                set.close_over_case();
+            }
+            return Ok((set, true));
+        }
+        let mut set = ClassSet::from_ranges([u32::from(first)..=u32::from(first)]);
+        if self.ignore_case {
+            set.close_over_case();
+        }
+        Ok((set, false))
+    }
+
+    /// Parses the strings of a `\q{...}` escape, after its opening brace.
+    fn class_strings(&mut self) -> Result<ClassSet, Error> {
+        let mut set = ClassSet::empty();
+        let mut string = String::new();
+        loop {
+            match self.peek() {
+                Some('}') => {
+                    self.position += 1;
+                    set.union(ClassSet::from_string(string));
+                    break;
+                }
+                Some('|') => {
+                    self.position += 1;
+                    set.union(ClassSet::from_string(std::mem::take(&mut string)));
+                }
+                Some(_) => string.push(self.class_set_character()?),
+                None => return error("Unterminated class string disjunction"),
+            }
+        }
+        if self.ignore_case {
+            set.close_over_case();
+        }
+        Ok(set)
+    }
+
+    /// Parses a single character of a class with the `v` flag.
+    fn class_set_character(&mut self) -> Result<char, Error> {
+        let c = match self.next() {
+            Some(c) => c,
+            None => return error("Unbalanced bracket"),
+        };
+
+        if c != '\\' {
+            if CLASS_SET_SYNTAX_CHARACTERS.contains(c)
+                || (CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS.contains(c) && self.peek() == Some(c))
+            {
+                return error("Invalid character in character class");
+            }
+            return Ok(c);
+        }
+
+        let escape = match self.next() {
+            Some(c) => c,
+            None => return error("Unterminated escape"),
+        };
+        let c = match escape {
+            'f' => '\u{C}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\u{B}',
+            'b' => '\u{8}',
+            'c' => match self.next() {
+                Some(letter) if letter.is_ascii_alphabetic() => char::from(letter as u8 % 32),
+                _ => return error("Invalid character escape"),
+            },
+            '0' if !matches!(self.peek(), Some(c) if c.is_ascii_digit()) => '\0',
+            'x' => {
+                let value = self.hex_digits(2)?;
+                char::from_u32(value).expect("two hex digits are a valid code point")
+            }
+            'u' => self.unicode_escape()?,
+            c if SYNTAX_CHARACTERS.contains(c) || CLASS_SET_RESERVED_PUNCTUATORS.contains(c) => c,
+            _ => return error("Invalid character escape"),
+        };
+        Ok(c)
+    }
+
+    /// Parses the given number of hexadecimal digits.
+    fn hex_digits(&mut self, count: usize) -> Result<u32, Error> {
+        let mut value = 0;
+        for _ in 0..count {
+            match self.next().and_then(|c| c.to_digit(16)) {
+                Some(digit) => value = value * 16 + digit,
+                None => return error("Invalid character escape"),
+            }
+        }
+        Ok(value)
+    }
+
+    /// Parses a Unicode escape after its `\u`, joining escaped surrogate pairs.
+    fn unicode_escape(&mut self) -> Result<char, Error> {
+        let value = if self.eat('{') {
+            let mut value: u32 = 0;
+            let mut digits = 0;
+            while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                self.position += 1;
+                digits += 1;
+                value = value.saturating_mul(16).saturating_add(digit);
+            }
+            if digits == 0 || !self.eat('}') || value > 0x10_FFFF {
+                return error("Invalid Unicode escape");
+            }
+            value
+        } else {
+            let lead = self.hex_digits(4)?;
+            if (0xD800..=0xDBFF).contains(&lead) {
+                let start = self.position;
+                if self.eat_str("\\u") {
+                    match self.hex_digits(4) {
+                        Ok(trail) if (0xDC00..=0xDFFF).contains(&trail) => {
+                            return Ok(char::from_u32(
+                                0x1_0000 + ((lead - 0xD800) << 10) + (trail - 0xDC00),
+                            )
+                            .expect("surrogate pairs are valid code points"));
+                        }
+                        _ => self.position = start,
+                    }
+                }
+            }
+            lead
+        };
+        match char::from_u32(value) {
+            Some(c) => Ok(c),
+            None => error("Lone surrogates are not supported in character classes"),
+        }
+    }
+
+    /// Gets the set of a character class escape like `\d` or `\W`.
+    fn class_escape(&self, escape: char) -> Result<ClassSet, Error> {
+        let mut set = match escape.to_ascii_lowercase() {
+            'd' => ClassSet::from_ranges([0x30..=0x39]),
+            's' => ClassSet::from_ranges([
+                0x09..=0x0D,
+                0x20..=0x20,
+                0xA0..=0xA0,
+                0x1680..=0x1680,
+                0x2000..=0x200A,
+                0x2028..=0x2029,
+                0x202F..=0x202F,
+                0x205F..=0x205F,
+                0x3000..=0x3000,
+                0xFEFF..=0xFEFF,
+            ]),
+            _ => {
+                let mut set =
+                    ClassSet::from_ranges([0x30..=0x39, 0x41..=0x5A, 0x5F..=0x5F, 0x61..=0x7A]);
+                if self.ignore_case {
+                    set.union(ClassSet::from_ranges([0x17F..=0x17F, 0x212A..=0x212A]));
+                }
+                set
+            }
+        };
+        if self.ignore_case {
+            set.close_over_case();
+        }
+        if escape.is_ascii_uppercase() {
+            set.complement()?;
+        }
+        Ok(set)
+    }
+
+    /// Parses a property escape after its `\p` or `\P`, and gets its set.
+    fn property_escape(&mut self, negated: bool) -> Result<ClassSet, Error> {
+        if !self.eat('{') {
+            return error("Invalid property name");
+        }
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_' || c == '=') {
+            self.position += 1;
+        }
+        let expression: String = self.chars[start..self.position].iter().collect();
+        if !self.eat('}') {
+            return error("Invalid property name");
+        }
+
+        let mut set = match expression.split_once('=') {
+            Some((name, value)) => property_value_set(name, value)?,
+            None => self.lone_property_set(&expression)?,
+        };
+        if self.unicode_sets && self.ignore_case {
+            set.close_over_case();
+        }
+        if negated {
+            if !set.strings.is_empty() {
+                return error("Invalid property name");
+            }
+            set.complement()?;
+        }
+        Ok(set)
+    }
+
+    /// Gets the set of a property escape without a value, which is a general category, a binary
+    /// property, or a property of strings with the `v` flag.
+    fn lone_property_set(&self, name: &str) -> Result<ClassSet, Error> {
+        match name {
+            "Any" => return Ok(ClassSet::from_ranges([0..=0x10_FFFF])),
+            "ASCII" => return Ok(ClassSet::from_ranges([0..=0x7F])),
+            "Assigned" => {
+                let mut set = ClassSet::from_ranges(
+                    maps::general_category().iter_ranges_for_value(GeneralCategory::Unassigned),
+                );
+                set.complement()?;
+                return Ok(set);
+            }
+            _ => {}
+        }
+
+        if let Some(group) = GeneralCategoryGroup::name_to_enum_mapper().get_strict(name) {
+            return Ok(ClassSet::from_ranges(
+                maps::general_category().iter_ranges_for_group(group),
+            ));
+        }
+        if let Ok(set) = sets::load_for_ecma262(name) {
+            return Ok(ClassSet::from_ranges(set.iter_ranges()));
+        }
+
+        if self.unicode_sets {
+            match name {
+                "Basic_Emoji" => {
+                    let data = sets::basic_emoji().static_to_owned();
+                    let list = data.to_code_point_inversion_list_string_list();
+                    let mut set = ClassSet::from_ranges(list.code_points().iter_ranges());
+                    set.strings
+                        .extend(list.strings().iter().map(ToString::to_string));
+                    return Ok(set);
+                }
+                "Emoji_Keycap_Sequence" => {
+                    let mut set = ClassSet::empty();
+                    set.strings.extend(
+                        "#*0123456789"
+                            .chars()
+                            .map(|c| format!("{}\u{FE0F}\u{20E3}", c)),
+                    );
+                    return Ok(set);
+                }
+                "RGI_Emoji"
+                | "RGI_Emoji_Flag_Sequence"
+                | "RGI_Emoji_Modifier_Sequence"
+                | "RGI_Emoji_Tag_Sequence"
+                | "RGI_Emoji_ZWJ_Sequence" => {
+                    return error("Unsupported property of strings");
+                }
+                _ => {}
+            }
+        }
+
+        error("Invalid property name")
+    }
+}
+
+/// Gets the set of a property escape with a value, like `\p{Script=Greek}`.
+fn property_value_set(name: &str, value: &str) -> Result<ClassSet, Error> {
+    match name {
+        "General_Category" | "gc" => {
+            match GeneralCategoryGroup::name_to_enum_mapper().get_strict(value) {
+                Some(group) => Ok(ClassSet::from_ranges(
+                    maps::general_category().iter_ranges_for_group(group),
+                )),
+                None => error("Invalid property value"),
+            }
+        }
+        "Script" | "sc" | "Script_Extensions" | "scx" => {
+            let script = match Script::name_to_enum_mapper().get_strict(value) {
+                Some(script) => script,
+                None => return error("Invalid property value"),
+            };
+            if name.starts_with("Script_") || name == "scx" {
+                Ok(ClassSet::from_ranges(
+                    script::script_with_extensions().get_script_extensions_ranges(script),
+                ))
+            } else {
+                Ok(ClassSet::from_ranges(
+                    maps::script().iter_ranges_for_value(script),
+                ))
+            }
+        }
+        _ => error("Invalid property name"),
+    }
+}
@@ -12,9 +12,11 @@
 
 use crate::{
     builtins::{iterable::get_iterator, BuiltIn},
+    error::JsErrorCode,
     object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     BoaProfiler, Context, JsResult, JsValue,
 };
 use ordered_set::OrderedSet;
@@ -27,13 +29,13 @@ pub mod ordered_set;
 mod tests;
 
 #[derive(Debug, Clone)]
-pub(crate) struct Set(OrderedSet<JsValue>);
+pub(crate) struct Set;
 
 impl BuiltIn for Set {
     const NAME: &'static str = "Set";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -78,27 +80,11 @@ impl BuiltIn for Set {
         .method(Self::entries, "entries", 0)
         .method(Self::for_each, "forEach", 1)
         .method(Self::has, "has", 1)
-        .property(
-            "keys",
-            values_function.clone(),
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
+        .property("keys", values_function.clone(), Attribute::builtin())
         .accessor("size", Some(size_getter), None, Attribute::CONFIGURABLE)
-        .property(
-            "values",
-            values_function.clone(),
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
-        .property(
-            iterator_symbol,
-            values_function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
-        .property(
-            to_string_tag,
-            Self::NAME,
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
+        .property("values", values_function.clone(), Attribute::builtin())
+        .property(iterator_symbol, values_function, Attribute::builtin())
+        .property(to_string_tag, Self::NAME, Attribute::readonly_builtin())
         .build();
 
         (Self::NAME, set_object.into(), Self::attribute())
@@ -139,7 +125,7 @@ impl Set {
         // 3
         set.set_data(ObjectData::set(OrderedSet::default()));
 
-        let iterable = args.get(0).cloned().unwrap_or_default();
+        let iterable = args.get_or_undefined(0).clone();
         // 4
         if iterable.is_null_or_undefined() {
             return Ok(set);
@@ -156,20 +142,12 @@ impl Set {
         // 7
         let iterator_record = get_iterator(context, iterable)?;
 
-        // 8.a
-        let mut next = iterator_record.next(context)?;
-
-        // 8
-        while !next.is_done() {
-            // c
-            let next_value = next.value();
-
+        // 8.a, 8.b, 8.c
+        while let Some(next_value) = iterator_record.step(context)? {
             // d, e
             if let Err(status) = context.call(&adder, &set, &[next_value]) {
                 return iterator_record.close(Err(status), context);
             }
-
-            next = iterator_record.next(context)?
         }
 
         // 8.b
@@ -206,19 +184,21 @@ impl Set {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let mut value = args.get(0).cloned().unwrap_or_default();
+        let mut value = args.get_or_undefined(0).clone();
 
         if let Some(object) = this.as_object() {
             if let Some(set) = object.borrow_mut().as_set_mut() {
-                if value.as_number().map(|n| n == -0f64).unwrap_or(false) {
+                // Values are compared with SameValueZero, so `-0` is stored as `+0`. The
+                // comparison matches both zeroes.
+                if value.as_number().map(|n| n == 0.0).unwrap_or(false) {
                     value = JsValue::Integer(0);
                 }
                 set.add(value);
             } else {
-                return context.throw_type_error("'this' is not a Set");
+                return context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set");
             }
         } else {
-            return context.throw_type_error("'this' is not a Set");
+            return context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set");
         };
 
         Ok(this.clone())
@@ -240,10 +220,10 @@ impl Set {
                 this.set_data(ObjectData::set(OrderedSet::new()));
                 Ok(JsValue::undefined())
             } else {
-                context.throw_type_error("'this' is not a Set")
+                context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set")
             }
         } else {
-            context.throw_type_error("'this' is not a Set")
+            context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set")
         }
     }
 
@@ -263,16 +243,16 @@ impl Set {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let value = args.get(0).cloned().unwrap_or_default();
+        let value = args.get_or_undefined(0).clone();
 
         let res = if let Some(object) = this.as_object() {
             if let Some(set) = object.borrow_mut().as_set_mut() {
                 set.delete(&value)
             } else {
-                return context.throw_type_error("'this' is not a Set");
+                return context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set");
             }
         } else {
-            return context.throw_type_error("'this' is not a Set");
+            return context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set");
         };
 
         Ok(res.into())
@@ -332,7 +312,7 @@ impl Set {
         }
 
         let callback_arg = &args[0];
-        let this_arg = args.get(1).cloned().unwrap_or_else(JsValue::undefined);
+        let this_arg = args.get_or_undefined(1).clone();
         // TODO: if condition should also check that we are not in strict mode
         let this_arg = if this_arg.is_undefined() {
             JsValue::Object(context.global_object())
@@ -349,10 +329,11 @@ impl Set {
                     set.get_index(index)
                         .map(|value| [value.clone(), value.clone(), this.clone()])
                 } else {
-                    return context.throw_type_error("'this' is not a Set");
+                    return context
+                        .throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set");
                 }
             } else {
-                return context.throw_type_error("'this' is not a Set");
+                return context.throw_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set");
             };
 
             if let Some(arguments) = arguments {
@@ -380,7 +361,7 @@ impl Set {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let value = args.get(0).cloned().unwrap_or_default();
+        let value = args.get_or_undefined(0).clone();
 
         if let JsValue::Object(ref object) = this {
             let object = object.borrow();
@@ -389,7 +370,7 @@ impl Set {
             }
         }
 
-        Err(context.construct_type_error("'this' is not a Set"))
+        Err(context.construct_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set"))
     }
 
     /// `Set.prototype.values( )`
@@ -437,10 +418,10 @@ impl Set {
             if let Some(set) = object.as_set_ref() {
                 Ok(set.size())
             } else {
-                Err(context.construct_type_error("'this' is not a Set"))
+                Err(context.construct_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set"))
             }
         } else {
-            Err(context.construct_type_error("'this' is not a Set"))
+            Err(context.construct_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set"))
         }
     }
 }
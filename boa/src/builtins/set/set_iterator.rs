@@ -3,9 +3,11 @@ use crate::{
     builtins::iterable::create_iter_result_object,
     builtins::Array,
     builtins::JsValue,
+    error::JsErrorCode,
     object::{JsObject, ObjectData},
     property::{PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
+    teardown::Contents,
     BoaProfiler, Context, JsResult,
 };
 use gc::{Finalize, Trace};
@@ -26,6 +28,11 @@ pub struct SetIterator {
 impl SetIterator {
     pub(crate) const NAME: &'static str = "SetIterator";
 
+    /// Queues the iterated set to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.push_value(&self.iterated_set);
+    }
+
     /// Constructs a new `SetIterator`, that will iterate over `set`, starting at index 0
     fn new(set: JsValue, kind: PropertyNameKind) -> Self {
         SetIterator {
@@ -115,10 +122,12 @@ impl SetIterator {
                             }
                         }
                     } else {
-                        return Err(context.construct_type_error("'this' is not a Set"));
+                        return Err(context
+                            .construct_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set"));
                     }
                 } else {
-                    return Err(context.construct_type_error("'this' is not a Set"));
+                    return Err(context
+                        .construct_coded_error(JsErrorCode::NOT_A_SET, "'this' is not a Set"));
                 }
 
                 set_iterator.iterated_set = JsValue::undefined();
@@ -246,3 +246,19 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Set constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn negative_zero_is_stored_as_positive_zero() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([-0]);
+        set.add(-0);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.is(set.values().next().value, 0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "set.has(0)"), "true");
+    assert_eq!(forward(&mut context, "set.size"), "1");
+}
@@ -0,0 +1,321 @@
+//! This module implements the global `SharedArrayBuffer` object.
+//!
+//! A `SharedArrayBuffer` is a fixed-length raw binary data buffer, like an `ArrayBuffer`, whose
+//! memory can be shared between agents. Its contents can be manipulated atomically with the
+//! `Atomics` functions.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer
+
+mod shared_data_block;
+
+#[cfg(test)]
+mod tests;
+
+pub use shared_data_block::SharedDataBlock;
+
+use crate::{
+    builtins::{array_buffer::ArrayBuffer, Array, BuiltIn},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::{ArgumentsExt, JsValue},
+    BoaProfiler, Context, JsResult,
+};
+
+/// The JavaScript `SharedArrayBuffer` constructor.
+///
+/// The data of a `SharedArrayBuffer` object is an [`ArrayBuffer`] holding a [`SharedDataBlock`].
+#[derive(Debug, Clone, Copy)]
+pub struct SharedArrayBuffer;
+
+impl BuiltIn for SharedArrayBuffer {
+    const NAME: &'static str = "SharedArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::get_byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+
+        let shared_array_buffer_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context
+                .standard_objects()
+                .shared_array_buffer_object()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (
+            Self::NAME,
+            shared_array_buffer_object.into(),
+            Self::attribute(),
+        )
+    }
+}
+
+impl SharedArrayBuffer {
+    /// The amount of arguments the `SharedArrayBuffer` constructor takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates a `SharedArrayBuffer` object whose memory is the given block.
+    ///
+    /// The block can be shared with other contexts, including contexts running in other
+    /// threads, which see the changes made to its bytes.
+    pub fn from_block(block: SharedDataBlock, context: &mut Context) -> JsObject {
+        let prototype = context
+            .standard_objects()
+            .shared_array_buffer_object()
+            .prototype();
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer::from_shared_block(block));
+        obj
+    }
+
+    /// `SharedArrayBuffer ( length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/SharedArrayBuffer
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin SharedArrayBuffer constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 3. Return ? AllocateSharedArrayBuffer(NewTarget, byteLength).
+        Ok(Self::allocate(new_target, byte_length, context)?.into())
+    }
+
+    /// `get SharedArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/@@species
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `get SharedArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/byteLength
+    fn get_byte_length(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        // 4. Let length be O.[[ArrayBufferByteLength]].
+        // 5. Return 𝔽(length).
+        let byte_length = this.as_object().and_then(|obj| {
+            obj.borrow()
+                .as_array_buffer()
+                .filter(|buffer| buffer.is_shared_array_buffer())
+                .map(ArrayBuffer::byte_length)
+        });
+        match byte_length {
+            Some(byte_length) => Ok(byte_length.into()),
+            None => context.throw_type_error(
+                "SharedArrayBuffer.prototype.byteLength called on a non-SharedArrayBuffer",
+            ),
+        }
+    }
+
+    /// `SharedArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let (obj, block) = match this.as_object() {
+            Some(obj) => match obj
+                .borrow()
+                .as_array_buffer()
+                .and_then(ArrayBuffer::shared_data_block)
+            {
+                Some(block) => (obj.clone(), block.clone()),
+                None => {
+                    return context.throw_type_error(
+                        "SharedArrayBuffer.prototype.slice called on a non-SharedArrayBuffer",
+                    )
+                }
+            },
+            None => {
+                return context.throw_type_error(
+                    "SharedArrayBuffer.prototype.slice called on a non-SharedArrayBuffer",
+                )
+            }
+        };
+
+        // 4. Let len be O.[[ArrayBufferByteLength]].
+        let len = block.len();
+
+        // 5. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 6-7. Let first be the clamped relativeStart.
+        let first = Array::get_relative_start(context, args.get(0), len)?;
+
+        // 8. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        // 9-10. Let final be the clamped relativeEnd.
+        let r#final = Array::get_relative_end(context, args.get(1), len)?;
+
+        // 11. Let newLen be max(final - first, 0).
+        let new_len = r#final.saturating_sub(first);
+
+        // 12. Let ctor be ? SpeciesConstructor(O, %SharedArrayBuffer%).
+        let ctor = obj.species_constructor(
+            context
+                .standard_objects()
+                .shared_array_buffer_object()
+                .constructor()
+                .into(),
+            context,
+        )?;
+
+        // 13. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = match ctor.as_object() {
+            Some(ctor) => ctor.construct(&[new_len.into()], &ctor.clone().into(), context)?,
+            None => {
+                return context.throw_type_error("SharedArrayBuffer species is not a constructor")
+            }
+        };
+
+        // 14. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+        // 15. If IsSharedArrayBuffer(new) is false, throw a TypeError exception.
+        let new_block = match new
+            .as_object()
+            .and_then(|new| new.borrow().as_array_buffer()?.shared_data_block().cloned())
+        {
+            Some(new_block) => new_block,
+            None => {
+                return context.throw_type_error(
+                    "SharedArrayBuffer species did not construct a SharedArrayBuffer",
+                )
+            }
+        };
+
+        // 16. If new.[[ArrayBufferData]] and O.[[ArrayBufferData]] are the same Shared Data Block values, throw a TypeError exception.
+        if new_block.ptr_eq(&block) {
+            return context
+                .throw_type_error("SharedArrayBuffer species constructed the same buffer");
+        }
+
+        // 17. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
+        if new_block.len() < new_len {
+            return context
+                .throw_type_error("SharedArrayBuffer species constructed a too small buffer");
+        }
+
+        // 18. Let fromBuf be O.[[ArrayBufferData]].
+        // 19. Let toBuf be new.[[ArrayBufferData]].
+        // 20. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+        let mut bytes = vec![0; new_len];
+        block.read(first, &mut bytes);
+        new_block.write(0, &bytes);
+
+        // 21. Return new.
+        Ok(new)
+    }
+
+    /// Abstract operation `AllocateSharedArrayBuffer ( constructor, byteLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatesharedarraybuffer
+    fn allocate(
+        constructor: &JsValue,
+        byte_length: usize,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%SharedArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]] »).
+        let prototype = get_prototype_from_constructor(
+            constructor,
+            StandardObjects::shared_array_buffer_object,
+            context,
+        )?;
+
+        // 2. Let block be ? CreateSharedByteDataBlock(byteLength).
+        let block = match SharedDataBlock::try_new(byte_length) {
+            Some(block) => block,
+            None => {
+                return Err(context.construct_range_error("shared array buffer allocation failed"))
+            }
+        };
+
+        // 3. Set obj.[[ArrayBufferData]] to block.
+        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        let obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer::from_shared_block(block));
+
+        // 5. Return obj.
+        Ok(obj)
+    }
+}
@@ -0,0 +1,310 @@
+//! This module implements the Shared Data Block, the memory of a `SharedArrayBuffer`.
+//!
+//! A shared data block can be shared between threads, so it is accessed through atomic
+//! operations only. The operations of the `Atomics` namespace additionally run in the critical
+//! section of the block, which makes them atomic with respect to each other.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-data-blocks
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+/// The memory of a `SharedArrayBuffer`, which can be shared between contexts running in
+/// different threads.
+///
+/// Cloning a block creates another handle to the same memory.
+///
+/// # Examples
+/// ```
+///# use boa::{builtins::shared_array_buffer::{SharedArrayBuffer, SharedDataBlock}, property::Attribute, Context};
+/// let block = SharedDataBlock::from(vec![1, 2, 3, 4]);
+///
+/// let mut context = Context::new();
+/// let buffer = SharedArrayBuffer::from_block(block.clone(), &mut context);
+/// context.register_global_property("buffer", buffer, Attribute::all());
+/// context.eval("new Uint8Array(buffer)[0] = 42").unwrap();
+///
+/// assert_eq!(block.to_vec(), vec![42, 2, 3, 4]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedDataBlock {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// The bytes of the block.
+    bytes: Box<[AtomicU8]>,
+    /// The critical section of the block, guarding its waiter list.
+    critical_section: Mutex<WaiterList>,
+    /// The condition the suspended agents wait on.
+    condvar: Condvar,
+}
+
+/// The agents waiting on the locations of a block, in the order they started waiting.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-waiterlist-objects
+#[derive(Debug, Default)]
+struct WaiterList {
+    waiters: VecDeque<Waiter>,
+    next_id: u64,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    id: u64,
+    byte_index: usize,
+    notified: bool,
+}
+
+/// The result of waiting on a location of a shared data block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitResult {
+    Ok,
+    NotEqual,
+    TimedOut,
+}
+
+impl WaitResult {
+    /// Gets the string returned by `Atomics.wait` for the result.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::NotEqual => "not-equal",
+            Self::TimedOut => "timed-out",
+        }
+    }
+}
+
+impl SharedDataBlock {
+    /// Creates a block of `len` bytes, all set to `0`.
+    pub fn new(len: usize) -> Self {
+        Self::from_bytes((0..len).map(|_| AtomicU8::new(0)).collect())
+    }
+
+    /// Creates a block of `len` bytes, all set to `0`, or returns `None` if the memory can't be
+    /// allocated.
+    pub(crate) fn try_new(len: usize) -> Option<Self> {
+        let mut bytes = Vec::new();
+        bytes.try_reserve_exact(len).ok()?;
+        bytes.resize_with(len, || AtomicU8::new(0));
+        Some(Self::from_bytes(bytes.into_boxed_slice()))
+    }
+
+    fn from_bytes(bytes: Box<[AtomicU8]>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                bytes,
+                critical_section: Mutex::default(),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Gets the length of the block in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.bytes.len()
+    }
+
+    /// Checks if the block is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.bytes.is_empty()
+    }
+
+    /// Checks if both blocks are handles to the same memory.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Copies the bytes of the block starting at `byte_index` to `dest`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of bytes is out of the block.
+    pub fn read(&self, byte_index: usize, dest: &mut [u8]) {
+        let bytes = &self.inner.bytes[byte_index..byte_index + dest.len()];
+        for (dest, byte) in dest.iter_mut().zip(bytes) {
+            *dest = byte.load(Ordering::Relaxed);
+        }
+    }
+
+    /// Copies `src` to the bytes of the block starting at `byte_index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of bytes is out of the block.
+    pub fn write(&self, byte_index: usize, src: &[u8]) {
+        let bytes = &self.inner.bytes[byte_index..byte_index + src.len()];
+        for (byte, src) in bytes.iter().zip(src) {
+            byte.store(*src, Ordering::Relaxed);
+        }
+    }
+
+    /// Copies the bytes of the block to a new vector.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = vec![0; self.len()];
+        self.read(0, &mut bytes);
+        bytes
+    }
+
+    /// Copies the bytes of `src` within the block to `dest`, like `slice::copy_within`.
+    pub(crate) fn copy_within(&self, src: std::ops::Range<usize>, dest: usize) {
+        let mut bytes = vec![0; src.len()];
+        self.read(src.start, &mut bytes);
+        self.write(dest, &bytes);
+    }
+
+    /// Enters the critical section of the block.
+    fn enter_critical_section(&self) -> MutexGuard<'_, WaiterList> {
+        self.inner
+            .critical_section
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Reads the `len` bytes starting at `byte_index` and replaces them with the result of `op`,
+    /// if any, atomically with respect to the other atomic operations on the block.
+    ///
+    /// Returns the bytes read.
+    pub(crate) fn modify<F>(&self, byte_index: usize, len: usize, op: F) -> Vec<u8>
+    where
+        F: FnOnce(&[u8]) -> Option<Vec<u8>>,
+    {
+        let _critical_section = self.enter_critical_section();
+        let mut old = vec![0; len];
+        self.read(byte_index, &mut old);
+        if let Some(new) = op(&old) {
+            self.write(byte_index, &new);
+        }
+        old
+    }
+
+    /// Abstract operation `DoWait ( mode, typedArray, index, value, timeout )`, once the
+    /// arguments are validated.
+    ///
+    /// Suspends the thread until the location at `byte_index` is notified or the timeout
+    /// expires, if it holds the bytes `expected`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dowait
+    pub(crate) fn wait(
+        &self,
+        byte_index: usize,
+        expected: &[u8],
+        timeout: Option<Duration>,
+    ) -> WaitResult {
+        // 19. Perform EnterCriticalSection(WL).
+        let mut list = self.enter_critical_section();
+
+        // 20-21. If v ≠ w, then perform LeaveCriticalSection(WL) and return "not-equal".
+        let mut current = vec![0; expected.len()];
+        self.read(byte_index, &mut current);
+        if current != expected {
+            return WaitResult::NotEqual;
+        }
+
+        // 23. Perform AddWaiter(WL, waiterRecord).
+        let id = list.next_id;
+        list.next_id += 1;
+        list.waiters.push_back(Waiter {
+            id,
+            byte_index,
+            notified: false,
+        });
+
+        // 24. Perform SuspendThisAgent(WL, waiterRecord).
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let position = list
+                .waiters
+                .iter()
+                .position(|waiter| waiter.id == id)
+                .expect("a waiter stays in the list until it stops waiting");
+            if list.waiters[position].notified {
+                list.waiters.remove(position);
+                return WaitResult::Ok;
+            }
+
+            list = match deadline {
+                None => self
+                    .inner
+                    .condvar
+                    .wait(list)
+                    .unwrap_or_else(PoisonError::into_inner),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        // 25-26. If the waiter was not notified, remove it and return "timed-out".
+                        list.waiters.remove(position);
+                        return WaitResult::TimedOut;
+                    }
+                    self.inner
+                        .condvar
+                        .wait_timeout(list, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+            };
+        }
+    }
+
+    /// Abstract operation `Atomics.notify ( typedArray, index, count )`, once the arguments are
+    /// validated.
+    ///
+    /// Wakes up at most `count` of the agents waiting on the location at `byte_index`, in the
+    /// order they started waiting, and returns the number of woken agents.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    pub(crate) fn notify(&self, byte_index: usize, count: usize) -> usize {
+        // 10. Perform EnterCriticalSection(WL).
+        let mut list = self.enter_critical_section();
+
+        // 11. Let S be RemoveWaiters(WL, c).
+        // 12. For each element W of S, do
+        //     a. Perform NotifyWaiter(WL, W).
+        let mut notified = 0;
+        for waiter in list.waiters.iter_mut() {
+            if notified == count {
+                break;
+            }
+            if waiter.byte_index == byte_index && !waiter.notified {
+                waiter.notified = true;
+                notified += 1;
+            }
+        }
+        if notified > 0 {
+            self.inner.condvar.notify_all();
+        }
+
+        // 13. Perform LeaveCriticalSection(WL).
+        // 14. Let n be the number of elements in S.
+        // 15. Return 𝔽(n).
+        notified
+    }
+}
+
+impl From<Vec<u8>> for SharedDataBlock {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_bytes(bytes.into_iter().map(AtomicU8::new).collect())
+    }
+}
@@ -0,0 +1,89 @@
+use super::{SharedArrayBuffer, SharedDataBlock};
+use crate::{forward, property::Attribute, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var buffer = new SharedArrayBuffer(8);");
+
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(buffer)"),
+        "\"[object SharedArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(&mut context, "buffer instanceof ArrayBuffer"),
+        "false"
+    );
+}
+
+#[test]
+fn not_an_array_buffer() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function error(f) {
+            try { f(); } catch (e) { return e.name; }
+        }
+        var byteLength = Object.getOwnPropertyDescriptor(ArrayBuffer.prototype, "byteLength").get;
+        var sharedByteLength = Object.getOwnPropertyDescriptor(SharedArrayBuffer.prototype, "byteLength").get;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => byteLength.call(new SharedArrayBuffer(1)))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "error(() => sharedByteLength.call(new ArrayBuffer(1)))"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "error(() => SharedArrayBuffer(1))"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn slice() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var buffer = new SharedArrayBuffer(4);
+        new Uint8Array(buffer).set([1, 2, 3, 4]);
+        var sliced = buffer.slice(1, -1);
+        new Uint8Array(sliced)[0] = 42;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sliced.byteLength"), "2");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(sliced).join()"),
+        "\"42,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(buffer).join()"),
+        "\"1,2,3,4\""
+    );
+}
+
+#[test]
+fn from_block() {
+    let block = SharedDataBlock::from(vec![1, 2, 3, 4]);
+
+    let mut context = Context::new();
+    let buffer = SharedArrayBuffer::from_block(block.clone(), &mut context);
+    context.register_global_property("buffer", buffer, Attribute::all());
+
+    assert_eq!(forward(&mut context, "new Uint16Array(buffer)[1]"), "1027");
+    forward(&mut context, "new Uint8Array(buffer).fill(7, 2)");
+
+    assert_eq!(block.to_vec(), vec![1, 2, 7, 7]);
+}
@@ -0,0 +1,205 @@
+//! This module implements the case conversion of strings used by `String.prototype.toLowerCase`,
+//! `String.prototype.toUpperCase` and their locale sensitive counterparts.
+//!
+//! The default conversion is the full case mapping of the Unicode Character Database, including
+//! the unconditional special casings (like `ß` to `SS`) and the final sigma rule, which is the
+//! one implemented by `str::to_lowercase` and `str::to_uppercase`. The languages with tailored
+//! special casings, Turkish, Azerbaijani and Lithuanian, rewrite the characters they tailor before
+//! applying it.
+//!
+//! More information:
+//!  - [Unicode special casing][special-casing]
+//!
+//! [special-casing]: https://www.unicode.org/Public/UCD/latest/ucd/SpecialCasing.txt
+
+use crate::JsString;
+use icu_properties::{maps, sets, CanonicalCombiningClass};
+use std::string::String as StdString;
+
+/// The `COMBINING DOT ABOVE` character, added or removed by the tailored special casings.
+const COMBINING_DOT_ABOVE: char = '\u{307}';
+
+/// The special casings applying to a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CaseLocale {
+    /// The special casings of the root locale, which apply to every language.
+    Root,
+    /// The special casings of Turkish and Azerbaijani, where `I` and `i` are different letters
+    /// than `ı` and `İ`.
+    Turkic,
+    /// The special casings of Lithuanian, which keep the dot of `i` and `j` when an accent is
+    /// placed above.
+    Lithuanian,
+}
+
+impl CaseLocale {
+    /// Gets the special casings of the language of a locale.
+    pub(crate) fn from_language(language: &str) -> Self {
+        match language {
+            "tr" | "az" => Self::Turkic,
+            "lt" => Self::Lithuanian,
+            _ => Self::Root,
+        }
+    }
+}
+
+/// The direction of a case conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Case {
+    Lower,
+    Upper,
+}
+
+/// Converts the string to the given case, following the special casings of the locale.
+///
+/// The string is returned as is, without allocating, if it is made of ASCII characters which are
+/// already in the target case.
+pub(crate) fn transform_case(string: &JsString, case: Case, locale: CaseLocale) -> JsString {
+    if string.is_ascii() {
+        let unchanged = match case {
+            Case::Lower => !string.bytes().any(|b| b.is_ascii_uppercase()),
+            Case::Upper => !string.bytes().any(|b| b.is_ascii_lowercase()),
+        };
+        // Only `I` and `i` have tailored mappings among the ASCII characters.
+        let tailored = match (locale, case) {
+            (CaseLocale::Turkic, Case::Lower) => string.contains('I'),
+            (CaseLocale::Turkic, Case::Upper) => string.contains('i'),
+            _ => false,
+        };
+        if unchanged && !tailored {
+            return string.clone();
+        }
+        if !tailored {
+            let mut converted = StdString::from(string.as_str());
+            match case {
+                Case::Lower => converted.make_ascii_lowercase(),
+                Case::Upper => converted.make_ascii_uppercase(),
+            }
+            return converted.into();
+        }
+    }
+
+    let converted = match (locale, case) {
+        (CaseLocale::Root, Case::Lower) => string.to_lowercase(),
+        (CaseLocale::Root, Case::Upper) => string.to_uppercase(),
+        (CaseLocale::Turkic, Case::Lower) => turkic_lowercase_tailoring(string).to_lowercase(),
+        (CaseLocale::Turkic, Case::Upper) => string.replace('i', "\u{130}").to_uppercase(),
+        (CaseLocale::Lithuanian, Case::Lower) => {
+            lithuanian_lowercase_tailoring(string).to_lowercase()
+        }
+        (CaseLocale::Lithuanian, Case::Upper) => {
+            lithuanian_uppercase_tailoring(string).to_uppercase()
+        }
+    };
+    if converted == string.as_str() {
+        string.clone()
+    } else {
+        converted.into()
+    }
+}
+
+/// Gets the canonical combining class of a character.
+fn combining_class(c: char) -> CanonicalCombiningClass {
+    maps::canonical_combining_class().get(c)
+}
+
+/// Checks if the characters start with a character of combining class `Above`, after any
+/// number of combining characters of other classes.
+///
+/// This is the `More_Above` condition of the special casings when given the characters
+/// following the one being converted.
+fn is_more_above(following: &str) -> bool {
+    for c in following.chars() {
+        match combining_class(c) {
+            CanonicalCombiningClass::Above => return true,
+            CanonicalCombiningClass::NotReordered => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Checks if the characters start with `COMBINING DOT ABOVE`, after any number of combining
+/// characters which are not of combining class `Above`.
+///
+/// This is the `Before_Dot` condition of the special casings when given the characters
+/// following the one being converted.
+fn is_before_dot(following: &str) -> bool {
+    for c in following.chars() {
+        if c == COMBINING_DOT_ABOVE {
+            return true;
+        }
+        match combining_class(c) {
+            CanonicalCombiningClass::Above | CanonicalCombiningClass::NotReordered => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Rewrites the characters having a tailored lowercase mapping in Turkish and Azerbaijani.
+fn turkic_lowercase_tailoring(string: &str) -> StdString {
+    let mut result = StdString::with_capacity(string.len());
+    // Whether the last character was an `I`, with no character of combining class `Above` or
+    // `NotReordered` since: this is the `After_I` condition.
+    let mut after_i = false;
+    for (index, c) in string.char_indices() {
+        match c {
+            '\u{130}' => result.push('i'),
+            'I' if is_before_dot(&string[index + 1..]) => result.push('i'),
+            'I' => result.push('\u{131}'),
+            COMBINING_DOT_ABOVE if after_i => {}
+            _ => result.push(c),
+        }
+        after_i = match c {
+            'I' => true,
+            _ => {
+                after_i
+                    && !matches!(
+                        combining_class(c),
+                        CanonicalCombiningClass::Above | CanonicalCombiningClass::NotReordered
+                    )
+            }
+        };
+    }
+    result
+}
+
+/// Rewrites the characters having a tailored lowercase mapping in Lithuanian.
+fn lithuanian_lowercase_tailoring(string: &str) -> StdString {
+    let mut result = StdString::with_capacity(string.len());
+    for (index, c) in string.char_indices() {
+        match c {
+            'I' | 'J' | '\u{12E}' if is_more_above(&string[index + c.len_utf8()..]) => {
+                result.extend(c.to_lowercase());
+                result.push(COMBINING_DOT_ABOVE);
+            }
+            '\u{CC}' => result.push_str("i\u{307}\u{300}"),
+            '\u{CD}' => result.push_str("i\u{307}\u{301}"),
+            '\u{128}' => result.push_str("i\u{307}\u{303}"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Rewrites the characters having a tailored uppercase mapping in Lithuanian.
+fn lithuanian_uppercase_tailoring(string: &str) -> StdString {
+    let soft_dotted = sets::soft_dotted();
+    let mut result = StdString::with_capacity(string.len());
+    // Whether there is a soft dotted character before, with no character of combining class
+    // `Above` or `NotReordered` since: this is the `After_Soft_Dotted` condition.
+    let mut after_soft_dotted = false;
+    for c in string.chars() {
+        if c != COMBINING_DOT_ABOVE || !after_soft_dotted {
+            result.push(c);
+        }
+        after_soft_dotted = soft_dotted.contains(c)
+            || after_soft_dotted
+                && !matches!(
+                    combining_class(c),
+                    CanonicalCombiningClass::Above | CanonicalCombiningClass::NotReordered
+                );
+    }
+    result
+}
@@ -9,6 +9,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-string-object
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String
 
+mod case_mapping;
 pub mod string_iterator;
 #[cfg(test)]
 mod tests;
@@ -16,10 +17,15 @@ mod tests;
 use crate::builtins::Symbol;
 use crate::object::{JsObject, PROTOTYPE};
 use crate::{
-    builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
+    builtins::{
+        intl::{canonicalize_locale_list, collator::Collator, LanguageTag},
+        string::string_iterator::StringIterator,
+        Array, BuiltIn, RegExp,
+    },
     object::{ConstructorBuilder, ObjectData},
     property::{Attribute, PropertyDescriptor},
     symbol::WellKnownSymbols,
+    value::ArgumentsExt,
     BoaProfiler, Context, JsResult, JsString, JsValue,
 };
 use std::{
@@ -29,6 +35,8 @@ use std::{
 };
 use unicode_normalization::UnicodeNormalization;
 
+use self::case_mapping::{transform_case, Case, CaseLocale};
+
 pub(crate) fn code_point_at(string: JsString, position: i32) -> Option<(u32, u8, bool)> {
     let size = string.encode_utf16().count() as i32;
     if position < 0 || position >= size {
@@ -87,7 +95,7 @@ impl BuiltIn for String {
     const NAME: &'static str = "String";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -95,7 +103,7 @@ impl BuiltIn for String {
 
         let symbol_iterator = WellKnownSymbols::iterator();
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::constant();
         let string_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -116,8 +124,9 @@ impl BuiltIn for String {
         .method(Self::includes, "includes", 1)
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
+        .method(Self::locale_compare, "localeCompare", 1)
         .method(Self::r#match, "match", 1)
-        .method(Self::normalize, "normalize", 1)
+        .method(Self::normalize, "normalize", 0)
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
@@ -125,6 +134,8 @@ impl BuiltIn for String {
         .method(Self::trim_end, "trimEnd", 0)
         .method(Self::to_lowercase, "toLowerCase", 0)
         .method(Self::to_uppercase, "toUpperCase", 0)
+        .method(Self::to_locale_lowercase, "toLocaleLowerCase", 0)
+        .method(Self::to_locale_uppercase, "toLocaleUpperCase", 0)
         .method(Self::substring, "substring", 2)
         .method(Self::substr, "substr", 2)
         .method(Self::split, "split", 2)
@@ -562,9 +573,9 @@ impl String {
         // Then we convert it into a Rust String by wrapping it in from_value
         let primitive_val = this.to_string(context)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
+        let arg = args.get_or_undefined(0).clone();
 
-        if Self::is_regexp_object(&arg) {
+        if arg.is_regexp(context)? {
             context.throw_type_error(
                 "First argument to String.prototype.startsWith must not be a regular expression",
             )?;
@@ -617,9 +628,9 @@ impl String {
         // Then we convert it into a Rust String by wrapping it in from_value
         let primitive_val = this.to_string(context)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
+        let arg = args.get_or_undefined(0).clone();
 
-        if Self::is_regexp_object(&arg) {
+        if arg.is_regexp(context)? {
             context.throw_type_error(
                 "First argument to String.prototype.endsWith must not be a regular expression",
             )?;
@@ -671,9 +682,9 @@ impl String {
         // Then we convert it into a Rust String by wrapping it in from_value
         let primitive_val = this.to_string(context)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(JsValue::undefined);
+        let arg = args.get_or_undefined(0).clone();
 
-        if Self::is_regexp_object(&arg) {
+        if arg.is_regexp(context)? {
             context.throw_type_error(
                 "First argument to String.prototype.includes must not be a regular expression",
             )?;
@@ -700,13 +711,6 @@ impl String {
         Ok(JsValue::new(this_string.contains(search_string.as_str())))
     }
 
-    fn is_regexp_object(value: &JsValue) -> bool {
-        match value {
-            JsValue::Object(ref obj) => obj.borrow().is_regexp(),
-            _ => false,
-        }
-    }
-
     /// `String.prototype.replace( regexp|substr, newSubstr|function )`
     ///
     /// The `replace()` method returns a new string with some or all matches of a `pattern` replaced by a `replacement`.
@@ -730,17 +734,14 @@ impl String {
         // 1. Let O be ? RequireObjectCoercible(this value).
         this.require_object_coercible(context)?;
 
-        let search_value = args.get(0).cloned().unwrap_or_default();
+        let search_value = args.get_or_undefined(0).clone();
 
-        let replace_value = args.get(1).cloned().unwrap_or_default();
+        let replace_value = args.get_or_undefined(1).clone();
 
         // 2. If searchValue is neither undefined nor null, then
         if !search_value.is_null_or_undefined() {
             // a. Let replacer be ? GetMethod(searchValue, @@replace).
-            let replacer = search_value
-                .as_object()
-                .unwrap_or_default()
-                .get_method(context, WellKnownSymbols::replace())?;
+            let replacer = search_value.get_method(context, WellKnownSymbols::replace())?;
 
             // b. If replacer is not undefined, then
             if let Some(replacer) = replacer {
@@ -846,35 +847,30 @@ impl String {
         // 1. Let O be ? RequireObjectCoercible(this value).
         let o = this.require_object_coercible(context)?;
 
-        let search_value = args.get(0).cloned().unwrap_or_default();
-        let replace_value = args.get(1).cloned().unwrap_or_default();
+        let search_value = args.get_or_undefined(0).clone();
+        let replace_value = args.get_or_undefined(1).clone();
 
         // 2. If searchValue is neither undefined nor null, then
         if !search_value.is_null_or_undefined() {
             // a. Let isRegExp be ? IsRegExp(searchValue).
-            if let Some(obj) = search_value.as_object() {
-                // b. If isRegExp is true, then
-                if obj.is_regexp() {
-                    // i. Let flags be ? Get(searchValue, "flags").
-                    let flags = obj.get("flags", context)?;
-
-                    // ii. Perform ? RequireObjectCoercible(flags).
-                    flags.require_object_coercible(context)?;
-
-                    // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                    if !flags.to_string(context)?.contains('g') {
-                        return context.throw_type_error(
-                            "String.prototype.replaceAll called with a non-global RegExp argument",
-                        );
-                    }
+            // b. If isRegExp is true, then
+            if search_value.is_regexp(context)? {
+                // i. Let flags be ? Get(searchValue, "flags").
+                let flags = search_value.get_field("flags", context)?;
+
+                // ii. Perform ? RequireObjectCoercible(flags).
+                flags.require_object_coercible(context)?;
+
+                // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
+                if !flags.to_string(context)?.contains('g') {
+                    return context.throw_type_error(
+                        "String.prototype.replaceAll called with a non-global RegExp argument",
+                    );
                 }
             }
 
             // c. Let replacer be ? GetMethod(searchValue, @@replace).
-            let replacer = search_value
-                .as_object()
-                .unwrap_or_default()
-                .get_method(context, WellKnownSymbols::replace())?;
+            let replacer = search_value.get_method(context, WellKnownSymbols::replace())?;
 
             // d. If replacer is not undefined, then
             if let Some(replacer) = replacer {
@@ -1089,6 +1085,44 @@ impl String {
         Ok(JsValue::new(-1))
     }
 
+    /// `String.prototype.localeCompare( that [ , locales [ , options ] ] )`
+    ///
+    /// The `localeCompare()` method returns a number indicating whether a reference string comes before, or after, or is
+    /// the same as the given string in sort order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-String.prototype.localeCompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    pub(crate) fn locale_compare(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let s = o.to_string(context)?;
+
+        // 3. Let thatValue be ? ToString(that).
+        let that_value = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        // 4. Let collator be ? Construct(%Collator%, « locales, options »).
+        let locales = args.get_or_undefined(1).clone();
+        let options = args.get_or_undefined(2).clone();
+        let collator = Collator::new(&locales, &options, context)?;
+
+        // 5. Return CompareStrings(collator, S, thatValue).
+        Ok(JsValue::new(collator.compare(&s, &that_value) as i32))
+    }
+
     /// `String.prototype.match( regexp )`
     ///
     /// The `match()` method retrieves the result of matching a **string** against a [`regular expression`][regex].
@@ -1109,15 +1143,13 @@ impl String {
         let o = this.require_object_coercible(context)?;
 
         // 2. If regexp is neither undefined nor null, then
-        let regexp = args.get(0).cloned().unwrap_or_default();
+        let regexp = args.get_or_undefined(0).clone();
         if !regexp.is_null_or_undefined() {
             // a. Let matcher be ? GetMethod(regexp, @@match).
             // b. If matcher is not undefined, then
-            if let Some(obj) = regexp.as_object() {
-                if let Some(matcher) = obj.get_method(context, WellKnownSymbols::match_())? {
-                    // i. Return ? Call(matcher, regexp, « O »).
-                    return matcher.call(&regexp, &[o.clone()], context);
-                }
+            if let Some(matcher) = regexp.get_method(context, WellKnownSymbols::match_())? {
+                // i. Return ? Call(matcher, regexp, « O »).
+                return matcher.call(&regexp, &[o.clone()], context);
             }
         }
 
@@ -1317,12 +1349,14 @@ impl String {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let this_str = this.to_string(context)?;
-        // The Rust String is mapped to uppercase using the builtin .to_lowercase().
-        // There might be corner cases where it does not behave exactly like Javascript expects
-        Ok(JsValue::new(this_str.to_lowercase()))
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3-6. Return the result of the full, locale-insensitive, lowercase mapping of S.
+        Ok(transform_case(&string, Case::Lower, CaseLocale::Root).into())
     }
 
     /// `String.prototype.toUpperCase()`
@@ -1335,7 +1369,7 @@ impl String {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.toUppercase
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.touppercase
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toUpperCase
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_uppercase(
@@ -1343,12 +1377,98 @@ impl String {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let this_str = this.to_string(context)?;
-        // The Rust String is mapped to uppercase using the builtin .to_uppercase().
-        // There might be corner cases where it does not behave exactly like Javascript expects
-        Ok(JsValue::new(this_str.to_uppercase()))
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3-6. Return the result of the full, locale-insensitive, uppercase mapping of S.
+        Ok(transform_case(&string, Case::Upper, CaseLocale::Root).into())
+    }
+
+    /// `String.prototype.toLocaleLowerCase( [ locales ] )`
+    ///
+    /// The `toLocaleLowerCase()` method returns the calling string value converted to lower case,
+    /// according to the special casings of the requested locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-string.prototype.tolocalelowercase
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleLowerCase
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_lowercase(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3. Return ? TransformCase(S, locales, lower).
+        let locale = Self::case_locale(args.get_or_undefined(0), context)?;
+        Ok(transform_case(&string, Case::Lower, locale).into())
+    }
+
+    /// `String.prototype.toLocaleUpperCase( [ locales ] )`
+    ///
+    /// The `toLocaleUpperCase()` method returns the calling string value converted to upper case,
+    /// according to the special casings of the requested locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-string.prototype.tolocaleuppercase
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleUpperCase
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_uppercase(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3. Return ? TransformCase(S, locales, upper).
+        let locale = Self::case_locale(args.get_or_undefined(0), context)?;
+        Ok(transform_case(&string, Case::Upper, locale).into())
+    }
+
+    /// Gets the special casings of the locale requested by the `locales` argument of
+    /// `toLocaleLowerCase` and `toLocaleUpperCase`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-transform-case
+    fn case_locale(locales: &JsValue, context: &mut Context) -> JsResult<CaseLocale> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. If requestedLocales is not an empty List, then
+        //     a. Let requestedLocale be requestedLocales[0].
+        // 3. Else,
+        //     a. Let requestedLocale be ! DefaultLocale().
+        // 4. Let noExtensionsLocale be the String value that is requestedLocale with any Unicode locale extension sequences removed.
+        // 5. Let availableLocales be a List with language tags that includes the languages for which the Unicode Character Database contains language sensitive case mappings.
+        // 6. Let locale be ! BestAvailableLocale(availableLocales, noExtensionsLocale).
+        // The only languages with tailored case mappings are identified by their language
+        // subtag, which the default locale doesn't tailor.
+        Ok(requested_locales
+            .first()
+            .and_then(|tag| LanguageTag::parse(tag))
+            .map_or(CaseLocale::Root, |tag| {
+                CaseLocale::from_language(tag.language())
+            }))
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -1485,18 +1605,14 @@ impl String {
         // 1. Let O be ? RequireObjectCoercible(this value).
         let this = this.require_object_coercible(context)?;
 
-        let separator = args.get(0).cloned().unwrap_or_default();
-        let limit = args.get(1).cloned().unwrap_or_default();
+        let separator = args.get_or_undefined(0).clone();
+        let limit = args.get_or_undefined(1).clone();
 
         // 2. If separator is neither undefined nor null, then
         if !separator.is_null_or_undefined() {
             // a. Let splitter be ? GetMethod(separator, @@split).
             // b. If splitter is not undefined, then
-            if let Some(splitter) = separator
-                .as_object()
-                .unwrap_or_default()
-                .get_method(context, WellKnownSymbols::split())?
-            {
+            if let Some(splitter) = separator.get_method(context, WellKnownSymbols::split())? {
                 // i. Return ? Call(splitter, separator, « O, limit »).
                 return splitter.call(&separator, &[this.clone(), limit], context);
             }
@@ -1661,11 +1777,11 @@ impl String {
         let o = this.require_object_coercible(context)?;
 
         // 2. If regexp is neither undefined nor null, then
-        let regexp = args.get(0).cloned().unwrap_or_default();
+        let regexp = args.get_or_undefined(0).clone();
         if !regexp.is_null_or_undefined() {
             // a. Let isRegExp be ? IsRegExp(regexp).
             // b. If isRegExp is true, then
-            if regexp.as_object().unwrap_or_default().is_regexp() {
+            if regexp.is_regexp(context)? {
                 // i. Let flags be ? Get(regexp, "flags").
                 let flags = regexp.get_field("flags", context)?;
 
@@ -1682,11 +1798,9 @@ impl String {
 
             // c. Let matcher be ? GetMethod(regexp, @@matchAll).
             // d. If matcher is not undefined, then
-            if let Some(obj) = regexp.as_object() {
-                if let Some(matcher) = obj.get_method(context, WellKnownSymbols::match_all())? {
-                    // i. Return ? Call(matcher, regexp, « O »).
-                    return matcher.call(&regexp, &[o.clone()], context);
-                }
+            if let Some(matcher) = regexp.get_method(context, WellKnownSymbols::match_all())? {
+                // i. Return ? Call(matcher, regexp, « O »).
+                return matcher.call(&regexp, &[o.clone()], context);
             }
         }
 
@@ -1722,7 +1836,7 @@ impl String {
     ) -> JsResult<JsValue> {
         let this = this.require_object_coercible(context)?;
         let s = this.to_string(context)?;
-        let form = args.get(0).cloned().unwrap_or_default();
+        let form = args.get_or_undefined(0).clone();
 
         let f_str;
 
@@ -1762,15 +1876,13 @@ impl String {
         let o = this.require_object_coercible(context)?;
 
         // 2. If regexp is neither undefined nor null, then
-        let regexp = args.get(0).cloned().unwrap_or_default();
+        let regexp = args.get_or_undefined(0).clone();
         if !regexp.is_null_or_undefined() {
             // a. Let searcher be ? GetMethod(regexp, @@search).
             // b. If searcher is not undefined, then
-            if let Some(obj) = regexp.as_object() {
-                if let Some(searcher) = obj.get_method(context, WellKnownSymbols::search())? {
-                    // i. Return ? Call(searcher, regexp, « O »).
-                    return searcher.call(&regexp, &[o.clone()], context);
-                }
+            if let Some(searcher) = regexp.get_method(context, WellKnownSymbols::search())? {
+                // i. Return ? Call(searcher, regexp, « O »).
+                return searcher.call(&regexp, &[o.clone()], context);
             }
         }
 
@@ -1144,3 +1144,261 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn symbol_protocol_custom_matcher() {
+    let mut context = Context::new();
+    let init = r#"
+        const matcher = { flags: 'g' };
+        matcher[Symbol.match] = function(s) { return 'match ' + s; };
+        matcher[Symbol.matchAll] = function(s) { return 'matchAll ' + s; };
+        matcher[Symbol.replace] = function(s, r) { return 'replace ' + s + ' ' + r; };
+        matcher[Symbol.search] = function(s) { return 'search ' + s; };
+        matcher[Symbol.split] = function(s, l) { return 'split ' + s + ' ' + l; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'a'.match(matcher)"), "\"match a\"");
+    assert_eq!(
+        forward(&mut context, "'a'.matchAll(matcher)"),
+        "\"matchAll a\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a'.replace(matcher, 'b')"),
+        "\"replace a b\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a'.replaceAll(matcher, 'b')"),
+        "\"replace a b\""
+    );
+    assert_eq!(forward(&mut context, "'a'.search(matcher)"), "\"search a\"");
+    assert_eq!(
+        forward(&mut context, "'a'.split(matcher, 2)"),
+        "\"split a 2\""
+    );
+}
+
+#[test]
+fn symbol_protocol_primitive_matcher() {
+    let mut context = Context::new();
+    let init = r#"
+        Number.prototype[Symbol.search] = function(s) { return 'search ' + this + ' ' + s; };
+        Number.prototype[Symbol.split] = function(s) { return 'split ' + this + ' ' + s; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'a1'.search(1)"), "\"search 1 a1\"");
+    assert_eq!(forward(&mut context, "'a1'.split(1)"), "\"split 1 a1\"");
+}
+
+#[test]
+fn symbol_protocol_regexp_subclass() {
+    let mut context = Context::new();
+    let init = r#"
+        function Upper(pattern) {
+            return Object.setPrototypeOf(new RegExp(pattern), Upper.prototype);
+        }
+        Upper.prototype = Object.create(RegExp.prototype);
+        Upper.prototype[Symbol.replace] = function(s, r) {
+            return RegExp.prototype[Symbol.replace].call(this, s, r).toUpperCase();
+        };
+        Upper.prototype[Symbol.split] = function(s, l) {
+            return RegExp.prototype[Symbol.split].call(this, s, l).reverse();
+        };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "'abc'.replace(new Upper('b'), 'x')"),
+        "\"AXC\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a,b,c'.split(new Upper(',')).join('')"),
+        "\"cba\""
+    );
+}
+
+#[test]
+fn symbol_protocol_is_regexp() {
+    let mut context = Context::new();
+    let init = r#"
+        const fake = { flags: '' };
+        fake[Symbol.match] = true;
+        fake[Symbol.replace] = function() { return 'replaced'; };
+        const plain = /a/;
+        plain[Symbol.match] = false;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "'a'.replaceAll(fake, 'b')"),
+        "Uncaught \"TypeError\": \"String.prototype.replaceAll called with a non-global RegExp argument\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a'.startsWith(fake)"),
+        "Uncaught \"TypeError\": \"First argument to String.prototype.startsWith must not be a regular expression\""
+    );
+    assert_eq!(forward(&mut context, "'/a/'.includes(plain)"), "true");
+}
+
+#[test]
+fn locale_compare() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut context, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('a')"), "0");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('B')"), "-1");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('A')"), "-1");
+    assert_eq!(
+        forward(&mut context, "'résumé'.localeCompare('resume')"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "'résumé'.localeCompare('resumes')"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\u0041\\u030a'.localeCompare('\\u00c5')"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "'a2'.localeCompare('a10')"), "1");
+}
+
+#[test]
+fn locale_compare_options() {
+    let mut context = Context::new();
+
+    let cases = [
+        (
+            "'a'.localeCompare('á', undefined, { sensitivity: 'base' })",
+            "0",
+        ),
+        (
+            "'a'.localeCompare('A', undefined, { sensitivity: 'base' })",
+            "0",
+        ),
+        (
+            "'a'.localeCompare('á', undefined, { sensitivity: 'accent' })",
+            "-1",
+        ),
+        (
+            "'a'.localeCompare('A', undefined, { sensitivity: 'accent' })",
+            "0",
+        ),
+        (
+            "'a'.localeCompare('á', undefined, { sensitivity: 'case' })",
+            "0",
+        ),
+        (
+            "'a'.localeCompare('A', undefined, { sensitivity: 'case' })",
+            "-1",
+        ),
+        ("'a'.localeCompare('A', 'en', { caseFirst: 'upper' })", "1"),
+        ("'a'.localeCompare('A', 'en-u-kf-upper')", "1"),
+        (
+            "'a2'.localeCompare('a10', undefined, { numeric: true })",
+            "-1",
+        ),
+        ("'a2'.localeCompare('a10', 'en-u-kn')", "-1"),
+        (
+            "'a2'.localeCompare('a10', 'en-u-kn', { numeric: false })",
+            "1",
+        ),
+        (
+            "'a-b'.localeCompare('ab', undefined, { ignorePunctuation: true })",
+            "0",
+        ),
+    ];
+    for (source, expected) in &cases {
+        assert_eq!(forward(&mut context, source), *expected, "{}", source);
+    }
+
+    for invalid in &[
+        "'a'.localeCompare('b', 'en--US')",
+        "'a'.localeCompare('b', undefined, { sensitivity: 'none' })",
+        "'a'.localeCompare('b', undefined, { caseFirst: 'true' })",
+        "'a'.localeCompare('b', undefined, { usage: 'filter' })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {}; }} catch (e) {{ e.name }}", invalid)
+            ),
+            "\"RangeError\"",
+            "{}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn case_mapping() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'Hello World'.toLowerCase()"),
+        "\"hello world\""
+    );
+    assert_eq!(
+        forward(&mut context, "'Hello World'.toUpperCase()"),
+        "\"HELLO WORLD\""
+    );
+    assert_eq!(
+        forward(&mut context, "'straße'.toUpperCase()"),
+        "\"STRASSE\""
+    );
+    assert_eq!(forward(&mut context, "'ΟΔΟΣ'.toLowerCase()"), "\"οδος\"");
+    assert_eq!(forward(&mut context, "'İ'.toLowerCase().length"), "2");
+    assert_eq!(forward(&mut context, "'ŉ'.toUpperCase()"), "\"ʼN\"");
+    assert_eq!(
+        forward(&mut context, "String.prototype.toUpperCase.call(true)"),
+        "\"TRUE\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.prototype.toLowerCase.call(null)"),
+        "Uncaught \"TypeError\": \"cannot convert null or undefined to Object\""
+    );
+}
+
+#[test]
+fn locale_case_mapping() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'I'.toLocaleLowerCase()"), "\"i\"");
+    assert_eq!(
+        forward(&mut context, "'Iİ'.toLocaleLowerCase('tr')"),
+        "\"ıi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'I\\u0307'.toLocaleLowerCase('az')"),
+        "\"i\""
+    );
+    assert_eq!(
+        forward(&mut context, "'iı'.toLocaleUpperCase(['tr-TR', 'en'])"),
+        "\"İI\""
+    );
+    assert_eq!(
+        forward(&mut context, "'i'.toLocaleUpperCase('en-u-co-trad')"),
+        "\"I\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'I\\u0301'.toLocaleLowerCase('lt') === 'i\\u0307\\u0301'"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\u00CC'.toLocaleLowerCase('lt').length"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "'i\\u0307'.toLocaleUpperCase('lt')"),
+        "\"I\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a'.toLocaleLowerCase([1])"),
+        "Uncaught \"TypeError\": \"locale should be a String or an Object\""
+    );
+}
@@ -0,0 +1,101 @@
+//! This module implements the global `structuredClone` function.
+//!
+//! The `structuredClone()` function creates a deep clone of a value, using the
+//! [serialization](crate::serialization) of values. `ArrayBuffer`s listed in the `transfer`
+//! option are moved to the clone instead of being copied, and are detached.
+//!
+//! Since there is no `DOMException`, the `DataCloneError`s of the specification are thrown as
+//! `TypeError`s.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+use crate::{
+    builtins::{iterable::iterable_to_list, BuiltIn},
+    object::{FunctionBuilder, JsObject},
+    property::Attribute,
+    serialization::{deserialize_with_transfer, serialize_with_transfer},
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `structuredClone` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl BuiltIn for StructuredClone {
+    const NAME: &'static str = "structuredClone";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let structured_clone = FunctionBuilder::native(context, Self::structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, structured_clone.into(), Self::attribute())
+    }
+}
+
+impl StructuredClone {
+    /// `structuredClone ( value [ , options ] )`
+    ///
+    /// Returns a deep clone of `value`, transferring the `ArrayBuffer`s listed in the `transfer`
+    /// property of `options`.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+    fn structured_clone(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+        let transfer = Self::transfer_list(args.get_or_undefined(1), context)?;
+
+        // 1. Let serialized be ? StructuredSerializeWithTransfer(value, options["transfer"]).
+        let (bytes, transferred) = serialize_with_transfer(value, &transfer, context)?;
+
+        // 2. Let deserializeRecord be ? StructuredDeserializeWithTransfer(serialized, this's relevant realm).
+        // 3. Return deserializeRecord.[[Deserialized]].
+        deserialize_with_transfer(&bytes, transferred, context)
+    }
+
+    /// Reads the `transfer` sequence of a `StructuredSerializeOptions` dictionary.
+    fn transfer_list(options: &JsValue, context: &mut Context) -> JsResult<Vec<JsObject>> {
+        if options.is_null_or_undefined() {
+            return Ok(Vec::new());
+        }
+        if !options.is_object() {
+            return context
+                .throw_type_error("structuredClone options must be an object")
+                .map(|_| Vec::new());
+        }
+
+        let transfer = options.get_field("transfer", context)?;
+        if transfer.is_undefined() {
+            return Ok(Vec::new());
+        }
+        iterable_to_list(context, transfer)?
+            .into_iter()
+            .map(|value| {
+                value.as_object().ok_or_else(|| {
+                    context.construct_type_error("transferred values must be objects")
+                })
+            })
+            .collect()
+    }
+}
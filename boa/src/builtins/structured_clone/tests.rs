@@ -0,0 +1,106 @@
+use crate::{forward, Context};
+
+#[test]
+fn clones_values() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var original = {
+            list: [1, 'two', 3n],
+            when: new Date(0),
+            pattern: /a+/gi,
+            map: new Map([['key', { nested: true }]]),
+            set: new Set([1, 2]),
+            bytes: new Uint8Array([1, 2, 3]),
+        };
+        original.self = original;
+        var clone = structuredClone(original);
+        "#,
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            clone !== original
+                && clone.self === clone
+                && clone.list.join() === '1,two,3'
+                && clone.when instanceof Date && clone.when.getTime() === 0
+                && clone.pattern instanceof RegExp && clone.pattern.flags === 'gi'
+                && clone.map.get('key').nested === true
+                && clone.map.get('key') !== original.map.get('key')
+                && clone.set.has(2)
+                && clone.bytes.buffer !== original.bytes.buffer
+                && clone.bytes.join() === '1,2,3'
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "structuredClone('text')"), "\"text\"");
+    assert_eq!(forward(&mut context, "structuredClone.length"), "1");
+}
+
+#[test]
+fn transfers_array_buffers() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var buffer = new ArrayBuffer(4);
+        var view = new Uint8Array(buffer);
+        view[0] = 42;
+        var clone = structuredClone({ view: view }, { transfer: [buffer] });
+        "#,
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[buffer.byteLength, clone.view.byteLength, clone.view[0]].join()"
+        ),
+        "\"0,4,42\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var other = new ArrayBuffer(2); structuredClone(1, { transfer: [other] }); other.byteLength"
+        ),
+        "0"
+    );
+}
+
+#[test]
+fn throws_data_clone_errors() {
+    let mut context = Context::new();
+
+    for source in &[
+        "structuredClone(function () {})",
+        "structuredClone({ symbol: Symbol() })",
+        "structuredClone(1, 2)",
+        "structuredClone(1, { transfer: [{}] })",
+        "structuredClone(1, { transfer: [1] })",
+        "var twice = new ArrayBuffer(1); structuredClone(1, { transfer: [twice, twice] })",
+        "var detached = new ArrayBuffer(1); structuredClone(1, { transfer: [detached] }); structuredClone(1, { transfer: [detached] })",
+        "structuredClone(1, { transfer: [new SharedArrayBuffer(1)] })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {}; false }} catch (e) {{ e instanceof TypeError }}", source)
+            ),
+            "true",
+            "{}",
+            source
+        );
+    }
+
+    // Nothing is detached when the clone fails.
+    assert_eq!(
+        forward(
+            &mut context,
+            "var kept = new ArrayBuffer(1); try { structuredClone(function () {}, { transfer: [kept] }) } catch (e) {} kept.byteLength"
+        ),
+        "1"
+    );
+}
@@ -23,6 +23,7 @@ use crate::{
     object::{ConstructorBuilder, FunctionBuilder},
     property::Attribute,
     symbol::{JsSymbol, WellKnownSymbols},
+    value::ArgumentsExt,
     value::JsValue,
     BoaProfiler, Context, JsResult, JsString,
 };
@@ -75,7 +76,7 @@ impl BuiltIn for Symbol {
     const NAME: &'static str = "Symbol";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::builtin()
     }
 
     fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -95,13 +96,19 @@ impl BuiltIn for Symbol {
         let symbol_to_string_tag = WellKnownSymbols::to_string_tag();
         let symbol_unscopables = WellKnownSymbols::unscopables();
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::constant();
 
         let get_description = FunctionBuilder::native(context, Self::get_description)
             .name("get description")
             .constructable(false)
             .build();
 
+        let to_primitive = FunctionBuilder::native(context, Self::to_primitive)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .constructable(false)
+            .build();
+
         let symbol_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -121,22 +128,28 @@ impl BuiltIn for Symbol {
         .static_property("search", symbol_search, attribute)
         .static_property("species", symbol_species, attribute)
         .static_property("split", symbol_split, attribute)
-        .static_property("toPrimitive", symbol_to_primitive, attribute)
+        .static_property("toPrimitive", symbol_to_primitive.clone(), attribute)
         .static_property("toStringTag", symbol_to_string_tag.clone(), attribute)
         .static_property("unscopables", symbol_unscopables, attribute)
         .method(Self::to_string, "toString", 0)
+        .method(Self::value_of, "valueOf", 0)
         .accessor(
             "description",
             Some(get_description),
             None,
-            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            Attribute::readonly_builtin(),
         )
         .callable(true)
         .constructable(false)
         .property(
             symbol_to_string_tag,
             Self::NAME,
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            Attribute::readonly_builtin(),
+        )
+        .property(
+            symbol_to_primitive.clone(),
+            to_primitive,
+            Attribute::readonly_builtin(),
         )
         .build();
 
@@ -160,13 +173,12 @@ impl Symbol {
     /// [spec]: https://tc39.es/ecma262/#sec-symbol-description
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/Symbol
     pub(crate) fn constructor(
-        new_target: &JsValue,
+        _: &JsValue,
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        if new_target.is_undefined() {
-            return context.throw_type_error("Symbol is not a constructor");
-        }
+        // 1. If NewTarget is not undefined, throw a TypeError exception.
+        // `Symbol` is not constructable, so `new Symbol()` already throws before getting here.
         let description = match args.get(0) {
             Some(value) if !value.is_undefined() => Some(value.to_string(context)?),
             _ => None,
@@ -210,6 +222,45 @@ impl Symbol {
         Ok(symbol.to_string().into())
     }
 
+    /// `Symbol.prototype.valueOf()`
+    ///
+    /// This method returns the primitive value of a `Symbol` object.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf
+    pub(crate) fn value_of(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? thisSymbolValue(this value).
+        Ok(Self::this_symbol_value(this, context)?.into())
+    }
+
+    /// `Symbol.prototype [ @@toPrimitive ] ( hint )`
+    ///
+    /// This method converts a `Symbol` object to its primitive value, whatever the hint is.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype-@@toprimitive
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/@@toPrimitive
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_primitive(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? thisSymbolValue(this value).
+        Ok(Self::this_symbol_value(this, context)?.into())
+    }
+
     /// `get Symbol.prototype.description`
     ///
     /// This accessor returns the description of the `Symbol` object.
@@ -276,7 +327,7 @@ impl Symbol {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let sym = args.get(0).cloned().unwrap_or_default();
+        let sym = args.get_or_undefined(0).clone();
         // 1. If Type(sym) is not Symbol, throw a TypeError exception.
         if let Some(sym) = sym.as_symbol() {
             // 2. For each element e of the GlobalSymbolRegistry List (see 20.4.2.2), do
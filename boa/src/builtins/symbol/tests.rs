@@ -38,3 +38,33 @@ fn symbol_access() {
         TestAction::TestEq("x['Symbol(Hello)']", "undefined"),
     ]);
 }
+
+#[test]
+fn symbol_to_primitive() {
+    let mut context = Context::new();
+    let init = r#"
+        var wrapper = Object(Symbol.iterator);
+        var desc = Object.getOwnPropertyDescriptor(Symbol.prototype, Symbol.toPrimitive);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "wrapper == Symbol.iterator"), "true");
+    assert_eq!(
+        forward(&mut context, "wrapper.valueOf() === Symbol.iterator"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.prototype[Symbol.toPrimitive].call(Symbol.iterator, 'number') === Symbol.iterator"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "desc.writable"), "false");
+    assert_eq!(forward(&mut context, "desc.configurable"), "true");
+    assert_eq!(
+        forward(&mut context, "desc.value.name"),
+        "\"[Symbol.toPrimitive]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.prototype.valueOf.call({})"),
+        "Uncaught \"TypeError\": \"'this' is not a Symbol\""
+    );
+}
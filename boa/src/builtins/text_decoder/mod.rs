@@ -0,0 +1,409 @@
+//! This module implements the global `TextDecoder` object.
+//!
+//! A `TextDecoder` decodes bytes in one of the encodings of the WHATWG Encoding Standard,
+//! selected by any of their labels, into strings. The input can be decoded in chunks, by
+//! decoding with the `stream` option until the last chunk.
+//!
+//! More information:
+//!  - [WHATWG reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#interface-textdecoder
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder
+
+use crate::{
+    builtins::BuiltIn,
+    context::StandardObjects,
+    gc::{Finalize, Trace},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+use encoding_rs::{CoderResult, Decoder, DecoderResult, Encoding, REPLACEMENT, UTF_8};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `TextDecoder` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct TextDecoder {
+    #[unsafe_ignore_trace]
+    encoding: &'static Encoding,
+    fatal: bool,
+    ignore_bom: bool,
+    /// The decoder of the stream being decoded, holding the bytes of its incomplete characters.
+    #[unsafe_ignore_trace]
+    decoder: Option<Decoder>,
+}
+
+impl BuiltIn for TextDecoder {
+    const NAME: &'static str = "TextDecoder";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_encoding = FunctionBuilder::native(context, Self::get_encoding)
+            .name("get encoding")
+            .constructable(false)
+            .build();
+        let get_fatal = FunctionBuilder::native(context, Self::get_fatal)
+            .name("get fatal")
+            .constructable(false)
+            .build();
+        let get_ignore_bom = FunctionBuilder::native(context, Self::get_ignore_bom)
+            .name("get ignoreBOM")
+            .constructable(false)
+            .build();
+
+        let text_decoder_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().text_decoder_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .accessor(
+            "encoding",
+            Some(get_encoding),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "fatal",
+            Some(get_fatal),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "ignoreBOM",
+            Some(get_ignore_bom),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::decode, "decode", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, text_decoder_object.into(), Self::attribute())
+    }
+}
+
+impl TextDecoder {
+    /// `TextDecoder ( [ label [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/TextDecoder
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin TextDecoder constructor without new is forbidden",
+            );
+        }
+
+        // 1. Let encoding be the result of getting an encoding from label.
+        // 2. If encoding is failure or replacement, then throw a RangeError.
+        let encoding = match args.get_or_undefined(0) {
+            JsValue::Undefined => UTF_8,
+            label => {
+                let label = label.to_string(context)?;
+                match Encoding::for_label(label.as_bytes()) {
+                    Some(encoding) if encoding != REPLACEMENT => encoding,
+                    _ => {
+                        return context.throw_range_error(format!(
+                            "The encoding label provided ('{}') is invalid",
+                            label
+                        ))
+                    }
+                }
+            }
+        };
+
+        // 4. If options["fatal"] is true, then set this's error mode to "fatal".
+        // 5. Set this's ignore BOM to options["ignoreBOM"].
+        let options = get_options(args.get_or_undefined(1), "TextDecoder", context)?;
+        let fatal = get_bool_option(options.as_ref(), "fatal", context)?;
+        let ignore_bom = get_bool_option(options.as_ref(), "ignoreBOM", context)?;
+
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::text_decoder_object,
+            context,
+        )?;
+        let decoder = context.construct_object();
+        decoder.set_prototype_instance(prototype.into());
+        decoder.borrow_mut().data = ObjectData::text_decoder(Box::new(Self {
+            encoding,
+            fatal,
+            ignore_bom,
+            decoder: None,
+        }));
+        Ok(decoder.into())
+    }
+
+    /// Gets the `TextDecoder` object of `this`.
+    fn require_text_decoder(
+        this: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        match this.as_object() {
+            Some(object) if object.borrow().as_text_decoder().is_some() => Ok(object.clone()),
+            _ => Err(context.construct_type_error(format!(
+                "TextDecoder.prototype.{} called on a non-TextDecoder",
+                method
+            ))),
+        }
+    }
+
+    /// `get TextDecoder.prototype.encoding`
+    ///
+    /// Returns the lowercase name of the encoding of the decoder.
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-encoding
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/encoding
+    fn get_encoding(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let decoder = Self::require_text_decoder(this, "encoding", context)?;
+        let decoder = decoder.borrow();
+        let decoder = decoder.as_text_decoder().expect("checked to be a decoder");
+        Ok(decoder.encoding.name().to_ascii_lowercase().into())
+    }
+
+    /// `get TextDecoder.prototype.fatal`
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-fatal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/fatal
+    fn get_fatal(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let decoder = Self::require_text_decoder(this, "fatal", context)?;
+        let decoder = decoder.borrow();
+        let decoder = decoder.as_text_decoder().expect("checked to be a decoder");
+        Ok(decoder.fatal.into())
+    }
+
+    /// `get TextDecoder.prototype.ignoreBOM`
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-ignorebom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/ignoreBOM
+    fn get_ignore_bom(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let decoder = Self::require_text_decoder(this, "ignoreBOM", context)?;
+        let decoder = decoder.borrow();
+        let decoder = decoder.as_text_decoder().expect("checked to be a decoder");
+        Ok(decoder.ignore_bom.into())
+    }
+
+    /// `TextDecoder.prototype.decode ( [ input [ , options ] ] )`
+    ///
+    /// Decodes the bytes of `input`. With the `stream` option, the bytes of an incomplete
+    /// character at the end of `input` are kept, to be decoded with the next call.
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-decode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode
+    fn decode(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = Self::require_text_decoder(this, "decode", context)?;
+
+        let input = match args.get_or_undefined(0) {
+            JsValue::Undefined => Vec::new(),
+            input => match buffer_source_bytes(input) {
+                Some(bytes) => bytes,
+                None => {
+                    return context.throw_type_error(
+                        "TextDecoder.prototype.decode input must be an ArrayBuffer or a view",
+                    )
+                }
+            },
+        };
+        let options = get_options(args.get_or_undefined(1), "decode", context)?;
+        let stream = get_bool_option(options.as_ref(), "stream", context)?;
+
+        // 1. If this's do not flush is false, then set this's decoder to a new instance of this's
+        //    encoding's decoder, this's I/O queue to the I/O queue of bytes « end-of-queue », and
+        //    this's BOM seen to false.
+        // 2. Set this's do not flush to options["stream"].
+        let mut object = object.borrow_mut();
+        let text_decoder = object
+            .as_text_decoder_mut()
+            .expect("checked to be a decoder");
+        let mut decoder = match text_decoder.decoder.take() {
+            Some(decoder) => decoder,
+            None if text_decoder.ignore_bom => {
+                text_decoder.encoding.new_decoder_without_bom_handling()
+            }
+            None => text_decoder.encoding.new_decoder_with_bom_removal(),
+        };
+
+        // 3. If input is given, then push a copy of input to this's I/O queue.
+        // 4. Let output be the I/O queue of scalar values « end-of-queue ».
+        // 5. While true:
+        //     a. Let item be the result of reading from this's I/O queue.
+        //     b. If item is end-of-queue and this's do not flush is true, then return the result
+        //        of running serialize I/O queue with this and output.
+        //     c. Otherwise:
+        //         i. Let result be the result of processing an item with item, this's decoder,
+        //            this's I/O queue, output, and this's error mode.
+        //         ii. If result is finished, then return the result of running serialize I/O
+        //             queue with this and output.
+        //         iii. Otherwise, if result is error, throw a TypeError.
+        let output = decode_bytes(&mut decoder, &input, !stream, text_decoder.fatal);
+        if stream && output.is_some() {
+            text_decoder.decoder = Some(decoder);
+        }
+        drop(object);
+
+        match output {
+            Some(output) => Ok(output.into()),
+            None => context.throw_type_error("The encoded data was not valid"),
+        }
+    }
+}
+
+/// Decodes `bytes` with `decoder`, or returns `None` if they are malformed and `fatal` is set.
+///
+/// Unless `last` is set, the bytes of an incomplete character at the end of `bytes` are kept by
+/// the decoder.
+fn decode_bytes(
+    decoder: &mut Decoder,
+    mut bytes: &[u8],
+    last: bool,
+    fatal: bool,
+) -> Option<String> {
+    let mut output = String::new();
+    loop {
+        let capacity = if fatal {
+            decoder.max_utf8_buffer_length_without_replacement(bytes.len())
+        } else {
+            decoder.max_utf8_buffer_length(bytes.len())
+        };
+        output.reserve(capacity.unwrap_or(bytes.len()).max(4));
+
+        let (output_full, read) = if fatal {
+            match decoder.decode_to_string_without_replacement(bytes, &mut output, last) {
+                (DecoderResult::Malformed(_, _), _) => return None,
+                (result, read) => (result == DecoderResult::OutputFull, read),
+            }
+        } else {
+            let (result, read, _) = decoder.decode_to_string(bytes, &mut output, last);
+            (result == CoderResult::OutputFull, read)
+        };
+        bytes = &bytes[read..];
+
+        if !output_full {
+            return Some(output);
+        }
+    }
+}
+
+/// Returns the bytes viewed by a `BufferSource`, an `ArrayBuffer` or a view of one, or `None` if
+/// `value` is not a `BufferSource`.
+///
+/// More information:
+///  - [WebIDL reference][spec]
+///
+/// [spec]: https://webidl.spec.whatwg.org/#BufferSource
+pub(crate) fn buffer_source_bytes(value: &JsValue) -> Option<Vec<u8>> {
+    let object = value.as_object()?;
+    let object = object.borrow();
+
+    let (buffer, offset, length) = if let Some(buffer) = object.as_array_buffer() {
+        let mut bytes = vec![0; buffer.byte_length()];
+        if !buffer.is_detached_buffer() {
+            buffer.read_bytes(0, &mut bytes);
+        }
+        return Some(bytes);
+    } else if let Some(typed_array) = object.as_typed_array() {
+        if typed_array.is_out_of_bounds() {
+            return Some(Vec::new());
+        }
+        (
+            typed_array
+                .viewed_array_buffer()
+                .expect("attached typed arrays view a buffer")
+                .clone(),
+            typed_array.byte_offset(),
+            typed_array.byte_length(),
+        )
+    } else if let Some(data_view) = object.as_data_view() {
+        (
+            data_view.viewed_array_buffer().clone(),
+            data_view.byte_offset(),
+            data_view.byte_length(),
+        )
+    } else {
+        return None;
+    };
+    drop(object);
+
+    let buffer = buffer.borrow();
+    let buffer = buffer
+        .as_array_buffer()
+        .expect("views always view an array buffer");
+    let mut bytes = vec![0; length];
+    if !buffer.is_detached_buffer() && offset + length <= buffer.byte_length() {
+        buffer.read_bytes(offset, &mut bytes);
+    }
+    Some(bytes)
+}
+
+/// Converts the `options` dictionary argument of `method`, which must be an object, `undefined`
+/// or `null`.
+fn get_options(
+    options: &JsValue,
+    method: &str,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    match options {
+        JsValue::Undefined | JsValue::Null => Ok(None),
+        JsValue::Object(options) => Ok(Some(options.clone())),
+        _ => {
+            Err(context
+                .construct_type_error(format!("The options of {} must be an object", method)))
+        }
+    }
+}
+
+/// Gets the boolean member `property` of an options dictionary, which defaults to `false`.
+fn get_bool_option(
+    options: Option<&JsObject>,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<bool> {
+    match options {
+        Some(options) => Ok(options.get(property, context)?.to_boolean()),
+        None => Ok(false),
+    }
+}
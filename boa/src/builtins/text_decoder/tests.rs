@@ -0,0 +1,144 @@
+use crate::{forward, Context};
+
+#[test]
+fn decode() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var decoder = new TextDecoder(); [decoder.encoding, decoder.fatal, decoder.ignoreBOM].join()"
+        ),
+        "\"utf-8,false,false\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "decoder.decode(new Uint8Array([0xEF, 0xBB, 0xBF, 97, 0xC3, 0xA9, 0xFF]))"
+        ),
+        "\"aé\u{FFFD}\""
+    );
+    assert_eq!(forward(&mut context, "decoder.decode()"), "\"\"");
+
+    // Any buffer source can be decoded.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var buffer = new TextEncoder().encode('xyz').buffer;
+            [
+                decoder.decode(buffer),
+                decoder.decode(new DataView(buffer, 1)),
+                decoder.decode(new Uint16Array(buffer, 0, 1)),
+            ].join()
+            "#
+        ),
+        "\"xyz,yz,xy\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { decoder.decode('abc'); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn labels_and_options() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[' UTF8 ', 'latin1', 'shift_jis', 'utf-16le'].map(l => new TextDecoder(l).encoding).join()"
+        ),
+        "\"utf-8,windows-1252,shift_jis,utf-16le\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new TextDecoder('latin1').decode(new Uint8Array([0x63, 0x61, 0x66, 0xE9, 0x80]))"
+        ),
+        "\"café€\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new TextDecoder('utf-16le').decode(new Uint8Array([0xFF, 0xFE, 0x3D, 0xD8, 0x00, 0xDE]))"
+        ),
+        "\"😀\""
+    );
+
+    for label in &["'unknown'", "'replacement'", "'iso-2022-kr'"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ new TextDecoder({}); }} catch (e) {{ e instanceof RangeError }}",
+                    label
+                )
+            ),
+            "true",
+            "{}",
+            label
+        );
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var fatal = new TextDecoder('utf-8', { fatal: true, ignoreBOM: true });
+            var bom = fatal.decode(new Uint8Array([0xEF, 0xBB, 0xBF, 97]));
+            [fatal.fatal, fatal.ignoreBOM, bom.length].join()
+            "#
+        ),
+        "\"true,true,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fatal.decode(new Uint8Array([0xC3])); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn stream() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var decoder = new TextDecoder('utf-8', { fatal: true });
+            var bytes = new TextEncoder().encode('€🦀');
+            var chunks = [];
+            for (var i = 0; i < bytes.length; i++) {
+                chunks.push(decoder.decode(bytes.subarray(i, i + 1), { stream: true }));
+            }
+            chunks.push(decoder.decode());
+            chunks.join('|')
+            "#
+        ),
+        "\"||€||||🦀|\""
+    );
+
+    // A stream ending with an incomplete character is malformed.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            decoder.decode(new Uint8Array([0xE2, 0x82]), { stream: true });
+            try { decoder.decode(); } catch (e) { e instanceof TypeError }
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "decoder.decode(new Uint8Array([97]))"),
+        "\"a\""
+    );
+}
@@ -0,0 +1,238 @@
+//! This module implements the global `TextEncoder` object.
+//!
+//! A `TextEncoder` encodes strings into their UTF-8 bytes.
+//!
+//! More information:
+//!  - [WHATWG reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#interface-textencoder
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder
+
+use crate::{
+    builtins::{typed_array::TypedArrayName, ArrayBuffer, BuiltIn},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `TextEncoder` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextEncoder;
+
+impl BuiltIn for TextEncoder {
+    const NAME: &'static str = "TextEncoder";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_encoding = FunctionBuilder::native(context, Self::get_encoding)
+            .name("get encoding")
+            .constructable(false)
+            .build();
+
+        let text_encoder_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().text_encoder_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .accessor(
+            "encoding",
+            Some(get_encoding),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::encode, "encode", 0)
+        .method(Self::encode_into, "encodeInto", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .build();
+
+        (Self::NAME, text_encoder_object.into(), Self::attribute())
+    }
+}
+
+impl TextEncoder {
+    /// `TextEncoder ( )`
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/TextEncoder
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin TextEncoder constructor without new is forbidden",
+            );
+        }
+
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::text_encoder_object,
+            context,
+        )?;
+        let encoder = context.construct_object();
+        encoder.set_prototype_instance(prototype.into());
+        encoder.borrow_mut().data = ObjectData::text_encoder();
+        Ok(encoder.into())
+    }
+
+    /// Checks that `this` is a `TextEncoder` object.
+    fn require_text_encoder(this: &JsValue, method: &str, context: &mut Context) -> JsResult<()> {
+        match this.as_object() {
+            Some(object) if object.borrow().is_text_encoder() => Ok(()),
+            _ => context
+                .throw_type_error(format!(
+                    "TextEncoder.prototype.{} called on a non-TextEncoder",
+                    method
+                ))
+                .map(|_| ()),
+        }
+    }
+
+    /// `get TextEncoder.prototype.encoding`
+    ///
+    /// A `TextEncoder` always encodes to UTF-8.
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encoding
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encoding
+    fn get_encoding(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::require_text_encoder(this, "encoding", context)?;
+        Ok("utf-8".into())
+    }
+
+    /// `TextEncoder.prototype.encode ( [ input ] )`
+    ///
+    /// Returns a new `Uint8Array` holding the UTF-8 bytes of `input`.
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encode
+    fn encode(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::require_text_encoder(this, "encode", context)?;
+
+        let input = match args.get_or_undefined(0) {
+            JsValue::Undefined => String::new(),
+            input => input.to_string(context)?.to_string(),
+        };
+
+        Self::create_uint8_array(input.into_bytes(), context)
+    }
+
+    /// `TextEncoder.prototype.encodeInto ( source, destination )`
+    ///
+    /// Writes the UTF-8 bytes of `source` into the `destination` array, stopping before the first
+    /// character that does not fit, and returns the amount of UTF-16 code units read and of bytes
+    /// written.
+    ///
+    /// More information:
+    ///  - [WHATWG reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encodeinto
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encodeInto
+    fn encode_into(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::require_text_encoder(this, "encodeInto", context)?;
+
+        let source = args.get_or_undefined(0).to_string(context)?;
+        let destination = args
+            .get_or_undefined(1)
+            .as_object()
+            .and_then(|destination| {
+                let destination = destination.borrow();
+                let typed_array = destination.as_typed_array().filter(|typed_array| {
+                    typed_array.typed_array_name() == TypedArrayName::Uint8Array
+                })?;
+                if typed_array.is_out_of_bounds() {
+                    return Some((None, 0, 0));
+                }
+                Some((
+                    typed_array.viewed_array_buffer().cloned(),
+                    typed_array.byte_offset(),
+                    typed_array.array_length(),
+                ))
+            });
+        let (buffer, offset, length) = match destination {
+            Some(destination) => destination,
+            None => {
+                return context.throw_type_error(
+                    "TextEncoder.prototype.encodeInto destination must be a Uint8Array",
+                )
+            }
+        };
+
+        // Only the characters that fit entirely in the destination are written.
+        let mut read = 0;
+        let mut bytes = Vec::with_capacity(length.min(source.len()));
+        for c in source.chars() {
+            if bytes.len() + c.len_utf8() > length {
+                break;
+            }
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            read += c.len_utf16();
+        }
+
+        if let Some(buffer) = buffer {
+            buffer
+                .borrow_mut()
+                .as_array_buffer_mut()
+                .expect("typed arrays view an array buffer")
+                .write_bytes(offset, &bytes);
+        }
+
+        let result = context.construct_object();
+        result.create_data_property_or_throw("read", read, context)?;
+        result.create_data_property_or_throw("written", bytes.len(), context)?;
+        Ok(result.into())
+    }
+
+    /// Creates a `Uint8Array` viewing a new `ArrayBuffer` that holds `bytes`.
+    pub(crate) fn create_uint8_array(bytes: Vec<u8>, context: &mut Context) -> JsResult<JsValue> {
+        let buffer = context.construct_object();
+        buffer.set_prototype_instance(
+            context
+                .standard_objects()
+                .array_buffer_object()
+                .prototype()
+                .into(),
+        );
+        buffer.borrow_mut().data = ObjectData::array_buffer(ArrayBuffer::from_bytes(bytes));
+
+        let constructor = context
+            .standard_objects()
+            .typed_uint8_array_object()
+            .constructor();
+        constructor.construct(&[buffer.into()], &constructor.clone().into(), context)
+    }
+}
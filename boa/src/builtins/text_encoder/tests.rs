@@ -0,0 +1,73 @@
+use crate::{forward, Context};
+
+#[test]
+fn encode() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var encoder = new TextEncoder(); encoder.encoding"
+        ),
+        "\"utf-8\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.join.call(encoder.encode('aé€🦀'))"
+        ),
+        "\"97,195,169,226,130,172,240,159,166,128\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var empty = encoder.encode(); empty instanceof Uint8Array && empty.length"
+        ),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "encoder.encode(12).length"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { TextEncoder(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { encoder.encode.call({}, 'a'); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn encode_into() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        r#"
+        var encoder = new TextEncoder();
+        var bytes = new Uint8Array(8);
+        var view = new Uint8Array(bytes.buffer, 2, 5);
+        var result = encoder.encodeInto('a€🦀', view);
+        "#,
+    );
+    assert_eq!(
+        forward(&mut context, "[result.read, result.written].join()"),
+        "\"2,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.prototype.join.call(bytes)"),
+        "\"0,0,97,226,130,172,0,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { encoder.encodeInto('a', new Int8Array(2)); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
@@ -0,0 +1,178 @@
+//! This module implements the `IntegerIndexed` exotic object, the data of every typed array.
+//!
+//! An `IntegerIndexed` object is an exotic object that performs special handling of integer
+//! index property keys, reading and writing its elements directly from and to the
+//! `ArrayBuffer` it views.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
+
+use crate::{
+    builtins::{array_buffer::ArrayBuffer, typed_array::TypedArrayName},
+    gc::{Finalize, Trace},
+    object::JsObject,
+};
+
+/// The internal slots of a typed array.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct IntegerIndexed {
+    /// `[[ViewedArrayBuffer]]`, which is `None` while the typed array is being initialized.
+    viewed_array_buffer: Option<JsObject>,
+    /// `[[TypedArrayName]]`, which also determines `[[ContentType]]`.
+    #[unsafe_ignore_trace]
+    typed_array_name: TypedArrayName,
+    /// `[[ByteOffset]]`
+    byte_offset: usize,
+    /// `[[ArrayLength]]`, which is `None` for a length-tracking typed array, whose length follows
+    /// the length of its resizable buffer.
+    array_length: Option<usize>,
+}
+
+impl IntegerIndexed {
+    /// Creates the slots of a typed array that doesn't view any buffer yet.
+    #[inline]
+    pub(crate) fn new(typed_array_name: TypedArrayName) -> Self {
+        Self {
+            viewed_array_buffer: None,
+            typed_array_name,
+            byte_offset: 0,
+            array_length: Some(0),
+        }
+    }
+
+    /// Makes the typed array view `array_length` elements of `buffer`, starting at `byte_offset`.
+    ///
+    /// If `array_length` is `None`, the typed array tracks the length of the buffer, viewing
+    /// every element from `byte_offset` to its end.
+    #[inline]
+    pub(crate) fn set_viewed_array_buffer(
+        &mut self,
+        buffer: JsObject,
+        byte_offset: usize,
+        array_length: Option<usize>,
+    ) {
+        self.viewed_array_buffer = Some(buffer);
+        self.byte_offset = byte_offset;
+        self.array_length = array_length;
+    }
+
+    /// Gets the `ArrayBuffer` object viewed by the typed array.
+    #[inline]
+    pub fn viewed_array_buffer(&self) -> Option<&JsObject> {
+        self.viewed_array_buffer.as_ref()
+    }
+
+    /// Gets the name of the typed array, which determines the type of its elements.
+    #[inline]
+    pub fn typed_array_name(&self) -> TypedArrayName {
+        self.typed_array_name
+    }
+
+    /// Gets the offset of the first element of the typed array in its buffer, in bytes.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Gets the length of the typed array in bytes, which is `0` if it is out of bounds.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.array_length() * self.typed_array_name.element_size()
+    }
+
+    /// Abstract operation `TypedArrayLength ( taRecord )`
+    ///
+    /// Gets the number of elements of the typed array, which is `0` if it is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraylength
+    pub fn array_length(&self) -> usize {
+        // 1. Assert: IsTypedArrayOutOfBounds(taRecord) is false.
+        let buffer_byte_length = match self.buffer_byte_length() {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => return 0,
+        };
+
+        match self.array_length {
+            // 3. If O.[[ArrayLength]] is not auto, return O.[[ArrayLength]].
+            Some(array_length) => {
+                let byte_end =
+                    self.byte_offset + array_length * self.typed_array_name.element_size();
+                if byte_end > buffer_byte_length {
+                    return 0;
+                }
+                array_length
+            }
+            // 6. Let byteOffset be O.[[ByteOffset]].
+            // 7. Let elementSize be TypedArrayElementSize(O).
+            // 8. Let byteLength be taRecord.[[CachedBufferByteLength]].
+            // 9. Return floor((byteLength - byteOffset) / elementSize).
+            None => buffer_byte_length
+                .checked_sub(self.byte_offset)
+                .map_or(0, |len| len / self.typed_array_name.element_size()),
+        }
+    }
+
+    /// Checks if the typed array tracks the length of its buffer.
+    #[inline]
+    pub fn is_length_tracking(&self) -> bool {
+        self.array_length.is_none()
+    }
+
+    /// Abstract operation `IsTypedArrayOutOfBounds ( taRecord )`
+    ///
+    /// Checks if the typed array views bytes past the end of its buffer, which happens once a
+    /// resizable buffer shrinks. A detached typed array is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub fn is_out_of_bounds(&self) -> bool {
+        // 2. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, return true.
+        let buffer_byte_length = match self.buffer_byte_length() {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => return true,
+        };
+
+        // 4. Let byteOffsetStart be O.[[ByteOffset]].
+        // 5. If O.[[ArrayLength]] is auto, then
+        //     a. Let byteOffsetEnd be bufferByteLength.
+        // 6. Else,
+        //     a. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
+        // 7. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 8. Return false.
+        let byte_offset_end = self
+            .array_length
+            .map_or(buffer_byte_length, |array_length| {
+                self.byte_offset + array_length * self.typed_array_name.element_size()
+            });
+        self.byte_offset > buffer_byte_length || byte_offset_end > buffer_byte_length
+    }
+
+    /// Gets the current length of the viewed buffer, or `None` if it is detached.
+    fn buffer_byte_length(&self) -> Option<usize> {
+        let buffer = self.viewed_array_buffer.as_ref()?.borrow();
+        buffer
+            .as_array_buffer()
+            .filter(|buffer| !buffer.is_detached_buffer())
+            .map(ArrayBuffer::byte_length)
+    }
+
+    /// Checks if the buffer viewed by the typed array has been detached.
+    ///
+    /// A typed array that doesn't view a buffer yet is considered detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.viewed_array_buffer.as_ref().map_or(true, |buffer| {
+            buffer
+                .borrow()
+                .as_array_buffer()
+                .map_or(true, ArrayBuffer::is_detached_buffer)
+        })
+    }
+}
@@ -0,0 +1,1237 @@
+//! This module implements the `%TypedArray%` intrinsic object and the global typed array
+//! constructors, like `Uint8Array` or `Float64Array`.
+//!
+//! A typed array is an array-like view of the binary data of an `ArrayBuffer`, whose elements
+//! all have the same numeric type.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer,
+        iterable::iterable_to_list,
+        number::{f64_to_int32, f64_to_uint32},
+        BuiltIn,
+    },
+    context::{StandardConstructor, StandardObjects},
+    error::JsErrorCode,
+    object::{
+        internal_methods::{
+            get_prototype_from_constructor, integer_indexed_element_get,
+            integer_indexed_element_set,
+        },
+        ConstructorBuilder, FunctionBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsBigInt, JsResult, JsValue,
+};
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive};
+use std::{cmp::Ordering, convert::TryInto};
+
+pub mod integer_indexed_object;
+mod prototype;
+
+pub use integer_indexed_object::IntegerIndexed;
+
+#[cfg(test)]
+mod tests;
+
+macro_rules! typed_array {
+    ($ty:ident, $variant:ident, $name:literal, $standard_object:ident) => {
+        #[doc = concat!("JavaScript `", $name, "` built-in implementation.")]
+        #[derive(Debug, Copy, Clone)]
+        pub(crate) struct $ty;
+
+        impl BuiltIn for $ty {
+            const NAME: &'static str = $name;
+
+            fn attribute() -> Attribute {
+                Attribute::builtin()
+            }
+
+            fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+                let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+                let typed_array_object = context.standard_objects().typed_array_object().clone();
+                let bytes_per_element = TypedArrayName::$variant.element_size();
+
+                let object = ConstructorBuilder::with_standard_object(
+                    context,
+                    Self::constructor,
+                    context.standard_objects().$standard_object().clone(),
+                )
+                .name(Self::NAME)
+                .length(Self::LENGTH)
+                .inherit(typed_array_object.prototype().into())
+                .static_property(
+                    "BYTES_PER_ELEMENT",
+                    bytes_per_element,
+                    Attribute::constant(),
+                )
+                .property(
+                    "BYTES_PER_ELEMENT",
+                    bytes_per_element,
+                    Attribute::constant(),
+                )
+                .build();
+
+                // The typed array constructors inherit from `%TypedArray%`.
+                object.set_prototype_instance(typed_array_object.constructor().into());
+
+                (Self::NAME, object.into(), Self::attribute())
+            }
+        }
+
+        impl $ty {
+            /// The amount of arguments the typed array constructors take.
+            pub(crate) const LENGTH: usize = 3;
+
+            #[doc = concat!("`", $name, " ( ...args )`")]
+            ///
+            /// More information:
+            ///  - [ECMAScript reference][spec]
+            ///
+            /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+            fn constructor(
+                new_target: &JsValue,
+                args: &[JsValue],
+                context: &mut Context,
+            ) -> JsResult<JsValue> {
+                TypedArray::construct(TypedArrayName::$variant, new_target, args, context)
+            }
+        }
+    };
+}
+
+typed_array!(Int8Array, Int8Array, "Int8Array", typed_int8_array_object);
+typed_array!(
+    Uint8Array,
+    Uint8Array,
+    "Uint8Array",
+    typed_uint8_array_object
+);
+typed_array!(
+    Uint8ClampedArray,
+    Uint8ClampedArray,
+    "Uint8ClampedArray",
+    typed_uint8clamped_array_object
+);
+typed_array!(
+    Int16Array,
+    Int16Array,
+    "Int16Array",
+    typed_int16_array_object
+);
+typed_array!(
+    Uint16Array,
+    Uint16Array,
+    "Uint16Array",
+    typed_uint16_array_object
+);
+typed_array!(
+    Int32Array,
+    Int32Array,
+    "Int32Array",
+    typed_int32_array_object
+);
+typed_array!(
+    Uint32Array,
+    Uint32Array,
+    "Uint32Array",
+    typed_uint32_array_object
+);
+typed_array!(
+    BigInt64Array,
+    BigInt64Array,
+    "BigInt64Array",
+    typed_bigint64_array_object
+);
+typed_array!(
+    BigUint64Array,
+    BigUint64Array,
+    "BigUint64Array",
+    typed_biguint64_array_object
+);
+typed_array!(
+    Float32Array,
+    Float32Array,
+    "Float32Array",
+    typed_float32_array_object
+);
+typed_array!(
+    Float64Array,
+    Float64Array,
+    "Float64Array",
+    typed_float64_array_object
+);
+
+/// The names of the typed arrays, which determine the type of their elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypedArrayName {
+    Int8Array,
+    Uint8Array,
+    Uint8ClampedArray,
+    Int16Array,
+    Uint16Array,
+    Int32Array,
+    Uint32Array,
+    BigInt64Array,
+    BigUint64Array,
+    Float32Array,
+    Float64Array,
+}
+
+/// The `[[ContentType]]` of a typed array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ContentType {
+    Number,
+    BigInt,
+}
+
+impl TypedArrayName {
+    /// Gets the name of the constructor of the typed array.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Int8Array => "Int8Array",
+            Self::Uint8Array => "Uint8Array",
+            Self::Uint8ClampedArray => "Uint8ClampedArray",
+            Self::Int16Array => "Int16Array",
+            Self::Uint16Array => "Uint16Array",
+            Self::Int32Array => "Int32Array",
+            Self::Uint32Array => "Uint32Array",
+            Self::BigInt64Array => "BigInt64Array",
+            Self::BigUint64Array => "BigUint64Array",
+            Self::Float32Array => "Float32Array",
+            Self::Float64Array => "Float64Array",
+        }
+    }
+
+    /// Gets the size of an element of the typed array, in bytes.
+    #[inline]
+    pub const fn element_size(self) -> usize {
+        match self {
+            Self::Int8Array | Self::Uint8Array | Self::Uint8ClampedArray => 1,
+            Self::Int16Array | Self::Uint16Array => 2,
+            Self::Int32Array | Self::Uint32Array | Self::Float32Array => 4,
+            Self::BigInt64Array | Self::BigUint64Array | Self::Float64Array => 8,
+        }
+    }
+
+    /// Gets the `[[ContentType]]` of the typed array.
+    #[inline]
+    pub(crate) const fn content_type(self) -> ContentType {
+        match self {
+            Self::BigInt64Array | Self::BigUint64Array => ContentType::BigInt,
+            _ => ContentType::Number,
+        }
+    }
+
+    /// Gets the accessor of the standard constructor of the typed array.
+    pub(crate) fn standard_constructor(self) -> fn(&StandardObjects) -> &StandardConstructor {
+        match self {
+            Self::Int8Array => StandardObjects::typed_int8_array_object,
+            Self::Uint8Array => StandardObjects::typed_uint8_array_object,
+            Self::Uint8ClampedArray => StandardObjects::typed_uint8clamped_array_object,
+            Self::Int16Array => StandardObjects::typed_int16_array_object,
+            Self::Uint16Array => StandardObjects::typed_uint16_array_object,
+            Self::Int32Array => StandardObjects::typed_int32_array_object,
+            Self::Uint32Array => StandardObjects::typed_uint32_array_object,
+            Self::BigInt64Array => StandardObjects::typed_bigint64_array_object,
+            Self::BigUint64Array => StandardObjects::typed_biguint64_array_object,
+            Self::Float32Array => StandardObjects::typed_float32_array_object,
+            Self::Float64Array => StandardObjects::typed_float64_array_object,
+        }
+    }
+
+    /// Converts `value` to a BigInt or a Number, depending on the content type of the typed array.
+    #[inline]
+    pub(crate) fn to_element_value(
+        self,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        match self.content_type() {
+            ContentType::BigInt => Ok(value.to_bigint_strict(context)?.into()),
+            ContentType::Number => Ok(value.to_number(context)?.into()),
+        }
+    }
+
+    /// Abstract operation `RawBytesToNumeric ( type, rawBytes, isLittleEndian )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
+    pub(crate) fn raw_bytes_to_numeric(self, raw_bytes: &[u8], is_little_endian: bool) -> JsValue {
+        macro_rules! read {
+            ($ty:ty) => {{
+                let bytes = raw_bytes
+                    .try_into()
+                    .expect("the raw bytes should have the size of the element");
+                if is_little_endian {
+                    <$ty>::from_le_bytes(bytes)
+                } else {
+                    <$ty>::from_be_bytes(bytes)
+                }
+            }};
+        }
+
+        match self {
+            Self::Int8Array => i32::from(read!(i8)).into(),
+            Self::Uint8Array | Self::Uint8ClampedArray => i32::from(read!(u8)).into(),
+            Self::Int16Array => i32::from(read!(i16)).into(),
+            Self::Uint16Array => i32::from(read!(u16)).into(),
+            Self::Int32Array => read!(i32).into(),
+            Self::Uint32Array => read!(u32).into(),
+            Self::BigInt64Array => JsBigInt::new(read!(i64)).into(),
+            Self::BigUint64Array => JsBigInt::new(read!(u64)).into(),
+            Self::Float32Array => f64::from(read!(f32)).into(),
+            Self::Float64Array => read!(f64).into(),
+        }
+    }
+
+    /// Abstract operation `NumericToRawBytes ( type, value, isLittleEndian )`
+    ///
+    /// `value` must be a BigInt for the BigInt typed arrays and a Number for the rest.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    pub(crate) fn numeric_to_raw_bytes(self, value: &JsValue, is_little_endian: bool) -> Vec<u8> {
+        macro_rules! write {
+            ($value:expr) => {{
+                let value = $value;
+                if is_little_endian {
+                    value.to_le_bytes().to_vec()
+                } else {
+                    value.to_be_bytes().to_vec()
+                }
+            }};
+        }
+
+        if self.content_type() == ContentType::BigInt {
+            // ToBigInt64 and ToBigUint64 take the value modulo 2^64.
+            let bigint = value
+                .as_bigint()
+                .expect("the value of a BigInt typed array should be a BigInt");
+            let modulo = bigint
+                .as_inner()
+                .mod_floor(&(num_bigint::BigInt::one() << 64))
+                .to_u64()
+                .expect("a BigInt modulo 2^64 should fit in a u64");
+            return if self == Self::BigInt64Array {
+                write!(modulo as i64)
+            } else {
+                write!(modulo)
+            };
+        }
+
+        let number = value
+            .as_number()
+            .expect("the value of a Number typed array should be a Number");
+        match self {
+            Self::Int8Array => write!(f64_to_int32(number) as i8),
+            Self::Uint8Array => write!(f64_to_uint32(number) as u8),
+            Self::Uint8ClampedArray => write!(f64_to_uint8_clamp(number)),
+            Self::Int16Array => write!(f64_to_int32(number) as i16),
+            Self::Uint16Array => write!(f64_to_uint32(number) as u16),
+            Self::Int32Array => write!(f64_to_int32(number)),
+            Self::Uint32Array => write!(f64_to_uint32(number)),
+            Self::Float32Array => write!(number as f32),
+            Self::Float64Array => write!(number),
+            Self::BigInt64Array | Self::BigUint64Array => unreachable!("handled above"),
+        }
+    }
+}
+
+/// Abstract operation `ToUint8Clamp ( argument )`, for a value already converted to a Number.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+fn f64_to_uint8_clamp(number: f64) -> u8 {
+    // 2. If number is NaN, return +0𝔽.
+    // 3. If ℝ(number) ≤ 0, return +0𝔽.
+    if number.is_nan() || number <= 0.0 {
+        return 0;
+    }
+
+    // 4. If ℝ(number) ≥ 255, return 255𝔽.
+    if number >= 255.0 {
+        return 255;
+    }
+
+    // 5. Let f be floor(ℝ(number)).
+    let f = number.floor();
+
+    // 6. If f + 0.5 < ℝ(number), return 𝔽(f + 1).
+    // 7. If ℝ(number) < f + 0.5, return 𝔽(f).
+    // 8. If f is odd, return 𝔽(f + 1).
+    // 9. Return 𝔽(f).
+    let f = f as u8;
+    match (number - f64::from(f)).partial_cmp(&0.5) {
+        Some(Ordering::Greater) => f + 1,
+        Some(Ordering::Less) => f,
+        _ if f % 2 == 1 => f + 1,
+        _ => f,
+    }
+}
+
+/// The `%TypedArray%` intrinsic object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TypedArray;
+
+impl TypedArray {
+    const NAME: &'static str = "TypedArray";
+    const LENGTH: usize = 0;
+
+    /// Initializes `%TypedArray%` and `%TypedArray.prototype%`.
+    ///
+    /// `%TypedArray.prototype.toString%` is the same function object as
+    /// `Array.prototype.toString`, so this must run after `Array` is initialized.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let get_buffer = FunctionBuilder::native(context, Self::buffer)
+            .name("get buffer")
+            .constructable(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+
+        let get_byte_offset = FunctionBuilder::native(context, Self::byte_offset)
+            .name("get byteOffset")
+            .constructable(false)
+            .build();
+
+        let get_length = FunctionBuilder::native(context, Self::length)
+            .name("get length")
+            .constructable(false)
+            .build();
+
+        let get_to_string_tag = FunctionBuilder::native(context, Self::to_string_tag)
+            .name("get [Symbol.toStringTag]")
+            .constructable(false)
+            .build();
+
+        let values_function = FunctionBuilder::native(context, Self::values)
+            .name("values")
+            .length(0)
+            .constructable(false)
+            .build();
+
+        let array_to_string = context
+            .standard_objects()
+            .array_object()
+            .prototype()
+            .get("toString", context)
+            .expect("Array.prototype.toString should be a data property");
+
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().typed_array_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
+        .accessor(
+            "buffer",
+            Some(get_buffer),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "byteOffset",
+            Some(get_byte_offset),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            "length",
+            Some(get_length),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .accessor(
+            WellKnownSymbols::to_string_tag(),
+            Some(get_to_string_tag),
+            None,
+            Attribute::readonly_builtin(),
+        )
+        .property("values", values_function.clone(), Attribute::builtin())
+        .property(
+            WellKnownSymbols::iterator(),
+            values_function,
+            Attribute::builtin(),
+        )
+        .property("toString", array_to_string, Attribute::builtin())
+        .method(Self::at, "at", 1)
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::entries, "entries", 0)
+        .method(Self::every, "every", 1)
+        .method(Self::fill, "fill", 1)
+        .method(Self::filter, "filter", 1)
+        .method(Self::find, "find", 1)
+        .method(Self::find_index, "findIndex", 1)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::includes, "includes", 1)
+        .method(Self::index_of, "indexOf", 1)
+        .method(Self::join, "join", 1)
+        .method(Self::keys, "keys", 0)
+        .method(Self::last_index_of, "lastIndexOf", 1)
+        .method(Self::map, "map", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::reduce_right, "reduceRight", 1)
+        .method(Self::reverse, "reverse", 0)
+        .method(Self::set, "set", 1)
+        .method(Self::slice, "slice", 2)
+        .method(Self::some, "some", 1)
+        .method(Self::sort, "sort", 1)
+        .method(Self::subarray, "subarray", 2)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::to_reversed, "toReversed", 0)
+        .method(Self::to_sorted, "toSorted", 1)
+        .method(Self::with, "with", 2)
+        .build();
+    }
+
+    /// `%TypedArray% ( )`
+    ///
+    /// The abstract `%TypedArray%` constructor always throws.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+    fn constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("the TypedArray constructor should never be called directly")
+    }
+
+    /// `TypedArray ( ...args )`, shared by all the typed array constructors.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+    fn construct(
+        constructor_name: TypedArrayName,
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(format!(
+                "calling a builtin {} constructor without new is forbidden",
+                constructor_name.name()
+            ));
+        }
+
+        // 2. Let constructorName be the String value of the Constructor Name value specified in Table 72 for this TypedArray constructor.
+        // 3. Let proto be "%TypedArray.prototype%".
+        // 4. Let numberOfArgs be the number of elements in args.
+        // 5. If numberOfArgs = 0, then
+        if args.is_empty() {
+            // a. Return ? AllocateTypedArray(constructorName, NewTarget, proto, 0).
+            return Ok(Self::allocate(constructor_name, new_target, Some(0), context)?.into());
+        }
+
+        // 6. Else,
+        // a. Let firstArgument be args[0].
+        let first_argument = &args[0];
+
+        // b. If Type(firstArgument) is Object, then
+        if let Some(first_argument) = first_argument.as_object() {
+            // i. Let O be ? AllocateTypedArray(constructorName, NewTarget, proto).
+            let o = Self::allocate(constructor_name, new_target, None, context)?;
+
+            let (is_typed_array, is_array_buffer) = {
+                let first_argument = first_argument.borrow();
+                (
+                    first_argument.is_typed_array(),
+                    first_argument.is_array_buffer(),
+                )
+            };
+
+            // ii. If firstArgument has a [[TypedArrayName]] internal slot, then
+            if is_typed_array {
+                // 1. Perform ? InitializeTypedArrayFromTypedArray(O, firstArgument).
+                Self::initialize_from_typed_array(&o, &first_argument, context)?;
+            }
+            // iii. Else if firstArgument has an [[ArrayBufferData]] internal slot, then
+            else if is_array_buffer {
+                // 1. If numberOfArgs > 1, let byteOffset be args[1]; else let byteOffset be undefined.
+                // 2. If numberOfArgs > 2, let length be args[2]; else let length be undefined.
+                // 3. Perform ? InitializeTypedArrayFromArrayBuffer(O, firstArgument, byteOffset, length).
+                Self::initialize_from_array_buffer(
+                    &o,
+                    first_argument,
+                    args.get_or_undefined(1),
+                    args.get_or_undefined(2),
+                    context,
+                )?;
+            }
+            // iv. Else,
+            else {
+                // 1. Assert: Type(firstArgument) is Object and firstArgument does not have either a [[TypedArrayName]] or an [[ArrayBufferData]] internal slot.
+                // 2. Let usingIterator be ? GetMethod(firstArgument, @@iterator).
+                let using_iterator =
+                    first_argument.get_method(context, WellKnownSymbols::iterator())?;
+
+                // 3. If usingIterator is not undefined, then
+                if using_iterator.is_some() {
+                    // a. Let values be ? IterableToList(firstArgument, usingIterator).
+                    let values = iterable_to_list(context, first_argument.into())?;
+
+                    // b. Perform ? InitializeTypedArrayFromList(O, values).
+                    Self::initialize_from_list(&o, values, context)?;
+                }
+                // 4. Else,
+                else {
+                    // a. NOTE: firstArgument is not an Iterable so assume it is already an array-like object.
+                    // b. Perform ? InitializeTypedArrayFromArrayLike(O, firstArgument).
+                    Self::initialize_from_array_like(&o, &first_argument, context)?;
+                }
+            }
+
+            // v. Return O.
+            return Ok(o.into());
+        }
+
+        // c. Else,
+        // i. Assert: firstArgument is not an Object.
+        // ii. Let elementLength be ? ToIndex(firstArgument).
+        let element_length = first_argument.to_index(context)?;
+
+        // iii. Return ? AllocateTypedArray(constructorName, NewTarget, proto, elementLength).
+        Ok(Self::allocate(constructor_name, new_target, Some(element_length), context)?.into())
+    }
+
+    /// `get %TypedArray% [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/@@species
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.from ( source [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/from
+    fn from(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. If IsConstructor(C) is false, throw a TypeError exception.
+        let constructor = match this.as_object() {
+            Some(constructor) if constructor.is_constructable() => constructor,
+            _ => return context.throw_type_error("TypedArray.from: `this` is not a constructor"),
+        };
+
+        // 3. If mapfn is undefined, let mapping be false.
+        // 4. Else,
+        // a. If IsCallable(mapfn) is false, throw a TypeError exception.
+        // b. Let mapping be true.
+        let mapping = match args.get_or_undefined(1) {
+            JsValue::Undefined => None,
+            JsValue::Object(mapfn) if mapfn.is_callable() => Some(mapfn.clone()),
+            _ => return context.throw_type_error("TypedArray.from: mapfn is not callable"),
+        };
+        let this_arg = args.get_or_undefined(2);
+        let source = args.get_or_undefined(0);
+
+        // 5. Let usingIterator be ? GetMethod(source, @@iterator).
+        let using_iterator = match source.as_object() {
+            Some(source) => source.get_method(context, WellKnownSymbols::iterator())?,
+            None => source
+                .get_field(WellKnownSymbols::iterator(), context)?
+                .as_object()
+                .filter(JsObject::is_callable),
+        };
+
+        // 6. If usingIterator is not undefined, then
+        let (values, array_like, len) = if using_iterator.is_some() {
+            // a. Let values be ? IterableToList(source, usingIterator).
+            let values = iterable_to_list(context, source.clone())?;
+            // b. Let len be the number of elements in values.
+            let len = values.len();
+            (Some(values), None, len)
+        }
+        // 7. NOTE: source is not an Iterable so assume it is already an array-like object.
+        else {
+            // 8. Let arrayLike be ! ToObject(source).
+            let array_like = source.to_object(context)?;
+            // 9. Let len be ? LengthOfArrayLike(arrayLike).
+            let len = array_like.length_of_array_like(context)?;
+            (None, Some(array_like), len)
+        };
+
+        // 6.c / 10. Let targetObj be ? TypedArrayCreate(C, « 𝔽(len) »).
+        let target_obj = Self::create(&constructor, &[len.into()], context)?;
+
+        // 6.d / 11. Let k be 0.
+        // 6.e / 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be the first element of values and remove that element from values. / Let kValue be ? Get(arrayLike, Pk).
+            let k_value = match (&values, &array_like) {
+                (Some(values), _) => values[k].clone(),
+                (None, Some(array_like)) => array_like.get(k, context)?,
+                (None, None) => unreachable!("either the values or the array-like are present"),
+            };
+
+            // c. If mapping is true, then
+            // i. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+            // d. Else, let mappedValue be kValue.
+            let mapped_value = match &mapping {
+                Some(mapfn) => mapfn.call(this_arg, &[k_value, k.into()], context)?,
+                None => k_value,
+            };
+
+            // e. Perform ? Set(targetObj, Pk, mappedValue, true).
+            target_obj.set(k, mapped_value, true, context)?;
+        }
+
+        // 13. Return targetObj.
+        Ok(target_obj.into())
+    }
+
+    /// `%TypedArray%.of ( ...items )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let len be the number of elements in items.
+        // 2. Let C be the this value.
+        // 3. If IsConstructor(C) is false, throw a TypeError exception.
+        let constructor = match this.as_object() {
+            Some(constructor) if constructor.is_constructable() => constructor,
+            _ => return context.throw_type_error("TypedArray.of: `this` is not a constructor"),
+        };
+
+        // 4. Let newObj be ? TypedArrayCreate(C, « 𝔽(len) »).
+        let new_obj = Self::create(&constructor, &[args.len().into()], context)?;
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for (k, k_value) in args.iter().enumerate() {
+            // a. Let kValue be items[k].
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Perform ? Set(newObj, Pk, kValue, true).
+            new_obj.set(k, k_value.clone(), true, context)?;
+        }
+
+        // 7. Return newObj.
+        Ok(new_obj.into())
+    }
+
+    /// Abstract operation `TypedArrayCreate ( constructor, argumentList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-create
+    fn create(
+        constructor: &JsObject,
+        argument_list: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let newTypedArray be ? Construct(constructor, argumentList).
+        let new_typed_array =
+            constructor.construct(argument_list, &constructor.clone().into(), context)?;
+
+        // 2. Perform ? ValidateTypedArray(newTypedArray).
+        let new_typed_array = Self::validate(&new_typed_array, context)?;
+
+        // 3. If argumentList is a List of a single Number, then
+        if let [JsValue::Integer(_) | JsValue::Rational(_)] = argument_list {
+            // a. If newTypedArray.[[ArrayLength]] < ℝ(argumentList[0]), throw a TypeError exception.
+            let length = argument_list[0]
+                .as_number()
+                .expect("checked to be a number");
+            if (Self::array_length(&new_typed_array) as f64) < length {
+                return Err(context.construct_type_error(
+                    "TypedArray species constructor created a too short array",
+                ));
+            }
+        }
+
+        // 4. Return newTypedArray.
+        Ok(new_typed_array)
+    }
+
+    /// Abstract operation `ValidateTypedArray ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validatetypedarray
+    pub(crate) fn validate(o: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        // 1. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 2. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        let o = Self::require_typed_array(o, context)?;
+
+        // 3. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, order).
+        // 4. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if Self::is_out_of_bounds(&o) {
+            return Err(
+                context.construct_type_error("TypedArray buffer was detached or is out of bounds")
+            );
+        }
+
+        // 5. Return buffer.
+        Ok(o)
+    }
+
+    /// Abstract operation `AllocateTypedArray ( constructorName, newTarget, defaultProto [ , length ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatetypedarray
+    fn allocate(
+        constructor_name: TypedArrayName,
+        new_target: &JsValue,
+        length: Option<usize>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let proto be ? GetPrototypeFromConstructor(newTarget, defaultProto).
+        let proto = get_prototype_from_constructor(
+            new_target,
+            constructor_name.standard_constructor(),
+            context,
+        )?;
+
+        // 2. Let obj be ! IntegerIndexedObjectCreate(proto).
+        // 3. Assert: obj.[[ViewedArrayBuffer]] is undefined.
+        // 4. Set obj.[[TypedArrayName]] to constructorName.
+        // 5. If constructorName is "BigInt64Array" or "BigUint64Array", set obj.[[ContentType]] to BigInt.
+        // 6. Otherwise, set obj.[[ContentType]] to Number.
+        let obj = context.construct_object();
+        obj.set_prototype_instance(proto.into());
+        obj.borrow_mut().data = ObjectData::integer_indexed(IntegerIndexed::new(constructor_name));
+
+        // 7. If length is not present, then
+        // a. Set obj.[[ByteLength]] to 0.
+        // b. Set obj.[[ByteOffset]] to 0.
+        // c. Set obj.[[ArrayLength]] to 0.
+        // 8. Else,
+        if let Some(length) = length {
+            // a. Perform ? AllocateTypedArrayBuffer(obj, length).
+            Self::allocate_buffer(&obj, length, context)?;
+        }
+
+        // 9. Return obj.
+        Ok(obj)
+    }
+
+    /// Abstract operation `AllocateTypedArrayBuffer ( O, length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatetypedarraybuffer
+    fn allocate_buffer(o: &JsObject, length: usize, context: &mut Context) -> JsResult<()> {
+        // 1. Assert: O.[[ViewedArrayBuffer]] is undefined.
+        // 2. Let constructorName be the String value of O.[[TypedArrayName]].
+        // 3. Let elementSize be the Element Size value specified in Table 72 for constructorName.
+        let element_size = Self::name(o).element_size();
+
+        // 4. Let byteLength be elementSize × length.
+        let byte_length = match element_size.checked_mul(length) {
+            Some(byte_length) => byte_length,
+            None => return Err(context.construct_range_error("TypedArray length is too large")),
+        };
+
+        // 5. Let data be ? AllocateArrayBuffer(%ArrayBuffer%, byteLength).
+        let array_buffer_constructor = context
+            .standard_objects()
+            .array_buffer_object()
+            .constructor()
+            .into();
+        let data = ArrayBuffer::allocate(&array_buffer_constructor, byte_length, None, context)?;
+
+        // 6. Set O.[[ViewedArrayBuffer]] to data.
+        // 7. Set O.[[ByteLength]] to byteLength.
+        // 8. Set O.[[ByteOffset]] to 0.
+        // 9. Set O.[[ArrayLength]] to length.
+        o.borrow_mut()
+            .as_typed_array_mut()
+            .expect("checked to be a typed array")
+            .set_viewed_array_buffer(data, 0, Some(length));
+
+        // 10. Return O.
+        Ok(())
+    }
+
+    /// Abstract operation `InitializeTypedArrayFromTypedArray ( O, srcArray )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromtypedarray
+    fn initialize_from_typed_array(
+        o: &JsObject,
+        src_array: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let srcData be srcArray.[[ViewedArrayBuffer]].
+        // 2. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(srcArray, seq-cst).
+        // 3. If IsTypedArrayOutOfBounds(srcRecord) is true, throw a TypeError exception.
+        if Self::is_out_of_bounds(src_array) {
+            return Err(context.construct_type_error(
+                "cannot construct a TypedArray from a detached or out of bounds typed array",
+            ));
+        }
+
+        // 3. Let constructorName be the String value of O.[[TypedArrayName]].
+        // 4. Let elementType be the Element Type value in Table 72 for constructorName.
+        let element_type = Self::name(o);
+
+        // 6. Let srcType be the Element Type value in Table 72 for srcName.
+        // 9. Let elementLength be srcArray.[[ArrayLength]].
+        let src_type = Self::name(src_array);
+        let element_length = Self::array_length(src_array);
+
+        // 13.b. If srcArray.[[ContentType]] ≠ O.[[ContentType]], throw a TypeError exception.
+        if element_type.content_type() != src_type.content_type() {
+            return Err(context.construct_type_error(
+                "cannot construct a TypedArray from one of another content type",
+            ));
+        }
+
+        // 12. If elementType is srcType, then
+        if element_type == src_type {
+            // a. Let data be ? CloneArrayBuffer(srcData, srcByteOffset, byteLength, bufferConstructor).
+            let (src_data, src_byte_offset, byte_length) = {
+                let src_array = src_array.borrow();
+                let inner = src_array
+                    .as_typed_array()
+                    .expect("checked to be a typed array");
+                (
+                    inner
+                        .viewed_array_buffer()
+                        .cloned()
+                        .expect("checked to be attached"),
+                    inner.byte_offset(),
+                    inner.byte_length(),
+                )
+            };
+            let buffer_constructor = context
+                .standard_objects()
+                .array_buffer_object()
+                .constructor()
+                .into();
+            let data = ArrayBuffer::clone_array_buffer(
+                &src_data,
+                src_byte_offset,
+                byte_length,
+                &buffer_constructor,
+                context,
+            )?;
+
+            // 14. Set O.[[ViewedArrayBuffer]] to data.
+            // 15. Set O.[[ByteLength]] to byteLength.
+            // 16. Set O.[[ByteOffset]] to 0.
+            // 17. Set O.[[ArrayLength]] to elementLength.
+            o.borrow_mut()
+                .as_typed_array_mut()
+                .expect("checked to be a typed array")
+                .set_viewed_array_buffer(data, 0, Some(element_length));
+            return Ok(());
+        }
+
+        // 13. Else,
+        // a. Let data be ? AllocateArrayBuffer(bufferConstructor, byteLength).
+        // d. Repeat, while count > 0, copy the elements of srcArray, converting them to elementType.
+        Self::allocate_buffer(o, element_length, context)?;
+        for k in 0..element_length {
+            if let Some(value) = integer_indexed_element_get(src_array, k as f64) {
+                integer_indexed_element_set(o, k as f64, &value, context)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Abstract operation `InitializeTypedArrayFromArrayBuffer ( O, buffer, byteOffset, length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraybuffer
+    fn initialize_from_array_buffer(
+        o: &JsObject,
+        buffer: JsObject,
+        byte_offset: &JsValue,
+        length: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let constructorName be the String value of O.[[TypedArrayName]].
+        // 2. Let elementSize be the Element Size value specified in Table 72 for constructorName.
+        let element_size = Self::name(o).element_size();
+
+        // 3. Let offset be ? ToIndex(byteOffset).
+        let offset = byte_offset.to_index(context)?;
+
+        // 4. If offset modulo elementSize ≠ 0, throw a RangeError exception.
+        if offset % element_size != 0 {
+            return Err(context.construct_range_error(format!(
+                "start offset of {} should be a multiple of {}",
+                Self::name(o).name(),
+                element_size
+            )));
+        }
+
+        // 5. If length is not undefined, then
+        // a. Let newLength be ? ToIndex(length).
+        let new_length = if length.is_undefined() {
+            None
+        } else {
+            Some(length.to_index(context)?)
+        };
+
+        // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        // 7. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+        // 8. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
+        let buffer_byte_length = match buffer.borrow().as_array_buffer() {
+            Some(array_buffer) if !array_buffer.is_detached_buffer() => {
+                Some((array_buffer.byte_length(), array_buffer.is_fixed_length()))
+            }
+            _ => None,
+        };
+        let (buffer_byte_length, buffer_is_fixed_length) = match buffer_byte_length {
+            Some(buffer_byte_length) => buffer_byte_length,
+            None => {
+                return Err(context.construct_coded_error(
+                    JsErrorCode::CONSTRUCT_FROM_DETACHED_BUFFER,
+                    "cannot construct a TypedArray from a detached buffer",
+                ))
+            }
+        };
+
+        // 9. If length is undefined and bufferIsFixedLength is false, then
+        if new_length.is_none() && !buffer_is_fixed_length {
+            // a. If offset > bufferByteLength, throw a RangeError exception.
+            if offset > buffer_byte_length {
+                return Err(context.construct_range_error(format!(
+                    "start offset {} is outside the bounds of the buffer",
+                    offset
+                )));
+            }
+
+            // b. Set O.[[ByteLength]] to auto.
+            // c. Set O.[[ArrayLength]] to auto.
+            o.borrow_mut()
+                .as_typed_array_mut()
+                .expect("checked to be a typed array")
+                .set_viewed_array_buffer(buffer, offset, None);
+            return Ok(());
+        }
+
+        // 10. Else,
+        // a. If length is undefined, then
+        let new_byte_length = match new_length {
+            None => {
+                // a. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
+                if buffer_byte_length % element_size != 0 {
+                    return Err(context.construct_range_error(format!(
+                        "byte length of {} should be a multiple of {}",
+                        Self::name(o).name(),
+                        element_size
+                    )));
+                }
+
+                // b. Let newByteLength be bufferByteLength - offset.
+                // c. If newByteLength < 0, throw a RangeError exception.
+                match buffer_byte_length.checked_sub(offset) {
+                    Some(new_byte_length) => new_byte_length,
+                    None => {
+                        return Err(context.construct_range_error(format!(
+                            "start offset {} is outside the bounds of the buffer",
+                            offset
+                        )))
+                    }
+                }
+            }
+            // 9. Else,
+            Some(new_length) => {
+                // a. Let newByteLength be newLength × elementSize.
+                // b. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
+                let new_byte_length = new_length.saturating_mul(element_size);
+                if offset.saturating_add(new_byte_length) > buffer_byte_length {
+                    return Err(context.construct_range_error(format!(
+                        "invalid typed array length: {}",
+                        new_length
+                    )));
+                }
+                new_byte_length
+            }
+        };
+
+        // 10. Set O.[[ViewedArrayBuffer]] to buffer.
+        // 11. Set O.[[ByteLength]] to newByteLength.
+        // 12. Set O.[[ByteOffset]] to offset.
+        // 13. Set O.[[ArrayLength]] to newByteLength / elementSize.
+        o.borrow_mut()
+            .as_typed_array_mut()
+            .expect("checked to be a typed array")
+            .set_viewed_array_buffer(buffer, offset, Some(new_byte_length / element_size));
+
+        Ok(())
+    }
+
+    /// Abstract operation `InitializeTypedArrayFromList ( O, values )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromlist
+    fn initialize_from_list(
+        o: &JsObject,
+        values: Vec<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let len be the number of elements in values.
+        // 2. Perform ? AllocateTypedArrayBuffer(O, len).
+        Self::allocate_buffer(o, values.len(), context)?;
+
+        // 3. Let k be 0.
+        // 4. Repeat, while k < len,
+        for (k, k_value) in values.into_iter().enumerate() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be the first element of values and remove that element from values.
+            // c. Perform ? Set(O, Pk, kValue, true).
+            o.set(k, k_value, true, context)?;
+        }
+
+        // 5. Assert: values is now an empty List.
+        Ok(())
+    }
+
+    /// Abstract operation `InitializeTypedArrayFromArrayLike ( O, arrayLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraylike
+    fn initialize_from_array_like(
+        o: &JsObject,
+        array_like: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like.length_of_array_like(context)?;
+
+        // 2. Perform ? AllocateTypedArrayBuffer(O, len).
+        Self::allocate_buffer(o, len, context)?;
+
+        // 3. Let k be 0.
+        // 4. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = array_like.get(k, context)?;
+
+            // c. Perform ? Set(O, Pk, kValue, true).
+            o.set(k, k_value, true, context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `this` if it is a typed array, or throws a `TypeError`.
+    fn require_typed_array(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        match this.as_object() {
+            Some(obj) if obj.borrow().is_typed_array() => Ok(obj),
+            _ => Err(context.construct_type_error("`this` is not a typed array")),
+        }
+    }
+
+    /// Returns the first argument if it is callable, or throws a `TypeError` naming `method`.
+    fn callback(args: &[JsValue], method: &str, context: &mut Context) -> JsResult<JsObject> {
+        match args.get_or_undefined(0) {
+            JsValue::Object(callback) if callback.is_callable() => Ok(callback.clone()),
+            _ => Err(context.construct_type_error(format!(
+                "TypedArray.prototype.{}: callback function is not callable",
+                method
+            ))),
+        }
+    }
+
+    /// Gets the `[[TypedArrayName]]` of the typed array `o`.
+    fn name(o: &JsObject) -> TypedArrayName {
+        o.borrow()
+            .as_typed_array()
+            .expect("checked to be a typed array")
+            .typed_array_name()
+    }
+
+    /// Gets the `[[ArrayLength]]` of the typed array `o`.
+    fn array_length(o: &JsObject) -> usize {
+        o.borrow()
+            .as_typed_array()
+            .expect("checked to be a typed array")
+            .array_length()
+    }
+
+    /// Checks if the typed array `o` is out of the bounds of its buffer, which includes the case
+    /// where its buffer has been detached.
+    fn is_out_of_bounds(o: &JsObject) -> bool {
+        o.borrow()
+            .as_typed_array()
+            .expect("checked to be a typed array")
+            .is_out_of_bounds()
+    }
+}
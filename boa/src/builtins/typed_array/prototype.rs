@@ -0,0 +1,1791 @@
+//! This module implements the methods and accessors of `%TypedArray.prototype%`.
+//!
+//! The methods work on the elements of any typed array through `[[Get]]`, `[[Set]]` and the
+//! element conversions of `TypedArrayName`, so a single implementation covers every element type.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-properties-of-the-%typedarrayprototype%-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray#instance_methods
+
+use super::{IntegerIndexed, TypedArray};
+use crate::{
+    builtins::{array::array_iterator::ArrayIterator, Array},
+    error::JsErrorCode,
+    object::{
+        internal_methods::{integer_indexed_element_get, integer_indexed_element_set},
+        JsObject,
+    },
+    property::PropertyNameKind,
+    value::{ArgumentsExt, IntegerOrInfinity},
+    Context, JsResult, JsString, JsValue,
+};
+use std::cmp::Ordering;
+
+impl TypedArray {
+    /// `%TypedArray%.prototype.at ( index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-%typedarray%.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/at
+    pub(super) fn at(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o) as i64;
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        // 5. If relativeIndex ≥ 0, then
+        // a. Let k be relativeIndex.
+        // 6. Else,
+        // a. Let k be len + relativeIndex.
+        let k = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i,
+            IntegerOrInfinity::Integer(i) => len + i,
+            _ => return Ok(JsValue::undefined()),
+        };
+
+        // 7. If k < 0 or k ≥ len, return undefined.
+        if k < 0 || k >= len {
+            return Ok(JsValue::undefined());
+        }
+
+        // 8. Return ! Get(O, ! ToString(𝔽(k))).
+        o.get(k, context)
+    }
+
+    /// `get %TypedArray%.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer
+    pub(super) fn buffer(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        // 4. Let buffer be O.[[ViewedArrayBuffer]].
+        // 5. Return buffer.
+        let o = Self::require_typed_array(this, context)?;
+        let o = o.borrow();
+        Ok(o.as_typed_array()
+            .and_then(IntegerIndexed::viewed_array_buffer)
+            .cloned()
+            .map(JsValue::new)
+            .unwrap_or_default())
+    }
+
+    /// `get %TypedArray%.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteLength
+    pub(super) fn byte_length(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        let o = Self::require_typed_array(this, context)?;
+        let o = o.borrow();
+        let inner = o.as_typed_array().expect("checked to be a typed array");
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. Let size be TypedArrayByteLength(taRecord).
+        // 6. Return 𝔽(size).
+        Ok(inner.byte_length().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteOffset
+    pub(super) fn byte_offset(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        let o = Self::require_typed_array(this, context)?;
+        let o = o.borrow();
+        let inner = o.as_typed_array().expect("checked to be a typed array");
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
+        // 6. Let offset be O.[[ByteOffset]].
+        // 7. Return 𝔽(offset).
+        if inner.is_out_of_bounds() {
+            Ok(0.into())
+        } else {
+            Ok(inner.byte_offset().into())
+        }
+    }
+
+    /// `%TypedArray%.prototype.copyWithin ( target, start [ , end ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/copyWithin
+    pub(super) fn copy_within(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4-5. Let to be the clamped relativeTarget.
+        let to = Array::get_relative_start(context, args.get(0), len)?;
+
+        // 6-7. Let from be the clamped relativeStart.
+        let from = Array::get_relative_start(context, args.get(1), len)?;
+
+        // 8-9. Let final be the clamped relativeEnd.
+        let r#final = Array::get_relative_end(context, args.get(2), len)?;
+
+        // 10. Let count be min(final - from, len - to).
+        let count = r#final.saturating_sub(from).min(len.saturating_sub(to));
+
+        // 11. If count > 0, then
+        if count > 0 {
+            // a. NOTE: The copying must be performed in a manner that preserves the bit-level encoding of the source data.
+            // b. Let buffer be O.[[ViewedArrayBuffer]].
+            // c. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // d. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            let o = o.borrow();
+            let inner = o.as_typed_array().expect("checked to be a typed array");
+            if inner.is_out_of_bounds() {
+                drop(o);
+                return context.throw_coded_error(
+                    JsErrorCode::DETACHED_TYPED_ARRAY,
+                    "TypedArray buffer was detached",
+                );
+            }
+
+            // e. Set len to TypedArrayLength(taRecord).
+            let len = inner.array_length();
+
+            // f. Let elementSize be TypedArrayElementSize(O).
+            let element_size = inner.typed_array_name().element_size();
+
+            // g. Let byteOffset be O.[[ByteOffset]].
+            let byte_offset = inner.byte_offset();
+
+            // h. Let bufferByteLimit be (len × elementSize) + byteOffset.
+            let buffer_byte_limit = len * element_size + byte_offset;
+
+            // i. Let toByteIndex be to × elementSize + byteOffset.
+            let to_byte_index = to * element_size + byte_offset;
+
+            // j. Let fromByteIndex be from × elementSize + byteOffset.
+            let from_byte_index = from * element_size + byte_offset;
+
+            // k. Let countBytes be count × elementSize.
+            // l. Only the bytes below bufferByteLimit are copied, since the buffer may have
+            //    shrunk while the arguments were converted.
+            let count_bytes = (count * element_size)
+                .min(buffer_byte_limit.saturating_sub(to_byte_index))
+                .min(buffer_byte_limit.saturating_sub(from_byte_index));
+
+            // m-n. Copy the bytes, as if one at a time in the right direction for overlapping ranges.
+            let mut buffer = inner
+                .viewed_array_buffer()
+                .expect("checked to be attached")
+                .borrow_mut();
+            buffer
+                .as_array_buffer_mut()
+                .expect("a typed array should view an ArrayBuffer")
+                .copy_bytes_within(
+                    from_byte_index..from_byte_index + count_bytes,
+                    to_byte_index,
+                );
+        }
+
+        // 12. Return O.
+        Ok(o.into())
+    }
+
+    /// `%TypedArray%.prototype.entries ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/entries
+    pub(super) fn entries(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Return CreateArrayIterator(O, key+value).
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            o.into(),
+            PropertyNameKind::KeyAndValue,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.every ( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/every
+    pub(super) fn every(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback_fn = Self::callback(args, "every", context)?;
+        let this_arg = args.get_or_undefined(1);
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Let testResult be ! ToBoolean(? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »)).
+            let test_result = callback_fn
+                .call(this_arg, &[k_value, k.into(), o.clone().into()], context)?
+                .to_boolean();
+
+            // d. If testResult is false, return false.
+            if !test_result {
+                return Ok(false.into());
+            }
+        }
+
+        // 7. Return true.
+        Ok(true.into())
+    }
+
+    /// `%TypedArray%.prototype.fill ( value [ , start [ , end ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill
+    pub(super) fn fill(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If O.[[ContentType]] is BigInt, set value to ? ToBigInt(value).
+        // 5. Otherwise, set value to ? ToNumber(value).
+        let value = Self::name(&o).to_element_value(args.get_or_undefined(0), context)?;
+
+        // 6-7. Let k be the clamped relativeStart.
+        let k = Array::get_relative_start(context, args.get(1), len)?;
+
+        // 8-9. Let final be the clamped relativeEnd.
+        let r#final = Array::get_relative_end(context, args.get(2), len)?;
+
+        // 10. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 11. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if Self::is_out_of_bounds(&o) {
+            return context.throw_coded_error(
+                JsErrorCode::DETACHED_TYPED_ARRAY,
+                "TypedArray buffer was detached",
+            );
+        }
+
+        // 12. Set len to TypedArrayLength(taRecord).
+        // 13. Set endIndex to min(endIndex, len).
+        let r#final = r#final.min(Self::array_length(&o));
+
+        // 14. Repeat, while k < final,
+        for k in k..r#final {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Perform ! Set(O, Pk, value, true).
+            o.set(k, value.clone(), true, context)?;
+        }
+
+        // 12. Return O.
+        Ok(o.into())
+    }
+
+    /// `%TypedArray%.prototype.filter ( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/filter
+    pub(super) fn filter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback_fn = Self::callback(args, "filter", context)?;
+        let this_arg = args.get_or_undefined(1);
+
+        // 5. Let kept be a new empty List.
+        let mut kept = Vec::new();
+
+        // 6. Let k be 0.
+        // 7. Let captured be 0.
+        // 8. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Let selected be ! ToBoolean(? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »)).
+            let selected = callback_fn
+                .call(
+                    this_arg,
+                    &[k_value.clone(), k.into(), o.clone().into()],
+                    context,
+                )?
+                .to_boolean();
+
+            // d. If selected is true, then
+            if selected {
+                // i. Append kValue to the end of kept.
+                // ii. Set captured to captured + 1.
+                kept.push(k_value);
+            }
+        }
+
+        // 9. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(captured) »).
+        let a = Self::species_create(&o, &[kept.len().into()], context)?;
+
+        // 10. Let n be 0.
+        // 11. For each element e of kept, do
+        for (n, e) in kept.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(n)), e, true).
+            // b. Set n to n + 1.
+            a.set(n, e, true, context)?;
+        }
+
+        // 12. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.find ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/find
+    pub(super) fn find(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(Self::find_element(this, args, "find", context)?
+            .map(|(_, value)| value)
+            .unwrap_or_default())
+    }
+
+    /// `%TypedArray%.prototype.findIndex ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findIndex
+    pub(super) fn find_index(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Ok(Self::find_element(this, args, "findIndex", context)?
+            .map_or(JsValue::new(-1), |(index, _)| index.into()))
+    }
+
+    /// Shared steps of `find` and `findIndex`, returning the first element, and its index, for
+    /// which `predicate` returns a truthy value.
+    fn find_element(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<(usize, JsValue)>> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callback(args, method, context)?;
+        let this_arg = args.get_or_undefined(1);
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            let test_result = predicate
+                .call(
+                    this_arg,
+                    &[k_value.clone(), k.into(), o.clone().into()],
+                    context,
+                )?
+                .to_boolean();
+
+            // d. If testResult is true, return kValue / 𝔽(k).
+            if test_result {
+                return Ok(Some((k, k_value)));
+            }
+        }
+
+        // 7. Return undefined / -1𝔽.
+        Ok(None)
+    }
+
+    /// `%TypedArray%.prototype.forEach ( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/forEach
+    pub(super) fn for_each(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback_fn = Self::callback(args, "forEach", context)?;
+        let this_arg = args.get_or_undefined(1);
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Perform ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
+            callback_fn.call(this_arg, &[k_value, k.into(), o.clone().into()], context)?;
+        }
+
+        // 7. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `%TypedArray%.prototype.includes ( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.includes
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/includes
+    pub(super) fn includes(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If len is 0, return false.
+        if len == 0 {
+            return Ok(false.into());
+        }
+
+        // 5-10. Let k be the clamped fromIndex.
+        let k = Array::get_relative_start(context, args.get(1), len)?;
+        let search_element = args.get_or_undefined(0);
+
+        // 11. Repeat, while k < len,
+        for k in k..len {
+            // a. Let elementK be ! Get(O, ! ToString(𝔽(k))).
+            let element_k = o.get(k, context)?;
+
+            // b. If SameValueZero(searchElement, elementK) is true, return true.
+            if JsValue::same_value_zero(search_element, &element_k) {
+                return Ok(true.into());
+            }
+        }
+
+        // 12. Return false.
+        Ok(false.into())
+    }
+
+    /// `%TypedArray%.prototype.indexOf ( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/indexOf
+    pub(super) fn index_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If len is 0, return -1𝔽.
+        if len == 0 {
+            return Ok((-1).into());
+        }
+
+        // 5-10. Let k be the clamped fromIndex.
+        let k = Array::get_relative_start(context, args.get(1), len)?;
+        let search_element = args.get_or_undefined(0);
+
+        // 11. Repeat, while k < len,
+        for k in k..len {
+            // a. Let kPresent be ! HasProperty(O, ! ToString(𝔽(k))).
+            // b. If kPresent is true, then
+            if o.has_property(k, context)? {
+                // i. Let elementK be ! Get(O, ! ToString(𝔽(k))).
+                let element_k = o.get(k, context)?;
+
+                // ii. Let same be IsStrictlyEqual(searchElement, elementK).
+                // iii. If same is true, return 𝔽(k).
+                if search_element.strict_equals(&element_k) {
+                    return Ok(k.into());
+                }
+            }
+        }
+
+        // 12. Return -1𝔽.
+        Ok((-1).into())
+    }
+
+    /// `%TypedArray%.prototype.join ( separator )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.join
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/join
+    pub(super) fn join(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If separator is undefined, let sep be the single-element String ",".
+        // 5. Else, let sep be ? ToString(separator).
+        let sep = match args.get_or_undefined(0) {
+            JsValue::Undefined => JsString::new(","),
+            separator => separator.to_string(context)?,
+        };
+
+        // 6. Let R be the empty String.
+        let mut r = String::new();
+
+        // 7. Let k be 0.
+        // 8. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, set R to the string-concatenation of R and sep.
+            if k > 0 {
+                r.push_str(&sep);
+            }
+
+            // b. Let element be ! Get(O, ! ToString(𝔽(k))).
+            let element = o.get(k, context)?;
+
+            // c. If element is undefined, let next be the empty String; otherwise, let next be ! ToString(element).
+            // d. Set R to the string-concatenation of R and next.
+            if !element.is_undefined() {
+                r.push_str(&element.to_string(context)?);
+            }
+        }
+
+        // 9. Return R.
+        Ok(r.into())
+    }
+
+    /// `%TypedArray%.prototype.keys ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/keys
+    pub(super) fn keys(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Return CreateArrayIterator(O, key).
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            o.into(),
+            PropertyNameKind::Key,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.lastIndexOf ( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.lastindexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/lastIndexOf
+    pub(super) fn last_index_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o) as i64;
+
+        // 4. If len is 0, return -1𝔽.
+        if len == 0 {
+            return Ok((-1).into());
+        }
+
+        // 5. If fromIndex is present, let n be ? ToIntegerOrInfinity(fromIndex); else let n be len - 1.
+        // 6. If n is -∞, return -1𝔽.
+        // 7. If n ≥ 0, then
+        // a. Let k be min(n, len - 1).
+        // 8. Else,
+        // a. Let k be len + n.
+        let k = match args.get(1) {
+            None => len - 1,
+            Some(from_index) => match from_index.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::NegativeInfinity => return Ok((-1).into()),
+                IntegerOrInfinity::PositiveInfinity => len - 1,
+                IntegerOrInfinity::Integer(n) if n >= 0 => n.min(len - 1),
+                IntegerOrInfinity::Integer(n) => len + n,
+            },
+        };
+        let search_element = args.get_or_undefined(0);
+
+        // 9. Repeat, while k ≥ 0,
+        for k in (0..=k).rev() {
+            // a. Let kPresent be ! HasProperty(O, ! ToString(𝔽(k))).
+            // b. If kPresent is true, then
+            if o.has_property(k, context)? {
+                // i. Let elementK be ! Get(O, ! ToString(𝔽(k))).
+                let element_k = o.get(k, context)?;
+
+                // ii. Let same be IsStrictlyEqual(searchElement, elementK).
+                // iii. If same is true, return 𝔽(k).
+                if search_element.strict_equals(&element_k) {
+                    return Ok(k.into());
+                }
+            }
+        }
+
+        // 10. Return -1𝔽.
+        Ok((-1).into())
+    }
+
+    /// `get %TypedArray%.prototype.length`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/length
+    pub(super) fn length(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 3. Assert: O has [[ViewedArrayBuffer]] and [[ArrayLength]] internal slots.
+        let o = Self::require_typed_array(this, context)?;
+        let o = o.borrow();
+        let inner = o.as_typed_array().expect("checked to be a typed array");
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
+        // 6. Let length be TypedArrayLength(taRecord).
+        // 7. Return 𝔽(length).
+        Ok(inner.array_length().into())
+    }
+
+    /// `%TypedArray%.prototype.map ( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/map
+    pub(super) fn map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback_fn = Self::callback(args, "map", context)?;
+        let this_arg = args.get_or_undefined(1);
+
+        // 5. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(len) »).
+        let a = Self::species_create(&o, &[len.into()], context)?;
+
+        // 6. Let k be 0.
+        // 7. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Let mappedValue be ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
+            let mapped_value =
+                callback_fn.call(this_arg, &[k_value, k.into(), o.clone().into()], context)?;
+
+            // d. Perform ? Set(A, Pk, mappedValue, true).
+            a.set(k, mapped_value, true, context)?;
+        }
+
+        // 8. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.reduce ( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduce
+    pub(super) fn reduce(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        Self::reduce_elements(&o, args, 0..len, "reduce", context)
+    }
+
+    /// `%TypedArray%.prototype.reduceRight ( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduceright
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduceRight
+    pub(super) fn reduce_right(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        Self::reduce_elements(&o, args, (0..len).rev(), "reduceRight", context)
+    }
+
+    /// Shared steps of `reduce` and `reduceRight`, visiting the elements in the order of `indices`.
+    fn reduce_elements<I>(
+        o: &JsObject,
+        args: &[JsValue],
+        mut indices: I,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsValue>
+    where
+        I: Iterator<Item = usize>,
+    {
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback_fn = Self::callback(args, method, context)?;
+
+        // 6. Let accumulator be undefined.
+        // 7. If initialValue is present, then
+        // a. Set accumulator to initialValue.
+        // 8. Else,
+        // a. Let Pk be ! ToString(𝔽(k)).
+        // b. Set accumulator to ! Get(O, Pk).
+        let mut accumulator = match args.get(1) {
+            Some(initial_value) => initial_value.clone(),
+            None => match indices.next() {
+                Some(k) => o.get(k, context)?,
+                // 5. If len = 0 and initialValue is not present, throw a TypeError exception.
+                None => {
+                    return context.throw_type_error(format!(
+                        "TypedArray.prototype.{} of empty array with no initial value",
+                        method
+                    ))
+                }
+            },
+        };
+
+        // 9. Repeat, while k < len / k ≥ 0,
+        for k in indices {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Set accumulator to ? Call(callbackfn, undefined, « accumulator, kValue, 𝔽(k), O »).
+            accumulator = callback_fn.call(
+                &JsValue::undefined(),
+                &[accumulator, k_value, k.into(), o.clone().into()],
+                context,
+            )?;
+        }
+
+        // 10. Return accumulator.
+        Ok(accumulator)
+    }
+
+    /// `%TypedArray%.prototype.reverse ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reverse
+    pub(super) fn reverse(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. Let middle be floor(len / 2).
+        let middle = len / 2;
+
+        // 5. Let lower be 0.
+        // 6. Repeat, while lower ≠ middle,
+        for lower in 0..middle {
+            // a. Let upper be len - lower - 1.
+            let upper = len - lower - 1;
+
+            // b. Let upperP be ! ToString(𝔽(upper)).
+            // c. Let lowerP be ! ToString(𝔽(lower)).
+            // d. Let lowerValue be ! Get(O, lowerP).
+            let lower_value = o.get(lower, context)?;
+            // e. Let upperValue be ! Get(O, upperP).
+            let upper_value = o.get(upper, context)?;
+
+            // f. Perform ! Set(O, lowerP, upperValue, true).
+            o.set(lower, upper_value, true, context)?;
+            // g. Perform ! Set(O, upperP, lowerValue, true).
+            o.set(upper, lower_value, true, context)?;
+        }
+
+        // 7. Return O.
+        Ok(o.into())
+    }
+
+    /// `%TypedArray%.prototype.set ( source [ , offset ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set
+    pub(super) fn set(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let target be the this value.
+        // 2. Perform ? RequireInternalSlot(target, [[TypedArrayName]]).
+        // 3. Assert: target has a [[ViewedArrayBuffer]] internal slot.
+        let target = Self::require_typed_array(this, context)?;
+
+        // 4. Let targetOffset be ? ToIntegerOrInfinity(offset).
+        // 5. If targetOffset < 0, throw a RangeError exception.
+        let target_offset = match args.get_or_undefined(1).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i as usize,
+            IntegerOrInfinity::PositiveInfinity => usize::MAX,
+            _ => return context.throw_range_error("TypedArray.prototype.set: negative offset"),
+        };
+
+        let source = args.get_or_undefined(0);
+        match source.as_object() {
+            // 6. If source is an Object that has a [[TypedArrayName]] internal slot, then
+            Some(source) if source.borrow().is_typed_array() => {
+                // a. Perform ? SetTypedArrayFromTypedArray(target, targetOffset, source).
+                Self::set_from_typed_array(&target, target_offset, &source, context)?;
+            }
+            // 7. Else,
+            _ => {
+                // a. Perform ? SetTypedArrayFromArrayLike(target, targetOffset, source).
+                Self::set_from_array_like(&target, target_offset, source, context)?;
+            }
+        }
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation `SetTypedArrayFromTypedArray ( target, targetOffset, source )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-settypedarrayfromtypedarray
+    fn set_from_typed_array(
+        target: &JsObject,
+        target_offset: usize,
+        source: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
+        // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
+        // 4. Let srcBuffer be source.[[ViewedArrayBuffer]].
+        // 5. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
+        if Self::is_out_of_bounds(target) || Self::is_out_of_bounds(source) {
+            return Err(context.construct_coded_error(
+                JsErrorCode::DETACHED_TYPED_ARRAY,
+                "TypedArray buffer was detached",
+            ));
+        }
+
+        // 3. Let targetLength be target.[[ArrayLength]].
+        let target_length = Self::array_length(target);
+        let target_type = Self::name(target);
+
+        // 6. Let targetName be the String value of target.[[TypedArrayName]].
+        // 7. Let targetType be the Element Type value in Table 72 for targetName.
+        // 10. Let srcName be the String value of source.[[TypedArrayName]].
+        // 11. Let srcType be the Element Type value in Table 72 for srcName.
+        // 13. Let srcLength be source.[[ArrayLength]].
+        let src_length = Self::array_length(source);
+        let src_type = Self::name(source);
+
+        // 15. If targetOffset is +∞, throw a RangeError exception.
+        // 16. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+        if target_offset == usize::MAX || src_length + target_offset > target_length {
+            return Err(
+                context.construct_range_error("TypedArray.prototype.set: source is too large")
+            );
+        }
+
+        // 17. If target.[[ContentType]] ≠ source.[[ContentType]], throw a TypeError exception.
+        if target_type.content_type() != src_type.content_type() {
+            return Err(context.construct_type_error(
+                "TypedArray.prototype.set: cannot mix BigInt and Number arrays",
+            ));
+        }
+
+        // 18-23. The source elements are read before writing any of them, so it doesn't matter
+        // whether both arrays share the same buffer.
+        let values: Vec<_> = (0..src_length)
+            .filter_map(|index| integer_indexed_element_get(source, index as f64))
+            .collect();
+
+        // 24-25. Set the elements of the target, converting them to the target type.
+        for (index, value) in values.iter().enumerate() {
+            integer_indexed_element_set(target, (target_offset + index) as f64, value, context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Abstract operation `SetTypedArrayFromArrayLike ( target, targetOffset, source )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-settypedarrayfromarraylike
+    fn set_from_array_like(
+        target: &JsObject,
+        target_offset: usize,
+        source: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
+        // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
+        if Self::is_out_of_bounds(target) {
+            return Err(context.construct_coded_error(
+                JsErrorCode::DETACHED_TYPED_ARRAY,
+                "TypedArray buffer was detached",
+            ));
+        }
+
+        // 3. Let targetLength be target.[[ArrayLength]].
+        let target_length = Self::array_length(target);
+
+        // 4. Let src be ? ToObject(source).
+        let src = source.to_object(context)?;
+
+        // 5. Let srcLength be ? LengthOfArrayLike(src).
+        let src_length = src.length_of_array_like(context)?;
+
+        // 6. If targetOffset is +∞, throw a RangeError exception.
+        // 7. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+        if target_offset == usize::MAX || src_length + target_offset > target_length {
+            return Err(
+                context.construct_range_error("TypedArray.prototype.set: source is too large")
+            );
+        }
+
+        // 8. Let k be 0.
+        // 9. Repeat, while k < srcLength,
+        for k in 0..src_length {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let value be ? Get(src, Pk).
+            let value = src.get(k, context)?;
+
+            // c. Let targetIndex be 𝔽(targetOffset + k).
+            // d. Perform ? IntegerIndexedElementSet(target, targetIndex, value).
+            integer_indexed_element_set(target, (target_offset + k) as f64, &value, context)?;
+        }
+
+        Ok(())
+    }
+
+    /// `%TypedArray%.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/slice
+    pub(super) fn slice(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4-5. Let k be the clamped relativeStart.
+        let k = Array::get_relative_start(context, args.get(0), len)?;
+
+        // 6-7. Let final be the clamped relativeEnd.
+        let r#final = Array::get_relative_end(context, args.get(1), len)?;
+
+        // 8. Let count be max(final - k, 0).
+        let count = r#final.saturating_sub(k);
+
+        // 9. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(count) »).
+        let a = Self::species_create(&o, &[count.into()], context)?;
+
+        // 10. If count > 0, then
+        if count > 0 {
+            // a. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if Self::is_out_of_bounds(&o) {
+                return context.throw_coded_error(
+                    JsErrorCode::DETACHED_TYPED_ARRAY,
+                    "TypedArray buffer was detached",
+                );
+            }
+
+            // c. Set endIndex to min(endIndex, TypedArrayLength(taRecord)).
+            let r#final = r#final.min(Self::array_length(&o));
+
+            // d-e. The elements are copied one by one; when both arrays have the same type
+            // this preserves their bit-level encoding as the spec requires.
+            // f. Let n be 0.
+            // g. Repeat, while k < final,
+            for (n, k) in (k..r#final).enumerate() {
+                // i. Let Pk be ! ToString(𝔽(k)).
+                // ii. Let kValue be ! Get(O, Pk).
+                let k_value = o.get(k, context)?;
+
+                // iii. Perform ! Set(A, ! ToString(𝔽(n)), kValue, true).
+                a.set(n, k_value, true, context)?;
+            }
+        }
+
+        // 11. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.some ( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/some
+    pub(super) fn some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback_fn = Self::callback(args, "some", context)?;
+        let this_arg = args.get_or_undefined(1);
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = o.get(k, context)?;
+
+            // c. Let testResult be ! ToBoolean(? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »)).
+            let test_result = callback_fn
+                .call(this_arg, &[k_value, k.into(), o.clone().into()], context)?
+                .to_boolean();
+
+            // d. If testResult is true, return true.
+            if test_result {
+                return Ok(true.into());
+            }
+        }
+
+        // 7. Return false.
+        Ok(false.into())
+    }
+
+    /// `%TypedArray%.prototype.toReversed ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.toreversed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toReversed
+    pub(super) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let length be O.[[ArrayLength]].
+        let length = Self::array_length(&o);
+
+        // 4. Let A be ? TypedArrayCreateSameType(O, « 𝔽(length) »).
+        let a = Self::create_same_type(&o, length, context)?;
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < length,
+        for k in 0..length {
+            // a. Let from be ! ToString(𝔽(length - k - 1)).
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ! Get(O, from).
+            let from_value = o.get(length - k - 1, context)?;
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)?;
+            // e. Set k to k + 1.
+        }
+
+        // 7. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.sort ( comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/sort
+    pub(super) fn sort(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = match args.get_or_undefined(0) {
+            JsValue::Undefined => None,
+            JsValue::Object(comparefn) if comparefn.is_callable() => Some(comparefn.clone()),
+            _ => {
+                return context.throw_type_error(
+                    "The comparison function must be either a function or undefined",
+                )
+            }
+        };
+
+        // 2. Let obj be the this value.
+        // 3. Perform ? ValidateTypedArray(obj).
+        let obj = Self::validate(this, context)?;
+
+        // 4. Let len be obj.[[ArrayLength]].
+        let len = Self::array_length(&obj);
+
+        // 5. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.27.
+        // 6. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareTypedArrayElements(x, y, comparefn).
+        // 7. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
+        let sorted_list = Self::sort_indexed_properties(&obj, len, comparefn.as_ref(), context)?;
+
+        // 8. Let j be 0.
+        // 9. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            obj.set(j, item, true, context)?;
+        }
+
+        // 10. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `%TypedArray%.prototype.toSorted ( comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toSorted
+    pub(super) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = match args.get_or_undefined(0) {
+            JsValue::Undefined => None,
+            JsValue::Object(comparefn) if comparefn.is_callable() => Some(comparefn.clone()),
+            _ => {
+                return context.throw_type_error(
+                    "The comparison function must be either a function or undefined",
+                )
+            }
+        };
+
+        // 2. Let O be the this value.
+        // 3. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 4. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o);
+
+        // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(&o, len, context)?;
+
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.34.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareTypedArrayElements(x, y, comparefn).
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted_list = Self::sort_indexed_properties(&o, len, comparefn.as_ref(), context)?;
+
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, item) in sorted_list.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+            a.set(j, item, true, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 11. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.with ( index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/with
+    pub(super) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = Self::array_length(&o) as i64;
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        // 6. Else, let actualIndex be len + relativeIndex.
+        let actual_index = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(i) if i >= 0 => Some(i),
+            IntegerOrInfinity::Integer(i) => Some(len + i),
+            _ => None,
+        };
+
+        // 7. If O.[[ContentType]] is BigInt, let numericValue be ? ToBigInt(value).
+        // 8. Else, let numericValue be ? ToNumber(value).
+        let numeric_value = Self::name(&o).to_element_value(args.get_or_undefined(1), context)?;
+
+        // 9. If IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+        let actual_index = match actual_index {
+            Some(i) if !Self::is_out_of_bounds(&o) && (0..len).contains(&i) => i as usize,
+            _ => return context.throw_range_error("TypedArray.prototype.with: index out of range"),
+        };
+
+        // 10. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(&o, len as usize, context)?;
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len as usize {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be numericValue.
+            // c. Else, let fromValue be ! Get(O, Pk).
+            let from_value = if k == actual_index {
+                numeric_value.clone()
+            } else {
+                o.get(k, context)?
+            };
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)?;
+            // e. Set k to k + 1.
+        }
+
+        // 13. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.subarray ( begin, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray
+    pub(super) fn subarray(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        let o = Self::require_typed_array(this, context)?;
+
+        // 4. Let buffer be O.[[ViewedArrayBuffer]].
+        // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+        //     a. Let srcLength be 0.
+        // 7. Else,
+        //     a. Let srcLength be TypedArrayLength(srcRecord).
+        // 13. Let elementSize be TypedArrayElementSize(O).
+        // 14. Let srcByteOffset be O.[[ByteOffset]].
+        let (buffer, src_length, element_size, src_byte_offset, length_tracking) = {
+            let o = o.borrow();
+            let inner = o.as_typed_array().expect("checked to be a typed array");
+            (
+                inner
+                    .viewed_array_buffer()
+                    .cloned()
+                    .map(JsValue::new)
+                    .unwrap_or_default(),
+                inner.array_length(),
+                inner.typed_array_name().element_size(),
+                inner.byte_offset(),
+                inner.is_length_tracking(),
+            )
+        };
+
+        // 8-9. Let beginIndex be the clamped relativeBegin.
+        let begin_index = Array::get_relative_start(context, args.get(0), src_length)?;
+
+        // 15. Let beginByteOffset be srcByteOffset + beginIndex × elementSize.
+        let begin_byte_offset = src_byte_offset + begin_index * element_size;
+
+        // 16. If O.[[ArrayLength]] is auto and end is undefined, then
+        //     a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+        let end = args.get_or_undefined(1);
+        if length_tracking && end.is_undefined() {
+            return Ok(
+                Self::species_create(&o, &[buffer, begin_byte_offset.into()], context)?.into(),
+            );
+        }
+
+        // 17. Else,
+        //     a-d. Let endIndex be the clamped relativeEnd.
+        let end_index = Array::get_relative_end(context, args.get(1), src_length)?;
+
+        //     e. Let newLength be max(endIndex - beginIndex, 0).
+        let new_length = end_index.saturating_sub(begin_index);
+
+        //     f. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+        // 18. Return ? TypedArraySpeciesCreate(O, argumentsList).
+        Ok(Self::species_create(
+            &o,
+            &[buffer, begin_byte_offset.into(), new_length.into()],
+            context,
+        )?
+        .into())
+    }
+
+    /// `%TypedArray%.prototype.toLocaleString ( [ reserved1 [ , reserved2 ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toLocaleString
+    pub(super) fn to_locale_string(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? ValidateTypedArray(array).
+        let array = Self::validate(this, context)?;
+
+        // 2. Let len be array.[[ArrayLength]].
+        let len = Self::array_length(&array);
+
+        // 3. Let separator be the implementation-defined list-separator String appropriate for the host environment's current locale.
+        // 4. Let R be the empty String.
+        let mut r = String::new();
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, set R to the string-concatenation of R and separator.
+            if k > 0 {
+                r.push(',');
+            }
+
+            // b. Let nextElement be ? Get(array, ! ToString(k)).
+            let next_element = array.get(k, context)?;
+
+            // c. If nextElement is not undefined or null, then
+            if !next_element.is_null_or_undefined() {
+                // i. Let S be ? ToString(? Invoke(nextElement, "toLocaleString")).
+                let to_locale_string = next_element.get_v("toLocaleString", context)?;
+                let s = context
+                    .call(&to_locale_string, &next_element, &[])?
+                    .to_string(context)?;
+
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(&s);
+            }
+        }
+
+        // 7. Return R.
+        Ok(r.into())
+    }
+
+    /// `get %TypedArray%.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-@@tostringtag
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/@@toStringTag
+    pub(super) fn to_string_tag(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If Type(O) is not Object, return undefined.
+        // 3. If O does not have a [[TypedArrayName]] internal slot, return undefined.
+        // 4. Let name be O.[[TypedArrayName]].
+        // 5. Assert: Type(name) is String.
+        // 6. Return name.
+        Ok(this
+            .as_object()
+            .and_then(|obj| {
+                obj.borrow()
+                    .as_typed_array()
+                    .map(|inner| inner.typed_array_name().name().into())
+            })
+            .unwrap_or_default())
+    }
+
+    /// `%TypedArray%.prototype.values ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/values
+    pub(super) fn values(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = Self::validate(this, context)?;
+
+        // 3. Return CreateArrayIterator(O, value).
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            o.into(),
+            PropertyNameKind::Value,
+        ))
+    }
+
+    /// Abstract operation `TypedArraySpeciesCreate ( exemplar, argumentList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-species-create
+    fn species_create(
+        exemplar: &JsObject,
+        argument_list: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let defaultConstructor be the intrinsic object listed in column one of Table 72 for exemplar.[[TypedArrayName]].
+        let exemplar_type = Self::name(exemplar);
+        let default_constructor = exemplar_type.standard_constructor()(context.standard_objects())
+            .constructor()
+            .into();
+
+        // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
+        let constructor = exemplar.species_constructor(default_constructor, context)?;
+        let constructor = constructor
+            .as_object()
+            .expect("SpeciesConstructor only returns constructors");
+
+        // 3. Let result be ? TypedArrayCreate(constructor, argumentList).
+        let result = Self::create(&constructor, argument_list, context)?;
+
+        // 4. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+        // 5. If result.[[ContentType]] ≠ exemplar.[[ContentType]], throw a TypeError exception.
+        if Self::name(&result).content_type() != exemplar_type.content_type() {
+            return Err(context.construct_type_error(
+                "TypedArray species constructor created an array of another content type",
+            ));
+        }
+
+        // 6. Return result.
+        Ok(result)
+    }
+
+    /// Abstract operation `TypedArrayCreateSameType ( exemplar, argumentList )`
+    ///
+    /// Creates a typed array of the same type as `exemplar`, without looking up its species.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray-create-same-type
+    fn create_same_type(
+        exemplar: &JsObject,
+        length: usize,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let constructor be the intrinsic object associated with the constructor name exemplar.[[TypedArrayName]] in Table 71.
+        let constructor =
+            Self::name(exemplar).standard_constructor()(context.standard_objects()).constructor();
+
+        // 2. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
+        // 3. Return result.
+        Self::create(&constructor, &[length.into()], context)
+    }
+
+    /// Abstract operation `SortIndexedProperties ( obj, len, SortCompare, read-through-holes )`
+    ///
+    /// Reads the `len` first elements of the typed array `obj` and sorts them with
+    /// `CompareTypedArrayElements`, using `comparefn` if given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortindexedproperties
+    fn sort_indexed_properties(
+        obj: &JsObject,
+        len: usize,
+        comparefn: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Vec<JsValue>> {
+        let sort_compare =
+            |x: &JsValue, y: &JsValue, context: &mut Context| -> JsResult<Ordering> {
+                // a. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
+                // b. If comparefn is not undefined, then
+                if let Some(comparefn) = comparefn {
+                    // i. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+                    let v = comparefn
+                        .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
+                        .to_number(context)?;
+
+                    // ii. If v is NaN, return +0𝔽.
+                    // iii. Return v.
+                    return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+                }
+
+                // c-j. Compare the numeric values, sorting NaN last and -0 before +0.
+                Ok(compare_typed_array_elements(x, y))
+            };
+
+        let mut items = Vec::with_capacity(len);
+        for k in 0..len {
+            items.push(obj.get(k, context)?);
+        }
+
+        let mut sort_err = Ok(());
+        items.sort_by(|x, y| {
+            if sort_err.is_ok() {
+                sort_compare(x, y, context).unwrap_or_else(|err| {
+                    sort_err = Err(err);
+                    Ordering::Equal
+                })
+            } else {
+                Ordering::Equal
+            }
+        });
+        sort_err?;
+
+        Ok(items)
+    }
+}
+
+/// The default comparison of `%TypedArray%.prototype.sort`, which orders the elements
+/// numerically, with `NaN` last and `-0` before `+0`.
+fn compare_typed_array_elements(x: &JsValue, y: &JsValue) -> Ordering {
+    if let (Some(x), Some(y)) = (x.as_bigint(), y.as_bigint()) {
+        return x.cmp(y);
+    }
+
+    let x = x
+        .as_number()
+        .expect("typed array elements are numbers or BigInts");
+    let y = y
+        .as_number()
+        .expect("typed array elements are numbers or BigInts");
+
+    match (x.is_nan(), y.is_nan()) {
+        // c. If x and y are both NaN, return +0𝔽.
+        (true, true) => Ordering::Equal,
+        // d. If x is NaN, return 1𝔽.
+        (true, false) => Ordering::Greater,
+        // e. If y is NaN, return -1𝔽.
+        (false, true) => Ordering::Less,
+        // f. If x < y, return -1𝔽.
+        // g. If x > y, return 1𝔽.
+        // h. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+        // i. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+        // j. Return +0𝔽.
+        (false, false) => x
+            .partial_cmp(&y)
+            .expect("neither value is NaN")
+            .then_with(|| y.is_sign_negative().cmp(&x.is_sign_negative())),
+    }
+}
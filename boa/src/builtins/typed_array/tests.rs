@@ -0,0 +1,551 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructors() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "new Int8Array(4).length"), "4");
+    assert_eq!(
+        forward(&mut context, "new Float64Array(4).byteLength"),
+        "32"
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array([1, 2, 3]).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int16Array({ length: 2, 0: 7, 1: 8 }).join()"
+        ),
+        "\"7,8\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint16Array(new Set([5, 6])).join()"),
+        "\"5,6\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Float32Array(new Int8Array([-1, 2])).join()"
+        ),
+        "\"-1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { Int8Array(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Int8Array(-1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new BigInt64Array(new Int8Array(1)) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn views_of_buffers() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer);
+        var words = new Uint16Array(buffer, 2, 2);
+        words[0] = 0xffff;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "words.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "words.byteLength"), "4");
+    assert_eq!(forward(&mut context, "words.buffer === buffer"), "true");
+    assert_eq!(
+        forward(&mut context, "bytes.join()"),
+        "\"0,0,255,255,0,0,0,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int32Array(buffer, 4).length"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Int32Array(buffer, 1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Int32Array(buffer, 4, 2) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn hierarchy() {
+    let mut context = Context::new();
+    let init = r#"
+        var TypedArray = Object.getPrototypeOf(Int8Array);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Float64Array) === TypedArray"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Uint8Array.prototype) === TypedArray.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "TypedArray.name"), "\"TypedArray\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new TypedArray() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "Int8Array.length"), "3");
+    assert_eq!(forward(&mut context, "Float64Array.BYTES_PER_ELEMENT"), "8");
+    assert_eq!(
+        forward(&mut context, "Uint16Array.prototype.BYTES_PER_ELEMENT"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "TypedArray.prototype.toString === Array.prototype.toString"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "TypedArray.prototype[Symbol.iterator] === TypedArray.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8ClampedArray(1)[Symbol.toStringTag]"),
+        "\"Uint8ClampedArray\""
+    );
+    assert_eq!(
+        forward(&mut context, "TypedArray.prototype[Symbol.toStringTag]"),
+        "undefined"
+    );
+}
+
+#[test]
+fn element_conversions() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int8Array([127, 128, -129, 1.9, NaN]).join()"
+        ),
+        "\"127,-128,127,1,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array([256, -1, 3.7]).join()"),
+        "\"0,255,3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Uint8ClampedArray([300, -5, 1.5, 2.5, 2.6, NaN]).join()"
+        ),
+        "\"255,0,2,2,3,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint32Array([-1])[0]"),
+        "4294967295"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Float32Array([0.1])[0] === Math.fround(0.1)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new BigInt64Array([2n ** 63n])[0]"),
+        "-9223372036854775808n"
+    );
+    assert_eq!(
+        forward(&mut context, "new BigUint64Array([-1n])[0]"),
+        "18446744073709551615n"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new BigInt64Array([undefined]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new BigUint64Array(1).fill(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new BigInt64Array(['7', true]).join()"),
+        "\"7,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Float64Array([1.5, 2]).toLocaleString()"),
+        "\"1.5,2\""
+    );
+}
+
+#[test]
+fn integer_indexed_properties() {
+    let mut context = Context::new();
+    let init = r#"
+        var ta = new Int8Array([1, 2]);
+        ta[5] = 9;
+        ta["-0"] = 9;
+        ta[1.5] = 9;
+        ta.foo = "bar";
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "ta.length"), "2");
+    assert_eq!(forward(&mut context, "ta[5]"), "undefined");
+    assert_eq!(forward(&mut context, "ta[1.5]"), "undefined");
+    assert_eq!(forward(&mut context, "5 in ta"), "false");
+    assert_eq!(forward(&mut context, "1 in ta"), "true");
+    assert_eq!(forward(&mut context, "'-0' in ta"), "false");
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(ta).join()"),
+        "\"0,1,foo\""
+    );
+    assert_eq!(forward(&mut context, "delete ta[0]"), "false");
+    assert_eq!(forward(&mut context, "delete ta[2]"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify(Object.getOwnPropertyDescriptor(ta, 0))"
+        ),
+        r#""{"value":1,"writable":true,"enumerable":true,"configurable":true}""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(ta, 0, { value: 3, configurable: false })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.defineProperty(ta, 0, { value: 3 })"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "ta[0]"), "3");
+}
+
+#[test]
+fn from_and_of() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "Int8Array.from([1, 2, 3], x => x * 2).join()"),
+        "\"2,4,6\""
+    );
+    assert_eq!(
+        forward(&mut context, "Uint8Array.from({ length: 2, 1: 4 }).join()"),
+        "\"0,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "Int16Array.of(1, -1).join()"),
+        "\"1,-1\""
+    );
+    assert_eq!(
+        forward(&mut context, "Int8Array.of(1) instanceof Int8Array"),
+        "true"
+    );
+}
+
+#[test]
+fn prototype_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var ta = new Int8Array([3, 1, 4, 1, 5]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "ta.at(-1)"), "5");
+    assert_eq!(forward(&mut context, "ta.indexOf(1)"), "1");
+    assert_eq!(forward(&mut context, "ta.lastIndexOf(1)"), "3");
+    assert_eq!(forward(&mut context, "ta.includes(4)"), "true");
+    assert_eq!(forward(&mut context, "ta.find(x => x > 3)"), "4");
+    assert_eq!(forward(&mut context, "ta.findIndex(x => x > 3)"), "2");
+    assert_eq!(forward(&mut context, "ta.every(x => x > 0)"), "true");
+    assert_eq!(forward(&mut context, "ta.some(x => x > 4)"), "true");
+    assert_eq!(forward(&mut context, "ta.reduce((a, b) => a + b)"), "14");
+    assert_eq!(
+        forward(&mut context, "ta.reduceRight((a, b) => a + '' + b, '')"),
+        "\"51413\""
+    );
+    assert_eq!(
+        forward(&mut context, "ta.map(x => x * 100).join()"),
+        "\"44,100,-112,100,-12\""
+    );
+    assert_eq!(
+        forward(&mut context, "ta.filter(x => x !== 1) instanceof Int8Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "ta.slice(1, 3).join()"), "\"1,4\"");
+    assert_eq!(forward(&mut context, "ta.join('-')"), "\"3-1-4-1-5\"");
+    assert_eq!(forward(&mut context, "ta.toString()"), "\"3,1,4,1,5\"");
+    assert_eq!(
+        forward(&mut context, "[...ta.keys()].join()"),
+        "\"0,1,2,3,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "[...ta.entries()][2].join()"),
+        "\"2,4\""
+    );
+    assert_eq!(forward(&mut context, "[...ta].length"), "5");
+
+    forward(&mut context, "var sum = 0; ta.forEach(x => sum += x)");
+    assert_eq!(forward(&mut context, "sum"), "14");
+}
+
+#[test]
+fn mutating_methods() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int8Array([1, 2, 3, 4, 5]).copyWithin(0, 3).join()"
+        ),
+        "\"4,5,3,4,5\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int8Array(4).fill(7, 1, -1).join()"),
+        "\"0,7,7,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int8Array([1, 2, 3]).reverse().join()"),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Float64Array([3, NaN, -0, 0, -5]).sort().join()"
+        ),
+        "\"-5,0,0,3,NaN\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.is(new Float64Array([0, -0]).sort()[0], -0)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int8Array([1, 3, 2]).sort((a, b) => b - a).join()"
+        ),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new BigInt64Array([3n, -1n, 2n]).sort().join()"
+        ),
+        "\"-1,2,3\""
+    );
+
+    let init = r#"
+        var target = new Int16Array(5);
+        target.set([1, 2], 1);
+        target.set(new Int8Array([-1]), 4);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "target.join()"), "\"0,1,2,0,-1\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { target.set([1, 2], 4) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { target.set([1], -1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+
+    // Setting from a typed array overlapping the same buffer copies the elements first.
+    let init = r#"
+        var bytes = new Uint8Array([1, 2, 3, 4]);
+        bytes.set(bytes.subarray(0, 3), 1);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "bytes.join()"), "\"1,1,2,3\"");
+}
+
+#[test]
+fn subarray() {
+    let mut context = Context::new();
+    let init = r#"
+        var ta = new Int32Array([1, 2, 3, 4]);
+        var sub = ta.subarray(1, 3);
+        sub[0] = 20;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sub.length"), "2");
+    assert_eq!(forward(&mut context, "sub.byteOffset"), "4");
+    assert_eq!(forward(&mut context, "sub.buffer === ta.buffer"), "true");
+    assert_eq!(forward(&mut context, "ta[1]"), "20");
+}
+
+#[test]
+fn species() {
+    let mut context = Context::new();
+    let init = r#"
+        var species = {};
+        species[Symbol.species] = Int16Array;
+        var ta = new Int8Array([1, 2, 3]);
+        ta.constructor = species;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "ta.map(x => x) instanceof Int16Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "try { ta.subarray(1) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(forward(&mut context, "ta.slice(1).join()"), "\"2,3\"");
+    forward(&mut context, "species[Symbol.species] = BigInt64Array");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ta.filter(x => x) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "Int8Array[Symbol.species] === Int8Array"),
+        "true"
+    );
+}
+
+#[test]
+fn change_by_copy_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var ta = new Int8Array([3, 1, 2]);
+        var species = {};
+        species[Symbol.species] = function () { throw 'species'; };
+        ta.constructor = species;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "ta.toReversed().join()"), "\"2,1,3\"");
+    assert_eq!(forward(&mut context, "ta.toSorted().join()"), "\"1,2,3\"");
+    assert_eq!(
+        forward(&mut context, "ta.toSorted((a, b) => b - a).join()"),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "ta.with(-1, 300).join()"),
+        "\"3,1,44\""
+    );
+    assert_eq!(forward(&mut context, "ta.join()"), "\"3,1,2\"");
+
+    // The copies have the type of the original array, ignoring its species.
+    assert_eq!(
+        forward(
+            &mut context,
+            "[ta.toReversed(), ta.toSorted(), ta.with(0, 0)].every(a => a instanceof Int8Array)"
+        ),
+        "true"
+    );
+
+    assert_eq!(
+        forward(&mut context, "try { ta.with(3, 0) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new BigInt64Array(2).with(1, 5n).join()"),
+        "\"0,5\""
+    );
+}
+
+#[test]
+fn resizable_buffers() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8, { maxByteLength: 16 });
+        var tracking = new Uint16Array(buffer, 2);
+        var fixed = new Uint16Array(buffer, 2, 2);
+        var sub = tracking.subarray(1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "tracking.length"), "3");
+    forward(&mut context, "buffer.resize(12)");
+    assert_eq!(forward(&mut context, "tracking.length"), "5");
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "10");
+    assert_eq!(forward(&mut context, "sub.length"), "4");
+    assert_eq!(forward(&mut context, "fixed.length"), "2");
+
+    forward(&mut context, "buffer.resize(5)");
+    assert_eq!(forward(&mut context, "tracking.length"), "1");
+    assert_eq!(forward(&mut context, "fixed.length"), "0");
+    assert_eq!(forward(&mut context, "fixed.byteOffset"), "0");
+    assert_eq!(forward(&mut context, "fixed[0]"), "undefined");
+    assert_eq!(
+        forward(&mut context, "try { fixed.fill(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    forward(&mut context, "buffer.resize(1)");
+    assert_eq!(forward(&mut context, "tracking.length"), "0");
+    assert_eq!(
+        forward(&mut context, "try { tracking.at(0) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    forward(&mut context, "buffer.resize(6)");
+    assert_eq!(forward(&mut context, "fixed.length"), "2");
+    assert_eq!(forward(&mut context, "tracking.length"), "2");
+}
+
+#[test]
+fn buffer_shrunk_by_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8, { maxByteLength: 8 });
+        var array = new Uint8Array(buffer);
+        array.set([1, 2, 3, 4, 5, 6, 7, 8]);
+        var shrink = { valueOf() { buffer.resize(4); return 0; } };
+        "#;
+    forward(&mut context, init);
+
+    forward(&mut context, "array.copyWithin(2, shrink)");
+    assert_eq!(forward(&mut context, "[...array].join()"), "\"1,2,1,2\"");
+
+    forward(
+        &mut context,
+        "buffer.resize(8); shrink = { valueOf() { buffer.resize(2); return 8; } }",
+    );
+    assert_eq!(forward(&mut context, "array.slice(0, shrink).length"), "8");
+    assert_eq!(forward(&mut context, "array.length"), "2");
+}
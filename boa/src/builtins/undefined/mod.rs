@@ -22,7 +22,7 @@ impl BuiltIn for Undefined {
     const NAME: &'static str = "undefined";
 
     fn attribute() -> Attribute {
-        Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT
+        Attribute::constant()
     }
 
     fn init(_: &mut Context) -> (&'static str, JsValue, Attribute) {
@@ -0,0 +1,220 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` maps objects to values without keeping its keys alive. The value of a key is
+//! stored on the key object itself, so it is garbage collected together with the key.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use crate::{
+    builtins::{map::add_entries_from_iterable, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, WeakJsObject, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `WeakMap` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    /// The amount of arguments the `WeakMap` constructor takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakMap.prototype%", « [[WeakMapData]] »).
+        let weak_map_prototype = context.standard_objects().weak_map_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(weak_map_prototype);
+
+        let map = context.construct_object();
+        map.set_prototype_instance(prototype.into());
+
+        // 3. Set map.[[WeakMapData]] to a new empty List.
+        map.borrow_mut().data = ObjectData::weak_map();
+        let map = JsValue::new(map);
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = args.get_or_undefined(0).clone();
+        if iterable.is_null_or_undefined() {
+            return Ok(map);
+        }
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = map.get_field("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&map, iterable, &adder, context)
+    }
+
+    /// `WeakMap.prototype.delete ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let map = Self::this_weak_map(this, "delete", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(key) => Ok(key.borrow_mut().remove_ephemeron(&map).into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `WeakMap.prototype.get ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let map = Self::this_weak_map(this, "get", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(key) => Ok(key.borrow().ephemeron(&map).cloned().unwrap_or_default()),
+            _ => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// `WeakMap.prototype.has ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let map = Self::this_weak_map(this, "has", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(key) => Ok(key.borrow().ephemeron(&map).is_some().into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `WeakMap.prototype.set ( key, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let map = Self::this_weak_map(this, "set", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(key) => {
+                key.borrow_mut()
+                    .set_ephemeron(map, args.get_or_undefined(1).clone());
+                Ok(this.clone())
+            }
+            _ => context.throw_type_error("WeakMap key must be an object"),
+        }
+    }
+
+    /// Returns a weak reference to the `WeakMap` object `this`, identifying it in the
+    /// ephemerons of its keys, or throws a `TypeError` naming `method`.
+    fn this_weak_map(
+        this: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<WeakJsObject> {
+        match this {
+            JsValue::Object(map) if map.borrow().is_weak_map() => Ok(map.downgrade()),
+            _ => Err(context.construct_type_error(format!(
+                "WeakMap.prototype.{} called on a non-WeakMap",
+                method
+            ))),
+        }
+    }
+}
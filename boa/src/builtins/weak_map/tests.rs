@@ -0,0 +1,105 @@
+use crate::{forward, Context};
+
+#[test]
+fn get_set_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        var key = {};
+        var other = {};
+        var map = new WeakMap([[key, "value"]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut context, "map.has(other)"), "false");
+    assert_eq!(forward(&mut context, "map.set(other, 1) === map"), "true");
+    assert_eq!(forward(&mut context, "map.get(other)"), "1");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+    assert_eq!(forward(&mut context, "map.get(1)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(map)"),
+        "\"[object WeakMap]\""
+    );
+}
+
+#[test]
+fn maps_are_independent() {
+    let mut context = Context::new();
+    let init = r#"
+        var key = {};
+        var a = new WeakMap();
+        var b = new WeakMap();
+        a.set(key, "a");
+        a.set(a, "self");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "b.has(key)"), "false");
+    assert_eq!(forward(&mut context, "a.get(a)"), "\"self\"");
+}
+
+#[test]
+fn keys_are_weak() {
+    let mut context = Context::new();
+    let init = r#"
+        var map = new WeakMap();
+        var kept = {};
+        var value = { v: 1 };
+        map.set(kept, "kept");
+        var key = {};
+        map.set(key, value);
+        var keyRef = new WeakRef(key);
+        var valueRef = new WeakRef(value);
+        key = undefined;
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+
+    // The value is reachable from the global object, the key is only reachable from the map.
+    assert_eq!(forward(&mut context, "keyRef.deref()"), "undefined");
+    assert_eq!(forward(&mut context, "valueRef.deref() === value"), "true");
+    assert_eq!(forward(&mut context, "map.get(kept)"), "\"kept\"");
+}
+
+#[test]
+fn values_live_as_long_as_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        var map = new WeakMap();
+        var key = {};
+        map.set(key, { v: 1 });
+        var valueRef = new WeakRef(map.get(key));
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "valueRef.deref().v"), "1");
+
+    forward(&mut context, "key = undefined;");
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "valueRef.deref()"), "undefined");
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+    let cases = [
+        "WeakMap()",
+        "new WeakMap().set(1, 1)",
+        "new WeakMap([1])",
+        "WeakMap.prototype.get.call(new Map(), {})",
+    ];
+    for case in cases {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {} }} catch (e) {{ e.name }}", case)
+            ),
+            "\"TypeError\"",
+            "{}",
+            case
+        );
+    }
+}
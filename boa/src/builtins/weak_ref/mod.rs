@@ -0,0 +1,148 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` holds a weak reference to an object, which does not prevent the object from being
+//! garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+use crate::{
+    builtins::BuiltIn,
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `WeakRef` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl BuiltIn for WeakRef {
+    const NAME: &'static str = "WeakRef";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_ref_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_ref_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::deref, "deref", 0)
+        .build();
+
+        (Self::NAME, weak_ref_object.into(), Self::attribute())
+    }
+}
+
+impl WeakRef {
+    /// The amount of arguments the `WeakRef` constructor takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `WeakRef ( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/WeakRef
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakRef constructor without new is forbidden",
+            );
+        }
+
+        // 2. If Type(target) is not Object, throw a TypeError exception.
+        let target = match args.get_or_undefined(0) {
+            JsValue::Object(target) => target.clone(),
+            _ => return context.throw_type_error("WeakRef target must be an object"),
+        };
+
+        // 3. Let weakRef be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakRef.prototype%", « [[WeakRefTarget]] »).
+        let weak_ref_prototype = context.standard_objects().weak_ref_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(weak_ref_prototype);
+
+        let weak_ref = context.construct_object();
+        weak_ref.set_prototype_instance(prototype.into());
+
+        // 4. Perform ! AddToKeptObjects(target).
+        // 5. Set weakRef.[[WeakRefTarget]] to target.
+        weak_ref.borrow_mut().data = ObjectData::weak_ref(target.downgrade());
+        context.add_to_kept_objects(target);
+
+        // 6. Return weakRef.
+        Ok(weak_ref.into())
+    }
+
+    /// `WeakRef.prototype.deref ( )`
+    ///
+    /// Returns the target of the `WeakRef`, or `undefined` if it has been collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let weakRef be the this value.
+        // 2. Perform ? RequireInternalSlot(weakRef, [[WeakRefTarget]]).
+        let target = match this.as_object() {
+            Some(weak_ref) => weak_ref
+                .borrow()
+                .as_weak_ref()
+                .map(|target| target.upgrade()),
+            None => None,
+        };
+        let target = match target {
+            Some(target) => target,
+            None => {
+                return context.throw_type_error("WeakRef.prototype.deref called on a non-WeakRef")
+            }
+        };
+
+        // 3. Return ! WeakRefDeref(weakRef).
+        match target {
+            Some(target) => {
+                context.add_to_kept_objects(target.clone());
+                Ok(target.into())
+            }
+            None => Ok(JsValue::undefined()),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{forward, Context};
+
+#[test]
+fn deref() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = { a: 1 };
+        var ref = new WeakRef(target);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "ref.deref() === target"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(ref)"),
+        "\"[object WeakRef]\""
+    );
+
+    // The target is still reachable, so it survives collections.
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "ref.deref().a"), "1");
+}
+
+#[test]
+fn collected_target() {
+    let mut context = Context::new();
+    forward(&mut context, "var ref = (() => new WeakRef({ a: 1 }))();");
+
+    // The target is kept alive until the end of the job that created the `WeakRef`.
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "ref.deref().a"), "1");
+
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "ref.deref()"), "undefined");
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "try { WeakRef({}) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new WeakRef(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakRef.prototype.deref.call({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
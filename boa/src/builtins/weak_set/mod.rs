@@ -0,0 +1,219 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A `WeakSet` is a collection of objects which does not keep its values alive. Like the keys of
+//! a `WeakMap`, the membership of an object is stored on the object itself.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, WeakJsObject, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `WeakSet` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn attribute() -> Attribute {
+        Attribute::builtin()
+    }
+
+    fn init(context: &mut Context) -> (&'static str, JsValue, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_set_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::readonly_builtin(),
+        )
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .build();
+
+        (Self::NAME, weak_set_object.into(), Self::attribute())
+    }
+}
+
+impl WeakSet {
+    /// The amount of arguments the `WeakSet` constructor takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakSet ( [ iterable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakSet constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let set be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakSet.prototype%", « [[WeakSetData]] »).
+        let weak_set_prototype = context.standard_objects().weak_set_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.__get__(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(weak_set_prototype);
+
+        let set = context.construct_object();
+        set.set_prototype_instance(prototype.into());
+
+        // 3. Set set.[[WeakSetData]] to a new empty List.
+        set.borrow_mut().data = ObjectData::weak_set();
+        let set = JsValue::new(set);
+
+        // 4. If iterable is either undefined or null, return set.
+        let iterable = args.get_or_undefined(0).clone();
+        if iterable.is_null_or_undefined() {
+            return Ok(set);
+        }
+
+        // 5. Let adder be ? Get(set, "add").
+        let adder = set.get_field("add", context)?;
+
+        // 6. If IsCallable(adder) is false, throw a TypeError exception.
+        if !adder.is_function() {
+            return context.throw_type_error("'add' of 'newTarget' is not a function");
+        }
+
+        // 7. Let iteratorRecord be ? GetIterator(iterable).
+        let iterator_record = get_iterator(context, iterable)?;
+
+        // 8. Repeat,
+        loop {
+            // a. Let next be ? IteratorStep(iteratorRecord).
+            // b. If next is false, return set.
+            // c. Let nextValue be ? IteratorValue(next).
+            let next_value = match iterator_record.step(context)? {
+                Some(value) => value,
+                None => return Ok(set),
+            };
+
+            // d. Let status be Call(adder, set, « nextValue »).
+            // e. IfAbruptCloseIterator(status, iteratorRecord).
+            if let Err(status) = context.call(&adder, &set, &[next_value]) {
+                return iterator_record.close(Err(status), context);
+            }
+        }
+    }
+
+    /// `WeakSet.prototype.add ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let set = Self::this_weak_set(this, "add", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(value) => {
+                value.borrow_mut().set_ephemeron(set, JsValue::undefined());
+                Ok(this.clone())
+            }
+            _ => context.throw_type_error("WeakSet value must be an object"),
+        }
+    }
+
+    /// `WeakSet.prototype.delete ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let set = Self::this_weak_set(this, "delete", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(value) => Ok(value.borrow_mut().remove_ephemeron(&set).into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `WeakSet.prototype.has ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let set = Self::this_weak_set(this, "has", context)?;
+
+        match args.get_or_undefined(0) {
+            JsValue::Object(value) => Ok(value.borrow().ephemeron(&set).is_some().into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// Returns a weak reference to the `WeakSet` object `this`, identifying it in the
+    /// ephemerons of its values, or throws a `TypeError` naming `method`.
+    fn this_weak_set(
+        this: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<WeakJsObject> {
+        match this {
+            JsValue::Object(set) if set.borrow().is_weak_set() => Ok(set.downgrade()),
+            _ => Err(context.construct_type_error(format!(
+                "WeakSet.prototype.{} called on a non-WeakSet",
+                method
+            ))),
+        }
+    }
+}
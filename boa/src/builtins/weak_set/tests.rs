@@ -0,0 +1,43 @@
+use crate::{forward, Context};
+
+#[test]
+fn add_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = {};
+        var b = {};
+        var set = new WeakSet([a]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.has(a)"), "true");
+    assert_eq!(forward(&mut context, "set.has(b)"), "false");
+    assert_eq!(forward(&mut context, "set.add(b) === set"), "true");
+    assert_eq!(forward(&mut context, "set.has(b)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(a)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(a)"), "false");
+    assert_eq!(forward(&mut context, "set.has(1)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(set)"),
+        "\"[object WeakSet]\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { set.add(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn values_are_weak() {
+    let mut context = Context::new();
+    let init = r#"
+        var set = new WeakSet();
+        var value = {};
+        set.add(value);
+        var valueRef = new WeakRef(value);
+        value = undefined;
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    context.collect_garbage();
+    assert_eq!(forward(&mut context, "valueRef.deref()"), "undefined");
+}
@@ -1,13 +1,23 @@
 use crate::{
+    builtins::function::FunctionFlags,
+    exec::{is_anonymous_function_definition, BindingKind},
     syntax::ast::{
-        node::{Declaration, GetConstField, GetField, Identifier, StatementList},
+        node::{
+            declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
+            template::TemplateElement,
+            AssignTarget, Class, ClassElement, Declaration, DeclarationList, DeclarationPattern,
+            ExportDecl, FormalParameter, FunctionExpr, GetConstField, GetField, GetPrivateField,
+            Identifier, IterableLoopInitializer, MethodDefinitionKind, Optional, OptionalItem,
+            OptionalItemKind, PropertyDefinition, PropertyName, RcStatementList, Return,
+            StatementList,
+        },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
         Const, Node,
     },
-    vm::{CodeBlock, Opcode},
+    vm::{CodeBlock, CompletionType, FunctionCode, Opcode, PropertyCache},
     JsBigInt, JsString, JsValue,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Literal {
@@ -21,12 +31,47 @@ struct Label {
     index: u32,
 }
 
+/// The kind of statement targeted by `break` and `continue` statements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JumpControlKind {
+    Loop,
+    Switch,
+    Labelled,
+}
+
 #[derive(Debug, Clone)]
 struct JumpControlInfo {
     label: Option<Box<str>>,
-    start_address: u32,
-    is_loop: bool,
+    kind: JumpControlKind,
     breaks: Vec<Label>,
+    continues: Vec<Label>,
+
+    /// The number of scopes around the statement.
+    scope_depth: usize,
+}
+
+/// A scope of the code being compiled, which has to be left by the `break` and `continue`
+/// statements jumping out of it.
+#[derive(Debug)]
+enum Scope {
+    /// A block with its own environment, which is popped.
+    Environment,
+
+    /// A `try` block with a `catch` block, whose handler is removed.
+    Try,
+
+    /// A `try` or `catch` block with a `finally` block, which is run before the jump goes on.
+    /// The jumps to the `finally` block are patched once it is compiled.
+    Finally { jumps: Vec<Label> },
+
+    /// A `finally` block, whose pending completion is discarded.
+    Completion,
+
+    /// A `for...of` loop, whose iterator is closed.
+    ForOf { is_async: bool },
+
+    /// A `for...in` loop, whose iterator is dropped.
+    ForIn,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -34,7 +79,26 @@ enum Access<'a> {
     Variable { name: &'a Identifier },
     ByName { node: &'a GetConstField },
     ByValue { node: &'a GetField },
-    This,
+    Private { node: &'a GetPrivateField },
+}
+
+/// The property assigned to by a destructuring assignment, whose object, and key if it is
+/// computed, are on the stack.
+#[derive(Debug, Clone, Copy)]
+enum PropertyTarget {
+    ByName(u32),
+    ByValue,
+}
+
+impl PropertyTarget {
+    /// The number of values of the reference on the stack.
+    #[inline]
+    fn len(self) -> u8 {
+        match self {
+            Self::ByName(_) => 1,
+            Self::ByValue => 2,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -43,6 +107,15 @@ pub struct ByteCompiler {
     literals_map: HashMap<Literal, u32>,
     names_map: HashMap<JsString, u32>,
     jump_info: Vec<JumpControlInfo>,
+
+    /// The scopes around the code being compiled, the innermost one last.
+    scopes: Vec<Scope>,
+
+    /// Whether the code being compiled can `await`.
+    is_async: bool,
+
+    /// Whether the code being compiled is the body of a generator, which can `yield`.
+    is_generator: bool,
 }
 
 impl Default for ByteCompiler {
@@ -62,7 +135,58 @@ impl ByteCompiler {
             literals_map: HashMap::new(),
             names_map: HashMap::new(),
             jump_info: Vec::new(),
+            scopes: Vec::new(),
+            is_async: false,
+            is_generator: false,
+        }
+    }
+
+    /// Compiles script code, which returns the value of the last statement evaluated.
+    pub fn compile_script(statement_list: &StatementList) -> CodeBlock {
+        let mut compiler = Self::new();
+        compiler.compile_statement_list(statement_list, true);
+        compiler.emit_opcode(Opcode::GetCompletion);
+        compiler.emit_opcode(Opcode::Return);
+        compiler.finish()
+    }
+
+    /// Compiles the body of a function.
+    ///
+    /// The environments, the parameters and the lexical declarations of the function are set up
+    /// by the call before the body runs. The body of an async function or of a generator is run
+    /// in a frame that is suspended at its `await` and `yield` expressions.
+    pub fn compile_function_body(body: &StatementList, flags: FunctionFlags) -> Rc<CodeBlock> {
+        let mut compiler = Self::new();
+        compiler.is_async = flags.is_async();
+        compiler.is_generator = flags.is_generator();
+        compiler.compile_statement_list(body, false);
+        compiler.emit_opcode(Opcode::PushUndefined);
+        compiler.emit_opcode(Opcode::Return);
+        Rc::new(compiler.finish())
+    }
+
+    /// Compiles a single expression, which returns its value.
+    pub fn compile_expression(expr: &Node) -> Rc<CodeBlock> {
+        let mut compiler = Self::new();
+        compiler.compile_expr(expr, true);
+        compiler.emit_opcode(Opcode::Return);
+        Rc::new(compiler.finish())
+    }
+
+    /// Compiles the statements of the body of a module, which are run like the body of an
+    /// async function, since they can `await`.
+    pub fn compile_module_body<'a, I>(items: I) -> Rc<CodeBlock>
+    where
+        I: IntoIterator<Item = &'a Node>,
+    {
+        let mut compiler = Self::new();
+        compiler.is_async = true;
+        for item in items {
+            compiler.compile_stmt(item, false);
         }
+        compiler.emit_opcode(Opcode::PushUndefined);
+        compiler.emit_opcode(Opcode::Return);
+        Rc::new(compiler.finish())
     }
 
     #[inline]
@@ -95,6 +219,44 @@ impl ByteCompiler {
         index
     }
 
+    /// Gets the index of the binding of the private name `#name`, which holds its symbol.
+    #[inline]
+    fn get_or_insert_private_name(&mut self, name: &str) -> u32 {
+        self.get_or_insert_name(&format!("#{}", name))
+    }
+
+    #[inline]
+    fn insert_node(&mut self, node: &Node) -> u32 {
+        let index = self.code_block.nodes.len() as u32;
+        self.code_block.nodes.push(node.clone());
+        index
+    }
+
+    /// Adds a function to the code block, compiling its body.
+    fn insert_function(
+        &mut self,
+        name: &str,
+        params: &[FormalParameter],
+        body: &[Node],
+        strict: bool,
+        flags: FunctionFlags,
+        source_text: Option<&str>,
+    ) -> u32 {
+        let body = RcStatementList::from(StatementList::from(body.to_vec()).with_strict(strict));
+        let code = Self::compile_function_body(&body, flags);
+
+        let index = self.code_block.functions.len() as u32;
+        self.code_block.functions.push(FunctionCode {
+            name: name.into(),
+            params: params.into(),
+            body,
+            flags,
+            source_text: source_text.map(JsString::from),
+            code,
+        });
+        index
+    }
+
     #[inline]
     fn next_opcode_location(&mut self) -> u32 {
         assert!(self.code_block.code.len() < u32::MAX as usize);
@@ -151,6 +313,14 @@ impl ByteCompiler {
         }
     }
 
+    /// Emits a property access by name, with its own inline cache.
+    #[inline]
+    fn emit_property_access(&mut self, opcode: Opcode, name_index: u32) {
+        let cache_index = self.code_block.property_caches.len() as u32;
+        self.code_block.property_caches.push(PropertyCache::new());
+        self.emit(opcode, &[name_index, cache_index]);
+    }
+
     #[inline]
     fn emit_push_literal(&mut self, liternal: Literal) {
         let index = self.get_or_insert_literal(liternal);
@@ -172,8 +342,9 @@ impl ByteCompiler {
         }
 
         // Check if the f64 value can fit in an i32.
+        // `-0` can't, since it would be pushed as `+0`.
         #[allow(clippy::float_cmp)]
-        if value as i32 as f64 == value {
+        if value as i32 as f64 == value && !(value == 0.0 && value.is_sign_negative()) {
             self.emit_push_integer(value as i32);
         } else {
             self.emit_opcode(Opcode::PushRational);
@@ -181,6 +352,83 @@ impl ByteCompiler {
         }
     }
 
+    /// Sets the completion value to `undefined`, if the value of the statement is used.
+    #[inline]
+    fn emit_empty_completion(&mut self, use_expr: bool) {
+        if use_expr {
+            self.emit_opcode(Opcode::PushUndefined);
+            self.emit_opcode(Opcode::SetCompletion);
+        }
+    }
+
+    #[inline]
+    fn push_environment(&mut self) {
+        self.emit_opcode(Opcode::PushDeclarativeEnvironment);
+        self.scopes.push(Scope::Environment);
+    }
+
+    #[inline]
+    fn pop_environment(&mut self) {
+        self.emit_opcode(Opcode::PopEnvironment);
+        let scope = self.scopes.pop();
+        assert!(matches!(scope, Some(Scope::Environment)));
+    }
+
+    /// Emits an instruction throwing a `SyntaxError` with the given message.
+    #[inline]
+    fn emit_throw_syntax_error(&mut self, message: &str) {
+        let index = self.get_or_insert_literal(Literal::String(message.into()));
+        self.emit(Opcode::ThrowSyntaxError, &[index]);
+    }
+
+    /// Replaces the `undefined` value on the stack with the value of `init`, if any.
+    #[inline]
+    fn emit_default(&mut self, init: Option<&Node>) {
+        if let Some(init) = init {
+            let skip = self.jump_with_custom_opcode(Opcode::JumpIfNotUndefined);
+            self.emit_opcode(Opcode::Pop);
+            self.compile_expr(init, true);
+            self.patch_jump(skip);
+        }
+    }
+
+    /// Binds `name` to the value on the stack.
+    #[inline]
+    fn emit_binding(&mut self, kind: BindingKind, name: &str) {
+        let index = self.get_or_insert_name(name);
+        match kind {
+            BindingKind::Var => self.emit(Opcode::DefInitVar, &[index]),
+            BindingKind::Let => self.emit(Opcode::DefInitLet, &[index]),
+            BindingKind::Const => self.emit(Opcode::DefInitConst, &[index]),
+            BindingKind::Assignment => {
+                self.emit(Opcode::SetName, &[index]);
+                self.emit_opcode(Opcode::Pop);
+            }
+        }
+    }
+
+    /// Closes the innermost iterator of the frame with the completion on the stack, which is
+    /// left on the stack.
+    fn emit_iterator_close_with_completion(&mut self, is_async: bool) {
+        let skip = self.jump_with_custom_opcode(Opcode::IteratorCallReturn);
+        if is_async {
+            self.emit_opcode(Opcode::Await);
+        } else {
+            self.emit_opcode(Opcode::PushZero);
+        }
+        self.emit_opcode(Opcode::IteratorCloseResult);
+        self.patch_jump(skip);
+    }
+
+    /// Closes the innermost iterator of the frame with a normal completion.
+    fn emit_iterator_close(&mut self, is_async: bool) {
+        self.emit_opcode(Opcode::PushUndefined);
+        self.emit_opcode(Opcode::PushZero);
+        self.emit_iterator_close_with_completion(is_async);
+        self.emit_opcode(Opcode::Pop);
+        self.emit_opcode(Opcode::Pop);
+    }
+
     #[inline]
     fn jump(&mut self) -> Label {
         let index = self.next_opcode_location();
@@ -203,6 +451,16 @@ impl ByteCompiler {
         Label { index }
     }
 
+    /// Emits an instruction with two jump targets, returning the label of each target.
+    #[inline]
+    fn jump_with_two_targets(&mut self, opcode: Opcode) -> (Label, Label) {
+        let index = self.next_opcode_location();
+        self.emit(opcode, &[Self::DUMMY_ADDRESS, Self::DUMMY_ADDRESS]);
+
+        // A label is patched right after its index, so the second one is one operand further.
+        (Label { index }, Label { index: index + 4 })
+    }
+
     #[inline]
     fn patch_jump_with_target(&mut self, label: Label, target: u32) {
         let Label { index } = label;
@@ -223,55 +481,90 @@ impl ByteCompiler {
     }
 
     #[inline]
-    fn push_loop_control_info(&mut self, label: Option<Box<str>>, start_address: u32) {
+    fn push_jump_control_info(&mut self, label: Option<&str>, kind: JumpControlKind) {
         self.jump_info.push(JumpControlInfo {
-            label,
-            start_address,
-            is_loop: true,
+            label: label.map(Into::into),
+            kind,
             breaks: Vec::new(),
+            continues: Vec::new(),
+            scope_depth: self.scopes.len(),
         })
     }
 
+    /// Patches the `continue` statements of the innermost loop to jump to `target`.
     #[inline]
-    fn pop_loop_control_info(&mut self) {
-        let loop_info = self.jump_info.pop().unwrap();
-
-        assert!(loop_info.is_loop);
+    fn patch_continues(&mut self, target: u32) {
+        let info = self.jump_info.last_mut().expect("jump control info");
+        assert_eq!(info.kind, JumpControlKind::Loop);
 
-        for label in loop_info.breaks {
-            self.patch_jump(label);
+        for label in std::mem::take(&mut info.continues) {
+            self.patch_jump_with_target(label, target);
         }
     }
 
+    /// Pops the innermost jump control info, patching its `break` statements to jump here.
     #[inline]
-    fn push_switch_control_info(&mut self, label: Option<Box<str>>, start_address: u32) {
-        self.jump_info.push(JumpControlInfo {
-            label,
-            start_address,
-            is_loop: false,
-            breaks: Vec::new(),
-        })
-    }
-
-    #[inline]
-    fn pop_switch_control_info(&mut self) {
-        let info = self.jump_info.pop().unwrap();
-
-        assert!(!info.is_loop);
+    fn pop_jump_control_info(&mut self) {
+        let info = self.jump_info.pop().expect("jump control info");
 
+        assert!(info.continues.is_empty());
         for label in info.breaks {
             self.patch_jump(label);
         }
     }
 
-    #[inline]
-    fn compile_access<'a>(&mut self, node: &'a Node) -> Access<'a> {
-        match node {
-            Node::Identifier(name) => Access::Variable { name },
-            Node::GetConstField(node) => Access::ByName { node },
-            Node::GetField(node) => Access::ByValue { node },
-            Node::This => Access::This,
-            _ => unreachable!(),
+    /// Returns the index of the jump control info targeted by a `break` or `continue` statement.
+    ///
+    /// The parser rejects the statements that target nothing around them.
+    fn jump_target(&mut self, label: Option<&str>, is_break: bool) -> usize {
+        self.jump_info
+            .iter()
+            .rposition(|info| match label {
+                Some(label) if info.label.as_deref() != Some(label) => false,
+                _ if !is_break => info.kind == JumpControlKind::Loop,
+                Some(_) => true,
+                None => info.kind != JumpControlKind::Labelled,
+            })
+            .expect("`break` or `continue` statement without a target")
+    }
+
+    /// Emits a jump to the statement with the given jump control info, leaving the scopes
+    /// between them, the innermost one first.
+    fn jump_to_control_info(&mut self, index: usize, is_break: bool) {
+        let scope_depth = self.jump_info[index].scope_depth;
+        for scope_index in (scope_depth..self.scopes.len()).rev() {
+            match self.scopes[scope_index] {
+                Scope::Environment => self.emit_opcode(Opcode::PopEnvironment),
+                Scope::Try => self.emit_opcode(Opcode::TryEnd),
+                // The `finally` block runs with the jump as its completion, which jumps back
+                // here once the block ends.
+                Scope::Finally { .. } => {
+                    self.emit_opcode(Opcode::TryEnd);
+                    let resume = self.jump_with_custom_opcode(Opcode::PushJumpCompletion);
+                    let finally = self.jump();
+                    self.patch_jump(resume);
+                    if let Scope::Finally { jumps } = &mut self.scopes[scope_index] {
+                        jumps.push(finally);
+                    }
+                }
+                Scope::Completion => {
+                    self.emit_opcode(Opcode::Pop);
+                    self.emit_opcode(Opcode::Pop);
+                }
+                Scope::ForOf { is_async } => {
+                    self.emit_opcode(Opcode::TryEnd);
+                    self.emit_iterator_close(is_async);
+                }
+                Scope::ForIn => self.emit_opcode(Opcode::IteratorPop),
+            }
+        }
+
+        let label = self.jump();
+        let info = &mut self.jump_info[index];
+        if is_break {
+            info.breaks.push(label);
+        } else {
+            info.continues.push(label);
         }
     }
 
@@ -285,15 +578,17 @@ impl ByteCompiler {
             Access::ByName { node } => {
                 let index = self.get_or_insert_name(node.field());
                 self.compile_expr(node.obj(), true);
-                self.emit(Opcode::GetPropertyByName, &[index]);
+                self.emit_property_access(Opcode::GetPropertyByName, index);
             }
             Access::ByValue { node } => {
-                self.compile_expr(node.field(), true);
                 self.compile_expr(node.obj(), true);
+                self.compile_expr(node.field(), true);
                 self.emit(Opcode::GetPropertyByValue, &[]);
             }
-            Access::This => {
-                self.emit(Opcode::This, &[]);
+            Access::Private { node } => {
+                let index = self.get_or_insert_private_name(node.field());
+                self.compile_expr(node.obj(), true);
+                self.emit(Opcode::GetPrivateField, &[index]);
             }
         }
 
@@ -303,43 +598,192 @@ impl ByteCompiler {
     }
 
     #[inline]
-    fn access_set(&mut self, access: Access<'_>, expr: Option<&Node>, use_expr: bool) {
-        if let Some(expr) = expr {
-            self.compile_expr(expr, true);
-        }
-
-        if use_expr {
-            self.emit(Opcode::Dup, &[]);
-        }
-
+    fn access_set(&mut self, access: Access<'_>, expr: &Node, use_expr: bool) {
         match access {
             Access::Variable { name } => {
+                self.compile_expr(expr, true);
                 let index = self.get_or_insert_name(name.as_ref());
                 self.emit(Opcode::SetName, &[index]);
             }
             Access::ByName { node } => {
                 self.compile_expr(node.obj(), true);
+                self.compile_expr(expr, true);
                 let index = self.get_or_insert_name(node.field());
-                self.emit(Opcode::SetPropertyByName, &[index]);
+                self.emit_property_access(Opcode::SetPropertyByName, index);
             }
             Access::ByValue { node } => {
-                self.compile_expr(node.field(), true);
                 self.compile_expr(node.obj(), true);
+                self.compile_expr(node.field(), true);
+                self.compile_expr(expr, true);
                 self.emit(Opcode::SetPropertyByValue, &[]);
             }
-            Access::This => todo!("access_set 'this'"),
+            Access::Private { node } => {
+                self.compile_expr(node.obj(), true);
+                self.compile_expr(expr, true);
+                let index = self.get_or_insert_private_name(node.field());
+                self.emit(Opcode::SetPrivateField, &[index]);
+            }
+        }
+
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
+        }
+    }
+
+    /// Compiles an update of the target of an assignment, like `a += 1` or `++a`, or throws a
+    /// `SyntaxError` if the target is not a simple assignment target.
+    ///
+    /// The current value of the target is pushed above its reference before `update` is
+    /// called with the number of values of the reference, and `update` has to replace it with
+    /// the new value. If `update` returns a label, it can jump to it with the current value on
+    /// the stack to short-circuit the assignment.
+    fn access_update<F>(&mut self, target: &Node, use_expr: bool, update: F)
+    where
+        F: FnOnce(&mut Self, u8) -> Option<Label>,
+    {
+        match target {
+            Node::Identifier(name) => {
+                let index = self.get_or_insert_name(name.as_ref());
+                self.emit(Opcode::GetName, &[index]);
+                let exit = update(self, 0);
+                self.emit(Opcode::SetName, &[index]);
+                if let Some(exit) = exit {
+                    self.patch_jump(exit);
+                }
+            }
+            Node::GetConstField(node) => {
+                let index = self.get_or_insert_name(node.field());
+                self.compile_expr(node.obj(), true);
+                self.emit_opcode(Opcode::Dup);
+                self.emit_property_access(Opcode::GetPropertyByName, index);
+                let exit = update(self, 1);
+                self.emit_property_access(Opcode::SetPropertyByName, index);
+                if let Some(exit) = exit {
+                    let end = self.jump();
+                    self.patch_jump(exit);
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    self.patch_jump(end);
+                }
+            }
+            Node::GetField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::Dup2);
+                self.emit_opcode(Opcode::GetPropertyByValue);
+                let exit = update(self, 2);
+                self.emit_opcode(Opcode::SetPropertyByValue);
+                if let Some(exit) = exit {
+                    let end = self.jump();
+                    self.patch_jump(exit);
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    self.patch_jump(end);
+                }
+            }
+            Node::GetPrivateField(node) => {
+                let index = self.get_or_insert_private_name(node.field());
+                self.compile_expr(node.obj(), true);
+                self.emit_opcode(Opcode::Dup);
+                self.emit(Opcode::GetPrivateField, &[index]);
+                let exit = update(self, 1);
+                self.emit(Opcode::SetPrivateField, &[index]);
+                if let Some(exit) = exit {
+                    let end = self.jump();
+                    self.patch_jump(exit);
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    self.patch_jump(end);
+                }
+            }
+            Node::GetSuperField(node) => {
+                self.emit_opcode(Opcode::This);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::Dup2);
+                self.emit_opcode(Opcode::GetSuperProperty);
+                let exit = update(self, 2);
+                self.emit_opcode(Opcode::SetSuperProperty);
+                if let Some(exit) = exit {
+                    let end = self.jump();
+                    self.patch_jump(exit);
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    self.patch_jump(end);
+                }
+            }
+            // The parser only allows simple assignment targets.
+            _ => self.emit_throw_syntax_error("invalid assignment target"),
+        }
+
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
         }
     }
 
+    /// Compiles the statements of a statement list.
+    ///
+    /// If `use_expr` is `true`, the completion value is set to the value of the statements.
     #[inline]
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool) {
-        for (i, node) in list.items().iter().enumerate() {
-            if i + 1 == list.items().len() {
-                self.compile_stmt(node, use_expr);
-                break;
+        for node in list.items() {
+            self.compile_stmt(node, use_expr);
+        }
+    }
+
+    /// Creates the uninitialized bindings of the `let` and `const` declarations directly
+    /// contained in `items`, returning `false` if there are none.
+    fn compile_lexical_declarations(&mut self, items: &[Node]) -> bool {
+        let mut has_declarations = false;
+        for item in items {
+            let (list, opcode) = match item {
+                Node::LetDeclList(list) => (list, Opcode::DefLet),
+                Node::ConstDeclList(list) => (list, Opcode::DefConst),
+                Node::ClassDecl(class) => {
+                    let index = self.get_or_insert_name(class.name().unwrap_or("*default*"));
+                    self.emit(Opcode::DefLet, &[index]);
+                    has_declarations = true;
+                    continue;
+                }
+                _ => continue,
+            };
+            for decl in list.as_ref() {
+                let names = match decl {
+                    Declaration::Identifier { ident, .. } => vec![ident.as_ref()],
+                    Declaration::Pattern(pattern) => pattern.idents(),
+                };
+                for name in names {
+                    let index = self.get_or_insert_name(name);
+                    self.emit(opcode, &[index]);
+                    has_declarations = true;
+                }
             }
+        }
+        has_declarations
+    }
+
+    /// Compiles a block, in its own environment if it has lexical declarations.
+    fn compile_block(&mut self, items: &[Node], use_expr: bool) {
+        let has_declarations = items.iter().any(|item| {
+            matches!(
+                item,
+                Node::LetDeclList(_) | Node::ConstDeclList(_) | Node::ClassDecl(_)
+            )
+        });
+        if has_declarations {
+            self.push_environment();
+            self.compile_lexical_declarations(items);
+        }
 
-            self.compile_stmt(node, false);
+        for item in items {
+            self.compile_stmt(item, use_expr);
+        }
+
+        if has_declarations {
+            self.pop_environment();
         }
     }
 
@@ -351,7 +795,9 @@ impl ByteCompiler {
                     Const::String(v) => self.emit_push_literal(Literal::String(v.as_ref().into())),
                     Const::Int(v) => self.emit_push_integer(*v),
                     Const::Num(v) => self.emit_push_rational(*v),
-                    Const::BigInt(v) => self.emit_push_literal(Literal::BigInt(v.clone())),
+                    Const::BigInt(v) => {
+                        self.emit_push_literal(Literal::BigInt(JsBigInt::new(v.clone())))
+                    }
                     Const::Bool(true) => self.emit(Opcode::PushTrue, &[]),
                     Const::Bool(false) => self.emit(Opcode::PushFalse, &[]),
                     Const::Null => self.emit(Opcode::PushNull, &[]),
@@ -364,167 +810,217 @@ impl ByteCompiler {
             }
             Node::UnaryOp(unary) => {
                 let opcode = match unary.op() {
-                    UnaryOp::IncrementPre => todo!(),
-                    UnaryOp::DecrementPre => todo!(),
-                    UnaryOp::IncrementPost => todo!(),
-                    UnaryOp::DecrementPost => todo!(),
-                    UnaryOp::Delete => todo!(),
-                    UnaryOp::Minus => Some(Opcode::Neg),
-                    UnaryOp::Plus => Some(Opcode::Pos),
-                    UnaryOp::Not => Some(Opcode::LogicalNot),
-                    UnaryOp::Tilde => Some(Opcode::BitNot),
-                    UnaryOp::TypeOf => Some(Opcode::TypeOf),
-                    UnaryOp::Void => Some(Opcode::Void),
-                };
-
-                if let Some(opcode) = opcode {
-                    self.compile_expr(unary.target(), true);
-                    self.emit(opcode, &[]);
-
-                    if !use_expr {
-                        self.emit(Opcode::Pop, &[]);
+                    UnaryOp::IncrementPre | UnaryOp::DecrementPre => {
+                        let opcode = if unary.op() == UnaryOp::IncrementPre {
+                            Opcode::Inc
+                        } else {
+                            Opcode::Dec
+                        };
+                        self.access_update(unary.target(), use_expr, |compiler, _| {
+                            compiler.emit_opcode(opcode);
+                            None
+                        });
+                        return;
                     }
-                }
-            }
-            Node::BinOp(binary) => {
-                self.compile_expr(binary.lhs(), true);
-                match binary.op() {
-                    BinOp::Num(op) => {
-                        self.compile_expr(binary.rhs(), true);
-                        match op {
-                            NumOp::Add => self.emit_opcode(Opcode::Add),
-                            NumOp::Sub => self.emit_opcode(Opcode::Sub),
-                            NumOp::Div => self.emit_opcode(Opcode::Div),
-                            NumOp::Mul => self.emit_opcode(Opcode::Mul),
-                            NumOp::Exp => self.emit_opcode(Opcode::Pow),
-                            NumOp::Mod => self.emit_opcode(Opcode::Mod),
-                        }
-
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
+                    UnaryOp::IncrementPost | UnaryOp::DecrementPost => {
+                        let opcode = if unary.op() == UnaryOp::IncrementPost {
+                            Opcode::Inc
+                        } else {
+                            Opcode::Dec
+                        };
+                        // The old value is kept below the reference of the target, so that it
+                        // is left on the stack once the new value is popped.
+                        self.access_update(unary.target(), use_expr, |compiler, len| {
+                            if use_expr {
+                                compiler.emit_opcode(Opcode::Pos);
+                                compiler.emit_opcode(Opcode::Dup);
+                                if len > 0 {
+                                    compiler.emit_opcode(Opcode::RotateRight);
+                                    compiler.emit_u8(len + 2);
+                                }
+                            }
+                            compiler.emit_opcode(opcode);
+                            None
+                        });
+                        if use_expr {
+                            self.emit_opcode(Opcode::Pop);
                         }
+                        return;
                     }
-                    BinOp::Bit(op) => {
-                        self.compile_expr(binary.rhs(), true);
-                        match op {
-                            BitOp::And => self.emit_opcode(Opcode::BitAnd),
-                            BitOp::Or => self.emit_opcode(Opcode::BitOr),
-                            BitOp::Xor => self.emit_opcode(Opcode::BitXor),
-                            BitOp::Shl => self.emit_opcode(Opcode::ShiftLeft),
-                            BitOp::Shr => self.emit_opcode(Opcode::ShiftRight),
-                            BitOp::UShr => self.emit_opcode(Opcode::UnsignedShiftRight),
-                        }
-
+                    UnaryOp::Delete => {
+                        self.compile_delete(unary.target(), unary.strict());
                         if !use_expr {
                             self.emit(Opcode::Pop, &[]);
                         }
+                        return;
                     }
-                    BinOp::Comp(op) => {
-                        self.compile_expr(binary.rhs(), true);
-                        match op {
-                            CompOp::Equal => self.emit_opcode(Opcode::Eq),
-                            CompOp::NotEqual => self.emit_opcode(Opcode::NotEq),
-                            CompOp::StrictEqual => self.emit_opcode(Opcode::StrictEq),
-                            CompOp::StrictNotEqual => self.emit_opcode(Opcode::StrictNotEq),
-                            CompOp::GreaterThan => self.emit_opcode(Opcode::GreaterThan),
-                            CompOp::GreaterThanOrEqual => self.emit_opcode(Opcode::GreaterThanOrEq),
-                            CompOp::LessThan => self.emit_opcode(Opcode::LessThan),
-                            CompOp::LessThanOrEqual => self.emit_opcode(Opcode::LessThanOrEq),
-                            CompOp::In => self.emit_opcode(Opcode::In),
-                            CompOp::InstanceOf => self.emit_opcode(Opcode::InstanceOf),
-                        }
+                    UnaryOp::Minus => Opcode::Neg,
+                    UnaryOp::Plus => Opcode::Pos,
+                    UnaryOp::Not => Opcode::LogicalNot,
+                    UnaryOp::Tilde => Opcode::BitNot,
+                    UnaryOp::TypeOf => Opcode::TypeOf,
+                    UnaryOp::Void => Opcode::Void,
+                };
 
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
-                    }
-                    BinOp::Log(op) => {
-                        match op {
-                            LogOp::And => {
-                                let exit = self.jump_with_custom_opcode(Opcode::LogicalAnd);
-                                self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
-                                self.patch_jump(exit);
-                            }
-                            LogOp::Or => {
-                                let exit = self.jump_with_custom_opcode(Opcode::LogicalOr);
-                                self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
-                                self.patch_jump(exit);
-                            }
-                            LogOp::Coalesce => {
-                                let exit = self.jump_with_custom_opcode(Opcode::Coalesce);
-                                self.compile_expr(binary.rhs(), true);
-                                self.patch_jump(exit);
-                            }
-                        };
+                self.compile_expr(unary.target(), true);
+                self.emit(opcode, &[]);
 
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::BinOp(binary) => match binary.op() {
+                BinOp::Num(op) => {
+                    self.compile_expr(binary.lhs(), true);
+                    self.compile_expr(binary.rhs(), true);
+                    match op {
+                        NumOp::Add => self.emit_opcode(Opcode::Add),
+                        NumOp::Sub => self.emit_opcode(Opcode::Sub),
+                        NumOp::Div => self.emit_opcode(Opcode::Div),
+                        NumOp::Mul => self.emit_opcode(Opcode::Mul),
+                        NumOp::Exp => self.emit_opcode(Opcode::Pow),
+                        NumOp::Mod => self.emit_opcode(Opcode::Mod),
                     }
-                    BinOp::Assign(op) => {
-                        let opcode = match op {
-                            AssignOp::Add => Some(Opcode::Add),
-                            AssignOp::Sub => Some(Opcode::Sub),
-                            AssignOp::Mul => Some(Opcode::Mul),
-                            AssignOp::Div => Some(Opcode::Div),
-                            AssignOp::Mod => Some(Opcode::Mod),
-                            AssignOp::Exp => Some(Opcode::Pow),
-                            AssignOp::And => Some(Opcode::BitAnd),
-                            AssignOp::Or => Some(Opcode::BitOr),
-                            AssignOp::Xor => Some(Opcode::BitXor),
-                            AssignOp::Shl => Some(Opcode::ShiftLeft),
-                            AssignOp::Shr => Some(Opcode::ShiftRight),
-                            AssignOp::Ushr => Some(Opcode::UnsignedShiftRight),
-                            AssignOp::BoolAnd => {
-                                let exit = self.jump_with_custom_opcode(Opcode::LogicalAnd);
-                                self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
-                                self.patch_jump(exit);
-
-                                None
-                            }
-                            AssignOp::BoolOr => {
-                                let exit = self.jump_with_custom_opcode(Opcode::LogicalOr);
-                                self.compile_expr(binary.rhs(), true);
-                                self.emit(Opcode::ToBoolean, &[]);
-                                self.patch_jump(exit);
-
-                                None
-                            }
-                            AssignOp::Coalesce => {
-                                let exit = self.jump_with_custom_opcode(Opcode::Coalesce);
-                                self.compile_expr(binary.rhs(), true);
-                                self.patch_jump(exit);
-
-                                None
-                            }
-                        };
 
-                        if let Some(opcode) = opcode {
-                            self.compile_expr(binary.rhs(), true);
-                            self.emit(opcode, &[]);
-                        }
+                    if !use_expr {
+                        self.emit(Opcode::Pop, &[]);
+                    }
+                }
+                BinOp::Bit(op) => {
+                    self.compile_expr(binary.lhs(), true);
+                    self.compile_expr(binary.rhs(), true);
+                    match op {
+                        BitOp::And => self.emit_opcode(Opcode::BitAnd),
+                        BitOp::Or => self.emit_opcode(Opcode::BitOr),
+                        BitOp::Xor => self.emit_opcode(Opcode::BitXor),
+                        BitOp::Shl => self.emit_opcode(Opcode::ShiftLeft),
+                        BitOp::Shr => self.emit_opcode(Opcode::ShiftRight),
+                        BitOp::UShr => self.emit_opcode(Opcode::UnsignedShiftRight),
+                    }
 
-                        let access = self.compile_access(binary.lhs());
-                        self.access_set(access, None, use_expr);
+                    if !use_expr {
+                        self.emit(Opcode::Pop, &[]);
+                    }
+                }
+                BinOp::Comp(op) => {
+                    self.compile_expr(binary.lhs(), true);
+                    self.compile_expr(binary.rhs(), true);
+                    match op {
+                        CompOp::Equal => self.emit_opcode(Opcode::Eq),
+                        CompOp::NotEqual => self.emit_opcode(Opcode::NotEq),
+                        CompOp::StrictEqual => self.emit_opcode(Opcode::StrictEq),
+                        CompOp::StrictNotEqual => self.emit_opcode(Opcode::StrictNotEq),
+                        CompOp::GreaterThan => self.emit_opcode(Opcode::GreaterThan),
+                        CompOp::GreaterThanOrEqual => self.emit_opcode(Opcode::GreaterThanOrEq),
+                        CompOp::LessThan => self.emit_opcode(Opcode::LessThan),
+                        CompOp::LessThanOrEqual => self.emit_opcode(Opcode::LessThanOrEq),
+                        CompOp::In => self.emit_opcode(Opcode::In),
+                        CompOp::InstanceOf => self.emit_opcode(Opcode::InstanceOf),
                     }
-                    BinOp::Comma => {
+
+                    if !use_expr {
                         self.emit(Opcode::Pop, &[]);
-                        self.compile_expr(binary.rhs(), true);
+                    }
+                }
+                BinOp::Log(op) => {
+                    self.compile_expr(binary.lhs(), true);
+                    let opcode = match op {
+                        LogOp::And => Opcode::LogicalAnd,
+                        LogOp::Or => Opcode::LogicalOr,
+                        LogOp::Coalesce => Opcode::Coalesce,
+                    };
+                    let exit = self.jump_with_custom_opcode(opcode);
+                    self.compile_expr(binary.rhs(), true);
+                    self.patch_jump(exit);
 
-                        if !use_expr {
-                            self.emit(Opcode::Pop, &[]);
-                        }
+                    if !use_expr {
+                        self.emit(Opcode::Pop, &[]);
                     }
                 }
-            }
+                BinOp::Assign(op) => {
+                    let opcode = match op {
+                        AssignOp::Add => Opcode::Add,
+                        AssignOp::Sub => Opcode::Sub,
+                        AssignOp::Mul => Opcode::Mul,
+                        AssignOp::Div => Opcode::Div,
+                        AssignOp::Mod => Opcode::Mod,
+                        AssignOp::Exp => Opcode::Pow,
+                        AssignOp::And => Opcode::BitAnd,
+                        AssignOp::Or => Opcode::BitOr,
+                        AssignOp::Xor => Opcode::BitXor,
+                        AssignOp::Shl => Opcode::ShiftLeft,
+                        AssignOp::Shr => Opcode::ShiftRight,
+                        AssignOp::Ushr => Opcode::UnsignedShiftRight,
+                        AssignOp::BoolAnd => Opcode::LogicalAnd,
+                        AssignOp::BoolOr => Opcode::LogicalOr,
+                        AssignOp::Coalesce => Opcode::Coalesce,
+                    };
+                    let short_circuits = matches!(
+                        op,
+                        AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce
+                    );
+
+                    let rhs = binary.rhs();
+                    self.access_update(binary.lhs(), use_expr, |compiler, _| {
+                        if short_circuits {
+                            let exit = compiler.jump_with_custom_opcode(opcode);
+                            compiler.compile_expr(rhs, true);
+                            Some(exit)
+                        } else {
+                            compiler.compile_expr(rhs, true);
+                            compiler.emit_opcode(opcode);
+                            None
+                        }
+                    });
+                }
+                BinOp::Comma => {
+                    self.compile_expr(binary.lhs(), false);
+                    self.compile_expr(binary.rhs(), use_expr);
+                }
+            },
             Node::Object(object) => {
-                if object.properties().is_empty() {
-                    self.emit(Opcode::PushEmptyObject, &[]);
-                } else {
-                    todo!("object literal with properties");
+                self.emit(Opcode::PushEmptyObject, &[]);
+                for property in object.properties() {
+                    match property {
+                        PropertyDefinition::IdentifierReference(name) => {
+                            let index = self.get_or_insert_name(name);
+                            self.emit(Opcode::GetName, &[index]);
+                            self.emit(Opcode::DefineOwnPropertyByName, &[index]);
+                        }
+                        PropertyDefinition::Property(name, value) => {
+                            self.compile_expr(value, true);
+                            let index = self.get_or_insert_name(name);
+                            self.emit(Opcode::DefineOwnPropertyByName, &[index]);
+                        }
+                        PropertyDefinition::MethodDefinition(kind, name, function) => {
+                            let index = self.insert_function(
+                                name,
+                                function.parameters(),
+                                function.body(),
+                                function.strict(),
+                                FunctionFlags::CONSTRUCTABLE,
+                                function.source_text(),
+                            );
+                            self.emit(Opcode::GetFunction, &[index]);
+
+                            let index = self.get_or_insert_name(name);
+                            let opcode = match kind {
+                                MethodDefinitionKind::Get => Opcode::SetGetterByName,
+                                MethodDefinitionKind::Set => Opcode::SetSetterByName,
+                                MethodDefinitionKind::Ordinary => Opcode::DefineMethodByName,
+                            };
+                            self.emit(opcode, &[index]);
+                        }
+                        PropertyDefinition::SpreadObject(node) => {
+                            self.compile_expr(node, true);
+                            self.emit(Opcode::CopyDataProperties, &[0]);
+                        }
+                        // A shorthand property with an initializer is only valid as the target
+                        // of a destructuring assignment.
+                        PropertyDefinition::CoverInitializedName(..) => {
+                            self.emit_throw_syntax_error("invalid shorthand property initializer");
+                        }
+                    }
                 }
 
                 if !use_expr {
@@ -536,8 +1032,32 @@ impl ByteCompiler {
                 self.access_get(access, use_expr);
             }
             Node::Assign(assign) => {
-                let access = self.compile_access(assign.lhs());
-                self.access_set(access, Some(assign.rhs()), use_expr);
+                let access = match assign.lhs() {
+                    AssignTarget::Identifier(name) => Access::Variable { name },
+                    AssignTarget::GetConstField(node) => Access::ByName { node },
+                    AssignTarget::GetField(node) => Access::ByValue { node },
+                    AssignTarget::GetPrivateField(node) => Access::Private { node },
+                    AssignTarget::GetSuperField(node) => {
+                        self.emit_opcode(Opcode::This);
+                        self.compile_expr(node.field(), true);
+                        self.compile_expr(assign.rhs(), true);
+                        self.emit_opcode(Opcode::SetSuperProperty);
+
+                        if !use_expr {
+                            self.emit(Opcode::Pop, &[]);
+                        }
+                        return;
+                    }
+                    AssignTarget::DeclarationPattern(pattern) => {
+                        self.compile_expr(assign.rhs(), true);
+                        if use_expr {
+                            self.emit_opcode(Opcode::Dup);
+                        }
+                        self.compile_pattern(pattern, BindingKind::Assignment);
+                        return;
+                    }
+                };
+                self.access_set(access, assign.rhs(), use_expr);
             }
             Node::GetConstField(node) => {
                 let access = Access::ByName { node };
@@ -547,6 +1067,19 @@ impl ByteCompiler {
                 let access = Access::ByValue { node };
                 self.access_get(access, use_expr);
             }
+            Node::GetPrivateField(node) => {
+                let access = Access::Private { node };
+                self.access_get(access, use_expr);
+            }
+            Node::PrivateIn(node) => {
+                self.compile_expr(node.target(), true);
+                let index = self.get_or_insert_private_name(node.name());
+                self.emit(Opcode::InPrivate, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
             Node::ConditionalOp(op) => {
                 self.compile_expr(op.cond(), true);
                 let jelse = self.jump_if_false();
@@ -561,114 +1094,1078 @@ impl ByteCompiler {
                 }
             }
             Node::ArrayDecl(array) => {
-                let mut count = 0;
-                for element in array.as_ref().iter().rev() {
-                    if let Node::Spread(_) = element {
-                        todo!("array with spread element");
-                    } else {
+                if array
+                    .as_ref()
+                    .iter()
+                    .any(|element| matches!(element, Node::Spread(_)))
+                {
+                    self.compile_spread_array(array.as_ref());
+                } else {
+                    for element in array.as_ref() {
                         self.compile_expr(element, true);
                     }
-                    count += 1;
+                    self.emit(Opcode::PushNewArray, &[array.as_ref().len() as u32]);
                 }
-                self.emit(Opcode::PushNewArray, &[count]);
 
                 if !use_expr {
                     self.emit(Opcode::Pop, &[]);
                 }
             }
-            Node::This => {
-                self.access_get(Access::This, use_expr);
+            Node::NewTarget => {
+                self.emit_opcode(Opcode::NewTarget);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
             }
-            expr => todo!("TODO compile: {}", expr),
-        }
-    }
+            Node::This => {
+                self.emit(Opcode::This, &[]);
 
-    #[inline]
-    pub fn compile_stmt(&mut self, node: &Node, use_expr: bool) {
-        match node {
-            Node::VarDeclList(list) => {
-                for decl in list.as_ref() {
-                    match decl {
-                        Declaration::Identifier { ident, .. } => {
-                            let index = self.get_or_insert_name(ident.as_ref());
-                            self.emit(Opcode::DefVar, &[index]);
-
-                            if let Some(expr) = decl.init() {
-                                self.compile_expr(expr, true);
-                                self.emit(Opcode::InitLexical, &[index]);
-                            };
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::Call(call) => {
+                self.compile_callee(call.expr());
+
+                // A call to `eval` may be a direct eval, which runs in the environment of the
+                // caller.
+                let is_eval =
+                    matches!(call.expr(), Node::Identifier(name) if name.as_ref() == "eval");
+                match (self.compile_arguments(call.args()), is_eval) {
+                    (Some(count), false) => self.emit(Opcode::Call, &[count]),
+                    (Some(count), true) => self.emit(Opcode::CallEval, &[count]),
+                    (None, false) => self.emit_opcode(Opcode::CallSpread),
+                    (None, true) => self.emit_opcode(Opcode::CallEvalSpread),
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::SuperCall(call) => {
+                // The parent class is looked up before the arguments are evaluated.
+                self.emit_opcode(Opcode::SuperConstructor);
+                match self.compile_arguments(call.args()) {
+                    Some(count) => self.emit(Opcode::SuperCall, &[count]),
+                    None => self.emit_opcode(Opcode::SuperCallSpread),
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::New(new) => {
+                self.compile_expr(new.expr(), true);
+                let count = self.compile_arguments(new.args());
+                let index = self.get_or_insert_name(&new.expr().to_string());
+                match count {
+                    Some(count) => self.emit(Opcode::New, &[count, index]),
+                    None => self.emit(Opcode::NewSpread, &[index]),
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::FunctionExpr(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or(""),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::CONSTRUCTABLE,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::ClassExpr(class) => {
+                self.compile_class(class);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::ArrowFunctionDecl(function) => {
+                let index = self.insert_function(
+                    "",
+                    function.params(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::LEXICAL_THIS_MODE,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::AsyncFunctionExpr(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or(""),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::ASYNC,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::GeneratorExpr(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or(""),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::GENERATOR,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::AsyncGeneratorExpr(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or(""),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::ASYNC | FunctionFlags::GENERATOR,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::GetSuperField(node) => {
+                self.emit_opcode(Opcode::This);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::GetSuperProperty);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::Optional(optional) => {
+                self.compile_optional(optional, None);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::TemplateLit(template) => {
+                for element in template.elements() {
+                    match element {
+                        TemplateElement::String(s) => {
+                            self.emit_push_literal(Literal::String(s.as_ref().into()))
                         }
-                        Declaration::Pattern(pattern) => {
-                            for ident in pattern.idents() {
-                                let index = self.get_or_insert_name(ident);
-                                self.emit(Opcode::DefVar, &[index]);
-
-                                if let Some(expr) = decl.init() {
-                                    self.compile_expr(expr, true);
-                                    self.emit(Opcode::InitLexical, &[index]);
-                                };
-                            }
+                        TemplateElement::Expr(expr) => self.compile_expr(expr, true),
+                    }
+                }
+                self.emit(Opcode::ConcatToString, &[template.elements().len() as u32]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::TaggedTemplate(template) => {
+                // The template object is the first argument of the tag, followed by the values
+                // of the substitutions.
+                self.compile_callee(template.tag());
+                let index = self.insert_node(expr);
+                self.emit(Opcode::GetTemplateObject, &[index]);
+                for expr in template.exprs() {
+                    self.compile_expr(expr, true);
+                }
+                self.emit(Opcode::Call, &[template.exprs().len() as u32 + 1]);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::ImportCall(import) => {
+                self.compile_expr(import.specifier(), true);
+                self.emit_opcode(Opcode::ImportCall);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::AwaitExpr(await_expr) => {
+                self.compile_expr(await_expr.expr(), true);
+                self.emit_opcode(Opcode::Await);
+                self.emit_opcode(Opcode::ResumeCompletion);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::YieldExpr(yield_expr) => {
+                match yield_expr.expr() {
+                    Some(expr) => self.compile_expr(expr, true),
+                    None => self.emit_opcode(Opcode::PushUndefined),
+                }
+
+                if yield_expr.delegate() {
+                    self.compile_yield_delegate();
+                } else if self.is_async {
+                    // An async generator awaits the values it yields, and the values it is
+                    // resumed to return with.
+                    self.emit_opcode(Opcode::Await);
+                    self.emit_opcode(Opcode::ResumeCompletion);
+                    self.emit_opcode(Opcode::Yield);
+                    let resume = self.jump_with_custom_opcode(Opcode::JumpIfNotCompletionType);
+                    self.emit_u8(CompletionType::Return as u8);
+                    self.emit_opcode(Opcode::Pop);
+                    self.emit_opcode(Opcode::Await);
+                    self.emit_opcode(Opcode::ResumeCompletion);
+                    self.emit_opcode(Opcode::Return);
+                    self.patch_jump(resume);
+                    self.emit_opcode(Opcode::ResumeCompletion);
+                } else {
+                    self.emit_opcode(Opcode::CreateIteratorResult);
+                    self.emit_opcode(Opcode::Yield);
+                    self.emit_opcode(Opcode::ResumeCompletion);
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            // Statements and spread elements are never parsed as expressions.
+            expr => unreachable!("{:?} is not an expression", expr),
+        }
+    }
+
+    /// Compiles the callee of a call, pushing the `this` value of the call below the function.
+    fn compile_callee(&mut self, callee: &Node) {
+        match callee {
+            // A primitive base is the `this` value of the call as is, only strict functions can
+            // tell it apart from its wrapper object.
+            Node::GetConstField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.emit_opcode(Opcode::Dup);
+                let index = self.get_or_insert_name(node.field());
+                self.emit_property_access(Opcode::GetPropertyByName, index);
+            }
+            Node::GetField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.emit_opcode(Opcode::Dup);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::GetPropertyByValue);
+            }
+            Node::GetPrivateField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.emit_opcode(Opcode::Dup);
+                let index = self.get_or_insert_private_name(node.field());
+                self.emit(Opcode::GetPrivateField, &[index]);
+            }
+            Node::GetSuperField(node) => {
+                self.emit_opcode(Opcode::This);
+                self.emit_opcode(Opcode::Dup);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::GetSuperProperty);
+            }
+            Node::Identifier(name) => {
+                let index = self.get_or_insert_name(name.as_ref());
+                self.emit(Opcode::ImplicitThis, &[index]);
+                self.emit(Opcode::GetName, &[index]);
+            }
+            // The callee binds `this` itself, to the global `this` value if it is not strict.
+            callee => {
+                self.emit_opcode(Opcode::PushUndefined);
+                self.compile_expr(callee, true);
+            }
+        }
+    }
+
+    /// Compiles a class, pushing its constructor.
+    ///
+    /// The class is defined in its own environment, which binds the name of the class and the
+    /// private names it declares, like `#field`, each to a new symbol. The heritage of a
+    /// derived class is evaluated in this environment, before the private names are bound.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    fn compile_class(&mut self, class: &Class) {
+        self.push_environment();
+        if let Some(name) = class.name() {
+            let index = self.get_or_insert_name(name);
+            self.emit(Opcode::DefConst, &[index]);
+        }
+        if let Some(heritage) = class.heritage() {
+            self.compile_expr(heritage, true);
+        }
+
+        let mut private_names = Vec::new();
+        for element in class.elements() {
+            match element {
+                ClassElement::PrivateMethodDefinition(name, ..)
+                | ClassElement::PrivateStaticMethodDefinition(name, ..)
+                | ClassElement::PrivateFieldDefinition(name, _)
+                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                    // A getter and a setter share their private name.
+                    if !private_names.contains(&name) {
+                        private_names.push(name);
+                    }
+                }
+                ClassElement::MethodDefinition(..)
+                | ClassElement::StaticMethodDefinition(..)
+                | ClassElement::FieldDefinition(..)
+                | ClassElement::StaticFieldDefinition(..)
+                | ClassElement::StaticBlock(_) => {}
+            }
+        }
+        for name in private_names {
+            let index = self.get_or_insert_private_name(name);
+            self.emit(Opcode::DefPrivateName, &[index]);
+        }
+
+        let mut flags = FunctionFlags::CONSTRUCTABLE | FunctionFlags::CLASS_CONSTRUCTOR;
+        if class.heritage().is_some() {
+            flags |= FunctionFlags::DERIVED_CONSTRUCTOR;
+        }
+        let name = class.name().unwrap_or("");
+        let index = match class.constructor() {
+            Some(constructor) => self.insert_function(
+                name,
+                constructor.parameters(),
+                constructor.body(),
+                true,
+                flags,
+                class.source_text(),
+            ),
+            None if class.heritage().is_some() => {
+                self.insert_default_derived_constructor(name, flags, class.source_text())
+            }
+            None => self.insert_function(name, &[], &[], true, flags, class.source_text()),
+        };
+        self.emit(Opcode::GetFunction, &[index]);
+        self.emit_opcode(if class.heritage().is_some() {
+            Opcode::PushDerivedClassPrototype
+        } else {
+            Opcode::PushClassPrototype
+        });
+
+        for element in class.elements() {
+            match element {
+                ClassElement::MethodDefinition(name, kind, function) => {
+                    self.compile_property_name(name);
+                    self.compile_method(function);
+                    self.emit_opcode(match kind {
+                        MethodDefinitionKind::Get => Opcode::DefineClassGetter,
+                        MethodDefinitionKind::Set => Opcode::DefineClassSetter,
+                        MethodDefinitionKind::Ordinary => Opcode::DefineClassMethod,
+                    });
+                }
+                ClassElement::StaticMethodDefinition(name, kind, function) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.compile_property_name(name);
+                    self.compile_method(function);
+                    self.emit_opcode(match kind {
+                        MethodDefinitionKind::Get => Opcode::DefineClassGetter,
+                        MethodDefinitionKind::Set => Opcode::DefineClassSetter,
+                        MethodDefinitionKind::Ordinary => Opcode::DefineClassMethod,
+                    });
+                    self.emit_opcode(Opcode::Swap);
+                }
+                ClassElement::PrivateMethodDefinition(name, kind, function) => {
+                    self.compile_method(function);
+                    let index = self.get_or_insert_private_name(name);
+                    let opcode = match kind {
+                        MethodDefinitionKind::Get => Opcode::PushClassPrivateGetter,
+                        MethodDefinitionKind::Set => Opcode::PushClassPrivateSetter,
+                        MethodDefinitionKind::Ordinary => Opcode::PushClassPrivateMethod,
+                    };
+                    self.emit(opcode, &[index]);
+                }
+                ClassElement::PrivateStaticMethodDefinition(name, kind, function) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.compile_method(function);
+                    let index = self.get_or_insert_private_name(name);
+                    let opcode = match kind {
+                        MethodDefinitionKind::Get => Opcode::DefinePrivateGetter,
+                        MethodDefinitionKind::Set => Opcode::DefinePrivateSetter,
+                        MethodDefinitionKind::Ordinary => Opcode::DefinePrivateMethod,
+                    };
+                    self.emit(opcode, &[index]);
+                    self.emit_opcode(Opcode::Swap);
+                }
+                ClassElement::PrivateFieldDefinition(name, init) => {
+                    self.compile_field_initializer(init.as_ref());
+                    let index = self.get_or_insert_private_name(name);
+                    self.emit(Opcode::PushClassPrivateField, &[index]);
+                }
+                ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                    self.compile_field_initializer(init.as_ref());
+                    let index = self.get_or_insert_private_name(name);
+                    self.emit(Opcode::PushClassStaticPrivateField, &[index]);
+                }
+                ClassElement::FieldDefinition(name, init) => {
+                    self.compile_property_name(name);
+                    self.compile_field_initializer(init.as_ref());
+                    self.emit_opcode(Opcode::PushClassField);
+                }
+                ClassElement::StaticFieldDefinition(name, init) => {
+                    self.compile_property_name(name);
+                    self.compile_field_initializer(init.as_ref());
+                    self.emit_opcode(Opcode::PushClassStaticField);
+                }
+                ClassElement::StaticBlock(body) => {
+                    let index = self.insert_function(
+                        "",
+                        &[],
+                        body.items(),
+                        true,
+                        FunctionFlags::empty(),
+                        None,
+                    );
+                    self.emit(Opcode::GetFunction, &[index]);
+                    self.emit_opcode(Opcode::PushClassStaticBlock);
+                }
+            }
+        }
+        self.emit_opcode(Opcode::Pop);
+
+        if let Some(name) = class.name() {
+            self.emit_opcode(Opcode::Dup);
+            self.emit_binding(BindingKind::Const, name);
+        }
+
+        // The static fields and blocks are evaluated once the class is bound.
+        self.emit_opcode(Opcode::DefineClassStaticElements);
+        self.pop_environment();
+    }
+
+    /// Inserts the default constructor of a derived class, which passes its arguments to the
+    /// constructor of the parent class.
+    ///
+    /// It behaves like `constructor(...args) { super(...args); }`, without iterating the
+    /// arguments, which are taken from the `arguments` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    fn insert_default_derived_constructor(
+        &mut self,
+        name: &str,
+        flags: FunctionFlags,
+        source_text: Option<&str>,
+    ) -> u32 {
+        let mut compiler = Self::new();
+        let args = compiler.get_or_insert_name("arguments");
+        compiler.emit_opcode(Opcode::SuperConstructor);
+        compiler.emit(Opcode::GetName, &[args]);
+        compiler.emit_opcode(Opcode::SuperCallSpread);
+        compiler.emit_opcode(Opcode::Pop);
+        compiler.emit_opcode(Opcode::PushUndefined);
+        compiler.emit_opcode(Opcode::Return);
+
+        let index = self.code_block.functions.len() as u32;
+        self.code_block.functions.push(FunctionCode {
+            name: name.into(),
+            params: Box::new([]),
+            body: RcStatementList::from(StatementList::from(Vec::new()).with_strict(true)),
+            flags,
+            source_text: source_text.map(JsString::from),
+            code: Rc::new(compiler.finish()),
+        });
+        index
+    }
+
+    /// Pushes the key of a method or a field of a class.
+    fn compile_property_name(&mut self, name: &PropertyName) {
+        match name {
+            PropertyName::Literal(name) => {
+                self.emit_push_literal(Literal::String(name.as_ref().into()))
+            }
+            PropertyName::Computed(expr) => self.compile_expr(expr, true),
+        }
+    }
+
+    /// Pushes a method of a class, which is named when it is defined.
+    fn compile_method(&mut self, function: &FunctionExpr) {
+        let index = self.insert_function(
+            "",
+            function.parameters(),
+            function.body(),
+            true,
+            FunctionFlags::empty(),
+            function.source_text(),
+        );
+        self.emit(Opcode::GetFunction, &[index]);
+    }
+
+    /// Pushes the function evaluating the initializer of a field of a class, or `undefined` if
+    /// the field has no initializer.
+    ///
+    /// The initializer is called with the object the field is defined on as `this`.
+    fn compile_field_initializer(&mut self, init: Option<&Node>) {
+        match init {
+            Some(init) => {
+                let body = [Node::Return(Return::new(init.clone(), None))];
+                let index =
+                    self.insert_function("", &[], &body, true, FunctionFlags::empty(), None);
+                self.emit(Opcode::GetFunction, &[index]);
+            }
+            None => self.emit_opcode(Opcode::PushUndefined),
+        }
+    }
+
+    /// Compiles the arguments of a call, returning their number, or `None` if they are spread
+    /// into an array.
+    fn compile_arguments(&mut self, args: &[Node]) -> Option<u32> {
+        if args.iter().any(|arg| matches!(arg, Node::Spread(_))) {
+            self.compile_spread_array(args);
+            return None;
+        }
+
+        for arg in args {
+            self.compile_expr(arg, true);
+        }
+        Some(args.len() as u32)
+    }
+
+    /// Compiles elements with spread elements into an array.
+    fn compile_spread_array(&mut self, elements: &[Node]) {
+        self.emit(Opcode::PushNewArray, &[0]);
+        for element in elements {
+            if let Node::Spread(spread) = element {
+                self.compile_expr(spread.val(), true);
+                self.emit_opcode(Opcode::PushIteratorToArray);
+            } else {
+                self.compile_expr(element, true);
+                self.emit_opcode(Opcode::PushValueToArray);
+            }
+        }
+    }
+
+    /// Compiles a `delete` expression.
+    fn compile_delete(&mut self, target: &Node, strict: bool) {
+        match target {
+            Node::GetConstField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.emit_push_literal(Literal::String(node.field().into()));
+                self.emit_opcode(Opcode::DeletePropertyByValue);
+                self.emit_u8(strict as u8);
+            }
+            Node::GetField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::DeletePropertyByValue);
+                self.emit_u8(strict as u8);
+            }
+            Node::GetSuperField(node) => {
+                self.emit_opcode(Opcode::This);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::DeleteSuperProperty);
+            }
+            Node::Optional(optional) => self.compile_optional(optional, Some(strict)),
+            Node::Identifier(name) => {
+                let index = self.get_or_insert_name(name.as_ref());
+                self.emit(Opcode::DeleteName, &[index]);
+            }
+            // Deleting anything else only evaluates it.
+            target => {
+                self.compile_expr(target, false);
+                self.emit_opcode(Opcode::PushTrue);
+            }
+        }
+    }
+
+    /// Compiles an optional chain, or the `delete` of its last item if `delete_strict` is given.
+    ///
+    /// The `this` value of the next call of the chain is kept below the current value, and the
+    /// chain evaluates to `undefined`, or deletes nothing, as soon as a shorted item is reached
+    /// on `null` or `undefined`.
+    fn compile_optional(&mut self, optional: &Optional, delete_strict: Option<bool>) {
+        self.compile_callee(optional.target());
+
+        let mut shorts = Vec::new();
+        if let (Some(strict), Some((last, chain))) = (delete_strict, optional.chain().split_last())
+        {
+            for item in chain {
+                self.compile_optional_item(item, &mut shorts);
+            }
+            match last.kind() {
+                OptionalItemKind::Call(_) => {
+                    self.compile_optional_item(last, &mut shorts);
+                    self.emit_opcode(Opcode::Pop);
+                    self.emit_opcode(Opcode::Pop);
+                    self.emit_opcode(Opcode::PushTrue);
+                }
+                kind => {
+                    if last.shorted() {
+                        shorts.push(self.jump_with_custom_opcode(Opcode::JumpIfNullOrUndefined));
+                    }
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Pop);
+                    match kind {
+                        OptionalItemKind::GetConstField(field) => {
+                            self.emit_push_literal(Literal::String(field.as_ref().into()))
+                        }
+                        OptionalItemKind::GetField(field) => self.compile_expr(field, true),
+                        // The parser rejects deleting a private field.
+                        OptionalItemKind::GetPrivateField(_) | OptionalItemKind::Call(_) => {
+                            unreachable!()
                         }
                     }
+                    self.emit_opcode(Opcode::DeletePropertyByValue);
+                    self.emit_u8(strict as u8);
                 }
             }
-            Node::LetDeclList(list) => {
-                for decl in list.as_ref() {
-                    match decl {
-                        Declaration::Identifier { ident, .. } => {
-                            let index = self.get_or_insert_name(ident.as_ref());
-                            self.emit(Opcode::DefLet, &[index]);
+        } else {
+            for item in optional.chain() {
+                self.compile_optional_item(item, &mut shorts);
+            }
+            self.emit_opcode(Opcode::Swap);
+            self.emit_opcode(Opcode::Pop);
+        }
+        let exit = self.jump();
 
-                            if let Some(expr) = decl.init() {
-                                self.compile_expr(expr, true);
-                                self.emit(Opcode::InitLexical, &[index]);
-                            };
+        for short in shorts {
+            self.patch_jump(short);
+        }
+        self.emit_opcode(Opcode::Pop);
+        self.emit_opcode(Opcode::Pop);
+        if delete_strict.is_some() {
+            self.emit_opcode(Opcode::PushTrue);
+        } else {
+            self.emit_opcode(Opcode::PushUndefined);
+        }
+        self.patch_jump(exit);
+    }
+
+    /// Compiles an item of an optional chain, which replaces the `this` value and the current
+    /// value of the chain.
+    fn compile_optional_item(&mut self, item: &OptionalItem, shorts: &mut Vec<Label>) {
+        if item.shorted() {
+            shorts.push(self.jump_with_custom_opcode(Opcode::JumpIfNullOrUndefined));
+        }
+
+        match item.kind() {
+            OptionalItemKind::GetConstField(field) => {
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::Pop);
+                self.emit_opcode(Opcode::Dup);
+                let index = self.get_or_insert_name(field);
+                self.emit_property_access(Opcode::GetPropertyByName, index);
+            }
+            OptionalItemKind::GetField(field) => {
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::Pop);
+                self.emit_opcode(Opcode::Dup);
+                self.compile_expr(field, true);
+                self.emit_opcode(Opcode::GetPropertyByValue);
+            }
+            OptionalItemKind::GetPrivateField(field) => {
+                self.emit_opcode(Opcode::Swap);
+                self.emit_opcode(Opcode::Pop);
+                self.emit_opcode(Opcode::Dup);
+                let index = self.get_or_insert_private_name(field);
+                self.emit(Opcode::GetPrivateField, &[index]);
+            }
+            OptionalItemKind::Call(args) => {
+                match self.compile_arguments(args) {
+                    Some(count) => self.emit(Opcode::Call, &[count]),
+                    None => self.emit_opcode(Opcode::CallSpread),
+                }
+                self.emit_opcode(Opcode::PushUndefined);
+                self.emit_opcode(Opcode::Swap);
+            }
+        }
+    }
+
+    /// Compiles a `yield*` expression, delegating to the iterator of the value on the stack.
+    ///
+    /// The completions the generator is resumed with are passed on to the iterator, until it
+    /// is done.
+    fn compile_yield_delegate(&mut self) {
+        let is_async = self.is_async;
+        if is_async {
+            self.emit_opcode(Opcode::GetAsyncIterator);
+        } else {
+            self.emit_opcode(Opcode::GetIterator);
+        }
+        self.emit_opcode(Opcode::PushUndefined);
+        self.emit_opcode(Opcode::PushZero);
+
+        let start = self.next_opcode_location();
+        let (throw_method_undefined, return_method_undefined) =
+            self.jump_with_two_targets(Opcode::GeneratorDelegateNext);
+        if is_async {
+            self.emit_opcode(Opcode::Await);
+            self.emit_opcode(Opcode::ResumeCompletion);
+        }
+        let (return_value, exit) = self.jump_with_two_targets(Opcode::GeneratorDelegateResume);
+        if is_async {
+            // The results of an async iterator are yielded by their value, and a return
+            // completion the generator is resumed with awaits its value first.
+            let index = self.get_or_insert_name("value");
+            self.emit_property_access(Opcode::GetPropertyByName, index);
+            self.emit_opcode(Opcode::Yield);
+            self.emit(Opcode::JumpIfNotCompletionType, &[start]);
+            self.emit_u8(CompletionType::Return as u8);
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::Await);
+            self.emit(Opcode::JumpIfNotCompletionType, &[start]);
+            self.emit_u8(CompletionType::Normal as u8);
+            self.emit_opcode(Opcode::Pop);
+            self.emit_push_integer(CompletionType::Return as i32);
+        } else {
+            self.emit_opcode(Opcode::Yield);
+        }
+        self.emit(Opcode::Jump, &[start]);
+
+        // The iterator is closed before the missing `throw` method is reported.
+        self.patch_jump(throw_method_undefined);
+        self.emit_iterator_close(is_async);
+        let index = self.get_or_insert_literal(Literal::String(
+            "the delegated iterator has no `throw` method".into(),
+        ));
+        self.emit(Opcode::ThrowTypeError, &[index]);
+
+        self.patch_jump(return_method_undefined);
+        if is_async {
+            self.emit_opcode(Opcode::Await);
+            self.emit_opcode(Opcode::ResumeCompletion);
+        }
+        self.emit_opcode(Opcode::Return);
+
+        self.patch_jump(return_value);
+        self.emit_opcode(Opcode::Return);
+
+        self.patch_jump(exit);
+        self.emit_opcode(Opcode::IteratorPop);
+    }
+
+    /// Compiles a `var`, `let` or `const` declaration list.
+    fn compile_declaration_list(&mut self, list: &DeclarationList, use_expr: bool) {
+        let kind = match list {
+            DeclarationList::Var(_) => BindingKind::Var,
+            DeclarationList::Let(_) => BindingKind::Let,
+            DeclarationList::Const(_) => BindingKind::Const,
+        };
+
+        for decl in list.as_ref() {
+            match decl {
+                Declaration::Identifier { ident, init } => {
+                    let index = self.get_or_insert_name(ident.as_ref());
+                    match (kind, init) {
+                        (BindingKind::Var, None) => self.emit(Opcode::DefVar, &[index]),
+                        (_, Some(init)) => {
+                            self.compile_expr(init, true);
+                            self.emit_binding(kind, ident.as_ref());
                         }
-                        Declaration::Pattern(pattern) => {
-                            for ident in pattern.idents() {
-                                let index = self.get_or_insert_name(ident);
-                                self.emit(Opcode::DefLet, &[index]);
-
-                                if let Some(expr) = decl.init() {
-                                    self.compile_expr(expr, true);
-                                    self.emit(Opcode::InitLexical, &[index]);
-                                };
-                            }
+                        // The parser rejects `const` declarations without initializer.
+                        (_, None) => {
+                            self.emit_opcode(Opcode::PushUndefined);
+                            self.emit_binding(kind, ident.as_ref());
                         }
                     }
                 }
+                Declaration::Pattern(pattern) => {
+                    match pattern.init() {
+                        Some(init) => self.compile_expr(init, true),
+                        None => self.emit_opcode(Opcode::PushUndefined),
+                    }
+                    self.compile_pattern(pattern, kind);
+                }
             }
-            Node::ConstDeclList(list) => {
-                for decl in list.as_ref() {
-                    match decl {
-                        Declaration::Identifier { ident, .. } => {
-                            let index = self.get_or_insert_name(ident.as_ref());
-                            self.emit(Opcode::DefConst, &[index]);
+        }
 
-                            if let Some(expr) = decl.init() {
-                                self.compile_expr(expr, true);
-                                self.emit(Opcode::InitLexical, &[index]);
-                            };
+        self.emit_empty_completion(use_expr);
+    }
+
+    /// Compiles the reference of the property access that a destructuring assignment assigns
+    /// to, or a `SyntaxError` if the target isn't a property access.
+    fn compile_property_target(&mut self, access: &Node) -> Option<PropertyTarget> {
+        match access {
+            Node::GetConstField(node) => {
+                self.compile_expr(node.obj(), true);
+                Some(PropertyTarget::ByName(
+                    self.get_or_insert_name(node.field()),
+                ))
+            }
+            Node::GetField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.compile_expr(node.field(), true);
+                Some(PropertyTarget::ByValue)
+            }
+            _ => {
+                self.emit_throw_syntax_error("invalid destructuring assignment target");
+                None
+            }
+        }
+    }
+
+    /// Assigns the value on the stack to the property referenced below it.
+    #[inline]
+    fn emit_property_store(&mut self, target: PropertyTarget) {
+        match target {
+            PropertyTarget::ByName(index) => {
+                self.emit_property_access(Opcode::SetPropertyByName, index)
+            }
+            PropertyTarget::ByValue => self.emit_opcode(Opcode::SetPropertyByValue),
+        }
+        self.emit_opcode(Opcode::Pop);
+    }
+
+    /// Compiles a destructuring pattern, binding its identifiers to the parts of the value on
+    /// the stack, which is consumed.
+    ///
+    /// The initializer of the pattern itself is evaluated by the caller.
+    fn compile_pattern(&mut self, pattern: &DeclarationPattern, kind: BindingKind) {
+        match pattern {
+            DeclarationPattern::Object(pattern) => {
+                self.emit_opcode(Opcode::ValueNotNullOrUndefined);
+                for binding in pattern.bindings() {
+                    match binding {
+                        BindingPatternTypeObject::Empty => {}
+                        BindingPatternTypeObject::SingleName {
+                            ident,
+                            property_name,
+                            default_init,
+                        } => {
+                            self.emit_opcode(Opcode::Dup);
+                            let index = self.get_or_insert_name(property_name);
+                            self.emit_property_access(Opcode::GetPropertyByName, index);
+                            self.emit_default(default_init.as_ref());
+                            self.emit_binding(kind, ident);
+                        }
+                        BindingPatternTypeObject::RestProperty {
+                            ident,
+                            excluded_keys,
+                        } => {
+                            self.emit_opcode(Opcode::Dup);
+                            self.compile_rest_object(excluded_keys);
+                            self.emit_binding(kind, ident);
                         }
-                        Declaration::Pattern(pattern) => {
-                            for ident in pattern.idents() {
-                                let index = self.get_or_insert_name(ident);
-                                self.emit(Opcode::DefConst, &[index]);
-
-                                if let Some(expr) = decl.init() {
-                                    self.compile_expr(expr, true);
-                                    self.emit(Opcode::InitLexical, &[index]);
-                                };
+                        BindingPatternTypeObject::BindingPattern {
+                            ident,
+                            pattern,
+                            default_init,
+                        } => {
+                            self.emit_opcode(Opcode::Dup);
+                            let index = self.get_or_insert_name(ident);
+                            self.emit_property_access(Opcode::GetPropertyByName, index);
+                            self.emit_default(default_init.as_ref());
+                            self.compile_pattern(pattern, kind);
+                        }
+                        BindingPatternTypeObject::AssignmentPropertyAccess {
+                            property_name,
+                            access,
+                            default_init,
+                        } => {
+                            if let Some(target) = self.compile_property_target(access) {
+                                self.emit_value_above_reference(target);
+                                let index = self.get_or_insert_name(property_name);
+                                self.emit_property_access(Opcode::GetPropertyByName, index);
+                                self.emit_default(default_init.as_ref());
+                                self.emit_property_store(target);
+                            }
+                        }
+                        BindingPatternTypeObject::AssignmentRestProperty {
+                            access,
+                            excluded_keys,
+                        } => {
+                            if let Some(target) = self.compile_property_target(access) {
+                                self.emit_value_above_reference(target);
+                                self.compile_rest_object(excluded_keys);
+                                self.emit_property_store(target);
                             }
                         }
                     }
                 }
+                self.emit_opcode(Opcode::Pop);
+            }
+            DeclarationPattern::Array(pattern) => {
+                // The iterator is closed when the pattern is left, unless it is done.
+                self.emit_opcode(Opcode::ValueNotNullOrUndefined);
+                self.emit_opcode(Opcode::GetIterator);
+                let handler = self.jump_with_custom_opcode(Opcode::FinallyStart);
+
+                for binding in pattern.bindings() {
+                    match binding {
+                        BindingPatternTypeArray::Empty => {}
+                        BindingPatternTypeArray::Elision => {
+                            self.emit_opcode(Opcode::IteratorStepValue);
+                            self.emit_opcode(Opcode::Pop);
+                        }
+                        BindingPatternTypeArray::SingleName {
+                            ident,
+                            default_init,
+                        } => {
+                            self.emit_opcode(Opcode::IteratorStepValue);
+                            self.emit_default(default_init.as_ref());
+                            self.emit_binding(kind, ident);
+                        }
+                        // The initializer of a nested pattern is its default value.
+                        BindingPatternTypeArray::BindingPattern { pattern } => {
+                            self.emit_opcode(Opcode::IteratorStepValue);
+                            self.emit_default(pattern.init());
+                            self.compile_pattern(pattern, kind);
+                        }
+                        BindingPatternTypeArray::SingleNameRest { ident } => {
+                            self.emit_opcode(Opcode::IteratorToArray);
+                            self.emit_binding(kind, ident);
+                        }
+                        BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                            self.emit_opcode(Opcode::IteratorToArray);
+                            self.compile_pattern(pattern, kind);
+                        }
+                        BindingPatternTypeArray::PropertyAccess {
+                            access,
+                            default_init,
+                        } => {
+                            if let Some(target) = self.compile_property_target(access) {
+                                self.emit_opcode(Opcode::IteratorStepValue);
+                                self.emit_default(default_init.as_ref());
+                                self.emit_property_store(target);
+                            }
+                        }
+                        BindingPatternTypeArray::PropertyAccessRest { access } => {
+                            if let Some(target) = self.compile_property_target(access) {
+                                self.emit_opcode(Opcode::IteratorToArray);
+                                self.emit_property_store(target);
+                            }
+                        }
+                    }
+                }
+
+                self.emit_opcode(Opcode::TryEnd);
+                self.emit_iterator_close(false);
+                let exit = self.jump();
+
+                self.patch_jump(handler);
+                self.emit_iterator_close_with_completion(false);
+                self.emit_opcode(Opcode::FinallyEnd);
+                self.patch_jump(exit);
+            }
+        }
+    }
+
+    /// Copies the value below the reference of a property on the stack above it.
+    #[inline]
+    fn emit_value_above_reference(&mut self, target: PropertyTarget) {
+        self.emit_opcode(Opcode::RotateLeft);
+        self.emit_u8(target.len() + 1);
+        self.emit_opcode(Opcode::Dup);
+        self.emit_opcode(Opcode::RotateRight);
+        self.emit_u8(target.len() + 2);
+    }
+
+    /// Replaces the value on the stack with a new object holding its own enumerable properties,
+    /// except the given ones.
+    fn compile_rest_object(&mut self, excluded_keys: &[Box<str>]) {
+        self.emit_opcode(Opcode::PushEmptyObject);
+        self.emit_opcode(Opcode::Swap);
+        for key in excluded_keys {
+            self.emit_push_literal(Literal::String(key.as_ref().into()));
+        }
+        self.emit(Opcode::CopyDataProperties, &[excluded_keys.len() as u32]);
+    }
+
+    /// Compiles the binding of the value on the stack to the variable of a `for...in` or
+    /// `for...of` loop, which consumes it.
+    fn compile_loop_initializer(&mut self, initializer: &IterableLoopInitializer) {
+        let (decl, kind) = match initializer {
+            IterableLoopInitializer::Identifier(ident) => {
+                return self.emit_binding(BindingKind::Assignment, ident.as_ref());
+            }
+            IterableLoopInitializer::GetConstField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.emit_opcode(Opcode::Swap);
+                let index = self.get_or_insert_name(node.field());
+                self.emit_property_access(Opcode::SetPropertyByName, index);
+                self.emit_opcode(Opcode::Pop);
+                return;
+            }
+            IterableLoopInitializer::GetField(node) => {
+                self.compile_expr(node.obj(), true);
+                self.compile_expr(node.field(), true);
+                self.emit_opcode(Opcode::RotateLeft);
+                self.emit_u8(3);
+                self.emit_opcode(Opcode::SetPropertyByValue);
+                self.emit_opcode(Opcode::Pop);
+                return;
+            }
+            IterableLoopInitializer::DeclarationPattern(pattern) => {
+                return self.compile_pattern(pattern, BindingKind::Assignment);
+            }
+            IterableLoopInitializer::Var(decl) => (decl, BindingKind::Var),
+            IterableLoopInitializer::Let(decl) => (decl, BindingKind::Let),
+            IterableLoopInitializer::Const(decl) => (decl, BindingKind::Const),
+        };
+
+        match decl {
+            Declaration::Identifier { ident, .. } => self.emit_binding(kind, ident.as_ref()),
+            Declaration::Pattern(pattern) => self.compile_pattern(pattern, kind),
+        }
+    }
+
+    /// Compiles a statement.
+    ///
+    /// If `use_expr` is `true`, the completion value is set to the value of the statement.
+    #[inline]
+    pub fn compile_stmt(&mut self, node: &Node, use_expr: bool) {
+        match node {
+            Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+                self.compile_declaration_list(list, use_expr);
+            }
+            Node::FunctionDecl(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or("default"),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::CONSTRUCTABLE,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+
+                // An anonymous declaration is the default export of a module, bound to
+                // `*default*`.
+                let index = self.get_or_insert_name(function.name().unwrap_or("*default*"));
+                self.emit(Opcode::DefInitVar, &[index]);
+
+                self.emit_empty_completion(use_expr);
+            }
+            Node::ClassDecl(class) => {
+                self.compile_class(class);
+
+                // An anonymous declaration is the default export of a module, bound to
+                // `*default*`.
+                if class.name().is_none() {
+                    let index = self.get_or_insert_name("default");
+                    self.emit(Opcode::SetFunctionName, &[index]);
+                }
+                self.emit_binding(BindingKind::Let, class.name().unwrap_or("*default*"));
+
+                self.emit_empty_completion(use_expr);
             }
             Node::If(node) => {
+                self.emit_empty_completion(use_expr);
                 self.compile_expr(node.cond(), true);
                 let jelse = self.jump_if_false();
 
-                self.compile_stmt(node.body(), false);
+                self.compile_stmt(node.body(), use_expr);
 
                 match node.else_node() {
                     None => {
@@ -677,98 +2174,372 @@ impl ByteCompiler {
                     Some(else_body) => {
                         let exit = self.jump();
                         self.patch_jump(jelse);
-                        self.compile_stmt(else_body, false);
+                        self.compile_stmt(else_body, use_expr);
                         self.patch_jump(exit);
                     }
                 }
             }
             Node::WhileLoop(while_) => {
+                self.emit_empty_completion(use_expr);
                 let start_address = self.next_opcode_location();
-                self.push_loop_control_info(while_.label().map(Into::into), start_address);
+                self.push_jump_control_info(while_.label(), JumpControlKind::Loop);
 
                 self.compile_expr(while_.cond(), true);
                 let exit = self.jump_if_false();
-                self.compile_stmt(while_.body(), false);
+                self.compile_stmt(while_.body(), use_expr);
                 self.emit(Opcode::Jump, &[start_address]);
                 self.patch_jump(exit);
 
-                self.pop_loop_control_info();
+                self.patch_continues(start_address);
+                self.pop_jump_control_info();
             }
             Node::DoWhileLoop(do_while) => {
+                self.emit_empty_completion(use_expr);
                 let start_address = self.next_opcode_location();
-                self.push_loop_control_info(do_while.label().map(Into::into), start_address);
+                self.push_jump_control_info(do_while.label(), JumpControlKind::Loop);
 
-                self.compile_stmt(do_while.body(), false);
+                self.compile_stmt(do_while.body(), use_expr);
 
+                let condition_address = self.next_opcode_location();
+                self.patch_continues(condition_address);
                 self.compile_expr(do_while.cond(), true);
                 self.emit(Opcode::JumpIfTrue, &[start_address]);
 
-                self.pop_loop_control_info();
+                self.pop_jump_control_info();
             }
-            Node::Continue(node) => {
-                let label = self.jump();
-                let mut items = self.jump_info.iter_mut().rev().filter(|info| info.is_loop);
-                let target = if node.label().is_none() {
-                    items.next()
-                } else {
-                    items.find(|info| info.label.as_deref() == node.label())
+            Node::ForLoop(for_loop) => {
+                // The loop has its own environment, holding the declarations of its initializer.
+                self.push_environment();
+                if let Some(init) = for_loop.init() {
+                    self.compile_stmt(init, false);
+                }
+
+                let start_address = self.next_opcode_location();
+                self.push_jump_control_info(for_loop.label(), JumpControlKind::Loop);
+
+                let exit = for_loop.condition().map(|condition| {
+                    self.compile_expr(condition, true);
+                    self.jump_if_false()
+                });
+                self.compile_stmt(for_loop.body(), use_expr);
+
+                let final_address = self.next_opcode_location();
+                self.patch_continues(final_address);
+                if let Some(final_expr) = for_loop.final_expr() {
+                    self.compile_expr(final_expr, false);
+                }
+                self.emit(Opcode::Jump, &[start_address]);
+
+                if let Some(exit) = exit {
+                    self.patch_jump(exit);
                 }
-                .expect("continue target")
-                .start_address;
+                self.pop_jump_control_info();
+                self.pop_environment();
 
-                self.patch_jump_with_target(label, target);
+                // The value of a `for` loop is always `undefined`.
+                self.emit_empty_completion(use_expr);
+            }
+            Node::Labelled(labelled) => {
+                self.push_jump_control_info(Some(labelled.label()), JumpControlKind::Labelled);
+                self.compile_stmt(labelled.statement(), use_expr);
+                self.pop_jump_control_info();
+            }
+            Node::Continue(node) => {
+                self.emit_empty_completion(use_expr);
+                let index = self.jump_target(node.label(), false);
+                self.jump_to_control_info(index, false);
             }
             Node::Break(node) => {
-                let label = self.jump();
-                if node.label().is_none() {
-                    self.jump_info.last_mut().unwrap().breaks.push(label);
-                } else {
-                    for info in self.jump_info.iter_mut().rev() {
-                        if info.label.as_deref() == node.label() {
-                            info.breaks.push(label);
-                            break;
-                        }
-                    }
-                }
+                self.emit_empty_completion(use_expr);
+                let index = self.jump_target(node.label(), true);
+                self.jump_to_control_info(index, true);
             }
             Node::Block(block) => {
-                for node in block.items() {
-                    self.compile_stmt(node, false);
-                }
+                self.emit_empty_completion(use_expr);
+                self.compile_block(block.items(), use_expr);
             }
             Node::Throw(throw) => {
                 self.compile_expr(throw.expr(), true);
                 self.emit(Opcode::Throw, &[]);
             }
+            Node::Return(ret) => {
+                match ret.expr() {
+                    Some(expr) => self.compile_expr(expr, true),
+                    None => self.emit_opcode(Opcode::PushUndefined),
+                }
+                // An async generator awaits the value it returns.
+                if self.is_async && self.is_generator {
+                    self.emit_opcode(Opcode::Await);
+                    self.emit_opcode(Opcode::ResumeCompletion);
+                }
+                self.emit_opcode(Opcode::Return);
+            }
             Node::Switch(switch) => {
-                let start_address = self.next_opcode_location();
-                self.push_switch_control_info(None, start_address);
-
+                self.emit_empty_completion(use_expr);
                 self.compile_expr(switch.val(), true);
+
+                // The cases share a new declarative environment, holding their lexical
+                // declarations.
+                self.push_environment();
+                for case in switch.cases() {
+                    self.compile_lexical_declarations(case.body().items());
+                }
+                if let Some(default) = switch.default() {
+                    self.compile_lexical_declarations(default);
+                }
+
+                self.push_jump_control_info(None, JumpControlKind::Switch);
+
                 let mut labels = Vec::with_capacity(switch.cases().len());
                 for case in switch.cases() {
                     self.compile_expr(case.condition(), true);
                     labels.push(self.jump_with_custom_opcode(Opcode::Case));
                 }
 
-                let exit = self.jump_with_custom_opcode(Opcode::Default);
+                let default = self.jump_with_custom_opcode(Opcode::Default);
 
+                // The cases fall through to the next ones, but not to the default clause.
                 for (label, case) in labels.into_iter().zip(switch.cases()) {
                     self.patch_jump(label);
-                    self.compile_statement_list(case.body(), false);
+                    self.compile_statement_list(case.body(), use_expr);
                 }
+                let exit = self.jump();
 
-                self.patch_jump(exit);
+                self.patch_jump(default);
                 if let Some(body) = switch.default() {
                     for node in body {
-                        self.compile_stmt(node, false);
+                        self.compile_stmt(node, use_expr);
+                    }
+                }
+                self.patch_jump(exit);
+
+                self.pop_jump_control_info();
+                self.pop_environment();
+            }
+            Node::Try(try_node) => {
+                self.emit_empty_completion(use_expr);
+
+                // The `finally` block is the handler of both the `try` and the `catch` blocks.
+                let finally_start = try_node.finally().map(|_| {
+                    let label = self.jump_with_custom_opcode(Opcode::FinallyStart);
+                    self.scopes.push(Scope::Finally { jumps: Vec::new() });
+                    label
+                });
+
+                match try_node.catch() {
+                    Some(catch) => {
+                        let catch_start = self.jump_with_custom_opcode(Opcode::TryStart);
+                        self.scopes.push(Scope::Try);
+                        self.compile_block(try_node.block().items(), use_expr);
+                        self.scopes.pop();
+                        self.emit_opcode(Opcode::TryEnd);
+                        let exit = self.jump();
+
+                        // The exception is on the stack when the catch block starts. The catch
+                        // parameter is bound in its own declarative environment.
+                        self.patch_jump(catch_start);
+                        self.push_environment();
+                        match catch.parameter() {
+                            Some(Declaration::Identifier { ident, .. }) => {
+                                self.emit_binding(BindingKind::Let, ident.as_ref());
+                            }
+                            Some(Declaration::Pattern(pattern)) => {
+                                self.compile_pattern(pattern, BindingKind::Let);
+                            }
+                            None => self.emit_opcode(Opcode::Pop),
+                        }
+                        self.compile_block(catch.block().items(), use_expr);
+                        self.pop_environment();
+
+                        self.patch_jump(exit);
+                    }
+                    None => self.compile_block(try_node.block().items(), use_expr),
+                }
+
+                if let (Some(finally), Some(finally_start)) = (try_node.finally(), finally_start) {
+                    let jumps = match self.scopes.pop() {
+                        Some(Scope::Finally { jumps }) => jumps,
+                        scope => unreachable!("scope of a `finally` block: {:?}", scope),
+                    };
+
+                    // The block is entered with a normal completion when it isn't left early.
+                    self.emit_opcode(Opcode::TryEnd);
+                    self.emit_opcode(Opcode::PushUndefined);
+                    self.emit_push_integer(CompletionType::Normal as i32);
+
+                    self.patch_jump(finally_start);
+                    for jump in jumps {
+                        self.patch_jump(jump);
                     }
+                    self.scopes.push(Scope::Completion);
+                    self.compile_block(finally.items(), false);
+                    self.scopes.pop();
+                    self.emit_opcode(Opcode::FinallyEnd);
+                }
+            }
+            Node::ForOfLoop(for_of) => {
+                self.emit_empty_completion(use_expr);
+                self.compile_expr(for_of.iterable(), true);
+
+                // The iterator is closed by a handler if the loop is left by an exception or a
+                // `return` statement, and before jumping out of it otherwise.
+                let is_async = for_of.is_await();
+                if is_async {
+                    self.emit_opcode(Opcode::GetAsyncIterator);
+                } else {
+                    self.emit_opcode(Opcode::GetIterator);
+                }
+                let handler = self.jump_with_custom_opcode(Opcode::FinallyStart);
+                self.scopes.push(Scope::ForOf { is_async });
+
+                let start_address = self.next_opcode_location();
+                self.push_jump_control_info(for_of.label(), JumpControlKind::Loop);
+
+                self.emit_opcode(Opcode::IteratorNext);
+                if is_async {
+                    self.emit_opcode(Opcode::Await);
+                    self.emit_opcode(Opcode::ResumeCompletion);
                 }
+                let exit = self.jump_with_custom_opcode(Opcode::IteratorResult);
+
+                // Each iteration has its own environment, holding the declarations of the loop
+                // variable.
+                self.push_environment();
+                self.compile_loop_initializer(for_of.variable());
+                self.compile_stmt(for_of.body(), use_expr);
+                self.pop_environment();
+                self.emit(Opcode::Jump, &[start_address]);
+
+                self.patch_continues(start_address);
+                self.pop_jump_control_info();
+                self.scopes.pop();
+                self.emit_opcode(Opcode::TryEnd);
+                self.emit_iterator_close(is_async);
+                let break_exit = self.jump();
+
+                self.patch_jump(exit);
+                self.emit_opcode(Opcode::TryEnd);
+                self.emit_opcode(Opcode::IteratorPop);
+                let done_exit = self.jump();
 
-                self.pop_switch_control_info();
+                self.patch_jump(handler);
+                self.emit_iterator_close_with_completion(is_async);
+                self.emit_opcode(Opcode::FinallyEnd);
+
+                self.patch_jump(break_exit);
+                self.patch_jump(done_exit);
+            }
+            Node::ForInLoop(for_in) => {
+                self.emit_empty_completion(use_expr);
+                self.compile_expr(for_in.expr(), true);
+                let empty_exit = self.jump_with_custom_opcode(Opcode::ForInLoopInitIterator);
+                self.scopes.push(Scope::ForIn);
+
+                let start_address = self.next_opcode_location();
+                self.push_jump_control_info(for_in.label(), JumpControlKind::Loop);
+
+                self.emit_opcode(Opcode::IteratorNext);
+                let exit = self.jump_with_custom_opcode(Opcode::IteratorResult);
+
+                self.push_environment();
+                self.compile_loop_initializer(for_in.variable());
+                self.compile_stmt(for_in.body(), use_expr);
+                self.pop_environment();
+                self.emit(Opcode::Jump, &[start_address]);
+
+                self.patch_continues(start_address);
+                self.pop_jump_control_info();
+                self.patch_jump(exit);
+                self.scopes.pop();
+                self.emit_opcode(Opcode::IteratorPop);
+
+                self.patch_jump(empty_exit);
+            }
+            Node::With(with) => {
+                self.emit_empty_completion(use_expr);
+                self.compile_expr(with.object(), true);
+                self.emit_opcode(Opcode::PushObjectEnvironment);
+                self.scopes.push(Scope::Environment);
+                self.compile_stmt(with.statement(), use_expr);
+                self.pop_environment();
+            }
+            Node::Empty => self.emit_empty_completion(use_expr),
+            Node::AsyncFunctionDecl(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or("default"),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::ASYNC,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+                let index = self.get_or_insert_name(function.name().unwrap_or("*default*"));
+                self.emit(Opcode::DefInitVar, &[index]);
+
+                self.emit_empty_completion(use_expr);
+            }
+            Node::GeneratorDecl(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or("default"),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::GENERATOR,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+                let index = self.get_or_insert_name(function.name().unwrap_or("*default*"));
+                self.emit(Opcode::DefInitVar, &[index]);
+
+                self.emit_empty_completion(use_expr);
+            }
+            Node::AsyncGeneratorDecl(function) => {
+                let index = self.insert_function(
+                    function.name().unwrap_or("default"),
+                    function.parameters(),
+                    function.body(),
+                    function.strict(),
+                    FunctionFlags::ASYNC | FunctionFlags::GENERATOR,
+                    function.source_text(),
+                );
+                self.emit(Opcode::GetFunction, &[index]);
+                let index = self.get_or_insert_name(function.name().unwrap_or("*default*"));
+                self.emit(Opcode::DefInitVar, &[index]);
+
+                self.emit_empty_completion(use_expr);
+            }
+            Node::ExportDecl(export) => match export {
+                ExportDecl::Declaration(decl) | ExportDecl::DefaultDeclaration(decl) => {
+                    self.compile_stmt(decl, use_expr);
+                }
+                ExportDecl::DefaultExpression(expr) => {
+                    self.compile_expr(expr, true);
+                    if is_anonymous_function_definition(expr) {
+                        let index = self.get_or_insert_name("default");
+                        self.emit(Opcode::SetFunctionName, &[index]);
+                    }
+                    self.emit_binding(BindingKind::Const, "*default*");
+
+                    self.emit_empty_completion(use_expr);
+                }
+                // Exports lists and re-exports are bound when their module is linked.
+                ExportDecl::List(_)
+                | ExportDecl::ReExport { .. }
+                | ExportDecl::StarReExport { .. } => {
+                    self.emit_empty_completion(use_expr);
+                }
+            },
+            // Imports are bound when their module is linked.
+            Node::ImportDecl(_) => self.emit_empty_completion(use_expr),
+            expr => {
+                self.compile_expr(expr, true);
+                if use_expr {
+                    self.emit_opcode(Opcode::SetCompletion);
+                } else {
+                    self.emit(Opcode::Pop, &[]);
+                }
             }
-            Node::Empty => {}
-            expr => self.compile_expr(expr, use_expr),
         }
     }
 
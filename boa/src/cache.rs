@@ -0,0 +1,260 @@
+//! An engine-level cache of compilation results, shared by every `Context`.
+//!
+//! Embedders creating many contexts, for example one per request, usually evaluate the same
+//! scripts and create the same regular expressions in each of them. With the `shared-cache`
+//! feature, the engine keeps the results of compiling them in this cache, keyed by a hash of
+//! their source, so that only the first context pays for the compilation.
+//!
+//! Regular expression matchers are shared by the whole process. Parsed scripts are shared by the
+//! contexts of the same thread, because the syntax tree shares the bodies of its functions through
+//! reference counted pointers that can't be sent to other threads.
+//!
+//! # Examples
+//! ```
+//!# use boa::{cache, Context};
+//! cache::clear();
+//!
+//! Context::new().eval("/a+b/.test('aab')").unwrap();
+//! Context::new().eval("/a+b/.test('aab')").unwrap();
+//!
+//! let stats = cache::stats();
+//! assert_eq!(stats.script_misses, 1);
+//! assert_eq!(stats.script_hits, 1);
+//! ```
+
+use crate::syntax::{
+    ast::node::StatementList,
+    parser::{ParseError, Parser},
+};
+use regress::Regex;
+use rustc_hash::{FxHashMap, FxHasher};
+use std::{
+    cell::RefCell,
+    hash::{Hash, Hasher},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// The number of entries kept by each cache before it is emptied, bounding its memory usage.
+const CAPACITY: usize = 4096;
+
+/// The compiled regular expression matchers of the process, by the hash of their pattern and
+/// flags.
+static MATCHERS: Mutex<Option<FxHashMap<u64, Vec<MatcherEntry>>>> = Mutex::new(None);
+
+static MATCHER_HITS: AtomicUsize = AtomicUsize::new(0);
+static MATCHER_MISSES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The parsed scripts of the current thread.
+    static SCRIPTS: RefCell<ScriptCache> = RefCell::default();
+}
+
+/// A cached matcher, with the pattern and flags it was compiled from to tell apart sources with
+/// the same hash.
+#[derive(Debug)]
+struct MatcherEntry {
+    pattern: Box<str>,
+    flags: Box<str>,
+    matcher: Arc<Regex>,
+}
+
+/// The parsed scripts of a thread, by the hash of their source.
+#[derive(Debug, Default)]
+struct ScriptCache {
+    entries: FxHashMap<u64, Vec<ScriptEntry>>,
+    hits: usize,
+    misses: usize,
+}
+
+/// A cached script, with the source it was parsed from to tell apart sources with the same hash.
+#[derive(Debug)]
+struct ScriptEntry {
+    source: Box<[u8]>,
+    statement_list: Rc<StatementList>,
+}
+
+/// The number of lookups in the cache since it was last cleared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of regular expressions whose matcher was found in the cache.
+    pub matcher_hits: usize,
+    /// The number of regular expressions whose matcher had to be compiled.
+    pub matcher_misses: usize,
+    /// The number of scripts found in the cache of the current thread.
+    pub script_hits: usize,
+    /// The number of scripts that had to be parsed by the current thread.
+    pub script_misses: usize,
+}
+
+/// Gets the number of lookups in the cache.
+///
+/// Like the caches themselves, the counters of matchers are shared by the whole process, while
+/// the counters of scripts are those of the current thread.
+pub fn stats() -> CacheStats {
+    let (script_hits, script_misses) = SCRIPTS.with(|scripts| {
+        let scripts = scripts.borrow();
+        (scripts.hits, scripts.misses)
+    });
+    CacheStats {
+        matcher_hits: MATCHER_HITS.load(Ordering::Relaxed),
+        matcher_misses: MATCHER_MISSES.load(Ordering::Relaxed),
+        script_hits,
+        script_misses,
+    }
+}
+
+/// Empties the cache of matchers, the cache of scripts of the current thread, and resets the
+/// counters of [`stats`].
+pub fn clear() {
+    *MATCHERS.lock().unwrap_or_else(|error| error.into_inner()) = None;
+    SCRIPTS.with(|scripts| *scripts.borrow_mut() = ScriptCache::default());
+    MATCHER_HITS.store(0, Ordering::Relaxed);
+    MATCHER_MISSES.store(0, Ordering::Relaxed);
+}
+
+/// Hashes the given source.
+fn hash<T: Hash + ?Sized>(source: &T) -> u64 {
+    let mut hasher = FxHasher::default();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Gets the matcher of the pattern with the flags, compiling it if it isn't cached.
+pub(crate) fn matcher(pattern: &str, flags: &str) -> Result<Arc<Regex>, regress::Error> {
+    let key = hash(&(pattern, flags));
+    {
+        // A poisoned lock only means another thread panicked while holding it, the map itself
+        // is still valid.
+        let mut matchers = MATCHERS.lock().unwrap_or_else(|error| error.into_inner());
+        let entry = matchers
+            .get_or_insert_with(FxHashMap::default)
+            .get(&key)
+            .and_then(|entries| {
+                entries
+                    .iter()
+                    .find(|entry| &*entry.pattern == pattern && &*entry.flags == flags)
+            });
+        if let Some(entry) = entry {
+            MATCHER_HITS.fetch_add(1, Ordering::Relaxed);
+            return Ok(entry.matcher.clone());
+        }
+    }
+
+    // The lock isn't held while compiling, so that other threads aren't blocked by it.
+    MATCHER_MISSES.fetch_add(1, Ordering::Relaxed);
+    let matcher = Arc::new(crate::builtins::regexp::unicode::compile(pattern, flags)?);
+
+    let mut matchers = MATCHERS.lock().unwrap_or_else(|error| error.into_inner());
+    let matchers = matchers.get_or_insert_with(FxHashMap::default);
+    if matchers.len() >= CAPACITY {
+        matchers.clear();
+    }
+    let entries = matchers.entry(key).or_default();
+    if !entries
+        .iter()
+        .any(|entry| &*entry.pattern == pattern && &*entry.flags == flags)
+    {
+        entries.push(MatcherEntry {
+            pattern: pattern.into(),
+            flags: flags.into(),
+            matcher: matcher.clone(),
+        });
+    }
+    Ok(matcher)
+}
+
+/// Gets the statement list of the script with the given source, parsing it if it isn't cached.
+///
+/// Scripts failing to parse are not cached.
+pub(crate) fn script(source: &[u8]) -> Result<Rc<StatementList>, ParseError> {
+    let key = hash(source);
+    let cached = SCRIPTS.with(|scripts| {
+        let mut scripts = scripts.borrow_mut();
+        let statement_list = scripts.entries.get(&key).and_then(|entries| {
+            entries
+                .iter()
+                .find(|entry| &*entry.source == source)
+                .map(|entry| entry.statement_list.clone())
+        });
+        if statement_list.is_some() {
+            scripts.hits += 1;
+        } else {
+            scripts.misses += 1;
+        }
+        statement_list
+    });
+    if let Some(statement_list) = cached {
+        return Ok(statement_list);
+    }
+
+    let statement_list = Rc::new(Parser::new(source, false).parse_all()?);
+    SCRIPTS.with(|scripts| {
+        let entries = &mut scripts.borrow_mut().entries;
+        if entries.len() >= CAPACITY {
+            entries.clear();
+        }
+        entries.entry(key).or_default().push(ScriptEntry {
+            source: source.into(),
+            statement_list: statement_list.clone(),
+        });
+    });
+    Ok(statement_list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matcher, script, stats};
+    use crate::{Context, JsValue};
+    use std::{rc::Rc, sync::Arc, thread};
+
+    #[test]
+    fn scripts_are_shared_by_contexts() {
+        let source = "let shared = 40; shared + 2";
+        let before = stats();
+
+        let mut first = Context::new();
+        assert_eq!(first.eval(source).unwrap(), JsValue::new(42));
+        let mut second = Context::new();
+        assert_eq!(second.eval(source).unwrap(), JsValue::new(42));
+
+        let after = stats();
+        assert_eq!(after.script_misses - before.script_misses, 1);
+        assert_eq!(after.script_hits - before.script_hits, 1);
+
+        assert!(Rc::ptr_eq(
+            &script(source.as_bytes()).unwrap(),
+            &script(source.as_bytes()).unwrap()
+        ));
+    }
+
+    #[test]
+    fn scripts_failing_to_parse_are_not_cached() {
+        let source = "let = shared;";
+        let before = stats();
+
+        assert!(Context::new().eval(source).is_err());
+        assert!(Context::new().eval(source).is_err());
+
+        let after = stats();
+        assert_eq!(after.script_misses - before.script_misses, 2);
+        assert_eq!(after.script_hits, before.script_hits);
+    }
+
+    #[test]
+    fn matchers_are_shared_by_threads() {
+        let first = matcher("shared[a-z]+", "gi").unwrap();
+        let second = thread::spawn(|| matcher("shared[a-z]+", "gi").unwrap())
+            .join()
+            .unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        let other_flags = matcher("shared[a-z]+", "g").unwrap();
+        assert!(!Arc::ptr_eq(&first, &other_flags));
+
+        assert!(matcher("shared(", "").is_err());
+    }
+}
@@ -6,6 +6,7 @@
 //!#    property::Attribute,
 //!#    class::{Class, ClassBuilder},
 //!#    gc::{Finalize, Trace},
+//!#    value::ArgumentsExt,
 //!#    Context, JsResult, JsValue,
 //!# };
 //!#
@@ -27,7 +28,7 @@
 //!     // This is what is called when we do `new Animal()`
 //!     fn constructor(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
 //!         // This is equivalent to `String(arg)`.
-//!         let kind = args.get(0).cloned().unwrap_or_default().to_string(context)?;
+//!         let kind = args.get_or_undefined(0).to_string(context)?;
 //!
 //!         let animal = match kind.as_str() {
 //!             "cat" => Self::Cat,
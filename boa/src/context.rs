@@ -3,32 +3,52 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, FunctionFlags, NativeFunction},
+        finalization_registry::FinalizationRegistry,
+        function::{create_throw_type_error, Function, FunctionFlags, NativeFunction},
+        global_this::GlobalThis,
         iterable::IteratorPrototypes,
+        number::NumberStringCache,
+        promise::PromiseState,
+        regexp::RegExpCache,
+        BuiltIn,
     },
     class::{Class, ClassBuilder},
-    exec::Interpreter,
-    object::{FunctionBuilder, JsObject, Object, PROTOTYPE},
+    environment::{
+        global_environment_record::GlobalEnvironmentRecord, lexical_environment::LexicalEnvironment,
+    },
+    error::{JsError, JsErrorCode, JsNativeErrorKind},
+    exec::{super_property_reference, Interpreter},
+    job::NativeJob,
+    module::{self, Module, ModuleLoader},
+    object::{FunctionBuilder, JsObject, Object, ObjectSnapshot, WeakJsObject, PROTOTYPE},
+    policy::{DynamicCodeAttempt, SourceKind, SourcePolicy},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     syntax::{
         ast::{
-            node::{
-                statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
-                StatementList,
-            },
-            Const, Node,
+            node::{statement_list::RcStatementList, FormalParameter, StatementList},
+            Node,
         },
-        Parser,
+        parser::ParseError,
     },
     BoaProfiler, Executable, JsResult, JsString, JsValue,
 };
+use gc::GcCell;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{borrow::Cow, collections::VecDeque, fmt, rc::Rc};
 
 #[cfg(feature = "console")]
-use crate::builtins::console::Console;
+use crate::builtins::console::{Console, ConsoleLogger, StdConsoleLogger};
+
+use crate::{
+    bytecompiler::ByteCompiler,
+    vm::{CodeBlock, FunctionCode, Vm},
+};
 
-#[cfg(feature = "vm")]
-use crate::vm::Vm;
+use crate::exec::instantiate_lexical_declarations;
+
+#[cfg(feature = "async")]
+use crate::builtins::iterable::StreamIterator;
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
 #[derive(Debug, Clone)]
@@ -91,8 +111,50 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
+    date: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
+    promise: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
+    weak_ref: StandardConstructor,
+    iterator: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    array_buffer: StandardConstructor,
+    shared_array_buffer: StandardConstructor,
+    data_view: StandardConstructor,
+    typed_array: StandardConstructor,
+    typed_int8_array: StandardConstructor,
+    typed_uint8_array: StandardConstructor,
+    typed_uint8clamped_array: StandardConstructor,
+    typed_int16_array: StandardConstructor,
+    typed_uint16_array: StandardConstructor,
+    typed_int32_array: StandardConstructor,
+    typed_uint32_array: StandardConstructor,
+    typed_bigint64_array: StandardConstructor,
+    typed_biguint64_array: StandardConstructor,
+    typed_float32_array: StandardConstructor,
+    typed_float64_array: StandardConstructor,
+    async_function: StandardConstructor,
+    generator_function: StandardConstructor,
+    async_generator_function: StandardConstructor,
+    collator: StandardConstructor,
+    #[cfg(feature = "intl")]
+    number_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    date_time_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    display_names: StandardConstructor,
+    #[cfg(feature = "intl")]
+    locale: StandardConstructor,
+    #[cfg(feature = "encoding")]
+    text_encoder: StandardConstructor,
+    #[cfg(feature = "encoding")]
+    text_decoder: StandardConstructor,
+    throw_type_error: JsObject,
+    array_prototype_values: JsObject,
+    eval: JsObject,
 }
 
 impl Default for StandardObjects {
@@ -114,8 +176,50 @@ impl Default for StandardObjects {
             syntax_error: StandardConstructor::default(),
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
+            aggregate_error: StandardConstructor::default(),
+            date: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
+            weak_ref: StandardConstructor::default(),
+            iterator: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            array_buffer: StandardConstructor::default(),
+            shared_array_buffer: StandardConstructor::default(),
+            data_view: StandardConstructor::default(),
+            typed_array: StandardConstructor::default(),
+            typed_int8_array: StandardConstructor::default(),
+            typed_uint8_array: StandardConstructor::default(),
+            typed_uint8clamped_array: StandardConstructor::default(),
+            typed_int16_array: StandardConstructor::default(),
+            typed_uint16_array: StandardConstructor::default(),
+            typed_int32_array: StandardConstructor::default(),
+            typed_uint32_array: StandardConstructor::default(),
+            typed_bigint64_array: StandardConstructor::default(),
+            typed_biguint64_array: StandardConstructor::default(),
+            typed_float32_array: StandardConstructor::default(),
+            typed_float64_array: StandardConstructor::default(),
+            async_function: StandardConstructor::default(),
+            generator_function: StandardConstructor::default(),
+            async_generator_function: StandardConstructor::default(),
+            collator: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            number_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            date_time_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            display_names: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            locale: StandardConstructor::default(),
+            #[cfg(feature = "encoding")]
+            text_encoder: StandardConstructor::default(),
+            #[cfg(feature = "encoding")]
+            text_decoder: StandardConstructor::default(),
+            throw_type_error: JsObject::default(),
+            array_prototype_values: JsObject::default(),
+            eval: JsObject::default(),
         }
     }
 }
@@ -201,6 +305,16 @@ impl StandardObjects {
         &self.uri_error
     }
 
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
+    #[inline]
+    pub fn date_object(&self) -> &StandardConstructor {
+        &self.date
+    }
+
     #[inline]
     pub fn map_object(&self) -> &StandardConstructor {
         &self.map
@@ -210,6 +324,382 @@ impl StandardObjects {
     pub fn set_object(&self) -> &StandardConstructor {
         &self.set
     }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set_object(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
+    #[inline]
+    pub fn weak_ref_object(&self) -> &StandardConstructor {
+        &self.weak_ref
+    }
+
+    #[inline]
+    pub fn iterator_object(&self) -> &StandardConstructor {
+        &self.iterator
+    }
+
+    #[inline]
+    pub fn finalization_registry_object(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    #[inline]
+    pub fn array_buffer_object(&self) -> &StandardConstructor {
+        &self.array_buffer
+    }
+
+    #[inline]
+    pub fn shared_array_buffer_object(&self) -> &StandardConstructor {
+        &self.shared_array_buffer
+    }
+
+    #[inline]
+    pub fn data_view_object(&self) -> &StandardConstructor {
+        &self.data_view
+    }
+
+    #[inline]
+    pub fn typed_array_object(&self) -> &StandardConstructor {
+        &self.typed_array
+    }
+
+    #[inline]
+    pub fn typed_int8_array_object(&self) -> &StandardConstructor {
+        &self.typed_int8_array
+    }
+
+    #[inline]
+    pub fn typed_uint8_array_object(&self) -> &StandardConstructor {
+        &self.typed_uint8_array
+    }
+
+    #[inline]
+    pub fn typed_uint8clamped_array_object(&self) -> &StandardConstructor {
+        &self.typed_uint8clamped_array
+    }
+
+    #[inline]
+    pub fn typed_int16_array_object(&self) -> &StandardConstructor {
+        &self.typed_int16_array
+    }
+
+    #[inline]
+    pub fn typed_uint16_array_object(&self) -> &StandardConstructor {
+        &self.typed_uint16_array
+    }
+
+    #[inline]
+    pub fn typed_int32_array_object(&self) -> &StandardConstructor {
+        &self.typed_int32_array
+    }
+
+    #[inline]
+    pub fn typed_uint32_array_object(&self) -> &StandardConstructor {
+        &self.typed_uint32_array
+    }
+
+    #[inline]
+    pub fn typed_bigint64_array_object(&self) -> &StandardConstructor {
+        &self.typed_bigint64_array
+    }
+
+    #[inline]
+    pub fn typed_biguint64_array_object(&self) -> &StandardConstructor {
+        &self.typed_biguint64_array
+    }
+
+    #[inline]
+    pub fn typed_float32_array_object(&self) -> &StandardConstructor {
+        &self.typed_float32_array
+    }
+
+    #[inline]
+    pub fn typed_float64_array_object(&self) -> &StandardConstructor {
+        &self.typed_float64_array
+    }
+
+    #[inline]
+    pub fn async_function_object(&self) -> &StandardConstructor {
+        &self.async_function
+    }
+
+    #[inline]
+    pub fn generator_function_object(&self) -> &StandardConstructor {
+        &self.generator_function
+    }
+
+    #[inline]
+    pub fn async_generator_function_object(&self) -> &StandardConstructor {
+        &self.async_generator_function
+    }
+
+    #[inline]
+    pub fn collator_object(&self) -> &StandardConstructor {
+        &self.collator
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn number_format_object(&self) -> &StandardConstructor {
+        &self.number_format
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn date_time_format_object(&self) -> &StandardConstructor {
+        &self.date_time_format
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn display_names_object(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn locale_object(&self) -> &StandardConstructor {
+        &self.locale
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn text_encoder_object(&self) -> &StandardConstructor {
+        &self.text_encoder
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn text_decoder_object(&self) -> &StandardConstructor {
+        &self.text_decoder
+    }
+}
+
+/// Typed accessors for the intrinsic objects of a [`Context`].
+///
+/// Unlike the properties of the global object, which scripts are free to replace, the intrinsics
+/// are the original standard objects the `Context` was created with.
+#[derive(Debug, Clone, Copy)]
+pub struct Intrinsics<'a> {
+    standard_objects: &'a StandardObjects,
+}
+
+impl<'a> Intrinsics<'a> {
+    /// Gets `%Error%` and `%Error.prototype%`.
+    #[inline]
+    pub fn error(self) -> &'a StandardConstructor {
+        &self.standard_objects.error
+    }
+
+    /// Gets `%EvalError%` and `%EvalError.prototype%`.
+    #[inline]
+    pub fn eval_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.eval_error
+    }
+
+    /// Gets `%RangeError%` and `%RangeError.prototype%`.
+    #[inline]
+    pub fn range_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.range_error
+    }
+
+    /// Gets `%ReferenceError%` and `%ReferenceError.prototype%`.
+    #[inline]
+    pub fn reference_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.referece_error
+    }
+
+    /// Gets `%SyntaxError%` and `%SyntaxError.prototype%`.
+    #[inline]
+    pub fn syntax_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.syntax_error
+    }
+
+    /// Gets `%TypeError%` and `%TypeError.prototype%`.
+    #[inline]
+    pub fn type_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.type_error
+    }
+
+    /// Gets `%URIError%` and `%URIError.prototype%`.
+    #[inline]
+    pub fn uri_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.uri_error
+    }
+
+    /// Gets `%AggregateError%` and `%AggregateError.prototype%`.
+    #[inline]
+    pub fn aggregate_error(self) -> &'a StandardConstructor {
+        &self.standard_objects.aggregate_error
+    }
+
+    /// Gets the constructor and the prototype of the given kind of native error.
+    #[inline]
+    pub fn native_error(self, kind: JsNativeErrorKind) -> &'a StandardConstructor {
+        match kind {
+            JsNativeErrorKind::Error => self.error(),
+            JsNativeErrorKind::Eval => self.eval_error(),
+            JsNativeErrorKind::Range => self.range_error(),
+            JsNativeErrorKind::Reference => self.reference_error(),
+            JsNativeErrorKind::Syntax => self.syntax_error(),
+            JsNativeErrorKind::Type => self.type_error(),
+            JsNativeErrorKind::Uri => self.uri_error(),
+        }
+    }
+
+    /// Gets `%ThrowTypeError%`.
+    #[inline]
+    pub fn throw_type_error(self) -> &'a JsObject {
+        &self.standard_objects.throw_type_error
+    }
+
+    /// Gets `%Array.prototype.values%`.
+    #[inline]
+    pub fn array_prototype_values(self) -> &'a JsObject {
+        &self.standard_objects.array_prototype_values
+    }
+
+    /// Gets `%eval%`.
+    #[inline]
+    pub fn eval(self) -> &'a JsObject {
+        &self.standard_objects.eval
+    }
+}
+
+/// The default of [`Context::max_nesting_depth`].
+const DEFAULT_MAX_NESTING_DEPTH: usize = 10_000;
+
+/// A handler able to interrupt long running evaluations.
+struct InterruptHandler(Box<dyn FnMut(&mut Context) -> JsResult<()>>);
+
+impl fmt::Debug for InterruptHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InterruptHandler")
+    }
+}
+
+/// A callback recording the attempts to compile source text at runtime.
+struct DynamicCodeAudit(Box<dyn FnMut(&DynamicCodeAttempt<'_>)>);
+
+impl fmt::Debug for DynamicCodeAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DynamicCodeAudit")
+    }
+}
+
+/// The state [`Context::reset`] restores a context to.
+///
+/// It records the global declarations, and the objects reachable from them, from the global
+/// object and from the intrinsics no global property leads to, following property values,
+/// accessors and prototypes.
+#[derive(Debug)]
+struct ResetPoint {
+    /// A copy of the global environment record, holding the global declarations.
+    global_env: GlobalEnvironmentRecord,
+    objects: Vec<(JsObject, ObjectSnapshot)>,
+}
+
+impl ResetPoint {
+    /// Records the current state of the objects reachable from the roots of the context.
+    fn capture(context: &Context) -> Self {
+        let _timer = BoaProfiler::global().start_event("ResetPoint::capture", "context");
+
+        let global_env = context
+            .get_global_environment()
+            .as_global_record()
+            .expect("the outermost environment is a global environment")
+            .clone();
+
+        // The builtins are found from the properties of the global object, except the
+        // intrinsics only reachable from the objects created by scripts, like the prototype of
+        // the iterators of arrays.
+        let standard_objects = &context.standard_objects;
+        let iterator_prototypes = &context.iterator_prototypes;
+        let mut pending = vec![
+            context.realm.global_object.clone(),
+            iterator_prototypes.iterator_prototype(),
+            iterator_prototypes.array_iterator(),
+            iterator_prototypes.set_iterator(),
+            iterator_prototypes.string_iterator(),
+            iterator_prototypes.regexp_string_iterator(),
+            iterator_prototypes.map_iterator(),
+            iterator_prototypes.for_in_iterator(),
+            iterator_prototypes.generator(),
+            iterator_prototypes.async_iterator_prototype(),
+            iterator_prototypes.async_from_sync_iterator(),
+            iterator_prototypes.async_generator(),
+            iterator_prototypes.iterator_helper(),
+        ];
+        for standard in [
+            &standard_objects.async_function,
+            &standard_objects.generator_function,
+            &standard_objects.async_generator_function,
+        ] {
+            pending.push(standard.constructor.clone());
+            pending.push(standard.prototype.clone());
+        }
+
+        pending.extend(
+            global_env
+                .declarative_record
+                .env_rec
+                .borrow()
+                .values()
+                .filter_map(|binding| binding.value.as_ref()?.as_object()),
+        );
+
+        let mut visited = FxHashSet::default();
+        let mut objects = Vec::new();
+        while let Some(object) = pending.pop() {
+            if !visited.insert(object.as_ref() as *const GcCell<Object>) {
+                continue;
+            }
+
+            let borrowed = object.borrow();
+            for (_, property) in borrowed.properties().iter() {
+                for value in [property.value(), property.get(), property.set()]
+                    .iter()
+                    .flatten()
+                {
+                    if let Some(object) = value.as_object() {
+                        pending.push(object);
+                    }
+                }
+            }
+            if let Some(prototype) = borrowed.prototype_instance().as_object() {
+                pending.push(prototype);
+            }
+            let snapshot = borrowed.snapshot();
+            drop(borrowed);
+            objects.push((object, snapshot));
+        }
+
+        Self {
+            global_env,
+            objects,
+        }
+    }
+
+    /// Restores the recorded objects to their recorded state, and returns a lexical environment
+    /// holding the recorded global declarations.
+    fn restore(&self) -> LexicalEnvironment {
+        for (object, snapshot) in &self.objects {
+            object.borrow_mut().restore(snapshot);
+        }
+        LexicalEnvironment::with_global_record(self.global_env.clone())
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -265,14 +755,69 @@ pub struct Context {
     #[cfg(feature = "console")]
     console: Console,
 
+    /// The backend receiving the output of the console object.
+    #[cfg(feature = "console")]
+    console_logger: Rc<dyn ConsoleLogger>,
+
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
 
     /// Cached standard objects and their prototypes.
     standard_objects: StandardObjects,
 
+    /// The jobs waiting to be run, in the order they were enqueued.
+    job_queue: VecDeque<NativeJob>,
+
+    /// The stream iterators waiting for their stream to wake them.
+    #[cfg(feature = "async")]
+    pub(crate) pending_streams: Vec<JsObject>,
+
+    /// The targets of the `WeakRef`s created or dereferenced by the running job, kept alive
+    /// until it finishes.
+    kept_alive: Vec<JsObject>,
+
+    /// The `FinalizationRegistry` objects created in the context.
+    finalization_registries: Vec<WeakJsObject>,
+
+    /// The loader of the imported modules, if any.
+    pub(crate) module_loader: Option<Rc<dyn ModuleLoader>>,
+
+    /// The loaded modules, by the key their loader resolved them to.
+    pub(crate) module_map: FxHashMap<JsString, Module>,
+
+    /// The policy controlling the code compiled or loaded at runtime, if any.
+    source_policy: Option<Rc<dyn SourcePolicy>>,
+
+    /// Whether or not `eval` and the `Function` constructors may compile source text.
+    dynamic_code_enabled: bool,
+
+    /// The callback recording the attempts to compile source text at runtime, if any.
+    dynamic_code_audit: Option<DynamicCodeAudit>,
+
+    /// The handler able to interrupt long running evaluations, if any.
+    interrupt_handler: Option<InterruptHandler>,
+
+    /// Whether or not `Atomics.wait` may suspend the thread running the context.
+    can_block: bool,
+
+    /// The maximum nesting depth of the values walked by `JSON`, the serialization and the
+    /// structural comparison of values.
+    max_nesting_depth: usize,
+
+    /// The compiled regular expression matchers.
+    pub(crate) regexp_cache: RegExpCache,
+
+    /// The strings of the small integers.
+    pub(crate) number_strings: NumberStringCache,
+
+    /// The state restored by `Context::reset`, if one was captured.
+    reset_point: Option<ResetPoint>,
+
     /// Whether or not to show trace of instructions being ran
     pub trace: bool,
+
+    /// The virtual machine running the compiled code.
+    pub(crate) vm: Vm,
 }
 
 impl Default for Context {
@@ -284,15 +829,51 @@ impl Default for Context {
             executor,
             #[cfg(feature = "console")]
             console: Console::default(),
+            #[cfg(feature = "console")]
+            console_logger: Rc::new(StdConsoleLogger),
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            job_queue: VecDeque::new(),
+            #[cfg(feature = "async")]
+            pending_streams: Vec::new(),
+            kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            module_loader: None,
+            module_map: FxHashMap::default(),
+            source_policy: None,
+            dynamic_code_enabled: true,
+            dynamic_code_audit: None,
+            interrupt_handler: None,
+            can_block: true,
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            regexp_cache: RegExpCache::default(),
+            number_strings: NumberStringCache::default(),
+            reset_point: None,
             trace: false,
+            vm: Vm::default(),
         };
 
         // Add new builtIns to Context Realm
         // At a later date this can be removed from here and called explicitly,
         // but for now we almost always want these default builtins
         context.create_intrinsics();
+        context.standard_objects.throw_type_error = create_throw_type_error(&mut context);
+        context.standard_objects.array_prototype_values = context
+            .standard_objects
+            .array
+            .prototype()
+            .borrow()
+            .properties()
+            .get(&"values".into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_object))
+            .expect("Array.prototype.values is defined by the Array builtin");
+        context.standard_objects.eval = context
+            .global_object()
+            .borrow()
+            .properties()
+            .get(&"eval".into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_object))
+            .expect("eval is defined by the Eval builtin");
         context.iterator_prototypes = IteratorPrototypes::init(&mut context);
         context
     }
@@ -323,6 +904,26 @@ impl Context {
         &mut self.console
     }
 
+    /// Sets the backend receiving the output of the `console` object, instead of the standard
+    /// output.
+    ///
+    /// See [`ConsoleLogger`] for an example.
+    #[cfg(feature = "console")]
+    #[inline]
+    pub fn set_console_logger<L>(&mut self, logger: L)
+    where
+        L: ConsoleLogger + 'static,
+    {
+        self.console_logger = Rc::new(logger);
+    }
+
+    /// Returns the backend receiving the output of the `console` object.
+    #[cfg(feature = "console")]
+    #[inline]
+    pub(crate) fn console_logger(&self) -> Rc<dyn ConsoleLogger> {
+        self.console_logger.clone()
+    }
+
     /// Sets up the default global objects within Global
     #[inline]
     fn create_intrinsics(&mut self) {
@@ -348,7 +949,7 @@ impl Context {
     ) -> JsResult<JsValue> {
         match *f {
             JsValue::Object(ref object) => object.call(this, args, self),
-            _ => self.throw_type_error("not a function"),
+            _ => self.throw_coded_error(JsErrorCode::NOT_A_FUNCTION, "not a function"),
         }
     }
 
@@ -358,19 +959,136 @@ impl Context {
         self.realm.global_object.clone()
     }
 
+    /// Returns the value of `this` in global code, which is also the value of `globalThis`.
+    ///
+    /// It is the global object, unless replaced with [`Context::set_global_this_binding`].
+    #[inline]
+    pub fn global_this_binding(&self) -> JsObject {
+        self.get_global_environment()
+            .as_global_record()
+            .expect("the outermost environment is a global environment")
+            .global_this_binding()
+    }
+
+    /// Replaces the value of `this` in global code, like a host customizing its realm with a
+    /// `this` value other than the global object.
+    ///
+    /// The `globalThis` property of the global object is updated to the new value, unless it was
+    /// changed from the previous `this` value by a script.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setrealmglobalobject
+    ///
+    /// # Example
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::new();
+    ///
+    /// let this_value = context.construct_object();
+    /// context.set_global_this_binding(this_value);
+    ///
+    /// let value = context.eval("this === globalThis && this !== Object").unwrap();
+    /// assert_eq!(value.as_boolean(), Some(true));
+    /// ```
+    pub fn set_global_this_binding(&mut self, this_value: JsObject) {
+        let global_env = self.get_global_environment();
+        let global_env = global_env
+            .as_global_record()
+            .expect("the outermost environment is a global environment");
+        let previous = global_env.global_this_binding();
+        global_env.set_global_this_binding(this_value.clone());
+        self.realm
+            .global_env
+            .set_global_this_binding(this_value.clone());
+
+        let global = self.global_object();
+        let property = global
+            .borrow()
+            .properties()
+            .get(&GlobalThis::NAME.into())
+            .cloned();
+        if let Some(property) = property {
+            let reflects_previous = property
+                .value()
+                .and_then(JsValue::as_object)
+                .map_or(false, |value| JsObject::equals(&value, &previous));
+            if reflects_previous {
+                global.insert_property(
+                    GlobalThis::NAME,
+                    PropertyDescriptor::builder()
+                        .value(this_value)
+                        .writable(property.expect_writable())
+                        .enumerable(property.expect_enumerable())
+                        .configurable(property.expect_configurable()),
+                );
+            }
+        }
+    }
+
+    /// Constructs a native error of the given kind with the specified message.
+    ///
+    /// The error is created by the intrinsic constructor of its kind, so it is not affected by
+    /// changes to the global object.
+    pub fn construct_native_error<M>(&mut self, kind: JsNativeErrorKind, message: M) -> JsValue
+    where
+        M: Into<Box<str>>,
+    {
+        let constructor = self.intrinsics().native_error(kind).constructor();
+        constructor
+            .construct(
+                &[JsString::from(message.into()).into()],
+                &constructor.clone().into(),
+                self,
+            )
+            .expect("Into<String> used as message")
+    }
+
+    /// Constructs a native error identified by the given [`JsErrorCode`], with the specified
+    /// message.
+    ///
+    /// The error has the kind of the code, and its `code` property is set to the code.
+    pub fn construct_coded_error<M>(&mut self, code: JsErrorCode, message: M) -> JsValue
+    where
+        M: Into<Box<str>>,
+    {
+        let error = self.construct_native_error(code.kind(), message);
+        if let Some(object) = error.as_object() {
+            object.insert_property(
+                "code",
+                PropertyDescriptor::builder()
+                    .value(code.to_string())
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+            );
+        }
+        error
+    }
+
+    /// Throws a native error identified by the given [`JsErrorCode`], with the specified message.
+    #[inline]
+    pub fn throw_coded_error<M>(&mut self, code: JsErrorCode, message: M) -> JsResult<JsValue>
+    where
+        M: Into<Box<str>>,
+    {
+        Err(self.construct_coded_error(code, message))
+    }
+
+    /// Constructs the `SyntaxError` reporting a parse error.
+    pub(crate) fn construct_parse_error(&mut self, error: &ParseError) -> JsValue {
+        self.construct_coded_error(JsErrorCode::of_parse_error(error), error.to_string())
+    }
+
     /// Constructs a `Error` with the specified message.
     #[inline]
     pub fn construct_error<M>(&mut self, message: M) -> JsValue
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new Error(message)`.
-        New::from(Call::new(
-            Identifier::from("Error"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Error, message)
     }
 
     /// Throws a `Error` with the specified message.
@@ -388,13 +1106,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new RangeError(message)`.
-        New::from(Call::new(
-            Identifier::from("RangeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Range, message)
     }
 
     /// Throws a `RangeError` with the specified message.
@@ -412,13 +1124,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new TypeError(message)`.
-        New::from(Call::new(
-            Identifier::from("TypeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Type, message)
     }
 
     /// Throws a `TypeError` with the specified message.
@@ -436,12 +1142,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("ReferenceError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Reference, message)
     }
 
     /// Throws a `ReferenceError` with the specified message.
@@ -459,12 +1160,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("SyntaxError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Syntax, message)
     }
 
     /// Throws a `SyntaxError` with the specified message.
@@ -481,12 +1177,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("EvalError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Eval, message)
     }
 
     /// Constructs a `URIError` with the specified message.
@@ -494,12 +1185,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("URIError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_native_error(JsNativeErrorKind::Uri, message)
     }
 
     /// Throws a `EvalError` with the specified message.
@@ -525,44 +1211,118 @@ impl Context {
         params: P,
         body: B,
         flags: FunctionFlags,
+        source_text: Option<&str>,
     ) -> JsResult<JsValue>
     where
         N: Into<JsString>,
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
     {
-        let name = name.into();
-        let function_prototype: JsValue =
-            self.standard_objects().function_object().prototype().into();
+        let body = RcStatementList::from(body.into());
+        let code = ByteCompiler::compile_function_body(&body, flags);
 
-        // Every new function has a prototype property pre-made
-        let prototype = self.construct_object();
+        self.create_function_object(
+            name.into(),
+            params.into(),
+            body,
+            flags,
+            source_text.map(JsString::from),
+            code,
+        )
+    }
+
+    /// Creates a function object from the code of a function compiled by the vm.
+    pub(crate) fn create_function_from_code(
+        &mut self,
+        function: &FunctionCode,
+    ) -> JsResult<JsValue> {
+        self.create_function_object(
+            function.name.clone(),
+            function.params.clone(),
+            function.body.clone(),
+            function.flags,
+            function.source_text.clone(),
+            function.code.clone(),
+        )
+    }
+
+    /// Creates a function object closing over the current environment.
+    fn create_function_object(
+        &mut self,
+        name: JsString,
+        params: Box<[FormalParameter]>,
+        body: RcStatementList,
+        flags: FunctionFlags,
+        source_text: Option<JsString>,
+        code: Rc<CodeBlock>,
+    ) -> JsResult<JsValue> {
+        let function_prototype: JsValue = if flags.is_async() && flags.is_generator() {
+            self.standard_objects()
+                .async_generator_function_object()
+                .prototype()
+        } else if flags.is_async() {
+            self.standard_objects().async_function_object().prototype()
+        } else if flags.is_generator() {
+            self.standard_objects()
+                .generator_function_object()
+                .prototype()
+        } else {
+            self.standard_objects().function_object().prototype()
+        }
+        .into();
 
-        let params = params.into();
         let params_len = params.len();
         let func = Function::Ordinary {
             flags,
-            body: RcStatementList::from(body.into()),
+            body,
             params,
             environment: self.get_current_environment().clone(),
+            home_object: None,
+            source_text,
+            code,
+            fields: Vec::new(),
+            private_methods: Vec::new(),
+            static_elements: Vec::new(),
         };
 
         let function = JsObject::new(Object::function(func, function_prototype));
 
-        // Set constructor field to the newly created Value (function object)
-        let constructor = PropertyDescriptor::builder()
-            .value(function.clone())
-            .writable(true)
-            .enumerable(false)
-            .configurable(true);
-        prototype.define_property_or_throw("constructor", constructor, self)?;
+        // Every new constructor has a prototype property pre-made, except class constructors,
+        // whose prototype is made with their methods. The prototype of a generator function is
+        // the prototype of the generators it returns, so it has no constructor.
+        if flags.is_generator() {
+            let prototype = self.construct_object();
+            let generator_prototype = if flags.is_async() {
+                self.iterator_prototypes().async_generator()
+            } else {
+                self.iterator_prototypes().generator()
+            };
+            prototype.set_prototype_instance(generator_prototype.into());
+
+            let prototype = PropertyDescriptor::builder()
+                .value(prototype)
+                .writable(true)
+                .enumerable(false)
+                .configurable(false);
+            function.define_property_or_throw(PROTOTYPE, prototype, self)?;
+        } else if flags.is_constructable() && !flags.is_class_constructor() {
+            let prototype = self.construct_object();
+
+            // Set constructor field to the newly created Value (function object)
+            let constructor = PropertyDescriptor::builder()
+                .value(function.clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true);
+            prototype.define_property_or_throw("constructor", constructor, self)?;
 
-        let prototype = PropertyDescriptor::builder()
-            .value(prototype)
-            .writable(true)
-            .enumerable(false)
-            .configurable(false);
-        function.define_property_or_throw(PROTOTYPE, prototype, self)?;
+            let prototype = PropertyDescriptor::builder()
+                .value(prototype)
+                .writable(true)
+                .enumerable(false)
+                .configurable(false);
+            function.define_property_or_throw(PROTOTYPE, prototype, self)?;
+        }
 
         let length = PropertyDescriptor::builder()
             .value(params_len)
@@ -683,7 +1443,15 @@ impl Context {
                     .run(self)?
                     .set_field(key, value, false, self)?)
             }
-            _ => self.throw_type_error(format!("invalid assignment to {}", node)),
+            Node::GetSuperField(ref get_super_field) => {
+                let (base, key, this) = super_property_reference(get_super_field.field(), self)?;
+                base.__set__(key, value.clone(), this, self)?;
+                Ok(value)
+            }
+            _ => self.throw_coded_error(
+                JsErrorCode::INVALID_ASSIGNMENT,
+                format!("invalid assignment to {}", node),
+            ),
         }
     }
 
@@ -718,6 +1486,45 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global error constructor named `name`, for host-defined error types.
+    ///
+    /// The constructor behaves like the `NativeError` constructors such as `ReferenceError`:
+    /// it inherits from `parent`, or from `Error` if `parent` is `None`, so instances can be
+    /// caught and tested with `instanceof` against the new class and any of its ancestors.
+    ///
+    /// Throws a `TypeError` if `parent` is not a constructor with a `prototype` object.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::new();
+    ///
+    /// let app_error = context.register_error_class("AppError", None).unwrap();
+    /// context
+    ///     .register_error_class("NetworkError", Some(&app_error))
+    ///     .unwrap();
+    ///
+    /// let value = context
+    ///     .eval("try { throw new NetworkError('offline') } catch (e) { e instanceof AppError && String(e) }")
+    ///     .unwrap();
+    /// assert_eq!(value.as_string().unwrap().as_str(), "NetworkError: offline");
+    /// ```
+    #[inline]
+    pub fn register_error_class(
+        &mut self,
+        name: &str,
+        parent: Option<&JsObject>,
+    ) -> JsResult<JsObject> {
+        let parent = match parent {
+            Some(parent) => parent.clone(),
+            None => self.standard_objects().error_object().constructor(),
+        };
+        let class = builtins::error::create_error_class(name, &parent, self)?;
+        self.register_global_property(name, class.clone(), Attribute::builtin());
+        Ok(class)
+    }
+
     /// Register a global property.
     ///
     /// # Example
@@ -766,7 +1573,10 @@ impl Context {
         );
     }
 
-    /// Evaluates the given code.
+    /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
+    ///
+    /// The source is expected to be UTF-8, optionally starting with a byte order mark. Sources
+    /// starting with a UTF-16 byte order mark are decoded as UTF-16 before being parsed.
     ///
     /// # Examples
     /// ```
@@ -778,79 +1588,572 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[cfg(not(feature = "vm"))]
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> Result<JsValue, JsError> {
+        let src_bytes = decode_source(src.as_ref())?;
+        let src_bytes: &[u8] = src_bytes.as_ref();
+
+        let result = {
+            // The timer needs to end before the BoaProfiler is dropped.
+            let _timer = BoaProfiler::global().start_event("Main", "Main");
+            match parse_script(src_bytes) {
+                Ok(statement_list) => {
+                    let strict = self.executor().strict();
+                    self.executor().set_strict(statement_list.strict());
+                    let result = instantiate_lexical_declarations(statement_list.items(), self)
+                        .and_then(|()| {
+                            let code = ByteCompiler::compile_script(&statement_list);
+                            self.run_code_block(Rc::new(code))
+                        });
+                    self.executor().set_strict(strict);
+                    result.map_err(|e| JsError::from_opaque(e, self))
+                }
+                Err(e) => Err(
+                    JsError::coded(JsErrorCode::of_parse_error(&e), e.to_string()).with_source(e),
+                ),
+            }
+        };
+        BoaProfiler::global().drop();
+
+        result
+    }
+
+    /// Evaluates the given UTF-16 encoded code.
+    ///
+    /// A leading byte order mark is ignored. Unpaired surrogates can't be represented in the
+    /// parsed source, so they are reported as a `SyntaxError`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let src: Vec<u16> = "1 + 3".encode_utf16().collect();
+    /// let value = context.eval_utf16(&src).unwrap();
+    ///
+    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// ```
+    #[inline]
+    pub fn eval_utf16(&mut self, src: &[u16]) -> Result<JsValue, JsError> {
+        let src = String::from_utf16(src).map_err(|e| {
+            JsError::coded(
+                JsErrorCode::INVALID_UTF16,
+                "source text is not valid UTF-16",
+            )
+            .with_source(e)
+        })?;
+        self.eval(src)
+    }
+
+    /// Return the cached iterator prototypes.
+    #[inline]
+    pub fn iterator_prototypes(&self) -> &IteratorPrototypes {
+        &self.iterator_prototypes
+    }
+
+    /// Return the core standard objects.
+    #[inline]
+    pub fn standard_objects(&self) -> &StandardObjects {
+        &self.standard_objects
+    }
+
+    /// Return the intrinsic objects of the context.
+    #[inline]
+    pub fn intrinsics(&self) -> Intrinsics<'_> {
+        Intrinsics {
+            standard_objects: &self.standard_objects,
+        }
+    }
+
+    /// Enqueues a job, to be run by the next call to [`Context::run_jobs`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    #[inline]
+    pub fn enqueue_job(&mut self, job: NativeJob) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Runs the enqueued jobs until the job queue is empty, including the jobs enqueued while
+    /// running them.
+    ///
+    /// `Context::eval` does not run jobs by itself, so the reactions to settled promises only
+    /// happen when the embedder calls this function. If a job throws, the error is returned and
+    /// the remaining jobs stay in the queue.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, JsValue};
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var value; Promise.resolve(5).then(v => value = v);").unwrap();
+    /// assert!(context.eval("value").unwrap().is_undefined());
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("value").unwrap(), JsValue::new(5));
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<(), JsError> {
+        // The script that enqueued the jobs has finished.
+        self.clear_kept_objects();
+        self.process_finalization();
+
+        while let Some(job) = self.next_job().map_err(|e| JsError::from_opaque(e, self))? {
+            let result = job.call(self);
+            self.clear_kept_objects();
+            if let Err(e) = result {
+                return Err(JsError::from_opaque(e, self));
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes the next job to run from the job queue.
+    ///
+    /// Once the queue is empty, the streams woken since they were last polled are polled again,
+    /// which may enqueue the jobs reacting to their items.
+    fn next_job(&mut self) -> JsResult<Option<NativeJob>> {
+        #[cfg(feature = "async")]
+        if self.job_queue.is_empty() {
+            StreamIterator::poll_woken(self)?;
+        }
+        Ok(self.job_queue.pop_front())
+    }
+
+    /// `AddToKeptObjects ( object )`
+    ///
+    /// Keeps the object alive until the end of the current job, so that a `WeakRef` to it can
+    /// be dereferenced consistently.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    #[inline]
+    pub(crate) fn add_to_kept_objects(&mut self, object: JsObject) {
+        self.kept_alive.push(object);
+    }
+
+    /// `ClearKeptObjects ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clear-kept-objects
     #[inline]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> JsResult<JsValue> {
-        let main_timer = BoaProfiler::global().start_event("Main", "Main");
-        let src_bytes: &[u8] = src.as_ref();
+    pub(crate) fn clear_kept_objects(&mut self) {
+        self.kept_alive.clear();
+    }
 
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
+    /// Tracks a `FinalizationRegistry` object, to clean it up after its targets are collected.
+    #[inline]
+    pub(crate) fn register_finalization_registry(&mut self, registry: &JsObject) {
+        self.finalization_registries.push(registry.downgrade());
+    }
 
-        let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
-            Err(e) => self.throw_syntax_error(e),
+    /// Enqueues a cleanup job for each `FinalizationRegistry` with targets collected since the
+    /// last cleanup.
+    ///
+    /// This is called by [`Context::run_jobs`] and [`Context::collect_garbage`]. Hosts forcing
+    /// collections themselves should call it afterwards, so the cleanup callbacks run with the
+    /// next jobs.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub fn process_finalization(&mut self) {
+        let registries: Vec<JsObject> = {
+            self.finalization_registries.retain(WeakJsObject::is_alive);
+            self.finalization_registries
+                .iter()
+                .filter_map(WeakJsObject::upgrade)
+                .collect()
         };
+        for registry in &registries {
+            FinalizationRegistry::enqueue_cleanup(registry, self);
+        }
+    }
 
-        // The main_timer needs to be dropped before the BoaProfiler is.
-        drop(main_timer);
-        BoaProfiler::global().drop();
+    /// Runs the garbage collector, then enqueues the cleanup jobs of the `FinalizationRegistry`
+    /// objects whose targets were collected.
+    ///
+    /// The objects kept alive by the `WeakRef`s used in the current job are not collected.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, JsValue};
+    /// let mut context = Context::new();
+    ///
+    /// context
+    ///     .eval("var registry = new FinalizationRegistry(v => globalThis.cleaned = v); registry.register({}, 'held');")
+    ///     .unwrap();
+    /// context.run_jobs().unwrap();
+    ///
+    /// context.collect_garbage();
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("cleaned").unwrap(), JsValue::new("held"));
+    /// ```
+    pub fn collect_garbage(&mut self) {
+        crate::gc::force_collect();
+        self.process_finalization();
+    }
 
-        execution_result
+    /// Records the current state of the global object and of the builtins as the state
+    /// [`Context::reset`] restores.
+    ///
+    /// Capturing walks every object reachable from the globals, so it is left to the embedders
+    /// that reset their contexts. Calling this function after registering globals or running
+    /// setup scripts keeps them across resets. Capturing again replaces the previous reset point.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{property::Attribute, Context, JsValue};
+    /// let mut context = Context::new();
+    /// context.register_global_property("version", 1, Attribute::all());
+    /// context.capture_reset_point();
+    ///
+    /// context.eval("var request = 'first';").unwrap();
+    /// context.reset().unwrap();
+    ///
+    /// assert_eq!(context.eval("version").unwrap(), JsValue::new(1));
+    /// assert!(context.eval("request").is_err());
+    /// ```
+    pub fn capture_reset_point(&mut self) {
+        self.reset_point = Some(ResetPoint::capture(self));
     }
 
-    /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
+    /// Restores the context to the state recorded by [`Context::capture_reset_point`].
+    ///
+    /// The global variables and lexical declarations created since are removed, and the changes
+    /// made to the global object and to the builtins, such as new methods on `Array.prototype`,
+    /// are undone. The pending jobs and the loaded modules are discarded, while the module loader,
+    /// the source policy, the dynamic code settings and the interrupt handler of the context are
+    /// kept.
+    ///
+    /// Resetting a context is much cheaper than creating a new one, which allows running
+    /// unrelated scripts in isolation with the same context. Only the properties, prototypes and
+    /// extensibility of the recorded objects are restored, so the values held in builtin
+    /// collections created before the reset point, like a `Map` stored in a global, are not.
+    ///
+    /// Returns an error, leaving the context untouched, if no reset point was captured.
     ///
     /// # Examples
     /// ```
     ///# use boa::Context;
     /// let mut context = Context::new();
+    /// assert!(context.reset().is_err());
+    /// context.capture_reset_point();
     ///
-    /// let value = context.eval("1 + 3").unwrap();
+    /// context
+    ///     .eval("let user = 'alice'; Array.prototype.first = function() { return this[0]; };")
+    ///     .unwrap();
+    /// context.reset().unwrap();
     ///
-    /// assert!(value.is_number());
-    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// assert!(context.eval("user").is_err());
+    /// assert!(context.eval("[1, 2].first").unwrap().is_undefined());
+    /// ```
+    pub fn reset(&mut self) -> Result<(), JsError> {
+        let _timer = BoaProfiler::global().start_event("Context::reset", "context");
+
+        let reset_point = self.reset_point.as_ref().ok_or_else(|| {
+            JsError::native(JsNativeErrorKind::Error, "no reset point was captured")
+        })?;
+        self.realm.environment = reset_point.restore();
+        self.job_queue.clear();
+        #[cfg(feature = "async")]
+        self.pending_streams.clear();
+        self.kept_alive.clear();
+        self.finalization_registries.clear();
+        self.module_map.clear();
+        #[cfg(feature = "console")]
+        {
+            self.console = Console::default();
+        }
+        Ok(())
+    }
+
+    /// Sets the loader used to load the modules imported by [`Context::import_module`] and by
+    /// the `import` declarations of modules.
+    #[inline]
+    pub fn set_module_loader<L>(&mut self, loader: L)
+    where
+        L: ModuleLoader + 'static,
+    {
+        self.module_loader = Some(Rc::new(loader));
+    }
+
+    /// Imports the module requested with `specifier`, and returns its namespace object.
+    ///
+    /// The module and the modules it imports are loaded with the module loader of the context,
+    /// then linked and evaluated. Modules are cached by the key the loader resolves them to, so
+    /// importing a module again returns the same namespace without evaluating the module again.
+    ///
+    /// If the module or one of its imports awaits at its top level, the jobs of the context are
+    /// run until the evaluation of the module completes.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{module::MapModuleLoader, property::Attribute, Context, JsValue};
+    /// let mut loader = MapModuleLoader::new();
+    /// loader.insert("circle", "export const tau = 2 * Math.PI;");
+    /// loader.insert("main", r#"import { tau } from "circle"; export let half = tau / 2;"#);
+    ///
+    /// let mut context = Context::new();
+    /// context.set_module_loader(loader);
+    ///
+    /// let main = context.import_module("main").unwrap();
+    /// context.register_global_property("main", main, Attribute::all());
+    /// assert_eq!(context.eval("main.half").unwrap(), JsValue::new(std::f64::consts::PI));
+    /// ```
+    #[inline]
+    pub fn import_module(&mut self, specifier: &str) -> Result<JsObject, JsError> {
+        let (namespace, evaluation) =
+            module::import(specifier, None, self).map_err(|e| JsError::from_opaque(e, self))?;
+
+        // A module awaiting at its top level is evaluated by the jobs of the context.
+        if let Some(evaluation) = evaluation {
+            self.run_jobs()?;
+            let state = evaluation
+                .borrow()
+                .as_promise()
+                .expect("the evaluation of a module is a promise")
+                .state()
+                .clone();
+            match state {
+                PromiseState::Fulfilled(_) => {}
+                PromiseState::Rejected(ref error) => {
+                    return Err(JsError::from_opaque(error.clone(), self))
+                }
+                PromiseState::Pending => {
+                    let error = self.construct_error(format!(
+                        "The evaluation of the module {} did not complete",
+                        specifier
+                    ));
+                    return Err(JsError::from_opaque(error, self));
+                }
+            }
+        }
+        Ok(namespace)
+    }
+
+    /// Sets the policy consulted before compiling or loading code at runtime, with `eval`, the
+    /// `Function` constructor, dynamic `import()` or the module loader.
+    ///
+    /// See [`SourcePolicy`] for an example.
+    #[inline]
+    pub fn set_source_policy<P>(&mut self, policy: P)
+    where
+        P: SourcePolicy + 'static,
+    {
+        self.source_policy = Some(Rc::new(policy));
+    }
+
+    /// Removes the source policy of the context, if any, allowing all code again.
+    #[inline]
+    pub fn clear_source_policy(&mut self) {
+        self.source_policy = None;
+    }
+
+    /// Sets whether or not `eval` and the `Function`, `GeneratorFunction`, `AsyncFunction` and
+    /// `AsyncGeneratorFunction` constructors may compile source text, which they may by default.
+    ///
+    /// Hardened deployments can disable dynamic code, so that those throw an `EvalError` instead,
+    /// whatever the source policy of the context.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_dynamic_code_enabled(false);
+    ///
+    /// assert!(context.eval("eval('1 + 1')").is_err());
+    /// assert!(context.eval("new Function('return 1')").is_err());
+    /// assert!(context.eval("1 + 1").is_ok());
+    /// ```
+    #[inline]
+    pub fn set_dynamic_code_enabled(&mut self, enabled: bool) {
+        self.dynamic_code_enabled = enabled;
+    }
+
+    /// Checks if `eval` and the `Function` constructors may compile source text.
+    #[inline]
+    pub fn dynamic_code_enabled(&self) -> bool {
+        self.dynamic_code_enabled
+    }
+
+    /// Sets the callback recording every attempt of scripts to compile source text with `eval` or
+    /// the `Function` constructors, whether it is allowed or not.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    ///# use std::{cell::RefCell, rc::Rc};
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let mut context = Context::new();
+    /// let recorded = log.clone();
+    /// context.set_dynamic_code_audit(move |attempt| {
+    ///     recorded
+    ///         .borrow_mut()
+    ///         .push((attempt.source.to_owned(), attempt.allowed));
+    /// });
+    /// context.set_dynamic_code_enabled(false);
+    ///
+    /// assert!(context.eval("eval('1 + 1')").is_err());
+    /// assert_eq!(*log.borrow(), [("1 + 1".to_owned(), false)]);
     /// ```
-    #[cfg(feature = "vm")]
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> JsResult<JsValue> {
-        let main_timer = BoaProfiler::global().start_event("Main", "Main");
-        let src_bytes: &[u8] = src.as_ref();
-
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
-
-        let statement_list = match parsing_result {
-            Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+    #[inline]
+    pub fn set_dynamic_code_audit<F>(&mut self, audit: F)
+    where
+        F: FnMut(&DynamicCodeAttempt<'_>) + 'static,
+    {
+        self.dynamic_code_audit = Some(DynamicCodeAudit(Box::new(audit)));
+    }
+
+    /// Removes the dynamic code audit of the context, if any.
+    #[inline]
+    pub fn clear_dynamic_code_audit(&mut self) {
+        self.dynamic_code_audit = None;
+    }
+
+    /// Consults the source policy, if any, returning the error rejecting the given source.
+    ///
+    /// Source text compiled by `eval` and the `Function` constructors is rejected when dynamic
+    /// code is disabled, and the attempt to compile it is recorded by the dynamic code audit.
+    pub(crate) fn check_source(
+        &mut self,
+        kind: SourceKind,
+        source: &str,
+        origin: Option<&str>,
+    ) -> JsResult<()> {
+        let dynamic_code = matches!(kind, SourceKind::Eval | SourceKind::Function);
+        let result = if dynamic_code && !self.dynamic_code_enabled {
+            Err(self.construct_eval_error("Dynamic code compilation is disabled"))
+        } else {
+            match self.source_policy.clone() {
+                Some(policy) => policy.check(kind, source, origin, self),
+                None => Ok(()),
+            }
         };
 
-        let mut compiler = crate::bytecompiler::ByteCompiler::default();
-        compiler.compile_statement_list(&statement_list, true);
-        let code_block = compiler.finish();
-        let mut vm = Vm::new(code_block, self);
-        let result = vm.run();
+        if dynamic_code {
+            if let Some(ref mut audit) = self.dynamic_code_audit {
+                (audit.0)(&DynamicCodeAttempt {
+                    kind,
+                    source,
+                    origin,
+                    allowed: result.is_ok(),
+                });
+            }
+        }
+        result
+    }
 
-        // The main_timer needs to be dropped before the BoaProfiler is.
-        drop(main_timer);
-        BoaProfiler::global().drop();
+    /// Sets the handler called at each iteration of the loops of scripts, and periodically by the
+    /// long running builtins such as `Array.prototype.sort`, `Array.prototype.join` and the
+    /// regular expression matching methods.
+    ///
+    /// Returning an error from the handler interrupts the evaluation by throwing that error,
+    /// which allows embedders to stop scripts running for too long.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// let mut fuel = 1000;
+    /// context.set_interrupt_handler(move |context| {
+    ///     fuel -= 1;
+    ///     if fuel == 0 {
+    ///         return Err(context.construct_error("out of fuel"));
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// assert!(context.eval("while (true) {}").is_err());
+    /// ```
+    #[inline]
+    pub fn set_interrupt_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut Context) -> JsResult<()> + 'static,
+    {
+        self.interrupt_handler = Some(InterruptHandler(Box::new(handler)));
+    }
+
+    /// Removes the interrupt handler of the context, if any.
+    #[inline]
+    pub fn clear_interrupt_handler(&mut self) {
+        self.interrupt_handler = None;
+    }
+
+    /// Calls the interrupt handler, if any, returning the error interrupting the evaluation.
+    #[inline]
+    pub(crate) fn check_interrupt(&mut self) -> JsResult<()> {
+        let mut handler = match self.interrupt_handler.take() {
+            Some(handler) => handler,
+            None => return Ok(()),
+        };
+        let result = (handler.0)(self);
 
+        // The handler may have replaced itself while running.
+        if self.interrupt_handler.is_none() {
+            self.interrupt_handler = Some(handler);
+        }
         result
     }
 
-    /// Return the cached iterator prototypes.
+    /// Sets whether or not `Atomics.wait` may suspend the thread running the context, which it
+    /// may by default.
+    ///
+    /// Embedders running the context on a thread that must not block, such as the thread of an
+    /// event loop, can disallow it, so that `Atomics.wait` throws a `TypeError` instead.
     #[inline]
-    pub fn iterator_prototypes(&self) -> &IteratorPrototypes {
-        &self.iterator_prototypes
+    pub fn set_can_block(&mut self, can_block: bool) {
+        self.can_block = can_block;
     }
 
-    /// Return the core standard objects.
+    /// Checks if `Atomics.wait` may suspend the thread running the context.
     #[inline]
-    pub fn standard_objects(&self) -> &StandardObjects {
-        &self.standard_objects
+    pub fn can_block(&self) -> bool {
+        self.can_block
+    }
+
+    /// Sets how deeply the values walked by `JSON.parse`, `JSON.stringify`, the
+    /// [serialization](crate::serialization) and the [structural comparison](crate::test_support)
+    /// of values may nest objects, which is 10000 levels by default.
+    ///
+    /// Those walks do not recurse on the native stack, so the limit only bounds the memory they
+    /// use: exceeding it throws a `RangeError`.
+    #[inline]
+    pub fn set_max_nesting_depth(&mut self, depth: usize) {
+        self.max_nesting_depth = depth;
+    }
+
+    /// Gets how deeply the values walked by `JSON` and the serialization may nest objects.
+    #[inline]
+    pub fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth
+    }
+
+    /// Throws a `RangeError` if a walk entering `depth` levels of nested objects goes past the
+    /// maximum nesting depth.
+    #[inline]
+    pub(crate) fn check_nesting_depth(&mut self, depth: usize) -> JsResult<()> {
+        if depth > self.max_nesting_depth {
+            return Err(self.construct_range_error(format!(
+                "Maximum nesting depth of {} exceeded",
+                self.max_nesting_depth
+            )));
+        }
+        Ok(())
+    }
+
+    /// Sets the amount of integers, starting from zero, whose strings are created once and shared
+    /// when they are stringified, which is 256 by default.
+    ///
+    /// A size of zero disables the cache.
+    #[inline]
+    pub fn set_number_string_cache_size(&mut self, size: u32) {
+        self.number_strings = NumberStringCache::with_size(size);
     }
 
     /// Set the value of trace on the context
@@ -858,3 +2161,58 @@ impl Context {
         self.trace = trace;
     }
 }
+
+/// Decodes source text starting with a UTF-16 byte order mark into UTF-8.
+///
+/// Any other source is passed through unchanged, since the lexer already handles UTF-8 input
+/// and a leading UTF-8 byte order mark.
+fn decode_source(src: &[u8]) -> Result<Cow<'_, [u8]>, JsError> {
+    let (units, little_endian) = match src {
+        [0xFF, 0xFE, units @ ..] => (units, true),
+        [0xFE, 0xFF, units @ ..] => (units, false),
+        _ => return Ok(Cow::Borrowed(src)),
+    };
+
+    if units.len() % 2 != 0 {
+        return Err(JsError::native(
+            JsNativeErrorKind::Syntax,
+            "UTF-16 source text has an odd number of bytes",
+        ));
+    }
+
+    let units: Vec<u16> = units
+        .chunks_exact(2)
+        .map(|pair| {
+            if little_endian {
+                u16::from_le_bytes([pair[0], pair[1]])
+            } else {
+                u16::from_be_bytes([pair[0], pair[1]])
+            }
+        })
+        .collect();
+
+    String::from_utf16(&units)
+        .map(|src| Cow::Owned(src.into_bytes()))
+        .map_err(|e| {
+            JsError::coded(
+                JsErrorCode::INVALID_UTF16,
+                "source text is not valid UTF-16",
+            )
+            .with_source(e)
+        })
+}
+
+/// Parses the source of a script evaluated by [`Context::eval`].
+#[cfg(not(feature = "shared-cache"))]
+fn parse_script(src: &[u8]) -> Result<Rc<StatementList>, ParseError> {
+    crate::syntax::Parser::new(src, false)
+        .parse_all()
+        .map(Rc::new)
+}
+
+/// Parses the source of a script evaluated by [`Context::eval`], reusing the statement list of
+/// a previous evaluation of the same source on this thread.
+#[cfg(feature = "shared-cache")]
+fn parse_script(src: &[u8]) -> Result<Rc<StatementList>, ParseError> {
+    crate::cache::script(src)
+}
@@ -10,6 +10,7 @@ use crate::{
         environment_record_trait::EnvironmentRecordTrait,
         lexical_environment::{Environment, EnvironmentType},
     },
+    error::JsErrorCode,
     gc::{Finalize, Trace},
     object::JsObject,
     BoaProfiler, Context, JsResult, JsValue,
@@ -96,7 +97,7 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
             name.into_boxed_str(),
             DeclarativeEnvironmentRecordBinding {
                 value: None,
-                can_delete: true,
+                can_delete: false,
                 mutable: false,
                 strict,
             },
@@ -155,10 +156,10 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
             let record = env_rec.get_mut(name).unwrap();
             record.value = Some(value);
         } else if strict {
-            return Err(context.construct_reference_error(format!(
-                "Cannot mutate an immutable binding {}",
-                name
-            )));
+            return Err(context.construct_coded_error(
+                JsErrorCode::IMMUTABLE_BINDING,
+                format!("Cannot mutate an immutable binding {}", name),
+            ));
         }
 
         Ok(())
@@ -181,18 +182,15 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         }
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
-        match self.env_rec.borrow().get(name) {
-            Some(binding) => {
-                if binding.can_delete {
-                    self.env_rec.borrow_mut().remove(name);
-                    true
-                } else {
-                    false
-                }
-            }
-            None => panic!("env_rec has no binding for {}", name),
+    fn delete_binding(&self, name: &str, _context: &mut Context) -> JsResult<bool> {
+        let can_delete = match self.env_rec.borrow().get(name) {
+            Some(binding) => binding.can_delete,
+            None => return Ok(true),
+        };
+        if can_delete {
+            self.env_rec.borrow_mut().remove(name);
         }
+        Ok(can_delete)
     }
 
     fn has_this_binding(&self) -> bool {
@@ -215,6 +213,14 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         self.outer_env.as_ref()
     }
 
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        self.env_rec
+            .borrow_mut()
+            .drain()
+            .filter_map(|(_, binding)| binding.value.clone())
+            .collect()
+    }
+
     fn set_outer_environment(&mut self, env: Environment) {
         self.outer_env = Some(env);
     }
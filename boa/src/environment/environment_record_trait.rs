@@ -9,11 +9,17 @@
 //! There are 5 Environment record kinds. They all have methods in common, these are implemented as a the `EnvironmentRecordTrait`
 //!
 
-use crate::{environment::lexical_environment::VariableScope, object::JsObject};
 use crate::{
     environment::lexical_environment::{Environment, EnvironmentType},
     gc::{Finalize, Trace},
-    Context, JsResult, JsValue,
+    Context, JsResult, JsString, JsValue,
+};
+use crate::{
+    environment::{
+        function_environment_record::FunctionEnvironmentRecord,
+        global_environment_record::GlobalEnvironmentRecord, lexical_environment::VariableScope,
+    },
+    object::JsObject,
 };
 use std::fmt::Debug;
 
@@ -85,7 +91,7 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// The String value name is the text of the bound name.
     /// If a binding for name exists, remove the binding and return true.
     /// If the binding exists but cannot be removed return false. If the binding does not exist return true.
-    fn delete_binding(&self, name: &str) -> bool;
+    fn delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool>;
 
     /// Determine if an Environment Record establishes a this binding.
     /// Return true if it does and false if it does not.
@@ -114,6 +120,43 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Get the type of environment this is
     fn get_environment_type(&self) -> EnvironmentType;
 
+    /// Get the key of the module this is the environment of, if this is a module environment
+    fn get_module_key(&self) -> Option<JsString> {
+        None
+    }
+
+    /// Get whether the code this is the environment of is strict mode code, if this environment
+    /// determines it
+    fn is_strict(&self) -> Option<bool> {
+        None
+    }
+
+    /// Get this environment as a global environment record, if it is one
+    fn as_global_record(&self) -> Option<&GlobalEnvironmentRecord> {
+        None
+    }
+
+    /// Get this environment as a function environment record, if it is one
+    fn as_function_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Get the base object of `super` property accesses, the prototype of the `[[HomeObject]]`,
+    /// if this is a function environment with a super binding
+    fn get_super_base(&self, _context: &mut Context) -> JsResult<Option<JsValue>> {
+        Ok(None)
+    }
+
+    /// Get the `[[NewTarget]]` of this environment, if it is a function environment
+    fn get_new_target(&self) -> Option<JsValue> {
+        None
+    }
+
+    /// Take the values of the bindings of the environment, removing the bindings
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        Vec::new()
+    }
+
     /// Return the `this` binding from the environment or try to get it from outer environments
     fn recursive_get_this_binding(&self, context: &mut Context) -> JsResult<JsValue> {
         if self.has_this_binding() {
@@ -126,6 +169,41 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
         }
     }
 
+    /// Return the function environment providing the `this` binding, or `None` if that is not
+    /// a function environment
+    fn recursive_get_this_function_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        if self.has_this_binding() {
+            self.as_function_record()
+        } else {
+            self.get_outer_environment_ref()
+                .and_then(|outer| outer.recursive_get_this_function_record())
+        }
+    }
+
+    /// Return the base of `super` property accesses of the environment providing the `this`
+    /// binding, or `None` if that environment has no super binding
+    fn recursive_get_super_base(&self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        if self.has_this_binding() {
+            self.get_super_base(context)
+        } else {
+            match self.get_outer_environment_ref() {
+                Some(outer) => outer.recursive_get_super_base(context),
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Return the `[[NewTarget]]` of the environment providing the `this` binding, or `None` if
+    /// that is not a function environment
+    fn recursive_get_new_target(&self) -> Option<JsValue> {
+        if self.has_this_binding() {
+            self.get_new_target()
+        } else {
+            self.get_outer_environment_ref()
+                .and_then(|outer| outer.recursive_get_new_target())
+        }
+    }
+
     /// Create mutable binding while handling outer environments
     fn recursive_create_mutable_binding(
         &self,
@@ -202,6 +280,29 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
             }
     }
 
+    /// Return the `WithBaseObject` of the closest environment having the binding, which is the
+    /// implicit `this` value of calls to `name`
+    fn recursive_get_with_base_object(&self, name: &str) -> Option<JsObject> {
+        if self.has_binding(name) {
+            self.with_base_object()
+        } else {
+            self.get_outer_environment_ref()
+                .and_then(|outer| outer.recursive_get_with_base_object(name))
+        }
+    }
+
+    /// Delete a binding from the closest environment having it, returning true if there is none.
+    fn recursive_delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool> {
+        if self.has_binding(name) {
+            self.delete_binding(name, context)
+        } else {
+            match self.get_outer_environment_ref() {
+                Some(outer) => outer.recursive_delete_binding(name, context),
+                None => Ok(true),
+            }
+        }
+    }
+
     /// Retrieve binding from current or any outer environment
     fn recursive_get_binding_value(&self, name: &str, context: &mut Context) -> JsResult<JsValue> {
         if self.has_binding(name) {
@@ -0,0 +1,172 @@
+//! # Eval Environment Records
+//!
+//! An eval Environment Record is a declarative Environment Record that is used as the
+//! LexicalEnvironment of the code evaluated by `eval`. If the eval code is strict mode code, it
+//! is also its VariableEnvironment, so that `var` and function declarations don't leak out of
+//! the evaluated code. Otherwise those declarations are instantiated in the VariableEnvironment
+//! of the caller, or in the global environment for indirect calls.
+//! More info: <https://tc39.es/ecma262/#sec-performeval>
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentType, VariableScope},
+    },
+    gc::{Finalize, Trace},
+    object::JsObject,
+    Context, JsResult, JsValue,
+};
+use gc::Gc;
+
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct EvalEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    /// Whether the eval code is strict mode code.
+    strict: bool,
+}
+
+impl EvalEnvironmentRecord {
+    pub fn new(env: Option<Environment>, strict: bool) -> EvalEnvironmentRecord {
+        EvalEnvironmentRecord {
+            declarative_record: DeclarativeEnvironmentRecord::new(env),
+            strict,
+        }
+    }
+}
+
+impl EnvironmentRecordTrait for EvalEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        self.declarative_record.has_binding(name)
+    }
+
+    fn create_mutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        allow_name_reuse: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .create_mutable_binding(name, deletion, allow_name_reuse, context)
+    }
+
+    fn create_immutable_binding(
+        &self,
+        name: String,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .create_immutable_binding(name, strict, context)
+    }
+
+    fn initialize_binding(
+        &self,
+        name: &str,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .initialize_binding(name, value, context)
+    }
+
+    fn set_mutable_binding(
+        &self,
+        name: &str,
+        value: JsValue,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .set_mutable_binding(name, value, strict, context)
+    }
+
+    fn get_binding_value(
+        &self,
+        name: &str,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        self.declarative_record
+            .get_binding_value(name, strict, context)
+    }
+
+    fn delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool> {
+        self.declarative_record.delete_binding(name, context)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        false
+    }
+
+    fn get_this_binding(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::undefined())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Option<JsObject> {
+        None
+    }
+
+    fn get_outer_environment_ref(&self) -> Option<&Environment> {
+        self.declarative_record.get_outer_environment_ref()
+    }
+
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        self.declarative_record.take_binding_values()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Declarative
+    }
+
+    fn is_strict(&self) -> Option<bool> {
+        Some(self.strict)
+    }
+
+    fn recursive_create_mutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        scope: VariableScope,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match scope {
+            VariableScope::Function if !self.strict => self
+                .get_outer_environment_ref()
+                .expect("No function or global environment")
+                .recursive_create_mutable_binding(name, deletion, scope, context),
+            _ => self.create_mutable_binding(name, deletion, false, context),
+        }
+    }
+
+    fn recursive_create_immutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        scope: VariableScope,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match scope {
+            VariableScope::Function if !self.strict => self
+                .get_outer_environment_ref()
+                .expect("No function or global environment")
+                .recursive_create_immutable_binding(name, deletion, scope, context),
+            _ => self.create_immutable_binding(name, deletion, context),
+        }
+    }
+}
+
+impl From<EvalEnvironmentRecord> for Environment {
+    fn from(env: EvalEnvironmentRecord) -> Environment {
+        Gc::new(Box::new(env))
+    }
+}
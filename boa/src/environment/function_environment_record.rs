@@ -8,7 +8,7 @@
 //! from within the function.
 //! More info: <https://tc39.es/ecma262/#sec-function-environment-records>
 
-use gc::Gc;
+use gc::{Gc, GcCell};
 
 use crate::{
     environment::{
@@ -42,9 +42,9 @@ unsafe impl Trace for BindingStatus {
 pub struct FunctionEnvironmentRecord {
     pub declarative_record: DeclarativeEnvironmentRecord,
     /// This is the this value used for this invocation of the function.
-    pub this_value: JsValue,
+    pub this_value: GcCell<JsValue>,
     /// If the value is "lexical", this is an ArrowFunction and does not have a local this value.
-    pub this_binding_status: BindingStatus,
+    pub this_binding_status: GcCell<BindingStatus>,
     /// The function object whose invocation caused this Environment Record to be created.
     pub function: JsObject,
     /// If the associated function has super property accesses and is not an ArrowFunction,
@@ -63,37 +63,45 @@ impl FunctionEnvironmentRecord {
         this: Option<JsValue>,
         outer: Option<Environment>,
         binding_status: BindingStatus,
+        home_object: JsValue,
         new_target: JsValue,
     ) -> FunctionEnvironmentRecord {
-        let mut func_env = FunctionEnvironmentRecord {
+        // If a `this` value has been passed, bind it to the environment
+        let (this_binding_status, this_value) = match this {
+            Some(v) => (BindingStatus::Initialized, v),
+            None => (binding_status, JsValue::undefined()),
+        };
+        FunctionEnvironmentRecord {
             declarative_record: DeclarativeEnvironmentRecord::new(outer), // the outer environment will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
             function: f,
-            this_binding_status: binding_status,
-            home_object: JsValue::undefined(),
+            this_binding_status: GcCell::new(this_binding_status),
+            home_object,
             new_target,
-            this_value: JsValue::undefined(),
-        };
-        // If a `this` value has been passed, bind it to the environment
-        if let Some(v) = this {
-            func_env.bind_this_value(v).unwrap();
+            this_value: GcCell::new(this_value),
         }
-        func_env
     }
 
-    pub fn bind_this_value(&mut self, value: JsValue) -> JsResult<JsValue> {
-        match self.this_binding_status {
+    /// Binds the `this` value of the function, which is only bound later than the creation of
+    /// the environment in the constructor of a derived class, by its `super(...)` call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bindthisvalue
+    pub fn bind_this_value(&self, value: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let status = *self.this_binding_status.borrow();
+        match status {
             // You can not bind an arrow function, their `this` value comes from the lexical scope above
             BindingStatus::Lexical => {
                 panic!("Cannot bind to an arrow function!");
             }
             // You can not bind a function twice
             BindingStatus::Initialized => {
-                todo!();
-                // context.throw_reference_error("Cannot bind to an initialised function!")
+                context.throw_reference_error("Super constructor may only be called once")
             }
             BindingStatus::Uninitialized => {
-                self.this_value = value.clone();
-                self.this_binding_status = BindingStatus::Initialized;
+                *self.this_value.borrow_mut() = value.clone();
+                *self.this_binding_status.borrow_mut() = BindingStatus::Initialized;
                 Ok(value)
             }
         }
@@ -169,28 +177,29 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             .get_binding_value(name, strict, context)
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
-        self.declarative_record.delete_binding(name)
+    fn delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool> {
+        self.declarative_record.delete_binding(name, context)
     }
 
     fn has_this_binding(&self) -> bool {
-        !matches!(self.this_binding_status, BindingStatus::Lexical)
+        !matches!(*self.this_binding_status.borrow(), BindingStatus::Lexical)
     }
 
     fn get_this_binding(&self, context: &mut Context) -> JsResult<JsValue> {
-        match self.this_binding_status {
+        let status = *self.this_binding_status.borrow();
+        match status {
             BindingStatus::Lexical => {
                 panic!("There is no this for a lexical function record");
             }
-            BindingStatus::Uninitialized => {
-                context.throw_reference_error("Uninitialised binding for this function")
-            }
-            BindingStatus::Initialized => Ok(self.this_value.clone()),
+            BindingStatus::Uninitialized => context.throw_reference_error(
+                "Must call super constructor before accessing 'this' in a derived class",
+            ),
+            BindingStatus::Initialized => Ok(self.this_value.borrow().clone()),
         }
     }
 
     fn has_super_binding(&self) -> bool {
-        if let BindingStatus::Lexical = self.this_binding_status {
+        if let BindingStatus::Lexical = *self.this_binding_status.borrow() {
             false
         } else {
             !self.home_object.is_undefined()
@@ -205,6 +214,36 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         self.declarative_record.get_outer_environment_ref()
     }
 
+    fn get_super_base(&self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // 1. Let home be envRec.[[FunctionObject]].[[HomeObject]].
+        // 2. If home has the value undefined, return undefined.
+        if !self.has_super_binding() {
+            return Ok(None);
+        }
+
+        // 3. Assert: Type(home) is Object.
+        let home = self
+            .home_object
+            .as_object()
+            .expect("the home object of a function must be an object");
+
+        // 4. Return ? home.[[GetPrototypeOf]]().
+        home.__get_prototype_of__(context).map(Some)
+    }
+
+    fn get_new_target(&self) -> Option<JsValue> {
+        Some(self.new_target.clone())
+    }
+
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        let mut values = self.declarative_record.take_binding_values();
+        values.push(self.this_value.borrow().clone());
+        values.push(self.function.clone().into());
+        values.push(self.home_object.clone());
+        values.push(self.new_target.clone());
+        values
+    }
+
     fn set_outer_environment(&mut self, env: Environment) {
         self.declarative_record.set_outer_environment(env)
     }
@@ -213,6 +252,17 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         EnvironmentType::Function
     }
 
+    fn as_function_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        Some(self)
+    }
+
+    fn is_strict(&self) -> Option<bool> {
+        self.function
+            .borrow()
+            .as_function()
+            .map(|function| function.is_strict())
+    }
+
     fn recursive_create_mutable_binding(
         &self,
         name: String,
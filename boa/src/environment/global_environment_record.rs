@@ -25,7 +25,7 @@ use rustc_hash::FxHashSet;
 #[derive(Debug, Trace, Finalize, Clone)]
 pub struct GlobalEnvironmentRecord {
     pub object_record: ObjectEnvironmentRecord,
-    pub global_this_binding: JsObject,
+    pub global_this_binding: GcCell<JsObject>,
     pub declarative_record: DeclarativeEnvironmentRecord,
     pub var_names: GcCell<FxHashSet<Box<str>>>,
 }
@@ -35,11 +35,11 @@ impl GlobalEnvironmentRecord {
         let obj_rec = ObjectEnvironmentRecord {
             bindings: global.into(),
             outer_env: None,
-            /// Object Environment Records created for with statements (13.11)
-            /// can provide their binding object as an implicit this value for use in function calls.
-            /// The capability is controlled by a withEnvironment Boolean value that is associated
-            /// with each object Environment Record. By default, the value of withEnvironment is false
-            /// for any object Environment Record.
+            // Object Environment Records created for with statements (13.11)
+            // can provide their binding object as an implicit this value for use in function calls.
+            // The capability is controlled by a withEnvironment Boolean value that is associated
+            // with each object Environment Record. By default, the value of withEnvironment is false
+            // for any object Environment Record.
             with_environment: false,
         };
 
@@ -47,12 +47,22 @@ impl GlobalEnvironmentRecord {
 
         GlobalEnvironmentRecord {
             object_record: obj_rec,
-            global_this_binding: this_value,
+            global_this_binding: GcCell::new(this_value),
             declarative_record: dcl_rec,
             var_names: GcCell::new(FxHashSet::default()),
         }
     }
 
+    /// Returns the value of `this` in global code, the `[[GlobalThisValue]]` of the record.
+    pub fn global_this_binding(&self) -> JsObject {
+        self.global_this_binding.borrow().clone()
+    }
+
+    /// Replaces the value of `this` in global code.
+    pub fn set_global_this_binding(&self, this_value: JsObject) {
+        *self.global_this_binding.borrow_mut() = this_value;
+    }
+
     pub fn has_var_declaration(&self, name: &str) -> bool {
         self.var_names.borrow().contains(name)
     }
@@ -233,23 +243,40 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.get_binding_value(name, strict, context)
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
+    fn delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool> {
+        // 1. Let DclRec be envRec.[[DeclarativeRecord]].
+        // 2. If DclRec.HasBinding(N) is true, then
         if self.declarative_record.has_binding(name) {
-            return self.declarative_record.delete_binding(name);
+            // a. Return DclRec.DeleteBinding(N).
+            return self.declarative_record.delete_binding(name, context);
         }
 
-        let global: &JsValue = &self.object_record.bindings;
-        if global.has_field(name) {
-            let status = self.object_record.delete_binding(name);
+        // 3. Let ObjRec be envRec.[[ObjectRecord]].
+        // 4. Let globalObject be ObjRec.[[BindingObject]].
+        // 5. Let existingProp be ? HasOwnProperty(globalObject, N).
+        let existing_prop = match self.object_record.bindings.as_object() {
+            Some(global_object) => global_object.has_own_property(name, context)?,
+            None => false,
+        };
+
+        // 6. If existingProp is true, then
+        if existing_prop {
+            // a. Let status be ? ObjRec.DeleteBinding(N).
+            let status = self.object_record.delete_binding(name, context)?;
+
+            // b. If status is true, then
             if status {
-                let mut var_names = self.var_names.borrow_mut();
-                if var_names.contains(name) {
-                    var_names.remove(name);
-                    return status;
-                }
+                // i. Let varNames be envRec.[[VarNames]].
+                // ii. If N is an element of varNames, remove that element from the varNames.
+                self.var_names.borrow_mut().remove(name);
             }
+
+            // c. Return status.
+            return Ok(status);
         }
-        true
+
+        // 7. Return true.
+        Ok(true)
     }
 
     fn has_this_binding(&self) -> bool {
@@ -257,7 +284,7 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
     }
 
     fn get_this_binding(&self, _context: &mut Context) -> JsResult<JsValue> {
-        Ok(self.global_this_binding.clone().into())
+        Ok(self.global_this_binding().into())
     }
 
     fn has_super_binding(&self) -> bool {
@@ -276,6 +303,13 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         None
     }
 
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        let mut values = self.declarative_record.take_binding_values();
+        values.append(&mut self.object_record.take_binding_values());
+        values.push(self.global_this_binding().into());
+        values
+    }
+
     fn set_outer_environment(&mut self, _env: Environment) {
         // TODO: Implement
         todo!("Not implemented yet")
@@ -285,14 +319,32 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         EnvironmentType::Global
     }
 
+    fn as_global_record(&self) -> Option<&GlobalEnvironmentRecord> {
+        Some(self)
+    }
+
     fn recursive_create_mutable_binding(
         &self,
         name: String,
         deletion: bool,
-        _scope: VariableScope,
+        scope: VariableScope,
         context: &mut Context,
     ) -> JsResult<()> {
-        self.create_mutable_binding(name, deletion, false, context)
+        match scope {
+            // Top-level `var` and function declarations are properties of the global object.
+            // <https://tc39.es/ecma262/#sec-createglobalvarbinding>
+            VariableScope::Function if !self.declarative_record.has_binding(&name) => {
+                self.object_record.create_mutable_binding(
+                    name.clone(),
+                    deletion,
+                    false,
+                    context,
+                )?;
+                self.var_names.borrow_mut().insert(name.into_boxed_str());
+                Ok(())
+            }
+            _ => self.create_mutable_binding(name, deletion, false, context),
+        }
     }
 
     fn recursive_create_immutable_binding(
@@ -8,7 +8,7 @@
 use super::global_environment_record::GlobalEnvironmentRecord;
 use crate::{
     environment::environment_record_trait::EnvironmentRecordTrait, object::JsObject, BoaProfiler,
-    Context, JsResult, JsValue,
+    Context, JsResult, JsString, JsValue,
 };
 use gc::Gc;
 use std::{collections::VecDeque, error, fmt};
@@ -23,6 +23,7 @@ pub enum EnvironmentType {
     Declarative,
     Function,
     Global,
+    Module,
     Object,
 }
 
@@ -74,6 +75,15 @@ impl LexicalEnvironment {
         lexical_env.environment_stack.push_back(global_env.into());
         lexical_env
     }
+
+    /// Creates a lexical environment whose outermost environment is the given global record.
+    pub(crate) fn with_global_record(global_env: GlobalEnvironmentRecord) -> Self {
+        let mut lexical_env = Self {
+            environment_stack: VecDeque::new(),
+        };
+        lexical_env.environment_stack.push_back(global_env.into());
+        lexical_env
+    }
 }
 
 impl Context {
@@ -84,15 +94,82 @@ impl Context {
             .push_back(env.into());
     }
 
+    /// Returns the global environment, which is the outermost environment of the stack.
+    pub(crate) fn get_global_environment(&self) -> Environment {
+        self.realm
+            .environment
+            .environment_stack
+            .front()
+            .expect("the global environment is always on the stack")
+            .clone()
+    }
+
+    /// Returns the key of the module whose code is running, if any.
+    ///
+    /// Functions keep the environment they were created in, so the module of a function is found
+    /// by walking up its environments, wherever the function is called from.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getactivescriptormodule
+    pub(crate) fn get_active_module_key(&mut self) -> Option<JsString> {
+        let mut environment = Some(self.get_current_environment().clone());
+        while let Some(env) = environment {
+            if let Some(key) = env.get_module_key() {
+                return Some(key);
+            }
+            environment = env.get_outer_environment();
+        }
+        None
+    }
+
+    /// Checks if the running code is strict mode code, which is determined by the closest
+    /// function, module or eval environment, or by the running script otherwise.
+    pub(crate) fn is_strict(&mut self) -> bool {
+        let mut environment = Some(self.get_current_environment().clone());
+        while let Some(env) = environment {
+            if let Some(strict) = env.is_strict() {
+                return strict;
+            }
+            environment = env.get_outer_environment();
+        }
+        self.executor().strict()
+    }
+
     pub(crate) fn pop_environment(&mut self) -> Option<Environment> {
         self.realm.environment.environment_stack.pop_back()
     }
 
+    /// Returns the number of environments in the environment stack.
+    pub(crate) fn environment_stack_len(&self) -> usize {
+        self.realm.environment.environment_stack.len()
+    }
+
+    /// Removes the environments above the first `len` ones from the environment stack, and
+    /// returns them with the innermost one last.
+    pub(crate) fn split_off_environments(&mut self, len: usize) -> Vec<Environment> {
+        self.realm
+            .environment
+            .environment_stack
+            .split_off(len)
+            .into()
+    }
+
     pub(crate) fn get_this_binding(&mut self) -> JsResult<JsValue> {
         self.get_current_environment()
             .recursive_get_this_binding(self)
     }
 
+    pub(crate) fn get_super_base(&mut self) -> JsResult<Option<JsValue>> {
+        self.get_current_environment()
+            .recursive_get_super_base(self)
+    }
+
+    pub(crate) fn get_new_target(&mut self) -> Option<JsValue> {
+        self.get_current_environment().recursive_get_new_target()
+    }
+
     pub(crate) fn create_mutable_binding(
         &mut self,
         name: String,
@@ -143,10 +220,24 @@ impl Context {
         self.get_current_environment().recursive_has_binding(name)
     }
 
+    /// Gets the implicit `this` value of a call to `name`, which is the binding object of the
+    /// closest `with` statement providing the binding, or `undefined` otherwise.
+    pub(crate) fn get_implicit_this_value(&mut self, name: &str) -> JsValue {
+        self.get_current_environment()
+            .recursive_get_with_base_object(name)
+            .map(JsValue::from)
+            .unwrap_or_default()
+    }
+
     pub(crate) fn get_binding_value(&mut self, name: &str) -> JsResult<JsValue> {
         self.get_current_environment()
             .recursive_get_binding_value(name, self)
     }
+
+    pub(crate) fn delete_binding(&mut self, name: &str) -> JsResult<bool> {
+        self.get_current_environment()
+            .recursive_delete_binding(name, self)
+    }
 }
 
 #[cfg(test)]
@@ -243,4 +334,69 @@ mod tests {
 
         assert_eq!(&exec(scenario), "true");
     }
+
+    #[test]
+    fn let_temporal_dead_zone() {
+        let scenario = r#"
+          let bar = "outer";
+          function read() { return baz; }
+          let results = [];
+          {
+            try {
+              bar;
+            } catch (err) {
+              results.push(err.name);
+            }
+            let bar = "inner";
+            results.push(bar);
+          }
+          try {
+            read();
+          } catch (err) {
+            results.push(err.name);
+          }
+          let baz = "baz";
+          results.push(read());
+          results.join()
+        "#;
+
+        assert_eq!(
+            &exec(scenario),
+            "\"ReferenceError,inner,ReferenceError,baz\""
+        );
+    }
+
+    #[test]
+    fn const_temporal_dead_zone_in_switch() {
+        let scenario = r#"
+          let result;
+          switch (1) {
+            case 0:
+              const bar = "bar";
+            case 1:
+              try {
+                bar;
+              } catch (err) {
+                result = err.name;
+              }
+          }
+          result
+        "#;
+
+        assert_eq!(&exec(scenario), "\"ReferenceError\"");
+    }
+
+    #[test]
+    fn const_reassignment() {
+        let scenario = r#"
+          const bar = "bar";
+          try {
+            bar = "foo";
+          } catch (err) {
+            err.name + ": " + bar
+          }
+        "#;
+
+        assert_eq!(&exec(scenario), "\"TypeError: bar\"");
+    }
 }
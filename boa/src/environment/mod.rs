@@ -2,7 +2,9 @@
 
 pub mod declarative_environment_record;
 pub mod environment_record_trait;
+pub mod eval_environment_record;
 pub mod function_environment_record;
 pub mod global_environment_record;
 pub mod lexical_environment;
+pub mod module_environment_record;
 pub mod object_environment_record;
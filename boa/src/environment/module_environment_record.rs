@@ -0,0 +1,209 @@
+//! # Module Environment Records
+//!
+//! A module Environment Record is a declarative Environment Record that is used to represent the
+//! outer scope of an ECMAScript Module. In addition to normal mutable and immutable bindings,
+//! module Environment Records also provide immutable import bindings which are bindings that
+//! provide indirect access to a target binding that exists in another Environment Record.
+//! More info: <https://tc39.es/ecma262/#sec-module-environment-records>
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentType, VariableScope},
+    },
+    error::JsErrorCode,
+    gc::{Finalize, Trace},
+    module::Module,
+    object::JsObject,
+    Context, JsResult, JsString, JsValue,
+};
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+
+/// An import binding, giving access to the binding `name` of the environment of another module.
+#[derive(Debug, Trace, Finalize, Clone)]
+struct ImportBinding {
+    module: Module,
+    name: Box<str>,
+}
+
+/// <https://tc39.es/ecma262/#sec-module-environment-records>
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    imports: GcCell<FxHashMap<Box<str>, ImportBinding>>,
+    module_key: JsString,
+}
+
+impl ModuleEnvironmentRecord {
+    pub fn new(env: Option<Environment>, module_key: JsString) -> ModuleEnvironmentRecord {
+        ModuleEnvironmentRecord {
+            declarative_record: DeclarativeEnvironmentRecord::new(env),
+            imports: GcCell::new(FxHashMap::default()),
+            module_key,
+        }
+    }
+
+    /// `CreateImportBinding ( N, M, N2 )`
+    ///
+    /// Creates an immutable indirect binding for `name`, which resolves to the binding
+    /// `target_name` of the environment of the `target` module each time it is accessed.
+    pub(crate) fn create_import_binding(&self, name: String, target: Module, target_name: &str) {
+        assert!(
+            !self.has_binding(&name),
+            "Identifier {} has already been declared",
+            name
+        );
+
+        self.imports.borrow_mut().insert(
+            name.into_boxed_str(),
+            ImportBinding {
+                module: target,
+                name: target_name.into(),
+            },
+        );
+    }
+}
+
+impl EnvironmentRecordTrait for ModuleEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        self.imports.borrow().contains_key(name) || self.declarative_record.has_binding(name)
+    }
+
+    fn create_mutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        allow_name_reuse: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .create_mutable_binding(name, deletion, allow_name_reuse, context)
+    }
+
+    fn create_immutable_binding(
+        &self,
+        name: String,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .create_immutable_binding(name, strict, context)
+    }
+
+    fn initialize_binding(
+        &self,
+        name: &str,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.declarative_record
+            .initialize_binding(name, value, context)
+    }
+
+    fn set_mutable_binding(
+        &self,
+        name: &str,
+        value: JsValue,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if self.imports.borrow().contains_key(name) {
+            return Err(context.construct_coded_error(
+                JsErrorCode::ASSIGN_TO_IMPORT,
+                format!("Cannot assign to the imported binding {}", name),
+            ));
+        }
+        self.declarative_record
+            .set_mutable_binding(name, value, strict, context)
+    }
+
+    fn get_binding_value(
+        &self,
+        name: &str,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let import = self.imports.borrow().get(name).cloned();
+        match import {
+            Some(import) => import.module.get_binding_value(&import.name, context),
+            None => self
+                .declarative_record
+                .get_binding_value(name, strict, context),
+        }
+    }
+
+    fn delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool> {
+        if self.imports.borrow().contains_key(name) {
+            return Ok(false);
+        }
+        self.declarative_record.delete_binding(name, context)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        true
+    }
+
+    fn get_this_binding(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::undefined())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Option<JsObject> {
+        None
+    }
+
+    fn get_outer_environment_ref(&self) -> Option<&Environment> {
+        self.declarative_record.get_outer_environment_ref()
+    }
+
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        self.declarative_record.take_binding_values()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Module
+    }
+
+    fn get_module_key(&self) -> Option<JsString> {
+        Some(self.module_key.clone())
+    }
+
+    fn is_strict(&self) -> Option<bool> {
+        Some(true)
+    }
+
+    fn recursive_create_mutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        _scope: VariableScope,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.create_mutable_binding(name, deletion, false, context)
+    }
+
+    fn recursive_create_immutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        _scope: VariableScope,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.create_immutable_binding(name, deletion, context)
+    }
+}
+
+impl From<ModuleEnvironmentRecord> for Environment {
+    fn from(env: ModuleEnvironmentRecord) -> Environment {
+        Gc::new(Box::new(env))
+    }
+}
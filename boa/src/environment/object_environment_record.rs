@@ -15,7 +15,8 @@ use crate::{
     },
     gc::{Finalize, Trace},
     object::JsObject,
-    property::PropertyDescriptor,
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
     Context, JsResult, JsValue,
 };
 
@@ -32,11 +33,11 @@ impl ObjectEnvironmentRecord {
         ObjectEnvironmentRecord {
             bindings: object,
             outer_env: environment,
-            /// Object Environment Records created for with statements (13.11)
-            /// can provide their binding object as an implicit this value for use in function calls.
-            /// The capability is controlled by a withEnvironment Boolean value that is associated
-            /// with each object Environment Record. By default, the value of withEnvironment is false
-            /// for any object Environment Record.
+            // Object Environment Records created for with statements (13.11)
+            // can provide their binding object as an implicit this value for use in function calls.
+            // The capability is controlled by a withEnvironment Boolean value that is associated
+            // with each object Environment Record. By default, the value of withEnvironment is false
+            // for any object Environment Record.
             with_environment: false,
         }
     }
@@ -44,13 +45,34 @@ impl ObjectEnvironmentRecord {
 
 impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     fn has_binding(&self, name: &str) -> bool {
-        if self.bindings.has_field(name) {
-            if self.with_environment {
-                // TODO: implement unscopables
-            }
-            true
-        } else {
-            false
+        // 1. Let bindingObject be envRec.[[BindingObject]].
+        // 2. Let foundBinding be ? HasProperty(bindingObject, N).
+        // 3. If foundBinding is false, return false.
+        if self.bindings.get_property(name).is_none() {
+            return false;
+        }
+
+        // 4. If envRec.[[IsWithEnvironment]] is false, return true.
+        if !self.with_environment {
+            return true;
+        }
+
+        // 5. Let unscopables be ? Get(bindingObject, @@unscopables).
+        // 6. If Type(unscopables) is Object, then
+        //     a. Let blocked be ! ToBoolean(? Get(unscopables, N)).
+        //     b. If blocked is true, return false.
+        // 7. Return true.
+        // NOTE: without a context, accessors of `@@unscopables` are not invoked.
+        let data_value = |value: &JsValue, key: PropertyKey| {
+            value
+                .get_property(key)
+                .and_then(|prop| prop.value().cloned())
+        };
+        match data_value(&self.bindings, WellKnownSymbols::unscopables().into()) {
+            Some(unscopables) if unscopables.is_object() => !data_value(&unscopables, name.into())
+                .map(|blocked| blocked.to_boolean())
+                .unwrap_or(false),
+            _ => true,
         }
     }
 
@@ -101,17 +123,20 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         name: &str,
         value: JsValue,
         strict: bool,
-        _context: &mut Context,
+        context: &mut Context,
     ) -> JsResult<()> {
-        debug_assert!(value.is_object() || value.is_function());
-        let property = PropertyDescriptor::builder()
-            .value(value)
-            .enumerable(true)
-            .configurable(strict);
+        // 1. Let bindingObject be envRec.[[BindingObject]].
+        // 2. Let stillExists be ? HasProperty(bindingObject, N).
+        // 3. If stillExists is false and S is true, throw a ReferenceError exception.
+        if strict && self.bindings.get_property(name).is_none() {
+            return Err(context.construct_reference_error(format!("{} is not defined", name)));
+        }
+
+        // 4. Return ? Set(bindingObject, N, V, S).
         self.bindings
             .as_object()
             .expect("binding object")
-            .insert(name, property);
+            .set(name, value, strict, context)?;
         Ok(())
     }
 
@@ -121,24 +146,29 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         strict: bool,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        if self.bindings.has_field(name) {
-            Ok(self
-                .bindings
-                .get_property(name)
-                .as_ref()
-                .and_then(|prop| prop.value())
-                .cloned()
-                .unwrap_or_default())
-        } else if strict {
-            context.throw_reference_error(format!("{} has no binding", name))
-        } else {
-            Ok(JsValue::undefined())
+        // 1. Let bindingObject be envRec.[[BindingObject]].
+        // 2. Let value be ? HasProperty(bindingObject, N).
+        // 3. If value is false, then
+        //     a. If S is false, return the value undefined; otherwise throw a ReferenceError exception.
+        if self.bindings.get_property(name).is_none() {
+            return if strict {
+                context.throw_reference_error(format!("{} has no binding", name))
+            } else {
+                Ok(JsValue::undefined())
+            };
         }
+
+        // 4. Return ? Get(bindingObject, N).
+        self.bindings.get_field(name, context)
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
-        self.bindings.remove_property(name);
-        true
+    fn delete_binding(&self, name: &str, context: &mut Context) -> JsResult<bool> {
+        // 1. Let bindingObject be envRec.[[BindingObject]].
+        // 2. Return ? bindingObject.[[Delete]](N).
+        match self.bindings.as_object() {
+            Some(binding_object) => binding_object.__delete__(&name.into(), context),
+            None => Ok(true),
+        }
     }
 
     fn has_this_binding(&self) -> bool {
@@ -167,12 +197,16 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         self.outer_env.as_ref()
     }
 
+    fn take_binding_values(&self) -> Vec<JsValue> {
+        vec![self.bindings.clone()]
+    }
+
     fn set_outer_environment(&mut self, env: Environment) {
         self.outer_env = Some(env);
     }
 
     fn get_environment_type(&self) -> EnvironmentType {
-        EnvironmentType::Function
+        EnvironmentType::Object
     }
 }
 
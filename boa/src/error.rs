@@ -0,0 +1,770 @@
+//! This module implements the `JsError` type, the error type returned to embedders.
+//!
+//! Inside the engine, errors are represented by the thrown `JsValue`. Embedders, on the other
+//! hand, usually want to inspect an error without having to re-enter the `Context` to read its
+//! properties. `JsError` gives them a structured view of a thrown value that can be matched on,
+//! and that implements [`std::error::Error`].
+//!
+//! Errors generated by the engine itself also carry a [`JsErrorCode`], a stable identifier like
+//! `BOA-TYP-0003` that hosts can match on instead of parsing the English message. The code is
+//! exposed on the error object as its `code` property, and through [`JsError::code`].
+
+use crate::{object::JsObject, syntax::parser::ParseError, Context, JsValue};
+use std::{error, fmt, str::FromStr};
+
+/// The kind of a native error object.
+///
+/// Each kind corresponds to one of the native error constructors of the global object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsNativeErrorKind {
+    /// The `Error` kind.
+    Error,
+    /// The `EvalError` kind.
+    Eval,
+    /// The `RangeError` kind.
+    Range,
+    /// The `ReferenceError` kind.
+    Reference,
+    /// The `SyntaxError` kind.
+    Syntax,
+    /// The `TypeError` kind.
+    Type,
+    /// The `URIError` kind.
+    Uri,
+}
+
+impl JsNativeErrorKind {
+    /// All the native error kinds, the most specific ones first.
+    const ALL: [Self; 7] = [
+        Self::Eval,
+        Self::Range,
+        Self::Reference,
+        Self::Syntax,
+        Self::Type,
+        Self::Uri,
+        Self::Error,
+    ];
+
+    /// Gets the name of the constructor of this kind of error.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Error => "Error",
+            Self::Eval => "EvalError",
+            Self::Range => "RangeError",
+            Self::Reference => "ReferenceError",
+            Self::Syntax => "SyntaxError",
+            Self::Type => "TypeError",
+            Self::Uri => "URIError",
+        }
+    }
+
+    /// Gets the three-letter abbreviation used for this kind in error codes.
+    fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Error => "ERR",
+            Self::Eval => "EVL",
+            Self::Range => "RNG",
+            Self::Reference => "REF",
+            Self::Syntax => "SYN",
+            Self::Type => "TYP",
+            Self::Uri => "URI",
+        }
+    }
+}
+
+impl fmt::Display for JsNativeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A stable, machine-readable code identifying an error message generated by the engine.
+///
+/// Codes are displayed as `BOA-<kind>-<number>`, for example `BOA-TYP-0003`. A code is never
+/// reused for a different message once assigned, even if the wording of the message changes.
+///
+/// # Examples
+///
+/// ```
+/// use boa::{error::JsErrorCode, Context};
+///
+/// let mut context = Context::new();
+///
+/// let error = context.eval("null.property").unwrap_err();
+/// let code = error.code().unwrap();
+/// assert_eq!(code.to_string(), "BOA-TYP-0001");
+/// assert_eq!(Some(code), "BOA-TYP-0001".parse::<JsErrorCode>().ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JsErrorCode {
+    kind: JsNativeErrorKind,
+    number: u16,
+}
+
+impl JsErrorCode {
+    // New codes are only ever appended to their kind, so that existing codes stay stable.
+    // Codes whose message is no longer generated by the engine are kept, and never reused.
+
+    /// `TypeError: cannot convert 'null' or 'undefined' to object`
+    pub const NULL_TO_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 1);
+
+    /// `TypeError: not a function`
+    pub const NOT_A_FUNCTION: Self = Self::new(JsNativeErrorKind::Type, 2);
+
+    /// `TypeError: {} is not a function`
+    pub const NAMED_NOT_A_FUNCTION: Self = Self::new(JsNativeErrorKind::Type, 3);
+
+    /// `TypeError: not a constructor`
+    pub const NOT_A_CONSTRUCTOR: Self = Self::new(JsNativeErrorKind::Type, 4);
+
+    /// `TypeError: {} is not a constructor`
+    pub const NAMED_NOT_A_CONSTRUCTOR: Self = Self::new(JsNativeErrorKind::Type, 5);
+
+    /// `TypeError: Cannot mutate an immutable binding {}`
+    pub const IMMUTABLE_BINDING: Self = Self::new(JsNativeErrorKind::Type, 6);
+
+    /// `TypeError: right-hand side of 'instanceof' is not callable`
+    pub const INSTANCEOF_NOT_CALLABLE: Self = Self::new(JsNativeErrorKind::Type, 7);
+
+    /// `TypeError: value is not an object`
+    pub const NOT_AN_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 8);
+
+    /// `TypeError: target must be an object`
+    pub const TARGET_NOT_AN_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 9);
+
+    /// `TypeError: target must be a function`
+    pub const TARGET_NOT_A_FUNCTION: Self = Self::new(JsNativeErrorKind::Type, 10);
+
+    /// `TypeError: target must be a constructor`
+    pub const TARGET_NOT_A_CONSTRUCTOR: Self = Self::new(JsNativeErrorKind::Type, 11);
+
+    /// `TypeError: property descriptor must be an object`
+    pub const DESCRIPTOR_NOT_AN_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 12);
+
+    /// `TypeError: proto must be an object or null`
+    pub const INVALID_PROTOTYPE: Self = Self::new(JsNativeErrorKind::Type, 13);
+
+    /// `TypeError: property 'constructor' is not an object`
+    pub const CONSTRUCTOR_NOT_AN_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 14);
+
+    /// `TypeError: property 'constructor' is not a constructor`
+    pub const CONSTRUCTOR_NOT_A_CONSTRUCTOR: Self = Self::new(JsNativeErrorKind::Type, 15);
+
+    /// `TypeError: Symbol.species must be a constructor`
+    pub const SPECIES_NOT_A_CONSTRUCTOR: Self = Self::new(JsNativeErrorKind::Type, 16);
+
+    /// `TypeError: cannot redefine property: {}`
+    pub const REDEFINE_PROPERTY: Self = Self::new(JsNativeErrorKind::Type, 17);
+
+    /// `TypeError: cannot set non-writable property: {}`
+    pub const NON_WRITABLE_PROPERTY: Self = Self::new(JsNativeErrorKind::Type, 18);
+
+    /// `TypeError: cannot delete property: {}`
+    pub const DELETE_PROPERTY: Self = Self::new(JsNativeErrorKind::Type, 19);
+
+    /// `TypeError: Cannot delete property '{}' of object`
+    pub const DELETE_OBJECT_PROPERTY: Self = Self::new(JsNativeErrorKind::Type, 20);
+
+    /// `TypeError: Cannot destructure 'undefined' value`
+    pub const DESTRUCTURE_UNDEFINED: Self = Self::new(JsNativeErrorKind::Type, 21);
+
+    /// `TypeError: Cannot destructure 'null' value`
+    pub const DESTRUCTURE_NULL: Self = Self::new(JsNativeErrorKind::Type, 22);
+
+    /// `TypeError: iterator result is not an object`
+    pub const ITERATOR_RESULT_NOT_AN_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 23);
+
+    /// `TypeError: iterator is not an object`
+    pub const ITERATOR_NOT_AN_OBJECT: Self = Self::new(JsNativeErrorKind::Type, 24);
+
+    /// `TypeError: Could not find property `next``
+    pub const MISSING_NEXT: Self = Self::new(JsNativeErrorKind::Type, 25);
+
+    /// `TypeError: 'this' is not a Map`
+    pub const NOT_A_MAP: Self = Self::new(JsNativeErrorKind::Type, 26);
+
+    /// `TypeError: 'this' is not a Set`
+    pub const NOT_A_SET: Self = Self::new(JsNativeErrorKind::Type, 27);
+
+    /// `TypeError: TypedArray buffer was detached`
+    pub const DETACHED_TYPED_ARRAY: Self = Self::new(JsNativeErrorKind::Type, 28);
+
+    /// `TypeError: DataView: buffer is detached`
+    pub const DETACHED_DATA_VIEW: Self = Self::new(JsNativeErrorKind::Type, 29);
+
+    /// `TypeError: cannot construct a TypedArray from a detached buffer`
+    pub const CONSTRUCT_FROM_DETACHED_BUFFER: Self = Self::new(JsNativeErrorKind::Type, 30);
+
+    /// `TypeError: Cannot assign to the imported binding {}`
+    pub const ASSIGN_TO_IMPORT: Self = Self::new(JsNativeErrorKind::Type, 31);
+
+    /// `TypeError: invalid assignment to {}`
+    pub const INVALID_ASSIGNMENT: Self = Self::new(JsNativeErrorKind::Type, 32);
+
+    /// `TypeError: {} objects cannot be serialized`
+    pub const NOT_SERIALIZABLE: Self = Self::new(JsNativeErrorKind::Type, 33);
+
+    /// `SyntaxError: a declaration in the head of a for-in loop can't have an initializer`
+    pub const FOR_IN_INITIALIZER: Self = Self::new(JsNativeErrorKind::Syntax, 1);
+
+    /// `SyntaxError: a declaration in the head of a for-of loop can't have an initializer`
+    pub const FOR_OF_INITIALIZER: Self = Self::new(JsNativeErrorKind::Syntax, 2);
+
+    /// `SyntaxError: unknown left hand side in head of for-in loop`
+    pub const FOR_IN_TARGET: Self = Self::new(JsNativeErrorKind::Syntax, 3);
+
+    /// `SyntaxError: unknown left hand side in head of for-of loop`
+    pub const FOR_OF_TARGET: Self = Self::new(JsNativeErrorKind::Syntax, 4);
+
+    /// `SyntaxError: missing = in const declaration`
+    pub const CONST_WITHOUT_INITIALIZER: Self = Self::new(JsNativeErrorKind::Syntax, 5);
+
+    /// `SyntaxError: invalid parameters or body for a dynamic function`
+    pub const INVALID_DYNAMIC_FUNCTION: Self = Self::new(JsNativeErrorKind::Syntax, 6);
+
+    /// `SyntaxError: RegExp flags contains multiple '{}'`
+    pub const DUPLICATE_REGEXP_FLAG: Self = Self::new(JsNativeErrorKind::Syntax, 7);
+
+    /// `SyntaxError: RegExp pattern contains duplicate capture group name '{}'`
+    pub const DUPLICATE_CAPTURE_GROUP: Self = Self::new(JsNativeErrorKind::Syntax, 8);
+
+    /// `SyntaxError: failed to create matcher: {}`
+    pub const INVALID_REGEXP: Self = Self::new(JsNativeErrorKind::Syntax, 9);
+
+    /// `SyntaxError: Unexpected end of JSON input`
+    pub const JSON_ABRUPT_END: Self = Self::new(JsNativeErrorKind::Syntax, 10);
+
+    /// `SyntaxError: source text is not valid UTF-16`
+    pub const INVALID_UTF16: Self = Self::new(JsNativeErrorKind::Syntax, 11);
+
+    /// `SyntaxError: expected {}, got '{}' in {} at line {}, col {}`
+    pub const EXPECTED_TOKEN: Self = Self::new(JsNativeErrorKind::Syntax, 12);
+
+    /// `SyntaxError: unexpected token '{}'{} at line {}, col {}`
+    pub const UNEXPECTED_TOKEN: Self = Self::new(JsNativeErrorKind::Syntax, 13);
+
+    /// `SyntaxError: abrupt end`
+    pub const ABRUPT_END: Self = Self::new(JsNativeErrorKind::Syntax, 14);
+
+    /// `SyntaxError: Syntax Error: {} at position: {}`
+    pub const LEX_ERROR: Self = Self::new(JsNativeErrorKind::Syntax, 15);
+
+    /// `SyntaxError: {} not yet implemented at line {}, col {}`
+    pub const UNIMPLEMENTED: Self = Self::new(JsNativeErrorKind::Syntax, 16);
+
+    /// `SyntaxError: {} at line {}, col {}`
+    pub const PARSE_ERROR: Self = Self::new(JsNativeErrorKind::Syntax, 17);
+
+    /// All the codes of the catalog.
+    const ALL: &'static [Self] = &[
+        Self::NULL_TO_OBJECT,
+        Self::NOT_A_FUNCTION,
+        Self::NAMED_NOT_A_FUNCTION,
+        Self::NOT_A_CONSTRUCTOR,
+        Self::NAMED_NOT_A_CONSTRUCTOR,
+        Self::IMMUTABLE_BINDING,
+        Self::INSTANCEOF_NOT_CALLABLE,
+        Self::NOT_AN_OBJECT,
+        Self::TARGET_NOT_AN_OBJECT,
+        Self::TARGET_NOT_A_FUNCTION,
+        Self::TARGET_NOT_A_CONSTRUCTOR,
+        Self::DESCRIPTOR_NOT_AN_OBJECT,
+        Self::INVALID_PROTOTYPE,
+        Self::CONSTRUCTOR_NOT_AN_OBJECT,
+        Self::CONSTRUCTOR_NOT_A_CONSTRUCTOR,
+        Self::SPECIES_NOT_A_CONSTRUCTOR,
+        Self::REDEFINE_PROPERTY,
+        Self::NON_WRITABLE_PROPERTY,
+        Self::DELETE_PROPERTY,
+        Self::DELETE_OBJECT_PROPERTY,
+        Self::DESTRUCTURE_UNDEFINED,
+        Self::DESTRUCTURE_NULL,
+        Self::ITERATOR_RESULT_NOT_AN_OBJECT,
+        Self::ITERATOR_NOT_AN_OBJECT,
+        Self::MISSING_NEXT,
+        Self::NOT_A_MAP,
+        Self::NOT_A_SET,
+        Self::DETACHED_TYPED_ARRAY,
+        Self::DETACHED_DATA_VIEW,
+        Self::CONSTRUCT_FROM_DETACHED_BUFFER,
+        Self::ASSIGN_TO_IMPORT,
+        Self::INVALID_ASSIGNMENT,
+        Self::NOT_SERIALIZABLE,
+        Self::FOR_IN_INITIALIZER,
+        Self::FOR_OF_INITIALIZER,
+        Self::FOR_IN_TARGET,
+        Self::FOR_OF_TARGET,
+        Self::CONST_WITHOUT_INITIALIZER,
+        Self::INVALID_DYNAMIC_FUNCTION,
+        Self::DUPLICATE_REGEXP_FLAG,
+        Self::DUPLICATE_CAPTURE_GROUP,
+        Self::INVALID_REGEXP,
+        Self::JSON_ABRUPT_END,
+        Self::INVALID_UTF16,
+        Self::EXPECTED_TOKEN,
+        Self::UNEXPECTED_TOKEN,
+        Self::ABRUPT_END,
+        Self::LEX_ERROR,
+        Self::UNIMPLEMENTED,
+        Self::PARSE_ERROR,
+    ];
+
+    /// Creates the code with the given kind and number.
+    const fn new(kind: JsNativeErrorKind, number: u16) -> Self {
+        Self { kind, number }
+    }
+
+    /// Gets the code of a parse error, based on its variant.
+    pub(crate) fn of_parse_error(error: &ParseError) -> Self {
+        match error {
+            ParseError::Expected { .. } => Self::EXPECTED_TOKEN,
+            ParseError::Unexpected { .. } => Self::UNEXPECTED_TOKEN,
+            ParseError::AbruptEnd => Self::ABRUPT_END,
+            ParseError::Lex { .. } => Self::LEX_ERROR,
+            ParseError::Unimplemented { .. } => Self::UNIMPLEMENTED,
+            ParseError::General { .. } => Self::PARSE_ERROR,
+        }
+    }
+
+    /// Gets the kind of the errors with this code.
+    pub fn kind(self) -> JsNativeErrorKind {
+        self.kind
+    }
+
+    /// Gets the number of this code, unique among the codes of the same kind.
+    pub fn number(self) -> u16 {
+        self.number
+    }
+}
+
+impl fmt::Display for JsErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BOA-{}-{:04}", self.kind.abbreviation(), self.number)
+    }
+}
+
+impl FromStr for JsErrorCode {
+    type Err = ();
+
+    /// Parses a code of the catalog, like `BOA-TYP-0003`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.strip_prefix("BOA-").ok_or(())?.splitn(2, '-');
+        let abbreviation = parts.next().ok_or(())?;
+        let number = parts.next().ok_or(())?;
+        let kind = JsNativeErrorKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.abbreviation() == abbreviation)
+            .ok_or(())?;
+        if number.len() != 4 {
+            return Err(());
+        }
+        let code = Self::new(kind, number.parse().map_err(|_| ())?);
+        if Self::ALL.contains(&code) {
+            Ok(code)
+        } else {
+            Err(())
+        }
+    }
+}
+
+/// The error type returned by the embedder-facing APIs of the engine.
+///
+/// # Examples
+///
+/// ```
+/// use boa::{error::JsNativeErrorKind, Context};
+///
+/// let mut context = Context::new();
+///
+/// let error = context.eval("null.property").unwrap_err();
+/// assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+/// ```
+#[derive(Debug)]
+pub enum JsError {
+    /// An error of one of the native error kinds.
+    Native {
+        /// The kind of the error.
+        kind: JsNativeErrorKind,
+        /// The message of the error.
+        message: Box<str>,
+        /// The code of the error, if it was generated by the engine.
+        code: Option<JsErrorCode>,
+        /// The error object, if the error was thrown by JavaScript code.
+        value: Option<JsValue>,
+        /// The Rust error that caused this error, if any.
+        source: Option<Box<dyn error::Error + 'static>>,
+    },
+    /// A thrown value that is not a native error object, like in `throw 5`.
+    Opaque(JsValue),
+}
+
+impl JsError {
+    /// Creates a new native error of the given kind, with the given message.
+    pub fn native<M>(kind: JsNativeErrorKind, message: M) -> Self
+    where
+        M: Into<Box<str>>,
+    {
+        Self::Native {
+            kind,
+            message: message.into(),
+            code: None,
+            value: None,
+            source: None,
+        }
+    }
+
+    /// Creates a new native error identified by the given code, with the given message.
+    ///
+    /// The kind of the error is the kind of the code.
+    pub fn coded<M>(code: JsErrorCode, message: M) -> Self
+    where
+        M: Into<Box<str>>,
+    {
+        Self::Native {
+            kind: code.kind(),
+            message: message.into(),
+            code: Some(code),
+            value: None,
+            source: None,
+        }
+    }
+
+    /// Sets the Rust error that caused this error.
+    ///
+    /// This does nothing on opaque errors.
+    pub fn with_source<E>(mut self, error: E) -> Self
+    where
+        E: error::Error + 'static,
+    {
+        if let Self::Native { ref mut source, .. } = self {
+            *source = Some(Box::new(error));
+        }
+        self
+    }
+
+    /// Creates a `JsError` from a thrown value.
+    ///
+    /// Values inheriting from the prototype of one of the native error constructors become
+    /// native errors, with the message and code read from their `message` and `code` properties.
+    pub fn from_opaque(value: JsValue, context: &mut Context) -> Self {
+        let object = match value.as_object() {
+            Some(object) if object.is_error() => object,
+            _ => return Self::Opaque(value),
+        };
+
+        let mut prototype = object.prototype_instance();
+        while let Some(current) = prototype.as_object() {
+            let kind = JsNativeErrorKind::ALL.iter().copied().find(|kind| {
+                JsObject::equals(
+                    &current,
+                    &context.intrinsics().native_error(*kind).prototype(),
+                )
+            });
+            if let Some(kind) = kind {
+                let message = value
+                    .get_field("message", context)
+                    .and_then(|message| message.to_string(context))
+                    .map(|message| message.as_str().into())
+                    .unwrap_or_default();
+                let code = value
+                    .get_field("code", context)
+                    .ok()
+                    .and_then(|code| code.as_string().and_then(|code| code.parse().ok()));
+                return Self::Native {
+                    kind,
+                    message,
+                    code,
+                    value: Some(value),
+                    source: None,
+                };
+            }
+            prototype = current.prototype_instance();
+        }
+
+        Self::Opaque(value)
+    }
+
+    /// Converts the error to the value that would be thrown in JavaScript code.
+    ///
+    /// Native errors that were not thrown by JavaScript code get a new error object created.
+    pub fn to_opaque(&self, context: &mut Context) -> JsValue {
+        match self {
+            Self::Native {
+                value: Some(value), ..
+            }
+            | Self::Opaque(value) => value.clone(),
+            Self::Native {
+                code: Some(code),
+                message,
+                ..
+            } => context.construct_coded_error(*code, message.clone()),
+            Self::Native { kind, message, .. } => {
+                context.construct_native_error(*kind, message.clone())
+            }
+        }
+    }
+
+    /// Gets the kind of the error, if it is a native error.
+    pub fn kind(&self) -> Option<JsNativeErrorKind> {
+        match self {
+            Self::Native { kind, .. } => Some(*kind),
+            Self::Opaque(_) => None,
+        }
+    }
+
+    /// Gets the message of the error, if it is a native error.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Native { message, .. } => Some(message),
+            Self::Opaque(_) => None,
+        }
+    }
+
+    /// Gets the code of the error, if it is a native error generated by the engine.
+    pub fn code(&self) -> Option<JsErrorCode> {
+        match self {
+            Self::Native { code, .. } => *code,
+            Self::Opaque(_) => None,
+        }
+    }
+
+    /// Gets the thrown value, if the error was thrown by JavaScript code.
+    pub fn as_opaque(&self) -> Option<&JsValue> {
+        match self {
+            Self::Native { value, .. } => value.as_ref(),
+            Self::Opaque(value) => Some(value),
+        }
+    }
+}
+
+impl From<JsValue> for JsError {
+    /// Wraps a thrown value without inspecting it.
+    ///
+    /// Use [`JsError::from_opaque`] to recognize native errors.
+    fn from(value: JsValue) -> Self {
+        Self::Opaque(value)
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Native { kind, message, .. } if message.is_empty() => write!(f, "{}", kind),
+            Self::Native { kind, message, .. } => write!(f, "{}: {}", kind, message),
+            Self::Opaque(value) => write!(f, "{}", value.display()),
+        }
+    }
+}
+
+impl error::Error for JsError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Native {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsError, JsErrorCode, JsNativeErrorKind};
+    use crate::{object::JsObject, Context, JsValue};
+    use std::error::Error;
+
+    #[test]
+    fn native_error_kinds() {
+        let mut context = Context::new();
+
+        let error = context.eval("null.prop").unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+
+        let error = context.eval("undefinedVariable").unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Reference));
+
+        let error = context
+            .eval("throw new RangeError('out of range')")
+            .unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Range));
+        assert_eq!(error.message(), Some("out of range"));
+        assert_eq!(error.to_string(), "RangeError: out of range");
+        assert!(error.as_opaque().is_some());
+    }
+
+    #[test]
+    fn subclassed_native_error() {
+        let mut context = Context::new();
+
+        let error = context
+            .eval(
+                r#"
+                function MyError(message) {
+                    this.message = message;
+                }
+                MyError.prototype = Object.create(TypeError.prototype);
+                let e = new TypeError('custom');
+                Object.setPrototypeOf(e, MyError.prototype);
+                throw e;
+                "#,
+            )
+            .unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+        assert_eq!(error.message(), Some("custom"));
+    }
+
+    #[test]
+    fn opaque_error() {
+        let mut context = Context::new();
+
+        let error = context.eval("throw 5").unwrap_err();
+        assert_eq!(error.kind(), None);
+        assert_eq!(error.message(), None);
+        assert_eq!(error.as_opaque(), Some(&JsValue::new(5)));
+        assert_eq!(error.to_opaque(&mut context), JsValue::new(5));
+
+        let error = context
+            .eval("throw { message: 'not an error' }")
+            .unwrap_err();
+        assert_eq!(error.kind(), None);
+    }
+
+    #[test]
+    fn syntax_error_source() {
+        let mut context = Context::new();
+
+        let error = context.eval("let = ;").unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Syntax));
+        assert!(error.as_opaque().is_none());
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn native_error_to_opaque() {
+        let mut context = Context::new();
+
+        let error = JsError::native(JsNativeErrorKind::Uri, "malformed");
+        let value = error.to_opaque(&mut context);
+        context.register_global_property("error", value, Default::default());
+
+        assert_eq!(
+            context.eval("error instanceof URIError").unwrap(),
+            JsValue::new(true)
+        );
+        assert_eq!(
+            context.eval("error.message").unwrap(),
+            JsValue::new("malformed")
+        );
+    }
+
+    #[test]
+    fn native_errors_use_intrinsics() {
+        let mut context = Context::new();
+
+        let type_error = context.intrinsics().type_error().constructor();
+        assert!(JsObject::equals(
+            &type_error,
+            &context.eval("TypeError").unwrap().as_object().unwrap()
+        ));
+
+        context
+            .eval("var OriginalTypeError = TypeError; TypeError = {};")
+            .unwrap();
+        let error = context.eval("null.prop").unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+        let value = error.to_opaque(&mut context);
+        context.register_global_property("error", value, Default::default());
+        assert_eq!(
+            context.eval("error instanceof OriginalTypeError").unwrap(),
+            JsValue::new(true)
+        );
+    }
+
+    #[test]
+    fn error_codes() {
+        let mut context = Context::new();
+
+        let error = context.eval("null.prop").unwrap_err();
+        let code = error.code().unwrap();
+        assert_eq!(code.kind(), JsNativeErrorKind::Type);
+        assert_eq!(code.number(), 1);
+        assert_eq!(code.to_string(), "BOA-TYP-0001");
+
+        let error = context.eval("let = ;").unwrap_err();
+        assert_eq!(error.code().unwrap().to_string(), "BOA-SYN-0012");
+
+        let error = context.eval("new RegExp('a', 'gg')").unwrap_err();
+        assert_eq!(error.code().unwrap().to_string(), "BOA-SYN-0007");
+
+        let error = context
+            .eval("throw new TypeError('not a function')")
+            .unwrap_err();
+        assert_eq!(error.code(), None);
+
+        let error = context.eval("new Promise(1)").unwrap_err();
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+        assert_eq!(error.code(), None);
+
+        let error = context.eval("throw 5").unwrap_err();
+        assert_eq!(error.code(), None);
+    }
+
+    #[test]
+    fn error_code_property() {
+        let mut context = Context::new();
+
+        assert_eq!(
+            context
+                .eval("try { undefined.prop } catch (e) { e.code }")
+                .unwrap(),
+            JsValue::new("BOA-TYP-0001")
+        );
+        assert_eq!(
+            context
+                .eval("try { eval('let = ;') } catch (e) { e.code }")
+                .unwrap(),
+            JsValue::new("BOA-SYN-0012")
+        );
+        assert_eq!(
+            context
+                .eval("try { null.prop } catch (e) { Object.keys(e).includes('code') }")
+                .unwrap(),
+            JsValue::new(false)
+        );
+        assert_eq!(
+            context
+                .eval("new TypeError('not a function').code")
+                .unwrap(),
+            JsValue::undefined()
+        );
+
+        let error = JsError::native(JsNativeErrorKind::Type, "x is not a function");
+        let value = error.to_opaque(&mut context);
+        context.register_global_property("uncoded", value, Default::default());
+        assert_eq!(context.eval("uncoded.code").unwrap(), JsValue::undefined());
+
+        let error = JsError::coded(JsErrorCode::NAMED_NOT_A_FUNCTION, "x is not a function");
+        assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+        let value = error.to_opaque(&mut context);
+        context.register_global_property("error", value, Default::default());
+        assert_eq!(
+            context.eval("error.code").unwrap(),
+            JsValue::new("BOA-TYP-0003")
+        );
+    }
+
+    #[test]
+    fn parse_error_codes() {
+        let code = "BOA-TYP-0003".parse::<JsErrorCode>().unwrap();
+        assert_eq!(code.kind(), JsNativeErrorKind::Type);
+        assert_eq!(code.number(), 3);
+        assert_eq!(code, JsErrorCode::NAMED_NOT_A_FUNCTION);
+
+        assert!("BOA-TYP-3".parse::<JsErrorCode>().is_err());
+        assert!("BOA-XYZ-0001".parse::<JsErrorCode>().is_err());
+        assert!("BOA-TYP-9999".parse::<JsErrorCode>().is_err());
+        assert!("TYP-0001".parse::<JsErrorCode>().is_err());
+    }
+}
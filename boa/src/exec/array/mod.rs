@@ -0,0 +1,27 @@
+//! Execution of the `ArrayDecl` node.
+
+use crate::{
+    builtins::{iterable, Array},
+    exec::Executable,
+    syntax::ast::node::{ArrayDecl, Node},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for ArrayDecl {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
+        let array = Array::new_array(context);
+        let mut elements = Vec::new();
+        for elem in self.as_ref() {
+            if let Node::Spread(ref x) = elem {
+                let val = x.run(context)?;
+                elements.extend(iterable::iterable_to_list(context, val)?);
+            } else {
+                elements.push(elem.run(context)?);
+            }
+        }
+
+        Array::add_to_array_object(&array, &elements, context)?;
+        Ok(array)
+    }
+}
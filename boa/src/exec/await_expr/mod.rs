@@ -0,0 +1,14 @@
+//! Execution of the `AwaitExpr` node.
+
+use crate::{
+    exec::Executable, syntax::ast::node::AwaitExpr, BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for AwaitExpr {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("AwaitExpression", "exec");
+
+        // The body of an async function is compiled, and `await` suspends its frame in the vm.
+        context.throw_syntax_error("`await` is only valid in the body of an async function")
+    }
+}
@@ -0,0 +1,59 @@
+//! Execution of the `Block` node.
+
+use crate::{
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    exec::{instantiate_lexical_declarations, Executable, InterpreterState},
+    syntax::ast::node::Block,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Block {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Block", "exec");
+        {
+            let env = context.get_current_environment();
+            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+        }
+        if let Err(e) = instantiate_lexical_declarations(self.items(), context) {
+            context.pop_environment();
+            return Err(e);
+        }
+
+        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
+        // The return value is uninitialized, which means it defaults to Value::Undefined
+        let mut obj = JsValue::default();
+        for statement in self.items() {
+            obj = statement.run(context).map_err(|e| {
+                // No matter how control leaves the Block the LexicalEnvironment is always
+                // restored to its former state.
+                context.pop_environment();
+                e
+            })?;
+
+            match context.executor().get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    break;
+                }
+                InterpreterState::Break(_label) => {
+                    // TODO, break to a label.
+
+                    // Early break.
+                    break;
+                }
+                InterpreterState::Continue(_label) => {
+                    // TODO, continue to a label
+                    break;
+                }
+                InterpreterState::Executing => {
+                    // Continue execution
+                }
+            }
+        }
+
+        // pop the block env
+        let _ = context.pop_environment();
+
+        Ok(obj)
+    }
+}
@@ -0,0 +1,20 @@
+//! Execution of the `Break` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::Break,
+    Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for Break {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        context
+            .executor()
+            .set_current_state(InterpreterState::Break(self.label().map(Box::from)));
+
+        Ok(JsValue::undefined())
+    }
+}
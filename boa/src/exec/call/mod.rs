@@ -0,0 +1,169 @@
+//! Execution of the `Call` and `SuperCall` nodes.
+
+use crate::{
+    builtins::{iterable, Eval},
+    exec::{private_name, super_property_reference, Executable, InterpreterState},
+    object::JsObject,
+    syntax::ast::node::{Call, Node, SuperCall},
+    value::ArgumentsExt,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Call {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Call", "exec");
+        let (this, func) = match self.expr() {
+            // A primitive base is the `this` value of the call as is, only strict functions can
+            // tell it apart from its wrapper object.
+            Node::GetConstField(ref get_const_field) => {
+                let obj = get_const_field.obj().run(context)?;
+                let func = obj.get_v(get_const_field.field(), context)?;
+                (obj, func)
+            }
+            Node::GetField(ref get_field) => {
+                let obj = get_field.obj().run(context)?;
+                let field = get_field.field().run(context)?;
+                let func = obj.get_v(field.to_property_key(context)?, context)?;
+                (obj, func)
+            }
+            Node::GetPrivateField(ref get_private_field) => {
+                let obj = get_private_field.obj().run(context)?;
+                let name = private_name(&format!("#{}", get_private_field.field()), context)?;
+                let func = obj.to_object(context)?.private_get(&name, context)?;
+                (obj, func)
+            }
+            Node::GetSuperField(ref get_super_field) => {
+                let (base, key, this) = super_property_reference(get_super_field.field(), context)?;
+                let func = base.__get__(&key, this.clone(), context)?;
+                (this, func)
+            }
+            Node::Identifier(ref identifier) => (
+                context.get_implicit_this_value(identifier.as_ref()),
+                identifier.run(context)?,
+            ),
+            _ => (
+                // The callee binds `this` itself, to the global `this` value if it is not strict.
+                JsValue::undefined(),
+                self.expr().run(context)?,
+            ),
+        };
+        let mut v_args = Vec::with_capacity(self.args().len());
+        for arg in self.args() {
+            if let Node::Spread(ref x) = arg {
+                let val = x.run(context)?;
+                v_args.extend(iterable::iterable_to_list(context, val)?);
+            } else {
+                v_args.push(arg.run(context)?);
+            }
+        }
+
+        // A call to %eval% through the identifier `eval` is a direct eval, which runs in the
+        // environment of the caller.
+        let direct_eval = matches!(self.expr(), Node::Identifier(ref ident) if ident.as_ref() == "eval")
+            && func.as_object().map_or(false, |func| {
+                JsObject::equals(&func, context.intrinsics().eval())
+            });
+
+        // execute the function call itself
+        let fnct_result = if direct_eval {
+            let strict_caller = context.is_strict();
+            Eval::perform_eval(v_args.get_or_undefined(0), strict_caller, true, context)
+        } else {
+            context.call(&func, &this, &v_args)
+        };
+
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        fnct_result
+    }
+}
+
+/// Returns the constructor of the parent class of the derived class constructor being run,
+/// which is the prototype of the constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsuperconstructor
+pub(crate) fn super_constructor(context: &mut Context) -> JsResult<JsValue> {
+    // 1. Let envRec be GetThisEnvironment().
+    // 2. Assert: envRec is a function Environment Record.
+    let environment = context.get_current_environment();
+    let environment = environment
+        .recursive_get_this_function_record()
+        .expect("super calls are only parsed in the constructors of derived classes");
+
+    // 3. Let activeFunction be envRec.[[FunctionObject]].
+    // 4. Assert: activeFunction is an ECMAScript function object.
+    // 5. Let superConstructor be ! activeFunction.[[GetPrototypeOf]]().
+    // 6. Return superConstructor.
+    environment.function.__get_prototype_of__(context)
+}
+
+/// Constructs the object of the derived class constructor being run with `func`, the
+/// constructor of its parent class, binding it to `this` and adding the fields of the class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-super-keyword-runtime-semantics-evaluation
+pub(crate) fn super_call(
+    func: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let environment = context.get_current_environment();
+    let environment = environment
+        .recursive_get_this_function_record()
+        .expect("super calls are only parsed in the constructors of derived classes");
+
+    // 1. Let newTarget be GetNewTarget().
+    // 2. Assert: Type(newTarget) is Object.
+    let new_target = environment.new_target.clone();
+
+    // 5. If IsConstructor(func) is false, throw a TypeError exception.
+    let func = match func {
+        JsValue::Object(func) if func.is_constructable() => func,
+        _ => return context.throw_type_error("Super constructor is not a constructor"),
+    };
+
+    // 6. Let result be ? Construct(func, argList, newTarget).
+    let result = func.construct(args, &new_target, context)?;
+
+    // 7. Let thisER be GetThisEnvironment().
+    // 8. Perform ? thisER.BindThisValue(result).
+    environment.bind_this_value(result.clone(), context)?;
+
+    // 9. Let F be thisER.[[FunctionObject]].
+    // 10. Assert: F is an ECMAScript function object.
+    // 11. Perform ? InitializeInstanceElements(result, F).
+    if let Some(object) = result.as_object() {
+        object.initialize_instance_elements(&environment.function, context)?;
+    }
+
+    // 12. Return result.
+    Ok(result)
+}
+
+impl Executable for SuperCall {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("SuperCall", "exec");
+
+        // The parent class is looked up before the arguments are evaluated.
+        let func = super_constructor(context)?;
+        let mut args = Vec::with_capacity(self.args().len());
+        for arg in self.args() {
+            if let Node::Spread(ref x) = arg {
+                let val = x.run(context)?;
+                args.extend(iterable::iterable_to_list(context, val)?);
+            } else {
+                args.push(arg.run(context)?);
+            }
+        }
+
+        super_call(&func, &args, context)
+    }
+}
@@ -0,0 +1,13 @@
+//! Execution of the `ConditionalOp` node.
+
+use crate::{exec::Executable, syntax::ast::node::ConditionalOp, Context, JsResult, JsValue};
+
+impl Executable for ConditionalOp {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        Ok(if self.cond().run(context)?.to_boolean() {
+            self.if_true().run(context)?
+        } else {
+            self.if_false().run(context)?
+        })
+    }
+}
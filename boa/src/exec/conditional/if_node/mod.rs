@@ -0,0 +1,15 @@
+//! Execution of the `If` node.
+
+use crate::{exec::Executable, syntax::ast::node::If, Context, JsResult, JsValue};
+
+impl Executable for If {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        Ok(if self.cond().run(context)?.to_boolean() {
+            self.body().run(context)?
+        } else if let Some(else_e) = self.else_node() {
+            else_e.run(context)?
+        } else {
+            JsValue::undefined()
+        })
+    }
+}
@@ -0,0 +1,4 @@
+//! Conditional node execution.
+
+mod conditional_op;
+mod if_node;
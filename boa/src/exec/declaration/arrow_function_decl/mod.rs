@@ -0,0 +1,20 @@
+//! Execution of the `ArrowFunctionDecl` node.
+
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    syntax::ast::node::{ArrowFunctionDecl, StatementList},
+    Context, JsResult, JsValue,
+};
+
+impl Executable for ArrowFunctionDecl {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        context.create_function(
+            "",
+            self.params().to_vec(),
+            StatementList::from(self.body().to_vec()).with_strict(self.strict()),
+            FunctionFlags::LEXICAL_THIS_MODE,
+            self.source_text(),
+        )
+    }
+}
@@ -0,0 +1,20 @@
+//! Execution of the `AsyncGeneratorExpr` node.
+
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    syntax::ast::node::{AsyncGeneratorExpr, StatementList},
+    Context, JsResult, JsValue,
+};
+
+impl Executable for AsyncGeneratorExpr {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        context.create_function(
+            self.name().unwrap_or(""),
+            self.parameters().to_vec(),
+            StatementList::from(self.body().to_vec()).with_strict(self.strict()),
+            FunctionFlags::ASYNC | FunctionFlags::GENERATOR,
+            self.source_text(),
+        )
+    }
+}
@@ -0,0 +1,17 @@
+//! Execution of the `Class` node.
+
+use crate::{
+    bytecompiler::ByteCompiler,
+    exec::Executable,
+    syntax::ast::node::{Class, Node},
+    Context, JsResult, JsValue,
+};
+
+impl Executable for Class {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        // Classes are only implemented by the vm, which defines their methods and private
+        // elements.
+        let code = ByteCompiler::compile_expression(&Node::ClassExpr(self.clone()));
+        context.run_code_block(code)
+    }
+}
@@ -0,0 +1,22 @@
+//! Execution of the `FunctionExpr` node.
+
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    syntax::ast::node::{FunctionExpr, StatementList},
+    Context, JsResult, JsValue,
+};
+
+impl Executable for FunctionExpr {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let val = context.create_function(
+            self.name().unwrap_or(""),
+            self.parameters().to_vec(),
+            StatementList::from(self.body().to_vec()).with_strict(self.strict()),
+            FunctionFlags::CONSTRUCTABLE,
+            self.source_text(),
+        )?;
+
+        Ok(val)
+    }
+}
@@ -0,0 +1,33 @@
+//! Execution of the `GeneratorDecl` node.
+
+use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    syntax::ast::node::{GeneratorDecl, StatementList},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for GeneratorDecl {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("GeneratorDecl", "exec");
+        let val = context.create_function(
+            self.name().unwrap_or("default"),
+            self.parameters().to_vec(),
+            StatementList::from(self.body().to_vec()).with_strict(self.strict()),
+            FunctionFlags::GENERATOR,
+            self.source_text(),
+        )?;
+
+        // An anonymous declaration is the default export of a module, bound to `*default*`.
+        let name = self.name().unwrap_or("*default*");
+        if context.has_binding(name) {
+            context.set_mutable_binding(name, val, true)?;
+        } else {
+            context.create_mutable_binding(name.to_owned(), false, VariableScope::Function)?;
+
+            context.initialize_binding(name, val)?;
+        }
+        Ok(JsValue::undefined())
+    }
+}
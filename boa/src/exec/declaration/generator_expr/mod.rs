@@ -0,0 +1,20 @@
+//! Execution of the `GeneratorExpr` node.
+
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    syntax::ast::node::{GeneratorExpr, StatementList},
+    Context, JsResult, JsValue,
+};
+
+impl Executable for GeneratorExpr {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        context.create_function(
+            self.name().unwrap_or(""),
+            self.parameters().to_vec(),
+            StatementList::from(self.body().to_vec()).with_strict(self.strict()),
+            FunctionFlags::GENERATOR,
+            self.source_text(),
+        )
+    }
+}
@@ -0,0 +1,672 @@
+//! Execution of the declaration nodes.
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array,
+    },
+    environment::lexical_environment::VariableScope,
+    error::JsErrorCode,
+    exec::Executable,
+    property::PropertyKey,
+    syntax::ast::node::{
+        declaration::{
+            BindingPatternTypeArray, BindingPatternTypeObject, Declaration, DeclarationList,
+            DeclarationPattern, DeclarationPatternArray, DeclarationPatternObject,
+        },
+        Node,
+    },
+    Context, JsResult, JsValue,
+};
+
+mod arrow_function_decl;
+mod async_function_decl;
+mod async_function_expr;
+mod async_generator_decl;
+mod async_generator_expr;
+mod class_decl;
+mod function_decl;
+mod function_expr;
+mod generator_decl;
+mod generator_expr;
+
+#[cfg(test)]
+mod tests;
+
+/// The kind of binding that an identifier of a declaration or destructuring pattern is bound with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    /// A `var` declaration, which reuses an existing binding of the same name.
+    Var,
+    /// A `let` declaration.
+    Let,
+    /// A `const` declaration.
+    Const,
+    /// An assignment, which creates a global binding if none exists in sloppy mode code.
+    Assignment,
+}
+
+impl BindingKind {
+    /// Binds `name` to `value` in the current environment.
+    pub(crate) fn bind(self, name: &str, value: JsValue, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Var | Self::Assignment if context.has_binding(name) => {
+                context.set_mutable_binding(name, value, true)
+            }
+            // Assigning to an unresolvable reference throws in strict mode code.
+            // <https://tc39.es/ecma262/#sec-putvalue>
+            Self::Assignment if context.is_strict() => {
+                Err(context.construct_reference_error(format!("{} is not defined", name)))
+            }
+            Self::Var | Self::Assignment => {
+                context.create_mutable_binding(
+                    name.to_owned(),
+                    self == Self::Assignment,
+                    VariableScope::Function,
+                )?;
+                context.initialize_binding(name, value)
+            }
+            // The binding was created uninitialized when entering the scope of the declaration.
+            Self::Let | Self::Const if context.get_current_environment().has_binding(name) => {
+                context.initialize_binding(name, value)
+            }
+            Self::Let => {
+                context.create_mutable_binding(name.to_owned(), false, VariableScope::Block)?;
+                context.initialize_binding(name, value)
+            }
+            Self::Const => {
+                context.create_immutable_binding(name.to_owned(), true, VariableScope::Block)?;
+                context.initialize_binding(name, value)
+            }
+        }
+    }
+}
+
+/// Creates the bindings of the `let` and `const` declarations directly contained in `items` in
+/// the current environment. They are left uninitialized, so that using them before their
+/// declaration is evaluated throws a `ReferenceError`: this is their temporal dead zone.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-blockdeclarationinstantiation
+pub(crate) fn instantiate_lexical_declarations(
+    items: &[Node],
+    context: &mut Context,
+) -> JsResult<()> {
+    for item in items {
+        let (list, is_const) = match item {
+            Node::LetDeclList(list) => (list, false),
+            Node::ConstDeclList(list) => (list, true),
+            Node::ClassDecl(class) => {
+                let name = class.name().unwrap_or("*default*");
+                context.create_mutable_binding(name.to_owned(), false, VariableScope::Block)?;
+                continue;
+            }
+            _ => continue,
+        };
+        for decl in list.as_ref() {
+            let names = match decl {
+                Declaration::Identifier { ident, .. } => vec![ident.as_ref()],
+                Declaration::Pattern(pattern) => pattern.idents(),
+            };
+            for name in names {
+                if is_const {
+                    context.create_immutable_binding(
+                        name.to_owned(),
+                        true,
+                        VariableScope::Block,
+                    )?;
+                } else {
+                    context.create_mutable_binding(name.to_owned(), false, VariableScope::Block)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The runtime semantics of the binding patterns of declarations.
+pub(crate) trait BindingInitialization {
+    /// Initializes the values of the binding pattern, calling `bind` with each bound identifier
+    /// and its value as soon as the value is known.
+    fn run(
+        &self,
+        init: Option<JsValue>,
+        bind: &mut dyn FnMut(&str, JsValue, &mut Context) -> JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()>;
+}
+
+impl Executable for DeclarationList {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let kind = match self {
+            DeclarationList::Var(_) => BindingKind::Var,
+            DeclarationList::Let(_) => BindingKind::Let,
+            DeclarationList::Const(_) => BindingKind::Const,
+        };
+
+        for decl in self.as_ref() {
+            if decl.init().is_none() && self.is_const() {
+                return context.throw_coded_error(
+                    JsErrorCode::CONST_WITHOUT_INITIALIZER,
+                    "missing = in const declaration",
+                );
+            }
+
+            match &decl {
+                Declaration::Identifier { ident, init } => {
+                    let val = match init {
+                        Some(init) => init.run(context)?,
+                        // A `var` declaration without initializer doesn't reset a hoisted binding.
+                        None if self.is_var() && context.has_binding(ident.as_ref()) => continue,
+                        None => JsValue::undefined(),
+                    };
+
+                    kind.bind(ident.as_ref(), val, context)?;
+                }
+                Declaration::Pattern(p) => {
+                    p.run(
+                        None,
+                        &mut |name, value, context| kind.bind(name, value, context),
+                        context,
+                    )?;
+                }
+            }
+        }
+
+        Ok(JsValue::undefined())
+    }
+}
+
+impl BindingInitialization for DeclarationPattern {
+    /// Initialize the values of an object/array binding pattern.
+    ///
+    /// This function only calls the specific initialization function for either the object or the array binding pattern.
+    /// For specific documentation and references to the ECMAScript spec, look at the called initialization functions.
+    fn run(
+        &self,
+        init: Option<JsValue>,
+        bind: &mut dyn FnMut(&str, JsValue, &mut Context) -> JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match &self {
+            DeclarationPattern::Object(pattern) => pattern.run(init, bind, context),
+            DeclarationPattern::Array(pattern) => pattern.run(init, bind, context),
+        }
+    }
+}
+
+impl BindingInitialization for DeclarationPatternObject {
+    /// Initialize the values of an object binding pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference: 8.5.2 Runtime Semantics: BindingInitialization][spec1]
+    ///  - [ECMAScript reference:14.3.3.3 Runtime Semantics: KeyedBindingInitialization][spec2]
+    ///  - [ECMAScript reference:14.3.3.2 Runtime Semantics: RestBindingInitialization][spec3]
+    ///
+    /// [spec1]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    /// [spec2]: https://tc39.es/ecma262/#sec-runtime-semantics-keyedbindinginitialization
+    /// [spec3]:  https://tc39.es/ecma262/#sec-destructuring-binding-patterns-runtime-semantics-restbindinginitialization
+    fn run(
+        &self,
+        init: Option<JsValue>,
+        bind: &mut dyn FnMut(&str, JsValue, &mut Context) -> JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let value = if let Some(value) = init {
+            value
+        } else if let Some(node) = self.init() {
+            node.run(context)?
+        } else {
+            JsValue::undefined()
+        };
+
+        if value.is_null() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::DESTRUCTURE_NULL,
+                "Cannot destructure 'null' value",
+            ));
+        }
+        if value.is_undefined() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::DESTRUCTURE_UNDEFINED,
+                "Cannot destructure 'undefined' value",
+            ));
+        }
+
+        // 1. Perform ? RequireObjectCoercible(value).
+        let value = value.require_object_coercible(context)?;
+
+        // 2. Return the result of performing BindingInitialization for ObjectBindingPattern using value and environment as arguments.
+        for binding in self.bindings() {
+            use BindingPatternTypeObject::*;
+
+            match binding {
+                // ObjectBindingPattern : { }
+                Empty => {
+                    // 1. Return NormalCompletion(empty).
+                }
+                //  SingleNameBinding : BindingIdentifier Initializer[opt]
+                SingleName {
+                    ident,
+                    property_name,
+                    default_init,
+                } => {
+                    // 1. Let bindingId be StringValue of BindingIdentifier.
+                    // 2. Let lhs be ? ResolveBinding(bindingId, environment).
+
+                    // 3. Let v be ? GetV(value, propertyName).
+                    let mut v = value.get_v(property_name.as_ref(), context)?;
+
+                    // 4. If Initializer is present and v is undefined, then
+                    if let Some(init) = default_init {
+                        if v.is_undefined() {
+                            // TODO: a. not implemented yet:
+                            // a. If IsAnonymousFunctionDefinition(Initializer) is true, then
+                            // i. Set v to the result of performing NamedEvaluation for Initializer with argument bindingId.
+
+                            // b. Else,
+                            // i. Let defaultValue be the result of evaluating Initializer.
+                            // ii. Set v to ? GetValue(defaultValue).
+                            v = init.run(context)?;
+                        }
+                    }
+
+                    // 5. If environment is undefined, return ? PutValue(lhs, v).
+                    // 6. Return InitializeReferencedBinding(lhs, v).
+                    bind(ident, v, context)?;
+                }
+                //  BindingRestProperty : ... BindingIdentifier
+                RestProperty {
+                    ident,
+                    excluded_keys,
+                } => {
+                    // 1. Let lhs be ? ResolveBinding(StringValue of BindingIdentifier, environment).
+
+                    // 2. Let restObj be ! OrdinaryObjectCreate(%Object.prototype%).
+                    let mut rest_obj = context.construct_object();
+
+                    // 3. Perform ? CopyDataProperties(restObj, value, excludedNames).
+                    rest_obj.copy_data_properties(value, excluded_keys.clone(), context)?;
+
+                    // 4. If environment is undefined, return PutValue(lhs, restObj).
+                    // 5. Return InitializeReferencedBinding(lhs, restObj).
+                    bind(ident, rest_obj.into(), context)?;
+                }
+                //  BindingElement : BindingPattern Initializer[opt]
+                BindingPattern {
+                    ident,
+                    pattern,
+                    default_init,
+                } => {
+                    // 1. Let v be ? GetV(value, propertyName).
+                    let mut v = value.get_v(ident.as_ref(), context)?;
+
+                    // 2. If Initializer is present and v is undefined, then
+                    if let Some(init) = default_init {
+                        if v.is_undefined() {
+                            // a. Let defaultValue be the result of evaluating Initializer.
+                            // b. Set v to ? GetValue(defaultValue).
+                            v = init.run(context)?;
+                        }
+                    }
+
+                    // 3. Return the result of performing BindingInitialization for BindingPattern passing v and environment as arguments.
+                    pattern.run(Some(v), bind, context)?;
+                }
+                // AssignmentProperty : PropertyName : AssignmentElement
+                AssignmentPropertyAccess {
+                    property_name,
+                    access,
+                    default_init,
+                } => {
+                    // 1. If DestructuringAssignmentTarget is neither an ObjectLiteral nor an ArrayLiteral, then
+                    // a. Let lref be the result of evaluating DestructuringAssignmentTarget.
+                    let (object, key) = property_access_reference(access, context)?;
+
+                    // 2. Let v be ? GetV(value, propertyName).
+                    let mut v = value.get_v(property_name.as_ref(), context)?;
+
+                    // 3. If Initializer is present and v is undefined, then
+                    if let Some(init) = default_init {
+                        if v.is_undefined() {
+                            // b. Else,
+                            // i. Let defaultValue be the result of evaluating Initializer.
+                            // ii. Let rhsValue be ? GetValue(defaultValue).
+                            v = init.run(context)?;
+                        }
+                    }
+
+                    // 6. Return ? PutValue(lref, rhsValue).
+                    object.set_field(key, v, false, context)?;
+                }
+                // AssignmentRestProperty : ... DestructuringAssignmentTarget
+                AssignmentRestProperty {
+                    access,
+                    excluded_keys,
+                } => {
+                    // 1. Let lref be the result of evaluating DestructuringAssignmentTarget.
+                    let (object, key) = property_access_reference(access, context)?;
+
+                    // 2. Let restObj be ! OrdinaryObjectCreate(%Object.prototype%).
+                    let mut rest_obj = context.construct_object();
+
+                    // 3. Perform ? CopyDataProperties(restObj, value, excludedNames).
+                    rest_obj.copy_data_properties(value, excluded_keys.clone(), context)?;
+
+                    // 4. Return PutValue(lref, restObj).
+                    object.set_field(key, rest_obj, false, context)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BindingInitialization for DeclarationPatternArray {
+    /// Initialize the values of an array binding pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference: 8.5.2 Runtime Semantics: BindingInitialization][spec1]
+    ///  - [ECMAScript reference: 8.5.3 Runtime Semantics: IteratorBindingInitialization][spec2]
+    ///
+    /// [spec1]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    /// [spec2]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+    fn run(
+        &self,
+        init: Option<JsValue>,
+        bind: &mut dyn FnMut(&str, JsValue, &mut Context) -> JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let value = if let Some(value) = init {
+            value
+        } else if let Some(node) = self.init() {
+            node.run(context)?
+        } else {
+            JsValue::undefined()
+        };
+
+        if value.is_null() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::DESTRUCTURE_NULL,
+                "Cannot destructure 'null' value",
+            ));
+        }
+        if value.is_undefined() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::DESTRUCTURE_UNDEFINED,
+                "Cannot destructure 'undefined' value",
+            ));
+        }
+
+        // 1. Let iteratorRecord be ? GetIterator(value).
+        let iterator = get_iterator(context, value)?;
+        let mut done = false;
+
+        // 2. Let result be IteratorBindingInitialization of ArrayBindingPattern with arguments iteratorRecord and environment.
+        let result = iterator_binding_initialization(self, &iterator, &mut done, bind, context);
+
+        // 3. If iteratorRecord.[[Done]] is false, return ? IteratorClose(iteratorRecord, result).
+        if !done {
+            let completion = result
+                .as_ref()
+                .map(|_| JsValue::undefined())
+                .map_err(JsValue::clone);
+            iterator.close(completion, context)?;
+        }
+
+        // 4. Return result.
+        result
+    }
+}
+
+/// Binds the elements of the pattern to the values produced by `iterator`, keeping track of
+/// whether the iterator is done.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+fn iterator_binding_initialization(
+    pattern: &DeclarationPatternArray,
+    iterator: &IteratorRecord,
+    done: &mut bool,
+    bind: &mut dyn FnMut(&str, JsValue, &mut Context) -> JsResult<()>,
+    context: &mut Context,
+) -> JsResult<()> {
+    for binding in pattern.bindings() {
+        use BindingPatternTypeArray::*;
+
+        match binding {
+            // ArrayBindingPattern : [ ]
+            Empty => {
+                // 1. Return NormalCompletion(empty).
+            }
+            // ArrayBindingPattern : [ Elision ]
+            // Note: This captures all elisions due to our representation of a the binding pattern.
+            Elision => {
+                // 1. If iteratorRecord.[[Done]] is false, then
+                // a. Let next be IteratorStep(iteratorRecord).
+                // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // c. ReturnIfAbrupt(next).
+                // d. If next is false, set iteratorRecord.[[Done]] to true.
+                binding_iterator_step(iterator, done, context)?;
+
+                // 2. Return NormalCompletion(empty).
+            }
+            // SingleNameBinding : BindingIdentifier Initializer[opt]
+            SingleName {
+                ident,
+                default_init,
+            } => {
+                // 1. Let bindingId be StringValue of BindingIdentifier.
+                // 2. Let lhs be ? ResolveBinding(bindingId, environment).
+
+                // 3. If iteratorRecord.[[Done]] is false, then
+                // a. Let next be IteratorStep(iteratorRecord).
+                // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // c. ReturnIfAbrupt(next).
+                // d. If next is false, set iteratorRecord.[[Done]] to true.
+                // e. Else,
+                // i. Let v be IteratorValue(next).
+                // ii. If v is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // iii. ReturnIfAbrupt(v).
+                // 4. If iteratorRecord.[[Done]] is true, let v be undefined.
+                let mut v = binding_iterator_step(iterator, done, context)?.unwrap_or_default();
+
+                // 5. If Initializer is present and v is undefined, then
+                if let Some(init) = default_init {
+                    if v.is_undefined() {
+                        // TODO: a. not implemented yet:
+                        // a. If IsAnonymousFunctionDefinition(Initializer) is true, then
+                        // i. Set v to the result of performing NamedEvaluation for Initializer with argument bindingId.
+
+                        // b. Else,
+                        // i. Let defaultValue be the result of evaluating Initializer.
+                        // ii. Set v to ? GetValue(defaultValue).
+                        v = init.run(context)?
+                    }
+                }
+
+                // 6. If environment is undefined, return ? PutValue(lhs, v).
+                // 7. Return InitializeReferencedBinding(lhs, v).
+                bind(ident, v, context)?;
+            }
+            // BindingElement : BindingPattern Initializer[opt]
+            BindingPattern { pattern } => {
+                // 1. If iteratorRecord.[[Done]] is false, then
+                // a. Let next be IteratorStep(iteratorRecord).
+                // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // c. ReturnIfAbrupt(next).
+                // d. If next is false, set iteratorRecord.[[Done]] to true.
+                // e. Else,
+                // i. Let v be IteratorValue(next).
+                // ii. If v is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // iii. ReturnIfAbrupt(v).
+                // 2. If iteratorRecord.[[Done]] is true, let v be undefined.
+                let v = binding_iterator_step(iterator, done, context)?;
+
+                // 3. If Initializer is present and v is undefined, then
+                // a. Let defaultValue be the result of evaluating Initializer.
+                // b. Set v to ? GetValue(defaultValue).
+                // Note: The initializer is part of the pattern, which evaluates it when no value is passed.
+                let v = v.filter(|v| !v.is_undefined());
+
+                // 4. Return the result of performing BindingInitialization of BindingPattern with v and environment as the arguments.
+                pattern.run(v, bind, context)?;
+            }
+            // BindingRestElement : ... BindingIdentifier
+            SingleNameRest { ident } => {
+                // 1. Let lhs be ? ResolveBinding(StringValue of BindingIdentifier, environment).
+                // 2. Let A be ! ArrayCreate(0).
+                // 3. Let n be 0.
+                let a = Array::array_create(0, None, context)
+                    .expect("Array creation with 0 length should never fail");
+
+                // 4. Repeat,
+                // a. If iteratorRecord.[[Done]] is false, then
+                // i. Let next be IteratorStep(iteratorRecord).
+                // ii. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // iii. ReturnIfAbrupt(next).
+                // iv. If next is false, set iteratorRecord.[[Done]] to true.
+                // c. Let nextValue be IteratorValue(next).
+                // d. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // e. ReturnIfAbrupt(nextValue).
+                while let Some(next_value) = binding_iterator_step(iterator, done, context)? {
+                    // f. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), nextValue).
+                    // g. Set n to n + 1.
+                    Array::add_to_array_object(&a.clone().into(), &[next_value], context)?;
+                }
+
+                // b. If iteratorRecord.[[Done]] is true, then
+                // i. If environment is undefined, return ? PutValue(lhs, A).
+                // ii. Return InitializeReferencedBinding(lhs, A).
+                bind(ident, a.into(), context)?;
+            }
+            // BindingRestElement : ... BindingPattern
+            BindingPatternRest { pattern } => {
+                // 1. Let A be ! ArrayCreate(0).
+                // 2. Let n be 0.
+                let a = Array::array_create(0, None, context)
+                    .expect("Array creation with 0 length should never fail");
+
+                // 3. Repeat,
+                // a. If iteratorRecord.[[Done]] is false, then
+                // i. Let next be IteratorStep(iteratorRecord).
+                // ii. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // iii. ReturnIfAbrupt(next).
+                // iv. If next is false, set iteratorRecord.[[Done]] to true.
+                // c. Let nextValue be IteratorValue(next).
+                // d. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+                // e. ReturnIfAbrupt(nextValue).
+                while let Some(next_value) = binding_iterator_step(iterator, done, context)? {
+                    // f. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), nextValue).
+                    // g. Set n to n + 1.
+                    Array::add_to_array_object(&a.clone().into(), &[next_value], context)?;
+                }
+
+                // b. If iteratorRecord.[[Done]] is true, then
+                // i. Return the result of performing BindingInitialization of BindingPattern with A and environment as the arguments.
+                pattern.run(Some(a.into()), bind, context)?;
+            }
+            // AssignmentElement : DestructuringAssignmentTarget Initializer[opt]
+            PropertyAccess {
+                access,
+                default_init,
+            } => {
+                // 1. If DestructuringAssignmentTarget is neither an ObjectLiteral nor an ArrayLiteral, then
+                // a. Let lref be the result of evaluating DestructuringAssignmentTarget.
+                let (object, key) = property_access_reference(access, context)?;
+
+                // 2. If iteratorRecord.[[Done]] is false, then
+                // a. Let next be IteratorStep(iteratorRecord).
+                // ...
+                // 3. If iteratorRecord.[[Done]] is true, let value be undefined.
+                let mut v = binding_iterator_step(iterator, done, context)?.unwrap_or_default();
+
+                // 4. If Initializer is present and value is undefined, then
+                if let Some(init) = default_init {
+                    if v.is_undefined() {
+                        // b. Else,
+                        // i. Let defaultValue be the result of evaluating Initializer.
+                        // ii. Let v be ? GetValue(defaultValue).
+                        v = init.run(context)?;
+                    }
+                }
+
+                // 7. Return ? PutValue(lref, v).
+                object.set_field(key, v, false, context)?;
+            }
+            // AssignmentRestElement : ... DestructuringAssignmentTarget
+            PropertyAccessRest { access } => {
+                // 1. If DestructuringAssignmentTarget is neither an ObjectLiteral nor an ArrayLiteral, then
+                // a. Let lref be the result of evaluating DestructuringAssignmentTarget.
+                let (object, key) = property_access_reference(access, context)?;
+
+                // 2. Let A be ! ArrayCreate(0).
+                // 3. Let n be 0.
+                let a = Array::array_create(0, None, context)
+                    .expect("Array creation with 0 length should never fail");
+
+                // 4. Repeat, while iteratorRecord.[[Done]] is false,
+                while let Some(next_value) = binding_iterator_step(iterator, done, context)? {
+                    // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), nextValue).
+                    // e. Set n to n + 1.
+                    Array::add_to_array_object(&a.clone().into(), &[next_value], context)?;
+                }
+
+                // 5. If DestructuringAssignmentTarget is neither an ObjectLiteral nor an ArrayLiteral, then
+                // a. Return ? PutValue(lref, A).
+                object.set_field(key, a, false, context)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Evaluates the property access target of an assignment pattern to the object and key that
+/// are assigned to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-property-accessors-runtime-semantics-evaluation
+fn property_access_reference(
+    access: &Node,
+    context: &mut Context,
+) -> JsResult<(JsValue, PropertyKey)> {
+    match access {
+        Node::GetConstField(get_const_field) => {
+            let object = get_const_field.obj().run(context)?;
+            Ok((object, get_const_field.field().into()))
+        }
+        Node::GetField(get_field) => {
+            let object = get_field.obj().run(context)?;
+            let key = get_field.field().run(context)?.to_property_key(context)?;
+            Ok((object, key))
+        }
+        _ => Err(context.construct_syntax_error("invalid destructuring assignment target")),
+    }
+}
+
+/// Steps the iterator of an array binding pattern, unless it is already done.
+///
+/// The iterator is marked as done once it is exhausted, or if stepping it throws, since it must
+/// not be closed in those cases.
+fn binding_iterator_step(
+    iterator: &IteratorRecord,
+    done: &mut bool,
+    context: &mut Context,
+) -> JsResult<Option<JsValue>> {
+    if *done {
+        return Ok(None);
+    }
+    let next = iterator.step(context);
+    if !matches!(next, Ok(Some(_))) {
+        *done = true;
+    }
+    next
+}
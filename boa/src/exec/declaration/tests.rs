@@ -0,0 +1,295 @@
+use crate::exec;
+
+#[test]
+fn duplicate_function_name() {
+    let scenario = r#"
+    function f () {}
+    function f () {return 12;}
+    f()
+    "#;
+
+    assert_eq!(&exec(scenario), "12");
+}
+
+#[test]
+fn binding_pattern_defaults_see_earlier_bindings() {
+    let scenario = r#"
+    let [x, y = x, [z] = [y + 1]] = [1];
+    let {a, b: {c = a} = {}} = {a: 4};
+    [x, y, z, a, c].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,1,2,4,4\"");
+}
+
+#[test]
+fn object_pattern_of_primitive() {
+    let scenario = r#"
+    let {length, ...rest} = "abc";
+    length + ":" + Object.keys(rest).join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"3:0,1,2\"");
+}
+
+#[test]
+fn function_parameter_patterns() {
+    let scenario = r#"
+    function f({a, b = 2}, [c, d] = [3, 4], ...[e]) {
+        return [a, b, c, d, e].join();
+    }
+    var g = ({x}, [y]) => x + y;
+    f({a: 1}, undefined, 5) + " " + g({x: 1}, [2])
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,4,5 3\"");
+}
+
+#[test]
+fn destructuring_assignment() {
+    let scenario = r#"
+    var a = 1, b = 2, o = {};
+    [a, b] = [b, a];
+    ({x: o.x, y: [o.y = 4] = [], ...o.rest} = {x: a, z: b});
+    [a, b, o.x, o.y, Object.keys(o.rest), o.rest.z].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"2,1,2,4,z,1\"");
+}
+
+#[test]
+fn destructuring_for_of_head() {
+    let scenario = r#"
+    var result = [], k, v;
+    for (const [key, {value = 0}] of [[1, {}], [2, {value: 3}]]) {
+        result.push(key, value);
+    }
+    for ([k, v] of [[5, 6]]);
+    result.push(k, v);
+    result.join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,0,2,3,5,6\"");
+}
+
+#[test]
+fn cover_initialized_name_outside_pattern() {
+    let scenario = r#"
+    try {
+        ({a = 1});
+    } catch (e) {
+        e.name
+    }
+    "#;
+
+    assert_eq!(&exec(scenario), "\"SyntaxError\"");
+}
+
+#[test]
+fn class_methods_and_accessors() {
+    let scenario = r#"
+    class Point {
+        constructor(x, y) { this.x = x; this.y = y; }
+        get norm() { return this.x * this.x + this.y * this.y; }
+        ["add"](other) { return new Point(this.x + other.x, this.y + other.y); }
+        static origin() { return new Point(0, 0); }
+    }
+    var p = new Point(1, 2).add(Point.origin()).add(new Point(2, 2));
+    var desc = Object.getOwnPropertyDescriptor(Point, "prototype");
+    [
+        p.norm, typeof Point, Point.name, Point.prototype.add.name,
+        Object.keys(Point.prototype).length, desc.writable, desc.configurable,
+        Object.getOwnPropertyDescriptor(Point.prototype, "norm").get.name,
+    ].join()
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"25,function,Point,add,0,false,false,get norm\""
+    );
+}
+
+#[test]
+fn class_private_members() {
+    let scenario = r#"
+    class Counter {
+        #count = 1;
+        static #instances = 0;
+        constructor() { Counter.#instances++; }
+        #double() { return this.#count * 2; }
+        get #value() { return this.#count; }
+        set #value(v) { this.#count = v; }
+        static #describe() { return "counter"; }
+        increment() { this.#value += 1; return this.#double(); }
+        static instances() { return Counter.#describe() + ":" + Counter.#instances; }
+        static isCounter(o) { return #count in o; }
+    }
+    var c = new Counter();
+    new Counter();
+    [
+        c.increment(), c.increment(), Counter.instances(),
+        Counter.isCounter(c), Counter.isCounter({}),
+    ].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"4,6,counter:2,true,false\"");
+}
+
+#[test]
+fn class_private_names_are_unique_per_evaluation() {
+    let scenario = r#"
+    function make() {
+        return class { #x = 1; static has(o) { return #x in o; } };
+    }
+    var A = make(), B = make();
+    [A.has(new A()), A.has(new B())].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"true,false\"");
+}
+
+#[test]
+fn class_errors() {
+    let scenario = r#"
+    class A {
+        #x = 1;
+        #m() {}
+        static read(o) { return o.#x; }
+        static write() { new A().#m = 1; }
+    }
+    var errors = [];
+    try { A(); } catch (e) { errors.push(e.name); }
+    try { A.read({}); } catch (e) { errors.push(e.name); }
+    try { A.write(); } catch (e) { errors.push(e.name); }
+    try { (class { static #y = 1; static has() { return #y in 1; } }).has(); }
+    catch (e) { errors.push(e.name); }
+    try { new B(); class B {} } catch (e) { errors.push(e.name); }
+    errors.join()
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"TypeError,TypeError,TypeError,TypeError,ReferenceError\""
+    );
+}
+
+#[test]
+fn class_fields_initialization_order() {
+    let scenario = r#"
+    var order = [];
+    function key(k) { order.push(k); return k; }
+    class A {
+        a = order.push("a") && this.b;
+        [key("b")] = 2;
+        static s = order.push("s") && A.name;
+        static [key("t")] = this.s + "!";
+        #c = this.b * 10;
+        getC() { return this.#c; }
+    }
+    order.push("new");
+    var a = new A();
+    order.push(a.a, a.b, a.getC(), A.s, A.t, Object.keys(a));
+    order.join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"b,t,s,new,a,,2,20,A,A!,a,b\"");
+}
+
+#[test]
+fn class_static_blocks() {
+    let scenario = r#"
+    var order = [];
+    class A {
+        static x = 1;
+        static {
+            var local = this.x + 1;
+            this.y = local;
+            order.push(this === A, #z in A);
+        }
+        static #z = 3;
+        static { order.push(this.#z); }
+    }
+    order.push(A.y, "local" in globalThis);
+    order.join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"true,false,3,2,false\"");
+}
+
+#[test]
+fn derived_classes() {
+    let scenario = r#"
+    var order = [];
+    class A {
+        a = order.push("A fields");
+        constructor(x) {
+            order.push("A " + new.target.name);
+            this.x = x;
+        }
+        m() { return "A.m"; }
+        static s() { return "A.s"; }
+    }
+    class B extends A {
+        b = this.x + 1;
+        constructor(x) {
+            order.push("B");
+            const init = () => super(x * 2);
+            init();
+            order.push(this.b);
+        }
+        m() { return super.m() + ",B.m"; }
+    }
+    class C extends B {}
+    var c = new C(1);
+    order.push(
+        c.x, c.m(), C.s(), C.length, c instanceof A,
+        Object.getPrototypeOf(C) === B, Object.getPrototypeOf(C.prototype) === B.prototype
+    );
+    order.join()
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"B,A fields,A C,3,2,A.m,B.m,A.s,0,true,true,true\""
+    );
+}
+
+#[test]
+fn derived_class_builtins_and_null() {
+    let scenario = r#"
+    class E extends Error { constructor(m) { super(m); this.name = "E"; } }
+    class L extends Array {}
+    class N extends null { constructor() { return Object.create(N.prototype); } }
+    var l = new L();
+    l.push(1, 2);
+    var n = new N();
+    [
+        String(new E("boom")), new E() instanceof Error, l.length, Array.isArray(l),
+        n instanceof N, Object.getPrototypeOf(N.prototype)
+    ].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"E: boom,true,2,true,true,\"");
+}
+
+#[test]
+fn derived_class_errors() {
+    let scenario = r#"
+    class A {}
+    var errors = [];
+    function test(f) { try { f(); } catch (e) { errors.push(e.name); } }
+    test(() => new (class extends A { constructor() { this.x = 1; super(); } })());
+    test(() => new (class extends A { constructor() {} })());
+    test(() => new (class extends A { constructor() { super(); super(); } })());
+    test(() => new (class extends A { constructor() { super(); return 1; } })());
+    test(() => class extends 1 {});
+    test(() => { function P() {} P.prototype = 1; class B extends P {} });
+    test(() => { class B extends B {} });
+    errors.join()
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError,ReferenceError,ReferenceError,TypeError,TypeError,TypeError,ReferenceError\""
+    );
+}
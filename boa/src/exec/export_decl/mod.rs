@@ -0,0 +1,73 @@
+//! Execution of the `ExportDecl` node.
+
+use crate::{
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    property::PropertyDescriptor,
+    syntax::ast::node::{ExportDecl, Node},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for ExportDecl {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ExportDecl", "exec");
+
+        match self {
+            Self::Declaration(decl) | Self::DefaultDeclaration(decl) => decl.run(context),
+            // ExportDeclaration : export default AssignmentExpression ;
+            Self::DefaultExpression(expr) => {
+                // 1. If IsAnonymousFunctionDefinition(AssignmentExpression) is true, then
+                //     a. Let value be NamedEvaluation of AssignmentExpression with argument "default".
+                // 2. Else,
+                //     a. Let rhs be the result of evaluating AssignmentExpression.
+                //     b. Let value be ? GetValue(rhs).
+                let value = expr.run(context)?;
+                if is_anonymous_function_definition(expr) {
+                    if let Some(function) = value.as_object() {
+                        function.define_property_or_throw(
+                            "name",
+                            PropertyDescriptor::builder()
+                                .value("default")
+                                .writable(false)
+                                .enumerable(false)
+                                .configurable(true),
+                            context,
+                        )?;
+                    }
+                }
+
+                // 3. Let env be the running execution context's LexicalEnvironment.
+                // 4. Perform ? InitializeBoundName("*default*", value, env).
+                context.create_immutable_binding(
+                    "*default*".to_owned(),
+                    true,
+                    VariableScope::Block,
+                )?;
+                context.initialize_binding("*default*", value)?;
+                Ok(JsValue::undefined())
+            }
+            // Exports lists and re-exports are bound when their module is linked.
+            Self::List(_) | Self::ReExport { .. } | Self::StarReExport { .. } => {
+                Ok(JsValue::undefined())
+            }
+        }
+    }
+}
+
+/// `IsAnonymousFunctionDefinition ( expr )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isanonymousfunctiondefinition
+pub(crate) fn is_anonymous_function_definition(expr: &Node) -> bool {
+    match expr {
+        Node::ArrowFunctionDecl(_) => true,
+        Node::FunctionExpr(expr) => expr.name().is_none(),
+        Node::GeneratorExpr(expr) => expr.name().is_none(),
+        Node::AsyncFunctionExpr(expr) => expr.name().is_none(),
+        Node::AsyncGeneratorExpr(expr) => expr.name().is_none(),
+        Node::ClassExpr(class) => class.name().is_none(),
+        _ => false,
+    }
+}
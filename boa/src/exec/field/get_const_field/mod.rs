@@ -0,0 +1,14 @@
+//! Execution of the `GetConstField` node.
+
+use crate::{exec::Executable, syntax::ast::node::GetConstField, Context, JsResult, JsValue};
+
+impl Executable for GetConstField {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let mut obj = self.obj().run(context)?;
+        if !obj.is_object() {
+            obj = JsValue::Object(obj.to_object(context)?);
+        }
+
+        obj.get_field(self.field(), context)
+    }
+}
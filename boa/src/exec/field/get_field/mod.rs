@@ -0,0 +1,15 @@
+//! Execution of the `GetField` node.
+
+use crate::{exec::Executable, syntax::ast::node::GetField, Context, JsResult, JsValue};
+
+impl Executable for GetField {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let mut obj = self.obj().run(context)?;
+        if !obj.is_object() {
+            obj = JsValue::Object(obj.to_object(context)?);
+        }
+        let field = self.field().run(context)?;
+
+        obj.get_field(field.to_property_key(context)?, context)
+    }
+}
@@ -0,0 +1,22 @@
+//! Execution of the `GetPrivateField` node.
+
+use crate::{
+    exec::Executable, syntax::ast::node::GetPrivateField, Context, JsResult, JsSymbol, JsValue,
+};
+
+/// Gets the symbol of the private name `name`, like `#field`, declared by the class bodies
+/// enclosing the code being executed.
+pub(crate) fn private_name(name: &str, context: &mut Context) -> JsResult<JsSymbol> {
+    Ok(context
+        .get_binding_value(name)?
+        .as_symbol()
+        .expect("private names are bound to symbols"))
+}
+
+impl Executable for GetPrivateField {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let obj = self.obj().run(context)?.to_object(context)?;
+        let name = private_name(&format!("#{}", self.field()), context)?;
+        obj.private_get(&name, context)
+    }
+}
@@ -0,0 +1,60 @@
+//! Execution of the `GetSuperField` node.
+
+use crate::{
+    exec::Executable,
+    object::JsObject,
+    property::PropertyKey,
+    syntax::ast::node::{GetSuperField, Node},
+    Context, JsResult, JsValue,
+};
+
+/// Evaluates a `super` property reference, returning its base object, property key and `this`
+/// value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+pub(crate) fn super_property_reference(
+    field: &Node,
+    context: &mut Context,
+) -> JsResult<(JsObject, PropertyKey, JsValue)> {
+    // 1. Let env be GetThisEnvironment().
+    // 2. Let actualThis be ? env.GetThisBinding().
+    let this = context.get_this_binding()?;
+
+    // 3. Let propertyNameReference be the result of evaluating Expression.
+    // 4. Let propertyNameValue be ? GetValue(propertyNameReference).
+    // 5. Let propertyKey be ? ToPropertyKey(propertyNameValue).
+    let key = field.run(context)?.to_property_key(context)?;
+
+    let base = super_base(context)?;
+
+    Ok((base, key, this))
+}
+
+/// Returns the base object of the `super` property references of the current function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+pub(crate) fn super_base(context: &mut Context) -> JsResult<JsObject> {
+    // MakeSuperPropertyReference ( actualThis, propertyKey, strict )
+    // 1. Let env be GetThisEnvironment().
+    // 2. Assert: env.HasSuperBinding() is true.
+    // 3. Let baseValue be ? env.GetSuperBase().
+    let base = context
+        .get_super_base()?
+        .ok_or_else(|| context.construct_syntax_error("'super' keyword unexpected here"))?;
+
+    // GetValue and PutValue on a super reference both start with ToObject(baseValue).
+    base.to_object(context)
+}
+
+impl Executable for GetSuperField {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let (base, key, this) = super_property_reference(self.field(), context)?;
+        base.__get__(&key, this, context)
+    }
+}
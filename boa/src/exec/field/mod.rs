@@ -0,0 +1,9 @@
+//! Field access node execution.
+
+mod get_const_field;
+mod get_field;
+mod get_private_field;
+mod get_super_field;
+
+pub(crate) use get_private_field::private_name;
+pub(crate) use get_super_field::{super_base, super_property_reference};
@@ -0,0 +1,12 @@
+//! Execution of the `Identifier` node.
+
+use crate::{
+    exec::Executable, syntax::ast::node::Identifier, BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Identifier {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Identifier", "exec");
+        context.get_binding_value(self.as_ref())
+    }
+}
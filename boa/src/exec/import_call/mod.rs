@@ -0,0 +1,100 @@
+//! Execution of the `ImportCall` node.
+
+use crate::{
+    builtins::Promise,
+    exec::Executable,
+    job::NativeJob,
+    module,
+    object::{FunctionBuilder, JsObject},
+    policy::SourceKind,
+    syntax::ast::node::ImportCall,
+    BoaProfiler, Context, JsResult, JsString, JsValue,
+};
+
+impl Executable for ImportCall {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ImportCall", "exec");
+
+        // 2. Let argRef be the result of evaluating AssignmentExpression.
+        // 3. Let specifier be ? GetValue(argRef).
+        let specifier = self.specifier().run(context)?;
+
+        import_dynamically(specifier, context)
+    }
+}
+
+/// Imports the module with the given specifier, returning the promise of its namespace object.
+///
+/// The module is loaded by a job, so the promise settles when the embedder runs the jobs.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-import-call-runtime-semantics-evaluation
+pub(crate) fn import_dynamically(specifier: JsValue, context: &mut Context) -> JsResult<JsValue> {
+    // 1. Let referencingScriptOrModule be GetActiveScriptOrModule().
+    let referrer = context.get_active_module_key();
+
+    // 4. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    let constructor = context
+        .standard_objects()
+        .promise_object()
+        .constructor()
+        .into();
+    let capability = Promise::new_promise_capability(&constructor, context)?;
+
+    // 5. Let specifierString be ToString(specifier).
+    // 6. IfAbruptRejectPromise(specifierString, promiseCapability).
+    let specifier = match specifier.to_string(context) {
+        Ok(specifier) => specifier,
+        Err(error) => return capability.reject_abrupt(error, context),
+    };
+    if let Err(error) = context.check_source(
+        SourceKind::DynamicImport,
+        specifier.as_str(),
+        referrer.as_ref().map(JsString::as_str),
+    ) {
+        return capability.reject_abrupt(error, context);
+    }
+
+    // 7. Perform ! HostImportModuleDynamically(referencingScriptOrModule, specifierString, promiseCapability).
+    // The module is loaded by a job, so the promise settles when the embedder runs the jobs.
+    let promise = capability.promise().clone();
+    context.enqueue_job(NativeJob::new(move |context| {
+        match module::import(&specifier, referrer.as_ref(), context) {
+            Ok((namespace, None)) => {
+                capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[namespace.into()], context)
+            }
+            // The promise is fulfilled with the namespace once the module is evaluated.
+            Ok((namespace, Some(evaluation))) => {
+                let on_fulfilled = FunctionBuilder::closure_with_captures(
+                    context,
+                    |_, _, namespace, _| {
+                        Ok(namespace
+                            .downcast_ref::<JsObject>()
+                            .expect("the fulfillment handler captures the namespace")
+                            .clone()
+                            .into())
+                    },
+                    namespace,
+                )
+                .build();
+                Ok(Promise::perform_promise_then(
+                    &evaluation,
+                    &on_fulfilled.into(),
+                    &JsValue::undefined(),
+                    Some(capability),
+                    context,
+                ))
+            }
+            Err(error) => capability
+                .reject()
+                .call(&JsValue::undefined(), &[error], context),
+        }
+    }));
+
+    // 8. Return promiseCapability.[[Promise]].
+    Ok(promise.into())
+}
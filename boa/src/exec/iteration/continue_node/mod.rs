@@ -0,0 +1,17 @@
+//! Execution of the `Continue` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::Continue,
+    Context, JsResult, JsValue,
+};
+
+impl Executable for Continue {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        context
+            .executor()
+            .set_current_state(InterpreterState::Continue(self.label().map(Box::from)));
+
+        Ok(JsValue::undefined())
+    }
+}
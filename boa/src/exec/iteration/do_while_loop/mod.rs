@@ -0,0 +1,36 @@
+//! Execution of the `DoWhileLoop` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::DoWhileLoop,
+    Context, JsResult, JsValue,
+};
+
+impl Executable for DoWhileLoop {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let mut result;
+        loop {
+            context.check_interrupt()?;
+            result = self.body().run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Break(label) => {
+                    handle_state_with_labels!(self, label, context, break);
+                    break;
+                }
+                InterpreterState::Continue(label) => {
+                    handle_state_with_labels!(self, label, context, continue);
+                }
+                InterpreterState::Return => {
+                    return Ok(result);
+                }
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+            if !self.cond().run(context)?.to_boolean() {
+                break;
+            }
+        }
+        Ok(result)
+    }
+}
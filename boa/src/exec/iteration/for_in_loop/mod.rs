@@ -0,0 +1,69 @@
+//! Execution of the `ForInLoop` node.
+
+use crate::{
+    builtins::{iterable::IteratorRecord, ForInIterator},
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    error::JsErrorCode,
+    exec::{iteration::initialize_loop_variable, Executable, InterpreterState},
+    syntax::ast::node::ForInLoop,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for ForInLoop {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+        let object = self.expr().run(context)?;
+        let mut result = JsValue::undefined();
+
+        if object.is_null_or_undefined() {
+            return Ok(result);
+        }
+        let object = object.to_object(context)?;
+        let for_in_iterator = ForInIterator::create_for_in_iterator(context, JsValue::new(object));
+        let next_function = for_in_iterator
+            .get_property("next")
+            .as_ref()
+            .map(|p| p.expect_value())
+            .cloned()
+            .ok_or_else(|| {
+                context.construct_coded_error(
+                    JsErrorCode::MISSING_NEXT,
+                    "Could not find property `next`",
+                )
+            })?;
+        let iterator = IteratorRecord::new(for_in_iterator, next_function);
+
+        loop {
+            context.check_interrupt()?;
+            {
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+            let iterator_result = iterator.next(context)?;
+            if iterator_result.is_done() {
+                context.pop_environment();
+                break;
+            }
+            let next_result = iterator_result.value();
+
+            initialize_loop_variable(self.variable(), next_result, context)?;
+
+            result = self.body().run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Break(label) => {
+                    handle_state_with_labels!(self, label, context, break);
+                    break;
+                }
+                InterpreterState::Continue(label) => {
+                    handle_state_with_labels!(self, label, context, continue);
+                }
+                InterpreterState::Return => return Ok(result),
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+            let _ = context.pop_environment();
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,57 @@
+//! Execution of the `ForOfLoop` node.
+
+use crate::{
+    builtins::iterable::get_iterator,
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    exec::{iteration::initialize_loop_variable, Executable, InterpreterState},
+    syntax::ast::node::ForOfLoop,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for ForOfLoop {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("ForOf", "exec");
+        if self.is_await() {
+            // The body of an async function is compiled, and `for await` suspends its frame in
+            // the vm.
+            return context
+                .throw_syntax_error("`for await` is only valid in the body of an async function");
+        }
+        let iterable = self.iterable().run(context)?;
+        let iterator = get_iterator(context, iterable)?;
+        let mut result = JsValue::undefined();
+
+        loop {
+            context.check_interrupt()?;
+            {
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+            let iterator_result = iterator.next(context)?;
+            if iterator_result.is_done() {
+                context.pop_environment();
+                break;
+            }
+            let next_result = iterator_result.value();
+
+            initialize_loop_variable(self.variable(), next_result, context)?;
+
+            result = self.body().run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Break(label) => {
+                    handle_state_with_labels!(self, label, context, break);
+                    break;
+                }
+                InterpreterState::Continue(label) => {
+                    handle_state_with_labels!(self, label, context, continue);
+                }
+                InterpreterState::Return => return Ok(result),
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+            let _ = context.pop_environment();
+        }
+        Ok(result)
+    }
+}
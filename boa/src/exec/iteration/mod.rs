@@ -0,0 +1,87 @@
+//! Iteration node execution.
+
+// Checking labels for break and continue is the same operation for `ForLoop`, `While` and `DoWhile`
+macro_rules! handle_state_with_labels {
+    ($self:ident, $label:ident, $interpreter:ident, $state:tt) => {{
+        if let Some(brk_label) = $label {
+            if let Some(stmt_label) = $self.label() {
+                // Break from where we are, keeping "continue" set as the state
+                if stmt_label != brk_label.as_ref() {
+                    break;
+                }
+            } else {
+                // if a label is set but the current block has no label, break
+                break;
+            }
+        }
+
+        $interpreter
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+    }};
+}
+
+use crate::{
+    exec::{BindingInitialization, BindingKind, Executable},
+    syntax::ast::node::{Declaration, IterableLoopInitializer},
+    Context, JsResult, JsValue,
+};
+
+mod continue_node;
+mod do_while_loop;
+mod for_in_loop;
+mod for_loop;
+mod for_of_loop;
+mod while_loop;
+
+#[cfg(test)]
+mod tests;
+
+/// Binds the value of the current iteration of a `for...in` or `for...of` loop to the loop's
+/// variable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-forin-div-ofbodyevaluation-lhs-stmt-iterator-lhskind-labelset
+fn initialize_loop_variable(
+    variable: &IterableLoopInitializer,
+    value: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    let (declaration, kind) = match variable {
+        IterableLoopInitializer::Identifier(ident) => {
+            return BindingKind::Assignment.bind(ident.as_ref(), value, context);
+        }
+        IterableLoopInitializer::GetConstField(get_const_field) => {
+            let object = get_const_field.obj().run(context)?;
+            object.set_field(get_const_field.field(), value, false, context)?;
+            return Ok(());
+        }
+        IterableLoopInitializer::GetField(get_field) => {
+            let object = get_field.obj().run(context)?;
+            let key = get_field.field().run(context)?.to_property_key(context)?;
+            object.set_field(key, value, false, context)?;
+            return Ok(());
+        }
+        IterableLoopInitializer::DeclarationPattern(pattern) => {
+            return pattern.run(
+                Some(value),
+                &mut |name, value, context| BindingKind::Assignment.bind(name, value, context),
+                context,
+            );
+        }
+        IterableLoopInitializer::Var(declaration) => (declaration, BindingKind::Var),
+        IterableLoopInitializer::Let(declaration) => (declaration, BindingKind::Let),
+        IterableLoopInitializer::Const(declaration) => (declaration, BindingKind::Const),
+    };
+
+    match declaration {
+        Declaration::Identifier { ident, .. } => kind.bind(ident.as_ref(), value, context),
+        Declaration::Pattern(pattern) => pattern.run(
+            Some(value),
+            &mut |name, value, context| kind.bind(name, value, context),
+            context,
+        ),
+    }
+}
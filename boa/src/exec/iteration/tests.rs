@@ -485,74 +485,43 @@ fn for_in_continue_label() {
 }
 
 #[test]
-fn fmt() {
-    // Labeled and unlabeled for in loops
-    super::super::test_formatting(
-        r#"
+fn labelled_block_break() {
+    let scenario = r#"
         var str = "";
-        outer: for (let i in [1, 2]) {
-            for (let b in [2, 3, 4]) {
-                if (b === "1") {
-                    continue outer;
-                }
-                str = str + b;
-            };
-            str = str + i;
-        };
-        str;
-        "#,
-    );
-    // Labeled and unlabeled for loops
-    super::super::test_formatting(
-        r#"
+        outer: {
+            str = str + "a";
+            inner: if (true) {
+                break outer;
+            }
+            str = str + "b";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"a\"");
+}
+
+#[test]
+fn multiple_labels_continue() {
+    let scenario = r#"
         var str = "";
-        outer: for (let i = 0; i < 10; ++i) {
-            for (let j = 3; j < 6; ++j) {
-                if (j === "1") {
-                    continue outer;
+        first: second: for (let i = 0; i < 3; i++) {
+            for (let j = 0; j < 3; j++) {
+                if (j === 1) {
+                    continue first;
                 }
-                str = str + j;
-            };
-            str = str + i;
-        };
-        str;
-        "#,
-    );
-    // Labeled and unlabeled for of loops
-    super::super::test_formatting(
-        r#"
-        for (i of [1, 2, 3]) {
-            if (false) {
-                break;
+                str = str + i + j;
             }
-        };
-        label: for (i of [1, 2, 3]) {
-            if (false) {
-                break label;
-            }
-        };
-        "#,
-    );
-    // Labeled and unlabeled do while loops
-    super::super::test_formatting(
-        r#"
-        do {
-            break;
-        } while (true);
-        label: do {
-            break label;
-        } while (true);
-        "#,
-    );
-    // Labeled and unlabeled while loops
-    super::super::test_formatting(
-        r#"
-        while (true) {
-            break;
-        }
-        label: while (true) {
-            break label;
         }
-        "#,
-    );
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"001020\"");
+}
+
+#[test]
+fn invalid_break_and_continue_targets() {
+    check_output(&[
+        TestAction::TestStartsWith("foo: { break bar; }", "Uncaught \"SyntaxError\": "),
+        TestAction::TestStartsWith("foo: { continue foo; }", "Uncaught \"SyntaxError\": "),
+        TestAction::TestStartsWith("continue;", "Uncaught \"SyntaxError\": "),
+    ]);
 }
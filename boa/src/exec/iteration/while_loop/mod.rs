@@ -0,0 +1,33 @@
+//! Execution of the `WhileLoop` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::WhileLoop,
+    Context, JsResult, JsValue,
+};
+
+impl Executable for WhileLoop {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let mut result = JsValue::undefined();
+        while self.cond().run(context)?.to_boolean() {
+            context.check_interrupt()?;
+            result = self.body().run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Break(label) => {
+                    handle_state_with_labels!(self, label, context, break);
+                    break;
+                }
+                InterpreterState::Continue(label) => {
+                    handle_state_with_labels!(self, label, context, continue)
+                }
+                InterpreterState::Return => {
+                    return Ok(result);
+                }
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+        }
+        Ok(result)
+    }
+}
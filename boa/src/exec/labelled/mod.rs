@@ -0,0 +1,30 @@
+//! Execution of the `Labelled` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::Labelled,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Labelled {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Labelled", "exec");
+
+        // 1. Let stmtResult be LabelledEvaluation of LabelledItem with argument newLabelSet.
+        let result = self.statement().run(context)?;
+
+        // 2. If stmtResult.[[Type]] is break and SameValue(stmtResult.[[Target]], label) is true, then
+        //     a. Set stmtResult to NormalCompletion(stmtResult.[[Value]]).
+        if matches!(
+            context.executor().get_current_state(),
+            InterpreterState::Break(Some(label)) if label.as_ref() == self.label()
+        ) {
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+        }
+
+        // 3. Return Completion(stmtResult).
+        Ok(result)
+    }
+}
@@ -1,16 +1,56 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+mod array;
+mod await_expr;
+mod block;
+mod break_node;
+mod call;
+mod conditional;
+mod declaration;
+mod export_decl;
+mod field;
+mod identifier;
+mod import_call;
+mod iteration;
+mod labelled;
+mod new;
+mod object;
+mod operator;
+mod optional;
+mod return_smt;
+mod spread;
+mod statement_list;
+mod switch;
+mod template;
+mod throw;
+mod try_node;
+mod with;
+mod yield_expr;
+
 #[cfg(test)]
 mod tests;
 
-use crate::{Context, JsResult, JsValue};
+pub(crate) use self::{
+    call::{super_call, super_constructor},
+    declaration::{instantiate_lexical_declarations, BindingInitialization, BindingKind},
+    export_decl::is_anonymous_function_definition,
+    field::{private_name, super_base, super_property_reference},
+    import_call::import_dynamically,
+    optional::{run_chain, run_item},
+    template::get_template_object,
+};
+
+use crate::{
+    syntax::ast::{node::Node, Const},
+    BoaProfiler, Context, JsBigInt, JsResult, JsString, JsValue,
+};
 
 pub trait Executable {
     /// Runs this executable in the given context.
     fn run(&self, context: &mut Context) -> JsResult<JsValue>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -18,11 +58,46 @@ pub(crate) enum InterpreterState {
     Continue(Option<Box<str>>),
 }
 
+/// A frame of the JavaScript call stack.
+///
+/// Frames are pushed every time a function object is called or constructed, and are used to
+/// build the `stack` property of error objects.
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    /// The name of the called function, empty for anonymous functions.
+    function_name: JsString,
+}
+
+impl StackFrame {
+    /// Creates a new `StackFrame` for a call to the function with the given name.
+    #[inline]
+    pub fn new<N>(function_name: N) -> Self
+    where
+        N: Into<JsString>,
+    {
+        Self {
+            function_name: function_name.into(),
+        }
+    }
+
+    /// Gets the name of the called function.
+    #[inline]
+    pub fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+}
+
 /// A Javascript intepreter
 #[derive(Debug)]
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+
+    /// The frames of the current call stack, the innermost call being the last one.
+    call_stack: Vec<StackFrame>,
+
+    /// Whether the script being run is strict mode code.
+    strict: bool,
 }
 
 impl Default for Interpreter {
@@ -36,6 +111,8 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            call_stack: Vec::new(),
+            strict: false,
         }
     }
 
@@ -48,4 +125,136 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    #[inline]
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict
+    }
+
+    #[inline]
+    pub(crate) fn strict(&self) -> bool {
+        self.strict
+    }
+
+    #[inline]
+    pub(crate) fn push_frame(&mut self, frame: StackFrame) {
+        self.call_stack.push(frame)
+    }
+
+    #[inline]
+    pub(crate) fn pop_frame(&mut self) -> Option<StackFrame> {
+        self.call_stack.pop()
+    }
+
+    /// Returns the frames of the current call stack, the innermost call being the last one.
+    #[inline]
+    pub fn call_stack(&self) -> &[StackFrame] {
+        &self.call_stack
+    }
+
+    /// Formats the current call stack, skipping the `skip` innermost frames.
+    ///
+    /// Each frame is written on its own line, starting with the innermost call.
+    pub(crate) fn format_call_stack(&self, skip: usize) -> String {
+        let mut result = String::new();
+        for frame in self.call_stack.iter().rev().skip(skip) {
+            result.push_str("\n    at ");
+            if frame.function_name().is_empty() {
+                result.push_str("<anonymous>");
+            } else {
+                result.push_str(frame.function_name());
+            }
+        }
+        result
+    }
+}
+
+impl Executable for Node {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Executable", "exec");
+        match *self {
+            Node::AsyncFunctionDecl(ref decl) => decl.run(context),
+            Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
+            Node::AsyncGeneratorDecl(ref decl) => decl.run(context),
+            Node::AsyncGeneratorExpr(ref expr) => expr.run(context),
+            Node::AwaitExpr(ref expr) => expr.run(context),
+            Node::GeneratorDecl(ref decl) => decl.run(context),
+            Node::GeneratorExpr(ref expr) => expr.run(context),
+            Node::YieldExpr(ref expr) => expr.run(context),
+            Node::Call(ref call) => call.run(context),
+            Node::SuperCall(ref call) => call.run(context),
+            Node::Const(Const::Null) => Ok(JsValue::null()),
+            Node::Const(Const::Num(num)) => Ok(JsValue::new(num)),
+            Node::Const(Const::Int(num)) => Ok(JsValue::new(num)),
+            Node::Const(Const::BigInt(ref num)) => Ok(JsValue::new(JsBigInt::new(num.clone()))),
+            Node::Const(Const::Undefined) => Ok(JsValue::undefined()),
+            // we can't move String from Const into value, because const is a garbage collected value
+            // Which means Drop() get's called on Const, but str will be gone at that point.
+            // Do Const values need to be garbage collected? We no longer need them once we've generated Values
+            Node::Const(Const::String(ref value)) => Ok(JsValue::new(value.to_string())),
+            Node::Const(Const::Bool(value)) => Ok(JsValue::new(value)),
+            Node::Block(ref block) => block.run(context),
+            Node::Identifier(ref identifier) => identifier.run(context),
+            Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(context),
+            Node::GetField(ref get_field) => get_field.run(context),
+            Node::GetSuperField(ref get_super_field) => get_super_field.run(context),
+            Node::GetPrivateField(ref get_private_field) => get_private_field.run(context),
+            Node::PrivateIn(ref private_in) => private_in.run(context),
+            Node::ClassExpr(ref class) => class.run(context),
+            Node::ClassDecl(ref class) => {
+                let value = class.run(context)?;
+                BindingKind::Let.bind(class.name().unwrap_or("*default*"), value, context)?;
+                Ok(JsValue::undefined())
+            }
+            Node::Optional(ref optional) => optional.run(context),
+            Node::WhileLoop(ref while_loop) => while_loop.run(context),
+            Node::DoWhileLoop(ref do_while) => do_while.run(context),
+            Node::With(ref with) => with.run(context),
+            Node::Labelled(ref labelled) => labelled.run(context),
+            Node::ForLoop(ref for_loop) => for_loop.run(context),
+            Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(context),
+            Node::ForInLoop(ref for_in_loop) => for_in_loop.run(context),
+            Node::If(ref if_smt) => if_smt.run(context),
+            Node::ConditionalOp(ref op) => op.run(context),
+            Node::Switch(ref switch) => switch.run(context),
+            Node::Object(ref obj) => obj.run(context),
+            Node::ArrayDecl(ref arr) => arr.run(context),
+            // <https://tc39.es/ecma262/#sec-createdynamicfunction>
+            Node::FunctionDecl(ref decl) => decl.run(context),
+            // <https://tc39.es/ecma262/#sec-createdynamicfunction>
+            Node::FunctionExpr(ref function_expr) => function_expr.run(context),
+            Node::ArrowFunctionDecl(ref decl) => decl.run(context),
+            Node::BinOp(ref op) => op.run(context),
+            Node::UnaryOp(ref op) => op.run(context),
+            Node::New(ref call) => call.run(context),
+            Node::Return(ref ret) => ret.run(context),
+            Node::TaggedTemplate(ref template) => template.run(context),
+            Node::TemplateLit(ref template) => template.run(context),
+            Node::Throw(ref throw) => throw.run(context),
+            Node::Assign(ref op) => op.run(context),
+            Node::VarDeclList(ref decl) => decl.run(context),
+            Node::LetDeclList(ref decl) => decl.run(context),
+            Node::ConstDeclList(ref decl) => decl.run(context),
+            Node::Spread(ref spread) => spread.run(context),
+            Node::This => {
+                // Will either return `this` binding or undefined
+                context.get_this_binding()
+            }
+            Node::NewTarget => {
+                // 1. Return GetNewTarget().
+                match context.get_new_target() {
+                    Some(new_target) => Ok(new_target),
+                    None => context.throw_syntax_error("new.target expression is not allowed here"),
+                }
+            }
+            Node::Try(ref try_node) => try_node.run(context),
+            Node::Break(ref break_node) => break_node.run(context),
+            Node::Continue(ref continue_node) => continue_node.run(context),
+            Node::Empty => Ok(JsValue::undefined()),
+            Node::ImportCall(ref call) => call.run(context),
+            // Imports are bound when their module is linked.
+            Node::ImportDecl(_) => Ok(JsValue::undefined()),
+            Node::ExportDecl(ref decl) => decl.run(context),
+        }
+    }
 }
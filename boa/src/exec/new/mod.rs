@@ -0,0 +1,44 @@
+//! Execution of the `New` node.
+
+use crate::{
+    builtins::iterable,
+    error::JsErrorCode,
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::{New, Node},
+    value::JsValue,
+    BoaProfiler, Context, JsResult,
+};
+
+impl Executable for New {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("New", "exec");
+
+        let func_object = self.expr().run(context)?;
+        let mut v_args = Vec::with_capacity(self.args().len());
+        for arg in self.args() {
+            if let Node::Spread(ref x) = arg {
+                let val = x.run(context)?;
+                v_args.extend(iterable::iterable_to_list(context, val)?);
+            } else {
+                v_args.push(arg.run(context)?);
+            }
+        }
+
+        let result = match func_object {
+            JsValue::Object(ref object) => {
+                object.construct(&v_args, &object.clone().into(), context)
+            }
+            _ => context.throw_coded_error(
+                JsErrorCode::NAMED_NOT_A_CONSTRUCTOR,
+                format!("{} is not a constructor", self.expr().to_string(),),
+            ),
+        };
+
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
+    }
+}
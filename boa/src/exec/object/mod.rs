@@ -0,0 +1,103 @@
+//! Execution of the `Object` node.
+
+use crate::{
+    exec::Executable,
+    property::{PropertyDescriptor, PropertyKey},
+    syntax::ast::node::{MethodDefinitionKind, Object, PropertyDefinition},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Object {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("object", "exec");
+        let obj = JsValue::new_object(context);
+
+        // TODO: Implement the rest of the property types.
+        for property in self.properties().iter() {
+            match property {
+                PropertyDefinition::Property(key, value) => {
+                    obj.set_property(
+                        key.clone(),
+                        PropertyDescriptor::builder()
+                            .value(value.run(context)?)
+                            .writable(true)
+                            .enumerable(true)
+                            .configurable(true),
+                    );
+                }
+                PropertyDefinition::MethodDefinition(kind, name, func) => {
+                    // MakeMethod ( F, homeObject )
+                    let method = func.run(context)?;
+                    if let Some(function) = method.as_object() {
+                        if let Some(function) = function.borrow_mut().as_function_mut() {
+                            function.set_home_object(
+                                obj.as_object()
+                                    .expect("object literal should always be an object"),
+                            );
+                        }
+                    }
+                    match kind {
+                        MethodDefinitionKind::Ordinary => {
+                            obj.set_property(
+                                name.clone(),
+                                PropertyDescriptor::builder()
+                                    .value(method)
+                                    .writable(true)
+                                    .enumerable(true)
+                                    .configurable(true),
+                            );
+                        }
+                        MethodDefinitionKind::Get => {
+                            let set = obj
+                                .get_property(name.clone())
+                                .as_ref()
+                                .and_then(|a| a.set())
+                                .cloned();
+                            obj.set_property(
+                                name.clone(),
+                                PropertyDescriptor::builder()
+                                    .maybe_get(method.as_object())
+                                    .maybe_set(set)
+                                    .enumerable(true)
+                                    .configurable(true),
+                            )
+                        }
+                        MethodDefinitionKind::Set => {
+                            let get = obj
+                                .get_property(name.clone())
+                                .as_ref()
+                                .and_then(|a| a.get())
+                                .cloned();
+                            obj.set_property(
+                                name.clone(),
+                                PropertyDescriptor::builder()
+                                    .maybe_get(get)
+                                    .maybe_set(method.as_object())
+                                    .enumerable(true)
+                                    .configurable(true),
+                            )
+                        }
+                    }
+                }
+                PropertyDefinition::SpreadObject(node) => {
+                    // 1. Let exprValue be the result of evaluating AssignmentExpression.
+                    // 2. Let fromValue be ? GetValue(exprValue).
+                    let from_value = node.run(context)?;
+                    // 3. Let excludedNames be a new empty List.
+                    // 4. Return ? CopyDataProperties(object, fromValue, excludedNames).
+                    obj.as_object()
+                        .expect("object literal should always be an object")
+                        .copy_data_properties::<PropertyKey>(&from_value, Vec::new(), context)?;
+                }
+                // A `CoverInitializedName` is only valid in an object literal that is reparsed as an
+                // assignment pattern.
+                PropertyDefinition::CoverInitializedName(..) => {
+                    return context.throw_syntax_error("invalid shorthand property initializer");
+                }
+                _ => {} //unimplemented!("{:?} type of property", i),
+            }
+        }
+
+        Ok(obj)
+    }
+}
@@ -0,0 +1,48 @@
+//! Execution of the `Assign` node.
+
+use crate::{
+    exec::{
+        private_name, super_property_reference, BindingInitialization, BindingKind, Executable,
+    },
+    syntax::ast::node::{Assign, AssignTarget},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Assign {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Assign", "exec");
+        let val = self.rhs().run(context)?;
+        match self.lhs() {
+            AssignTarget::Identifier(ref name) => {
+                BindingKind::Assignment.bind(name.as_ref(), val.clone(), context)?;
+            }
+            AssignTarget::GetConstField(ref get_const_field) => {
+                let val_obj = get_const_field.obj().run(context)?;
+                val_obj.set_field(get_const_field.field(), val.clone(), false, context)?;
+            }
+            AssignTarget::GetField(ref get_field) => {
+                let object = get_field.obj().run(context)?;
+                let field = get_field.field().run(context)?;
+                let key = field.to_property_key(context)?;
+                object.set_field(key, val.clone(), false, context)?;
+            }
+            AssignTarget::GetPrivateField(ref get_private_field) => {
+                let object = get_private_field.obj().run(context)?.to_object(context)?;
+                let name = private_name(&format!("#{}", get_private_field.field()), context)?;
+                object.private_set(&name, val.clone(), context)?;
+            }
+            AssignTarget::GetSuperField(ref get_super_field) => {
+                let (base, key, this) = super_property_reference(get_super_field.field(), context)?;
+                base.__set__(key, val.clone(), this, context)?;
+            }
+            AssignTarget::DeclarationPattern(ref pattern) => {
+                pattern.run(
+                    Some(val.clone()),
+                    &mut |name, value, context| BindingKind::Assignment.bind(name, value, context),
+                    context,
+                )?;
+            }
+        }
+        Ok(val)
+    }
+}
@@ -0,0 +1,180 @@
+//! Execution of the `BinOp` node.
+
+use crate::{
+    exec::{super_property_reference, Executable},
+    syntax::ast::{
+        node::{BinOp, Node},
+        op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
+    },
+    Context, JsResult, JsValue,
+};
+
+impl Executable for BinOp {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        match self.op() {
+            op::BinOp::Num(op) => {
+                let x = self.lhs().run(context)?;
+                let y = self.rhs().run(context)?;
+                match op {
+                    NumOp::Add => x.add(&y, context),
+                    NumOp::Sub => x.sub(&y, context),
+                    NumOp::Mul => x.mul(&y, context),
+                    NumOp::Exp => x.pow(&y, context),
+                    NumOp::Div => x.div(&y, context),
+                    NumOp::Mod => x.rem(&y, context),
+                }
+            }
+            op::BinOp::Bit(op) => {
+                let x = self.lhs().run(context)?;
+                let y = self.rhs().run(context)?;
+                match op {
+                    BitOp::And => x.bitand(&y, context),
+                    BitOp::Or => x.bitor(&y, context),
+                    BitOp::Xor => x.bitxor(&y, context),
+                    BitOp::Shl => x.shl(&y, context),
+                    BitOp::Shr => x.shr(&y, context),
+                    BitOp::UShr => x.ushr(&y, context),
+                }
+            }
+            op::BinOp::Comp(op) => {
+                let x = self.lhs().run(context)?;
+                let y = self.rhs().run(context)?;
+                Ok(JsValue::new(match op {
+                    CompOp::Equal => x.equals(&y, context)?,
+                    CompOp::NotEqual => !x.equals(&y, context)?,
+                    CompOp::StrictEqual => x.strict_equals(&y),
+                    CompOp::StrictNotEqual => !x.strict_equals(&y),
+                    CompOp::GreaterThan => x.gt(&y, context)?,
+                    CompOp::GreaterThanOrEqual => x.ge(&y, context)?,
+                    CompOp::LessThan => x.lt(&y, context)?,
+                    CompOp::LessThanOrEqual => x.le(&y, context)?,
+                    CompOp::In => {
+                        if !y.is_object() {
+                            return context.throw_type_error(format!(
+                                "right-hand side of 'in' should be an object, got {}",
+                                y.type_of()
+                            ));
+                        }
+                        let key = x.to_property_key(context)?;
+                        context.has_property(&y, &key)?
+                    }
+                    CompOp::InstanceOf => x.instance_of(&y, context)?,
+                }))
+            }
+            op::BinOp::Log(op) => Ok(match op {
+                LogOp::And => {
+                    let left = self.lhs().run(context)?;
+                    if !left.to_boolean() {
+                        left
+                    } else {
+                        self.rhs().run(context)?
+                    }
+                }
+                LogOp::Or => {
+                    let left = self.lhs().run(context)?;
+                    if left.to_boolean() {
+                        left
+                    } else {
+                        self.rhs().run(context)?
+                    }
+                }
+                LogOp::Coalesce => {
+                    let left = self.lhs().run(context)?;
+                    if left.is_null_or_undefined() {
+                        self.rhs().run(context)?
+                    } else {
+                        left
+                    }
+                }
+            }),
+            op::BinOp::Assign(op) => match self.lhs() {
+                Node::Identifier(ref name) => {
+                    let v_a = context.get_binding_value(name.as_ref())?;
+                    if short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+
+                    let value = run_assign(op, v_a, self.rhs(), context)?;
+                    context.set_mutable_binding(name.as_ref(), value.clone(), true)?;
+                    Ok(value)
+                }
+                Node::GetConstField(ref get_const_field) => {
+                    let v_r_a = get_const_field.obj().run(context)?;
+                    let v_a = v_r_a.get_field(get_const_field.field(), context)?;
+                    if short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+
+                    let value = run_assign(op, v_a, self.rhs(), context)?;
+                    v_r_a.set_field(get_const_field.field(), value.clone(), false, context)?;
+                    Ok(value)
+                }
+                Node::GetField(ref get_field) => {
+                    let v_r_a = get_field.obj().run(context)?;
+                    let key = get_field.field().run(context)?.to_property_key(context)?;
+                    let v_a = v_r_a.get_field(key.clone(), context)?;
+                    if short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+
+                    let value = run_assign(op, v_a, self.rhs(), context)?;
+                    v_r_a.set_field(key, value.clone(), false, context)?;
+                    Ok(value)
+                }
+                Node::GetSuperField(ref get_super_field) => {
+                    let (base, key, this) =
+                        super_property_reference(get_super_field.field(), context)?;
+                    let v_a = base.__get__(&key, this.clone(), context)?;
+                    if short_circuits(op, &v_a) {
+                        return Ok(v_a);
+                    }
+
+                    let value = run_assign(op, v_a, self.rhs(), context)?;
+                    base.__set__(key, value.clone(), this, context)?;
+                    Ok(value)
+                }
+                _ => Ok(JsValue::undefined()),
+            },
+            op::BinOp::Comma => {
+                self.lhs().run(context)?;
+                Ok(self.rhs().run(context)?)
+            }
+        }
+    }
+}
+
+/// Runs the assignment operators.
+fn run_assign(op: AssignOp, x: JsValue, y: &Node, context: &mut Context) -> JsResult<JsValue> {
+    match op {
+        AssignOp::Add => x.add(&y.run(context)?, context),
+        AssignOp::Sub => x.sub(&y.run(context)?, context),
+        AssignOp::Mul => x.mul(&y.run(context)?, context),
+        AssignOp::Exp => x.pow(&y.run(context)?, context),
+        AssignOp::Div => x.div(&y.run(context)?, context),
+        AssignOp::Mod => x.rem(&y.run(context)?, context),
+        AssignOp::And => x.bitand(&y.run(context)?, context),
+        AssignOp::Or => x.bitor(&y.run(context)?, context),
+        AssignOp::Xor => x.bitxor(&y.run(context)?, context),
+        AssignOp::Shl => x.shl(&y.run(context)?, context),
+        AssignOp::Shr => x.shr(&y.run(context)?, context),
+        AssignOp::Ushr => x.ushr(&y.run(context)?, context),
+        // The target did not short-circuit, see `short_circuits`.
+        AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => y.run(context),
+    }
+}
+
+/// Checks if a logical assignment operator short-circuits on the current value of its target, in
+/// which case the right hand side is not evaluated and the target is not assigned.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-runtime-semantics-evaluation
+fn short_circuits(op: AssignOp, x: &JsValue) -> bool {
+    match op {
+        AssignOp::BoolAnd => !x.to_boolean(),
+        AssignOp::BoolOr => x.to_boolean(),
+        AssignOp::Coalesce => !x.is_null_or_undefined(),
+        _ => false,
+    }
+}
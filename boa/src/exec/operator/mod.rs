@@ -0,0 +1,9 @@
+//! Operator node execution.
+
+mod assign;
+mod bin_op;
+mod private_in;
+mod unary_op;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,24 @@
+//! Execution of the `PrivateIn` node.
+
+use crate::{
+    exec::{private_name, Executable},
+    syntax::ast::node::PrivateIn,
+    Context, JsResult, JsValue,
+};
+
+impl Executable for PrivateIn {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let target = self.target().run(context)?;
+        let object = match target.as_object() {
+            Some(object) => object,
+            None => {
+                return context.throw_type_error(format!(
+                    "right-hand side of 'in' should be an object, got {}",
+                    target.type_of()
+                ))
+            }
+        };
+        let name = private_name(&format!("#{}", self.name()), context)?;
+        Ok(object.has_private_element(&name).into())
+    }
+}
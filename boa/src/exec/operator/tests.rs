@@ -115,26 +115,85 @@ fn logical_assignment() {
 }
 
 #[test]
-fn fmt() {
-    super::super::test_formatting(
-        r#"
-        let a = 20;
-        a += 10;
-        a -= 10;
-        a *= 10;
-        a **= 10;
-        a /= 10;
-        a %= 10;
-        a &= 10;
-        a |= 10;
-        a ^= 10;
-        a <<= 10;
-        a >>= 10;
-        a >>>= 10;
-        a &&= 10;
-        a ||= 10;
-        a ??= 10;
-        a;
-        "#,
+fn logical_assignment_short_circuits() {
+    let scenario = r#"
+        let stores = 0;
+        let o = {};
+        Object.defineProperty(o, "p", {
+            get: function() { return 1; },
+            set: function(v) { stores++; },
+        });
+        o.p ||= 2;
+        o.p ??= 2;
+        o["p"] ||= 2;
+        const c = 1;
+        c ||= 2;
+        stores;
+        "#;
+
+    assert_eq!(&exec(scenario), "0");
+
+    let scenario = r#"
+        let i = 0;
+        let o = { a: 0, b: null };
+        o[i++] ??= 1;
+        o.a ||= 5;
+        o.b ??= 6;
+        o["a"] &&= 7;
+        [i, o[0], o.a, o.b];
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 1, 1, 7, 6 ]");
+
+    let scenario = r#"
+        let evaluated = false;
+        let a = 1;
+        a ??= (evaluated = true);
+        evaluated;
+        "#;
+
+    assert_eq!(&exec(scenario), "false");
+}
+
+#[test]
+fn optional_chaining() {
+    let scenario = r#"
+        let o = { a: { b: [1, 2], f: function() { return this.b; } } };
+        let n = null;
+        let i = 0;
+        [
+            o?.a?.b[1],
+            n?.a.b.c,
+            n?.[i++],
+            n?.(i++),
+            o.a?.f()[0],
+            o.a.g?.(),
+            o?.["a"].f?.()[1],
+            i,
+        ];
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "[ 2, undefined, undefined, undefined, 1, undefined, 2, 0 ]"
     );
+
+    let scenario = r#"
+        let o = { a: 1 };
+        let n;
+        [delete o?.a, "a" in o, delete n?.a];
+        "#;
+
+    assert_eq!(&exec(scenario), "[ true, false, true ]");
+
+    let scenario = r#"
+        let n = null;
+        try {
+            (n?.a).b;
+        } catch (e) {
+            e.name;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "\"TypeError\"");
 }
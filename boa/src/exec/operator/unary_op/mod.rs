@@ -0,0 +1,141 @@
+//! Execution of the `UnaryOp` node.
+
+use crate::{
+    builtins::Number,
+    error::JsErrorCode,
+    exec::{run_chain, run_item, super_property_reference, Executable},
+    syntax::ast::{
+        node::{Node, OptionalItemKind, UnaryOp},
+        op,
+    },
+    value::Numeric,
+    Context, JsBigInt, JsResult, JsValue,
+};
+
+impl Executable for UnaryOp {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        Ok(match self.op() {
+            op::UnaryOp::Minus => self.target().run(context)?.neg(context)?,
+            op::UnaryOp::Plus => JsValue::new(self.target().run(context)?.to_number(context)?),
+            op::UnaryOp::IncrementPost => {
+                let x = self.target().run(context)?;
+                let ret = x.clone();
+                let result = x.to_number(context)? + 1.0;
+                context.set_value(self.target(), result.into())?;
+                ret
+            }
+            op::UnaryOp::IncrementPre => {
+                let result = self.target().run(context)?.to_number(context)? + 1.0;
+                context.set_value(self.target(), result.into())?
+            }
+            op::UnaryOp::DecrementPost => {
+                let x = self.target().run(context)?;
+                let ret = x.clone();
+                let result = x.to_number(context)? - 1.0;
+                context.set_value(self.target(), result.into())?;
+                ret
+            }
+            op::UnaryOp::DecrementPre => {
+                let result = self.target().run(context)?.to_number(context)? - 1.0;
+                context.set_value(self.target(), result.into())?
+            }
+            op::UnaryOp::Not => self.target().run(context)?.not(context)?.into(),
+            op::UnaryOp::Tilde => {
+                let expr = self.target().run(context)?;
+                let old_v = expr.to_numeric(context)?;
+                match old_v {
+                    Numeric::Number(x) => JsValue::new(Number::not(x)),
+                    Numeric::BigInt(x) => JsValue::new(JsBigInt::not(&x)),
+                }
+            }
+            op::UnaryOp::Void => {
+                self.target().run(context)?;
+                JsValue::undefined()
+            }
+            // UnaryExpression : delete UnaryExpression
+            op::UnaryOp::Delete => {
+                // 1. Let ref be the result of evaluating UnaryExpression.
+                // 4. If IsPropertyReference(ref) is true, then
+                //     c. Let baseObj be ? ToObject(ref.[[Base]]).
+                let (base_obj, key) = match *self.target() {
+                    Node::GetConstField(ref get_const_field) => {
+                        let base_obj = get_const_field.obj().run(context)?.to_object(context)?;
+                        (base_obj, get_const_field.field().into())
+                    }
+                    Node::GetField(ref get_field) => {
+                        let base = get_field.obj().run(context)?;
+                        let field = get_field.field().run(context)?;
+                        let base_obj = base.to_object(context)?;
+                        (base_obj, field.to_property_key(context)?)
+                    }
+                    //     b. If IsSuperReference(ref) is true, throw a ReferenceError exception.
+                    Node::GetSuperField(ref get_super_field) => {
+                        super_property_reference(get_super_field.field(), context)?;
+                        return context.throw_reference_error("Cannot delete a super property");
+                    }
+                    Node::Optional(ref optional) => {
+                        let (last, chain) = optional
+                            .chain()
+                            .split_last()
+                            .expect("optional chain without operations");
+                        let (this, base) = match run_chain(optional, chain.len(), context)? {
+                            Some(reference) => reference,
+                            None => return Ok(JsValue::new(true)),
+                        };
+                        if last.shorted() && base.is_null_or_undefined() {
+                            return Ok(JsValue::new(true));
+                        }
+                        match last.kind() {
+                            OptionalItemKind::GetConstField(field) => {
+                                (base.to_object(context)?, field.as_ref().into())
+                            }
+                            OptionalItemKind::GetField(field) => {
+                                let field = field.run(context)?;
+                                let base_obj = base.to_object(context)?;
+                                (base_obj, field.to_property_key(context)?)
+                            }
+                            // The parser rejects deleting a private field.
+                            OptionalItemKind::GetPrivateField(_) => unreachable!(),
+                            // 2. If ref is not a Reference Record, return true.
+                            OptionalItemKind::Call(_) => {
+                                run_item(last, &this, &base, context)?;
+                                return Ok(JsValue::new(true));
+                            }
+                        }
+                    }
+                    // 3. If IsUnresolvableReference(ref) is true, then
+                    //     b. Return true.
+                    // 5. Else,
+                    //     a. Let base be ref.[[Base]].
+                    //     b. Assert: base is an Environment Record.
+                    //     c. Return ? base.DeleteBinding(ref.[[ReferencedName]]).
+                    Node::Identifier(ref identifier) => {
+                        return context
+                            .delete_binding(identifier.as_ref())
+                            .map(JsValue::new);
+                    }
+                    // 2. If ref is not a Reference Record, return true.
+                    ref target => {
+                        target.run(context)?;
+                        return Ok(JsValue::new(true));
+                    }
+                };
+
+                // d. Let deleteStatus be ? baseObj.[[Delete]](ref.[[ReferencedName]]).
+                let delete_status = base_obj.__delete__(&key, context)?;
+
+                // e. If deleteStatus is false and ref.[[Strict]] is true, throw a TypeError exception.
+                if !delete_status && self.strict() {
+                    return context.throw_coded_error(
+                        JsErrorCode::DELETE_OBJECT_PROPERTY,
+                        format!("Cannot delete property '{}' of object", key),
+                    );
+                }
+
+                // f. Return deleteStatus.
+                JsValue::new(delete_status)
+            }
+            op::UnaryOp::TypeOf => JsValue::new(self.target().run(context)?.type_of()),
+        })
+    }
+}
@@ -0,0 +1,115 @@
+//! Execution of the `Optional` node.
+
+use crate::{
+    builtins::iterable,
+    exec::{private_name, Executable, InterpreterState},
+    syntax::ast::node::{Node, Optional, OptionalItem, OptionalItemKind},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for Optional {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+
+        Ok(run_chain(self, self.chain().len(), context)?
+            .map(|(_, value)| value)
+            .unwrap_or_else(JsValue::undefined))
+    }
+}
+
+/// Evaluates the target of the optional chain and the first `len` operations of its chain,
+/// returning the value of the last operation along with the value it was read from, used as the
+/// `this` value of a following call.
+///
+/// Returns `None` if an operation preceded by `?.` short-circuited the chain.
+pub(crate) fn run_chain(
+    optional: &Optional,
+    len: usize,
+    context: &mut Context,
+) -> JsResult<Option<(JsValue, JsValue)>> {
+    let (mut this, mut value) = match optional.target() {
+        Node::GetConstField(ref get_const_field) => {
+            let obj = get_const_field.obj().run(context)?.to_object(context)?;
+            let value = obj.get(get_const_field.field(), context)?;
+            (obj.into(), value)
+        }
+        Node::GetField(ref get_field) => {
+            let obj = get_field.obj().run(context)?.to_object(context)?;
+            let key = get_field.field().run(context)?.to_property_key(context)?;
+            let value = obj.get(key, context)?;
+            (obj.into(), value)
+        }
+        Node::GetPrivateField(ref get_private_field) => {
+            let obj = get_private_field.obj().run(context)?.to_object(context)?;
+            let name = private_name(&format!("#{}", get_private_field.field()), context)?;
+            let value = obj.private_get(&name, context)?;
+            (obj.into(), value)
+        }
+        Node::Identifier(ref identifier) => (
+            context.get_implicit_this_value(identifier.as_ref()),
+            identifier.run(context)?,
+        ),
+        target => (
+            // The callee binds `this` itself, to the global `this` value if it is not strict.
+            JsValue::undefined(),
+            target.run(context)?,
+        ),
+    };
+
+    for item in &optional.chain()[..len] {
+        if item.shorted() && value.is_null_or_undefined() {
+            return Ok(None);
+        }
+        let next = run_item(item, &this, &value, context)?;
+        this = match item.kind() {
+            OptionalItemKind::Call(_) => JsValue::undefined(),
+            _ => value,
+        };
+        value = next;
+    }
+    Ok(Some((this, value)))
+}
+
+/// Evaluates an operation of an optional chain on the value of the previous one.
+pub(crate) fn run_item(
+    item: &OptionalItem,
+    this: &JsValue,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    match item.kind() {
+        OptionalItemKind::GetConstField(field) => {
+            value.to_object(context)?.get(field.as_ref(), context)
+        }
+        OptionalItemKind::GetField(field) => {
+            let obj = value.to_object(context)?;
+            let key = field.run(context)?.to_property_key(context)?;
+            obj.get(key, context)
+        }
+        OptionalItemKind::GetPrivateField(field) => {
+            let obj = value.to_object(context)?;
+            let name = private_name(&format!("#{}", field), context)?;
+            obj.private_get(&name, context)
+        }
+        OptionalItemKind::Call(args) => {
+            let mut v_args = Vec::with_capacity(args.len());
+            for arg in args.iter() {
+                if let Node::Spread(ref x) = arg {
+                    let val = x.run(context)?;
+                    v_args.extend(iterable::iterable_to_list(context, val)?);
+                } else {
+                    v_args.push(arg.run(context)?);
+                }
+            }
+
+            let result = context.call(value, this, &v_args);
+
+            // unset the early return flag
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+
+            result
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Execution of the `Return` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::Return,
+    Context, JsResult, JsValue,
+};
+
+impl Executable for Return {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let result = match self.expr() {
+            Some(v) => v.run(context)?,
+            None => JsValue::undefined(),
+        };
+        // Set flag for return
+        context
+            .executor()
+            .set_current_state(InterpreterState::Return);
+        Ok(result)
+    }
+}
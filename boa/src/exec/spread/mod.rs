@@ -0,0 +1,13 @@
+//! Execution of the `Spread` node.
+
+use crate::{exec::Executable, syntax::ast::node::Spread, Context, JsResult, JsValue};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for Spread {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        // TODO: for now we can do nothing but return the value as-is
+        self.val().run(context)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::exec;
+
+#[test]
+fn spread_with_new() {
+    let scenario = r#"
+    function F(m) {
+        this.m = m;
+    }
+    function f(...args) {
+        return new F(...args);
+    }
+    let a = f('message');
+    a.m;
+    "#;
+    assert_eq!(&exec(scenario), r#""message""#);
+}
+
+#[test]
+fn spread_with_call() {
+    let scenario = r#"
+    function f(m) {
+        return m;
+    }
+    function g(...args) {
+        return f(...args);
+    }
+    let a = g('message');
+    a;
+    "#;
+    assert_eq!(&exec(scenario), r#""message""#);
+}
+
+#[test]
+fn spread_mixed_with_arguments() {
+    let scenario = r#"
+    function f(...args) {
+        return args.join();
+    }
+    function F(a, b, c, d) {
+        this.m = [a, b, c, d].join();
+    }
+    [f(0, ...[1, 2], 3, ...'ab'), new F(...[1], 2, ...new Set([3, 4])).m].join("|");
+    "#;
+    assert_eq!(&exec(scenario), r#""0,1,2,3,a,b|1,2,3,4""#);
+}
+
+#[test]
+fn spread_in_array_literal() {
+    let scenario = r#"
+    let iterable = {};
+    iterable[Symbol.iterator] = function () {
+        let i = 0;
+        return { next: function () { i++; return { value: i, done: i > 2 }; } };
+    };
+    [0, ...iterable, ...'xy', ...[3]].join();
+    "#;
+    assert_eq!(&exec(scenario), r#""0,1,2,x,y,3""#);
+}
+
+#[test]
+fn spread_in_object_literal() {
+    let scenario = r#"
+    let source = { b: 2, get c() { return 3; } };
+    Object.defineProperty(source, "hidden", { value: 4, enumerable: false });
+    let copy = { a: 1, b: 0, ...source, ...null, ...undefined, ...'x', d: 5 };
+    let desc = Object.getOwnPropertyDescriptor(copy, "c");
+    [
+        JSON.stringify(copy),
+        "hidden" in copy,
+        desc.value,
+        desc.writable,
+    ].join("|");
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        r#""{"0":"x","a":1,"b":2,"c":3,"d":5}|false|3|true""#
+    );
+}
@@ -0,0 +1,45 @@
+//! Execution of the `StatementList` node.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::StatementList,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for StatementList {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("StatementList", "exec");
+
+        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
+        // The return value is uninitialized, which means it defaults to Value::Undefined
+        let mut obj = JsValue::default();
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        for (i, item) in self.items().iter().enumerate() {
+            let val = item.run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    obj = val;
+                    break;
+                }
+                InterpreterState::Break(_label) => {
+                    // Early break.
+                    break;
+                }
+                InterpreterState::Continue(_label) => {
+                    break;
+                }
+                InterpreterState::Executing => {
+                    // Continue execution
+                }
+            }
+            if i + 1 == self.items().len() {
+                obj = val;
+            }
+        }
+
+        Ok(obj)
+    }
+}
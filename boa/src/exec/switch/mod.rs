@@ -0,0 +1,118 @@
+//! Execution of the `Switch` node.
+
+use crate::{
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    exec::{instantiate_lexical_declarations, Executable, InterpreterState},
+    syntax::ast::node::Switch,
+    Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for Switch {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let val = self.val().run(context)?;
+
+        // The cases share a new declarative environment, holding their lexical declarations.
+        // https://tc39.es/ecma262/#sec-switch-statement-runtime-semantics-evaluation
+        {
+            let env = context.get_current_environment();
+            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+        }
+        let result = case_block_evaluation(self, &val, context);
+        context.pop_environment();
+        result
+    }
+}
+
+/// Runs the cases of the switch statement whose value is `val`.
+fn case_block_evaluation(
+    switch: &Switch,
+    val: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    for case in switch.cases() {
+        instantiate_lexical_declarations(case.body().items(), context)?;
+    }
+    if let Some(default) = switch.default() {
+        instantiate_lexical_declarations(default, context)?;
+    }
+
+    let mut result = JsValue::null();
+    let mut matched = false;
+    context
+        .executor()
+        .set_current_state(InterpreterState::Executing);
+
+    // If a case block does not end with a break statement then subsequent cases will be run without
+    // checking their conditions until a break is encountered.
+    let mut fall_through: bool = false;
+
+    for case in switch.cases().iter() {
+        let cond = case.condition();
+        let block = case.body();
+        if fall_through || val.strict_equals(&cond.run(context)?) {
+            matched = true;
+            let result = block.run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    return Ok(result);
+                }
+                InterpreterState::Break(None) => {
+                    // Break statement encountered so therefore end switch statement.
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    break;
+                }
+                InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                    // Labelled break and continue statements target an enclosing statement.
+                    break;
+                }
+                InterpreterState::Executing => {
+                    // Continuing execution / falling through to next case statement(s).
+                    fall_through = true;
+                }
+            }
+        }
+    }
+
+    if !matched {
+        if let Some(default) = switch.default() {
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+            for (i, item) in default.iter().enumerate() {
+                let val = item.run(context)?;
+                match context.executor().get_current_state() {
+                    InterpreterState::Return => {
+                        // Early return.
+                        result = val;
+                        break;
+                    }
+                    InterpreterState::Break(None) => {
+                        // Early break.
+                        context
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                        break;
+                    }
+                    InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                        // Labelled break and continue statements target an enclosing statement.
+                        break;
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution
+                    }
+                }
+                if i == default.len() - 1 {
+                    result = val;
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
@@ -208,37 +208,41 @@ fn bigger_switch_example() {
 }
 
 #[test]
-fn fmt() {
-    super::super::test_formatting(
-        r#"
-        let a = 3;
-        let b = "unknown";
-        switch (a) {
-            case 0:
-                b = "Mon";
-                break;
-            case 1:
-                b = "Tue";
-                break;
-            case 2:
-                b = "Wed";
-                break;
-            case 3:
-                b = "Thurs";
-                break;
-            case 4:
-                b = "Fri";
-                break;
-            case 5:
-                b = "Sat";
-                break;
-            case 6:
-                b = "Sun";
-                break;
-            default:
-                b = "Unknown";
+fn break_and_continue_in_default() {
+    let scenario = r#"
+        let a = 0;
+        let b = 0;
+        for (let i = 0; i < 3; i++) {
+            switch (i) {
+                default:
+                    a++;
+                    break;
+            }
+            switch (i) {
+                default:
+                    continue;
+            }
+            b++;
         }
-        b;
-        "#,
-    );
+        [a, b].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"3,0\"");
+}
+
+#[test]
+fn labelled_break_through_switch() {
+    let scenario = r#"
+        let a = 0;
+        outer: for (let i = 0; i < 3; i++) {
+            switch (i) {
+                case 1:
+                    break outer;
+            }
+            a++;
+        }
+        a
+    "#;
+
+    assert_eq!(&exec(scenario), "1");
 }
@@ -0,0 +1,184 @@
+//! Execution of the `TemplateLit`, `TaggedTemplate` nodes.
+
+use crate::{
+    builtins::Array,
+    exec::Executable,
+    object::JsObject,
+    property::PropertyDescriptor,
+    syntax::ast::node::{
+        template::{TaggedTemplate, TemplateElement},
+        Node, TemplateLit,
+    },
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for TemplateLit {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "exec");
+        let mut result = String::new();
+
+        for element in self.elements().iter() {
+            match element {
+                TemplateElement::String(s) => {
+                    result.push_str(s);
+                }
+                TemplateElement::Expr(node) => {
+                    let value = node.run(context)?;
+                    let s = value.to_string(context)?;
+                    result.push_str(&s);
+                }
+            }
+        }
+        Ok(result.into())
+    }
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+
+        let template_object = get_template_object(self, context)?;
+
+        let (this, func) = match *self.tag() {
+            Node::GetConstField(ref get_const_field) => {
+                let mut obj = get_const_field.obj().run(context)?;
+                if !obj.is_object() {
+                    obj = JsValue::Object(obj.to_object(context)?);
+                }
+                (
+                    obj.clone(),
+                    obj.get_field(get_const_field.field(), context)?,
+                )
+            }
+            Node::GetField(ref get_field) => {
+                let obj = get_field.obj().run(context)?;
+                let field = get_field.field().run(context)?;
+                (
+                    obj.clone(),
+                    obj.get_field(field.to_property_key(context)?, context)?,
+                )
+            }
+            Node::Identifier(ref identifier) => (
+                context.get_implicit_this_value(identifier.as_ref()),
+                identifier.run(context)?,
+            ),
+            _ => (JsValue::undefined(), self.tag().run(context)?),
+        };
+
+        let mut args = vec![template_object.into()];
+        for expr in self.exprs().iter() {
+            args.push(expr.run(context)?);
+        }
+
+        context.call(&func, &this, &args)
+    }
+}
+
+/// `13.2.8.3 GetTemplateObject ( templateLiteral )`
+///
+/// Returns the frozen strings array passed to the tag function, creating it the first time
+/// the call site is evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+pub(crate) fn get_template_object(
+    template: &TaggedTemplate,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. Let realm be the current Realm Record.
+    // 2. Let templateRegistry be realm.[[TemplateMap]].
+    // 3. For each element e of templateRegistry, do
+    //     a. If e.[[Site]] is the same Parse Node as templateLiteral, then
+    //         i. Return e.[[Array]].
+    if let Some(array) = context.realm.template_map.get(template.site()) {
+        return Ok(array);
+    }
+
+    // 4. Let rawStrings be TemplateStrings of templateLiteral with argument true.
+    let raw_strings = template.raws();
+    // 5. Let cookedStrings be TemplateStrings of templateLiteral with argument false.
+    let cooked_strings = template.cookeds();
+    // 6. Let count be the number of elements in the List cookedStrings.
+    let count = cooked_strings.len();
+
+    // 7. Assert: count ≤ 2^32 - 1.
+    // 8. Let template be ! ArrayCreate(count).
+    let template_object =
+        Array::array_create(count, None, context).expect("ArrayCreate should never fail here");
+    // 9. Let rawObj be ! ArrayCreate(count).
+    let raw_obj =
+        Array::array_create(count, None, context).expect("ArrayCreate should never fail here");
+
+    // 10. Let index be 0.
+    // 11. Repeat, while index < count,
+    for (index, (cooked, raw)) in cooked_strings.iter().zip(raw_strings.iter()).enumerate() {
+        // a. Let prop be ! ToString(𝔽(index)).
+        // b. Let cookedValue be cookedStrings[index].
+        let cooked_value = cooked
+            .as_ref()
+            .map_or_else(JsValue::undefined, |cooked| JsValue::new(cooked.as_ref()));
+        // c. Perform ! DefinePropertyOrThrow(template, prop, PropertyDescriptor { [[Value]]: cookedValue, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }).
+        template_object
+            .define_property_or_throw(
+                index,
+                PropertyDescriptor::builder()
+                    .value(cooked_value)
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                context,
+            )
+            .expect("DefinePropertyOrThrow should never fail here");
+        // d. Let rawValue be the String value rawStrings[index].
+        // e. Perform ! DefinePropertyOrThrow(rawObj, prop, PropertyDescriptor { [[Value]]: rawValue, [[Writable]]: false, [[Enumerable]]: true, [[Configurable]]: false }).
+        raw_obj
+            .define_property_or_throw(
+                index,
+                PropertyDescriptor::builder()
+                    .value(raw.as_ref())
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                context,
+            )
+            .expect("DefinePropertyOrThrow should never fail here");
+        // f. Set index to index + 1.
+    }
+
+    // 12. Perform ! SetIntegrityLevel(rawObj, frozen).
+    raw_obj
+        .freeze(context)
+        .expect("SetIntegrityLevel should never fail here");
+
+    // 13. Perform ! DefinePropertyOrThrow(template, "raw", PropertyDescriptor { [[Value]]: rawObj, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+    template_object
+        .define_property_or_throw(
+            "raw",
+            PropertyDescriptor::builder()
+                .value(raw_obj)
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            context,
+        )
+        .expect("DefinePropertyOrThrow should never fail here");
+
+    // 14. Perform ! SetIntegrityLevel(template, frozen).
+    template_object
+        .freeze(context)
+        .expect("SetIntegrityLevel should never fail here");
+
+    // 15. Append the Record { [[Site]]: templateLiteral, [[Array]]: template } to templateRegistry.
+    context
+        .realm
+        .template_map
+        .insert(template.site(), template_object.clone());
+
+    // 16. Return template.
+    Ok(template_object)
+}
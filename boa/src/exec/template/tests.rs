@@ -0,0 +1,100 @@
+use crate::exec;
+
+#[test]
+fn template_literal() {
+    let scenario = r#"
+        let a = 10;
+        `result: ${a} and ${a+10}`;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"result: 10 and 20\"");
+}
+
+#[test]
+fn tagged_template() {
+    let scenario = r#"
+        function tag(t, ...args) {
+           let a = []
+           a = a.concat([t[0], t[1], t[2]]);
+           a = a.concat([t.raw[0], t.raw[1], t.raw[2]]);
+           a = a.concat([args[0], args[1]]);
+           return a
+        }
+        let a = 10;
+        tag`result: ${a} \x26 ${a+10}`;
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        r#"[ "result: ", " & ", "", "result: ", " \x26 ", "", 10, 20 ]"#
+    );
+}
+
+#[test]
+fn folded_template_literal() {
+    let scenario = r#"
+        let a = 10;
+        `${"result"}: ${1} and ${a}` + " or " + "not";
+        "#;
+
+    assert_eq!(&exec(scenario), "\"result: 1 and 10 or not\"");
+}
+
+#[test]
+fn tagged_template_object_is_frozen() {
+    let scenario = r#"
+        function tag(t) { return t; }
+        let t = tag`a${1}b`;
+        let raw = Object.getOwnPropertyDescriptor(t, "raw");
+        let first = Object.getOwnPropertyDescriptor(t, "0");
+        [
+            Array.isArray(t), t.length, Reflect.isExtensible(t), Reflect.isExtensible(t.raw),
+            Reflect.set(t, 0, "x"), Reflect.set(t.raw, 0, "x"),
+            raw.writable, raw.enumerable, raw.configurable,
+            first.writable, first.enumerable, first.configurable,
+            Object.keys(t).join(),
+        ].join();
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        r#""true,2,false,false,false,false,false,false,false,false,true,false,0,1""#
+    );
+}
+
+#[test]
+fn tagged_template_invalid_escape() {
+    let scenario = r#"
+        function tag(t) { return [t[0] === undefined, t.raw[0]].join(); }
+        tag`\unicode`;
+        "#;
+
+    assert_eq!(&exec(scenario), r#""true,\unicode""#);
+}
+
+#[test]
+fn tagged_template_object_is_cached_per_site() {
+    let scenario = r#"
+        function tag(t) { return t; }
+        function site() { return tag`same`; }
+        let first = site();
+        let other = tag`same`;
+        [first === site(), first === other, first[0] === other[0]].join();
+        "#;
+
+    assert_eq!(&exec(scenario), r#""true,false,true""#);
+}
+
+#[test]
+fn tagged_template_sites_of_dropped_scripts_are_released() {
+    let mut context = crate::Context::new();
+    context
+        .eval("function tag(t) { return t; } var first = tag`same`;")
+        .unwrap();
+
+    // Each script is a new parse node, so its site gets a new template object.
+    for _ in 0..100 {
+        assert_eq!(context.eval("tag`same` === first").unwrap(), false.into());
+    }
+    assert!(context.realm.template_map.len() < 32);
+}
@@ -1,5 +1,11 @@
 use crate::{
-    builtins::Number, check_output, exec, forward, forward_val, Context, JsValue, TestAction,
+    builtins::Number,
+    check_output,
+    error::JsNativeErrorKind,
+    exec, forward, forward_val,
+    module::MapModuleLoader,
+    policy::{SourceKind, SourcePolicy},
+    set_fuel, Context, JsResult, JsValue, TestAction,
 };
 
 #[test]
@@ -659,6 +665,84 @@ fn unary_delete() {
     assert_eq!(&exec(delete_recursive), "true");
 }
 
+#[test]
+fn unary_delete_bindings() {
+    let delete_var = r#"
+        var a = 5;
+        delete a + '' + typeof a
+    "#;
+    assert_eq!(&exec(delete_var), "\"falsenumber\"");
+
+    let delete_implicit_global = r#"
+        b = 5;
+        delete b + '' + ('b' in this)
+    "#;
+    assert_eq!(&exec(delete_implicit_global), "\"truefalse\"");
+
+    let delete_unresolvable = r#"
+        delete notDefined
+    "#;
+    assert_eq!(&exec(delete_unresolvable), "true");
+
+    let delete_function_var = r#"
+        function f() {
+            var x = 1;
+            return delete x + '' + x;
+        }
+        f()
+    "#;
+    assert_eq!(&exec(delete_function_var), "\"false1\"");
+
+    let delete_evaluates_operand = r#"
+        var called = false;
+        function g() {
+            called = true;
+        }
+        delete g() + '' + called
+    "#;
+    assert_eq!(&exec(delete_evaluates_operand), "\"truetrue\"");
+}
+
+#[test]
+fn unary_delete_non_configurable() {
+    let sloppy = r#"
+        var o = Object.defineProperty({}, 'a', { value: 1 });
+        delete o.a + '' + o.a
+    "#;
+    assert_eq!(&exec(sloppy), "\"false1\"");
+
+    let strict = r#"
+        'use strict';
+        var o = Object.defineProperty({}, 'a', { value: 1 });
+        try {
+            delete o['a'];
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(&exec(strict), "true");
+
+    let strict_function = r#"
+        function f() {
+            'use strict';
+            return delete Object.prototype;
+        }
+        try {
+            f();
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(&exec(strict_function), "true");
+
+    let strict_configurable = r#"
+        'use strict';
+        var o = { a: 1 };
+        delete o.a + '' + ('a' in o)
+    "#;
+    assert_eq!(&exec(strict_configurable), "\"truefalse\"");
+}
+
 #[cfg(test)]
 mod in_operator {
     use super::*;
@@ -1439,6 +1523,23 @@ fn test_strict_mode_delete() {
     )]);
 }
 
+#[test]
+fn test_strict_mode_delete_in_function() {
+    // The early error also applies to strict functions, and to parenthesized identifiers.
+
+    let scenario = r#"
+    function f() {
+        'use strict';
+        delete (x);
+    }
+    "#;
+
+    check_output(&[TestAction::TestStartsWith(
+        scenario,
+        "Uncaught \"SyntaxError\": ",
+    )]);
+}
+
 #[test]
 fn test_strict_mode_reserved_name() {
     // Checks that usage of a reserved keyword for an identifier name is
@@ -1502,6 +1603,80 @@ fn test_strict_mode_dup_func_parameters() {
     )]);
 }
 
+#[test]
+fn test_strict_mode_undeclared_assignment() {
+    // Checks that assigning to an undeclared variable throws a ReferenceError in strict mode
+    // code as per https://tc39.es/ecma262/#sec-putvalue, instead of creating a global binding.
+
+    let scenario = r#"
+    x = 1;
+    function f() {
+        'use strict';
+        y = 2;
+    }
+    try {
+        f();
+    } catch (e) {
+        e.name + ": " + e.message + ", " + x + ", " + typeof globalThis.y;
+    }
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: y is not defined, 1, undefined\""
+    );
+}
+
+#[test]
+fn test_strict_mode_this() {
+    // Checks that the `this` value of strict functions is not coerced, as per
+    // https://tc39.es/ecma262/#sec-ordinarycallbindthis.
+
+    let scenario = r#"
+    function sloppy() { return this; }
+    function strict() { 'use strict'; return this; }
+    [
+        sloppy() === globalThis,
+        sloppy.call(null) === globalThis,
+        typeof sloppy.call(1),
+        strict(),
+        strict.call(null),
+        typeof strict.call(1),
+    ]
+    "#;
+
+    check_output(&[TestAction::TestEq(
+        scenario,
+        "[ true, true, \"object\", undefined, null, \"number\" ]",
+    )]);
+}
+
+#[test]
+fn global_declarations() {
+    // Top-level `var` and function declarations are properties of the global object, unlike
+    // the lexical declarations.
+    let scenario = r#"
+    var v = 1;
+    function f() {}
+    let l = 2;
+    implicit = 3;
+    var desc = Object.getOwnPropertyDescriptor(globalThis, "v");
+    [
+        globalThis.v,
+        desc.configurable,
+        typeof globalThis.f,
+        "l" in globalThis,
+        delete globalThis.implicit,
+        "implicit" in globalThis,
+    ]
+    "#;
+
+    check_output(&[TestAction::TestEq(
+        scenario,
+        "[ 1, false, \"function\", false, true, false ]",
+    )]);
+}
+
 #[test]
 fn test_empty_statement() {
     let src = r#"
@@ -1511,3 +1686,438 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn interrupt_loops() {
+    let mut context = Context::new();
+    let loops = [
+        "while (true) {}",
+        "do {} while (true)",
+        "for (;;) {}",
+        "for (var k in new Array(1000).fill(0)) {}",
+        "for (var v of new Array(1000).fill(0)) {}",
+    ];
+    for source in loops {
+        set_fuel(&mut context, 100);
+        assert_eq!(
+            forward(&mut context, source),
+            "Uncaught \"RangeError\": \"out of fuel\"",
+            "{}",
+            source
+        );
+    }
+
+    set_fuel(&mut context, 100);
+    assert_eq!(
+        forward(&mut context, "var i = 0; while (i < 10) { i++ }; i"),
+        "10"
+    );
+
+    context.clear_interrupt_handler();
+    assert_eq!(
+        forward(&mut context, "var i = 0; while (i < 1000) { i++ }; i"),
+        "1000"
+    );
+}
+
+/// A source policy recording the sources it is consulted for, and denying those containing
+/// `deny`.
+#[derive(Debug, Default)]
+struct RecordingPolicy {
+    checked: std::rc::Rc<std::cell::RefCell<Vec<(SourceKind, String, Option<String>)>>>,
+}
+
+impl SourcePolicy for RecordingPolicy {
+    fn check(
+        &self,
+        kind: SourceKind,
+        source: &str,
+        origin: Option<&str>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.checked
+            .borrow_mut()
+            .push((kind, source.to_owned(), origin.map(str::to_owned)));
+        if source.contains("deny") {
+            return Err(context.construct_eval_error("denied by policy"));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn source_policy() {
+    let mut context = Context::new();
+    let mut loader = MapModuleLoader::new();
+    loader.insert("allowed", "export let value = globalThis['eval']('1 + 1');");
+    loader.insert("denied", "export let deny = 1;");
+    context.set_module_loader(loader);
+    let policy = RecordingPolicy::default();
+    let checked = policy.checked.clone();
+    context.set_source_policy(policy);
+
+    assert_eq!(forward(&mut context, "eval('2 * 3')"), "6");
+    assert_eq!(
+        forward(&mut context, "eval('deny')"),
+        "Uncaught \"EvalError\": \"denied by policy\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Function('deny', 'return 1')"),
+        "Uncaught \"EvalError\": \"denied by policy\""
+    );
+    forward(
+        &mut context,
+        r#"
+        import('allowed').then(ns => globalThis.allowed = ns.value);
+        import('deny').catch(e => globalThis.dynamic = e.message);
+        import('denied').catch(e => globalThis.fetched = e.message);
+        "#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "allowed"), "2");
+    assert_eq!(forward(&mut context, "dynamic"), "\"denied by policy\"");
+    assert_eq!(forward(&mut context, "fetched"), "\"denied by policy\"");
+
+    let checked = checked.borrow();
+    assert_eq!(
+        checked[..3],
+        [
+            (SourceKind::Eval, "2 * 3".to_owned(), None),
+            (SourceKind::Eval, "deny".to_owned(), None),
+            (
+                SourceKind::Function,
+                "function anonymous(deny\n) {\nreturn 1\n}".to_owned(),
+                None
+            ),
+        ]
+    );
+    assert!(checked.contains(&(
+        SourceKind::Module,
+        "export let value = globalThis['eval']('1 + 1');".to_owned(),
+        Some("allowed".to_owned())
+    )));
+    assert!(checked.contains(&(
+        SourceKind::Eval,
+        "1 + 1".to_owned(),
+        Some("allowed".to_owned())
+    )));
+    assert!(checked.contains(&(SourceKind::DynamicImport, "deny".to_owned(), None)));
+    drop(checked);
+
+    context.clear_source_policy();
+    assert_eq!(forward(&mut context, "eval('var deny = 1; deny')"), "1");
+}
+
+#[test]
+fn dynamic_code_disabled() {
+    let mut context = Context::new();
+    let attempts = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let recorded = attempts.clone();
+    context.set_dynamic_code_audit(move |attempt| {
+        recorded
+            .borrow_mut()
+            .push((attempt.kind, attempt.source.to_owned(), attempt.allowed));
+    });
+    context.set_source_policy(RecordingPolicy::default());
+
+    assert_eq!(forward(&mut context, "eval('1 + 1')"), "2");
+    assert_eq!(
+        forward(&mut context, "eval('deny')"),
+        "Uncaught \"EvalError\": \"denied by policy\""
+    );
+
+    context.set_dynamic_code_enabled(false);
+    assert!(!context.dynamic_code_enabled());
+    assert_eq!(
+        forward(&mut context, "eval('2 + 2')"),
+        "Uncaught \"EvalError\": \"Dynamic code compilation is disabled\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function('return 1'); } catch (e) { e instanceof EvalError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var GeneratorFunction = Object.getPrototypeOf(function* () {}).constructor;
+            try { new GeneratorFunction('yield 1'); } catch (e) { e instanceof EvalError }
+            "#
+        ),
+        "true"
+    );
+
+    // Values other than strings are not compiled, so they are neither rejected nor recorded.
+    assert_eq!(forward(&mut context, "eval(5)"), "5");
+    assert_eq!(forward(&mut context, "1 + 1"), "2");
+
+    assert_eq!(
+        *attempts.borrow(),
+        [
+            (SourceKind::Eval, "1 + 1".to_owned(), true),
+            (SourceKind::Eval, "deny".to_owned(), false),
+            (SourceKind::Eval, "2 + 2".to_owned(), false),
+            (
+                SourceKind::Function,
+                "function anonymous(\n) {\nreturn 1\n}".to_owned(),
+                false
+            ),
+            (
+                SourceKind::Function,
+                "function* anonymous(\n) {\nyield 1\n}".to_owned(),
+                false
+            ),
+        ]
+    );
+
+    context.set_dynamic_code_enabled(true);
+    context.clear_dynamic_code_audit();
+    assert_eq!(forward(&mut context, "eval('3 + 3')"), "6");
+    assert_eq!(attempts.borrow().len(), 5);
+}
+
+#[test]
+fn eval_source_encodings() {
+    let mut context = Context::new();
+
+    let utf8_bom = "\u{FEFF}var s = 'héllo'; s.length";
+    assert_eq!(context.eval(utf8_bom).unwrap().as_number(), Some(5.0));
+
+    let mut utf16_le = vec![0xFF, 0xFE];
+    let mut utf16_be = vec![0xFE, 0xFF];
+    for unit in "'héllo' + '✓'".encode_utf16() {
+        utf16_le.extend_from_slice(&unit.to_le_bytes());
+        utf16_be.extend_from_slice(&unit.to_be_bytes());
+    }
+    let value = context.eval(&utf16_le).unwrap();
+    assert_eq!(value.display().to_string(), "\"héllo✓\"");
+    let value = context.eval(&utf16_be).unwrap();
+    assert_eq!(value.display().to_string(), "\"héllo✓\"");
+
+    let units: Vec<u16> = "\u{FEFF}[1, 2].length".encode_utf16().collect();
+    assert_eq!(context.eval_utf16(&units).unwrap().as_number(), Some(2.0));
+
+    let err = context.eval_utf16(&[0xD800]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "SyntaxError: source text is not valid UTF-16"
+    );
+    assert!(context.eval([0xFF, 0xFE, 0x31]).is_err());
+}
+
+#[test]
+fn reset_context() {
+    let mut context = Context::new();
+    context.capture_reset_point();
+    forward(
+        &mut context,
+        r#"
+        var user = 'alice';
+        let session = 1;
+        const token = 'secret';
+        globalThis.leaked = true;
+        Array.prototype.first = function() { return this[0]; };
+        Math.max = function() { return 0; };
+        Reflect.preventExtensions(JSON);
+        delete Object.keys;
+        Promise.resolve().then(() => globalThis.late = true);
+        "#,
+    );
+    context.reset().unwrap();
+    context.run_jobs().unwrap();
+
+    for name in ["user", "session", "token", "leaked", "late"] {
+        assert_eq!(
+            forward(&mut context, name),
+            format!("Uncaught \"ReferenceError\": \"{} is not defined\"", name)
+        );
+    }
+    assert_eq!(forward(&mut context, "[1, 2].first"), "undefined");
+    assert_eq!(forward(&mut context, "Math.max(1, 2)"), "2");
+    assert_eq!(forward(&mut context, "Reflect.isExtensible(JSON)"), "true");
+    assert_eq!(forward(&mut context, "Object.keys({ a: 1 }).length"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "let session = 2; const token = 3; session + token"
+        ),
+        "5"
+    );
+}
+
+#[test]
+fn reset_restores_builtins_reachable_from_globals() {
+    let mut context = Context::new();
+    context.capture_reset_point();
+    forward(
+        &mut context,
+        r#"
+        structuredClone = null;
+        Object.getPrototypeOf(Int8Array.prototype).extra = 1;
+        Object.getPrototypeOf(function* () {}).extra = 2;
+        Object.getPrototypeOf([][Symbol.iterator]()).extra = 3;
+        "#,
+    );
+    context.reset().unwrap();
+
+    assert_eq!(
+        forward(&mut context, "typeof structuredClone"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "new Int8Array(1).extra"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(function* () {}).extra"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "[][Symbol.iterator]().extra"),
+        "undefined"
+    );
+}
+
+#[test]
+fn reset_without_reset_point() {
+    let mut context = Context::new();
+    forward(&mut context, "var kept = 1;");
+
+    let error = context.reset().unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Error));
+    assert_eq!(forward(&mut context, "kept"), "1");
+}
+
+#[test]
+fn reset_context_to_reset_point() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "var config = { retries: 3 }; String.prototype.shout = function() { return this + '!'; };",
+    );
+    context.capture_reset_point();
+
+    forward(
+        &mut context,
+        "config.retries = 0; config.extra = 1; var request = 1; delete String.prototype.shout;",
+    );
+    context.reset().unwrap();
+
+    assert_eq!(forward(&mut context, "config.retries"), "3");
+    assert_eq!(forward(&mut context, "'extra' in config"), "false");
+    assert_eq!(
+        forward(&mut context, "request"),
+        "Uncaught \"ReferenceError\": \"request is not defined\""
+    );
+    assert_eq!(forward(&mut context, "'hi'.shout()"), "\"hi!\"");
+}
+
+#[test]
+fn new_target() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function F() { this.target = new.target; }
+        function G() {}
+        function arrow() { return (() => new.target)(); }
+        function defaultParam(target = new.target) { this.target = target; }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "new F().target === F"), "true");
+    assert_eq!(forward(&mut context, "arrow()"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Reflect.construct(F, [], G).target === G"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new defaultParam().target === defaultParam"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { eval('new.target') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+}
+
+#[test]
+fn super_property() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var proto = { v: 'proto', x: 1, g() { return this.v; } };
+        var obj = {
+            v: 'obj',
+            f() { return super.g(); },
+            sum() { return super.x + super['x']; },
+            set() { super.y = 2; return [this.y, proto.y]; },
+            arrow() { return (() => super.g())(); },
+            get accessor() { return super.v; },
+            remove() { delete super.x; },
+        };
+        Object.setPrototypeOf(obj, proto);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.f()"), "\"obj\"");
+    assert_eq!(forward(&mut context, "obj.sum()"), "2");
+    assert_eq!(forward(&mut context, "obj.set().join()"), "\"2,\"");
+    assert_eq!(forward(&mut context, "obj.arrow()"), "\"obj\"");
+    assert_eq!(forward(&mut context, "obj.accessor"), "\"proto\"");
+    assert_eq!(
+        forward(&mut context, "try { obj.remove() } catch (e) { e.name }"),
+        "\"ReferenceError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "function f() { return super.x; } try { f() } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+}
+
+#[test]
+fn with_statement() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var a = 'outer';
+        var keys = 'outer keys';
+        var obj = { a: 1, get g() { return 'getter'; }, f() { return this === obj; } };
+        var blocked = { a: 'blocked' };
+        blocked[Symbol.unscopables] = { a: true };
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "with (obj) { [a, g].join() }"),
+        "\"1,getter\""
+    );
+    assert_eq!(forward(&mut context, "with (obj) { f() }"), "true");
+    assert_eq!(forward(&mut context, "with (obj) { f`` }"), "true");
+    assert_eq!(
+        forward(&mut context, "with (obj) { a = 2; } [obj.a, a].join()"),
+        "\"2,outer\""
+    );
+    assert_eq!(
+        forward(&mut context, "with (Object.create(obj)) { a }"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "with (blocked) { a }"), "\"outer\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "with ([1, 2]) { [length, keys, join('-')].join() }"
+        ),
+        "\"2,outer keys,1-2\""
+    );
+    assert_eq!(forward(&mut context, "with ('str') { charAt(1) }"), "\"t\"");
+    assert_eq!(
+        forward(&mut context, "try { with (null) {} } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,10 @@
+//! Execution of the `Throw` node.
+
+use crate::{exec::Executable, syntax::ast::node::Throw, Context, JsResult, JsValue};
+
+impl Executable for Throw {
+    #[inline]
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        Err(self.expr().run(context)?)
+    }
+}
@@ -0,0 +1,68 @@
+//! Execution of the `Try` node.
+
+use crate::{
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    exec::{BindingInitialization, BindingKind, Executable, InterpreterState},
+    syntax::ast::node::{Declaration, Try},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for Try {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("Try", "exec");
+        let res = self.block().run(context).map_or_else(
+            |err| {
+                // A generator resumed by `return` unwinds its body as if it were an error, which
+                // is not caught.
+                if context.executor().get_current_state() == &InterpreterState::Return {
+                    Err(err)
+                } else if let Some(catch) = self.catch() {
+                    // The catch parameter is bound in its own declarative environment.
+                    // https://tc39.es/ecma262/#sec-runtime-semantics-catchclauseevaluation
+                    let env = context.get_current_environment();
+                    context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+
+                    let res = match catch.parameter() {
+                        Some(Declaration::Identifier { ident, .. }) => {
+                            BindingKind::Let.bind(ident.as_ref(), err, context)
+                        }
+                        Some(Declaration::Pattern(pattern)) => pattern.run(
+                            Some(err),
+                            &mut |name, value, context| BindingKind::Let.bind(name, value, context),
+                            context,
+                        ),
+                        None => Ok(()),
+                    }
+                    .and_then(|()| catch.block().run(context));
+
+                    // pop the block env
+                    let _ = context.pop_environment();
+
+                    res
+                } else {
+                    Err(err)
+                }
+            },
+            Ok,
+        );
+
+        if let Some(finally) = self.finally() {
+            // The `finally` block runs on its own, and only replaces the completion of the `try`
+            // statement if it returns, breaks, continues or throws.
+            let state = context.executor().get_current_state().clone();
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+            let finally_result = finally.run(context)?;
+            if context.executor().get_current_state() != &InterpreterState::Executing {
+                return Ok(finally_result);
+            }
+            context.executor().set_current_state(state);
+        }
+
+        res
+    }
+}
@@ -95,21 +95,81 @@ fn catch_binding_finally() {
 }
 
 #[test]
-fn fmt() {
-    super::super::test_formatting(
-        r#"
+fn finally_after_return() {
+    let scenario = r#"
+        function f() {
+            let a = [];
+            try {
+                return a;
+            } finally {
+                a.push(1);
+                a.push(2);
+            }
+        }
+
+        f().length;
+    "#;
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn finally_overrides_return() {
+    let scenario = r#"
+        function f() {
+            try {
+                return 10;
+            } finally {
+                return 20;
+            }
+        }
+
+        f();
+    "#;
+    assert_eq!(&exec(scenario), "20");
+}
+
+#[test]
+fn catch_binding_pattern() {
+    let scenario = r#"
+        let a = 10;
         try {
-            throw "hello";
-        } catch(e) {
-            console.log(e);
-        } finally {
-            console.log("things");
-        };
+            throw { a: 20, b: [30] };
+        } catch ({ a, b: [c] }) {
+            a = a + c;
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "10");
+
+    let scenario = r#"
+        let res;
         try {
-            throw "hello";
-        } catch {
-            console.log("something went wrong");
-        };
-        "#,
-    );
+            throw [1, 2];
+        } catch ([x, y]) {
+            res = x + y;
+        }
+
+        res;
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn catch_binding_pattern_error() {
+    let scenario = r#"
+        let res;
+        try {
+            try {
+                throw null;
+            } catch ({ a }) {
+                res = "caught";
+            }
+        } catch (e) {
+            res = e.name;
+        }
+
+        res;
+    "#;
+    assert_eq!(&exec(scenario), "\"TypeError\"");
 }
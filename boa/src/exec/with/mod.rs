@@ -0,0 +1,35 @@
+//! Execution of the `With` node.
+
+use crate::{
+    environment::object_environment_record::ObjectEnvironmentRecord, exec::Executable,
+    syntax::ast::node::With, BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for With {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("With", "exec");
+
+        // 1. Let val be the result of evaluating Expression.
+        // 2. Let obj be ? ToObject(? GetValue(val)).
+        let obj = self.object().run(context)?.to_object(context)?;
+
+        // 3. Let oldEnv be the running execution context's LexicalEnvironment.
+        // 4. Let newEnv be NewObjectEnvironment(obj, true, oldEnv).
+        // 5. Set the running execution context's LexicalEnvironment to newEnv.
+        let old_env = context.get_current_environment();
+        context.push_environment(ObjectEnvironmentRecord {
+            bindings: obj.into(),
+            with_environment: true,
+            outer_env: Some(old_env),
+        });
+
+        // 6. Let C be the result of evaluating Statement.
+        let result = self.statement().run(context);
+
+        // 7. Set the running execution context's LexicalEnvironment to oldEnv.
+        context.pop_environment();
+
+        // 8. Return Completion(UpdateEmpty(C, undefined)).
+        result
+    }
+}
@@ -0,0 +1,14 @@
+//! Execution of the `YieldExpr` node.
+
+use crate::{
+    exec::Executable, syntax::ast::node::YieldExpr, BoaProfiler, Context, JsResult, JsValue,
+};
+
+impl Executable for YieldExpr {
+    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "exec");
+
+        // The body of a generator is compiled, and `yield` suspends its frame in the vm.
+        context.throw_syntax_error("`yield` is only valid in the body of a generator")
+    }
+}
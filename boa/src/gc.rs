@@ -1,10 +1,121 @@
 //! This module represents the main way to interact with the garbage collector.
+//!
+//! Rust values stored in the internal slot of an object must implement [`Trace`], so that the
+//! collector finds the JavaScript values they hold, and [`Finalize`]. Both traits can be derived,
+//! without depending on the `gc` crate:
+//!
+//! ```
+//!# use boa::{
+//!#     gc::{force_collect, Finalize, Trace},
+//!#     object::{JsObject, Object},
+//!#     Context, JsValue,
+//!# };
+//! #[derive(Debug, Trace, Finalize)]
+//! struct Listener {
+//!     /// The object notified by the listener, kept alive by the object holding the listener.
+//!     target: JsObject,
+//!     arguments: Vec<JsValue>,
+//!     /// Fields that don't hold JavaScript values can be skipped.
+//!     #[unsafe_ignore_trace]
+//!     calls: std::cell::Cell<u32>,
+//! }
+//!
+//! let mut context = Context::new();
+//! let target = context.eval("['a', 'b']").unwrap().as_object().unwrap();
+//! let listener = JsObject::new(Object::native_object(Listener {
+//!     target,
+//!     arguments: vec![JsValue::new(1), JsValue::new(2)],
+//!     calls: Default::default(),
+//! }));
+//! force_collect();
+//!
+//! let listener = listener.downcast_ref::<Listener>().unwrap();
+//! listener.calls.set(listener.calls.get() + 1);
+//! let target = JsValue::new(listener.target.clone()).to_string(&mut context).unwrap();
+//! assert_eq!(target.as_str(), "a,b");
+//! ```
+//!
+//! Skipping a field with `#[unsafe_ignore_trace]` is only sound if it can't hold JavaScript values,
+//! since the collector would free the objects it references while they are still reachable.
+//! Cleanup code goes in [`Finalize::finalize`], as the derived [`Trace`] implements `Drop`.
 
 // This is because `rust-gc` unsafe_empty_trace has a `unsafe_`
 // when it should be `empty_trace`.
 #![allow(clippy::unsafe_removed_from_name)]
 
+pub use self::traits::*;
+pub use boa_gc_derive::{Finalize, Trace};
 pub use gc::{
-    custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
-    GcCellRefMut as RefMut, Trace,
+    custom_trace, force_collect, unsafe_empty_trace as empty_trace, GcCellRef as Ref,
+    GcCellRefMut as RefMut,
 };
+
+/// Used by the implementations generated by the derive macros.
+#[doc(hidden)]
+pub use gc::finalizer_safe;
+
+/// The garbage collection traits, without the derive macros of the `gc` crate sharing their names,
+/// which are shadowed by the ones of `boa_gc_derive` above.
+mod traits {
+    pub use gc::{Finalize, Trace};
+}
+
+#[cfg(feature = "gc-barriers")]
+use crate::object::JsObject;
+#[cfg(feature = "gc-barriers")]
+use rustc_hash::FxHashSet;
+#[cfg(feature = "gc-barriers")]
+use std::cell::RefCell;
+
+/// The size, in bytes, of the memory regions tracked by the write barrier.
+///
+/// Mutating an object marks the whole card holding it as dirty, so that a collector only has to
+/// scan the dirty cards for references to the objects it moved or didn't trace.
+#[cfg(feature = "gc-barriers")]
+pub const CARD_SIZE: usize = 512;
+
+#[cfg(feature = "gc-barriers")]
+thread_local! {
+    /// The cards holding objects mutated since the dirty cards were last taken.
+    static DIRTY_CARDS: RefCell<FxHashSet<usize>> = RefCell::default();
+}
+
+/// Gets the card holding the memory at `address`.
+#[cfg(feature = "gc-barriers")]
+#[inline]
+fn card_of<T: ?Sized>(address: *const T) -> usize {
+    address as *const u8 as usize / CARD_SIZE
+}
+
+/// The write barrier, run before an object is mutated, marking the card holding it as dirty.
+#[cfg(feature = "gc-barriers")]
+#[inline]
+pub(crate) fn write_barrier<T: ?Sized>(cell: &T) {
+    // Finalizers may still mutate objects while the thread is torn down, after the dirty cards
+    // are destroyed, at which point there is no collection left to track the mutations for.
+    let _ = DIRTY_CARDS.try_with(|cards| cards.borrow_mut().insert(card_of(cell)));
+}
+
+/// The write barrier, which does nothing since the collector in use doesn't need it.
+#[cfg(not(feature = "gc-barriers"))]
+#[inline(always)]
+pub(crate) fn write_barrier<T: ?Sized>(_: &T) {}
+
+/// Checks if `object` was mutated since the dirty cards were last taken.
+///
+/// Objects sharing a card with a mutated object are reported as mutated too.
+#[cfg(feature = "gc-barriers")]
+pub fn is_dirty(object: &JsObject) -> bool {
+    let card = card_of(object.as_ref());
+    DIRTY_CARDS.with(|cards| cards.borrow().contains(&card))
+}
+
+/// Takes the cards, in ascending order, holding the objects mutated since they were last taken.
+///
+/// A card `n` spans the addresses from `n * CARD_SIZE` to `(n + 1) * CARD_SIZE`.
+#[cfg(feature = "gc-barriers")]
+pub fn take_dirty_cards() -> Vec<usize> {
+    let mut cards: Vec<_> = DIRTY_CARDS.with(|cards| cards.borrow_mut().drain().collect());
+    cards.sort_unstable();
+    cards
+}
@@ -0,0 +1,63 @@
+//! This module implements the jobs of the engine.
+//!
+//! A job is an abstract operation that is run after the currently running script has finished
+//! executing, like the reaction of a `Promise` to its settlement. Jobs are queued on the
+//! [`Context`] and run by [`Context::run_jobs`], so embedders decide when microtasks are
+//! performed.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use crate::{Context, JsResult, JsValue};
+use std::fmt;
+
+/// A job implemented by a Rust closure.
+///
+/// # Examples
+///
+/// ```
+/// use boa::{job::NativeJob, Context, JsValue};
+///
+/// let mut context = Context::new();
+///
+/// context.enqueue_job(NativeJob::new(|context| {
+///     context.eval("globalThis.ran = true").map_err(|e| e.to_opaque(context))
+/// }));
+/// assert!(context.eval("globalThis.ran").unwrap().is_undefined());
+///
+/// context.run_jobs().unwrap();
+/// assert_eq!(context.eval("globalThis.ran").unwrap(), JsValue::new(true));
+/// ```
+pub struct NativeJob {
+    #[allow(clippy::type_complexity)]
+    function: Box<dyn FnOnce(&mut Context) -> JsResult<JsValue>>,
+}
+
+impl NativeJob {
+    /// Creates a new job from a closure.
+    #[inline]
+    pub fn new<F>(function: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self {
+            function: Box::new(function),
+        }
+    }
+
+    /// Runs the job.
+    #[inline]
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        (self.function)(context)
+    }
+}
+
+impl fmt::Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeJob")
+            .field("function", &"function")
+            .finish()
+    }
+}
@@ -41,30 +41,49 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
     missing_doc_code_examples
 )]
 
+// Lets the implementations generated by `boa_gc_derive`, which refer to `::boa`, be used inside
+// the crate.
+extern crate self as boa;
+
 pub mod bigint;
 pub mod builtins;
+#[cfg(feature = "shared-cache")]
+pub mod cache;
 pub mod class;
 pub mod context;
 pub mod environment;
+pub mod error;
 pub mod exec;
 pub mod gc;
+pub mod job;
+pub mod module;
 pub mod object;
-pub mod profiler;
+pub mod policy;
 pub mod property;
 pub mod realm;
+pub mod serialization;
 pub mod string;
 pub mod symbol;
-pub mod syntax;
+pub mod teardown;
+pub mod test_support;
 pub mod value;
 
-#[cfg(feature = "vm")]
 pub mod bytecompiler;
-#[cfg(feature = "vm")]
 pub mod vm;
 
+#[doc(inline)]
+pub use boa_profiler as profiler;
+#[doc(inline)]
+pub use boa_syntax as syntax;
+
 /// A convenience module that re-exports the most commonly-used Boa APIs
 pub mod prelude {
-    pub use crate::{object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue};
+    pub use crate::{
+        error::JsError,
+        object::JsObject,
+        value::{ArgumentsExt, TryFromJs},
+        Context, JsBigInt, JsResult, JsString, JsValue,
+    };
 }
 
 use std::result::Result as StdResult;
@@ -74,7 +93,8 @@ pub(crate) use crate::{exec::Executable, profiler::BoaProfiler};
 // Export things to root level
 #[doc(inline)]
 pub use crate::{
-    bigint::JsBigInt, context::Context, string::JsString, symbol::JsSymbol, value::JsValue,
+    bigint::JsBigInt, context::Context, error::JsError, string::JsString, symbol::JsSymbol,
+    value::JsValue,
 };
 
 use crate::syntax::{
@@ -100,51 +120,31 @@ pub fn parse<T: AsRef<[u8]>>(src: T, strict_mode: bool) -> StdResult<StatementLi
 /// The str is consumed and the state of the Context is changed
 #[cfg(test)]
 pub(crate) fn forward<T: AsRef<[u8]>>(context: &mut Context, src: T) -> String {
-    let src_bytes: &[u8] = src.as_ref();
-
-    // Setup executor
-    let expr = match parse(src_bytes, false) {
-        Ok(res) => res,
-        Err(e) => {
-            return format!(
-                "Uncaught {}",
-                context
-                    .throw_syntax_error(e.to_string())
-                    .expect_err("interpreter.throw_syntax_error() did not return an error")
-                    .display()
-            );
-        }
-    };
-    expr.run(context).map_or_else(
-        |e| format!("Uncaught {}", e.display()),
-        |v| v.display().to_string(),
-    )
+    match context.eval(src) {
+        Ok(value) => value.display().to_string(),
+        Err(error) => format!("Uncaught {}", error.to_opaque(context).display()),
+    }
 }
 
 /// Execute the code using an existing Context.
 /// The str is consumed and the state of the Context is changed
 /// Similar to `forward`, except the current value is returned instad of the string
 /// If the interpreter fails parsing an error value is returned instead (error object)
-#[allow(clippy::unit_arg, clippy::drop_copy)]
 #[cfg(test)]
 pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> JsResult<JsValue> {
-    let main_timer = BoaProfiler::global().start_event("Main", "Main");
+    context.eval(src).map_err(|error| error.to_opaque(context))
+}
 
-    let src_bytes: &[u8] = src.as_ref();
-    // Setup executor
-    let result = parse(src_bytes, false)
-        .map_err(|e| {
-            context
-                .throw_syntax_error(e.to_string())
-                .expect_err("interpreter.throw_syntax_error() did not return an error")
-        })
-        .and_then(|expr| expr.run(context));
-
-    // The main_timer needs to be dropped before the BoaProfiler is.
-    drop(main_timer);
-    BoaProfiler::global().drop();
-
-    result
+/// Sets an interrupt handler throwing a `RangeError` once it has been called `fuel` times.
+#[cfg(test)]
+pub(crate) fn set_fuel(context: &mut Context, mut fuel: usize) {
+    context.set_interrupt_handler(move |context| {
+        if fuel == 0 {
+            return Err(context.construct_range_error("out of fuel"));
+        }
+        fuel -= 1;
+        Ok(())
+    });
 }
 
 /// Create a clean Context and execute the code
@@ -152,9 +152,10 @@ pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> JsRe
 pub(crate) fn exec<T: AsRef<[u8]>>(src: T) -> String {
     let src_bytes: &[u8] = src.as_ref();
 
-    match Context::new().eval(src_bytes) {
+    let mut context = Context::new();
+    match context.eval(src_bytes) {
         Ok(value) => value.display().to_string(),
-        Err(error) => error.display().to_string(),
+        Err(error) => error.to_opaque(&mut context).display().to_string(),
     }
 }
 
@@ -0,0 +1,118 @@
+//! The module loaders provided by the engine.
+
+use super::ModuleLoader;
+use crate::{Context, JsResult, JsString};
+use rustc_hash::FxHashMap;
+use std::path::{Component, Path, PathBuf};
+
+/// A module loader serving the source text of modules from memory.
+///
+/// Modules are identified by their specifier, which is used as is.
+///
+/// # Examples
+/// ```
+/// use boa::{module::MapModuleLoader, Context};
+///
+/// let mut loader = MapModuleLoader::new();
+/// loader.insert("answer", "export const answer = 42;");
+///
+/// let mut context = Context::new();
+/// context.set_module_loader(loader);
+/// assert!(context.import_module("question").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MapModuleLoader {
+    modules: FxHashMap<String, Vec<u8>>,
+}
+
+impl MapModuleLoader {
+    /// Creates an empty loader.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a module to the loader, replacing the module with the same specifier, if any.
+    #[inline]
+    pub fn insert<K, S>(&mut self, specifier: K, source: S)
+    where
+        K: Into<String>,
+        S: Into<Vec<u8>>,
+    {
+        self.modules.insert(specifier.into(), source.into());
+    }
+}
+
+impl ModuleLoader for MapModuleLoader {
+    fn fetch(&self, key: &str, context: &mut Context) -> JsResult<Vec<u8>> {
+        match self.modules.get(key) {
+            Some(source) => Ok(source.clone()),
+            None => Err(context.construct_error(format!("Cannot find module {}", key))),
+        }
+    }
+}
+
+/// A module loader reading the source text of modules from the file system.
+///
+/// The specifiers starting with `./` or `../` of the imports of a module are resolved against
+/// the directory of that module, and all the other specifiers are resolved against the root
+/// directory of the loader. Modules are identified by their normalized path.
+#[derive(Debug, Clone)]
+pub struct FsModuleLoader {
+    root: PathBuf,
+}
+
+impl FsModuleLoader {
+    /// Creates a loader resolving specifiers against the `root` directory.
+    #[inline]
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+}
+
+impl ModuleLoader for FsModuleLoader {
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        _context: &mut Context,
+    ) -> JsResult<JsString> {
+        let base = match referrer {
+            Some(referrer) if specifier.starts_with("./") || specifier.starts_with("../") => {
+                Path::new(referrer).parent().unwrap_or(&self.root)
+            }
+            _ => &self.root,
+        };
+        Ok(normalize(&base.join(specifier))
+            .to_string_lossy()
+            .as_ref()
+            .into())
+    }
+
+    fn fetch(&self, key: &str, context: &mut Context) -> JsResult<Vec<u8>> {
+        std::fs::read(key)
+            .map_err(|e| context.construct_error(format!("Cannot load module {}: {}", key, e)))
+    }
+}
+
+/// Removes the `.` and `..` components of a path, without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
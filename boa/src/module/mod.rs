@@ -0,0 +1,906 @@
+//! This module implements ECMAScript modules.
+//!
+//! The engine does not know where the source text of a module comes from: embedders provide it
+//! by implementing the [`ModuleLoader`] trait, and setting their loader with
+//! [`Context::set_module_loader`]. [`Context::import_module`] then loads the requested module and
+//! the modules it imports, links their bindings and evaluates them. Scripts and modules can also
+//! import modules with `import()`, which loads them in a job run by [`Context::run_jobs`].
+//!
+//! Modules are cached by the key their loader resolves them to, so a module is only evaluated
+//! once, however many modules import it, including modules importing each other cyclically.
+//!
+//! Modules can `await` at their top level. Such a module, and the modules importing it, are
+//! evaluated asynchronously: the body of an importing module only runs once the modules it
+//! imports are evaluated, which happens as the jobs of the context are run.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+mod loader;
+#[cfg(test)]
+mod tests;
+
+pub use loader::{FsModuleLoader, MapModuleLoader};
+
+use crate::{
+    builtins::{Array, AsyncFunction, Promise},
+    bytecompiler::ByteCompiler,
+    environment::{
+        environment_record_trait::EnvironmentRecordTrait, lexical_environment::Environment,
+        module_environment_record::ModuleEnvironmentRecord,
+    },
+    exec::instantiate_lexical_declarations,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject, Object, ObjectData},
+    policy::SourceKind,
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::{
+        ast::node::{Declaration, ExportDecl, ImportDecl, Node, StatementList},
+        Parser,
+    },
+    teardown::Contents,
+    vm::{CompletionType, FrameState, SuspendedFrame},
+    Context, Executable, JsResult, JsString, JsValue,
+};
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+use std::{fmt::Debug, slice};
+
+/// The hooks through which the embedder loads the source text of modules.
+///
+/// A loader can fetch modules from anywhere, like the file system ([`FsModuleLoader`]), an
+/// in-memory map ([`MapModuleLoader`]) or the network.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader: Debug {
+    /// Resolves the specifier of an import to the key identifying the imported module.
+    ///
+    /// `referrer` is the key of the importing module, or `None` for the modules imported with
+    /// [`Context::import_module`]. Specifiers resolving to the same key import the same module.
+    ///
+    /// By default, the specifier itself is the key.
+    fn resolve(
+        &self,
+        specifier: &str,
+        _referrer: Option<&str>,
+        _context: &mut Context,
+    ) -> JsResult<JsString> {
+        Ok(specifier.into())
+    }
+
+    /// Fetches the source text of the module identified by `key`.
+    fn fetch(&self, key: &str, context: &mut Context) -> JsResult<Vec<u8>>;
+}
+
+/// The status of a module, in the order a module goes through them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ModuleStatus {
+    Unlinked,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+/// The state of a module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Debug, Trace, Finalize)]
+struct ModuleRecord {
+    key: JsString,
+    body: StatementList,
+    #[unsafe_ignore_trace]
+    status: ModuleStatus,
+    environment: Option<Environment>,
+    /// The modules imported by this module, by specifier.
+    requested: Vec<(Box<str>, Module)>,
+    /// The bindings exported by this module, by export name.
+    exports: FxHashMap<Box<str>, ExportEntry>,
+    /// The specifiers of the modules whose named exports are all re-exported by this module.
+    star_exports: Vec<Box<str>>,
+    namespace: Option<JsObject>,
+    /// The error thrown by the evaluation of the module, if any.
+    error: Option<JsValue>,
+    /// The promise of the evaluation of the module, if the module or one of its imports awaits.
+    evaluation: Option<JsObject>,
+}
+
+/// The binding an export of a module refers to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#exportentry-record
+#[derive(Debug, Clone, Trace, Finalize)]
+enum ExportEntry {
+    /// A binding of the module, which may itself be imported from another module.
+    Local(Box<str>),
+    /// The export `import_name` of the module imported with the specifier `module`, or its
+    /// namespace object if `import_name` is `None`.
+    Indirect {
+        module: Box<str>,
+        import_name: Option<Box<str>>,
+    },
+}
+
+/// The result of resolving an export of a module.
+#[derive(Debug)]
+enum ResolvedExport {
+    /// The export refers to the binding `name` of the module `module`.
+    Binding { module: Module, name: Box<str> },
+    /// The export is provided by several star exports, referring to different bindings.
+    Ambiguous,
+}
+
+/// The name of the binding referring to the namespace object of a module, which is created on
+/// first use instead of being stored in the environment of the module.
+const NAMESPACE_BINDING: &str = "*namespace*";
+
+/// A module, shared by the modules importing it.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct Module(Gc<GcCell<ModuleRecord>>);
+
+impl Module {
+    fn new(key: JsString, body: StatementList) -> Self {
+        let mut exports = FxHashMap::default();
+        let mut star_exports = Vec::new();
+        for item in body.items() {
+            let export = match item {
+                Node::ExportDecl(export) => export,
+                _ => continue,
+            };
+            match export {
+                ExportDecl::Declaration(decl) => {
+                    for name in bound_names(decl) {
+                        exports.insert(name.into(), ExportEntry::Local(name.into()));
+                    }
+                }
+                ExportDecl::List(specifiers) => {
+                    for specifier in specifiers.iter() {
+                        exports.insert(
+                            specifier.exported().into(),
+                            ExportEntry::Local(specifier.local().into()),
+                        );
+                    }
+                }
+                ExportDecl::DefaultDeclaration(decl) => {
+                    for name in bound_names(decl) {
+                        exports.insert("default".into(), ExportEntry::Local(name.into()));
+                    }
+                }
+                ExportDecl::DefaultExpression(_) => {
+                    exports.insert("default".into(), ExportEntry::Local("*default*".into()));
+                }
+                ExportDecl::ReExport { specifiers, module } => {
+                    for specifier in specifiers.iter() {
+                        exports.insert(
+                            specifier.exported().into(),
+                            ExportEntry::Indirect {
+                                module: module.clone(),
+                                import_name: Some(specifier.local().into()),
+                            },
+                        );
+                    }
+                }
+                ExportDecl::StarReExport {
+                    namespace: Some(namespace),
+                    module,
+                } => {
+                    exports.insert(
+                        namespace.clone(),
+                        ExportEntry::Indirect {
+                            module: module.clone(),
+                            import_name: None,
+                        },
+                    );
+                }
+                ExportDecl::StarReExport {
+                    namespace: None,
+                    module,
+                } => star_exports.push(module.clone()),
+            }
+        }
+
+        Self(Gc::new(GcCell::new(ModuleRecord {
+            key,
+            body,
+            status: ModuleStatus::Unlinked,
+            environment: None,
+            requested: Vec::new(),
+            exports,
+            star_exports,
+            namespace: None,
+            error: None,
+            evaluation: None,
+        })))
+    }
+
+    fn key(&self) -> JsString {
+        self.0.borrow().key.clone()
+    }
+
+    fn status(&self) -> ModuleStatus {
+        self.0.borrow().status
+    }
+
+    /// Gets the environment of the module, once it is linked.
+    pub(crate) fn environment(&self) -> Option<Environment> {
+        self.0.borrow().environment.clone()
+    }
+
+    /// Gets the value of the binding `name` of the module environment.
+    ///
+    /// The bindings of a module are only created when their declaration is evaluated, so
+    /// accessing them before throws a `ReferenceError`.
+    pub(crate) fn get_binding_value(&self, name: &str, context: &mut Context) -> JsResult<JsValue> {
+        if name == NAMESPACE_BINDING {
+            return Ok(self.namespace().into());
+        }
+        match self.environment() {
+            Some(environment) if environment.has_binding(name) => {
+                environment.get_binding_value(name, true, context)
+            }
+            _ => context.throw_reference_error(format!("{} is an uninitialized binding", name)),
+        }
+    }
+
+    /// Gets the specifiers of the modules imported or re-exported by this module, without
+    /// duplicates.
+    fn requested_specifiers(&self) -> Vec<Box<str>> {
+        let mut specifiers: Vec<Box<str>> = Vec::new();
+        for item in self.0.borrow().body.items() {
+            let specifier = match item {
+                Node::ImportDecl(import) => import.module(),
+                Node::ExportDecl(export) => match export.module() {
+                    Some(module) => module,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            if !specifiers.iter().any(|s| **s == *specifier) {
+                specifiers.push(specifier.into());
+            }
+        }
+        specifiers
+    }
+
+    /// Gets the module imported by this module with `specifier`.
+    fn requested_module(&self, specifier: &str) -> Module {
+        self.0
+            .borrow()
+            .requested
+            .iter()
+            .find(|(requested, _)| **requested == *specifier)
+            .map(|(_, module)| module.clone())
+            .expect("imported modules are loaded before their importer is linked")
+    }
+
+    /// Gets the imported module and the imported name of the named import bound to `local`.
+    fn import_entry(&self, local: &str) -> Option<(Module, Box<str>)> {
+        let record = self.0.borrow();
+        record.body.items().iter().find_map(|item| match item {
+            Node::ImportDecl(import) => {
+                let imported = if import.default() == Some(local) {
+                    Some("default")
+                } else {
+                    import
+                        .specifiers()
+                        .iter()
+                        .find(|specifier| specifier.local() == local)
+                        .map(|specifier| specifier.imported())
+                };
+                imported.map(|imported| (self.requested_module(import.module()), imported.into()))
+            }
+            _ => None,
+        })
+    }
+
+    /// `GetExportedNames ( [ exportStarSet ] )`
+    ///
+    /// Gets the names exported by the module, including the names exported by the modules whose
+    /// exports it re-exports. `export_star_set` holds the modules already visited, to stop on
+    /// cycles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn exported_names(&self, export_star_set: &mut Vec<Module>) -> Vec<Box<str>> {
+        if export_star_set
+            .iter()
+            .any(|visited| Gc::ptr_eq(&visited.0, &self.0))
+        {
+            return Vec::new();
+        }
+        export_star_set.push(self.clone());
+
+        let (mut names, star_exports): (Vec<Box<str>>, _) = {
+            let record = self.0.borrow();
+            (
+                record.exports.keys().cloned().collect(),
+                record.star_exports.clone(),
+            )
+        };
+        for specifier in star_exports {
+            let module = self.requested_module(&specifier);
+            for name in module.exported_names(export_star_set) {
+                // The default export of a module is never re-exported by a star export.
+                if &*name != "default" && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// `ResolveExport ( exportName [ , resolveSet ] )`
+    ///
+    /// Finds the module and the local name of the binding exported as `name`, following the
+    /// exported imports and the re-exports. `resolve_set` holds the exports already visited, to
+    /// stop on cycles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        name: &str,
+        resolve_set: &mut Vec<(JsString, Box<str>)>,
+    ) -> Option<ResolvedExport> {
+        let key = self.key();
+        if resolve_set
+            .iter()
+            .any(|(visited, visited_name)| *visited == key && **visited_name == *name)
+        {
+            return None;
+        }
+        resolve_set.push((key, name.into()));
+
+        let entry = self.0.borrow().exports.get(name).cloned();
+        match &entry {
+            Some(ExportEntry::Local(local)) => {
+                return match self.import_entry(local) {
+                    Some((module, imported)) => module.resolve_export(&imported, resolve_set),
+                    None => Some(ResolvedExport::Binding {
+                        module: self.clone(),
+                        name: local.clone(),
+                    }),
+                };
+            }
+            Some(ExportEntry::Indirect {
+                module,
+                import_name: None,
+            }) => {
+                return Some(ResolvedExport::Binding {
+                    module: self.requested_module(module),
+                    name: NAMESPACE_BINDING.into(),
+                })
+            }
+            Some(ExportEntry::Indirect {
+                module,
+                import_name: Some(import_name),
+            }) => {
+                return self
+                    .requested_module(module)
+                    .resolve_export(import_name, resolve_set)
+            }
+            None => {}
+        }
+
+        // The default export of a module is never re-exported by a star export.
+        if name == "default" {
+            return None;
+        }
+
+        let star_exports = self.0.borrow().star_exports.clone();
+        let mut star_resolution: Option<(Module, Box<str>)> = None;
+        for specifier in star_exports {
+            let module = self.requested_module(&specifier);
+            match module.resolve_export(name, resolve_set) {
+                Some(ResolvedExport::Ambiguous) => return Some(ResolvedExport::Ambiguous),
+                Some(ResolvedExport::Binding { module, name }) => match &star_resolution {
+                    None => star_resolution = Some((module, name)),
+                    Some((resolved_module, resolved_name)) => {
+                        if !Gc::ptr_eq(&resolved_module.0, &module.0) || *resolved_name != name {
+                            return Some(ResolvedExport::Ambiguous);
+                        }
+                    }
+                },
+                None => {}
+            }
+        }
+        star_resolution.map(|(module, name)| ResolvedExport::Binding { module, name })
+    }
+
+    /// Resolves the export `name` of the module, throwing a `SyntaxError` if it is missing or
+    /// ambiguous.
+    fn resolve_import(&self, name: &str, context: &mut Context) -> JsResult<(Module, Box<str>)> {
+        match self.resolve_export(name, &mut Vec::new()) {
+            Some(ResolvedExport::Binding { module, name }) => Ok((module, name)),
+            Some(ResolvedExport::Ambiguous) => Err(context.construct_syntax_error(format!(
+                "The module {} provides several conflicting exports named {}",
+                self.key(),
+                name
+            ))),
+            None => Err(context.construct_syntax_error(format!(
+                "The module {} does not provide an export named {}",
+                self.key(),
+                name
+            ))),
+        }
+    }
+
+    /// `GetModuleNamespace ( module )`
+    ///
+    /// Gets the namespace object of the module, creating it on first use.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    fn namespace(&self) -> JsObject {
+        if let Some(namespace) = self.0.borrow().namespace.clone() {
+            return namespace;
+        }
+
+        // Ambiguous exports are left out of the namespace.
+        let mut names = self.exported_names(&mut Vec::new());
+        names.sort_unstable();
+        let exports = names
+            .into_iter()
+            .filter_map(|name| match self.resolve_export(&name, &mut Vec::new())? {
+                ResolvedExport::Binding {
+                    module,
+                    name: local,
+                } => Some(ExportBinding {
+                    name: name.into(),
+                    module,
+                    local,
+                }),
+                ResolvedExport::Ambiguous => None,
+            })
+            .collect();
+
+        let mut object = Object::with_prototype(
+            JsValue::null(),
+            ObjectData::module_namespace(ModuleNamespace { exports }),
+        );
+        object.insert_property(
+            WellKnownSymbols::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value("Module")
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+        let namespace = JsObject::new(object);
+        self.0.borrow_mut().namespace = Some(namespace.clone());
+        namespace
+    }
+
+    /// `InitializeEnvironment ( )`
+    ///
+    /// Creates the environment of the module, binds its imports and its `var` declarations, and
+    /// instantiates its function declarations, so they can be used by the modules importing it
+    /// before it is evaluated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn link(&self, context: &mut Context) -> JsResult<()> {
+        let environment =
+            ModuleEnvironmentRecord::new(Some(context.get_global_environment()), self.key());
+
+        let imports: Vec<ImportDecl> = self
+            .0
+            .borrow()
+            .body
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                Node::ImportDecl(import) => Some(import.clone()),
+                _ => None,
+            })
+            .collect();
+        for import in imports {
+            let module = self.requested_module(import.module());
+            if let Some(local) = import.namespace() {
+                let namespace = module.namespace();
+                environment.create_immutable_binding(local.to_owned(), true, context)?;
+                environment.initialize_binding(local, namespace.into(), context)?;
+            }
+
+            let named_imports = import
+                .default()
+                .map(|local| ("default", local))
+                .into_iter()
+                .chain(
+                    import
+                        .specifiers()
+                        .iter()
+                        .map(|specifier| (specifier.imported(), specifier.local())),
+                );
+            for (imported, local) in named_imports {
+                let (target, name) = module.resolve_import(imported, context)?;
+                environment.create_import_binding(local.to_owned(), target, &name);
+            }
+        }
+
+        // The re-exports of the module must resolve, even if no module imports them.
+        let indirect_exports: Vec<(Box<str>, Box<str>)> = self
+            .0
+            .borrow()
+            .exports
+            .values()
+            .filter_map(|entry| match entry {
+                ExportEntry::Indirect {
+                    module,
+                    import_name: Some(import_name),
+                } => Some((module.clone(), import_name.clone())),
+                _ => None,
+            })
+            .collect();
+        for (specifier, import_name) in indirect_exports {
+            self.requested_module(&specifier)
+                .resolve_import(&import_name, context)?;
+        }
+
+        let body = self.0.borrow().body.clone();
+        for name in body.var_declared_names() {
+            if !environment.has_binding(name) {
+                environment.create_mutable_binding(name.to_owned(), false, false, context)?;
+                environment.initialize_binding(name, JsValue::undefined(), context)?;
+            }
+        }
+
+        let environment: Environment = environment.into();
+        self.0.borrow_mut().environment = Some(environment.clone());
+
+        context.push_environment(environment);
+        let result = body
+            .items()
+            .iter()
+            .filter_map(lexical_declaration)
+            .try_for_each(|decl| instantiate_lexical_declarations(slice::from_ref(decl), context))
+            .and_then(|()| {
+                body.items()
+                    .iter()
+                    .filter_map(hoisted_declaration)
+                    .try_for_each(|decl| decl.run(context).map(|_| ()))
+            });
+        context.pop_environment();
+        result?;
+
+        self.0.borrow_mut().status = ModuleStatus::Linked;
+        Ok(())
+    }
+
+    /// `Evaluate ( )`
+    ///
+    /// Evaluates the modules imported by the module, then the module itself. The result of the
+    /// evaluation is cached: evaluating the module again rethrows the error it threw, if any.
+    ///
+    /// A module awaiting at its top level, or importing such a module, is evaluated
+    /// asynchronously: the promise of its evaluation is returned, and its body only runs once
+    /// the evaluation of its asynchronous imports completes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    fn evaluate(&self, context: &mut Context) -> JsResult<Option<JsObject>> {
+        match self.status() {
+            ModuleStatus::Evaluated => {
+                let record = self.0.borrow();
+                return match record.error.clone() {
+                    Some(error) => Err(error),
+                    None => Ok(record.evaluation.clone()),
+                };
+            }
+            // The module imports itself through a cycle, and is already being evaluated.
+            ModuleStatus::Evaluating => return Ok(None),
+            ModuleStatus::Linked => {}
+            ModuleStatus::Unlinked => unreachable!("modules are linked before being evaluated"),
+        }
+
+        self.0.borrow_mut().status = ModuleStatus::Evaluating;
+        let result = self.execute(context);
+
+        let mut record = self.0.borrow_mut();
+        record.status = ModuleStatus::Evaluated;
+        match &result {
+            Ok(evaluation) => record.evaluation = evaluation.clone(),
+            Err(error) => record.error = Some(error.clone()),
+        }
+        result
+    }
+
+    /// Evaluates the modules imported by the module, then the body of the module once the
+    /// asynchronous imports are evaluated.
+    fn execute(&self, context: &mut Context) -> JsResult<Option<JsObject>> {
+        let requested: Vec<Module> = self
+            .0
+            .borrow()
+            .requested
+            .iter()
+            .map(|(_, module)| module.clone())
+            .collect();
+        let mut pending = Vec::new();
+        for module in requested {
+            if let Some(evaluation) = module.evaluate(context)? {
+                pending.push(JsValue::from(evaluation));
+            }
+        }
+
+        if pending.is_empty() {
+            return self.execute_body(context);
+        }
+
+        // The body runs once all the asynchronous imports are evaluated, and the evaluation of
+        // the module completes with the evaluation of its body.
+        let constructor: JsValue = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+        let pending = Array::create_array_from_list(pending, context);
+        let imports = Promise::all(&constructor, &[pending.into()], context)?;
+        let capability = Promise::new_promise_capability(&constructor, context)?;
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, captures, context| {
+                let module = captures
+                    .downcast_ref::<Module>()
+                    .expect("the evaluation of the body captures its module")
+                    .clone();
+                match module.execute_body(context)? {
+                    Some(evaluation) => Ok(evaluation.into()),
+                    None => Ok(JsValue::undefined()),
+                }
+            },
+            self.clone(),
+        )
+        .build();
+        Promise::perform_promise_then(
+            &imports.as_object().expect("Promise.all returns a promise"),
+            &on_fulfilled.into(),
+            &JsValue::undefined(),
+            Some(capability.clone()),
+            context,
+        );
+        Ok(Some(capability.promise().clone()))
+    }
+
+    /// Runs the body of the module.
+    ///
+    /// The body runs in a frame of the vm like the body of an async function, so it can await
+    /// at its top level. If it does, the promise of the completion of the body is returned.
+    fn execute_body(&self, context: &mut Context) -> JsResult<Option<JsObject>> {
+        let (body, environment) = self.body_and_environment();
+        let code = ByteCompiler::compile_module_body(
+            body.items()
+                .iter()
+                .filter(|item| hoisted_declaration(item).is_none()),
+        );
+        let frame = SuspendedFrame::new(code, vec![environment]);
+
+        match frame.resume(JsValue::undefined(), CompletionType::Normal, context) {
+            FrameState::Complete(result) => result.map(|_| None),
+            state => {
+                let constructor = context
+                    .standard_objects()
+                    .promise_object()
+                    .constructor()
+                    .into();
+                let capability = Promise::new_promise_capability(&constructor, context)?;
+                AsyncFunction::settle_or_await(state, &capability, context)?;
+                Ok(Some(capability.promise().clone()))
+            }
+        }
+    }
+
+    /// Gets the body and the environment of a linked module.
+    fn body_and_environment(&self) -> (StatementList, Environment) {
+        let record = self.0.borrow();
+        (
+            record.body.clone(),
+            record
+                .environment
+                .clone()
+                .expect("linked modules have an environment"),
+        )
+    }
+}
+
+/// Gets the `let`, `const` or `class` declaration of a module item, whose bindings are created
+/// uninitialized when the module is linked.
+fn lexical_declaration(item: &Node) -> Option<&Node> {
+    let decl = match item {
+        Node::ExportDecl(export) => export.declaration()?,
+        item => item,
+    };
+    match decl {
+        Node::LetDeclList(_) | Node::ConstDeclList(_) | Node::ClassDecl(_) => Some(decl),
+        _ => None,
+    }
+}
+
+/// Gets the function declaration of a module item, which is instantiated when the module is
+/// linked instead of when it is evaluated.
+fn hoisted_declaration(item: &Node) -> Option<&Node> {
+    let decl = match item {
+        Node::ExportDecl(export) => export.declaration()?,
+        item => item,
+    };
+    match decl {
+        Node::FunctionDecl(_)
+        | Node::GeneratorDecl(_)
+        | Node::AsyncFunctionDecl(_)
+        | Node::AsyncGeneratorDecl(_) => Some(decl),
+        _ => None,
+    }
+}
+
+/// Gets the names bound by an exported declaration.
+fn bound_names(decl: &Node) -> Vec<&str> {
+    // An anonymous declaration is the default export of its module, bound to `*default*`.
+    match decl {
+        Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| match decl {
+                Declaration::Identifier { ident, .. } => vec![ident.as_ref()],
+                Declaration::Pattern(pattern) => pattern.idents(),
+            })
+            .collect(),
+        Node::FunctionDecl(decl) => vec![decl.name().unwrap_or("*default*")],
+        Node::GeneratorDecl(decl) => vec![decl.name().unwrap_or("*default*")],
+        Node::AsyncFunctionDecl(decl) => vec![decl.name().unwrap_or("*default*")],
+        Node::AsyncGeneratorDecl(decl) => vec![decl.name().unwrap_or("*default*")],
+        Node::ClassDecl(class) => vec![class.name().unwrap_or("*default*")],
+        _ => Vec::new(),
+    }
+}
+
+/// An export of a module namespace object, with the binding it resolves to.
+#[derive(Debug, Trace, Finalize)]
+struct ExportBinding {
+    name: JsString,
+    module: Module,
+    local: Box<str>,
+}
+
+/// The data of a module namespace exotic object, which exposes the exports of a module as
+/// properties reading their bindings.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct ModuleNamespace {
+    /// The exports of the module, sorted by name.
+    exports: Vec<ExportBinding>,
+}
+
+impl ModuleNamespace {
+    /// Queues the environments of the exported bindings to be emptied by the teardown of the
+    /// context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        for export in &self.exports {
+            if let Some(environment) = export.module.environment() {
+                contents.push_environment(&environment);
+            }
+        }
+    }
+
+    /// Gets the module and the local name of the binding exported as `key`, if any.
+    pub(crate) fn binding(&self, key: &PropertyKey) -> Option<(Module, Box<str>)> {
+        let name = match key {
+            PropertyKey::String(name) => name.clone(),
+            PropertyKey::Index(index) => index.to_string().into(),
+            PropertyKey::Symbol(_) => return None,
+        };
+        self.exports
+            .binary_search_by(|export| export.name.cmp(&name))
+            .ok()
+            .map(|index| {
+                let export = &self.exports[index];
+                (export.module.clone(), export.local.clone())
+            })
+    }
+
+    /// Gets the names of the exports, in ascending order.
+    pub(crate) fn names(&self) -> impl Iterator<Item = &JsString> {
+        self.exports.iter().map(|export| &export.name)
+    }
+}
+
+/// Loads the module requested with `specifier` and, recursively, the modules it imports.
+///
+/// The modules that are not already cached are added to the module map of the context and
+/// pushed to `loaded`.
+fn load(
+    specifier: &str,
+    referrer: Option<&JsString>,
+    loaded: &mut Vec<Module>,
+    context: &mut Context,
+) -> JsResult<Module> {
+    let loader = match context.module_loader.clone() {
+        Some(loader) => loader,
+        None => {
+            return Err(context.construct_type_error(format!(
+                "Cannot import the module {}: no module loader is set",
+                specifier
+            )))
+        }
+    };
+
+    let key = loader.resolve(specifier, referrer.map(JsString::as_str), context)?;
+    if let Some(module) = context.module_map.get(&key) {
+        return Ok(module.clone());
+    }
+
+    let source = loader.fetch(&key, context)?;
+    context.check_source(
+        SourceKind::Module,
+        &String::from_utf8_lossy(&source),
+        Some(key.as_str()),
+    )?;
+    let body = Parser::new(source.as_slice(), true)
+        .parse_module()
+        .map_err(|e| context.construct_parse_error(&e))?;
+
+    // The module is cached before its imports are loaded, so cyclic imports find it.
+    let module = Module::new(key.clone(), body);
+    context.module_map.insert(key.clone(), module.clone());
+    loaded.push(module.clone());
+
+    for specifier in module.requested_specifiers() {
+        let requested = load(&specifier, Some(&key), loaded, context)?;
+        module.0.borrow_mut().requested.push((specifier, requested));
+    }
+    Ok(module)
+}
+
+/// Imports the module requested with `specifier` by the module `referrer`, loading, linking and
+/// evaluating it if needed, and returns its namespace object along with the promise of its
+/// evaluation, if it is evaluated asynchronously.
+///
+/// If loading or linking fails, the modules loaded by this import are removed from the module
+/// map, so importing them again retries.
+pub(crate) fn import(
+    specifier: &str,
+    referrer: Option<&JsString>,
+    context: &mut Context,
+) -> JsResult<(JsObject, Option<JsObject>)> {
+    let mut loaded = Vec::new();
+    let module = load(specifier, referrer, &mut loaded, context).and_then(|module| {
+        for module in &loaded {
+            module.link(context)?;
+        }
+        Ok(module)
+    });
+    let module = match module {
+        Ok(module) => module,
+        Err(error) => {
+            for module in loaded {
+                context.module_map.remove(&module.key());
+            }
+            return Err(error);
+        }
+    };
+
+    let evaluation = module.evaluate(context)?;
+    Ok((module.namespace(), evaluation))
+}
@@ -0,0 +1,488 @@
+use super::{FsModuleLoader, MapModuleLoader};
+use crate::{error::JsNativeErrorKind, forward, property::Attribute, Context};
+
+/// Creates a context loading the given modules from memory.
+fn context_with_modules(modules: &[(&str, &str)]) -> Context {
+    let mut loader = MapModuleLoader::new();
+    for (specifier, source) in modules {
+        loader.insert(*specifier, *source);
+    }
+    let mut context = Context::new();
+    context.set_module_loader(loader);
+    context
+}
+
+/// Imports the module `specifier` and binds its namespace to the global `name`.
+#[track_caller]
+fn import_as(context: &mut Context, specifier: &str, name: &str) {
+    let namespace = context
+        .import_module(specifier)
+        .expect("failed to import the module");
+    context.register_global_property(name, namespace, Attribute::all());
+}
+
+#[test]
+fn import_bindings() {
+    let mut context = context_with_modules(&[
+        (
+            "math",
+            r#"
+            export const pi = 3;
+            export function double(x) { return 2 * x; }
+            export default function () { return "default"; }
+        "#,
+        ),
+        (
+            "main",
+            r#"
+            import byDefault, { pi, double as twice } from "math";
+            import * as math from "math";
+            export let values = [twice(pi), byDefault(), byDefault.name, math.pi];
+        "#,
+        ),
+    ]);
+    import_as(&mut context, "main", "main");
+
+    assert_eq!(
+        forward(&mut context, "main.values.join()"),
+        "\"6,default,default,3\""
+    );
+}
+
+#[test]
+fn export_lists() {
+    let mut context = context_with_modules(&[
+        (
+            "a",
+            "let a = 1; const b = 2; export { a, b as c, a as \"a b\" };",
+        ),
+        ("b", "import { c } from \"a\"; export { c as d };"),
+    ]);
+    import_as(&mut context, "a", "a");
+    import_as(&mut context, "b", "b");
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(a).join()"),
+        "\"a,a b,c\""
+    );
+    assert_eq!(forward(&mut context, "a.c + a['a b']"), "3");
+    assert_eq!(forward(&mut context, "b.d"), "2");
+}
+
+#[test]
+fn live_bindings() {
+    let mut context = context_with_modules(&[
+        (
+            "counter",
+            "export let count = 0; export function increment() { count++; }",
+        ),
+        (
+            "main",
+            r#"
+            import { count, increment } from "counter";
+            increment();
+            export const seen = count;
+        "#,
+        ),
+    ]);
+    import_as(&mut context, "main", "main");
+    import_as(&mut context, "counter", "counter");
+
+    assert_eq!(forward(&mut context, "main.seen"), "1");
+    forward(&mut context, "counter.increment()");
+    assert_eq!(forward(&mut context, "counter.count"), "2");
+}
+
+#[test]
+fn re_exports() {
+    let mut context = context_with_modules(&[
+        (
+            "counter",
+            "export let count = 0; export function increment() { count++; } export default 'd';",
+        ),
+        (
+            "extra",
+            "export const extra = 'extra'; export default 'extra default';",
+        ),
+        (
+            "index",
+            r#"
+            export { count as total, increment, default as counterDefault } from "counter";
+            export * from "extra";
+            export * as counter from "counter";
+        "#,
+        ),
+        (
+            "main",
+            r#"
+            import { total, increment, counterDefault, extra, counter } from "index";
+            increment();
+            export const seen = [total, counter.count, counterDefault, extra].join();
+        "#,
+        ),
+    ]);
+    import_as(&mut context, "main", "main");
+    import_as(&mut context, "index", "index");
+
+    assert_eq!(forward(&mut context, "main.seen"), "\"1,1,d,extra\"");
+    assert_eq!(
+        forward(&mut context, "Object.keys(index).join()"),
+        "\"counter,counterDefault,extra,increment,total\""
+    );
+
+    // Re-exported bindings are live.
+    forward(&mut context, "index.increment()");
+    assert_eq!(forward(&mut context, "index.total"), "2");
+    assert_eq!(forward(&mut context, "index.counter.count"), "2");
+}
+
+#[test]
+fn ambiguous_star_exports() {
+    let mut context = context_with_modules(&[
+        ("a", "export const shared = 'a'; export const a = 1;"),
+        ("b", "export const shared = 'b'; export const b = 2;"),
+        ("c", "export { shared } from \"a\";"),
+        ("ab", "export * from \"a\"; export * from \"b\";"),
+        ("ac", "export * from \"a\"; export * from \"c\";"),
+        ("cyclic", "export * from \"cyclic\"; export * from \"a\";"),
+        ("main", "import { shared } from \"ab\";"),
+    ]);
+    import_as(&mut context, "ab", "ab");
+    import_as(&mut context, "ac", "ac");
+    import_as(&mut context, "cyclic", "cyclic");
+
+    // Conflicting star exports are left out of the namespace, and cannot be imported.
+    assert_eq!(forward(&mut context, "Object.keys(ab).join()"), "\"a,b\"");
+    let error = context.import_module("main").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Syntax));
+
+    // Star exports resolving to the same binding do not conflict.
+    assert_eq!(forward(&mut context, "ac.shared"), "\"a\"");
+    assert_eq!(
+        forward(&mut context, "Object.keys(cyclic).join()"),
+        "\"a,shared\""
+    );
+}
+
+#[test]
+fn default_expressions() {
+    let mut context = context_with_modules(&[
+        ("value", "let x = 20; export default x * 2 + 2;"),
+        ("function", "export default function () {};"),
+        ("arrow", "export default () => 'arrow';"),
+        ("named", "export default (function named() {});"),
+        (
+            "main",
+            r#"
+            import value from "value";
+            import arrow from "arrow";
+            export const seen = [value, arrow(), arrow.name].join();
+        "#,
+        ),
+    ]);
+    import_as(&mut context, "main", "main");
+    import_as(&mut context, "named", "named");
+
+    assert_eq!(forward(&mut context, "main.seen"), "\"42,arrow,default\"");
+    assert_eq!(forward(&mut context, "named.default.name"), "\"named\"");
+}
+
+#[test]
+fn imports_are_immutable() {
+    let mut context = context_with_modules(&[
+        ("value", "export let value = 1;"),
+        ("main", "import { value } from \"value\"; value = 2;"),
+    ]);
+
+    let error = context.import_module("main").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let mut context = context_with_modules(&[
+        (
+            "shared",
+            "globalThis.evaluations = (globalThis.evaluations || 0) + 1;",
+        ),
+        ("a", "import \"shared\";"),
+        ("b", "import \"shared\"; import \"a\";"),
+    ]);
+    import_as(&mut context, "a", "a");
+    import_as(&mut context, "b", "b");
+    import_as(&mut context, "b", "b2");
+
+    assert_eq!(forward(&mut context, "evaluations"), "1");
+    assert_eq!(forward(&mut context, "b === b2"), "true");
+}
+
+#[test]
+fn cyclic_imports() {
+    let mut context = context_with_modules(&[
+        (
+            "even",
+            r#"
+            import { isOdd } from "odd";
+            export function isEven(n) { return n === 0 || isOdd(n - 1); }
+        "#,
+        ),
+        (
+            "odd",
+            r#"
+            import { isEven } from "even";
+            export function isOdd(n) { return n !== 0 && isEven(n - 1); }
+            export const tenIsEven = isEven(10);
+        "#,
+        ),
+    ]);
+    import_as(&mut context, "even", "even");
+    import_as(&mut context, "odd", "odd");
+
+    assert_eq!(forward(&mut context, "even.isEven(7)"), "false");
+    assert_eq!(forward(&mut context, "odd.tenIsEven"), "true");
+}
+
+#[test]
+fn cyclic_import_before_initialization() {
+    let mut context = context_with_modules(&[
+        ("a", "import { b } from \"b\"; export const a = 1;"),
+        ("b", "import { a } from \"a\"; export const b = a;"),
+    ]);
+
+    let error = context.import_module("a").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Reference));
+}
+
+#[test]
+fn namespace_objects() {
+    let mut context = context_with_modules(&[("m", "export var x = 1; export let y;")]);
+    import_as(&mut context, "m", "m");
+
+    assert_eq!(forward(&mut context, "Object.getPrototypeOf(m)"), "null");
+    assert_eq!(forward(&mut context, "m[Symbol.toStringTag]"), "\"Module\"");
+    assert_eq!(forward(&mut context, "'x' in m"), "true");
+    assert_eq!(forward(&mut context, "'z' in m"), "false");
+    assert_eq!(forward(&mut context, "m.x = 2; m.x"), "1");
+    assert_eq!(forward(&mut context, "delete m.x"), "false");
+    assert_eq!(forward(&mut context, "delete m.z"), "true");
+}
+
+#[test]
+fn evaluation_errors_are_cached() {
+    let mut context = context_with_modules(&[
+        (
+            "throws",
+            "globalThis.tries = (globalThis.tries || 0) + 1; throw 'oops';",
+        ),
+        ("main", "import \"throws\";"),
+    ]);
+
+    assert!(context.import_module("main").is_err());
+    assert!(context.import_module("throws").is_err());
+    assert_eq!(forward(&mut context, "tries"), "1");
+}
+
+#[test]
+fn link_errors() {
+    let mut context = context_with_modules(&[
+        ("a", "export const a = 1;"),
+        ("missing export", "import { b } from \"a\";"),
+        ("missing re-export", "export { b } from \"a\";"),
+        ("missing module", "import \"nowhere\";"),
+        ("syntax", "export const = 1;"),
+    ]);
+
+    let error = context.import_module("missing export").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Syntax));
+    let error = context.import_module("missing re-export").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Syntax));
+    let error = context.import_module("missing module").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Error));
+    let error = context.import_module("syntax").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Syntax));
+
+    // The modules of a failed import are not cached.
+    assert_eq!(context.module_map.len(), 0);
+    assert!(context.import_module("a").is_ok());
+}
+
+#[test]
+fn no_module_loader() {
+    let mut context = Context::new();
+    let error = context.import_module("a").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Type));
+}
+
+#[test]
+fn module_scope() {
+    let mut context = context_with_modules(&[("m", "var local = 1; export const self = this;")]);
+    import_as(&mut context, "m", "m");
+
+    assert_eq!(forward(&mut context, "m.self"), "undefined");
+    assert_eq!(forward(&mut context, "globalThis.local"), "undefined");
+}
+
+#[test]
+fn fs_module_loader() {
+    let root = std::env::temp_dir().join(format!("boa_fs_module_loader_{}", std::process::id()));
+    std::fs::create_dir_all(root.join("lib")).unwrap();
+    std::fs::write(
+        root.join("main.js"),
+        "import { value } from \"./lib/value.js\"; export const result = value;",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("lib").join("value.js"),
+        "import { base } from \"../base.js\"; export const value = base + 1;",
+    )
+    .unwrap();
+    std::fs::write(root.join("base.js"), "export const base = 41;").unwrap();
+
+    let mut context = Context::new();
+    context.set_module_loader(FsModuleLoader::new(&root));
+    let namespace = context.import_module("main.js");
+    std::fs::remove_dir_all(&root).unwrap();
+
+    context.register_global_property("main", namespace.unwrap(), Attribute::all());
+    assert_eq!(forward(&mut context, "main.result"), "42");
+    assert_eq!(context.module_map.len(), 3);
+}
+
+#[test]
+fn map_module_loader() {
+    let mut context = context_with_modules(&[("a", "export const a = 1;")]);
+    assert_eq!(
+        context
+            .import_module("b")
+            .unwrap_err()
+            .message()
+            .map(str::to_owned),
+        Some("Cannot find module b".to_owned())
+    );
+}
+
+#[test]
+fn dynamic_import() {
+    let mut context = context_with_modules(&[("m", "export const value = 42;")]);
+    forward(
+        &mut context,
+        r#"
+        var value, error;
+        import("m").then(m => value = m.value);
+        import("nowhere").catch(e => error = e.message);
+    "#,
+    );
+    assert_eq!(forward(&mut context, "value"), "undefined");
+
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "value"), "42");
+    assert_eq!(
+        forward(&mut context, "error"),
+        "\"Cannot find module nowhere\""
+    );
+
+    import_as(&mut context, "m", "m");
+    forward(
+        &mut context,
+        "var same; import('m').then(ns => same = ns === m);",
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "same"), "true");
+}
+
+#[test]
+fn dynamic_import_referrer() {
+    let root = std::env::temp_dir().join(format!("boa_dynamic_import_{}", std::process::id()));
+    std::fs::create_dir_all(root.join("lib")).unwrap();
+    std::fs::write(
+        root.join("lib").join("main.js"),
+        r#"
+        import("./value.js").then(ns => globalThis.fromModule = ns.value);
+        export function load() { return import("./value.js"); }
+    "#,
+    )
+    .unwrap();
+    std::fs::write(root.join("lib").join("value.js"), "export const value = 1;").unwrap();
+
+    let mut context = Context::new();
+    context.set_module_loader(FsModuleLoader::new(&root));
+    let namespace = context.import_module("lib/main.js").unwrap();
+    context.register_global_property("main", namespace, Attribute::all());
+    forward(
+        &mut context,
+        "main.load().then(ns => globalThis.fromFunction = ns.value)",
+    );
+    context.run_jobs().unwrap();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(forward(&mut context, "fromModule"), "1");
+    assert_eq!(forward(&mut context, "fromFunction"), "1");
+}
+
+#[test]
+fn top_level_await() {
+    let mut context = context_with_modules(&[
+        ("value", "export const value = await Promise.resolve(21);"),
+        (
+            "main",
+            "import { value } from \"value\"; export const doubled = value * 2;",
+        ),
+    ]);
+    import_as(&mut context, "main", "main");
+
+    assert_eq!(forward(&mut context, "main.doubled"), "42");
+}
+
+#[test]
+fn async_module_evaluation_order() {
+    let mut context = context_with_modules(&[
+        (
+            "async",
+            r#"
+            log.push("async start");
+            await null;
+            log.push("async end");
+        "#,
+        ),
+        ("sync", "log.push(\"sync\");"),
+        (
+            "main",
+            "import \"async\"; import \"sync\"; log.push(\"main\");",
+        ),
+    ]);
+    forward(&mut context, "var log = [];");
+    import_as(&mut context, "main", "main");
+
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"async start,sync,async end,main\""
+    );
+}
+
+#[test]
+fn async_evaluation_errors() {
+    let mut context = context_with_modules(&[
+        ("throws", "await null; throw new RangeError('oops');"),
+        ("main", "import \"throws\"; globalThis.evaluated = true;"),
+    ]);
+
+    let error = context.import_module("main").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Range));
+    assert_eq!(forward(&mut context, "globalThis.evaluated"), "undefined");
+
+    let error = context.import_module("throws").unwrap_err();
+    assert_eq!(error.kind(), Some(JsNativeErrorKind::Range));
+}
+
+#[test]
+fn dynamic_import_of_async_module() {
+    let mut context =
+        context_with_modules(&[("m", "export let value = 1; await null; value = 2;")]);
+    forward(
+        &mut context,
+        "var value; import('m').then(m => value = m.value);",
+    );
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "value"), "2");
+}
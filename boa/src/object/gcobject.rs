@@ -4,27 +4,34 @@
 
 use super::{NativeObject, Object, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, ClosureFunction, Function, NativeFunction,
+    builtins::{
+        function::{
+            arguments::{create_mapped_arguments_object, is_simple_parameter_list},
+            create_unmapped_arguments_object, Captures, ClosureFunction, Function, NativeFunction,
+        },
+        AsyncFunction, AsyncGenerator, Generator,
     },
     environment::{
-        environment_record_trait::EnvironmentRecordTrait,
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         lexical_environment::Environment,
     },
-    exec::InterpreterState,
+    error::JsErrorCode,
+    exec::{instantiate_lexical_declarations, StackFrame},
+    gc::{empty_trace, write_barrier},
     object::{ObjectData, ObjectKind},
-    property::{PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
+    property::{PropertyChange, PropertyDescriptor, PropertyKey, PropertyWatcher},
     value::PreferredType,
     Context, Executable, JsResult, JsValue,
 };
+
+use crate::vm::CodeBlock;
 use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::HashMap,
     error::Error,
     fmt::{self, Debug, Display},
+    mem::ManuallyDrop,
     rc::Rc,
     result::Result as StdResult,
 };
@@ -46,8 +53,23 @@ pub struct JsObject(Gc<GcCell<Object>>);
 enum FunctionBody {
     BuiltInFunction(NativeFunction),
     BuiltInConstructor(NativeFunction),
-    Closure(Rc<ClosureFunction>),
-    Ordinary(RcStatementList),
+    Closure {
+        function: Rc<ClosureFunction>,
+        captures: Captures,
+    },
+    Compiled(Rc<CodeBlock>),
+    Async {
+        code: Rc<CodeBlock>,
+        environments: Vec<Environment>,
+    },
+    Generator {
+        code: Rc<CodeBlock>,
+        environments: Vec<Environment>,
+    },
+    AsyncGenerator {
+        code: Rc<CodeBlock>,
+        environments: Vec<Environment>,
+    },
 }
 
 impl JsObject {
@@ -101,9 +123,14 @@ impl JsObject {
     /// The object be borrowed while this borrow is active.
     ///
     /// This is the non-panicking variant of [`borrow_mut`](#method.borrow_mut).
+    ///
+    /// Since every mutation of the object goes through this borrow, the write barrier of the
+    /// garbage collector is run here.
     #[inline]
     pub fn try_borrow_mut(&self) -> StdResult<RefMut<'_, Object, Object>, BorrowMutError> {
-        self.0.try_borrow_mut().map_err(|_| BorrowMutError)
+        let object = self.0.try_borrow_mut().map_err(|_| BorrowMutError)?;
+        write_barrier(self.as_ref());
+        Ok(object)
     }
 
     /// Checks if the garbage collected memory is the same.
@@ -133,13 +160,65 @@ impl JsObject {
         let this_function_object = self.clone();
         let mut has_parameter_expressions = false;
 
+        // The object constructed by an ordinary function is created, and the fields of its class
+        // are added to it, before the function is borrowed to evaluate its body, since the
+        // initializers of the fields can use the function.
+        // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
+        //
+        // The object constructed by a derived class constructor is bound to `this` by the
+        // `super(...)` call of its body instead.
+        let (constructs_ordinary, constructs_derived) = match self.borrow().as_function() {
+            Some(Function::Ordinary { flags, .. }) if construct && flags.is_constructable() => {
+                let derived = flags.is_derived_constructor();
+                (!derived, derived)
+            }
+            _ => (false, false),
+        };
+        let constructed_this = if constructs_ordinary {
+            // If the prototype of the constructor is not an object, then use the default object
+            // prototype as prototype for the new object
+            // see <https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor>
+            // see <https://tc39.es/ecma262/#sec-getprototypefromconstructor>
+            let proto = this_target.as_object().unwrap().__get__(
+                &PROTOTYPE.into(),
+                this_target.clone(),
+                context,
+            )?;
+            let proto = if proto.is_object() {
+                proto
+            } else {
+                context
+                    .standard_objects()
+                    .object_object()
+                    .prototype()
+                    .into()
+            };
+            let this = JsObject::new(Object::create(proto));
+            this.initialize_instance_elements(self, context)?;
+            Some(JsValue::new(this))
+        } else {
+            None
+        };
+
         let body = if let Some(function) = self.borrow().as_function() {
             if construct && !function.is_constructable() {
                 let name = self
                     .__get__(&"name".into(), self.clone().into(), context)?
                     .display()
                     .to_string();
-                return context.throw_type_error(format!("{} is not a constructor", name));
+                return context.throw_coded_error(
+                    JsErrorCode::NAMED_NOT_A_CONSTRUCTOR,
+                    format!("{} is not a constructor", name),
+                );
+            } else if !construct && function.is_class_constructor() {
+                let name = self
+                    .__get__(&"name".into(), self.clone().into(), context)?
+                    .display()
+                    .to_string();
+                return context.throw_type_error(format!(
+                    "Class constructor {} cannot be invoked without 'new'",
+                    name
+                ));
             } else {
                 match function {
                     Function::Native {
@@ -152,46 +231,55 @@ impl JsObject {
                             FunctionBody::BuiltInFunction(function.0)
                         }
                     }
-                    Function::Closure { function, .. } => FunctionBody::Closure(function.clone()),
+                    Function::Closure {
+                        function, captures, ..
+                    } => FunctionBody::Closure {
+                        function: function.clone(),
+                        captures: captures.clone(),
+                    },
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        home_object,
+                        code,
+                        ..
                     } => {
-                        let this = if construct {
-                            // If the prototype of the constructor is not an object, then use the default object
-                            // prototype as prototype for the new object
-                            // see <https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor>
-                            // see <https://tc39.es/ecma262/#sec-getprototypefromconstructor>
-                            let proto = this_target.as_object().unwrap().__get__(
-                                &PROTOTYPE.into(),
-                                this_target.clone(),
-                                context,
-                            )?;
-                            let proto = if proto.is_object() {
-                                proto
-                            } else {
-                                context
-                                    .standard_objects()
-                                    .object_object()
-                                    .prototype()
-                                    .into()
-                            };
-                            JsValue::new(Object::create(proto))
+                        let this = if let Some(ref this) = constructed_this {
+                            this.clone()
+                        } else if body.strict() || flags.is_lexical_this_mode() {
+                            this_target.clone()
+                        } else if this_target.is_null_or_undefined() {
+                            // Sloppy mode functions get the global `this` value in place of
+                            // `undefined` and `null`, and primitive values are wrapped.
+                            // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                            context.global_this_binding().into()
                         } else {
+                            this_target.to_object(context)?.into()
+                        };
+
+                        // When constructing, `this_target` is the newTarget of `[[Construct]]`.
+                        // <https://tc39.es/ecma262/#sec-newfunctionenvironment>
+                        let new_target = if construct {
                             this_target.clone()
+                        } else {
+                            JsValue::undefined()
+                        };
+
+                        // Arrow functions do not bind `this`, and derived class constructors bind
+                        // it with their `super(...)` call.
+                        let this = if flags.is_lexical_this_mode() || constructs_derived {
+                            None
+                        } else {
+                            Some(this)
                         };
 
                         // Create a new Function environment whose parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = FunctionEnvironmentRecord::new(
                             this_function_object.clone(),
-                            if construct || !flags.is_lexical_this_mode() {
-                                Some(this.clone())
-                            } else {
-                                None
-                            },
+                            this.clone(),
                             Some(environment.clone()),
                             // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
                             if flags.is_lexical_this_mode() {
@@ -199,16 +287,20 @@ impl JsObject {
                             } else {
                                 BindingStatus::Uninitialized
                             },
-                            JsValue::undefined(),
+                            home_object.clone().map(JsValue::from).unwrap_or_default(),
+                            new_target.clone(),
                         );
 
+                        // Turn local_env into Environment so it can be cloned
+                        let local_env: Environment = local_env.into();
+
                         let mut arguments_in_parameter_names = false;
 
                         for param in params.iter() {
                             has_parameter_expressions =
                                 has_parameter_expressions || param.init().is_some();
-                            arguments_in_parameter_names =
-                                arguments_in_parameter_names || param.name() == "arguments";
+                            arguments_in_parameter_names = arguments_in_parameter_names
+                                || param.names().contains(&"arguments");
                         }
 
                         // An arguments object is added when all of the following conditions are met
@@ -223,8 +315,20 @@ impl JsObject {
                                 || (!body.lexically_declared_names().contains("arguments")
                                     && !body.function_declared_names().contains("arguments")))
                         {
-                            // Add arguments object
-                            let arguments_obj = create_unmapped_arguments_object(args, context)?;
+                            // Add arguments object, mapped to the parameters if the function is
+                            // not strict and has a simple parameter list (10.2.11.22)
+                            let arguments_obj =
+                                if !body.strict() && is_simple_parameter_list(params) {
+                                    create_mapped_arguments_object(
+                                        &this_function_object,
+                                        params,
+                                        args,
+                                        &local_env,
+                                        context,
+                                    )
+                                } else {
+                                    create_unmapped_arguments_object(args, context)?
+                                };
                             local_env.create_mutable_binding(
                                 "arguments".to_string(),
                                 false,
@@ -234,31 +338,27 @@ impl JsObject {
                             local_env.initialize_binding("arguments", arguments_obj, context)?;
                         }
 
-                        // Turn local_env into Environment so it can be cloned
-                        let local_env: Environment = local_env.into();
-
                         // Push the environment first so that it will be used by default parameters
+                        let depth = context.environment_stack_len();
                         context.push_environment(local_env.clone());
 
                         // Add argument bindings to the function environment
                         for (i, param) in params.iter().enumerate() {
                             // Rest Parameters
                             if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
+                                function.add_rest_param(param, i, args, context, &local_env)?;
                                 break;
                             }
 
-                            let value = match args.get(i).cloned() {
-                                None | Some(JsValue::Undefined) => param
-                                    .init()
-                                    .map(|init| init.run(context).ok())
-                                    .flatten()
-                                    .unwrap_or_default(),
-                                Some(value) => value,
+                            let value = match (args.get(i).cloned(), param.init()) {
+                                (None | Some(JsValue::Undefined), Some(init)) => {
+                                    init.run(context)?
+                                }
+                                (value, _) => value.unwrap_or_default(),
                             };
 
                             function
-                                .add_arguments_to_environment(param, value, &local_env, context);
+                                .add_arguments_to_environment(param, value, &local_env, context)?;
                         }
 
                         if has_parameter_expressions {
@@ -266,13 +366,17 @@ impl JsObject {
                             // This prevents variables declared in the function body from being
                             // used in default parameter initializers.
                             // https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+                            //
+                            // The `this` value of a derived class constructor is already bound
+                            // if a default value of its parameters called the parent class.
+                            let this = if constructs_derived {
+                                local_env.get_this_binding(context).ok()
+                            } else {
+                                this
+                            };
                             let second_env = FunctionEnvironmentRecord::new(
                                 this_function_object,
-                                if construct || !flags.is_lexical_this_mode() {
-                                    Some(this)
-                                } else {
-                                    None
-                                },
+                                this,
                                 Some(local_env),
                                 // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
                                 if flags.is_lexical_this_mode() {
@@ -280,20 +384,56 @@ impl JsObject {
                                 } else {
                                     BindingStatus::Uninitialized
                                 },
-                                JsValue::undefined(),
+                                home_object.clone().map(JsValue::from).unwrap_or_default(),
+                                new_target,
                             );
                             context.push_environment(second_env);
                         }
 
-                        FunctionBody::Ordinary(body.clone())
+                        instantiate_lexical_declarations(body.items(), context)?;
+
+                        if flags.is_async() && flags.is_generator() {
+                            // The frame of an async generator is suspended until it is
+                            // resumed by the requests of the returned generator.
+                            FunctionBody::AsyncGenerator {
+                                code: code.clone(),
+                                environments: context.split_off_environments(depth),
+                            }
+                        } else if flags.is_async() {
+                            // The frame of an async function owns the environments of the
+                            // call while it awaits.
+                            FunctionBody::Async {
+                                code: code.clone(),
+                                environments: context.split_off_environments(depth),
+                            }
+                        } else if flags.is_generator() {
+                            // The frame of a generator runs once the returned generator is
+                            // resumed.
+                            FunctionBody::Generator {
+                                code: code.clone(),
+                                environments: context.split_off_environments(depth),
+                            }
+                        } else {
+                            FunctionBody::Compiled(code.clone())
+                        }
                     }
                 }
             }
         } else {
-            return context.throw_type_error("not a function");
+            return context.throw_coded_error(JsErrorCode::NOT_A_FUNCTION, "not a function");
         };
 
-        match body {
+        let function_name = self
+            .borrow()
+            .properties()
+            .get(&"name".into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+            .unwrap_or_default();
+        context
+            .executor()
+            .push_frame(StackFrame::new(function_name));
+
+        let result = match body {
             FunctionBody::BuiltInConstructor(function) if construct => {
                 function(this_target, args, context)
             }
@@ -301,9 +441,11 @@ impl JsObject {
                 function(&JsValue::undefined(), args, context)
             }
             FunctionBody::BuiltInFunction(function) => function(this_target, args, context),
-            FunctionBody::Closure(function) => (function)(this_target, args, context),
-            FunctionBody::Ordinary(body) => {
-                let result = body.run(context);
+            FunctionBody::Closure { function, captures } => {
+                (function)(this_target, args, &captures, context)
+            }
+            FunctionBody::Compiled(code) => {
+                let result = context.run_code_block(code);
                 let this = context.get_this_binding();
 
                 if has_parameter_expressions {
@@ -311,28 +453,34 @@ impl JsObject {
                 }
                 context.pop_environment();
 
+                // The value of a compiled body is `undefined`, unless it is returned.
+                // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
                 if construct {
-                    // https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget
-                    // 12. If result.[[Type]] is return, then
-                    if context.executor().get_current_state() == &InterpreterState::Return {
-                        // a. If Type(result.[[Value]]) is Object, return NormalCompletion(result.[[Value]]).
-                        if let Ok(v) = &result {
-                            if v.is_object() {
-                                return result;
-                            }
-                        }
+                    match result {
+                        Ok(v) if v.is_object() => Ok(v),
+                        Ok(v) if constructs_derived && !v.is_undefined() => context
+                            .throw_type_error(
+                                "Derived constructors may only return object or undefined",
+                            ),
+                        result => result.and(this),
                     }
-
-                    // 13. Else, ReturnIfAbrupt(result).
-                    result?;
-
-                    // 14. Return ? constructorEnv.GetThisBinding().
-                    this
                 } else {
                     result
                 }
             }
-        }
+            FunctionBody::Async { code, environments } => {
+                AsyncFunction::start(code, environments, context)
+            }
+            FunctionBody::Generator { code, environments } => {
+                Generator::start(self, code, environments, context)
+            }
+            FunctionBody::AsyncGenerator { code, environments } => {
+                AsyncGenerator::start(self, code, environments, context)
+            }
+        };
+
+        context.executor().pop_frame();
+        result
     }
 
     /// Converts an object to a primitive.
@@ -517,6 +665,17 @@ impl JsObject {
         self.borrow().is_map()
     }
 
+    /// Checks if it a `Promise` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_promise(&self) -> bool {
+        self.borrow().is_promise()
+    }
+
     /// Checks if it a `String` object.
     ///
     /// # Panics
@@ -644,9 +803,16 @@ impl JsObject {
             return Ok(false);
         }
 
-        // TODO: 2. If C has a [[BoundTargetFunction]] internal slot, then
-        //         a. Let BC be C.[[BoundTargetFunction]].
-        //         b.  Return ? InstanceofOperator(O, BC).
+        // 2. If C has a [[BoundTargetFunction]] internal slot, then
+        let bound_target = self
+            .borrow()
+            .as_bound_function()
+            .map(|bound_function| bound_function.target_function().clone());
+        if let Some(bound_target) = bound_target {
+            // a. Let BC be C.[[BoundTargetFunction]].
+            // b. Return ? InstanceofOperator(O, BC).
+            return value.instance_of(&bound_target.into(), context);
+        }
 
         // 3. If Type(O) is not Object, return false.
         if let Some(object) = value.as_object() {
@@ -847,13 +1013,6 @@ impl JsObject {
         self.borrow_mut().insert(key, property)
     }
 
-    /// Helper function for property removal.
-    #[inline]
-    #[track_caller]
-    pub(crate) fn remove(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
-        self.borrow_mut().remove(key)
-    }
-
     /// Inserts a field in the object `properties` without checking if it's writable.
     ///
     /// If a field was already in the object with the same name that a `Some` is returned
@@ -867,6 +1026,72 @@ impl JsObject {
         self.insert(key.into(), property)
     }
 
+    /// Watches the own data property `key` of the object, calling `watcher` each time the
+    /// property is written or deleted by a script.
+    ///
+    /// The watcher replaces the previous watcher of the property, if any, and is removed when the
+    /// property is deleted or redefined as an accessor property. Returns `false` if the object
+    /// has no own data property `key`.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::{object::ObjectInitializer, property::{Attribute, PropertyChange}, Context};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// let mut context = Context::new();
+    /// let model = ObjectInitializer::new(&mut context)
+    ///     .property("count", 0, Attribute::all())
+    ///     .build();
+    ///
+    /// let count = Rc::new(Cell::new(0.0));
+    /// let seen = count.clone();
+    /// model.watch_property("count", move |_, _, change, context| {
+    ///     if let PropertyChange::Write { new, .. } = change {
+    ///         seen.set(new.to_number(context)?);
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// context.register_global_property("model", model, Attribute::all());
+    /// context.eval("model.count += 2").unwrap();
+    /// assert_eq!(count.get(), 2.0);
+    /// ```
+    #[inline]
+    pub fn watch_property<K, F>(&self, key: K, watcher: F) -> bool
+    where
+        K: Into<PropertyKey>,
+        F: Fn(&JsObject, &PropertyKey, PropertyChange, &mut Context) -> JsResult<()> + 'static,
+    {
+        let key = key.into();
+        let mut object = self.borrow_mut();
+        let desc = match object.properties.get(&key) {
+            Some(desc) if desc.is_data_descriptor() => desc.clone(),
+            _ => return false,
+        };
+        object
+            .properties
+            .insert(key, desc.into_watched(PropertyWatcher::new(watcher)));
+        true
+    }
+
+    /// Stops watching the own property `key` of the object.
+    ///
+    /// Returns `false` if the property was not watched.
+    #[inline]
+    pub fn unwatch_property<K>(&self, key: K) -> bool
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        let mut object = self.borrow_mut();
+        let desc = match object.properties.get(&key) {
+            Some(desc) if desc.watcher().is_some() => desc.clone(),
+            _ => return false,
+        };
+        object.properties.insert(key, desc.into_unwatched());
+        true
+    }
+
     /// It determines if Object is a callable function with a `[[Call]]` internal method.
     ///
     /// More information:
@@ -901,6 +1126,85 @@ impl JsObject {
             }
         )
     }
+
+    /// Creates a weak reference to the object, which does not keep the object alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn downgrade(&self) -> WeakJsObject {
+        let alive = self
+            .borrow_mut()
+            .weak_slot
+            .get_or_insert_with(|| Rc::new(Cell::new(true)))
+            .clone();
+        WeakJsObject {
+            ptr: self.as_ref(),
+            alive,
+        }
+    }
+}
+
+/// A weak reference to a garbage collected `Object`.
+///
+/// The object is collected once it is only reachable through weak references, after which the
+/// reference can't be upgraded anymore.
+#[derive(Clone)]
+pub struct WeakJsObject {
+    ptr: *const GcCell<Object>,
+    /// Shared with the object, which clears it when it is finalized.
+    alive: Rc<Cell<bool>>,
+}
+
+impl WeakJsObject {
+    /// Returns the object, or `None` if it has been collected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<JsObject> {
+        if !self.alive.get() {
+            return None;
+        }
+
+        // SAFETY: `alive` is cleared when the object is finalized, before it is freed, so the
+        // pointer is still valid. The `Gc` made from it is never dropped, because it does not own
+        // the root it would remove, and the returned clone is rooted on its own.
+        let gc = ManuallyDrop::new(unsafe { Gc::from_raw(self.ptr) });
+        Some(JsObject(Gc::clone(&gc)))
+    }
+
+    /// Returns `true` if the object has not been collected.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.alive.get()
+    }
+
+    /// Returns `true` if the reference points to the given live object.
+    #[inline]
+    pub fn points_to(&self, object: &JsObject) -> bool {
+        self.is_alive() && std::ptr::eq(self.ptr, object.as_ref())
+    }
+
+    /// Returns `true` if both references point to the same object.
+    #[inline]
+    pub fn ptr_eq(lhs: &Self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&lhs.alive, &rhs.alive)
+    }
+}
+
+impl Finalize for WeakJsObject {}
+
+// Safety: a weak reference must not mark its object, so there is nothing to trace.
+unsafe impl Trace for WeakJsObject {
+    empty_trace!();
+}
+
+impl Debug for WeakJsObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakJsObject")
+            .field("alive", &self.is_alive())
+            .finish()
+    }
 }
 
 impl AsRef<GcCell<Object>> for JsObject {
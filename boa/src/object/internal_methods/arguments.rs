@@ -0,0 +1,249 @@
+use crate::{
+    environment::lexical_environment::Environment,
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsValue,
+};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for arguments exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+pub(crate) static ARGUMENTS_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __get_own_property__: arguments_exotic_get_own_property,
+        __define_own_property__: arguments_exotic_define_own_property,
+        __get__: arguments_exotic_get,
+        __set__: arguments_exotic_set,
+        __delete__: arguments_exotic_delete,
+        ordinary_get: false,
+        ordinary_set: false,
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Gets the environment and the name of the parameter binding mapped to `key`, if any.
+fn mapped_binding(obj: &JsObject, key: &PropertyKey) -> Option<(Environment, Box<str>)> {
+    obj.borrow()
+        .as_parameter_map()
+        .expect("arguments exotic objects hold a parameter map")
+        .binding(key)
+}
+
+/// Removes the mapping of `key` from the parameter map of `obj`.
+fn delete_mapping(obj: &JsObject, key: &PropertyKey) {
+    obj.borrow_mut()
+        .as_parameter_map_mut()
+        .expect("arguments exotic objects hold a parameter map")
+        .delete(key);
+}
+
+/// Gets own property of arguments exotic object
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-getownproperty-p
+#[inline]
+pub(crate) fn arguments_exotic_get_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<Option<PropertyDescriptor>> {
+    // 1. Let desc be OrdinaryGetOwnProperty(args, P).
+    // 2. If desc is undefined, return desc.
+    let desc = match super::ordinary_get_own_property(obj, key, context)? {
+        Some(desc) => desc,
+        None => return Ok(None),
+    };
+
+    // 3. Let map be args.[[ParameterMap]].
+    // 4. Let isMapped be ! HasOwnProperty(map, P).
+    // 5. If isMapped is true, then
+    if let Some((environment, name)) = mapped_binding(obj, key) {
+        // a. Set desc.[[Value]] to Get(map, P).
+        let value = environment.get_binding_value(&name, false, context)?;
+        return Ok(Some(
+            PropertyDescriptor::builder()
+                .value(value)
+                .maybe_writable(desc.writable())
+                .maybe_enumerable(desc.enumerable())
+                .maybe_configurable(desc.configurable())
+                .build(),
+        ));
+    }
+
+    // 6. Return desc.
+    Ok(Some(desc))
+}
+
+/// Defines own property of arguments exotic object
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-defineownproperty-p-desc
+#[inline]
+pub(crate) fn arguments_exotic_define_own_property(
+    obj: &JsObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Let map be args.[[ParameterMap]].
+    // 2. Let isMapped be HasOwnProperty(map, P).
+    let mapped = mapped_binding(obj, &key);
+
+    // 3. Let newArgDesc be Desc.
+    // 4. If isMapped is true and IsDataDescriptor(Desc) is true, then
+    //     a. If Desc does not have a [[Value]] field and Desc has a [[Writable]] field, and
+    //        Desc.[[Writable]] is false, then
+    //         i. Set newArgDesc to a copy of Desc.
+    //         ii. Set newArgDesc.[[Value]] to Get(map, P).
+    let new_arg_desc = match mapped {
+        Some((ref environment, ref name))
+            if desc.is_data_descriptor()
+                && desc.value().is_none()
+                && desc.writable() == Some(false) =>
+        {
+            PropertyDescriptor::builder()
+                .value(environment.get_binding_value(name, false, context)?)
+                .writable(false)
+                .maybe_enumerable(desc.enumerable())
+                .maybe_configurable(desc.configurable())
+                .build()
+        }
+        _ => desc.clone(),
+    };
+
+    // 5. Let allowed be ? OrdinaryDefineOwnProperty(args, P, newArgDesc).
+    // 6. If allowed is false, return false.
+    if !super::ordinary_define_own_property(obj, key.clone(), new_arg_desc, context)? {
+        return Ok(false);
+    }
+
+    // 7. If isMapped is true, then
+    if let Some((environment, name)) = mapped {
+        // a. If IsAccessorDescriptor(Desc) is true, then
+        if desc.is_accessor_descriptor() {
+            // i. Call map.[[Delete]](P).
+            delete_mapping(obj, &key);
+        } else {
+            // b. Else,
+            //     i. If Desc has a [[Value]] field, then
+            if let Some(value) = desc.value() {
+                // 1. Let setStatus be Set(map, P, Desc.[[Value]], false).
+                // 2. Assert: setStatus is true because formal parameters mapped by argument
+                //    objects are always writable.
+                environment.set_mutable_binding(&name, value.clone(), false, context)?;
+            }
+
+            //     ii. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, then
+            if desc.writable() == Some(false) {
+                // 1. Call map.[[Delete]](P).
+                delete_mapping(obj, &key);
+            }
+        }
+    }
+
+    // 8. Return true.
+    Ok(true)
+}
+
+/// Gets a property of arguments exotic object
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-get-p-receiver
+#[inline]
+pub(crate) fn arguments_exotic_get(
+    obj: &JsObject,
+    key: &PropertyKey,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let map be args.[[ParameterMap]].
+    // 2. Let isMapped be ! HasOwnProperty(map, P).
+    match mapped_binding(obj, key) {
+        // 3. If isMapped is false, then
+        //     a. Return ? OrdinaryGet(args, P, Receiver).
+        None => super::ordinary_get(obj, key, receiver, context),
+        // 4. Else,
+        //     a. Assert: map contains a formal parameter mapping for P.
+        //     b. Return Get(map, P).
+        Some((environment, name)) => environment.get_binding_value(&name, false, context),
+    }
+}
+
+/// Sets a property of arguments exotic object
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-set-p-v-receiver
+#[inline]
+pub(crate) fn arguments_exotic_set(
+    obj: &JsObject,
+    key: PropertyKey,
+    value: JsValue,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. If SameValue(args, Receiver) is false, then
+    //     a. Let isMapped be false.
+    // 2. Else,
+    //     a. Let map be args.[[ParameterMap]].
+    //     b. Let isMapped be ! HasOwnProperty(map, P).
+    let mapped = match receiver.as_object() {
+        Some(receiver) if JsObject::equals(obj, &receiver) => mapped_binding(obj, &key),
+        _ => None,
+    };
+
+    // 3. If isMapped is true, then
+    if let Some((environment, name)) = mapped {
+        // a. Let setStatus be Set(map, P, V, false).
+        // b. Assert: setStatus is true because formal parameters mapped by argument objects are
+        //    always writable.
+        environment.set_mutable_binding(&name, value.clone(), false, context)?;
+    }
+
+    // 4. Return ? OrdinarySet(args, P, V, Receiver).
+    super::ordinary_set(obj, key, value, receiver, context)
+}
+
+/// Deletes a property of arguments exotic object
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-delete-p
+#[inline]
+pub(crate) fn arguments_exotic_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Let map be args.[[ParameterMap]].
+    // 2. Let isMapped be ! HasOwnProperty(map, P).
+    let is_mapped = obj
+        .borrow()
+        .as_parameter_map()
+        .expect("arguments exotic objects hold a parameter map")
+        .is_mapped(key);
+
+    // 3. Let result be ? OrdinaryDelete(args, P).
+    let result = super::ordinary_delete(obj, key, context)?;
+
+    // 4. If result is true and isMapped is true, then
+    if result && is_mapped {
+        // a. Call map.[[Delete]](P).
+        delete_mapping(obj, key);
+    }
+
+    // 5. Return result.
+    Ok(result)
+}
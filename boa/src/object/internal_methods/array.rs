@@ -14,6 +14,7 @@ use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
 /// [spec]: https://tc39.es/ecma262/#sec-array-exotic-objects
 pub(crate) static ARRAY_EXOTIC_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
     __define_own_property__: array_exotic_define_own_property,
+    ordinary_set: false,
     ..ORDINARY_INTERNAL_METHODS
 };
 
@@ -132,17 +133,13 @@ pub(crate) fn array_exotic_define_own_property(
 
             // 17. For each own property key P of A that is an array index, whose numeric value is
             // greater than or equal to newLen, in descending numeric index order, do
-            let ordered_keys = {
-                let mut keys: Vec<_> = obj
-                    .borrow()
-                    .properties
-                    .index_property_keys()
-                    .filter(|idx| new_len <= **idx && **idx < u32::MAX)
-                    .copied()
-                    .collect();
-                keys.sort_unstable_by(|x, y| y.cmp(x));
-                keys
-            };
+            let ordered_keys: Vec<_> = obj
+                .borrow()
+                .properties
+                .index_property_keys()
+                .rev()
+                .filter(|idx| new_len <= *idx && *idx < u32::MAX)
+                .collect();
 
             for index in ordered_keys {
                 // a. Let deleteSucceeded be ! A.[[Delete]](P).
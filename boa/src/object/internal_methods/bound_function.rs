@@ -0,0 +1,97 @@
+use crate::{error::JsErrorCode, object::JsObject, Context, JsResult, JsValue};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for bound function exotic objects.
+///
+/// Only `[[Call]]` and `[[Construct]]` differ from the ordinary internal methods, forwarding
+/// the bound `this` value and arguments to the target function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+pub(crate) static BOUND_FUNCTION_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __call__: bound_function_exotic_call,
+        __construct__: bound_function_exotic_construct,
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Gets the target function, the bound `this` value and the arguments of a bound function
+/// exotic object, with `args` appended to the bound arguments.
+#[inline]
+fn bound_function_data(obj: &JsObject, args: &[JsValue]) -> (JsObject, JsValue, Vec<JsValue>) {
+    let obj = obj.borrow();
+    let bound_function = obj
+        .as_bound_function()
+        .expect("bound function exotic objects hold a bound function");
+
+    let mut bound_args = bound_function.args().to_vec();
+    bound_args.extend_from_slice(args);
+
+    (
+        bound_function.target_function().clone(),
+        bound_function.this().clone(),
+        bound_args,
+    )
+}
+
+/// Calls a bound function exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-call-thisargument-argumentslist
+#[inline]
+pub(crate) fn bound_function_exotic_call(
+    obj: &JsObject,
+    _: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[BoundTargetFunction]].
+    // 2. Let boundThis be F.[[BoundThis]].
+    // 3. Let boundArgs be F.[[BoundArguments]].
+    // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+    let (target, bound_this, args) = bound_function_data(obj, args);
+
+    // 5. Return ? Call(target, boundThis, args).
+    target.call(&bound_this, &args, context)
+}
+
+/// Constructs an instance of a bound function exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-construct-argumentslist-newtarget
+#[inline]
+pub(crate) fn bound_function_exotic_construct(
+    obj: &JsObject,
+    args: &[JsValue],
+    new_target: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // Only bound functions of constructor targets have a [[Construct]] internal method.
+    if !obj.is_constructable() {
+        return Err(
+            context.construct_coded_error(JsErrorCode::NOT_A_CONSTRUCTOR, "not a constructor")
+        );
+    }
+
+    // 1. Let target be F.[[BoundTargetFunction]].
+    // 2. Assert: IsConstructor(target) is true.
+    // 3. Let boundArgs be F.[[BoundArguments]].
+    // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+    let (target, _, args) = bound_function_data(obj, args);
+
+    // 5. If SameValue(F, newTarget) is true, set newTarget to target.
+    let new_target = match new_target.as_object() {
+        Some(new_target) if JsObject::equals(obj, &new_target) => target.clone().into(),
+        _ => new_target.clone(),
+    };
+
+    // 6. Return ? Construct(target, args, newTarget).
+    target.construct(&args, &new_target, context)
+}
@@ -0,0 +1,410 @@
+use crate::{
+    builtins::Number,
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsString, JsValue,
+};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for integer-indexed exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
+pub(crate) static INTEGER_INDEXED_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __get_own_property__: integer_indexed_exotic_get_own_property,
+        __has_property__: integer_indexed_exotic_has_property,
+        __define_own_property__: integer_indexed_exotic_define_own_property,
+        __get__: integer_indexed_exotic_get,
+        __set__: integer_indexed_exotic_set,
+        __delete__: integer_indexed_exotic_delete,
+        __own_property_keys__: integer_indexed_exotic_own_property_keys,
+        ordinary_get: false,
+        ordinary_set: false,
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Gets the numeric index of a property key, if it is a canonical numeric string.
+///
+/// Integer indices are already parsed by `PropertyKey`, the remaining string keys go through
+/// `CanonicalNumericIndexString`, so keys like `"-0"` or `"1.5"` are also numeric indices.
+fn numeric_index(key: &PropertyKey, context: &mut Context) -> Option<f64> {
+    match key {
+        PropertyKey::Index(index) => Some(f64::from(*index)),
+        PropertyKey::String(string) => canonical_numeric_index_string(string, context),
+        PropertyKey::Symbol(_) => None,
+    }
+}
+
+/// Abstract operation `CanonicalNumericIndexString ( argument )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+fn canonical_numeric_index_string(argument: &JsString, context: &mut Context) -> Option<f64> {
+    // 2. If argument is "-0", return -0𝔽.
+    if argument.as_str() == "-0" {
+        return Some(-0.0);
+    }
+
+    // 3. Let n be ! ToNumber(argument).
+    let n = JsValue::from(argument.clone())
+        .to_number(context)
+        .expect("converting a string to a number cannot fail");
+
+    // 4. If SameValue(! ToString(n), argument) is false, return undefined.
+    // 5. Return n.
+    if Number::to_native_string(n) == argument.as_str() {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Abstract operation `IsValidIntegerIndex ( O, index )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+pub(crate) fn is_valid_integer_index(obj: &JsObject, index: f64) -> bool {
+    let obj = obj.borrow();
+    let inner = obj
+        .as_typed_array()
+        .expect("integer indexed exotic method should only be callable from typed arrays");
+
+    // 1. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, return false.
+    // 2. If ! IsIntegralNumber(index) is false, return false.
+    // 3. If index is -0𝔽, return false.
+    // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, unordered).
+    // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return false.
+    // 6. Let length be TypedArrayLength(taRecord).
+    // 7. If ℝ(index) < 0 or ℝ(index) ≥ length, return false.
+    // 8. Return true.
+    !inner.is_out_of_bounds()
+        && index.is_finite()
+        && index.trunc() == index
+        && !(index == 0.0 && index.is_sign_negative())
+        && index >= 0.0
+        && index < inner.array_length() as f64
+}
+
+/// Abstract operation `IntegerIndexedElementGet ( O, index )`
+///
+/// Returns `None` for an invalid index, which is `undefined` in the spec.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementget
+pub(crate) fn integer_indexed_element_get(obj: &JsObject, index: f64) -> Option<JsValue> {
+    // 1. If ! IsValidIntegerIndex(O, index) is false, return undefined.
+    if !is_valid_integer_index(obj, index) {
+        return None;
+    }
+
+    let obj = obj.borrow();
+    let inner = obj
+        .as_typed_array()
+        .expect("already checked that it's a typed array");
+
+    // 2. Let offset be O.[[ByteOffset]].
+    // 3. Let arrayTypeName be the String value of O.[[TypedArrayName]].
+    // 4. Let elementSize be the Element Size value specified in Table 72 for arrayTypeName.
+    let element_type = inner.typed_array_name();
+
+    // 5. Let indexedPosition be (ℝ(index) × elementSize) + offset.
+    let indexed_position = index as usize * element_type.element_size() + inner.byte_offset();
+
+    // 6. Let elementType be the Element Type value in Table 72 for arrayTypeName.
+    // 7. Return GetValueFromBuffer(O.[[ViewedArrayBuffer]], indexedPosition, elementType, true, Unordered).
+    let buffer = inner
+        .viewed_array_buffer()
+        .expect("a valid integer index implies an attached buffer")
+        .borrow();
+    let buffer = buffer
+        .as_array_buffer()
+        .expect("a typed array should view an ArrayBuffer");
+    Some(buffer.get_value_from_buffer(indexed_position, element_type, true))
+}
+
+/// Abstract operation `IntegerIndexedElementSet ( O, index, value )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementset
+pub(crate) fn integer_indexed_element_set(
+    obj: &JsObject,
+    index: f64,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    let element_type = obj
+        .borrow()
+        .as_typed_array()
+        .expect("integer indexed exotic method should only be callable from typed arrays")
+        .typed_array_name();
+
+    // 1. If O.[[ContentType]] is BigInt, let numValue be ? ToBigInt(value).
+    // 2. Otherwise, let numValue be ? ToNumber(value).
+    let num_value = element_type.to_element_value(value, context)?;
+
+    // 3. If ! IsValidIntegerIndex(O, index) is true, then
+    if is_valid_integer_index(obj, index) {
+        let obj = obj.borrow();
+        let inner = obj
+            .as_typed_array()
+            .expect("already checked that it's a typed array");
+
+        // a. Let offset be O.[[ByteOffset]].
+        // b. Let arrayTypeName be the String value of O.[[TypedArrayName]].
+        // c. Let elementSize be the Element Size value specified in Table 72 for arrayTypeName.
+        // d. Let indexedPosition be (ℝ(index) × elementSize) + offset.
+        let indexed_position = index as usize * element_type.element_size() + inner.byte_offset();
+
+        // e. Let elementType be the Element Type value in Table 72 for arrayTypeName.
+        // f. Perform SetValueInBuffer(O.[[ViewedArrayBuffer]], indexedPosition, elementType, numValue, true, Unordered).
+        let mut buffer = inner
+            .viewed_array_buffer()
+            .expect("a valid integer index implies an attached buffer")
+            .borrow_mut();
+        buffer
+            .as_array_buffer_mut()
+            .expect("a typed array should view an ArrayBuffer")
+            .set_value_in_buffer(indexed_position, element_type, &num_value, true);
+    }
+
+    // 4. Return NormalCompletion(undefined).
+    Ok(())
+}
+
+/// Get an own property of an integer-indexed exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-getownproperty-p
+pub(crate) fn integer_indexed_exotic_get_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<Option<PropertyDescriptor>> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Assert: O is an Integer-Indexed exotic object.
+    // 3. If Type(P) is String, then
+    // a. Let numericIndex be ! CanonicalNumericIndexString(P).
+    // b. If numericIndex is not undefined, then
+    if let Some(numeric_index) = numeric_index(key, context) {
+        // i. Let value be ! IntegerIndexedElementGet(O, numericIndex).
+        // ii. If value is undefined, return undefined.
+        // iii. Return the PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: true }.
+        return Ok(
+            integer_indexed_element_get(obj, numeric_index).map(|value| {
+                PropertyDescriptor::builder()
+                    .value(value)
+                    .writable(true)
+                    .enumerable(true)
+                    .configurable(true)
+                    .build()
+            }),
+        );
+    }
+
+    // 4. Return OrdinaryGetOwnProperty(O, P).
+    super::ordinary_get_own_property(obj, key, context)
+}
+
+/// Check if an integer-indexed exotic object has a property.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-hasproperty-p
+pub(crate) fn integer_indexed_exotic_has_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 3. If Type(P) is String, then
+    // a. Let numericIndex be ! CanonicalNumericIndexString(P).
+    // b. If numericIndex is not undefined, return ! IsValidIntegerIndex(O, numericIndex).
+    if let Some(numeric_index) = numeric_index(key, context) {
+        return Ok(is_valid_integer_index(obj, numeric_index));
+    }
+
+    // 4. Return ? OrdinaryHasProperty(O, P).
+    super::ordinary_has_property(obj, key, context)
+}
+
+/// Define an own property of an integer-indexed exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-defineownproperty-p-desc
+pub(crate) fn integer_indexed_exotic_define_own_property(
+    obj: &JsObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 3. If Type(P) is String, then
+    // a. Let numericIndex be ! CanonicalNumericIndexString(P).
+    // b. If numericIndex is not undefined, then
+    if let Some(numeric_index) = numeric_index(&key, context) {
+        // i. If ! IsValidIntegerIndex(O, numericIndex) is false, return false.
+        // ii. If Desc has a [[Configurable]] field and if Desc.[[Configurable]] is false, return false.
+        // iii. If Desc has an [[Enumerable]] field and if Desc.[[Enumerable]] is false, return false.
+        // iv. If ! IsAccessorDescriptor(Desc) is true, return false.
+        // v. If Desc has a [[Writable]] field and if Desc.[[Writable]] is false, return false.
+        if !is_valid_integer_index(obj, numeric_index)
+            || desc.configurable() == Some(false)
+            || desc.enumerable() == Some(false)
+            || desc.is_accessor_descriptor()
+            || desc.writable() == Some(false)
+        {
+            return Ok(false);
+        }
+
+        // vi. If Desc has a [[Value]] field, perform ? IntegerIndexedElementSet(O, numericIndex, Desc.[[Value]]).
+        if let Some(value) = desc.value() {
+            integer_indexed_element_set(obj, numeric_index, value, context)?;
+        }
+
+        // vii. Return true.
+        return Ok(true);
+    }
+
+    // 4. Return ! OrdinaryDefineOwnProperty(O, P, Desc).
+    super::ordinary_define_own_property(obj, key, desc, context)
+}
+
+/// Get a property of an integer-indexed exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-get-p-receiver
+pub(crate) fn integer_indexed_exotic_get(
+    obj: &JsObject,
+    key: &PropertyKey,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 2. If Type(P) is String, then
+    // a. Let numericIndex be ! CanonicalNumericIndexString(P).
+    // b. If numericIndex is not undefined, then
+    if let Some(numeric_index) = numeric_index(key, context) {
+        // i. Return ! IntegerIndexedElementGet(O, numericIndex).
+        return Ok(integer_indexed_element_get(obj, numeric_index).unwrap_or_default());
+    }
+
+    // 3. Return ? OrdinaryGet(O, P, Receiver).
+    super::ordinary_get(obj, key, receiver, context)
+}
+
+/// Set a property of an integer-indexed exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-set-p-v-receiver
+pub(crate) fn integer_indexed_exotic_set(
+    obj: &JsObject,
+    key: PropertyKey,
+    value: JsValue,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 2. If Type(P) is String, then
+    // a. Let numericIndex be ! CanonicalNumericIndexString(P).
+    // b. If numericIndex is not undefined, then
+    if let Some(numeric_index) = numeric_index(&key, context) {
+        // i. Perform ? IntegerIndexedElementSet(O, numericIndex, V).
+        integer_indexed_element_set(obj, numeric_index, &value, context)?;
+
+        // ii. Return true.
+        return Ok(true);
+    }
+
+    // 3. Return ? OrdinarySet(O, P, V, Receiver).
+    super::ordinary_set(obj, key, value, receiver, context)
+}
+
+/// Delete a property of an integer-indexed exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-delete-p
+pub(crate) fn integer_indexed_exotic_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 3. If Type(P) is String, then
+    // a. Let numericIndex be ! CanonicalNumericIndexString(P).
+    // b. If numericIndex is not undefined, then
+    if let Some(numeric_index) = numeric_index(key, context) {
+        // i. If ! IsValidIntegerIndex(O, numericIndex) is false, return true; else return false.
+        return Ok(!is_valid_integer_index(obj, numeric_index));
+    }
+
+    // 4. Return ? OrdinaryDelete(O, P).
+    super::ordinary_delete(obj, key, context)
+}
+
+/// Get the own property keys of an integer-indexed exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-ownpropertykeys
+#[inline]
+pub(crate) fn integer_indexed_exotic_own_property_keys(
+    obj: &JsObject,
+    _context: &mut Context,
+) -> JsResult<Vec<PropertyKey>> {
+    let obj = obj.borrow();
+    let inner = obj
+        .as_typed_array()
+        .expect("integer indexed exotic method should only be callable from typed arrays");
+
+    // 1. Let keys be a new empty List.
+    let mut keys = Vec::new();
+
+    // 2. Assert: O is an Integer-Indexed exotic object.
+    // 3. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+    // 4. If IsTypedArrayOutOfBounds(taRecord) is false, then
+    if !inner.is_out_of_bounds() {
+        // a. For each integer i starting with 0 such that i < TypedArrayLength(taRecord), in ascending order, do
+        // i. Add ! ToString(𝔽(i)) as the last element of keys.
+        keys.extend((0..inner.array_length()).map(PropertyKey::from));
+    }
+
+    // 4. For each own property key P of O such that Type(P) is String and P is not an integer index, in ascending chronological order of property creation, do
+    // a. Add P as the last element of keys.
+    keys.extend(
+        obj.properties
+            .string_property_keys()
+            .cloned()
+            .map(|s| s.into()),
+    );
+
+    // 5. For each own property key P of O such that Type(P) is Symbol, in ascending chronological order of property creation, do
+    // a. Add P as the last element of keys.
+    keys.extend(
+        obj.properties
+            .symbol_property_keys()
+            .cloned()
+            .map(|sym| sym.into()),
+    );
+
+    // 6. Return keys.
+    Ok(keys)
+}
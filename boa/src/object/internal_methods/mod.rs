@@ -6,15 +6,23 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::{
-    object::JsObject,
-    property::{DescriptorKind, PropertyDescriptor, PropertyKey},
+    context::{StandardConstructor, StandardObjects},
+    object::{JsObject, PROTOTYPE},
+    property::{DescriptorKind, PropertyChange, PropertyDescriptor, PropertyKey},
     value::JsValue,
     BoaProfiler, Context, JsResult,
 };
 
+pub(super) mod arguments;
 pub(super) mod array;
+pub(super) mod bound_function;
+pub(super) mod integer_indexed;
+pub(super) mod module_namespace;
+pub(super) mod proxy;
 pub(super) mod string;
 
+pub(crate) use integer_indexed::{integer_indexed_element_get, integer_indexed_element_set};
+
 impl JsObject {
     /// Internal method `[[GetPrototypeOf]]`
     ///
@@ -72,7 +80,7 @@ impl JsObject {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-preventextensions
     #[inline]
-    pub(crate) fn __prevent_extensions__(&mut self, context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn __prevent_extensions__(&self, context: &mut Context) -> JsResult<bool> {
         let func = self.borrow().data.internal_methods.__prevent_extensions__;
         func(self, context)
     }
@@ -204,6 +212,46 @@ impl JsObject {
         let func = self.borrow().data.internal_methods.__own_property_keys__;
         func(self, context)
     }
+
+    /// Internal method `[[Call]]`
+    ///
+    /// Call this object with the given `this` value and arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist
+    #[inline]
+    #[track_caller]
+    pub(crate) fn __call__(
+        &self,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let func = self.borrow().data.internal_methods.__call__;
+        func(self, this, args, context)
+    }
+
+    /// Internal method `[[Construct]]`
+    ///
+    /// Construct an instance of this object with the given arguments and `new.target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget
+    #[inline]
+    #[track_caller]
+    pub(crate) fn __construct__(
+        &self,
+        args: &[JsValue],
+        new_target: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let func = self.borrow().data.internal_methods.__construct__;
+        func(self, args, new_target, context)
+    }
 }
 
 /// Definitions of the internal object methods for ordinary objects.
@@ -229,6 +277,11 @@ pub(crate) static ORDINARY_INTERNAL_METHODS: InternalObjectMethods = InternalObj
     __set__: ordinary_set,
     __delete__: ordinary_delete,
     __own_property_keys__: ordinary_own_property_keys,
+    __call__: ordinary_call,
+    __construct__: ordinary_construct,
+    ordinary_get: true,
+    ordinary_set: true,
+    ordinary_get_prototype_of: true,
 };
 
 /// The internal representation of the internal methods of a `JsObject`.
@@ -254,6 +307,21 @@ pub(crate) struct InternalObjectMethods {
         fn(&JsObject, PropertyKey, JsValue, JsValue, &mut Context) -> JsResult<bool>,
     pub(crate) __delete__: fn(&JsObject, &PropertyKey, &mut Context) -> JsResult<bool>,
     pub(crate) __own_property_keys__: fn(&JsObject, &mut Context) -> JsResult<Vec<PropertyKey>>,
+    pub(crate) __call__: fn(&JsObject, &JsValue, &[JsValue], &mut Context) -> JsResult<JsValue>,
+    pub(crate) __construct__:
+        fn(&JsObject, &[JsValue], &JsValue, &mut Context) -> JsResult<JsValue>,
+
+    /// Whether `[[Get]]` reads the own properties like the ordinary one, so an own data property
+    /// can be read from the property map directly.
+    pub(crate) ordinary_get: bool,
+
+    /// Whether `[[Set]]` writes the own properties like the ordinary one, so an own writable data
+    /// property can be written in the property map directly.
+    pub(crate) ordinary_set: bool,
+
+    /// Whether `[[GetPrototypeOf]]` is the ordinary one, which `OrdinarySetPrototypeOf` follows
+    /// when looking for prototype chain cycles.
+    pub(crate) ordinary_get_prototype_of: bool,
 }
 
 /// Abstract operation `OrdinaryGetPrototypeOf`.
@@ -319,8 +387,11 @@ pub(crate) fn ordinary_set_prototype_of(
                 // c. Else,
                 // i. If p.[[GetPrototypeOf]] is not the ordinary object internal method defined
                 // in 10.1.1, set done to true.
-                else if proto.borrow().data.internal_methods.__get_prototype_of__ as usize
-                    != ordinary_get_prototype_of as usize
+                else if !proto
+                    .borrow()
+                    .data
+                    .internal_methods
+                    .ordinary_get_prototype_of
                 {
                     done = true;
                 }
@@ -418,13 +489,28 @@ pub(crate) fn ordinary_define_own_property(
     // 2. Let extensible be ? IsExtensible(O).
     let extensible = obj.__is_extensible__(context)?;
 
+    // A write of a watched property is reported to its watcher once applied.
+    let watch = match (current.as_ref(), desc.value()) {
+        (Some(current), Some(new)) => current.watcher().map(|watcher| {
+            (
+                watcher.clone(),
+                key.clone(),
+                current.expect_value().clone(),
+                new.clone(),
+            )
+        }),
+        _ => None,
+    };
+
     // 3. Return ValidateAndApplyPropertyDescriptor(O, P, extensible, Desc, current).
-    Ok(validate_and_apply_property_descriptor(
-        Some((obj, key)),
-        extensible,
-        desc,
-        current,
-    ))
+    if !validate_and_apply_property_descriptor(Some((obj, key)), extensible, desc, current) {
+        return Ok(false);
+    }
+
+    if let Some((watcher, key, old, new)) = watch {
+        watcher.call(obj, &key, PropertyChange::Write { old, new }, context)?;
+    }
+    Ok(true)
 }
 
 /// Abstract operation `OrdinaryHasProperty`.
@@ -491,6 +577,9 @@ pub(crate) fn ordinary_get(
             // 4. If IsDataDescriptor(desc) is true, return desc.[[Value]].
             DescriptorKind::Data {
                 value: Some(value), ..
+            }
+            | DescriptorKind::Watched {
+                value: Some(value), ..
             } => Ok(value.clone()),
             // 5. Assert: IsAccessorDescriptor(desc) is true.
             // 6. Let getter be desc.[[Get]].
@@ -620,22 +709,27 @@ pub(crate) fn ordinary_delete(
     context: &mut Context,
 ) -> JsResult<bool> {
     // 1. Assert: IsPropertyKey(P) is true.
-    Ok(
-        // 2. Let desc be ? O.[[GetOwnProperty]](P).
-        match obj.__get_own_property__(key, context)? {
-            // 4. If desc.[[Configurable]] is true, then
-            Some(desc) if desc.expect_configurable() => {
-                // a. Remove the own property with name P from O.
-                obj.borrow_mut().remove(key);
-                // b. Return true.
-                true
+    // 2. Let desc be ? O.[[GetOwnProperty]](P).
+    match obj.__get_own_property__(key, context)? {
+        // 4. If desc.[[Configurable]] is true, then
+        Some(desc) if desc.expect_configurable() => {
+            // a. Remove the own property with name P from O.
+            obj.borrow_mut().remove(key);
+
+            // The deletion of a watched property is reported to its watcher.
+            if let Some(watcher) = desc.watcher() {
+                let old = desc.expect_value().clone();
+                watcher.call(obj, key, PropertyChange::Delete { old }, context)?;
             }
-            // 5. Return false.
-            Some(_) => false,
-            // 3. If desc is undefined, return true.
-            None => true,
-        },
-    )
+
+            // b. Return true.
+            Ok(true)
+        }
+        // 5. Return false.
+        Some(_) => Ok(false),
+        // 3. If desc is undefined, return true.
+        None => Ok(true),
+    }
 }
 
 /// Abstract operation `OrdinaryOwnPropertyKeys`.
@@ -652,20 +746,14 @@ pub(crate) fn ordinary_own_property_keys(
     // 1. Let keys be a new empty List.
     let mut keys = Vec::new();
 
-    let ordered_indexes = {
-        let mut indexes: Vec<_> = obj
-            .borrow()
-            .properties
-            .index_property_keys()
-            .copied()
-            .collect();
-        indexes.sort_unstable();
-        indexes
-    };
-
     // 2. For each own property key P of O such that P is an array index, in ascending numeric index order, do
     // a. Add P as the last element of keys.
-    keys.extend(ordered_indexes.into_iter().map(|idx| idx.into()));
+    keys.extend(
+        obj.borrow()
+            .properties
+            .index_property_keys()
+            .map(|idx| idx.into()),
+    );
 
     // 3. For each own property key P of O such that Type(P) is String and P is not an array index, in ascending chronological order of property creation, do
     // a. Add P as the last element of keys.
@@ -691,6 +779,42 @@ pub(crate) fn ordinary_own_property_keys(
     Ok(keys)
 }
 
+/// The `[[Call]]` internal method of ordinary objects, which calls the function held by the
+/// object and throws a `TypeError` if the object is not a function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist
+#[inline]
+#[track_caller]
+pub(crate) fn ordinary_call(
+    obj: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    obj.call_construct(this, args, context, false)
+}
+
+/// The `[[Construct]]` internal method of ordinary objects, which constructs an instance of the
+/// function held by the object and throws a `TypeError` if the object is not a constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget
+#[inline]
+#[track_caller]
+pub(crate) fn ordinary_construct(
+    obj: &JsObject,
+    args: &[JsValue],
+    new_target: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    obj.call_construct(new_target, args, context, true)
+}
+
 /// Abstract operation `IsCompatiblePropertyDescriptor`
 ///
 /// More information:
@@ -859,3 +983,32 @@ pub(crate) fn validate_and_apply_property_descriptor(
     // 10. Return true.
     true
 }
+
+/// Abstract operation `GetPrototypeFromConstructor`
+///
+/// Gets the `prototype` of `constructor`, falling back to the prototype of the standard
+/// constructor selected by `default` if it is not an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getprototypefromconstructor
+pub(crate) fn get_prototype_from_constructor(
+    constructor: &JsValue,
+    default: fn(&StandardObjects) -> &StandardConstructor,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. Assert: intrinsicDefaultProto is this specification's name of an intrinsic object.
+    // 2. Let proto be ? Get(constructor, "prototype").
+    if let Some(constructor) = constructor.as_object() {
+        if let Some(proto) = constructor.get(PROTOTYPE, context)?.as_object() {
+            // 4. Return proto.
+            return Ok(proto);
+        }
+    }
+
+    // 3. If Type(proto) is not Object, then
+    // a. Let realm be ? GetFunctionRealm(constructor).
+    // b. Set proto to realm's intrinsic object named intrinsicDefaultProto.
+    Ok(default(context.standard_objects()).prototype())
+}
@@ -0,0 +1,952 @@
+use crate::{
+    builtins::{object::Object as BuiltInObjectObject, Array},
+    error::JsErrorCode,
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyKey},
+    value::Type,
+    Context, JsResult, JsValue,
+};
+
+use super::{validate_and_apply_property_descriptor, InternalObjectMethods};
+
+/// Definitions of the internal object methods for proxy exotic objects.
+///
+/// Each internal method calls the matching trap of the handler of the proxy, if it has one,
+/// checking the result of the trap against the invariants of the target. The internal methods
+/// of the target are used instead for the traps the handler does not define.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots
+pub(crate) static PROXY_EXOTIC_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    __get_prototype_of__: proxy_exotic_get_prototype_of,
+    __set_prototype_of__: proxy_exotic_set_prototype_of,
+    __is_extensible__: proxy_exotic_is_extensible,
+    __prevent_extensions__: proxy_exotic_prevent_extensions,
+    __get_own_property__: proxy_exotic_get_own_property,
+    __define_own_property__: proxy_exotic_define_own_property,
+    __has_property__: proxy_exotic_has_property,
+    __get__: proxy_exotic_get,
+    __set__: proxy_exotic_set,
+    __delete__: proxy_exotic_delete,
+    __own_property_keys__: proxy_exotic_own_property_keys,
+    __call__: proxy_exotic_call,
+    __construct__: proxy_exotic_construct,
+    ordinary_get: false,
+    ordinary_set: false,
+    ordinary_get_prototype_of: false,
+};
+
+/// Gets the target and the handler of the proxy `obj`, throwing a `TypeError` if it was revoked.
+#[inline]
+fn proxy_data(obj: &JsObject, context: &mut Context) -> JsResult<(JsObject, JsObject)> {
+    let proxy = obj.borrow().as_proxy().cloned();
+    proxy
+        .expect("proxy exotic objects hold a proxy")
+        .try_data(context)
+}
+
+/// Gets the internal method `[[GetPrototypeOf]]` of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getprototypeof
+#[inline]
+pub(crate) fn proxy_exotic_get_prototype_of(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let handler be O.[[ProxyHandler]].
+    // 2. If handler is null, throw a TypeError exception.
+    // 3. Assert: Type(handler) is Object.
+    // 4. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 5. Let trap be ? GetMethod(handler, "getPrototypeOf").
+    // 6. If trap is undefined, then
+    let trap = match handler.get_method(context, "getPrototypeOf")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[GetPrototypeOf]]().
+        None => return target.__get_prototype_of__(context),
+    };
+
+    // 7. Let handlerProto be ? Call(trap, handler, « target »).
+    let handler_proto = trap.call(&handler.into(), &[target.clone().into()], context)?;
+
+    // 8. If Type(handlerProto) is neither Object nor Null, throw a TypeError exception.
+    if !handler_proto.is_object() && !handler_proto.is_null() {
+        return Err(context.construct_type_error("Proxy trap result is neither object nor null"));
+    }
+
+    // 9. Let extensibleTarget be ? IsExtensible(target).
+    // 10. If extensibleTarget is true, return handlerProto.
+    if target.__is_extensible__(context)? {
+        return Ok(handler_proto);
+    }
+
+    // 11. Let targetProto be ? target.[[GetPrototypeOf]]().
+    let target_proto = target.__get_prototype_of__(context)?;
+
+    // 12. If SameValue(handlerProto, targetProto) is false, throw a TypeError exception.
+    if !JsValue::same_value(&handler_proto, &target_proto) {
+        return Err(context.construct_type_error("Proxy trap returned unexpected prototype"));
+    }
+
+    // 13. Return handlerProto.
+    Ok(handler_proto)
+}
+
+/// Sets the prototype of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-setprototypeof-v
+#[inline]
+pub(crate) fn proxy_exotic_set_prototype_of(
+    obj: &JsObject,
+    val: JsValue,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Assert: Either Type(V) is Object or Type(V) is Null.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (mut target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "setPrototypeOf").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "setPrototypeOf")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[SetPrototypeOf]](V).
+        None => return target.__set_prototype_of__(val, context),
+    };
+
+    // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, V »)).
+    // 9. If booleanTrapResult is false, return false.
+    if !trap
+        .call(
+            &handler.into(),
+            &[target.clone().into(), val.clone()],
+            context,
+        )?
+        .to_boolean()
+    {
+        return Ok(false);
+    }
+
+    // 10. Let extensibleTarget be ? IsExtensible(target).
+    // 11. If extensibleTarget is true, return true.
+    if target.__is_extensible__(context)? {
+        return Ok(true);
+    }
+
+    // 12. Let targetProto be ? target.[[GetPrototypeOf]]().
+    let target_proto = target.__get_prototype_of__(context)?;
+
+    // 13. If SameValue(V, targetProto) is false, throw a TypeError exception.
+    if !JsValue::same_value(&val, &target_proto) {
+        return Err(context.construct_type_error(
+            "Proxy trap failed to set the prototype of a non-extensible target",
+        ));
+    }
+
+    // 14. Return true.
+    Ok(true)
+}
+
+/// Checks if a proxy exotic object is extensible.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-isextensible
+#[inline]
+pub(crate) fn proxy_exotic_is_extensible(obj: &JsObject, context: &mut Context) -> JsResult<bool> {
+    // 1. Let handler be O.[[ProxyHandler]].
+    // 2. If handler is null, throw a TypeError exception.
+    // 3. Assert: Type(handler) is Object.
+    // 4. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 5. Let trap be ? GetMethod(handler, "isExtensible").
+    // 6. If trap is undefined, then
+    let trap = match handler.get_method(context, "isExtensible")? {
+        Some(trap) => trap,
+        // a. Return ? IsExtensible(target).
+        None => return target.__is_extensible__(context),
+    };
+
+    // 7. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target »)).
+    let boolean_trap_result = trap
+        .call(&handler.into(), &[target.clone().into()], context)?
+        .to_boolean();
+
+    // 8. Let targetResult be ? IsExtensible(target).
+    let target_result = target.__is_extensible__(context)?;
+
+    // 9. If SameValue(booleanTrapResult, targetResult) is false, throw a TypeError exception.
+    if boolean_trap_result != target_result {
+        return Err(context.construct_type_error("Proxy trap result does not match the target"));
+    }
+
+    // 10. Return booleanTrapResult.
+    Ok(boolean_trap_result)
+}
+
+/// Prevents the extensions of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-preventextensions
+#[inline]
+pub(crate) fn proxy_exotic_prevent_extensions(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Let handler be O.[[ProxyHandler]].
+    // 2. If handler is null, throw a TypeError exception.
+    // 3. Assert: Type(handler) is Object.
+    // 4. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 5. Let trap be ? GetMethod(handler, "preventExtensions").
+    // 6. If trap is undefined, then
+    let trap = match handler.get_method(context, "preventExtensions")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[PreventExtensions]]().
+        None => return target.__prevent_extensions__(context),
+    };
+
+    // 7. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target »)).
+    let boolean_trap_result = trap
+        .call(&handler.into(), &[target.clone().into()], context)?
+        .to_boolean();
+
+    // 8. If booleanTrapResult is true, then
+    //     a. Let extensibleTarget be ? IsExtensible(target).
+    //     b. If extensibleTarget is true, throw a TypeError exception.
+    if boolean_trap_result && target.__is_extensible__(context)? {
+        return Err(context
+            .construct_type_error("Proxy trap failed to prevent the extensions of the target"));
+    }
+
+    // 9. Return booleanTrapResult.
+    Ok(boolean_trap_result)
+}
+
+/// Gets own property of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getownproperty-p
+#[inline]
+pub(crate) fn proxy_exotic_get_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<Option<PropertyDescriptor>> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "getOwnPropertyDescriptor").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "getOwnPropertyDescriptor")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[GetOwnProperty]](P).
+        None => return target.__get_own_property__(key, context),
+    };
+
+    // 8. Let trapResultObj be ? Call(trap, handler, « target, P »).
+    let trap_result_obj = trap.call(
+        &handler.into(),
+        &[target.clone().into(), key.clone().into()],
+        context,
+    )?;
+
+    // 9. If Type(trapResultObj) is neither Object nor Undefined, throw a TypeError exception.
+    if !trap_result_obj.is_object() && !trap_result_obj.is_undefined() {
+        return Err(
+            context.construct_type_error("Proxy trap result is neither object nor undefined")
+        );
+    }
+
+    // 10. Let targetDesc be ? target.[[GetOwnProperty]](P).
+    let target_desc = target.__get_own_property__(key, context)?;
+
+    // 11. If trapResultObj is undefined, then
+    if trap_result_obj.is_undefined() {
+        if let Some(desc) = target_desc {
+            // b. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
+            if !desc.expect_configurable() {
+                return Err(context.construct_type_error(
+                    "Proxy trap reported a non-configurable property as non-existent",
+                ));
+            }
+
+            // c. Let extensibleTarget be ? IsExtensible(target).
+            // d. If extensibleTarget is false, throw a TypeError exception.
+            if !target.__is_extensible__(context)? {
+                return Err(context.construct_type_error(
+                    "Proxy trap reported an existing property of a non-extensible target as non-existent",
+                ));
+            }
+        }
+
+        // a. If targetDesc is undefined, return undefined.
+        // e. Return undefined.
+        return Ok(None);
+    }
+
+    // 12. Let extensibleTarget be ? IsExtensible(target).
+    let extensible_target = target.__is_extensible__(context)?;
+
+    // 13. Let resultDesc be ? ToPropertyDescriptor(trapResultObj).
+    // 14. Call CompletePropertyDescriptor(resultDesc).
+    let result_desc = trap_result_obj
+        .to_property_descriptor(context)?
+        .complete_property_descriptor();
+
+    // 15. Let valid be IsCompatiblePropertyDescriptor(extensibleTarget, resultDesc, targetDesc).
+    // 16. If valid is false, throw a TypeError exception.
+    if !validate_and_apply_property_descriptor(
+        None,
+        extensible_target,
+        result_desc.clone(),
+        target_desc.clone(),
+    ) {
+        return Err(
+            context.construct_type_error("Proxy trap returned an incompatible property descriptor")
+        );
+    }
+
+    // 17. If resultDesc.[[Configurable]] is false, then
+    if !result_desc.expect_configurable() {
+        match target_desc {
+            // a. If targetDesc is undefined or targetDesc.[[Configurable]] is true, then
+            //     i. Throw a TypeError exception.
+            Some(ref desc) if !desc.expect_configurable() => {
+                // b. If resultDesc has a [[Writable]] field and resultDesc.[[Writable]] is false, then
+                //     i. If targetDesc.[[Writable]] is true, throw a TypeError exception.
+                if result_desc.writable() == Some(false) && desc.expect_writable() {
+                    return Err(context.construct_type_error(
+                        "Proxy trap reported a writable property as non-writable",
+                    ));
+                }
+            }
+            _ => return Err(context.construct_type_error(
+                "Proxy trap reported a configurable or non-existent property as non-configurable",
+            )),
+        }
+    }
+
+    // 18. Return resultDesc.
+    Ok(Some(result_desc))
+}
+
+/// Defines own property of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+#[inline]
+pub(crate) fn proxy_exotic_define_own_property(
+    obj: &JsObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "defineProperty").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "defineProperty")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[DefineOwnProperty]](P, Desc).
+        None => return target.__define_own_property__(key, desc, context),
+    };
+
+    // 8. Let descObj be FromPropertyDescriptor(Desc).
+    let desc_obj = BuiltInObjectObject::from_property_descriptor(desc.clone(), context);
+
+    // 9. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P, descObj »)).
+    // 10. If booleanTrapResult is false, return false.
+    if !trap
+        .call(
+            &handler.into(),
+            &[target.clone().into(), key.clone().into(), desc_obj],
+            context,
+        )?
+        .to_boolean()
+    {
+        return Ok(false);
+    }
+
+    // 11. Let targetDesc be ? target.[[GetOwnProperty]](P).
+    let target_desc = target.__get_own_property__(&key, context)?;
+
+    // 12. Let extensibleTarget be ? IsExtensible(target).
+    let extensible_target = target.__is_extensible__(context)?;
+
+    // 13. If Desc has a [[Configurable]] field and if Desc.[[Configurable]] is false, then
+    //     a. Let settingConfigFalse be true.
+    // 14. Else, let settingConfigFalse be false.
+    let setting_config_false = desc.configurable() == Some(false);
+
+    match target_desc {
+        // 15. If targetDesc is undefined, then
+        None => {
+            // a. If extensibleTarget is false, throw a TypeError exception.
+            if !extensible_target {
+                return Err(context.construct_type_error(
+                    "Proxy trap defined a property on a non-extensible target",
+                ));
+            }
+
+            // b. If settingConfigFalse is true, throw a TypeError exception.
+            if setting_config_false {
+                return Err(context.construct_type_error(
+                    "Proxy trap defined a non-existent property as non-configurable",
+                ));
+            }
+        }
+        // 16. Else,
+        Some(target_desc) => {
+            // a. If IsCompatiblePropertyDescriptor(extensibleTarget, Desc, targetDesc) is false, throw a TypeError exception.
+            if !validate_and_apply_property_descriptor(
+                None,
+                extensible_target,
+                desc.clone(),
+                Some(target_desc.clone()),
+            ) {
+                return Err(context.construct_type_error(
+                    "Proxy trap defined an incompatible property descriptor",
+                ));
+            }
+
+            // b. If settingConfigFalse is true and targetDesc.[[Configurable]] is true, throw a TypeError exception.
+            if setting_config_false && target_desc.expect_configurable() {
+                return Err(context.construct_type_error(
+                    "Proxy trap defined a configurable property as non-configurable",
+                ));
+            }
+
+            // c. If IsDataDescriptor(targetDesc) is true, targetDesc.[[Configurable]] is false, and targetDesc.[[Writable]] is true, then
+            //     i. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, throw a TypeError exception.
+            if target_desc.is_data_descriptor()
+                && !target_desc.expect_configurable()
+                && target_desc.expect_writable()
+                && desc.writable() == Some(false)
+            {
+                return Err(context.construct_type_error(
+                    "Proxy trap defined a writable property as non-writable",
+                ));
+            }
+        }
+    }
+
+    // 17. Return true.
+    Ok(true)
+}
+
+/// Checks if a proxy exotic object has a property.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+#[inline]
+pub(crate) fn proxy_exotic_has_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "has").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "has")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[HasProperty]](P).
+        None => return target.__has_property__(key, context),
+    };
+
+    // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P »)).
+    let boolean_trap_result = trap
+        .call(
+            &handler.into(),
+            &[target.clone().into(), key.clone().into()],
+            context,
+        )?
+        .to_boolean();
+
+    // 9. If booleanTrapResult is false, then
+    if !boolean_trap_result {
+        // a. Let targetDesc be ? target.[[GetOwnProperty]](P).
+        // b. If targetDesc is not undefined, then
+        if let Some(target_desc) = target.__get_own_property__(key, context)? {
+            // i. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
+            if !target_desc.expect_configurable() {
+                return Err(context.construct_type_error(
+                    "Proxy trap reported a non-configurable property as non-existent",
+                ));
+            }
+
+            // ii. Let extensibleTarget be ? IsExtensible(target).
+            // iii. If extensibleTarget is false, throw a TypeError exception.
+            if !target.__is_extensible__(context)? {
+                return Err(context.construct_type_error(
+                    "Proxy trap reported an existing property of a non-extensible target as non-existent",
+                ));
+            }
+        }
+    }
+
+    // 10. Return booleanTrapResult.
+    Ok(boolean_trap_result)
+}
+
+/// Gets a property of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+#[inline]
+pub(crate) fn proxy_exotic_get(
+    obj: &JsObject,
+    key: &PropertyKey,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "get").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "get")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[Get]](P, Receiver).
+        None => return target.__get__(key, receiver, context),
+    };
+
+    // 8. Let trapResult be ? Call(trap, handler, « target, P, Receiver »).
+    let trap_result = trap.call(
+        &handler.into(),
+        &[target.clone().into(), key.clone().into(), receiver],
+        context,
+    )?;
+
+    // 9. Let targetDesc be ? target.[[GetOwnProperty]](P).
+    // 10. If targetDesc is not undefined and targetDesc.[[Configurable]] is false, then
+    if let Some(target_desc) = target.__get_own_property__(key, context)? {
+        if !target_desc.expect_configurable() {
+            // a. If IsDataDescriptor(targetDesc) is true and targetDesc.[[Writable]] is false, then
+            //     i. If SameValue(trapResult, targetDesc.[[Value]]) is false, throw a TypeError exception.
+            if target_desc.is_data_descriptor()
+                && !target_desc.expect_writable()
+                && !JsValue::same_value(&trap_result, target_desc.expect_value())
+            {
+                return Err(context.construct_type_error(
+                    "Proxy trap returned a different value for a non-writable, non-configurable property",
+                ));
+            }
+
+            // b. If IsAccessorDescriptor(targetDesc) is true and targetDesc.[[Get]] is undefined, then
+            //     i. If trapResult is not undefined, throw a TypeError exception.
+            if target_desc.is_accessor_descriptor()
+                && target_desc.expect_get().is_undefined()
+                && !trap_result.is_undefined()
+            {
+                return Err(context.construct_type_error(
+                    "Proxy trap returned a value for a non-configurable property without a getter",
+                ));
+            }
+        }
+    }
+
+    // 11. Return trapResult.
+    Ok(trap_result)
+}
+
+/// Sets a property of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+#[inline]
+pub(crate) fn proxy_exotic_set(
+    obj: &JsObject,
+    key: PropertyKey,
+    value: JsValue,
+    receiver: JsValue,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "set").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "set")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[Set]](P, V, Receiver).
+        None => return target.__set__(key, value, receiver, context),
+    };
+
+    // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P, V, Receiver »)).
+    // 9. If booleanTrapResult is false, return false.
+    if !trap
+        .call(
+            &handler.into(),
+            &[
+                target.clone().into(),
+                key.clone().into(),
+                value.clone(),
+                receiver,
+            ],
+            context,
+        )?
+        .to_boolean()
+    {
+        return Ok(false);
+    }
+
+    // 10. Let targetDesc be ? target.[[GetOwnProperty]](P).
+    // 11. If targetDesc is not undefined and targetDesc.[[Configurable]] is false, then
+    if let Some(target_desc) = target.__get_own_property__(&key, context)? {
+        if !target_desc.expect_configurable() {
+            // a. If IsDataDescriptor(targetDesc) is true and targetDesc.[[Writable]] is false, then
+            //     i. If SameValue(V, targetDesc.[[Value]]) is false, throw a TypeError exception.
+            if target_desc.is_data_descriptor()
+                && !target_desc.expect_writable()
+                && !JsValue::same_value(&value, target_desc.expect_value())
+            {
+                return Err(context.construct_type_error(
+                    "Proxy trap set a different value for a non-writable, non-configurable property",
+                ));
+            }
+
+            // b. If IsAccessorDescriptor(targetDesc) is true, then
+            //     i. If targetDesc.[[Set]] is undefined, throw a TypeError exception.
+            if target_desc.is_accessor_descriptor() && target_desc.expect_set().is_undefined() {
+                return Err(context.construct_type_error(
+                    "Proxy trap set a value for a non-configurable property without a setter",
+                ));
+            }
+        }
+    }
+
+    // 12. Return true.
+    Ok(true)
+}
+
+/// Deletes a property of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+#[inline]
+pub(crate) fn proxy_exotic_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> JsResult<bool> {
+    // 1. Assert: IsPropertyKey(P) is true.
+    // 2. Let handler be O.[[ProxyHandler]].
+    // 3. If handler is null, throw a TypeError exception.
+    // 4. Assert: Type(handler) is Object.
+    // 5. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "deleteProperty").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "deleteProperty")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[Delete]](P).
+        None => return target.__delete__(key, context),
+    };
+
+    // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P »)).
+    // 9. If booleanTrapResult is false, return false.
+    if !trap
+        .call(
+            &handler.into(),
+            &[target.clone().into(), key.clone().into()],
+            context,
+        )?
+        .to_boolean()
+    {
+        return Ok(false);
+    }
+
+    // 10. Let targetDesc be ? target.[[GetOwnProperty]](P).
+    // 11. If targetDesc is undefined, return true.
+    if let Some(target_desc) = target.__get_own_property__(key, context)? {
+        // 12. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
+        if !target_desc.expect_configurable() {
+            return Err(
+                context.construct_type_error("Proxy trap deleted a non-configurable property")
+            );
+        }
+
+        // 13. Let extensibleTarget be ? IsExtensible(target).
+        // 14. If extensibleTarget is false, throw a TypeError exception.
+        if !target.__is_extensible__(context)? {
+            return Err(context
+                .construct_type_error("Proxy trap deleted a property of a non-extensible target"));
+        }
+    }
+
+    // 15. Return true.
+    Ok(true)
+}
+
+/// Gets the own property keys of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-ownpropertykeys
+#[inline]
+pub(crate) fn proxy_exotic_own_property_keys(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<PropertyKey>> {
+    // 1. Let handler be O.[[ProxyHandler]].
+    // 2. If handler is null, throw a TypeError exception.
+    // 3. Assert: Type(handler) is Object.
+    // 4. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 5. Let trap be ? GetMethod(handler, "ownKeys").
+    // 6. If trap is undefined, then
+    let trap = match handler.get_method(context, "ownKeys")? {
+        Some(trap) => trap,
+        // a. Return ? target.[[OwnPropertyKeys]]().
+        None => return target.__own_property_keys__(context),
+    };
+
+    // 7. Let trapResultArray be ? Call(trap, handler, « target »).
+    let trap_result_array = trap.call(&handler.into(), &[target.clone().into()], context)?;
+
+    // 8. Let trapResult be ? CreateListFromArrayLike(trapResultArray, « String, Symbol »).
+    let trap_result_raw =
+        trap_result_array.create_list_from_array_like(&[Type::String, Type::Symbol], context)?;
+
+    // 9. If trapResult contains any duplicate entries, throw a TypeError exception.
+    let mut trap_result = Vec::with_capacity(trap_result_raw.len());
+    for value in trap_result_raw {
+        let key = value.to_property_key(context)?;
+        if trap_result.contains(&key) {
+            return Err(context.construct_type_error("Proxy trap result contains duplicate keys"));
+        }
+        trap_result.push(key);
+    }
+
+    // 10. Let extensibleTarget be ? IsExtensible(target).
+    let extensible_target = target.__is_extensible__(context)?;
+
+    // 11. Let targetKeys be ? target.[[OwnPropertyKeys]]().
+    // 12. Assert: targetKeys is a List whose elements are only String and Symbol values.
+    // 13. Assert: targetKeys contains no duplicate entries.
+    let target_keys = target.__own_property_keys__(context)?;
+
+    // 14. Let targetConfigurableKeys be a new empty List.
+    // 15. Let targetNonconfigurableKeys be a new empty List.
+    let mut target_configurable_keys = Vec::new();
+    let mut target_nonconfigurable_keys = Vec::new();
+
+    // 16. For each element key of targetKeys, do
+    for key in target_keys {
+        // a. Let desc be ? target.[[GetOwnProperty]](key).
+        match target.__get_own_property__(&key, context)? {
+            // b. If desc is not undefined and desc.[[Configurable]] is false, then
+            //     i. Append key as an element of targetNonconfigurableKeys.
+            Some(desc) if !desc.expect_configurable() => target_nonconfigurable_keys.push(key),
+            // c. Else,
+            //     i. Append key as an element of targetConfigurableKeys.
+            _ => target_configurable_keys.push(key),
+        }
+    }
+
+    // 17. If extensibleTarget is true and targetNonconfigurableKeys is empty, then
+    //     a. Return trapResult.
+    if extensible_target && target_nonconfigurable_keys.is_empty() {
+        return Ok(trap_result);
+    }
+
+    // 18. Let uncheckedResultKeys be a List whose elements are the elements of trapResult.
+    let mut unchecked_result_keys = trap_result.clone();
+
+    // 19. For each element key of targetNonconfigurableKeys, do
+    for key in target_nonconfigurable_keys {
+        // a. If key is not an element of uncheckedResultKeys, throw a TypeError exception.
+        // b. Remove key from uncheckedResultKeys.
+        match unchecked_result_keys.iter().position(|k| *k == key) {
+            Some(position) => {
+                unchecked_result_keys.remove(position);
+            }
+            None => {
+                return Err(context
+                    .construct_type_error("Proxy trap result is missing a non-configurable key"))
+            }
+        }
+    }
+
+    // 20. If extensibleTarget is true, return trapResult.
+    if extensible_target {
+        return Ok(trap_result);
+    }
+
+    // 21. For each element key of targetConfigurableKeys, do
+    for key in target_configurable_keys {
+        // a. If key is not an element of uncheckedResultKeys, throw a TypeError exception.
+        // b. Remove key from uncheckedResultKeys.
+        match unchecked_result_keys.iter().position(|k| *k == key) {
+            Some(position) => {
+                unchecked_result_keys.remove(position);
+            }
+            None => {
+                return Err(context.construct_type_error(
+                    "Proxy trap result is missing a key of a non-extensible target",
+                ))
+            }
+        }
+    }
+
+    // 22. If uncheckedResultKeys is not empty, throw a TypeError exception.
+    if !unchecked_result_keys.is_empty() {
+        return Err(context
+            .construct_type_error("Proxy trap result added a key to a non-extensible target"));
+    }
+
+    // 23. Return trapResult.
+    Ok(trap_result)
+}
+
+/// Calls a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+#[inline]
+pub(crate) fn proxy_exotic_call(
+    obj: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // Only proxies of callable targets have a [[Call]] internal method.
+    if !obj.is_callable() {
+        return Err(context.construct_coded_error(JsErrorCode::NOT_A_FUNCTION, "not a function"));
+    }
+
+    // 1. Let handler be O.[[ProxyHandler]].
+    // 2. If handler is null, throw a TypeError exception.
+    // 3. Assert: Type(handler) is Object.
+    // 4. Let target be O.[[ProxyTarget]].
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 5. Let trap be ? GetMethod(handler, "apply").
+    // 6. If trap is undefined, then
+    let trap = match handler.get_method(context, "apply")? {
+        Some(trap) => trap,
+        // a. Return ? Call(target, thisArgument, argumentsList).
+        None => return target.call(this, args, context),
+    };
+
+    // 7. Let argArray be ! CreateArrayFromList(argumentsList).
+    let arg_array = Array::create_array_from_list(args.to_vec(), context);
+
+    // 8. Return ? Call(trap, handler, « target, thisArgument, argArray »).
+    trap.call(
+        &handler.into(),
+        &[target.into(), this.clone(), arg_array.into()],
+        context,
+    )
+}
+
+/// Constructs an instance of a proxy exotic object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+#[inline]
+pub(crate) fn proxy_exotic_construct(
+    obj: &JsObject,
+    args: &[JsValue],
+    new_target: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // Only proxies of constructor targets have a [[Construct]] internal method.
+    if !obj.is_constructable() {
+        return Err(
+            context.construct_coded_error(JsErrorCode::NOT_A_CONSTRUCTOR, "not a constructor")
+        );
+    }
+
+    // 1. Let handler be O.[[ProxyHandler]].
+    // 2. If handler is null, throw a TypeError exception.
+    // 3. Assert: Type(handler) is Object.
+    // 4. Let target be O.[[ProxyTarget]].
+    // 5. Assert: IsConstructor(target) is true.
+    let (target, handler) = proxy_data(obj, context)?;
+
+    // 6. Let trap be ? GetMethod(handler, "construct").
+    // 7. If trap is undefined, then
+    let trap = match handler.get_method(context, "construct")? {
+        Some(trap) => trap,
+        // a. Return ? Construct(target, argumentsList, newTarget).
+        None => return target.construct(args, new_target, context),
+    };
+
+    // 8. Let argArray be ! CreateArrayFromList(argumentsList).
+    let arg_array = Array::create_array_from_list(args.to_vec(), context);
+
+    // 9. Let newObj be ? Call(trap, handler, « target, argArray, newTarget »).
+    let new_obj = trap.call(
+        &handler.into(),
+        &[target.into(), arg_array.into(), new_target.clone()],
+        context,
+    )?;
+
+    // 10. If Type(newObj) is not Object, throw a TypeError exception.
+    if !new_obj.is_object() {
+        return Err(context.construct_type_error("Proxy trap constructed a non-object"));
+    }
+
+    // 11. Return newObj.
+    Ok(new_obj)
+}
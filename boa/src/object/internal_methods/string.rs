@@ -16,6 +16,8 @@ pub(crate) static STRING_EXOTIC_INTERNAL_METHODS: InternalObjectMethods = Intern
     __get_own_property__: string_exotic_get_own_property,
     __define_own_property__: string_exotic_define_own_property,
     __own_property_keys__: string_exotic_own_property_keys,
+    ordinary_get: false,
+    ordinary_set: false,
     ..ORDINARY_INTERNAL_METHODS
 };
 
@@ -108,14 +110,12 @@ pub(crate) fn string_exotic_own_property_keys(
     // 6. For each own property key P of O such that P is an array index
     // and ! ToIntegerOrInfinity(P) ≥ len, in ascending numeric index order, do
     // a. Add P as the last element of keys.
-    let mut remaining_indices: Vec<_> = obj
-        .properties
-        .index_property_keys()
-        .cloned()
-        .filter(|idx| (*idx as usize) >= len)
-        .collect();
-    remaining_indices.sort_unstable();
-    keys.extend(remaining_indices.into_iter().map(|idx| idx.into()));
+    keys.extend(
+        obj.properties
+            .index_property_keys()
+            .filter(|idx| (*idx as usize) >= len)
+            .map(|idx| idx.into()),
+    );
 
     // 7. For each own property key P of O such that Type(P) is String and P is not
     // an array index, in ascending chronological order of property creation, do
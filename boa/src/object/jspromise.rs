@@ -0,0 +1,143 @@
+//! This module implements the `JsPromise` structure, a handle to a promise object for the
+//! embedders of the engine.
+
+use crate::{
+    builtins::{promise::PromiseState, Promise},
+    gc::{Finalize, Trace},
+    object::JsObject,
+    Context, JsResult, JsValue,
+};
+use std::ops::Deref;
+
+/// A handle to a `Promise` object.
+///
+/// # Examples
+/// ```
+///# use boa::{
+///#     builtins::promise::PromiseState, object::JsPromise, property::Attribute, Context, JsValue,
+///# };
+/// let mut context = Context::new();
+/// let (promise, resolvers) = JsPromise::new_pending(&mut context);
+/// context.register_global_property("promise", promise.clone(), Attribute::all());
+/// context.eval("var result; promise.then(v => result = v * 2);").unwrap();
+///
+/// resolvers.resolve(21, &mut context).unwrap();
+/// context.run_jobs().unwrap();
+/// assert!(matches!(promise.state(), PromiseState::Fulfilled(_)));
+/// assert_eq!(context.eval("result").unwrap(), JsValue::new(42));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsPromise {
+    inner: JsObject,
+}
+
+impl JsPromise {
+    /// Creates a new pending promise, returning it along with the functions settling it.
+    #[inline]
+    pub fn new_pending(context: &mut Context) -> (Self, ResolvingFunctions) {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let capability = Promise::new_promise_capability(&constructor.into(), context)
+            .expect("creating a promise from the intrinsic constructor should never fail");
+        (
+            Self {
+                inner: capability.promise().clone(),
+            },
+            ResolvingFunctions {
+                resolve: capability.resolve().clone(),
+                reject: capability.reject().clone(),
+            },
+        )
+    }
+
+    /// Creates a handle to the promise object, throwing a `TypeError` if the object is not a
+    /// promise.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.is_promise() {
+            Ok(Self { inner: object })
+        } else {
+            Err(context.construct_type_error("object is not a Promise"))
+        }
+    }
+
+    /// Gets the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> PromiseState {
+        self.inner
+            .borrow()
+            .as_promise()
+            .expect("a JsPromise should always be a promise")
+            .state()
+            .clone()
+    }
+}
+
+impl Deref for JsPromise {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl From<JsPromise> for JsObject {
+    #[inline]
+    fn from(promise: JsPromise) -> Self {
+        promise.inner.clone()
+    }
+}
+
+impl From<JsPromise> for JsValue {
+    #[inline]
+    fn from(promise: JsPromise) -> Self {
+        promise.inner.clone().into()
+    }
+}
+
+/// The functions resolving and rejecting a promise, created along with it by
+/// [`JsPromise::new_pending`].
+///
+/// Only the first call to either of them settles the promise, the following calls are ignored.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ResolvingFunctions {
+    resolve: JsObject,
+    reject: JsObject,
+}
+
+impl ResolvingFunctions {
+    /// Resolves the promise with the value, which fulfills it unless the value is a thenable
+    /// whose state the promise then follows.
+    #[inline]
+    pub fn resolve<V>(&self, value: V, context: &mut Context) -> JsResult<()>
+    where
+        V: Into<JsValue>,
+    {
+        self.resolve
+            .call(&JsValue::undefined(), &[value.into()], context)?;
+        Ok(())
+    }
+
+    /// Rejects the promise with the reason.
+    #[inline]
+    pub fn reject<V>(&self, reason: V, context: &mut Context) -> JsResult<()>
+    where
+        V: Into<JsValue>,
+    {
+        self.reject
+            .call(&JsValue::undefined(), &[reason.into()], context)?;
+        Ok(())
+    }
+
+    /// Gets the resolve function, to pass it to a script.
+    #[inline]
+    pub fn resolve_function(&self) -> &JsObject {
+        &self.resolve
+    }
+
+    /// Gets the reject function, to pass it to a script.
+    #[inline]
+    pub fn reject_function(&self) -> &JsObject {
+        &self.reject
+    }
+}
@@ -3,22 +3,38 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{Function, NativeFunction},
+        array_buffer::ArrayBuffer,
+        async_generator::AsyncGenerator,
+        dataview::DataView,
+        finalization_registry::FinalizationRegistry,
+        function::{
+            arguments::{Arguments, ParameterMap},
+            BoundFunction, Captures, Function, NativeFunction,
+        },
+        generator::Generator,
+        iterable::AsyncFromSyncIterator,
+        iterator::IteratorHelper,
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
+        promise::Promise,
+        proxy::Proxy,
         regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
         string::string_iterator::StringIterator,
+        typed_array::IntegerIndexed,
         Date, RegExp,
     },
     context::StandardConstructor,
     gc::{Finalize, Trace},
+    module::ModuleNamespace,
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    teardown::Contents,
     BoaProfiler, Context, JsBigInt, JsString, JsSymbol, JsValue,
 };
 use std::{
     any::Any,
+    cell::Cell,
     fmt::{self, Debug, Display},
     ops::{Deref, DerefMut},
     rc::Rc,
@@ -29,16 +45,33 @@ mod tests;
 
 mod gcobject;
 pub(crate) mod internal_methods;
+mod jspromise;
 mod operations;
 mod property_map;
+mod shape;
 
+use crate::builtins::intl::collator::Collator;
+#[cfg(feature = "intl")]
+use crate::builtins::intl::{
+    date_time_format::DateTimeFormat, display_names::DisplayNames, locale_object::Locale,
+    number_format::NumberFormat,
+};
+#[cfg(feature = "async")]
+use crate::builtins::iterable::StreamIterator;
 use crate::builtins::object::for_in_iterator::ForInIterator;
-pub use gcobject::{JsObject, RecursionLimiter, Ref, RefMut};
+#[cfg(feature = "encoding")]
+use crate::builtins::text_decoder::TextDecoder;
+pub use gcobject::{JsObject, RecursionLimiter, Ref, RefMut, WeakJsObject};
 use internal_methods::InternalObjectMethods;
+pub use jspromise::{JsPromise, ResolvingFunctions};
 pub use property_map::*;
 
 use self::internal_methods::{
-    array::ARRAY_EXOTIC_INTERNAL_METHODS, string::STRING_EXOTIC_INTERNAL_METHODS,
+    arguments::ARGUMENTS_EXOTIC_INTERNAL_METHODS, array::ARRAY_EXOTIC_INTERNAL_METHODS,
+    bound_function::BOUND_FUNCTION_EXOTIC_INTERNAL_METHODS,
+    integer_indexed::INTEGER_INDEXED_EXOTIC_INTERNAL_METHODS,
+    module_namespace::MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS,
+    proxy::PROXY_EXOTIC_INTERNAL_METHODS, string::STRING_EXOTIC_INTERNAL_METHODS,
     ORDINARY_INTERNAL_METHODS,
 };
 
@@ -69,7 +102,7 @@ impl<T: Any + Debug + Trace> NativeObject for T {
 }
 
 /// The internal representation of an JavaScript object.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Trace)]
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
@@ -78,6 +111,98 @@ pub struct Object {
     prototype: JsValue,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The liveness flag shared with the weak references to the object, if any.
+    #[unsafe_ignore_trace]
+    weak_slot: Option<Rc<Cell<bool>>>,
+    /// The values held for the weak collections using the object as a key.
+    ephemerons: Vec<Ephemeron>,
+    /// The private fields, methods and accessors of the object, by their private name.
+    private_elements: Vec<(JsSymbol, PrivateElement)>,
+}
+
+/// The own properties, prototype and extensibility of an object at some point in time.
+#[derive(Debug, Clone)]
+pub(crate) struct ObjectSnapshot {
+    properties: PropertyMap,
+    prototype: JsValue,
+    extensible: bool,
+}
+
+/// A value held by an object on behalf of a weak collection (`WeakMap` or `WeakSet`) it is a key
+/// of.
+///
+/// The value is traced through its key instead of through the collection, so it stays alive
+/// exactly as long as the key does, and the collection does not keep its keys alive.
+#[derive(Debug, Trace, Finalize)]
+struct Ephemeron {
+    collection: WeakJsObject,
+    value: JsValue,
+}
+
+/// A private element of an object, added by the class which declares its private name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-privateelement-specification-type
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PrivateElement {
+    /// A private field, holding a value which can be changed.
+    Field(JsValue),
+    /// A private method, which can not be changed.
+    Method(JsObject),
+    /// A private accessor, with a getter, a setter, or both.
+    Accessor {
+        getter: Option<JsObject>,
+        setter: Option<JsObject>,
+    },
+}
+
+impl PrivateElement {
+    /// Combines the getter or the setter `accessor` into the element, which is the accessor with
+    /// the same private name.
+    pub(crate) fn merge_accessor(&mut self, accessor: &PrivateElement) {
+        if let (
+            Self::Accessor { getter, setter },
+            Self::Accessor {
+                getter: new_getter,
+                setter: new_setter,
+            },
+        ) = (self, accessor)
+        {
+            if new_getter.is_some() {
+                *getter = new_getter.clone();
+            }
+            if new_setter.is_some() {
+                *setter = new_setter.clone();
+            }
+        }
+    }
+
+    /// Queues the values of the element to be emptied by the teardown of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        match self {
+            Self::Field(value) => contents.push_value(value),
+            Self::Method(method) => contents.push_object(method),
+            Self::Accessor { getter, setter } => {
+                if let Some(getter) = getter {
+                    contents.push_object(getter);
+                }
+                if let Some(setter) = setter {
+                    contents.push_object(setter);
+                }
+            }
+        }
+    }
+}
+
+impl Finalize for Object {
+    fn finalize(&self) {
+        // The object is about to be collected, so its weak references can't be upgraded anymore.
+        if let Some(alive) = &self.weak_slot {
+            alive.set(false);
+        }
+    }
 }
 
 /// Defines the kind of an object and its internal methods
@@ -92,14 +217,17 @@ pub struct ObjectData {
 pub enum ObjectKind {
     Array,
     ArrayIterator(ArrayIterator),
+    ArrayBuffer(ArrayBuffer),
     Map(OrderedMap<JsValue>),
     MapIterator(MapIterator),
     RegExp(Box<RegExp>),
     RegExpStringIterator(RegExpStringIterator),
     BigInt(JsBigInt),
     Boolean(bool),
+    DataView(DataView),
     ForInIterator(ForInIterator),
     Function(Function),
+    BoundFunction(BoundFunction),
     Set(OrderedSet<JsValue>),
     SetIterator(SetIterator),
     String(JsString),
@@ -107,9 +235,37 @@ pub enum ObjectKind {
     Number(f64),
     Symbol(JsSymbol),
     Error,
+    Arguments(Arguments),
     Ordinary,
     Date(Date),
+    Promise(Promise),
+    Generator(Generator),
+    AsyncGenerator(AsyncGenerator),
+    AsyncFromSyncIterator(AsyncFromSyncIterator),
+    IteratorHelper(IteratorHelper),
+    #[cfg(feature = "async")]
+    StreamIterator(StreamIterator),
     Global,
+    IntegerIndexed(IntegerIndexed),
+    ModuleNamespace(ModuleNamespace),
+    Proxy(Proxy),
+    WeakMap,
+    WeakSet,
+    WeakRef(WeakJsObject),
+    FinalizationRegistry(FinalizationRegistry),
+    Collator(Box<Collator>),
+    #[cfg(feature = "intl")]
+    NumberFormat(Box<NumberFormat>),
+    #[cfg(feature = "intl")]
+    DateTimeFormat(Box<DateTimeFormat>),
+    #[cfg(feature = "intl")]
+    DisplayNames(Box<DisplayNames>),
+    #[cfg(feature = "intl")]
+    Locale(Box<Locale>),
+    #[cfg(feature = "encoding")]
+    TextEncoder,
+    #[cfg(feature = "encoding")]
+    TextDecoder(Box<TextDecoder>),
     NativeObject(Box<dyn NativeObject>),
 }
 
@@ -130,6 +286,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the `ArrayBuffer` object data
+    pub fn array_buffer(array_buffer: ArrayBuffer) -> Self {
+        Self {
+            kind: ObjectKind::ArrayBuffer(array_buffer),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Map` object data
     pub fn map(map: OrderedMap<JsValue>) -> Self {
         Self {
@@ -178,6 +342,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the `DataView` object data
+    pub fn data_view(data_view: DataView) -> Self {
+        Self {
+            kind: ObjectKind::DataView(data_view),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `ForInIterator` object data
     pub fn for_in_iterator(for_in_iterator: ForInIterator) -> Self {
         Self {
@@ -194,6 +366,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the `BoundFunction` object data and reference its exclusive internal methods
+    pub fn bound_function(bound_function: BoundFunction) -> Self {
+        Self {
+            kind: ObjectKind::BoundFunction(bound_function),
+            internal_methods: &BOUND_FUNCTION_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Set` object data
     pub fn set(set: OrderedSet<JsValue>) -> Self {
         Self {
@@ -250,6 +430,18 @@ impl ObjectData {
         }
     }
 
+    /// Create the `Arguments` object data
+    pub fn arguments(arguments: Arguments) -> Self {
+        Self {
+            internal_methods: if matches!(arguments, Arguments::Mapped(_)) {
+                &ARGUMENTS_EXOTIC_INTERNAL_METHODS
+            } else {
+                &ORDINARY_INTERNAL_METHODS
+            },
+            kind: ObjectKind::Arguments(arguments),
+        }
+    }
+
     /// Create the `Ordinary` object data
     pub fn ordinary() -> Self {
         Self {
@@ -266,6 +458,149 @@ impl ObjectData {
         }
     }
 
+    /// Create the `Promise` object data
+    pub fn promise(promise: Promise) -> Self {
+        Self {
+            kind: ObjectKind::Promise(promise),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakMap` object data
+    pub fn weak_map() -> Self {
+        Self {
+            kind: ObjectKind::WeakMap,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakSet` object data
+    pub fn weak_set() -> Self {
+        Self {
+            kind: ObjectKind::WeakSet,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakRef` object data
+    pub fn weak_ref(target: WeakJsObject) -> Self {
+        Self {
+            kind: ObjectKind::WeakRef(target),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `FinalizationRegistry` object data
+    pub fn finalization_registry(registry: FinalizationRegistry) -> Self {
+        Self {
+            kind: ObjectKind::FinalizationRegistry(registry),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Intl.Collator` object data
+    pub fn collator(collator: Box<Collator>) -> Self {
+        Self {
+            kind: ObjectKind::Collator(collator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Intl.NumberFormat` object data
+    #[cfg(feature = "intl")]
+    pub fn number_format(number_format: Box<NumberFormat>) -> Self {
+        Self {
+            kind: ObjectKind::NumberFormat(number_format),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Intl.DateTimeFormat` object data
+    #[cfg(feature = "intl")]
+    pub fn date_time_format(date_time_format: Box<DateTimeFormat>) -> Self {
+        Self {
+            kind: ObjectKind::DateTimeFormat(date_time_format),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Intl.DisplayNames` object data
+    #[cfg(feature = "intl")]
+    pub fn display_names(display_names: Box<DisplayNames>) -> Self {
+        Self {
+            kind: ObjectKind::DisplayNames(display_names),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Intl.Locale` object data
+    #[cfg(feature = "intl")]
+    pub fn locale(locale: Box<Locale>) -> Self {
+        Self {
+            kind: ObjectKind::Locale(locale),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `TextEncoder` object data
+    #[cfg(feature = "encoding")]
+    pub fn text_encoder() -> Self {
+        Self {
+            kind: ObjectKind::TextEncoder,
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `TextDecoder` object data
+    #[cfg(feature = "encoding")]
+    pub fn text_decoder(text_decoder: Box<TextDecoder>) -> Self {
+        Self {
+            kind: ObjectKind::TextDecoder(text_decoder),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Generator` object data
+    pub fn generator(generator: Generator) -> Self {
+        Self {
+            kind: ObjectKind::Generator(generator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `AsyncGenerator` object data
+    pub fn async_generator(async_generator: AsyncGenerator) -> Self {
+        Self {
+            kind: ObjectKind::AsyncGenerator(async_generator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `AsyncFromSyncIterator` object data
+    pub fn async_from_sync_iterator(async_from_sync_iterator: AsyncFromSyncIterator) -> Self {
+        Self {
+            kind: ObjectKind::AsyncFromSyncIterator(async_from_sync_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `IteratorHelper` object data
+    pub fn iterator_helper(iterator_helper: IteratorHelper) -> Self {
+        Self {
+            kind: ObjectKind::IteratorHelper(iterator_helper),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `StreamIterator` object data
+    #[cfg(feature = "async")]
+    pub fn stream_iterator(stream_iterator: StreamIterator) -> Self {
+        Self {
+            kind: ObjectKind::StreamIterator(stream_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Global` object data
     pub fn global() -> Self {
         Self {
@@ -274,6 +609,30 @@ impl ObjectData {
         }
     }
 
+    /// Create the `IntegerIndexed` object data and reference its exclusive internal methods
+    pub fn integer_indexed(integer_indexed: IntegerIndexed) -> Self {
+        Self {
+            kind: ObjectKind::IntegerIndexed(integer_indexed),
+            internal_methods: &INTEGER_INDEXED_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `ModuleNamespace` object data and reference its exclusive internal methods
+    pub fn module_namespace(namespace: ModuleNamespace) -> Self {
+        Self {
+            kind: ObjectKind::ModuleNamespace(namespace),
+            internal_methods: &MODULE_NAMESPACE_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Proxy` object data and reference its exclusive internal methods
+    pub fn proxy(proxy: Proxy) -> Self {
+        Self {
+            kind: ObjectKind::Proxy(proxy),
+            internal_methods: &PROXY_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `NativeObject` object data
     pub fn native_object(native_object: Box<dyn NativeObject>) -> Self {
         Self {
@@ -291,8 +650,10 @@ impl Display for ObjectKind {
             match self {
                 Self::Array => "Array",
                 Self::ArrayIterator(_) => "ArrayIterator",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
                 Self::ForInIterator(_) => "ForInIterator",
                 Self::Function(_) => "Function",
+                Self::BoundFunction(_) => "BoundFunction",
                 Self::RegExp(_) => "RegExp",
                 Self::RegExpStringIterator(_) => "RegExpStringIterator",
                 Self::Map(_) => "Map",
@@ -303,12 +664,41 @@ impl Display for ObjectKind {
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
                 Self::Error => "Error",
+                Self::Arguments(_) => "Arguments",
                 Self::Ordinary => "Ordinary",
                 Self::Boolean(_) => "Boolean",
                 Self::Number(_) => "Number",
                 Self::BigInt(_) => "BigInt",
+                Self::DataView(_) => "DataView",
                 Self::Date(_) => "Date",
+                Self::Promise(_) => "Promise",
+                Self::Generator(_) => "Generator",
+                Self::AsyncGenerator(_) => "AsyncGenerator",
+                Self::AsyncFromSyncIterator(_) => "AsyncFromSyncIterator",
+                Self::IteratorHelper(_) => "IteratorHelper",
+                #[cfg(feature = "async")]
+                Self::StreamIterator(_) => "StreamIterator",
                 Self::Global => "Global",
+                Self::IntegerIndexed(_) => "TypedArray",
+                Self::ModuleNamespace(_) => "ModuleNamespace",
+                Self::Proxy(_) => "Proxy",
+                Self::WeakMap => "WeakMap",
+                Self::WeakSet => "WeakSet",
+                Self::WeakRef(_) => "WeakRef",
+                Self::FinalizationRegistry(_) => "FinalizationRegistry",
+                Self::Collator(_) => "Collator",
+                #[cfg(feature = "intl")]
+                Self::NumberFormat(_) => "NumberFormat",
+                #[cfg(feature = "intl")]
+                Self::DateTimeFormat(_) => "DateTimeFormat",
+                #[cfg(feature = "intl")]
+                Self::DisplayNames(_) => "DisplayNames",
+                #[cfg(feature = "intl")]
+                Self::Locale(_) => "Locale",
+                #[cfg(feature = "encoding")]
+                Self::TextEncoder => "TextEncoder",
+                #[cfg(feature = "encoding")]
+                Self::TextDecoder(_) => "TextDecoder",
                 Self::NativeObject(_) => "NativeObject",
             }
         )
@@ -333,6 +723,9 @@ impl Default for Object {
             properties: PropertyMap::default(),
             prototype: JsValue::null(),
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 }
@@ -353,6 +746,9 @@ impl Object {
             properties: PropertyMap::default(),
             prototype,
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 
@@ -378,6 +774,9 @@ impl Object {
             properties: PropertyMap::default(),
             prototype: JsValue::null(),
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 
@@ -389,6 +788,9 @@ impl Object {
             properties: PropertyMap::default(),
             prototype: JsValue::null(),
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 
@@ -403,6 +805,9 @@ impl Object {
             properties: PropertyMap::default(),
             prototype: JsValue::null(),
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 
@@ -414,6 +819,9 @@ impl Object {
             properties: PropertyMap::default(),
             prototype: JsValue::null(),
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 
@@ -428,6 +836,9 @@ impl Object {
             properties: PropertyMap::default(),
             prototype: JsValue::null(),
             extensible: true,
+            weak_slot: None,
+            ephemerons: Vec::new(),
+            private_elements: Vec::new(),
         }
     }
 
@@ -443,16 +854,18 @@ impl Object {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
-    // todo: functions are not the only objects that are callable.
-    // todo: e.g. https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
     pub fn is_callable(&self) -> bool {
-        matches!(
-            self.data,
+        match self.data {
             ObjectData {
-                kind: ObjectKind::Function(_),
+                kind: ObjectKind::Function(_) | ObjectKind::BoundFunction(_),
                 ..
-            }
-        )
+            } => true,
+            ObjectData {
+                kind: ObjectKind::Proxy(ref proxy),
+                ..
+            } => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     /// It determines if Object is a function object with a `[[Construct]]` internal method.
@@ -462,10 +875,22 @@ impl Object {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     #[inline]
-    // todo: functions are not the only objects that are constructable.
-    // todo: e.g. https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
     pub fn is_constructable(&self) -> bool {
-        matches!(self.data, ObjectData{kind: ObjectKind::Function(ref f), ..} if f.is_constructable())
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Function(ref function),
+                ..
+            } => function.is_constructable(),
+            ObjectData {
+                kind: ObjectKind::BoundFunction(ref bound_function),
+                ..
+            } => bound_function.is_constructable(),
+            ObjectData {
+                kind: ObjectKind::Proxy(ref proxy),
+                ..
+            } => proxy.is_constructable(),
+            _ => false,
+        }
     }
 
     /// Checks if it an `Array` object.
@@ -681,13 +1106,13 @@ impl Object {
         }
     }
 
-    /// Checks if it a `Function` object.
+    /// Checks if it a `Function` object, which includes bound functions.
     #[inline]
     pub fn is_function(&self) -> bool {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::Function(_),
+                kind: ObjectKind::Function(_) | ObjectKind::BoundFunction(_),
                 ..
             }
         )
@@ -704,6 +1129,28 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Function(ref mut function),
+                ..
+            } => Some(function),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_bound_function(&self) -> Option<&BoundFunction> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::BoundFunction(ref bound_function),
+                ..
+            } => Some(bound_function),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -739,6 +1186,42 @@ impl Object {
         )
     }
 
+    /// Checks if it an arguments object.
+    #[inline]
+    pub fn is_arguments(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Arguments(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the parameter map of a mapped arguments object.
+    #[inline]
+    pub(crate) fn as_parameter_map(&self) -> Option<&ParameterMap> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Arguments(Arguments::Mapped(ref map)),
+                ..
+            } => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable parameter map of a mapped arguments object.
+    #[inline]
+    pub(crate) fn as_parameter_map_mut(&mut self) -> Option<&mut ParameterMap> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Arguments(Arguments::Mapped(ref mut map)),
+                ..
+            } => Some(map),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_error(&self) -> Option<()> {
         match self.data {
@@ -840,70 +1323,693 @@ impl Object {
         }
     }
 
-    /// Checks if it a `RegExp` object.
+    /// Checks if it a `Promise` object.
     #[inline]
-    pub fn is_regexp(&self) -> bool {
+    pub fn is_promise(&self) -> bool {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::RegExp(_),
+                kind: ObjectKind::Promise(_),
                 ..
             }
         )
     }
 
     #[inline]
-    pub fn as_regexp(&self) -> Option<&RegExp> {
+    pub fn as_promise(&self) -> Option<&Promise> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::RegExp(ref regexp),
+                kind: ObjectKind::Promise(ref promise),
                 ..
-            } => Some(regexp),
+            } => Some(promise),
             _ => None,
         }
     }
 
-    /// Checks if it an ordinary object.
     #[inline]
-    pub fn is_ordinary(&self) -> bool {
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an `ArrayBuffer` or a `SharedArrayBuffer` object.
+    #[inline]
+    pub fn is_array_buffer(&self) -> bool {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::Ordinary,
+                kind: ObjectKind::ArrayBuffer(_),
                 ..
             }
         )
     }
 
+    /// Checks if it an `ArrayBuffer` object, which is not shared.
     #[inline]
-    pub fn prototype_instance(&self) -> &JsValue {
-        &self.prototype
+    pub fn is_unshared_array_buffer(&self) -> bool {
+        self.as_array_buffer()
+            .map_or(false, |buffer| !buffer.is_shared_array_buffer())
     }
 
-    /// Sets the prototype instance of the object.
-    ///
-    /// [More information][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-invariants-of-the-essential-internal-methods
+    /// Checks if it a `SharedArrayBuffer` object.
     #[inline]
-    #[track_caller]
-    pub fn set_prototype_instance(&mut self, prototype: JsValue) -> bool {
-        assert!(prototype.is_null() || prototype.is_object());
-        if self.extensible {
-            self.prototype = prototype;
-            true
-        } else {
-            // If target is non-extensible, [[SetPrototypeOf]] must return false
-            // unless V is the SameValue as the target's observed [[GetPrototypeOf]] value.
-            JsValue::same_value(&prototype, &self.prototype)
+    pub fn is_shared_array_buffer(&self) -> bool {
+        self.as_array_buffer()
+            .map_or(false, ArrayBuffer::is_shared_array_buffer)
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::ArrayBuffer(ref array_buffer),
+                ..
+            } => Some(array_buffer),
+            _ => None,
         }
     }
 
-    /// Similar to `Value::new_object`, but you can pass a prototype to create from, plus a kind
     #[inline]
-    pub fn with_prototype(proto: JsValue, data: ObjectData) -> Object {
-        let mut object = Object::new();
-        object.data = data;
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBuffer> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::ArrayBuffer(array_buffer),
+                ..
+            } => Some(array_buffer),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `DataView` object.
+    #[inline]
+    pub fn is_data_view(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::DataView(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_data_view(&self) -> Option<&DataView> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::DataView(ref data_view),
+                ..
+            } => Some(data_view),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a typed array object.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::IntegerIndexed(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&IntegerIndexed> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::IntegerIndexed(ref integer_indexed),
+                ..
+            } => Some(integer_indexed),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_typed_array_mut(&mut self) -> Option<&mut IntegerIndexed> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::IntegerIndexed(integer_indexed),
+                ..
+            } => Some(integer_indexed),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an `Intl.Collator` object.
+    #[inline]
+    pub fn is_collator(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Collator(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_collator(&self) -> Option<&Collator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Collator(ref collator),
+                ..
+            } => Some(collator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_collator_mut(&mut self) -> Option<&mut Collator> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::Collator(collator),
+                ..
+            } => Some(collator),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an `Intl.NumberFormat` object.
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn is_number_format(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::NumberFormat(_),
+                ..
+            }
+        )
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_number_format(&self) -> Option<&NumberFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::NumberFormat(ref number_format),
+                ..
+            } => Some(number_format),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::NumberFormat(number_format),
+                ..
+            } => Some(number_format),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an `Intl.DateTimeFormat` object.
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn is_date_time_format(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::DateTimeFormat(_),
+                ..
+            }
+        )
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_date_time_format(&self) -> Option<&DateTimeFormat> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::DateTimeFormat(ref date_time_format),
+                ..
+            } => Some(date_time_format),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_date_time_format_mut(&mut self) -> Option<&mut DateTimeFormat> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::DateTimeFormat(date_time_format),
+                ..
+            } => Some(date_time_format),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_display_names(&self) -> Option<&DisplayNames> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::DisplayNames(ref display_names),
+                ..
+            } => Some(display_names),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_locale(&self) -> Option<&Locale> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Locale(ref locale),
+                ..
+            } => Some(locale),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `TextEncoder` object.
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn is_text_encoder(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::TextEncoder,
+                ..
+            }
+        )
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn as_text_decoder(&self) -> Option<&TextDecoder> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::TextDecoder(ref text_decoder),
+                ..
+            } => Some(text_decoder),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "encoding")]
+    #[inline]
+    pub fn as_text_decoder_mut(&mut self) -> Option<&mut TextDecoder> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::TextDecoder(ref mut text_decoder),
+                ..
+            } => Some(text_decoder),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `WeakMap` object.
+    #[inline]
+    pub fn is_weak_map(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::WeakMap,
+                ..
+            }
+        )
+    }
+
+    /// Checks if it a `WeakSet` object.
+    #[inline]
+    pub fn is_weak_set(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::WeakSet,
+                ..
+            }
+        )
+    }
+
+    /// Gets the value the object holds as a key of the weak collection.
+    #[inline]
+    pub(crate) fn ephemeron(&self, collection: &WeakJsObject) -> Option<&JsValue> {
+        self.ephemerons
+            .iter()
+            .find(|e| WeakJsObject::ptr_eq(&e.collection, collection))
+            .map(|e| &e.value)
+    }
+
+    /// Gets the private element of the object with the private name `name`.
+    #[inline]
+    pub(crate) fn private_element(&self, name: &JsSymbol) -> Option<&PrivateElement> {
+        self.private_elements
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, element)| element)
+    }
+
+    /// Gets the private element of the object with the private name `name` mutably.
+    #[inline]
+    pub(crate) fn private_element_mut(&mut self, name: &JsSymbol) -> Option<&mut PrivateElement> {
+        self.private_elements
+            .iter_mut()
+            .find(|(key, _)| key == name)
+            .map(|(_, element)| element)
+    }
+
+    /// Adds a private element to the object, which must not already have one named `name`.
+    #[inline]
+    pub(crate) fn push_private_element(&mut self, name: JsSymbol, element: PrivateElement) {
+        debug_assert!(self.private_element(&name).is_none());
+        self.private_elements.push((name, element));
+    }
+
+    /// Sets the value the object holds as a key of the weak collection.
+    ///
+    /// The values of the collections that have been collected are dropped.
+    pub(crate) fn set_ephemeron(&mut self, collection: WeakJsObject, value: JsValue) {
+        self.ephemerons.retain(|e| e.collection.is_alive());
+        match self
+            .ephemerons
+            .iter_mut()
+            .find(|e| WeakJsObject::ptr_eq(&e.collection, &collection))
+        {
+            Some(ephemeron) => ephemeron.value = value,
+            None => self.ephemerons.push(Ephemeron { collection, value }),
+        }
+    }
+
+    /// Removes the value the object holds as a key of the weak collection, returning `true` if
+    /// there was one.
+    #[inline]
+    pub(crate) fn remove_ephemeron(&mut self, collection: &WeakJsObject) -> bool {
+        let len = self.ephemerons.len();
+        self.ephemerons
+            .retain(|e| !WeakJsObject::ptr_eq(&e.collection, collection));
+        self.ephemerons.len() != len
+    }
+
+    /// Empties the object, turning it into an ordinary object without properties, prototype nor
+    /// internal data, and returns the values and the environments it held.
+    ///
+    /// This is used by [`Teardown`][crate::teardown::Teardown] to cut the edges of the object
+    /// graph of a context, so that their contents are dropped piece by piece.
+    pub(crate) fn take_contents(&mut self) -> Contents {
+        let mut contents = Contents::default();
+        let properties = std::mem::take(&mut self.properties);
+        for descriptor in properties.values() {
+            contents.extend_values(descriptor.value());
+            contents.extend_values(descriptor.get());
+            contents.extend_values(descriptor.set());
+        }
+        contents.push_value(&std::mem::replace(&mut self.prototype, JsValue::null()));
+        for ephemeron in self.ephemerons.drain(..) {
+            contents.push_value(&ephemeron.value);
+        }
+        for (_, element) in self.private_elements.drain(..) {
+            element.collect_contents(&mut contents);
+        }
+
+        let data = std::mem::replace(&mut self.data, ObjectData::ordinary());
+        match &data.kind {
+            ObjectKind::ArrayIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::Map(map) => {
+                for (key, value) in map.iter() {
+                    contents.push_value(key);
+                    contents.push_value(value);
+                }
+            }
+            ObjectKind::MapIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::RegExpStringIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::DataView(view) => contents.push_object(view.viewed_array_buffer()),
+            ObjectKind::ForInIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::Function(function) => function.collect_contents(&mut contents),
+            ObjectKind::BoundFunction(function) => {
+                contents.push_object(function.target_function());
+                contents.push_value(function.this());
+                contents.extend_values(function.args());
+            }
+            ObjectKind::Set(set) => contents.extend_values(set.iter()),
+            ObjectKind::SetIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::Arguments(Arguments::Mapped(map)) => {
+                contents.push_environment(map.environment());
+            }
+            ObjectKind::Promise(promise) => promise.collect_contents(&mut contents),
+            ObjectKind::Generator(generator) => generator.collect_contents(&mut contents),
+            ObjectKind::AsyncGenerator(generator) => generator.collect_contents(&mut contents),
+            ObjectKind::AsyncFromSyncIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::IteratorHelper(helper) => helper.collect_contents(&mut contents),
+            #[cfg(feature = "async")]
+            ObjectKind::StreamIterator(iterator) => iterator.collect_contents(&mut contents),
+            ObjectKind::IntegerIndexed(typed_array) => {
+                if let Some(buffer) = typed_array.viewed_array_buffer() {
+                    contents.push_object(buffer);
+                }
+            }
+            ObjectKind::ModuleNamespace(namespace) => namespace.collect_contents(&mut contents),
+            ObjectKind::Proxy(proxy) => proxy.collect_contents(&mut contents),
+            ObjectKind::FinalizationRegistry(registry) => registry.collect_contents(&mut contents),
+            _ => {}
+        }
+        contents
+    }
+
+    #[inline]
+    pub fn as_weak_ref(&self) -> Option<&WeakJsObject> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakRef(ref target),
+                ..
+            } => Some(target),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(ref registry),
+                ..
+            } => Some(registry),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(registry),
+                ..
+            } => Some(registry),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator(&self) -> Option<&Generator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Generator(ref generator),
+                ..
+            } => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::Generator(generator),
+                ..
+            } => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_async_generator(&self) -> Option<&AsyncGenerator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::AsyncGenerator(ref async_generator),
+                ..
+            } => Some(async_generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_async_generator_mut(&mut self) -> Option<&mut AsyncGenerator> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::AsyncGenerator(async_generator),
+                ..
+            } => Some(async_generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_async_from_sync_iterator(&self) -> Option<&AsyncFromSyncIterator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::AsyncFromSyncIterator(ref iterator),
+                ..
+            } => Some(iterator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_iterator_helper(&self) -> Option<&IteratorHelper> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::IteratorHelper(ref iterator),
+                ..
+            } => Some(iterator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_iterator_helper_mut(&mut self) -> Option<&mut IteratorHelper> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::IteratorHelper(ref mut iterator),
+                ..
+            } => Some(iterator),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn as_stream_iterator(&self) -> Option<&StreamIterator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::StreamIterator(ref iterator),
+                ..
+            } => Some(iterator),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn as_stream_iterator_mut(&mut self) -> Option<&mut StreamIterator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::StreamIterator(ref mut iterator),
+                ..
+            } => Some(iterator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_module_namespace(&self) -> Option<&ModuleNamespace> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::ModuleNamespace(ref namespace),
+                ..
+            } => Some(namespace),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Proxy(ref proxy),
+                ..
+            } => Some(proxy),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_proxy_mut(&mut self) -> Option<&mut Proxy> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Proxy(ref mut proxy),
+                ..
+            } => Some(proxy),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `RegExp` object.
+    #[inline]
+    pub fn is_regexp(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::RegExp(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_regexp(&self) -> Option<&RegExp> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::RegExp(ref regexp),
+                ..
+            } => Some(regexp),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an ordinary object.
+    #[inline]
+    pub fn is_ordinary(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Ordinary,
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn prototype_instance(&self) -> &JsValue {
+        &self.prototype
+    }
+
+    /// Sets the prototype instance of the object.
+    ///
+    /// [More information][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-invariants-of-the-essential-internal-methods
+    #[inline]
+    #[track_caller]
+    pub fn set_prototype_instance(&mut self, prototype: JsValue) -> bool {
+        assert!(prototype.is_null() || prototype.is_object());
+        if self.extensible {
+            self.prototype = prototype;
+            true
+        } else {
+            // If target is non-extensible, [[SetPrototypeOf]] must return false
+            // unless V is the SameValue as the target's observed [[GetPrototypeOf]] value.
+            JsValue::same_value(&prototype, &self.prototype)
+        }
+    }
+
+    /// Similar to `Value::new_object`, but you can pass a prototype to create from, plus a kind
+    #[inline]
+    pub fn with_prototype(proto: JsValue, data: ObjectData) -> Object {
+        let mut object = Object::new();
+        object.data = data;
         object.set_prototype_instance(proto);
         object
     }
@@ -983,6 +2089,46 @@ impl Object {
         &self.properties
     }
 
+    #[inline]
+    pub(crate) fn properties_mut(&mut self) -> &mut PropertyMap {
+        &mut self.properties
+    }
+
+    /// Checks if the `[[Get]]` of the object reads its own properties like the ordinary one,
+    /// so an own data property can be read from the property map directly.
+    #[inline]
+    pub(crate) fn has_ordinary_get(&self) -> bool {
+        self.data.internal_methods.ordinary_get
+    }
+
+    /// Checks if the `[[Set]]` of the object writes its own properties like the ordinary one,
+    /// so an own writable data property can be written in the property map directly.
+    #[inline]
+    pub(crate) fn has_ordinary_set(&self) -> bool {
+        self.data.internal_methods.ordinary_set
+    }
+
+    /// Records the own properties, prototype and extensibility of the object.
+    ///
+    /// The internal data of the object is not part of the snapshot.
+    #[inline]
+    pub(crate) fn snapshot(&self) -> ObjectSnapshot {
+        ObjectSnapshot {
+            properties: self.properties.clone(),
+            prototype: self.prototype.clone(),
+            extensible: self.extensible,
+        }
+    }
+
+    /// Restores the own properties, prototype and extensibility recorded by
+    /// [`snapshot`](#method.snapshot), including when the object was made non-extensible since.
+    #[inline]
+    pub(crate) fn restore(&mut self, snapshot: &ObjectSnapshot) {
+        self.properties = snapshot.properties.clone();
+        self.prototype = snapshot.prototype.clone();
+        self.extensible = snapshot.extensible;
+    }
+
     /// Helper function for property insertion.
     #[inline]
     pub(crate) fn insert<K, P>(&mut self, key: K, property: P) -> Option<PropertyDescriptor>
@@ -1105,16 +2251,47 @@ impl<'context> FunctionBuilder<'context> {
     }
 
     /// Create a new `FunctionBuilder` for creating a closure function.
+    ///
+    /// The closure must not capture garbage collected values, since it is not traced by the
+    /// garbage collector. Use [`FunctionBuilder::closure_with_captures`] to capture them.
     #[inline]
     pub fn closure<F>(context: &'context mut Context, function: F) -> Self
     where
         F: Fn(&JsValue, &[JsValue], &mut Context) -> Result<JsValue, JsValue> + 'static,
+    {
+        Self {
+            context,
+            function: Some(Function::Closure {
+                function: Rc::new(move |this, args, _, context| function(this, args, context)),
+                constructable: false,
+                captures: Captures::default(),
+            }),
+            name: JsString::default(),
+            length: 0,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` for creating a closure function that captures garbage
+    /// collected values.
+    ///
+    /// The captured values are passed to every call of the closure, and are traced as part of the
+    /// function object.
+    #[inline]
+    pub fn closure_with_captures<F, C>(
+        context: &'context mut Context,
+        function: F,
+        captures: C,
+    ) -> Self
+    where
+        F: Fn(&JsValue, &[JsValue], &Captures, &mut Context) -> Result<JsValue, JsValue> + 'static,
+        C: NativeObject,
     {
         Self {
             context,
             function: Some(Function::Closure {
                 function: Rc::new(function),
                 constructable: false,
+                captures: Captures::new(captures),
             }),
             name: JsString::default(),
             length: 0,
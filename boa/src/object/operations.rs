@@ -1,12 +1,16 @@
 use crate::{
-    builtins::Array,
+    builtins::{
+        function::{ClassFieldDefinition, ClassFieldName},
+        Array,
+    },
+    error::JsErrorCode,
     property::{PropertyDescriptor, PropertyKey, PropertyNameKind},
     symbol::WellKnownSymbols,
     value::Type,
-    Context, JsResult, JsValue,
+    Context, JsResult, JsSymbol, JsValue,
 };
 
-use super::JsObject;
+use super::{JsObject, PrivateElement};
 
 impl JsObject {
     /// Get property from object or throw.
@@ -52,9 +56,10 @@ impl JsObject {
         let success = self.__set__(key.clone(), value.into(), self.clone().into(), context)?;
         // 5. If success is false and Throw is true, throw a TypeError exception.
         if !success && throw {
-            return Err(
-                context.construct_type_error(format!("cannot set non-writable property: {}", key))
-            );
+            return Err(context.construct_coded_error(
+                JsErrorCode::NON_WRITABLE_PROPERTY,
+                format!("cannot set non-writable property: {}", key),
+            ));
         }
         // 6. Return success.
         Ok(success)
@@ -113,7 +118,10 @@ impl JsObject {
         let success = self.create_data_property(key.clone(), value, context)?;
         // 4. If success is false, throw a TypeError exception.
         if !success {
-            return Err(context.construct_type_error(format!("cannot redefine property: {}", key)));
+            return Err(context.construct_coded_error(
+                JsErrorCode::REDEFINE_PROPERTY,
+                format!("cannot redefine property: {}", key),
+            ));
         }
         // 5. Return success.
         Ok(success)
@@ -143,7 +151,10 @@ impl JsObject {
         let success = self.__define_own_property__(key.clone(), desc.into(), context)?;
         // 4. If success is false, throw a TypeError exception.
         if !success {
-            return Err(context.construct_type_error(format!("cannot redefine property: {}", key)));
+            return Err(context.construct_coded_error(
+                JsErrorCode::REDEFINE_PROPERTY,
+                format!("cannot redefine property: {}", key),
+            ));
         }
         // 5. Return success.
         Ok(success)
@@ -171,7 +182,10 @@ impl JsObject {
         let success = self.__delete__(&key, context)?;
         // 4. If success is false, throw a TypeError exception.
         if !success {
-            return Err(context.construct_type_error(format!("cannot delete property: {}", key)));
+            return Err(context.construct_coded_error(
+                JsErrorCode::DELETE_PROPERTY,
+                format!("cannot delete property: {}", key),
+            ));
         }
         // 5. Return success.
         Ok(success)
@@ -260,7 +274,8 @@ impl JsObject {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        self.call_construct(this, args, context, false)
+        // 1. Return ? F.[[Call]](V, argumentsList).
+        self.__call__(this, args, context)
     }
 
     /// Construct an instance of this object with the specified arguments.
@@ -277,10 +292,60 @@ impl JsObject {
         new_target: &JsValue,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        self.call_construct(new_target, args, context, true)
+        // 1. Return ? F.[[Construct]](argumentsList, newTarget).
+        self.__construct__(args, new_target, context)
     }
 
-    // todo: SetIntegrityLevel
+    /// `7.3.15 SetIntegrityLevel ( O, frozen )`
+    ///
+    /// Freezes the object, making all of its own properties non-configurable and all of its
+    /// own data properties non-writable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub(crate) fn freeze(&self, context: &mut Context) -> JsResult<bool> {
+        // 1. Assert: Type(O) is Object.
+        // 2. Assert: level is either sealed or frozen.
+        // 3. Let status be ? O.[[PreventExtensions]]().
+        let status = self.__prevent_extensions__(context)?;
+        // 4. If status is false, return false.
+        if !status {
+            return Ok(false);
+        }
+
+        // 5. Let keys be ? O.[[OwnPropertyKeys]]().
+        let keys = self.__own_property_keys__(context)?;
+
+        // 6. If level is sealed, then (never the case here)
+        // 7. Else,
+        //     a. Assert: level is frozen.
+        //     b. For each element k of keys, do
+        for k in keys {
+            // i. Let currentDesc be ? O.[[GetOwnProperty]](k).
+            // ii. If currentDesc is not undefined, then
+            if let Some(current_desc) = self.__get_own_property__(&k, context)? {
+                // 1. If IsAccessorDescriptor(currentDesc) is true, then
+                //     a. Let desc be the PropertyDescriptor { [[Configurable]]: false }.
+                // 2. Else,
+                //     a. Let desc be the PropertyDescriptor { [[Configurable]]: false, [[Writable]]: false }.
+                let desc = if current_desc.is_accessor_descriptor() {
+                    PropertyDescriptor::builder().configurable(false).build()
+                } else {
+                    PropertyDescriptor::builder()
+                        .configurable(false)
+                        .writable(false)
+                        .build()
+                };
+                // 3. Perform ? DefinePropertyOrThrow(O, k, desc).
+                self.define_property_or_throw(k, desc, context)?;
+            }
+        }
+
+        // 8. Return true.
+        Ok(true)
+    }
 
     // todo: TestIntegrityLevel
 
@@ -317,7 +382,10 @@ impl JsObject {
 
         // 4. If Type(C) is not Object, throw a TypeError exception.
         if !c.is_object() {
-            return context.throw_type_error("property 'constructor' is not an object");
+            return context.throw_coded_error(
+                JsErrorCode::CONSTRUCTOR_NOT_AN_OBJECT,
+                "property 'constructor' is not an object",
+            );
         }
 
         // 5. Let S be ? Get(C, @@species).
@@ -334,10 +402,16 @@ impl JsObject {
             if obj.is_constructable() {
                 Ok(s)
             } else {
-                context.throw_type_error("property 'constructor' is not a constructor")
+                context.throw_coded_error(
+                    JsErrorCode::CONSTRUCTOR_NOT_A_CONSTRUCTOR,
+                    "property 'constructor' is not a constructor",
+                )
             }
         } else {
-            context.throw_type_error("property 'constructor' is not an object")
+            context.throw_coded_error(
+                JsErrorCode::CONSTRUCTOR_NOT_AN_OBJECT,
+                "property 'constructor' is not an object",
+            )
         }
     }
 
@@ -363,7 +437,7 @@ impl JsObject {
             // a. If Type(key) is String, then
             let key_str = match &key {
                 PropertyKey::String(s) => Some(s.clone()),
-                PropertyKey::Index(i) => Some(i.to_string().into()),
+                PropertyKey::Index(i) => Some(context.number_strings.index_to_string(*i)),
                 _ => None,
             };
 
@@ -407,19 +481,235 @@ impl JsObject {
 
     // todo: CopyDataProperties
 
-    // todo: PrivateElementFind
+    /// Adds the private field `name` to the object, with the value `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatefieldadd
+    pub(crate) fn private_field_add(
+        &self,
+        name: &JsSymbol,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let entry be ! PrivateElementFind(O, P).
+        // 2. If entry is not empty, throw a TypeError exception.
+        if self.borrow().private_element(name).is_some() {
+            return Err(context.construct_type_error(format!(
+                "cannot initialize {} twice on the same object",
+                name.description().unwrap_or_default()
+            )));
+        }
 
-    // todo: PrivateFieldAdd
+        // 3. Append PrivateElement { [[Key]]: P, [[Kind]]: field, [[Value]]: value } to
+        //    O.[[PrivateElements]].
+        self.borrow_mut()
+            .push_private_element(name.clone(), PrivateElement::Field(value));
+        Ok(())
+    }
 
-    // todo: PrivateMethodOrAccessorAdd
+    /// Adds the private method or accessor `method` to the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatemethodoraccessoradd
+    pub(crate) fn private_method_or_accessor_add(
+        &self,
+        name: &JsSymbol,
+        method: PrivateElement,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: method.[[Kind]] is either method or accessor.
+        debug_assert!(!matches!(method, PrivateElement::Field(_)));
+
+        // 2. Let entry be ! PrivateElementFind(O, method.[[Key]]).
+        // 3. If entry is not empty, throw a TypeError exception.
+        if self.borrow().private_element(name).is_some() {
+            return Err(context.construct_type_error(format!(
+                "cannot initialize {} twice on the same object",
+                name.description().unwrap_or_default()
+            )));
+        }
 
-    // todo: PrivateGet
+        // 4. Append method to O.[[PrivateElements]].
+        self.borrow_mut().push_private_element(name.clone(), method);
+        Ok(())
+    }
 
-    // todo: PrivateSet
+    /// Gets the value of the private element `name` of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateget
+    pub(crate) fn private_get(&self, name: &JsSymbol, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let entry be ! PrivateElementFind(O, P).
+        // The element is cloned, so that the getter can use the object.
+        let entry = self.borrow().private_element(name).cloned();
+        match entry.as_ref() {
+            // 2. If entry is empty, throw a TypeError exception.
+            None => context.throw_type_error(format!(
+                "cannot read private member {} from an object whose class did not declare it",
+                name.description().unwrap_or_default()
+            )),
+            // 3. If entry.[[Kind]] is either field or method, then
+            //     a. Return entry.[[Value]].
+            Some(PrivateElement::Field(value)) => Ok(value.clone()),
+            Some(PrivateElement::Method(method)) => Ok(method.clone().into()),
+            // 4. Assert: entry.[[Kind]] is accessor.
+            // 5. Let getter be entry.[[Get]].
+            // 6. If getter is undefined, throw a TypeError exception.
+            Some(PrivateElement::Accessor { getter: None, .. }) => {
+                context.throw_type_error(format!(
+                    "{} was defined without a getter",
+                    name.description().unwrap_or_default()
+                ))
+            }
+            // 7. Return ? Call(getter, O).
+            Some(PrivateElement::Accessor {
+                getter: Some(getter),
+                ..
+            }) => getter.call(&self.clone().into(), &[], context),
+        }
+    }
 
-    // todo: DefineField
+    /// Sets the value of the private element `name` of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateset
+    pub(crate) fn private_set(
+        &self,
+        name: &JsSymbol,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let entry be ! PrivateElementFind(O, P).
+        let setter = match self.borrow_mut().private_element_mut(name) {
+            // 2. If entry is empty, throw a TypeError exception.
+            None => None,
+            // 3. If entry.[[Kind]] is field, then
+            //     a. Set entry.[[Value]] to value.
+            Some(PrivateElement::Field(field)) => {
+                *field = value;
+                return Ok(());
+            }
+            // 4. Else if entry.[[Kind]] is method, then
+            //     a. Throw a TypeError exception.
+            Some(PrivateElement::Method(_)) => Some(None),
+            // 5. Else,
+            //     a. Assert: entry.[[Kind]] is accessor.
+            //     b. Let setter be entry.[[Set]].
+            //     c. If setter is undefined, throw a TypeError exception.
+            Some(PrivateElement::Accessor { setter, .. }) => Some(setter.clone()),
+        };
+
+        match setter {
+            None => Err(context.construct_type_error(format!(
+                "cannot write private member {} to an object whose class did not declare it",
+                name.description().unwrap_or_default()
+            ))),
+            Some(None) => Err(context.construct_type_error(format!(
+                "{} is read-only",
+                name.description().unwrap_or_default()
+            ))),
+            // d. Perform ? Call(setter, O, « value »).
+            Some(Some(setter)) => {
+                setter.call(&self.clone().into(), &[value], context)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks if the object has the private element `name`, for the `#name in object` brand
+    /// check.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-relational-operators-runtime-semantics-evaluation
+    #[inline]
+    pub(crate) fn has_private_element(&self, name: &JsSymbol) -> bool {
+        self.borrow().private_element(name).is_some()
+    }
+
+    /// Defines the field `field` of a class on the object, evaluating its initializer with the
+    /// object as `this`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-definefield
+    pub(crate) fn define_field(
+        &self,
+        field: &ClassFieldDefinition,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let fieldName be fieldRecord.[[Name]].
+        // 2. Let initializer be fieldRecord.[[Initializer]].
+        // 3. If initializer is not empty, then
+        //     a. Let initValue be ? Call(initializer, receiver).
+        // 4. Else, let initValue be undefined.
+        let value = match field.initializer() {
+            Some(initializer) => initializer.call(&self.clone().into(), &[], context)?,
+            None => JsValue::undefined(),
+        };
+
+        match field.name() {
+            // 5. If fieldName is a Private Name, then
+            //     a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
+            ClassFieldName::Private(name) => self.private_field_add(name, value, context),
+            // 6. Else,
+            //     a. Assert: IsPropertyKey(fieldName) is true.
+            //     b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
+            ClassFieldName::Public(key) => {
+                self.create_data_property_or_throw(key.clone(), value, context)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds the private methods and the fields declared by the class `constructor` to the
+    /// object, which `constructor` is constructing.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializeinstanceelements
+    pub(crate) fn initialize_instance_elements(
+        &self,
+        constructor: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // The elements are cloned, so that the initializers can use the constructor.
+        let (methods, fields) = match constructor.borrow().as_function() {
+            Some(function) => (
+                function.private_methods().to_vec(),
+                function.fields().to_vec(),
+            ),
+            None => return Ok(()),
+        };
 
-    // todo: InitializeInstanceElements
+        // 1. Let methods be the value of constructor.[[PrivateMethods]].
+        // 2. For each PrivateElement method of methods, do
+        for (name, method) in methods {
+            // a. Perform ? PrivateMethodOrAccessorAdd(O, method).
+            self.private_method_or_accessor_add(&name, method, context)?;
+        }
+
+        // 3. Let fields be the value of constructor.[[Fields]].
+        // 4. For each element fieldRecord of fields, do
+        for field in &fields {
+            // a. Perform ? DefineField(O, fieldRecord).
+            self.define_field(field, context)?;
+        }
+
+        // 5. Return unused.
+        Ok(())
+    }
 }
 
 impl JsValue {
@@ -1,18 +1,290 @@
-use super::{PropertyDescriptor, PropertyKey};
+use super::{
+    shape::{Shape, MAX_SHAPE_KEYS},
+    PropertyDescriptor, PropertyKey,
+};
 use crate::{
-    gc::{Finalize, Trace},
+    gc::{custom_trace, Finalize, Trace},
     JsString, JsSymbol,
 };
-use rustc_hash::FxHashMap;
-use std::{collections::hash_map, iter::FusedIterator};
+use indexmap::{map, IndexMap};
+use rustc_hash::FxHasher;
+use std::{
+    collections::{btree_map, BTreeMap},
+    hash::BuildHasherDefault,
+    iter::{Enumerate, FusedIterator, Zip},
+    slice,
+};
 
-#[derive(Default, Debug, Trace, Finalize)]
+/// Type alias to make it easier to work with the string and symbol properties, which are kept in
+/// insertion order.
+type OrderedHashMap<K> = IndexMap<K, PropertyDescriptor, BuildHasherDefault<FxHasher>>;
+
+/// The backing store of the own properties of an object.
+///
+/// The properties are stored in the order required by [`OrdinaryOwnPropertyKeys`][spec]: integer
+/// indices in ascending numeric order, then strings in insertion order, then symbols in
+/// insertion order. All of the iterators of this map yield properties in that order.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+#[derive(Default, Debug, Clone, Finalize)]
 pub struct PropertyMap {
-    indexed_properties: FxHashMap<u32, PropertyDescriptor>,
+    /// Integer index properties, sorted by index.
+    indexed_properties: IndexedPropertyStorage,
     /// Properties
-    string_properties: FxHashMap<JsString, PropertyDescriptor>,
+    string_properties: StringPropertyStorage,
     /// Symbol Properties
-    symbol_properties: FxHashMap<JsSymbol, PropertyDescriptor>,
+    symbol_properties: OrderedHashMap<JsSymbol>,
+}
+
+/// The integer index properties of an object.
+#[derive(Debug, Clone)]
+enum IndexedPropertyStorage {
+    /// The properties of the indices `0..len`, without holes, in the slots of their indices.
+    Dense(Vec<PropertyDescriptor>),
+    /// The properties of an object with holes in its indices, sorted by index.
+    Sparse(BTreeMap<u32, PropertyDescriptor>),
+}
+
+impl Default for IndexedPropertyStorage {
+    fn default() -> Self {
+        Self::Dense(Vec::new())
+    }
+}
+
+impl IndexedPropertyStorage {
+    fn get(&self, index: u32) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Dense(properties) => properties.get(index as usize),
+            Self::Sparse(properties) => properties.get(&index),
+        }
+    }
+
+    /// Turns the dense properties into sparse ones, before a hole is made in their indices.
+    fn make_sparse(&mut self) -> &mut BTreeMap<u32, PropertyDescriptor> {
+        if let Self::Dense(properties) = self {
+            *self = Self::Sparse(
+                std::mem::take(properties)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, property)| (index as u32, property))
+                    .collect(),
+            );
+        }
+
+        match self {
+            Self::Sparse(properties) => properties,
+            Self::Dense(_) => unreachable!("the properties were made sparse"),
+        }
+    }
+
+    fn insert(&mut self, index: u32, property: PropertyDescriptor) -> Option<PropertyDescriptor> {
+        if let Self::Dense(properties) = self {
+            let slot = index as usize;
+            if slot < properties.len() {
+                return Some(std::mem::replace(&mut properties[slot], property));
+            }
+            if slot == properties.len() {
+                properties.push(property);
+                return None;
+            }
+        }
+
+        let old = self.make_sparse().insert(index, property);
+        self.make_dense_if_filled();
+        old
+    }
+
+    fn remove(&mut self, index: u32) -> Option<PropertyDescriptor> {
+        if let Self::Dense(properties) = self {
+            let slot = index as usize;
+            if slot >= properties.len() {
+                return None;
+            }
+            // Removing the last element, as shrinking the `length` of an array does, keeps the
+            // properties dense.
+            if slot == properties.len() - 1 {
+                return properties.pop();
+            }
+        }
+
+        let old = self.make_sparse().remove(&index);
+        self.make_dense_if_filled();
+        old
+    }
+
+    /// Turns the sparse properties into dense ones, once their indices have no holes.
+    fn make_dense_if_filled(&mut self) {
+        if let Self::Sparse(properties) = self {
+            // The sorted indices are `0..len` when the last one is `len - 1`.
+            let filled = properties
+                .keys()
+                .next_back()
+                .map_or(true, |last| *last as usize + 1 == properties.len());
+            if filled {
+                *self = Self::Dense(std::mem::take(properties).into_values().collect());
+            }
+        }
+    }
+
+    fn contains_key(&self, index: u32) -> bool {
+        match self {
+            Self::Dense(properties) => (index as usize) < properties.len(),
+            Self::Sparse(properties) => properties.contains_key(&index),
+        }
+    }
+
+    fn iter(&self) -> IndexProperties<'_> {
+        IndexProperties(match self {
+            Self::Dense(properties) => IndexPropertiesInner::Dense(properties.iter().enumerate()),
+            Self::Sparse(properties) => IndexPropertiesInner::Sparse(properties.iter()),
+        })
+    }
+}
+
+/// The string properties of an object.
+#[derive(Debug, Clone)]
+enum StringPropertyStorage {
+    /// The properties, in the slots given to their keys by the shape of the object.
+    Shaped {
+        shape: Shape,
+        properties: Vec<PropertyDescriptor>,
+    },
+    /// The properties of an object with more string properties than a shape can have.
+    Dictionary(OrderedHashMap<JsString>),
+}
+
+impl Default for StringPropertyStorage {
+    fn default() -> Self {
+        Self::Shaped {
+            shape: Shape::root(),
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl StringPropertyStorage {
+    fn get(&self, key: &JsString) -> Option<&PropertyDescriptor> {
+        let (_, property) = self.get_full(key)?;
+        Some(property)
+    }
+
+    /// Returns the slot of the property `key`, and the property.
+    fn get_full(&self, key: &JsString) -> Option<(usize, &PropertyDescriptor)> {
+        match self {
+            Self::Shaped { shape, properties } => {
+                let slot = shape.slot(key)?;
+                Some((slot, &properties[slot]))
+            }
+            Self::Dictionary(properties) => {
+                let (slot, _, property) = properties.get_full(key)?;
+                Some((slot, property))
+            }
+        }
+    }
+
+    fn get_index(&self, slot: usize) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Shaped { properties, .. } => properties.get(slot),
+            Self::Dictionary(properties) => {
+                properties.get_index(slot).map(|(_, property)| property)
+            }
+        }
+    }
+
+    fn get_index_mut(&mut self, slot: usize) -> Option<&mut PropertyDescriptor> {
+        match self {
+            Self::Shaped { properties, .. } => properties.get_mut(slot),
+            Self::Dictionary(properties) => {
+                properties.get_index_mut(slot).map(|(_, property)| property)
+            }
+        }
+    }
+
+    fn insert(
+        &mut self,
+        key: &JsString,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, properties } => {
+                if let Some(slot) = shape.slot(key) {
+                    return Some(std::mem::replace(&mut properties[slot], property));
+                }
+
+                if shape.len() < MAX_SHAPE_KEYS {
+                    *shape = shape.with_key(key);
+                    properties.push(property);
+                    return None;
+                }
+
+                // The object gets the table of its keys from its shape.
+                let mut dictionary: OrderedHashMap<JsString> = shape
+                    .keys()
+                    .iter()
+                    .cloned()
+                    .zip(std::mem::take(properties))
+                    .collect();
+                dictionary.insert(key.clone(), property);
+                *self = Self::Dictionary(dictionary);
+                None
+            }
+            Self::Dictionary(properties) => properties.insert(key.clone(), property),
+        }
+    }
+
+    fn remove(&mut self, key: &JsString) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, properties } => {
+                let slot = shape.slot(key)?;
+
+                // The remaining keys keep their order, so the slots after the removed one move
+                // down.
+                let keys = shape.keys();
+                *shape = Shape::from_keys(keys[..slot].iter().chain(&keys[slot + 1..]));
+                Some(properties.remove(slot))
+            }
+            // `shift_remove` keeps the remaining properties in insertion order.
+            Self::Dictionary(properties) => properties.shift_remove(key),
+        }
+    }
+
+    fn contains_key(&self, key: &JsString) -> bool {
+        match self {
+            Self::Shaped { shape, .. } => shape.slot(key).is_some(),
+            Self::Dictionary(properties) => properties.contains_key(key),
+        }
+    }
+
+    fn iter(&self) -> StringProperties<'_> {
+        StringProperties(match self {
+            Self::Shaped { shape, properties } => {
+                StringPropertiesInner::Shaped(shape.keys().iter().zip(properties.iter()))
+            }
+            Self::Dictionary(properties) => StringPropertiesInner::Dictionary(properties.iter()),
+        })
+    }
+
+    /// Returns the id of the shape of the properties, if they have one.
+    fn shape_id(&self) -> Option<u64> {
+        match self {
+            Self::Shaped { shape, .. } => Some(shape.id()),
+            Self::Dictionary(_) => None,
+        }
+    }
+}
+
+unsafe impl Trace for PropertyMap {
+    custom_trace!(this, {
+        for (_, property) in this.indexed_properties.iter() {
+            mark(property);
+        }
+        for (_, property) in this.string_properties.iter() {
+            mark(property);
+        }
+        for property in this.symbol_properties.values() {
+            mark(property);
+        }
+    });
 }
 
 impl PropertyMap {
@@ -21,7 +293,7 @@ impl PropertyMap {
     }
     pub fn get(&self, key: &PropertyKey) -> Option<&PropertyDescriptor> {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.get(index),
+            PropertyKey::Index(index) => self.indexed_properties.get(*index),
             PropertyKey::String(string) => self.string_properties.get(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.get(symbol),
         }
@@ -34,20 +306,21 @@ impl PropertyMap {
     ) -> Option<PropertyDescriptor> {
         match &key {
             PropertyKey::Index(index) => self.indexed_properties.insert(*index, property),
-            PropertyKey::String(string) => self.string_properties.insert(string.clone(), property),
+            PropertyKey::String(string) => self.string_properties.insert(string, property),
             PropertyKey::Symbol(symbol) => self.symbol_properties.insert(symbol.clone(), property),
         }
     }
 
     pub fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.remove(index),
+            PropertyKey::Index(index) => self.indexed_properties.remove(*index),
             PropertyKey::String(string) => self.string_properties.remove(string),
-            PropertyKey::Symbol(symbol) => self.symbol_properties.remove(symbol),
+            // `shift_remove` keeps the remaining properties in insertion order.
+            PropertyKey::Symbol(symbol) => self.symbol_properties.shift_remove(symbol),
         }
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order. The iterator element type is `(PropertyKey, &'a Property)`.
+    /// An iterator visiting all key-value pairs in property key order. The iterator element type is `(PropertyKey, &'a Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -59,7 +332,7 @@ impl PropertyMap {
         }
     }
 
-    /// An iterator visiting all keys in arbitrary order. The iterator element type is `PropertyKey`.
+    /// An iterator visiting all keys in property key order. The iterator element type is `PropertyKey`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -67,7 +340,7 @@ impl PropertyMap {
         Keys(self.iter())
     }
 
-    /// An iterator visiting all values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all values in property key order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -75,7 +348,7 @@ impl PropertyMap {
         Values(self.iter())
     }
 
-    /// An iterator visiting all symbol key-value pairs in arbitrary order. The iterator element type is `(&'a RcSymbol, &'a Property)`.
+    /// An iterator visiting all symbol key-value pairs in insertion order. The iterator element type is `(&'a RcSymbol, &'a Property)`.
     ///
     ///
     /// This iterator does not recurse down the prototype chain.
@@ -84,7 +357,7 @@ impl PropertyMap {
         SymbolProperties(self.symbol_properties.iter())
     }
 
-    /// An iterator visiting all symbol keys in arbitrary order. The iterator element type is `&'a RcSymbol`.
+    /// An iterator visiting all symbol keys in insertion order. The iterator element type is `&'a RcSymbol`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -92,7 +365,7 @@ impl PropertyMap {
         SymbolPropertyKeys(self.symbol_properties.keys())
     }
 
-    /// An iterator visiting all symbol values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all symbol values in insertion order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -100,58 +373,101 @@ impl PropertyMap {
         SymbolPropertyValues(self.symbol_properties.values())
     }
 
-    /// An iterator visiting all indexed key-value pairs in arbitrary order. The iterator element type is `(&'a u32, &'a Property)`.
+    /// An iterator visiting all indexed key-value pairs in ascending index order. The iterator element type is `(u32, &'a Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_properties(&self) -> IndexProperties<'_> {
-        IndexProperties(self.indexed_properties.iter())
+        self.indexed_properties.iter()
     }
 
-    /// An iterator visiting all index keys in arbitrary order. The iterator element type is `&'a u32`.
+    /// An iterator visiting all index keys in ascending index order. The iterator element type is `u32`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_property_keys(&self) -> IndexPropertyKeys<'_> {
-        IndexPropertyKeys(self.indexed_properties.keys())
+        IndexPropertyKeys(self.indexed_properties.iter())
     }
 
-    /// An iterator visiting all index values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all index values in ascending index order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_property_values(&self) -> IndexPropertyValues<'_> {
-        IndexPropertyValues(self.indexed_properties.values())
+        IndexPropertyValues(self.indexed_properties.iter())
     }
 
-    /// An iterator visiting all string key-value pairs in arbitrary order. The iterator element type is `(&'a RcString, &'a Property)`.
+    /// Returns the properties of the indices `0..len`, in the slots of their indices, if there
+    /// are no holes in the indices.
+    #[inline]
+    pub(crate) fn dense_index_properties(&self) -> Option<&[PropertyDescriptor]> {
+        match &self.indexed_properties {
+            IndexedPropertyStorage::Dense(properties) => Some(properties),
+            IndexedPropertyStorage::Sparse(_) => None,
+        }
+    }
+
+    /// An iterator visiting all string key-value pairs in insertion order. The iterator element type is `(&'a RcString, &'a Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_properties(&self) -> StringProperties<'_> {
-        StringProperties(self.string_properties.iter())
+        self.string_properties.iter()
     }
 
-    /// An iterator visiting all string keys in arbitrary order. The iterator element type is `&'a RcString`.
+    /// An iterator visiting all string keys in insertion order. The iterator element type is `&'a RcString`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_property_keys(&self) -> StringPropertyKeys<'_> {
-        StringPropertyKeys(self.string_properties.keys())
+        StringPropertyKeys(self.string_properties.iter())
     }
 
-    /// An iterator visiting all string values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all string values in insertion order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn string_property_values(&self) -> StringPropertyValues<'_> {
-        StringPropertyValues(self.string_properties.values())
+        StringPropertyValues(self.string_properties.iter())
+    }
+
+    /// Returns the id of the shape of the string properties, if they have one.
+    ///
+    /// The maps with the same shape have the same string keys in the same slots, so the slot of
+    /// a string property found in a map can be reused for the maps with the same shape.
+    #[inline]
+    pub(crate) fn shape_id(&self) -> Option<u64> {
+        self.string_properties.shape_id()
+    }
+
+    /// Returns the slot of the string property `key`, and the property.
+    #[inline]
+    pub(crate) fn string_property_slot(
+        &self,
+        key: &JsString,
+    ) -> Option<(usize, &PropertyDescriptor)> {
+        self.string_properties.get_full(key)
+    }
+
+    /// Returns the string property in `slot`.
+    #[inline]
+    pub(crate) fn string_property_at(&self, slot: usize) -> Option<&PropertyDescriptor> {
+        self.string_properties.get_index(slot)
+    }
+
+    /// Returns the string property in `slot` mutably.
+    #[inline]
+    pub(crate) fn string_property_at_mut(
+        &mut self,
+        slot: usize,
+    ) -> Option<&mut PropertyDescriptor> {
+        self.string_properties.get_index_mut(slot)
     }
 
     #[inline]
     pub fn contains_key(&self, key: &PropertyKey) -> bool {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.contains_key(index),
+            PropertyKey::Index(index) => self.indexed_properties.contains_key(*index),
             PropertyKey::String(string) => self.string_properties.contains_key(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.contains_key(symbol),
         }
@@ -161,16 +477,16 @@ impl PropertyMap {
 /// An iterator over the property entries of an `Object`
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
-    indexed_properties: hash_map::Iter<'a, u32, PropertyDescriptor>,
-    string_properties: hash_map::Iter<'a, JsString, PropertyDescriptor>,
-    symbol_properties: hash_map::Iter<'a, JsSymbol, PropertyDescriptor>,
+    indexed_properties: IndexProperties<'a>,
+    string_properties: StringProperties<'a>,
+    symbol_properties: map::Iter<'a, JsSymbol, PropertyDescriptor>,
 }
 
 impl<'a> Iterator for Iter<'a> {
     type Item = (PropertyKey, &'a PropertyDescriptor);
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((key, value)) = self.indexed_properties.next() {
-            Some(((*key).into(), value))
+            Some((key.into(), value))
         } else if let Some((key, value)) = self.string_properties.next() {
             Some((key.clone().into(), value))
         } else {
@@ -233,7 +549,7 @@ impl FusedIterator for Values<'_> {}
 
 /// An iterator over the `Symbol` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct SymbolProperties<'a>(hash_map::Iter<'a, JsSymbol, PropertyDescriptor>);
+pub struct SymbolProperties<'a>(map::Iter<'a, JsSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolProperties<'a> {
     type Item = (&'a JsSymbol, &'a PropertyDescriptor);
@@ -260,7 +576,7 @@ impl FusedIterator for SymbolProperties<'_> {}
 
 /// An iterator over the keys (`RcSymbol`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct SymbolPropertyKeys<'a>(hash_map::Keys<'a, JsSymbol, PropertyDescriptor>);
+pub struct SymbolPropertyKeys<'a>(map::Keys<'a, JsSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolPropertyKeys<'a> {
     type Item = &'a JsSymbol;
@@ -287,7 +603,7 @@ impl FusedIterator for SymbolPropertyKeys<'_> {}
 
 /// An iterator over the `Symbol` values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct SymbolPropertyValues<'a>(hash_map::Values<'a, JsSymbol, PropertyDescriptor>);
+pub struct SymbolPropertyValues<'a>(map::Values<'a, JsSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
@@ -314,41 +630,79 @@ impl FusedIterator for SymbolPropertyValues<'_> {}
 
 /// An iterator over the indexed property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct IndexProperties<'a>(hash_map::Iter<'a, u32, PropertyDescriptor>);
+pub struct IndexProperties<'a>(IndexPropertiesInner<'a>);
+
+#[derive(Debug, Clone)]
+enum IndexPropertiesInner<'a> {
+    Dense(Enumerate<slice::Iter<'a, PropertyDescriptor>>),
+    Sparse(btree_map::Iter<'a, u32, PropertyDescriptor>),
+}
 
 impl<'a> Iterator for IndexProperties<'a> {
-    type Item = (&'a u32, &'a PropertyDescriptor);
+    type Item = (u32, &'a PropertyDescriptor);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        match &mut self.0 {
+            IndexPropertiesInner::Dense(iter) => {
+                let (index, property) = iter.next()?;
+                Some((index as u32, property))
+            }
+            IndexPropertiesInner::Sparse(iter) => {
+                let (index, property) = iter.next()?;
+                Some((*index, property))
+            }
+        }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        match &self.0 {
+            IndexPropertiesInner::Dense(iter) => iter.size_hint(),
+            IndexPropertiesInner::Sparse(iter) => iter.size_hint(),
+        }
     }
 }
 
 impl ExactSizeIterator for IndexProperties<'_> {
     #[inline]
     fn len(&self) -> usize {
-        self.0.len()
+        match &self.0 {
+            IndexPropertiesInner::Dense(iter) => iter.len(),
+            IndexPropertiesInner::Sparse(iter) => iter.len(),
+        }
     }
 }
 
 impl FusedIterator for IndexProperties<'_> {}
 
+impl DoubleEndedIterator for IndexProperties<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IndexPropertiesInner::Dense(iter) => {
+                let (index, property) = iter.next_back()?;
+                Some((index as u32, property))
+            }
+            IndexPropertiesInner::Sparse(iter) => {
+                let (index, property) = iter.next_back()?;
+                Some((*index, property))
+            }
+        }
+    }
+}
+
 /// An iterator over the index keys (`u32`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct IndexPropertyKeys<'a>(hash_map::Keys<'a, u32, PropertyDescriptor>);
+pub struct IndexPropertyKeys<'a>(IndexProperties<'a>);
 
 impl<'a> Iterator for IndexPropertyKeys<'a> {
-    type Item = &'a u32;
+    type Item = u32;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (key, _) = self.0.next()?;
+        Some(key)
     }
 
     #[inline]
@@ -366,16 +720,25 @@ impl ExactSizeIterator for IndexPropertyKeys<'_> {
 
 impl FusedIterator for IndexPropertyKeys<'_> {}
 
+impl DoubleEndedIterator for IndexPropertyKeys<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, _) = self.0.next_back()?;
+        Some(key)
+    }
+}
+
 /// An iterator over the index values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct IndexPropertyValues<'a>(hash_map::Values<'a, u32, PropertyDescriptor>);
+pub struct IndexPropertyValues<'a>(IndexProperties<'a>);
 
 impl<'a> Iterator for IndexPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (_, value) = self.0.next()?;
+        Some(value)
     }
 
     #[inline]
@@ -393,28 +756,51 @@ impl ExactSizeIterator for IndexPropertyValues<'_> {
 
 impl FusedIterator for IndexPropertyValues<'_> {}
 
+impl DoubleEndedIterator for IndexPropertyValues<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, value) = self.0.next_back()?;
+        Some(value)
+    }
+}
+
 /// An iterator over the `String` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct StringProperties<'a>(hash_map::Iter<'a, JsString, PropertyDescriptor>);
+pub struct StringProperties<'a>(StringPropertiesInner<'a>);
+
+#[derive(Debug, Clone)]
+enum StringPropertiesInner<'a> {
+    Shaped(Zip<slice::Iter<'a, JsString>, slice::Iter<'a, PropertyDescriptor>>),
+    Dictionary(map::Iter<'a, JsString, PropertyDescriptor>),
+}
 
 impl<'a> Iterator for StringProperties<'a> {
     type Item = (&'a JsString, &'a PropertyDescriptor);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        match &mut self.0 {
+            StringPropertiesInner::Shaped(iter) => iter.next(),
+            StringPropertiesInner::Dictionary(iter) => iter.next(),
+        }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        match &self.0 {
+            StringPropertiesInner::Shaped(iter) => iter.size_hint(),
+            StringPropertiesInner::Dictionary(iter) => iter.size_hint(),
+        }
     }
 }
 
 impl ExactSizeIterator for StringProperties<'_> {
     #[inline]
     fn len(&self) -> usize {
-        self.0.len()
+        match &self.0 {
+            StringPropertiesInner::Shaped(iter) => iter.len(),
+            StringPropertiesInner::Dictionary(iter) => iter.len(),
+        }
     }
 }
 
@@ -422,14 +808,15 @@ impl FusedIterator for StringProperties<'_> {}
 
 /// An iterator over the string keys (`RcString`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyKeys<'a>(hash_map::Keys<'a, JsString, PropertyDescriptor>);
+pub struct StringPropertyKeys<'a>(StringProperties<'a>);
 
 impl<'a> Iterator for StringPropertyKeys<'a> {
     type Item = &'a JsString;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (key, _) = self.0.next()?;
+        Some(key)
     }
 
     #[inline]
@@ -449,14 +836,15 @@ impl FusedIterator for StringPropertyKeys<'_> {}
 
 /// An iterator over the string values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyValues<'a>(hash_map::Values<'a, JsString, PropertyDescriptor>);
+pub struct StringPropertyValues<'a>(StringProperties<'a>);
 
 impl<'a> Iterator for StringPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        let (_, value) = self.0.next()?;
+        Some(value)
     }
 
     #[inline]
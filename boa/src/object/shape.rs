@@ -0,0 +1,134 @@
+//! The shapes of the string properties of objects.
+//!
+//! A shape maps the string keys of an object to the slots of their properties, so the objects
+//! with the same keys, added in the same order, share the same shape instead of each owning a
+//! hash table of their keys. Shapes are linked by transitions: adding a key to an object gives it
+//! the shape its shape transitions to with that key, which is created the first time the key is
+//! added to an object of that shape.
+
+use crate::JsString;
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+/// The maximum number of keys of a shape.
+///
+/// The objects with more string properties own the table of their keys, since each shape holds
+/// all of its keys.
+pub(crate) const MAX_SHAPE_KEYS: usize = 64;
+
+thread_local! {
+    /// The shape of the objects without string properties, from which all shapes transition.
+    static ROOT_SHAPE: Shape = Shape::new(None, Vec::new());
+}
+
+thread_local! {
+    /// The last id given to a shape.
+    static SHAPE_COUNT: std::cell::Cell<u64> = std::cell::Cell::new(0);
+}
+
+/// The string keys of an object, in insertion order, and the slots of their properties.
+#[derive(Clone)]
+pub(crate) struct Shape(Rc<ShapeInner>);
+
+struct ShapeInner {
+    /// The id of the shape, which no other shape has.
+    id: u64,
+
+    /// The keys, in the order of their slots.
+    keys: Box<[JsString]>,
+    slots: FxHashMap<JsString, u32>,
+
+    /// The shape this one transitioned from, kept alive so the objects taking the same
+    /// transitions share it.
+    _parent: Option<Shape>,
+
+    /// The shapes this one transitioned to, by added key.
+    transitions: RefCell<FxHashMap<JsString, Weak<ShapeInner>>>,
+}
+
+impl Shape {
+    fn new(parent: Option<Self>, keys: Vec<JsString>) -> Self {
+        let slots = keys
+            .iter()
+            .enumerate()
+            .map(|(slot, key)| (key.clone(), slot as u32))
+            .collect();
+        Self(Rc::new(ShapeInner {
+            id: SHAPE_COUNT.with(|count| {
+                let id = count.get() + 1;
+                count.set(id);
+                id
+            }),
+            keys: keys.into_boxed_slice(),
+            slots,
+            _parent: parent,
+            transitions: RefCell::default(),
+        }))
+    }
+
+    /// Returns the shape without keys.
+    pub(crate) fn root() -> Self {
+        ROOT_SHAPE.with(Self::clone)
+    }
+
+    /// Returns the shape with `keys`, in this order, taking the transitions from the root shape.
+    pub(crate) fn from_keys<'a, I>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = &'a JsString>,
+    {
+        keys.into_iter()
+            .fold(Self::root(), |shape, key| shape.with_key(key))
+    }
+
+    /// Returns the id of the shape.
+    #[inline]
+    pub(crate) fn id(&self) -> u64 {
+        self.0.id
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.0.keys.len()
+    }
+
+    /// Returns the keys, in the order of their slots.
+    #[inline]
+    pub(crate) fn keys(&self) -> &[JsString] {
+        &self.0.keys
+    }
+
+    /// Returns the slot of the property `key`.
+    #[inline]
+    pub(crate) fn slot(&self, key: &JsString) -> Option<usize> {
+        self.0.slots.get(key).map(|slot| *slot as usize)
+    }
+
+    /// Returns the shape with the keys of this one and `key` after them.
+    pub(crate) fn with_key(&self, key: &JsString) -> Self {
+        debug_assert!(self.slot(key).is_none());
+
+        if let Some(shape) = self.0.transitions.borrow().get(key).and_then(Weak::upgrade) {
+            return Self(shape);
+        }
+
+        let mut keys = self.0.keys.to_vec();
+        keys.push(key.clone());
+        let shape = Self::new(Some(self.clone()), keys);
+
+        // The transitions to the shapes no object has anymore are dropped.
+        let mut transitions = self.0.transitions.borrow_mut();
+        transitions.retain(|_, shape| shape.strong_count() > 0);
+        transitions.insert(key.clone(), Rc::downgrade(&shape.0));
+        shape
+    }
+}
+
+impl fmt::Debug for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Shape").field(&self.0.keys).finish()
+    }
+}
@@ -1,4 +1,11 @@
-use crate::exec;
+use crate::{
+    exec, forward, forward_val,
+    object::{JsObject, ObjectInitializer},
+    property::{Attribute, PropertyChange, PropertyKey},
+    Context, JsString, JsSymbol,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
 
 #[test]
 fn ordinary_has_instance_nonobject_prototype() {
@@ -17,3 +24,515 @@ fn ordinary_has_instance_nonobject_prototype() {
         "\"TypeError: function has non-object prototype in instanceof check\""
     );
 }
+
+#[test]
+fn instance_of_symbol_has_instance() {
+    let mut context = Context::new();
+    let init = r#"
+        function F() {}
+        var f = new F();
+        var even = {};
+        even[Symbol.hasInstance] = function (value) { return value % 2 === 0; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f instanceof F"), "true");
+    assert_eq!(forward(&mut context, "f instanceof Object"), "true");
+    assert_eq!(forward(&mut context, "({}) instanceof F"), "false");
+    assert_eq!(forward(&mut context, "1 instanceof F"), "false");
+
+    // Objects with a `@@hasInstance` method don't need to be callable.
+    assert_eq!(forward(&mut context, "2 instanceof even"), "true");
+    assert_eq!(forward(&mut context, "3 instanceof even"), "false");
+
+    // Functions can override the default method of `Function.prototype`.
+    forward(
+        &mut context,
+        "Object.defineProperty(F, Symbol.hasInstance, { value: function (v) { return v === 1; } })",
+    );
+    assert_eq!(forward(&mut context, "1 instanceof F"), "true");
+    assert_eq!(forward(&mut context, "f instanceof F"), "false");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { f instanceof {} } catch (e) { e.message }"
+        ),
+        "\"right-hand side of 'instanceof' is not callable\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { f instanceof 1 } catch (e) { e.message }"
+        ),
+        "\"right-hand side of 'instanceof' should be an object, got number\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "even[Symbol.hasInstance] = 1; try { 2 instanceof even } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn function_prototype_has_instance() {
+    let mut context = Context::new();
+    let init = r#"
+        function F() {}
+        var f = new F();
+        var hasInstance = Function.prototype[Symbol.hasInstance];
+        var descriptor = Object.getOwnPropertyDescriptor(Function.prototype, Symbol.hasInstance);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "hasInstance.call(F, f)"), "true");
+    assert_eq!(forward(&mut context, "hasInstance.call(Object, f)"), "true");
+    assert_eq!(forward(&mut context, "hasInstance.call({}, f)"), "false");
+    assert_eq!(
+        forward(&mut context, "hasInstance.name"),
+        "\"[Symbol.hasInstance]\""
+    );
+    assert_eq!(forward(&mut context, "hasInstance.length"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "descriptor.writable || descriptor.enumerable || descriptor.configurable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn instance_of_callable_proxy() {
+    let mut context = Context::new();
+    let init = r#"
+        function F() {}
+        var f = new F();
+        var proxy = new Proxy(F, {});
+        var trapped = new Proxy(F, {
+            get: function (target, key) {
+                return key === Symbol.hasInstance ? () => true : target[key];
+            },
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f instanceof proxy"), "true");
+    assert_eq!(forward(&mut context, "({}) instanceof proxy"), "false");
+    assert_eq!(forward(&mut context, "({}) instanceof trapped"), "true");
+}
+
+/// Creates a global `model` object whose `count` property is watched, returning the changes.
+fn watched_model(context: &mut Context) -> (Rc<RefCell<Vec<String>>>, JsObject) {
+    let model = ObjectInitializer::new(context)
+        .property("count", 0, Attribute::all())
+        .build();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let log = changes.clone();
+    assert!(model.watch_property("count", move |_, key, change, _| {
+        log.borrow_mut().push(match change {
+            PropertyChange::Write { old, new } => {
+                format!("write {} {} {}", key, old.display(), new.display())
+            }
+            PropertyChange::Delete { old } => format!("delete {} {}", key, old.display()),
+        });
+        Ok(())
+    }));
+    context.register_global_property("model", model.clone(), Attribute::all());
+    (changes, model)
+}
+
+#[test]
+fn property_watchers() {
+    let mut context = Context::new();
+    let (changes, _) = watched_model(&mut context);
+
+    forward(
+        &mut context,
+        r#"
+        model.count = 1;
+        model.count++;
+        Object.defineProperty(model, "count", { value: 5 });
+        model.other = 1;
+        delete model.count;
+        model.count = 6;
+    "#,
+    );
+    assert_eq!(
+        *changes.borrow(),
+        vec![
+            "write count 0 1",
+            "write count 1 2",
+            "write count 2 5",
+            "delete count 5",
+        ]
+    );
+}
+
+#[test]
+fn repeated_writes_report_to_watchers() {
+    let mut context = Context::new();
+    let (changes, model) = watched_model(&mut context);
+
+    // The inline cache of the write in `set` first sees an unwatched object with the shape of the
+    // model, then the writes of the model from the same site are all reported.
+    let plain = forward_val(
+        &mut context,
+        r#"
+        function set(o, value) { o.count = value; }
+        var plain = { count: 0 };
+        set(plain, 0);
+        plain
+    "#,
+    )
+    .unwrap()
+    .as_object()
+    .unwrap();
+    assert_eq!(
+        plain.borrow().properties().shape_id(),
+        model.borrow().properties().shape_id()
+    );
+
+    forward(
+        &mut context,
+        r#"
+        for (var i = 1; i <= 3; i++) {
+            set(model, i);
+        }
+    "#,
+    );
+    assert_eq!(
+        *changes.borrow(),
+        vec!["write count 0 1", "write count 1 2", "write count 2 3"]
+    );
+}
+
+#[test]
+fn watched_properties_are_data_properties() {
+    let mut context = Context::new();
+    watched_model(&mut context);
+
+    forward(
+        &mut context,
+        "var desc = Object.getOwnPropertyDescriptor(model, 'count');",
+    );
+    assert_eq!(forward(&mut context, "desc.value"), "0");
+    assert_eq!(forward(&mut context, "desc.writable"), "true");
+    assert_eq!(forward(&mut context, "'get' in desc"), "false");
+    assert_eq!(forward(&mut context, "model.count"), "0");
+    assert_eq!(
+        forward(&mut context, "Object.keys(model).join()"),
+        "\"count\""
+    );
+}
+
+#[test]
+fn unwatch_property() {
+    let mut context = Context::new();
+    let (changes, model) = watched_model(&mut context);
+
+    assert!(model.unwatch_property("count"));
+    assert!(!model.unwatch_property("count"));
+    assert!(!model.watch_property("missing", |_, _, _, _| Ok(())));
+    forward(&mut context, "model.count = 1");
+    assert!(changes.borrow().is_empty());
+}
+
+#[test]
+fn property_watcher_errors() {
+    let mut context = Context::new();
+    let model = ObjectInitializer::new(&mut context)
+        .property("value", 0, Attribute::all())
+        .build();
+    model.watch_property("value", |_, _, _, context| {
+        Err(context.construct_range_error("read only model"))
+    });
+    context.register_global_property("model", model, Attribute::all());
+
+    assert!(forward_val(&mut context, "model.value = 1").is_err());
+    assert_eq!(forward(&mut context, "model.value"), "1");
+}
+
+#[test]
+fn own_property_keys_order() {
+    let mut context = Context::new();
+    let init = r#"
+        var sym = Symbol("sym");
+        var obj = { b: 1, 10: 2, a: 4, 2: 5, "01": 6, "-1": 7, 4294967294: 8 };
+        obj[sym] = 3;
+        obj[0] = 9;
+        obj.c = 10;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(obj).map(String).join()"),
+        "\"0,2,10,4294967294,b,a,01,-1,c,Symbol(sym)\""
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(obj)"),
+        r#""{"0":9,"2":5,"10":2,"4294967294":8,"b":1,"a":4,"01":6,"-1":7,"c":10}""#
+    );
+    assert_eq!(forward(&mut context, "obj['01'] === obj[1]"), "false");
+
+    // Deleting and re-adding a property moves it to the end of the string keys.
+    forward(&mut context, "delete obj.b; obj.b = 11;");
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        "\"0,2,10,4294967294,a,01,-1,c,b\""
+    );
+
+    // Redefining an existing property keeps its position.
+    forward(
+        &mut context,
+        "Object.defineProperty(obj, 'a', { value: 12 });",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var keys = []; for (var k in obj) { keys.push(k); } keys.join()"
+        ),
+        "\"0,2,10,4294967294,a,01,-1,c,b\""
+    );
+}
+
+#[test]
+fn own_property_keys_order_randomized() {
+    const STRINGS: [&str; 8] = ["a", "b", "c", "length", "01", "-0", "1.5", "x"];
+    const INDICES: [u32; 8] = [0, 1, 2, 7, 10, 100, 65_536, 4_294_967_294];
+
+    let mut context = Context::new();
+    let symbols: Vec<_> = (0..4)
+        .map(|i| JsSymbol::new(Some(JsString::from(i.to_string()))))
+        .collect();
+
+    for seed in 0..64 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let object = context.construct_object();
+
+        // The expected order of the keys of `object`.
+        let mut indices = BTreeSet::new();
+        let mut strings: Vec<&str> = Vec::new();
+        let mut symbol_order: Vec<usize> = Vec::new();
+
+        for _ in 0..rng.gen_range(0..64) {
+            let delete = rng.gen_bool(0.3);
+            match rng.gen_range(0..3) {
+                0 => {
+                    let index = INDICES[rng.gen_range(0..INDICES.len())];
+                    if delete {
+                        object
+                            .delete_property_or_throw(index, &mut context)
+                            .unwrap();
+                        indices.remove(&index);
+                    } else {
+                        object
+                            .create_data_property_or_throw(index, seed, &mut context)
+                            .unwrap();
+                        indices.insert(index);
+                    }
+                }
+                1 => {
+                    let string = STRINGS[rng.gen_range(0..STRINGS.len())];
+                    let position = strings.iter().position(|s| *s == string);
+                    if delete {
+                        object
+                            .delete_property_or_throw(string, &mut context)
+                            .unwrap();
+                        if let Some(position) = position {
+                            strings.remove(position);
+                        }
+                    } else {
+                        object
+                            .create_data_property_or_throw(string, seed, &mut context)
+                            .unwrap();
+                        if position.is_none() {
+                            strings.push(string);
+                        }
+                    }
+                }
+                _ => {
+                    let symbol = rng.gen_range(0..symbols.len());
+                    let position = symbol_order.iter().position(|s| *s == symbol);
+                    if delete {
+                        object
+                            .delete_property_or_throw(symbols[symbol].clone(), &mut context)
+                            .unwrap();
+                        if let Some(position) = position {
+                            symbol_order.remove(position);
+                        }
+                    } else {
+                        object
+                            .create_data_property_or_throw(
+                                symbols[symbol].clone(),
+                                seed,
+                                &mut context,
+                            )
+                            .unwrap();
+                        if position.is_none() {
+                            symbol_order.push(symbol);
+                        }
+                    }
+                }
+            }
+        }
+
+        let expected: Vec<PropertyKey> = indices
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(strings.into_iter().map(PropertyKey::from))
+            .chain(
+                symbol_order
+                    .into_iter()
+                    .map(|symbol| PropertyKey::from(symbols[symbol].clone())),
+            )
+            .collect();
+        assert_eq!(
+            object.__own_property_keys__(&mut context).unwrap(),
+            expected,
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn many_string_properties() {
+    let mut context = Context::new();
+    let init = r#"
+        var obj = {};
+        for (var i = 0; i < 100; i++) {
+            obj["k" + i] = i;
+        }
+        for (var i = 0; i < 100; i += 3) {
+            delete obj["k" + i];
+        }
+        obj.k0 = "last";
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Object.keys(obj).length"), "67");
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).slice(0, 3).join()"),
+        "\"k1,k2,k4\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).slice(-2).join()"),
+        "\"k98,k0\""
+    );
+    assert_eq!(forward(&mut context, "obj.k50 + obj.k98"), "148");
+    assert_eq!(forward(&mut context, "obj.k0"), "\"last\"");
+}
+
+#[test]
+fn indexed_properties_dense_and_sparse() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [];
+        for (var i = 0; i < 10; i++) {
+            arr[i] = i;
+        }
+        delete arr[4];
+        arr[20] = 20;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(arr).join()"),
+        "\"0,1,2,3,5,6,7,8,9,20\""
+    );
+    assert_eq!(forward(&mut context, "4 in arr"), "false");
+    assert_eq!(forward(&mut context, "arr.length"), "21");
+
+    // Filling the holes and shrinking the array makes its elements dense again.
+    forward(&mut context, "arr[4] = 'four'; arr.length = 10;");
+    assert_eq!(
+        forward(&mut context, "Object.keys(arr).join()"),
+        "\"0,1,2,3,4,5,6,7,8,9\""
+    );
+    assert_eq!(forward(&mut context, "arr[4] + arr[9]"), "\"four9\"");
+
+    let arr = forward_val(&mut context, "arr").unwrap();
+    assert!(arr
+        .as_object()
+        .unwrap()
+        .borrow()
+        .properties()
+        .dense_index_properties()
+        .is_some());
+
+    forward(
+        &mut context,
+        "var reversed = []; reversed[2] = 'c'; reversed[1] = 'b';",
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(reversed).join()"),
+        "\"1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "reversed[0] = 'a'; reversed.join()"),
+        "\"a,b,c\""
+    );
+}
+
+#[test]
+fn objects_share_shapes() {
+    let mut context = Context::new();
+    let objects = forward_val(
+        &mut context,
+        r#"
+        function point(x, y) { return { x, y }; }
+        var a = point(1, 2);
+        var b = point(3, 4);
+        var c = { y: 5, x: 6 };
+        var d = { x: 7, y: 8, z: 9 };
+        delete d.z;
+        [a, b, c, d]
+        "#,
+    )
+    .unwrap();
+    let shape = |index: u32, context: &mut Context| {
+        objects
+            .get_field(index, context)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .borrow()
+            .properties()
+            .shape_id()
+            .unwrap()
+    };
+
+    let a = shape(0, &mut context);
+    assert_eq!(a, shape(1, &mut context));
+    assert_ne!(a, shape(2, &mut context));
+    assert_eq!(a, shape(3, &mut context));
+}
+
+#[cfg(feature = "gc-barriers")]
+#[test]
+fn write_barrier_marks_mutated_objects() {
+    use crate::gc::{is_dirty, take_dirty_cards, CARD_SIZE};
+
+    let mut context = Context::new();
+    let object = forward_val(&mut context, "var obj = { a: 1 }; obj")
+        .unwrap()
+        .as_object()
+        .unwrap();
+
+    take_dirty_cards();
+    assert!(!is_dirty(&object));
+    object.get("a", &mut context).unwrap();
+    assert!(!is_dirty(&object));
+
+    object.set("b", 2, true, &mut context).unwrap();
+    assert!(is_dirty(&object));
+    let card = object.as_ref() as *const _ as *const u8 as usize / CARD_SIZE;
+    assert!(take_dirty_cards().contains(&card));
+    assert!(!is_dirty(&object));
+
+    forward(&mut context, "obj.c = 3");
+    assert!(is_dirty(&object));
+}
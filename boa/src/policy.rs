@@ -0,0 +1,97 @@
+//! Hooks letting the embedder control the code that scripts compile or load at runtime.
+//!
+//! A [`SourcePolicy`] is consulted before `eval`, the `Function` constructor and its siblings,
+//! dynamic `import()` and the loading of modules, with the source text they are about to compile
+//! or load. It can reject any of them, similarly to the `script-src` directive of a Content
+//! Security Policy in browsers.
+//!
+//! Embedders can also disable the compilation of source text by `eval` and the `Function`
+//! constructors altogether with [`Context::set_dynamic_code_enabled`], and record every attempt
+//! to compile it with [`Context::set_dynamic_code_audit`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+
+use crate::{Context, JsResult};
+use std::fmt::Debug;
+
+/// The ways scripts can compile source text or load code at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceKind {
+    /// The source text passed to the global `eval` function.
+    Eval,
+    /// The source text of a function created by the `Function`, `GeneratorFunction`,
+    /// `AsyncFunction` or `AsyncGeneratorFunction` constructors, assembled from their arguments.
+    Function,
+    /// The specifier passed to a dynamic `import()`.
+    DynamicImport,
+    /// The source text of a module fetched by the module loader.
+    Module,
+}
+
+/// The hook through which the embedder allows or denies the code compiled or loaded at runtime.
+///
+/// The policy is not consulted for the code evaluated by the embedder with [`Context::eval`].
+///
+/// # Examples
+/// ```
+///# use boa::{policy::{SourceKind, SourcePolicy}, Context, JsResult};
+/// #[derive(Debug)]
+/// struct NoEval;
+///
+/// impl SourcePolicy for NoEval {
+///     fn check(
+///         &self,
+///         kind: SourceKind,
+///         _source: &str,
+///         _origin: Option<&str>,
+///         context: &mut Context,
+///     ) -> JsResult<()> {
+///         match kind {
+///             SourceKind::Eval | SourceKind::Function => {
+///                 Err(context.construct_eval_error("code generation from strings is disallowed"))
+///             }
+///             SourceKind::DynamicImport | SourceKind::Module => Ok(()),
+///         }
+///     }
+/// }
+///
+/// let mut context = Context::new();
+/// context.set_source_policy(NoEval);
+///
+/// assert!(context.eval("eval('1 + 1')").is_err());
+/// assert!(context.eval("new Function('return 1')").is_err());
+/// ```
+pub trait SourcePolicy: Debug {
+    /// Checks whether the given source text can be compiled or loaded, returning the error to
+    /// throw if it can't.
+    ///
+    /// `source` is the source text, except for [`SourceKind::DynamicImport`] where it is the
+    /// requested specifier. `origin` is the key of the module the request comes from, or of the
+    /// loaded module for [`SourceKind::Module`], and `None` for the code of scripts.
+    fn check(
+        &self,
+        kind: SourceKind,
+        source: &str,
+        origin: Option<&str>,
+        context: &mut Context,
+    ) -> JsResult<()>;
+}
+
+/// An attempt of a script to compile source text at runtime, with `eval` or the `Function`
+/// constructor and its siblings, as recorded by the audit set with
+/// [`Context::set_dynamic_code_audit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicCodeAttempt<'a> {
+    /// Either [`SourceKind::Eval`] or [`SourceKind::Function`].
+    pub kind: SourceKind,
+    /// The source text the script attempted to compile.
+    pub source: &'a str,
+    /// The key of the module the attempt comes from, or `None` for the code of scripts.
+    pub origin: Option<&'a str>,
+    /// Whether the source text was allowed to be compiled, which it isn't when dynamic code is
+    /// disabled or when the source policy rejects it.
+    pub allowed: bool,
+}
@@ -48,6 +48,54 @@ unsafe impl Trace for Attribute {
 }
 
 impl Attribute {
+    /// Creates an [`AttributeBuilder`] with all flags unset.
+    ///
+    /// ```
+    /// use boa::property::Attribute;
+    ///
+    /// let attribute = Attribute::builder().writable().configurable().build();
+    /// assert_eq!(attribute, Attribute::builtin());
+    /// ```
+    #[inline]
+    pub fn builder() -> AttributeBuilder {
+        AttributeBuilder::default()
+    }
+
+    /// The attributes of most properties of the standard built-in objects:
+    /// `{ [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ecmascript-standard-built-in-objects
+    #[inline]
+    pub const fn builtin() -> Self {
+        Self {
+            bits: Self::WRITABLE.bits | Self::CONFIGURABLE.bits,
+        }
+    }
+
+    /// The attributes of read-only built-in properties such as a function's `length` and `name`,
+    /// or `@@toStringTag`: `{ [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ecmascript-standard-built-in-objects
+    #[inline]
+    pub const fn readonly_builtin() -> Self {
+        Self {
+            bits: Self::CONFIGURABLE.bits,
+        }
+    }
+
+    /// The attributes of constant properties such as `Math.PI` or a constructor's `prototype`:
+    /// `{ [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }`.
+    #[inline]
+    pub const fn constant() -> Self {
+        Self { bits: 0 }
+    }
+
     /// Clear all flags.
     #[inline]
     pub fn clear(&mut self) {
@@ -60,7 +108,7 @@ impl Attribute {
         if value {
             *self |= Self::WRITABLE;
         } else {
-            *self |= *self & !Self::WRITABLE;
+            self.remove(Self::WRITABLE);
         }
     }
 
@@ -76,7 +124,7 @@ impl Attribute {
         if value {
             *self |= Self::ENUMERABLE;
         } else {
-            *self |= *self & !Self::ENUMERABLE;
+            self.remove(Self::ENUMERABLE);
         }
     }
 
@@ -92,7 +140,7 @@ impl Attribute {
         if value {
             *self |= Self::CONFIGURABLE;
         } else {
-            *self |= *self & !Self::CONFIGURABLE;
+            self.remove(Self::CONFIGURABLE);
         }
     }
 
@@ -111,3 +159,55 @@ impl Default for Attribute {
         Self::READONLY | Self::NON_ENUMERABLE | Self::PERMANENT
     }
 }
+
+/// A checked builder for [`Attribute`], created with [`Attribute::builder`].
+///
+/// Every flag starts unset, and setting the same flag twice panics, which catches
+/// copy-paste mistakes when spelling out the attributes of a property.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AttributeBuilder {
+    attribute: Attribute,
+}
+
+impl AttributeBuilder {
+    /// Sets the `writable` flag.
+    #[inline]
+    pub fn writable(self) -> Self {
+        self.with(Attribute::WRITABLE, "writable")
+    }
+
+    /// Sets the `enumerable` flag.
+    #[inline]
+    pub fn enumerable(self) -> Self {
+        self.with(Attribute::ENUMERABLE, "enumerable")
+    }
+
+    /// Sets the `configurable` flag.
+    #[inline]
+    pub fn configurable(self) -> Self {
+        self.with(Attribute::CONFIGURABLE, "configurable")
+    }
+
+    /// Returns the built `Attribute`.
+    #[inline]
+    pub fn build(self) -> Attribute {
+        self.attribute
+    }
+
+    fn with(mut self, flag: Attribute, name: &str) -> Self {
+        assert!(
+            !self.attribute.contains(flag),
+            "the `{}` attribute was set twice",
+            name
+        );
+        self.attribute.insert(flag);
+        self
+    }
+}
+
+impl From<AttributeBuilder> for Attribute {
+    #[inline]
+    fn from(builder: AttributeBuilder) -> Self {
+        builder.build()
+    }
+}
@@ -135,3 +135,53 @@ fn set_configurable_to_false() {
     assert!(!attribute.enumerable());
     assert!(!attribute.configurable());
 }
+
+#[test]
+fn set_flag_to_false_clears_it() {
+    let mut attribute = Attribute::all();
+
+    attribute.set_writable(false);
+    attribute.set_enumerable(false);
+
+    assert!(!attribute.writable());
+    assert!(!attribute.enumerable());
+    assert!(attribute.configurable());
+
+    attribute.set_configurable(false);
+
+    assert_eq!(attribute, Attribute::default());
+}
+
+#[test]
+fn builder() {
+    assert_eq!(Attribute::builder().build(), Attribute::default());
+    assert_eq!(
+        Attribute::builder()
+            .enumerable()
+            .configurable()
+            .writable()
+            .build(),
+        Attribute::all()
+    );
+
+    let attribute: Attribute = Attribute::builder().enumerable().into();
+    assert!(!attribute.writable());
+    assert!(attribute.enumerable());
+    assert!(!attribute.configurable());
+}
+
+#[test]
+#[should_panic(expected = "the `writable` attribute was set twice")]
+fn builder_rejects_duplicate_flags() {
+    Attribute::builder().writable().configurable().writable();
+}
+
+#[test]
+fn presets() {
+    assert_eq!(
+        Attribute::builtin(),
+        Attribute::WRITABLE | Attribute::CONFIGURABLE
+    );
+    assert_eq!(Attribute::readonly_builtin(), Attribute::CONFIGURABLE);
+    assert_eq!(Attribute::constant(), Attribute::empty());
+}
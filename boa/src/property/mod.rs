@@ -16,12 +16,13 @@
 
 use crate::{
     gc::{Finalize, Trace},
-    JsString, JsSymbol, JsValue,
+    object::JsObject,
+    Context, JsResult, JsString, JsSymbol, JsValue,
 };
-use std::{convert::TryFrom, fmt};
+use std::{convert::TryFrom, fmt, rc::Rc};
 
 mod attribute;
-pub use attribute::Attribute;
+pub use attribute::{Attribute, AttributeBuilder};
 use gc::unsafe_empty_trace;
 
 /// This represents a JavaScript Property AKA The Property Descriptor.
@@ -63,9 +64,68 @@ pub enum DescriptorKind {
         get: Option<JsValue>,
         set: Option<JsValue>,
     },
+    /// A data property watched by the host, which behaves as a data descriptor for scripts.
+    Watched {
+        value: Option<JsValue>,
+        writable: Option<bool>,
+        watcher: PropertyWatcher,
+    },
     Generic,
 }
 
+/// The change made to a watched property, reported to its [`PropertyWatcher`].
+#[derive(Debug, Clone)]
+pub enum PropertyChange {
+    /// The property was written, with its previous and its new value.
+    Write { old: JsValue, new: JsValue },
+    /// The property was deleted, with its last value.
+    Delete { old: JsValue },
+}
+
+/// The type of the callbacks watching a property.
+pub type WatcherFunction =
+    dyn Fn(&JsObject, &PropertyKey, PropertyChange, &mut Context) -> JsResult<()>;
+
+/// A host callback called each time a watched property is written or deleted.
+///
+/// The error returned by the callback is thrown by the operation that changed the property.
+#[derive(Clone, Finalize)]
+pub struct PropertyWatcher(Rc<WatcherFunction>);
+
+// SAFETY: the watcher is a host callback, which doesn't hold any traceable value.
+unsafe impl Trace for PropertyWatcher {
+    unsafe_empty_trace!();
+}
+
+impl PropertyWatcher {
+    /// Creates a watcher from the given callback.
+    #[inline]
+    pub fn new<F>(function: F) -> Self
+    where
+        F: Fn(&JsObject, &PropertyKey, PropertyChange, &mut Context) -> JsResult<()> + 'static,
+    {
+        Self(Rc::new(function))
+    }
+
+    /// Reports the `change` of the property `key` of `obj` to the watcher.
+    #[inline]
+    pub(crate) fn call(
+        &self,
+        obj: &JsObject,
+        key: &PropertyKey,
+        change: PropertyChange,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        (self.0)(obj, key, change, context)
+    }
+}
+
+impl fmt::Debug for PropertyWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PropertyWatcher")
+    }
+}
+
 impl Default for DescriptorKind {
     fn default() -> Self {
         Self::Generic
@@ -92,7 +152,10 @@ impl PropertyDescriptor {
     /// [spec]: https://tc39.es/ecma262/#sec-isdatadescriptor
     #[inline]
     pub fn is_data_descriptor(&self) -> bool {
-        matches!(self.kind, DescriptorKind::Data { .. })
+        matches!(
+            self.kind,
+            DescriptorKind::Data { .. } | DescriptorKind::Watched { .. }
+        )
     }
 
     /// A generic Property Descriptor is one that is neither a data descriptor nor an accessor descriptor.
@@ -124,7 +187,9 @@ impl PropertyDescriptor {
     #[inline]
     pub fn writable(&self) -> Option<bool> {
         match self.kind {
-            DescriptorKind::Data { writable, .. } => writable,
+            DescriptorKind::Data { writable, .. } | DescriptorKind::Watched { writable, .. } => {
+                writable
+            }
             _ => None,
         }
     }
@@ -132,11 +197,40 @@ impl PropertyDescriptor {
     #[inline]
     pub fn value(&self) -> Option<&JsValue> {
         match &self.kind {
-            DescriptorKind::Data { value, .. } => value.as_ref(),
+            DescriptorKind::Data { value, .. } | DescriptorKind::Watched { value, .. } => {
+                value.as_ref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the host watcher of a watched data descriptor.
+    #[inline]
+    pub fn watcher(&self) -> Option<&PropertyWatcher> {
+        match &self.kind {
+            DescriptorKind::Watched { watcher, .. } => Some(watcher),
             _ => None,
         }
     }
 
+    /// Gets the value of a writable data descriptor mutably.
+    ///
+    /// Returns `None` for a watched data descriptor, since the writes of its value have to be
+    /// reported to its watcher, as well as for accessor and non-writable descriptors.
+    #[inline]
+    pub(crate) fn writable_value_mut(&mut self) -> Option<&mut JsValue> {
+        match &mut self.kind {
+            DescriptorKind::Data {
+                value: Some(value),
+                writable: Some(true),
+            } => Some(value),
+            DescriptorKind::Data { .. }
+            | DescriptorKind::Watched { .. }
+            | DescriptorKind::Accessor { .. }
+            | DescriptorKind::Generic => None,
+        }
+    }
+
     #[inline]
     pub fn get(&self) -> Option<&JsValue> {
         match &self.kind {
@@ -238,6 +332,30 @@ impl PropertyDescriptor {
             .build()
     }
 
+    /// Converts a data descriptor into a descriptor watched by `watcher`.
+    #[inline]
+    pub(crate) fn into_watched(mut self, watcher: PropertyWatcher) -> Self {
+        debug_assert!(self.is_data_descriptor());
+        self.kind = DescriptorKind::Watched {
+            value: self.value().cloned(),
+            writable: self.writable(),
+            watcher,
+        };
+        self
+    }
+
+    /// Converts a watched descriptor back into a plain data descriptor.
+    #[inline]
+    pub(crate) fn into_unwatched(mut self) -> Self {
+        if self.watcher().is_some() {
+            self.kind = DescriptorKind::Data {
+                value: self.value().cloned(),
+                writable: self.writable(),
+            };
+        }
+        self
+    }
+
     #[inline]
     pub fn complete_property_descriptor(self) -> Self {
         PropertyDescriptorBuilder { inner: self }
@@ -249,10 +367,18 @@ impl PropertyDescriptor {
     pub fn fill_with(&mut self, desc: Self) {
         match (&mut self.kind, &desc.kind) {
             (
-                DescriptorKind::Data { value, writable },
+                DescriptorKind::Data { value, writable }
+                | DescriptorKind::Watched {
+                    value, writable, ..
+                },
                 DescriptorKind::Data {
                     value: desc_value,
                     writable: desc_writable,
+                }
+                | DescriptorKind::Watched {
+                    value: desc_value,
+                    writable: desc_writable,
+                    ..
                 },
             ) => {
                 if let Some(desc_value) = desc_value {
@@ -303,6 +429,9 @@ impl PropertyDescriptorBuilder {
         match self.inner.kind {
             DescriptorKind::Data {
                 value: ref mut v, ..
+            }
+            | DescriptorKind::Watched {
+                value: ref mut v, ..
             } => *v = Some(value.into()),
             // TODO: maybe panic when trying to convert accessor to data?
             _ => {
@@ -320,6 +449,10 @@ impl PropertyDescriptorBuilder {
             DescriptorKind::Data {
                 writable: ref mut w,
                 ..
+            }
+            | DescriptorKind::Watched {
+                writable: ref mut w,
+                ..
             } => *w = Some(writable),
             // TODO: maybe panic when trying to convert accessor to data?
             _ => {
@@ -422,6 +555,11 @@ impl PropertyDescriptorBuilder {
             DescriptorKind::Data {
                 ref mut value,
                 ref mut writable,
+            }
+            | DescriptorKind::Watched {
+                ref mut value,
+                ref mut writable,
+                ..
             } => {
                 if value.is_none() {
                     *value = Some(JsValue::undefined())
@@ -480,10 +618,23 @@ pub enum PropertyKey {
     Index(u32),
 }
 
+/// Parses `string` as an integer index key.
+///
+/// Only the canonical representation of an index is accepted, so keys like `"01"` or `"+1"`
+/// stay string keys, keeping their own identity and insertion order.
+#[inline]
+fn parse_index(string: &str) -> Option<u32> {
+    match string.as_bytes() {
+        [b'0'] => Some(0),
+        [b'1'..=b'9', rest @ ..] if rest.iter().all(u8::is_ascii_digit) => string.parse().ok(),
+        _ => None,
+    }
+}
+
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string)
@@ -494,7 +645,7 @@ impl From<JsString> for PropertyKey {
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -505,7 +656,7 @@ impl From<&str> for PropertyKey {
 impl From<String> for PropertyKey {
     #[inline]
     fn from(string: String) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -516,7 +667,7 @@ impl From<String> for PropertyKey {
 impl From<Box<str>> for PropertyKey {
     #[inline]
     fn from(string: Box<str>) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
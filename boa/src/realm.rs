@@ -9,9 +9,11 @@ use crate::{
         global_environment_record::GlobalEnvironmentRecord, lexical_environment::LexicalEnvironment,
     },
     object::{JsObject, Object, ObjectData},
+    syntax::ast::node::template::{TemplateSite, WeakTemplateSite},
     BoaProfiler,
 };
 use gc::Gc;
+use rustc_hash::FxHashMap;
 
 /// Representation of a Realm.
 ///
@@ -21,6 +23,63 @@ pub struct Realm {
     pub global_object: JsObject,
     pub global_env: Gc<GlobalEnvironmentRecord>,
     pub environment: LexicalEnvironment,
+    /// The template objects created for tagged templates, keyed by their call site.
+    pub(crate) template_map: TemplateRegistry,
+}
+
+/// The `[[TemplateMap]]` of a realm, holding the template objects of the call sites of tagged
+/// templates.
+///
+/// The template object of a site is dropped once the realm finds that no node of the site is
+/// left, so evaluating tagged templates in new scripts doesn't grow the registry.
+#[derive(Debug, Default)]
+pub(crate) struct TemplateRegistry {
+    records: FxHashMap<u64, TemplateRecord>,
+    /// The number of records at which the records of the dropped sites are removed.
+    prune_at: usize,
+}
+
+/// A cached template object, the `[[TemplateMap]]` entry of a single call site.
+#[derive(Debug)]
+struct TemplateRecord {
+    site: WeakTemplateSite,
+    array: JsObject,
+}
+
+impl TemplateRegistry {
+    /// The least number of records at which the records of the dropped sites are removed.
+    const MIN_PRUNE_AT: usize = 16;
+
+    /// Gets the template object of the site.
+    pub(crate) fn get(&self, site: &TemplateSite) -> Option<JsObject> {
+        self.records
+            .get(&site.id())
+            .map(|record| record.array.clone())
+    }
+
+    /// Records the template object of the site.
+    pub(crate) fn insert(&mut self, site: &TemplateSite, array: JsObject) {
+        // The records are pruned each time their number doubles, which keeps the cost of
+        // pruning proportional to the number of insertions.
+        if self.records.len() >= self.prune_at {
+            self.records.retain(|_, record| record.site.is_alive());
+            self.prune_at = (self.records.len() * 2).max(Self::MIN_PRUNE_AT);
+        }
+
+        self.records.insert(
+            site.id(),
+            TemplateRecord {
+                site: site.downgrade(),
+                array,
+            },
+        );
+    }
+
+    /// Gets the number of recorded template objects.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.records.len()
+    }
 }
 
 impl Realm {
@@ -43,6 +102,7 @@ impl Realm {
             global_object: gc_global.clone(),
             global_env: Gc::new(global_env),
             environment: LexicalEnvironment::new(gc_global),
+            template_map: TemplateRegistry::default(),
         }
     }
 }
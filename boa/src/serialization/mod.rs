@@ -0,0 +1,1029 @@
+//! A binary serialization of JavaScript values that does not depend on the realm or the process
+//! they were created in.
+//!
+//! [`serialize`] turns a value into bytes that [`deserialize`] turns back into an equivalent value
+//! in any [`Context`], so values can be persisted or sent between processes embedding Boa. The
+//! format supports the values that can be [structured cloned][clone]:
+//!  - All primitives, except symbols.
+//!  - Plain objects and arrays, with their own enumerable string-keyed properties. Arrays keep
+//!    their length, so holes are preserved.
+//!  - `Date`, `RegExp`, `Map` and `Set` objects, and the `Boolean`, `Number`, `String` and
+//!    `BigInt` wrapper objects.
+//!  - Errors, with their `name`, `message` and `stack`.
+//!  - `ArrayBuffer`s, typed arrays and `DataView`s, sharing their buffers. Resizable buffers
+//!    stay resizable, and views tracking the length of their buffer keep tracking it.
+//!
+//! Objects referenced more than once are only serialized once, so shared references and cycles
+//! are restored by [`deserialize`]. Prototypes are not serialized: a deserialized object always
+//! gets the intrinsic prototype of its kind.
+//!
+//! Serializing any other object, like a function, a `Promise`, a `Proxy` or a
+//! `SharedArrayBuffer`, throws a `TypeError`.
+//!
+//! [`serialize_with_transfer`] additionally moves the contents of some `ArrayBuffer`s out of the
+//! serialized value instead of copying them, detaching the original buffers. The moved contents
+//! are handed back to [`deserialize_with_transfer`].
+//!
+//! # Examples
+//! ```
+//! # use boa::{serialization::{deserialize, serialize}, Context};
+//! let mut source = Context::new();
+//! let value = source
+//!     .eval("({ list: [1, 2, 3], when: new Date(0) })")
+//!     .unwrap();
+//! let bytes = serialize(&value, &mut source).unwrap();
+//!
+//! let mut target = Context::new();
+//! let value = deserialize(&bytes, &mut target).unwrap();
+//! target.register_global_property("value", value, Default::default());
+//! assert_eq!(
+//!     target
+//!         .eval("value.list.length + value.when.getTime()")
+//!         .unwrap()
+//!         .as_number(),
+//!     Some(3.0)
+//! );
+//! ```
+//!
+//! [clone]: https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    bigint::RawBigInt,
+    builtins::{
+        array_buffer::ArrayBuffer, date::Date, map::ordered_map::OrderedMap,
+        set::ordered_set::OrderedSet, typed_array::TypedArrayName, Array, RegExp,
+    },
+    error::JsErrorCode,
+    error::JsNativeErrorKind,
+    object::{JsObject, ObjectData, ObjectKind},
+    property::{PropertyDescriptor, PropertyNameKind},
+    Context, JsBigInt, JsResult, JsString, JsValue,
+};
+use std::convert::TryFrom;
+
+/// The bytes every serialized value starts with.
+const MAGIC: &[u8; 3] = b"BOA";
+
+/// The version of the format, incremented on incompatible changes.
+const VERSION: u8 = 2;
+
+/// The typed arrays, in the order of their serialized tag.
+const TYPED_ARRAYS: [TypedArrayName; 11] = [
+    TypedArrayName::Int8Array,
+    TypedArrayName::Uint8Array,
+    TypedArrayName::Uint8ClampedArray,
+    TypedArrayName::Int16Array,
+    TypedArrayName::Uint16Array,
+    TypedArrayName::Int32Array,
+    TypedArrayName::Uint32Array,
+    TypedArrayName::BigInt64Array,
+    TypedArrayName::BigUint64Array,
+    TypedArrayName::Float32Array,
+    TypedArrayName::Float64Array,
+];
+
+/// The native errors that keep their kind, in the order of their serialized tag.
+const ERROR_KINDS: [(&str, JsNativeErrorKind); 7] = [
+    ("Error", JsNativeErrorKind::Error),
+    ("EvalError", JsNativeErrorKind::Eval),
+    ("RangeError", JsNativeErrorKind::Range),
+    ("ReferenceError", JsNativeErrorKind::Reference),
+    ("SyntaxError", JsNativeErrorKind::Syntax),
+    ("TypeError", JsNativeErrorKind::Type),
+    ("URIError", JsNativeErrorKind::Uri),
+];
+
+/// The tag preceding every serialized value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Tag {
+    Undefined,
+    Null,
+    False,
+    True,
+    Number,
+    BigInt,
+    String,
+    /// An object that was already serialized, by its index in the order of serialization.
+    Reference,
+    Object,
+    Array,
+    Date,
+    RegExp,
+    Map,
+    Set,
+    BooleanObject,
+    NumberObject,
+    StringObject,
+    BigIntObject,
+    Error,
+    ArrayBuffer,
+    TypedArray,
+    DataView,
+    /// An `ArrayBuffer` whose contents are transferred, by its index in the transfer list.
+    TransferredArrayBuffer,
+}
+
+impl TryFrom<u8> for Tag {
+    type Error = ();
+
+    fn try_from(tag: u8) -> Result<Self, ()> {
+        const TAGS: [Tag; 23] = [
+            Tag::Undefined,
+            Tag::Null,
+            Tag::False,
+            Tag::True,
+            Tag::Number,
+            Tag::BigInt,
+            Tag::String,
+            Tag::Reference,
+            Tag::Object,
+            Tag::Array,
+            Tag::Date,
+            Tag::RegExp,
+            Tag::Map,
+            Tag::Set,
+            Tag::BooleanObject,
+            Tag::NumberObject,
+            Tag::StringObject,
+            Tag::BigIntObject,
+            Tag::Error,
+            Tag::ArrayBuffer,
+            Tag::TypedArray,
+            Tag::DataView,
+            Tag::TransferredArrayBuffer,
+        ];
+        TAGS.get(usize::from(tag)).copied().ok_or(())
+    }
+}
+
+/// Serializes a value to bytes that can be deserialized by [`deserialize`].
+///
+/// Reading the properties of objects can run getters, and this function throws their errors. It
+/// throws a `TypeError` if the value contains something that cannot be serialized.
+/// Values nesting objects deeper than [`Context::max_nesting_depth`] throw a `RangeError`.
+///
+/// See the [module documentation](self) for the supported values.
+pub fn serialize(value: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    serialize_value(value, Vec::new(), context)
+}
+
+/// Serializes a value like [`serialize`], but transfers the contents of the `ArrayBuffer`s in
+/// `transfer` instead of copying them.
+///
+/// Returns the serialized bytes, and the contents of the transferred buffers in the order of
+/// `transfer`. The transferred buffers are detached once the value is serialized.
+///
+/// Throws a `TypeError` if `transfer` contains anything other than `ArrayBuffer`s that are not
+/// detached, or the same buffer twice.
+///
+/// # Examples
+/// ```
+/// # use boa::{serialization::{deserialize_with_transfer, serialize_with_transfer}, Context};
+/// let mut context = Context::new();
+/// let buffer = context.eval("var buffer = new ArrayBuffer(8); buffer").unwrap();
+/// let buffer = buffer.as_object().unwrap();
+///
+/// let (bytes, transferred) =
+///     serialize_with_transfer(&buffer.clone().into(), &[buffer], &mut context).unwrap();
+/// assert_eq!(context.eval("buffer.byteLength").unwrap().as_number(), Some(0.0));
+///
+/// let clone = deserialize_with_transfer(&bytes, transferred, &mut context).unwrap();
+/// context.register_global_property("clone", clone, Default::default());
+/// assert_eq!(context.eval("clone.byteLength").unwrap().as_number(), Some(8.0));
+/// ```
+pub fn serialize_with_transfer(
+    value: &JsValue,
+    transfer: &[JsObject],
+    context: &mut Context,
+) -> JsResult<(Vec<u8>, Vec<Vec<u8>>)> {
+    for (index, object) in transfer.iter().enumerate() {
+        let transferable = match object.borrow().as_array_buffer() {
+            Some(buffer) => !buffer.is_shared_array_buffer() && !buffer.is_detached_buffer(),
+            None => false,
+        };
+        if !transferable {
+            return Err(context.construct_type_error(
+                "Only ArrayBuffers that are not detached can be transferred",
+            ));
+        }
+        if transfer[..index]
+            .iter()
+            .any(|previous| JsObject::equals(previous, object))
+        {
+            return Err(
+                context.construct_type_error("An ArrayBuffer is transferred more than once")
+            );
+        }
+    }
+
+    let bytes = serialize_value(value, transfer.to_vec(), context)?;
+    let transferred = transfer
+        .iter()
+        .map(|object| {
+            object
+                .borrow_mut()
+                .as_array_buffer_mut()
+                .and_then(ArrayBuffer::detach)
+                .expect("transferred buffers are checked before serializing")
+        })
+        .collect();
+    Ok((bytes, transferred))
+}
+
+fn serialize_value(
+    value: &JsValue,
+    transfer: Vec<JsObject>,
+    context: &mut Context,
+) -> JsResult<Vec<u8>> {
+    let mut serializer = Serializer {
+        output: MAGIC.to_vec(),
+        memory: Vec::new(),
+        transfer,
+    };
+    serializer.output.push(VERSION);
+    serializer.write(value, context)?;
+    Ok(serializer.output)
+}
+
+/// Deserializes a value from the bytes produced by [`serialize`], creating its objects in the
+/// realm of `context`.
+///
+/// Throws a `TypeError` if the bytes are not a serialized value, and a `RangeError` if the value
+/// nests objects deeper than [`Context::max_nesting_depth`].
+pub fn deserialize(bytes: &[u8], context: &mut Context) -> JsResult<JsValue> {
+    deserialize_with_transfer(bytes, Vec::new(), context)
+}
+
+/// Deserializes a value from the bytes and the transferred buffer contents produced by
+/// [`serialize_with_transfer`], creating its objects in the realm of `context`.
+///
+/// Throws a `TypeError` if the bytes are not a serialized value, or if they refer to a
+/// transferred buffer that is missing.
+pub fn deserialize_with_transfer(
+    bytes: &[u8],
+    transferred: Vec<Vec<u8>>,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let mut deserializer = Deserializer {
+        input: bytes,
+        position: 0,
+        memory: Vec::new(),
+        transferred: transferred.into_iter().map(Some).collect(),
+    };
+    if deserializer.read_bytes(MAGIC.len(), context)? != MAGIC
+        || deserializer.read_u8(context)? != VERSION
+    {
+        return Err(invalid(context, "unknown format"));
+    }
+    let value = deserializer.read(context)?;
+    if deserializer.position != bytes.len() {
+        return Err(invalid(context, "trailing bytes"));
+    }
+    Ok(value)
+}
+
+/// Constructs the error thrown for bytes that are not a serialized value.
+fn invalid(context: &mut Context, reason: &str) -> JsValue {
+    context.construct_type_error(format!("Invalid serialized value: {}", reason))
+}
+
+/// The data of an object that is serialized, copied out of the object so that its properties can
+/// be read while serializing it.
+#[derive(Debug)]
+enum Kind {
+    Object,
+    Array,
+    Date(f64),
+    RegExp(JsString, JsString),
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+    Boolean(bool),
+    Number(f64),
+    String(JsString),
+    BigInt(JsBigInt),
+    Error,
+    ArrayBuffer(Vec<u8>, Option<usize>),
+    TypedArray(TypedArrayName, JsObject, usize, Option<usize>),
+    DataView(JsObject, usize, Option<usize>),
+}
+
+/// The contents of an object that remain to be serialized.
+#[derive(Debug)]
+enum PendingWrite {
+    /// The keys of the own enumerable string-keyed properties of an object, and the index of the
+    /// next one.
+    Properties(JsObject, Vec<JsValue>, usize),
+    /// The keys and values of a `Map`, or the values of a `Set`, and the index of the next one.
+    Values(Vec<JsValue>, usize),
+}
+
+/// The state of a serialization.
+#[derive(Debug)]
+struct Serializer {
+    output: Vec<u8>,
+    /// The objects serialized so far, in order.
+    memory: Vec<JsObject>,
+    /// The buffers whose contents are transferred.
+    transfer: Vec<JsObject>,
+}
+
+impl Serializer {
+    fn write_tag(&mut self, tag: Tag) {
+        self.output.push(tag as u8);
+    }
+
+    fn write_u32(&mut self, value: usize, context: &mut Context) -> JsResult<()> {
+        let value = u32::try_from(value)
+            .map_err(|_| context.construct_range_error("Value is too large to be serialized"))?;
+        self.output.extend_from_slice(&value.to_le_bytes());
+        Ok(())
+    }
+
+    fn write_optional_u32(&mut self, value: Option<usize>, context: &mut Context) -> JsResult<()> {
+        match value {
+            Some(value) => {
+                self.output.push(1);
+                self.write_u32(value, context)
+            }
+            None => {
+                self.output.push(0);
+                Ok(())
+            }
+        }
+    }
+
+    fn write_f64(&mut self, value: f64) {
+        self.output.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8], context: &mut Context) -> JsResult<()> {
+        self.write_u32(bytes.len(), context)?;
+        self.output.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn write_string(&mut self, string: &str, context: &mut Context) -> JsResult<()> {
+        self.write_bytes(string.as_bytes(), context)
+    }
+
+    fn write_bigint(&mut self, bigint: &JsBigInt, context: &mut Context) -> JsResult<()> {
+        self.write_bytes(&bigint.as_inner().to_signed_bytes_le(), context)
+    }
+
+    /// Writes a value, and the contents of the objects nested in it.
+    ///
+    /// The contents of the nested objects are written with an explicit stack of the objects
+    /// being written instead of recursive calls, so deeply nested values cannot overflow the
+    /// native stack.
+    fn write(&mut self, value: &JsValue, context: &mut Context) -> JsResult<()> {
+        let mut stack = Vec::new();
+        let mut pending = self.write_value(value, context)?;
+        loop {
+            if let Some(pending) = pending.take() {
+                context.check_nesting_depth(stack.len() + 1)?;
+                stack.push(pending);
+            }
+
+            let next = match stack.last_mut() {
+                None => return Ok(()),
+                Some(PendingWrite::Properties(object, keys, index)) => match keys.get(*index) {
+                    Some(key) => {
+                        *index += 1;
+                        let key = key.to_string(context)?;
+                        let value = object.get(key.clone(), context)?;
+                        self.write_string(&key, context)?;
+                        Some(value)
+                    }
+                    None => None,
+                },
+                Some(PendingWrite::Values(values, index)) => {
+                    let value = values.get(*index).cloned();
+                    *index += 1;
+                    value
+                }
+            };
+            match next {
+                Some(value) => pending = self.write_value(&value, context)?,
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    /// Writes a value, returning the contents of the object it is, if they remain to be written.
+    fn write_value(
+        &mut self,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<PendingWrite>> {
+        match value {
+            JsValue::Undefined => self.write_tag(Tag::Undefined),
+            JsValue::Null => self.write_tag(Tag::Null),
+            JsValue::Boolean(false) => self.write_tag(Tag::False),
+            JsValue::Boolean(true) => self.write_tag(Tag::True),
+            JsValue::Integer(integer) => {
+                self.write_tag(Tag::Number);
+                self.write_f64(f64::from(*integer));
+            }
+            JsValue::Rational(rational) => {
+                self.write_tag(Tag::Number);
+                self.write_f64(*rational);
+            }
+            JsValue::BigInt(bigint) => {
+                self.write_tag(Tag::BigInt);
+                self.write_bigint(bigint, context)?;
+            }
+            JsValue::String(string) => {
+                self.write_tag(Tag::String);
+                self.write_string(string, context)?;
+            }
+            JsValue::Symbol(_) => {
+                return context
+                    .throw_type_error("Symbols cannot be serialized")
+                    .map(|_| None)
+            }
+            JsValue::Object(object) => return self.write_object(object, context),
+        }
+        Ok(None)
+    }
+
+    fn write_object(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Option<PendingWrite>> {
+        if let Some(index) = self
+            .memory
+            .iter()
+            .position(|seen| JsObject::equals(seen, object))
+        {
+            self.write_tag(Tag::Reference);
+            self.write_u32(index, context)?;
+            return Ok(None);
+        }
+        self.memory.push(object.clone());
+
+        if let Some(index) = self
+            .transfer
+            .iter()
+            .position(|transferred| JsObject::equals(transferred, object))
+        {
+            let max_byte_length = object
+                .borrow()
+                .as_array_buffer()
+                .and_then(ArrayBuffer::max_byte_length);
+            self.write_tag(Tag::TransferredArrayBuffer);
+            self.write_u32(index, context)?;
+            self.write_optional_u32(max_byte_length, context)?;
+            return Ok(None);
+        }
+
+        let kind = {
+            let object = object.borrow();
+            match object.kind() {
+                ObjectKind::Ordinary => Some(Kind::Object),
+                ObjectKind::Array => Some(Kind::Array),
+                ObjectKind::Date(date) => Some(Kind::Date(date.get_time())),
+                ObjectKind::RegExp(regexp) => Some(Kind::RegExp(
+                    regexp.original_source().clone(),
+                    regexp.original_flags().clone(),
+                )),
+                ObjectKind::Map(map) => Some(Kind::Map(
+                    map.iter()
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect(),
+                )),
+                ObjectKind::Set(set) => Some(Kind::Set(set.iter().cloned().collect())),
+                ObjectKind::Boolean(boolean) => Some(Kind::Boolean(*boolean)),
+                ObjectKind::Number(number) => Some(Kind::Number(*number)),
+                ObjectKind::String(string) => Some(Kind::String(string.clone())),
+                ObjectKind::BigInt(bigint) => Some(Kind::BigInt(bigint.clone())),
+                ObjectKind::Error => Some(Kind::Error),
+                ObjectKind::ArrayBuffer(buffer) if !buffer.is_shared_array_buffer() => buffer
+                    .bytes()
+                    .map(|bytes| Kind::ArrayBuffer(bytes.to_vec(), buffer.max_byte_length())),
+                // Views that are out of bounds can't be constructed again.
+                ObjectKind::IntegerIndexed(typed_array) if !typed_array.is_out_of_bounds() => {
+                    typed_array.viewed_array_buffer().map(|buffer| {
+                        Kind::TypedArray(
+                            typed_array.typed_array_name(),
+                            buffer.clone(),
+                            typed_array.byte_offset(),
+                            Some(typed_array.array_length())
+                                .filter(|_| !typed_array.is_length_tracking()),
+                        )
+                    })
+                }
+                ObjectKind::DataView(data_view) if !data_view.is_out_of_bounds() => {
+                    Some(Kind::DataView(
+                        data_view.viewed_array_buffer().clone(),
+                        data_view.byte_offset(),
+                        Some(data_view.byte_length()).filter(|_| !data_view.is_length_tracking()),
+                    ))
+                }
+                _ => None,
+            }
+        };
+        let kind = match kind {
+            Some(kind) => kind,
+            None => {
+                let name = object.borrow().kind().to_string();
+                return context
+                    .throw_coded_error(
+                        JsErrorCode::NOT_SERIALIZABLE,
+                        format!("{} objects cannot be serialized", name),
+                    )
+                    .map(|_| None);
+            }
+        };
+
+        match kind {
+            Kind::Object => {
+                self.write_tag(Tag::Object);
+                return self.write_properties(object, context).map(Some);
+            }
+            Kind::Array => {
+                self.write_tag(Tag::Array);
+                let length = object.length_of_array_like(context)?;
+                self.write_u32(length, context)?;
+                return self.write_properties(object, context).map(Some);
+            }
+            Kind::Date(time) => {
+                self.write_tag(Tag::Date);
+                self.write_f64(time);
+            }
+            Kind::RegExp(source, flags) => {
+                self.write_tag(Tag::RegExp);
+                self.write_string(&source, context)?;
+                self.write_string(&flags, context)?;
+            }
+            Kind::Map(entries) => {
+                self.write_tag(Tag::Map);
+                self.write_u32(entries.len(), context)?;
+                let values = entries
+                    .into_iter()
+                    .flat_map(|(key, value)| [key, value])
+                    .collect();
+                return Ok(Some(PendingWrite::Values(values, 0)));
+            }
+            Kind::Set(values) => {
+                self.write_tag(Tag::Set);
+                self.write_u32(values.len(), context)?;
+                return Ok(Some(PendingWrite::Values(values, 0)));
+            }
+            Kind::Boolean(boolean) => {
+                self.write_tag(Tag::BooleanObject);
+                self.output.push(u8::from(boolean));
+            }
+            Kind::Number(number) => {
+                self.write_tag(Tag::NumberObject);
+                self.write_f64(number);
+            }
+            Kind::String(string) => {
+                self.write_tag(Tag::StringObject);
+                self.write_string(&string, context)?;
+            }
+            Kind::BigInt(bigint) => {
+                self.write_tag(Tag::BigIntObject);
+                self.write_bigint(&bigint, context)?;
+            }
+            Kind::Error => {
+                self.write_tag(Tag::Error);
+
+                // Errors of an unknown name are serialized as plain errors.
+                let name = object.get("name", context)?.to_string(context)?;
+                let kind = ERROR_KINDS
+                    .iter()
+                    .position(|(kind, _)| *kind == name.as_str())
+                    .unwrap_or(0);
+                self.output.push(kind as u8);
+
+                for property in &["message", "stack"] {
+                    if object.has_own_property(*property, context)? {
+                        let value = object.get(*property, context)?.to_string(context)?;
+                        self.output.push(1);
+                        self.write_string(&value, context)?;
+                    } else {
+                        self.output.push(0);
+                    }
+                }
+            }
+            Kind::ArrayBuffer(bytes, max_byte_length) => {
+                self.write_tag(Tag::ArrayBuffer);
+                self.write_bytes(&bytes, context)?;
+                self.write_optional_u32(max_byte_length, context)?;
+            }
+            Kind::TypedArray(name, buffer, byte_offset, length) => {
+                self.write_tag(Tag::TypedArray);
+                let name = TYPED_ARRAYS
+                    .iter()
+                    .position(|typed_array| *typed_array == name)
+                    .expect("every typed array has a tag");
+                self.output.push(name as u8);
+                // Buffers have no contents left to write.
+                self.write_object(&buffer, context)?;
+                self.write_u32(byte_offset, context)?;
+                // Views tracking the length of their buffer have no length.
+                self.write_optional_u32(length, context)?;
+            }
+            Kind::DataView(buffer, byte_offset, byte_length) => {
+                self.write_tag(Tag::DataView);
+                // Buffers have no contents left to write.
+                self.write_object(&buffer, context)?;
+                self.write_u32(byte_offset, context)?;
+                self.write_optional_u32(byte_length, context)?;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Writes the number of own enumerable string-keyed properties of an object, returning the
+    /// properties that remain to be written.
+    fn write_properties(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<PendingWrite> {
+        let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        self.write_u32(keys.len(), context)?;
+        Ok(PendingWrite::Properties(object.clone(), keys, 0))
+    }
+}
+
+/// The contents of an object that remain to be deserialized.
+#[derive(Debug)]
+enum PendingRead {
+    /// An object, and the number of its properties left to read.
+    Properties(JsObject, usize),
+    /// A `Map`, the number of its entries left to read, and the key of the entry being read.
+    Entries(JsObject, usize, Option<JsValue>),
+    /// A `Set`, and the number of its values left to read.
+    Values(JsObject, usize),
+}
+
+/// The state of a deserialization.
+#[derive(Debug)]
+struct Deserializer<'a> {
+    input: &'a [u8],
+    position: usize,
+    /// The objects deserialized so far, in order. The slot of an object is reserved before its
+    /// contents are read, and is `None` until the object is created.
+    memory: Vec<Option<JsObject>>,
+    /// The contents of the transferred buffers, taken when their buffer is created.
+    transferred: Vec<Option<Vec<u8>>>,
+}
+
+impl Deserializer<'_> {
+    fn read_bytes(&mut self, len: usize, context: &mut Context) -> JsResult<&[u8]> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.input.len())
+            .ok_or_else(|| invalid(context, "unexpected end of input"))?;
+        let bytes = &self.input[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self, context: &mut Context) -> JsResult<u8> {
+        Ok(self.read_bytes(1, context)?[0])
+    }
+
+    fn read_u32(&mut self, context: &mut Context) -> JsResult<usize> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.read_bytes(4, context)?);
+        Ok(u32::from_le_bytes(bytes) as usize)
+    }
+
+    fn read_f64(&mut self, context: &mut Context) -> JsResult<f64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.read_bytes(8, context)?);
+        Ok(f64::from_le_bytes(bytes))
+    }
+
+    fn read_byte_vec(&mut self, context: &mut Context) -> JsResult<Vec<u8>> {
+        let len = self.read_u32(context)?;
+        Ok(self.read_bytes(len, context)?.to_vec())
+    }
+
+    fn read_string(&mut self, context: &mut Context) -> JsResult<JsString> {
+        let bytes = self.read_byte_vec(context)?;
+        match String::from_utf8(bytes) {
+            Ok(string) => Ok(string.into()),
+            Err(_) => Err(invalid(context, "malformed string")),
+        }
+    }
+
+    fn read_bigint(&mut self, context: &mut Context) -> JsResult<JsBigInt> {
+        let bytes = self.read_byte_vec(context)?;
+        Ok(RawBigInt::from_signed_bytes_le(&bytes).into())
+    }
+
+    /// Reserves the slot of the next object, returning its index.
+    fn reserve(&mut self) -> usize {
+        self.memory.push(None);
+        self.memory.len() - 1
+    }
+
+    /// Creates an object with the intrinsic `prototype` and `data`, and stores it in the next
+    /// slot.
+    fn create(&mut self, prototype: JsObject, data: ObjectData, context: &mut Context) -> JsObject {
+        let object = context.construct_object();
+        object.set_prototype_instance(prototype.into());
+        object.borrow_mut().data = data;
+        self.memory.push(Some(object.clone()));
+        object
+    }
+
+    /// Reads a value, and the contents of the objects nested in it.
+    ///
+    /// Like [`Serializer::write`], the contents of the nested objects are read with an explicit
+    /// stack instead of recursive calls.
+    fn read(&mut self, context: &mut Context) -> JsResult<JsValue> {
+        let mut stack = Vec::new();
+        let (value, mut pending) = self.read_value(context)?;
+        loop {
+            if let Some(pending) = pending.take() {
+                context.check_nesting_depth(stack.len() + 1)?;
+                stack.push(pending);
+            }
+
+            match stack.last_mut() {
+                None => return Ok(value),
+                Some(PendingRead::Properties(object, remaining)) if *remaining > 0 => {
+                    *remaining -= 1;
+                    let key = self.read_string(context)?;
+                    let (value, contents) = self.read_value(context)?;
+                    object.create_data_property_or_throw(key, value, context)?;
+                    pending = contents;
+                }
+                Some(PendingRead::Entries(map, remaining, key)) if *remaining > 0 => {
+                    let (value, contents) = self.read_value(context)?;
+                    match key.take() {
+                        None => *key = Some(value),
+                        Some(key) => {
+                            *remaining -= 1;
+                            if let Some(map) = map.borrow_mut().as_map_mut() {
+                                map.insert(key, value);
+                            }
+                        }
+                    }
+                    pending = contents;
+                }
+                Some(PendingRead::Values(set, remaining)) if *remaining > 0 => {
+                    *remaining -= 1;
+                    let (value, contents) = self.read_value(context)?;
+                    if let Some(set) = set.borrow_mut().as_set_mut() {
+                        set.add(value);
+                    }
+                    pending = contents;
+                }
+                Some(_) => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    /// Reads a value, returning the contents of the object it is, if they remain to be read.
+    fn read_value(&mut self, context: &mut Context) -> JsResult<(JsValue, Option<PendingRead>)> {
+        let tag = self.read_u8(context)?;
+        let tag = Tag::try_from(tag).map_err(|_| invalid(context, "unknown tag"))?;
+        let value = match tag {
+            Tag::Undefined => JsValue::undefined(),
+            Tag::Null => JsValue::null(),
+            Tag::False => JsValue::new(false),
+            Tag::True => JsValue::new(true),
+            Tag::Number => JsValue::new(self.read_f64(context)?),
+            Tag::BigInt => JsValue::new(self.read_bigint(context)?),
+            Tag::String => JsValue::new(self.read_string(context)?),
+            Tag::Reference => {
+                let index = self.read_u32(context)?;
+                match self.memory.get(index) {
+                    Some(Some(object)) => object.clone().into(),
+                    _ => return Err(invalid(context, "dangling reference")),
+                }
+            }
+            Tag::Object => {
+                let object = context.construct_object();
+                self.memory.push(Some(object.clone()));
+                let properties = self.read_u32(context)?;
+                return Ok((
+                    object.clone().into(),
+                    Some(PendingRead::Properties(object, properties)),
+                ));
+            }
+            Tag::Array => {
+                let length = self.read_u32(context)?;
+                let array = Array::array_create(length, None, context)?;
+                self.memory.push(Some(array.clone()));
+                let properties = self.read_u32(context)?;
+                return Ok((
+                    array.clone().into(),
+                    Some(PendingRead::Properties(array, properties)),
+                ));
+            }
+            Tag::Date => {
+                let time = self.read_f64(context)?;
+                let prototype = context.standard_objects().date_object().prototype();
+                self.create(prototype, ObjectData::date(Date::new(time)), context)
+                    .into()
+            }
+            Tag::RegExp => {
+                let index = self.reserve();
+                let source = self.read_string(context)?;
+                let flags = self.read_string(context)?;
+                let regexp = RegExp::create(source.into(), flags.into(), context)?;
+                self.memory[index] = regexp.as_object();
+                regexp
+            }
+            Tag::Map => {
+                let prototype = context.standard_objects().map_object().prototype();
+                let map = self.create(prototype, ObjectData::map(OrderedMap::new()), context);
+                let entries = self.read_u32(context)?;
+                return Ok((
+                    map.clone().into(),
+                    Some(PendingRead::Entries(map, entries, None)),
+                ));
+            }
+            Tag::Set => {
+                let prototype = context.standard_objects().set_object().prototype();
+                let set = self.create(prototype, ObjectData::set(OrderedSet::new()), context);
+                let values = self.read_u32(context)?;
+                return Ok((set.clone().into(), Some(PendingRead::Values(set, values))));
+            }
+            Tag::BooleanObject => {
+                let boolean = self.read_u8(context)? != 0;
+                self.wrap(boolean.into(), context)?
+            }
+            Tag::NumberObject => {
+                let number = self.read_f64(context)?;
+                self.wrap(number.into(), context)?
+            }
+            Tag::StringObject => {
+                let string = self.read_string(context)?;
+                self.wrap(string.into(), context)?
+            }
+            Tag::BigIntObject => {
+                let bigint = self.read_bigint(context)?;
+                self.wrap(bigint.into(), context)?
+            }
+            Tag::Error => {
+                let index = self.reserve();
+                let kind = self.read_u8(context)?;
+                let kind = match ERROR_KINDS.get(usize::from(kind)) {
+                    Some((_, kind)) => *kind,
+                    None => return Err(invalid(context, "unknown error kind")),
+                };
+                let message = self.read_optional_string(context)?;
+                let stack = self.read_optional_string(context)?;
+
+                let constructor = context.intrinsics().native_error(kind).constructor();
+                let args: Vec<JsValue> = message.into_iter().map(JsValue::new).collect();
+                let error = constructor.construct(&args, &constructor.clone().into(), context)?;
+                let error = error
+                    .as_object()
+                    .expect("error constructors return objects");
+
+                // The stack captured by the constructor is the one of the deserialization.
+                match stack {
+                    Some(stack) => {
+                        error.insert_property(
+                            "stack",
+                            PropertyDescriptor::builder()
+                                .value(stack)
+                                .writable(true)
+                                .enumerable(false)
+                                .configurable(true),
+                        );
+                    }
+                    None => {
+                        error.delete_property_or_throw("stack", context)?;
+                    }
+                }
+                self.memory[index] = Some(error.clone());
+                error.into()
+            }
+            Tag::ArrayBuffer => {
+                let bytes = self.read_byte_vec(context)?;
+                let buffer = self.read_array_buffer(bytes, context)?;
+                let prototype = context.standard_objects().array_buffer_object().prototype();
+                self.create(prototype, ObjectData::array_buffer(buffer), context)
+                    .into()
+            }
+            Tag::TransferredArrayBuffer => {
+                let index = self.read_u32(context)?;
+                let bytes = match self.transferred.get_mut(index).and_then(Option::take) {
+                    Some(bytes) => bytes,
+                    None => return Err(invalid(context, "missing transferred buffer")),
+                };
+                let buffer = self.read_array_buffer(bytes, context)?;
+                let prototype = context.standard_objects().array_buffer_object().prototype();
+                self.create(prototype, ObjectData::array_buffer(buffer), context)
+                    .into()
+            }
+            Tag::TypedArray => {
+                let index = self.reserve();
+                let name = self.read_u8(context)?;
+                let name = match TYPED_ARRAYS.get(usize::from(name)) {
+                    Some(name) => *name,
+                    None => return Err(invalid(context, "unknown typed array")),
+                };
+                let buffer = self.read_buffer(context)?;
+                let byte_offset = self.read_u32(context)?;
+                let length = self.read_optional_u32(context)?;
+
+                let constructor =
+                    name.standard_constructor()(context.standard_objects()).constructor();
+                let typed_array = constructor.construct(
+                    &[
+                        buffer,
+                        byte_offset.into(),
+                        length.map(JsValue::new).unwrap_or_default(),
+                    ],
+                    &constructor.clone().into(),
+                    context,
+                )?;
+                self.memory[index] = typed_array.as_object();
+                typed_array
+            }
+            Tag::DataView => {
+                let index = self.reserve();
+                let buffer = self.read_buffer(context)?;
+                let byte_offset = self.read_u32(context)?;
+                let byte_length = self.read_optional_u32(context)?;
+
+                let constructor = context.standard_objects().data_view_object().constructor();
+                let data_view = constructor.construct(
+                    &[
+                        buffer,
+                        byte_offset.into(),
+                        byte_length.map(JsValue::new).unwrap_or_default(),
+                    ],
+                    &constructor.clone().into(),
+                    context,
+                )?;
+                self.memory[index] = data_view.as_object();
+                data_view
+            }
+        };
+        Ok((value, None))
+    }
+
+    fn read_optional_string(&mut self, context: &mut Context) -> JsResult<Option<JsString>> {
+        match self.read_u8(context)? {
+            0 => Ok(None),
+            _ => self.read_string(context).map(Some),
+        }
+    }
+
+    fn read_optional_u32(&mut self, context: &mut Context) -> JsResult<Option<usize>> {
+        match self.read_u8(context)? {
+            0 => Ok(None),
+            _ => self.read_u32(context).map(Some),
+        }
+    }
+
+    /// Reads the maximum length of an `ArrayBuffer` with the given contents, if it is resizable.
+    fn read_array_buffer(
+        &mut self,
+        bytes: Vec<u8>,
+        context: &mut Context,
+    ) -> JsResult<ArrayBuffer> {
+        match self.read_optional_u32(context)? {
+            None => Ok(ArrayBuffer::from_bytes(bytes)),
+            Some(max_byte_length) if bytes.len() <= max_byte_length => {
+                Ok(ArrayBuffer::resizable_from_bytes(bytes, max_byte_length))
+            }
+            Some(_) => Err(invalid(context, "buffer larger than its maximum length")),
+        }
+    }
+
+    /// Reads the buffer viewed by a typed array or a `DataView`.
+    fn read_buffer(&mut self, context: &mut Context) -> JsResult<JsValue> {
+        // Only buffers are read, so that views cannot nest other values in their buffer.
+        let tag = self.input.get(self.position).map(|tag| Tag::try_from(*tag));
+        let buffer = match tag {
+            Some(Ok(Tag::ArrayBuffer | Tag::TransferredArrayBuffer | Tag::Reference)) => {
+                self.read_value(context)?.0
+            }
+            _ => return Err(invalid(context, "expected an ArrayBuffer")),
+        };
+        match buffer.as_object() {
+            Some(object) if object.borrow().is_unshared_array_buffer() => Ok(buffer),
+            _ => Err(invalid(context, "expected an ArrayBuffer")),
+        }
+    }
+
+    /// Creates the wrapper object of a primitive, and stores it in the next slot.
+    fn wrap(&mut self, primitive: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let object = primitive.to_object(context)?;
+        self.memory.push(Some(object.clone()));
+        Ok(object.into())
+    }
+}
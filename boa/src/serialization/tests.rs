@@ -0,0 +1,420 @@
+use super::{deserialize, deserialize_with_transfer, serialize, serialize_with_transfer};
+use crate::{
+    forward, forward_val, property::Attribute, test_support::assert_deep_equals, Context, JsValue,
+};
+
+/// Evaluates `source`, serializes its result and deserializes it in the same context.
+fn roundtrip(context: &mut Context, source: &str) -> JsValue {
+    let value = forward_val(context, source).unwrap();
+    let bytes = serialize(&value, context).unwrap();
+    deserialize(&bytes, context).unwrap()
+}
+
+/// Checks that `source` survives a round trip structurally unchanged.
+fn assert_roundtrip(context: &mut Context, source: &str) {
+    let expected = forward_val(context, source).unwrap();
+    let actual = roundtrip(context, source);
+    assert_deep_equals(&actual, &expected, context);
+}
+
+#[test]
+fn primitives() {
+    let mut context = Context::new();
+
+    for source in &[
+        "undefined",
+        "null",
+        "true",
+        "false",
+        "0",
+        "-0",
+        "NaN",
+        "-Infinity",
+        "1.5",
+        "2 ** 53 + 2",
+        "0n",
+        "123n",
+        "-(2n ** 70n)",
+        "''",
+        "'héllo ✓ 🦀'",
+    ] {
+        assert_roundtrip(&mut context, source);
+    }
+}
+
+#[test]
+fn objects_and_arrays() {
+    let mut context = Context::new();
+
+    assert_roundtrip(
+        &mut context,
+        "({ a: 1, b: [1, [2], 3], c: { d: 'x' }, 'e f': null })",
+    );
+    assert_roundtrip(
+        &mut context,
+        "var arr = [1, [2, [3]]]; arr.extra = 'kept'; arr.length = 5; arr",
+    );
+
+    let value = roundtrip(&mut context, "var holes = [1]; holes[2] = 3; holes");
+    context.register_global_property("result", value, Attribute::all());
+    assert_eq!(forward(&mut context, "result.length"), "3");
+    assert_eq!(forward(&mut context, "1 in result"), "false");
+
+    // Only own enumerable properties are serialized, and getters are run.
+    let value = roundtrip(
+        &mut context,
+        r#"
+        var proto = { inherited: 1 };
+        var obj = Object.create(proto);
+        Object.defineProperty(obj, 'hidden', { value: 2, enumerable: false });
+        Object.defineProperty(obj, 'computed', { get: function () { return 3; }, enumerable: true });
+        obj
+        "#,
+    );
+    context.register_global_property("result", value, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys(result).join() + ',' + result.computed + ',' + result.inherited"
+        ),
+        "\"computed,3,undefined\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(result) === Object.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn builtin_objects() {
+    let mut context = Context::new();
+
+    for source in &[
+        "new Date(1234567890123)",
+        "new Date(NaN)",
+        "/a+(b)/gi",
+        "new Map([[1, 'one'], [{ key: true }, [2]], ['nested', new Map([[NaN, -0]])]])",
+        "new Set([1, 'two', { three: 3 }])",
+        "new Boolean(false)",
+        "new Number(-0)",
+        "new String('wrapped')",
+        "Object(10n)",
+        "new RangeError('out of range')",
+        "new Error()",
+    ] {
+        assert_roundtrip(&mut context, source);
+    }
+
+    let value = roundtrip(
+        &mut context,
+        "var e = new TypeError('bad'); e.stack = 'TypeError: bad\\n    at somewhere'; e",
+    );
+    context.register_global_property("result", value, Attribute::all());
+    assert_eq!(forward(&mut context, "result instanceof TypeError"), "true");
+    assert_eq!(
+        forward(&mut context, "result.stack"),
+        "\"TypeError: bad\n    at somewhere\""
+    );
+
+    let value = roundtrip(
+        &mut context,
+        "var e = new Error('custom'); e.name = 'CustomError'; e",
+    );
+    context.register_global_property("result", value, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(result) === Error.prototype && result.message"
+        ),
+        "\"custom\""
+    );
+}
+
+#[test]
+fn shared_references_and_cycles() {
+    let mut context = Context::new();
+
+    let value = roundtrip(
+        &mut context,
+        r#"
+        var shared = { value: 1 };
+        var root = { list: [shared, shared], map: new Map() };
+        root.self = root;
+        root.map.set(root, root.list);
+        root
+        "#,
+    );
+    context.register_global_property("result", value, Attribute::all());
+
+    assert_eq!(forward(&mut context, "result === root"), "false");
+    assert_eq!(forward(&mut context, "result.self === result"), "true");
+    assert_eq!(
+        forward(&mut context, "result.list[0] === result.list[1]"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "result.map.get(result) === result.list"),
+        "true"
+    );
+}
+
+#[test]
+fn buffers_and_views() {
+    let mut context = Context::new();
+
+    let value = roundtrip(
+        &mut context,
+        r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer, 2, 4);
+        bytes[0] = 7;
+        var view = new DataView(buffer, 1);
+        [bytes, view, new Float64Array([1.5, -2]), new BigInt64Array([-5n]), buffer]
+        "#,
+    );
+    context.register_global_property("result", value, Attribute::all());
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "result[0].buffer === result[1].buffer && result[0].buffer === result[4]"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[result[0].byteOffset, result[0].length, result[0][0]].join()"
+        ),
+        "\"2,4,7\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[result[1].byteOffset, result[1].byteLength].join()"
+        ),
+        "\"1,7\""
+    );
+    assert_eq!(forward(&mut context, "result[2][1]"), "-2");
+    assert_eq!(forward(&mut context, "result[3][0]"), "-5n");
+    assert_eq!(forward(&mut context, "result[4].byteLength"), "8");
+}
+
+#[test]
+fn resizable_buffers() {
+    let mut context = Context::new();
+
+    let value = roundtrip(
+        &mut context,
+        r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        [buffer, new Uint8Array(buffer, 1), new Uint8Array(buffer, 0, 2), new DataView(buffer)]
+        "#,
+    );
+    context.register_global_property("result", value, Attribute::all());
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[result[0].resizable, result[0].maxByteLength].join()"
+        ),
+        "\"true,8\""
+    );
+    forward(&mut context, "result[0].resize(6)");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[result[1].length, result[2].length, result[3].byteLength].join()"
+        ),
+        "\"5,2,6\""
+    );
+}
+
+#[test]
+fn across_contexts() {
+    let mut source = Context::new();
+    let value = forward_val(
+        &mut source,
+        "({ list: [1, 2], when: new Date(0), pattern: /x/g, error: new SyntaxError('s') })",
+    )
+    .unwrap();
+    let bytes = serialize(&value, &mut source).unwrap();
+
+    let mut target = Context::new();
+    let value = deserialize(&bytes, &mut target).unwrap();
+    target.register_global_property("result", value, Attribute::all());
+
+    assert_eq!(
+        forward(
+            &mut target,
+            r#"
+            Object.getPrototypeOf(result) === Object.prototype
+                && Array.isArray(result.list)
+                && result.when instanceof Date
+                && result.pattern instanceof RegExp
+                && result.error instanceof SyntaxError
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut target,
+            "result.list.concat(result.when.getTime()).join()"
+        ),
+        "\"1,2,0\""
+    );
+}
+
+#[test]
+fn unserializable_values() {
+    let mut context = Context::new();
+
+    for source in &[
+        "Symbol('s')",
+        "({ nested: [function () {}] })",
+        "Promise.resolve(1)",
+        "new WeakMap()",
+        "new Proxy({}, {})",
+        "new SharedArrayBuffer(8)",
+    ] {
+        let value = forward_val(&mut context, source).unwrap();
+        let error = serialize(&value, &mut context).unwrap_err();
+        context.register_global_property("error", error, Attribute::all());
+        assert_eq!(
+            forward(&mut context, "error instanceof TypeError"),
+            "true",
+            "{}",
+            source
+        );
+    }
+
+    let buffer = forward_val(&mut context, "new ArrayBuffer(8)").unwrap();
+    buffer
+        .as_object()
+        .unwrap()
+        .borrow_mut()
+        .as_array_buffer_mut()
+        .unwrap()
+        .detach();
+    let error = serialize(&buffer, &mut context).unwrap_err();
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+
+    let value = forward_val(
+        &mut context,
+        r#"
+        var throwing = {};
+        Object.defineProperty(throwing, 'failing', {
+            get: function () { throw new RangeError('getter'); },
+            enumerable: true,
+        });
+        throwing
+        "#,
+    )
+    .unwrap();
+    let error = serialize(&value, &mut context).unwrap_err();
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(forward(&mut context, "error.message"), "\"getter\"");
+}
+
+#[test]
+fn invalid_bytes() {
+    let mut context = Context::new();
+
+    let value = forward_val(&mut context, "({ a: [1, 2] })").unwrap();
+    let valid = serialize(&value, &mut context).unwrap();
+
+    let mut trailing = valid.clone();
+    trailing.push(0);
+    let mut unknown_tag = valid[..4].to_vec();
+    unknown_tag.push(200);
+    let mut dangling = valid[..4].to_vec();
+    dangling.extend_from_slice(&[7, 0, 0, 0, 0]);
+
+    for bytes in &[
+        Vec::new(),
+        b"JSON{}".to_vec(),
+        valid[..valid.len() - 1].to_vec(),
+        trailing,
+        unknown_tag,
+        dangling,
+    ] {
+        let error = deserialize(bytes, &mut context).unwrap_err();
+        context.register_global_property("error", error, Attribute::all());
+        assert_eq!(
+            forward(
+                &mut context,
+                "error instanceof TypeError && error.message.startsWith('Invalid serialized value')"
+            ),
+            "true",
+            "{:?}",
+            bytes
+        );
+    }
+}
+
+#[test]
+fn transferred_buffers() {
+    let mut context = Context::new();
+
+    let value = forward_val(
+        &mut context,
+        "var buffer = new ArrayBuffer(4); new Uint8Array(buffer)[1] = 9; [buffer, new Uint8Array(buffer)]",
+    )
+    .unwrap();
+    let buffer = forward_val(&mut context, "buffer")
+        .unwrap()
+        .as_object()
+        .unwrap();
+    let (bytes, transferred) =
+        serialize_with_transfer(&value, &[buffer.clone()], &mut context).unwrap();
+    assert_eq!(transferred, vec![vec![0, 9, 0, 0]]);
+    assert!(buffer
+        .borrow()
+        .as_array_buffer()
+        .unwrap()
+        .is_detached_buffer());
+
+    let error = deserialize(&bytes, &mut context).unwrap_err();
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+
+    let value = deserialize_with_transfer(&bytes, transferred, &mut context).unwrap();
+    context.register_global_property("result", value, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "result[1].buffer === result[0] && result[1][1] === 9 && result[0] !== buffer"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn deeply_nested_values() {
+    let mut context = Context::new();
+
+    let source = r#"
+        var deep = [0];
+        for (var i = 0; i < 1000; i++) {
+            deep = { a: [new Map([[i, new Set([deep])]])] };
+        }
+        deep
+    "#;
+    assert_roundtrip(&mut context, source);
+
+    let value = forward_val(&mut context, "deep").unwrap();
+    let bytes = serialize(&value, &mut context).unwrap();
+    context.set_max_nesting_depth(100);
+    for error in &[
+        serialize(&value, &mut context).unwrap_err(),
+        deserialize(&bytes, &mut context).unwrap_err(),
+    ] {
+        context.register_global_property("error", error.clone(), Attribute::all());
+        assert_eq!(forward(&mut context, "error instanceof RangeError"), "true");
+    }
+}
@@ -1,16 +0,0 @@
-//! The Javascript Abstract Syntax Tree.
-
-pub mod constant;
-pub mod keyword;
-pub mod node;
-pub mod op;
-pub mod position;
-pub mod punctuator;
-
-pub use self::{
-    constant::Const,
-    keyword::Keyword,
-    node::Node,
-    position::{Position, Span},
-    punctuator::Punctuator,
-};
@@ -1,10 +0,0 @@
-//! Operator nodes
-
-pub mod assign;
-pub mod bin_op;
-pub mod unary_op;
-
-pub use self::{assign::Assign, bin_op::BinOp, unary_op::UnaryOp};
-
-#[cfg(test)]
-mod tests;
@@ -0,0 +1,328 @@
+//! This module implements the incremental teardown of a [`Context`].
+//!
+//! Dropping a `Context` only unroots its objects: they are all freed by the next collection,
+//! which drops the properties, elements and bindings of the whole object graph in a single
+//! pause. For a context holding millions of objects this pause can be long, and it is paid by
+//! whichever allocation happens to trigger the collection.
+//!
+//! [`Context::teardown`] instead returns a [`Teardown`], which walks the object graph of the
+//! context and empties the objects and environments it finds, a bounded number at a time. Once it
+//! is finished the objects no longer reference each other, so the collection freeing them only
+//! has to release the empty objects themselves.
+//!
+//! The objects of the engine are not `Send`, so the teardown runs on the thread of the context:
+//! the embedder decides when to run it, for example between requests or while waiting for I/O.
+//!
+//! # Examples
+//!
+//! ```
+//! use boa::Context;
+//! use std::time::Duration;
+//!
+//! let mut context = Context::new();
+//! context
+//!     .eval("var list = []; for (let i = 0; i < 10000; i++) { list.push({ i }); }")
+//!     .unwrap();
+//!
+//! let mut teardown = context.teardown();
+//! while !teardown.run_for(Duration::from_millis(1)) {
+//!     // Serve other requests.
+//! }
+//! assert!(teardown.released() > 10000);
+//! ```
+
+use crate::{
+    environment::{
+        environment_record_trait::EnvironmentRecordTrait, lexical_environment::Environment,
+    },
+    object::JsObject,
+    Context, JsValue,
+};
+use rustc_hash::FxHashSet;
+use std::time::{Duration, Instant};
+
+/// The number of objects and environments emptied between two checks of the deadline of
+/// [`Teardown::run_for`].
+const RUN_FOR_BUDGET: usize = 1024;
+
+/// The values and environments referenced by an object being emptied, which are queued to be
+/// emptied by the following steps of the teardown.
+#[derive(Debug, Default)]
+pub(crate) struct Contents {
+    values: Vec<JsValue>,
+    environments: Vec<Environment>,
+}
+
+impl Contents {
+    /// Queues a value referenced by the object.
+    #[inline]
+    pub(crate) fn push_value(&mut self, value: &JsValue) {
+        if value.is_object() {
+            self.values.push(value.clone());
+        }
+    }
+
+    /// Queues the values referenced by the object.
+    #[inline]
+    pub(crate) fn extend_values<'a, I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = &'a JsValue>,
+    {
+        for value in values {
+            self.push_value(value);
+        }
+    }
+
+    /// Queues an object referenced by the object.
+    #[inline]
+    pub(crate) fn push_object(&mut self, object: &JsObject) {
+        self.values.push(object.clone().into());
+    }
+
+    /// Queues an environment referenced by the object.
+    #[inline]
+    pub(crate) fn push_environment(&mut self, environment: &Environment) {
+        self.environments.push(environment.clone());
+    }
+}
+
+/// The incremental teardown of the object graph of a [`Context`].
+///
+/// Each step empties some objects, making them ordinary objects without properties, prototype nor
+/// internal data, and some environments, removing their bindings. The contents are dropped as
+/// they are emptied, and what they referenced is emptied by the following steps.
+///
+/// The handles to the objects of the context kept by the embedder are emptied as well, so they
+/// should not be used anymore once the teardown has started.
+#[derive(Debug)]
+pub struct Teardown {
+    objects: Vec<JsObject>,
+    environments: Vec<Environment>,
+    visited_objects: FxHashSet<usize>,
+    visited_environments: FxHashSet<usize>,
+    released: usize,
+}
+
+impl Context {
+    /// Consumes the context, returning the [`Teardown`] emptying its object graph.
+    ///
+    /// The teardown does nothing until it is run, by [`Teardown::step`], [`Teardown::run_for`]
+    /// or [`Teardown::finish`]. Dropping it before it is finished leaves the rest of the objects
+    /// to the next collection.
+    pub fn teardown(mut self) -> Teardown {
+        let mut teardown = Teardown {
+            objects: Vec::new(),
+            environments: self.split_off_environments(0),
+            visited_objects: FxHashSet::default(),
+            visited_environments: FxHashSet::default(),
+            released: 0,
+        };
+        teardown.objects.push(self.realm.global_object.clone());
+        teardown.push_values(self.realm.global_env.take_binding_values());
+        teardown.environments.extend(
+            self.module_map
+                .drain()
+                .filter_map(|(_, module)| module.environment()),
+        );
+        teardown
+    }
+}
+
+impl Teardown {
+    /// Empties at most `budget` objects and environments, returning `true` if the teardown is
+    /// finished.
+    pub fn step(&mut self, budget: usize) -> bool {
+        for _ in 0..budget {
+            if let Some(object) = self.objects.pop() {
+                self.release_object(&object);
+            } else if let Some(environment) = self.environments.pop() {
+                self.release_environment(&environment);
+            } else {
+                break;
+            }
+        }
+        self.is_finished()
+    }
+
+    /// Runs the teardown until it is finished or `duration` has elapsed, returning `true` if it
+    /// is finished.
+    ///
+    /// The deadline is checked every few hundred objects, so it can be exceeded slightly.
+    pub fn run_for(&mut self, duration: Duration) -> bool {
+        let start = Instant::now();
+        while !self.step(RUN_FOR_BUDGET) {
+            if start.elapsed() >= duration {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Runs the teardown until it is finished.
+    pub fn finish(mut self) {
+        while !self.step(RUN_FOR_BUDGET) {}
+    }
+
+    /// Checks if every object and environment reachable from the context has been emptied.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.objects.is_empty() && self.environments.is_empty()
+    }
+
+    /// Gets the number of objects and environments emptied so far.
+    #[inline]
+    pub fn released(&self) -> usize {
+        self.released
+    }
+
+    /// Empties the object, and queues the objects and environment it referenced.
+    fn release_object(&mut self, object: &JsObject) {
+        // The objects referenced more than once, as in cycles, are queued again once emptied.
+        let address = object.as_ref() as *const _ as usize;
+        if !self.visited_objects.insert(address) {
+            return;
+        }
+
+        // An object borrowed by the embedder is left to the collector.
+        let contents = match object.try_borrow_mut() {
+            Ok(mut object) => object.take_contents(),
+            Err(_) => return,
+        };
+        self.released += 1;
+        self.push_values(contents.values);
+        self.environments.extend(contents.environments);
+    }
+
+    /// Removes the bindings of the environment, and queues the objects and environment they
+    /// referenced.
+    fn release_environment(&mut self, environment: &Environment) {
+        let address = &***environment as *const dyn EnvironmentRecordTrait as *const () as usize;
+        if !self.visited_environments.insert(address) {
+            return;
+        }
+        self.released += 1;
+        self.push_values(environment.take_binding_values());
+        self.environments
+            .extend(environment.get_outer_environment());
+    }
+
+    /// Queues the objects among the values.
+    fn push_values(&mut self, values: Vec<JsValue>) {
+        self.objects
+            .extend(values.iter().filter_map(|value| value.as_object()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Teardown;
+    use crate::{Context, JsValue};
+    use rustc_hash::FxHashSet;
+
+    #[test]
+    fn teardown_empties_the_object_graph() {
+        let mut context = Context::new();
+        let held = context
+            .eval(
+                r#"
+                var root = { children: [] };
+                for (let i = 0; i < 1000; i++) {
+                    root.children.push({ parent: root, map: new Map([[i, { i }]]) });
+                }
+                let lexical = new Set([root]);
+                function closure() { return lexical; }
+                root
+                "#,
+            )
+            .unwrap();
+        let held = held.as_object().unwrap();
+        assert!(held.borrow().properties().get(&"children".into()).is_some());
+
+        let mut teardown = context.teardown();
+        let mut steps = 0;
+        while !teardown.step(100) {
+            steps += 1;
+        }
+        assert!(steps > 10);
+        assert!(teardown.is_finished());
+        assert!(teardown.released() > 3000);
+        assert!(held.borrow().properties().get(&"children".into()).is_none());
+        assert_eq!(held.borrow().prototype_instance(), &JsValue::null());
+    }
+
+    #[test]
+    fn teardown_walks_the_internal_slots() {
+        let mut context = Context::new();
+        let leaves = context
+            .eval(
+                r#"
+                var leaves = [];
+                function leaf() {
+                    const object = { leaf: true };
+                    leaves.push(object);
+                    return object;
+                }
+
+                // Every link of the chain is only reachable through the internal slots of the
+                // previous one.
+                var chain = function () {};
+                for (let i = 0; i < 500; i++) {
+                    chain = new Proxy(chain.bind(leaf()), leaf());
+                }
+
+                function* suspended(value) { yield value; }
+                var generator = suspended(leaf());
+                var pending = new Promise(() => {});
+                (function () {
+                    const held = leaf();
+                    pending.then(() => held);
+                })();
+                leaves
+                "#,
+            )
+            .unwrap();
+        let leaves: Vec<_> = leaves
+            .as_object()
+            .unwrap()
+            .borrow()
+            .properties()
+            .index_property_values()
+            .filter_map(|descriptor| descriptor.value().and_then(JsValue::as_object))
+            .collect();
+        assert_eq!(leaves.len(), 1002);
+        context.eval("leaves = null").unwrap();
+
+        context.teardown().finish();
+        for leaf in leaves {
+            assert!(leaf.borrow().properties().get(&"leaf".into()).is_none());
+        }
+    }
+
+    #[test]
+    fn teardown_releases_cyclic_objects_once() {
+        let mut context = Context::new();
+        let root = context
+            .eval(
+                r#"
+                var a = Object.create(null);
+                var b = Object.create(null);
+                a.self = a;
+                a.b = b;
+                b.a = a;
+                b.again = a;
+                a
+                "#,
+            )
+            .unwrap();
+
+        let mut teardown = Teardown {
+            objects: vec![root.as_object().unwrap()],
+            environments: Vec::new(),
+            visited_objects: FxHashSet::default(),
+            visited_environments: FxHashSet::default(),
+            released: 0,
+        };
+        while !teardown.step(1) {}
+        assert_eq!(teardown.released(), 2);
+    }
+}
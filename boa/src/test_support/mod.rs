@@ -0,0 +1,533 @@
+//! Utilities for asserting on the values produced by scripts.
+//!
+//! Comparing the result of a script against an expected value usually needs more than `===`:
+//! two arrays with the same elements are different objects. This module provides a structural
+//! (deep) equality over [`JsValue`]s, and a [`ValueDiff`] that describes where two values
+//! differ in a form that reads well in a failed assertion.
+//!
+//! The comparison works as follows:
+//!  - Primitives are compared with [`SameValue`][samevalue], so `NaN` equals `NaN` but `+0`
+//!    does not equal `-0`.
+//!  - The same object is always equal to itself.
+//!  - Objects of different kinds (an array and a plain object, a `Map` and a `Set`, ...) are
+//!    never equal. Functions are only equal to themselves.
+//!  - Otherwise, the own enumerable string-keyed properties of both objects are compared
+//!    recursively. Arrays also compare their `length`, dates their time value, boxed primitives
+//!    their primitive value, errors their `name` and `message`, regular expressions their
+//!    `source` and `flags`, and maps and sets their entries in insertion order.
+//!  - Cyclic structures are handled: a pair of objects that is already being compared is
+//!    assumed to be equal.
+//!
+//! Prototypes are not compared.
+//!
+//! [samevalue]: https://tc39.es/ecma262/#sec-samevalue
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::date::Date, object::JsObject, property::PropertyKey, Context, JsResult, JsValue,
+};
+use std::fmt::{self, Display};
+
+/// Returns `true` if the two values are structurally equal.
+///
+/// See the [module documentation](self) for the rules used by the comparison.
+pub fn deep_equals(x: &JsValue, y: &JsValue, context: &mut Context) -> JsResult<bool> {
+    Ok(diff(x, y, context)?.is_empty())
+}
+
+/// Computes the structural differences between `actual` and `expected`.
+///
+/// The returned [`ValueDiff`] is empty if the values are structurally equal. An error is
+/// only returned if reading a property of one of the values throws, e.g. a throwing getter,
+/// or if the values nest objects deeper than [`Context::max_nesting_depth`].
+pub fn diff(actual: &JsValue, expected: &JsValue, context: &mut Context) -> JsResult<ValueDiff> {
+    let mut differ = Differ {
+        visited: Vec::new(),
+        differences: Vec::new(),
+        pending: Vec::new(),
+    };
+    differ.run(String::from("$"), actual, expected, context)?;
+
+    Ok(ValueDiff {
+        differences: differ.differences,
+    })
+}
+
+/// Asserts that `actual` is structurally equal to `expected`.
+///
+/// # Panics
+///
+/// Panics with the [`ValueDiff`] of the two values if they are not structurally equal, or if
+/// the comparison throws.
+#[track_caller]
+pub fn assert_deep_equals(actual: &JsValue, expected: &JsValue, context: &mut Context) {
+    match diff(actual, expected, context) {
+        Ok(diff) if diff.is_empty() => {}
+        Ok(diff) => panic!("values are not structurally equal:\n{}", diff),
+        Err(e) => panic!("uncaught exception while comparing values: {}", e.display()),
+    }
+}
+
+/// The list of structural differences between two values, as returned by [`diff`].
+///
+/// Its `Display` implementation prints one difference per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueDiff {
+    differences: Vec<Difference>,
+}
+
+impl ValueDiff {
+    /// Returns `true` if there are no differences, i.e. the values are structurally equal.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Returns the differences, in the order they were found.
+    #[inline]
+    pub fn differences(&self) -> &[Difference] {
+        &self.differences
+    }
+}
+
+impl Display for ValueDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, difference) in self.differences.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", difference)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single difference between two values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// The location of the difference, starting at `$` for the compared values themselves,
+    /// e.g. `$.items[2].name`.
+    pub path: String,
+    /// What differs at [`path`](Self::path).
+    pub kind: DifferenceKind,
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DifferenceKind::Mismatch { actual, expected } => {
+                write!(f, "{}: expected {}, found {}", self.path, expected, actual)
+            }
+            DifferenceKind::Missing { expected } => {
+                write!(f, "{}: missing, expected {}", self.path, expected)
+            }
+            DifferenceKind::Unexpected { actual } => {
+                write!(f, "{}: unexpected, found {}", self.path, actual)
+            }
+        }
+    }
+}
+
+/// The kind of a [`Difference`].
+///
+/// Values are stored as their display representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DifferenceKind {
+    /// Both values have something at the path, but they are different.
+    Mismatch { actual: String, expected: String },
+    /// Only the expected value has a property at the path.
+    Missing { expected: String },
+    /// Only the actual value has a property at the path.
+    Unexpected { actual: String },
+}
+
+/// The kind of an object, as far as the comparison is concerned.
+#[derive(Debug, Clone)]
+enum ObjectClass {
+    Ordinary,
+    Array,
+    Function,
+    Date(Date),
+    Primitive(JsValue),
+    Error,
+    RegExp,
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+}
+
+impl ObjectClass {
+    fn of(object: &JsObject) -> Self {
+        let object = object.borrow();
+        if object.is_array() {
+            Self::Array
+        } else if object.is_callable() {
+            Self::Function
+        } else if let Some(date) = object.as_date() {
+            Self::Date(*date)
+        } else if let Some(string) = object.as_string() {
+            Self::Primitive(string.into())
+        } else if let Some(number) = object.as_number() {
+            Self::Primitive(number.into())
+        } else if let Some(boolean) = object.as_boolean() {
+            Self::Primitive(boolean.into())
+        } else if let Some(symbol) = object.as_symbol() {
+            Self::Primitive(symbol.into())
+        } else if let Some(bigint) = object.as_bigint() {
+            Self::Primitive(bigint.clone().into())
+        } else if object.is_error() {
+            Self::Error
+        } else if object.is_regexp() {
+            Self::RegExp
+        } else if let Some(map) = object.as_map_ref() {
+            Self::Map(map.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        } else if let Some(set) = object.as_set_ref() {
+            Self::Set(set.iter().cloned().collect())
+        } else {
+            Self::Ordinary
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Ordinary => "object",
+            Self::Array => "array",
+            Self::Function => "function",
+            Self::Date(_) => "date",
+            Self::Primitive(_) => "primitive wrapper",
+            Self::Error => "error",
+            Self::RegExp => "regular expression",
+            Self::Map(_) => "map",
+            Self::Set(_) => "set",
+        }
+    }
+}
+
+/// A step of a [`diff`] call, left to do after the comparisons of the values nested in an
+/// object.
+#[derive(Debug)]
+enum Task {
+    /// Compares two values, found in objects nested `depth` levels deep.
+    Compare {
+        path: String,
+        actual: JsValue,
+        expected: JsValue,
+        depth: usize,
+    },
+    /// Records a difference that is found between the comparisons of nested values.
+    Report(Difference),
+}
+
+/// The state of a single [`diff`] call.
+#[derive(Debug)]
+struct Differ {
+    /// The pairs of objects that have been (or are being) compared.
+    visited: Vec<(JsObject, JsObject)>,
+    differences: Vec<Difference>,
+    /// The tasks found while comparing an object, in order.
+    pending: Vec<Task>,
+}
+
+impl Differ {
+    /// Compares two values, and the values nested in them.
+    ///
+    /// The nested values are compared with an explicit stack of tasks instead of recursive
+    /// calls, so deeply nested values cannot overflow the native stack. The tasks of an object
+    /// are pushed in reverse, so the differences are found in the order of a depth-first walk.
+    fn run(
+        &mut self,
+        path: String,
+        actual: &JsValue,
+        expected: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.diff_values(path, actual, expected, 0, context)?;
+
+        let mut stack: Vec<Task> = self.pending.drain(..).rev().collect();
+        while let Some(task) = stack.pop() {
+            match task {
+                Task::Compare {
+                    path,
+                    actual,
+                    expected,
+                    depth,
+                } => self.diff_values(path, &actual, &expected, depth, context)?,
+                Task::Report(difference) => self.differences.push(difference),
+            }
+            stack.extend(self.pending.drain(..).rev());
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, path: String, kind: DifferenceKind) {
+        self.differences.push(Difference { path, kind });
+    }
+
+    /// Records a difference after the comparisons of nested values found before it.
+    fn report(&mut self, path: String, kind: DifferenceKind) {
+        self.pending.push(Task::Report(Difference { path, kind }));
+    }
+
+    /// Compares two values nested in an object `depth` levels deep, once the comparisons found
+    /// before it are done.
+    fn compare(&mut self, path: String, actual: &JsValue, expected: &JsValue, depth: usize) {
+        self.pending.push(Task::Compare {
+            path,
+            actual: actual.clone(),
+            expected: expected.clone(),
+            depth,
+        });
+    }
+
+    fn mismatch(&mut self, path: String, actual: &JsValue, expected: &JsValue) {
+        self.push(
+            path,
+            DifferenceKind::Mismatch {
+                actual: actual.display().to_string(),
+                expected: expected.display().to_string(),
+            },
+        );
+    }
+
+    fn diff_values(
+        &mut self,
+        path: String,
+        actual: &JsValue,
+        expected: &JsValue,
+        depth: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if let (JsValue::Object(x), JsValue::Object(y)) = (actual, expected) {
+            return self.diff_objects(path, x, y, depth + 1, context);
+        }
+
+        if !JsValue::same_value(actual, expected) {
+            self.mismatch(path, actual, expected);
+        }
+        Ok(())
+    }
+
+    fn diff_objects(
+        &mut self,
+        path: String,
+        actual: &JsObject,
+        expected: &JsObject,
+        depth: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if JsObject::equals(actual, expected)
+            || self
+                .visited
+                .iter()
+                .any(|(x, y)| JsObject::equals(x, actual) && JsObject::equals(y, expected))
+        {
+            return Ok(());
+        }
+        context.check_nesting_depth(depth)?;
+        self.visited.push((actual.clone(), expected.clone()));
+
+        let actual_class = ObjectClass::of(actual);
+        let expected_class = ObjectClass::of(expected);
+
+        match (actual_class, expected_class) {
+            (ObjectClass::Function, ObjectClass::Function) => {
+                self.mismatch(path, &actual.clone().into(), &expected.clone().into());
+                return Ok(());
+            }
+            (ObjectClass::Array, ObjectClass::Array) => {
+                let actual_length = actual.length_of_array_like(context)?;
+                let expected_length = expected.length_of_array_like(context)?;
+                if actual_length != expected_length {
+                    self.mismatch(
+                        format!("{}.length", path),
+                        &actual_length.into(),
+                        &expected_length.into(),
+                    );
+                }
+            }
+            (ObjectClass::Date(x), ObjectClass::Date(y)) => {
+                if x != y {
+                    self.mismatch(
+                        path.clone(),
+                        &actual.clone().into(),
+                        &expected.clone().into(),
+                    );
+                }
+            }
+            (ObjectClass::Primitive(x), ObjectClass::Primitive(y)) => {
+                self.compare(format!("{}.[[PrimitiveValue]]", path), &x, &y, depth);
+            }
+            (ObjectClass::Error, ObjectClass::Error) => {
+                self.diff_properties(
+                    &path,
+                    actual,
+                    expected,
+                    &["name", "message"],
+                    depth,
+                    context,
+                )?;
+            }
+            (ObjectClass::RegExp, ObjectClass::RegExp) => {
+                self.diff_properties(
+                    &path,
+                    actual,
+                    expected,
+                    &["source", "flags"],
+                    depth,
+                    context,
+                )?;
+            }
+            (ObjectClass::Map(x), ObjectClass::Map(y)) => {
+                if x.len() != y.len() {
+                    self.mismatch(format!("{}.size", path), &x.len().into(), &y.len().into());
+                }
+                for (i, ((actual_key, actual_value), (expected_key, expected_value))) in
+                    x.iter().zip(&y).enumerate()
+                {
+                    let entry = format!("{}.[[MapData]][{}]", path, i);
+                    self.compare(format!("{}.key", entry), actual_key, expected_key, depth);
+                    self.compare(
+                        format!("{}.value", entry),
+                        actual_value,
+                        expected_value,
+                        depth,
+                    );
+                }
+            }
+            (ObjectClass::Set(x), ObjectClass::Set(y)) => {
+                if x.len() != y.len() {
+                    self.mismatch(format!("{}.size", path), &x.len().into(), &y.len().into());
+                }
+                for (i, (actual_value, expected_value)) in x.iter().zip(&y).enumerate() {
+                    self.compare(
+                        format!("{}.[[SetData]][{}]", path, i),
+                        actual_value,
+                        expected_value,
+                        depth,
+                    );
+                }
+            }
+            (ObjectClass::Ordinary, ObjectClass::Ordinary) => {}
+            (actual_class, expected_class) => {
+                self.push(
+                    path,
+                    DifferenceKind::Mismatch {
+                        actual: actual_class.name().to_string(),
+                        expected: expected_class.name().to_string(),
+                    },
+                );
+                return Ok(());
+            }
+        }
+
+        self.diff_own_properties(&path, actual, expected, depth, context)
+    }
+
+    /// Compares the values of the given properties, whether they are own properties or not.
+    fn diff_properties(
+        &mut self,
+        path: &str,
+        actual: &JsObject,
+        expected: &JsObject,
+        keys: &[&str],
+        depth: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        for key in keys {
+            let actual_value = actual.get(*key, context)?;
+            let expected_value = expected.get(*key, context)?;
+            self.compare(
+                format!("{}.{}", path, key),
+                &actual_value,
+                &expected_value,
+                depth,
+            );
+        }
+        Ok(())
+    }
+
+    /// Compares the own enumerable string-keyed properties of both objects.
+    fn diff_own_properties(
+        &mut self,
+        path: &str,
+        actual: &JsObject,
+        expected: &JsObject,
+        depth: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let actual_keys = enumerable_own_keys(actual, context)?;
+        let expected_keys = enumerable_own_keys(expected, context)?;
+
+        for key in &expected_keys {
+            let expected_value = expected.get(key.clone(), context)?;
+            if actual_keys.contains(key) {
+                let actual_value = actual.get(key.clone(), context)?;
+                self.compare(
+                    property_path(path, key),
+                    &actual_value,
+                    &expected_value,
+                    depth,
+                );
+            } else {
+                self.report(
+                    property_path(path, key),
+                    DifferenceKind::Missing {
+                        expected: expected_value.display().to_string(),
+                    },
+                );
+            }
+        }
+
+        for key in actual_keys
+            .iter()
+            .filter(|key| !expected_keys.contains(key))
+        {
+            let actual_value = actual.get(key.clone(), context)?;
+            self.report(
+                property_path(path, key),
+                DifferenceKind::Unexpected {
+                    actual: actual_value.display().to_string(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the own enumerable string-keyed properties of an object, in property order.
+fn enumerable_own_keys(object: &JsObject, context: &mut Context) -> JsResult<Vec<PropertyKey>> {
+    let mut keys = Vec::new();
+    for key in object.__own_property_keys__(context)? {
+        if matches!(key, PropertyKey::Symbol(_)) {
+            continue;
+        }
+        if let Some(desc) = object.__get_own_property__(&key, context)? {
+            if desc.expect_enumerable() {
+                keys.push(key);
+            }
+        }
+    }
+    Ok(keys)
+}
+
+/// Appends a property key to a path, using dot notation for identifier-like keys.
+fn property_path(path: &str, key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::Index(index) => format!("{}[{}]", path, index),
+        PropertyKey::String(name) => {
+            let mut chars = name.chars();
+            let is_identifier = chars
+                .next()
+                .map_or(false, |c| c.is_alphabetic() || c == '_' || c == '$')
+                && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+            if is_identifier {
+                format!("{}.{}", path, name)
+            } else {
+                format!("{}[{:?}]", path, name.as_str())
+            }
+        }
+        PropertyKey::Symbol(symbol) => format!("{}[{}]", path, symbol),
+    }
+}
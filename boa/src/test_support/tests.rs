@@ -0,0 +1,154 @@
+use super::{assert_deep_equals, deep_equals, diff};
+use crate::{forward, forward_val, Context};
+
+#[test]
+fn primitives() {
+    let mut context = Context::new();
+
+    let equals = |context: &mut Context, x: &str, y: &str| {
+        let x = forward_val(context, x).unwrap();
+        let y = forward_val(context, y).unwrap();
+        deep_equals(&x, &y, context).unwrap()
+    };
+
+    assert!(equals(&mut context, "1", "1.0"));
+    assert!(equals(&mut context, "NaN", "NaN"));
+    assert!(equals(&mut context, "'abc'", "'ab' + 'c'"));
+    assert!(equals(&mut context, "10n", "BigInt(10)"));
+    assert!(!equals(&mut context, "0", "-0"));
+    assert!(!equals(&mut context, "1", "'1'"));
+    assert!(!equals(&mut context, "null", "undefined"));
+}
+
+#[test]
+fn objects_and_arrays() {
+    let mut context = Context::new();
+
+    let equals = |context: &mut Context, x: &str, y: &str| {
+        let x = forward_val(context, x).unwrap();
+        let y = forward_val(context, y).unwrap();
+        deep_equals(&x, &y, context).unwrap()
+    };
+
+    assert!(equals(
+        &mut context,
+        "({ a: 1, b: [1, { c: 'x' }] })",
+        "({ b: [1, { c: 'x' }], a: 1 })"
+    ));
+    assert!(!equals(&mut context, "[1, 2]", "[1, 2, 3]"));
+    assert!(!equals(&mut context, "[]", "({})"));
+    assert!(!equals(&mut context, "[,]", "[]"));
+    assert!(!equals(&mut context, "({ a: undefined })", "({})"));
+    assert!(equals(
+        &mut context,
+        "new Map([[1, { a: 1 }]])",
+        "new Map([[1, { a: 1 }]])"
+    ));
+    assert!(!equals(&mut context, "new Set([1, 2])", "new Set([2, 1])"));
+    assert!(equals(&mut context, "new Date(0)", "new Date(0)"));
+    assert!(!equals(&mut context, "new Date(0)", "new Date(1)"));
+    assert!(equals(&mut context, "new String('a')", "new String('a')"));
+    assert!(!equals(&mut context, "new Number(1)", "new Number(2)"));
+    assert!(equals(&mut context, "/a/g", "/a/g"));
+    assert!(!equals(&mut context, "/a/g", "/a/i"));
+    assert!(equals(
+        &mut context,
+        "new TypeError('x')",
+        "new TypeError('x')"
+    ));
+    assert!(!equals(
+        &mut context,
+        "new TypeError('x')",
+        "new RangeError('x')"
+    ));
+    assert!(!equals(
+        &mut context,
+        "(function () {})",
+        "(function () {})"
+    ));
+}
+
+#[test]
+fn cycles() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var x = { name: 'node' };
+        x.self = x;
+        var y = { name: 'node' };
+        y.self = y;
+        var z = { name: 'other' };
+        z.self = z;
+        "#;
+    forward(&mut context, init);
+
+    let x = forward_val(&mut context, "x").unwrap();
+    let y = forward_val(&mut context, "y").unwrap();
+    let z = forward_val(&mut context, "z").unwrap();
+
+    assert_deep_equals(&x, &y, &mut context);
+    assert!(!deep_equals(&x, &z, &mut context).unwrap());
+}
+
+#[test]
+fn diff_output() {
+    let mut context = Context::new();
+
+    let actual = forward_val(
+        &mut context,
+        "({ a: 1, list: [1, 2], nested: { 'key with spaces': 'x' }, extra: true })",
+    )
+    .unwrap();
+    let expected = forward_val(
+        &mut context,
+        "({ a: 2, list: [1, 3, 4], nested: { 'key with spaces': 'y' }, missing: null })",
+    )
+    .unwrap();
+
+    let diff = diff(&actual, &expected, &mut context).unwrap();
+    assert_eq!(
+        diff.to_string(),
+        "$.a: expected 2, found 1\n\
+         $.list.length: expected 3, found 2\n\
+         $.list[1]: expected 3, found 2\n\
+         $.list[2]: missing, expected 4\n\
+         $.nested[\"key with spaces\"]: expected \"y\", found \"x\"\n\
+         $.missing: missing, expected null\n\
+         $.extra: unexpected, found true"
+    );
+    assert_eq!(diff.differences().len(), 7);
+}
+
+#[test]
+#[should_panic(expected = "$.a: expected 2, found 1")]
+fn assert_deep_equals_panics() {
+    let mut context = Context::new();
+
+    let actual = forward_val(&mut context, "({ a: 1 })").unwrap();
+    let expected = forward_val(&mut context, "({ a: 2 })").unwrap();
+    assert_deep_equals(&actual, &expected, &mut context);
+}
+
+#[test]
+fn deeply_nested_values() {
+    let mut context = Context::new();
+
+    let nested = |context: &mut Context, leaf: &str| {
+        let source = format!(
+            "var deep = [{}]; for (var i = 0; i < 3000; i++) {{ deep = {{ a: [deep] }}; }} deep",
+            leaf
+        );
+        forward_val(context, &source).unwrap()
+    };
+    let actual = nested(&mut context, "1");
+    let expected = nested(&mut context, "2");
+
+    let differences = diff(&actual, &expected, &mut context).unwrap();
+    assert_eq!(differences.differences().len(), 1);
+    assert!(differences.differences()[0].path.ends_with(".a[0].a[0][0]"));
+
+    context.set_max_nesting_depth(100);
+    let error = diff(&actual, &expected, &mut context).unwrap_err();
+    context.register_global_property("error", error, Default::default());
+    assert_eq!(forward(&mut context, "error instanceof RangeError"), "true");
+}
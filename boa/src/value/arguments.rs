@@ -0,0 +1,85 @@
+//! This module implements helpers to read the arguments of native functions.
+
+use super::TryFromJs;
+use crate::{Context, JsResult, JsValue};
+
+/// The value of missing arguments.
+const UNDEFINED: &JsValue = &JsValue::Undefined;
+
+/// Extracts the arguments passed to a native function.
+///
+/// # Examples
+///
+/// ```
+/// use boa::{value::ArgumentsExt, Context, JsResult, JsValue};
+///
+/// fn repeat(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+///     let text: String = args.require(0, "text", context)?;
+///     let count: Option<u32> = args.get_as(1, context)?;
+///     Ok(text.repeat(count.unwrap_or(1) as usize).into())
+/// }
+///
+/// let mut context = Context::new();
+/// context.register_global_function("repeat", 2, repeat).unwrap();
+/// assert_eq!(
+///     context.eval("repeat('ab', 2)").unwrap(),
+///     JsValue::new("abab")
+/// );
+/// assert!(context.eval("repeat()").is_err());
+/// ```
+pub trait ArgumentsExt {
+    /// Gets the argument at the given index, or `undefined` if it was not passed.
+    fn get_or_undefined(&self, index: usize) -> &JsValue;
+
+    /// Converts the argument at the given index, which is `undefined` if it was not passed.
+    fn get_as<T>(&self, index: usize, context: &mut Context) -> JsResult<T>
+    where
+        T: TryFromJs;
+
+    /// Converts the argument at the given index, throwing a `TypeError` that names the argument
+    /// if it was not passed.
+    fn require<T>(&self, index: usize, name: &str, context: &mut Context) -> JsResult<T>
+    where
+        T: TryFromJs;
+
+    /// Converts every argument from the given index on, like a rest parameter.
+    fn rest<T>(&self, start: usize, context: &mut Context) -> JsResult<Vec<T>>
+    where
+        T: TryFromJs;
+}
+
+impl ArgumentsExt for [JsValue] {
+    #[inline]
+    fn get_or_undefined(&self, index: usize) -> &JsValue {
+        self.get(index).unwrap_or(UNDEFINED)
+    }
+
+    #[inline]
+    fn get_as<T>(&self, index: usize, context: &mut Context) -> JsResult<T>
+    where
+        T: TryFromJs,
+    {
+        T::try_from_js(self.get_or_undefined(index), context)
+    }
+
+    fn require<T>(&self, index: usize, name: &str, context: &mut Context) -> JsResult<T>
+    where
+        T: TryFromJs,
+    {
+        match self.get(index) {
+            Some(value) => T::try_from_js(value, context),
+            None => Err(context.construct_type_error(format!("missing argument `{}`", name))),
+        }
+    }
+
+    fn rest<T>(&self, start: usize, context: &mut Context) -> JsResult<Vec<T>>
+    where
+        T: TryFromJs,
+    {
+        self.get(start..)
+            .unwrap_or_default()
+            .iter()
+            .map(|value| T::try_from_js(value, context))
+            .collect()
+    }
+}
@@ -1,7 +1,11 @@
 use super::*;
 
 use crate::builtins::Number;
-use std::hash::{Hash, Hasher};
+use crate::gc::custom_trace;
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+};
 
 impl PartialEq for JsValue {
     fn eq(&self, other: &Self) -> bool {
@@ -23,7 +27,7 @@ struct RationalHashable(f64);
 impl PartialEq for RationalHashable {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Number::same_value(self.0, other.0)
+        Number::same_value_zero(self.0, other.0)
     }
 }
 
@@ -32,7 +36,16 @@ impl Eq for RationalHashable {}
 impl Hash for RationalHashable {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        // `SameValueZero` treats every `NaN` as equal and `+0` as equal to `-0`,
+        // so those have to be normalized before hashing the bit pattern.
+        let number = if self.0.is_nan() {
+            f64::NAN
+        } else if self.0 == 0.0 {
+            0.0
+        } else {
+            self.0
+        };
+        number.to_bits().hash(state);
     }
 }
 
@@ -51,3 +64,76 @@ impl Hash for JsValue {
         }
     }
 }
+
+/// A wrapper around a [`JsValue`] that hashes and compares it using `SameValueZero`.
+///
+/// Strings and `BigInt`s are keyed by content, symbols and objects by identity, every
+/// `NaN` is equal to every other `NaN`, and `+0` is equal to `-0`. This is the same key
+/// equality used by `Map` and `Set`, so it can be used to key any Rust-side
+/// collection on JavaScript values.
+///
+/// A `JsValueHash` hashes exactly like the value it wraps, so maps keyed on it can be
+/// queried with a plain `&JsValue`:
+///
+/// ```
+/// # use boa::{JsValue, value::JsValueHash};
+/// # use std::collections::HashMap;
+/// let mut cache = HashMap::new();
+/// cache.insert(JsValueHash::new(JsValue::nan()), "not a number");
+/// cache.insert(JsValueHash::new(JsValue::new(-0.0)), "zero");
+///
+/// assert_eq!(cache.get(&JsValue::new(f64::NAN)), Some(&"not a number"));
+/// assert_eq!(cache.get(&JsValue::new(0)), Some(&"zero"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsValueHash(JsValue);
+
+// Traced by hand, since the derived implementation would also implement `Drop`, which keeps the
+// value from being moved out.
+impl Finalize for JsValueHash {}
+unsafe impl Trace for JsValueHash {
+    custom_trace!(this, {
+        mark(&this.0);
+    });
+}
+
+impl JsValueHash {
+    /// Wraps a value so it can be used as a key.
+    #[inline]
+    pub fn new(value: JsValue) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[inline]
+    pub fn as_value(&self) -> &JsValue {
+        &self.0
+    }
+
+    /// Unwraps the inner value.
+    #[inline]
+    pub fn into_inner(self) -> JsValue {
+        self.0
+    }
+}
+
+impl From<JsValue> for JsValueHash {
+    #[inline]
+    fn from(value: JsValue) -> Self {
+        Self(value)
+    }
+}
+
+impl From<JsValueHash> for JsValue {
+    #[inline]
+    fn from(value: JsValueHash) -> Self {
+        value.0
+    }
+}
+
+impl Borrow<JsValue> for JsValueHash {
+    #[inline]
+    fn borrow(&self) -> &JsValue {
+        &self.0
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
         number::{f64_to_int32, f64_to_uint32},
         Array, Number,
     },
+    error::JsErrorCode,
     object::{JsObject, Object, ObjectData},
     property::{PropertyDescriptor, PropertyKey},
     symbol::{JsSymbol, WellKnownSymbols},
@@ -24,19 +25,22 @@ use std::{
     str::FromStr,
 };
 
+mod arguments;
 mod conversions;
 pub(crate) mod display;
 mod equality;
 mod hash;
 mod operations;
+mod try_from_js;
 mod r#type;
 
+pub use arguments::ArgumentsExt;
 pub use conversions::*;
 pub use display::ValueDisplay;
-pub use equality::*;
 pub use hash::*;
 pub use operations::*;
-pub use r#type::Type;
+pub use r#type::{Type, TypeOf};
+pub use try_from_js::TryFromJs;
 
 /// A Javascript value
 #[derive(Trace, Finalize, Debug, Clone)]
@@ -200,6 +204,28 @@ impl JsValue {
         matches!(self, Self::Object(o) if o.is_function())
     }
 
+    /// Returns true if the value is an object with a `[[Call]]` internal method.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iscallable
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Self::Object(o) if o.is_callable())
+    }
+
+    /// Returns true if the value is an object with a `[[Construct]]` internal method.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
+    #[inline]
+    pub fn is_constructor(&self) -> bool {
+        matches!(self, Self::Object(o) if o.is_constructable())
+    }
+
     /// Returns true if the value is undefined.
     #[inline]
     pub fn is_undefined(&self) -> bool {
@@ -317,16 +343,6 @@ impl JsValue {
         }
     }
 
-    /// Removes a property from a [`JsValue`] object.
-    ///
-    /// It will return a boolean based on if the value was removed, if there was no value to remove false is returned.
-    pub(crate) fn remove_property<Key>(&self, key: Key) -> bool
-    where
-        Key: Into<PropertyKey>,
-    {
-        self.as_object().map(|x| x.remove(&key.into())).is_some()
-    }
-
     /// Resolve the property in the object.
     ///
     /// A copy of the Property is returned.
@@ -455,16 +471,28 @@ impl JsValue {
         // 1. Assert: input is an ECMAScript language value. (always a value not need to check)
         // 2. If Type(input) is Object, then
         if let JsValue::Object(obj) = self {
+            // a. Let exoticToPrim be ? GetMethod(input, @@toPrimitive).
+            // b. If exoticToPrim is not undefined, then
             if let Some(exotic_to_prim) =
                 obj.get_method(context, WellKnownSymbols::to_primitive())?
             {
+                // i. If preferredType is not present, let hint be "default".
+                // ii. Else if preferredType is string, let hint be "string".
+                // iii. Else,
+                //     1. Assert: preferredType is number.
+                //     2. Let hint be "number".
                 let hint = match preferred_type {
                     PreferredType::String => "string",
                     PreferredType::Number => "number",
                     PreferredType::Default => "default",
                 }
                 .into();
+
+                // iv. Let result be ? Call(exoticToPrim, input, « hint »).
                 let result = exotic_to_prim.call(self, &[hint], context)?;
+
+                // v. If Type(result) is not Object, return result.
+                // vi. Throw a TypeError exception.
                 return if result.is_object() {
                     Err(context.construct_type_error("Symbol.toPrimitive cannot return an object"))
                 } else {
@@ -472,13 +500,13 @@ impl JsValue {
                 };
             }
 
-            let mut hint = preferred_type;
-
-            if hint == PreferredType::Default {
-                hint = PreferredType::Number;
+            // c. If preferredType is not present, let preferredType be number.
+            let hint = match preferred_type {
+                PreferredType::Default => PreferredType::Number,
+                hint => hint,
             };
 
-            // g. Return ? OrdinaryToPrimitive(input, hint).
+            // d. Return ? OrdinaryToPrimitive(input, preferredType).
             obj.ordinary_to_primitive(context, hint)
         } else {
             // 3. Return input.
@@ -528,6 +556,29 @@ impl JsValue {
         }
     }
 
+    /// Abstract operation `ToBigInt ( argument )`.
+    ///
+    /// Unlike [`JsValue::to_bigint`], which follows `BigInt(value)`, this rejects Numbers with
+    /// a `TypeError` instead of converting them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-tobigint
+    pub(crate) fn to_bigint_strict(&self, context: &mut Context) -> JsResult<JsBigInt> {
+        // 1. Let prim be ? ToPrimitive(argument, number).
+        let prim = self.to_primitive(context, PreferredType::Number)?;
+
+        // 2. Return the value that prim corresponds to in Table 12.
+        if let Some(number) = prim.as_number() {
+            return Err(context.construct_type_error(format!(
+                "cannot convert the number {} to a BigInt",
+                number
+            )));
+        }
+        prim.to_bigint(context)
+    }
+
     /// Returns an object that implements `Display`.
     ///
     /// # Examples
@@ -552,8 +603,10 @@ impl JsValue {
             JsValue::Null => Ok("null".into()),
             JsValue::Undefined => Ok("undefined".into()),
             JsValue::Boolean(boolean) => Ok(boolean.to_string().into()),
-            JsValue::Rational(rational) => Ok(Number::to_native_string(*rational).into()),
-            JsValue::Integer(integer) => Ok(integer.to_string().into()),
+            JsValue::Rational(rational) => Ok(context.number_strings.number_to_string(*rational)),
+            JsValue::Integer(integer) => {
+                Ok(context.number_strings.number_to_string(f64::from(*integer)))
+            }
             JsValue::String(string) => Ok(string.clone()),
             JsValue::Symbol(_) => {
                 Err(context.construct_type_error("can't convert symbol to string"))
@@ -573,9 +626,10 @@ impl JsValue {
     /// See: <https://tc39.es/ecma262/#sec-toobject>
     pub fn to_object(&self, context: &mut Context) -> JsResult<JsObject> {
         match self {
-            JsValue::Undefined | JsValue::Null => {
-                Err(context.construct_type_error("cannot convert 'null' or 'undefined' to object"))
-            }
+            JsValue::Undefined | JsValue::Null => Err(context.construct_coded_error(
+                JsErrorCode::NULL_TO_OBJECT,
+                "cannot convert 'null' or 'undefined' to object",
+            )),
             JsValue::Boolean(boolean) => {
                 let prototype = context.standard_objects().boolean_object().prototype();
                 Ok(JsObject::new(Object::with_prototype(
@@ -654,11 +708,16 @@ impl JsValue {
     ///
     /// See: <https://tc39.es/ecma262/#sec-tonumeric>
     pub fn to_numeric(&self, context: &mut Context) -> JsResult<Numeric> {
+        // 1. Let primValue be ? ToPrimitive(value, number).
         let primitive = self.to_primitive(context, PreferredType::Number)?;
+
+        // 2. If Type(primValue) is BigInt, return primValue.
         if let Some(bigint) = primitive.as_bigint() {
             return Ok(bigint.clone().into());
         }
-        Ok(self.to_number(context)?.into())
+
+        // 3. Return ? ToNumber(primValue).
+        Ok(primitive.to_number(context)?.into())
     }
 
     /// Converts a value to an integral 32 bit unsigned integer.
@@ -850,31 +909,31 @@ impl JsValue {
         }
     }
 
-    /// `typeof` operator. Returns a string representing the type of the
-    /// given ECMA Value.
+    /// `typeof` operator. Returns the type of the given ECMA Value, as the operator reports it.
+    ///
+    /// Use [`TypeOf::as_str`] to get the string the operator evaluates to.
     ///
     /// More information:
     /// - [EcmaScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-typeof-operator
-    pub fn type_of(&self) -> JsString {
+    pub fn type_of(&self) -> TypeOf {
         match *self {
-            Self::Rational(_) | Self::Integer(_) => "number",
-            Self::String(_) => "string",
-            Self::Boolean(_) => "boolean",
-            Self::Symbol(_) => "symbol",
-            Self::Null => "object",
-            Self::Undefined => "undefined",
-            Self::BigInt(_) => "bigint",
+            Self::Rational(_) | Self::Integer(_) => TypeOf::Number,
+            Self::String(_) => TypeOf::String,
+            Self::Boolean(_) => TypeOf::Boolean,
+            Self::Symbol(_) => TypeOf::Symbol,
+            Self::Null => TypeOf::Object,
+            Self::Undefined => TypeOf::Undefined,
+            Self::BigInt(_) => TypeOf::BigInt,
             Self::Object(ref object) => {
-                if object.is_function() {
-                    "function"
+                if object.is_callable() {
+                    TypeOf::Function
                 } else {
-                    "object"
+                    TypeOf::Object
                 }
             }
         }
-        .into()
     }
 
     /// Check if it is an array.
@@ -883,20 +942,110 @@ impl JsValue {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isarray
-    pub(crate) fn is_array(&self, _context: &mut Context) -> JsResult<bool> {
+    pub(crate) fn is_array(&self, context: &mut Context) -> JsResult<bool> {
         // 1. If Type(argument) is not Object, return false.
         if let Some(object) = self.as_object() {
             // 2. If argument is an Array exotic object, return true.
-            //     a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
+            if object.is_array() {
+                return Ok(true);
+            }
+
             // 3. If argument is a Proxy exotic object, then
-            //     b. Let target be argument.[[ProxyTarget]].
-            //     c. Return ? IsArray(target).
+            let proxy = object.borrow().as_proxy().cloned();
+            if let Some(proxy) = proxy {
+                //     a. If argument.[[ProxyHandler]] is null, throw a TypeError exception.
+                //     b. Let target be argument.[[ProxyTarget]].
+                let (target, _) = proxy.try_data(context)?;
+                //     c. Return ? IsArray(target).
+                return JsValue::from(target).is_array(context);
+            }
+
             // 4. Return false.
-            Ok(object.is_array())
+            Ok(false)
         } else {
             Ok(false)
         }
     }
+
+    /// Retrieves the value of a property of the value, converting primitive values to an object
+    /// to look up the property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getv
+    pub(crate) fn get_v<K>(&self, key: K, context: &mut Context) -> JsResult<JsValue>
+    where
+        K: Into<PropertyKey>,
+    {
+        // 1. Assert: IsPropertyKey(P) is true.
+        // 2. Let O be ? ToObject(V).
+        let object = self.to_object(context)?;
+
+        // 3. Return ? O.[[Get]](P, V).
+        object.__get__(&key.into(), self.clone(), context)
+    }
+
+    /// Retrieves the value of a property of the value, when it is expected to be a function.
+    ///
+    /// Unlike [`JsObject::get_method`], primitive values are converted to an object to look up
+    /// the property, so methods inherited from their prototype are found as well.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmethod
+    pub(crate) fn get_method<K>(&self, context: &mut Context, key: K) -> JsResult<Option<JsObject>>
+    where
+        K: Into<PropertyKey>,
+    {
+        // 1. Assert: IsPropertyKey(P) is true.
+        // 2. Let func be ? GetV(V, P).
+        // 3. If func is either undefined or null, return undefined.
+        // 4. If IsCallable(func) is false, throw a TypeError exception.
+        // 5. Return func.
+        match self {
+            Self::Object(object) => object.get_method(context, key),
+            _ => {
+                let func = self.get_v(key, context)?;
+                if func.is_null_or_undefined() {
+                    return Ok(None);
+                }
+                match func.as_object() {
+                    Some(object) if object.is_callable() => Ok(Some(object)),
+                    _ => Err(context.construct_type_error(
+                        "value returned for property of object is not a function",
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Check if it is a regular expression, or an object asking to be treated as one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isregexp
+    pub(crate) fn is_regexp(&self, context: &mut Context) -> JsResult<bool> {
+        // 1. If Type(argument) is not Object, return false.
+        let object = match self {
+            Self::Object(object) => object,
+            _ => return Ok(false),
+        };
+
+        // 2. Let matcher be ? Get(argument, @@match).
+        let matcher = object.get(WellKnownSymbols::match_(), context)?;
+
+        // 3. If matcher is not undefined, return ! ToBoolean(matcher).
+        if !matcher.is_undefined() {
+            return Ok(matcher.to_boolean());
+        }
+
+        // 4. If argument has a [[RegExpMatcher]] internal slot, return true.
+        // 5. Return false.
+        Ok(object.is_regexp())
+    }
 }
 
 impl Default for JsValue {
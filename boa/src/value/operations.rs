@@ -12,8 +12,12 @@ impl JsValue {
             (Self::Rational(x), Self::Integer(y)) => Self::new(x + f64::from(*y)),
 
             (Self::String(ref x), Self::String(ref y)) => Self::from(JsString::concat(x, y)),
-            (Self::String(ref x), y) => Self::from(JsString::concat(x, y.to_string(context)?)),
-            (x, Self::String(ref y)) => Self::from(JsString::concat(x.to_string(context)?, y)),
+            (Self::String(ref x), y) if !y.is_object() => {
+                Self::from(JsString::concat(x, y.to_string(context)?))
+            }
+            (x, Self::String(ref y)) if !x.is_object() => {
+                Self::from(JsString::concat(x.to_string(context)?, y))
+            }
             (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::add(x, y)),
 
             // Slow path:
@@ -583,6 +587,47 @@ impl JsValue {
             AbstractRelation::True | AbstractRelation::Undefined => Ok(false),
         }
     }
+
+    /// The `instanceof` operator returns `true` if `target` considers the value to be one of its
+    /// instances, usually because the `prototype` of `target` is in the prototype chain of the
+    /// value.
+    ///
+    /// More Information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/instanceof
+    /// [spec]: https://tc39.es/ecma262/#sec-instanceofoperator
+    pub fn instance_of(&self, target: &Self, context: &mut Context) -> JsResult<bool> {
+        // 1. If Type(target) is not Object, throw a TypeError exception.
+        let object = match target {
+            Self::Object(object) => object,
+            _ => {
+                return Err(context.construct_type_error(format!(
+                    "right-hand side of 'instanceof' should be an object, got {}",
+                    target.type_of()
+                )))
+            }
+        };
+
+        // 2. Let instOfHandler be ? GetMethod(target, @@hasInstance).
+        // 3. If instOfHandler is not undefined, then
+        if let Some(handler) = object.get_method(context, WellKnownSymbols::has_instance())? {
+            // a. Return ! ToBoolean(? Call(instOfHandler, target, « V »)).
+            return Ok(handler.call(target, &[self.clone()], context)?.to_boolean());
+        }
+
+        // 4. If IsCallable(target) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(context.construct_coded_error(
+                JsErrorCode::INSTANCEOF_NOT_CALLABLE,
+                "right-hand side of 'instanceof' is not callable",
+            ));
+        }
+
+        // 5. Return ? OrdinaryHasInstance(target, V).
+        object.ordinary_has_instance(context, self)
+    }
 }
 
 /// The result of the [Abstract Relational Comparison][arc].
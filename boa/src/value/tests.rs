@@ -153,6 +153,47 @@ fn hash_object() {
     assert_ne!(hash_value(&object1), hash_value(&object2));
 }
 
+#[test]
+fn hash_same_value_zero() {
+    let positive_zero = JsValue::new(0.0);
+    let negative_zero = JsValue::new(-0.0);
+    assert_eq!(positive_zero, negative_zero);
+    assert_eq!(hash_value(&positive_zero), hash_value(&negative_zero));
+    assert_eq!(hash_value(&JsValue::new(0)), hash_value(&negative_zero));
+
+    let nan = JsValue::nan();
+    let negative_nan = JsValue::new(-f64::NAN);
+    assert_eq!(nan, negative_nan);
+    assert_eq!(hash_value(&nan), hash_value(&negative_nan));
+
+    assert_eq!(hash_value(&JsValue::new(2)), hash_value(&JsValue::new(2.0)));
+    assert_eq!(
+        hash_value(&JsValue::new("key")),
+        hash_value(&JsValue::new(String::from("key")))
+    );
+}
+
+#[test]
+fn js_value_hash_as_map_key() {
+    use std::collections::HashMap;
+
+    let object = JsValue::new(Object::default());
+    let mut map = HashMap::new();
+    map.insert(JsValueHash::new(JsValue::nan()), 1);
+    map.insert(JsValueHash::new(JsValue::new(-0.0)), 2);
+    map.insert(JsValueHash::new(JsValue::new("key")), 3);
+    map.insert(JsValueHash::new(object.clone()), 4);
+
+    assert_eq!(map.get(&JsValue::new(-f64::NAN)), Some(&1));
+    assert_eq!(map.get(&JsValue::new(0)), Some(&2));
+    assert_eq!(map.get(&JsValue::new(String::from("key"))), Some(&3));
+    assert_eq!(map.get(&object), Some(&4));
+    assert_eq!(map.get(&JsValue::new(Object::default())), None);
+
+    map.insert(JsValueHash::new(JsValue::new(0.0)), 5);
+    assert_eq!(map.len(), 4);
+}
+
 #[test]
 fn get_types() {
     let mut context = Context::new();
@@ -209,6 +250,53 @@ fn get_types() {
     );
 }
 
+#[test]
+fn type_of() {
+    let mut context = Context::new();
+
+    for (source, expected) in &[
+        ("undefined", TypeOf::Undefined),
+        ("null", TypeOf::Object),
+        ("true", TypeOf::Boolean),
+        ("1.5", TypeOf::Number),
+        ("10n", TypeOf::BigInt),
+        ("'Hi'", TypeOf::String),
+        ("Symbol()", TypeOf::Symbol),
+        ("({})", TypeOf::Object),
+        ("Math.max", TypeOf::Function),
+        ("new Proxy(function () {}, {})", TypeOf::Function),
+    ] {
+        let value = forward_val(&mut context, source).unwrap();
+        assert_eq!(value.type_of(), *expected, "{}", source);
+        assert_eq!(
+            forward(&mut context, &format!("typeof ({})", source)),
+            format!("\"{}\"", expected),
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn is_callable_and_constructor() {
+    let mut context = Context::new();
+
+    for (source, callable, constructor) in &[
+        ("1", false, false),
+        ("({})", false, false),
+        ("Math.max", true, false),
+        ("(() => 1)", true, false),
+        ("(function () {})", true, true),
+        ("Array", true, true),
+        ("new Proxy(Math.max, {})", true, false),
+        ("new Proxy(Array, {})", true, true),
+    ] {
+        let value = forward_val(&mut context, source).unwrap();
+        assert_eq!(value.is_callable(), *callable, "{}", source);
+        assert_eq!(value.is_constructor(), *constructor, "{}", source);
+    }
+}
+
 #[test]
 fn to_string() {
     let f64_to_str = |f| JsValue::new(f).display().to_string();
@@ -619,9 +707,91 @@ fn to_primitive() {
     ]);
 }
 
+#[test]
+fn to_primitive_hints() {
+    let mut context = Context::new();
+    let init = r#"
+        var hints = [];
+        var o = {};
+        o[Symbol.toPrimitive] = function(hint) {
+            hints.push(hint);
+            return hint === 'number' ? 42 : 'str';
+        };
+        function hintsOf(f) { hints = []; f(); return hints.join(); }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "o + 1"), "\"str1\"");
+    assert_eq!(forward(&mut context, "'a' + o"), "\"astr\"");
+    assert_eq!(
+        forward(&mut context, "hintsOf(function() { 'a' + o; })"),
+        "\"default\""
+    );
+    assert_eq!(
+        forward(&mut context, "hintsOf(function() { o == 'str'; })"),
+        "\"default\""
+    );
+    assert_eq!(
+        forward(&mut context, "hintsOf(function() { o < 50; })"),
+        "\"number\""
+    );
+    assert_eq!(
+        forward(&mut context, "hintsOf(function() { o * 1; })"),
+        "\"number\""
+    );
+    assert_eq!(
+        forward(&mut context, "hintsOf(function() { `${o}`; })"),
+        "\"string\""
+    );
+    assert_eq!(
+        forward(&mut context, "hintsOf(function() { ({})[o]; })"),
+        "\"string\""
+    );
+}
+
+#[test]
+fn to_primitive_errors() {
+    let mut context = Context::new();
+    let init = r#"
+        var notCallable = {};
+        notCallable[Symbol.toPrimitive] = 1;
+        var returnsObject = {};
+        returnsObject[Symbol.toPrimitive] = function() { return {}; };
+        var ordinary = { valueOf: function() { return 7; } };
+        ordinary[Symbol.toPrimitive] = null;
+        "#;
+    forward(&mut context, init);
+
+    assert!(forward(&mut context, "notCallable + 1").starts_with("Uncaught \"TypeError\""));
+    assert_eq!(
+        forward(&mut context, "returnsObject + 1"),
+        "Uncaught \"TypeError\": \"Symbol.toPrimitive cannot return an object\""
+    );
+    assert_eq!(forward(&mut context, "ordinary + 1"), "8");
+}
+
 /// Test cyclic conversions that previously caused stack overflows
 /// Relevant mitigations for these are in `JsObject::ordinary_to_primitive` and
 /// `JsObject::to_json`
+#[test]
+fn arguments_ext() {
+    let mut context = Context::new();
+    let args = [JsValue::new("12"), JsValue::undefined(), JsValue::new(3.5)];
+
+    assert!(args.get_or_undefined(5).is_undefined());
+    assert_eq!(args.get_as::<f64>(0, &mut context).unwrap(), 12.0);
+    assert_eq!(args.get_as::<Option<u32>>(1, &mut context).unwrap(), None);
+    assert_eq!(args.get_as::<Option<u32>>(7, &mut context).unwrap(), None);
+    assert_eq!(
+        args.require::<JsString>(2, "x", &mut context).unwrap(),
+        "3.5"
+    );
+    assert!(args.require::<f64>(3, "x", &mut context).is_err());
+    assert!(args.require::<JsObject>(0, "x", &mut context).is_err());
+    assert_eq!(args.rest::<i32>(0, &mut context).unwrap(), vec![12, 0, 3]);
+    assert!(args.rest::<i32>(4, &mut context).unwrap().is_empty());
+}
+
 mod cyclic_conversions {
     use super::*;
 
@@ -0,0 +1,118 @@
+//! This module implements the conversion of JavaScript values into Rust values.
+
+use crate::{error::JsErrorCode, object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue};
+
+/// A Rust type that can be created from a JavaScript value.
+///
+/// The conversion follows the abstract operation of the specification that coerces a value to
+/// the type, so it can run user code and throw, like `ToNumber` calling `valueOf`.
+///
+/// # Examples
+///
+/// ```
+/// use boa::{value::TryFromJs, Context, JsValue};
+///
+/// let mut context = Context::new();
+/// let value = JsValue::new("42");
+/// assert_eq!(f64::try_from_js(&value, &mut context).unwrap(), 42.0);
+/// ```
+pub trait TryFromJs: Sized {
+    /// Converts a JavaScript value into the type.
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self>;
+}
+
+impl TryFromJs for JsValue {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+/// Converts with `ToBoolean`.
+impl TryFromJs for bool {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        Ok(value.to_boolean())
+    }
+}
+
+/// Converts with `ToNumber`.
+impl TryFromJs for f64 {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.to_number(context)
+    }
+}
+
+/// Converts with `ToInt32`.
+impl TryFromJs for i32 {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.to_i32(context)
+    }
+}
+
+/// Converts with `ToUint32`.
+impl TryFromJs for u32 {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.to_u32(context)
+    }
+}
+
+/// Converts with `ToIndex`.
+impl TryFromJs for usize {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.to_index(context)
+    }
+}
+
+/// Converts with `ToString`.
+impl TryFromJs for JsString {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.to_string(context)
+    }
+}
+
+/// Converts with `ToString`.
+impl TryFromJs for String {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        Ok(value.to_string(context)?.to_string())
+    }
+}
+
+/// Converts with `ToBigInt`.
+impl TryFromJs for JsBigInt {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.to_bigint(context)
+    }
+}
+
+/// Only objects are converted, other values throw a `TypeError`.
+impl TryFromJs for JsObject {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value.as_object().ok_or_else(|| {
+            context.construct_coded_error(JsErrorCode::NOT_AN_OBJECT, "value is not an object")
+        })
+    }
+}
+
+/// `undefined` is converted to `None`, other values are converted to `T`.
+impl<T> TryFromJs for Option<T>
+where
+    T: TryFromJs,
+{
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_undefined() {
+            Ok(None)
+        } else {
+            T::try_from_js(value, context).map(Some)
+        }
+    }
+}
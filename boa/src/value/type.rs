@@ -1,4 +1,6 @@
 use super::JsValue;
+use crate::JsString;
+use std::fmt;
 
 /// Possible types of values as defined at <https://tc39.es/ecma262/#sec-typeof-operator>.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -13,6 +15,50 @@ pub enum Type {
     Object,
 }
 
+/// Possible results of the `typeof` operator, as defined at
+/// <https://tc39.es/ecma262/#sec-typeof-operator>.
+///
+/// Unlike [`Type`], `null` is an `Object`, and callable objects are a `Function`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TypeOf {
+    Undefined,
+    Object,
+    Boolean,
+    Number,
+    String,
+    Symbol,
+    BigInt,
+    Function,
+}
+
+impl TypeOf {
+    /// Returns the string the `typeof` operator evaluates to.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Undefined => "undefined",
+            Self::Object => "object",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Symbol => "symbol",
+            Self::BigInt => "bigint",
+            Self::Function => "function",
+        }
+    }
+}
+
+impl fmt::Display for TypeOf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<TypeOf> for JsString {
+    fn from(type_of: TypeOf) -> Self {
+        type_of.as_str().into()
+    }
+}
+
 impl JsValue {
     /// Get the type of a value
     ///
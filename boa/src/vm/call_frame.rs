@@ -0,0 +1,240 @@
+//! The call frames of the vm, and the frames of the suspended generators and async functions.
+
+use crate::{
+    builtins::iterable::IteratorRecord,
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    teardown::Contents,
+    vm::{CodeBlock, FrameExit},
+    Context, JsResult, JsValue,
+};
+
+use std::rc::Rc;
+
+/// A frame of the code being run by the vm, linked to the frame of its caller.
+#[derive(Debug)]
+pub struct CallFrame {
+    pub(crate) prev: Option<Box<Self>>,
+    pub(crate) code: Rc<CodeBlock>,
+    pub(crate) pc: usize,
+
+    /// The length of the environment stack when the frame was entered.
+    pub(crate) environment_depth: usize,
+
+    /// The length of the value stack when the frame was entered.
+    pub(crate) stack_base: usize,
+
+    /// The handlers of the `try` blocks being run, the innermost one last.
+    pub(crate) handlers: Vec<TryHandler>,
+
+    /// The iterators being iterated by the code of the frame, the innermost one last.
+    pub(crate) iterators: Vec<ActiveIterator>,
+
+    /// The value of the last statement evaluated in script code.
+    pub(crate) completion: JsValue,
+}
+
+/// What a handler of a `try` block runs when the block is left abruptly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HandlerKind {
+    /// A `catch` block, run with the exception pushed.
+    Catch,
+
+    /// A `finally` block, run with the completion of the block pushed, which is resumed by the
+    /// `FinallyEnd` at the end of the block.
+    Finally,
+}
+
+/// The state restored when a `try` block is left by an exception, or by a `finally` block.
+///
+/// The depths are relative to the depths of the frame when it was entered, since a frame is
+/// entered again at other depths each time a generator is resumed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TryHandler {
+    pub(crate) kind: HandlerKind,
+
+    /// The address of the `catch` or `finally` block.
+    pub(crate) address: u32,
+    pub(crate) environment_depth: usize,
+    pub(crate) stack_len: usize,
+    pub(crate) iterator_count: usize,
+}
+
+/// An iterator being iterated by the code of a frame, like the iterable of a `for...of` loop.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct ActiveIterator {
+    pub(crate) record: IteratorRecord,
+
+    /// Whether the iterator is done, or threw an exception, in which case it isn't closed.
+    #[unsafe_ignore_trace]
+    pub(crate) done: bool,
+}
+
+/// How the code of a frame completed, pushed as an integer with the value of the completion
+/// when a `finally` block or a suspended frame is entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompletionType {
+    Normal = 0,
+    Throw = 1,
+    Return = 2,
+
+    /// A `break` or `continue` statement, whose value is the address the `finally` block jumps
+    /// to when it ends.
+    Jump = 3,
+}
+
+impl CompletionType {
+    /// Converts an integer pushed as a completion type.
+    ///
+    /// # Panics
+    ///
+    /// If the value isn't a completion type, then this will panic.
+    #[track_caller]
+    pub(crate) fn from_value(value: &JsValue) -> Self {
+        match value {
+            JsValue::Integer(0) => Self::Normal,
+            JsValue::Integer(1) => Self::Throw,
+            JsValue::Integer(2) => Self::Return,
+            JsValue::Integer(3) => Self::Jump,
+            value => panic!("invalid completion type: {}", value.display()),
+        }
+    }
+}
+
+impl From<CompletionType> for JsValue {
+    #[inline]
+    fn from(completion: CompletionType) -> Self {
+        Self::Integer(completion as i32)
+    }
+}
+
+/// Why the code of a frame was suspended, with the value it was suspended with.
+#[derive(Debug, Clone)]
+pub(crate) enum Suspension {
+    /// An `await` expression, which is resumed when its promise is settled.
+    Await(JsValue),
+
+    /// A `yield` expression, which is resumed by the next call to the generator.
+    Yield(JsValue),
+}
+
+/// The state of a frame after it was resumed.
+#[derive(Debug)]
+pub(crate) enum FrameState {
+    /// The frame was suspended again.
+    Suspended(Suspension, SuspendedFrame),
+
+    /// The frame returned a value, or threw an exception.
+    Complete(JsResult<JsValue>),
+}
+
+/// The frame of a generator, or of an async function, which is not running.
+///
+/// The frame owns its values and its environments while it is suspended, which are moved back
+/// to the stacks of the vm when it is resumed, so it can be resumed from any frame of any
+/// caller.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct SuspendedFrame {
+    #[unsafe_ignore_trace]
+    code: Rc<CodeBlock>,
+    #[unsafe_ignore_trace]
+    pc: usize,
+    stack: Vec<JsValue>,
+    environments: Vec<Environment>,
+    #[unsafe_ignore_trace]
+    handlers: Vec<TryHandler>,
+    iterators: Vec<ActiveIterator>,
+}
+
+impl SuspendedFrame {
+    /// Queues the values, environments and iterators of the frame to be emptied by the teardown
+    /// of the context.
+    pub(crate) fn collect_contents(&self, contents: &mut Contents) {
+        contents.extend_values(&self.stack);
+        for environment in &self.environments {
+            contents.push_environment(environment);
+        }
+        for iterator in &self.iterators {
+            iterator.record.collect_contents(contents);
+        }
+    }
+
+    /// Creates the frame that runs `code` from its start, in the given environments.
+    pub(crate) fn new(code: Rc<CodeBlock>, environments: Vec<Environment>) -> Self {
+        Self {
+            code,
+            pc: 0,
+            stack: Vec::new(),
+            environments,
+            handlers: Vec::new(),
+            iterators: Vec::new(),
+        }
+    }
+
+    /// Runs the frame until it is suspended again, or until it completes.
+    ///
+    /// Unless the frame is started, the completion it is resumed with is pushed for the
+    /// instruction it was suspended at, which is a `Yield` or an `Await`. A frame is always
+    /// started with a normal completion.
+    pub(crate) fn resume(
+        mut self,
+        value: JsValue,
+        completion: CompletionType,
+        context: &mut Context,
+    ) -> FrameState {
+        let environment_depth = context.environment_stack_len();
+        for environment in std::mem::take(&mut self.environments) {
+            context.push_environment(environment);
+        }
+
+        let stack_base = context.vm.stack.len();
+        context.vm.stack.append(&mut self.stack);
+        if self.pc != 0 {
+            context.vm.push(value);
+            context.vm.push(completion);
+        }
+
+        let frame = CallFrame {
+            prev: context.vm.frame.take(),
+            code: self.code.clone(),
+            pc: self.pc,
+            environment_depth,
+            stack_base,
+            handlers: std::mem::take(&mut self.handlers),
+            iterators: std::mem::take(&mut self.iterators),
+            completion: JsValue::undefined(),
+        };
+        context.vm.frame = Some(Box::new(frame));
+
+        let result = context.run();
+
+        let mut frame = context
+            .vm
+            .frame
+            .take()
+            .expect("the frame of the code being run");
+        context.vm.frame = frame.prev.take();
+
+        match result {
+            Ok(FrameExit::Suspend(suspension)) => {
+                let frame = Self {
+                    stack: context.vm.stack.split_off(frame.stack_base),
+                    environments: context.split_off_environments(frame.environment_depth),
+                    code: frame.code,
+                    pc: frame.pc,
+                    handlers: frame.handlers,
+                    iterators: frame.iterators,
+                };
+                FrameState::Suspended(suspension, frame)
+            }
+            result => {
+                context.vm.stack.truncate(frame.stack_base);
+                context.restore_environments(frame.environment_depth);
+                FrameState::Complete(result.map(|exit| match exit {
+                    FrameExit::Return(value) => value,
+                    FrameExit::Suspend(_) => unreachable!(),
+                }))
+            }
+        }
+    }
+}
@@ -1,6 +1,14 @@
-use crate::{vm::Opcode, JsString, JsValue};
+use crate::{
+    builtins::function::FunctionFlags,
+    syntax::ast::{
+        node::{statement_list::RcStatementList, FormalParameter},
+        Node,
+    },
+    vm::{Opcode, PropertyCache},
+    JsString, JsValue,
+};
 
-use std::{convert::TryInto, fmt::Write, mem::size_of};
+use std::{convert::TryInto, fmt::Write, mem::size_of, rc::Rc};
 
 /// This represents wether an object can be read from [`CodeBlock`] code.
 pub unsafe trait Readable {}
@@ -26,6 +34,29 @@ pub struct CodeBlock {
 
     /// Variables names
     pub(crate) names: Vec<JsString>,
+
+    /// Nodes evaluated by walking their syntax tree, since they have no bytecode yet, and the
+    /// tagged templates whose template objects are created by `GetTemplateObject`.
+    pub(crate) nodes: Vec<Node>,
+
+    /// Functions defined in the code, created by `GetFunction`.
+    pub(crate) functions: Vec<FunctionCode>,
+
+    /// The inline caches of the property accesses by name.
+    pub(crate) property_caches: Vec<PropertyCache>,
+}
+
+/// The definition of a function, from which its function objects are created.
+#[derive(Debug)]
+pub(crate) struct FunctionCode {
+    pub(crate) name: JsString,
+    pub(crate) params: Box<[FormalParameter]>,
+    pub(crate) body: RcStatementList,
+    pub(crate) flags: FunctionFlags,
+    pub(crate) source_text: Option<JsString>,
+
+    /// The bytecode of the body.
+    pub(crate) code: Rc<CodeBlock>,
 }
 
 impl Default for CodeBlock {
@@ -40,6 +71,9 @@ impl CodeBlock {
             code: Vec::new(),
             literals: Vec::new(),
             names: Vec::new(),
+            nodes: Vec::new(),
+            functions: Vec::new(),
+            property_caches: Vec::new(),
         }
     }
 
@@ -96,25 +130,116 @@ impl CodeBlock {
             | Opcode::Default
             | Opcode::LogicalAnd
             | Opcode::LogicalOr
-            | Opcode::Coalesce => {
+            | Opcode::Coalesce
+            | Opcode::Call
+            | Opcode::TryStart
+            | Opcode::FinallyStart
+            | Opcode::PushJumpCompletion
+            | Opcode::JumpIfNotUndefined
+            | Opcode::JumpIfNullOrUndefined
+            | Opcode::CallEval
+            | Opcode::SuperCall
+            | Opcode::CopyDataProperties
+            | Opcode::ConcatToString
+            | Opcode::GetTemplateObject
+            | Opcode::ForInLoopInitIterator
+            | Opcode::IteratorResult
+            | Opcode::IteratorCallReturn => {
                 let result = self.read::<u32>(*pc).to_string();
                 *pc += size_of::<u32>();
                 result
             }
+            Opcode::RotateLeft | Opcode::RotateRight | Opcode::DeletePropertyByValue => {
+                let result = self.read::<u8>(*pc).to_string();
+                *pc += size_of::<u8>();
+                result
+            }
+            Opcode::JumpIfNotCompletionType => {
+                let address = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let completion_type = self.read::<u8>(*pc);
+                *pc += size_of::<u8>();
+                format!("{}, {}", address, completion_type)
+            }
+            Opcode::GeneratorDelegateNext | Opcode::GeneratorDelegateResume => {
+                let first = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let second = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!("{}, {}", first, second)
+            }
+            Opcode::ThrowTypeError | Opcode::ThrowSyntaxError => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{:04}: {}",
+                    operand,
+                    self.literals[operand as usize].display()
+                )
+            }
             Opcode::DefVar
+            | Opcode::DefInitVar
             | Opcode::DefLet
+            | Opcode::DefInitLet
             | Opcode::DefConst
-            | Opcode::InitLexical
+            | Opcode::DefInitConst
             | Opcode::GetName
             | Opcode::SetName
-            | Opcode::GetPropertyByName
-            | Opcode::SetPropertyByName => {
+            | Opcode::ImplicitThis
+            | Opcode::DefineOwnPropertyByName
+            | Opcode::DefineMethodByName
+            | Opcode::SetGetterByName
+            | Opcode::SetSetterByName
+            | Opcode::SetFunctionName
+            | Opcode::DefPrivateName
+            | Opcode::PushClassPrivateMethod
+            | Opcode::PushClassPrivateGetter
+            | Opcode::PushClassPrivateSetter
+            | Opcode::PushClassPrivateField
+            | Opcode::DefinePrivateMethod
+            | Opcode::DefinePrivateGetter
+            | Opcode::DefinePrivateSetter
+            | Opcode::PushClassStaticPrivateField
+            | Opcode::GetPrivateField
+            | Opcode::SetPrivateField
+            | Opcode::InPrivate
+            | Opcode::DeleteName
+            | Opcode::NewSpread => {
                 let operand = self.read::<u32>(*pc);
                 *pc += size_of::<u32>();
                 format!("{:04}: '{}'", operand, self.names[operand as usize])
             }
+            Opcode::GetPropertyByName | Opcode::SetPropertyByName => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let cache_index = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{:04}: '{}', cache {}",
+                    operand, self.names[operand as usize], cache_index
+                )
+            }
+            Opcode::New => {
+                let argument_count = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{}, {:04}: '{}'",
+                    argument_count, operand, self.names[operand as usize]
+                )
+            }
+            Opcode::GetFunction => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{:04}: '{}'",
+                    operand, self.functions[operand as usize].name
+                )
+            }
             Opcode::Pop
             | Opcode::Dup
+            | Opcode::Dup2
             | Opcode::Swap
             | Opcode::PushZero
             | Opcode::PushOne
@@ -154,11 +279,53 @@ impl CodeBlock {
             | Opcode::LogicalNot
             | Opcode::Pos
             | Opcode::Neg
+            | Opcode::Inc
+            | Opcode::Dec
             | Opcode::GetPropertyByValue
             | Opcode::SetPropertyByValue
             | Opcode::ToBoolean
             | Opcode::Throw
             | Opcode::This
+            | Opcode::NewTarget
+            | Opcode::Return
+            | Opcode::TryEnd
+            | Opcode::PushDeclarativeEnvironment
+            | Opcode::PopEnvironment
+            | Opcode::SetCompletion
+            | Opcode::GetCompletion
+            | Opcode::PushValueToArray
+            | Opcode::PushIteratorToArray
+            | Opcode::GetSuperProperty
+            | Opcode::SetSuperProperty
+            | Opcode::DeleteSuperProperty
+            | Opcode::PushClassPrototype
+            | Opcode::PushDerivedClassPrototype
+            | Opcode::DefineClassMethod
+            | Opcode::DefineClassGetter
+            | Opcode::DefineClassSetter
+            | Opcode::PushClassField
+            | Opcode::PushClassStaticField
+            | Opcode::PushClassStaticBlock
+            | Opcode::DefineClassStaticElements
+            | Opcode::ImportCall
+            | Opcode::CallSpread
+            | Opcode::CallEvalSpread
+            | Opcode::SuperConstructor
+            | Opcode::SuperCallSpread
+            | Opcode::FinallyEnd
+            | Opcode::PushObjectEnvironment
+            | Opcode::GetIterator
+            | Opcode::GetAsyncIterator
+            | Opcode::IteratorNext
+            | Opcode::IteratorStepValue
+            | Opcode::IteratorToArray
+            | Opcode::IteratorPop
+            | Opcode::IteratorCloseResult
+            | Opcode::ValueNotNullOrUndefined
+            | Opcode::Yield
+            | Opcode::Await
+            | Opcode::ResumeCompletion
+            | Opcode::CreateIteratorResult
             | Opcode::Nop => String::new(),
         }
     }
@@ -206,6 +373,17 @@ impl std::fmt::Display for CodeBlock {
             writeln!(f, "    <empty>")?;
         }
 
+        f.write_char('\n')?;
+
+        f.write_str("Functions:\n")?;
+        if !self.functions.is_empty() {
+            for (i, function) in self.functions.iter().enumerate() {
+                writeln!(f, "    {:04}: '{}'", i, function.name)?;
+            }
+        } else {
+            writeln!(f, "    <empty>")?;
+        }
+
         Ok(())
     }
 }
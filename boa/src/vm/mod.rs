@@ -3,47 +3,78 @@
 //! plus an interpreter to execute those instructions
 
 use crate::{
-    builtins::Array, environment::lexical_environment::VariableScope, symbol::WellKnownSymbols,
-    BoaProfiler, Context, JsResult, JsValue,
+    builtins::{
+        function::{ClassFieldDefinition, ClassFieldName, ClassStaticElement},
+        iterable::{
+            create_iter_result_object, get_async_iterator, get_iterator, iterable_to_list,
+            IteratorRecord, IteratorResult,
+        },
+        Array, Eval, ForInIterator, Number,
+    },
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::VariableScope, object_environment_record::ObjectEnvironmentRecord,
+    },
+    error::JsErrorCode,
+    exec::{
+        get_template_object, import_dynamically, private_name, super_base, super_call,
+        super_constructor, BindingKind,
+    },
+    object::{JsObject, Object, PrivateElement, PROTOTYPE},
+    property::{PropertyDescriptor, PropertyKey},
+    syntax::ast::Node,
+    value::{ArgumentsExt, Numeric},
+    BoaProfiler, Context, JsBigInt, JsResult, JsSymbol, JsValue,
 };
 
+mod call_frame;
 mod code_block;
 mod opcode;
+mod property_cache;
 
+pub use call_frame::CallFrame;
 pub use code_block::CodeBlock;
 pub use opcode::Opcode;
 
-use std::{convert::TryInto, mem::size_of, time::Instant};
+pub(crate) use call_frame::{
+    ActiveIterator, CompletionType, FrameState, HandlerKind, SuspendedFrame, Suspension, TryHandler,
+};
+pub(crate) use code_block::FunctionCode;
+pub(crate) use property_cache::PropertyCache;
+
+use std::{convert::TryInto, mem::size_of, rc::Rc, time::Instant};
 
 use self::code_block::Readable;
 
 /// Virtual Machine.
+#[derive(Debug, Default)]
+pub struct Vm {
+    /// The frame of the code being run, linked to the frames of its callers.
+    pub(crate) frame: Option<Box<CallFrame>>,
+
+    /// The value stack, shared by all the frames.
+    pub(crate) stack: Vec<JsValue>,
+}
+
+/// How the run of the current frame continues after an instruction.
 #[derive(Debug)]
-pub struct Vm<'a> {
-    context: &'a mut Context,
-    pc: usize,
-    code: CodeBlock,
-    stack: Vec<JsValue>,
-    stack_pointer: usize,
-    is_trace: bool,
+enum Flow {
+    Continue,
+    Return,
+    Suspend(Suspension),
+}
+
+/// How the run of a frame was exited, besides an exception.
+#[derive(Debug)]
+pub(crate) enum FrameExit {
+    Return(JsValue),
+    Suspend(Suspension),
 }
 
 #[cfg(test)]
 mod tests;
 
-impl<'a> Vm<'a> {
-    pub fn new(code: CodeBlock, context: &'a mut Context) -> Self {
-        let trace = context.trace;
-        Self {
-            context,
-            pc: 0,
-            code,
-            stack: Vec::with_capacity(128),
-            stack_pointer: 0,
-            is_trace: trace,
-        }
-    }
-
+impl Vm {
     /// Push a value on the stack.
     #[inline]
     pub fn push<T>(&mut self, value: T)
@@ -64,84 +95,565 @@ impl<'a> Vm<'a> {
         self.stack.pop().unwrap()
     }
 
+    /// Pop the `n` values on the top of the stack, in the order they were pushed.
+    #[inline]
+    #[track_caller]
+    fn pop_n(&mut self, n: usize) -> Vec<JsValue> {
+        self.stack.split_off(self.stack.len() - n)
+    }
+
+    /// The frame being run.
+    ///
+    /// # Panics
+    ///
+    /// If no code is being run, then this will panic.
+    #[inline]
+    #[track_caller]
+    pub fn frame(&self) -> &CallFrame {
+        self.frame.as_ref().unwrap()
+    }
+
+    /// The frame being run.
+    ///
+    /// # Panics
+    ///
+    /// If no code is being run, then this will panic.
+    #[inline]
+    #[track_caller]
+    pub fn frame_mut(&mut self) -> &mut CallFrame {
+        self.frame.as_mut().unwrap()
+    }
+
     fn read<T: Readable>(&mut self) -> T {
-        let value = self.code.read::<T>(self.pc);
-        self.pc += size_of::<T>();
+        let frame = self.frame_mut();
+        let value = frame.code.read::<T>(frame.pc);
+        frame.pc += size_of::<T>();
         value
     }
+}
 
-    fn execute_instruction(&mut self) -> JsResult<()> {
+impl Context {
+    /// Runs `code` in a new frame of the vm, returning the value it returns.
+    ///
+    /// No matter how the frame is exited, the value stack and the environment stack are
+    /// restored to their state when it was entered.
+    pub(crate) fn run_code_block(&mut self, code: Rc<CodeBlock>) -> JsResult<JsValue> {
+        let frame = CallFrame {
+            prev: self.vm.frame.take(),
+            code,
+            pc: 0,
+            environment_depth: self.environment_stack_len(),
+            stack_base: self.vm.stack.len(),
+            handlers: Vec::new(),
+            iterators: Vec::new(),
+            completion: JsValue::undefined(),
+        };
+        self.vm.frame = Some(Box::new(frame));
+
+        // Only the code of generators and async functions can be suspended, which is run in a
+        // suspended frame.
+        let result = self.run().and_then(|exit| match exit {
+            FrameExit::Return(value) => Ok(value),
+            FrameExit::Suspend(_) => Err(self.construct_syntax_error(
+                "`await` and `yield` are only valid in async functions and generators",
+            )),
+        });
+
+        let frame = self
+            .vm
+            .frame
+            .take()
+            .expect("the frame of the code being run");
+        self.vm.stack.truncate(frame.stack_base);
+        self.restore_environments(frame.environment_depth);
+        self.vm.frame = frame.prev;
+
+        result
+    }
+
+    /// Pops the environments pushed above the first `depth` ones.
+    fn restore_environments(&mut self, depth: usize) {
+        while self.environment_stack_len() > depth {
+            self.pop_environment();
+        }
+    }
+
+    /// Restores the state of the frame saved by a handler, before jumping to its block.
+    fn enter_handler(&mut self, handler: TryHandler) {
+        let frame = self.vm.frame_mut();
+        let stack_len = frame.stack_base + handler.stack_len;
+        let environment_depth = frame.environment_depth + handler.environment_depth;
+        frame.iterators.truncate(handler.iterator_count);
+        frame.pc = handler.address as usize;
+
+        self.vm.stack.truncate(stack_len);
+        self.restore_environments(environment_depth);
+    }
+
+    /// Returns `value` from the current frame, after running the `finally` blocks around the
+    /// current instruction.
+    ///
+    /// The innermost `finally` block is entered with the return completion pushed, which is
+    /// resumed by the `FinallyEnd` at its end.
+    fn unwind_return(&mut self, value: JsValue) -> Flow {
+        while let Some(handler) = self.vm.frame_mut().handlers.pop() {
+            if handler.kind == HandlerKind::Finally {
+                self.enter_handler(handler);
+                self.vm.push(value);
+                self.vm.push(CompletionType::Return);
+                return Flow::Continue;
+            }
+        }
+
+        self.vm.push(value);
+        Flow::Return
+    }
+
+    /// Calls `function` with the arguments of a call through the identifier `eval`, which is a
+    /// direct eval if it is %eval%, running in the environment of the caller.
+    fn call_maybe_eval(
+        &mut self,
+        function: &JsValue,
+        this: &JsValue,
+        args: &[JsValue],
+    ) -> JsResult<JsValue> {
+        let direct_eval = function
+            .as_object()
+            .is_some_and(|function| JsObject::equals(&function, self.intrinsics().eval()));
+
+        if direct_eval {
+            let strict_caller = self.is_strict();
+            Eval::perform_eval(args.get_or_undefined(0), strict_caller, true, self)
+        } else {
+            self.call(function, this, args)
+        }
+    }
+
+    /// The innermost iterator of the current frame.
+    fn active_iterator(&mut self) -> &mut ActiveIterator {
+        self.vm
+            .frame_mut()
+            .iterators
+            .last_mut()
+            .expect("iterator of the frame")
+    }
+
+    /// Executes the instruction at the program counter.
+    fn execute_instruction(&mut self) -> JsResult<Flow> {
         let _timer = BoaProfiler::global().start_event("execute_instruction", "vm");
 
         macro_rules! bin_op {
             ($op:ident) => {{
-                let rhs = self.pop();
-                let lhs = self.pop();
-                let value = lhs.$op(&rhs, self.context)?;
-                self.push(value)
+                let rhs = self.vm.pop();
+                let lhs = self.vm.pop();
+                let value = lhs.$op(&rhs, self)?;
+                self.vm.push(value)
             }};
         }
 
-        let opcode = self.code.code[self.pc].try_into().unwrap();
-        self.pc += 1;
+        let opcode = {
+            let frame = self.vm.frame_mut();
+            let opcode = frame.code.code[frame.pc].try_into().unwrap();
+            frame.pc += 1;
+            opcode
+        };
 
         match opcode {
             Opcode::Nop => {}
             Opcode::Pop => {
-                let _ = self.pop();
+                let _ = self.vm.pop();
             }
             Opcode::Dup => {
-                let value = self.pop();
-                self.push(value.clone());
-                self.push(value);
+                let value = self.vm.pop();
+                self.vm.push(value.clone());
+                self.vm.push(value);
+            }
+            Opcode::Dup2 => {
+                let second = self.vm.pop();
+                let first = self.vm.pop();
+                self.vm.push(first.clone());
+                self.vm.push(second.clone());
+                self.vm.push(first);
+                self.vm.push(second);
             }
             Opcode::Swap => {
-                let first = self.pop();
-                let second = self.pop();
-
-                self.push(first);
-                self.push(second);
-            }
-            Opcode::PushUndefined => self.push(JsValue::undefined()),
-            Opcode::PushNull => self.push(JsValue::null()),
-            Opcode::PushTrue => self.push(true),
-            Opcode::PushFalse => self.push(false),
-            Opcode::PushZero => self.push(0),
-            Opcode::PushOne => self.push(1),
+                let first = self.vm.pop();
+                let second = self.vm.pop();
+
+                self.vm.push(first);
+                self.vm.push(second);
+            }
+            Opcode::PushUndefined => self.vm.push(JsValue::undefined()),
+            Opcode::PushNull => self.vm.push(JsValue::null()),
+            Opcode::PushTrue => self.vm.push(true),
+            Opcode::PushFalse => self.vm.push(false),
+            Opcode::PushZero => self.vm.push(0),
+            Opcode::PushOne => self.vm.push(1),
             Opcode::PushInt8 => {
-                let value = self.read::<i8>();
-                self.push(value as i32);
+                let value = self.vm.read::<i8>();
+                self.vm.push(value as i32);
             }
             Opcode::PushInt16 => {
-                let value = self.read::<i16>();
-                self.push(value as i32);
+                let value = self.vm.read::<i16>();
+                self.vm.push(value as i32);
             }
             Opcode::PushInt32 => {
-                let value = self.read::<i32>();
-                self.push(value);
+                let value = self.vm.read::<i32>();
+                self.vm.push(value);
             }
             Opcode::PushRational => {
-                let value = self.read::<f64>();
-                self.push(value);
+                let value = self.vm.read::<f64>();
+                self.vm.push(value);
             }
-            Opcode::PushNaN => self.push(JsValue::nan()),
-            Opcode::PushPositiveInfinity => self.push(JsValue::positive_inifnity()),
-            Opcode::PushNegativeInfinity => self.push(JsValue::negative_inifnity()),
+            Opcode::PushNaN => self.vm.push(JsValue::nan()),
+            Opcode::PushPositiveInfinity => self.vm.push(JsValue::positive_inifnity()),
+            Opcode::PushNegativeInfinity => self.vm.push(JsValue::negative_inifnity()),
             Opcode::PushLiteral => {
-                let index = self.read::<u32>() as usize;
-                let value = self.code.literals[index].clone();
-                self.push(value)
+                let index = self.vm.read::<u32>() as usize;
+                let value = self.vm.frame().code.literals[index].clone();
+                self.vm.push(value)
+            }
+            Opcode::PushEmptyObject => {
+                let object = JsValue::new_object(self);
+                self.vm.push(object)
             }
-            Opcode::PushEmptyObject => self.push(JsValue::new_object(self.context)),
             Opcode::PushNewArray => {
-                let count = self.read::<u32>();
-                let mut elements = Vec::with_capacity(count as usize);
-                for _ in 0..count {
-                    elements.push(self.pop());
+                let count = self.vm.read::<u32>();
+                let elements = self.vm.pop_n(count as usize);
+                let array = Array::new_array(self);
+                Array::add_to_array_object(&array, &elements, self)?;
+                self.vm.push(array);
+            }
+            Opcode::DefineOwnPropertyByName => {
+                let index = self.vm.read::<u32>();
+                let value = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let object = self.vm.stack.last().expect("object literal on the stack");
+                object.set_property(
+                    name,
+                    PropertyDescriptor::builder()
+                        .value(value)
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true),
+                );
+            }
+            Opcode::RotateLeft => {
+                let n = self.vm.read::<u8>() as usize;
+                let len = self.vm.stack.len();
+                self.vm.stack[len - n..].rotate_left(1);
+            }
+            Opcode::RotateRight => {
+                let n = self.vm.read::<u8>() as usize;
+                let len = self.vm.stack.len();
+                self.vm.stack[len - n..].rotate_right(1);
+            }
+            Opcode::PushValueToArray => {
+                let value = self.vm.pop();
+                let array = self.vm.stack.last().expect("array on the stack").clone();
+                Array::add_to_array_object(&array, &[value], self)?;
+            }
+            Opcode::PushIteratorToArray => {
+                let iterable = self.vm.pop();
+                let values = iterable_to_list(self, iterable)?;
+                let array = self.vm.stack.last().expect("array on the stack").clone();
+                Array::add_to_array_object(&array, &values, self)?;
+            }
+            Opcode::DefineMethodByName | Opcode::SetGetterByName | Opcode::SetSetterByName => {
+                let index = self.vm.read::<u32>();
+                let function = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+                let object = self.vm.stack.last().expect("object literal on the stack");
+
+                // MakeMethod ( F, homeObject )
+                let function = function.as_object().expect("method");
+                if let Some(method) = function.borrow_mut().as_function_mut() {
+                    method.set_home_object(object.as_object().expect("object literal"));
+                }
+
+                let descriptor = match opcode {
+                    Opcode::DefineMethodByName => PropertyDescriptor::builder()
+                        .value(function)
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true),
+                    // An accessor keeps the other function of an accessor defined before.
+                    Opcode::SetGetterByName => {
+                        let set = object
+                            .get_property(name.clone())
+                            .as_ref()
+                            .and_then(|property| property.set())
+                            .cloned();
+                        PropertyDescriptor::builder()
+                            .get(function)
+                            .maybe_set(set)
+                            .enumerable(true)
+                            .configurable(true)
+                    }
+                    _ => {
+                        let get = object
+                            .get_property(name.clone())
+                            .as_ref()
+                            .and_then(|property| property.get())
+                            .cloned();
+                        PropertyDescriptor::builder()
+                            .maybe_get(get)
+                            .set(function)
+                            .enumerable(true)
+                            .configurable(true)
+                    }
+                };
+                object.set_property(name, descriptor);
+            }
+            Opcode::CopyDataProperties => {
+                let excluded_count = self.vm.read::<u32>();
+                let excluded_keys = self.vm.pop_n(excluded_count as usize);
+                let source = self.vm.pop();
+
+                let excluded_keys = excluded_keys
+                    .iter()
+                    .map(|key| key.to_property_key(self))
+                    .collect::<JsResult<Vec<_>>>()?;
+                let mut object = self
+                    .vm
+                    .stack
+                    .last()
+                    .and_then(JsValue::as_object)
+                    .expect("object on the stack");
+                object.copy_data_properties(&source, excluded_keys, self)?;
+            }
+            Opcode::SetFunctionName => {
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
+                let function = self.vm.stack.last().and_then(JsValue::as_object);
+                if let Some(function) = function {
+                    function.define_property_or_throw(
+                        "name",
+                        PropertyDescriptor::builder()
+                            .value(name)
+                            .writable(false)
+                            .enumerable(false)
+                            .configurable(true),
+                        self,
+                    )?;
+                }
+            }
+            Opcode::DefPrivateName => {
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let symbol = JsSymbol::new(Some(name.clone()));
+                BindingKind::Const.bind(&name, symbol.into(), self)?;
+            }
+            Opcode::PushClassPrototype | Opcode::PushDerivedClassPrototype => {
+                let class = self.vm.pop();
+                let class = class.as_object().expect("class on the stack");
+
+                let prototype_parent = if opcode == Opcode::PushDerivedClassPrototype {
+                    let superclass = self.vm.pop();
+                    let (prototype_parent, constructor_parent) = class_heritage(&superclass, self)?;
+                    class.set_prototype_instance(constructor_parent);
+                    prototype_parent
+                } else {
+                    self.standard_objects().object_object().prototype().into()
+                };
+                let prototype_object = JsObject::new(Object::create(prototype_parent));
+                let prototype = JsValue::new(prototype_object.clone());
+                class.insert_property(
+                    "prototype",
+                    PropertyDescriptor::builder()
+                        .value(prototype.clone())
+                        .writable(false)
+                        .enumerable(false)
+                        .configurable(false),
+                );
+                prototype_object.insert_property(
+                    "constructor",
+                    PropertyDescriptor::builder()
+                        .value(class.clone())
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true),
+                );
+                if let Some(constructor) = class.borrow_mut().as_function_mut() {
+                    constructor.set_home_object(prototype_object);
+                }
+                self.vm.push(class);
+                self.vm.push(prototype);
+            }
+            Opcode::DefineClassMethod | Opcode::DefineClassGetter | Opcode::DefineClassSetter => {
+                let function = self.vm.pop();
+                let key = self.vm.pop();
+                let object = self
+                    .vm
+                    .stack
+                    .last()
+                    .and_then(JsValue::as_object)
+                    .expect("class or prototype on the stack");
+
+                let key = key.to_property_key(self)?;
+                let function = function.as_object().expect("method");
+                make_method(&function, object.clone());
+
+                let descriptor = match opcode {
+                    Opcode::DefineClassMethod => {
+                        set_function_name(&function, &key, None, self)?;
+                        PropertyDescriptor::builder()
+                            .value(function)
+                            .writable(true)
+                            .enumerable(false)
+                            .configurable(true)
+                    }
+                    Opcode::DefineClassGetter => {
+                        set_function_name(&function, &key, Some("get"), self)?;
+                        PropertyDescriptor::builder()
+                            .get(function)
+                            .enumerable(false)
+                            .configurable(true)
+                    }
+                    _ => {
+                        set_function_name(&function, &key, Some("set"), self)?;
+                        PropertyDescriptor::builder()
+                            .set(function)
+                            .enumerable(false)
+                            .configurable(true)
+                    }
+                };
+                object.define_property_or_throw(key, descriptor, self)?;
+            }
+            Opcode::PushClassPrivateMethod
+            | Opcode::PushClassPrivateGetter
+            | Opcode::PushClassPrivateSetter => {
+                let index = self.vm.read::<u32>();
+                let function = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let symbol = private_name(&name, self)?;
+                let function = function.as_object().expect("private method");
+                let prototype = self.vm.stack[self.vm.stack.len() - 1]
+                    .as_object()
+                    .expect("class prototype on the stack");
+                let class = self.vm.stack[self.vm.stack.len() - 2]
+                    .as_object()
+                    .expect("class on the stack");
+
+                let element = private_method_element(&function, &symbol, prototype, opcode, self)?;
+                if let Some(constructor) = class.borrow_mut().as_function_mut() {
+                    constructor.push_private_method(symbol, element);
+                }
+                drop(class);
+            }
+            Opcode::PushClassField
+            | Opcode::PushClassStaticField
+            | Opcode::PushClassPrivateField
+            | Opcode::PushClassStaticPrivateField => {
+                let initializer = self.vm.pop();
+                let name = if matches!(
+                    opcode,
+                    Opcode::PushClassPrivateField | Opcode::PushClassStaticPrivateField
+                ) {
+                    let index = self.vm.read::<u32>();
+                    let name = self.vm.frame().code.names[index as usize].clone();
+                    ClassFieldName::Private(private_name(&name, self)?)
+                } else {
+                    let key = self.vm.pop();
+                    ClassFieldName::Public(key.to_property_key(self)?)
+                };
+                let prototype = self.vm.stack[self.vm.stack.len() - 1]
+                    .as_object()
+                    .expect("class prototype on the stack");
+                let class = self.vm.stack[self.vm.stack.len() - 2]
+                    .as_object()
+                    .expect("class on the stack");
+
+                // The home object of the initializer of a static field is the class.
+                let is_static = matches!(
+                    opcode,
+                    Opcode::PushClassStaticField | Opcode::PushClassStaticPrivateField
+                );
+                let initializer = initializer.as_object();
+                if let Some(ref initializer) = initializer {
+                    make_method(
+                        initializer,
+                        if is_static { class.clone() } else { prototype },
+                    );
+                }
+                let field = ClassFieldDefinition::new(name, initializer);
+                if let Some(constructor) = class.borrow_mut().as_function_mut() {
+                    if is_static {
+                        constructor.push_static_element(ClassStaticElement::Field(field));
+                    } else {
+                        constructor.push_field(field);
+                    }
+                }
+                drop(class);
+            }
+            Opcode::PushClassStaticBlock => {
+                let function = self.vm.pop();
+                let class = self.vm.stack[self.vm.stack.len() - 2]
+                    .as_object()
+                    .expect("class on the stack");
+
+                let function = function.as_object().expect("static block");
+                make_method(&function, class.clone());
+                if let Some(constructor) = class.borrow_mut().as_function_mut() {
+                    constructor.push_static_element(ClassStaticElement::Block(function));
+                }
+                drop(class);
+            }
+            Opcode::DefineClassStaticElements => {
+                let class = self
+                    .vm
+                    .stack
+                    .last()
+                    .and_then(JsValue::as_object)
+                    .expect("class on the stack");
+
+                let elements = match class.borrow_mut().as_function_mut() {
+                    Some(constructor) => constructor.take_static_elements(),
+                    None => Vec::new(),
+                };
+                for element in &elements {
+                    match element {
+                        ClassStaticElement::Field(field) => class.define_field(field, self)?,
+                        ClassStaticElement::Block(block) => {
+                            block.call(&class.clone().into(), &[], self)?;
+                        }
+                    }
+                }
+            }
+            Opcode::DefinePrivateMethod
+            | Opcode::DefinePrivateGetter
+            | Opcode::DefinePrivateSetter => {
+                let index = self.vm.read::<u32>();
+                let function = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let symbol = private_name(&name, self)?;
+                let function = function.as_object().expect("private method");
+                let object = self
+                    .vm
+                    .stack
+                    .last()
+                    .and_then(JsValue::as_object)
+                    .expect("class on the stack");
+
+                let element =
+                    private_method_element(&function, &symbol, object.clone(), opcode, self)?;
+                // A getter and a setter with the same name are combined into a single accessor.
+                let mut borrowed = object.borrow_mut();
+                match borrowed.private_element_mut(&symbol) {
+                    Some(existing @ PrivateElement::Accessor { .. })
+                        if matches!(element, PrivateElement::Accessor { .. }) =>
+                    {
+                        existing.merge_accessor(&element);
+                    }
+                    _ => {
+                        drop(borrowed);
+                        object.private_method_or_accessor_add(&symbol, element, self)?;
+                    }
                 }
-                let array = Array::new_array(self.context);
-                Array::add_to_array_object(&array, &elements, self.context)?;
-                self.push(array);
             }
             Opcode::Add => bin_op!(add),
             Opcode::Sub => bin_op!(sub),
@@ -156,297 +668,820 @@ impl<'a> Vm<'a> {
             Opcode::ShiftRight => bin_op!(shr),
             Opcode::UnsignedShiftRight => bin_op!(ushr),
             Opcode::Eq => {
-                let rhs = self.pop();
-                let lhs = self.pop();
-                let value = lhs.equals(&rhs, self.context)?;
-                self.push(value);
+                let rhs = self.vm.pop();
+                let lhs = self.vm.pop();
+                let value = lhs.equals(&rhs, self)?;
+                self.vm.push(value);
             }
             Opcode::NotEq => {
-                let rhs = self.pop();
-                let lhs = self.pop();
-                let value = !lhs.equals(&rhs, self.context)?;
-                self.push(value);
+                let rhs = self.vm.pop();
+                let lhs = self.vm.pop();
+                let value = !lhs.equals(&rhs, self)?;
+                self.vm.push(value);
             }
             Opcode::StrictEq => {
-                let rhs = self.pop();
-                let lhs = self.pop();
-                self.push(lhs.strict_equals(&rhs));
+                let rhs = self.vm.pop();
+                let lhs = self.vm.pop();
+                self.vm.push(lhs.strict_equals(&rhs));
             }
             Opcode::StrictNotEq => {
-                let rhs = self.pop();
-                let lhs = self.pop();
-                self.push(!lhs.strict_equals(&rhs));
+                let rhs = self.vm.pop();
+                let lhs = self.vm.pop();
+                self.vm.push(!lhs.strict_equals(&rhs));
             }
             Opcode::GreaterThan => bin_op!(gt),
             Opcode::GreaterThanOrEq => bin_op!(ge),
             Opcode::LessThan => bin_op!(lt),
             Opcode::LessThanOrEq => bin_op!(le),
             Opcode::In => {
-                let rhs = self.pop();
-                let lhs = self.pop();
+                let rhs = self.vm.pop();
+                let lhs = self.vm.pop();
 
                 if !rhs.is_object() {
-                    return Err(self.context.construct_type_error(format!(
+                    return Err(self.construct_type_error(format!(
                         "right-hand side of 'in' should be an object, got {}",
                         rhs.type_of()
                     )));
                 }
-                let key = lhs.to_property_key(self.context)?;
-                let has_property = self.context.has_property(&rhs, &key)?;
-                self.push(has_property);
+                let key = lhs.to_property_key(self)?;
+                let has_property = self.has_property(&rhs, &key)?;
+                self.vm.push(has_property);
             }
             Opcode::InstanceOf => {
-                let y = self.pop();
-                let x = self.pop();
-                let value = if let Some(object) = y.as_object() {
-                    let key = WellKnownSymbols::has_instance();
-
-                    match object.get_method(self.context, key)? {
-                        Some(instance_of_handler) => instance_of_handler
-                            .call(&y, &[x], self.context)?
-                            .to_boolean(),
-                        None if object.is_callable() => {
-                            object.ordinary_has_instance(self.context, &x)?
-                        }
-                        None => {
-                            return Err(self.context.construct_type_error(
-                                "right-hand side of 'instanceof' is not callable",
-                            ));
-                        }
-                    }
-                } else {
-                    return Err(self.context.construct_type_error(format!(
-                        "right-hand side of 'instanceof' should be an object, got {}",
-                        y.type_of()
-                    )));
-                };
-
-                self.push(value);
+                let y = self.vm.pop();
+                let x = self.vm.pop();
+                let value = x.instance_of(&y, self)?;
+                self.vm.push(value);
             }
             Opcode::Void => {
-                let _ = self.pop();
-                self.push(JsValue::undefined());
+                let _ = self.vm.pop();
+                self.vm.push(JsValue::undefined());
             }
             Opcode::TypeOf => {
-                let value = self.pop();
-                self.push(value.type_of());
+                let value = self.vm.pop();
+                self.vm.push(value.type_of());
             }
             Opcode::Pos => {
-                let value = self.pop();
-                let value = value.to_number(self.context)?;
-                self.push(value);
+                let value = self.vm.pop();
+                let value = value.to_number(self)?;
+                self.vm.push(value);
             }
             Opcode::Neg => {
-                let value = self.pop().neg(self.context)?;
-                self.push(value);
+                let value = self.vm.pop().neg(self)?;
+                self.vm.push(value);
+            }
+            Opcode::Inc => {
+                let value = self.vm.pop().to_number(self)? + 1.0;
+                self.vm.push(value);
+            }
+            Opcode::Dec => {
+                let value = self.vm.pop().to_number(self)? - 1.0;
+                self.vm.push(value);
             }
             Opcode::LogicalNot => {
-                let value = self.pop();
-                self.push(!value.to_boolean());
+                let value = self.vm.pop();
+                self.vm.push(!value.to_boolean());
             }
             Opcode::BitNot => {
-                let target = self.pop();
-                let num = target.to_number(self.context)?;
-                let value = if num.is_nan() {
-                    -1
-                } else {
-                    // TODO: this is not spec compliant.
-                    !(num as i32)
+                let value = match self.vm.pop().to_numeric(self)? {
+                    Numeric::Number(x) => JsValue::new(Number::not(x)),
+                    Numeric::BigInt(x) => JsValue::new(JsBigInt::not(&x)),
                 };
-                self.push(value);
+                self.vm.push(value);
             }
             Opcode::DefVar => {
-                let index = self.read::<u32>();
-                let name = &self.code.names[index as usize];
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
 
-                self.context.create_mutable_binding(
-                    name.to_string(),
-                    false,
-                    VariableScope::Function,
-                )?;
+                // A `var` declaration without initializer doesn't reset a hoisted binding.
+                if !self.has_binding(&name) {
+                    BindingKind::Var.bind(&name, JsValue::undefined(), self)?;
+                }
+            }
+            Opcode::DefInitVar => {
+                let index = self.vm.read::<u32>();
+                let value = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                BindingKind::Var.bind(&name, value, self)?;
             }
             Opcode::DefLet => {
-                let index = self.read::<u32>();
-                let name = &self.code.names[index as usize];
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                self.create_mutable_binding(name.to_string(), false, VariableScope::Block)?;
+            }
+            Opcode::DefInitLet => {
+                let index = self.vm.read::<u32>();
+                let value = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
 
-                self.context.create_mutable_binding(
-                    name.to_string(),
-                    false,
-                    VariableScope::Block,
-                )?;
+                BindingKind::Let.bind(&name, value, self)?;
             }
             Opcode::DefConst => {
-                let index = self.read::<u32>();
-                let name = &self.code.names[index as usize];
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
 
-                self.context.create_immutable_binding(
-                    name.to_string(),
-                    false,
-                    VariableScope::Block,
-                )?;
+                self.create_immutable_binding(name.to_string(), true, VariableScope::Block)?;
             }
-            Opcode::InitLexical => {
-                let index = self.read::<u32>();
-                let value = self.pop();
-                let name = &self.code.names[index as usize];
+            Opcode::DefInitConst => {
+                let index = self.vm.read::<u32>();
+                let value = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
 
-                self.context.initialize_binding(name, value)?;
+                BindingKind::Const.bind(&name, value, self)?;
             }
             Opcode::GetName => {
-                let index = self.read::<u32>();
-                let name = &self.code.names[index as usize];
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
 
-                let value = self.context.get_binding_value(name)?;
-                self.push(value);
+                let value = self.get_binding_value(&name)?;
+                self.vm.push(value);
             }
             Opcode::SetName => {
-                let index = self.read::<u32>();
-                let value = self.pop();
-                let name = &self.code.names[index as usize];
+                let index = self.vm.read::<u32>();
+                let value = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
 
-                if self.context.has_binding(name) {
-                    // Binding already exists
-                    self.context.set_mutable_binding(name, value, true)?;
-                } else {
-                    self.context.create_mutable_binding(
-                        name.to_string(),
-                        true,
-                        VariableScope::Function,
-                    )?;
-                    self.context.initialize_binding(name, value)?;
-                }
+                BindingKind::Assignment.bind(&name, value.clone(), self)?;
+                self.vm.push(value);
+            }
+            Opcode::ImplicitThis => {
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let this = self.get_implicit_this_value(&name);
+                self.vm.push(this);
             }
             Opcode::Jump => {
-                let address = self.read::<u32>();
-                self.pc = address as usize;
+                let address = self.vm.read::<u32>() as usize;
+                // Loops jump back to their start, which is where they can be interrupted.
+                if address < self.vm.frame().pc {
+                    self.check_interrupt()?;
+                }
+                self.vm.frame_mut().pc = address;
             }
             Opcode::JumpIfFalse => {
-                let address = self.read::<u32>();
-                if !self.pop().to_boolean() {
-                    self.pc = address as usize;
+                let address = self.vm.read::<u32>();
+                if !self.vm.pop().to_boolean() {
+                    self.vm.frame_mut().pc = address as usize;
                 }
             }
             Opcode::JumpIfTrue => {
-                let address = self.read::<u32>();
-                if self.pop().to_boolean() {
-                    self.pc = address as usize;
+                let address = self.vm.read::<u32>() as usize;
+                if self.vm.pop().to_boolean() {
+                    if address < self.vm.frame().pc {
+                        self.check_interrupt()?;
+                    }
+                    self.vm.frame_mut().pc = address;
+                }
+            }
+            Opcode::JumpIfNotUndefined => {
+                let address = self.vm.read::<u32>();
+                if !self
+                    .vm
+                    .stack
+                    .last()
+                    .expect("value on the stack")
+                    .is_undefined()
+                {
+                    self.vm.frame_mut().pc = address as usize;
+                }
+            }
+            Opcode::JumpIfNullOrUndefined => {
+                let address = self.vm.read::<u32>();
+                if self
+                    .vm
+                    .stack
+                    .last()
+                    .expect("value on the stack")
+                    .is_null_or_undefined()
+                {
+                    self.vm.frame_mut().pc = address as usize;
                 }
             }
             Opcode::LogicalAnd => {
-                let exit = self.read::<u32>();
-                let lhs = self.pop();
+                let exit = self.vm.read::<u32>();
+                let lhs = self.vm.pop();
                 if !lhs.to_boolean() {
-                    self.pc = exit as usize;
-                    self.push(false);
+                    self.vm.frame_mut().pc = exit as usize;
+                    self.vm.push(lhs);
                 }
             }
             Opcode::LogicalOr => {
-                let exit = self.read::<u32>();
-                let lhs = self.pop();
+                let exit = self.vm.read::<u32>();
+                let lhs = self.vm.pop();
                 if lhs.to_boolean() {
-                    self.pc = exit as usize;
-                    self.push(true);
+                    self.vm.frame_mut().pc = exit as usize;
+                    self.vm.push(lhs);
                 }
             }
             Opcode::Coalesce => {
-                let exit = self.read::<u32>();
-                let lhs = self.pop();
+                let exit = self.vm.read::<u32>();
+                let lhs = self.vm.pop();
                 if !lhs.is_null_or_undefined() {
-                    self.pc = exit as usize;
-                    self.push(lhs);
+                    self.vm.frame_mut().pc = exit as usize;
+                    self.vm.push(lhs);
                 }
             }
             Opcode::ToBoolean => {
-                let value = self.pop();
-                self.push(value.to_boolean());
+                let value = self.vm.pop();
+                self.vm.push(value.to_boolean());
             }
             Opcode::GetPropertyByName => {
-                let index = self.read::<u32>();
+                let index = self.vm.read::<u32>();
+                let cache_index = self.vm.read::<u32>() as usize;
+                let value = self.vm.pop();
+
+                if let JsValue::Object(ref object) = value {
+                    let cache = &self.vm.frame().code.property_caches[cache_index];
+                    if let Some(result) = cache.get(&object.borrow()) {
+                        self.vm.push(result);
+                        return Ok(Flow::Continue);
+                    }
+                }
 
-                let value = self.pop();
-                let object = if let Some(object) = value.as_object() {
-                    object
+                let name = self.vm.frame().code.names[index as usize].clone();
+                let result = value.get_v(name.clone(), self)?;
+                if let JsValue::Object(ref object) = value {
+                    let cache = &self.vm.frame().code.property_caches[cache_index];
+                    cache.update_get(&object.borrow(), &name);
+                }
+                self.vm.push(result)
+            }
+            Opcode::GetPropertyByValue => {
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+
+                let key = key.to_property_key(self)?;
+                let result = object.get_v(key, self)?;
+                self.vm.push(result)
+            }
+            Opcode::SetPropertyByName => {
+                let index = self.vm.read::<u32>();
+                let cache_index = self.vm.read::<u32>() as usize;
+                let value = self.vm.pop();
+                let object = self.vm.pop();
+
+                if let JsValue::Object(ref object) = object {
+                    let cache = &self.vm.frame().code.property_caches[cache_index];
+                    if cache.set(&mut object.borrow_mut(), value.clone()).is_ok() {
+                        self.vm.push(value);
+                        return Ok(Flow::Continue);
+                    }
+                }
+
+                let name = self.vm.frame().code.names[index as usize].clone();
+                object.set_field(name.clone(), value.clone(), false, self)?;
+                if let JsValue::Object(ref object) = object {
+                    let cache = &self.vm.frame().code.property_caches[cache_index];
+                    cache.update_set(&object.borrow(), &name);
+                }
+                self.vm.push(value);
+            }
+            Opcode::SetPropertyByValue => {
+                let value = self.vm.pop();
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+
+                let key = key.to_property_key(self)?;
+                object.set_field(key, value.clone(), false, self)?;
+                self.vm.push(value);
+            }
+            Opcode::GetSuperProperty => {
+                let key = self.vm.pop();
+                let this = self.vm.pop();
+
+                let key = key.to_property_key(self)?;
+                let base = super_base(self)?;
+                let result = base.__get__(&key, this, self)?;
+                self.vm.push(result);
+            }
+            Opcode::SetSuperProperty => {
+                let value = self.vm.pop();
+                let key = self.vm.pop();
+                let this = self.vm.pop();
+
+                let key = key.to_property_key(self)?;
+                let base = super_base(self)?;
+                base.__set__(key, value.clone(), this, self)?;
+                self.vm.push(value);
+            }
+            Opcode::DeleteSuperProperty => {
+                let key = self.vm.pop();
+                let _ = self.vm.pop();
+
+                key.to_property_key(self)?;
+                super_base(self)?;
+                return Err(self.construct_reference_error("Cannot delete a super property"));
+            }
+            Opcode::GetPrivateField => {
+                let index = self.vm.read::<u32>();
+                let object = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let symbol = private_name(&name, self)?;
+                let object = object.to_object(self)?;
+                let value = object.private_get(&symbol, self)?;
+                self.vm.push(value);
+            }
+            Opcode::SetPrivateField => {
+                let index = self.vm.read::<u32>();
+                let value = self.vm.pop();
+                let object = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let symbol = private_name(&name, self)?;
+                let object = object.to_object(self)?;
+                object.private_set(&symbol, value.clone(), self)?;
+                self.vm.push(value);
+            }
+            Opcode::InPrivate => {
+                let index = self.vm.read::<u32>();
+                let object = self.vm.pop();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let symbol = private_name(&name, self)?;
+                let object = match object.as_object() {
+                    Some(object) => object,
+                    None => {
+                        return Err(self.construct_type_error(format!(
+                            "right-hand side of 'in' should be an object, got {}",
+                            object.type_of()
+                        )))
+                    }
+                };
+                let result = object.has_private_element(&symbol);
+                self.vm.push(result);
+            }
+            Opcode::DeletePropertyByValue => {
+                let strict = self.vm.read::<u8>() != 0;
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+
+                let object = object.to_object(self)?;
+                let key = key.to_property_key(self)?;
+                let deleted = object.__delete__(&key, self)?;
+                if !deleted && strict {
+                    return Err(self.construct_coded_error(
+                        JsErrorCode::DELETE_OBJECT_PROPERTY,
+                        format!("Cannot delete property '{}' of object", key),
+                    ));
+                }
+                self.vm.push(deleted);
+            }
+            Opcode::DeleteName => {
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.names[index as usize].clone();
+
+                let deleted = self.delete_binding(&name)?;
+                self.vm.push(deleted);
+            }
+            Opcode::Throw => {
+                let value = self.vm.pop();
+                return Err(value);
+            }
+            Opcode::This => {
+                let this = self.get_this_binding()?;
+                self.vm.push(this);
+            }
+            Opcode::NewTarget => match self.get_new_target() {
+                Some(new_target) => self.vm.push(new_target),
+                None => {
+                    return Err(
+                        self.construct_syntax_error("new.target expression is not allowed here")
+                    )
+                }
+            },
+            Opcode::Case => {
+                let address = self.vm.read::<u32>();
+                let cond = self.vm.pop();
+                let value = self.vm.pop();
+
+                if !value.strict_equals(&cond) {
+                    self.vm.push(value);
                 } else {
-                    value.to_object(self.context)?
+                    self.vm.frame_mut().pc = address as usize;
+                }
+            }
+            Opcode::Default => {
+                let exit = self.vm.read::<u32>();
+                let _ = self.vm.pop();
+                self.vm.frame_mut().pc = exit as usize;
+            }
+            Opcode::ConcatToString => {
+                let count = self.vm.read::<u32>();
+                let values = self.vm.pop_n(count as usize);
+
+                let mut string = String::new();
+                for value in values {
+                    string.push_str(value.to_string(self)?.as_str());
+                }
+                self.vm.push(string);
+            }
+            Opcode::GetTemplateObject => {
+                let index = self.vm.read::<u32>();
+                let code = self.vm.frame().code.clone();
+
+                let template = match &code.nodes[index as usize] {
+                    Node::TaggedTemplate(template) => template,
+                    _ => unreachable!("the node of a template object is a tagged template"),
                 };
+                let template = get_template_object(template, self)?;
+                self.vm.push(template);
+            }
+            Opcode::ImportCall => {
+                let specifier = self.vm.pop();
+                let promise = import_dynamically(specifier, self)?;
+                self.vm.push(promise);
+            }
+            Opcode::GetFunction => {
+                let index = self.vm.read::<u32>();
+                let code = self.vm.frame().code.clone();
 
-                let name = self.code.names[index as usize].clone();
-                let result = object.get(name, self.context)?;
+                let function = self.create_function_from_code(&code.functions[index as usize])?;
+                self.vm.push(function);
+            }
+            Opcode::Call => {
+                let argument_count = self.vm.read::<u32>();
+                let args = self.vm.pop_n(argument_count as usize);
+                let function = self.vm.pop();
+                let this = self.vm.pop();
 
-                self.push(result)
+                let result = self.call(&function, &this, &args)?;
+                self.vm.push(result);
             }
-            Opcode::GetPropertyByValue => {
-                let value = self.pop();
-                let key = self.pop();
-                let object = if let Some(object) = value.as_object() {
-                    object
+            Opcode::CallSpread | Opcode::CallEvalSpread => {
+                let args = self.vm.pop();
+                let function = self.vm.pop();
+                let this = self.vm.pop();
+
+                let args = spread_arguments(&args, self)?;
+                let result = if opcode == Opcode::CallEvalSpread {
+                    self.call_maybe_eval(&function, &this, &args)?
+                } else {
+                    self.call(&function, &this, &args)?
+                };
+                self.vm.push(result);
+            }
+            Opcode::SuperConstructor => {
+                let constructor = super_constructor(self)?;
+                self.vm.push(constructor);
+            }
+            Opcode::SuperCall | Opcode::SuperCallSpread => {
+                let args = if opcode == Opcode::SuperCall {
+                    let argument_count = self.vm.read::<u32>();
+                    self.vm.pop_n(argument_count as usize)
                 } else {
-                    value.to_object(self.context)?
+                    let args = self.vm.pop();
+                    spread_arguments(&args, self)?
                 };
+                let constructor = self.vm.pop();
 
-                let key = key.to_property_key(self.context)?;
-                let result = object.get(key, self.context)?;
+                let result = super_call(&constructor, &args, self)?;
+                self.vm.push(result);
+            }
+            Opcode::CallEval => {
+                let argument_count = self.vm.read::<u32>();
+                let args = self.vm.pop_n(argument_count as usize);
+                let function = self.vm.pop();
+                let this = self.vm.pop();
 
-                self.push(result)
+                let result = self.call_maybe_eval(&function, &this, &args)?;
+                self.vm.push(result);
             }
-            Opcode::SetPropertyByName => {
-                let index = self.read::<u32>();
+            Opcode::New | Opcode::NewSpread => {
+                let args = if opcode == Opcode::New {
+                    let argument_count = self.vm.read::<u32>();
+                    self.vm.pop_n(argument_count as usize)
+                } else {
+                    let args = self.vm.pop();
+                    spread_arguments(&args, self)?
+                };
+                let index = self.vm.read::<u32>();
+                let constructor = self.vm.pop();
 
-                let object = self.pop();
-                let value = self.pop();
-                let object = if let Some(object) = object.as_object() {
-                    object
+                if let JsValue::Object(ref constructor) = constructor {
+                    let result = constructor.construct(&args, &constructor.clone().into(), self)?;
+                    self.vm.push(result);
+                } else {
+                    let name = self.vm.frame().code.names[index as usize].clone();
+                    return Err(self.construct_coded_error(
+                        JsErrorCode::NAMED_NOT_A_CONSTRUCTOR,
+                        format!("{} is not a constructor", name),
+                    ));
+                }
+            }
+            Opcode::Return => {
+                let value = self.vm.pop();
+                return Ok(self.unwind_return(value));
+            }
+            Opcode::TryStart | Opcode::FinallyStart => {
+                let address = self.vm.read::<u32>();
+                let kind = if opcode == Opcode::TryStart {
+                    HandlerKind::Catch
                 } else {
-                    object.to_object(self.context)?
+                    HandlerKind::Finally
                 };
+                let environment_depth = self.environment_stack_len();
+                let stack_len = self.vm.stack.len();
+                let frame = self.vm.frame_mut();
+                let handler = TryHandler {
+                    kind,
+                    address,
+                    environment_depth: environment_depth - frame.environment_depth,
+                    stack_len: stack_len - frame.stack_base,
+                    iterator_count: frame.iterators.len(),
+                };
+                frame.handlers.push(handler);
+            }
+            Opcode::TryEnd => {
+                self.vm.frame_mut().handlers.pop();
+            }
+            Opcode::FinallyEnd => {
+                let completion = CompletionType::from_value(&self.vm.pop());
+                let value = self.vm.pop();
+                match completion {
+                    CompletionType::Normal => {}
+                    CompletionType::Throw => return Err(value),
+                    CompletionType::Return => return Ok(self.unwind_return(value)),
+                    CompletionType::Jump => {
+                        let address = value.as_number().expect("address of a jump") as usize;
+                        self.vm.frame_mut().pc = address;
+                    }
+                }
+            }
+            Opcode::PushJumpCompletion => {
+                let address = self.vm.read::<u32>();
+                self.vm.push(address);
+                self.vm.push(CompletionType::Jump);
+            }
+            Opcode::PushDeclarativeEnvironment => {
+                let env = self.get_current_environment();
+                self.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+            Opcode::PushObjectEnvironment => {
+                let object = self.vm.pop().to_object(self)?;
+                let env = self.get_current_environment();
+                self.push_environment(ObjectEnvironmentRecord {
+                    bindings: object.into(),
+                    with_environment: true,
+                    outer_env: Some(env),
+                });
+            }
+            Opcode::PopEnvironment => {
+                self.pop_environment();
+            }
+            Opcode::SetCompletion => {
+                let value = self.vm.pop();
+                self.vm.frame_mut().completion = value;
+            }
+            Opcode::GetCompletion => {
+                let value = self.vm.frame().completion.clone();
+                self.vm.push(value);
+            }
+            Opcode::GetIterator | Opcode::GetAsyncIterator => {
+                let iterable = self.vm.pop();
+                let record = if opcode == Opcode::GetIterator {
+                    get_iterator(self, iterable)?
+                } else {
+                    get_async_iterator(self, iterable)?
+                };
+                self.vm.frame_mut().iterators.push(ActiveIterator {
+                    record,
+                    done: false,
+                });
+            }
+            Opcode::ForInLoopInitIterator => {
+                let exit = self.vm.read::<u32>();
+                let object = self.vm.pop();
 
-                let name = self.code.names[index as usize].clone();
+                if object.is_null_or_undefined() {
+                    self.vm.frame_mut().pc = exit as usize;
+                } else {
+                    let object = object.to_object(self)?;
+                    let iterator = ForInIterator::create_for_in_iterator(self, object.into());
+                    let next_function = iterator.get_field("next", self)?;
+                    self.vm.frame_mut().iterators.push(ActiveIterator {
+                        record: IteratorRecord::new(iterator, next_function),
+                        done: false,
+                    });
+                }
+            }
+            Opcode::IteratorNext => {
+                let iterator = self.active_iterator();
+                // The iterator is done if `next` throws, until its result says otherwise.
+                iterator.done = true;
+                let record = iterator.record.clone();
 
-                object.set(name, value, true, self.context)?;
+                let result = self.call(record.next_function(), record.iterator_object(), &[])?;
+                self.vm.push(result);
             }
-            Opcode::SetPropertyByValue => {
-                let object = self.pop();
-                let key = self.pop();
-                let value = self.pop();
-                let object = if let Some(object) = object.as_object() {
-                    object
+            Opcode::IteratorResult => {
+                let exit = self.vm.read::<u32>();
+                let result = self.vm.pop();
+
+                let result = IteratorResult::from_result_object(result, self)?;
+                if result.is_done() {
+                    self.vm.frame_mut().pc = exit as usize;
                 } else {
-                    object.to_object(self.context)?
+                    self.active_iterator().done = false;
+                    self.vm.push(result.value());
+                }
+            }
+            Opcode::IteratorStepValue => {
+                let iterator = self.active_iterator();
+                let value = if iterator.done {
+                    JsValue::undefined()
+                } else {
+                    iterator.done = true;
+                    let record = iterator.record.clone();
+                    match record.step(self)? {
+                        Some(value) => {
+                            self.active_iterator().done = false;
+                            value
+                        }
+                        None => JsValue::undefined(),
+                    }
                 };
+                self.vm.push(value);
+            }
+            Opcode::IteratorToArray => {
+                let iterator = self.active_iterator();
+                let mut values = Vec::new();
+                if !iterator.done {
+                    iterator.done = true;
+                    let record = iterator.record.clone();
+                    while let Some(value) = record.step(self)? {
+                        values.push(value);
+                    }
+                }
 
-                let key = key.to_property_key(self.context)?;
-                object.set(key, value, true, self.context)?;
+                let array = Array::array_create(0, None, self)?;
+                Array::add_to_array_object(&array.clone().into(), &values, self)?;
+                self.vm.push(array);
             }
-            Opcode::Throw => {
-                let value = self.pop();
-                return Err(value);
+            Opcode::IteratorPop => {
+                self.vm.frame_mut().iterators.pop();
             }
-            Opcode::This => {
-                let this = self.context.get_this_binding()?;
-                self.push(this);
+            Opcode::IteratorCallReturn => {
+                let skip = self.vm.read::<u32>() as usize;
+                let iterator = self
+                    .vm
+                    .frame_mut()
+                    .iterators
+                    .pop()
+                    .expect("iterator of the frame");
+                let completion = CompletionType::from_value(self.vm.stack.last().expect("kind"));
+
+                // The exceptions of `return` are ignored if the iterator is closed by an
+                // exception, which is rethrown instead.
+                let iterator_object = iterator.record.iterator_object();
+                let result = match iterator_object.get_method(self, "return") {
+                    _ if iterator.done => None,
+                    Ok(Some(r#return)) => Some(r#return.call(iterator_object, &[], self)),
+                    Ok(None) => None,
+                    Err(error) => Some(Err(error)),
+                };
+                match result {
+                    Some(Ok(result)) => self.vm.push(result),
+                    Some(Err(error)) if completion != CompletionType::Throw => return Err(error),
+                    _ => self.vm.frame_mut().pc = skip,
+                }
             }
-            Opcode::Case => {
-                let address = self.read::<u32>();
-                let cond = self.pop();
-                let value = self.pop();
+            Opcode::IteratorCloseResult => {
+                let result_completion = CompletionType::from_value(&self.vm.pop());
+                let result = self.vm.pop();
+                let completion = CompletionType::from_value(self.vm.stack.last().expect("kind"));
 
-                if !value.strict_equals(&cond) {
-                    self.push(value);
+                if completion != CompletionType::Throw {
+                    if result_completion == CompletionType::Throw {
+                        return Err(result);
+                    }
+                    if !result.is_object() {
+                        return Err(self.construct_type_error(
+                            "`return` method of iterator didn't return an Object",
+                        ));
+                    }
+                }
+            }
+            Opcode::ValueNotNullOrUndefined => {
+                let value = self.vm.stack.last().expect("value on the stack");
+                if value.is_null() {
+                    return Err(self.construct_coded_error(
+                        JsErrorCode::DESTRUCTURE_NULL,
+                        "Cannot destructure 'null' value",
+                    ));
+                }
+                if value.is_undefined() {
+                    return Err(self.construct_coded_error(
+                        JsErrorCode::DESTRUCTURE_UNDEFINED,
+                        "Cannot destructure 'undefined' value",
+                    ));
+                }
+            }
+            Opcode::Yield => {
+                let value = self.vm.pop();
+                return Ok(Flow::Suspend(Suspension::Yield(value)));
+            }
+            Opcode::Await => {
+                let value = self.vm.pop();
+                return Ok(Flow::Suspend(Suspension::Await(value)));
+            }
+            Opcode::ResumeCompletion => {
+                let completion = CompletionType::from_value(&self.vm.pop());
+                let value = self.vm.pop();
+                match completion {
+                    CompletionType::Throw => return Err(value),
+                    CompletionType::Return => return Ok(self.unwind_return(value)),
+                    _ => self.vm.push(value),
+                }
+            }
+            Opcode::JumpIfNotCompletionType => {
+                let address = self.vm.read::<u32>();
+                let expected = self.vm.read::<u8>();
+                let completion = self.vm.stack.last().expect("completion type");
+                if CompletionType::from_value(completion) as u8 != expected {
+                    self.vm.frame_mut().pc = address as usize;
+                }
+            }
+            Opcode::CreateIteratorResult => {
+                let value = self.vm.pop();
+                let result = create_iter_result_object(self, value, false);
+                self.vm.push(result);
+            }
+            Opcode::GeneratorDelegateNext => {
+                let throw_method_undefined = self.vm.read::<u32>() as usize;
+                let return_method_undefined = self.vm.read::<u32>() as usize;
+                let completion = CompletionType::from_value(&self.vm.pop());
+                let received = self.vm.pop();
+
+                let record = self.active_iterator().record.clone();
+                let iterator = record.iterator_object();
+                match completion {
+                    CompletionType::Normal => {
+                        let result = self.call(record.next_function(), iterator, &[received])?;
+                        self.vm.push(false);
+                        self.vm.push(result);
+                    }
+                    CompletionType::Throw => {
+                        if let Some(throw) = iterator.get_method(self, "throw")? {
+                            let result = throw.call(iterator, &[received], self)?;
+                            self.vm.push(false);
+                            self.vm.push(result);
+                        } else {
+                            self.vm.frame_mut().pc = throw_method_undefined;
+                        }
+                    }
+                    _ => {
+                        if let Some(r#return) = iterator.get_method(self, "return")? {
+                            let result = r#return.call(iterator, &[received], self)?;
+                            self.vm.push(true);
+                            self.vm.push(result);
+                        } else {
+                            self.vm.push(received);
+                            self.vm.frame_mut().pc = return_method_undefined;
+                        }
+                    }
+                }
+            }
+            Opcode::GeneratorDelegateResume => {
+                let return_address = self.vm.read::<u32>() as usize;
+                let exit = self.vm.read::<u32>() as usize;
+                let result = self.vm.pop();
+                let is_return = self.vm.pop().to_boolean();
+
+                if !result.is_object() {
+                    return Err(self.construct_type_error(
+                        "iterator result of the delegated iterator is not an object",
+                    ));
+                }
+                if result.get_field("done", self)?.to_boolean() {
+                    let value = result.get_field("value", self)?;
+                    self.vm.push(value);
+                    self.vm.frame_mut().pc = if is_return { return_address } else { exit };
                 } else {
-                    self.pc = address as usize;
+                    self.vm.push(result);
                 }
             }
-            Opcode::Default => {
-                let exit = self.read::<u32>();
-                let _ = self.pop();
-                self.pc = exit as usize;
+            Opcode::ThrowTypeError | Opcode::ThrowSyntaxError => {
+                let index = self.vm.read::<u32>();
+                let message = self.vm.frame().code.literals[index as usize].clone();
+                let message = message.to_string(self)?.to_string();
+                return Err(if opcode == Opcode::ThrowTypeError {
+                    self.construct_type_error(message)
+                } else {
+                    self.construct_syntax_error(message)
+                });
             }
         }
 
-        Ok(())
+        Ok(Flow::Continue)
     }
 
-    pub fn run(&mut self) -> JsResult<JsValue> {
+    /// Runs the current frame until it returns, is suspended, or throws an exception that isn't
+    /// caught.
+    fn run(&mut self) -> JsResult<FrameExit> {
         let _timer = BoaProfiler::global().start_event("run", "vm");
 
         const COLUMN_WIDTH: usize = 24;
@@ -455,8 +1490,8 @@ impl<'a> Vm<'a> {
         const OPERAND_COLUMN_WIDTH: usize = COLUMN_WIDTH;
         const NUMBER_OF_COLUMNS: usize = 4;
 
-        if self.is_trace {
-            println!("{}\n", self.code);
+        if self.trace {
+            println!("{}\n", self.vm.frame().code);
             println!(
                 "{:-^width$}",
                 " Vm Start ",
@@ -473,23 +1508,23 @@ impl<'a> Vm<'a> {
             );
         }
 
-        self.pc = 0;
-        while self.pc < self.code.code.len() {
-            if self.is_trace {
-                let mut pc = self.pc;
+        loop {
+            let result = if self.trace {
+                let mut pc = self.vm.frame().pc;
 
                 let instant = Instant::now();
-                self.execute_instruction()?;
+                let result = self.execute_instruction();
                 let duration = instant.elapsed();
 
-                let opcode: Opcode = self.code.read::<u8>(pc).try_into().unwrap();
+                let code = self.vm.frame().code.clone();
+                let opcode: Opcode = code.read::<u8>(pc).try_into().unwrap();
 
                 println!(
                     "{:<time_width$} {:<opcode_width$} {:<operand_width$} {}",
                     format!("{}μs", duration.as_micros()),
                     opcode.as_str(),
-                    self.code.instruction_operands(&mut pc),
-                    match self.stack.last() {
+                    code.instruction_operands(&mut pc),
+                    match self.vm.stack.last() {
                         None => "<empty>".to_string(),
                         Some(value) => format!("{}", value.display()),
                     },
@@ -497,15 +1532,37 @@ impl<'a> Vm<'a> {
                     opcode_width = OPCODE_COLUMN_WIDTH,
                     operand_width = OPERAND_COLUMN_WIDTH,
                 );
+
+                result
             } else {
-                self.execute_instruction()?;
+                self.execute_instruction()
+            };
+
+            match result {
+                Ok(Flow::Continue) => {}
+                Ok(Flow::Return) => break,
+                Ok(Flow::Suspend(suspension)) => return Ok(FrameExit::Suspend(suspension)),
+                Err(error) => {
+                    // The exception is handled by the innermost `try` block of the frame, if
+                    // any, otherwise it is propagated to the caller.
+                    let handler = match self.vm.frame_mut().handlers.pop() {
+                        Some(handler) => handler,
+                        None => return Err(error),
+                    };
+                    self.enter_handler(handler);
+                    self.vm.push(error);
+                    if handler.kind == HandlerKind::Finally {
+                        self.vm.push(CompletionType::Throw);
+                    }
+                }
             }
         }
 
-        if self.is_trace {
+        if self.trace {
+            let stack_base = self.vm.frame().stack_base;
             println!("\nStack:");
-            if !self.stack.is_empty() {
-                for (i, value) in self.stack.iter().enumerate() {
+            if self.vm.stack.len() > stack_base {
+                for (i, value) in self.vm.stack[stack_base..].iter().enumerate() {
                     println!(
                         "{:04}{:<width$} {}",
                         i,
@@ -520,10 +1577,147 @@ impl<'a> Vm<'a> {
             println!("\n");
         }
 
-        if self.stack.is_empty() {
-            return Ok(JsValue::undefined());
+        Ok(FrameExit::Return(self.vm.pop()))
+    }
+}
+
+/// Collects the arguments of a call with spread arguments, from the array they were pushed to.
+fn spread_arguments(array: &JsValue, context: &mut Context) -> JsResult<Vec<JsValue>> {
+    let length = array.get_field("length", context)?.to_length(context)?;
+    (0..length)
+        .map(|index| array.get_field(index, context))
+        .collect()
+}
+
+/// Returns the prototypes of the prototype and of the constructor of a class deriving from
+/// `superclass`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+fn class_heritage(superclass: &JsValue, context: &mut Context) -> JsResult<(JsValue, JsValue)> {
+    match superclass {
+        // e. If superclass is null, then
+        //     i. Let protoParent be null.
+        //     ii. Let constructorParent be %Function.prototype%.
+        JsValue::Null => Ok((
+            JsValue::null(),
+            context
+                .standard_objects()
+                .function_object()
+                .prototype()
+                .into(),
+        )),
+        // g. Else,
+        //     i. Let protoParent be ? Get(superclass, "prototype").
+        //     ii. If Type(protoParent) is neither Object nor Null, throw a TypeError exception.
+        //     iii. Let constructorParent be superclass.
+        JsValue::Object(object) if object.is_constructable() => {
+            let prototype_parent = object.get(PROTOTYPE, context)?;
+            if !prototype_parent.is_object() && !prototype_parent.is_null() {
+                return Err(context.construct_type_error(
+                    "Class extends value does not have a valid prototype property",
+                ));
+            }
+            Ok((prototype_parent, superclass.clone()))
         }
+        // f. Else if IsConstructor(superclass) is false, throw a TypeError exception.
+        _ => Err(context.construct_type_error(format!(
+            "Class extends value {} is not a constructor or null",
+            superclass.display()
+        ))),
+    }
+}
 
-        Ok(self.pop())
+/// Makes `function` a method of `home_object`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makemethod
+fn make_method(function: &JsObject, home_object: JsObject) {
+    if let Some(method) = function.borrow_mut().as_function_mut() {
+        method.set_home_object(home_object);
     }
 }
+
+/// Sets the `name` property of the method `function`, defined with the key `key`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
+fn set_function_name(
+    function: &JsObject,
+    key: &PropertyKey,
+    prefix: Option<&str>,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 2. If name is a Symbol, then
+    //     a. Let description be name's [[Description]] value.
+    //     b. If description is undefined, set name to the empty String.
+    //     c. Else, set name to the string-concatenation of "[", description, and "]".
+    let name = match key {
+        PropertyKey::Symbol(symbol) => symbol
+            .description()
+            .map(|description| format!("[{}]", description))
+            .unwrap_or_default(),
+        key => key.to_string(),
+    };
+
+    // 5. If prefix is present, then
+    //     a. Set name to the string-concatenation of prefix, the code unit 0x0020 (SPACE), and
+    //        name.
+    let name = match prefix {
+        Some(prefix) => format!("{} {}", prefix, name),
+        None => name,
+    };
+
+    // 6. Perform ! DefinePropertyOrThrow(F, "name", PropertyDescriptor { [[Value]]: name,
+    //    [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }).
+    function.define_property_or_throw(
+        "name",
+        PropertyDescriptor::builder()
+            .value(name)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+    Ok(())
+}
+
+/// Makes `function` a private method, getter or setter of `home_object`, as defined by the
+/// instruction `opcode`, with the private name `name`.
+fn private_method_element(
+    function: &JsObject,
+    name: &JsSymbol,
+    home_object: JsObject,
+    opcode: Opcode,
+    context: &mut Context,
+) -> JsResult<PrivateElement> {
+    make_method(function, home_object);
+    let key = PropertyKey::from(name.description().unwrap_or_default());
+    let element = match opcode {
+        Opcode::PushClassPrivateMethod | Opcode::DefinePrivateMethod => {
+            set_function_name(function, &key, None, context)?;
+            PrivateElement::Method(function.clone())
+        }
+        Opcode::PushClassPrivateGetter | Opcode::DefinePrivateGetter => {
+            set_function_name(function, &key, Some("get"), context)?;
+            PrivateElement::Accessor {
+                getter: Some(function.clone()),
+                setter: None,
+            }
+        }
+        _ => {
+            set_function_name(function, &key, Some("set"), context)?;
+            PrivateElement::Accessor {
+                getter: None,
+                setter: Some(function.clone()),
+            }
+        }
+    };
+    Ok(element)
+}
@@ -18,6 +18,13 @@ pub enum Opcode {
     /// Stack: value **=>** value, value
     Dup,
 
+    /// Push a copy of the top two values on the stack.
+    ///
+    /// Operands:
+    ///
+    /// Stack: v1, v2 **=>** v1, v2, v1, v2
+    Dup2,
+
     /// Swap the top two values on the stack.
     ///
     /// Operands:
@@ -25,6 +32,20 @@ pub enum Opcode {
     /// Stack: v1, v2 **=>** v2, v1
     Swap,
 
+    /// Move the value `n` places below the top of the stack to the top.
+    ///
+    /// Operands: n: `u8`
+    ///
+    /// Stack: v1, v2, ... vn **=>** v2, ... vn, v1
+    RotateLeft,
+
+    /// Move the top value of the stack `n - 1` places below the top.
+    ///
+    /// Operands: n: `u8`
+    ///
+    /// Stack: v1, ... vn-1, vn **=>** vn, v1, ... vn-1
+    RotateRight,
+
     /// Push integer `0` on the stack.
     ///
     /// Operands:
@@ -137,9 +158,205 @@ pub enum Opcode {
     ///
     /// Operands: n: `u32`
     ///
-    /// Stack: v1, v1, ... vn **=>** [v1, v2, ..., vn]
+    /// Stack: v1, v2, ... vn **=>** [v1, v2, ..., vn]
     PushNewArray,
 
+    /// Defines an own data property by name on the object below the value, keeping the object
+    /// on the stack.
+    ///
+    /// Like `{ name: value }`
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, value **=>** object
+    DefineOwnPropertyByName,
+
+    /// Append a value to an array.
+    ///
+    /// Operands:
+    ///
+    /// Stack: array, value **=>** array
+    PushValueToArray,
+
+    /// Append the values of an iterable to an array.
+    ///
+    /// Operands:
+    ///
+    /// Stack: array, iterable **=>** array
+    PushIteratorToArray,
+
+    /// Define a method of an object literal, whose home object is the object.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, function **=>** object
+    DefineMethodByName,
+
+    /// Define the getter of an accessor property of an object literal, whose home object is
+    /// the object.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, function **=>** object
+    SetGetterByName,
+
+    /// Define the setter of an accessor property of an object literal, whose home object is
+    /// the object.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, function **=>** object
+    SetSetterByName,
+
+    /// Copy the own enumerable properties of a value to an object, except the excluded keys.
+    ///
+    /// Operands: excluded_key_count: `u32`
+    ///
+    /// Stack: object, source, key1, ... keyn **=>** object
+    CopyDataProperties,
+
+    /// Set the `name` property of a function, which is left on the stack.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: function **=>** function
+    SetFunctionName,
+
+    /// Create a new private name, which is bound to the current environment by its source text.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: **=>**
+    DefPrivateName,
+
+    /// Push the prototype of a class, created for its constructor, which is left on the stack.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class **=>** class, prototype
+    PushClassPrototype,
+
+    /// Push the prototype of a derived class, created for its constructor, which is left on the
+    /// stack, after making the constructor and the prototype inherit from the parent class.
+    ///
+    /// Operands:
+    ///
+    /// Stack: superclass, class **=>** class, prototype
+    PushDerivedClassPrototype,
+
+    /// Define a method of a class on an object, which is the home object of the method.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key, function **=>** object
+    DefineClassMethod,
+
+    /// Define the getter of an accessor property of a class on an object, which is the home
+    /// object of the getter.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key, function **=>** object
+    DefineClassGetter,
+
+    /// Define the setter of an accessor property of a class on an object, which is the home
+    /// object of the setter.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key, function **=>** object
+    DefineClassSetter,
+
+    /// Add a private method to the instances of a class.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: class, prototype, function **=>** class, prototype
+    PushClassPrivateMethod,
+
+    /// Add the getter of a private accessor to the instances of a class.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: class, prototype, function **=>** class, prototype
+    PushClassPrivateGetter,
+
+    /// Add the setter of a private accessor to the instances of a class.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: class, prototype, function **=>** class, prototype
+    PushClassPrivateSetter,
+
+    /// Add a private field to the instances of a class, initialized by calling the initializer
+    /// if it is not `undefined`.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: class, prototype, initializer **=>** class, prototype
+    PushClassPrivateField,
+
+    /// Add a private method to an object, which is the home object of the method.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, function **=>** object
+    DefinePrivateMethod,
+
+    /// Add the getter of a private accessor to an object, which is the home object of the
+    /// getter.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, function **=>** object
+    DefinePrivateGetter,
+
+    /// Add the setter of a private accessor to an object, which is the home object of the
+    /// setter.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, function **=>** object
+    DefinePrivateSetter,
+
+    /// Add a field to the instances of a class, initialized by calling the initializer if it is
+    /// not `undefined`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, prototype, key, initializer **=>** class, prototype
+    PushClassField,
+
+    /// Add a static field to a class, which is defined once the class is bound to its name.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, prototype, key, initializer **=>** class, prototype
+    PushClassStaticField,
+
+    /// Add a private static field to a class, which is defined once the class is bound to its
+    /// name.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: class, prototype, initializer **=>** class, prototype
+    PushClassStaticPrivateField,
+
+    /// Add a static block to a class, which is called once the class is bound to its name.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, prototype, function **=>** class, prototype
+    PushClassStaticBlock,
+
+    /// Define the static fields and call the static blocks of a class, in the order they were
+    /// added.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class **=>** class
+    DefineClassStaticElements,
+
     /// Binary `+` operator.
     ///
     /// Operands:
@@ -303,30 +520,33 @@ pub enum Opcode {
 
     /// Binary logical `&&` operator.
     ///
-    /// This is a short-circit operator, if the `lhs` value is `false`, then it jumps to `exit` address.
+    /// This is a short-circit operator, if the `lhs` value is `false`, then it is pushed back
+    /// and it jumps to `exit` address. Otherwise the `rhs` that follows is evaluated.
     ///
     /// Operands: exit: `u32`
     ///
-    /// Stack: lhs, rhs **=>** (lhs && rhs)
+    /// Stack: lhs **=>** lhs (if short-circuited)
     LogicalAnd,
 
     /// Binary logical `||` operator.
     ///
-    /// This is a short-circit operator, if the `lhs` value is `true`, then it jumps to `exit` address.
+    /// This is a short-circit operator, if the `lhs` value is `true`, then it is pushed back
+    /// and it jumps to `exit` address. Otherwise the `rhs` that follows is evaluated.
     ///
     /// Operands: exit: `u32`
     ///
-    /// Stack: lhs, rhs **=>** (lhs || rhs)
+    /// Stack: lhs **=>** lhs (if short-circuited)
     LogicalOr,
 
     /// Binary `??` operator.
     ///
     /// This is a short-circit operator, if the `lhs` value is **not** `null` or `undefined`,
-    /// then it jumps to `exit` address.
+    /// then it is pushed back and it jumps to `exit` address. Otherwise the `rhs` that follows
+    /// is evaluated.
     ///
     /// Operands: exit: `u32`
     ///
-    /// Stack: lhs, rhs **=>** (lhs && rhs)
+    /// Stack: lhs **=>** lhs (if short-circuited)
     Coalesce,
 
     /// Unary `typeof` operator.
@@ -364,33 +584,61 @@ pub enum Opcode {
     /// Stack: value **=>** (-value)
     Neg,
 
-    /// Declate `var` type variable.
+    /// Unary `++` operator, applied to the number value of the operand.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** (value + 1)
+    Inc,
+
+    /// Unary `--` operator, applied to the number value of the operand.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** (value - 1)
+    Dec,
+
+    /// Declare `var` type variable, initialized to `undefined` if it doesn't exist yet.
     ///
     /// Operands: name_index: `u32`
     ///
     /// Stack: **=>**
     DefVar,
 
-    /// Declate `let` type variable.
+    /// Declare `var` type variable and assign it the popped value.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: value **=>**
+    DefInitVar,
+
+    /// Declare `let` type variable, left uninitialized until its declaration is evaluated.
     ///
     /// Operands: name_index: `u32`
     ///
     /// Stack: **=>**
     DefLet,
 
-    /// Declate `const` type variable.
+    /// Declare `let` type variable and initialize it with the popped value.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: value **=>**
+    DefInitLet,
+
+    /// Declare `const` type variable, left uninitialized until its declaration is evaluated.
     ///
     /// Operands: name_index: `u32`
     ///
     /// Stack: **=>**
     DefConst,
 
-    /// Initialize a lexical binding.
+    /// Declare `const` type variable and initialize it with the popped value.
     ///
     /// Operands: name_index: `u32`
     ///
-    /// Stack: **=>**
-    InitLexical,
+    /// Stack: value **=>**
+    DefInitConst,
 
     /// Find a binding on the environment chain and push its value.
     ///
@@ -403,14 +651,22 @@ pub enum Opcode {
     ///
     /// Operands: name_index: `u32`
     ///
-    /// Stack: value **=>**
+    /// Stack: value **=>** value
     SetName,
 
+    /// Push the `this` value of a call to the binding, like `undefined` unless it is found in
+    /// an object environment record.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: **=>** this
+    ImplicitThis,
+
     /// Get a property by name from an object an push it on the stack.
     ///
     /// Like `object.name`
     ///
-    /// Operands: name_index: `u32`
+    /// Operands: name_index: `u32`, cache_index: `u32`
     ///
     /// Stack: object **=>** value
     GetPropertyByName,
@@ -421,16 +677,16 @@ pub enum Opcode {
     ///
     /// Operands:
     ///
-    /// Stack: key, object **=>** value
+    /// Stack: object, key **=>** value
     GetPropertyByValue,
 
     /// Sets a property by name of an object.
     ///
     /// Like `object.name = value`
     ///
-    /// Operands: name_index: `u32`
+    /// Operands: name_index: `u32`, cache_index: `u32`
     ///
-    /// Stack: value, object **=>**
+    /// Stack: object, value **=>** value
     SetPropertyByName,
 
     /// Sets a property by value of an object.
@@ -439,9 +695,72 @@ pub enum Opcode {
     ///
     /// Operands:
     ///
-    /// Stack: value, key, object **=>**
+    /// Stack: object, key, value **=>** value
     SetPropertyByValue,
 
+    /// Get a property of the home object of the current function, like `super.name` or
+    /// `super[key]`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: this, key **=>** value
+    GetSuperProperty,
+
+    /// Set a property of the home object of the current function.
+    ///
+    /// Operands:
+    ///
+    /// Stack: this, key, value **=>** value
+    SetSuperProperty,
+
+    /// Throw the `ReferenceError` of a `delete` of a property of the home object of the current
+    /// function, after evaluating the reference.
+    ///
+    /// Operands:
+    ///
+    /// Stack: this, key **=>**
+    DeleteSuperProperty,
+
+    /// Get the value of a private element of an object.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object **=>** value
+    GetPrivateField,
+
+    /// Set the value of a private element of an object.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object, value **=>** value
+    SetPrivateField,
+
+    /// Check if an object has a private element, for `#name in object`.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: object **=>** (#name in object)
+    InPrivate,
+
+    /// Delete a property of an object, throwing a `TypeError` in strict mode code if it can't
+    /// be deleted.
+    ///
+    /// Like `delete object[key]`
+    ///
+    /// Operands: strict: `u8`
+    ///
+    /// Stack: object, key **=>** deleted
+    DeletePropertyByValue,
+
+    /// Delete a binding of the current environment.
+    ///
+    /// Like `delete name`
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: **=>** deleted
+    DeleteName,
+
     /// Unconditional jump to address.
     ///
     /// Operands: address: `u32`
@@ -470,6 +789,20 @@ pub enum Opcode {
     /// [truthy]: https://developer.mozilla.org/en-US/docs/Glossary/Truthy
     JumpIfTrue,
 
+    /// Jump to address if the value on the top of the stack is not `undefined`.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: value **=>** value
+    JumpIfNotUndefined,
+
+    /// Jump to address if the value on the top of the stack is `null` or `undefined`.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: value **=>** value
+    JumpIfNullOrUndefined,
+
     /// Throw exception
     ///
     /// Operands:
@@ -477,6 +810,20 @@ pub enum Opcode {
     /// Stack: `exc` **=>**
     Throw,
 
+    /// Throw a new `TypeError` with the message in the literals.
+    ///
+    /// Operands: message_index: `u32`
+    ///
+    /// Stack: **=>**
+    ThrowTypeError,
+
+    /// Throw a new `SyntaxError` with the message in the literals.
+    ///
+    /// Operands: message_index: `u32`
+    ///
+    /// Stack: **=>**
+    ThrowSyntaxError,
+
     /// Pops value converts it to boolean and pushes it back.
     ///
     /// Operands:
@@ -491,6 +838,13 @@ pub enum Opcode {
     /// Stack: **=>** `this`
     This,
 
+    /// Pushes the `new.target` value of the current function.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** `new.target`
+    NewTarget,
+
     /// Pop the two values of the stack, strict equal compares the two values,
     /// if true jumps to address, otherwise push the second poped value.
     ///
@@ -506,6 +860,324 @@ pub enum Opcode {
     /// Stack: `value` **=>**
     Default,
 
+    /// Push a new function object created from the function with the given index in the code
+    /// block, closing over the current environment.
+    ///
+    /// Operands: function_index: `u32`
+    ///
+    /// Stack: **=>** function
+    GetFunction,
+
+    /// Concatenate the string values of the parts of a template literal.
+    ///
+    /// Operands: value_count: `u32`
+    ///
+    /// Stack: v1, ... vn **=>** string
+    ConcatToString,
+
+    /// Push the template object of the tagged template with the given index in the nodes of the
+    /// code block.
+    ///
+    /// Operands: node_index: `u32`
+    ///
+    /// Stack: **=>** template
+    GetTemplateObject,
+
+    /// Import a module dynamically, like `import(specifier)`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: specifier **=>** promise
+    ImportCall,
+
+    /// Call a function.
+    ///
+    /// Operands: argument_count: `u32`
+    ///
+    /// Stack: this, function, arg1, ... argn **=>** result
+    Call,
+
+    /// Call a function with the values of an array as arguments.
+    ///
+    /// Operands:
+    ///
+    /// Stack: this, function, arguments **=>** result
+    CallSpread,
+
+    /// Call a function through the identifier `eval`, which is a direct eval if it is %eval%.
+    ///
+    /// Operands: argument_count: `u32`
+    ///
+    /// Stack: this, function, arg1, ... argn **=>** result
+    CallEval,
+
+    /// Call a function through the identifier `eval` with the values of an array as arguments.
+    ///
+    /// Operands:
+    ///
+    /// Stack: this, function, arguments **=>** result
+    CallEvalSpread,
+
+    /// Push the constructor of the parent class of the derived class constructor being run.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** super_constructor
+    SuperConstructor,
+
+    /// Construct the object of the derived class constructor being run with the constructor of
+    /// its parent class, binding it to `this`.
+    ///
+    /// Operands: argument_count: `u32`
+    ///
+    /// Stack: super_constructor, arg1, ... argn **=>** object
+    SuperCall,
+
+    /// Construct the object of the derived class constructor being run with the constructor of
+    /// its parent class and the values of an array as arguments, binding it to `this`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: super_constructor, arguments **=>** object
+    SuperCallSpread,
+
+    /// Construct an object with a constructor, the source text of the constructor expression
+    /// being used for the error message if it isn't a constructor.
+    ///
+    /// Operands: argument_count: `u32`, name_index: `u32`
+    ///
+    /// Stack: constructor, arg1, ... argn **=>** object
+    New,
+
+    /// Construct an object with a constructor and the values of an array as arguments.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: constructor, arguments **=>** object
+    NewSpread,
+
+    /// Return from the current frame with the popped value, after running the `finally` blocks
+    /// around the instruction.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>**
+    Return,
+
+    /// Push a handler for the exceptions thrown until the matching `TryEnd`, which restores
+    /// the stack and the environments before jumping to `address` with the exception pushed.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: **=>**
+    TryStart,
+
+    /// Pop the innermost exception handler.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    TryEnd,
+
+    /// Push a handler for the completions of the code until the matching `TryEnd`, which
+    /// restores the stack and the environments before jumping to the `finally` block at
+    /// `address` with the completion pushed, when the code throws or returns.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: **=>**
+    FinallyStart,
+
+    /// Resume the completion of the code before a `finally` block.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value, completion_type **=>**
+    FinallyEnd,
+
+    /// Push the completion of a `break` or `continue` statement leaving a `finally` block, which
+    /// is resumed by jumping to `address`.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: **=>** address, completion_type
+    PushJumpCompletion,
+
+    /// Push a declarative environment, whose parent is the current environment.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PushDeclarativeEnvironment,
+
+    /// Push the object environment of a `with` statement, whose parent is the current
+    /// environment.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>**
+    PushObjectEnvironment,
+
+    /// Pop the current environment.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PopEnvironment,
+
+    /// Pop a value and store it as the completion value of the current frame.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>**
+    SetCompletion,
+
+    /// Push the completion value of the current frame.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** value
+    GetCompletion,
+
+    /// Push an iterator of an iterable on the iterators of the frame.
+    ///
+    /// Operands:
+    ///
+    /// Stack: iterable **=>**
+    GetIterator,
+
+    /// Push an async iterator of an iterable on the iterators of the frame.
+    ///
+    /// Operands:
+    ///
+    /// Stack: iterable **=>**
+    GetAsyncIterator,
+
+    /// Push the iterator of the keys of a `for...in` loop on the iterators of the frame, or jump
+    /// to address if the value is `null` or `undefined`.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: value **=>**
+    ForInLoopInitIterator,
+
+    /// Call the `next` method of the innermost iterator.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** result
+    IteratorNext,
+
+    /// Push the value of an iterator result, or jump to address if it is done.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: result **=>** value
+    IteratorResult,
+
+    /// Push the next value of the innermost iterator, or `undefined` once it is done.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** value
+    IteratorStepValue,
+
+    /// Push an array of the remaining values of the innermost iterator.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** array
+    IteratorToArray,
+
+    /// Pop the innermost iterator, without closing it.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    IteratorPop,
+
+    /// Pop the innermost iterator and call its `return` method, or jump to address if it is done
+    /// or has no `return` method.
+    ///
+    /// The exceptions of the call are ignored if the completion closing the iterator is a
+    /// `throw` completion.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: value, completion_type **=>** value, completion_type, result
+    IteratorCallReturn,
+
+    /// Check the result of the `return` method of an iterator, unless the completion closing the
+    /// iterator is a `throw` completion.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value, completion_type, result, result_completion_type **=>** value, completion_type
+    IteratorCloseResult,
+
+    /// Throw a `TypeError` if the value destructured by a pattern is `null` or `undefined`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** value
+    ValueNotNullOrUndefined,
+
+    /// Suspend the frame of a generator, yielding the value, until it is resumed with a
+    /// completion.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** received, completion_type
+    Yield,
+
+    /// Suspend the frame of an async function until the value is settled.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** result, completion_type
+    Await,
+
+    /// Resume the completion a frame was resumed with, pushing the value of a normal completion.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value, completion_type **=>** value
+    ResumeCompletion,
+
+    /// Jump to address if the completion type on the top of the stack is not the given one.
+    ///
+    /// Operands: address: `u32`, completion_type: `u8`
+    ///
+    /// Stack: completion_type **=>** completion_type
+    JumpIfNotCompletionType,
+
+    /// Create an iterator result which is not done.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** result
+    CreateIteratorResult,
+
+    /// Pass the completion received by a `yield*` expression to the innermost iterator.
+    ///
+    /// Jumps to the first address if the iterator has no `throw` method for a `throw`
+    /// completion, and to the second address with the value pushed if it has no `return`
+    /// method for a `return` completion.
+    ///
+    /// Operands: throw_method_undefined: `u32`, return_method_undefined: `u32`
+    ///
+    /// Stack: received, completion_type **=>** is_return, result
+    GeneratorDelegateNext,
+
+    /// Push the result of the innermost iterator of a `yield*` expression to be yielded, or its
+    /// value if it is done, jumping to the first address if it was returned from, and to the
+    /// second address otherwise.
+    ///
+    /// Operands: return: `u32`, exit: `u32`
+    ///
+    /// Stack: is_return, result **=>** result
+    GeneratorDelegateResume,
+
     /// No-operation instruction, does nothing.
     ///
     /// Operands:
@@ -530,7 +1202,10 @@ impl Opcode {
         match self {
             Opcode::Pop => "Pop",
             Opcode::Dup => "Dup",
+            Opcode::Dup2 => "Dup2",
             Opcode::Swap => "Swap",
+            Opcode::RotateLeft => "RotateLeft",
+            Opcode::RotateRight => "RotateRight",
             Opcode::PushZero => "PushZero",
             Opcode::PushOne => "PushOne",
             Opcode::PushInt8 => "PushInt8",
@@ -547,6 +1222,32 @@ impl Opcode {
             Opcode::PushLiteral => "PushLiteral",
             Opcode::PushEmptyObject => "PushEmptyObject",
             Opcode::PushNewArray => "PushNewArray",
+            Opcode::DefineOwnPropertyByName => "DefineOwnPropertyByName",
+            Opcode::PushValueToArray => "PushValueToArray",
+            Opcode::PushIteratorToArray => "PushIteratorToArray",
+            Opcode::DefineMethodByName => "DefineMethodByName",
+            Opcode::SetGetterByName => "SetGetterByName",
+            Opcode::SetSetterByName => "SetSetterByName",
+            Opcode::CopyDataProperties => "CopyDataProperties",
+            Opcode::SetFunctionName => "SetFunctionName",
+            Opcode::DefPrivateName => "DefPrivateName",
+            Opcode::PushClassPrototype => "PushClassPrototype",
+            Opcode::PushDerivedClassPrototype => "PushDerivedClassPrototype",
+            Opcode::DefineClassMethod => "DefineClassMethod",
+            Opcode::DefineClassGetter => "DefineClassGetter",
+            Opcode::DefineClassSetter => "DefineClassSetter",
+            Opcode::PushClassPrivateMethod => "PushClassPrivateMethod",
+            Opcode::PushClassPrivateGetter => "PushClassPrivateGetter",
+            Opcode::PushClassPrivateSetter => "PushClassPrivateSetter",
+            Opcode::PushClassPrivateField => "PushClassPrivateField",
+            Opcode::DefinePrivateMethod => "DefinePrivateMethod",
+            Opcode::DefinePrivateGetter => "DefinePrivateGetter",
+            Opcode::DefinePrivateSetter => "DefinePrivateSetter",
+            Opcode::PushClassField => "PushClassField",
+            Opcode::PushClassStaticField => "PushClassStaticField",
+            Opcode::PushClassStaticPrivateField => "PushClassStaticPrivateField",
+            Opcode::PushClassStaticBlock => "PushClassStaticBlock",
+            Opcode::DefineClassStaticElements => "DefineClassStaticElements",
             Opcode::Add => "Add",
             Opcode::Sub => "Sub",
             Opcode::Div => "Div",
@@ -570,32 +1271,92 @@ impl Opcode {
             Opcode::LessThan => "LessThan",
             Opcode::LessThanOrEq => "LessThanOrEq",
             Opcode::InstanceOf => "InstanceOf",
-            Opcode::TypeOf => "TypeOf",
-            Opcode::Void => "Void",
-            Opcode::LogicalNot => "LogicalNot",
             Opcode::LogicalAnd => "LogicalAnd",
             Opcode::LogicalOr => "LogicalOr",
             Opcode::Coalesce => "Coalesce",
+            Opcode::TypeOf => "TypeOf",
+            Opcode::Void => "Void",
+            Opcode::LogicalNot => "LogicalNot",
             Opcode::Pos => "Pos",
             Opcode::Neg => "Neg",
+            Opcode::Inc => "Inc",
+            Opcode::Dec => "Dec",
             Opcode::DefVar => "DefVar",
+            Opcode::DefInitVar => "DefInitVar",
             Opcode::DefLet => "DefLet",
+            Opcode::DefInitLet => "DefInitLet",
             Opcode::DefConst => "DefConst",
-            Opcode::InitLexical => "InitLexical",
+            Opcode::DefInitConst => "DefInitConst",
             Opcode::GetName => "GetName",
             Opcode::SetName => "SetName",
+            Opcode::ImplicitThis => "ImplicitThis",
             Opcode::GetPropertyByName => "GetPropertyByName",
             Opcode::GetPropertyByValue => "GetPropertyByValue",
             Opcode::SetPropertyByName => "SetPropertyByName",
             Opcode::SetPropertyByValue => "SetPropertyByValue",
+            Opcode::GetSuperProperty => "GetSuperProperty",
+            Opcode::SetSuperProperty => "SetSuperProperty",
+            Opcode::DeleteSuperProperty => "DeleteSuperProperty",
+            Opcode::GetPrivateField => "GetPrivateField",
+            Opcode::SetPrivateField => "SetPrivateField",
+            Opcode::InPrivate => "InPrivate",
+            Opcode::DeletePropertyByValue => "DeletePropertyByValue",
+            Opcode::DeleteName => "DeleteName",
             Opcode::Jump => "Jump",
             Opcode::JumpIfFalse => "JumpIfFalse",
             Opcode::JumpIfTrue => "JumpIfTrue",
+            Opcode::JumpIfNotUndefined => "JumpIfNotUndefined",
+            Opcode::JumpIfNullOrUndefined => "JumpIfNullOrUndefined",
             Opcode::Throw => "Throw",
+            Opcode::ThrowTypeError => "ThrowTypeError",
+            Opcode::ThrowSyntaxError => "ThrowSyntaxError",
             Opcode::ToBoolean => "ToBoolean",
             Opcode::This => "This",
+            Opcode::NewTarget => "NewTarget",
             Opcode::Case => "Case",
             Opcode::Default => "Default",
+            Opcode::GetFunction => "GetFunction",
+            Opcode::ConcatToString => "ConcatToString",
+            Opcode::GetTemplateObject => "GetTemplateObject",
+            Opcode::ImportCall => "ImportCall",
+            Opcode::Call => "Call",
+            Opcode::CallSpread => "CallSpread",
+            Opcode::CallEval => "CallEval",
+            Opcode::CallEvalSpread => "CallEvalSpread",
+            Opcode::SuperConstructor => "SuperConstructor",
+            Opcode::SuperCall => "SuperCall",
+            Opcode::SuperCallSpread => "SuperCallSpread",
+            Opcode::New => "New",
+            Opcode::NewSpread => "NewSpread",
+            Opcode::Return => "Return",
+            Opcode::TryStart => "TryStart",
+            Opcode::TryEnd => "TryEnd",
+            Opcode::FinallyStart => "FinallyStart",
+            Opcode::FinallyEnd => "FinallyEnd",
+            Opcode::PushJumpCompletion => "PushJumpCompletion",
+            Opcode::PushDeclarativeEnvironment => "PushDeclarativeEnvironment",
+            Opcode::PushObjectEnvironment => "PushObjectEnvironment",
+            Opcode::PopEnvironment => "PopEnvironment",
+            Opcode::SetCompletion => "SetCompletion",
+            Opcode::GetCompletion => "GetCompletion",
+            Opcode::GetIterator => "GetIterator",
+            Opcode::GetAsyncIterator => "GetAsyncIterator",
+            Opcode::ForInLoopInitIterator => "ForInLoopInitIterator",
+            Opcode::IteratorNext => "IteratorNext",
+            Opcode::IteratorResult => "IteratorResult",
+            Opcode::IteratorStepValue => "IteratorStepValue",
+            Opcode::IteratorToArray => "IteratorToArray",
+            Opcode::IteratorPop => "IteratorPop",
+            Opcode::IteratorCallReturn => "IteratorCallReturn",
+            Opcode::IteratorCloseResult => "IteratorCloseResult",
+            Opcode::ValueNotNullOrUndefined => "ValueNotNullOrUndefined",
+            Opcode::Yield => "Yield",
+            Opcode::Await => "Await",
+            Opcode::ResumeCompletion => "ResumeCompletion",
+            Opcode::JumpIfNotCompletionType => "JumpIfNotCompletionType",
+            Opcode::CreateIteratorResult => "CreateIteratorResult",
+            Opcode::GeneratorDelegateNext => "GeneratorDelegateNext",
+            Opcode::GeneratorDelegateResume => "GeneratorDelegateResume",
             Opcode::Nop => "Nop",
         }
     }
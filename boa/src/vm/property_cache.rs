@@ -0,0 +1,98 @@
+//! The inline caches of the property accesses by name of the vm.
+
+use crate::{object::Object, JsString, JsValue};
+
+use std::cell::Cell;
+
+/// The cache of a property access site, remembering where the property was last found.
+///
+/// Only the own data properties of the objects with the ordinary `[[Get]]` or `[[Set]]` are
+/// cached. The cache holds the shape of the object the property was found in, and the slot of
+/// the property, so the objects with the same shape find the property in the same slot without
+/// looking up its key.
+#[derive(Debug, Default)]
+pub(crate) struct PropertyCache {
+    /// The id of the cached shape, or `0` if nothing is cached.
+    shape: Cell<u64>,
+    slot: Cell<usize>,
+}
+
+impl PropertyCache {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the value of the cached property of `object`, if it has the cached shape.
+    #[inline]
+    pub(crate) fn get(&self, object: &Object) -> Option<JsValue> {
+        if !object.has_ordinary_get() || object.properties().shape_id() != Some(self.shape.get()) {
+            return None;
+        }
+
+        object
+            .properties()
+            .string_property_at(self.slot.get())?
+            .value()
+            .cloned()
+    }
+
+    /// Sets the value of the cached property of `object`, if it has the cached shape.
+    ///
+    /// Returns `value` back if the property has to be set by the `[[Set]]` of the object.
+    #[inline]
+    pub(crate) fn set(&self, object: &mut Object, value: JsValue) -> Result<(), JsValue> {
+        if !object.has_ordinary_set() || object.properties().shape_id() != Some(self.shape.get()) {
+            return Err(value);
+        }
+
+        match object
+            .properties_mut()
+            .string_property_at_mut(self.slot.get())
+            .and_then(|property| property.writable_value_mut())
+        {
+            Some(slot) => {
+                *slot = value;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Caches the property in `slot` of `object`, if the object has a shape.
+    fn cache(&self, object: &Object, slot: usize) {
+        if let Some(shape) = object.properties().shape_id() {
+            self.shape.set(shape);
+            self.slot.set(slot);
+        }
+    }
+
+    /// Caches the own property `key` of `object` after it was read.
+    pub(crate) fn update_get(&self, object: &Object, key: &JsString) {
+        if !object.has_ordinary_get() {
+            return;
+        }
+
+        if let Some((slot, property)) = object.properties().string_property_slot(key) {
+            if property.value().is_some() {
+                self.cache(object, slot);
+            }
+        }
+    }
+
+    /// Caches the own property `key` of `object` after it was written.
+    pub(crate) fn update_set(&self, object: &Object, key: &JsString) {
+        if !object.has_ordinary_set() {
+            return;
+        }
+
+        if let Some((slot, property)) = object.properties().string_property_slot(key) {
+            // Writes of the properties watched by the host are reported to their watcher.
+            if property.writable() == Some(true)
+                && property.value().is_some()
+                && property.watcher().is_none()
+            {
+                self.cache(object, slot);
+            }
+        }
+    }
+}
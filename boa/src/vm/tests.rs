@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, forward, Context};
 
 #[test]
 fn typeof_string() {
@@ -27,3 +27,327 @@ fn basic_op() {
     "#;
     assert_eq!(&exec(basic_op), "3");
 }
+
+#[test]
+fn loops_and_labels() {
+    let loops = r#"
+        let sum = 0;
+        outer: for (let i = 0; i < 5; i++) {
+            let j = 0;
+            while (true) {
+                j++;
+                if (j > i) continue outer;
+                if (i == 4) break outer;
+                sum += j;
+            }
+        }
+        sum
+    "#;
+    assert_eq!(&exec(loops), "10");
+}
+
+#[test]
+fn switch_fallthrough() {
+    let switch = r#"
+        function f(x) {
+            let s = "";
+            switch (x) {
+                case 1: s += "a";
+                case 2: s += "b"; break;
+                case 3: s += "c"; break;
+                default: s += "d";
+            }
+            return s;
+        }
+        [f(1), f(2), f(3), f(4)].join()
+    "#;
+    assert_eq!(&exec(switch), "\"ab,b,c,d\"");
+}
+
+#[test]
+fn calls_and_closures() {
+    let calls = r#"
+        function counter() {
+            let n = 0;
+            return { next: () => ++n };
+        }
+        const c = counter();
+        c.next();
+        c.next();
+        c.next()
+    "#;
+    assert_eq!(&exec(calls), "3");
+}
+
+#[test]
+fn construct_returning_object() {
+    let construct = r#"
+        function A() { this.x = 1; }
+        function B() { this.x = 1; return { x: 2 }; }
+        new A().x + new B().x
+    "#;
+    assert_eq!(&exec(construct), "3");
+}
+
+#[test]
+fn try_catch_unwinds() {
+    let try_catch = r#"
+        function thrower() { throw new Error("boom"); }
+        let result;
+        for (let i = 0; i < 3; i++) {
+            try {
+                let x = i;
+                if (x == 1) thrower();
+            } catch (e) {
+                result = e.message + i;
+                break;
+            }
+        }
+        result
+    "#;
+    assert_eq!(&exec(try_catch), "\"boom1\"");
+}
+
+#[test]
+fn completion_value() {
+    assert_eq!(&exec("if (true) { 1; } else { 2; }"), "1");
+    assert_eq!(&exec("let i = 0; while (i < 3) { i++; }"), "2");
+    assert_eq!(&exec("for (let i = 0; i < 3; i++) { i; }"), "undefined");
+}
+
+#[test]
+fn function_without_return() {
+    assert_eq!(&exec("function f() { 1; } f()"), "undefined");
+}
+
+#[test]
+fn fallback_statements() {
+    // `for...of` loops and `finally` blocks are run by walking their syntax tree.
+    let fallback = r#"
+        let s = 0;
+        for (const x of [1, 2, 3]) {
+            try { s += x; } finally { s *= 2; }
+        }
+        s
+    "#;
+    assert_eq!(&exec(fallback), "22");
+}
+
+#[test]
+fn jumps_out_of_evaluated_statements() {
+    // Statements without bytecode are evaluated by walking their syntax tree, and their `break`
+    // and `continue` statements jump to the statements of the bytecode around them.
+    let jumps = r#"
+        const log = [];
+        a: for (let i = 0; i < 3; i++) {
+            for (const x of [1, 2]) {
+                if (i === 0) continue a;
+                if (i === 2) break a;
+                log.push(`${i}${x}`);
+            }
+        }
+        for (;;) {
+            try { log.push("try"); break; } finally { log.push("finally"); }
+        }
+        let n = 0;
+        while (n < 2) {
+            n++;
+            with ({ n }) {
+                if (n === 1) continue;
+                log.push(`with${n}`);
+            }
+        }
+        b: {
+            for (const key in { k: 1 }) {
+                break b;
+            }
+            log.push("unreachable");
+        }
+        switch (1) {
+            case 1:
+                for (const x of [1]) break;
+                try { break; } finally { log.push("switch"); }
+            default:
+                log.push("default");
+        }
+        log.join()
+    "#;
+    assert_eq!(&exec(jumps), "\"11,12,try,finally,with2,switch\"");
+}
+
+#[test]
+fn compound_assignment() {
+    let compound = r#"
+        const o = { a: 1, b: null };
+        const k = "a";
+        o.a += 2;
+        o[k] *= 3;
+        o.b ??= 4;
+        o.a &&= o.a + o.b;
+        o.a
+    "#;
+    assert_eq!(&exec(compound), "13");
+}
+
+#[test]
+fn property_cache_layout_changes() {
+    let layout = r#"
+        function get(o) { return o.b; }
+        const o = { a: 1, b: 2 };
+        const results = [get(o)];
+        delete o.a;
+        results.push(get(o));
+        o.a = 3;
+        results.push(get(o));
+        results.push(get({ b: 4 }), get({ c: 5, b: 6 }), get({}));
+        results.join()
+    "#;
+    assert_eq!(&exec(layout), "\"2,2,2,4,6,\"");
+}
+
+#[test]
+fn property_cache_accessors() {
+    let accessors = r#"
+        function get(o) { return o.x; }
+        const o = { x: 1 };
+        const results = [get(o)];
+        Object.defineProperty(o, "x", { get() { return 2; } });
+        results.push(get(o));
+        results.push(get(Object.create({ x: 3 })));
+        results.join()
+    "#;
+    assert_eq!(&exec(accessors), "\"1,2,3\"");
+}
+
+#[test]
+fn property_cache_non_writable() {
+    let non_writable = r#"
+        function set(o, v) { o.x = v; }
+        const o = { x: 1 };
+        set(o, 2);
+        Object.defineProperty(o, "x", { writable: false });
+        set(o, 3);
+        o.x
+    "#;
+    assert_eq!(&exec(non_writable), "2");
+}
+
+#[test]
+fn property_cache_exotic_objects() {
+    let exotic = r#"
+        function get(o) { return o.length; }
+        const results = [get({ length: 1 }), get([1, 2]), get("abc")];
+        results.push(get(new Proxy({ length: 4 }, { get: () => 5 })));
+        results.join()
+    "#;
+    assert_eq!(&exec(exotic), "\"1,2,3,5\"");
+}
+
+#[test]
+fn generator_return_runs_finally() {
+    let scenario = r#"
+        let log = [];
+        function* g() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                log.push("finally");
+                yield 3;
+            }
+        }
+        const it = g();
+        it.next();
+        const first = it.return(4);
+        const second = it.next();
+        `${first.value} ${first.done} ${second.value} ${second.done} ${log}`
+    "#;
+    assert_eq!(&exec(scenario), "\"3 false 4 true finally\"");
+}
+
+#[test]
+fn generator_return_closes_for_of() {
+    let scenario = r#"
+        let closed = false;
+        const iterable = {};
+        iterable[Symbol.iterator] = () => ({
+            next() { return { value: 1, done: false }; },
+            return() { closed = true; return {}; },
+        });
+        function* g() {
+            for (const x of iterable) {
+                yield x;
+            }
+        }
+        const it = g();
+        it.next();
+        it.return();
+        closed
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn generator_delegates_yield() {
+    let scenario = r#"
+        function* inner() {
+            const a = yield 1;
+            yield a * 2;
+            return "done";
+        }
+        function* outer() {
+            const result = yield* inner();
+            yield result;
+        }
+        const it = outer();
+        [it.next().value, it.next(21).value, it.next().value, it.next().done].join(" ")
+    "#;
+    assert_eq!(&exec(scenario), "\"1 42 done true\"");
+}
+
+#[test]
+fn await_in_loops() {
+    let mut context = Context::new();
+    let init = r#"
+        let sum = 0;
+        async function f() {
+            for (let i = 0; i < 3; i++) {
+                for (const x of [1, 2]) {
+                    sum += await x * i;
+                }
+            }
+            return sum;
+        }
+        f().then(value => { sum = `resolved ${value}`; });
+    "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "sum"), "\"resolved 9\"");
+}
+
+#[test]
+fn suspended_frames_survive_moving_the_context() {
+    let context = Context::new();
+    let mut context = Box::new(context);
+    let init = r#"
+        function* g() {
+            let i = 0;
+            while (true) yield i++;
+        }
+        const it = g();
+        it.next();
+        let awaited;
+        async function f() {
+            awaited = await "value";
+        }
+        f();
+    "#;
+    forward(&mut context, init);
+
+    // The frames are saved in the generator and in the promise reactions, so they can be
+    // resumed by the context wherever it was moved.
+    let mut context = *context;
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "it.next().value"), "1");
+    assert_eq!(forward(&mut context, "awaited"), "\"value\"");
+}
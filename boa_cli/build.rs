@@ -0,0 +1,38 @@
+//! Generates the table of the builtins documented by the `help()` function of the REPL from
+//! `src/help.tsv`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+const SOURCE: &str = "src/help.tsv";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SOURCE);
+
+    let source = fs::read_to_string(SOURCE).expect("could not read the help metadata");
+    let mut table = String::from("&[\n");
+    for (index, line) in source.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        if let [path, signature, description] = fields[..] {
+            writeln!(
+                table,
+                "    HelpEntry {{ path: {:?}, signature: {:?}, description: {:?} }},",
+                path, signature, description
+            )
+            .unwrap();
+        } else {
+            panic!(
+                "{}:{}: expected a path, a signature and a description separated by tabs",
+                SOURCE,
+                index + 1
+            );
+        }
+    }
+    table.push(']');
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("help_table.rs"), table)
+        .expect("could not write the help table");
+}
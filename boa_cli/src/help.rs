@@ -0,0 +1,114 @@
+//! The `help()` function of the REPL, printing the signature and description of the builtins.
+
+use boa::{object::JsObject, property::PropertyKey, Context, JsResult, JsString, JsValue};
+use colored::*;
+
+/// The documentation of a builtin.
+#[derive(Debug, Clone, Copy)]
+struct HelpEntry {
+    /// The path of the builtin from the global object, like `Array.prototype.map`.
+    path: &'static str,
+    /// The signature of the builtin, with its optional parameters in brackets.
+    signature: &'static str,
+    /// A one sentence description of the builtin.
+    description: &'static str,
+}
+
+/// The documented builtins, generated by `build.rs` from `help.tsv`.
+static HELP_ENTRIES: &[HelpEntry] = include!(concat!(env!("OUT_DIR"), "/help_table.rs"));
+
+/// Registers the global `help` function in the context.
+pub(crate) fn register(context: &mut Context) -> JsResult<()> {
+    context.register_global_function("help", 1, help)
+}
+
+/// `help([value])`
+///
+/// Prints the documentation of a builtin, given either the builtin itself or its path as a
+/// string, like `help(Array.prototype.map)` or `help("Array.prototype.map")`.
+fn help(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = match args.first() {
+        Some(value) => value,
+        None => {
+            println!(
+                "Use {} or {} to learn about a builtin.",
+                "help(Array.prototype.map)".bold(),
+                "help(\"Math.max\")".bold()
+            );
+            return Ok(JsValue::undefined());
+        }
+    };
+
+    let entry = match value {
+        JsValue::String(path) => HELP_ENTRIES.iter().find(|e| e.path == path.as_str()),
+        JsValue::Object(object) => HELP_ENTRIES.iter().find(|e| {
+            resolve(e.path, context).map_or(false, |builtin| JsObject::equals(&builtin, object))
+        }),
+        _ => None,
+    };
+
+    match entry {
+        Some(entry) => {
+            println!("{}", entry.signature.bold());
+            println!("  {}", entry.description);
+        }
+        None => match value.as_object().filter(|object| object.is_callable()) {
+            Some(function) => {
+                let properties = function.borrow();
+                let property = |key: &str| {
+                    properties
+                        .properties()
+                        .get(&key.into())
+                        .and_then(|descriptor| descriptor.value().cloned())
+                        .unwrap_or_default()
+                };
+                let name = property("name")
+                    .as_string()
+                    .map_or_else(|| JsString::from("anonymous"), Clone::clone);
+                println!(
+                    "No help available for the function {}, taking {} argument(s).",
+                    name.as_str().bold(),
+                    property("length").display()
+                );
+            }
+            None => println!("No help available for {}.", value.display()),
+        },
+    }
+    Ok(JsValue::undefined())
+}
+
+/// Finds the builtin at the path from the global object, following its own data properties and
+/// the getters of its own accessor properties.
+fn resolve(path: &str, context: &Context) -> Option<JsObject> {
+    path.split('.')
+        .try_fold(context.global_object(), |object, key| {
+            let key = PropertyKey::from(key);
+            let object = object.borrow();
+            let descriptor = object.properties().get(&key)?;
+            descriptor
+                .value()
+                .or_else(|| descriptor.get())
+                .and_then(JsValue::as_object)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve, HELP_ENTRIES};
+    use boa::Context;
+
+    #[test]
+    fn help_entries_resolve() {
+        let context = Context::new();
+        let missing: Vec<_> = HELP_ENTRIES
+            .iter()
+            .map(|entry| entry.path)
+            .filter(|path| resolve(path, &context).is_none())
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "help.tsv documents builtins the engine does not provide: {:?}",
+            missing
+        );
+    }
+}
@@ -25,12 +25,22 @@
     clippy::as_conversions
 )]
 
-use boa::{syntax::ast::node::StatementList, Context};
+use boa::{
+    builtins::console::WriteConsoleLogger,
+    syntax::ast::{node::StatementList, versioned_ast_schema, AST_FORMAT_VERSION},
+    Context,
+};
 use colored::*;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read, path::PathBuf};
+use std::{
+    fmt::Debug,
+    fs::{read, File},
+    io::{self, Write},
+    path::PathBuf,
+};
 use structopt::{clap::arg_enum, StructOpt};
 
+mod help;
 mod helper;
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
@@ -66,14 +76,22 @@ struct Opt {
     )]
     dump_ast: Option<Option<DumpFormat>>,
 
-    /// Dump the AST to stdout with the given format.
-    #[cfg(feature = "vm")]
+    /// Print the JSON schema of the `Json` and `JsonPretty` AST dumps to stdout and exit.
+    #[structopt(long)]
+    dump_ast_schema: bool,
+
+    /// Print the bytecode of the evaluated code and trace its execution.
     #[structopt(long = "trace", short = "t")]
     trace: bool,
 
     /// Use vi mode in the REPL
     #[structopt(long = "vi")]
     vi_mode: bool,
+
+    /// Write the results, uncaught errors and console output of the evaluated files to the given
+    /// file instead of the standard output and error.
+    #[structopt(long, short = "o", value_name = "OUTPUT", parse(from_os_str))]
+    output: Option<PathBuf>,
 }
 
 impl Opt {
@@ -97,10 +115,10 @@ arg_enum! {
         // This is the default format that you get from std::fmt::Debug.
         Debug,
 
-        // This is a minified json format.
+        // This is a minified json format, wrapped in a versioned envelope.
         Json,
 
-        // This is a pretty printed json format.
+        // This is a pretty printed json format, wrapped in a versioned envelope.
         JsonPretty,
     }
 }
@@ -118,6 +136,17 @@ fn parse_tokens<T: AsRef<[u8]>>(src: T) -> Result<StatementList, String> {
         .map_err(|e| format!("ParsingError: {}", e))
 }
 
+/// Wraps the AST in a versioned envelope, so that external tools can check
+/// that they understand the serialized format before consuming it.
+///
+/// The envelope has the form `{ "version": <AST_FORMAT_VERSION>, "ast": <StatementList> }`.
+fn versioned_ast(ast: &StatementList) -> serde_json::Value {
+    serde_json::json!({
+        "version": AST_FORMAT_VERSION,
+        "ast": ast,
+    })
+}
+
 /// Dumps the AST to stdout with format controlled by the given arguments.
 ///
 /// Returns a error of type String with a error message,
@@ -130,9 +159,14 @@ fn dump<T: AsRef<[u8]>>(src: T, args: &Opt) -> Result<(), String> {
         match arg {
             Some(format) => match format {
                 DumpFormat::Debug => println!("{:#?}", ast),
-                DumpFormat::Json => println!("{}", serde_json::to_string(&ast).unwrap()),
+                DumpFormat::Json => {
+                    println!("{}", serde_json::to_string(&versioned_ast(&ast)).unwrap())
+                }
                 DumpFormat::JsonPretty => {
-                    println!("{}", serde_json::to_string_pretty(&ast).unwrap())
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&versioned_ast(&ast)).unwrap()
+                    )
                 }
             },
             // Default ast dumping format.
@@ -143,31 +177,75 @@ fn dump<T: AsRef<[u8]>>(src: T, args: &Opt) -> Result<(), String> {
     Ok(())
 }
 
-pub fn main() -> Result<(), std::io::Error> {
-    let args = Opt::from_args();
-
-    let mut context = Context::new();
-
-    // Trace Output
-    #[cfg(feature = "vm")]
-    context.set_trace(args.trace);
-
+/// Evaluates the files given in the arguments, writing their results to `out`, and their uncaught
+/// errors to `err`.
+fn run_files<O, E>(
+    context: &mut Context,
+    args: &Opt,
+    out: &WriteConsoleLogger<O>,
+    err: &WriteConsoleLogger<E>,
+) -> io::Result<()>
+where
+    O: Write + Debug,
+    E: Write + Debug,
+{
     for file in &args.files {
         let buffer = read(file)?;
 
         if args.has_dump_flag() {
-            if let Err(e) = dump(&buffer, &args) {
-                eprintln!("{}", e);
+            if let Err(e) = dump(&buffer, args) {
+                writeln!(err.stream(), "{}", e)?;
             }
         } else {
+            // The streams are only borrowed while writing, as the scripts can log to them.
             match context.eval(&buffer) {
-                Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Ok(v) => writeln!(out.stream(), "{}", v.display())?,
+                Err(e) => writeln!(err.stream(), "Uncaught {}", e)?,
+            }
+            if let Err(e) = context.run_jobs() {
+                writeln!(err.stream(), "Uncaught {}", e)?;
             }
         }
     }
 
+    out.stream().flush()?;
+    err.stream().flush()
+}
+
+pub fn main() -> Result<(), std::io::Error> {
+    let args = Opt::from_args();
+
+    if args.dump_ast_schema {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&versioned_ast_schema()).unwrap()
+        );
+        return Ok(());
+    }
+
+    let mut context = Context::new();
+
+    // Trace Output
+    context.set_trace(args.trace);
+
+    if let Some(ref path) = args.output {
+        let output = WriteConsoleLogger::new(File::create(path)?);
+        context.set_console_logger(output.clone());
+        run_files(&mut context, &args, &output, &output)?;
+    } else {
+        run_files(
+            &mut context,
+            &args,
+            &WriteConsoleLogger::new(io::stdout()),
+            &WriteConsoleLogger::new(io::stderr()),
+        )?;
+    }
+
     if args.files.is_empty() {
+        if let Err(e) = help::register(&mut context) {
+            eprintln!("Uncaught {}", e.display());
+        }
+
         let config = Config::builder()
             .keyseq_timeout(1)
             .edit_mode(if args.vi_mode {
@@ -198,9 +276,10 @@ pub fn main() -> Result<(), std::io::Error> {
                     } else {
                         match context.eval(line.trim_end()) {
                             Ok(v) => println!("{}", v.display()),
-                            Err(v) => {
-                                eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
-                            }
+                            Err(e) => eprintln!("{}: {}", "Uncaught".red(), e.to_string().red()),
+                        }
+                        if let Err(e) = context.run_jobs() {
+                            eprintln!("{}: {}", "Uncaught".red(), e.to_string().red());
                         }
                     }
                 }
@@ -0,0 +1,105 @@
+//! Derive macros for the garbage collection traits of the Boa JavaScript engine.
+//!
+//! The macros are re-exported by the `boa::gc` module, which should be used instead of depending
+//! on this crate directly. Unlike the macros of the `gc` crate, the implementations they generate
+//! only refer to items of `boa`, so embedders don't need to depend on the `gc` crate, nor keep
+//! its version in sync with the one used by the engine.
+
+#![deny(
+    unused_qualifications,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    deprecated_in_future,
+    meta_variable_misuse,
+    non_ascii_idents,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+
+use quote::quote;
+use synstructure::{decl_derive, AddBounds, Structure};
+
+decl_derive!([Trace, attributes(unsafe_ignore_trace)] => derive_trace);
+
+/// Implements `Trace` by tracing every field, except those marked with `#[unsafe_ignore_trace]`,
+/// and `Drop` by calling `Finalize::finalize` when it is safe to do so.
+fn derive_trace(mut s: Structure<'_>) -> proc_macro2::TokenStream {
+    s.filter(|bi| {
+        !bi.ast()
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("unsafe_ignore_trace"))
+    });
+    let trace_body = s.each(|bi| quote!(mark(#bi)));
+
+    s.add_bounds(AddBounds::Fields);
+    let trace_impl = s.unsafe_bound_impl(
+        quote!(::boa::gc::Trace),
+        quote! {
+            #[inline] unsafe fn trace(&self) {
+                #[allow(dead_code)]
+                #[inline]
+                unsafe fn mark<T: ::boa::gc::Trace + ?Sized>(it: &T) {
+                    ::boa::gc::Trace::trace(it);
+                }
+                match *self { #trace_body }
+            }
+            #[inline] unsafe fn root(&self) {
+                #[allow(dead_code)]
+                #[inline]
+                unsafe fn mark<T: ::boa::gc::Trace + ?Sized>(it: &T) {
+                    ::boa::gc::Trace::root(it);
+                }
+                match *self { #trace_body }
+            }
+            #[inline] unsafe fn unroot(&self) {
+                #[allow(dead_code)]
+                #[inline]
+                unsafe fn mark<T: ::boa::gc::Trace + ?Sized>(it: &T) {
+                    ::boa::gc::Trace::unroot(it);
+                }
+                match *self { #trace_body }
+            }
+            #[inline] fn finalize_glue(&self) {
+                ::boa::gc::Finalize::finalize(self);
+                #[allow(dead_code)]
+                #[inline]
+                fn mark<T: ::boa::gc::Trace + ?Sized>(it: &T) {
+                    ::boa::gc::Trace::finalize_glue(it);
+                }
+                match *self { #trace_body }
+            }
+        },
+    );
+
+    // Implementing `Drop` prevents the type from having its own, which could access fields
+    // already collected. Cleanup code belongs in `Finalize::finalize` instead.
+    let drop_impl = s.unbound_impl(
+        quote!(::std::ops::Drop),
+        quote! {
+            fn drop(&mut self) {
+                if ::boa::gc::finalizer_safe() {
+                    ::boa::gc::Finalize::finalize(self);
+                }
+            }
+        },
+    );
+
+    quote! {
+        #trace_impl
+        #drop_impl
+    }
+}
+
+decl_derive!([Finalize] => derive_finalize);
+
+/// Implements `Finalize` with the default, empty, finalizer.
+fn derive_finalize(s: Structure<'_>) -> proc_macro2::TokenStream {
+    s.unbound_impl(quote!(::boa::gc::Finalize), quote!())
+}
@@ -1,3 +1,7 @@
+//! The profiler used by the Boa JavaScript engine.
+//!
+//! When the `profiler` feature is disabled, all the profiling calls are no-ops.
+
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
 #[cfg(feature = "profiler")]
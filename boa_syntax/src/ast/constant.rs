@@ -7,10 +7,8 @@
 //! [spec]: https://tc39.es/ecma262/#sec-primary-expression-literals
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#Literals
 
-use crate::{
-    gc::{Finalize, Trace},
-    JsBigInt,
-};
+use crate::gc::{Finalize, Trace};
+use num_bigint::BigInt;
 use std::fmt::{Display, Formatter, Result};
 
 #[cfg(feature = "deser")]
@@ -27,6 +25,7 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-primary-expression-literals
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#Literals
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum Const {
     /// A string literal is zero or more characters enclosed in double (`"`) or single (`'`) quotation marks.
@@ -76,7 +75,12 @@ pub enum Const {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-terms-and-definitions-bigint-value
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#Numeric_literals
-    BigInt(JsBigInt),
+    BigInt(
+        #[unsafe_ignore_trace]
+        // A big integer is serialized as its sign and its 32 bit digits.
+        #[cfg_attr(feature = "schema", schemars(with = "(i8, Vec<u32>)"))]
+        BigInt,
+    ),
 
     /// The Boolean type has two literal values: `true` and `false`.
     ///
@@ -151,8 +155,8 @@ impl From<i32> for Const {
     }
 }
 
-impl From<JsBigInt> for Const {
-    fn from(i: JsBigInt) -> Self {
+impl From<BigInt> for Const {
+    fn from(i: BigInt) -> Self {
         Self::BigInt(i)
     }
 }
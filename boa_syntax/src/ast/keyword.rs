@@ -7,7 +7,7 @@
 //! [spec]: https://www.ecma-international.org/ecma-262/#sec-keywords
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar#Keywords
 
-use crate::syntax::ast::op::{BinOp, CompOp};
+use crate::ast::op::{BinOp, CompOp};
 use std::{convert::TryInto, error, fmt, str::FromStr};
 
 #[cfg(feature = "deser")]
@@ -24,6 +24,7 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://www.ecma-international.org/ecma-262/#sec-keywords
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar#Keywords
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Keyword {
     /// The `await` keyword.
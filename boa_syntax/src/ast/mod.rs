@@ -0,0 +1,47 @@
+//! The Javascript Abstract Syntax Tree.
+
+pub mod constant;
+pub mod keyword;
+pub mod node;
+pub mod op;
+pub mod position;
+pub mod punctuator;
+
+pub use self::{
+    constant::Const,
+    keyword::Keyword,
+    node::Node,
+    position::{Position, Span},
+    punctuator::Punctuator,
+};
+
+/// The version of the serialized AST format.
+///
+/// It is bumped whenever a change to the AST nodes alters their serialized representation, so
+/// that tools consuming the output of the `deser` feature can detect incompatible dumps. The
+/// changes of each version are listed in `docs/debugging.md`.
+pub const AST_FORMAT_VERSION: u32 = 2;
+
+/// The envelope wrapping a serialized AST, with the version of its format.
+#[cfg(feature = "schema")]
+#[derive(schemars::JsonSchema)]
+#[allow(dead_code)]
+struct VersionedAst {
+    /// The version of the format of the AST.
+    version: u32,
+    /// The statements of the script or module.
+    ast: node::StatementList,
+}
+
+/// Generates the JSON schema of a serialized AST wrapped in its versioned envelope, of the form
+/// `{ "version": AST_FORMAT_VERSION, "ast": StatementList }`.
+#[cfg(feature = "schema")]
+pub fn versioned_ast_schema() -> schemars::schema::RootSchema {
+    use schemars::schema::Schema;
+
+    let mut root = schemars::schema_for!(VersionedAst);
+    if let Some(Schema::Object(version)) = root.schema.object().properties.get_mut("version") {
+        version.const_value = Some(AST_FORMAT_VERSION.into());
+    }
+    root
+}
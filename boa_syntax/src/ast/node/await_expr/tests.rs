@@ -0,0 +1,11 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        async function f() {
+            await function_call();
+            let a = await fn();
+        };
+        "#,
+    );
+}
@@ -2,13 +2,10 @@
 
 use super::{Node, StatementList};
 use crate::{
-    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
-    exec::Executable,
-    exec::InterpreterState,
+    ast::Span,
     gc::{Finalize, Trace},
-    BoaProfiler, Context, JsResult, JsValue,
 };
-use std::fmt;
+use std::{collections::HashSet, fmt};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
@@ -32,19 +29,30 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-BlockStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/block
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deser", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Block {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     statements: StatementList,
 }
 
 impl Block {
     /// Gets the list of statements and declarations in this block.
-    pub(crate) fn items(&self) -> &[Node] {
+    pub fn items(&self) -> &[Node] {
         self.statements.items()
     }
 
+    /// Gets the spans of the statements and declarations in this block, if it was parsed from
+    /// source code.
+    pub fn spans(&self) -> &[Span] {
+        self.statements.spans()
+    }
+
+    /// Gets the names of the `let` and `const` declarations in this block.
+    pub fn lexically_declared_names(&self) -> HashSet<&str> {
+        self.statements.lexically_declared_names()
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         writeln!(f, "{{")?;
@@ -53,53 +61,6 @@ impl Block {
     }
 }
 
-impl Executable for Block {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
-            let env = context.get_current_environment();
-            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-        }
-
-        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
-        // The return value is uninitialized, which means it defaults to Value::Undefined
-        let mut obj = JsValue::default();
-        for statement in self.items() {
-            obj = statement.run(context).map_err(|e| {
-                // No matter how control leaves the Block the LexicalEnvironment is always
-                // restored to its former state.
-                context.pop_environment();
-                e
-            })?;
-
-            match context.executor().get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
-
-                    // Early break.
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    // TODO, continue to a label
-                    break;
-                }
-                InterpreterState::Executing => {
-                    // Continue execution
-                }
-            }
-        }
-
-        // pop the block env
-        let _ = context.pop_environment();
-
-        Ok(obj)
-    }
-}
-
 impl<T> From<T> for Block
 where
     T: Into<StatementList>,
@@ -1,10 +1,5 @@
 use super::Node;
-use crate::{
-    exec::Executable,
-    exec::InterpreterState,
-    gc::{Finalize, Trace},
-    Context, JsResult, JsValue,
-};
+use crate::gc::{Finalize, Trace};
 use std::fmt;
 
 #[cfg(feature = "deser")]
@@ -28,6 +23,7 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-BreakStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/break
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Break {
     label: Option<Box<str>>,
@@ -51,16 +47,6 @@ impl Break {
     }
 }
 
-impl Executable for Break {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        context
-            .executor()
-            .set_current_state(InterpreterState::Break(self.label().map(Box::from)));
-
-        Ok(JsValue::undefined())
-    }
-}
-
 impl fmt::Display for Break {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -0,0 +1,16 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        outer: {
+            while (true) {
+                break outer;
+            }
+            skipped_call();
+        }
+        while (true) {
+            break;
+        }
+        "#,
+    );
+}
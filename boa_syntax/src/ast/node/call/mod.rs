@@ -0,0 +1,118 @@
+use crate::{
+    ast::node::{join_nodes, Node},
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// Calling the function actually performs the specified actions with the indicated parameters.
+///
+/// Defining a function does not execute it. Defining it simply names the function and
+/// specifies what to do when the function is called. Functions must be in scope when they are
+/// called, but the function declaration can be hoisted. The scope of a function is the
+/// function in which it is declared (or the entire program, if it is declared at the top
+/// level).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-CallExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Call {
+    expr: Box<Node>,
+    args: Box<[Node]>,
+}
+
+impl Call {
+    /// Creates a new `Call` AST node.
+    pub fn new<E, A>(expr: E, args: A) -> Self
+    where
+        E: Into<Node>,
+        A: Into<Box<[Node]>>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+            args: args.into(),
+        }
+    }
+
+    /// Gets the name of the function call.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Retrieves the arguments passed to the function.
+    pub fn args(&self) -> &[Node] {
+        &self.args
+    }
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.expr)?;
+        join_nodes(f, &self.args)?;
+        f.write_str(")")
+    }
+}
+
+impl From<Call> for Node {
+    fn from(call: Call) -> Self {
+        Self::Call(call)
+    }
+}
+
+/// The `super(...)` call of the constructor of a derived class, which constructs the object
+/// with the constructor of the parent class and binds it to `this`.
+///
+/// Until the parent class is called, `this` can not be used in the constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct SuperCall {
+    args: Box<[Node]>,
+}
+
+impl SuperCall {
+    /// Creates a new `SuperCall` AST node.
+    pub fn new<A>(args: A) -> Self
+    where
+        A: Into<Box<[Node]>>,
+    {
+        Self { args: args.into() }
+    }
+
+    /// Retrieves the arguments passed to the constructor of the parent class.
+    pub fn args(&self) -> &[Node] {
+        &self.args
+    }
+}
+
+impl fmt::Display for SuperCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("super(")?;
+        join_nodes(f, &self.args)?;
+        f.write_str(")")
+    }
+}
+
+impl From<SuperCall> for Node {
+    fn from(call: SuperCall) -> Self {
+        Self::SuperCall(call)
+    }
+}
@@ -1,8 +1,6 @@
 use crate::{
-    exec::Executable,
+    ast::node::Node,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -24,6 +22,7 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-ConditionalExpression
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Grammar_and_types#Literals
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ConditionalOp {
     condition: Box<Node>,
@@ -59,16 +58,6 @@ impl ConditionalOp {
     }
 }
 
-impl Executable for ConditionalOp {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        Ok(if self.cond().run(context)?.to_boolean() {
-            self.if_true().run(context)?
-        } else {
-            self.if_false().run(context)?
-        })
-    }
-}
-
 impl fmt::Display for ConditionalOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
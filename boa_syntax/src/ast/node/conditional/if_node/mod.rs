@@ -1,8 +1,6 @@
 use crate::{
-    exec::Executable,
+    ast::node::Node,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -26,6 +24,7 @@ use serde::{Deserialize, Serialize};
 /// [falsy]: https://developer.mozilla.org/en-US/docs/Glossary/falsy
 /// [expression]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Expressions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct If {
     cond: Box<Node>,
@@ -61,7 +60,7 @@ impl If {
         }
     }
 
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indent: usize,
@@ -78,18 +77,6 @@ impl If {
     }
 }
 
-impl Executable for If {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        Ok(if self.cond().run(context)?.to_boolean() {
-            self.body().run(context)?
-        } else if let Some(else_e) = self.else_node() {
-            else_e.run(context)?
-        } else {
-            JsValue::undefined()
-        })
-    }
-}
-
 impl fmt::Display for If {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -1,9 +1,6 @@
 use crate::{
-    builtins::function::FunctionFlags,
-    exec::Executable,
+    ast::node::{declaration::SourceText, join_nodes, FormalParameter, Node, StatementList},
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -24,15 +21,17 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-ArrowFunction
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ArrowFunctionDecl {
     params: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl ArrowFunctionDecl {
     /// Creates a new `ArrowFunctionDecl` AST node.
-    pub(in crate::syntax) fn new<P, B>(params: P, body: B) -> Self
+    pub fn new<P, B>(params: P, body: B) -> Self
     where
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
@@ -40,21 +39,41 @@ impl ArrowFunctionDecl {
         Self {
             params: params.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
     /// Gets the list of parameters of the arrow function.
-    pub(crate) fn params(&self) -> &[FormalParameter] {
+    pub fn params(&self) -> &[FormalParameter] {
         &self.params
     }
 
     /// Gets the body of the arrow function.
-    pub(crate) fn body(&self) -> &[Node] {
+    pub fn body(&self) -> &[Node] {
         self.body.items()
     }
 
+    /// Checks if the body of the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the function, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -71,17 +90,6 @@ impl ArrowFunctionDecl {
     }
 }
 
-impl Executable for ArrowFunctionDecl {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        context.create_function(
-            "",
-            self.params().to_vec(),
-            self.body().to_vec(),
-            FunctionFlags::LEXICAL_THIS_MODE,
-        )
-    }
-}
-
 impl fmt::Display for ArrowFunctionDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
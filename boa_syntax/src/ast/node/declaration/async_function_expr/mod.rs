@@ -1,10 +1,6 @@
 //! Async Function Expression.
 
-use crate::{
-    exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
-    Context, JsResult, JsValue,
-};
+use crate::ast::node::{declaration::SourceText, join_nodes, FormalParameter, Node, StatementList};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -21,16 +17,18 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct AsyncFunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl AsyncFunctionExpr {
     /// Creates a new function expression
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
         N: Into<Option<Box<str>>>,
         P: Into<Box<[FormalParameter]>>,
@@ -40,6 +38,7 @@ impl AsyncFunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -58,8 +57,27 @@ impl AsyncFunctionExpr {
         self.body.items()
     }
 
+    /// Checks if the body of the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the function, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -80,13 +98,6 @@ impl AsyncFunctionExpr {
     }
 }
 
-impl Executable for AsyncFunctionExpr {
-    fn run(&self, _: &mut Context) -> JsResult<JsValue> {
-        // TODO: Implement AsyncFunctionExpr
-        Ok(JsValue::undefined())
-    }
-}
-
 impl fmt::Display for AsyncFunctionExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
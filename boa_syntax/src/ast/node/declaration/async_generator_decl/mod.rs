@@ -0,0 +1,113 @@
+//! Async Generator Function Declaration.
+
+use crate::ast::node::{declaration::SourceText, join_nodes, FormalParameter, Node, StatementList};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `async function*` declaration defines an async generator function, which returns an
+/// `AsyncGenerator` object.
+///
+/// An async generator produces its values asynchronously: its body can `await` promises, and
+/// each request for a value returns a promise of the next iterator result.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncGeneratorDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function*
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncGeneratorDecl {
+    name: Option<Box<str>>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+    source_text: SourceText,
+}
+
+impl AsyncGeneratorDecl {
+    /// Creates a new async generator function declaration.
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+            source_text: SourceText::default(),
+        }
+    }
+
+    /// Gets the name of the async generator function declaration.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the list of parameters of the async generator function declaration.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the async generator function declaration.
+    pub fn body(&self) -> &[Node] {
+        self.body.items()
+    }
+
+    /// Checks if the body of the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the function, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "async function* {}(", name)?,
+            None => write!(f, "async function* (")?,
+        }
+        join_nodes(f, &self.parameters)?;
+        if self.body().is_empty() {
+            f.write_str(") {}")
+        } else {
+            f.write_str(") {\n")?;
+            self.body.display(f, indentation + 1)?;
+            write!(f, "{}}}", "    ".repeat(indentation))
+        }
+    }
+}
+
+impl From<AsyncGeneratorDecl> for Node {
+    fn from(decl: AsyncGeneratorDecl) -> Self {
+        Self::AsyncGeneratorDecl(decl)
+    }
+}
+
+impl fmt::Display for AsyncGeneratorDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
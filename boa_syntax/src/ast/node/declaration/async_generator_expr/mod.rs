@@ -0,0 +1,111 @@
+//! Async Generator Function Expression.
+
+use crate::ast::node::{declaration::SourceText, join_nodes, FormalParameter, Node, StatementList};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// An async generator function expression is very similar to an async generator function
+/// declaration except used within a wider expression (for example during an assignment).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncGeneratorExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function*
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncGeneratorExpr {
+    name: Option<Box<str>>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+    source_text: SourceText,
+}
+
+impl AsyncGeneratorExpr {
+    /// Creates a new async generator function expression.
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+            source_text: SourceText::default(),
+        }
+    }
+
+    /// Gets the name of the async generator function expression.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the list of parameters of the async generator function expression.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the async generator function expression.
+    pub fn body(&self) -> &[Node] {
+        self.body.items()
+    }
+
+    /// Checks if the body of the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the function, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("async function*")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        f.write_str("(")?;
+        join_nodes(f, &self.parameters)?;
+        if self.body().is_empty() {
+            f.write_str(") {}")
+        } else {
+            f.write_str(") {\n")?;
+            self.body.display(f, indentation + 1)?;
+            write!(f, "{}}}", "    ".repeat(indentation))
+        }
+    }
+}
+
+impl fmt::Display for AsyncGeneratorExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<AsyncGeneratorExpr> for Node {
+    fn from(expr: AsyncGeneratorExpr) -> Self {
+        Self::AsyncGeneratorExpr(expr)
+    }
+}
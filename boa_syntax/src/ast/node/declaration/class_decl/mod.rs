@@ -0,0 +1,284 @@
+use crate::{
+    ast::node::{
+        declaration::SourceText, join_nodes, FunctionExpr, MethodDefinitionKind, Node,
+        PropertyName, StatementList,
+    },
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// A class declaration or expression.
+///
+/// Classes are a template for creating objects: the class evaluates to its constructor, whose
+/// `prototype` holds the methods of the instances. A class body is always strict mode code.
+///
+/// The private names declared by the class, like `#field`, are only visible in its body.
+///
+/// A class with a heritage, like `class A extends B {}`, is a derived class, whose instances
+/// are constructed by the parent class with the `super(...)` call of its constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Class {
+    name: Option<Box<str>>,
+    heritage: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+    source_text: SourceText,
+}
+
+impl Class {
+    /// Creates a new class.
+    pub fn new<N, C, E>(name: N, constructor: C, elements: E) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            heritage: None,
+            constructor: constructor.into(),
+            elements: elements.into(),
+            source_text: SourceText::default(),
+        }
+    }
+
+    /// Gets the name of the class.
+    ///
+    /// Only class expressions and the declaration of a default export can be anonymous.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the expression of the parent class, after `extends`, if the class is derived.
+    pub fn heritage(&self) -> Option<&Node> {
+        self.heritage.as_deref()
+    }
+
+    /// Sets the expression of the parent class, making the class derived.
+    pub fn with_heritage<H>(mut self, heritage: H) -> Self
+    where
+        H: Into<Node>,
+    {
+        self.heritage = Some(Box::new(heritage.into()));
+        self
+    }
+
+    /// Gets the `constructor` method of the class, if it has one.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the elements of the class body, in the order they were written.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Gets the source text of the class, if it was parsed from source code.
+    ///
+    /// It is also the source text of its constructor.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the class, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("class")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        if let Some(ref heritage) = self.heritage {
+            write!(f, " extends {}", heritage)?;
+        }
+        if self.constructor.is_none() && self.elements.is_empty() {
+            return f.write_str(" {}");
+        }
+
+        f.write_str(" {\n")?;
+        let indent = "    ".repeat(indentation + 1);
+        if let Some(ref constructor) = self.constructor {
+            write!(f, "{}constructor(", indent)?;
+            join_nodes(f, constructor.parameters())?;
+            f.write_str(") ")?;
+            constructor.display_block(f, indentation + 1)?;
+            f.write_str("\n")?;
+        }
+        for element in self.elements.iter() {
+            f.write_str(&indent)?;
+            element.display(f, indentation + 1)?;
+            f.write_str("\n")?;
+        }
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// An element of a class body, besides its constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes#class_body_and_method_definitions
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ClassElement {
+    /// A method, getter or setter of the instances, defined on the prototype of the class.
+    MethodDefinition(PropertyName, MethodDefinitionKind, FunctionExpr),
+
+    /// A `static` method, getter or setter, defined on the constructor.
+    StaticMethodDefinition(PropertyName, MethodDefinitionKind, FunctionExpr),
+
+    /// A private method, getter or setter of the instances, like `#method() {}`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields#private_methods
+    PrivateMethodDefinition(Box<str>, MethodDefinitionKind, FunctionExpr),
+
+    /// A private `static` method, getter or setter, which only the constructor has.
+    PrivateStaticMethodDefinition(Box<str>, MethodDefinitionKind, FunctionExpr),
+
+    /// A private field of the instances, like `#field = value;`, added to each instance when it
+    /// is constructed.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+    PrivateFieldDefinition(Box<str>, Option<Node>),
+
+    /// A private `static` field, added to the constructor when the class is defined.
+    PrivateStaticFieldDefinition(Box<str>, Option<Node>),
+
+    /// A field of the instances, like `field = value;`, defined on each instance when it is
+    /// constructed.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Public_class_fields
+    FieldDefinition(PropertyName, Option<Node>),
+
+    /// A `static` field, defined on the constructor when the class is defined.
+    StaticFieldDefinition(PropertyName, Option<Node>),
+
+    /// A `static { }` block, run with the constructor as `this` when the class is defined.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Class_static_initialization_blocks
+    StaticBlock(StatementList),
+}
+
+impl ClassElement {
+    /// Implements the display formatting with indentation, without indenting the element
+    /// itself.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        match self {
+            Self::MethodDefinition(name, kind, function) => {
+                display_method(f, &name.to_string(), *kind, function, indentation)
+            }
+            Self::StaticMethodDefinition(name, kind, function) => {
+                f.write_str("static ")?;
+                display_method(f, &name.to_string(), *kind, function, indentation)
+            }
+            Self::PrivateMethodDefinition(name, kind, function) => {
+                display_method(f, &format!("#{}", name), *kind, function, indentation)
+            }
+            Self::PrivateStaticMethodDefinition(name, kind, function) => {
+                f.write_str("static ")?;
+                display_method(f, &format!("#{}", name), *kind, function, indentation)
+            }
+            Self::PrivateFieldDefinition(name, init) => {
+                write!(f, "#{}", name)?;
+                display_initializer(f, init.as_ref(), indentation)
+            }
+            Self::PrivateStaticFieldDefinition(name, init) => {
+                write!(f, "static #{}", name)?;
+                display_initializer(f, init.as_ref(), indentation)
+            }
+            Self::FieldDefinition(name, init) => {
+                write!(f, "{}", name)?;
+                display_initializer(f, init.as_ref(), indentation)
+            }
+            Self::StaticFieldDefinition(name, init) => {
+                write!(f, "static {}", name)?;
+                display_initializer(f, init.as_ref(), indentation)
+            }
+            Self::StaticBlock(body) => {
+                if body.items().is_empty() {
+                    return f.write_str("static {}");
+                }
+                f.write_str("static {\n")?;
+                body.display(f, indentation + 1)?;
+                write!(f, "{}}}", "    ".repeat(indentation))
+            }
+        }
+    }
+}
+
+/// Displays a method of a class body, after its `static` keyword.
+fn display_method(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    kind: MethodDefinitionKind,
+    function: &FunctionExpr,
+    indentation: usize,
+) -> fmt::Result {
+    match kind {
+        MethodDefinitionKind::Get => f.write_str("get ")?,
+        MethodDefinitionKind::Set => f.write_str("set ")?,
+        MethodDefinitionKind::Ordinary => {}
+    }
+    write!(f, "{}(", name)?;
+    join_nodes(f, function.parameters())?;
+    f.write_str(") ")?;
+    function.display_block(f, indentation)
+}
+
+/// Displays the initializer of a field, if any, and the semicolon ending the field.
+fn display_initializer(
+    f: &mut fmt::Formatter<'_>,
+    init: Option<&Node>,
+    indentation: usize,
+) -> fmt::Result {
+    if let Some(init) = init {
+        f.write_str(" = ")?;
+        init.display_no_indent(f, indentation)?;
+    }
+    f.write_str(";")
+}
@@ -1,10 +1,6 @@
 use crate::{
-    builtins::function::FunctionFlags,
-    environment::lexical_environment::VariableScope,
-    exec::Executable,
+    ast::node::{declaration::SourceText, join_nodes, FormalParameter, Node, StatementList},
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
-    BoaProfiler, Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -30,18 +26,20 @@ use serde::{Deserialize, Serialize};
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function
 /// [func_expr]: ../enum.Node.html#variant.FunctionExpr
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct FunctionDecl {
-    name: Box<str>,
+    name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl FunctionDecl {
     /// Creates a new function declaration.
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<Option<Box<str>>>,
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
     {
@@ -49,12 +47,15 @@ impl FunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
     /// Gets the name of the function declaration.
-    pub fn name(&self) -> &str {
-        &self.name
+    ///
+    /// Only the declaration of a default export can be anonymous.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
     }
 
     /// Gets the list of parameters of the function declaration.
@@ -67,13 +68,35 @@ impl FunctionDecl {
         self.body.items()
     }
 
+    /// Checks if the body of the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the function, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        write!(f, "function {}(", self.name)?;
+        match &self.name {
+            Some(name) => write!(f, "function {}(", name)?,
+            None => write!(f, "function (")?,
+        }
         join_nodes(f, &self.parameters)?;
         if self.body().is_empty() {
             f.write_str(") {}")
@@ -85,31 +108,6 @@ impl FunctionDecl {
     }
 }
 
-impl Executable for FunctionDecl {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
-        let val = context.create_function(
-            self.name(),
-            self.parameters().to_vec(),
-            self.body().to_vec(),
-            FunctionFlags::CONSTRUCTABLE,
-        )?;
-
-        if context.has_binding(self.name()) {
-            context.set_mutable_binding(self.name(), val, true)?;
-        } else {
-            context.create_mutable_binding(
-                self.name().to_owned(),
-                false,
-                VariableScope::Function,
-            )?;
-
-            context.initialize_binding(self.name(), val)?;
-        }
-        Ok(JsValue::undefined())
-    }
-}
-
 impl From<FunctionDecl> for Node {
     fn from(decl: FunctionDecl) -> Self {
         Self::FunctionDecl(decl)
@@ -1,9 +1,6 @@
 use crate::{
-    builtins::function::FunctionFlags,
-    exec::Executable,
+    ast::node::{declaration::SourceText, join_nodes, FormalParameter, Node, StatementList},
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -27,16 +24,18 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-terms-and-definitions-function
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct FunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl FunctionExpr {
     /// Creates a new function expression
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
         N: Into<Option<Box<str>>>,
         P: Into<Box<[FormalParameter]>>,
@@ -46,6 +45,7 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -64,8 +64,27 @@ impl FunctionExpr {
         self.body.items()
     }
 
+    /// Checks if the body of the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
+    /// Gets the source text of the function, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_str()
+    }
+
+    /// Sets the source text of the function, as it was written in the parsed source code.
+    pub fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = SourceText::new(source_text);
+        self
+    }
+
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -82,7 +101,7 @@ impl FunctionExpr {
 
     /// Displays the function's body. This includes the curly braces at the start and end.
     /// This will not indent the first brace, but will indent the last brace.
-    pub(in crate::syntax::ast::node) fn display_block(
+    pub(in crate::ast::node) fn display_block(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -97,19 +116,6 @@ impl FunctionExpr {
     }
 }
 
-impl Executable for FunctionExpr {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let val = context.create_function(
-            self.name().unwrap_or(""),
-            self.parameters().to_vec(),
-            self.body().to_vec(),
-            FunctionFlags::CONSTRUCTABLE,
-        )?;
-
-        Ok(val)
-    }
-}
-
 impl fmt::Display for FunctionExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
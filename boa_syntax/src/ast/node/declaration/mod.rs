@@ -1,11 +1,7 @@
 //! Declaration nodes
 use crate::{
-    builtins::{iterable::get_iterator, Array},
-    environment::lexical_environment::VariableScope,
-    exec::Executable,
+    ast::node::{join_nodes, Identifier, Node},
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Identifier, Node},
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -15,19 +11,62 @@ use serde::{Deserialize, Serialize};
 pub mod arrow_function_decl;
 pub mod async_function_decl;
 pub mod async_function_expr;
+pub mod async_generator_decl;
+pub mod async_generator_expr;
+pub mod class_decl;
 pub mod function_decl;
 pub mod function_expr;
+pub mod generator_decl;
+pub mod generator_expr;
 
 pub use self::{
-    arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
-    async_function_expr::AsyncFunctionExpr, function_decl::FunctionDecl,
+    arrow_function_decl::ArrowFunctionDecl,
+    async_function_decl::AsyncFunctionDecl,
+    async_function_expr::AsyncFunctionExpr,
+    async_generator_decl::AsyncGeneratorDecl,
+    async_generator_expr::AsyncGeneratorExpr,
+    class_decl::{Class, ClassElement},
+    function_decl::FunctionDecl,
     function_expr::FunctionExpr,
+    generator_decl::GeneratorDecl,
+    generator_expr::GeneratorExpr,
 };
 
 #[cfg(test)]
 mod tests;
 
+/// The source text of a function, as it was written in the parsed source code.
+///
+/// It is what `Function.prototype.toString` returns, but it is not part of the structure of the
+/// function, so it is ignored when comparing nodes.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, Trace, Finalize)]
+pub(in crate::ast::node) struct SourceText(Option<Box<str>>);
+
+impl SourceText {
+    /// Creates the source text of a function.
+    pub(in crate::ast::node) fn new<S>(text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        Self(Some(text.into()))
+    }
+
+    /// Gets the source text, if the function was parsed from source code.
+    pub(in crate::ast::node) fn as_str(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+}
+
+impl PartialEq for SourceText {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum DeclarationList {
     /// The `const` statements are block-scoped, much like variables defined using the `let`
@@ -90,93 +129,15 @@ pub enum DeclarationList {
     Var(Box<[Declaration]>),
 }
 
-impl Executable for DeclarationList {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        for decl in self.as_ref() {
-            use DeclarationList::*;
-            let val = match decl.init() {
-                None if self.is_const() => {
-                    return context.throw_syntax_error("missing = in const declaration")
-                }
-                Some(init) => init.run(context)?,
-                None => JsValue::undefined(),
-            };
-
-            match &decl {
-                Declaration::Identifier { ident, init } => {
-                    if self.is_var() && context.has_binding(ident.as_ref()) {
-                        if init.is_some() {
-                            context.set_mutable_binding(ident.as_ref(), val, true)?;
-                        }
-                        continue;
-                    }
-
-                    match &self {
-                        Const(_) => context.create_immutable_binding(
-                            ident.to_string(),
-                            false,
-                            VariableScope::Block,
-                        )?,
-                        Let(_) => context.create_mutable_binding(
-                            ident.to_string(),
-                            false,
-                            VariableScope::Block,
-                        )?,
-                        Var(_) => context.create_mutable_binding(
-                            ident.to_string(),
-                            false,
-                            VariableScope::Function,
-                        )?,
-                    }
-
-                    context.initialize_binding(ident.as_ref(), val)?;
-                }
-                Declaration::Pattern(p) => {
-                    for (ident, value) in p.run(None, context)? {
-                        if self.is_var() && context.has_binding(ident.as_ref()) {
-                            if !value.is_undefined() {
-                                context.set_mutable_binding(ident.as_ref(), value, true)?;
-                            }
-                            continue;
-                        }
-
-                        match &self {
-                            Const(_) => context.create_immutable_binding(
-                                ident.to_string(),
-                                false,
-                                VariableScope::Block,
-                            )?,
-                            Let(_) => context.create_mutable_binding(
-                                ident.to_string(),
-                                false,
-                                VariableScope::Block,
-                            )?,
-                            Var(_) => context.create_mutable_binding(
-                                ident.to_string(),
-                                false,
-                                VariableScope::Function,
-                            )?,
-                        }
-
-                        context.initialize_binding(ident.as_ref(), value)?;
-                    }
-                }
-            }
-        }
-
-        Ok(JsValue::undefined())
-    }
-}
-
 impl DeclarationList {
     #[allow(dead_code)]
-    pub(in crate::syntax) fn is_let(&self) -> bool {
+    pub fn is_let(&self) -> bool {
         matches!(self, Self::Let(_))
     }
-    pub(in crate::syntax) fn is_const(&self) -> bool {
+    pub fn is_const(&self) -> bool {
         matches!(self, Self::Const(_))
     }
-    pub(in crate::syntax) fn is_var(&self) -> bool {
+    pub fn is_var(&self) -> bool {
         matches!(self, Self::Var(_))
     }
 }
@@ -236,6 +197,7 @@ impl From<Declaration> for Box<[Declaration]> {
 /// [spec2]: https://tc39.es/ecma262/#prod-VariableDeclaration
 /// [spec3]:  https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum Declaration {
     Identifier {
@@ -265,7 +227,7 @@ impl fmt::Display for Declaration {
 impl Declaration {
     /// Creates a new variable declaration with a BindingIdentifier.
     #[inline]
-    pub(in crate::syntax) fn new_with_identifier<N, I>(ident: N, init: I) -> Self
+    pub fn new_with_identifier<N, I>(ident: N, init: I) -> Self
     where
         N: Into<Identifier>,
         I: Into<Option<Node>>,
@@ -278,10 +240,7 @@ impl Declaration {
 
     /// Creates a new variable declaration with an ObjectBindingPattern.
     #[inline]
-    pub(in crate::syntax) fn new_with_object_pattern<I>(
-        bindings: Vec<BindingPatternTypeObject>,
-        init: I,
-    ) -> Self
+    pub fn new_with_object_pattern<I>(bindings: Vec<BindingPatternTypeObject>, init: I) -> Self
     where
         I: Into<Option<Node>>,
     {
@@ -293,10 +252,7 @@ impl Declaration {
 
     /// Creates a new variable declaration with an ArrayBindingPattern.
     #[inline]
-    pub(in crate::syntax) fn new_with_array_pattern<I>(
-        bindings: Vec<BindingPatternTypeArray>,
-        init: I,
-    ) -> Self
+    pub fn new_with_array_pattern<I>(bindings: Vec<BindingPatternTypeArray>, init: I) -> Self
     where
         I: Into<Option<Node>>,
     {
@@ -308,7 +264,7 @@ impl Declaration {
 
     /// Gets the initialization node for the declaration, if any.
     #[inline]
-    pub(crate) fn init(&self) -> Option<&Node> {
+    pub fn init(&self) -> Option<&Node> {
         match &self {
             Self::Identifier { init, .. } => init.as_ref(),
             Self::Pattern(pattern) => pattern.init(),
@@ -325,6 +281,7 @@ impl Declaration {
 ///
 /// [spec1]: https://tc39.es/ecma262/#prod-BindingPattern
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum DeclarationPattern {
     Object(DeclarationPatternObject),
@@ -346,22 +303,6 @@ impl fmt::Display for DeclarationPattern {
 }
 
 impl DeclarationPattern {
-    /// Initialize the values of an object/array binding pattern.
-    ///
-    /// This function only calls the specific initialization function for either the object or the array binding pattern.
-    /// For specific documentation and references to the ECMAScript spec, look at the called initialization functions.
-    #[inline]
-    pub(in crate::syntax) fn run(
-        &self,
-        init: Option<JsValue>,
-        context: &mut Context,
-    ) -> JsResult<Vec<(Box<str>, JsValue)>> {
-        match &self {
-            DeclarationPattern::Object(pattern) => pattern.run(init, context),
-            DeclarationPattern::Array(pattern) => pattern.run(init, context),
-        }
-    }
-
     /// Gets the list of identifiers declared by the binding pattern.
     ///
     /// A single binding pattern may declare 0 to n identifiers.
@@ -392,6 +333,7 @@ impl DeclarationPattern {
 ///
 /// [spec1]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct DeclarationPatternObject {
     bindings: Vec<BindingPatternTypeObject>,
@@ -419,139 +361,25 @@ impl fmt::Display for DeclarationPatternObject {
 impl DeclarationPatternObject {
     /// Create a new object binding pattern.
     #[inline]
-    pub(in crate::syntax) fn new(
-        bindings: Vec<BindingPatternTypeObject>,
-        init: Option<Node>,
-    ) -> Self {
+    pub fn new(bindings: Vec<BindingPatternTypeObject>, init: Option<Node>) -> Self {
         Self { bindings, init }
     }
 
     /// Gets the initialization node for the object binding pattern, if any.
     #[inline]
-    pub(in crate::syntax) fn init(&self) -> Option<&Node> {
+    pub fn init(&self) -> Option<&Node> {
         self.init.as_ref()
     }
 
-    /// Initialize the values of an object binding pattern.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference: 8.5.2 Runtime Semantics: BindingInitialization][spec1]
-    ///  - [ECMAScript reference:14.3.3.3 Runtime Semantics: KeyedBindingInitialization][spec2]
-    ///  - [ECMAScript reference:14.3.3.2 Runtime Semantics: RestBindingInitialization][spec3]
-    ///
-    /// [spec1]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
-    /// [spec2]: https://tc39.es/ecma262/#sec-runtime-semantics-keyedbindinginitialization
-    /// [spec3]:  https://tc39.es/ecma262/#sec-destructuring-binding-patterns-runtime-semantics-restbindinginitialization
-    pub(in crate::syntax) fn run(
-        &self,
-        init: Option<JsValue>,
-        context: &mut Context,
-    ) -> JsResult<Vec<(Box<str>, JsValue)>> {
-        let value = if let Some(value) = init {
-            value
-        } else if let Some(node) = &self.init {
-            node.run(context)?
-        } else {
-            JsValue::undefined()
-        };
-
-        if value.is_null() {
-            return Err(context.construct_type_error("Cannot destructure 'null' value"));
-        }
-        if value.is_undefined() {
-            return Err(context.construct_type_error("Cannot destructure 'undefined' value"));
-        }
-
-        // 1. Perform ? RequireObjectCoercible(value).
-        let value = value.require_object_coercible(context)?;
-        let mut results = Vec::new();
-
-        // 2. Return the result of performing BindingInitialization for ObjectBindingPattern using value and environment as arguments.
-        for binding in &self.bindings {
-            use BindingPatternTypeObject::*;
-
-            match binding {
-                // ObjectBindingPattern : { }
-                Empty => {
-                    // 1. Return NormalCompletion(empty).
-                }
-                //  SingleNameBinding : BindingIdentifier Initializer[opt]
-                SingleName {
-                    ident,
-                    property_name,
-                    default_init,
-                } => {
-                    // 1. Let bindingId be StringValue of BindingIdentifier.
-                    // 2. Let lhs be ? ResolveBinding(bindingId, environment).
-
-                    // 3. Let v be ? GetV(value, propertyName).
-                    let mut v = value.get_field(property_name.as_ref(), context)?;
-
-                    // 4. If Initializer is present and v is undefined, then
-                    if let Some(init) = default_init {
-                        if v.is_undefined() {
-                            // TODO: a. not implemented yet:
-                            // a. If IsAnonymousFunctionDefinition(Initializer) is true, then
-                            // i. Set v to the result of performing NamedEvaluation for Initializer with argument bindingId.
-
-                            // b. Else,
-                            // i. Let defaultValue be the result of evaluating Initializer.
-                            // ii. Set v to ? GetValue(defaultValue).
-                            v = init.run(context)?;
-                        }
-                    }
-
-                    // 5. If environment is undefined, return ? PutValue(lhs, v).
-                    // 6. Return InitializeReferencedBinding(lhs, v).
-                    results.push((ident.clone(), v));
-                }
-                //  BindingRestProperty : ... BindingIdentifier
-                RestProperty {
-                    ident,
-                    excluded_keys,
-                } => {
-                    // 1. Let lhs be ? ResolveBinding(StringValue of BindingIdentifier, environment).
-
-                    // 2. Let restObj be ! OrdinaryObjectCreate(%Object.prototype%).
-                    let mut rest_obj = context.construct_object();
-
-                    // 3. Perform ? CopyDataProperties(restObj, value, excludedNames).
-                    rest_obj.copy_data_properties(value, excluded_keys.clone(), context)?;
-
-                    // 4. If environment is undefined, return PutValue(lhs, restObj).
-                    // 5. Return InitializeReferencedBinding(lhs, restObj).
-                    results.push((ident.clone(), rest_obj.into()));
-                }
-                //  BindingElement : BindingPattern Initializer[opt]
-                BindingPattern {
-                    ident,
-                    pattern,
-                    default_init,
-                } => {
-                    // 1. Let v be ? GetV(value, propertyName).
-                    let mut v = value.get_field(ident.as_ref(), context)?;
-
-                    // 2. If Initializer is present and v is undefined, then
-                    if let Some(init) = default_init {
-                        if v.is_undefined() {
-                            // a. Let defaultValue be the result of evaluating Initializer.
-                            // b. Set v to ? GetValue(defaultValue).
-                            v = init.run(context)?;
-                        }
-                    }
-
-                    // 3. Return the result of performing BindingInitialization for BindingPattern passing v and environment as arguments.
-                    results.append(&mut pattern.run(Some(v), context)?);
-                }
-            }
-        }
-
-        Ok(results)
+    /// Gets the bindings of the object binding pattern.
+    #[inline]
+    pub fn bindings(&self) -> &[BindingPatternTypeObject] {
+        &self.bindings
     }
 
     /// Gets the list of identifiers declared by the object binding pattern.
     #[inline]
-    pub(in crate::syntax) fn idents(&self) -> Vec<&str> {
+    pub fn idents(&self) -> Vec<&str> {
         let mut idents = Vec::new();
 
         for binding in &self.bindings {
@@ -581,6 +409,7 @@ impl DeclarationPatternObject {
                         idents.push(ident);
                     }
                 }
+                AssignmentPropertyAccess { .. } | AssignmentRestProperty { .. } => {}
             }
         }
 
@@ -597,6 +426,7 @@ impl DeclarationPatternObject {
 ///
 /// [spec1]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct DeclarationPatternArray {
     bindings: Vec<BindingPatternTypeArray>,
@@ -627,218 +457,25 @@ impl fmt::Display for DeclarationPatternArray {
 impl DeclarationPatternArray {
     /// Create a new array binding pattern.
     #[inline]
-    pub(in crate::syntax) fn new(
-        bindings: Vec<BindingPatternTypeArray>,
-        init: Option<Node>,
-    ) -> Self {
+    pub fn new(bindings: Vec<BindingPatternTypeArray>, init: Option<Node>) -> Self {
         Self { bindings, init }
     }
 
     /// Gets the initialization node for the array binding pattern, if any.
     #[inline]
-    pub(in crate::syntax) fn init(&self) -> Option<&Node> {
+    pub fn init(&self) -> Option<&Node> {
         self.init.as_ref()
     }
 
-    /// Initialize the values of an array binding pattern.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference: 8.5.2 Runtime Semantics: BindingInitialization][spec1]
-    ///  - [ECMAScript reference: 8.5.3 Runtime Semantics: IteratorBindingInitialization][spec2]
-    ///
-    /// [spec1]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
-    /// [spec2]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
-    pub(in crate::syntax) fn run(
-        &self,
-        init: Option<JsValue>,
-        context: &mut Context,
-    ) -> JsResult<Vec<(Box<str>, JsValue)>> {
-        let value = if let Some(value) = init {
-            value
-        } else if let Some(node) = &self.init {
-            node.run(context)?
-        } else {
-            JsValue::undefined()
-        };
-
-        if value.is_null() {
-            return Err(context.construct_type_error("Cannot destructure 'null' value"));
-        }
-        if value.is_undefined() {
-            return Err(context.construct_type_error("Cannot destructure 'undefined' value"));
-        }
-
-        // 1. Let iteratorRecord be ? GetIterator(value).
-        let iterator = get_iterator(context, value)?;
-        let mut result = Vec::new();
-
-        // 2. Let result be IteratorBindingInitialization of ArrayBindingPattern with arguments iteratorRecord and environment.
-        for binding in &self.bindings {
-            use BindingPatternTypeArray::*;
-
-            match binding {
-                // ArrayBindingPattern : [ ]
-                Empty => {
-                    // 1. Return NormalCompletion(empty).
-                }
-                // ArrayBindingPattern : [ Elision ]
-                // Note: This captures all elisions due to our representation of a the binding pattern.
-                Elision => {
-                    // 1. If iteratorRecord.[[Done]] is false, then
-                    // a. Let next be IteratorStep(iteratorRecord).
-                    // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                    // c. ReturnIfAbrupt(next).
-                    // d. If next is false, set iteratorRecord.[[Done]] to true.
-                    let _ = iterator.next(context)?;
-
-                    // 2. Return NormalCompletion(empty).
-                }
-                // SingleNameBinding : BindingIdentifier Initializer[opt]
-                SingleName {
-                    ident,
-                    default_init,
-                } => {
-                    // 1. Let bindingId be StringValue of BindingIdentifier.
-                    // 2. Let lhs be ? ResolveBinding(bindingId, environment).
-
-                    let next = iterator.next(context)?;
-
-                    // 3. If iteratorRecord.[[Done]] is false, then
-                    // 4. If iteratorRecord.[[Done]] is true, let v be undefined.
-                    let mut v = if !next.is_done() {
-                        // a. Let next be IteratorStep(iteratorRecord).
-                        // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // c. ReturnIfAbrupt(next).
-                        // d. If next is false, set iteratorRecord.[[Done]] to true.
-                        // e. Else,
-                        // i. Let v be IteratorValue(next).
-                        // ii. If v is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // iii. ReturnIfAbrupt(v).
-                        next.value()
-                    } else {
-                        JsValue::undefined()
-                    };
-
-                    // 5. If Initializer is present and v is undefined, then
-                    if let Some(init) = default_init {
-                        if v.is_undefined() {
-                            // TODO: a. not implemented yet:
-                            // a. If IsAnonymousFunctionDefinition(Initializer) is true, then
-                            // i. Set v to the result of performing NamedEvaluation for Initializer with argument bindingId.
-
-                            // b. Else,
-                            // i. Let defaultValue be the result of evaluating Initializer.
-                            // ii. Set v to ? GetValue(defaultValue).
-                            v = init.run(context)?
-                        }
-                    }
-
-                    // 6. If environment is undefined, return ? PutValue(lhs, v).
-                    // 7. Return InitializeReferencedBinding(lhs, v).
-                    result.push((ident.clone(), v));
-                }
-                // BindingElement : BindingPattern Initializer[opt]
-                BindingPattern { pattern } => {
-                    let next = iterator.next(context)?;
-
-                    // 1. If iteratorRecord.[[Done]] is false, then
-                    // 2. If iteratorRecord.[[Done]] is true, let v be undefined.
-                    let v = if !next.is_done() {
-                        // a. Let next be IteratorStep(iteratorRecord).
-                        // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // c. ReturnIfAbrupt(next).
-                        // d. If next is false, set iteratorRecord.[[Done]] to true.
-                        // e. Else,
-                        // i. Let v be IteratorValue(next).
-                        // ii. If v is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // iii. ReturnIfAbrupt(v).
-                        Some(next.value())
-                    } else {
-                        None
-                    };
-
-                    // 3. If Initializer is present and v is undefined, then
-                    // a. Let defaultValue be the result of evaluating Initializer.
-                    // b. Set v to ? GetValue(defaultValue).
-
-                    // 4. Return the result of performing BindingInitialization of BindingPattern with v and environment as the arguments.
-                    result.append(&mut pattern.run(v, context)?);
-                }
-                // BindingRestElement : ... BindingIdentifier
-                SingleNameRest { ident } => {
-                    // 1. Let lhs be ? ResolveBinding(StringValue of BindingIdentifier, environment).
-                    // 2. Let A be ! ArrayCreate(0).
-                    // 3. Let n be 0.
-                    let a = Array::array_create(0, None, context)
-                        .expect("Array creation with 0 length should never fail");
-
-                    // 4. Repeat,
-                    loop {
-                        let next = iterator.next(context)?;
-                        // a. If iteratorRecord.[[Done]] is false, then
-                        // i. Let next be IteratorStep(iteratorRecord).
-                        // ii. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // iii. ReturnIfAbrupt(next).
-                        // iv. If next is false, set iteratorRecord.[[Done]] to true.
-
-                        // b. If iteratorRecord.[[Done]] is true, then
-                        if next.is_done() {
-                            // i. If environment is undefined, return ? PutValue(lhs, A).
-                            // ii. Return InitializeReferencedBinding(lhs, A).
-                            break result.push((ident.clone(), a.clone().into()));
-                        }
-
-                        // c. Let nextValue be IteratorValue(next).
-                        // d. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // e. ReturnIfAbrupt(nextValue).
-
-                        // f. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), nextValue).
-                        // g. Set n to n + 1.
-                        Array::add_to_array_object(&a.clone().into(), &[next.value()], context)?;
-                    }
-                }
-                // BindingRestElement : ... BindingPattern
-                BindingPatternRest { pattern } => {
-                    // 1. Let A be ! ArrayCreate(0).
-                    // 2. Let n be 0.
-                    let a = Array::array_create(0, None, context)
-                        .expect("Array creation with 0 length should never fail");
-
-                    // 3. Repeat,
-                    loop {
-                        // a. If iteratorRecord.[[Done]] is false, then
-                        // i. Let next be IteratorStep(iteratorRecord).
-                        // ii. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // iii. ReturnIfAbrupt(next).
-                        // iv. If next is false, set iteratorRecord.[[Done]] to true.
-                        let next = iterator.next(context)?;
-
-                        // b. If iteratorRecord.[[Done]] is true, then
-                        if next.is_done() {
-                            // i. Return the result of performing BindingInitialization of BindingPattern with A and environment as the arguments.
-                            break result
-                                .append(&mut pattern.run(Some(a.clone().into()), context)?);
-                        }
-
-                        // c. Let nextValue be IteratorValue(next).
-                        // d. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
-                        // e. ReturnIfAbrupt(nextValue).
-                        // f. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), nextValue).
-                        // g. Set n to n + 1.
-                        Array::add_to_array_object(&a.clone().into(), &[next.value()], context)?;
-                    }
-                }
-            }
-        }
-
-        // 3. If iteratorRecord.[[Done]] is false, return ? IteratorClose(iteratorRecord, result).
-        // 4. Return result.
-        Ok(result)
+    /// Gets the bindings of the array binding pattern.
+    #[inline]
+    pub fn bindings(&self) -> &[BindingPatternTypeArray] {
+        &self.bindings
     }
 
     /// Gets the list of identifiers declared by the array binding pattern.
     #[inline]
-    pub(in crate::syntax) fn idents(&self) -> Vec<&str> {
+    pub fn idents(&self) -> Vec<&str> {
         let mut idents = Vec::new();
 
         for binding in &self.bindings {
@@ -858,6 +495,7 @@ impl DeclarationPatternArray {
                     idents.append(&mut i)
                 }
                 SingleNameRest { ident } => idents.push(ident),
+                PropertyAccess { .. } | PropertyAccessRest { .. } => {}
             }
         }
 
@@ -872,6 +510,7 @@ impl DeclarationPatternArray {
 ///
 /// [spec1]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum BindingPatternTypeObject {
     /// Empty represents an empty object binding pattern e.g. `{ }`.
@@ -922,6 +561,35 @@ pub enum BindingPatternTypeObject {
         pattern: DeclarationPattern,
         default_init: Option<Node>,
     },
+
+    /// AssignmentPropertyAccess represents an `AssignmentProperty` whose target is a property access,
+    /// with an optional default initializer.
+    ///
+    /// It only occurs in the assignment patterns of destructuring assignments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference: 13.15.5 Destructuring Assignment - AssignmentProperty][spec1]
+    ///
+    /// [spec1]: https://tc39.es/ecma262/#prod-AssignmentProperty
+    AssignmentPropertyAccess {
+        property_name: Box<str>,
+        access: Node,
+        default_init: Option<Node>,
+    },
+
+    /// AssignmentRestProperty represents an `AssignmentRestProperty` whose target is a property access.
+    ///
+    /// It also includes a list of the property keys that should be excluded from the rest,
+    /// because they where already assigned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference: 13.15.5 Destructuring Assignment - AssignmentRestProperty][spec1]
+    ///
+    /// [spec1]: https://tc39.es/ecma262/#prod-AssignmentRestProperty
+    AssignmentRestProperty {
+        access: Node,
+        excluded_keys: Vec<Box<str>>,
+    },
 }
 
 impl fmt::Display for BindingPatternTypeObject {
@@ -958,6 +626,19 @@ impl fmt::Display for BindingPatternTypeObject {
                     write!(f, " = {}", init)?;
                 }
             }
+            BindingPatternTypeObject::AssignmentPropertyAccess {
+                property_name,
+                access,
+                default_init,
+            } => {
+                write!(f, " {} : {}", property_name, access)?;
+                if let Some(ref init) = default_init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+            BindingPatternTypeObject::AssignmentRestProperty { access, .. } => {
+                write!(f, " ... {}", access)?;
+            }
         }
         Ok(())
     }
@@ -970,6 +651,7 @@ impl fmt::Display for BindingPatternTypeObject {
 ///
 /// [spec1]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum BindingPatternTypeArray {
     /// Empty represents an empty array binding pattern e.g. `[ ]`.
@@ -1030,6 +712,28 @@ pub enum BindingPatternTypeArray {
     ///
     /// [spec1]: https://tc39.es/ecma262/#prod-BindingRestElement
     BindingPatternRest { pattern: DeclarationPattern },
+
+    /// PropertyAccess represents an `AssignmentElement` whose target is a property access,
+    /// with an optional default initializer.
+    ///
+    /// It only occurs in the assignment patterns of destructuring assignments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference: 13.15.5 Destructuring Assignment - AssignmentElement][spec1]
+    ///
+    /// [spec1]: https://tc39.es/ecma262/#prod-AssignmentElement
+    PropertyAccess {
+        access: Node,
+        default_init: Option<Node>,
+    },
+
+    /// PropertyAccessRest represents an `AssignmentRestElement` whose target is a property access.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference: 13.15.5 Destructuring Assignment - AssignmentRestElement][spec1]
+    ///
+    /// [spec1]: https://tc39.es/ecma262/#prod-AssignmentRestElement
+    PropertyAccessRest { access: Node },
 }
 
 impl fmt::Display for BindingPatternTypeArray {
@@ -1057,6 +761,18 @@ impl fmt::Display for BindingPatternTypeArray {
             BindingPatternTypeArray::BindingPatternRest { pattern } => {
                 write!(f, " ... {}", pattern)?;
             }
+            BindingPatternTypeArray::PropertyAccess {
+                access,
+                default_init,
+            } => {
+                write!(f, " {}", access)?;
+                if let Some(ref init) = default_init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+            BindingPatternTypeArray::PropertyAccessRest { access } => {
+                write!(f, " ... {}", access)?;
+            }
         }
         Ok(())
     }
@@ -1,16 +1,3 @@
-use crate::exec;
-
-#[test]
-fn duplicate_function_name() {
-    let scenario = r#"
-    function f () {}
-    function f () {return 12;}
-    f()
-    "#;
-
-    assert_eq!(&exec(scenario), "12");
-}
-
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -19,6 +6,15 @@ fn fmt() {
             console.log(a);
         };
         function func_2(a, b) {};
+        function* gen_func(a, b) {
+            yield a;
+        };
+        function* gen_func_2(a, b) {};
+        async function* async_gen_func(a, b) {
+            for await (const x of a) {
+                yield x;
+            };
+        };
         let arrow_func = (a, b) => {
             console.log("in multi statement arrow");
             console.log(b);
@@ -35,6 +31,13 @@ fn fmt() {
         let arrow_func_2 = (a, b) => {};
         async function async_func_2(a, b) {};
         pass_async_func(async function(a, b) {});
+        pass_gen_func(function*(a, b) {
+            yield* b;
+        });
+        pass_gen_func(function*(a, b) {});
+        pass_async_gen_func(async function*(a, b) {
+            yield await b;
+        });
         pass_func(function(a, b) {});
         "#,
     );
@@ -80,3 +83,47 @@ fn fmt_binding_pattern() {
         "#,
     );
 }
+
+#[test]
+fn fmt_class() {
+    super::super::test_formatting(
+        r#"
+        class Empty {};
+        class Counter {
+            constructor(start) {
+                this.#count = start;
+            }
+            #count = 0;
+            static #instances;
+            get count() {
+                return this.#count;
+            }
+            set #value(v) {
+                this.#count = v;
+            }
+            static [Symbol.iterator]() {}
+            static #reset(counter) {
+                counter.#count = 0;
+            }
+            has(o) {
+                return #count in o && o?.#count;
+            }
+            step = 1;
+            static ["max"] = 10;
+            static {
+                this.created = 0;
+            }
+            static {}
+        };
+        let Anonymous = class {
+            m() {}
+        };
+        class Derived extends Counter {
+            constructor(start, ...rest) {
+                super(start, ...rest);
+            }
+        };
+        let Orphan = class extends null {};
+        "#,
+    );
+}
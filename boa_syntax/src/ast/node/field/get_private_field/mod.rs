@@ -0,0 +1,64 @@
+use crate::{
+    ast::node::Node,
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// This property accessor provides access to a private member of an object, like
+/// `object.#field`.
+///
+/// A private name can only be used in the body of the class that declares it, and the access
+/// throws a `TypeError` if the object does not have the private member.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetPrivateField {
+    obj: Box<Node>,
+    field: Box<str>,
+}
+
+impl GetPrivateField {
+    /// Creates a `GetPrivateField` AST node.
+    pub fn new<V, L>(value: V, label: L) -> Self
+    where
+        V: Into<Node>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            obj: Box::new(value.into()),
+            field: label.into(),
+        }
+    }
+
+    /// Gets the original object from where to get the field from.
+    pub fn obj(&self) -> &Node {
+        &self.obj
+    }
+
+    /// Gets the private name of the field to retrieve, without its `#`.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+}
+
+impl fmt::Display for GetPrivateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.#{}", self.obj(), self.field())
+    }
+}
+
+impl From<GetPrivateField> for Node {
+    fn from(get_private_field: GetPrivateField) -> Self {
+        Self::GetPrivateField(get_private_field)
+    }
+}
@@ -1,10 +1,8 @@
 //! Local identifier node.
 
 use crate::{
-    exec::Executable,
+    ast::node::Node,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
-    BoaProfiler, Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -27,19 +25,13 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-Identifier
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Identifier
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deser", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Identifier {
     ident: Box<str>,
 }
 
-impl Executable for Identifier {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("Identifier", "exec");
-        context.get_binding_value(self.as_ref())
-    }
-}
-
 impl fmt::Display for Identifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(&self.ident, f)
@@ -1,8 +1,6 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    ast::node::Node,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -23,6 +21,7 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-ContinueStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/continue
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Continue {
     label: Option<Box<str>>,
@@ -45,16 +44,6 @@ impl Continue {
     }
 }
 
-impl Executable for Continue {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        context
-            .executor()
-            .set_current_state(InterpreterState::Continue(self.label().map(Box::from)));
-
-        Ok(JsValue::undefined())
-    }
-}
-
 impl fmt::Display for Continue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "continue")?;
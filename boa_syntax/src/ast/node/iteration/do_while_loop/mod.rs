@@ -1,8 +1,6 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    ast::node::Node,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -22,6 +20,7 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-do-while-statement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/do...while
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct DoWhileLoop {
     body: Box<Node>,
@@ -59,7 +58,7 @@ impl DoWhileLoop {
         }
     }
 
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -73,34 +72,6 @@ impl DoWhileLoop {
     }
 }
 
-impl Executable for DoWhileLoop {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let mut result;
-        loop {
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
-            }
-            if !self.cond().run(context)?.to_boolean() {
-                break;
-            }
-        }
-        Ok(result)
-    }
-}
-
 impl fmt::Display for DoWhileLoop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
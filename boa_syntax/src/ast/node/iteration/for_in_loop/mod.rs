@@ -0,0 +1,74 @@
+use crate::{
+    ast::node::{iteration::IterableLoopInitializer, Node},
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForInLoop {
+    variable: Box<IterableLoopInitializer>,
+    expr: Box<Node>,
+    body: Box<Node>,
+    label: Option<Box<str>>,
+}
+
+impl ForInLoop {
+    pub fn new<V, I, B>(variable: V, expr: I, body: B) -> Self
+    where
+        V: Into<IterableLoopInitializer>,
+        I: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            variable: Box::new(variable.into()),
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+            label: None,
+        }
+    }
+
+    pub fn variable(&self) -> &IterableLoopInitializer {
+        &self.variable
+    }
+
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(ref label) = self.label {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "for ({} in {}) ", self.variable, self.expr)?;
+        self.body().display(f, indentation)
+    }
+}
+
+impl fmt::Display for ForInLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForInLoop> for Node {
+    fn from(for_in: ForInLoop) -> Node {
+        Self::ForInLoop(for_in)
+    }
+}
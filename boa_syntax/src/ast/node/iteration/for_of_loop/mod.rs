@@ -0,0 +1,97 @@
+use crate::{
+    ast::node::{iteration::IterableLoopInitializer, Node},
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForOfLoop {
+    variable: Box<IterableLoopInitializer>,
+    iterable: Box<Node>,
+    body: Box<Node>,
+    label: Option<Box<str>>,
+    is_await: bool,
+}
+
+impl ForOfLoop {
+    pub fn new<V, I, B>(variable: V, iterable: I, body: B) -> Self
+    where
+        V: Into<IterableLoopInitializer>,
+        I: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            variable: Box::new(variable.into()),
+            iterable: Box::new(iterable.into()),
+            body: Box::new(body.into()),
+            label: None,
+            is_await: false,
+        }
+    }
+
+    /// Creates a `for await (… of …)` loop, which iterates over an async iterable.
+    pub fn new_await<V, I, B>(variable: V, iterable: I, body: B) -> Self
+    where
+        V: Into<IterableLoopInitializer>,
+        I: Into<Node>,
+        B: Into<Node>,
+    {
+        let mut for_of = Self::new(variable, iterable, body);
+        for_of.is_await = true;
+        for_of
+    }
+
+    pub fn variable(&self) -> &IterableLoopInitializer {
+        &self.variable
+    }
+
+    pub fn iterable(&self) -> &Node {
+        &self.iterable
+    }
+
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    /// Checks if this is a `for await (… of …)` loop.
+    pub fn is_await(&self) -> bool {
+        self.is_await
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(ref label) = self.label {
+            write!(f, "{}: ", label)?;
+        }
+        f.write_str("for ")?;
+        if self.is_await {
+            f.write_str("await ")?;
+        }
+        write!(f, "({} of {}) ", self.variable, self.iterable)?;
+        self.body().display(f, indentation)
+    }
+}
+
+impl fmt::Display for ForOfLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForOfLoop> for Node {
+    fn from(for_of: ForOfLoop) -> Node {
+        Self::ForOfLoop(for_of)
+    }
+}
@@ -0,0 +1,67 @@
+//! Iteration nodes
+
+use crate::{
+    ast::node::{
+        declaration::{Declaration, DeclarationPattern},
+        GetConstField, GetField, Identifier,
+    },
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+pub use self::{
+    continue_node::Continue, do_while_loop::DoWhileLoop, for_in_loop::ForInLoop, for_loop::ForLoop,
+    for_of_loop::ForOfLoop, while_loop::WhileLoop,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub mod continue_node;
+pub mod do_while_loop;
+pub mod for_in_loop;
+pub mod for_loop;
+pub mod for_of_loop;
+pub mod while_loop;
+
+/// The head of a `for (… in …)` or `for (… of …)` loop, which receives each of the values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForInOfStatement
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum IterableLoopInitializer {
+    Identifier(Identifier),
+    GetConstField(GetConstField),
+    GetField(GetField),
+    Var(Declaration),
+    Let(Declaration),
+    Const(Declaration),
+    DeclarationPattern(DeclarationPattern),
+}
+
+impl fmt::Display for IterableLoopInitializer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(ident) => fmt::Display::fmt(ident, f),
+            Self::GetConstField(field) => fmt::Display::fmt(field, f),
+            Self::GetField(field) => fmt::Display::fmt(field, f),
+            Self::Var(declaration) => write!(f, "var {}", declaration),
+            Self::Let(declaration) => write!(f, "let {}", declaration),
+            Self::Const(declaration) => write!(f, "const {}", declaration),
+            Self::DeclarationPattern(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<Identifier> for IterableLoopInitializer {
+    fn from(ident: Identifier) -> Self {
+        Self::Identifier(ident)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    ast::node::Node,
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// A labelled statement can be the target of `break` statements using its label.
+///
+/// Syntax: `label: statement`
+///
+/// Labelled iteration statements don't use this node, their label is set on the loop node
+/// instead, so that `continue` statements can target them as well.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-LabelledStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Labelled {
+    label: Box<str>,
+    statement: Box<Node>,
+}
+
+impl Labelled {
+    /// Gets the label of the statement.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the labelled statement.
+    pub fn statement(&self) -> &Node {
+        &self.statement
+    }
+
+    /// Creates a `Labelled` AST node.
+    pub fn new<L, S>(label: L, statement: S) -> Self
+    where
+        L: Into<Box<str>>,
+        S: Into<Node>,
+    {
+        Self {
+            label: label.into(),
+            statement: Box::new(statement.into()),
+        }
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "{}: ", self.label)?;
+        self.statement.display_no_indent(f, indentation)
+    }
+}
+
+impl fmt::Display for Labelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<Labelled> for Node {
+    fn from(labelled: Labelled) -> Node {
+        Self::Labelled(labelled)
+    }
+}
@@ -0,0 +1,12 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        outer: {
+            inner: if (true) {
+                break outer;
+            }
+        }
+        "#,
+    );
+}
@@ -10,9 +10,12 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod labelled;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
@@ -20,24 +23,32 @@ pub mod switch;
 pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod with;
+pub mod yield_expr;
 
 pub use self::{
     array::ArrayDecl,
     await_expr::AwaitExpr,
     block::Block,
     break_node::Break,
-    call::Call,
+    call::{Call, SuperCall},
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList,
-        FunctionDecl, FunctionExpr,
+        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, AsyncGeneratorDecl,
+        AsyncGeneratorExpr, Class, ClassElement, Declaration, DeclarationList, DeclarationPattern,
+        FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr,
     },
-    field::{GetConstField, GetField},
+    field::{GetConstField, GetField, GetPrivateField, GetSuperField},
     identifier::Identifier,
-    iteration::{Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    iteration::{
+        Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, WhileLoop,
+    },
+    labelled::Labelled,
+    module::{ExportDecl, ExportSpecifier, ImportCall, ImportDecl, ImportSpecifier},
     new::New,
     object::Object,
-    operator::{Assign, BinOp, UnaryOp},
+    operator::{Assign, AssignTarget, BinOp, PrivateIn, UnaryOp},
+    optional::{Optional, OptionalItem, OptionalItemKind},
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
@@ -45,13 +56,11 @@ pub use self::{
     template::{TaggedTemplate, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    with::With,
+    yield_expr::YieldExpr,
 };
 use super::Const;
-use crate::{
-    exec::Executable,
-    gc::{empty_trace, Finalize, Trace},
-    BoaProfiler, Context, JsResult, JsValue,
-};
+use crate::gc::{empty_trace, Finalize, Trace};
 use std::{
     cmp::Ordering,
     fmt::{self, Display},
@@ -62,6 +71,7 @@ use serde::{Deserialize, Serialize};
 
 // TODO: This should be split into Expression and Statement.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum Node {
     /// Array declaration node. [More information](./array/struct.ArrayDecl.html).
@@ -79,6 +89,12 @@ pub enum Node {
     /// An async function expression node. [More information](./declaration/struct.AsyncFunctionExpr.html).
     AsyncFunctionExpr(AsyncFunctionExpr),
 
+    /// An async generator function declaration node. [More information](./declaration/struct.AsyncGeneratorDecl.html).
+    AsyncGeneratorDecl(AsyncGeneratorDecl),
+
+    /// An async generator function expression node. [More information](./declaration/struct.AsyncGeneratorExpr.html).
+    AsyncGeneratorExpr(AsyncGeneratorExpr),
+
     /// An await expression node. [More information](./await_expr/struct.AwaitExpression.html).
     AwaitExpr(AwaitExpr),
 
@@ -94,6 +110,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration node. [More information](./declaration/struct.Class.html).
+    ClassDecl(Class),
+
+    /// A class expression node. [More information](./declaration/struct.Class.html).
+    ClassExpr(Class),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -124,12 +146,27 @@ pub enum Node {
     /// A function expression node. [More information](./declaration/struct.FunctionExpr.html).
     FunctionExpr(FunctionExpr),
 
+    /// A generator function declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator function expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// Provides access to an object types' constant properties. [More information](./declaration/struct.GetConstField.html).
     GetConstField(GetConstField),
 
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to the private members of an object. [More information](./field/struct.GetPrivateField.html).
+    GetPrivateField(GetPrivateField),
+
+    /// Provides access to the fields of the prototype of a method's home object. [More information](./field/struct.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
+    /// The `super(...)` call of a derived class constructor. [More information](./call/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
@@ -139,6 +176,9 @@ pub enum Node {
     /// A `for...of` statement. [More information](./iteration/struct.ForOf.html).
     ForOfLoop(ForOfLoop),
 
+    /// An `export` declaration. [More information](./module/enum.ExportDecl.html).
+    ExportDecl(ExportDecl),
+
     /// An 'if' statement. [More information](./conditional/struct.If.html).
     If(If),
 
@@ -148,12 +188,35 @@ pub enum Node {
     /// A local identifier node. [More information](./identifier/struct.Identifier.html).
     Identifier(Identifier),
 
+    /// An `import()` call. [More information](./module/struct.ImportCall.html).
+    ImportCall(ImportCall),
+
+    /// An `import` declaration. [More information](./module/struct.ImportDecl.html).
+    ImportDecl(ImportDecl),
+
     /// A `new` expression. [More information](./expression/struct.New.html).
     New(New),
 
+    /// The `new.target` meta property, which refers to the constructor that `new` was invoked on
+    /// inside of functions, and is `undefined` in plain function calls.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-NewTarget
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new.target
+    NewTarget,
+
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
+    /// A private brand check, like `#x in obj`. [More information](./operator/struct.PrivateIn.html).
+    PrivateIn(PrivateIn),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -198,6 +261,15 @@ pub enum Node {
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
 
+    /// A labelled statement. [More information](./labelled/struct.Labelled.html).
+    Labelled(Labelled),
+
+    /// A `with` statement. [More information](./with/struct.With.html).
+    With(With),
+
+    /// A yield expression node. [More information](./yield_expr/struct.YieldExpr.html).
+    YieldExpr(YieldExpr),
+
     /// A empty node.
     ///
     /// Empty statement do nothing, just return undefined.
@@ -225,11 +297,23 @@ impl From<Const> for Node {
 
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
-    pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
+    pub fn hoistable_order(a: &Node, b: &Node) -> Ordering {
+        let is_hoistable = |node: &Node| {
+            let node = match node {
+                Node::ExportDecl(decl) => match decl.declaration() {
+                    Some(decl) => decl,
+                    None => return false,
+                },
+                node => node,
+            };
+            matches!(
+                node,
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncGeneratorDecl(_)
+            )
+        };
+        match (is_hoistable(a), is_hoistable(b)) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
 
             (_, _) => Ordering::Equal,
         }
@@ -277,10 +361,17 @@ impl Node {
             Self::Block(ref block) => block.display(f, indentation),
             Self::Identifier(ref s) => Display::fmt(s, f),
             Self::New(ref expr) => Display::fmt(expr, f),
+            Self::NewTarget => write!(f, "new.target"),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetPrivateField(ref get_private_field) => Display::fmt(get_private_field, f),
+            Self::GetSuperField(ref get_super_field) => Display::fmt(get_super_field, f),
+            Self::SuperCall(ref call) => Display::fmt(call, f),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
+            Self::Labelled(ref labelled) => labelled.display(f, indentation),
+            Self::With(ref with) => with.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
@@ -289,8 +380,12 @@ impl Node {
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::ClassDecl(ref class) | Self::ClassExpr(ref class) => {
+                class.display(f, indentation)
+            }
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
+            Self::PrivateIn(ref op) => Display::fmt(op, f),
             Self::Return(ref ret) => Display::fmt(ret, f),
             Self::TaggedTemplate(ref template) => Display::fmt(template, f),
             Self::TemplateLit(ref template) => Display::fmt(template, f),
@@ -300,73 +395,20 @@ impl Node {
             Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::AsyncGeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncGeneratorExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => Display::fmt(expr, f),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
+            Self::YieldExpr(ref expr) => Display::fmt(expr, f),
+            Self::ImportCall(ref call) => Display::fmt(call, f),
+            Self::ImportDecl(ref decl) => Display::fmt(decl, f),
+            Self::ExportDecl(ref decl) => decl.display(f, indentation),
             Self::Empty => write!(f, ";"),
         }
     }
 }
 
-impl Executable for Node {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("Executable", "exec");
-        match *self {
-            Node::AsyncFunctionDecl(ref decl) => decl.run(context),
-            Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
-            Node::AwaitExpr(ref expr) => expr.run(context),
-            Node::Call(ref call) => call.run(context),
-            Node::Const(Const::Null) => Ok(JsValue::null()),
-            Node::Const(Const::Num(num)) => Ok(JsValue::new(num)),
-            Node::Const(Const::Int(num)) => Ok(JsValue::new(num)),
-            Node::Const(Const::BigInt(ref num)) => Ok(JsValue::new(num.clone())),
-            Node::Const(Const::Undefined) => Ok(JsValue::undefined()),
-            // we can't move String from Const into value, because const is a garbage collected value
-            // Which means Drop() get's called on Const, but str will be gone at that point.
-            // Do Const values need to be garbage collected? We no longer need them once we've generated Values
-            Node::Const(Const::String(ref value)) => Ok(JsValue::new(value.to_string())),
-            Node::Const(Const::Bool(value)) => Ok(JsValue::new(value)),
-            Node::Block(ref block) => block.run(context),
-            Node::Identifier(ref identifier) => identifier.run(context),
-            Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(context),
-            Node::GetField(ref get_field) => get_field.run(context),
-            Node::WhileLoop(ref while_loop) => while_loop.run(context),
-            Node::DoWhileLoop(ref do_while) => do_while.run(context),
-            Node::ForLoop(ref for_loop) => for_loop.run(context),
-            Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(context),
-            Node::ForInLoop(ref for_in_loop) => for_in_loop.run(context),
-            Node::If(ref if_smt) => if_smt.run(context),
-            Node::ConditionalOp(ref op) => op.run(context),
-            Node::Switch(ref switch) => switch.run(context),
-            Node::Object(ref obj) => obj.run(context),
-            Node::ArrayDecl(ref arr) => arr.run(context),
-            // <https://tc39.es/ecma262/#sec-createdynamicfunction>
-            Node::FunctionDecl(ref decl) => decl.run(context),
-            // <https://tc39.es/ecma262/#sec-createdynamicfunction>
-            Node::FunctionExpr(ref function_expr) => function_expr.run(context),
-            Node::ArrowFunctionDecl(ref decl) => decl.run(context),
-            Node::BinOp(ref op) => op.run(context),
-            Node::UnaryOp(ref op) => op.run(context),
-            Node::New(ref call) => call.run(context),
-            Node::Return(ref ret) => ret.run(context),
-            Node::TaggedTemplate(ref template) => template.run(context),
-            Node::TemplateLit(ref template) => template.run(context),
-            Node::Throw(ref throw) => throw.run(context),
-            Node::Assign(ref op) => op.run(context),
-            Node::VarDeclList(ref decl) => decl.run(context),
-            Node::LetDeclList(ref decl) => decl.run(context),
-            Node::ConstDeclList(ref decl) => decl.run(context),
-            Node::Spread(ref spread) => spread.run(context),
-            Node::This => {
-                // Will either return `this` binding or undefined
-                context.get_this_binding()
-            }
-            Node::Try(ref try_node) => try_node.run(context),
-            Node::Break(ref break_node) => break_node.run(context),
-            Node::Continue(ref continue_node) => continue_node.run(context),
-            Node::Empty => Ok(JsValue::undefined()),
-        }
-    }
-}
-
 /// Utility to join multiple Nodes into a single string.
 fn join_nodes<N>(f: &mut fmt::Formatter<'_>, nodes: &[N]) -> fmt::Result
 where
@@ -399,40 +441,60 @@ where
 /// [spec]: https://tc39.es/ecma262/#prod-FormalParameter
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Errors/Missing_formal_parameter
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
-    init: Option<Node>,
+    declaration: Declaration,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
-    /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    /// Creates a new formal parameter binding an identifier.
+    pub fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
     where
         N: Into<Box<str>>,
     {
         Self {
-            name: name.into(),
-            init,
+            declaration: Declaration::new_with_identifier(name.into(), init),
+            is_rest_param,
+        }
+    }
+
+    /// Creates a new formal parameter destructuring its argument with a binding pattern.
+    pub fn new_with_pattern(pattern: DeclarationPattern, is_rest_param: bool) -> Self {
+        Self {
+            declaration: Declaration::Pattern(pattern),
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the names bound by the formal parameter.
+    pub fn names(&self) -> Vec<&str> {
+        match &self.declaration {
+            Declaration::Identifier { ident, .. } => vec![ident.as_ref()],
+            Declaration::Pattern(pattern) => pattern.idents(),
+        }
+    }
+
+    /// Gets the declaration of the formal parameter.
+    pub fn declaration(&self) -> &Declaration {
+        &self.declaration
     }
 
     /// Gets the initialization node of the formal parameter, if any.
     pub fn init(&self) -> Option<&Node> {
-        self.init.as_ref()
+        self.declaration.init()
     }
 
     /// Gets wether the parameter is a rest parameter.
     pub fn is_rest_param(&self) -> bool {
         self.is_rest_param
     }
+
+    /// Checks if the parameter is a single identifier, without a binding pattern.
+    pub fn is_identifier(&self) -> bool {
+        matches!(&self.declaration, Declaration::Identifier { .. })
+    }
 }
 
 impl Display for FormalParameter {
@@ -440,11 +502,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
-        if let Some(n) = self.init.as_ref() {
-            write!(f, " = {}", n)?;
-        }
-        Ok(())
+        write!(f, "{}", self.declaration)
     }
 }
 
@@ -462,6 +520,7 @@ impl Display for FormalParameter {
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/property/JavaScript
 // TODO: Support all features: https://tc39.es/ecma262/#prod-PropertyDefinition
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub enum PropertyDefinition {
     /// Puts a variable into an object.
@@ -506,6 +565,17 @@ pub enum PropertyDefinition {
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Spread_properties
     SpreadObject(Node),
+
+    /// A shorthand property with an initializer, like `{ a = 1 }`.
+    ///
+    /// This is only valid in object literals that are reinterpreted as assignment patterns,
+    /// where it gives a default value to the assigned identifier.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverInitializedName
+    CoverInitializedName(Box<str>, Node),
 }
 
 impl PropertyDefinition {
@@ -555,6 +625,7 @@ impl PropertyDefinition {
 /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Copy, Finalize)]
 pub enum MethodDefinitionKind {
     /// The `get` syntax binds an object property to a function that will be called when that property is looked up.
@@ -604,6 +675,42 @@ unsafe impl Trace for MethodDefinitionKind {
     empty_trace!();
 }
 
+/// The name of a method or a field of a class, which can be computed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum PropertyName {
+    /// A literal name, like `name`, `"name"` or `1`, as the string it is converted to.
+    Literal(Box<str>),
+
+    /// A computed name, like `[expression]`, which is converted to a property key when the
+    /// class is defined.
+    Computed(Node),
+}
+
+impl Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(name) => f.write_str(name),
+            Self::Computed(expr) => write!(f, "[{}]", expr),
+        }
+    }
+}
+
+impl<T> From<T> for PropertyName
+where
+    T: Into<Box<str>>,
+{
+    fn from(name: T) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
 /// This parses the given source code, and then makes sure that
 /// the resulting StatementList is formatted in the same manner
 /// as the source code. This is expected to have a preceding
@@ -628,7 +735,12 @@ fn test_formatting(source: &'static str) {
         .map(|l| &l[characters_to_remove..]) // Remove preceding whitespace from each line
         .collect::<Vec<&'static str>>()
         .join("\n");
-    let result = format!("{}", crate::parse(&scenario, false).unwrap());
+    let result = format!(
+        "{}",
+        crate::Parser::new(scenario.as_bytes(), false)
+            .parse_all()
+            .unwrap()
+    );
     if scenario != result {
         eprint!("========= Expected:\n{}", scenario);
         eprint!("========= Got:\n{}", result);
@@ -0,0 +1,365 @@
+//! Module item nodes: the `import` and `export` declarations.
+
+use crate::{
+    ast::node::Node,
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `import` declaration binds the values exported by another module.
+///
+/// Syntax: `import defaultBinding, * as namespace from "module";` or
+/// `import defaultBinding, { name, other as alias } from "module";`
+///
+/// An import declaration without bindings, like `import "module";`, only loads and evaluates the
+/// module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    default: Option<Box<str>>,
+    namespace: Option<Box<str>>,
+    specifiers: Box<[ImportSpecifier]>,
+    module: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates a new `import` declaration.
+    pub fn new<D, N, S, M>(default: D, namespace: N, specifiers: S, module: M) -> Self
+    where
+        D: Into<Option<Box<str>>>,
+        N: Into<Option<Box<str>>>,
+        S: Into<Box<[ImportSpecifier]>>,
+        M: Into<Box<str>>,
+    {
+        Self {
+            default: default.into(),
+            namespace: namespace.into(),
+            specifiers: specifiers.into(),
+            module: module.into(),
+        }
+    }
+
+    /// Gets the local name bound to the default export of the module, if any.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Gets the local name bound to the namespace object of the module, if any.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Gets the named imports of the declaration.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Gets the local names bound by the declaration.
+    pub fn bound_names(&self) -> impl Iterator<Item = &str> {
+        self.default()
+            .into_iter()
+            .chain(self.namespace())
+            .chain(self.specifiers.iter().map(ImportSpecifier::local))
+    }
+}
+
+impl From<ImportDecl> for Node {
+    fn from(decl: ImportDecl) -> Self {
+        Self::ImportDecl(decl)
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("import ")?;
+        let mut clauses = Vec::new();
+        if let Some(default) = &self.default {
+            clauses.push(default.to_string());
+        }
+        if let Some(namespace) = &self.namespace {
+            clauses.push(format!("* as {}", namespace));
+        }
+        if !self.specifiers.is_empty() {
+            let specifiers: Vec<_> = self.specifiers.iter().map(ToString::to_string).collect();
+            clauses.push(format!("{{ {} }}", specifiers.join(", ")));
+        }
+        if !clauses.is_empty() {
+            write!(f, "{} from ", clauses.join(", "))?;
+        }
+        write!(f, "\"{}\"", self.module)
+    }
+}
+
+/// The `import()` call loads a module dynamically, returning a promise for its namespace object.
+///
+/// Unlike `import` declarations, it can be used in scripts as well as in modules.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportCall {
+    specifier: Box<Node>,
+}
+
+impl ImportCall {
+    /// Creates a new `import()` call.
+    pub fn new<S>(specifier: S) -> Self
+    where
+        S: Into<Node>,
+    {
+        Self {
+            specifier: Box::new(specifier.into()),
+        }
+    }
+
+    /// Gets the expression of the specifier of the imported module.
+    pub fn specifier(&self) -> &Node {
+        &self.specifier
+    }
+}
+
+impl From<ImportCall> for Node {
+    fn from(call: ImportCall) -> Self {
+        Self::ImportCall(call)
+    }
+}
+
+impl fmt::Display for ImportCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "import({})", self.specifier)
+    }
+}
+
+/// A named import of an `import` declaration, like `name` or `name as alias`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    imported: Box<str>,
+    local: Box<str>,
+}
+
+impl ImportSpecifier {
+    /// Creates a new import specifier.
+    pub fn new<I, L>(imported: I, local: L) -> Self
+    where
+        I: Into<Box<str>>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            imported: imported.into(),
+            local: local.into(),
+        }
+    }
+
+    /// Gets the name exported by the imported module.
+    pub fn imported(&self) -> &str {
+        &self.imported
+    }
+
+    /// Gets the local name bound to the import.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.imported == self.local {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.imported, self.local)
+        }
+    }
+}
+
+/// The `export` declaration makes bindings of a module available to the modules importing it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// Exports the bindings of a declaration under their own names, like
+    /// `export const a = 1;` or `export function f() {}`.
+    Declaration(Box<Node>),
+
+    /// Exports local bindings, like `export { a, b as c };`.
+    List(Box<[ExportSpecifier]>),
+
+    /// Exports a function declaration as the default export, like
+    /// `export default function () {}`.
+    ///
+    /// The declaration is anonymous if it has no name of its own.
+    DefaultDeclaration(Box<Node>),
+
+    /// Exports the value of an expression as the default export, like `export default a + b;`.
+    DefaultExpression(Box<Node>),
+
+    /// Exports bindings of another module, like `export { a, b as c } from "module";`.
+    ///
+    /// The local names of the specifiers are the names exported by the other module.
+    ReExport {
+        specifiers: Box<[ExportSpecifier]>,
+        module: Box<str>,
+    },
+
+    /// Exports all the named exports of another module, like `export * from "module";`, or
+    /// its namespace object, like `export * as ns from "module";`.
+    StarReExport {
+        namespace: Option<Box<str>>,
+        module: Box<str>,
+    },
+}
+
+impl ExportDecl {
+    /// Gets the declaration exported by this node, if any.
+    pub fn declaration(&self) -> Option<&Node> {
+        match self {
+            Self::Declaration(decl) | Self::DefaultDeclaration(decl) => Some(decl),
+            Self::List(_)
+            | Self::DefaultExpression(_)
+            | Self::ReExport { .. }
+            | Self::StarReExport { .. } => None,
+        }
+    }
+
+    /// Gets the specifier of the module whose exports are re-exported, if any.
+    pub fn module(&self) -> Option<&str> {
+        match self {
+            Self::ReExport { module, .. } | Self::StarReExport { module, .. } => Some(module),
+            _ => None,
+        }
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match self {
+            Self::Declaration(decl) => {
+                f.write_str("export ")?;
+                decl.display_no_indent(f, indentation)
+            }
+            Self::List(specifiers) => {
+                let specifiers: Vec<_> = specifiers.iter().map(ToString::to_string).collect();
+                write!(f, "export {{ {} }}", specifiers.join(", "))
+            }
+            Self::DefaultDeclaration(decl) | Self::DefaultExpression(decl) => {
+                f.write_str("export default ")?;
+                decl.display_no_indent(f, indentation)
+            }
+            Self::ReExport { specifiers, module } => {
+                let specifiers: Vec<_> = specifiers.iter().map(ToString::to_string).collect();
+                write!(
+                    f,
+                    "export {{ {} }} from \"{}\"",
+                    specifiers.join(", "),
+                    module
+                )
+            }
+            Self::StarReExport { namespace, module } => match namespace {
+                Some(namespace) => write!(f, "export * as {} from \"{}\"", namespace, module),
+                None => write!(f, "export * from \"{}\"", module),
+            },
+        }
+    }
+}
+
+impl From<ExportDecl> for Node {
+    fn from(decl: ExportDecl) -> Self {
+        Self::ExportDecl(decl)
+    }
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// A named export of an `export` declaration, like `name` or `name as alias`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local: Box<str>,
+    exported: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates a new export specifier.
+    pub fn new<L, E>(local: L, exported: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local: local.into(),
+            exported: exported.into(),
+        }
+    }
+
+    /// Gets the name of the exported local binding, or of the re-exported export of another
+    /// module.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// Gets the name the binding is exported as.
+    pub fn exported(&self) -> &str {
+        &self.exported
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local == self.exported {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.local, self.exported)
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Parser;
+
+#[test]
+fn fmt() {
+    let module = r#"import "a";
+import b from "b";
+import c, * as d from "c";
+import { e, f as g } from "e";
+export const h = 1;
+export function i() {};
+export { h as j, i };
+export default function () {};
+export default 1 + 2;
+export { m, n as o } from "m";
+export * from "p";
+export * as q from "q";
+import("k").then(l);
+"#;
+    let result = Parser::new(module.as_bytes(), false)
+        .parse_module()
+        .expect("failed to parse")
+        .to_string();
+    assert_eq!(result, module);
+}
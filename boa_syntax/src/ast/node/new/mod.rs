@@ -1,10 +1,6 @@
 use crate::{
-    builtins::iterable,
-    exec::Executable,
+    ast::node::{Call, Node},
     gc::{Finalize, Trace},
-    syntax::ast::node::{Call, Node},
-    value::JsValue,
-    BoaProfiler, Context, JsResult,
 };
 use std::fmt;
 
@@ -30,6 +26,7 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-NewExpression
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct New {
     call: Call,
@@ -47,40 +44,6 @@ impl New {
     }
 }
 
-impl Executable for New {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("New", "exec");
-
-        let func_object = self.expr().run(context)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    v_args.push(next_value.clone());
-                }
-                break; // after spread we don't accept any new arguments
-            } else {
-                v_args.push(arg.run(context)?);
-            }
-        }
-
-        match func_object {
-            JsValue::Object(ref object) => {
-                object.construct(&v_args, &object.clone().into(), context)
-            }
-            _ => context
-                .throw_type_error(format!("{} is not a constructor", self.expr().to_string(),)),
-        }
-    }
-}
-
 impl From<Call> for New {
     fn from(call: Call) -> Self {
         Self { call }
@@ -1,11 +1,8 @@
 //! Object node.
 
 use crate::{
-    exec::Executable,
+    ast::node::{join_nodes, MethodDefinitionKind, Node, PropertyDefinition},
     gc::{Finalize, Trace},
-    property::PropertyDescriptor,
-    syntax::ast::node::{join_nodes, MethodDefinitionKind, Node, PropertyDefinition},
-    BoaProfiler, Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -35,6 +32,7 @@ mod tests;
 /// [object]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 /// [primitive]: https://developer.mozilla.org/en-US/docs/Glossary/primitive
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deser", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Object {
@@ -47,7 +45,7 @@ impl Object {
     }
 
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indent: usize,
@@ -67,6 +65,9 @@ impl Object {
                 PropertyDefinition::SpreadObject(key) => {
                     writeln!(f, "{}...{},", indentation, key)?;
                 }
+                PropertyDefinition::CoverInitializedName(key, init) => {
+                    writeln!(f, "{}{} = {},", indentation, key, init)?;
+                }
                 PropertyDefinition::MethodDefinition(kind, key, node) => {
                     write!(f, "{}", indentation)?;
                     match &kind {
@@ -86,74 +87,6 @@ impl Object {
     }
 }
 
-impl Executable for Object {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("object", "exec");
-        let obj = JsValue::new_object(context);
-
-        // TODO: Implement the rest of the property types.
-        for property in self.properties().iter() {
-            match property {
-                PropertyDefinition::Property(key, value) => {
-                    obj.set_property(
-                        key.clone(),
-                        PropertyDescriptor::builder()
-                            .value(value.run(context)?)
-                            .writable(true)
-                            .enumerable(true)
-                            .configurable(true),
-                    );
-                }
-                PropertyDefinition::MethodDefinition(kind, name, func) => match kind {
-                    MethodDefinitionKind::Ordinary => {
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::builder()
-                                .value(func.run(context)?)
-                                .writable(true)
-                                .enumerable(true)
-                                .configurable(true),
-                        );
-                    }
-                    MethodDefinitionKind::Get => {
-                        let set = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|a| a.set())
-                            .cloned();
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::builder()
-                                .maybe_get(func.run(context)?.as_object())
-                                .maybe_set(set)
-                                .enumerable(true)
-                                .configurable(true),
-                        )
-                    }
-                    MethodDefinitionKind::Set => {
-                        let get = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|a| a.get())
-                            .cloned();
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::builder()
-                                .maybe_get(get)
-                                .maybe_set(func.run(context)?.as_object())
-                                .enumerable(true)
-                                .configurable(true),
-                        )
-                    }
-                },
-                _ => {} //unimplemented!("{:?} type of property", i),
-            }
-        }
-
-        Ok(obj)
-    }
-}
-
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -0,0 +1,148 @@
+use crate::{
+    ast::node::{
+        declaration::DeclarationPattern, GetConstField, GetField, GetPrivateField, GetSuperField,
+        Identifier, Node,
+    },
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// An assignment operator assigns a value to its left operand based on the value of its right
+/// operand.
+///
+/// Assignment operator (`=`), assigns the value of its right operand to its left operand.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Assignment_Operators
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Assign {
+    lhs: Box<AssignTarget>,
+    rhs: Box<Node>,
+}
+
+impl Assign {
+    /// Creates an `Assign` AST node.
+    pub fn new<L, R>(lhs: L, rhs: R) -> Self
+    where
+        L: Into<AssignTarget>,
+        R: Into<Node>,
+    {
+        Self {
+            lhs: Box::new(lhs.into()),
+            rhs: Box::new(rhs.into()),
+        }
+    }
+
+    /// Gets the left hand side of the assignment operation.
+    pub fn lhs(&self) -> &AssignTarget {
+        &self.lhs
+    }
+
+    /// Gets the right hand side of the assignment operation.
+    pub fn rhs(&self) -> &Node {
+        &self.rhs
+    }
+}
+
+impl fmt::Display for Assign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.lhs, self.rhs)
+    }
+}
+
+impl From<Assign> for Node {
+    fn from(op: Assign) -> Self {
+        Self::Assign(op)
+    }
+}
+
+/// The left hand side of an assignment: a simple target, or an assignment pattern for a
+/// destructuring assignment.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum AssignTarget {
+    Identifier(Identifier),
+    GetConstField(GetConstField),
+    GetField(GetField),
+    GetPrivateField(GetPrivateField),
+    GetSuperField(GetSuperField),
+    DeclarationPattern(DeclarationPattern),
+}
+
+impl AssignTarget {
+    /// Converts the node into a simple assignment target, if it is one.
+    pub fn from_node(node: &Node) -> Option<Self> {
+        match node {
+            Node::Identifier(ident) => Some(Self::Identifier(ident.clone())),
+            Node::GetConstField(field) => Some(Self::GetConstField(field.clone())),
+            Node::GetField(field) => Some(Self::GetField(field.clone())),
+            Node::GetPrivateField(field) => Some(Self::GetPrivateField(field.clone())),
+            Node::GetSuperField(field) => Some(Self::GetSuperField(field.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AssignTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(ident) => fmt::Display::fmt(ident, f),
+            Self::GetConstField(field) => fmt::Display::fmt(field, f),
+            Self::GetField(field) => fmt::Display::fmt(field, f),
+            Self::GetPrivateField(field) => fmt::Display::fmt(field, f),
+            Self::GetSuperField(field) => fmt::Display::fmt(field, f),
+            Self::DeclarationPattern(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<Identifier> for AssignTarget {
+    fn from(ident: Identifier) -> Self {
+        Self::Identifier(ident)
+    }
+}
+
+impl From<GetConstField> for AssignTarget {
+    fn from(field: GetConstField) -> Self {
+        Self::GetConstField(field)
+    }
+}
+
+impl From<GetField> for AssignTarget {
+    fn from(field: GetField) -> Self {
+        Self::GetField(field)
+    }
+}
+
+impl From<GetPrivateField> for AssignTarget {
+    fn from(field: GetPrivateField) -> Self {
+        Self::GetPrivateField(field)
+    }
+}
+
+impl From<GetSuperField> for AssignTarget {
+    fn from(field: GetSuperField) -> Self {
+        Self::GetSuperField(field)
+    }
+}
+
+impl From<DeclarationPattern> for AssignTarget {
+    fn from(pattern: DeclarationPattern) -> Self {
+        Self::DeclarationPattern(pattern)
+    }
+}
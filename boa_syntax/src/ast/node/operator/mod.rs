@@ -0,0 +1,16 @@
+//! Operator nodes
+
+pub mod assign;
+pub mod bin_op;
+pub mod private_in;
+pub mod unary_op;
+
+pub use self::{
+    assign::{Assign, AssignTarget},
+    bin_op::BinOp,
+    private_in::PrivateIn,
+    unary_op::UnaryOp,
+};
+
+#[cfg(test)]
+mod tests;
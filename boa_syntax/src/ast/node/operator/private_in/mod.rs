@@ -0,0 +1,63 @@
+use crate::{
+    ast::node::Node,
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The ergonomic brand check `#name in object`, which is `true` if the object has the private
+/// member `#name`.
+///
+/// Unlike the `in` operator, it throws a `TypeError` if its right operand is not an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-RelationalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields#checking_if_a_private_field_exists
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PrivateIn {
+    name: Box<str>,
+    target: Box<Node>,
+}
+
+impl PrivateIn {
+    /// Creates a `PrivateIn` AST node.
+    pub fn new<N, T>(name: N, target: T) -> Self
+    where
+        N: Into<Box<str>>,
+        T: Into<Node>,
+    {
+        Self {
+            name: name.into(),
+            target: Box::new(target.into()),
+        }
+    }
+
+    /// Gets the private name looked for, without its `#`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the object looked into.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+}
+
+impl fmt::Display for PrivateIn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} in {}", self.name, self.target)
+    }
+}
+
+impl From<PrivateIn> for Node {
+    fn from(private_in: PrivateIn) -> Self {
+        Self::PrivateIn(private_in)
+    }
+}
@@ -0,0 +1,24 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        let a = 20;
+        a += 10;
+        a -= 10;
+        a *= 10;
+        a **= 10;
+        a /= 10;
+        a %= 10;
+        a &= 10;
+        a |= 10;
+        a ^= 10;
+        a <<= 10;
+        a >>= 10;
+        a >>>= 10;
+        a &&= 10;
+        a ||= 10;
+        a ??= 10;
+        a;
+        "#,
+    );
+}
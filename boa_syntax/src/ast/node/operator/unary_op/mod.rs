@@ -0,0 +1,72 @@
+use crate::{
+    ast::{node::Node, op},
+    gc::{Finalize, Trace},
+};
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A unary operation is an operation with only one operand.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-UnaryExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Unary_operators
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct UnaryOp {
+    op: op::UnaryOp,
+    target: Box<Node>,
+    strict: bool,
+}
+
+impl UnaryOp {
+    /// Creates a new `UnaryOp` AST node.
+    pub fn new<V>(op: op::UnaryOp, target: V) -> Self
+    where
+        V: Into<Node>,
+    {
+        Self {
+            op,
+            target: Box::new(target.into()),
+            strict: false,
+        }
+    }
+
+    /// Sets whether the operation is in strict mode code, where deleting a property that cannot
+    /// be deleted throws a `TypeError`.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Gets the unary operation of the node.
+    pub fn op(&self) -> op::UnaryOp {
+        self.op
+    }
+
+    /// Gets the target of this unary operator.
+    pub fn target(&self) -> &Node {
+        self.target.as_ref()
+    }
+
+    /// Checks if the operation is in strict mode code.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+}
+
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op, self.target)
+    }
+}
+
+impl From<UnaryOp> for Node {
+    fn from(op: UnaryOp) -> Self {
+        Self::UnaryOp(op)
+    }
+}
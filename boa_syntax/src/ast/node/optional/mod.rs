@@ -0,0 +1,138 @@
+use crate::{
+    ast::node::{join_nodes, Node},
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The optional chaining operator `?.` accesses a property or calls a function of a value,
+/// evaluating to `undefined` instead of throwing if the value is `null` or `undefined`.
+///
+/// The whole chain following the target is skipped when an optional operation short-circuits:
+/// in `a?.b.c()`, neither `.c` nor the call are evaluated if `a` is `null` or `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalItem]>,
+}
+
+impl Optional {
+    /// Creates a new `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalItem]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the expression the chain is applied to.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain, in evaluation order.
+    pub fn chain(&self) -> &[OptionalItem] {
+        &self.chain
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+        for item in self.chain.iter() {
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
+
+/// An operation of an optional chain.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalItem {
+    kind: OptionalItemKind,
+    shorted: bool,
+}
+
+impl OptionalItem {
+    /// Creates a new operation of an optional chain.
+    ///
+    /// A `shorted` operation is preceded by `?.`, and short-circuits the rest of the chain if the
+    /// value it is applied to is `null` or `undefined`.
+    pub fn new(kind: OptionalItemKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalItemKind {
+        &self.kind
+    }
+
+    /// Checks if the operation is preceded by `?.`.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match &self.kind {
+            OptionalItemKind::GetConstField(field) if self.shorted => write!(f, "{}", field),
+            OptionalItemKind::GetConstField(field) => write!(f, ".{}", field),
+            OptionalItemKind::GetPrivateField(field) if self.shorted => write!(f, "#{}", field),
+            OptionalItemKind::GetPrivateField(field) => write!(f, ".#{}", field),
+            OptionalItemKind::GetField(field) => write!(f, "[{}]", field),
+            OptionalItemKind::Call(args) => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The kinds of operations of an optional chain.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalItemKind {
+    /// A property access with the dot notation, like `.name` or `?.name`.
+    GetConstField(Box<str>),
+
+    /// A private member access, like `.#name` or `?.#name`.
+    GetPrivateField(Box<str>),
+
+    /// A property access with the bracket notation, like `[key]` or `?.[key]`.
+    GetField(Node),
+
+    /// A call, like `(args)` or `?.(args)`.
+    Call(Box<[Node]>),
+}
@@ -0,0 +1,12 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        a?.b;
+        a?.b.c;
+        a?.[1][2];
+        a.b?.(1, 2)(3);
+        a?.b?.c?.();
+        "#,
+    );
+}
@@ -1,8 +1,6 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    ast::node::Node,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
-    Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -31,6 +29,7 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-ReturnStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/return
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Return {
     expr: Option<Box<Node>>,
@@ -60,20 +59,6 @@ impl Return {
     }
 }
 
-impl Executable for Return {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let result = match self.expr() {
-            Some(v) => v.run(context),
-            None => Ok(JsValue::undefined()),
-        };
-        // Set flag for return
-        context
-            .executor()
-            .set_current_state(InterpreterState::Return);
-        result
-    }
-}
-
 impl From<Return> for Node {
     fn from(return_smt: Return) -> Node {
         Node::Return(return_smt)
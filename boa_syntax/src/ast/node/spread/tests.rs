@@ -0,0 +1,15 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        function f(m) {
+            return m;
+        };
+        function g(...args) {
+            return f(...args);
+        };
+        let a = g("message");
+        a;
+        "#,
+    );
+}
@@ -1,10 +1,11 @@
 //! Statement list node.
 
 use crate::{
-    exec::{Executable, InterpreterState},
+    ast::{
+        node::{Declaration, Node},
+        Span,
+    },
     gc::{empty_trace, Finalize, Trace},
-    syntax::ast::node::{Declaration, Node},
-    BoaProfiler, Context, JsResult, JsValue,
 };
 use std::{collections::HashSet, fmt, ops::Deref, rc::Rc};
 
@@ -20,10 +21,15 @@ use serde::{Deserialize, Serialize};
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct StatementList {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     items: Box<[Node]>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(default))]
+    spans: StatementSpans,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    strict: bool,
 }
 
 impl StatementList {
@@ -32,8 +38,37 @@ impl StatementList {
         &self.items
     }
 
+    /// Gets the spans of the items, in the same order as the items.
+    ///
+    /// It is empty if the statement list was not parsed from source code.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans.0
+    }
+
+    /// Sets the spans of the items, as they were written in the parsed source code.
+    pub fn with_spans<S>(mut self, spans: S) -> Self
+    where
+        S: Into<Box<[Span]>>,
+    {
+        let spans = spans.into();
+        debug_assert_eq!(spans.len(), self.items.len(), "a span is needed per item");
+        self.spans = StatementSpans(spans);
+        self
+    }
+
+    /// Sets whether the statement list is the body of a function in strict mode code.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Checks if the statement list is the body of a function in strict mode code.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -43,9 +78,8 @@ impl StatementList {
             // We rely on the node to add the correct indent.
             node.display(f, indentation)?;
 
-            match node {
-                Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
-                _ => write!(f, ";")?,
+            if needs_semicolon(node) {
+                write!(f, ";")?;
             }
             writeln!(f)?;
         }
@@ -55,7 +89,13 @@ impl StatementList {
     pub fn lexically_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
-            if let Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) = stmt {
+            if let Node::ClassDecl(class) = stmt {
+                if let Some(name) = class.name() {
+                    if !set.insert(name) {
+                        unreachable!("Redeclaration of {}", name);
+                    }
+                }
+            } else if let Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) = stmt {
                 for decl in decl_list.as_ref() {
                     // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
                     // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
@@ -82,8 +122,11 @@ impl StatementList {
     pub fn function_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
-            if let Node::FunctionDecl(decl) = stmt {
-                set.insert(decl.name());
+            match stmt {
+                Node::FunctionDecl(decl) => set.extend(decl.name()),
+                Node::GeneratorDecl(decl) => set.extend(decl.name()),
+                Node::AsyncGeneratorDecl(decl) => set.extend(decl.name()),
+                _ => {}
             }
         }
         set
@@ -111,41 +154,30 @@ impl StatementList {
     }
 }
 
-impl Executable for StatementList {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("StatementList", "exec");
-
-        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
-        // The return value is uninitialized, which means it defaults to Value::Undefined
-        let mut obj = JsValue::default();
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-        for (i, item) in self.items().iter().enumerate() {
-            let val = item.run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    obj = val;
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // Early break.
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    break;
-                }
-                InterpreterState::Executing => {
-                    // Continue execution
-                }
-            }
-            if i + 1 == self.items().len() {
-                obj = val;
-            }
-        }
+/// The spans of the items of a statement list, as they were written in the parsed source code.
+///
+/// They are not part of the structure of the statement list, so they are ignored when comparing
+/// nodes.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "deser", serde(transparent))]
+#[derive(Clone, Debug, Default)]
+struct StatementSpans(Box<[Span]>);
+
+impl PartialEq for StatementSpans {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
 
-        Ok(obj)
+/// Checks if the display of a statement must be terminated by a semicolon.
+fn needs_semicolon(node: &Node) -> bool {
+    match node {
+        Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) | Node::With(_) => {
+            false
+        }
+        Node::Labelled(labelled) => needs_semicolon(labelled.statement()),
+        _ => true,
     }
 }
 
@@ -154,7 +186,11 @@ where
     T: Into<Box<[Node]>>,
 {
     fn from(stm: T) -> Self {
-        Self { items: stm.into() }
+        Self {
+            items: stm.into(),
+            spans: StatementSpans::default(),
+            strict: false,
+        }
     }
 }
 
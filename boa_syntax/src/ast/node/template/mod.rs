@@ -0,0 +1,183 @@
+//! Template literal node.
+
+use super::Node;
+use gc::{Finalize, Trace};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Weak,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Template literals are string literals allowing embedded expressions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TemplateLit {
+    elements: Vec<TemplateElement>,
+}
+
+impl TemplateLit {
+    pub fn new(elements: Vec<TemplateElement>) -> Self {
+        TemplateLit { elements }
+    }
+
+    /// Gets the string and expression elements of the template literal.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
+}
+
+impl fmt::Display for TemplateLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`")?;
+        for elt in &self.elements {
+            match elt {
+                TemplateElement::String(s) => write!(f, "{}", s)?,
+                TemplateElement::Expr(n) => write!(f, "${{{}}}", n)?,
+            }
+        }
+        write!(f, "`")
+    }
+}
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct TaggedTemplate {
+    tag: Box<Node>,
+    raws: Vec<Box<str>>,
+    cookeds: Vec<Option<Box<str>>>,
+    exprs: Vec<Node>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip, default = "TemplateSite::new"))]
+    site: TemplateSite,
+}
+
+impl TaggedTemplate {
+    pub fn new(
+        tag: Node,
+        raws: Vec<Box<str>>,
+        cookeds: Vec<Option<Box<str>>>,
+        exprs: Vec<Node>,
+    ) -> Self {
+        Self {
+            tag: Box::new(tag),
+            raws,
+            cookeds,
+            exprs,
+            site: TemplateSite::new(),
+        }
+    }
+
+    /// Gets the tag function expression of the template.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the raw strings of the template.
+    pub fn raws(&self) -> &[Box<str>] {
+        &self.raws
+    }
+
+    /// Gets the cooked strings of the template, `None` for invalid escape sequences.
+    pub fn cookeds(&self) -> &[Option<Box<str>>] {
+        &self.cookeds
+    }
+
+    /// Gets the substitution expressions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+
+    /// Gets the call site of the template, which identifies the parse node of the template.
+    pub fn site(&self) -> &TemplateSite {
+        &self.site
+    }
+}
+
+// The site is the identity of the node, not part of its structure.
+impl PartialEq for TaggedTemplate {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag
+            && self.raws == other.raws
+            && self.cookeds == other.cookeds
+            && self.exprs == other.exprs
+    }
+}
+
+/// The call site of a tagged template, shared by the clones of its node.
+///
+/// Each parsed tagged template gets a site with a new id, so the template objects of the sites
+/// can be cached by id. A site is alive as long as a clone of its node is, which tells when the
+/// template object cached for it can be dropped.
+#[derive(Clone, Debug)]
+pub struct TemplateSite(Arc<u64>);
+
+/// A reference to a [`TemplateSite`] which doesn't keep it alive.
+#[derive(Clone, Debug)]
+pub struct WeakTemplateSite(Weak<u64>);
+
+impl TemplateSite {
+    fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(Arc::new(NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+    }
+
+    /// Gets the id of the site, which no other site has.
+    #[inline]
+    pub fn id(&self) -> u64 {
+        *self.0
+    }
+
+    /// Creates a reference to the site which doesn't keep it alive.
+    #[inline]
+    pub fn downgrade(&self) -> WeakTemplateSite {
+        WeakTemplateSite(Arc::downgrade(&self.0))
+    }
+}
+
+impl WeakTemplateSite {
+    /// Checks if a node of the site still exists.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+}
+
+impl fmt::Display for TaggedTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}`", self.tag)?;
+        for (raw, expr) in self.raws.iter().zip(self.exprs.iter()) {
+            write!(f, "{}${{{}}}", raw, expr)?;
+        }
+        write!(f, "`")
+    }
+}
+
+impl From<TaggedTemplate> for Node {
+    fn from(template: TaggedTemplate) -> Self {
+        Node::TaggedTemplate(template)
+    }
+}
+
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum TemplateElement {
+    String(Box<str>),
+    Expr(Node),
+}
@@ -1,12 +1,6 @@
 use crate::{
-    environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecord,
-        lexical_environment::VariableScope,
-    },
-    exec::Executable,
+    ast::node::{Block, Declaration, Node},
     gc::{Finalize, Trace},
-    syntax::ast::node::{Block, Identifier, Node},
-    BoaProfiler, Context, JsResult, JsValue,
 };
 use std::fmt;
 
@@ -30,6 +24,7 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-TryStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/try...catch
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Try {
     block: Block,
@@ -39,11 +34,7 @@ pub struct Try {
 
 impl Try {
     /// Creates a new `Try` AST node.
-    pub(in crate::syntax) fn new<B>(
-        block: B,
-        catch: Option<Catch>,
-        finally: Option<Finally>,
-    ) -> Self
+    pub fn new<B>(block: B, catch: Option<Catch>, finally: Option<Finally>) -> Self
     where
         B: Into<Block>,
     {
@@ -75,7 +66,7 @@ impl Try {
     }
 
     /// Implements the display formatting with indentation.
-    pub(in crate::syntax::ast::node) fn display(
+    pub(in crate::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
@@ -94,47 +85,6 @@ impl Try {
     }
 }
 
-impl Executable for Try {
-    fn run(&self, context: &mut Context) -> JsResult<JsValue> {
-        let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(context).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    {
-                        let env = context.get_current_environment();
-                        context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-
-                        if let Some(param) = catch.parameter() {
-                            context.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            )?;
-                            context.initialize_binding(param, err)?;
-                        }
-                    }
-
-                    let res = catch.block().run(context);
-
-                    // pop the block env
-                    let _ = context.pop_environment();
-
-                    res
-                } else {
-                    Err(err)
-                }
-            },
-            Ok,
-        );
-
-        if let Some(finally) = self.finally() {
-            finally.run(context)?;
-        }
-
-        res
-    }
-}
-
 impl fmt::Display for Try {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -148,30 +98,34 @@ impl From<Try> for Node {
 }
 
 /// Catch block.
+///
+/// The parameter of the catch block is optional, and can be either an identifier or a binding
+/// pattern, but has no initializer.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<Box<Declaration>>,
     block: Block,
 }
 
 impl Catch {
     /// Creates a new catch block.
-    pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
+    pub fn new<OD, D, B>(parameter: OD, block: B) -> Self
     where
-        OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        OD: Into<Option<D>>,
+        D: Into<Declaration>,
         B: Into<Block>,
     {
         Self {
-            parameter: parameter.into().map(I::into),
+            parameter: parameter.into().map(|d| Box::new(d.into())),
             block: block.into(),
         }
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&Declaration> {
+        self.parameter.as_deref()
     }
 
     /// Retrieves the catch execution block.
@@ -198,6 +152,7 @@ impl fmt::Display for Catch {
 
 /// Finally block.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Finally {
     block: Block,
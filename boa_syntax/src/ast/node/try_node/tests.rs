@@ -0,0 +1,24 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        try {
+            throw "hello";
+        } catch(e) {
+            console.log(e);
+        } finally {
+            console.log("things");
+        };
+        try {
+            throw "hello";
+        } catch {
+            console.log("something went wrong");
+        };
+        try {
+            throw "hello";
+        } catch({ message, name }) {
+            console.log(message);
+        };
+        "#,
+    );
+}
@@ -0,0 +1,81 @@
+use crate::{
+    ast::node::Node,
+    gc::{Finalize, Trace},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `with` statement extends the scope chain for a statement.
+///
+/// Syntax: `with (expression) statement`
+///
+/// The properties of the object the expression evaluates to become bindings visible to the
+/// statement, unless they are blocked by its `@@unscopables` property. Functions called by
+/// name through those bindings receive the object as their `this` value.
+///
+/// It is not allowed in strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct With {
+    object: Box<Node>,
+    statement: Box<Node>,
+}
+
+impl With {
+    /// Gets the expression evaluating to the binding object of the statement.
+    pub fn object(&self) -> &Node {
+        &self.object
+    }
+
+    /// Gets the statement executed with the extended scope chain.
+    pub fn statement(&self) -> &Node {
+        &self.statement
+    }
+
+    /// Creates a `With` AST node.
+    pub fn new<O, S>(object: O, statement: S) -> Self
+    where
+        O: Into<Node>,
+        S: Into<Node>,
+    {
+        Self {
+            object: Box::new(object.into()),
+            statement: Box::new(statement.into()),
+        }
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "with ({}) ", self.object)?;
+        self.statement.display(f, indentation)
+    }
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<With> for Node {
+    fn from(with: With) -> Node {
+        Self::With(with)
+    }
+}
@@ -0,0 +1,10 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        with (Math) {
+            console.log(max(PI, E));
+        }
+        "#,
+    );
+}
@@ -0,0 +1,70 @@
+//! Yield expression node.
+
+use super::Node;
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// A yield expression is used within a generator function to pause its execution and return
+/// a value to the caller of the generator.
+///
+/// A `yield*` expression delegates to another iterable, yielding each of its values in turn.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct YieldExpr {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl YieldExpr {
+    /// Creates a new yield expression.
+    pub fn new<E>(expr: E, delegate: bool) -> Self
+    where
+        E: Into<Option<Node>>,
+    {
+        Self {
+            expr: expr.into().map(Box::new),
+            delegate,
+        }
+    }
+
+    /// Gets the expression whose value is yielded, if any.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_deref()
+    }
+
+    /// Checks if this is a `yield*` expression, delegating to another iterable.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+}
+
+impl fmt::Display for YieldExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.delegate { "yield*" } else { "yield" })?;
+        if let Some(ref expr) = self.expr {
+            f.write_str(" ")?;
+            expr.display(f, 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<YieldExpr> for Node {
+    fn from(expr: YieldExpr) -> Self {
+        Self::YieldExpr(expr)
+    }
+}
@@ -0,0 +1,12 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        function* g() {
+            yield;
+            let a = yield 1;
+            yield* other();
+        };
+        "#,
+    );
+}
@@ -5,7 +5,7 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#prod-Punctuator
 
-use crate::syntax::ast::op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp};
+use crate::ast::op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp};
 use std::{
     convert::TryInto,
     fmt::{Display, Error, Formatter},
@@ -21,6 +21,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Punctuator
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum Punctuator {
     /// `+`
@@ -113,6 +114,8 @@ pub enum Punctuator {
     OpenBracket,
     /// `(`
     OpenParen,
+    /// `?.`
+    Optional,
     /// `|`
     Or,
     /// `**`
@@ -245,6 +248,7 @@ impl Display for Punctuator {
                 Self::OpenBlock => "{",
                 Self::OpenBracket => "[",
                 Self::OpenParen => "(",
+                Self::Optional => "?.",
                 Self::Or => "|",
                 Self::Exp => "**",
                 Self::Question => "?",
@@ -2,11 +2,9 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
+    ast::{Position, Span},
+    lexer::{Token, TokenKind},
     profiler::BoaProfiler,
-    syntax::{
-        ast::{Position, Span},
-        lexer::{Token, TokenKind},
-    },
 };
 use std::io::Read;
 
@@ -1,5 +1,5 @@
 //! Module implementing the lexer cursor. This is used for managing the input byte stream.
-use crate::{profiler::BoaProfiler, syntax::ast::Position};
+use crate::{ast::Position, profiler::BoaProfiler};
 use std::io::{self, Bytes, Error, ErrorKind, Read};
 
 /// Cursor over the source code.
@@ -31,6 +31,20 @@ impl<R> Cursor<R> {
         self.pos = Position::new(next_line, 1);
     }
 
+    /// Gets the number of bytes of the source code consumed by the cursor.
+    #[inline]
+    pub(super) fn offset(&self) -> usize {
+        self.iter.source.len()
+    }
+
+    /// Gets the consumed source code between the byte offsets `start` and `end`.
+    #[inline]
+    pub(super) fn source_text(&self, start: usize, end: usize) -> String {
+        let source = &self.iter.source;
+        let end = end.min(source.len());
+        String::from_utf8_lossy(&source[start.min(end)..end]).into_owned()
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.strict_mode
@@ -276,6 +290,8 @@ where
             }
             // '\n' | '\u{2028}' | '\u{2029}'
             Some(0xA) | Some(0x2028) | Some(0x2029) => self.next_line(),
+            // A leading byte order mark is not part of the source text, so it takes no column.
+            Some(0xFEFF) if self.pos == Position::new(1, 1) => {}
             Some(_) => self.next_column(),
             _ => {}
         }
@@ -291,6 +307,8 @@ struct InnerIter<R> {
     num_peeked_bytes: u8,
     peeked_bytes: u32,
     peeked_char: Option<Option<u32>>,
+    /// The bytes consumed so far, kept for the source text of functions.
+    source: Vec<u8>,
 }
 
 impl<R> InnerIter<R> {
@@ -302,6 +320,7 @@ impl<R> InnerIter<R> {
             num_peeked_bytes: 0,
             peeked_bytes: 0,
             peeked_char: None,
+            source: Vec::new(),
         }
     }
 }
@@ -426,14 +445,16 @@ where
     #[inline]
     fn next_byte(&mut self) -> io::Result<Option<u8>> {
         self.peeked_char = None;
-        if self.num_peeked_bytes > 0 {
+        let byte = if self.num_peeked_bytes > 0 {
             let byte = (self.peeked_bytes & 0xFF) as u8;
             self.num_peeked_bytes -= 1;
             self.peeked_bytes >>= 8;
-            Ok(Some(byte))
+            Some(byte)
         } else {
-            self.iter.next().transpose()
-        }
+            self.iter.next().transpose()?
+        };
+        self.source.extend(byte);
+        Ok(byte)
     }
 
     /// Retrieves the next unchecked char in u32 code point.
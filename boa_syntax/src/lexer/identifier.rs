@@ -2,20 +2,20 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
+    ast::{Keyword, Position, Span},
+    lexer::{StringLiteral, Token, TokenKind},
     profiler::BoaProfiler,
-    syntax::{
-        ast::{Keyword, Position, Span},
-        lexer::{StringLiteral, Token, TokenKind},
-    },
 };
 use boa_unicode::UnicodeProperties;
 use core::convert::TryFrom;
 use std::io::Read;
 use std::str;
 
-const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
-    "eval",
-    "arguments",
+/// The identifiers reserved in strict mode code.
+///
+/// `static` is reserved in strict mode code too, but classes, which are always strict, use it as
+/// a modifier and a name of their elements, so the parser checks it instead.
+const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 8] = [
     "implements",
     "interface",
     "let",
@@ -23,7 +23,6 @@ const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
     "private",
     "protected",
     "public",
-    "static",
     "yield",
 ];
 
@@ -130,6 +129,48 @@ impl<R> Tokenizer<R> for Identifier {
     }
 }
 
+/// Private identifier lexing.
+///
+/// The leading `#` has already been consumed when this lexer is called.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PrivateIdentifier
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PrivateIdentifier;
+
+impl<R> Tokenizer<R> for PrivateIdentifier {
+    fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("PrivateIdentifier", "Lexing");
+
+        let init = match cursor.peek_char()? {
+            Some(ch) if ch == 0x005C /* \ */ || Identifier::is_identifier_start(ch) => {
+                let _ = cursor.next_char()?;
+                char::try_from(ch).expect("identifier start must be a valid char")
+            }
+            _ => {
+                return Err(Error::Syntax(
+                    "expected an identifier after '#'".into(),
+                    start_pos,
+                ))
+            }
+        };
+
+        let (identifier_name, _) = Identifier::take_identifier_name(cursor, start_pos, init)?;
+
+        Ok(Token::new(
+            TokenKind::private_identifier(identifier_name.into_boxed_str()),
+            Span::new(start_pos, cursor.pos()),
+        ))
+    }
+}
+
 impl Identifier {
     #[inline]
     fn take_identifier_name<R>(
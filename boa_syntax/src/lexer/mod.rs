@@ -32,7 +32,7 @@ mod tests;
 use self::{
     comment::{MultiLineComment, SingleLineComment},
     cursor::Cursor,
-    identifier::Identifier,
+    identifier::{Identifier, PrivateIdentifier},
     number::NumberLiteral,
     operator::Operator,
     regex::RegexLiteral,
@@ -40,8 +40,8 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
-use crate::syntax::ast::{Punctuator, Span};
-pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
+use crate::ast::{Punctuator, Span};
+pub use crate::{ast::Position, profiler::BoaProfiler};
 use core::convert::TryFrom;
 pub use error::Error;
 use std::io::Read;
@@ -59,6 +59,8 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    /// The byte offset of the start of the last token lexed.
+    token_start: usize,
 }
 
 impl<R> Lexer<R> {
@@ -91,6 +93,24 @@ impl<R> Lexer<R> {
         self.goal_symbol
     }
 
+    /// Gets the byte offsets of the start and the end of the last token lexed by `next()`.
+    #[inline]
+    pub(crate) fn token_offsets(&self) -> (usize, usize) {
+        (self.token_start, self.cursor.offset())
+    }
+
+    /// Gets the number of bytes of the source code lexed so far.
+    #[inline]
+    pub(crate) fn offset(&self) -> usize {
+        self.cursor.offset()
+    }
+
+    /// Gets the source code lexed between the byte offsets `start` and `end`.
+    #[inline]
+    pub(crate) fn source_text(&self, start: usize, end: usize) -> String {
+        self.cursor.source_text(start, end)
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.cursor.strict_mode()
@@ -110,6 +130,7 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: Default::default(),
+            token_start: 0,
         }
     }
 
@@ -181,6 +202,7 @@ impl<R> Lexer<R> {
 
         let (start, next_ch) = loop {
             let start = self.cursor.pos();
+            self.token_start = self.cursor.offset();
             if let Some(next_ch) = self.cursor.next_char()? {
                 // Ignore whitespace
                 if !Self::is_whitespace(next_ch) {
@@ -243,6 +265,13 @@ impl<R> Lexer<R> {
                     Span::new(start, self.cursor.pos()),
                 )),
                 '/' => self.lex_slash_token(start),
+                // A hashbang comment is only allowed at the very start of the source text.
+                // https://tc39.es/ecma262/#sec-hashbang
+                '#' if start == Position::new(1, 1) && self.cursor.peek()? == Some(b'!') => {
+                    self.cursor.next_byte()?.expect("! token vanished"); // Consume the '!'
+                    SingleLineComment.lex(&mut self.cursor, start)
+                }
+                '#' => PrivateIdentifier.lex(&mut self.cursor, start),
                 '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
                     Operator::new(next_ch as u8).lex(&mut self.cursor, start)
                 }
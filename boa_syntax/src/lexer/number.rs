@@ -2,13 +2,11 @@
 
 use super::{Cursor, Error, TokenKind, Tokenizer};
 use crate::{
+    ast::{Position, Span},
+    lexer::{token::Numeric, Token},
     profiler::BoaProfiler,
-    syntax::{
-        ast::{Position, Span},
-        lexer::{token::Numeric, Token},
-    },
-    JsBigInt,
 };
+use num_bigint::BigInt;
 use std::io::Read;
 use std::str;
 
@@ -377,7 +375,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
         let num = match kind {
             NumericKind::BigInt(base) => {
                 Numeric::BigInt(
-                    JsBigInt::from_string_radix(num_str, base).expect("Could not convert to BigInt")
+                    BigInt::parse_bytes(num_str.as_bytes(), base).expect("Could not convert to BigInt")
                     )
             }
             NumericKind::Rational /* base: 10 */ => {
@@ -1,13 +1,11 @@
 //! This module implements lexing for operators (+, - etc.) used in the JavaScript programing language.
 
 use super::{Cursor, Error, Tokenizer};
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
+    ast::{Position, Punctuator, Span},
+    lexer::Token,
     profiler::BoaProfiler,
-    syntax::{
-        ast::{Position, Punctuator, Span},
-        lexer::Token,
-    },
 };
 use std::io::Read;
 
@@ -20,7 +18,6 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             }
             Some(_) => $op,
@@ -31,12 +28,10 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             },
             $($case => {
                 $cursor.next_byte()?.expect("Token vanished");
-                $cursor.next_column();
                 $block
             })+,
             _ => $op,
@@ -46,7 +41,6 @@ macro_rules! vop {
         match $cursor.peek().ok_or_else(|| Error::syntax("could not preview next value", $cursor.pos()))? {
             $($case => {
                 $cursor.next_byte()?;
-                $cursor.next_column();
                 $block
             })+,
             _ => $op
@@ -134,6 +128,15 @@ impl<R> Tokenizer<R> for Operator {
                         Ok(Punctuator::Coalesce)
                     )
                 }
+                // `?.` followed by a digit is a conditional operator with a numeric literal, as
+                // in `a?.5:b`.
+                Some(b'.') if !((cursor.peek_n(2)? >> 8) as u8).is_ascii_digit() => {
+                    let _ = cursor.next_byte()?.expect(". vanished");
+                    Ok(Token::new(
+                        TokenKind::Punctuator(Punctuator::Optional),
+                        Span::new(start_pos, cursor.pos()),
+                    ))
+                }
                 _ => Ok(Token::new(
                     TokenKind::Punctuator(Punctuator::Question),
                     Span::new(start_pos, cursor.pos()),
@@ -2,11 +2,9 @@
 
 use super::{Cursor, Error, Span, Tokenizer};
 use crate::{
+    ast::Position,
+    lexer::{Token, TokenKind},
     profiler::BoaProfiler,
-    syntax::{
-        ast::Position,
-        lexer::{Token, TokenKind},
-    },
 };
 use bitflags::bitflags;
 use std::io::{self, ErrorKind};
@@ -139,6 +137,7 @@ bitflags! {
         const DOT_ALL = 0b0000_1000;
         const UNICODE = 0b0001_0000;
         const STICKY = 0b0010_0000;
+        const UNICODE_SETS = 0b0100_0000;
     }
 }
 
@@ -151,6 +150,7 @@ pub(crate) fn parse_regex_flags(s: &str, start: Position) -> Result<RegExpFlags,
             b'm' => RegExpFlags::MULTILINE,
             b's' => RegExpFlags::DOT_ALL,
             b'u' => RegExpFlags::UNICODE,
+            b'v' => RegExpFlags::UNICODE_SETS,
             b'y' => RegExpFlags::STICKY,
             _ => {
                 return Err(Error::syntax(
@@ -191,6 +191,9 @@ impl Display for RegExpFlags {
         if self.contains(Self::UNICODE) {
             f.write_char('u')?;
         }
+        if self.contains(Self::UNICODE_SETS) {
+            f.write_char('v')?;
+        }
         if self.contains(Self::STICKY) {
             f.write_char('y')?;
         }
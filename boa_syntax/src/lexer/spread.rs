@@ -2,11 +2,9 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
+    ast::{Position, Punctuator, Span},
+    lexer::Token,
     profiler::BoaProfiler,
-    syntax::{
-        ast::{Position, Punctuator, Span},
-        lexer::Token,
-    },
 };
 use std::io::Read;
 
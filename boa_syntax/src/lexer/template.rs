@@ -2,12 +2,10 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
+    ast::{Position, Span},
+    lexer::string::{StringLiteral, UTF16CodeUnitsBuffer},
+    lexer::{Token, TokenKind},
     profiler::BoaProfiler,
-    syntax::lexer::string::{StringLiteral, UTF16CodeUnitsBuffer},
-    syntax::{
-        ast::{Position, Span},
-        lexer::{Token, TokenKind},
-    },
 };
 use std::io::{self, ErrorKind, Read};
 
@@ -5,8 +5,8 @@ use super::regex::RegExpFlags;
 use super::token::Numeric;
 use super::*;
 use super::{Error, Position};
-use crate::syntax::ast::Keyword;
-use crate::syntax::lexer::template::TemplateString;
+use crate::ast::Keyword;
+use crate::lexer::template::TemplateString;
 use std::str;
 
 fn span(start: (u32, u32), end: (u32, u32)) -> Span {
@@ -57,6 +57,33 @@ fn check_single_line_comment_with_crlf_ending() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_hashbang_comment() {
+    let s = "#!/usr/bin/env boa\nvar";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [TokenKind::LineTerminator, TokenKind::Keyword(Keyword::Var)];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_invalid_hashbang_comment() {
+    let s = " #!/usr/bin/env boa";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    lexer.next().expect_err("hashbang comment after whitespace");
+
+    let s = "var\n#!/usr/bin/env boa";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    lexer.next().unwrap();
+    lexer.next().unwrap();
+    lexer
+        .next()
+        .expect_err("hashbang comment after the first line");
+}
+
 #[test]
 fn check_multi_line_comment() {
     let s = "var /* await \n break \n*/ x";
@@ -120,6 +147,33 @@ fn check_invalid_identifier_part() {
     }
 }
 
+#[test]
+fn check_private_identifier() {
+    let s = "#x this.#_y #if #\\u0078";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::private_identifier("x"),
+        TokenKind::Keyword(Keyword::This),
+        TokenKind::Punctuator(Punctuator::Dot),
+        TokenKind::private_identifier("_y"),
+        TokenKind::private_identifier("if"),
+        TokenKind::private_identifier("x"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_invalid_private_identifier() {
+    for s in ["#", "# x", "#1", "#😀"].iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+        lexer
+            .next()
+            .expect_err("Invalid private identifier not rejected as expected");
+    }
+}
+
 #[test]
 fn check_string() {
     let s = "'aaa' \"bbb\"";
@@ -162,7 +216,7 @@ fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
     let s = "{ ( ) [ ] . ... ; , < > <= >= == != === !== \
              + - * % -- << >> >>> & | ^ ! ~ && || ? : \
-             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||=";
+             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||= ?.";
     let mut lexer = Lexer::new(s.as_bytes());
 
     let expected = [
@@ -219,6 +273,24 @@ fn check_punctuators() {
         TokenKind::Punctuator(Punctuator::AssignCoalesce),
         TokenKind::Punctuator(Punctuator::AssignBoolAnd),
         TokenKind::Punctuator(Punctuator::AssignBoolOr),
+        TokenKind::Punctuator(Punctuator::Optional),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_optional_chaining_before_digit() {
+    let mut lexer = Lexer::new(&b"a?.5:b?.c"[..]);
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Question),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::Punctuator(Punctuator::Colon),
+        TokenKind::identifier("b"),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::identifier("c"),
     ];
 
     expect_tokens(&mut lexer, &expected);
@@ -369,6 +441,41 @@ fn check_positions_codepoint() {
     );
 }
 
+#[test]
+fn check_positions_byte_order_mark() {
+    let s = "\u{FEFF}let x;\n\u{FEFF}y";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    // The leading BOM is skipped without taking a column.
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 1), (1, 4)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 5), (1, 6)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 6), (1, 7)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 7), (2, 1)));
+
+    // Any other U+FEFF is regular whitespace.
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((2, 2), (2, 3)));
+}
+
+#[test]
+fn check_positions_operators() {
+    let s = "a += b-- >>>= c";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    // Each character of an operator takes a single column.
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 1), (1, 2)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 3), (1, 5)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 6), (1, 7)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 7), (1, 9)));
+    assert_eq!(
+        lexer.next().unwrap().unwrap().span(),
+        span((1, 10), (1, 14))
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().span(),
+        span((1, 15), (1, 16))
+    );
+}
+
 #[test]
 fn check_line_numbers() {
     let s = "x\ny\n";
@@ -484,6 +591,32 @@ fn numbers_with_bad_separators() {
     }
 }
 
+#[test]
+fn bigint_literals() {
+    let mut lexer = Lexer::new(&b"0n 123n 1_000n 0x1Fn 0o17n 0b11n"[..]);
+
+    let expected = [
+        TokenKind::NumericLiteral(Numeric::BigInt(0.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(123.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(1000.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(31.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(15.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(3.into())),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn invalid_bigint_literals() {
+    let numbers = ["1.5n", "1e3n", "01n", "08n"];
+
+    for n in numbers.iter() {
+        let mut lexer = Lexer::new(n.as_bytes());
+        assert!(lexer.next().is_err());
+    }
+}
+
 #[test]
 fn big_exp_numbers() {
     let mut lexer = Lexer::new(&b"1.0e25 1.0e36 9.0e50"[..]);
@@ -585,6 +718,18 @@ fn regex_literal_flags() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn regex_literal_unicode_sets_flag() {
+    let mut lexer = Lexer::new(&br"/[\p{L}--[a-z]]/v"[..]);
+
+    let expected = [TokenKind::regular_expression_literal(
+        "[\\p{L}--[a-z]]",
+        RegExpFlags::UNICODE_SETS,
+    )];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::new(&b"1+1"[..]);
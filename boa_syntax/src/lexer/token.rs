@@ -8,10 +8,10 @@
 use super::regex::RegExpFlags;
 
 use crate::{
-    syntax::ast::{Keyword, Punctuator, Span},
-    syntax::lexer::template::TemplateString,
-    JsBigInt,
+    ast::{Keyword, Punctuator, Span},
+    lexer::template::TemplateString,
 };
+use num_bigint::BigInt;
 use std::fmt::{self, Debug, Display, Formatter};
 
 #[cfg(feature = "deser")]
@@ -69,7 +69,7 @@ pub enum Numeric {
     Integer(i32),
 
     // A BigInt
-    BigInt(JsBigInt),
+    BigInt(BigInt),
 }
 
 impl From<f64> for Numeric {
@@ -86,9 +86,9 @@ impl From<i32> for Numeric {
     }
 }
 
-impl From<JsBigInt> for Numeric {
+impl From<BigInt> for Numeric {
     #[inline]
-    fn from(n: JsBigInt) -> Self {
+    fn from(n: BigInt) -> Self {
         Self::BigInt(n)
     }
 }
@@ -106,6 +106,9 @@ pub enum TokenKind {
     /// An identifier.
     Identifier(Box<str>),
 
+    /// A private identifier, like `#field`, with the `#` removed.
+    PrivateIdentifier(Box<str>),
+
     /// A keyword.
     ///
     /// see: [`Keyword`](../keyword/enum.Keyword.html)
@@ -184,6 +187,14 @@ impl TokenKind {
         Self::Identifier(ident.into())
     }
 
+    /// Creates a `PrivateIdentifier` token type.
+    pub fn private_identifier<I>(ident: I) -> Self
+    where
+        I: Into<Box<str>>,
+    {
+        Self::PrivateIdentifier(ident.into())
+    }
+
     /// Creates a `Keyword` token kind.
     pub fn keyword(keyword: Keyword) -> Self {
         Self::Keyword(keyword)
@@ -244,6 +255,7 @@ impl Display for TokenKind {
             Self::BooleanLiteral(ref val) => write!(f, "{}", val),
             Self::EOF => write!(f, "end of file"),
             Self::Identifier(ref ident) => write!(f, "{}", ident),
+            Self::PrivateIdentifier(ref ident) => write!(f, "#{}", ident),
             Self::Keyword(ref word) => write!(f, "{}", word),
             Self::NullLiteral => write!(f, "null"),
             Self::NumericLiteral(Numeric::Rational(num)) => write!(f, "{}", num),
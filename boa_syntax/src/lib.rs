@@ -0,0 +1,62 @@
+/*!
+The front end of the Boa JavaScript engine: the lexer, the parser and the Abstract Syntax Tree (AST).
+
+This crate does not depend on the interpreter, so tools that only need to analyze JavaScript
+source code can use it without pulling in the rest of the engine.
+
+# Crate Features
+ - **deser** - Enables serialization and deserialization of the AST (Abstract Syntax Tree).
+**/
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/jasonwilliams/boa/master/assets/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/jasonwilliams/boa/master/assets/logo.svg"
+)]
+#![deny(
+    clippy::all,
+    unused_qualifications,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    deprecated_in_future,
+    meta_variable_misuse,
+    non_ascii_idents,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+#![warn(clippy::perf, clippy::single_match_else, clippy::dbg_macro)]
+#![allow(
+    clippy::missing_inline_in_public_items,
+    clippy::cognitive_complexity,
+    clippy::must_use_candidate,
+    clippy::missing_errors_doc,
+    clippy::as_conversions,
+    clippy::let_unit_value,
+    missing_doc_code_examples
+)]
+// syntax module has a lot of acronyms
+#![allow(clippy::upper_case_acronyms)]
+
+pub mod ast;
+pub mod lexer;
+pub mod parser;
+
+pub use lexer::Lexer;
+pub use parser::Parser;
+
+pub(crate) use boa_profiler::{self as profiler, BoaProfiler};
+
+/// The garbage collector traits implemented by the AST nodes, so that they can be stored in
+/// garbage collected objects.
+pub(crate) mod gc {
+    // This is because `rust-gc` unsafe_empty_trace has a `unsafe_`
+    // when it should be `empty_trace`.
+    #![allow(clippy::unsafe_removed_from_name)]
+
+    pub(crate) use ::gc::{unsafe_empty_trace as empty_trace, Finalize, Trace};
+}
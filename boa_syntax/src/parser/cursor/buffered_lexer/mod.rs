@@ -1,9 +1,7 @@
 use crate::{
+    lexer::{InputElement, Lexer, Position, Token, TokenKind},
+    parser::error::ParseError,
     profiler::BoaProfiler,
-    syntax::{
-        lexer::{InputElement, Lexer, Position, Token, TokenKind},
-        parser::error::ParseError,
-    },
 };
 use std::io::Read;
 
@@ -27,8 +25,15 @@ const PEEK_BUF_SIZE: usize = (MAX_PEEK_SKIP + 1) * 2;
 pub(super) struct BufferedLexer<R> {
     lexer: Lexer<R>,
     peeked: [Option<Token>; PEEK_BUF_SIZE],
+    /// The byte offsets of the start and the end of the peeked tokens.
+    peeked_offsets: [(usize, usize); PEEK_BUF_SIZE],
     read_index: usize,
     write_index: usize,
+    /// The byte offsets of the start and the end of the last token consumed, which is not a line
+    /// terminator.
+    last_offsets: (usize, usize),
+    /// The position of the end of the last token consumed, which is not a line terminator.
+    last_end: Position,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -49,8 +54,11 @@ where
                 None::<Token>,
                 None::<Token>,
             ],
+            peeked_offsets: [(0, 0); PEEK_BUF_SIZE],
             read_index: 0,
             write_index: 0,
+            last_offsets: (0, 0),
+            last_end: Position::new(1, 1),
         }
     }
 }
@@ -81,13 +89,51 @@ where
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
         let _timer = BoaProfiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer.lex_slash_token(start).map_err(|e| e.into())
+        let token = self.lexer.lex_slash_token(start)?;
+        self.last_offsets.1 = self.lexer.offset();
+        self.last_end = token.span().end();
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
     /// '}' has already been consumed.
     pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.lexer.lex_template(start).map_err(ParseError::from)
+        let token = self.lexer.lex_template(start)?;
+        self.last_offsets.1 = self.lexer.offset();
+        self.last_end = token.span().end();
+        Ok(token)
+    }
+
+    /// Gets the byte offsets of the start and the end of the last token consumed.
+    ///
+    /// A regular expression or a template continuation lexed after its first character was
+    /// consumed as a punctuator extends the punctuator token.
+    #[inline]
+    pub(super) fn last_offsets(&self) -> (usize, usize) {
+        self.last_offsets
+    }
+
+    /// Gets the position of the end of the last token consumed.
+    #[inline]
+    pub(super) fn last_end(&self) -> Position {
+        self.last_end
+    }
+
+    /// Gets the byte offset of the start of the next token, skipping line terminators.
+    pub(super) fn peek_start_offset(&mut self) -> Result<usize, ParseError> {
+        self.peek(0, true)?;
+        let mut index = self.read_index;
+        if matches!(self.peeked[index], Some(ref token) if token.kind() == &TokenKind::LineTerminator)
+        {
+            index = (index + 1) % PEEK_BUF_SIZE;
+        }
+        Ok(self.peeked_offsets[index].0)
+    }
+
+    /// Gets the source code between the byte offsets `start` and `end`.
+    #[inline]
+    pub(super) fn source_text(&self, start: usize, end: usize) -> String {
+        self.lexer.source_text(start, end)
     }
 
     #[inline]
@@ -133,6 +179,7 @@ where
         } else {
             self.peeked[self.write_index] = self.lexer.next()?;
         }
+        self.peeked_offsets[self.write_index] = self.lexer.token_offsets();
         self.write_index = (self.write_index + 1) % PEEK_BUF_SIZE;
 
         debug_assert_ne!(
@@ -172,6 +219,13 @@ where
                 }
             }
             let tok = self.peeked[self.read_index].take();
+            match tok {
+                Some(ref token) if token.kind() != &TokenKind::LineTerminator => {
+                    self.last_offsets = self.peeked_offsets[self.read_index];
+                    self.last_end = token.span().end();
+                }
+                _ => {}
+            }
             self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
 
             Ok(tok)
@@ -1,5 +1,5 @@
 use super::BufferedLexer;
-use crate::syntax::lexer::{Token, TokenKind};
+use crate::lexer::{Token, TokenKind};
 
 #[test]
 fn peek_skip_accending() {
@@ -0,0 +1,385 @@
+//! Cursor implementation for the parser.
+mod buffered_lexer;
+
+use super::ParseError;
+use crate::{
+    ast::Punctuator,
+    lexer::{Error as LexError, InputElement, Lexer, Position, Token, TokenKind},
+};
+use buffered_lexer::BufferedLexer;
+use std::{collections::HashSet, io::Read};
+
+/// The result of a peek for a semicolon.
+#[derive(Debug)]
+pub(super) enum SemicolonResult<'s> {
+    Found(Option<&'s Token>),
+    NotFound(&'s Token),
+}
+
+/// The statements that `break` and `continue` statements can target, in the function being
+/// parsed.
+#[derive(Debug, Default)]
+pub(super) struct BreakTargets {
+    /// The labels of the enclosing labelled statements, with the label set on the iteration
+    /// statement they label, if any.
+    labels: Vec<(Box<str>, Option<Box<str>>)>,
+    /// The number of enclosing iteration statements.
+    iterations: usize,
+    /// The number of enclosing `switch` statements.
+    switches: usize,
+}
+
+/// Token cursor.
+///
+/// This internal structure gives basic testable operations to the parser.
+#[derive(Debug)]
+pub(super) struct Cursor<R> {
+    buffered_lexer: BufferedLexer<R>,
+    break_targets: BreakTargets,
+    /// The private names used in the bodies of the classes being parsed, innermost class last,
+    /// with the position where they are used, which have to be declared by an enclosing class.
+    private_names: Vec<Vec<(Box<str>, Position)>>,
+    /// If `super(...)` calls are allowed in the code being parsed.
+    super_call: bool,
+}
+
+impl<R> Cursor<R>
+where
+    R: Read,
+{
+    /// Creates a new cursor with the given reader.
+    #[inline]
+    pub(super) fn new(reader: R) -> Self {
+        Self {
+            buffered_lexer: Lexer::new(reader).into(),
+            break_targets: BreakTargets::default(),
+            private_names: Vec::new(),
+            super_call: false,
+        }
+    }
+
+    #[inline]
+    pub(super) fn set_goal(&mut self, elm: InputElement) {
+        self.buffered_lexer.set_goal(elm)
+    }
+
+    #[inline]
+    pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
+        self.buffered_lexer.lex_regex(start)
+    }
+
+    #[inline]
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        self.buffered_lexer.lex_template(start)
+    }
+
+    #[inline]
+    pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
+        self.buffered_lexer.next(true)
+    }
+
+    #[inline]
+    pub(super) fn peek(&mut self, skip_n: usize) -> Result<Option<&Token>, ParseError> {
+        self.buffered_lexer.peek(skip_n, true)
+    }
+
+    /// Gets the byte offset of the start of the last token consumed.
+    ///
+    /// This is where the source text of a function starting with this token begins.
+    #[inline]
+    pub(super) fn source_start(&self) -> usize {
+        self.buffered_lexer.last_offsets().0
+    }
+
+    /// Gets the byte offset of the start of the next token.
+    ///
+    /// This is where the source text of a function starting with this token begins.
+    #[inline]
+    pub(super) fn peek_source_start(&mut self) -> Result<usize, ParseError> {
+        self.buffered_lexer.peek_start_offset()
+    }
+
+    /// Gets the source text from the byte offset `start` to the end of the last token consumed.
+    #[inline]
+    pub(super) fn source_text_from(&self, start: usize) -> Box<str> {
+        let end = self.buffered_lexer.last_offsets().1;
+        self.buffered_lexer.source_text(start, end).into()
+    }
+
+    /// Gets the position of the end of the last token consumed.
+    ///
+    /// This is where the span of a node ending with this token ends.
+    #[inline]
+    pub(super) fn last_end(&self) -> Position {
+        self.buffered_lexer.last_end()
+    }
+
+    #[inline]
+    pub(super) fn strict_mode(&self) -> bool {
+        self.buffered_lexer.strict_mode()
+    }
+
+    #[inline]
+    pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
+        self.buffered_lexer.set_strict_mode(strict_mode)
+    }
+
+    /// Takes the break targets of the current function, leaving none, before parsing the body of
+    /// a nested function.
+    #[inline]
+    pub(super) fn take_break_targets(&mut self) -> BreakTargets {
+        std::mem::take(&mut self.break_targets)
+    }
+
+    /// Restores the break targets of the current function, once the body of a nested function
+    /// has been parsed.
+    #[inline]
+    pub(super) fn set_break_targets(&mut self, break_targets: BreakTargets) {
+        self.break_targets = break_targets;
+    }
+
+    /// Enters a labelled statement, whose statement is an iteration statement with the label
+    /// `loop_label` set if `loop_label` is not `None`.
+    #[inline]
+    pub(super) fn push_label(&mut self, label: Box<str>, loop_label: Option<Box<str>>) {
+        self.break_targets.labels.push((label, loop_label));
+    }
+
+    /// Exits the innermost labelled statement.
+    #[inline]
+    pub(super) fn pop_label(&mut self) {
+        self.break_targets.labels.pop();
+    }
+
+    /// Checks if the statement being parsed is in a statement labelled `label`.
+    #[inline]
+    pub(super) fn has_label(&self, label: &str) -> bool {
+        self.break_targets
+            .labels
+            .iter()
+            .any(|(name, _)| name.as_ref() == label)
+    }
+
+    /// Gets the label set on the iteration statement labelled `label`, if `label` labels an
+    /// iteration statement.
+    #[inline]
+    pub(super) fn loop_label(&self, label: &str) -> Option<&str> {
+        self.break_targets
+            .labels
+            .iter()
+            .rev()
+            .find(|(name, _)| name.as_ref() == label)
+            .and_then(|(_, loop_label)| loop_label.as_deref())
+    }
+
+    /// Enters an iteration statement, if `iteration` is true, or a `switch` statement.
+    #[inline]
+    pub(super) fn enter_breakable(&mut self, iteration: bool) {
+        if iteration {
+            self.break_targets.iterations += 1;
+        } else {
+            self.break_targets.switches += 1;
+        }
+    }
+
+    /// Exits an iteration statement, if `iteration` is true, or a `switch` statement.
+    #[inline]
+    pub(super) fn exit_breakable(&mut self, iteration: bool) {
+        if iteration {
+            self.break_targets.iterations -= 1;
+        } else {
+            self.break_targets.switches -= 1;
+        }
+    }
+
+    /// Checks if the statement being parsed is in an iteration statement.
+    #[inline]
+    pub(super) fn in_iteration(&self) -> bool {
+        self.break_targets.iterations > 0
+    }
+
+    /// Checks if the statement being parsed is in an iteration or a `switch` statement.
+    #[inline]
+    pub(super) fn in_breakable(&self) -> bool {
+        self.break_targets.iterations > 0 || self.break_targets.switches > 0
+    }
+
+    /// Enters the body of a class, which can use the private names it declares.
+    #[inline]
+    pub(super) fn push_private_environment(&mut self) {
+        self.private_names.push(Vec::new());
+    }
+
+    /// Exits the body of a class declaring the private names `declared`.
+    ///
+    /// The other private names used in the body have to be declared by an enclosing class, so
+    /// this returns an error if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-class-definitions-static-semantics-early-errors
+    pub(super) fn pop_private_environment(
+        &mut self,
+        declared: &HashSet<Box<str>>,
+    ) -> Result<(), ParseError> {
+        let used = self
+            .private_names
+            .pop()
+            .expect("the private environment of a class");
+        for (name, position) in used {
+            if !declared.contains(&name) {
+                self.use_private_name(name, position)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Uses the private name `name` at `position`, which is an error outside of a class body.
+    #[inline]
+    pub(super) fn use_private_name(
+        &mut self,
+        name: Box<str>,
+        position: Position,
+    ) -> Result<(), ParseError> {
+        match self.private_names.last_mut() {
+            Some(used) => {
+                used.push((name, position));
+                Ok(())
+            }
+            None => Err(ParseError::lex(LexError::Syntax(
+                format!("private name #{} is not defined", name).into(),
+                position,
+            ))),
+        }
+    }
+
+    /// Checks if `super(...)` calls are allowed in the code being parsed, which is the case in
+    /// the constructor of a derived class and in the arrow functions it contains.
+    #[inline]
+    pub(super) fn super_call_allowed(&self) -> bool {
+        self.super_call
+    }
+
+    /// Allows or disallows `super(...)` calls in the code being parsed, returning if they were
+    /// allowed before.
+    #[inline]
+    pub(super) fn set_super_call_allowed(&mut self, allowed: bool) -> bool {
+        std::mem::replace(&mut self.super_call, allowed)
+    }
+
+    /// Returns an error if the next token is not of kind `kind`.
+    #[inline]
+    pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
+    where
+        K: Into<TokenKind>,
+    {
+        let next_token = self.next()?.ok_or(ParseError::AbruptEnd)?;
+        let kind = kind.into();
+
+        if next_token.kind() == &kind {
+            Ok(next_token)
+        } else {
+            Err(ParseError::expected(vec![kind], next_token, context))
+        }
+    }
+
+    /// It will peek for the next token, to see if it's a semicolon.
+    ///
+    /// It will automatically insert a semicolon if needed, as specified in the [spec][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-automatic-semicolon-insertion
+    #[inline]
+    pub(super) fn peek_semicolon(&mut self) -> Result<SemicolonResult<'_>, ParseError> {
+        match self.buffered_lexer.peek(0, false)? {
+            Some(tk) => match tk.kind() {
+                TokenKind::Punctuator(Punctuator::Semicolon)
+                | TokenKind::LineTerminator
+                | TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                    Ok(SemicolonResult::Found(Some(tk)))
+                }
+                _ => Ok(SemicolonResult::NotFound(tk)),
+            },
+            None => Ok(SemicolonResult::Found(None)),
+        }
+    }
+
+    /// Consumes the next token if it is a semicolon, or returns a `ParseError` if it's not.
+    ///
+    /// It will automatically insert a semicolon if needed, as specified in the [spec][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-automatic-semicolon-insertion
+    #[inline]
+    pub(super) fn expect_semicolon(&mut self, context: &'static str) -> Result<(), ParseError> {
+        match self.peek_semicolon()? {
+            SemicolonResult::Found(Some(tk)) => match *tk.kind() {
+                TokenKind::Punctuator(Punctuator::Semicolon) | TokenKind::LineTerminator => {
+                    let _ = self.buffered_lexer.next(false)?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            SemicolonResult::Found(None) => Ok(()),
+            SemicolonResult::NotFound(tk) => Err(ParseError::expected(
+                vec![TokenKind::Punctuator(Punctuator::Semicolon)],
+                tk.clone(),
+                context,
+            )),
+        }
+    }
+
+    /// It will make sure that the peeked token (skipping n tokens) is not a line terminator.
+    ///
+    /// It expects that the token stream does not end here.
+    ///
+    /// This is just syntatic sugar for a .peek(skip_n) call followed by a check that the result is not a line terminator or None.
+    #[inline]
+    pub(super) fn peek_expect_no_lineterminator(
+        &mut self,
+        skip_n: usize,
+        context: &'static str,
+    ) -> Result<&Token, ParseError> {
+        if let Some(t) = self.buffered_lexer.peek(skip_n, false)? {
+            if t.kind() == &TokenKind::LineTerminator {
+                Err(ParseError::unexpected(t.clone(), context))
+            } else {
+                Ok(t)
+            }
+        } else {
+            Err(ParseError::AbruptEnd)
+        }
+    }
+
+    /// Checks if the peeked token (skipping n tokens) is a line terminator.
+    ///
+    /// This is used by the restricted productions, which don't allow a line terminator at some
+    /// position, and have a semicolon inserted there instead.
+    #[inline]
+    pub(super) fn peek_is_line_terminator(&mut self, skip_n: usize) -> Result<bool, ParseError> {
+        Ok(matches!(
+            self.buffered_lexer.peek(skip_n, false)?,
+            Some(tk) if tk.kind() == &TokenKind::LineTerminator
+        ))
+    }
+
+    /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
+    ///
+    /// When the next token is a `kind` token, get the token, otherwise return `None`.
+    ///
+    /// No next token also returns None.
+    #[inline]
+    pub(super) fn next_if<K>(&mut self, kind: K) -> Result<Option<Token>, ParseError>
+    where
+        K: Into<TokenKind>,
+    {
+        Ok(if let Some(token) = self.peek(0)? {
+            if token.kind() == &kind.into() {
+                self.next()?
+            } else {
+                None
+            }
+        } else {
+            None
+        })
+    }
+}
@@ -1,8 +1,8 @@
 //! Error and result implementation for the parser.
 
-use crate::syntax::ast::{position::Position, Node};
-use crate::syntax::lexer::{Error as LexError, Token, TokenKind};
-use std::fmt;
+use crate::ast::{position::Position, Node};
+use crate::lexer::{Error as LexError, Token, TokenKind};
+use std::{error::Error, fmt};
 
 /// Result of a parsing operation.
 pub type ParseResult = Result<Node, ParseError>;
@@ -96,12 +96,6 @@ impl ParseError {
     pub(super) fn lex(e: LexError) -> Self {
         Self::Lex { err: e }
     }
-
-    /// Creates a new `Unimplemented` parsing error.
-    #[allow(dead_code)]
-    pub(super) fn unimplemented(message: &'static str, position: Position) -> Self {
-        Self::Unimplemented { message, position }
-    }
 }
 
 impl fmt::Display for ParseError {
@@ -177,3 +171,12 @@ impl fmt::Display for ParseError {
         }
     }
 }
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Lex { err } => Some(err),
+            _ => None,
+        }
+    }
+}
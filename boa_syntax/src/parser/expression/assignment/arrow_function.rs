@@ -9,18 +9,16 @@
 
 use super::AssignmentExpression;
 use crate::{
-    syntax::{
-        ast::{
-            node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
-            Punctuator,
-        },
-        lexer::{Error as LexError, Position, TokenKind},
-        parser::{
-            error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
-            statement::BindingIdentifier,
-            AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
-        },
+    ast::{
+        node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
+        Punctuator,
+    },
+    lexer::{Error as LexError, Position, TokenKind},
+    parser::{
+        error::{ErrorContext, ParseError, ParseResult},
+        function::{FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
+        AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
     },
     BoaProfiler,
 };
@@ -36,7 +34,7 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
 /// [spec]: https://tc39.es/ecma262/#prod-ArrowFunction
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser) struct ArrowFunction {
+pub(in crate::parser) struct ArrowFunction {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
@@ -44,11 +42,7 @@ pub(in crate::syntax::parser) struct ArrowFunction {
 
 impl ArrowFunction {
     /// Creates a new `ArrowFunction` parser.
-    pub(in crate::syntax::parser) fn new<I, Y, A>(
-        allow_in: I,
-        allow_yield: Y,
-        allow_await: A,
-    ) -> Self
+    pub(in crate::parser) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
         Y: Into<AllowYield>,
@@ -70,6 +64,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
+        let source_start = cursor.peek_source_start()?;
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
@@ -89,28 +84,47 @@ where
         cursor.peek_expect_no_lineterminator(0, "arrow function")?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
-
-        // It is a Syntax Error if any element of the BoundNames of ArrowParameters
-        // also occurs in the LexicallyDeclaredNames of ConciseBody.
-        // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
-        {
-            let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
-                    return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
-                        match cursor.peek(0)? {
-                            Some(token) => token.span().end(),
-                            None => Position::new(1, 1),
-                        },
-                    )));
-                }
+        parse_arrow_function_body(cursor, params, self.allow_in, source_start)
+    }
+}
+
+/// Parses the `ConciseBody` of an arrow function whose parameters have already been parsed,
+/// either directly or by reinterpreting a parenthesized expression.
+///
+/// The source text of the arrow function starts at the byte offset `source_start`.
+pub(super) fn parse_arrow_function_body<R>(
+    cursor: &mut Cursor<R>,
+    params: Box<[FormalParameter]>,
+    allow_in: AllowIn,
+    source_start: usize,
+) -> Result<ArrowFunctionDecl, ParseError>
+where
+    R: Read,
+{
+    let body = ConciseBody::new(allow_in).parse(cursor)?;
+
+    // It is a Syntax Error if any element of the BoundNames of ArrowParameters
+    // also occurs in the LexicallyDeclaredNames of ConciseBody.
+    // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
+    {
+        let lexically_declared_names = body.lexically_declared_names();
+        for param_name in params.iter().flat_map(|param| param.names()) {
+            if lexically_declared_names.contains(param_name) {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("Redeclaration of formal parameter `{}`", param_name).into(),
+                    match cursor.peek(0)? {
+                        Some(token) => token.span().end(),
+                        None => Position::new(1, 1),
+                    },
+                )));
             }
         }
-
-        Ok(ArrowFunctionDecl::new(params, body))
     }
+
+    Ok(
+        ArrowFunctionDecl::new(params, body)
+            .with_source_text(cursor.source_text_from(source_start)),
+    )
 }
 
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
@@ -141,7 +155,9 @@ where
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::new(false, false)
+                    .with_super_call(cursor.super_call_allowed())
+                    .parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
@@ -149,7 +165,8 @@ where
                 ExpressionBody::new(self.allow_in, false).parse(cursor)?,
                 None,
             )
-            .into()])),
+            .into()])
+            .with_strict(cursor.strict_mode())),
         }
     }
 }
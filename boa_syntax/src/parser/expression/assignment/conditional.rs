@@ -7,14 +7,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Conditional_Operator
 //! [spec]: https://tc39.es/ecma262/#sec-conditional-operator
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::ConditionalOp, Node, Punctuator},
-        parser::{
-            expression::{AssignmentExpression, ShortCircuitExpression},
-            AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser,
-        },
+    ast::{node::ConditionalOp, Node, Punctuator},
+    parser::{
+        expression::{AssignmentExpression, ShortCircuitExpression},
+        AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser,
     },
     BoaProfiler,
 };
@@ -30,7 +28,7 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Conditional_Operator
 /// [spec]: https://tc39.es/ecma262/#prod-ConditionalExpression
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::expression) struct ConditionalExpression {
+pub(in crate::parser::expression) struct ConditionalExpression {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
@@ -38,7 +36,7 @@ pub(in crate::syntax::parser::expression) struct ConditionalExpression {
 
 impl ConditionalExpression {
     /// Creates a new `ConditionalExpression` parser.
-    pub(in crate::syntax::parser::expression) fn new<I, Y, A>(
+    pub(in crate::parser::expression) fn new<I, Y, A>(
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
@@ -8,18 +8,16 @@
 //! [spec]: https://tc39.es/ecma262/#sec-exp-operator
 
 use super::ParseError;
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{
-            node::{BinOp, Node},
-            op::NumOp,
-            Keyword, Punctuator,
-        },
-        parser::{
-            expression::{unary::UnaryExpression, update::UpdateExpression},
-            AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
-        },
+    ast::{
+        node::{BinOp, Node},
+        op::NumOp,
+        Keyword, Punctuator,
+    },
+    parser::{
+        expression::{unary::UnaryExpression, update::UpdateExpression},
+        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
     },
     BoaProfiler,
 };
@@ -35,14 +33,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Arithmetic_Operators#Exponentiation
 /// [spec]: https://tc39.es/ecma262/#prod-ExponentiationExpression
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::expression) struct ExponentiationExpression {
+pub(in crate::parser::expression) struct ExponentiationExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl ExponentiationExpression {
     /// Creates a new `ExponentiationExpression` parser.
-    pub(in crate::syntax::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -55,21 +53,25 @@ impl ExponentiationExpression {
 }
 
 /// Checks by looking at the next token to see whether it's a unary operator or not.
-fn is_unary_expression<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+fn is_unary_expression<R>(
+    cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
+) -> Result<bool, ParseError>
 where
     R: Read,
 {
     Ok(if let Some(tok) = cursor.peek(0)? {
-        matches!(
-            tok.kind(),
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Await) => allow_await.0,
             TokenKind::Keyword(Keyword::Delete)
-                | TokenKind::Keyword(Keyword::Void)
-                | TokenKind::Keyword(Keyword::TypeOf)
-                | TokenKind::Punctuator(Punctuator::Add)
-                | TokenKind::Punctuator(Punctuator::Sub)
-                | TokenKind::Punctuator(Punctuator::Not)
-                | TokenKind::Punctuator(Punctuator::Neg)
-        )
+            | TokenKind::Keyword(Keyword::Void)
+            | TokenKind::Keyword(Keyword::TypeOf)
+            | TokenKind::Punctuator(Punctuator::Add)
+            | TokenKind::Punctuator(Punctuator::Sub)
+            | TokenKind::Punctuator(Punctuator::Not)
+            | TokenKind::Punctuator(Punctuator::Neg) => true,
+            _ => false,
+        }
     } else {
         false
     })
@@ -84,7 +86,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
 
-        if is_unary_expression(cursor)? {
+        if is_unary_expression(cursor, self.allow_await)? {
             return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
         }
 
@@ -0,0 +1,557 @@
+//! Assignment operator parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Assignment_Operators#Assignment
+//! [spec]: https://tc39.es/ecma262/#sec-assignment-operators
+
+mod arrow_function;
+mod conditional;
+mod exponentiation;
+mod r#yield;
+
+use self::{
+    arrow_function::{parse_arrow_function_body, ArrowFunction},
+    conditional::ConditionalExpression,
+    r#yield::YieldExpression,
+};
+use crate::lexer::{Error as LexError, InputElement, TokenKind};
+use crate::{
+    ast::{
+        node::{
+            declaration::{
+                BindingPatternTypeArray, BindingPatternTypeObject, DeclarationPattern,
+                DeclarationPatternArray, DeclarationPatternObject,
+            },
+            ArrayDecl, Assign, AssignTarget, BinOp, FormalParameter, Node, Object,
+            PropertyDefinition,
+        },
+        op::BinOp as BinOpKind,
+        Const, Keyword, Punctuator,
+    },
+    parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+    BoaProfiler,
+};
+pub(super) use exponentiation::ExponentiationExpression;
+
+use std::io::Read;
+
+/// Assignment expression parsing.
+///
+/// This can be one of the following:
+///
+///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
+///  - `YieldExpression`
+///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
+///  - `AsyncArrowFunction`
+///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
+///  - [`LeftHandSideExpression`][lhs] `AssignmentOperator` `AssignmentExpression`
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Assignment_Operators#Assignment
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentExpression
+/// [lhs]: ../lhs_expression/struct.LeftHandSideExpression.html
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct AssignmentExpression {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl AssignmentExpression {
+    /// Creates a new `AssignmentExpression` parser.
+    pub(in crate::parser) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AssignmentExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
+        cursor.set_goal(InputElement::Div);
+
+        // Yield expression, only allowed in generator functions.
+        if self.allow_yield.0
+            && cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+                == &TokenKind::Keyword(Keyword::Yield)
+        {
+            return YieldExpression::new(self.allow_in, self.allow_await)
+                .parse(cursor)
+                .map(Node::from);
+        }
+
+        // Arrow function
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            // a=>{}
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(Keyword::Yield)
+            | TokenKind::Keyword(Keyword::Await) => {
+                if let Ok(tok) = cursor.peek_expect_no_lineterminator(1, "assignment expression") {
+                    if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                        return ArrowFunction::new(
+                            self.allow_in,
+                            self.allow_yield,
+                            self.allow_await,
+                        )
+                        .parse(cursor)
+                        .map(Node::ArrowFunctionDecl);
+                    }
+                }
+            }
+
+            // (a,b)=>{} or (a,b) or (Expression)
+            TokenKind::Punctuator(Punctuator::OpenParen) => {
+                if let Some(next_token) = cursor.peek(1)? {
+                    match *next_token.kind() {
+                        TokenKind::Punctuator(Punctuator::CloseParen) => {
+                            // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
+                            // otherwise it is an expression of the form (b).
+                            if let Some(t) = cursor.peek(2)? {
+                                if t.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                                    return ArrowFunction::new(
+                                        self.allow_in,
+                                        self.allow_yield,
+                                        self.allow_await,
+                                    )
+                                    .parse(cursor)
+                                    .map(Node::ArrowFunctionDecl);
+                                }
+                            }
+                        }
+                        TokenKind::Punctuator(Punctuator::Spread) => {
+                            return ArrowFunction::new(
+                                self.allow_in,
+                                self.allow_yield,
+                                self.allow_await,
+                            )
+                            .parse(cursor)
+                            .map(Node::ArrowFunctionDecl);
+                        }
+                        TokenKind::Identifier(_) => {
+                            if let Some(t) = cursor.peek(2)? {
+                                match *t.kind() {
+                                    TokenKind::Punctuator(Punctuator::Comma) => {
+                                        // This must be an argument list and therefore (a, b) => {}
+                                        return ArrowFunction::new(
+                                            self.allow_in,
+                                            self.allow_yield,
+                                            self.allow_await,
+                                        )
+                                        .parse(cursor)
+                                        .map(Node::ArrowFunctionDecl);
+                                    }
+                                    TokenKind::Punctuator(Punctuator::CloseParen) => {
+                                        // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
+                                        // otherwise it is an expression of the form (b).
+                                        if let Some(t) = cursor.peek(3)? {
+                                            if t.kind() == &TokenKind::Punctuator(Punctuator::Arrow)
+                                            {
+                                                return ArrowFunction::new(
+                                                    self.allow_in,
+                                                    self.allow_yield,
+                                                    self.allow_await,
+                                                )
+                                                .parse(cursor)
+                                                .map(Node::ArrowFunctionDecl);
+                                            }
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        cursor.set_goal(InputElement::Div);
+
+        let starts_with_paren = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Punctuator(Punctuator::OpenParen);
+        let source_start = cursor.peek_source_start()?;
+
+        let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+            .parse(cursor)?;
+
+        // A parenthesized expression followed by `=>` is reparsed as the
+        // ArrowFormalParameters of a CoverParenthesizedExpressionAndArrowParameterList.
+        if starts_with_paren {
+            if let Some(tok) = cursor.peek(0)?.cloned() {
+                if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                    cursor.peek_expect_no_lineterminator(0, "arrow function")?;
+                    let params = expression_to_formal_parameters(&lhs).ok_or_else(|| {
+                        ParseError::general("invalid arrow function parameters", tok.span().start())
+                    })?;
+                    cursor.next()?.expect("=> token vanished"); // Consume the token.
+                    return parse_arrow_function_body(cursor, params, self.allow_in, source_start)
+                        .map(Node::ArrowFunctionDecl);
+                }
+            }
+        }
+
+        // Review if we are trying to assign to an invalid left hand side expression.
+        // TODO: can we avoid cloning?
+        if let Some(tok) = cursor.peek(0)?.cloned() {
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Assign) => {
+                    cursor.next()?.expect("= token vanished"); // Consume the token.
+                    if let Some(target) = node_to_assign_target(&lhs) {
+                        lhs = Assign::new(target, self.parse(cursor)?).into();
+                    } else {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "Invalid left-hand side in assignment".into(),
+                            tok.span().start(),
+                        )));
+                    }
+                }
+                TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
+                    cursor.next()?.expect("token vanished"); // Consume the token.
+                    if is_assignable(&lhs) {
+                        let binop = p.as_binop().expect("binop disappeared");
+                        let expr = self.parse(cursor)?;
+
+                        lhs = BinOp::new(binop, lhs, expr).into();
+                    } else {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "Invalid left-hand side in assignment".into(),
+                            tok.span().start(),
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(lhs)
+    }
+}
+
+/// Returns true if as per spec[spec] the node is a simple assignment target, which compound
+/// assignments and update expressions can assign a value to.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-assignmenttargettype
+#[inline]
+pub(crate) fn is_assignable(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::GetConstField(_)
+            | Node::GetField(_)
+            | Node::GetPrivateField(_)
+            | Node::GetSuperField(_)
+            | Node::Identifier(_)
+    )
+}
+
+/// Converts the left hand side of an `=` assignment to its target, reinterpreting object and
+/// array literals as an [`AssignmentPattern`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentPattern
+fn node_to_assign_target(node: &Node) -> Option<AssignTarget> {
+    match node {
+        Node::Object(object) => {
+            object_decl_to_declaration_pattern(object, false).map(AssignTarget::DeclarationPattern)
+        }
+        Node::ArrayDecl(array) => {
+            array_decl_to_declaration_pattern(array, false).map(AssignTarget::DeclarationPattern)
+        }
+        _ => AssignTarget::from_node(node),
+    }
+}
+
+/// Reinterprets the parenthesized expression of a `CoverParenthesizedExpressionAndArrowParameterList`
+/// as the formal parameters of an arrow function.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrowFormalParameters
+fn expression_to_formal_parameters(node: &Node) -> Option<Box<[FormalParameter]>> {
+    let mut nodes = Vec::new();
+    let mut current = node;
+    while let Node::BinOp(bin_op) = current {
+        if bin_op.op() != BinOpKind::Comma {
+            break;
+        }
+        nodes.push(bin_op.rhs());
+        current = bin_op.lhs();
+    }
+    nodes.push(current);
+    nodes.reverse();
+
+    let mut params = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        let param = match node {
+            Node::Identifier(ident) => FormalParameter::new(ident.as_ref(), None, false),
+            Node::Assign(assign) => match assign.lhs() {
+                AssignTarget::Identifier(ident) => {
+                    FormalParameter::new(ident.as_ref(), Some(assign.rhs().clone()), false)
+                }
+                AssignTarget::DeclarationPattern(pattern) => FormalParameter::new_with_pattern(
+                    pattern_with_init(pattern, assign.rhs().clone()),
+                    false,
+                ),
+                _ => return None,
+            },
+            Node::Object(object) => FormalParameter::new_with_pattern(
+                object_decl_to_declaration_pattern(object, true)?,
+                false,
+            ),
+            Node::ArrayDecl(array) => FormalParameter::new_with_pattern(
+                array_decl_to_declaration_pattern(array, true)?,
+                false,
+            ),
+            _ => return None,
+        };
+        params.push(param);
+    }
+
+    let mut names = Vec::new();
+    for name in params.iter().flat_map(|param| param.names()) {
+        if names.contains(&name) {
+            return None;
+        }
+        names.push(name);
+    }
+
+    Some(params.into_boxed_slice())
+}
+
+/// Returns a copy of the pattern that uses `init` when the destructured value is `undefined`.
+fn pattern_with_init(pattern: &DeclarationPattern, init: Node) -> DeclarationPattern {
+    match pattern {
+        DeclarationPattern::Object(pattern) => DeclarationPattern::Object(
+            DeclarationPatternObject::new(pattern.bindings().to_vec(), Some(init)),
+        ),
+        DeclarationPattern::Array(pattern) => DeclarationPattern::Array(
+            DeclarationPatternArray::new(pattern.bindings().to_vec(), Some(init)),
+        ),
+    }
+}
+
+/// Converts an object literal to an object destructuring pattern.
+///
+/// If `binding` is true the pattern can only bind identifiers, otherwise property accesses are
+/// valid assignment targets too.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectAssignmentPattern
+pub(in crate::parser) fn object_decl_to_declaration_pattern(
+    object: &Object,
+    binding: bool,
+) -> Option<DeclarationPattern> {
+    let mut bindings = Vec::new();
+    let mut excluded_keys = Vec::new();
+    let properties = object.properties();
+    for (i, property) in properties.iter().enumerate() {
+        match property {
+            PropertyDefinition::IdentifierReference(ident) => {
+                bindings.push(BindingPatternTypeObject::SingleName {
+                    ident: ident.clone(),
+                    property_name: ident.clone(),
+                    default_init: None,
+                });
+                excluded_keys.push(ident.clone());
+            }
+            PropertyDefinition::Property(name, node) => {
+                let pattern = match node {
+                    Node::Identifier(ident) => BindingPatternTypeObject::SingleName {
+                        ident: ident.as_ref().into(),
+                        property_name: name.clone(),
+                        default_init: None,
+                    },
+                    Node::Assign(assign) => match assign.lhs() {
+                        AssignTarget::Identifier(ident) => BindingPatternTypeObject::SingleName {
+                            ident: ident.as_ref().into(),
+                            property_name: name.clone(),
+                            default_init: Some(assign.rhs().clone()),
+                        },
+                        AssignTarget::DeclarationPattern(pattern) => {
+                            BindingPatternTypeObject::BindingPattern {
+                                ident: name.clone(),
+                                pattern: pattern.clone(),
+                                default_init: Some(assign.rhs().clone()),
+                            }
+                        }
+                        AssignTarget::GetConstField(field) if !binding => {
+                            BindingPatternTypeObject::AssignmentPropertyAccess {
+                                property_name: name.clone(),
+                                access: field.clone().into(),
+                                default_init: Some(assign.rhs().clone()),
+                            }
+                        }
+                        AssignTarget::GetField(field) if !binding => {
+                            BindingPatternTypeObject::AssignmentPropertyAccess {
+                                property_name: name.clone(),
+                                access: field.clone().into(),
+                                default_init: Some(assign.rhs().clone()),
+                            }
+                        }
+                        _ => return None,
+                    },
+                    Node::Object(object) => BindingPatternTypeObject::BindingPattern {
+                        ident: name.clone(),
+                        pattern: object_decl_to_declaration_pattern(object, binding)?,
+                        default_init: None,
+                    },
+                    Node::ArrayDecl(array) => BindingPatternTypeObject::BindingPattern {
+                        ident: name.clone(),
+                        pattern: array_decl_to_declaration_pattern(array, binding)?,
+                        default_init: None,
+                    },
+                    Node::GetConstField(_) | Node::GetField(_) if !binding => {
+                        BindingPatternTypeObject::AssignmentPropertyAccess {
+                            property_name: name.clone(),
+                            access: node.clone(),
+                            default_init: None,
+                        }
+                    }
+                    _ => return None,
+                };
+                bindings.push(pattern);
+                excluded_keys.push(name.clone());
+            }
+            PropertyDefinition::CoverInitializedName(ident, init) => {
+                bindings.push(BindingPatternTypeObject::SingleName {
+                    ident: ident.clone(),
+                    property_name: ident.clone(),
+                    default_init: Some(init.clone()),
+                });
+                excluded_keys.push(ident.clone());
+            }
+            PropertyDefinition::SpreadObject(node) if i == properties.len() - 1 => match node {
+                Node::Identifier(ident) => {
+                    bindings.push(BindingPatternTypeObject::RestProperty {
+                        ident: ident.as_ref().into(),
+                        excluded_keys: excluded_keys.clone(),
+                    });
+                }
+                Node::GetConstField(_) | Node::GetField(_) if !binding => {
+                    bindings.push(BindingPatternTypeObject::AssignmentRestProperty {
+                        access: node.clone(),
+                        excluded_keys: excluded_keys.clone(),
+                    });
+                }
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+
+    if bindings.is_empty() {
+        bindings.push(BindingPatternTypeObject::Empty);
+    }
+
+    Some(DeclarationPattern::Object(DeclarationPatternObject::new(
+        bindings, None,
+    )))
+}
+
+/// Converts an array literal to an array destructuring pattern.
+///
+/// If `binding` is true the pattern can only bind identifiers, otherwise property accesses are
+/// valid assignment targets too.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayAssignmentPattern
+pub(in crate::parser) fn array_decl_to_declaration_pattern(
+    array: &ArrayDecl,
+    binding: bool,
+) -> Option<DeclarationPattern> {
+    let mut bindings = Vec::new();
+    let elements = array.as_ref();
+    for (i, element) in elements.iter().enumerate() {
+        let pattern = match element {
+            Node::Const(Const::Undefined) => BindingPatternTypeArray::Elision,
+            Node::Identifier(ident) => BindingPatternTypeArray::SingleName {
+                ident: ident.as_ref().into(),
+                default_init: None,
+            },
+            Node::Assign(assign) => match assign.lhs() {
+                AssignTarget::Identifier(ident) => BindingPatternTypeArray::SingleName {
+                    ident: ident.as_ref().into(),
+                    default_init: Some(assign.rhs().clone()),
+                },
+                AssignTarget::DeclarationPattern(pattern) => {
+                    BindingPatternTypeArray::BindingPattern {
+                        pattern: pattern_with_init(pattern, assign.rhs().clone()),
+                    }
+                }
+                AssignTarget::GetConstField(field) if !binding => {
+                    BindingPatternTypeArray::PropertyAccess {
+                        access: field.clone().into(),
+                        default_init: Some(assign.rhs().clone()),
+                    }
+                }
+                AssignTarget::GetField(field) if !binding => {
+                    BindingPatternTypeArray::PropertyAccess {
+                        access: field.clone().into(),
+                        default_init: Some(assign.rhs().clone()),
+                    }
+                }
+                _ => return None,
+            },
+            Node::Object(object) => BindingPatternTypeArray::BindingPattern {
+                pattern: object_decl_to_declaration_pattern(object, binding)?,
+            },
+            Node::ArrayDecl(array) => BindingPatternTypeArray::BindingPattern {
+                pattern: array_decl_to_declaration_pattern(array, binding)?,
+            },
+            Node::GetConstField(_) | Node::GetField(_) if !binding => {
+                BindingPatternTypeArray::PropertyAccess {
+                    access: element.clone(),
+                    default_init: None,
+                }
+            }
+            Node::Spread(spread) if i == elements.len() - 1 => match spread.val() {
+                Node::Identifier(ident) => BindingPatternTypeArray::SingleNameRest {
+                    ident: ident.as_ref().into(),
+                },
+                Node::Object(object) => BindingPatternTypeArray::BindingPatternRest {
+                    pattern: object_decl_to_declaration_pattern(object, binding)?,
+                },
+                Node::ArrayDecl(array) => BindingPatternTypeArray::BindingPatternRest {
+                    pattern: array_decl_to_declaration_pattern(array, binding)?,
+                },
+                Node::GetConstField(_) | Node::GetField(_) if !binding => {
+                    BindingPatternTypeArray::PropertyAccessRest {
+                        access: spread.val().clone(),
+                    }
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        bindings.push(pattern);
+    }
+
+    Some(DeclarationPattern::Array(DeclarationPatternArray::new(
+        bindings, None,
+    )))
+}
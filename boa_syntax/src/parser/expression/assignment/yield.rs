@@ -0,0 +1,78 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+use crate::{
+    ast::{node::YieldExpr, Keyword, Punctuator},
+    lexer::TokenKind,
+    parser::{cursor::SemicolonResult, AllowAwait, AllowIn, Cursor, ParseError, TokenParser},
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses a yield expression.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(in crate::parser) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = YieldExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+        cursor.expect(TokenKind::Keyword(Keyword::Yield), "yield expression")?;
+
+        // The operand of a yield expression is optional, and must be on the same line.
+        let delegate = match cursor.peek_semicolon()? {
+            SemicolonResult::Found(_) => return Ok(YieldExpr::new(None, false)),
+            SemicolonResult::NotFound(token) => match token.kind() {
+                TokenKind::Punctuator(Punctuator::CloseParen)
+                | TokenKind::Punctuator(Punctuator::CloseBracket)
+                | TokenKind::Punctuator(Punctuator::Comma)
+                | TokenKind::Punctuator(Punctuator::Colon)
+                | TokenKind::Keyword(Keyword::In) => return Ok(YieldExpr::new(None, false)),
+                TokenKind::Punctuator(Punctuator::Mul) => true,
+                _ => false,
+            },
+        };
+        if delegate {
+            cursor.next()?.expect("`*` token vanished");
+        }
+
+        let expr =
+            AssignmentExpression::new(self.allow_in, true, self.allow_await).parse(cursor)?;
+        Ok(YieldExpr::new(expr, delegate))
+    }
+}
@@ -9,7 +9,7 @@
 
 use super::unary::UnaryExpression;
 
-use crate::syntax::{
+use crate::{
     ast::{node::AwaitExpr, Keyword},
     lexer::TokenKind,
     parser::{AllowYield, Cursor, ParseError, TokenParser},
@@ -25,13 +25,13 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/await
 /// [spec]: https://tc39.es/ecma262/#prod-AwaitExpression
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser) struct AwaitExpression {
+pub(in crate::parser) struct AwaitExpression {
     allow_yield: AllowYield,
 }
 
 impl AwaitExpression {
     /// Creates a new `AwaitExpression` parser.
-    pub(in crate::syntax::parser) fn new<Y>(allow_yield: Y) -> Self
+    pub(in crate::parser) fn new<Y>(allow_yield: Y) -> Self
     where
         Y: Into<AllowYield>,
     {
@@ -7,15 +7,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Argument
 //! [spec]: https://tc39.es/ecma262/#prod-Arguments
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::Spread, Node, Punctuator},
-        lexer::InputElement,
-        parser::{
-            expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            TokenParser,
-        },
+    ast::{node::Spread, Node, Punctuator},
+    lexer::InputElement,
+    parser::{
+        expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -31,14 +28,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Argument
 /// [spec]: https://tc39.es/ecma262/#prod-Arguments
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::expression) struct Arguments {
+pub(in crate::parser::expression) struct Arguments {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl Arguments {
     /// Creates a new `Arguments` parser.
-    pub(in crate::syntax::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -9,19 +9,20 @@
 
 use super::arguments::Arguments;
 use crate::{
-    syntax::{
-        ast::{
-            node::{
-                field::{GetConstField, GetField},
-                Call, Node,
-            },
-            Punctuator,
+    ast::{
+        node::{
+            self,
+            field::{GetConstField, GetField, GetPrivateField},
+            Call, Node,
         },
-        lexer::TokenKind,
-        parser::{
-            expression::{left_hand_side::template::TaggedTemplateLiteral, Expression},
-            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        expression::{
+            left_hand_side::template::TaggedTemplateLiteral, AssignmentExpression, Expression,
         },
+        AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
     },
     BoaProfiler,
 };
@@ -67,7 +68,7 @@ where
 
         let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
-        let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+        let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
             Node::from(Call::new(self.first_member_expr, args))
         } else {
@@ -79,6 +80,49 @@ where
             ));
         };
 
+        CallExpressionTail::new(self.allow_yield, self.allow_await, lhs).parse(cursor)
+    }
+}
+
+/// Parses the arguments, property accesses and tagged templates following the head of a call
+/// expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-CallExpression
+#[derive(Debug)]
+pub(super) struct CallExpressionTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    call: Node,
+}
+
+impl CallExpressionTail {
+    /// Creates a new `CallExpressionTail` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, call: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            call,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for CallExpressionTail
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("CallExpressionTail", "Parsing");
+
+        let mut lhs = self.call;
         while let Some(tok) = cursor.peek(0)? {
             let token = tok.clone();
             match token.kind() {
@@ -89,13 +133,18 @@ where
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next()?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
-                    match &cursor.next()?.ok_or(ParseError::AbruptEnd)?.kind() {
+                    let field = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match field.kind() {
                         TokenKind::Identifier(name) => {
                             lhs = GetConstField::new(lhs, name.clone()).into();
                         }
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_string()).into();
                         }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.use_private_name(name.clone(), field.span().start())?;
+                            lhs = GetPrivateField::new(lhs, name.clone()).into();
+                        }
                         _ => {
                             return Err(ParseError::expected(
                                 vec![TokenKind::identifier("identifier")],
@@ -127,3 +176,50 @@ where
         Ok(lhs)
     }
 }
+
+/// Parses an import call, which imports a module dynamically.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportCall
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ImportCall {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ImportCall {
+    /// Creates a new `ImportCall` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ImportCall
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ImportCall", "Parsing");
+
+        cursor.expect(Keyword::Import, "import call")?;
+        cursor.expect(Punctuator::OpenParen, "import call")?;
+        let specifier =
+            AssignmentExpression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "import call")?;
+
+        Ok(node::ImportCall::new(specifier).into())
+    }
+}
@@ -0,0 +1,208 @@
+//! Member expression parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+
+use super::arguments::Arguments;
+use crate::{
+    ast::{
+        node::{
+            field::{GetConstField, GetField, GetPrivateField, GetSuperField},
+            Call, New, Node, SuperCall,
+        },
+        Const, Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        expression::{
+            left_hand_side::template::TaggedTemplateLiteral, primary::PrimaryExpression, Expression,
+        },
+        AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses a member expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct MemberExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl MemberExpression {
+    /// Creates a new `MemberExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for MemberExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("MemberExpression", "Parsing");
+
+        let mut lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::New)
+        {
+            let _ = cursor.next().expect("new keyword disappeared");
+
+            if cursor.next_if(Punctuator::Dot)?.is_some() {
+                // NewTarget : new . target
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                match token.kind() {
+                    TokenKind::Identifier(name) if name.as_ref() == "target" => {}
+                    _ => {
+                        return Err(ParseError::expected(
+                            vec![TokenKind::identifier("target")],
+                            token,
+                            "new.target expression",
+                        ))
+                    }
+                }
+                Node::NewTarget
+            } else {
+                let lhs = self.parse(cursor)?;
+                let args = match cursor.peek(0)? {
+                    Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
+                        Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?
+                    }
+                    Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::Optional) => {
+                        return Err(ParseError::general(
+                            "invalid optional chain from new expression",
+                            next.span().start(),
+                        ));
+                    }
+                    _ => Box::new([]),
+                };
+                let call_node = Call::new(lhs, args);
+
+                Node::from(New::from(call_node))
+            }
+        } else if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::Super)
+        {
+            let super_token = cursor.next()?.expect("super keyword disappeared");
+            if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+                == &TokenKind::Punctuator(Punctuator::OpenParen)
+            {
+                // SuperCall : super Arguments
+                if !cursor.super_call_allowed() {
+                    return Err(ParseError::general(
+                        "'super' call is only allowed in the constructor of a derived class",
+                        super_token.span().start(),
+                    ));
+                }
+                let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                SuperCall::new(args).into()
+            } else {
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                match token.kind() {
+                    // SuperProperty : super . IdentifierName
+                    TokenKind::Punctuator(Punctuator::Dot) => {
+                        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                        match token.kind() {
+                            TokenKind::Identifier(name) => {
+                                GetSuperField::new(Const::from(name.clone())).into()
+                            }
+                            TokenKind::Keyword(kw) => {
+                                GetSuperField::new(Const::from(kw.to_string())).into()
+                            }
+                            _ => {
+                                return Err(ParseError::expected(
+                                    vec![TokenKind::identifier("identifier")],
+                                    token,
+                                    "super property",
+                                ));
+                            }
+                        }
+                    }
+                    // SuperProperty : super [ Expression ]
+                    TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                        let field = Expression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor)?;
+                        cursor.expect(Punctuator::CloseBracket, "super property")?;
+                        GetSuperField::new(field).into()
+                    }
+                    _ => {
+                        return Err(ParseError::general(
+                            "'super' keyword unexpected here",
+                            super_token.span().start(),
+                        ));
+                    }
+                }
+            }
+        } else {
+            PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
+        };
+        while let Some(tok) = cursor.peek(0)? {
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    cursor.next()?.expect("dot punctuator token disappeared"); // We move the parser forward.
+
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+
+                    match token.kind() {
+                        TokenKind::Identifier(name) => {
+                            lhs = GetConstField::new(lhs, name.clone()).into()
+                        }
+                        TokenKind::Keyword(kw) => {
+                            lhs = GetConstField::new(lhs, kw.to_string()).into()
+                        }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.use_private_name(name.clone(), token.span().start())?;
+                            lhs = GetPrivateField::new(lhs, name.clone()).into()
+                        }
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "member expression",
+                            ));
+                        }
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared"); // We move the parser forward.
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "member expression")?;
+                    lhs = GetField::new(lhs, idx).into();
+                }
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    lhs = TaggedTemplateLiteral::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        tok.span().start(),
+                        lhs,
+                    )
+                    .parse(cursor)?;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(lhs)
+    }
+}
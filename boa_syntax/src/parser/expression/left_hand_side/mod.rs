@@ -10,16 +10,19 @@
 mod arguments;
 mod call;
 mod member;
+mod optional;
 mod template;
 
-use self::{call::CallExpression, member::MemberExpression};
+use self::{
+    call::{CallExpression, CallExpressionTail, ImportCall},
+    member::MemberExpression,
+    optional::OptionalExpression,
+};
 use crate::{
+    ast::{Keyword, Node, Punctuator},
+    lexer::{InputElement, TokenKind},
+    parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
     profiler::BoaProfiler,
-    syntax::{
-        ast::{Node, Punctuator},
-        lexer::{InputElement, TokenKind},
-        parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
-    },
 };
 
 use std::io::Read;
@@ -33,14 +36,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Left-hand-side_expressions
 /// [spec]: https://tc39.es/ecma262/#prod-LeftHandSideExpression
 #[derive(Debug, Clone, Copy)]
-pub(super) struct LeftHandSideExpression {
+pub(in crate::parser) struct LeftHandSideExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl LeftHandSideExpression {
     /// Creates a new `LeftHandSideExpression` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -63,11 +66,25 @@ where
 
         cursor.set_goal(InputElement::TemplateTail);
 
-        // TODO: Implement NewExpression: new MemberExpression
-        let lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let mut lhs = if cursor.peek(0)?.map(|tok| tok.kind())
+            == Some(&TokenKind::Keyword(Keyword::Import))
+        {
+            let import_call = ImportCall::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            CallExpressionTail::new(self.allow_yield, self.allow_await, import_call)
+                .parse(cursor)?
+        } else {
+            // TODO: Implement NewExpression: new MemberExpression
+            MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
+        };
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                return CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor);
+                lhs = CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor)?;
+            }
+        }
+        if let Some(tok) = cursor.peek(0)? {
+            if tok.kind() == &TokenKind::Punctuator(Punctuator::Optional) {
+                return OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
+                    .parse(cursor);
             }
         }
         Ok(lhs)
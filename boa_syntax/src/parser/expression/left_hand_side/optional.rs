@@ -0,0 +1,134 @@
+//! Optional chain parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    ast::{
+        node::{Optional, OptionalItem, OptionalItemKind},
+        Node, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+        TokenParser,
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses the optional chains following a member or call expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        let mut chain = Vec::new();
+        while let Some(tok) = cursor.peek(0)? {
+            let token = tok.clone();
+            let shorted = match token.kind() {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    cursor.next()?.expect("?. punctuator token disappeared"); // We move the parser forward.
+                    true
+                }
+                TokenKind::Punctuator(Punctuator::Dot)
+                | TokenKind::Punctuator(Punctuator::OpenBracket)
+                | TokenKind::Punctuator(Punctuator::OpenParen) => false,
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    return Err(ParseError::general(
+                        "tagged template in optional chain",
+                        token.span().start(),
+                    ));
+                }
+                _ => break,
+            };
+
+            let kind = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => OptionalItemKind::Call(
+                    Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                ),
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared"); // We move the parser forward.
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalItemKind::GetField(idx)
+                }
+                _ => {
+                    if !shorted {
+                        cursor.expect(Punctuator::Dot, "optional chain")?;
+                    }
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match token.kind() {
+                        TokenKind::Identifier(name) => {
+                            OptionalItemKind::GetConstField(name.clone())
+                        }
+                        TokenKind::Keyword(kw) => {
+                            OptionalItemKind::GetConstField(kw.to_string().into())
+                        }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.use_private_name(name.clone(), token.span().start())?;
+                            OptionalItemKind::GetPrivateField(name.clone())
+                        }
+                        TokenKind::TemplateNoSubstitution { .. }
+                        | TokenKind::TemplateMiddle { .. } => {
+                            return Err(ParseError::general(
+                                "tagged template in optional chain",
+                                token.span().start(),
+                            ));
+                        }
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "optional chain",
+                            ));
+                        }
+                    }
+                }
+            };
+            chain.push(OptionalItem::new(kind, shorted));
+        }
+
+        Ok(Optional::new(self.target, chain).into())
+    }
+}
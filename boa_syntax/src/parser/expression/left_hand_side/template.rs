@@ -1,14 +1,12 @@
 use crate::{
-    profiler::BoaProfiler,
-    syntax::{
-        ast::node::TaggedTemplate,
-        ast::{Node, Position, Punctuator},
-        lexer::TokenKind,
-        parser::{
-            cursor::Cursor, expression::Expression, AllowAwait, AllowYield, ParseError,
-            ParseResult, TokenParser,
-        },
+    ast::node::TaggedTemplate,
+    ast::{Node, Position, Punctuator},
+    lexer::TokenKind,
+    parser::{
+        cursor::Cursor, expression::Expression, AllowAwait, AllowYield, ParseError, ParseResult,
+        TokenParser,
     },
+    profiler::BoaProfiler,
 };
 use std::io::Read;
 
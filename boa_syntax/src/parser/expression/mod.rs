@@ -15,23 +15,27 @@ mod tests;
 mod unary;
 mod update;
 
-pub(in crate::syntax::parser) mod await_expr;
+mod await_expr;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
+pub(super) use self::{
+    assignment::{
+        array_decl_to_declaration_pattern, object_decl_to_declaration_pattern, AssignmentExpression,
+    },
+    left_hand_side::LeftHandSideExpression,
+    primary::Initializer,
+};
 use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
 
 use crate::{
-    profiler::BoaProfiler,
-    syntax::{
-        ast::op::LogOp,
-        ast::{
-            node::{BinOp, Node},
-            Keyword, Punctuator,
-        },
-        lexer::{InputElement, TokenKind},
-        parser::ParseError,
+    ast::op::{self, LogOp, NumOp},
+    ast::{
+        node::{BinOp, Node, PrivateIn},
+        Const, Keyword, Punctuator,
     },
+    lexer::{InputElement, Token, TokenKind},
+    parser::ParseError,
+    profiler::BoaProfiler,
 };
 use std::io::Read;
 
@@ -51,6 +55,25 @@ impl PartialEq<Punctuator> for Keyword {
     }
 }
 
+/// Creates a binary operation node, folding the concatenation of two string literals.
+///
+/// Adjacent string literals, like in `"a" + "b"`, are joined into a single string literal at
+/// parse time, so that the concatenation is not repeated every time the expression is evaluated.
+fn fold_binop(op: op::BinOp, lhs: Node, rhs: Node) -> Node {
+    if let (
+        op::BinOp::Num(NumOp::Add),
+        Node::Const(Const::String(left)),
+        Node::Const(Const::String(right)),
+    ) = (op, &lhs, &rhs)
+    {
+        let mut string = String::with_capacity(left.len() + right.len());
+        string.push_str(left);
+        string.push_str(right);
+        return Const::from(string).into();
+    }
+    BinOp::new(op, lhs, rhs).into()
+}
+
 /// Generates an expression parser for a number of expressions whose production rules are of the following pattern.
 /// <TargetExpression>[allowed_identifiers]
 ///     => <InnerExpression>[?allowed_identifiers]
@@ -84,19 +107,19 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                 match *tok.kind() {
                     TokenKind::Punctuator(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
+                        lhs = fold_binop(
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
                             $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                        );
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
+                        lhs = fold_binop(
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
                             $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                        );
                     }
                     _ => break
                 }
@@ -486,7 +509,28 @@ where
             cursor.set_goal(None::<InputElement>.unwrap());
         }
 
-        let mut lhs = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        // RelationalExpression : PrivateIdentifier in ShiftExpression
+        let private_in = match cursor.peek(0)?.map(Token::kind) {
+            Some(TokenKind::PrivateIdentifier(name)) if self.allow_in.0 => Some(name.clone()),
+            _ => None,
+        };
+        let private_in = match private_in {
+            Some(name)
+                if cursor.peek(1)?.map(Token::kind) == Some(&TokenKind::Keyword(Keyword::In)) =>
+            {
+                Some(name)
+            }
+            _ => None,
+        };
+        let mut lhs = if let Some(name) = private_in {
+            let token = cursor.next()?.expect("private identifier disappeared");
+            cursor.use_private_name(name.clone(), token.span().start())?;
+            let _ = cursor.next()?.expect("in keyword disappeared");
+            let target = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            PrivateIn::new(name, target).into()
+        } else {
+            ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
+        };
         while let Some(tok) = cursor.peek(0)? {
             match *tok.kind() {
                 TokenKind::Punctuator(op)
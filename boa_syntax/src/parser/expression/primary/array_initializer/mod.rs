@@ -11,15 +11,12 @@
 mod tests;
 
 use crate::{
-    syntax::{
-        ast::{
-            node::{ArrayDecl, Node, Spread},
-            Const, Punctuator,
-        },
-        parser::{
-            expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            TokenParser,
-        },
+    ast::{
+        node::{ArrayDecl, Node, Spread},
+        Const, Punctuator,
+    },
+    parser::{
+        expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
     },
     BoaProfiler,
 };
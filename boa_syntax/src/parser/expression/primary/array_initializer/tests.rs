@@ -1,6 +1,6 @@
 // ! Tests for array initializer parsing.
 
-use crate::syntax::{
+use crate::{
     ast::{node::ArrayDecl, Const},
     parser::tests::check_parser,
 };
@@ -2,14 +2,12 @@
 mod tests;
 
 use crate::{
-    syntax::{
-        ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
-        lexer::{Error as LexError, Position, TokenKind},
-        parser::{
-            function::{FormalParameters, FunctionBody},
-            statement::BindingIdentifier,
-            AllowYield, Cursor, ParseError, TokenParser,
-        },
+    ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
+    lexer::{Error as LexError, Position, TokenKind},
+    parser::{
+        function::{FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
+        AllowYield, Cursor, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -79,10 +77,10 @@ where
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -1,4 +1,4 @@
-use crate::syntax::{
+use crate::{
     ast::{
         node::{AsyncFunctionExpr, Declaration, DeclarationList, Return, StatementList},
         Const,
@@ -0,0 +1,89 @@
+//! Async generator expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function*
+//! [spec]: https://tc39.es/ecma262/#prod-AsyncGeneratorExpression
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ast::{node::AsyncGeneratorExpr, Keyword, Punctuator},
+    lexer::{Error as LexError, Position, TokenKind},
+    parser::{
+        function::{FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
+        Cursor, ParseError, TokenParser,
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Async generator expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function*
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncGeneratorExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AsyncGeneratorExpression;
+
+impl<R> TokenParser<R> for AsyncGeneratorExpression
+where
+    R: Read,
+{
+    type Output = AsyncGeneratorExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncGeneratorExpression", "Parsing");
+        cursor.peek_expect_no_lineterminator(0, "async generator expression")?;
+        cursor.expect(Keyword::Function, "async generator expression")?;
+        cursor.expect(Punctuator::Mul, "async generator expression")?;
+
+        let name = if let Some(token) = cursor.peek(0)? {
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => None,
+                _ => Some(BindingIdentifier::new(true, true).parse(cursor)?),
+            }
+        } else {
+            return Err(ParseError::AbruptEnd);
+        };
+
+        cursor.expect(Punctuator::OpenParen, "async generator expression")?;
+
+        let params = FormalParameters::new(true, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async generator expression")?;
+        cursor.expect(Punctuator::OpenBlock, "async generator expression")?;
+
+        let body = FunctionBody::new(true, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "async generator expression")?;
+
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters
+        // also occurs in the LexicallyDeclaredNames of FunctionBody.
+        // https://tc39.es/ecma262/#sec-async-generator-function-definitions-static-semantics-early-errors
+        {
+            let lexically_declared_names = body.lexically_declared_names();
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
+                        match cursor.peek(0)? {
+                            Some(token) => token.span().end(),
+                            None => Position::new(1, 1),
+                        },
+                    )));
+                }
+            }
+        }
+
+        Ok(AsyncGeneratorExpr::new(name, params, body))
+    }
+}
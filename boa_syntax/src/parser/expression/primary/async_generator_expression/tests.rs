@@ -0,0 +1,73 @@
+use crate::{
+    ast::{
+        node::{
+            AsyncGeneratorExpr, AwaitExpr, Declaration, DeclarationList, Identifier, Node,
+            StatementList, YieldExpr,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks async generator expression parsing.
+#[test]
+fn check_async_generator_expression() {
+    check_parser(
+        "const gen = async function*() {
+            yield await a;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new_with_identifier(
+                "gen",
+                Some(
+                    AsyncGeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                        None,
+                        [],
+                        vec![YieldExpr::new(
+                            Node::from(AwaitExpr::from(Node::from(Identifier::from("a")))),
+                            false,
+                        )
+                        .into()]
+                        .into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks named async generator expression parsing.
+#[test]
+fn check_named_async_generator_expression() {
+    check_parser(
+        "const gen = async function* values() { yield 1; };",
+        vec![DeclarationList::Const(
+            vec![Declaration::new_with_identifier(
+                "gen",
+                Some(
+                    AsyncGeneratorExpr::new::<_, _, StatementList>(
+                        Some(Box::from("values")),
+                        [],
+                        vec![YieldExpr::new(Node::from(Const::from(1)), false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// There cannot be a line terminator between `async` and `function`.
+#[test]
+fn check_async_generator_expression_line_terminator() {
+    check_invalid(
+        "const gen = async
+        function*() {};",
+    );
+}
@@ -0,0 +1,83 @@
+//! Class expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ast::{node::Class, Keyword},
+    lexer::TokenKind,
+    parser::{
+        statement::{BindingIdentifier, ClassTail},
+        AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Class expression parsing, after the `class` keyword.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassExpression {
+    /// The byte offset of the start of the `class` keyword.
+    source_start: usize,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassExpression {
+    /// Creates a new `ClassExpression` parser.
+    pub(super) fn new<Y, A>(source_start: usize, allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            source_start,
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassExpression
+where
+    R: Read,
+{
+    type Output = Class;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassExpression", "Parsing");
+
+        // All parts of a class are strict mode code, including its name.
+        let strict_mode = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+
+        let name = match cursor.peek(0)?.map(|t| t.kind()) {
+            Some(TokenKind::Identifier(_))
+            | Some(TokenKind::Keyword(Keyword::Yield))
+            | Some(TokenKind::Keyword(Keyword::Await)) => {
+                Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?)
+            }
+            _ => None,
+        };
+
+        let class = ClassTail::new(name, self.source_start, self.allow_yield, self.allow_await)
+            .parse(cursor)?;
+        cursor.set_strict_mode(strict_mode);
+        Ok(class)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    ast::node::{
+        Class, ClassElement, Declaration, DeclarationList, FunctionExpr, MethodDefinitionKind,
+        Node, StatementList,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks the parsing of anonymous and named class expressions.
+#[test]
+fn check_class_expression() {
+    check_parser(
+        "let A = class {}; let B = class C { #m() {} };",
+        vec![
+            DeclarationList::Let(
+                vec![Declaration::new_with_identifier(
+                    "A",
+                    Node::ClassExpr(Class::new(None, None, vec![])),
+                )]
+                .into(),
+            )
+            .into(),
+            DeclarationList::Let(
+                vec![Declaration::new_with_identifier(
+                    "B",
+                    Node::ClassExpr(Class::new(
+                        Box::<str>::from("C"),
+                        None,
+                        vec![ClassElement::PrivateMethodDefinition(
+                            "m".into(),
+                            MethodDefinitionKind::Ordinary,
+                            FunctionExpr::new(
+                                None,
+                                vec![],
+                                StatementList::from(vec![]).with_strict(true),
+                            ),
+                        )],
+                    )),
+                )]
+                .into(),
+            )
+            .into(),
+        ],
+    );
+}
+
+/// Checks that the name of a class expression is strict mode code.
+#[test]
+fn check_class_expression_strict_name() {
+    check_invalid("(class arguments {});");
+}
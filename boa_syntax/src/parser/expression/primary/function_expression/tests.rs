@@ -1,9 +1,9 @@
-use crate::syntax::{
+use crate::{
     ast::{
-        node::{Declaration, DeclarationList, FunctionExpr, Return, StatementList},
+        node::{Declaration, DeclarationList, FunctionExpr, Node, Return, StatementList},
         Const,
     },
-    parser::tests::check_parser,
+    parser::{tests::check_parser, Parser},
 };
 
 /// Checks async expression parsing.
@@ -79,3 +79,35 @@ fn check_nested_function_expression() {
         .into()],
     );
 }
+
+/// Checks that the source text of function expressions is kept.
+#[test]
+fn check_function_expression_source_text() {
+    let statements = Parser::new(
+        "var f = function  named ( a ) {\n    return () => a; // comment\n} ;".as_bytes(),
+        false,
+    )
+    .parse_all()
+    .expect("failed to parse");
+
+    let function = match statements.items() {
+        [Node::VarDeclList(list)] => match list.as_ref()[0].init() {
+            Some(Node::FunctionExpr(function)) => function,
+            init => panic!("unexpected initializer: {:?}", init),
+        },
+        items => panic!("unexpected statements: {:?}", items),
+    };
+    assert_eq!(
+        function.source_text(),
+        Some("function  named ( a ) {\n    return () => a; // comment\n}")
+    );
+
+    let arrow = match function.body() {
+        [Node::Return(ret)] => match ret.expr() {
+            Some(Node::ArrowFunctionDecl(arrow)) => arrow,
+            expr => panic!("unexpected return value: {:?}", expr),
+        },
+        body => panic!("unexpected body: {:?}", body),
+    };
+    assert_eq!(arrow.source_text(), Some("() => a"));
+}
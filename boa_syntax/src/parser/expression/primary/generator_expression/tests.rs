@@ -0,0 +1,56 @@
+use crate::{
+    ast::{
+        node::{Declaration, DeclarationList, GeneratorExpr, Node, StatementList, YieldExpr},
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+/// Checks generator expression parsing.
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "const gen = function*() {
+            yield 1;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new_with_identifier(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                        None,
+                        [],
+                        vec![YieldExpr::new(Node::from(Const::from(1)), false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks named generator expression parsing.
+#[test]
+fn check_named_generator_expression() {
+    check_parser(
+        "const gen = function* g() {};",
+        vec![DeclarationList::Const(
+            vec![Declaration::new_with_identifier(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<_, _, StatementList>(
+                        Some(Box::from("g")),
+                        [],
+                        vec![].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
@@ -9,7 +9,10 @@
 
 mod array_initializer;
 mod async_function_expression;
+mod async_generator_expression;
+mod class_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
 mod template;
 #[cfg(test)]
@@ -17,24 +20,24 @@ mod tests;
 
 use self::{
     array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    function_expression::FunctionExpression, object_initializer::ObjectLiteral,
+    async_generator_expression::AsyncGeneratorExpression, class_expression::ClassExpression,
+    function_expression::FunctionExpression, generator_expression::GeneratorExpression,
+    object_initializer::ObjectLiteral,
 };
 use super::Expression;
 use crate::{
-    profiler::BoaProfiler,
-    syntax::{
-        ast::{
-            node::{Call, Identifier, New, Node},
-            Const, Keyword, Punctuator,
-        },
-        lexer::{token::Numeric, InputElement, TokenKind},
-        parser::{
-            expression::primary::template::TemplateLiteral, AllowAwait, AllowYield, Cursor,
-            ParseError, ParseResult, TokenParser,
-        },
+    ast::{
+        node::{Call, Identifier, New, Node},
+        Const, Keyword, Punctuator,
+    },
+    lexer::{token::Numeric, InputElement, TokenKind},
+    parser::{
+        expression::primary::template::TemplateLiteral, AllowAwait, AllowYield, Cursor, ParseError,
+        ParseResult, TokenParser,
     },
+    profiler::BoaProfiler,
 };
-pub(in crate::syntax::parser) use object_initializer::Initializer;
+pub(in crate::parser) use object_initializer::Initializer;
 
 use std::io::Read;
 
@@ -76,15 +79,49 @@ where
         let _timer = BoaProfiler::global().start_event("PrimaryExpression", "Parsing");
 
         let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let source_start = cursor.source_start();
 
         match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(source_start, self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::ClassExpr)
+            }
+            TokenKind::Keyword(Keyword::Function)
+                if matches!(
+                    cursor.peek(0)?.map(|t| t.kind()),
+                    Some(TokenKind::Punctuator(Punctuator::Mul))
+                ) =>
+            {
+                let expr = GeneratorExpression.parse(cursor)?;
+                Ok(expr
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
             TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+                let expr = FunctionExpression.parse(cursor)?;
+                Ok(expr
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
+            TokenKind::Keyword(Keyword::Async)
+                if matches!(
+                    cursor.peek(1)?.map(|t| t.kind()),
+                    Some(TokenKind::Punctuator(Punctuator::Mul))
+                ) =>
+            {
+                let expr = AsyncGeneratorExpression.parse(cursor)?;
+                Ok(expr
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
+            TokenKind::Keyword(Keyword::Async) => {
+                let expr = AsyncFunctionExpression::new(self.allow_yield).parse(cursor)?;
+                Ok(expr
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
             }
-            TokenKind::Keyword(Keyword::Async) => AsyncFunctionExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 cursor.set_goal(InputElement::RegExp);
                 let expr =
@@ -106,6 +143,12 @@ where
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
+            TokenKind::Identifier(ident) if cursor.strict_mode() && ident.as_ref() == "static" => {
+                Err(ParseError::general(
+                    "using future reserved keyword 'static' not allowed in strict mode",
+                    tok.span().start(),
+                ))
+            }
             TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
             TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
             TokenKind::TemplateNoSubstitution(template_string) => {
@@ -148,8 +191,7 @@ where
                     .map_err(ParseError::lex)?
                     .as_ref(),
             )
-            .parse(cursor)
-            .map(Node::TemplateLit),
+            .parse(cursor),
             _ => Err(ParseError::unexpected(tok.clone(), "primary expression")),
         }
     }
@@ -9,19 +9,17 @@
 
 #[cfg(test)]
 mod tests;
-use crate::syntax::ast::node::Identifier;
-use crate::syntax::lexer::TokenKind;
+use crate::ast::node::Identifier;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{
-            node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
-            Punctuator,
-        },
-        parser::{
-            expression::AssignmentExpression,
-            function::{FormalParameters, FunctionBody},
-            AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
-        },
+    ast::{
+        node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
+        Punctuator,
+    },
+    parser::{
+        expression::AssignmentExpression,
+        function::{FormalParameters, FunctionBody},
+        AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
     },
     BoaProfiler,
 };
@@ -152,11 +150,25 @@ where
                         return Err(ParseError::unexpected(token.clone(), "object literal"));
                     }
                 }
+                // CoverInitializedName, only valid when the object literal is reparsed as a pattern
+                TokenKind::Punctuator(Punctuator::Assign) => {
+                    let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+                    if let TokenKind::Identifier(ident) = token.kind() {
+                        let name = ident.clone();
+                        cursor.next()?.expect("token vanished"); // Consume the token.
+                        let init = Initializer::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor)?;
+                        return Ok(node::PropertyDefinition::CoverInitializedName(name, init));
+                    } else {
+                        return Err(ParseError::unexpected(token.clone(), "object literal"));
+                    }
+                }
                 _ => {}
             }
         }
 
         let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        let source_start = cursor.source_start();
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
@@ -179,8 +191,7 @@ where
             .is_some()
             || ["get", "set"].contains(&prop_name.as_str())
         {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
-                .parse(cursor);
+            return MethodDefinition::new(prop_name, source_start).parse(cursor);
         }
 
         let pos = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
@@ -196,23 +207,22 @@ where
 /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
 #[derive(Debug, Clone)]
 struct MethodDefinition {
-    allow_yield: AllowYield,
-    allow_await: AllowAwait,
+    // The parameters and the body of a method never allow `yield` or `await` expressions, and
+    // the name is already parsed, so the parser takes no `Yield` and `Await` parameters.
     identifier: String,
+    /// The byte offset of the start of the method definition.
+    source_start: usize,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    fn new<I>(identifier: I, source_start: usize) -> Self
     where
-        Y: Into<AllowYield>,
-        A: Into<AllowAwait>,
         I: Into<String>,
     {
         Self {
-            allow_yield: allow_yield.into(),
-            allow_await: allow_await.into(),
             identifier: identifier.into(),
+            source_start,
         }
     }
 }
@@ -287,7 +297,8 @@ where
         Ok(node::PropertyDefinition::method_definition(
             methodkind,
             prop_name,
-            FunctionExpr::new(None, params, body),
+            FunctionExpr::new(None, params, body)
+                .with_source_text(cursor.source_text_from(self.source_start)),
         ))
     }
 }
@@ -299,7 +310,7 @@ where
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Initializer
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser) struct Initializer {
+pub(in crate::parser) struct Initializer {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
@@ -307,11 +318,7 @@ pub(in crate::syntax::parser) struct Initializer {
 
 impl Initializer {
     /// Creates a new `Initializer` parser.
-    pub(in crate::syntax::parser) fn new<I, Y, A>(
-        allow_in: I,
-        allow_yield: Y,
-        allow_await: A,
-    ) -> Self
+    pub(in crate::parser) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
         Y: Into<AllowYield>,
@@ -8,16 +8,16 @@
 //! [spec]: https://tc39.es/ecma262/#sec-template-literals
 
 use crate::{
-    profiler::BoaProfiler,
-    syntax::{
-        ast::node::template::{TemplateElement, TemplateLit},
-        ast::Position,
-        ast::Punctuator,
-        lexer::TokenKind,
-        parser::cursor::Cursor,
-        parser::expression::Expression,
-        parser::{AllowAwait, AllowYield, ParseError, TokenParser},
+    ast::node::{
+        template::{TemplateElement, TemplateLit},
+        Node,
     },
+    ast::{Const, Position, Punctuator},
+    lexer::TokenKind,
+    parser::cursor::Cursor,
+    parser::expression::Expression,
+    parser::{AllowAwait, AllowYield, ParseError, TokenParser},
+    profiler::BoaProfiler,
 };
 use std::io::Read;
 
@@ -57,7 +57,7 @@ impl<R> TokenParser<R> for TemplateLiteral
 where
     R: Read,
 {
-    type Output = TemplateLit;
+    type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Parsing");
@@ -91,7 +91,7 @@ where
                     let cooked = template_string.to_owned_cooked().map_err(ParseError::lex)?;
 
                     elements.push(TemplateElement::String(cooked));
-                    return Ok(TemplateLit::new(elements));
+                    return Ok(fold_template(elements));
                 }
                 _ => {
                     return Err(ParseError::general(
@@ -103,3 +103,47 @@ where
         }
     }
 }
+
+/// Folds the literal substitutions of a template literal into the strings surrounding them.
+///
+/// A template literal whose substitutions are all literals, like `` `a${"b"}c` ``, is turned into
+/// a single string literal, so that it does not have to be built every time it is evaluated.
+fn fold_template(elements: Vec<TemplateElement>) -> Node {
+    let mut folded = Vec::with_capacity(elements.len());
+    for element in elements {
+        let string = match &element {
+            TemplateElement::String(string) => string.to_string(),
+            TemplateElement::Expr(node) => match literal_to_string(node) {
+                Some(string) => string,
+                None => {
+                    folded.push(element);
+                    continue;
+                }
+            },
+        };
+
+        if let Some(TemplateElement::String(last)) = folded.last_mut() {
+            let mut joined = std::mem::take(last).into_string();
+            joined.push_str(&string);
+            *last = joined.into_boxed_str();
+        } else {
+            folded.push(TemplateElement::String(string.into_boxed_str()));
+        }
+    }
+
+    if let [TemplateElement::String(string)] = folded.as_mut_slice() {
+        return Const::from(std::mem::take(string)).into();
+    }
+    Node::TemplateLit(TemplateLit::new(folded))
+}
+
+/// Gets the string value of a literal whose conversion does not depend on the engine.
+fn literal_to_string(node: &Node) -> Option<String> {
+    match node {
+        Node::Const(Const::String(string)) => Some(string.to_string()),
+        Node::Const(Const::Int(int)) => Some(int.to_string()),
+        Node::Const(Const::Bool(boolean)) => Some(boolean.to_string()),
+        Node::Const(Const::Null) => Some("null".to_owned()),
+        _ => None,
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    ast::{
+        node::{
+            template::{TemplateElement, TemplateLit},
+            Identifier, Node,
+        },
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+#[test]
+fn check_string() {
+    // Check empty string
+    check_parser("\"\"", vec![Const::from("").into()]);
+
+    // Check non-empty string
+    check_parser("\"hello\"", vec![Const::from("hello").into()]);
+}
+
+#[test]
+fn check_template_literal_folding() {
+    // Templates with only literal substitutions become a single string
+    check_parser(
+        "`a${\"b\"}c${1}${true}${null}`",
+        vec![Const::from("abc1truenull").into()],
+    );
+
+    // Literal substitutions are joined with the surrounding strings
+    check_parser(
+        "`a${\"b\"}${c}d${2}`",
+        vec![Node::TemplateLit(TemplateLit::new(vec![
+            TemplateElement::String("ab".into()),
+            TemplateElement::Expr(Identifier::from("c").into()),
+            TemplateElement::String("d2".into()),
+        ]))],
+    );
+}
@@ -1,7 +1,10 @@
-use crate::syntax::{
+use crate::{
     ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{
+            BinOp, Call, ConditionalOp, GetConstField, Identifier, Optional, OptionalItem,
+            OptionalItemKind,
+        },
         Const,
     },
     parser::tests::{check_invalid, check_parser},
@@ -200,6 +203,13 @@ fn check_assign_operations() {
         )
         .into()],
     );
+
+    check_invalid("a() += b");
+    check_invalid("this -= b");
+    check_invalid("(a, b) *= c");
+    check_invalid("1 ??= b");
+    check_invalid("a()++");
+    check_invalid("--this");
 }
 
 #[test]
@@ -281,3 +291,90 @@ fn check_logical_expressions() {
     check_invalid("a ?? b || c");
     check_invalid("a || b ?? c");
 }
+
+/// Checks that the concatenation of string literals is folded.
+#[test]
+fn check_string_concatenation_folding() {
+    check_parser(r#""a" + "b""#, vec![Const::from("ab").into()]);
+    check_parser(
+        r#""a" + "b" + c"#,
+        vec![BinOp::new(NumOp::Add, Const::from("ab"), Identifier::from("c")).into()],
+    );
+    check_parser(
+        r#"a + "b" + "c""#,
+        vec![BinOp::new(
+            NumOp::Add,
+            BinOp::new(NumOp::Add, Identifier::from("a"), Const::from("b")),
+            Const::from("c"),
+        )
+        .into()],
+    );
+    check_parser(
+        r#""a" - "b""#,
+        vec![BinOp::new(NumOp::Sub, Const::from("a"), Const::from("b")).into()],
+    );
+}
+
+/// Checks optional chains.
+#[test]
+fn check_optional_chaining() {
+    check_parser(
+        "a?.b.c",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalItem::new(OptionalItemKind::GetConstField("b".into()), true),
+                OptionalItem::new(OptionalItemKind::GetConstField("c".into()), false),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "a.b?.[c](d)?.(e)",
+        vec![Optional::new(
+            GetConstField::new(Identifier::from("a"), "b"),
+            vec![
+                OptionalItem::new(
+                    OptionalItemKind::GetField(Identifier::from("c").into()),
+                    true,
+                ),
+                OptionalItem::new(
+                    OptionalItemKind::Call(vec![Identifier::from("d").into()].into()),
+                    false,
+                ),
+                OptionalItem::new(
+                    OptionalItemKind::Call(vec![Identifier::from("e").into()].into()),
+                    true,
+                ),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "a()?.b",
+        vec![Optional::new(
+            Call::new(Identifier::from("a"), vec![]),
+            vec![OptionalItem::new(
+                OptionalItemKind::GetConstField("b".into()),
+                true,
+            )],
+        )
+        .into()],
+    );
+    check_parser(
+        "a?.5:b",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            Const::from(0.5),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+
+    check_invalid("a?.`b`");
+    check_invalid("a?.b`c`");
+    check_invalid("a?.b = c");
+    check_invalid("a?.b++");
+    check_invalid("new a?.b");
+    check_invalid("++a?.b");
+}
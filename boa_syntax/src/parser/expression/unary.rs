@@ -8,19 +8,17 @@
 //! [spec]: https://tc39.es/ecma262/#sec-unary-operators
 
 use crate::{
-    profiler::BoaProfiler,
-    syntax::{
-        ast::{
-            node::{self, Node},
-            op::UnaryOp,
-            Keyword, Punctuator,
-        },
-        lexer::{Error as LexError, TokenKind},
-        parser::{
-            expression::update::UpdateExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
-        },
+    ast::{
+        node::{self, Node},
+        op::UnaryOp,
+        Keyword, Punctuator,
+    },
+    lexer::{Error as LexError, TokenKind},
+    parser::{
+        expression::{await_expr::AwaitExpression, update::UpdateExpression},
+        AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
     },
+    profiler::BoaProfiler,
 };
 use std::io::Read;
 
@@ -33,14 +31,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Unary
 /// [spec]: https://tc39.es/ecma262/#prod-UnaryExpression
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser) struct UnaryExpression {
+pub(in crate::parser) struct UnaryExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl UnaryExpression {
     /// Creates a new `UnaryExpression` parser.
-    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -76,8 +74,24 @@ where
                         )));
                     }
                 }
+                let deletes_private_field = match val {
+                    Node::GetPrivateField(_) => true,
+                    Node::Optional(ref optional) => matches!(
+                        optional.chain().last().map(node::OptionalItem::kind),
+                        Some(node::OptionalItemKind::GetPrivateField(_))
+                    ),
+                    _ => false,
+                };
+                if deletes_private_field {
+                    return Err(ParseError::general(
+                        "private fields can not be deleted",
+                        token_start,
+                    ));
+                }
 
-                Ok(node::UnaryOp::new(UnaryOp::Delete, val).into())
+                Ok(node::UnaryOp::new(UnaryOp::Delete, val)
+                    .with_strict(cursor.strict_mode())
+                    .into())
             }
             TokenKind::Keyword(Keyword::Void) => {
                 cursor.next()?.expect("Void keyword vanished"); // Consume the token.
@@ -103,6 +117,11 @@ where
                 cursor.next()?.expect("! token vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::Not, self.parse(cursor)?).into())
             }
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                AwaitExpression::new(self.allow_yield)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             _ => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor),
         }
     }
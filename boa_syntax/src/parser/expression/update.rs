@@ -5,17 +5,15 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-update-expressions
 
-use super::left_hand_side::LeftHandSideExpression;
+use super::{assignment::is_assignable, left_hand_side::LeftHandSideExpression};
 use crate::{
-    profiler::BoaProfiler,
-    syntax::{
-        ast::{node, op::UnaryOp, Node, Punctuator},
-        lexer::TokenKind,
-        parser::{
-            expression::unary::UnaryExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
-        },
+    ast::{node, op::UnaryOp, Node, Position, Punctuator},
+    lexer::TokenKind,
+    parser::{
+        expression::unary::UnaryExpression, AllowAwait, AllowYield, Cursor, ParseError,
+        ParseResult, TokenParser,
     },
+    profiler::BoaProfiler,
 };
 
 use std::io::Read;
@@ -58,32 +56,46 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
         match tok.kind() {
             TokenKind::Punctuator(Punctuator::Inc) => {
-                cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let position = cursor
+                    .next()?
+                    .expect("Punctuator::Inc token disappeared")
+                    .span()
+                    .end();
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                check_update_target(&target, position)?;
+                return Ok(node::UnaryOp::new(UnaryOp::IncrementPre, target).into());
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
-                cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let position = cursor
+                    .next()?
+                    .expect("Punctuator::Dec token disappeared")
+                    .span()
+                    .end();
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                check_update_target(&target, position)?;
+                return Ok(node::UnaryOp::new(UnaryOp::DecrementPre, target).into());
             }
             _ => {}
         }
 
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        // A postfix operator must be on the same line as its operand, otherwise a semicolon is
+        // inserted before it, and it becomes the prefix operator of the next statement.
+        if cursor.peek_is_line_terminator(0)? {
+            return Ok(lhs);
+        }
         if let Some(tok) = cursor.peek(0)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
+                    check_update_target(&lhs, tok.span().start())?;
                     cursor.next()?.expect("Punctuator::Inc token disappeared");
                     return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
+                    check_update_target(&lhs, tok.span().start())?;
                     cursor.next()?.expect("Punctuator::Dec token disappeared");
                     return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
                 }
@@ -94,3 +106,20 @@ where
         Ok(lhs)
     }
 }
+
+/// Checks that the target of an update expression is a simple assignment target, like an
+/// identifier or a property access that is not part of an optional chain.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
+fn check_update_target(target: &Node, position: Position) -> Result<(), ParseError> {
+    if !is_assignable(target) {
+        return Err(ParseError::general(
+            "invalid left-hand side expression in update expression",
+            position,
+        ));
+    }
+    Ok(())
+}
@@ -11,14 +11,18 @@
 mod tests;
 
 use crate::{
-    syntax::{
-        ast::{node, Punctuator},
-        lexer::{InputElement, TokenKind},
-        parser::{
-            expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
-            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+    ast::{
+        node::{
+            self,
+            declaration::{DeclarationPattern, DeclarationPatternArray, DeclarationPatternObject},
         },
+        Punctuator,
+    },
+    lexer::{InputElement, TokenKind},
+    parser::{
+        expression::Initializer,
+        statement::{ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern, StatementList},
+        AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -33,14 +37,14 @@ use std::{collections::HashSet, io::Read};
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Parameter
 /// [spec]: https://tc39.es/ecma262/#prod-FormalParameters
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser) struct FormalParameters {
+pub(in crate::parser) struct FormalParameters {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl FormalParameters {
     /// Creates a new `FormalParameters` parser.
-    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -82,11 +86,13 @@ where
                 }
                 _ => FormalParameter::new(self.allow_yield, self.allow_await).parse(cursor)?,
             };
-            if param_names.contains(next_param.name()) {
-                return Err(ParseError::general("duplicate parameter name", position));
-            }
+            for param_name in next_param.names() {
+                if param_names.contains(param_name) {
+                    return Err(ParseError::general("duplicate parameter name", position));
+                }
 
-            param_names.insert(Box::from(next_param.name()));
+                param_names.insert(Box::from(param_name));
+            }
             params.push(next_param);
 
             if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
@@ -157,8 +163,13 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
+        if let Some(pattern) =
+            parse_binding_pattern(cursor, self.allow_yield, self.allow_await, false)?
+        {
+            return Ok(Self::Output::new_with_pattern(pattern, true));
+        }
+
         let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
 
         Ok(Self::Output::new(param, None, true))
     }
@@ -201,7 +212,11 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
+        if let Some(pattern) =
+            parse_binding_pattern(cursor, self.allow_yield, self.allow_await, true)?
+        {
+            return Ok(Self::Output::new_with_pattern(pattern, false));
+        }
 
         let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
@@ -220,13 +235,72 @@ where
     }
 }
 
+/// Parses the `BindingPattern` of a parameter, with its optional initializer, if the next token
+/// starts one.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+fn parse_binding_pattern<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    allow_initializer: bool,
+) -> Result<Option<DeclarationPattern>, ParseError>
+where
+    R: Read,
+{
+    let pattern = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+        TokenKind::Punctuator(Punctuator::OpenBlock) => {
+            let bindings =
+                ObjectBindingPattern::new(true, allow_yield, allow_await).parse(cursor)?;
+            let init =
+                parse_parameter_initializer(cursor, allow_yield, allow_await, allow_initializer)?;
+            DeclarationPattern::Object(DeclarationPatternObject::new(bindings, init))
+        }
+        TokenKind::Punctuator(Punctuator::OpenBracket) => {
+            let bindings =
+                ArrayBindingPattern::new(true, allow_yield, allow_await).parse(cursor)?;
+            let init =
+                parse_parameter_initializer(cursor, allow_yield, allow_await, allow_initializer)?;
+            DeclarationPattern::Array(DeclarationPatternArray::new(bindings, init))
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(pattern))
+}
+
+/// Parses the optional `Initializer` of a parameter.
+fn parse_parameter_initializer<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    allow_initializer: bool,
+) -> Result<Option<node::Node>, ParseError>
+where
+    R: Read,
+{
+    match cursor.peek(0)? {
+        Some(tok)
+            if allow_initializer && tok.kind() == &TokenKind::Punctuator(Punctuator::Assign) =>
+        {
+            Ok(Some(
+                Initializer::new(true, allow_yield, allow_await).parse(cursor)?,
+            ))
+        }
+        _ => Ok(None),
+    }
+}
+
 /// A `FunctionBody` is equivalent to a `FunctionStatementList`.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionBody
-pub(in crate::syntax::parser) type FunctionBody = FunctionStatementList;
+pub(in crate::parser) type FunctionBody = FunctionStatementList;
 
 /// The possible TokenKind which indicate the end of a function statement.
 const FUNCTION_BREAK_TOKENS: [TokenKind; 1] = [TokenKind::Punctuator(Punctuator::CloseBlock)];
@@ -238,14 +312,15 @@ const FUNCTION_BREAK_TOKENS: [TokenKind; 1] = [TokenKind::Punctuator(Punctuator:
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionStatementList
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser) struct FunctionStatementList {
+pub(in crate::parser) struct FunctionStatementList {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    allow_super_call: bool,
 }
 
 impl FunctionStatementList {
     /// Creates a new `FunctionStatementList` parser.
-    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -253,8 +328,16 @@ impl FunctionStatementList {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            allow_super_call: false,
         }
     }
+
+    /// Allows `super(...)` calls in the statements, which are only allowed in the constructor
+    /// of a derived class and in the arrow functions it contains.
+    pub(in crate::parser) fn with_super_call(mut self, allowed: bool) -> Self {
+        self.allow_super_call = allowed;
+        self
+    }
 }
 
 impl<R> TokenParser<R> for FunctionStatementList
@@ -270,7 +353,9 @@ where
         if let Some(tk) = cursor.peek(0)? {
             match tk.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
+                    return Ok(
+                        node::StatementList::from(Vec::new()).with_strict(global_strict_mode)
+                    );
                 }
                 TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
                     cursor.set_strict_mode(true);
@@ -279,6 +364,9 @@ where
             }
         }
 
+        // `break` and `continue` statements can't target statements outside of the function.
+        let break_targets = cursor.take_break_targets();
+        let super_call = cursor.set_super_call_allowed(self.allow_super_call);
         let stmlist = StatementList::new(
             self.allow_yield,
             self.allow_await,
@@ -286,7 +374,10 @@ where
             true,
             &FUNCTION_BREAK_TOKENS,
         )
-        .parse(cursor);
+        .parse(cursor)
+        .map(|stmlist| stmlist.with_strict(cursor.strict_mode()));
+        cursor.set_break_targets(break_targets);
+        cursor.set_super_call_allowed(super_call);
 
         // Reset strict mode back to the global scope.
         cursor.set_strict_mode(global_strict_mode);
@@ -1,4 +1,4 @@
-use crate::syntax::{
+use crate::{
     ast::node::{
         ArrowFunctionDecl, BinOp, Declaration, DeclarationList, FormalParameter, FunctionDecl,
         Identifier, Node, Return,
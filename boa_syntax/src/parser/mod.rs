@@ -4,12 +4,13 @@ mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod module;
 mod statement;
 #[cfg(test)]
 mod tests;
 
 pub use self::error::{ParseError, ParseResult};
-use crate::syntax::{ast::node::StatementList, lexer::TokenKind};
+use crate::{ast::node::StatementList, lexer::TokenKind};
 
 use cursor::Cursor;
 
@@ -104,6 +105,15 @@ impl<R> Parser<R> {
     {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses the source as a module, which is always strict mode code and can contain `import`
+    /// and `export` declarations.
+    pub fn parse_module(&mut self) -> Result<StatementList, ParseError>
+    where
+        R: Read,
+    {
+        self::module::Module.parse(&mut self.cursor)
+    }
 }
 
 /// Parses a full script.
@@ -130,9 +140,10 @@ where
                     }
                     _ => {}
                 }
-                ScriptBody.parse(cursor)
+                let body = ScriptBody.parse(cursor)?;
+                Ok(body.with_strict(cursor.strict_mode()))
             }
-            None => Ok(StatementList::from(Vec::new())),
+            None => Ok(StatementList::from(Vec::new()).with_strict(cursor.strict_mode())),
         }
     }
 }
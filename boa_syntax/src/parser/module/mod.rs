@@ -0,0 +1,416 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ast::{
+        node::{self, ExportDecl, ExportSpecifier, ImportDecl, ImportSpecifier, Node},
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        expression::AssignmentExpression,
+        statement::VariableStatement,
+        statement::{
+            BindingIdentifier, ClassDeclaration, Declaration, HoistableDeclaration,
+            StatementListItem,
+        },
+        Cursor, ParseError, TokenParser,
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses a full module.
+///
+/// Module code is always strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = node::StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("Module", "Parsing");
+        cursor.set_strict_mode(true);
+
+        let mut items = Vec::new();
+        while cursor.peek(0)?.is_some() {
+            items.push(ModuleItem.parse(cursor)?);
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+        }
+
+        Ok(items.into())
+    }
+}
+
+/// Parses a module item.
+///
+/// A module item is either an import declaration, an export declaration or a statement list
+/// item.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItem;
+
+impl<R> TokenParser<R> for ModuleItem
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Import) => {
+                // An `import()` call starts an expression statement.
+                match cursor.peek(1)? {
+                    Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
+                        StatementListItem::new(false, true, false, false).parse(cursor)
+                    }
+                    _ => ImportDeclaration.parse(cursor).map(Node::from),
+                }
+            }
+            TokenKind::Keyword(Keyword::Export) => ExportDeclaration.parse(cursor).map(Node::from),
+            _ => StatementListItem::new(false, true, false, false).parse(cursor),
+        }
+    }
+}
+
+/// Parses an import declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = ImportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        // import ModuleSpecifier ;
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(module) = tok.kind() {
+            let module = module.clone();
+            cursor.next()?;
+            cursor.expect_semicolon("import declaration")?;
+            return Ok(ImportDecl::new(None, None, Vec::new(), module));
+        }
+
+        // ImportedDefaultBinding
+        let default = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul)
+            | TokenKind::Punctuator(Punctuator::OpenBlock) => None,
+            _ => {
+                let default = BindingIdentifier::new(false, false).parse(cursor)?;
+                if cursor.next_if(Punctuator::Comma)?.is_none() {
+                    let module = FromClause.parse(cursor)?;
+                    cursor.expect_semicolon("import declaration")?;
+                    return Ok(ImportDecl::new(default, None, Vec::new(), module));
+                }
+                Some(default)
+            }
+        };
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let (namespace, specifiers) = match tok.kind() {
+            // NameSpaceImport : * as ImportedBinding
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?;
+                expect_contextual_keyword(cursor, "as", "import declaration")?;
+                let namespace = BindingIdentifier::new(false, false).parse(cursor)?;
+                (Some(namespace), Vec::new())
+            }
+            // NamedImports
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next()?;
+                let mut specifiers = Vec::new();
+                while cursor.next_if(Punctuator::CloseBlock)?.is_none() {
+                    let (imported, is_identifier) = ModuleExportName.parse(cursor)?;
+                    let local = if next_is_contextual_keyword(cursor, "as")? {
+                        cursor.next()?;
+                        BindingIdentifier::new(false, false).parse(cursor)?
+                    } else if is_identifier {
+                        imported.clone()
+                    } else {
+                        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                        return Err(ParseError::unexpected(tok, "expected `as` in named import"));
+                    };
+                    specifiers.push(ImportSpecifier::new(imported, local));
+
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "import declaration")?;
+                        break;
+                    }
+                }
+                (None, specifiers)
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![
+                        TokenKind::Punctuator(Punctuator::Mul),
+                        TokenKind::Punctuator(Punctuator::OpenBlock),
+                    ],
+                    tok.clone(),
+                    "import declaration",
+                ))
+            }
+        };
+
+        let module = FromClause.parse(cursor)?;
+        cursor.expect_semicolon("import declaration")?;
+
+        Ok(ImportDecl::new(default, namespace, specifiers, module))
+    }
+}
+
+/// Parses an export declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            // export NamedExports FromClause ;
+            // export NamedExports ;
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next()?;
+                let mut specifiers = Vec::new();
+                let mut first_non_identifier = None;
+                while cursor.next_if(Punctuator::CloseBlock)?.is_none() {
+                    let local_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+                    let (local, is_identifier) = ModuleExportName.parse(cursor)?;
+                    let exported = if next_is_contextual_keyword(cursor, "as")? {
+                        cursor.next()?;
+                        ModuleExportName.parse(cursor)?.0
+                    } else {
+                        local.clone()
+                    };
+                    if !is_identifier && first_non_identifier.is_none() {
+                        first_non_identifier = Some(local_token);
+                    }
+                    specifiers.push(ExportSpecifier::new(local, exported));
+
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "export declaration")?;
+                        break;
+                    }
+                }
+
+                if next_is_contextual_keyword(cursor, "from")? {
+                    let module = FromClause.parse(cursor)?;
+                    cursor.expect_semicolon("export declaration")?;
+                    return Ok(ExportDecl::ReExport {
+                        specifiers: specifiers.into(),
+                        module,
+                    });
+                }
+
+                // It is a Syntax Error if an exported local name is a reserved word or a
+                // string, since it must refer to a binding of the module.
+                if let Some(local_token) = first_non_identifier {
+                    return Err(ParseError::unexpected(
+                        local_token,
+                        "expected an identifier in export declaration",
+                    ));
+                }
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::List(specifiers.into()))
+            }
+            // export * FromClause ;
+            // export * as ModuleExportName FromClause ;
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?;
+                let namespace = if next_is_contextual_keyword(cursor, "as")? {
+                    cursor.next()?;
+                    Some(ModuleExportName.parse(cursor)?.0)
+                } else {
+                    None
+                };
+                let module = FromClause.parse(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::StarReExport { namespace, module })
+            }
+            // export VariableStatement
+            TokenKind::Keyword(Keyword::Var) => {
+                let decl = VariableStatement::new(false, true).parse(cursor)?;
+                Ok(ExportDecl::Declaration(Box::new(Node::VarDeclList(decl))))
+            }
+            // export Declaration
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Async)
+            | TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
+                let decl = Declaration::new(false, true, true).parse(cursor)?;
+                Ok(ExportDecl::Declaration(Box::new(decl)))
+            }
+            // export default HoistableDeclaration[Default]
+            // export default ClassDeclaration[Default]
+            // export default AssignmentExpression ;
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next()?;
+                let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+                if let TokenKind::Keyword(Keyword::Class) = tok.kind() {
+                    let class = ClassDeclaration::new(false, true, true).parse(cursor)?;
+                    return Ok(ExportDecl::DefaultDeclaration(Box::new(Node::ClassDecl(
+                        class,
+                    ))));
+                }
+                let is_declaration = match tok.kind() {
+                    TokenKind::Keyword(Keyword::Function) => true,
+                    TokenKind::Keyword(Keyword::Async) => matches!(
+                        cursor.peek(1)?.map(|tok| tok.kind()),
+                        Some(TokenKind::Keyword(Keyword::Function))
+                    ),
+                    _ => false,
+                };
+                if is_declaration {
+                    let decl = HoistableDeclaration::new(false, false, true).parse(cursor)?;
+                    return Ok(ExportDecl::DefaultDeclaration(Box::new(decl)));
+                }
+
+                let expr = AssignmentExpression::new(true, false, true).parse(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+                Ok(ExportDecl::DefaultExpression(Box::new(expr)))
+            }
+            _ => Err(ParseError::unexpected(tok.clone(), "in export declaration")),
+        }
+    }
+}
+
+/// Parses the `from` clause of an import or export declaration, returning the module specifier.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-FromClause
+#[derive(Debug, Clone, Copy)]
+struct FromClause;
+
+impl<R> TokenParser<R> for FromClause
+where
+    R: Read,
+{
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        expect_contextual_keyword(cursor, "from", "from clause")?;
+
+        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::StringLiteral(module) => Ok(module.clone()),
+            _ => Err(ParseError::unexpected(tok, "expected a module specifier")),
+        }
+    }
+}
+
+/// Parses a module export name, which is either an identifier name or a string literal.
+///
+/// Also returns whether the name is an identifier that can be used as a binding.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+#[derive(Debug, Clone, Copy)]
+struct ModuleExportName;
+
+impl<R> TokenParser<R> for ModuleExportName
+where
+    R: Read,
+{
+    type Output = (Box<str>, bool);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Identifier(name) => Ok((name.clone(), true)),
+            TokenKind::StringLiteral(name) => Ok((name.clone(), false)),
+            TokenKind::Keyword(keyword) => Ok((keyword.as_str().into(), false)),
+            TokenKind::BooleanLiteral(true) => Ok(("true".into(), false)),
+            TokenKind::BooleanLiteral(false) => Ok(("false".into(), false)),
+            TokenKind::NullLiteral => Ok(("null".into(), false)),
+            _ => Err(ParseError::unexpected(tok, "expected a module export name")),
+        }
+    }
+}
+
+/// Checks if the next token is the given contextual keyword, like `as` or `from`.
+fn next_is_contextual_keyword<R>(cursor: &mut Cursor<R>, keyword: &str) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    Ok(matches!(
+        cursor.peek(0)?.map(|tok| tok.kind()),
+        Some(TokenKind::Identifier(name)) if name.as_ref() == keyword
+    ))
+}
+
+/// Consumes the next token, which must be the given contextual keyword.
+fn expect_contextual_keyword<R>(
+    cursor: &mut Cursor<R>,
+    keyword: &str,
+    context: &'static str,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Identifier(name) if name.as_ref() == keyword => Ok(()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier(keyword)],
+            tok,
+            context,
+        )),
+    }
+}
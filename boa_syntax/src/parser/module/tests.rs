@@ -0,0 +1,303 @@
+use crate::{
+    ast::{
+        node::{
+            field::GetConstField, AwaitExpr, BinOp, Call, Declaration, DeclarationList, ExportDecl,
+            ExportSpecifier, FunctionDecl, GeneratorDecl, Identifier, ImportCall, ImportDecl,
+            ImportSpecifier, Node, StatementList,
+        },
+        op::NumOp,
+        Const,
+    },
+    Parser,
+};
+
+/// Checks that the given JavaScript module gives the expected module items.
+#[track_caller]
+fn check_module<L>(js: &str, items: L)
+where
+    L: Into<Box<[Node]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes(), false)
+            .parse_module()
+            .expect("failed to parse"),
+        StatementList::from(items)
+    );
+}
+
+/// The empty body of a function declared in a module, which is always strict mode code.
+fn empty_strict_body() -> StatementList {
+    StatementList::from(vec![]).with_strict(true)
+}
+
+/// Checks that the given JavaScript module creates a parse error.
+#[track_caller]
+fn check_invalid_module(js: &str) {
+    assert!(Parser::new(js.as_bytes(), false).parse_module().is_err());
+}
+
+#[test]
+fn import_declarations() {
+    check_module(
+        r#"import "a"; import b from "b"; import * as c from "c";"#,
+        vec![
+            ImportDecl::new(None, None, vec![], "a").into(),
+            ImportDecl::new(Box::from("b"), None, vec![], "b").into(),
+            ImportDecl::new(None, Box::from("c"), vec![], "c").into(),
+        ],
+    );
+
+    check_module(
+        r#"import d, { e, f as g, "h i" as h, default as j, } from "d";"#,
+        vec![ImportDecl::new(
+            Box::from("d"),
+            None,
+            vec![
+                ImportSpecifier::new("e", "e"),
+                ImportSpecifier::new("f", "g"),
+                ImportSpecifier::new("h i", "h"),
+                ImportSpecifier::new("default", "j"),
+            ],
+            "d",
+        )
+        .into()],
+    );
+
+    check_module(
+        r#"import k, * as l from "k"; import {} from "m";"#,
+        vec![
+            ImportDecl::new(Box::from("k"), Box::from("l"), vec![], "k").into(),
+            ImportDecl::new(None, None, vec![], "m").into(),
+        ],
+    );
+}
+
+#[test]
+fn export_declarations() {
+    check_module(
+        "export var a = 1; export const b = 2; export function c() {} export function* d() {}",
+        vec![
+            ExportDecl::Declaration(Box::new(
+                DeclarationList::Var(
+                    vec![Declaration::new_with_identifier(
+                        "a",
+                        Some(Const::from(1).into()),
+                    )]
+                    .into(),
+                )
+                .into(),
+            ))
+            .into(),
+            ExportDecl::Declaration(Box::new(
+                DeclarationList::Const(
+                    vec![Declaration::new_with_identifier(
+                        "b",
+                        Some(Const::from(2).into()),
+                    )]
+                    .into(),
+                )
+                .into(),
+            ))
+            .into(),
+            ExportDecl::Declaration(Box::new(
+                FunctionDecl::new(Box::from("c"), vec![], empty_strict_body()).into(),
+            ))
+            .into(),
+            ExportDecl::Declaration(Box::new(
+                GeneratorDecl::new(Box::from("d"), vec![], empty_strict_body()).into(),
+            ))
+            .into(),
+        ],
+    );
+
+    check_module(
+        r#"export { a, b as c, d as "e f", g as default };"#,
+        vec![ExportDecl::List(
+            vec![
+                ExportSpecifier::new("a", "a"),
+                ExportSpecifier::new("b", "c"),
+                ExportSpecifier::new("d", "e f"),
+                ExportSpecifier::new("g", "default"),
+            ]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn export_default_declarations() {
+    check_module(
+        "export default function f() {}",
+        vec![ExportDecl::DefaultDeclaration(Box::new(
+            FunctionDecl::new(Box::from("f"), vec![], empty_strict_body()).into(),
+        ))
+        .into()],
+    );
+
+    check_module(
+        "export default function () {}",
+        vec![ExportDecl::DefaultDeclaration(Box::new(
+            FunctionDecl::new(None, vec![], empty_strict_body()).into(),
+        ))
+        .into()],
+    );
+
+    check_module(
+        "export default function* () {}",
+        vec![ExportDecl::DefaultDeclaration(Box::new(
+            GeneratorDecl::new(None, vec![], empty_strict_body()).into(),
+        ))
+        .into()],
+    );
+}
+
+#[test]
+fn export_default_expressions() {
+    check_module(
+        "export default a + 1;",
+        vec![ExportDecl::DefaultExpression(Box::new(
+            BinOp::new(NumOp::Add, Identifier::from("a"), Const::from(1)).into(),
+        ))
+        .into()],
+    );
+
+    check_module(
+        "export default function () {}\n(1);",
+        vec![
+            ExportDecl::DefaultDeclaration(Box::new(
+                FunctionDecl::new(None, vec![], empty_strict_body()).into(),
+            ))
+            .into(),
+            Const::from(1).into(),
+        ],
+    );
+}
+
+#[test]
+fn re_exports() {
+    check_module(
+        r#"export { a, b as c, "d e" as f, default } from "a"; export { "g" } from "b";"#,
+        vec![
+            ExportDecl::ReExport {
+                specifiers: vec![
+                    ExportSpecifier::new("a", "a"),
+                    ExportSpecifier::new("b", "c"),
+                    ExportSpecifier::new("d e", "f"),
+                    ExportSpecifier::new("default", "default"),
+                ]
+                .into(),
+                module: "a".into(),
+            }
+            .into(),
+            ExportDecl::ReExport {
+                specifiers: vec![ExportSpecifier::new("g", "g")].into(),
+                module: "b".into(),
+            }
+            .into(),
+        ],
+    );
+
+    check_module(
+        r#"export * from "a"; export * as b from "b"; export * as "c d" from "c";"#,
+        vec![
+            ExportDecl::StarReExport {
+                namespace: None,
+                module: "a".into(),
+            }
+            .into(),
+            ExportDecl::StarReExport {
+                namespace: Some("b".into()),
+                module: "b".into(),
+            }
+            .into(),
+            ExportDecl::StarReExport {
+                namespace: Some("c d".into()),
+                module: "c".into(),
+            }
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn import_calls() {
+    let then_call: Node = Call::new(
+        GetConstField::new(ImportCall::new(Const::from("a")), "then"),
+        vec![Identifier::from("f").into()],
+    )
+    .into();
+
+    check_module(
+        r#"import("a").then(f); import(b);"#,
+        vec![
+            then_call.clone(),
+            ImportCall::new(Identifier::from("b")).into(),
+        ],
+    );
+
+    // Import calls are also allowed in scripts.
+    assert_eq!(
+        Parser::new(r#"import("a").then(f);"#.as_bytes(), false)
+            .parse_all()
+            .expect("failed to parse"),
+        StatementList::from(vec![then_call])
+    );
+
+    check_invalid_module("import();");
+    check_invalid_module(r#"import("a", "b");"#);
+}
+
+#[test]
+fn top_level_await() {
+    check_module(
+        "await a; export const b = await c;",
+        vec![
+            AwaitExpr::from(Node::from(Identifier::from("a"))).into(),
+            ExportDecl::Declaration(Box::new(
+                DeclarationList::Const(
+                    vec![Declaration::new_with_identifier(
+                        "b",
+                        Some(AwaitExpr::from(Node::from(Identifier::from("c"))).into()),
+                    )]
+                    .into(),
+                )
+                .into(),
+            ))
+            .into(),
+        ],
+    );
+
+    // Scripts cannot await at their top level.
+    assert!(Parser::new("await a;".as_bytes(), false)
+        .parse_all()
+        .is_err());
+}
+
+/// Module code is strict mode code.
+#[test]
+fn module_is_strict() {
+    check_invalid_module("var yield = 1;");
+}
+
+#[test]
+fn invalid_module_items() {
+    // Only a default export can be an anonymous declaration.
+    check_invalid_module("export function () {}");
+    // A local name of an export list must be an identifier.
+    check_invalid_module(r#"export { "a" };"#);
+    check_invalid_module("export { default };");
+    // Re-exports need a module specifier.
+    check_invalid_module("export * from a;");
+    check_invalid_module("export * as b;");
+    // A string import needs a local name.
+    check_invalid_module(r#"import { "a" } from "a";"#);
+    check_invalid_module(r#"import a from b;"#);
+    // Import and export declarations are only allowed in modules.
+    assert!(Parser::new(r#"import "a";"#.as_bytes(), false)
+        .parse_all()
+        .is_err());
+    assert!(Parser::new("export var a;".as_bytes(), false)
+        .parse_all()
+        .is_err());
+}
@@ -12,13 +12,11 @@ mod tests;
 
 use super::StatementList;
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
+    ast::{node, Punctuator},
+    parser::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser},
     profiler::BoaProfiler,
-    syntax::{
-        ast::{node, Punctuator},
-        parser::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser},
-    },
 };
 
 use std::io::Read;
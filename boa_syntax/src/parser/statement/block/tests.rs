@@ -1,6 +1,6 @@
 //! Block statement parsing tests.
 
-use crate::syntax::{
+use crate::{
     ast::{
         node::{
             Assign, Block, Call, Declaration, DeclarationList, FunctionDecl, Identifier, Node,
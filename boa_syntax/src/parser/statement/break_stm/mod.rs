@@ -12,14 +12,12 @@ mod tests;
 
 use super::LabelIdentifier;
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::Break, Keyword, Punctuator},
-        parser::{
-            cursor::{Cursor, SemicolonResult},
-            AllowAwait, AllowYield, ParseError, TokenParser,
-        },
+    ast::{node::Break, Keyword, Punctuator},
+    parser::{
+        cursor::{Cursor, SemicolonResult},
+        AllowAwait, AllowYield, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -62,7 +60,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("BreakStatement", "Parsing");
-        cursor.expect(Keyword::Break, "break statement")?;
+        let position = cursor
+            .expect(Keyword::Break, "break statement")?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon()? {
             match tok {
@@ -72,9 +73,18 @@ where
                 _ => {}
             }
 
+            if !cursor.in_breakable() {
+                return Err(ParseError::general(
+                    "break statement not in an iteration or switch statement",
+                    position,
+                ));
+            }
             None
         } else {
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if !cursor.has_label(&label) {
+                return Err(ParseError::general("undefined label", position));
+            }
             cursor.expect_semicolon("break statement")?;
 
             Some(label)
@@ -10,15 +10,13 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::Continue, Keyword, Punctuator},
-        parser::{
-            cursor::{Cursor, SemicolonResult},
-            statement::LabelIdentifier,
-            AllowAwait, AllowYield, ParseError, TokenParser,
-        },
+    ast::{node::Continue, Keyword, Punctuator},
+    parser::{
+        cursor::{Cursor, SemicolonResult},
+        statement::LabelIdentifier,
+        AllowAwait, AllowYield, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -61,7 +59,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ContinueStatement", "Parsing");
-        cursor.expect(Keyword::Continue, "continue statement")?;
+        let position = cursor
+            .expect(Keyword::Continue, "continue statement")?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon()? {
             match tok {
@@ -71,9 +72,27 @@ where
                 _ => {}
             }
 
+            if !cursor.in_iteration() {
+                return Err(ParseError::general(
+                    "continue statement not in an iteration statement",
+                    position,
+                ));
+            }
             None
         } else {
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            // The labels of an iteration statement are all continued through the label set on
+            // the loop node.
+            let label: Box<str> = match cursor.loop_label(&label) {
+                Some(loop_label) => loop_label.into(),
+                None if cursor.has_label(&label) => {
+                    return Err(ParseError::general(
+                        "continue statement target is not an iteration statement",
+                        position,
+                    ));
+                }
+                None => return Err(ParseError::general("undefined label", position)),
+            };
             cursor.expect_semicolon("continue statement")?;
 
             Some(label)
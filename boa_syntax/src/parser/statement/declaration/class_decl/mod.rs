@@ -0,0 +1,566 @@
+//! Class declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ast::{
+        node::{self, Class, FunctionExpr, MethodDefinitionKind, PropertyName},
+        Keyword, Punctuator,
+    },
+    lexer::{Error as LexError, Position, TokenKind},
+    parser::{
+        expression::{AssignmentExpression, Initializer, LeftHandSideExpression},
+        function::{FormalParameters, FunctionBody},
+        statement::{BindingIdentifier, StatementList},
+        AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
+    },
+    BoaProfiler,
+};
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    io::Read,
+};
+
+/// Class declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(in crate::parser) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = Class;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+        let source_start = cursor.peek_source_start()?;
+        cursor.expect(Keyword::Class, "class declaration")?;
+
+        // All parts of a class are strict mode code, including its name.
+        let strict_mode = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let name = match token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) | TokenKind::Keyword(Keyword::Extends)
+                if self.is_default.0 =>
+            {
+                None
+            }
+            _ => Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?),
+        };
+
+        let class =
+            ClassTail::new(name, source_start, self.allow_yield, self.allow_await).parse(cursor)?;
+        cursor.set_strict_mode(strict_mode);
+        Ok(class)
+    }
+}
+
+/// Parses the heritage and the body of a class, after its name.
+///
+/// The parser expects the strict mode of the cursor to be already enabled.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone)]
+pub(in crate::parser) struct ClassTail {
+    name: Option<Box<str>>,
+    /// The byte offset of the start of the `class` keyword.
+    source_start: usize,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::parser) fn new<Y, A>(
+        name: Option<Box<str>>,
+        source_start: usize,
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            name,
+            source_start,
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    type Output = Class;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        // ClassHeritage : extends LeftHandSideExpression
+        let heritage = if cursor.next_if(Keyword::Extends)?.is_some() {
+            Some(LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenBlock, "class body")?;
+        cursor.push_private_environment();
+
+        let mut constructor = None;
+        let mut elements = Vec::new();
+        // The kinds of the private methods declared so far, and if they are static, to check that
+        // a private name is only declared twice by a getter and a setter.
+        let mut private_names: HashMap<Box<str>, (bool, Option<MethodDefinitionKind>)> =
+            HashMap::new();
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            if cursor.next_if(Punctuator::Semicolon)?.is_some() {
+                continue;
+            }
+
+            let (element, position) =
+                ClassElement::new(heritage.is_some(), self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+            let element = match element {
+                ParsedElement::Constructor(function) => {
+                    if constructor.is_some() {
+                        return Err(ParseError::general(
+                            "a class may only have one constructor",
+                            position,
+                        ));
+                    }
+                    constructor = Some(function);
+                    continue;
+                }
+                ParsedElement::Element(element) => *element,
+            };
+
+            let private = match element {
+                node::ClassElement::PrivateMethodDefinition(ref name, kind, _) => {
+                    Some((name.clone(), false, Some(kind)))
+                }
+                node::ClassElement::PrivateStaticMethodDefinition(ref name, kind, _) => {
+                    Some((name.clone(), true, Some(kind)))
+                }
+                node::ClassElement::PrivateFieldDefinition(ref name, _) => {
+                    Some((name.clone(), false, None))
+                }
+                node::ClassElement::PrivateStaticFieldDefinition(ref name, _) => {
+                    Some((name.clone(), true, None))
+                }
+                _ => None,
+            };
+            if let Some((name, is_static, kind)) = private {
+                match private_names.entry(name) {
+                    Entry::Vacant(entry) => {
+                        entry.insert((is_static, kind));
+                    }
+                    Entry::Occupied(mut entry) => {
+                        // A getter and a setter can share their private name, if both are static
+                        // or both are not.
+                        let accessor_pair = match (*entry.get(), kind) {
+                            (
+                                (declared_static, Some(MethodDefinitionKind::Get)),
+                                Some(MethodDefinitionKind::Set),
+                            )
+                            | (
+                                (declared_static, Some(MethodDefinitionKind::Set)),
+                                Some(MethodDefinitionKind::Get),
+                            ) => declared_static == is_static,
+                            _ => false,
+                        };
+                        if !accessor_pair {
+                            return Err(ParseError::lex(LexError::Syntax(
+                                format!("private name #{} is already declared", entry.key()).into(),
+                                position,
+                            )));
+                        }
+                        entry.insert((is_static, None));
+                    }
+                }
+            }
+
+            elements.push(element);
+        }
+
+        let declared: HashSet<_> = private_names.into_keys().collect();
+        cursor.pop_private_environment(&declared)?;
+
+        let class = Class::new(self.name, constructor, elements)
+            .with_source_text(cursor.source_text_from(self.source_start));
+        Ok(match heritage {
+            Some(heritage) => class.with_heritage(heritage),
+            None => class,
+        })
+    }
+}
+
+/// The tokens ending the statements of a `static { }` block.
+const STATIC_BLOCK_BREAK_TOKENS: [TokenKind; 1] = [TokenKind::Punctuator(Punctuator::CloseBlock)];
+
+/// An element of a class body, as it is parsed.
+#[derive(Debug)]
+enum ParsedElement {
+    Constructor(FunctionExpr),
+    Element(Box<node::ClassElement>),
+}
+
+/// The name of a class element, as it is parsed.
+#[derive(Debug)]
+enum ElementName {
+    Literal(Box<str>),
+    Computed(node::Node),
+    Private(Box<str>),
+}
+
+/// Parses an element of a class body, which is not an empty `;` element.
+///
+/// This returns the element with the position where it starts, and checks the early errors of
+/// the element alone.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElement {
+    /// If the class has a heritage, so that its constructor can contain `super(...)` calls.
+    derived: bool,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElement {
+    /// Creates a new `ClassElement` parser.
+    fn new<Y, A>(derived: bool, allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            derived,
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+/// Checks if the token ends a class element name, so that `static`, `get` or `set` before it is
+/// the name of the element instead of a modifier.
+fn ends_name(kind: Option<&TokenKind>) -> bool {
+    matches!(
+        kind,
+        None | Some(TokenKind::Punctuator(Punctuator::OpenParen))
+            | Some(TokenKind::Punctuator(Punctuator::Assign))
+            | Some(TokenKind::Punctuator(Punctuator::Semicolon))
+            | Some(TokenKind::Punctuator(Punctuator::CloseBlock))
+    )
+}
+
+impl<R> TokenParser<R> for ClassElement
+where
+    R: Read,
+{
+    type Output = (ParsedElement, Position);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElement", "Parsing");
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
+        let is_static = matches!(
+            cursor.peek(0)?.map(|t| t.kind()),
+            Some(TokenKind::Identifier(name)) if name.as_ref() == "static"
+        ) && !ends_name(cursor.peek(1)?.map(|t| t.kind()));
+        if is_static {
+            cursor.next()?.expect("static token vanished");
+            if cursor.next_if(Punctuator::OpenBlock)?.is_some() {
+                return self.parse_static_block(cursor, position);
+            }
+        }
+
+        let source_start = cursor.peek_source_start()?;
+        let kind = match cursor.peek(0)?.map(|t| t.kind()) {
+            Some(TokenKind::Identifier(name)) if name.as_ref() == "get" => {
+                Some(MethodDefinitionKind::Get)
+            }
+            Some(TokenKind::Identifier(name)) if name.as_ref() == "set" => {
+                Some(MethodDefinitionKind::Set)
+            }
+            _ => None,
+        };
+        let kind = match kind {
+            Some(kind) if !ends_name(cursor.peek(1)?.map(|t| t.kind())) => {
+                cursor.next()?.expect("accessor token vanished");
+                Some(kind)
+            }
+            _ => None,
+        };
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let name = match token.kind() {
+            TokenKind::PrivateIdentifier(name) => {
+                if name.as_ref() == "constructor" {
+                    return Err(ParseError::general(
+                        "a private name can not be #constructor",
+                        token.span().start(),
+                    ));
+                }
+                ElementName::Private(name.clone())
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let expr = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "class element name")?;
+                ElementName::Computed(expr)
+            }
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(_)
+            | TokenKind::BooleanLiteral(_)
+            | TokenKind::NullLiteral
+            | TokenKind::NumericLiteral(_)
+            | TokenKind::StringLiteral(_) => ElementName::Literal(token.to_string().into()),
+            _ => return Err(ParseError::unexpected(token, "class element")),
+        };
+
+        if let ElementName::Literal(ref name) = name {
+            if is_static && name.as_ref() == "prototype" {
+                return Err(ParseError::general(
+                    "a class can not have a static element named 'prototype'",
+                    position,
+                ));
+            }
+        }
+
+        if cursor.next_if(Punctuator::OpenParen)?.is_none() {
+            if kind.is_some() {
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::OpenParen)],
+                    token,
+                    "class accessor",
+                ));
+            }
+            return self.parse_field(cursor, name, is_static, position);
+        }
+
+        // Only the constructor of a derived class can contain `super(...)` calls.
+        let super_call = self.derived
+            && !is_static
+            && kind.is_none()
+            && matches!(name, ElementName::Literal(ref name) if name.as_ref() == "constructor");
+        let enclosing_super_call = cursor.set_super_call_allowed(super_call);
+
+        let first_param = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "class method")?;
+        match kind {
+            Some(MethodDefinitionKind::Get) if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
+            }
+            Some(MethodDefinitionKind::Set) if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
+            }
+            _ => {}
+        }
+
+        cursor.expect(Punctuator::OpenBlock, "class method")?;
+        let body = FunctionBody::new(false, false)
+            .with_super_call(super_call)
+            .parse(cursor)?;
+        cursor.expect(Punctuator::CloseBlock, "class method")?;
+        cursor.set_super_call_allowed(enclosing_super_call);
+
+        let function = FunctionExpr::new(None, params, body);
+        let kind = kind.unwrap_or(MethodDefinitionKind::Ordinary);
+        let element = match name {
+            ElementName::Literal(name) if !is_static && name.as_ref() == "constructor" => {
+                if kind != MethodDefinitionKind::Ordinary {
+                    return Err(ParseError::general(
+                        "a class constructor can not be an accessor",
+                        position,
+                    ));
+                }
+                return Ok((ParsedElement::Constructor(function), position));
+            }
+            name => {
+                let function = function.with_source_text(cursor.source_text_from(source_start));
+                match name {
+                    ElementName::Literal(name) if is_static => {
+                        node::ClassElement::StaticMethodDefinition(
+                            PropertyName::Literal(name),
+                            kind,
+                            function,
+                        )
+                    }
+                    ElementName::Literal(name) => node::ClassElement::MethodDefinition(
+                        PropertyName::Literal(name),
+                        kind,
+                        function,
+                    ),
+                    ElementName::Computed(expr) if is_static => {
+                        node::ClassElement::StaticMethodDefinition(
+                            PropertyName::Computed(expr),
+                            kind,
+                            function,
+                        )
+                    }
+                    ElementName::Computed(expr) => node::ClassElement::MethodDefinition(
+                        PropertyName::Computed(expr),
+                        kind,
+                        function,
+                    ),
+                    ElementName::Private(name) if is_static => {
+                        node::ClassElement::PrivateStaticMethodDefinition(name, kind, function)
+                    }
+                    ElementName::Private(name) => {
+                        node::ClassElement::PrivateMethodDefinition(name, kind, function)
+                    }
+                }
+            }
+        };
+
+        Ok((ParsedElement::Element(Box::new(element)), position))
+    }
+}
+
+impl ClassElement {
+    /// Parses the rest of a field definition, after its name.
+    ///
+    /// The initializer of a field is evaluated like the body of a method, so it can not contain
+    /// `yield` or `await` expressions, nor `super(...)` calls.
+    fn parse_field<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        name: ElementName,
+        is_static: bool,
+        position: Position,
+    ) -> Result<(ParsedElement, Position), ParseError>
+    where
+        R: Read,
+    {
+        if let ElementName::Literal(ref name) = name {
+            if name.as_ref() == "constructor" {
+                return Err(ParseError::general(
+                    "a class field can not be named 'constructor'",
+                    position,
+                ));
+            }
+        }
+
+        let init = match cursor.peek(0)?.map(|t| t.kind()) {
+            Some(TokenKind::Punctuator(Punctuator::Assign)) => {
+                let enclosing_super_call = cursor.set_super_call_allowed(false);
+                let init = Initializer::new(true, false, false).parse(cursor)?;
+                cursor.set_super_call_allowed(enclosing_super_call);
+                Some(init)
+            }
+            _ => None,
+        };
+        cursor.expect_semicolon("class field")?;
+
+        let element = match name {
+            ElementName::Literal(name) if is_static => {
+                node::ClassElement::StaticFieldDefinition(PropertyName::Literal(name), init)
+            }
+            ElementName::Literal(name) => {
+                node::ClassElement::FieldDefinition(PropertyName::Literal(name), init)
+            }
+            ElementName::Computed(expr) if is_static => {
+                node::ClassElement::StaticFieldDefinition(PropertyName::Computed(expr), init)
+            }
+            ElementName::Computed(expr) => {
+                node::ClassElement::FieldDefinition(PropertyName::Computed(expr), init)
+            }
+            ElementName::Private(name) if is_static => {
+                node::ClassElement::PrivateStaticFieldDefinition(name, init)
+            }
+            ElementName::Private(name) => node::ClassElement::PrivateFieldDefinition(name, init),
+        };
+        Ok((ParsedElement::Element(Box::new(element)), position))
+    }
+
+    /// Parses the rest of a `static { }` block, after its opening brace.
+    ///
+    /// A static block is evaluated like the body of a method, but it can not contain `return`
+    /// statements, nor `super(...)` calls.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ClassStaticBlock
+    fn parse_static_block<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        position: Position,
+    ) -> Result<(ParsedElement, Position), ParseError>
+    where
+        R: Read,
+    {
+        // `break` and `continue` statements can't target statements outside of the block.
+        let break_targets = cursor.take_break_targets();
+        let enclosing_super_call = cursor.set_super_call_allowed(false);
+        let body =
+            StatementList::new(false, false, false, true, &STATIC_BLOCK_BREAK_TOKENS).parse(cursor);
+        cursor.set_super_call_allowed(enclosing_super_call);
+        cursor.set_break_targets(break_targets);
+        let body = body?.with_strict(true);
+        cursor.expect(Punctuator::CloseBlock, "class static block")?;
+
+        Ok((
+            ParsedElement::Element(Box::new(node::ClassElement::StaticBlock(body))),
+            position,
+        ))
+    }
+}
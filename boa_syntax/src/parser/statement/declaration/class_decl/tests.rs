@@ -0,0 +1,312 @@
+use crate::{
+    ast::{
+        node::{
+            ArrowFunctionDecl, Assign, Call, Class, ClassElement, FormalParameter, FunctionExpr,
+            GetConstField, GetPrivateField, Identifier, MethodDefinitionKind, Node, PrivateIn,
+            PropertyName, Return, StatementList, SuperCall,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Creates the strict body of a class method.
+fn body<B>(statements: B) -> StatementList
+where
+    B: Into<Box<[Node]>>,
+{
+    StatementList::from(statements).with_strict(true)
+}
+
+/// Checks the parsing of an empty class declaration.
+#[test]
+fn empty_class_declaration() {
+    check_parser(
+        "class A {}",
+        vec![Node::ClassDecl(Class::new(
+            Box::<str>::from("A"),
+            None,
+            vec![],
+        ))],
+    );
+}
+
+/// Checks the parsing of a class with a constructor, methods and private members.
+#[test]
+fn class_declaration_elements() {
+    check_parser(
+        "class A {
+            constructor(a) { this.#a = a; }
+            get a() { return this.#a; }
+            static ['m']() {}
+            #a = 1;
+            static #b;
+            #c() {}
+            has(o) { return #a in o; }
+        }",
+        vec![Node::ClassDecl(Class::new(
+            Box::<str>::from("A"),
+            FunctionExpr::new(
+                None,
+                vec![FormalParameter::new("a", None, false)],
+                body(vec![Assign::new(
+                    GetPrivateField::new(Node::This, "a"),
+                    Identifier::from("a"),
+                )
+                .into()]),
+            ),
+            vec![
+                ClassElement::MethodDefinition(
+                    "a".into(),
+                    MethodDefinitionKind::Get,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        body(vec![Return::new::<_, _, Option<Box<str>>>(
+                            GetPrivateField::new(Node::This, "a"),
+                            None,
+                        )
+                        .into()]),
+                    ),
+                ),
+                ClassElement::StaticMethodDefinition(
+                    PropertyName::Computed(Const::from("m").into()),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(None, vec![], body(vec![])),
+                ),
+                ClassElement::PrivateFieldDefinition("a".into(), Some(Const::from(1).into())),
+                ClassElement::PrivateStaticFieldDefinition("b".into(), None),
+                ClassElement::PrivateMethodDefinition(
+                    "c".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(None, vec![], body(vec![])),
+                ),
+                ClassElement::MethodDefinition(
+                    "has".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(
+                        None,
+                        vec![FormalParameter::new("o", None, false)],
+                        body(vec![Return::new::<_, _, Option<Box<str>>>(
+                            PrivateIn::new("a", Identifier::from("o")),
+                            None,
+                        )
+                        .into()]),
+                    ),
+                ),
+            ],
+        ))],
+    );
+}
+
+/// Checks that `static`, `get` and `set` are element names when they are not modifiers.
+#[test]
+fn class_modifier_names() {
+    check_parser(
+        "class A { static() {} get() {} static set(v) {} }",
+        vec![Node::ClassDecl(Class::new(
+            Box::<str>::from("A"),
+            None,
+            vec![
+                ClassElement::MethodDefinition(
+                    "static".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(None, vec![], body(vec![])),
+                ),
+                ClassElement::MethodDefinition(
+                    "get".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(None, vec![], body(vec![])),
+                ),
+                ClassElement::StaticMethodDefinition(
+                    "set".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(
+                        None,
+                        vec![FormalParameter::new("v", None, false)],
+                        body(vec![]),
+                    ),
+                ),
+            ],
+        ))],
+    );
+}
+
+/// Checks that a private getter and setter can share their name.
+#[test]
+fn class_private_accessor_pair() {
+    check_parser(
+        "class A { get #a() {} set #a(v) {} m() { this.#a.b; } }",
+        vec![Node::ClassDecl(Class::new(
+            Box::<str>::from("A"),
+            None,
+            vec![
+                ClassElement::PrivateMethodDefinition(
+                    "a".into(),
+                    MethodDefinitionKind::Get,
+                    FunctionExpr::new(None, vec![], body(vec![])),
+                ),
+                ClassElement::PrivateMethodDefinition(
+                    "a".into(),
+                    MethodDefinitionKind::Set,
+                    FunctionExpr::new(
+                        None,
+                        vec![FormalParameter::new("v", None, false)],
+                        body(vec![]),
+                    ),
+                ),
+                ClassElement::MethodDefinition(
+                    "m".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        body(vec![GetConstField::new(
+                            GetPrivateField::new(Node::This, "a"),
+                            "b",
+                        )
+                        .into()]),
+                    ),
+                ),
+            ],
+        ))],
+    );
+}
+
+/// Checks the parsing of public fields and static blocks.
+#[test]
+fn class_fields_and_static_blocks() {
+    check_parser(
+        "class A { a; static b = 1; ['c'] = this; static { this.d = 2; } static {} }",
+        vec![Node::ClassDecl(Class::new(
+            Box::<str>::from("A"),
+            None,
+            vec![
+                ClassElement::FieldDefinition("a".into(), None),
+                ClassElement::StaticFieldDefinition("b".into(), Some(Const::from(1).into())),
+                ClassElement::FieldDefinition(
+                    PropertyName::Computed(Const::from("c").into()),
+                    Some(Node::This),
+                ),
+                ClassElement::StaticBlock(body(vec![Assign::new(
+                    GetConstField::new(Node::This, "d"),
+                    Const::from(2),
+                )
+                .into()])),
+                ClassElement::StaticBlock(body(vec![])),
+            ],
+        ))],
+    );
+}
+
+/// Checks the parsing of derived classes, whose constructor can call the parent class.
+#[test]
+fn derived_class_declaration() {
+    check_parser(
+        "class B extends A { constructor(a) { super(a); () => super(); } }
+        class C extends mixin(A) {}",
+        vec![
+            Node::ClassDecl(
+                Class::new(
+                    Box::<str>::from("B"),
+                    FunctionExpr::new(
+                        None,
+                        vec![FormalParameter::new("a", None, false)],
+                        body(vec![
+                            SuperCall::new(vec![Identifier::from("a").into()]).into(),
+                            ArrowFunctionDecl::new(
+                                vec![],
+                                body(vec![Return::new(SuperCall::new(vec![]), None).into()]),
+                            )
+                            .into(),
+                        ]),
+                    ),
+                    vec![],
+                )
+                .with_heritage(Identifier::from("A")),
+            ),
+            Node::ClassDecl(
+                Class::new(Box::<str>::from("C"), None, vec![]).with_heritage(Call::new(
+                    Identifier::from("mixin"),
+                    vec![Identifier::from("A").into()],
+                )),
+            ),
+        ],
+    );
+}
+
+/// Checks that `super(...)` calls are only allowed in the constructor of a derived class.
+#[test]
+fn super_call_early_errors() {
+    check_invalid("super();");
+    check_invalid("function f() { super(); }");
+    check_invalid("class A { constructor() { super(); } }");
+    check_invalid("class A extends B { m() { super(); } }");
+    check_invalid("class A extends B { static constructor() { super(); } }");
+    check_invalid("class A extends B { constructor() { function f() { super(); } } }");
+    check_invalid("class A extends B { constructor() { class C { constructor() { super(); } } } }");
+    check_invalid("class A extends B { x = super(); }");
+    check_invalid("class A extends B { static { super(); } }");
+}
+
+/// Checks the early errors of class declarations.
+#[test]
+fn class_declaration_early_errors() {
+    check_invalid("class {}");
+    check_invalid("class A { constructor() {} constructor() {} }");
+    check_invalid("class A { get constructor() {} }");
+    check_invalid("class A { static prototype() {} }");
+    check_invalid("class A { #constructor() {} }");
+    check_invalid("class A { #a; #a() {} }");
+    check_invalid("class A { get #a() {} static set #a(v) {} }");
+    check_invalid("class A { m() { with (a) {} } }");
+    check_invalid("class eval {}");
+    check_invalid("class static {}");
+    check_invalid("class A { m() { return static; } }");
+    check_invalid("let A; class A {}");
+    check_invalid("class A { constructor = 1; }");
+    check_invalid("class A { static prototype; }");
+    check_invalid("class A { static { return; } }");
+    check_invalid("while (true) { class A { static { break; } } }");
+    check_invalid("class A { static { yield; } }");
+}
+
+/// Checks that private names have to be declared by an enclosing class.
+#[test]
+fn class_undeclared_private_names() {
+    check_invalid("this.#a;");
+    check_invalid("class A { m() { return this.#a; } }");
+    check_invalid("class A { #a; } class B { m() { return #a in this; } }");
+    check_invalid("class A { #a; m() { delete this.#a; } }");
+    check_parser(
+        "class A { #a; m() { class B { m() { this.#a; } } } }",
+        vec![Node::ClassDecl(Class::new(
+            Box::<str>::from("A"),
+            None,
+            vec![
+                ClassElement::PrivateFieldDefinition("a".into(), None),
+                ClassElement::MethodDefinition(
+                    "m".into(),
+                    MethodDefinitionKind::Ordinary,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        body(vec![Node::ClassDecl(Class::new(
+                            Box::<str>::from("B"),
+                            None,
+                            vec![ClassElement::MethodDefinition(
+                                "m".into(),
+                                MethodDefinitionKind::Ordinary,
+                                FunctionExpr::new(
+                                    None,
+                                    vec![],
+                                    body(vec![GetPrivateField::new(Node::This, "a").into()]),
+                                ),
+                            )],
+                        ))]),
+                    ),
+                ),
+            ],
+        ))],
+    );
+}
@@ -0,0 +1,61 @@
+use crate::{
+    ast::{
+        node::{
+            AsyncFunctionDecl, AwaitExpr, BinOp, Declaration, DeclarationList, Identifier, Node,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+/// Async function declaration parsing.
+#[test]
+fn async_function_declaration() {
+    check_parser(
+        "async function hello() {}",
+        vec![AsyncFunctionDecl::new(Box::from("hello"), vec![], vec![]).into()],
+    );
+}
+
+/// Async function declaration parsing with keywords.
+#[test]
+fn async_function_declaration_keywords() {
+    check_parser(
+        "async function yield() {}",
+        vec![AsyncFunctionDecl::new(Box::from("yield"), vec![], vec![]).into()],
+    );
+
+    check_parser(
+        "async function await() {}",
+        vec![AsyncFunctionDecl::new(Box::from("await"), vec![], vec![]).into()],
+    );
+}
+
+/// Await expressions in the body of an async function declaration.
+#[test]
+fn async_function_declaration_await() {
+    check_parser(
+        "async function hello() { let a = await b + 1; }",
+        vec![AsyncFunctionDecl::new(
+            Box::from("hello"),
+            vec![],
+            vec![DeclarationList::Let(
+                vec![Declaration::new_with_identifier(
+                    "a",
+                    Some(
+                        BinOp::new(
+                            NumOp::Add,
+                            AwaitExpr::from(Node::from(Identifier::from("b"))),
+                            Const::from(1),
+                        )
+                        .into(),
+                    ),
+                )]
+                .into(),
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
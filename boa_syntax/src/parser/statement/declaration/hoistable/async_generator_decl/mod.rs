@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ast::{node::AsyncGeneratorDecl, Keyword, Punctuator},
+    lexer::TokenKind,
+    parser::{
+        function::FormalParameters,
+        function::FunctionBody,
+        statement::{BindingIdentifier, LexError, Position},
+        AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
+    },
+};
+use std::io::Read;
+
+/// Async generator declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function*
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncGeneratorDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AsyncGeneratorDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl AsyncGeneratorDeclaration {
+    /// Creates a new `AsyncGeneratorDeclaration` parser.
+    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AsyncGeneratorDeclaration
+where
+    R: Read,
+{
+    type Output = AsyncGeneratorDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Keyword::Async, "async generator declaration")?;
+        cursor.peek_expect_no_lineterminator(0, "async generator declaration")?;
+        cursor.expect(Keyword::Function, "async generator declaration")?;
+        cursor.expect(Punctuator::Mul, "async generator declaration")?;
+        let tok = cursor.peek(0)?;
+
+        let name = if let Some(token) = tok {
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    if !self.is_default.0 {
+                        return Err(ParseError::unexpected(
+                            token.clone(),
+                            " in async generator declaration",
+                        ));
+                    }
+                    None
+                }
+                _ => {
+                    Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?)
+                }
+            }
+        } else {
+            return Err(ParseError::AbruptEnd);
+        };
+
+        cursor.expect(Punctuator::OpenParen, "async generator declaration")?;
+
+        let params = FormalParameters::new(true, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async generator declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "async generator declaration")?;
+
+        let body = FunctionBody::new(true, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "async generator declaration")?;
+
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters
+        // also occurs in the LexicallyDeclaredNames of FunctionBody.
+        // https://tc39.es/ecma262/#sec-async-generator-function-definitions-static-semantics-early-errors
+        {
+            let lexically_declared_names = body.lexically_declared_names();
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
+                        match cursor.peek(0)? {
+                            Some(token) => token.span().end(),
+                            None => Position::new(1, 1),
+                        },
+                    )));
+                }
+            }
+        }
+
+        Ok(AsyncGeneratorDecl::new(name, params, body))
+    }
+}
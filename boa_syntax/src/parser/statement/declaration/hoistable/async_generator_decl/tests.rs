@@ -0,0 +1,66 @@
+use crate::{
+    ast::{
+        node::{AsyncGeneratorDecl, AwaitExpr, ForOfLoop, Identifier, Node, YieldExpr},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Async generator declaration parsing.
+#[test]
+fn async_generator_declaration() {
+    check_parser(
+        "async function* gen() {}",
+        vec![AsyncGeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+
+    check_parser(
+        "async function *gen() {}",
+        vec![AsyncGeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Yield and await expressions in the body of an async generator declaration.
+#[test]
+fn async_generator_declaration_yield_await() {
+    check_parser(
+        "async function* gen() { yield 1; await a; yield* b; }",
+        vec![AsyncGeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                YieldExpr::new(Node::from(Const::from(1)), false).into(),
+                AwaitExpr::from(Node::from(Identifier::from("a"))).into(),
+                YieldExpr::new(Node::from(Identifier::from("b")), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// `for await` loops in the body of an async generator declaration.
+#[test]
+fn async_generator_declaration_for_await() {
+    check_parser(
+        "async function* gen() { for await (x of xs) yield x; }",
+        vec![AsyncGeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![ForOfLoop::new_await(
+                Identifier::from("x"),
+                Identifier::from("xs"),
+                YieldExpr::new(Node::from(Identifier::from("x")), false),
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// `for await` loops are only allowed where `await` is, and only iterate with `of`.
+#[test]
+fn for_await_outside_async() {
+    check_invalid("function f() { for await (x of xs); }");
+    check_invalid("async function f() { for await (x in xs); }");
+    check_invalid("async function f() { for await (;;); }");
+}
@@ -1,4 +1,4 @@
-use crate::syntax::{ast::node::FunctionDecl, parser::tests::check_parser};
+use crate::{ast::node::FunctionDecl, parser::tests::check_parser};
 
 /// Function declaration parsing.
 #[test]
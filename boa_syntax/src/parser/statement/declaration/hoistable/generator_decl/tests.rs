@@ -0,0 +1,82 @@
+use crate::{
+    ast::{
+        node::{BinOp, Declaration, DeclarationList, GeneratorDecl, Identifier, Node, YieldExpr},
+        op::NumOp,
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+
+    check_parser(
+        "function *gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Yield expressions in the body of a generator declaration.
+#[test]
+fn generator_declaration_yield() {
+    check_parser(
+        "function* gen() { yield; yield 1; let a = yield b + 1; yield* c; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                YieldExpr::new(None, false).into(),
+                YieldExpr::new(Node::from(Const::from(1)), false).into(),
+                DeclarationList::Let(
+                    vec![Declaration::new_with_identifier(
+                        "a",
+                        Some(
+                            YieldExpr::new(
+                                Node::from(BinOp::new(
+                                    NumOp::Add,
+                                    Identifier::from("b"),
+                                    Const::from(1),
+                                )),
+                                false,
+                            )
+                            .into(),
+                        ),
+                    )]
+                    .into(),
+                )
+                .into(),
+                YieldExpr::new(Node::from(Identifier::from("c")), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// The operand of a yield expression must be on the same line.
+#[test]
+fn generator_declaration_yield_line_terminator() {
+    check_parser(
+        "function* gen() {
+            yield
+            1
+        }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![YieldExpr::new(None, false).into(), Const::from(1).into()],
+        )
+        .into()],
+    );
+}
+
+/// Yield expressions are only allowed in the body of generators.
+#[test]
+fn yield_outside_generator() {
+    check_invalid("function* gen() { function f() { yield 1; } }");
+    check_invalid("function gen() { yield 1; }");
+}
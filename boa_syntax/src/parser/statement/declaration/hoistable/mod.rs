@@ -0,0 +1,119 @@
+//! Hoistable declaration parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-HoistableDeclaration
+
+#[cfg(test)]
+mod tests;
+
+mod async_function_decl;
+mod async_generator_decl;
+mod function_decl;
+mod generator_decl;
+
+use async_function_decl::AsyncFunctionDeclaration;
+use async_generator_decl::AsyncGeneratorDeclaration;
+use function_decl::FunctionDeclaration;
+use generator_decl::GeneratorDeclaration;
+
+use crate::{
+    ast::{Keyword, Node, Punctuator},
+    lexer::TokenKind,
+    parser::{AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Hoistable declaration parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-FunctionDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct HoistableDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl HoistableDeclaration {
+    /// Creates a new `HoistableDeclaration` parser.
+    pub(in crate::parser) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for HoistableDeclaration
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
+        let is_generator = matches!(
+            cursor.peek(1)?.map(|t| t.kind()),
+            Some(TokenKind::Punctuator(Punctuator::Mul))
+        );
+        let is_async_generator = matches!(
+            cursor.peek(2)?.map(|t| t.kind()),
+            Some(TokenKind::Punctuator(Punctuator::Mul))
+        );
+        let source_start = cursor.peek_source_start()?;
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Function) if is_generator => {
+                let decl =
+                    GeneratorDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
+                        .parse(cursor)?;
+                Ok(decl
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
+            TokenKind::Keyword(Keyword::Function) => {
+                let decl =
+                    FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
+                        .parse(cursor)?;
+                Ok(decl
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
+            TokenKind::Keyword(Keyword::Async) if is_async_generator => {
+                let decl = AsyncGeneratorDeclaration::new(
+                    self.allow_yield,
+                    self.allow_await,
+                    self.is_default,
+                )
+                .parse(cursor)?;
+                Ok(decl
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
+            TokenKind::Keyword(Keyword::Async) => {
+                let decl = AsyncFunctionDeclaration::new(
+                    self.allow_yield,
+                    self.allow_await,
+                    self.is_default,
+                )
+                .parse(cursor)?;
+                Ok(decl
+                    .with_source_text(cursor.source_text_from(source_start))
+                    .into())
+            }
+            _ => unreachable!("unknown token found: {:?}", tok),
+        }
+    }
+}
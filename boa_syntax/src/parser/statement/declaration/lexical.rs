@@ -8,21 +8,19 @@
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
 
 use crate::{
-    syntax::{
-        ast::{
-            node::{
-                declaration::{Declaration, DeclarationList},
-                Node,
-            },
-            Keyword, Punctuator,
-        },
-        lexer::TokenKind,
-        parser::{
-            cursor::{Cursor, SemicolonResult},
-            expression::Initializer,
-            statement::{ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern},
-            AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
+    ast::{
+        node::{
+            declaration::{Declaration, DeclarationList},
+            Node,
         },
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        cursor::{Cursor, SemicolonResult},
+        expression::Initializer,
+        statement::{ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern},
+        AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
     },
     BoaProfiler,
 };
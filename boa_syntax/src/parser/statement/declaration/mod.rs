@@ -7,19 +7,23 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class_decl;
 mod hoistable;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+pub(in crate::parser) use self::{
+    class_decl::{ClassDeclaration, ClassTail},
+    hoistable::HoistableDeclaration,
+};
+
+use self::lexical::LexicalDeclaration;
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{Keyword, Node},
-        parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
-    },
+    ast::{Keyword, Node},
+    parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
     BoaProfiler,
 };
 
@@ -32,14 +36,18 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Declaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct Declaration {
+pub(in crate::parser) struct Declaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     const_init_required: bool,
 }
 
 impl Declaration {
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, const_init_required: bool) -> Self
+    pub(in crate::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        const_init_required: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -75,6 +83,11 @@ where
                 )
                 .parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await, false)
+                    .parse(cursor)
+                    .map(Node::ClassDecl)
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         }
     }
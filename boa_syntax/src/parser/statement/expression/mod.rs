@@ -1,9 +1,7 @@
 use super::super::{expression::Expression, ParseResult};
 use crate::{
-    syntax::{
-        ast::node::Node,
-        parser::{AllowAwait, AllowYield, Cursor, TokenParser},
-    },
+    ast::node::Node,
+    parser::{AllowAwait, AllowYield, Cursor, TokenParser},
     BoaProfiler,
 };
 use std::io::Read;
@@ -15,14 +13,14 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-ExpressionStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct ExpressionStatement {
+pub(in crate::parser::statement) struct ExpressionStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl ExpressionStatement {
     /// Creates a new `ExpressionStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
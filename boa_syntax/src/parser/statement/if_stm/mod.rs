@@ -3,14 +3,12 @@ mod tests;
 
 use super::Statement;
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::If, Keyword, Node, Punctuator},
-        parser::{
-            expression::Expression, AllowAwait, AllowReturn, AllowYield, Cursor, ParseError,
-            TokenParser,
-        },
+    ast::{node::If, Keyword, Node, Punctuator},
+    parser::{
+        expression::Expression, AllowAwait, AllowReturn, AllowYield, Cursor, ParseError,
+        TokenParser,
     },
     BoaProfiler,
 };
@@ -1,4 +1,4 @@
-use crate::syntax::{
+use crate::{
     ast::{
         node::{Block, If, Node},
         Const,
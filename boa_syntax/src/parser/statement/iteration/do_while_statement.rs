@@ -7,14 +7,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/do...while
 //! [spec]: https://tc39.es/ecma262/#sec-do-while-statement
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::DoWhileLoop, Keyword, Punctuator},
-        parser::{
-            expression::Expression, statement::Statement, AllowAwait, AllowReturn, AllowYield,
-            Cursor, ParseError, TokenParser,
-        },
+    ast::{node::DoWhileLoop, Keyword, Punctuator},
+    parser::{
+        expression::Expression, statement::Statement, AllowAwait, AllowReturn, AllowYield, Cursor,
+        ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -29,7 +27,7 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/do...while
 /// [spec]: https://tc39.es/ecma262/#sec-do-while-statement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct DoWhileStatement {
+pub(in crate::parser::statement) struct DoWhileStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -37,7 +35,7 @@ pub(in crate::syntax::parser::statement) struct DoWhileStatement {
 
 impl DoWhileStatement {
     /// Creates a new `DoWhileStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A, R>(
+    pub(in crate::parser::statement) fn new<Y, A, R>(
         allow_yield: Y,
         allow_await: A,
         allow_return: R,
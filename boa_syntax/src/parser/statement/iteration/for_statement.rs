@@ -7,19 +7,22 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for
 //! [spec]: https://tc39.es/ecma262/#sec-for-statement
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::{Position, TokenKind};
 use crate::{
-    syntax::{
-        ast::{
-            node::{ForInLoop, ForLoop, ForOfLoop, Node},
-            Const, Keyword, Punctuator,
+    ast::{
+        node::{
+            declaration::DeclarationList, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer,
+            Node,
         },
-        parser::{
-            expression::Expression,
-            statement::declaration::Declaration,
-            statement::{variable::VariableDeclarationList, Statement},
-            AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
+        Const, Keyword, Punctuator,
+    },
+    parser::{
+        expression::{
+            array_decl_to_declaration_pattern, object_decl_to_declaration_pattern, Expression,
         },
+        statement::declaration::Declaration,
+        statement::{variable::VariableDeclarationList, Statement},
+        AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -35,7 +38,7 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for
 /// [spec]: https://tc39.es/ecma262/#sec-for-statement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct ForStatement {
+pub(in crate::parser::statement) struct ForStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -43,7 +46,7 @@ pub(in crate::syntax::parser::statement) struct ForStatement {
 
 impl ForStatement {
     /// Creates a new `ForStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A, R>(
+    pub(in crate::parser::statement) fn new<Y, A, R>(
         allow_yield: Y,
         allow_await: A,
         allow_return: R,
@@ -70,6 +73,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ForStatement", "Parsing");
         cursor.expect(Keyword::For, "for statement")?;
+        let is_await = self.allow_await.0 && cursor.next_if(Keyword::Await)?.is_some();
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
         let init = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
@@ -89,23 +93,45 @@ where
         };
 
         match cursor.peek(0)? {
-            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() => {
+            Some(tok)
+                if tok.kind() == &TokenKind::Keyword(Keyword::In)
+                    && init.is_some()
+                    && !is_await =>
+            {
+                let init = node_to_iterable_loop_initializer(
+                    &init.expect("for in initializer vanished"),
+                    tok.span().start(),
+                )?;
                 let _ = cursor.next();
                 let expr =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "for in statement")?;
                 let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)?;
-                return Ok(ForInLoop::new(init.unwrap(), expr, body).into());
+                return Ok(ForInLoop::new(init, expr, body).into());
             }
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
+                let init = node_to_iterable_loop_initializer(
+                    &init.expect("for of initializer vanished"),
+                    tok.span().start(),
+                )?;
                 let _ = cursor.next();
                 let iterable =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "for of statement")?;
                 let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)?;
-                return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
+                return if is_await {
+                    Ok(ForOfLoop::new_await(init, iterable, body).into())
+                } else {
+                    Ok(ForOfLoop::new(init, iterable, body).into())
+                };
+            }
+            Some(tok) if is_await => {
+                return Err(ParseError::unexpected(
+                    tok.clone(),
+                    "for await of statement",
+                ))
             }
             _ => {}
         }
@@ -138,3 +164,49 @@ where
         Ok(ForLoop::new(init, cond, step, body).into())
     }
 }
+
+/// Converts the left hand side of a `for...in` or `for...of` statement to its loop initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForInOfStatement
+fn node_to_iterable_loop_initializer(
+    node: &Node,
+    position: Position,
+) -> Result<IterableLoopInitializer, ParseError> {
+    let declaration = |list: &DeclarationList| match list.as_ref() {
+        [declaration] if declaration.init().is_none() => Ok(declaration.clone()),
+        [_] => Err(ParseError::general(
+            "a declaration in the head of a for-in/of loop can't have an initializer",
+            position,
+        )),
+        _ => Err(ParseError::general(
+            "only one variable can be declared in the head of a for-in/of loop",
+            position,
+        )),
+    };
+
+    match node {
+        Node::Identifier(ident) => Ok(IterableLoopInitializer::Identifier(ident.clone())),
+        Node::GetConstField(field) => Ok(IterableLoopInitializer::GetConstField(field.clone())),
+        Node::GetField(field) => Ok(IterableLoopInitializer::GetField(field.clone())),
+        Node::VarDeclList(list) => declaration(list).map(IterableLoopInitializer::Var),
+        Node::LetDeclList(list) => declaration(list).map(IterableLoopInitializer::Let),
+        Node::ConstDeclList(list) => declaration(list).map(IterableLoopInitializer::Const),
+        Node::Object(object) => object_decl_to_declaration_pattern(object, false)
+            .map(IterableLoopInitializer::DeclarationPattern)
+            .ok_or_else(|| {
+                ParseError::general("invalid left-hand side in for-in/of loop", position)
+            }),
+        Node::ArrayDecl(array) => array_decl_to_declaration_pattern(array, false)
+            .map(IterableLoopInitializer::DeclarationPattern)
+            .ok_or_else(|| {
+                ParseError::general("invalid left-hand side in for-in/of loop", position)
+            }),
+        _ => Err(ParseError::general(
+            "invalid left-hand side in for-in/of loop",
+            position,
+        )),
+    }
+}
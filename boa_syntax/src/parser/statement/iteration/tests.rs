@@ -1,4 +1,4 @@
-use crate::syntax::{
+use crate::{
     ast::{
         node::{
             field::GetConstField, BinOp, Block, Break, Call, Declaration, DeclarationList,
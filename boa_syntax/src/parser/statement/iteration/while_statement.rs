@@ -1,10 +1,8 @@
 use crate::{
-    syntax::{
-        ast::{node::WhileLoop, Keyword, Punctuator},
-        parser::{
-            expression::Expression, statement::Statement, AllowAwait, AllowReturn, AllowYield,
-            Cursor, ParseError, TokenParser,
-        },
+    ast::{node::WhileLoop, Keyword, Punctuator},
+    parser::{
+        expression::Expression, statement::Statement, AllowAwait, AllowReturn, AllowYield, Cursor,
+        ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -20,7 +18,7 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/while
 /// [spec]: https://tc39.es/ecma262/#sec-while-statement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct WhileStatement {
+pub(in crate::parser::statement) struct WhileStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -28,7 +26,7 @@ pub(in crate::syntax::parser::statement) struct WhileStatement {
 
 impl WhileStatement {
     /// Creates a new `WhileStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A, R>(
+    pub(in crate::parser::statement) fn new<Y, A, R>(
         allow_yield: Y,
         allow_await: A,
         allow_return: R,
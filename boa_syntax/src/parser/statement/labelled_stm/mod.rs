@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests;
+
+use std::io::Read;
+
+use super::{LabelIdentifier, Statement};
+use crate::{
+    ast::{node::Labelled, Keyword, Node, Punctuator},
+    lexer::{InputElement, Token, TokenKind},
+    parser::{cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser},
+    BoaProfiler,
+};
+/// Labelled Statement Parsing
+///
+/// Consecutive labels are parsed together, as the label set of the statement they label.
+///
+/// More information
+/// - [MDN documentation][mdn]
+/// - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+/// [spec]: https://tc39.es/ecma262/#sec-labelled-statements
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LabelledStatement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    allow_return: AllowReturn,
+}
+
+impl LabelledStatement {
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        R: Into<AllowReturn>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            allow_return: allow_return.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for LabelledStatement
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("Label", "Parsing");
+
+        let mut labels: Vec<Box<str>> = Vec::new();
+        loop {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            cursor.expect(Punctuator::Colon, "Labelled Statement")?;
+
+            // It is a Syntax Error if any source text is matched by this production, with a
+            // label that is already in the label set of an enclosing statement.
+            if labels.contains(&name) || cursor.has_label(&name) {
+                return Err(ParseError::general("label is already declared", position));
+            }
+            labels.push(name);
+
+            cursor.set_goal(InputElement::Div);
+            let next_is_label = matches!(
+                cursor.peek(0)?.map(Token::kind),
+                Some(
+                    TokenKind::Identifier(_) | TokenKind::Keyword(Keyword::Yield | Keyword::Await)
+                )
+            ) && matches!(
+                cursor.peek(1)?.map(Token::kind),
+                Some(TokenKind::Punctuator(Punctuator::Colon))
+            );
+            if !next_is_label {
+                break;
+            }
+        }
+
+        // The innermost label is set on a labelled iteration statement.
+        let loop_label = match cursor.peek(0)?.map(Token::kind) {
+            Some(TokenKind::Keyword(Keyword::For | Keyword::While | Keyword::Do)) => labels.last(),
+            _ => None,
+        }
+        .cloned();
+
+        for label in &labels {
+            cursor.push_label(label.clone(), loop_label.clone());
+        }
+        let mut stmt =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        for _ in &labels {
+            cursor.pop_label();
+        }
+
+        if let Some(loop_label) = loop_label {
+            labels.pop();
+            set_label_for_node(&mut stmt, loop_label);
+        }
+        Ok(labels
+            .into_iter()
+            .rev()
+            .fold(stmt, |stmt, label| Labelled::new(label, stmt).into()))
+    }
+}
+
+fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
+    match stmt {
+        Node::ForLoop(ref mut for_loop) => for_loop.set_label(name),
+        Node::ForOfLoop(ref mut for_of_loop) => for_of_loop.set_label(name),
+        Node::ForInLoop(ref mut for_in_loop) => for_in_loop.set_label(name),
+        Node::DoWhileLoop(ref mut do_while_loop) => do_while_loop.set_label(name),
+        Node::WhileLoop(ref mut while_loop) => while_loop.set_label(name),
+        _ => (),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    ast::{
+        node::{Block, Break, Continue, Labelled, WhileLoop},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_labelled_block() {
+    check_parser(
+        "a: { break a; }",
+        vec![Labelled::new("a", Block::from(vec![Break::new("a").into()])).into()],
+    );
+}
+
+#[test]
+fn check_labelled_loop() {
+    let mut while_loop = WhileLoop::new(Const::from(true), Continue::new("a"));
+    while_loop.set_label("a".into());
+
+    check_parser("a: while (true) continue a;", vec![while_loop.into()]);
+}
+
+#[test]
+fn check_multiple_loop_labels() {
+    // Every label of an iteration statement is continued through the label set on the loop.
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("b").into(), Break::new("a").into()]),
+    );
+    while_loop.set_label("b".into());
+
+    check_parser(
+        "a: b: while (true) { continue a; break a; }",
+        vec![Labelled::new("a", while_loop).into()],
+    );
+}
+
+#[test]
+fn check_invalid_labels() {
+    check_invalid("a: a: ;");
+    check_invalid("a: { a: ; }");
+    check_invalid("a: { break b; }");
+    check_invalid("a: { continue a; }");
+    check_invalid("a: while (true) { function f() { break a; } }");
+    check_invalid("a: while (true) { b: { continue b; } }");
+}
+
+#[test]
+fn check_invalid_unlabelled_break_and_continue() {
+    check_invalid("break;");
+    check_invalid("continue;");
+    check_invalid("switch (a) { case 1: continue; }");
+    check_invalid("while (true) { (function () { break; }); }");
+}
@@ -20,12 +20,17 @@ mod switch;
 mod throw;
 mod try_stm;
 mod variable;
+mod with;
+
+pub(in crate::parser) use self::{
+    declaration::{ClassDeclaration, ClassTail, Declaration, HoistableDeclaration},
+    variable::VariableStatement,
+};
 
 use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -33,9 +38,9 @@ use self::{
     switch::SwitchStatement,
     throw::ThrowStatement,
     try_stm::TryStatement,
-    variable::VariableStatement,
+    with::WithStatement,
 };
-use crate::syntax::{
+use crate::{
     ast::node::declaration::{
         DeclarationPattern, DeclarationPatternArray, DeclarationPatternObject,
     },
@@ -45,17 +50,14 @@ use crate::syntax::{
 use super::{AllowAwait, AllowIn, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 
 use crate::{
-    syntax::{
-        ast::{
-            node::{
-                self,
-                declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
-            },
-            Keyword, Node, Punctuator,
+    ast::{
+        node::{
+            self,
+            declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
         },
-        lexer::{Error as LexError, InputElement, Position, TokenKind},
-        parser::expression::await_expr::AwaitExpression,
+        Keyword, Node, Punctuator, Span,
     },
+    lexer::{Error as LexError, InputElement, Position, TokenKind},
     BoaProfiler,
 };
 use labelled_stm::LabelledStatement;
@@ -124,9 +126,6 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -138,19 +137,31 @@ where
                     .map(Node::from)
             }
             TokenKind::Keyword(Keyword::While) => {
-                WhileStatement::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor)
-                    .map(Node::from)
+                cursor.enter_breakable(true);
+                let while_loop =
+                    WhileStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse(cursor)
+                        .map(Node::from);
+                cursor.exit_breakable(true);
+                while_loop
             }
             TokenKind::Keyword(Keyword::Do) => {
-                DoWhileStatement::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor)
-                    .map(Node::from)
+                cursor.enter_breakable(true);
+                let do_while_loop =
+                    DoWhileStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse(cursor)
+                        .map(Node::from);
+                cursor.exit_breakable(true);
+                do_while_loop
             }
             TokenKind::Keyword(Keyword::For) => {
-                ForStatement::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor)
-                    .map(Node::from)
+                cursor.enter_breakable(true);
+                let for_loop =
+                    ForStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse(cursor)
+                        .map(Node::from);
+                cursor.exit_breakable(true);
+                for_loop
             }
             TokenKind::Keyword(Keyword::Return) => {
                 if self.allow_return.0 {
@@ -181,11 +192,20 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
-            TokenKind::Keyword(Keyword::Switch) => {
-                SwitchStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+            TokenKind::Keyword(Keyword::With) => {
+                WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::Switch) => {
+                cursor.enter_breakable(false);
+                let switch =
+                    SwitchStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse(cursor)
+                        .map(Node::from);
+                cursor.exit_breakable(false);
+                switch
+            }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 BlockStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -196,7 +216,8 @@ where
                 cursor.next().expect("semicolon disappeared");
                 Ok(Node::Empty)
             }
-            TokenKind::Identifier(_) => {
+            // `yield` and `await` are label identifiers where they are not reserved.
+            TokenKind::Identifier(_) | TokenKind::Keyword(Keyword::Yield | Keyword::Await) => {
                 // Labelled Statement check
                 cursor.set_goal(InputElement::Div);
                 let tok = cursor.peek(1)?;
@@ -289,6 +310,7 @@ where
                 _ => {}
             }
 
+            let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let item = StatementListItem::new(
                 self.allow_yield,
                 self.allow_await,
@@ -296,7 +318,7 @@ where
                 self.in_block,
             )
             .parse(cursor)?;
-            items.push(item);
+            items.push((item, Span::new(start, cursor.last_end())));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
@@ -309,7 +331,7 @@ where
             let mut var_declared_names: HashSet<&str> = HashSet::new();
 
             // TODO: Use more helpful positions in errors when spans are added to Nodes
-            for item in &items {
+            for (item, _) in &items {
                 match item {
                     Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
                         for decl in decl_list.as_ref() {
@@ -393,14 +415,29 @@ where
                             }
                         }
                     }
+                    Node::ClassDecl(class) => {
+                        let name = class.name().expect("class declarations have a name");
+                        if var_declared_names.contains(name)
+                            || !lexically_declared_names.insert(name)
+                        {
+                            return Err(ParseError::lex(LexError::Syntax(
+                                format!("Redeclaration of variable `{}`", name).into(),
+                                match cursor.peek(0)? {
+                                    Some(token) => token.span().end(),
+                                    None => Position::new(1, 1),
+                                },
+                            )));
+                        }
+                    }
                     _ => (),
                 }
             }
         }
 
-        items.sort_by(Node::hoistable_order);
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, spans): (Vec<_>, Vec<_>) = items.into_iter().unzip();
 
-        Ok(items.into())
+        Ok(node::StatementList::from(items).with_spans(spans))
     }
 }
 
@@ -415,7 +452,7 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(in crate::parser) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -424,7 +461,12 @@ struct StatementListItem {
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R, in_block: bool) -> Self
+    pub(in crate::parser) fn new<Y, A, R>(
+        allow_yield: Y,
+        allow_await: A,
+        allow_return: R,
+        in_block: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -460,7 +502,9 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
@@ -519,6 +563,15 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode()
+                    && matches!(s.as_ref(), "eval" | "arguments" | "static") =>
+            {
+                Err(ParseError::lex(LexError::Syntax(
+                    format!("binding '{}' not allowed in strict mode", s).into(),
+                    next_token.span().start(),
+                )))
+            }
             TokenKind::Identifier(ref s) => Ok(s.clone()),
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
                 if cursor.strict_mode() {
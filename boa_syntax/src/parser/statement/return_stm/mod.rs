@@ -1,15 +1,13 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::lexer::TokenKind;
+use crate::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::Return, Keyword, Node, Punctuator},
-        parser::{
-            cursor::{Cursor, SemicolonResult},
-            expression::Expression,
-            AllowAwait, AllowYield, ParseError, TokenParser,
-        },
+    ast::{node::Return, Keyword, Node, Punctuator},
+    parser::{
+        cursor::{Cursor, SemicolonResult},
+        expression::Expression,
+        AllowAwait, AllowYield, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -2,13 +2,11 @@
 mod tests;
 
 use crate::{
-    syntax::{
-        ast::{node, node::Switch, Keyword, Punctuator},
-        lexer::TokenKind,
-        parser::{
-            expression::Expression, statement::StatementList, AllowAwait, AllowReturn, AllowYield,
-            Cursor, ParseError, TokenParser,
-        },
+    ast::{node, node::Switch, Keyword, Punctuator},
+    lexer::TokenKind,
+    parser::{
+        expression::Expression, statement::StatementList, AllowAwait, AllowReturn, AllowYield,
+        Cursor, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -1,4 +1,4 @@
-use crate::syntax::{
+use crate::{
     ast::{
         node::{
             Break, Call, Case, Declaration, DeclarationList, GetConstField, Identifier, Node,
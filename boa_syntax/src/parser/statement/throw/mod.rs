@@ -1,12 +1,9 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::lexer::TokenKind;
 use crate::{
-    syntax::{
-        ast::{node::Throw, Keyword, Punctuator},
-        parser::{expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
-    },
+    ast::{node::Throw, Keyword},
+    parser::{expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
     BoaProfiler,
 };
 
@@ -53,11 +50,7 @@ where
         cursor.peek_expect_no_lineterminator(0, "throw statement")?;
 
         let expr = Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
-        if let Some(tok) = cursor.peek(0)? {
-            if tok.kind() == &TokenKind::Punctuator(Punctuator::Semicolon) {
-                let _ = cursor.next();
-            }
-        }
+        cursor.expect_semicolon("throw statement")?;
 
         Ok(Throw::new(expr))
     }
@@ -0,0 +1,28 @@
+use crate::{
+    ast::{
+        node::{Identifier, Throw},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_throw_parsing() {
+    check_parser(
+        "throw 'error';",
+        vec![Throw::new(Const::from("error")).into()],
+    );
+}
+
+#[test]
+fn check_throw_semicolon_insertion() {
+    check_parser(
+        "throw 'error'\nfoo",
+        vec![
+            Throw::new(Const::from("error")).into(),
+            Identifier::from("foo").into(),
+        ],
+    );
+    check_invalid("throw 'error' foo");
+    check_invalid("throw\n'error'");
+}
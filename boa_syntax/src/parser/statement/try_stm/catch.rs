@@ -0,0 +1,152 @@
+use crate::{
+    ast::{
+        node::{self, Declaration},
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        statement::{block::Block, ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern},
+        AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
+    },
+    BoaProfiler,
+};
+
+use std::{collections::HashSet, io::Read};
+
+/// Catch parsing
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/try...catch
+/// [spec]: https://tc39.es/ecma262/#prod-Catch
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Catch {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    allow_return: AllowReturn,
+}
+
+impl Catch {
+    /// Creates a new `Catch` block parser.
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        R: Into<AllowReturn>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            allow_return: allow_return.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for Catch
+where
+    R: Read,
+{
+    type Output = node::Catch;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("Catch", "Parsing");
+        cursor.expect(Keyword::Catch, "try statement")?;
+        let catch_param = if cursor.next_if(Punctuator::OpenParen)?.is_some() {
+            let catch_param =
+                CatchParameter::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            cursor.expect(Punctuator::CloseParen, "catch in try statement")?;
+            Some(catch_param)
+        } else {
+            None
+        };
+
+        // Catch block
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let catch_block =
+            Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+
+        // It is a Syntax Error if BoundNames of CatchParameter contains any duplicate elements.
+        // It is a Syntax Error if any element of the BoundNames of CatchParameter also occurs in
+        // the LexicallyDeclaredNames of Block.
+        // https://tc39.es/ecma262/#sec-try-statement-static-semantics-early-errors
+        if let Some(ref catch_param) = catch_param {
+            let bound_names = match catch_param {
+                Declaration::Identifier { ident, .. } => vec![ident.as_ref()],
+                Declaration::Pattern(pattern) => pattern.idents(),
+            };
+            let mut set = HashSet::new();
+            if !bound_names.into_iter().all(|name| set.insert(name)) {
+                return Err(ParseError::general(
+                    "duplicate catch parameter name",
+                    position,
+                ));
+            }
+            if !set.is_disjoint(&catch_block.lexically_declared_names()) {
+                return Err(ParseError::general(
+                    "catch parameter redeclared in catch block",
+                    position,
+                ));
+            }
+        }
+
+        Ok(node::Catch::new::<_, Declaration, _>(
+            catch_param,
+            catch_block,
+        ))
+    }
+}
+
+/// CatchParameter parsing
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/try...catch
+/// [spec]: https://tc39.es/ecma262/#prod-CatchParameter
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CatchParameter {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl CatchParameter {
+    /// Creates a new `CatchParameter` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for CatchParameter
+where
+    R: Read,
+{
+    type Output = Declaration;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Declaration, ParseError> {
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let bindings = ObjectBindingPattern::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                Ok(Declaration::new_with_object_pattern(bindings, None))
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let bindings = ArrayBindingPattern::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                Ok(Declaration::new_with_array_pattern(bindings, None))
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(|ident| Declaration::new_with_identifier(ident, None)),
+        }
+    }
+}
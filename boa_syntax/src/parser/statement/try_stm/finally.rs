@@ -1,10 +1,8 @@
 use crate::{
-    syntax::{
-        ast::{node, Keyword},
-        parser::{
-            statement::block::Block, AllowAwait, AllowReturn, AllowYield, Cursor, ParseError,
-            TokenParser,
-        },
+    ast::{node, Keyword},
+    parser::{
+        statement::block::Block, AllowAwait, AllowReturn, AllowYield, Cursor, ParseError,
+        TokenParser,
     },
     BoaProfiler,
 };
@@ -1,19 +1,17 @@
 //! Variable statement parsing.
 
 use crate::{
-    syntax::{
-        ast::{
-            node::{Declaration, DeclarationList},
-            Keyword, Punctuator,
-        },
-        lexer::TokenKind,
-        parser::statement::{ArrayBindingPattern, ObjectBindingPattern},
-        parser::{
-            cursor::{Cursor, SemicolonResult},
-            expression::Initializer,
-            statement::BindingIdentifier,
-            AllowAwait, AllowIn, AllowYield, ParseError, TokenParser,
-        },
+    ast::{
+        node::{Declaration, DeclarationList},
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::statement::{ArrayBindingPattern, ObjectBindingPattern},
+    parser::{
+        cursor::{Cursor, SemicolonResult},
+        expression::Initializer,
+        statement::BindingIdentifier,
+        AllowAwait, AllowIn, AllowYield, ParseError, TokenParser,
     },
     BoaProfiler,
 };
@@ -30,14 +28,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableStatement {
+pub(in crate::parser) struct VariableStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl VariableStatement {
     /// Creates a new `VariableStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -77,7 +75,7 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableDeclarationList
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableDeclarationList {
+pub(in crate::parser::statement) struct VariableDeclarationList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
@@ -85,7 +83,7 @@ pub(in crate::syntax::parser::statement) struct VariableDeclarationList {
 
 impl VariableDeclarationList {
     /// Creates a new `VariableDeclarationList` parser.
-    pub(in crate::syntax::parser::statement) fn new<I, Y, A>(
+    pub(in crate::parser::statement) fn new<I, Y, A>(
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
@@ -0,0 +1,39 @@
+use crate::{
+    ast::{
+        node::{Block, Call, GetConstField, Identifier, Node, With},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_with_parsing() {
+    check_parser(
+        "with (Math) { max(PI, 1); }",
+        vec![With::new(
+            Identifier::from("Math"),
+            Block::from(vec![Node::from(Call::new(
+                Identifier::from("max"),
+                vec![Identifier::from("PI").into(), Const::from(1).into()],
+            ))]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_single_statement() {
+    check_parser(
+        "with (a.b) c();",
+        vec![With::new(
+            GetConstField::new(Identifier::from("a"), "b"),
+            Call::new(Identifier::from("c"), vec![]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_strict_mode() {
+    check_invalid("'use strict'; with (a) {}");
+}
@@ -1,13 +1,18 @@
 //! Tests for the parser.
 
 use super::Parser;
-use crate::syntax::ast::{
+use crate::ast::{
     node::{
-        field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, Declaration, DeclarationList,
+        declaration::{
+            BindingPatternTypeArray, BindingPatternTypeObject, DeclarationPatternArray,
+            DeclarationPatternObject,
+        },
+        field::{GetConstField, GetSuperField},
+        ArrowFunctionDecl, Assign, BinOp, Call, Declaration, DeclarationList, DeclarationPattern,
         FormalParameter, FunctionDecl, Identifier, If, New, Node, Return, StatementList, UnaryOp,
     },
     op::{self, CompOp, LogOp, NumOp},
-    Const,
+    Const, Position, Span,
 };
 
 /// Checks that the given JavaScript string gives the expected expression.
@@ -277,6 +282,31 @@ fn assignment_multiline_terminator() {
     );
 }
 
+#[test]
+fn postfix_operator_line_terminator() {
+    check_parser(
+        "a\n++b",
+        vec![
+            Identifier::from("a").into(),
+            UnaryOp::new(op::UnaryOp::IncrementPre, Identifier::from("b")).into(),
+        ],
+    );
+    check_parser(
+        "a /*\n*/ --\nb",
+        vec![
+            Identifier::from("a").into(),
+            UnaryOp::new(op::UnaryOp::DecrementPre, Identifier::from("b")).into(),
+        ],
+    );
+    check_parser(
+        "a++\nb",
+        vec![
+            UnaryOp::new(op::UnaryOp::IncrementPost, Identifier::from("a")).into(),
+            Identifier::from("b").into(),
+        ],
+    );
+}
+
 #[test]
 fn bracketed_expr() {
     let s = r#"(b)"#;
@@ -319,6 +349,101 @@ fn spread_in_arrow_function() {
     );
 }
 
+#[test]
+fn pattern_in_arrow_function() {
+    check_parser(
+        "({a, b: c = 1}) => a",
+        vec![
+            ArrowFunctionDecl::new::<Box<[FormalParameter]>, StatementList>(
+                Box::new([FormalParameter::new_with_pattern(
+                    DeclarationPattern::Object(DeclarationPatternObject::new(
+                        vec![
+                            BindingPatternTypeObject::SingleName {
+                                ident: "a".into(),
+                                property_name: "a".into(),
+                                default_init: None,
+                            },
+                            BindingPatternTypeObject::SingleName {
+                                ident: "c".into(),
+                                property_name: "b".into(),
+                                default_init: Some(Const::from(1).into()),
+                            },
+                        ],
+                        None,
+                    )),
+                    false,
+                )]),
+                vec![Return::new::<_, _, Option<Box<str>>>(Identifier::from("a"), None).into()]
+                    .into(),
+            )
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn array_assignment_pattern() {
+    check_parser(
+        "[a, , b.c = 2, ...d] = e;",
+        vec![Assign::new(
+            DeclarationPattern::Array(DeclarationPatternArray::new(
+                vec![
+                    BindingPatternTypeArray::SingleName {
+                        ident: "a".into(),
+                        default_init: None,
+                    },
+                    BindingPatternTypeArray::Elision,
+                    BindingPatternTypeArray::PropertyAccess {
+                        access: GetConstField::new(Identifier::from("b"), "c").into(),
+                        default_init: Some(Const::from(2).into()),
+                    },
+                    BindingPatternTypeArray::SingleNameRest { ident: "d".into() },
+                ],
+                None,
+            )),
+            Identifier::from("e"),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn invalid_destructuring_targets() {
+    check_invalid("[a + 1] = b;");
+    check_invalid("({a: 1} = b);");
+    check_invalid("[...a, b] = c;");
+    check_invalid("({a: b.c}) => 0;");
+    check_invalid("(a, [a]) => 0;");
+    check_invalid("for (let [a] = b of c);");
+}
+
+#[test]
+fn new_target() {
+    check_parser(
+        "function f() { return new.target; }",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![Return::new(Node::NewTarget, None).into()],
+        )
+        .into()],
+    );
+    check_invalid("function f() { return new.tar; }");
+}
+
+#[test]
+fn super_property() {
+    check_parser(
+        "super.a; super[b];",
+        vec![
+            GetSuperField::new(Const::from("a")).into(),
+            GetSuperField::new(Identifier::from("b")).into(),
+        ],
+    );
+    check_invalid("super;");
+    check_invalid("super();");
+}
+
 #[test]
 fn empty_statement() {
     check_parser(
@@ -344,3 +469,84 @@ fn empty_statement() {
         ],
     );
 }
+
+/// Checks that the serialized AST can be read back into the same tree.
+#[cfg(feature = "deser")]
+#[test]
+fn serialized_ast_round_trip() {
+    let ast = Parser::new(
+        r"
+            let list = [1, 2, 3];
+            for (let i = 0; i < list.length; i++) {
+                { list[i] += 1; }
+            }
+        "
+        .as_bytes(),
+        false,
+    )
+    .parse_all()
+    .expect("failed to parse");
+
+    let json = serde_json::to_string(&ast).expect("failed to serialize the AST");
+    let deserialized: StatementList =
+        serde_json::from_str(&json).expect("failed to deserialize the AST");
+
+    assert_eq!(ast, deserialized);
+    assert_eq!(ast.spans(), deserialized.spans());
+}
+
+/// Checks that the spans of the statements are recorded.
+#[test]
+fn statement_spans() {
+    let ast = Parser::new(
+        "let a = 1;\nif (a) {\n  a += 1\n}\nfunction f() {}".as_bytes(),
+        false,
+    )
+    .parse_all()
+    .expect("failed to parse");
+
+    // Function declarations are hoisted to the start of the list, with their span. The
+    // semicolon after the `let` declaration is skipped by the statement list, so it is not part
+    // of the span of the declaration.
+    assert_eq!(
+        ast.spans(),
+        [
+            Span::new(Position::new(5, 1), Position::new(5, 16)),
+            Span::new(Position::new(1, 1), Position::new(1, 10)),
+            Span::new(Position::new(2, 1), Position::new(4, 2)),
+        ]
+    );
+
+    let block = match &ast.items()[2] {
+        Node::If(if_stm) => match if_stm.body() {
+            Node::Block(block) => block,
+            node => panic!("expected a block, got {:?}", node),
+        },
+        node => panic!("expected an if statement, got {:?}", node),
+    };
+    assert_eq!(
+        block.spans(),
+        [Span::new(Position::new(3, 3), Position::new(3, 9))]
+    );
+}
+
+/// Checks that the schema of the serialized AST describes the versioned envelope and the spans.
+#[cfg(feature = "schema")]
+#[test]
+fn versioned_ast_schema() {
+    use crate::ast::{versioned_ast_schema, AST_FORMAT_VERSION};
+
+    let schema = serde_json::to_value(versioned_ast_schema()).expect("failed to serialize");
+
+    assert_eq!(
+        schema["properties"]["version"]["const"],
+        serde_json::json!(AST_FORMAT_VERSION)
+    );
+    assert_eq!(
+        schema["properties"]["ast"]["allOf"][0]["$ref"],
+        "#/definitions/StatementList"
+    );
+    let statement_list = &schema["definitions"]["StatementList"]["properties"];
+    assert!(statement_list["items"].is_object());
+    assert!(statement_list["spans"].is_object());
+}
@@ -11,6 +11,7 @@ pub(super) fn init(context: &mut Context) -> JsObject {
 
     let obj = ObjectInitializer::new(context)
         .function(create_realm, "createRealm", 0)
+        .function(detach_array_buffer, "detachArrayBuffer", 2)
         .function(eval_script, "evalScript", 1)
         .property("global", global_obj, Attribute::default())
         // .property("agent", agent, Attribute::default())
@@ -39,13 +40,21 @@ fn create_realm(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> JsRes
 /// The `$262.detachArrayBuffer()` function.
 ///
 /// Implements the `DetachArrayBuffer` abstract operation.
-#[allow(dead_code)]
 fn detach_array_buffer(
     _this: &JsValue,
-    _: &[JsValue],
-    _context: &mut Context,
+    args: &[JsValue],
+    context: &mut Context,
 ) -> JsResult<JsValue> {
-    todo!()
+    if let Some(buffer) = args.get(0).and_then(JsValue::as_object) {
+        if let Some(buffer) = buffer.borrow_mut().as_array_buffer_mut() {
+            if !buffer.is_shared_array_buffer() {
+                buffer.detach();
+                return Ok(JsValue::null());
+            }
+        }
+    }
+
+    context.throw_type_error("detachArrayBuffer: the argument is not a non-shared ArrayBuffer")
 }
 
 /// The `$262.evalScript()` function.
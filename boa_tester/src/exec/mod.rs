@@ -6,10 +6,23 @@ use super::{
     Harness, Outcome, Phase, SuiteResult, Test, TestFlags, TestOutcomeResult, TestResult,
     TestSuite, IGNORED,
 };
-use boa::{parse, Context, JsValue};
+use boa::{
+    module::FsModuleLoader,
+    syntax::{
+        ast::node::StatementList,
+        parser::{ParseError, Parser},
+    },
+    Context, JsError, JsValue,
+};
 use colored::Colorize;
 use rayon::prelude::*;
-use std::panic;
+use std::{cell::RefCell, panic, rc::Rc};
+
+/// The result of an asynchronous test, reported through `$DONE` and `print()`.
+///
+/// It stays `None` until the test calls `$DONE`, which reports either success or the error the
+/// test failed with.
+type AsyncResult = Rc<RefCell<Option<Result<(), String>>>>;
 
 impl TestSuite {
     /// Runs the test suite.
@@ -86,6 +99,11 @@ impl TestSuite {
 impl Test {
     /// Runs the test.
     pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> Vec<TestResult> {
+        // Modules are always strict mode code, so they only run once.
+        if self.flags.contains(TestFlags::MODULE) {
+            return vec![self.run_once(harness, false, verbose)];
+        }
+
         let mut results = Vec::new();
         if self.flags.contains(TestFlags::STRICT) {
             results.push(self.run_once(harness, true, verbose));
@@ -111,44 +129,23 @@ impl Test {
         let (result, result_text) = if !IGNORED.contains_any_flag(self.flags)
             && !IGNORED.contains_test(&self.name)
             && !IGNORED.contains_any_feature(&self.features)
-            && (matches!(self.expected_outcome, Outcome::Positive)
-                || matches!(
-                    self.expected_outcome,
-                    Outcome::Negative {
-                        phase: Phase::Parse,
-                        error_type: _,
-                    }
-                )
-                || matches!(
-                    self.expected_outcome,
-                    Outcome::Negative {
-                        phase: Phase::Early,
-                        error_type: _,
-                    }
-                )
-                || matches!(
-                    self.expected_outcome,
-                    Outcome::Negative {
-                        phase: Phase::Runtime,
-                        error_type: _,
-                    }
-                )) {
+        {
             let res = panic::catch_unwind(|| match self.expected_outcome {
                 Outcome::Positive => {
-                    // TODO: implement async and add `harness/doneprintHandle.js` to the includes.
-
-                    match self.set_up_env(harness, strict) {
-                        Ok(mut context) => {
-                            let res = context.eval(&self.content.as_ref());
-
-                            let passed = res.is_ok();
-                            let text = match res {
-                                Ok(val) => format!("{}", val.display()),
-                                Err(e) => format!("Uncaught {}", e.display()),
-                            };
-
-                            (passed, text)
-                        }
+                    let async_result = AsyncResult::default();
+
+                    match self.set_up_env(harness, strict, &async_result) {
+                        Ok(mut context) => match self.evaluate(&mut context) {
+                            Err(e) => (false, format!("Uncaught {}", e)),
+                            Ok(val) if self.flags.contains(TestFlags::ASYNC) => {
+                                match async_result.borrow().as_ref() {
+                                    Some(Ok(())) => (true, format!("{}", val.display())),
+                                    Some(Err(e)) => (false, format!("Uncaught {}", e)),
+                                    None => (false, "the test did not call $DONE".to_owned()),
+                                }
+                            }
+                            Ok(val) => (true, format!("{}", val.display())),
+                        },
                         Err(e) => (false, e),
                     }
                 }
@@ -167,30 +164,29 @@ impl Test {
                         self.name
                     );
 
-                    match parse(&self.content.as_ref(), strict) {
+                    match self.parse(strict) {
                         Ok(n) => (false, format!("{:?}", n)),
                         Err(e) => (true, format!("Uncaught {}", e)),
                     }
                 }
                 Outcome::Negative {
                     phase: Phase::Resolution,
-                    error_type: _,
-                } => todo!("check module resolution errors"),
-                Outcome::Negative {
+                    ref error_type,
+                }
+                | Outcome::Negative {
                     phase: Phase::Runtime,
                     ref error_type,
                 } => {
-                    if let Err(e) = parse(&self.content.as_ref(), strict) {
+                    if let Err(e) = self.parse(strict) {
                         (false, format!("Uncaught {}", e))
                     } else {
-                        match self.set_up_env(harness, strict) {
-                            Ok(mut context) => match context.eval(&self.content.as_ref()) {
+                        match self.set_up_env(harness, strict, &AsyncResult::default()) {
+                            Ok(mut context) => match self.evaluate(&mut context) {
                                 Ok(res) => (false, format!("{}", res.display())),
                                 Err(e) => {
-                                    let passed =
-                                        e.display().to_string().contains(error_type.as_ref());
+                                    let passed = e.to_string().contains(error_type.as_ref());
 
-                                    (passed, format!("Uncaught {}", e.display()))
+                                    (passed, format!("Uncaught {}", e))
                                 }
                             },
                             Err(e) => (false, e),
@@ -258,14 +254,56 @@ impl Test {
         }
     }
 
+    /// Parses the test, as a module for module tests, and as a script otherwise.
+    fn parse(&self, strict: bool) -> Result<StatementList, ParseError> {
+        let mut parser = Parser::new(self.content.as_bytes(), strict);
+        if self.flags.contains(TestFlags::MODULE) {
+            parser.parse_module()
+        } else {
+            parser.parse_all()
+        }
+    }
+
+    /// Evaluates the test, and then runs the jobs it enqueued.
+    ///
+    /// Module tests are imported with the module loader of the context, so that the modules they
+    /// import are resolved against the directory of the test.
+    fn evaluate(&self, context: &mut Context) -> Result<JsValue, JsError> {
+        let value = if self.flags.contains(TestFlags::MODULE) {
+            let file_name = self.path.file_name().unwrap_or_default().to_string_lossy();
+            context.import_module(&format!("./{}", file_name))?.into()
+        } else {
+            context.eval(&self.content.as_ref())?
+        };
+        context.run_jobs()?;
+
+        Ok(value)
+    }
+
     /// Sets the environment up to run the test.
-    fn set_up_env(&self, harness: &Harness, strict: bool) -> Result<Context, String> {
+    ///
+    /// The `print()` function of the environment reports the result of asynchronous tests to
+    /// `async_result`.
+    fn set_up_env(
+        &self,
+        harness: &Harness,
+        strict: bool,
+        async_result: &AsyncResult,
+    ) -> Result<Context, String> {
         // Create new Realm
         let mut context = Context::new();
 
+        // Modules, and the dynamic imports of scripts, are loaded from the directory of the test.
+        if let Some(directory) = self.path.parent() {
+            context.set_module_loader(FsModuleLoader::new(directory));
+        }
+
         // Register the print() function.
+        let async_result = async_result.clone();
         context
-            .register_global_function("print", 1, test262_print)
+            .register_global_closure("print", 1, move |_this, args, context| {
+                test262_print(args, &async_result, context)
+            })
             .map_err(|e| {
                 format!(
                     "could not register the global print() function:\n{}",
@@ -279,15 +317,28 @@ impl Test {
         if strict {
             context
                 .eval(r#""use strict";"#)
-                .map_err(|e| format!("could not set strict mode:\n{}", e.display()))?;
+                .map_err(|e| format!("could not set strict mode:\n{}", e))?;
         }
 
         context
             .eval(&harness.assert.as_ref())
-            .map_err(|e| format!("could not run assert.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run assert.js:\n{}", e))?;
         context
             .eval(&harness.sta.as_ref())
-            .map_err(|e| format!("could not run sta.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run sta.js:\n{}", e))?;
+
+        // Asynchronous tests report their result with `$DONE`, which prints it.
+        if self.flags.contains(TestFlags::ASYNC) {
+            context
+                .eval(
+                    &harness
+                        .includes
+                        .get("doneprintHandle.js")
+                        .ok_or("could not find the doneprintHandle.js include file.")?
+                        .as_ref(),
+                )
+                .map_err(|e| format!("could not run doneprintHandle.js:\nUncaught {}", e))?;
+        }
 
         for include in self.includes.iter() {
             context
@@ -301,8 +352,7 @@ impl Test {
                 .map_err(|e| {
                     format!(
                         "could not run the {} include file:\nUncaught {}",
-                        include,
-                        e.display()
+                        include, e
                     )
                 })?;
         }
@@ -312,6 +362,27 @@ impl Test {
 }
 
 /// `print()` function required by the test262 suite.
-fn test262_print(_this: &JsValue, _: &[JsValue], _context: &mut Context) -> boa::JsResult<JsValue> {
-    todo!("print() function");
+///
+/// Asynchronous tests print `Test262:AsyncTestComplete` or `Test262:AsyncTestFailure:<error>`
+/// through `$DONE`, which is recorded in `async_result`. Any other message is ignored.
+fn test262_print(
+    args: &[JsValue],
+    async_result: &AsyncResult,
+    context: &mut Context,
+) -> boa::JsResult<JsValue> {
+    let message = args
+        .first()
+        .cloned()
+        .unwrap_or_default()
+        .to_string(context)?;
+
+    if message.as_str() == "Test262:AsyncTestComplete" {
+        async_result.borrow_mut().get_or_insert(Ok(()));
+    } else if let Some(error) = message.strip_prefix("Test262:AsyncTestFailure:") {
+        async_result
+            .borrow_mut()
+            .get_or_insert_with(|| Err(error.to_owned()));
+    }
+
+    Ok(JsValue::undefined())
 }